@@ -313,35 +313,49 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
                 };
                 Ok(Some(x))
             }
-            Cmd::Transaction(tx) => {
-                let mut condition = vec![];
-                for c in tx.condition {
-                    condition.push(self.proc_condition(c));
-                }
-
-                let mut if_then = vec![];
-                for op in tx.if_then {
-                    if_then.push(self.proc_txop(op)?);
-                }
-
-                let mut else_then = vec![];
-                for op in tx.else_then {
-                    else_then.push(self.proc_txop(op)?);
+            Cmd::Transaction(tx) => Ok(Some(LogEntry {
+                txid: log_entry.txid,
+                time_ms: log_entry.time_ms,
+                cmd: Cmd::Transaction(self.proc_txn(tx)?),
+            })),
+            Cmd::BatchTransaction(txs) => {
+                let mut txns = vec![];
+                for tx in txs {
+                    txns.push(self.proc_txn(tx)?);
                 }
 
                 Ok(Some(LogEntry {
                     txid: log_entry.txid,
                     time_ms: log_entry.time_ms,
-                    cmd: Cmd::Transaction(TxnRequest {
-                        condition,
-                        if_then,
-                        else_then,
-                    }),
+                    cmd: Cmd::BatchTransaction(txns),
                 }))
             }
         }
     }
 
+    fn proc_txn(&self, tx: TxnRequest) -> Result<TxnRequest, anyhow::Error> {
+        let mut condition = vec![];
+        for c in tx.condition {
+            condition.push(self.proc_condition(c));
+        }
+
+        let mut if_then = vec![];
+        for op in tx.if_then {
+            if_then.push(self.proc_txop(op)?);
+        }
+
+        let mut else_then = vec![];
+        for op in tx.else_then {
+            else_then.push(self.proc_txop(op)?);
+        }
+
+        Ok(TxnRequest {
+            condition,
+            if_then,
+            else_then,
+        })
+    }
+
     fn proc_upsert_kv(&self, ups: UpsertKV) -> Result<Option<UpsertKV>, anyhow::Error> {
         match ups.value {
             Operation::Update(v) => {
@@ -352,6 +366,7 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
                     seq: ups.seq,
                     value: Operation::Update(buf),
                     value_meta: ups.value_meta,
+                    keep_ttl: ups.keep_ttl,
                 }))
             }
             Operation::Delete => Ok(None),
@@ -35,12 +35,16 @@ use databend_query::api::HttpService;
 use databend_query::api::RpcService;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::metrics::MetricService;
+use databend_query::pipes::PipeScheduler;
 use databend_query::servers::FlightSQLServer;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::servers::MySQLHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
+use databend_query::sessions::ResultPersistJanitor;
+use databend_query::statistics::AnalyzeDaemon;
+use databend_query::tasks::TaskScheduler;
 use databend_query::GlobalServices;
 use tracing::info;
 
@@ -226,6 +230,18 @@ async fn main_entrypoint() -> Result<()> {
         );
     }
 
+    // Statistics refresh of external tables.
+    AnalyzeDaemon::instance().start().await;
+
+    // Removal of the expired persisted query results.
+    ResultPersistJanitor::instance().start().await;
+
+    // Scheduled tasks.
+    TaskScheduler::instance().start().await;
+
+    // Pipes consuming streaming sources.
+    PipeScheduler::instance().start().await;
+
     // Print information to users.
     println!("Databend Query");
     println!();
@@ -138,6 +138,11 @@ build_exceptions! {
     // create table or alter table add column with internal column name
     TableWithInternalColumnName(1110),
     EmptyShareEndpointConfig(1111),
+    ResultTooLarge(1112),
+    RecursiveCteLimitExceeded(1113),
+    HashJoinSpillLimitExceeded(1114),
+    // rename a column on which privileges are granted
+    ColumnGrantExists(1115),
 
     // Data Related Errors
 
@@ -183,6 +188,7 @@ build_exceptions! {
     IllegalUserInfoFormat(2203),
     UnknownRole(2204),
     InvalidRole(2206),
+    CyclicRoleGrant(2207),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -234,6 +240,49 @@ build_exceptions! {
     UnknownUDF(2602),
     UdfAlreadyExists(2603),
 
+    // Masking policy error codes.
+    MaskingPolicyAlreadyExists(2604),
+    UnknownMaskingPolicy(2605),
+    MaskingPolicyIsReferenced(2606),
+
+    // Network policy error codes.
+    NetworkPolicyAlreadyExists(2607),
+    UnknownNetworkPolicy(2608),
+    NetworkPolicyIsUsedByUser(2609),
+    NetworkPolicyViolation(2610),
+
+    // Task error codes.
+    TaskAlreadyExists(2611),
+    UnknownTask(2612),
+    TaskIsRunning(2613),
+
+    // Transaction error codes.
+    TransactionConflict(2614),
+    TransactionNotSupported(2615),
+    TransactionTimeout(2616),
+
+    // External function error codes.
+    ExternalFunctionAlreadyExists(2617),
+    UnknownExternalFunction(2618),
+    ExternalFunctionError(2619),
+
+    // Row access policy error codes.
+    RowAccessPolicyAlreadyExists(2620),
+    UnknownRowAccessPolicy(2621),
+    RowAccessPolicyIsReferenced(2622),
+
+    // Sequence error codes.
+    SequenceAlreadyExists(2623),
+    UnknownSequence(2624),
+    SequenceExhausted(2625),
+    SequenceIsReferenced(2630),
+
+    // Pipe error codes.
+    PipeAlreadyExists(2626),
+    UnknownPipe(2627),
+    PipeOffsetsMismatch(2628),
+    PipeSourceError(2629),
+
     // Database error codes.
     UnknownDatabaseEngine(2701),
     UnknownTableEngine(2702),
@@ -262,6 +311,7 @@ build_exceptions! {
     IllegalTenantQuotaFormat(2901),
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
+    DatabaseQuotaExceeded(2904),
 
 }
 
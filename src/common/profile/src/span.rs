@@ -22,11 +22,17 @@ pub type ProfSpanSetRef<K = u32> = Arc<Mutex<ProfSpanSet<K>>>;
 pub struct ProfSpan {
     /// The time spent to process in nanoseconds
     pub process_time: u64,
+    /// The bytes spilled to the storage
+    pub spilled_bytes: u64,
+    /// The number of the spilled partitions
+    pub spilled_partitions: u64,
 }
 
 impl ProfSpan {
     pub fn add(&mut self, other: &Self) {
         self.process_time += other.process_time;
+        self.spilled_bytes += other.spilled_bytes;
+        self.spilled_partitions += other.spilled_partitions;
     }
 }
 
@@ -65,6 +71,7 @@ impl ProfSpanBuilder {
     pub fn finish(self) -> ProfSpan {
         ProfSpan {
             process_time: self.process_time,
+            ..Default::default()
         }
     }
 }
@@ -46,6 +46,9 @@ use serde::Serialize;
 pub struct StorageConfig {
     pub num_cpus: u64,
     pub allow_insecure: bool,
+    /// Max number of requests sent to the storage at the same time by this node,
+    /// 0 means not limited.
+    pub max_inflight_requests: u64,
 
     pub params: StorageParams,
 }
@@ -0,0 +1,71 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use dashmap::DashMap;
+
+/// Per-file load status of a `COPY INTO <table>`, shared by the processors
+/// reading the files and the interpreter committing the copy.
+#[derive(Default, Debug)]
+pub struct CopyStatus {
+    /// path of file -> status
+    pub files: DashMap<String, FileStatus>,
+}
+
+impl CopyStatus {
+    pub fn add_chunk(&self, path: &str, status: FileStatus) {
+        self.files
+            .entry(path.to_string())
+            .and_modify(|s| s.merge(&status))
+            .or_insert(status);
+    }
+
+    pub fn get(&self, path: &str) -> Option<FileStatus> {
+        self.files.get(path).map(|s| s.value().clone())
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct FileStatus {
+    pub rows_loaded: u64,
+    pub error_count: u64,
+    pub first_error: Option<FileParseError>,
+}
+
+impl FileStatus {
+    pub fn rows_parsed(&self) -> u64 {
+        self.rows_loaded + self.error_count
+    }
+
+    fn merge(&mut self, other: &FileStatus) {
+        self.rows_loaded += other.rows_loaded;
+        self.error_count += other.error_count;
+        // Chunks of a file may be finished out of order, keep the error of the smallest line.
+        match (&self.first_error, &other.first_error) {
+            (None, Some(_)) => self.first_error = other.first_error.clone(),
+            (Some(a), Some(b)) if b.line.is_some() && (a.line.is_none() || b.line < a.line) => {
+                self.first_error = other.first_error.clone()
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileParseError {
+    pub message: String,
+    /// 1-based line of the row in the file, if known.
+    pub line: Option<u64>,
+    /// 1-based column failing to decode, if known.
+    pub column: Option<u64>,
+}
@@ -0,0 +1,545 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::future::Future;
+use std::io;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use common_base::base::tokio::sync::OwnedSemaphorePermit;
+use common_base::base::tokio::sync::Semaphore;
+use common_base::base::tokio::time::sleep;
+use futures::future::poll_fn;
+use futures::future::BoxFuture;
+use futures::future::WeakShared;
+use futures::FutureExt;
+use metrics::counter;
+use metrics::gauge;
+use opendal::ops::*;
+use opendal::raw::oio;
+use opendal::raw::Accessor;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::RpCreate;
+use opendal::raw::RpDelete;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpScan;
+use opendal::raw::RpStat;
+use opendal::raw::RpWrite;
+use opendal::Error;
+use opendal::ErrorKind;
+use opendal::Result;
+
+const MAX_THROTTLE_RETRIES: usize = 10;
+const MIN_THROTTLE_BACKOFF: Duration = Duration::from_millis(100);
+const MAX_THROTTLE_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Reads of larger ranges, or without a known size, are streamed instead of being
+/// buffered and shared by the concurrent readers.
+const MAX_COALESCED_READ_SIZE: u64 = 64 * 1024 * 1024;
+
+/// IoLimitLayer keeps a node from overwhelming the storage service under heavy
+/// parallel scans:
+///
+/// - At most `max_inflight` requests are sent at the same time, the others wait for
+///   a permit. Ranged reads hold the permit until all the bytes are received.
+/// - The requests throttled by the service are retried with exponential backoff,
+///   which is longer than the one of the `RetryLayer` below.
+/// - Concurrent reads of the same range of the same file are coalesced, only one
+///   request is sent and the bytes are shared by all the readers.
+///
+/// The layer should be shared by all the operators of the node, so that they are
+/// limited together.
+#[derive(Clone)]
+pub struct IoLimitLayer {
+    semaphore: Option<Arc<Semaphore>>,
+    stats: Arc<IoLimitStats>,
+    inflight_reads: Arc<Mutex<HashMap<ReadKey, WeakShared<SharedRead>>>>,
+}
+
+impl IoLimitLayer {
+    /// Create a new io limit layer, `max_inflight` of 0 means not limited.
+    pub fn new(max_inflight: usize) -> Self {
+        IoLimitLayer {
+            semaphore: match max_inflight {
+                0 => None,
+                n => Some(Arc::new(Semaphore::new(n))),
+            },
+            stats: Arc::new(IoLimitStats::default()),
+            inflight_reads: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn stats(&self) -> Arc<IoLimitStats> {
+        self.stats.clone()
+    }
+
+    async fn acquire(&self) -> InflightGuard {
+        let permit = match &self.semaphore {
+            Some(semaphore) => Some(
+                semaphore
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .expect("io limit semaphore must not be closed"),
+            ),
+            None => None,
+        };
+        self.stats.inc_inflight();
+
+        InflightGuard {
+            _permit: permit,
+            stats: self.stats.clone(),
+        }
+    }
+
+    fn backoff(&self) -> ThrottleBackoff {
+        ThrottleBackoff {
+            retries: 0,
+            delay: MIN_THROTTLE_BACKOFF,
+            stats: self.stats.clone(),
+        }
+    }
+}
+
+impl Debug for IoLimitLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IoLimitLayer")
+            .field("semaphore", &self.semaphore)
+            .field("stats", &self.stats)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Accessor> Layer<A> for IoLimitLayer {
+    type LayeredAccessor = IoLimitAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        IoLimitAccessor {
+            inner: Arc::new(inner),
+            limiter: self.clone(),
+        }
+    }
+}
+
+/// IoLimitStats holds the counters of the io limit layer, which are also reported
+/// as metrics.
+#[derive(Debug, Default)]
+pub struct IoLimitStats {
+    /// Requests being sent to the storage.
+    inflight: AtomicU64,
+    /// Requests retried because of throttling.
+    throttle_retries: AtomicU64,
+    /// Reads served by the request of another concurrent read.
+    coalesced_reads: AtomicU64,
+}
+
+impl IoLimitStats {
+    pub fn get_inflight(&self) -> u64 {
+        self.inflight.load(Ordering::Relaxed)
+    }
+
+    pub fn get_throttle_retries(&self) -> u64 {
+        self.throttle_retries.load(Ordering::Relaxed)
+    }
+
+    pub fn get_coalesced_reads(&self) -> u64 {
+        self.coalesced_reads.load(Ordering::Relaxed)
+    }
+
+    fn inc_inflight(&self) {
+        let v = self.inflight.fetch_add(1, Ordering::Relaxed) + 1;
+        gauge!("storage_io_inflight_requests", v as f64);
+    }
+
+    fn dec_inflight(&self) {
+        let v = self.inflight.fetch_sub(1, Ordering::Relaxed) - 1;
+        gauge!("storage_io_inflight_requests", v as f64);
+    }
+
+    fn inc_throttle_retries(&self) {
+        self.throttle_retries.fetch_add(1, Ordering::Relaxed);
+        counter!("storage_io_throttle_retries", 1);
+    }
+
+    fn inc_coalesced_reads(&self) {
+        self.coalesced_reads.fetch_add(1, Ordering::Relaxed);
+        counter!("storage_io_coalesced_reads", 1);
+    }
+}
+
+/// Releases the permit and the in-flight count of a request on drop.
+struct InflightGuard {
+    _permit: Option<OwnedSemaphorePermit>,
+    stats: Arc<IoLimitStats>,
+}
+
+impl Drop for InflightGuard {
+    fn drop(&mut self) {
+        self.stats.dec_inflight();
+    }
+}
+
+struct ThrottleBackoff {
+    retries: usize,
+    delay: Duration,
+    stats: Arc<IoLimitStats>,
+}
+
+impl ThrottleBackoff {
+    /// Returns the delay before retrying the request failed with `err`, or None if the
+    /// request should not be retried.
+    fn next(&mut self, err: &Error) -> Option<Duration> {
+        if !is_throttled(err) || self.retries >= MAX_THROTTLE_RETRIES {
+            return None;
+        }
+        self.retries += 1;
+        self.stats.inc_throttle_retries();
+
+        let delay = self.delay;
+        self.delay = (self.delay * 2).min(MAX_THROTTLE_BACKOFF);
+        Some(delay)
+    }
+}
+
+/// The error messages of the services asking the clients to slow down, such as
+/// `SlowDown` of S3 and `429 Too Many Requests` of the others.
+const THROTTLE_HINTS: &[&str] = &[
+    "SlowDown",
+    "TooManyRequests",
+    "Too Many Requests",
+    "RequestLimitExceeded",
+    "Throttl",
+    "ServerBusy",
+];
+
+/// The services report throttling as unexpected errors, which are told apart by the
+/// error message. They may have been marked as persistent by the `RetryLayer` after
+/// its retries have been used up, so the temporary flag is not checked.
+fn is_throttled(err: &Error) -> bool {
+    if err.kind() != ErrorKind::Unexpected {
+        return false;
+    }
+    let message = err.to_string();
+    THROTTLE_HINTS.iter().any(|hint| message.contains(hint))
+}
+
+macro_rules! retry_throttled {
+    ($limiter:expr, $request:expr) => {{
+        let mut backoff = $limiter.backoff();
+        loop {
+            match $request {
+                Err(err) => match backoff.next(&err) {
+                    Some(delay) => sleep(delay).await,
+                    None => break Err(err),
+                },
+                result => break result,
+            }
+        }
+    }};
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct ReadKey {
+    path: String,
+    offset: Option<u64>,
+    size: Option<u64>,
+}
+
+type SharedRead = BoxFuture<'static, std::result::Result<Bytes, SharedError>>;
+
+/// The error of a coalesced read, cloned for every reader.
+#[derive(Clone, Debug)]
+struct SharedError {
+    kind: ErrorKind,
+    message: String,
+    temporary: bool,
+}
+
+impl From<Error> for SharedError {
+    fn from(err: Error) -> Self {
+        SharedError {
+            kind: err.kind(),
+            message: err.to_string(),
+            temporary: err.is_temporary(),
+        }
+    }
+}
+
+impl From<SharedError> for Error {
+    fn from(err: SharedError) -> Self {
+        let e = Error::new(err.kind, &err.message);
+        if err.temporary { e.set_temporary() } else { e }
+    }
+}
+
+#[derive(Clone)]
+pub struct IoLimitAccessor<A: Accessor> {
+    inner: Arc<A>,
+    limiter: IoLimitLayer,
+}
+
+impl<A: Accessor> Debug for IoLimitAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IoLimitAccessor")
+            .field("inner", &self.inner)
+            .field("limiter", &self.limiter)
+            .finish()
+    }
+}
+
+impl<A: Accessor> IoLimitAccessor<A> {
+    async fn coalesced_read(&self, path: &str, args: OpRead) -> Result<Bytes> {
+        let range = args.range();
+        let key = ReadKey {
+            path: path.to_string(),
+            offset: range.offset(),
+            size: range.size(),
+        };
+
+        let read = {
+            let mut inflight_reads = self.limiter.inflight_reads.lock().unwrap();
+            match inflight_reads.get(&key).and_then(|read| read.upgrade()) {
+                Some(read) => {
+                    self.limiter.stats.inc_coalesced_reads();
+                    read
+                }
+                None => {
+                    let read = self.read_to_end(key.clone(), args).boxed().shared();
+                    if let Some(weak) = read.downgrade() {
+                        inflight_reads.insert(key, weak);
+                    }
+                    read
+                }
+            }
+        };
+
+        read.await.map_err(Error::from)
+    }
+
+    fn read_to_end(
+        &self,
+        key: ReadKey,
+        args: OpRead,
+    ) -> impl Future<Output = std::result::Result<Bytes, SharedError>> + Send + 'static {
+        let inner = self.inner.clone();
+        let limiter = self.limiter.clone();
+
+        async move {
+            let result = {
+                let _guard = limiter.acquire().await;
+                retry_throttled!(
+                    limiter,
+                    read_all(inner.as_ref(), &key.path, args.clone()).await
+                )
+            };
+            // The reads coming after this one will send a new request.
+            limiter.inflight_reads.lock().unwrap().remove(&key);
+            result.map_err(SharedError::from)
+        }
+    }
+}
+
+async fn read_all<A: Accessor>(inner: &A, path: &str, args: OpRead) -> Result<Bytes> {
+    let (_, mut reader) = inner.read(path, args).await?;
+
+    let mut content = Vec::new();
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let n = poll_fn(|cx| oio::Read::poll_read(&mut reader, cx, &mut buf)).await?;
+        if n == 0 {
+            break;
+        }
+        content.extend_from_slice(&buf[..n]);
+    }
+    Ok(content.into())
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for IoLimitAccessor<A> {
+    type Inner = A;
+    type Reader = IoLimitReader<A::Reader>;
+    type BlockingReader = A::BlockingReader;
+    type Writer = IoLimitWriter<A::Writer>;
+    type BlockingWriter = A::BlockingWriter;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create(&self, path: &str, args: OpCreate) -> Result<RpCreate> {
+        let _guard = self.limiter.acquire().await;
+        retry_throttled!(self.limiter, self.inner.create(path, args.clone()).await)
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        match args.range().size() {
+            Some(size) if size <= MAX_COALESCED_READ_SIZE => {
+                let content = self.coalesced_read(path, args).await?;
+                let rp = RpRead::new(content.len() as u64);
+                Ok((rp, IoLimitReader::Buffered { content, pos: 0 }))
+            }
+            _ => {
+                let _guard = self.limiter.acquire().await;
+                let (rp, r) =
+                    retry_throttled!(self.limiter, self.inner.read(path, args.clone()).await)?;
+                Ok((rp, IoLimitReader::Streaming(r)))
+            }
+        }
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let _guard = self.limiter.acquire().await;
+        retry_throttled!(self.limiter, self.inner.write(path, args.clone()).await).map(|(rp, w)| {
+            (rp, IoLimitWriter {
+                inner: w,
+                limiter: self.limiter.clone(),
+            })
+        })
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let _guard = self.limiter.acquire().await;
+        retry_throttled!(self.limiter, self.inner.stat(path, args.clone()).await)
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let _guard = self.limiter.acquire().await;
+        retry_throttled!(self.limiter, self.inner.delete(path, args.clone()).await)
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        let _guard = self.limiter.acquire().await;
+        retry_throttled!(self.limiter, self.inner.list(path, args.clone()).await)
+    }
+
+    async fn scan(&self, path: &str, args: OpScan) -> Result<(RpScan, Self::Pager)> {
+        let _guard = self.limiter.acquire().await;
+        retry_throttled!(self.limiter, self.inner.scan(path, args.clone()).await)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+
+    fn blocking_scan(&self, path: &str, args: OpScan) -> Result<(RpScan, Self::BlockingPager)> {
+        self.inner.blocking_scan(path, args)
+    }
+}
+
+pub enum IoLimitReader<R> {
+    /// The bytes of a coalesced read.
+    Buffered { content: Bytes, pos: usize },
+    /// Only opening the reader is limited, a streaming reader could be kept open for
+    /// long while its consumer waits for other reads.
+    Streaming(R),
+}
+
+impl<R: oio::Read> oio::Read for IoLimitReader<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        match self {
+            IoLimitReader::Buffered { content, pos } => {
+                let remaining = &content[(*pos).min(content.len())..];
+                let n = remaining.len().min(buf.len());
+                buf[..n].copy_from_slice(&remaining[..n]);
+                *pos += n;
+                Poll::Ready(Ok(n))
+            }
+            IoLimitReader::Streaming(inner) => inner.poll_read(cx, buf),
+        }
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, seek: io::SeekFrom) -> Poll<Result<u64>> {
+        match self {
+            IoLimitReader::Buffered { content, pos } => {
+                let new_pos = match seek {
+                    io::SeekFrom::Start(n) => Some(n),
+                    io::SeekFrom::End(n) => (content.len() as u64).checked_add_signed(n),
+                    io::SeekFrom::Current(n) => (*pos as u64).checked_add_signed(n),
+                };
+                match new_pos {
+                    Some(n) => {
+                        *pos = n as usize;
+                        Poll::Ready(Ok(n))
+                    }
+                    None => Poll::Ready(Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "invalid seek to a negative or overflowing position",
+                    ))),
+                }
+            }
+            IoLimitReader::Streaming(inner) => inner.poll_seek(cx, seek),
+        }
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        match self {
+            IoLimitReader::Buffered { content, pos } => {
+                if *pos >= content.len() {
+                    return Poll::Ready(None);
+                }
+                let bs = content.slice(*pos..);
+                *pos = content.len();
+                Poll::Ready(Some(Ok(bs)))
+            }
+            IoLimitReader::Streaming(inner) => inner.poll_next(cx),
+        }
+    }
+}
+
+/// Every write of the writer is limited and retried on its own, so the writer doesn't
+/// hold a permit while it's idle.
+pub struct IoLimitWriter<W> {
+    inner: W,
+    limiter: IoLimitLayer,
+}
+
+#[async_trait]
+impl<W: oio::Write> oio::Write for IoLimitWriter<W> {
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        let _guard = self.limiter.acquire().await;
+        retry_throttled!(self.limiter, self.inner.write(bs.clone()).await)
+    }
+
+    async fn append(&mut self, bs: Bytes) -> Result<()> {
+        let _guard = self.limiter.acquire().await;
+        retry_throttled!(self.limiter, self.inner.append(bs.clone()).await)
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let _guard = self.limiter.acquire().await;
+        retry_throttled!(self.limiter, self.inner.close().await)
+    }
+}
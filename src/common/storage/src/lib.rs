@@ -37,7 +37,12 @@ pub use config::StorageConfig;
 
 mod operator;
 pub use operator::init_operator;
+pub use operator::is_url_allowed;
 pub use operator::DataOperator;
+pub use operator::SpillOperator;
+
+mod operator_cache;
+pub use operator_cache::StorageOperatorCache;
 
 mod metrics;
 pub use metrics::StorageMetrics;
@@ -45,6 +50,10 @@ pub use metrics::StorageMetricsLayer;
 
 mod runtime_layer;
 
+mod io_limit_layer;
+pub use io_limit_layer::IoLimitLayer;
+pub use io_limit_layer::IoLimitStats;
+
 mod column_node;
 pub use column_node::ColumnNode;
 pub use column_node::ColumnNodes;
@@ -53,8 +62,14 @@ mod parquet;
 pub use parquet::read_parquet_metas_in_parallel;
 pub use parquet::read_parquet_schema_async;
 
+mod copy;
+pub use copy::CopyStatus;
+pub use copy::FileParseError;
+pub use copy::FileStatus;
+
 mod stage;
 pub use stage::init_stage_operator;
+pub use stage::stage_path_storage_params;
 pub use stage::StageFileInfo;
 pub use stage::StageFileStatus;
 pub use stage::StageFilesInfo;
@@ -16,6 +16,7 @@ use std::env;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Result;
+use std::path::PathBuf;
 use std::time::Duration;
 
 use anyhow::anyhow;
@@ -42,11 +43,15 @@ use opendal::layers::LoggingLayer;
 use opendal::layers::MetricsLayer;
 use opendal::layers::RetryLayer;
 use opendal::layers::TracingLayer;
+use opendal::raw::HttpClient;
 use opendal::services;
 use opendal::Builder;
 use opendal::Operator;
+use reqwest::redirect::Policy;
+use reqwest::Url;
 
 use crate::runtime_layer::RuntimeLayer;
+use crate::IoLimitLayer;
 use crate::StorageConfig;
 
 /// init_operator will init an opendal operator based on storage config.
@@ -172,12 +177,43 @@ fn init_ipfs_operator(cfg: &StorageIpfsConfig) -> Result<impl Builder> {
     Ok(builder)
 }
 
+/// Redirects followed when loading files from an HTTP endpoint.
+const HTTP_MAX_REDIRECTS: usize = 5;
+
 fn init_http_operator(cfg: &StorageHttpConfig) -> Result<(impl Builder, ImmutableIndexLayer)> {
     let mut builder = services::Http::default();
 
     // Endpoint.
     builder.endpoint(&cfg.endpoint_url);
 
+    // Auth.
+    if !cfg.token.is_empty() {
+        builder.token(&cfg.token);
+    }
+
+    // Http client, TLS certificates are always verified.
+    let redirect = if cfg.allowed_url_prefixes.is_empty() {
+        Policy::limited(HTTP_MAX_REDIRECTS)
+    } else {
+        // The target of each redirect must be allowed as well.
+        let prefixes = cfg.allowed_url_prefixes.clone();
+        Policy::custom(move |attempt| {
+            if attempt.previous().len() > HTTP_MAX_REDIRECTS {
+                attempt.error("too many redirects")
+            } else if !is_url_allowed(attempt.url().as_str(), &prefixes) {
+                let msg = format!(
+                    "redirect to {} is not allowed, it doesn't match any allowed url prefix",
+                    attempt.url()
+                );
+                attempt.error(msg)
+            } else {
+                attempt.follow()
+            }
+        })
+    };
+    let client = reqwest::ClientBuilder::new().redirect(redirect);
+    builder.http_client(HttpClient::build(client)?);
+
     // HTTP Service is read-only and doesn't support list operation.
     // ImmutableIndexLayer will build an in-memory immutable index for it.
     let mut immutable_layer = ImmutableIndexLayer::default();
@@ -194,6 +230,40 @@ fn init_http_operator(cfg: &StorageHttpConfig) -> Result<(impl Builder, Immutabl
     Ok((builder, immutable_layer))
 }
 
+/// Whether the url matches one of the allowed url prefixes.
+///
+/// Both are parsed and normalized first: the scheme, host and port must be the same, and the
+/// path must be under the path of the prefix on a `/` boundary. Urls with user info or encoded
+/// slashes in the path are never allowed.
+pub fn is_url_allowed(url: &str, prefixes: &[String]) -> bool {
+    let url = match Url::parse(url) {
+        Ok(url) => url,
+        Err(_) => return false,
+    };
+    if !url.username().is_empty() || url.password().is_some() || url.host_str().is_none() {
+        return false;
+    }
+    let path = url.path().to_ascii_lowercase();
+    if path.contains("%2f") || path.contains("%5c") {
+        return false;
+    }
+
+    prefixes.iter().any(|prefix| match Url::parse(prefix) {
+        Ok(prefix) => {
+            url.scheme() == prefix.scheme()
+                && url.host_str() == prefix.host_str()
+                && url.port_or_known_default() == prefix.port_or_known_default()
+                && match url.path().strip_prefix(prefix.path()) {
+                    Some(rest) => {
+                        prefix.path().ends_with('/') || rest.is_empty() || rest.starts_with('/')
+                    }
+                    None => false,
+                }
+        }
+        Err(_) => false,
+    })
+}
+
 /// init_memory_operator will init a opendal memory operator.
 fn init_memory_operator() -> Result<impl Builder> {
     let builder = services::Memory::default();
@@ -332,7 +402,11 @@ impl DataOperator {
     }
 
     pub async fn init(conf: &StorageConfig) -> common_exception::Result<()> {
-        GlobalInstance::set(Self::try_create(&conf.params).await?);
+        let mut data_operator = Self::try_create(&conf.params).await?;
+        // All the requests of this node to the data storage share the same limit.
+        let io_limit = IoLimitLayer::new(conf.max_inflight_requests as usize);
+        data_operator.operator = data_operator.operator.layer(io_limit);
+        GlobalInstance::set(data_operator);
 
         Ok(())
     }
@@ -367,3 +441,66 @@ impl DataOperator {
         GlobalInstance::get()
     }
 }
+
+/// The operator of the local directory that keeps the temporary files spilled by queries.
+#[derive(Clone, Debug)]
+pub struct SpillOperator {
+    operator: Operator,
+    root: PathBuf,
+}
+
+impl SpillOperator {
+    /// The directory of the files spilled by sort, relative to the root.
+    pub const SORT_SPILL_PREFIX: &'static str = "_sort_spill";
+    /// The directory of the files spilled by hash join, relative to the root.
+    pub const JOIN_SPILL_PREFIX: &'static str = "_join_spill";
+
+    pub fn operator(&self) -> Operator {
+        self.operator.clone()
+    }
+
+    /// The query nodes on the same host may share `spill_local_path`, each of them spills
+    /// into the directory named by `node`, which must be unique on the host and stay the
+    /// same across restarts, e.g. the flight address.
+    pub fn init(spill_local_path: &str, node: &str) -> common_exception::Result<()> {
+        let mut root = PathBuf::from(spill_local_path);
+        if root.is_relative() {
+            root = env::current_dir()?.join(root);
+        }
+        let node = node.replace(|c: char| !c.is_ascii_alphanumeric() && c != '.', "_");
+        root.push(node);
+
+        let operator = init_operator(&StorageParams::Fs(StorageFsConfig {
+            root: root.display().to_string(),
+        }))?;
+        GlobalInstance::set(SpillOperator { operator, root });
+
+        Ok(())
+    }
+
+    /// Removes the files spilled by sort and hash join in the directory of this node.
+    ///
+    /// The spill files are only read by the query writing them, so the ones found when
+    /// the node starts are left behind by its last run (e.g. it crashed).
+    pub fn sweep(&self) -> common_exception::Result<()> {
+        for prefix in [Self::SORT_SPILL_PREFIX, Self::JOIN_SPILL_PREFIX] {
+            let dir = self.root.join(prefix);
+            if !dir.exists() {
+                continue;
+            }
+
+            std::fs::remove_dir_all(&dir).map_err(|cause| {
+                ErrorCode::StorageOther(format!(
+                    "cannot clean up the spill directory {}: {cause}",
+                    dir.display()
+                ))
+            })?;
+        }
+
+        Ok(())
+    }
+
+    pub fn instance() -> SpillOperator {
+        GlobalInstance::get()
+    }
+}
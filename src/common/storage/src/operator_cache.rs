@@ -0,0 +1,84 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::GlobalInstance;
+use common_exception::Result;
+use common_meta_app::storage::StorageParams;
+use opendal::Operator;
+
+use crate::init_operator;
+
+/// How long a cached operator is used before it's built again.
+///
+/// Operators load some credentials by themselves, e.g. by assuming a role, rebuilding them
+/// regularly makes sure the rotated ones are picked up.
+const OPERATOR_TTL: Duration = Duration::from_secs(300);
+
+/// StorageOperatorCache caches the operators of the tables and stages that carry their own
+/// storage connection instead of using the one of [`crate::DataOperator`].
+///
+/// Operators are looked up by their storage params, so once the connection of a table or a
+/// stage is altered, the operator built with the new credentials is used right away.
+pub struct StorageOperatorCache {
+    ttl: Duration,
+    // `StorageParams` is not hashable, and there are only a few distinct connections.
+    operators: Mutex<Vec<CachedOperator>>,
+}
+
+struct CachedOperator {
+    params: StorageParams,
+    operator: Operator,
+    created_on: Instant,
+}
+
+impl StorageOperatorCache {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(Self::create(OPERATOR_TTL)));
+        Ok(())
+    }
+
+    pub fn create(ttl: Duration) -> StorageOperatorCache {
+        StorageOperatorCache {
+            ttl,
+            operators: Mutex::new(vec![]),
+        }
+    }
+
+    pub fn instance() -> Arc<StorageOperatorCache> {
+        GlobalInstance::get()
+    }
+
+    /// Returns the operator of the storage params, building it if it isn't cached or has expired.
+    pub fn get_or_create(&self, params: &StorageParams) -> Result<Operator> {
+        let mut operators = self.operators.lock().unwrap();
+        operators.retain(|cached| cached.created_on.elapsed() < self.ttl);
+
+        if let Some(cached) = operators.iter().find(|cached| &cached.params == params) {
+            return Ok(cached.operator.clone());
+        }
+
+        let operator = init_operator(params)?;
+        operators.push(CachedOperator {
+            params: params.clone(),
+            operator: operator.clone(),
+            created_on: Instant::now(),
+        });
+        Ok(operator)
+    }
+}
@@ -22,6 +22,7 @@ use common_exception::Result;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::principal::StageType;
 use common_meta_app::principal::UserIdentity;
+use common_meta_app::storage::StorageParams;
 use futures::TryStreamExt;
 use opendal::Entry;
 use opendal::EntryMode;
@@ -32,6 +33,7 @@ use regex::Regex;
 
 use crate::init_operator;
 use crate::DataOperator;
+use crate::StorageOperatorCache;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub enum StageFileStatus {
@@ -74,7 +76,7 @@ impl StageFileInfo {
 
 pub fn init_stage_operator(stage_info: &StageInfo) -> Result<Operator> {
     if stage_info.stage_type == StageType::External {
-        Ok(init_operator(&stage_info.stage_params.storage)?)
+        StorageOperatorCache::instance().get_or_create(&stage_info.stage_params.storage)
     } else {
         let stage_prefix = stage_info.stage_prefix();
         let param = DataOperator::instance()
@@ -84,6 +86,25 @@ pub fn init_stage_operator(stage_info: &StageInfo) -> Result<Operator> {
         Ok(init_operator(&param)?)
     }
 }
+
+/// The storage params rooted at `<path>` of the stage, to access the files under it by
+/// their paths relative to it.
+pub fn stage_path_storage_params(stage_info: &StageInfo, path: &str) -> StorageParams {
+    let params = if stage_info.stage_type == StageType::External {
+        stage_info.stage_params.storage.clone()
+    } else {
+        let stage_prefix = stage_info.stage_prefix();
+        DataOperator::instance()
+            .params()
+            .map_root(|root| format!("{root}/{stage_prefix}"))
+    };
+    let path = path.trim_matches('/');
+    if path.is_empty() {
+        return params;
+    }
+    params.map_root(|root| format!("{}/{path}/", root.trim_end_matches('/')))
+}
+
 /// select * from @s1/<path> (FILES => <files> PATTERN => <pattern>)
 /// copy from @s1/<path> FILES = <files> PATTERN => <pattern>
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Debug)]
@@ -96,7 +117,7 @@ pub struct StageFilesInfo {
 impl StageFilesInfo {
     fn get_pattern(&self) -> Result<Option<Regex>> {
         match &self.pattern {
-            Some(pattern) => match Regex::new(pattern) {
+            Some(pattern) => match Regex::new(&expand_recursive_wildcard(pattern)) {
                 Ok(r) => Ok(Some(r)),
                 Err(e) => Err(ErrorCode::SyntaxException(format!(
                     "Pattern format invalid, got:{}, error:{:?}",
@@ -130,7 +151,11 @@ impl StageFilesInfo {
             Ok(res)
         } else {
             let pattern = self.get_pattern()?;
-            StageFilesInfo::list_files_with_pattern(operator, &self.path, pattern, first_only).await
+            let mut files =
+                StageFilesInfo::list_files_with_pattern(operator, &self.path, pattern, first_only)
+                    .await?;
+            files.sort_by(|a, b| a.path.cmp(&b.path));
+            Ok(files)
         }
     }
 
@@ -177,7 +202,10 @@ impl StageFilesInfo {
             Ok(res)
         } else {
             let pattern = self.get_pattern()?;
-            blocking_list_files_with_pattern(operator, &self.path, pattern, first_only)
+            let mut files =
+                blocking_list_files_with_pattern(operator, &self.path, pattern, first_only)?;
+            files.sort_by(|a, b| a.path.cmp(&b.path));
+            Ok(files)
         }
     }
 
@@ -221,6 +249,12 @@ impl StageFilesInfo {
     }
 }
 
+/// Expand the glob style `**` in a pattern into a regex matching any depth of directories,
+/// so `dir/**/.*[.]parquet` matches `dir/a.parquet` and `dir/2023/01/a.parquet`.
+fn expand_recursive_wildcard(pattern: &str) -> String {
+    pattern.replace("**/", "(?:[^/]+/)*").replace("**", ".*")
+}
+
 fn check_file(path: &str, mode: EntryMode, pattern: &Option<Regex>) -> bool {
     if mode.is_file() {
         match pattern {
@@ -0,0 +1,213 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use common_base::base::tokio;
+use common_storage::IoLimitLayer;
+use futures::future::join_all;
+use opendal::ops::*;
+use opendal::raw::Accessor;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpScan;
+use opendal::raw::RpWrite;
+use opendal::services;
+use opendal::Error;
+use opendal::ErrorKind;
+use opendal::Operator;
+use opendal::Result;
+
+/// Counters of the requests received by the mock storage.
+#[derive(Debug, Default)]
+struct MockStats {
+    reads: AtomicUsize,
+    inflight: AtomicUsize,
+    max_inflight: AtomicUsize,
+    /// The number of the reads to be rejected by throttling.
+    throttled: AtomicUsize,
+}
+
+/// MockLayer makes the storage below slow, and throttles the reads on demand.
+#[derive(Clone, Debug)]
+struct MockLayer {
+    stats: Arc<MockStats>,
+}
+
+impl<A: Accessor> Layer<A> for MockLayer {
+    type LayeredAccessor = MockAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        MockAccessor {
+            inner,
+            stats: self.stats.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MockAccessor<A: Accessor> {
+    inner: A,
+    stats: Arc<MockStats>,
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for MockAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.stats.reads.fetch_add(1, Ordering::SeqCst);
+        let inflight = self.stats.inflight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.stats
+            .max_inflight
+            .fetch_max(inflight, Ordering::SeqCst);
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let throttled = self
+            .stats
+            .throttled
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok();
+        let result = if throttled {
+            Err(Error::new(
+                ErrorKind::Unexpected,
+                "SlowDown: please reduce your request rate",
+            )
+            .set_temporary())
+        } else {
+            self.inner.read(path, args).await
+        };
+
+        self.stats.inflight.fetch_sub(1, Ordering::SeqCst);
+        result
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    async fn scan(&self, path: &str, args: OpScan) -> Result<(RpScan, Self::Pager)> {
+        self.inner.scan(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+
+    fn blocking_scan(&self, path: &str, args: OpScan) -> Result<(RpScan, Self::BlockingPager)> {
+        self.inner.blocking_scan(path, args)
+    }
+}
+
+async fn new_operator(limiter: &IoLimitLayer) -> Result<(Operator, Arc<MockStats>)> {
+    let stats = Arc::new(MockStats::default());
+    let op = Operator::new(services::Memory::default())?
+        .layer(MockLayer {
+            stats: stats.clone(),
+        })
+        .layer(limiter.clone())
+        .finish();
+
+    op.write("data", (0..=255u8).collect::<Vec<_>>()).await?;
+    Ok((op, stats))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_io_limit_coalesce_reads() -> Result<()> {
+    let limiter = IoLimitLayer::new(0);
+    let (op, stats) = new_operator(&limiter).await?;
+
+    let reads = (0..8).map(|_| op.range_read("data", 16..32));
+    for content in join_all(reads).await {
+        assert_eq!(content?, (16..32u8).collect::<Vec<_>>());
+    }
+    assert_eq!(stats.reads.load(Ordering::SeqCst), 1);
+    assert_eq!(limiter.stats().get_coalesced_reads(), 7);
+
+    // The reads of other ranges, or coming after the finished ones, are not coalesced.
+    assert_eq!(op.range_read("data", 0..4).await?, vec![0, 1, 2, 3]);
+    assert_eq!(op.range_read("data", 16..32).await?.len(), 16);
+    assert_eq!(stats.reads.load(Ordering::SeqCst), 3);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_io_limit_max_inflight() -> Result<()> {
+    let limiter = IoLimitLayer::new(2);
+    let (op, stats) = new_operator(&limiter).await?;
+
+    let reads = (0..8u64).map(|i| op.range_read("data", i * 4..i * 4 + 4));
+    for (i, content) in join_all(reads).await.into_iter().enumerate() {
+        let start = i as u8 * 4;
+        assert_eq!(content?, (start..start + 4).collect::<Vec<_>>());
+    }
+    assert_eq!(stats.reads.load(Ordering::SeqCst), 8);
+    assert_eq!(stats.max_inflight.load(Ordering::SeqCst), 2);
+    assert_eq!(limiter.stats().get_inflight(), 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_io_limit_retry_throttled() -> Result<()> {
+    let limiter = IoLimitLayer::new(4);
+    let (op, stats) = new_operator(&limiter).await?;
+
+    stats.throttled.store(2, Ordering::SeqCst);
+    assert_eq!(op.range_read("data", 0..4).await?, vec![0, 1, 2, 3]);
+    assert_eq!(stats.reads.load(Ordering::SeqCst), 3);
+    assert_eq!(limiter.stats().get_throttle_retries(), 2);
+
+    // The streaming reads are retried as well.
+    stats.throttled.store(1, Ordering::SeqCst);
+    assert_eq!(op.read("data").await?.len(), 256);
+    assert_eq!(limiter.stats().get_throttle_retries(), 3);
+
+    // Other errors are returned at once.
+    let err = op.range_read("not_exists", 0..4).await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    assert_eq!(limiter.stats().get_throttle_retries(), 3);
+    assert_eq!(limiter.stats().get_inflight(), 0);
+
+    Ok(())
+}
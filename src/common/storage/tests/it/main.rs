@@ -13,3 +13,5 @@
 // limitations under the License.
 
 mod column_node;
+mod io_limit_layer;
+mod operator;
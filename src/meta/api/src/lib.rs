@@ -58,7 +58,9 @@ pub use util::send_txn;
 pub use util::serialize_struct;
 pub use util::serialize_u64;
 pub use util::table_has_to_exist;
+pub use util::txn_cond_exists;
 pub use util::txn_cond_seq;
+pub use util::txn_cond_value;
 pub use util::txn_op_del;
 pub use util::txn_op_put;
 pub use util::txn_op_put_with_expire;
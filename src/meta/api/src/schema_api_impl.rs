@@ -22,6 +22,7 @@ use common_meta_app::app_error::AppError;
 use common_meta_app::app_error::CreateDatabaseWithDropTime;
 use common_meta_app::app_error::CreateTableWithDropTime;
 use common_meta_app::app_error::DatabaseAlreadyExists;
+use common_meta_app::app_error::DatabaseQuotaExceeded;
 use common_meta_app::app_error::DropDbWithDropTime;
 use common_meta_app::app_error::DropTableWithDropTime;
 use common_meta_app::app_error::DuplicatedUpsertFiles;
@@ -74,6 +75,7 @@ use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableCopiedFileNameIdent;
+use common_meta_app::schema::TableCopyHistoryIdent;
 use common_meta_app::schema::TableId;
 use common_meta_app::schema::TableIdList;
 use common_meta_app::schema::TableIdListKey;
@@ -82,6 +84,7 @@ use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
+use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReply;
@@ -98,13 +101,19 @@ use common_meta_app::share::ShareGrantObject;
 use common_meta_app::share::ShareGrantObjectPrivilege;
 use common_meta_app::share::ShareId;
 use common_meta_app::share::ShareNameIdent;
+use common_meta_app::tenant::DatabaseQuota;
+use common_meta_app::tenant::DatabaseQuotaIdent;
+use common_meta_app::tenant::DatabaseUsage;
+use common_meta_app::tenant::DatabaseUsageIdent;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::Key;
+use common_meta_types::txn_condition::Target;
 use common_meta_types::txn_op::Request;
 use common_meta_types::txn_op_response::Response;
 use common_meta_types::ConditionResult;
 use common_meta_types::GCDroppedDataReply;
 use common_meta_types::GCDroppedDataReq;
+use common_meta_types::InvalidArgument;
 use common_meta_types::InvalidReply;
 use common_meta_types::MatchSeqExt;
 use common_meta_types::MetaError;
@@ -122,6 +131,7 @@ use ConditionResult::Eq;
 use crate::db_has_to_exist;
 use crate::deserialize_struct;
 use crate::fetch_id;
+use crate::get_object_shared_by_share_ids;
 use crate::get_pb_value;
 use crate::get_share_database_id_and_privilege;
 use crate::get_share_or_err;
@@ -133,6 +143,7 @@ use crate::list_u64_value;
 use crate::send_txn;
 use crate::serialize_struct;
 use crate::serialize_u64;
+use crate::share_api_impl::get_tenant_share_spec_vec;
 use crate::table_has_to_exist;
 use crate::txn_cond_seq;
 use crate::txn_op_del;
@@ -1727,7 +1738,7 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
             let (tb_id_seq, _) = get_u64_value(self, &dbid_tbname).await?;
             if tb_id_seq == 0 {
                 return if req.if_exists {
-                    Ok(DropTableReply {})
+                    Ok(DropTableReply { spec_vec: None })
                 } else {
                     return Err(KVAppError::AppError(AppError::UnknownTable(
                         UnknownTable::new(tbname, "drop_table_by_id"),
@@ -1830,7 +1841,17 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
                     "drop_table_by_id"
                 );
                 if succ {
-                    return Ok(DropTableReply {});
+                    // The share specs have to be refreshed if the table is shared,
+                    // the dropped table is no longer accessible through the shares.
+                    let (_share_ids_seq, share_ids) =
+                        get_object_shared_by_share_ids(self, &ShareGrantObject::Table(table_id))
+                            .await?;
+                    let spec_vec = if share_ids.share_ids.is_empty() {
+                        None
+                    } else {
+                        Some(get_tenant_share_spec_vec(self, tenant_dbname.tenant.clone()).await?)
+                    };
+                    return Ok(DropTableReply { spec_vec });
                 }
             }
         }
@@ -2154,6 +2175,16 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
                 else_then: vec![get_table_meta],
             };
 
+            let (quota_conditions, quota_operations) = check_database_quota(
+                self,
+                req.table_id,
+                &table_meta.as_ref().unwrap().statistics,
+                &req.new_table_meta.statistics,
+            )
+            .await?;
+            txn_req.condition.extend(quota_conditions.iter().cloned());
+            txn_req.if_then.extend(quota_operations);
+
             if let Some(req) = &req.copied_files {
                 let (conditions, match_operations) =
                     build_upsert_table_copied_file_info_conditions(
@@ -2189,7 +2220,9 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
                     // check table version
                     if req_seq.match_seq(tb_meta_seq).is_ok() {
                         // if table version does match, but tx failed,
-                        if fail_if_duplicated {
+                        if fail_if_duplicated
+                            && !database_quota_changed(self, &quota_conditions).await?
+                        {
                             // report file duplication error
                             return Err(KVAppError::AppError(AppError::from(
                                 DuplicatedUpsertFiles::new(req.table_id, "update_table_meta"),
@@ -2729,10 +2762,11 @@ async fn get_table_id_from_share_by_name(
         }
     }
 
+    // The names are in the order of the ids, not sorted.
     let table_names = get_table_names_by_ids(kv_api, &ids).await?;
-    match table_names.binary_search(table_name) {
-        Ok(i) => Ok(ids[i]),
-        Err(_) => Err(KVAppError::AppError(AppError::WrongShareObject(
+    match table_names.iter().position(|name| name == table_name) {
+        Some(i) => Ok(ids[i]),
+        None => Err(KVAppError::AppError(AppError::WrongShareObject(
             WrongShareObject::new(table_name.to_string_key()),
         ))),
     }
@@ -2773,9 +2807,139 @@ fn build_upsert_table_copied_file_info_conditions(
         }
         set_update_expire_operation(&key, &file_info, &req.expire_at, &mut if_then)?;
     }
+
+    // Load history is written along with the copied files, it expires by the retention of history.
+    if let Some(copy_history) = &req.copy_history {
+        for info in &copy_history.history {
+            let key = TableCopyHistoryIdent {
+                tenant: copy_history.tenant.clone(),
+                table_id,
+                query_id: copy_history.query_id.clone(),
+                file: info.file_name.clone(),
+            };
+            let value = serde_json::to_vec(info).map_err(|e| {
+                let inv = InvalidArgument::new(e, "");
+                MetaNetworkError::InvalidArgument(inv)
+            })?;
+            match copy_history.expire_at {
+                Some(expire_at) => if_then.push(txn_op_put_with_expire(&key, value, expire_at)),
+                None => if_then.push(txn_op_put(&key, value)),
+            }
+        }
+    }
     Ok((condition, if_then))
 }
 
+/// Checks the size quota of the database a table belongs to, if an update of the table grows it
+/// from `prev` to `new`.
+///
+/// Returns the conditions and operations to add to the txn of the update. The quota must not
+/// change and the usage of the database is rewritten on the seq it was read at, thus the updates
+/// growing tables of the same database are committed one by one, each checked against the
+/// statistics committed by the others.
+async fn check_database_quota(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    table_id: u64,
+    prev: &TableStatistics,
+    new: &TableStatistics,
+) -> Result<(Vec<TxnCondition>, Vec<TxnOp>), KVAppError> {
+    if new.data_bytes <= prev.data_bytes && new.number_of_rows <= prev.number_of_rows {
+        return Ok((vec![], vec![]));
+    }
+
+    let (_, dbid_tbname): (_, Option<DBIdTableName>) =
+        get_pb_value(kv_api, &TableIdToName { table_id }).await?;
+    let db_id = match dbid_tbname {
+        Some(dbid_tbname) => dbid_tbname.db_id,
+        None => return Ok((vec![], vec![])),
+    };
+
+    let quota_key = DatabaseQuotaIdent { db_id };
+    let usage_key = DatabaseUsageIdent { db_id };
+    let seq_values = kv_api
+        .mget_kv(&[quota_key.to_string_key(), usage_key.to_string_key()])
+        .await?;
+    let (quota_seq, quota) = match &seq_values[0] {
+        Some(seq_v) => {
+            let quota: DatabaseQuota = serde_json::from_slice(&seq_v.data).map_err(|e| {
+                let inv = InvalidReply::new("check_database_quota", &e);
+                MetaNetworkError::InvalidReply(inv)
+            })?;
+            (seq_v.seq, quota)
+        }
+        None => (0, DatabaseQuota::default()),
+    };
+    let usage_seq = seq_values[1].as_ref().map(|seq_v| seq_v.seq).unwrap_or(0);
+
+    // quota is not changed
+    let mut condition = vec![txn_cond_seq(&quota_key, Eq, quota_seq)];
+    if quota.is_size_unlimited() {
+        return Ok((condition, vec![]));
+    }
+
+    let mut usage = DatabaseUsage {
+        data_bytes: new.data_bytes,
+        number_of_rows: new.number_of_rows,
+    };
+    let dbid_tbname = DBIdTableName {
+        db_id,
+        table_name: "".to_string(),
+    };
+    let (_, table_ids) = list_u64_value(kv_api, &dbid_tbname).await?;
+    let keys: Vec<String> = table_ids
+        .into_iter()
+        .filter(|id| *id != table_id)
+        .map(|id| TableId { table_id: id }.to_string_key())
+        .collect();
+    for c in keys.chunks(DEFAULT_MGET_SIZE) {
+        let seq_metas: Vec<(u64, Option<TableMeta>)> = mget_pb_values(kv_api, c).await?;
+        for (_, table_meta) in seq_metas {
+            if let Some(table_meta) = table_meta {
+                usage.data_bytes += table_meta.statistics.data_bytes;
+                usage.number_of_rows += table_meta.statistics.number_of_rows;
+            }
+        }
+    }
+
+    for (limit, used, max) in [
+        ("bytes", usage.data_bytes, quota.max_bytes),
+        ("rows", usage.number_of_rows, quota.max_rows),
+    ] {
+        if max != 0 && used > max {
+            let (_, db_name): (_, Option<DatabaseNameIdent>) =
+                get_pb_value(kv_api, &DatabaseIdToName { db_id }).await?;
+            let db_name = db_name.map_or_else(|| db_id.to_string(), |name| name.db_name);
+            return Err(KVAppError::AppError(AppError::from(
+                DatabaseQuotaExceeded::new(db_name, limit, used, max, "update_table_meta"),
+            )));
+        }
+    }
+
+    let value = serde_json::to_vec(&usage).map_err(|e| {
+        let inv = InvalidArgument::new(e, "");
+        MetaNetworkError::InvalidArgument(inv)
+    })?;
+    // usage is not changed by another update
+    condition.push(txn_cond_seq(&usage_key, Eq, usage_seq));
+    Ok((condition, vec![txn_op_put(&usage_key, value)]))
+}
+
+/// Returns true if a key checked by `check_database_quota` is changed since it was read.
+async fn database_quota_changed(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    condition: &[TxnCondition],
+) -> Result<bool, KVAppError> {
+    if condition.is_empty() {
+        return Ok(false);
+    }
+    let keys: Vec<String> = condition.iter().map(|c| c.key.clone()).collect();
+    let seq_values = kv_api.mget_kv(&keys).await?;
+    Ok(condition.iter().zip(seq_values).any(|(c, seq_v)| {
+        let seq = seq_v.map_or(0, |seq_v| seq_v.seq);
+        c.target != Some(Target::Seq(seq))
+    }))
+}
+
 fn set_update_expire_operation(
     key: &TableCopiedFileNameIdent,
     file_info: &TableCopiedFileInfo,
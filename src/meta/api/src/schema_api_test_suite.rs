@@ -70,6 +70,10 @@ use common_meta_app::share::ShareGrantObjectPrivilege;
 use common_meta_app::share::ShareId;
 use common_meta_app::share::ShareMeta;
 use common_meta_app::share::ShareNameIdent;
+use common_meta_app::tenant::DatabaseQuota;
+use common_meta_app::tenant::DatabaseQuotaIdent;
+use common_meta_app::tenant::DatabaseUsage;
+use common_meta_app::tenant::DatabaseUsageIdent;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::Key;
 use common_meta_kvapi::kvapi::UpsertKVReq;
@@ -186,6 +190,7 @@ async fn upsert_test_data(
             seq: MatchSeq::GE(0),
             value: Operation::Update(value),
             value_meta: None,
+            keep_ttl: false,
         })
         .await?;
 
@@ -203,6 +208,7 @@ async fn delete_test_data(
             seq: MatchSeq::GE(0),
             value: Operation::Delete,
             value_meta: None,
+            keep_ttl: false,
         })
         .await?;
 
@@ -239,6 +245,9 @@ impl SchemaApiTestSuite {
         suite.table_create_get_drop(&b.build().await).await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite
+            .table_update_meta_database_quota(&b.build().await)
+            .await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
         suite.table_list_all(&b.build().await).await?;
@@ -724,6 +733,7 @@ impl SchemaApiTestSuite {
             let req = AddShareAccountsReq {
                 share_name: share_name.clone(),
                 share_on,
+                read_only: false,
                 if_exists: false,
                 accounts: vec![tenant1.to_string()],
             };
@@ -2084,6 +2094,7 @@ impl SchemaApiTestSuite {
                     file_info,
                     expire_at: None,
                     fail_if_duplicated: true,
+                    copy_history: None,
                 };
                 mt.update_table_meta(UpdateTableMetaReq {
                     table_id,
@@ -2123,6 +2134,7 @@ impl SchemaApiTestSuite {
                     file_info,
                     expire_at: None,
                     fail_if_duplicated: true,
+                    copy_history: None,
                 };
                 mt.update_table_meta(UpdateTableMetaReq {
                     table_id,
@@ -2162,6 +2174,7 @@ impl SchemaApiTestSuite {
                     file_info,
                     expire_at: None,
                     fail_if_duplicated: true,
+                    copy_history: None,
                 };
                 let result = mt
                     .update_table_meta(UpdateTableMetaReq {
@@ -2179,6 +2192,116 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn table_update_meta_database_quota<MT: SchemaApi + kvapi::AsKVApi<Error = MetaError>>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+
+        let table_meta = || TableMeta {
+            schema: Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )])),
+            engine: "JSON".to_string(),
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db and tables");
+        let db_id = {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?.db_id
+        };
+        for tbl_name in ["tb1", "tb2"] {
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: table_meta(),
+            };
+            mt.create_table(req).await?;
+        }
+
+        let update = |tbl_name: &'static str, data_bytes: u64| async move {
+            let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.statistics = TableStatistics {
+                data_bytes,
+                ..Default::default()
+            };
+            mt.update_table_meta(UpdateTableMetaReq {
+                table_id: table.ident.table_id,
+                seq: MatchSeq::Exact(table.ident.seq),
+                new_table_meta,
+                copied_files: None,
+            })
+            .await
+        };
+
+        info!("--- no quota: unlimited");
+        {
+            update("tb1", 100).await?;
+            let usage = mt
+                .as_kv_api()
+                .get_kv(&DatabaseUsageIdent { db_id }.to_string_key())
+                .await?;
+            assert!(usage.is_none(), "usage is not tracked without a quota");
+        }
+
+        let quota = DatabaseQuota {
+            max_bytes: 150,
+            ..Default::default()
+        };
+        upsert_test_data(
+            mt.as_kv_api(),
+            &DatabaseQuotaIdent { db_id },
+            serde_json::to_vec(&quota)?,
+        )
+        .await?;
+
+        info!("--- grow within the quota, usage sums all tables");
+        {
+            update("tb2", 50).await?;
+            let usage = mt
+                .as_kv_api()
+                .get_kv(&DatabaseUsageIdent { db_id }.to_string_key())
+                .await?
+                .unwrap();
+            let usage: DatabaseUsage = serde_json::from_slice(&usage.data)?;
+            assert_eq!(150, usage.data_bytes);
+        }
+
+        info!("--- grow over the quota");
+        {
+            let err = update("tb1", 101).await.unwrap_err();
+            let err = ErrorCode::from(err);
+            assert_eq!(ErrorCode::DATABASE_QUOTA_EXCEEDED, err.code());
+
+            let table = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            assert_eq!(100, table.meta.statistics.data_bytes);
+        }
+
+        info!("--- shrink is always allowed");
+        {
+            update("tb1", 10).await?;
+            update("tb2", 140).await?;
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn table_upsert_option<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
@@ -2638,6 +2761,7 @@ impl SchemaApiTestSuite {
                 file_info: file_info.clone(),
                 expire_at: Some((Utc::now().timestamp() + 86400) as u64),
                 fail_if_duplicated: true,
+                copy_history: None,
             };
 
             let _ = mt.upsert_table_copied_file_info(req).await?;
@@ -3359,6 +3483,7 @@ impl SchemaApiTestSuite {
                 file_info: file_info.clone(),
                 expire_at: Some((Utc::now().timestamp() + 86400) as u64),
                 fail_if_duplicated: true,
+                copy_history: None,
             };
 
             let _ = mt.upsert_table_copied_file_info(req).await?;
@@ -3389,6 +3514,7 @@ impl SchemaApiTestSuite {
                 file_info: file_info.clone(),
                 expire_at: Some((Utc::now().timestamp() - 86400) as u64),
                 fail_if_duplicated: true,
+                copy_history: None,
             };
 
             let _ = mt.upsert_table_copied_file_info(req).await?;
@@ -3474,6 +3600,7 @@ impl SchemaApiTestSuite {
                 file_info: file_info.clone(),
                 expire_at: Some((Utc::now().timestamp() + 86400) as u64),
                 fail_if_duplicated: true,
+                copy_history: None,
             };
 
             let _ = mt.upsert_table_copied_file_info(req).await?;
@@ -3597,6 +3724,7 @@ impl SchemaApiTestSuite {
             let req = AddShareAccountsReq {
                 share_name: share_name.clone(),
                 share_on,
+                read_only: false,
                 if_exists: false,
                 accounts: vec![tenant2.to_string()],
             };
@@ -4341,6 +4469,7 @@ impl SchemaApiTestSuite {
                 file_info: file_info.clone(),
                 expire_at: Some((Utc::now().timestamp() + 86400) as u64),
                 fail_if_duplicated: true,
+                copy_history: None,
             };
 
             let _ = mt.upsert_table_copied_file_info(req).await?;
@@ -4380,6 +4509,7 @@ impl SchemaApiTestSuite {
                 file_info: file_info.clone(),
                 expire_at: Some((Utc::now().timestamp() + 86400) as u64),
                 fail_if_duplicated: true,
+                copy_history: None,
             };
 
             let result = mt.upsert_table_copied_file_info(req).await;
@@ -4416,6 +4546,7 @@ impl SchemaApiTestSuite {
                 file_info: file_info.clone(),
                 expire_at: Some((Utc::now().timestamp() + 86400) as u64),
                 fail_if_duplicated: false,
+                copy_history: None,
             };
 
             mt.upsert_table_copied_file_info(req).await?;
@@ -41,6 +41,11 @@ pub trait ShareApi: Sync + Send {
         req: RemoveShareAccountsReq,
     ) -> Result<RemoveShareAccountsReply, KVAppError>;
 
+    async fn alter_share_comment(
+        &self,
+        req: AlterShareCommentReq,
+    ) -> Result<AlterShareCommentReply, KVAppError>;
+
     async fn get_share_grant_objects(
         &self,
         req: GetShareGrantObjectReq,
@@ -335,6 +335,7 @@ impl<KV: kvapi::KVApi<Error = MetaError>> ShareApi for KV {
             };
 
             let mut add_share_account_keys = vec![];
+            let mut restriction_changed = false;
             for account in req.accounts.iter() {
                 if account == &name_key.tenant {
                     continue;
@@ -344,9 +345,12 @@ impl<KV: kvapi::KVApi<Error = MetaError>> ShareApi for KV {
                         account: account.clone(),
                         share_id,
                     });
+                } else if share_meta.set_account_read_only(account, req.read_only) {
+                    // the account has been granted, only update its restrictions.
+                    restriction_changed = true;
                 }
             }
-            if add_share_account_keys.is_empty() {
+            if add_share_account_keys.is_empty() && !restriction_changed {
                 return Err(KVAppError::AppError(AppError::ShareAccountsAlreadyExists(
                     ShareAccountsAlreadyExists::new(
                         req.share_name.share_name,
@@ -383,7 +387,9 @@ impl<KV: kvapi::KVApi<Error = MetaError>> ShareApi for KV {
                     )); /* (account, share_id) -> share_account_meta */
 
                     share_meta.add_account(share_account_key.account.clone());
+                    share_meta.set_account_read_only(&share_account_key.account, req.read_only);
                 }
+                share_meta.update_on = Some(req.share_on);
                 if_then.push(txn_op_put(&id_key, serialize_struct(&share_meta)?)); /* (share_id) -> share_meta */
 
                 let txn_req = TxnRequest {
@@ -539,6 +545,72 @@ impl<KV: kvapi::KVApi<Error = MetaError>> ShareApi for KV {
         )))
     }
 
+    async fn alter_share_comment(
+        &self,
+        req: AlterShareCommentReq,
+    ) -> Result<AlterShareCommentReply, KVAppError> {
+        debug!(req = debug(&req), "ShareApi: {}", func_name!());
+
+        let name_key = &req.share_name;
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let res = get_share_or_err(
+                self,
+                name_key,
+                format!("alter_share_comment: {}", &name_key),
+            )
+            .await;
+
+            let (share_id_seq, share_id, share_meta_seq, mut share_meta) = match res {
+                Ok(x) => x,
+                Err(e) => {
+                    if let KVAppError::AppError(AppError::UnknownShare(_)) = e {
+                        if req.if_exists {
+                            return Ok(AlterShareCommentReply { share_id: None });
+                        }
+                    }
+                    return Err(e);
+                }
+            };
+
+            share_meta.comment = req.comment.clone();
+            share_meta.update_on = Some(req.update_on);
+
+            let id_key = ShareId { share_id };
+            let txn_req = TxnRequest {
+                condition: vec![
+                    txn_cond_seq(name_key, Eq, share_id_seq),
+                    txn_cond_seq(&id_key, Eq, share_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&id_key, serialize_struct(&share_meta)?), /* (share_id) -> share_meta */
+                ],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = debug(&name_key),
+                id = debug(&id_key),
+                succ = display(succ),
+                "alter_share_comment"
+            );
+
+            if succ {
+                return Ok(AlterShareCommentReply {
+                    share_id: Some(share_id),
+                });
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("alter_share_comment", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     async fn grant_share_object(
         &self,
         req: GrantShareObjectReq,
@@ -1604,7 +1676,7 @@ async fn drop_all_database_from_share(
     Ok(())
 }
 
-async fn get_tenant_share_spec_vec(
+pub(crate) async fn get_tenant_share_spec_vec(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     tenant: String,
 ) -> Result<Vec<ShareSpec>, KVAppError> {
@@ -1682,6 +1754,13 @@ async fn convert_share_meta_to_spec(
             let (_table_id_to_name_seq, table_name): (_, Option<DBIdTableName>) =
                 get_pb_value(kv_api, &table_id_to_name_key).await?;
             if let Some(table_name) = table_name {
+                // A dropped table is no longer accessible through the share.
+                let (_table_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(kv_api, &TableId { table_id }).await?;
+                match table_meta {
+                    Some(table_meta) if table_meta.drop_on.is_none() => {}
+                    _ => continue,
+                }
                 tables.push(ShareTableSpec::new(
                     &table_name.table_name,
                     table_name.db_id,
@@ -1698,6 +1777,7 @@ async fn convert_share_meta_to_spec(
         database,
         tables,
         tenants: Vec::from_iter(share_meta.accounts.into_iter()),
+        read_only_tenants: Vec::from_iter(share_meta.read_only_accounts.into_iter()),
     })
 }
 
@@ -357,6 +357,7 @@ impl ShareApiTestSuite {
             let req = AddShareAccountsReq {
                 share_name: share_name.clone(),
                 share_on,
+                read_only: false,
                 if_exists: false,
                 accounts: vec![account.to_string()],
             };
@@ -432,6 +433,7 @@ impl ShareApiTestSuite {
             let req = AddShareAccountsReq {
                 share_name: share_name.clone(),
                 share_on,
+                read_only: false,
                 if_exists,
                 accounts: vec![account.to_string()],
             };
@@ -472,6 +474,7 @@ impl ShareApiTestSuite {
             let req = AddShareAccountsReq {
                 share_name: share_name3.clone(),
                 share_on,
+                read_only: false,
                 if_exists,
                 accounts: vec![tenant.to_string()],
             };
@@ -527,6 +530,7 @@ impl ShareApiTestSuite {
             let req = AddShareAccountsReq {
                 share_name: share_name.clone(),
                 share_on,
+                read_only: false,
                 if_exists,
                 accounts: vec![account.to_string()],
             };
@@ -540,11 +544,12 @@ impl ShareApiTestSuite {
             );
         }
 
-        info!("--- add account account2");
+        info!("--- add account account2 with read-only restriction");
         {
             let req = AddShareAccountsReq {
                 share_name: share_name.clone(),
                 share_on,
+                read_only: true,
                 if_exists,
                 accounts: vec![account2.to_string()],
             };
@@ -552,10 +557,50 @@ impl ShareApiTestSuite {
             let res = mt.add_share_tenants(req).await;
             info!("add share account res: {:?}", res);
             assert!(res.is_ok());
+            let spec_vec = res.unwrap().spec_vec.unwrap();
+            let spec = spec_vec.iter().find(|spec| spec.name == share1).unwrap();
+            assert_eq!(spec.read_only_tenants, vec![account2.to_string()]);
 
             let (_share_meta_seq, share_meta) =
                 get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
             assert!(share_meta.has_account(&account2.to_string()));
+            assert!(share_meta.is_read_only_account(&account2.to_string()));
+            assert!(!share_meta.is_read_only_account(&account.to_string()));
+        }
+
+        info!("--- restrict account account1 to read-only");
+        {
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on,
+                read_only: true,
+                if_exists,
+                accounts: vec![account.to_string()],
+            };
+
+            let res = mt.add_share_tenants(req).await;
+            info!("add share account res: {:?}", res);
+            assert!(res.is_ok());
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert!(share_meta.is_read_only_account(&account.to_string()));
+
+            // the restriction is not changed
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on,
+                read_only: true,
+                if_exists,
+                accounts: vec![account.to_string()],
+            };
+
+            let res = mt.add_share_tenants(req).await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareAccountsAlreadyExists("").code(),
+                ErrorCode::from(err).code()
+            );
         }
 
         info!("--- remove account account2");
@@ -574,6 +619,7 @@ impl ShareApiTestSuite {
             let (_share_meta_seq, share_meta) =
                 get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
             assert!(!share_meta.has_account(&account2.to_string()));
+            assert!(!share_meta.is_read_only_account(&account2.to_string()));
 
             // check share account meta has been removed
             let share_account_name = ShareAccountNameIdent {
@@ -588,6 +634,37 @@ impl ShareApiTestSuite {
             );
         }
 
+        info!("--- alter share1 comment");
+        {
+            let req = AlterShareCommentReq {
+                share_name: share_name.clone(),
+                if_exists: false,
+                comment: Some(comment2.to_string()),
+                update_on: Utc::now(),
+            };
+
+            let res = mt.alter_share_comment(req).await;
+            info!("alter share comment res: {:?}", res);
+            assert_eq!(res?.share_id, Some(share_id));
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert_eq!(share_meta.comment, Some(comment2.to_string()));
+            assert!(share_meta.update_on.is_some());
+
+            let req = AlterShareCommentReq {
+                share_name: ShareNameIdent {
+                    tenant: tenant.to_string(),
+                    share_name: "unknown_share".to_string(),
+                },
+                if_exists: true,
+                comment: None,
+                update_on: Utc::now(),
+            };
+            let res = mt.alter_share_comment(req).await;
+            assert_eq!(res?.share_id, None);
+        }
+
         info!("--- drop share1 with if_exists=true");
         {
             let req = DropShareReq {
@@ -1298,6 +1375,7 @@ impl ShareApiTestSuite {
             let req = AddShareAccountsReq {
                 share_name: share_name1.clone(),
                 share_on: Utc::now(),
+                read_only: false,
                 if_exists: false,
                 accounts: vec![tenant2.to_string()],
             };
@@ -221,6 +221,7 @@ pub async fn fetch_id<T: kvapi::Key>(
             seq: MatchSeq::GE(0),
             value: Operation::Update(b"".to_vec()),
             value_meta: None,
+            keep_ttl: false,
         })
         .await?;
 
@@ -281,6 +282,24 @@ pub fn txn_cond_seq(key: &impl kvapi::Key, op: ConditionResult, seq: u64) -> Txn
     }
 }
 
+/// Build a TxnCondition that compares the value of a record.
+pub fn txn_cond_value(key: &impl kvapi::Key, op: ConditionResult, value: Vec<u8>) -> TxnCondition {
+    TxnCondition {
+        key: key.to_string_key(),
+        expected: op as i32,
+        target: Some(Target::Value(value)),
+    }
+}
+
+/// Build a TxnCondition that checks if a record exists or not.
+pub fn txn_cond_exists(key: &impl kvapi::Key, exists: bool) -> TxnCondition {
+    TxnCondition {
+        key: key.to_string_key(),
+        expected: ConditionResult::Eq as i32,
+        target: Some(Target::Exists(exists)),
+    }
+}
+
 /// Build a txn operation that puts a record.
 pub fn txn_op_put(key: &impl kvapi::Key, value: Vec<u8>) -> TxnOp {
     TxnOp {
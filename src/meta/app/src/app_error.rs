@@ -496,6 +496,34 @@ impl TxnRetryMaxTimes {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("DatabaseQuotaExceeded: max {limit} of `{db_name}`: {usage} > {quota} while `{context}`")]
+pub struct DatabaseQuotaExceeded {
+    db_name: String,
+    limit: String,
+    usage: u64,
+    quota: u64,
+    context: String,
+}
+
+impl DatabaseQuotaExceeded {
+    pub fn new(
+        db_name: impl Into<String>,
+        limit: impl Into<String>,
+        usage: u64,
+        quota: u64,
+        context: impl Into<String>,
+    ) -> Self {
+        Self {
+            db_name: db_name.into(),
+            limit: limit.into(),
+            usage,
+            quota,
+            context: context.into(),
+        }
+    }
+}
+
 /// Application error.
 ///
 /// The application does not get expected result but there is nothing wrong with meta-service.
@@ -555,6 +583,9 @@ pub enum AppError {
     #[error(transparent)]
     TxnRetryMaxTimes(#[from] TxnRetryMaxTimes),
 
+    #[error(transparent)]
+    DatabaseQuotaExceeded(#[from] DatabaseQuotaExceeded),
+
     // share api errors
     #[error(transparent)]
     ShareAlreadyExists(#[from] ShareAlreadyExists),
@@ -741,6 +772,15 @@ impl AppErrorMessage for UnknownShareEndpointId {
     }
 }
 
+impl AppErrorMessage for DatabaseQuotaExceeded {
+    fn message(&self) -> String {
+        format!(
+            "Max {} quota of database '{}' exceeded: {} > {}",
+            self.limit, self.db_name, self.usage, self.quota
+        )
+    }
+}
+
 impl AppErrorMessage for TxnRetryMaxTimes {
     fn message(&self) -> String {
         format!(
@@ -830,6 +870,7 @@ impl From<AppError> for ErrorCode {
                 ErrorCode::UnknownShareEndpointId(err.message())
             }
             AppError::TxnRetryMaxTimes(err) => ErrorCode::TxnRetryMaxTimes(err.message()),
+            AppError::DatabaseQuotaExceeded(err) => ErrorCode::DatabaseQuotaExceeded(err.message()),
             AppError::DuplicatedUpsertFiles(err) => ErrorCode::DuplicatedUpsertFiles(err.message()),
         }
     }
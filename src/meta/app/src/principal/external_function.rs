@@ -0,0 +1,89 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_expression::TableDataType;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub const DEFAULT_EXTERNAL_FUNCTION_CONNECTION_TIMEOUT_MS: u64 = 10_000;
+pub const DEFAULT_EXTERNAL_FUNCTION_REQUEST_TIMEOUT_MS: u64 = 60_000;
+pub const DEFAULT_EXTERNAL_FUNCTION_MAX_RETRIES: u64 = 3;
+
+/// A function served by a remote service, e.g. a model written in Python.
+///
+/// The arguments of a batch of rows are POSTed to `address`, the service replies
+/// with one result for each row, see `ExternalFunctionProtocol` for the payloads.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ExternalFunctionInfo {
+    pub name: String,
+    /// The names and types of the arguments, the names are only informational.
+    pub arguments: Vec<(String, TableDataType)>,
+    pub return_type: TableDataType,
+    pub language: String,
+    /// The name the service knows the function by.
+    pub handler: String,
+    pub address: String,
+    pub protocol: ExternalFunctionProtocol,
+    /// Sent with every request, usually the credentials of the service.
+    pub headers: BTreeMap<String, String>,
+    pub connection_timeout_ms: u64,
+    pub request_timeout_ms: u64,
+    pub max_retries: u64,
+    #[serde(default)]
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+/// How a batch of rows is encoded in the request and the response.
+///
+/// - `Arrow`: the request is an Arrow IPC stream with one column per argument,
+///   the response is an Arrow IPC stream whose first column holds the results.
+/// - `Json`: the request is `{"data": [[<row>, <arg>, ...], ...]}`, the response
+///   is `{"data": [[<row>, <result>], ...]}`, where `<row>` is the row number in the batch.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ExternalFunctionProtocol {
+    #[default]
+    Arrow,
+    Json,
+}
+
+impl Display for ExternalFunctionProtocol {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ExternalFunctionProtocol::Arrow => write!(f, "ARROW"),
+            ExternalFunctionProtocol::Json => write!(f, "JSON"),
+        }
+    }
+}
+
+impl FromStr for ExternalFunctionProtocol {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        match s.to_uppercase().as_str() {
+            "ARROW" => Ok(ExternalFunctionProtocol::Arrow),
+            "JSON" => Ok(ExternalFunctionProtocol::Json),
+            _ => Err(format!(
+                "Invalid external function protocol '{s}', must be one of {{ ARROW | JSON }}"
+            )),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A masking policy rewrites the values of the columns it is attached to.
+///
+/// The body is an expression over the arguments, e.g.
+/// `CASE WHEN current_role() IN ('pii_reader') THEN val ELSE '***' END`,
+/// where the first argument is bound to the masked column.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct MaskingPolicy {
+    pub name: String,
+    /// Argument names and their type names.
+    pub args: Vec<(String, String)>,
+    pub return_type: String,
+    pub body: String,
+    pub comment: String,
+    /// Ids of the tables which have columns attached to this policy.
+    pub referenced_tables: BTreeSet<u64>,
+}
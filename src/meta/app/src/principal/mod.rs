@@ -14,8 +14,15 @@
 
 //! Principal is a user or role that accesses an entity.
 
+mod external_function;
+mod masking_policy;
+mod network_policy;
+mod pipe;
 mod principal_identity;
 mod role_info;
+mod row_access_policy;
+mod sequence;
+mod task;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
@@ -27,9 +34,29 @@ mod user_quota;
 mod user_setting;
 mod user_stage;
 
+pub use external_function::ExternalFunctionInfo;
+pub use external_function::ExternalFunctionProtocol;
+pub use external_function::DEFAULT_EXTERNAL_FUNCTION_CONNECTION_TIMEOUT_MS;
+pub use external_function::DEFAULT_EXTERNAL_FUNCTION_MAX_RETRIES;
+pub use external_function::DEFAULT_EXTERNAL_FUNCTION_REQUEST_TIMEOUT_MS;
+pub use masking_policy::MaskingPolicy;
+pub use network_policy::NetworkPolicy;
+pub use pipe::PipeInfo;
+pub use pipe::PipePartitionStatus;
+pub use pipe::PipeRejectedRecord;
+pub use pipe::PipeSource;
+pub use pipe::PipeStatus;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use row_access_policy::RowAccessPolicy;
+pub use sequence::SequenceInfo;
+pub use task::TaskInfo;
+pub use task::TaskRun;
+pub use task::TaskRunState;
+pub use task::TaskRunTrigger;
+pub use task::TaskSchedule;
+pub use task::TaskState;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
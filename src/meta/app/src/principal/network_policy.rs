@@ -0,0 +1,33 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A network policy restricts the client addresses a user can authenticate from.
+///
+/// Both lists hold IPv4 or IPv6 CIDRs, e.g. `10.0.0.0/8` or `fd00::/8`,
+/// a bare address is treated as a single host.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct NetworkPolicy {
+    pub name: String,
+    pub allowed_ip_list: Vec<String>,
+    #[serde(default)]
+    pub blocked_ip_list: Vec<String>,
+    #[serde(default)]
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+}
@@ -0,0 +1,127 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::principal::FileFormatOptions;
+use crate::principal::OnErrorMode;
+use crate::principal::UserIdentity;
+
+/// A pipe keeps loading the data of a streaming source into a table, as its owner.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeInfo {
+    pub name: String,
+    pub owner: UserIdentity,
+    pub database: String,
+    pub table: String,
+    pub source: PipeSource,
+    pub file_format_options: FileFormatOptions,
+    pub on_error: OnErrorMode,
+    #[serde(default)]
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum PipeSource {
+    /// Every record of the topic is a piece of data in the format of the pipe.
+    Kafka {
+        brokers: Vec<String>,
+        topic: String,
+        group: String,
+    },
+}
+
+impl PipeSource {
+    /// The key of the offsets of the source in the snapshots of the table.
+    ///
+    /// The offsets belong to the consumer group, like the committed offsets of kafka do,
+    /// so a pipe re-created with the same group resumes where the old one stopped.
+    pub fn offsets_key(&self) -> String {
+        match self {
+            PipeSource::Kafka { topic, group, .. } => format!("kafka/{group}/{topic}"),
+        }
+    }
+}
+
+impl Display for PipeSource {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            PipeSource::Kafka {
+                brokers,
+                topic,
+                group,
+            } => write!(
+                f,
+                "KAFKA (brokers = '{}', topic = '{}', group = '{}')",
+                brokers.join(","),
+                topic,
+                group
+            ),
+        }
+    }
+}
+
+/// What the node holding the lease of a pipe reports after each batch it loads.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeStatus {
+    pub pipe_name: String,
+    /// The query node consuming the source.
+    pub holder: String,
+    pub partitions: Vec<PipePartitionStatus>,
+    pub rows_loaded: u64,
+    pub rows_rejected: u64,
+    /// Of the last batch, from the start of its fetch to its commit.
+    pub rows_per_second: u64,
+    pub last_committed_on: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub updated_on: DateTime<Utc>,
+}
+
+impl PipeStatus {
+    /// The number of records in the source that are not loaded yet.
+    pub fn lag(&self) -> u64 {
+        self.partitions
+            .iter()
+            .map(|p| (p.high_watermark - p.offset).max(0) as u64)
+            .sum()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PipePartitionStatus {
+    pub partition: i32,
+    /// The next offset to load, as committed along with the data of the table.
+    pub offset: i64,
+    /// The offset of the next record to be written into the partition.
+    pub high_watermark: i64,
+}
+
+/// A record of the source the format of the pipe failed to parse.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeRejectedRecord {
+    pub pipe_name: String,
+    pub partition: i32,
+    pub offset: i64,
+    pub error: String,
+    /// Lossily decoded as utf8, and truncated if it's large.
+    pub payload: String,
+    pub rejected_on: DateTime<Utc>,
+}
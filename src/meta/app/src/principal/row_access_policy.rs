@@ -0,0 +1,36 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A row access policy hides the rows of the tables it is added to.
+///
+/// The body is a boolean expression over the arguments, e.g.
+/// `current_role() = 'admin' OR region = 'eu'`, the arguments are bound to the
+/// columns listed when the policy is added to a table, only the rows for which
+/// the body is true are visible.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct RowAccessPolicy {
+    pub name: String,
+    /// Argument names and their type names.
+    pub args: Vec<(String, String)>,
+    pub body: String,
+    pub comment: String,
+    /// Ids of the tables this policy is added to.
+    pub referenced_tables: BTreeSet<u64>,
+}
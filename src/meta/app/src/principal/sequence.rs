@@ -0,0 +1,56 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A sequence generates unique numbers by `NEXTVAL('name')`.
+///
+/// Values step by `increment` from `start` within `[min_value, max_value]`,
+/// a sequence either wraps around when it passes a bound, if `cycle`,
+/// or is exhausted.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SequenceInfo {
+    pub name: String,
+    pub start: i64,
+    pub increment: i64,
+    pub min_value: i64,
+    pub max_value: i64,
+    pub cycle: bool,
+    /// The last value generated, None if `NEXTVAL` was never called.
+    #[serde(default)]
+    pub current: Option<i64>,
+    #[serde(default)]
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl SequenceInfo {
+    /// The value `NEXTVAL` generates after the current one, None if the sequence is exhausted.
+    pub fn next_value(&self) -> Option<i64> {
+        let current = match self.current {
+            None => return Some(self.start),
+            Some(current) => current,
+        };
+
+        match current.checked_add(self.increment) {
+            Some(next) if next >= self.min_value && next <= self.max_value => Some(next),
+            _ if !self.cycle => None,
+            _ if self.increment > 0 => Some(self.min_value),
+            _ => Some(self.max_value),
+        }
+    }
+}
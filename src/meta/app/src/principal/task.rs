@@ -0,0 +1,322 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::TimeZone;
+use chrono::Timelike;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::principal::UserIdentity;
+
+/// A task runs a statement on a schedule, as its owner and in the database
+/// that was current when it was created.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TaskInfo {
+    pub name: String,
+    pub owner: UserIdentity,
+    pub database: String,
+    pub schedule: TaskSchedule,
+    pub query_text: String,
+    pub state: TaskState,
+    #[serde(default)]
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+    /// When the last scheduled run started, manual runs don't count.
+    #[serde(default)]
+    pub last_scheduled_on: Option<DateTime<Utc>>,
+}
+
+impl TaskInfo {
+    /// The next time the task is due, None if it's suspended or its schedule never fires.
+    ///
+    /// Missed runs are not caught up: a task that was due long ago runs once,
+    /// and is scheduled again from then on.
+    pub fn next_scheduled_on(&self) -> Option<DateTime<Utc>> {
+        if self.state != TaskState::Started {
+            return None;
+        }
+
+        let since = match self.last_scheduled_on {
+            Some(last_scheduled_on) => last_scheduled_on.max(self.updated_on),
+            None => self.updated_on,
+        };
+        self.schedule.next_after(since)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TaskState {
+    Started,
+    Suspended,
+}
+
+impl Display for TaskState {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TaskState::Started => write!(f, "STARTED"),
+            TaskState::Suspended => write!(f, "SUSPENDED"),
+        }
+    }
+}
+
+/// The schedule of a task, either `<num> { MINUTE | HOUR | DAY }` or
+/// `USING CRON <expression>` with a five-field cron expression evaluated in UTC.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum TaskSchedule {
+    Interval { secs: u64 },
+    Cron { expression: String },
+}
+
+impl TaskSchedule {
+    /// The first time the schedule fires strictly after `after`.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            TaskSchedule::Interval { secs } => Some(after + Duration::seconds(*secs as i64)),
+            TaskSchedule::Cron { expression } => {
+                CronExpression::from_str(expression).ok()?.next_after(after)
+            }
+        }
+    }
+}
+
+impl FromStr for TaskSchedule {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        let parts = s.split_whitespace().collect::<Vec<_>>();
+        let cron_expression = match parts.as_slice() {
+            [using, cron, expression @ ..]
+                if using.eq_ignore_ascii_case("USING") && cron.eq_ignore_ascii_case("CRON") =>
+            {
+                expression.join(" ")
+            }
+            [num, unit] => {
+                let num = num
+                    .parse::<u64>()
+                    .map_err(|_| format!("Invalid task schedule '{s}', bad number '{num}'"))?;
+                let unit_secs = match unit.to_uppercase().as_str() {
+                    "MINUTE" | "MINUTES" => 60,
+                    "HOUR" | "HOURS" => 60 * 60,
+                    "DAY" | "DAYS" => 24 * 60 * 60,
+                    _ => {
+                        return Err(format!(
+                            "Invalid task schedule '{s}', the unit must be one of {{ MINUTE | HOUR | DAY }}"
+                        ));
+                    }
+                };
+                if num == 0 {
+                    return Err(format!(
+                        "Invalid task schedule '{s}', the interval must be greater than 0"
+                    ));
+                }
+                return Ok(TaskSchedule::Interval {
+                    secs: num * unit_secs,
+                });
+            }
+            _ => parts.join(" "),
+        };
+
+        CronExpression::from_str(&cron_expression)
+            .map_err(|e| format!("Invalid task schedule '{s}', {e}"))?;
+        Ok(TaskSchedule::Cron {
+            expression: cron_expression,
+        })
+    }
+}
+
+impl Display for TaskSchedule {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TaskSchedule::Interval { secs } if secs % (24 * 60 * 60) == 0 => {
+                write!(f, "{} DAY", secs / (24 * 60 * 60))
+            }
+            TaskSchedule::Interval { secs } if secs % (60 * 60) == 0 => {
+                write!(f, "{} HOUR", secs / (60 * 60))
+            }
+            TaskSchedule::Interval { secs } => write!(f, "{} MINUTE", secs / 60),
+            TaskSchedule::Cron { expression } => write!(f, "USING CRON {expression}"),
+        }
+    }
+}
+
+/// `minute hour day-of-month month day-of-week`, each field is `*` or a comma separated
+/// list of numbers and ranges, optionally with a `/step`.
+struct CronExpression {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    // Like cron, a day matches either of the day fields when both of them are restricted.
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+impl FromStr for CronExpression {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        let fields = s.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 5 {
+            return Err(format!(
+                "the cron expression '{s}' must have 5 fields: minute hour day-of-month month day-of-week"
+            ));
+        }
+
+        let mut days_of_week = parse_cron_field(fields[4], 0, 7)?;
+        // Both 0 and 7 are Sunday.
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+        Ok(CronExpression {
+            minutes: parse_cron_field(fields[0], 0, 59)?,
+            hours: parse_cron_field(fields[1], 0, 23)?,
+            days_of_month: parse_cron_field(fields[2], 1, 31)?,
+            months: parse_cron_field(fields[3], 1, 12)?,
+            days_of_week,
+            any_day_of_month: fields[2].starts_with('*'),
+            any_day_of_week: fields[4].starts_with('*'),
+        })
+    }
+}
+
+impl CronExpression {
+    // Expressions that can fire at all, even on Feb 29 only, fire within this many years.
+    const MAX_YEARS_AHEAD: i32 = 5;
+
+    fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let after = after.naive_utc();
+        let mut time =
+            after.date().and_hms_opt(after.hour(), after.minute(), 0)? + Duration::minutes(1);
+        while time.year() <= after.year() + Self::MAX_YEARS_AHEAD {
+            if !has_bit(self.months, time.month()) {
+                let (year, month) = match time.month() {
+                    12 => (time.year() + 1, 1),
+                    month => (time.year(), month + 1),
+                };
+                time = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+            } else if !self.matches_day(time.date()) {
+                time = time.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+            } else if !has_bit(self.hours, time.hour()) {
+                time = time.date().and_hms_opt(time.hour(), 0, 0)? + Duration::hours(1);
+            } else if !has_bit(self.minutes, time.minute()) {
+                time += Duration::minutes(1);
+            } else {
+                return Some(Utc.from_utc_datetime(&time));
+            }
+        }
+        None
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let day_of_month = has_bit(self.days_of_month, date.day());
+        let day_of_week = has_bit(self.days_of_week, date.weekday().num_days_from_sunday());
+        match (self.any_day_of_month, self.any_day_of_week) {
+            (true, true) => true,
+            (true, false) => day_of_week,
+            (false, true) => day_of_month,
+            (false, false) => day_of_month || day_of_week,
+        }
+    }
+}
+
+fn has_bit(bits: u64, value: u32) -> bool {
+    bits & (1 << value) != 0
+}
+
+fn parse_cron_field(field: &str, min: u32, max: u32) -> std::result::Result<u64, String> {
+    let parse_number = |s: &str| {
+        s.parse::<u32>()
+            .map_err(|_| format!("bad number '{s}' in cron field '{field}'"))
+    };
+
+    let mut bits = 0;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, parse_number(step)?),
+            None => (item, 1),
+        };
+        let (start, end) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((start, end)) => (parse_number(start)?, parse_number(end)?),
+            // `<start>/<step>` runs to the end of the range.
+            None if item.contains('/') => (parse_number(range)?, max),
+            None => {
+                let value = parse_number(range)?;
+                (value, value)
+            }
+        };
+        if step == 0 || start < min || end > max || start > end {
+            return Err(format!(
+                "cron field '{field}' is out of range, must be within {min}-{max}"
+            ));
+        }
+        for value in (start..=end).step_by(step as usize) {
+            bits |= 1 << value;
+        }
+    }
+    Ok(bits)
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TaskRunTrigger {
+    Scheduled,
+    Manual,
+}
+
+impl Display for TaskRunTrigger {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TaskRunTrigger::Scheduled => write!(f, "SCHEDULED"),
+            TaskRunTrigger::Manual => write!(f, "MANUAL"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TaskRunState {
+    Succeeded,
+    Failed,
+}
+
+impl Display for TaskRunState {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TaskRunState::Succeeded => write!(f, "SUCCEEDED"),
+            TaskRunState::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+/// A finished run of a task, the query id identifies it in the query log.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TaskRun {
+    pub task_name: String,
+    pub query_id: String,
+    pub trigger: TaskRunTrigger,
+    pub state: TaskRunState,
+    pub error: Option<String>,
+    pub started_on: DateTime<Utc>,
+    pub duration_ms: u64,
+}
@@ -26,26 +26,47 @@ pub enum GrantObject {
     Global,
     Database(String, String),
     Table(String, String, String),
+    Column(String, String, String, String),
+    Stage(String),
 }
 
 impl GrantObject {
     /// Comparing the grant objects, the Database object contains all the Table objects inside it.
-    /// Global object contains all the Database objects.
+    /// Global object contains all the Database objects, and Table object contains all its Column objects.
+    /// A Stage object contains only itself.
     pub fn contains(&self, object: &GrantObject) -> bool {
         match (self, object) {
             (GrantObject::Global, _) => true,
-            (GrantObject::Database(_, _), GrantObject::Global) => false,
+            (GrantObject::Database(_, _), GrantObject::Global)
+            | (GrantObject::Database(_, _), GrantObject::Stage(_)) => false,
             (GrantObject::Database(lcat, ldb), GrantObject::Database(rcat, rdb)) => {
                 lcat == rcat && ldb == rdb
             }
-            (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _)) => {
+            (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _))
+            | (GrantObject::Database(lcat, ldb), GrantObject::Column(rcat, rdb, _, _)) => {
                 lcat == rcat && ldb == rdb
             }
             (
                 GrantObject::Table(lcat, lhs_db, lhs_table),
                 GrantObject::Table(rcat, rhs_db, rhs_table),
+            )
+            | (
+                GrantObject::Table(lcat, lhs_db, lhs_table),
+                GrantObject::Column(rcat, rhs_db, rhs_table, _),
             ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
             (GrantObject::Table(_, _, _), _) => false,
+            (
+                GrantObject::Column(lcat, lhs_db, lhs_table, lhs_column),
+                GrantObject::Column(rcat, rhs_db, rhs_table, rhs_column),
+            ) => {
+                lcat == rcat
+                    && (lhs_db == rhs_db)
+                    && (lhs_table == rhs_table)
+                    && (lhs_column == rhs_column)
+            }
+            (GrantObject::Column(_, _, _, _), _) => false,
+            (GrantObject::Stage(lstage), GrantObject::Stage(rstage)) => lstage == rstage,
+            (GrantObject::Stage(_), _) => false,
         }
     }
 
@@ -55,6 +76,8 @@ impl GrantObject {
             GrantObject::Global => UserPrivilegeSet::available_privileges_on_global(),
             GrantObject::Database(_, _) => UserPrivilegeSet::available_privileges_on_database(),
             GrantObject::Table(_, _, _) => UserPrivilegeSet::available_privileges_on_table(),
+            GrantObject::Column(_, _, _, _) => UserPrivilegeSet::available_privileges_on_column(),
+            GrantObject::Stage(_) => UserPrivilegeSet::available_privileges_on_stage(),
         }
     }
 }
@@ -67,6 +90,10 @@ impl fmt::Display for GrantObject {
             GrantObject::Table(ref cat, ref db, ref table) => {
                 write!(f, "'{}'.'{}'.'{}'", cat, db, table)
             }
+            GrantObject::Column(ref cat, ref db, ref table, ref column) => {
+                write!(f, "'{}'.'{}'.'{}'('{}')", cat, db, table, column)
+            }
+            GrantObject::Stage(ref stage) => write!(f, "STAGE {}", stage),
         }
     }
 }
@@ -121,7 +148,10 @@ impl GrantEntry {
 impl fmt::Display for GrantEntry {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::result::Result<(), fmt::Error> {
         let privileges: UserPrivilegeSet = self.privileges.into();
-        let privileges_str = if self.has_all_available_privileges() {
+        // SELECT is the only privilege available on columns, which is clearer than ALL.
+        let privileges_str = if self.has_all_available_privileges()
+            && !matches!(self.object, GrantObject::Column(_, _, _, _))
+        {
             "ALL".to_string()
         } else {
             privileges.to_string()
@@ -174,6 +204,40 @@ impl UserGrantSet {
             .any(|e| e.verify_privilege(object, privilege.clone()))
     }
 
+    /// Returns the columns of the table on which the privilege is granted, or `None` if the
+    /// privilege is granted on the whole table or on none of its columns.
+    pub fn granted_columns(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        privilege: UserPrivilegeType,
+    ) -> Option<HashSet<String>> {
+        let table_object =
+            GrantObject::Table(catalog.to_string(), database.to_string(), table.to_string());
+        if self.verify_privilege(&table_object, vec![privilege]) {
+            return None;
+        }
+
+        let columns = self
+            .entries
+            .iter()
+            .filter_map(|e| match &e.object {
+                GrantObject::Column(_, _, _, column)
+                    if table_object.contains(&e.object) && e.privileges.contains(privilege) =>
+                {
+                    Some(column.clone())
+                }
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+        if columns.is_empty() {
+            None
+        } else {
+            Some(columns)
+        }
+    }
+
     pub fn grant_privileges(&mut self, object: &GrantObject, privileges: UserPrivilegeSet) {
         let privileges: BitFlags<UserPrivilegeType> = privileges.into();
         let mut new_entries: Vec<GrantEntry> = vec![];
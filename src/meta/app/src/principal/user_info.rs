@@ -105,6 +105,8 @@ pub struct UserOption {
     flags: BitFlags<UserOptionFlag>,
 
     default_role: Option<String>,
+
+    network_policy: Option<String>,
 }
 
 impl UserOption {
@@ -112,6 +114,7 @@ impl UserOption {
         Self {
             flags,
             default_role: None,
+            network_policy: None,
         }
     }
 
@@ -129,6 +132,11 @@ impl UserOption {
         self
     }
 
+    pub fn with_network_policy(mut self, network_policy: Option<String>) -> Self {
+        self.network_policy = network_policy;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -142,10 +150,18 @@ impl UserOption {
         self.default_role.as_ref()
     }
 
+    pub fn network_policy(&self) -> Option<&String> {
+        self.network_policy.as_ref()
+    }
+
     pub fn set_default_role(&mut self, default_role: Option<String>) {
         self.default_role = default_role;
     }
 
+    pub fn set_network_policy(&mut self, network_policy: Option<String>) {
+        self.network_policy = network_policy;
+    }
+
     pub fn set_all_flag(&mut self) {
         self.flags = BitFlags::all();
     }
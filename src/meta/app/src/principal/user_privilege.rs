@@ -62,6 +62,14 @@ pub enum UserPrivilegeType {
     DropRole = 1 << 14,
     // Privilege to Drop user.
     DropUser = 1 << 15,
+    // Privilege to call external functions.
+    Execute = 1 << 16,
+    // Privilege to see the rows hidden by row access policies.
+    BypassRowAccessPolicy = 1 << 17,
+    // Privilege to read the files of a stage.
+    Read = 1 << 18,
+    // Privilege to write files to a stage.
+    Write = 1 << 19,
     // TODO: remove this later
     Set = 1 << 4,
 }
@@ -82,6 +90,8 @@ const ALL_PRIVILEGES: BitFlags<UserPrivilegeType> = make_bitflags!(
         | DropRole
         | Grant
         | CreateStage
+        | Execute
+        | BypassRowAccessPolicy
         | Set
     }
 );
@@ -103,6 +113,10 @@ impl std::fmt::Display for UserPrivilegeType {
             UserPrivilegeType::CreateRole => "CREATE ROLE",
             UserPrivilegeType::DropRole => "DROP ROLE",
             UserPrivilegeType::CreateStage => "CREATE STAGE",
+            UserPrivilegeType::Execute => "EXECUTE",
+            UserPrivilegeType::BypassRowAccessPolicy => "BYPASS ROW ACCESS POLICY",
+            UserPrivilegeType::Read => "READ",
+            UserPrivilegeType::Write => "WRITE",
             UserPrivilegeType::Grant => "GRANT",
             UserPrivilegeType::Set => "SET",
         })
@@ -129,7 +143,7 @@ impl UserPrivilegeSet {
     /// on databases and tables, and has some Global only privileges.
     pub fn available_privileges_on_global() -> Self {
         let database_privs = Self::available_privileges_on_database();
-        let privs = make_bitflags!(UserPrivilegeType::{ Usage | Super | CreateUser | DropUser | CreateRole | DropRole | Grant });
+        let privs = make_bitflags!(UserPrivilegeType::{ Usage | Super | CreateUser | DropUser | CreateRole | DropRole | Grant | Execute | BypassRowAccessPolicy });
         (database_privs.privileges | privs).into()
     }
 
@@ -145,6 +159,16 @@ impl UserPrivilegeSet {
         make_bitflags!(UserPrivilegeType::{ Create | Update | Select | Insert | Delete | Drop | Alter | Grant }).into()
     }
 
+    /// The all privileges which available to the column object
+    pub fn available_privileges_on_column() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Select }).into()
+    }
+
+    /// The all privileges which available to the stage object
+    pub fn available_privileges_on_stage() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Read | Write }).into()
+    }
+
     // TODO: remove this, as ALL has different meanings on different objects
     pub fn all_privileges() -> Self {
         ALL_PRIVILEGES.into()
@@ -17,6 +17,7 @@
 mod catalog;
 mod database;
 mod table;
+mod table_statistics;
 
 pub use catalog::CatalogMeta;
 pub use catalog::CatalogNameIdent;
@@ -62,6 +63,9 @@ pub use table::TableCopiedFileInfo;
 pub use table::TableCopiedFileLock;
 pub use table::TableCopiedFileLockKey;
 pub use table::TableCopiedFileNameIdent;
+pub use table::TableCopyHistoryIdent;
+pub use table::TableCopyHistoryInfo;
+pub use table::TableCopyStatus;
 pub use table::TableId;
 pub use table::TableIdList;
 pub use table::TableIdListKey;
@@ -79,8 +83,11 @@ pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableCopiedFileReply;
 pub use table::UpsertTableCopiedFileReq;
+pub use table::UpsertTableCopyHistoryReq;
 pub use table::UpsertTableOptionReply;
 pub use table::UpsertTableOptionReq;
+pub use table_statistics::ExternalColumnStatistics;
+pub use table_statistics::ExternalTableStatistics;
 
 const PREFIX_DB_ID_LIST: &str = "__fd_db_id_list";
 const PREFIX_DATABASE: &str = "__fd_database";
@@ -94,3 +101,4 @@ const PREFIX_TABLE_COUNT: &str = "__fd_table_count";
 const PREFIX_TABLE_ID_TO_NAME: &str = "__fd_table_id_to_name";
 const PREFIX_TABLE_COPIED_FILES: &str = "__fd_table_copied_files";
 const PREFIX_TABLE_COPIED_FILES_LOCK: &str = "__fd_table_copied_file_lock";
+const PREFIX_TABLE_COPY_HISTORY: &str = "__fd_table_copy_history";
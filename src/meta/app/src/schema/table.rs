@@ -22,7 +22,10 @@ use std::sync::Arc;
 
 use chrono::DateTime;
 use chrono::Utc;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::FieldIndex;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_meta_types::MatchSeq;
@@ -31,6 +34,7 @@ use maplit::hashmap;
 
 use crate::schema::database::DatabaseNameIdent;
 use crate::share::ShareNameIdent;
+use crate::share::ShareSpec;
 use crate::storage::StorageParams;
 
 /// Globally unique identifier of a version of TableMeta.
@@ -225,6 +229,13 @@ pub struct TableMeta {
     // if used in CreateTableReq, this field MUST set to None.
     pub drop_on: Option<DateTime<Utc>>,
     pub statistics: TableStatistics,
+    // Masking policies attached to columns, keyed by column name.
+    #[serde(default)]
+    pub column_mask_policy: BTreeMap<String, String>,
+    // Row access policies added to the table, keyed by policy name, with the columns
+    // bound to the arguments of the policy.
+    #[serde(default)]
+    pub row_access_policies: BTreeMap<String, Vec<String>>,
 }
 
 impl TableMeta {
@@ -238,10 +249,74 @@ impl TableMeta {
         Ok(())
     }
 
+    pub fn add_column(
+        &mut self,
+        field: &TableField,
+        comment: &str,
+        index: FieldIndex,
+    ) -> Result<()> {
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.add_column(field, index)?;
+        self.schema = Arc::new(new_schema);
+        if index <= self.field_comments.len() {
+            self.field_comments.insert(index, comment.to_owned());
+        }
+        Ok(())
+    }
+
     pub fn drop_column(&mut self, column: &str) -> Result<()> {
+        if let Some((policy, _)) = self
+            .row_access_policies
+            .iter()
+            .find(|(_, columns)| columns.iter().any(|c| c == column))
+        {
+            return Err(ErrorCode::RowAccessPolicyIsReferenced(format!(
+                "column {} is used by row access policy {}, drop the policy from the table first",
+                column, policy
+            )));
+        }
+        let i = self.schema.index_of(column)?;
         let mut new_schema = self.schema.as_ref().to_owned();
         new_schema.drop_column(column)?;
         self.schema = Arc::new(new_schema);
+        if i < self.field_comments.len() {
+            self.field_comments.remove(i);
+        }
+        self.column_mask_policy.remove(column);
+        Ok(())
+    }
+
+    pub fn rename_column(&mut self, column: &str, new_column: &str) -> Result<()> {
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.rename_column(column, new_column)?;
+        self.schema = Arc::new(new_schema);
+        if let Some(policy) = self.column_mask_policy.remove(column) {
+            self.column_mask_policy
+                .insert(new_column.to_string(), policy);
+        }
+        for columns in self.row_access_policies.values_mut() {
+            for c in columns.iter_mut().filter(|c| *c == column) {
+                *c = new_column.to_string();
+            }
+        }
+        Ok(())
+    }
+
+    pub fn modify_column(&mut self, column: &str, data_type: TableDataType) -> Result<()> {
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.modify_column(column, data_type)?;
+        self.schema = Arc::new(new_schema);
+        Ok(())
+    }
+
+    pub fn modify_column_with_origin(
+        &mut self,
+        column: &str,
+        data_type: TableDataType,
+    ) -> Result<()> {
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.modify_column_with_origin(column, data_type)?;
+        self.schema = Arc::new(new_schema);
         Ok(())
     }
 }
@@ -320,6 +395,8 @@ impl Default for TableMeta {
             field_comments: vec![],
             drop_on: None,
             statistics: Default::default(),
+            column_mask_policy: BTreeMap::new(),
+            row_access_policies: BTreeMap::new(),
         }
     }
 }
@@ -332,6 +409,17 @@ impl TableMeta {
         self
     }
 
+    /// Replaces the text of the default cluster key, e.g. after a column it references is
+    /// renamed. The key id is kept, the blocks are still clustered by the same key.
+    pub fn replace_default_cluster_key(&mut self, cluster_key: String) {
+        if let Some(id) = self.default_cluster_key_id {
+            if let Some(key) = self.cluster_keys.get_mut(id as usize) {
+                *key = cluster_key.clone();
+            }
+            self.default_cluster_key = Some(cluster_key);
+        }
+    }
+
     pub fn cluster_key(&self) -> Option<(u32, String)> {
         self.default_cluster_key_id
             .zip(self.default_cluster_key.clone())
@@ -468,7 +556,10 @@ impl Display for DropTableByIdReq {
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
-pub struct DropTableReply {}
+pub struct DropTableReply {
+    // share specs to be refreshed if the table is shared
+    pub spec_vec: Option<Vec<ShareSpec>>,
+}
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct UndropTableReq {
@@ -704,11 +795,78 @@ pub struct UpsertTableCopiedFileReq {
     pub file_info: BTreeMap<String, TableCopiedFileInfo>,
     pub expire_at: Option<u64>,
     pub fail_if_duplicated: bool,
+    /// Load history of the copied files, written in the same transaction as `file_info`.
+    pub copy_history: Option<UpsertTableCopyHistoryReq>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct UpsertTableCopiedFileReply {}
 
+/// "__fd_table_copy_history/<tenant>/<table_id>/<query_id>/<file>"
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct TableCopyHistoryIdent {
+    pub tenant: String,
+    pub table_id: u64,
+    pub query_id: String,
+    pub file: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TableCopyStatus {
+    /// All rows of the file are loaded.
+    Loaded,
+    /// Some rows of the file are skipped by `ON_ERROR`.
+    PartiallyLoaded,
+    /// No row of the file is loaded.
+    LoadFailed,
+}
+
+impl TableCopyStatus {
+    pub fn create(rows_loaded: u64, error_count: u64) -> Self {
+        match (rows_loaded, error_count) {
+            (_, 0) => TableCopyStatus::Loaded,
+            (0, _) => TableCopyStatus::LoadFailed,
+            _ => TableCopyStatus::PartiallyLoaded,
+        }
+    }
+}
+
+impl Display for TableCopyStatus {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TableCopyStatus::Loaded => write!(f, "LOADED"),
+            TableCopyStatus::PartiallyLoaded => write!(f, "PARTIALLY_LOADED"),
+            TableCopyStatus::LoadFailed => write!(f, "LOAD_FAILED"),
+        }
+    }
+}
+
+/// Load history of a file copied into a table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableCopyHistoryInfo {
+    pub file_name: String,
+    pub database_name: String,
+    pub table_name: String,
+    pub stage_location: String,
+    pub file_size: u64,
+    pub rows_parsed: u64,
+    pub rows_loaded: u64,
+    pub error_count: u64,
+    pub status: TableCopyStatus,
+    pub first_error_message: Option<String>,
+    pub first_error_line: Option<u64>,
+    pub first_error_column: Option<u64>,
+    pub last_load_time: DateTime<Utc>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpsertTableCopyHistoryReq {
+    pub tenant: String,
+    pub query_id: String,
+    pub history: Vec<TableCopyHistoryInfo>,
+    pub expire_at: Option<u64>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct TruncateTableReq {
     pub table_id: u64,
@@ -732,6 +890,7 @@ mod kvapi_key_impl {
     use crate::schema::DBIdTableName;
     use crate::schema::TableCopiedFileLockKey;
     use crate::schema::TableCopiedFileNameIdent;
+    use crate::schema::TableCopyHistoryIdent;
     use crate::schema::TableId;
     use crate::schema::TableIdListKey;
     use crate::schema::TableIdToName;
@@ -739,6 +898,7 @@ mod kvapi_key_impl {
     use crate::schema::PREFIX_TABLE_BY_ID;
     use crate::schema::PREFIX_TABLE_COPIED_FILES;
     use crate::schema::PREFIX_TABLE_COPIED_FILES_LOCK;
+    use crate::schema::PREFIX_TABLE_COPY_HISTORY;
     use crate::schema::PREFIX_TABLE_COUNT;
     use crate::schema::PREFIX_TABLE_ID_LIST;
     use crate::schema::PREFIX_TABLE_ID_TO_NAME;
@@ -890,6 +1050,37 @@ mod kvapi_key_impl {
             Ok(TableCopiedFileLockKey { table_id })
         }
     }
+
+    /// "__fd_table_copy_history/<tenant>/<table_id>/<query_id>/<file>" -> TableCopyHistoryInfo
+    impl kvapi::Key for TableCopyHistoryIdent {
+        const PREFIX: &'static str = PREFIX_TABLE_COPY_HISTORY;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_u64(self.table_id)
+                .push_str(&self.query_id)
+                .push_str(&self.file)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let table_id = p.next_u64()?;
+            let query_id = p.next_str()?;
+            let file = p.next_str()?;
+            p.done()?;
+
+            Ok(TableCopyHistoryIdent {
+                tenant,
+                table_id,
+                query_id,
+                file,
+            })
+        }
+    }
 }
 
 #[cfg(test)]
@@ -898,6 +1089,28 @@ mod tests {
     use common_meta_kvapi::kvapi::Key;
 
     use crate::schema::TableCopiedFileNameIdent;
+    use crate::schema::TableCopyHistoryIdent;
+
+    #[test]
+    fn test_table_copy_history_ident_conversion() -> Result<(), kvapi::KeyError> {
+        let ident = TableCopyHistoryIdent {
+            tenant: "tenant1".to_string(),
+            table_id: 2,
+            query_id: "q1".to_string(),
+            file: "/path/to/file".to_string(),
+        };
+
+        let key = ident.to_string_key();
+        assert_eq!(
+            key,
+            format!(
+                "{}/tenant1/2/q1/%2fpath%2fto%2ffile",
+                TableCopyHistoryIdent::PREFIX
+            )
+        );
+        assert_eq!(TableCopyHistoryIdent::from_str_key(&key)?, ident);
+        Ok(())
+    }
 
     #[test]
     fn test_table_copied_file_name_ident_conversion() -> Result<(), kvapi::KeyError> {
@@ -0,0 +1,87 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_expression::Scalar;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Statistics of a table whose storage does not maintain them itself, e.g. tables of
+/// the hive or iceberg catalog.
+///
+/// They are refreshed by sampling the table every `analyze_interval_secs` seconds,
+/// an interval of 0 disables the refresh.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct ExternalTableStatistics {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub analyze_interval_secs: u64,
+    /// Estimated number of rows of the whole table.
+    #[serde(default)]
+    pub number_of_rows: Option<u64>,
+    /// Estimated size of the table data in bytes.
+    #[serde(default)]
+    pub data_bytes: Option<u64>,
+    /// Statistics of the primitive columns, keyed by the leaf index of the column.
+    #[serde(default)]
+    pub column_statistics: BTreeMap<u32, ExternalColumnStatistics>,
+    /// When the statistics were computed, `None` if the table is not analyzed yet.
+    #[serde(default)]
+    pub updated_on: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ExternalColumnStatistics {
+    pub min: Scalar,
+    pub max: Scalar,
+    pub null_count: u64,
+    pub number_of_distinct_values: u64,
+}
+
+impl ExternalTableStatistics {
+    pub fn new(catalog: &str, database: &str, table: &str, analyze_interval_secs: u64) -> Self {
+        ExternalTableStatistics {
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+            table: table.to_string(),
+            analyze_interval_secs,
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if the statistics were computed within the last `max_age_secs` seconds.
+    pub fn is_fresh(&self, now: DateTime<Utc>, max_age_secs: u64) -> bool {
+        match self.updated_on {
+            Some(updated_on) => (now - updated_on).num_seconds() <= max_age_secs as i64,
+            None => false,
+        }
+    }
+
+    /// Returns true if the table should be analyzed again at `now`.
+    pub fn need_refresh(&self, now: DateTime<Utc>) -> bool {
+        if self.analyze_interval_secs == 0 {
+            return false;
+        }
+        match self.updated_on {
+            Some(updated_on) => {
+                (now - updated_on).num_seconds() >= self.analyze_interval_secs as i64
+            }
+            None => true,
+        }
+    }
+}
@@ -17,6 +17,8 @@ mod share;
 
 pub use share::AddShareAccountsReply;
 pub use share::AddShareAccountsReq;
+pub use share::AlterShareCommentReply;
+pub use share::AlterShareCommentReq;
 pub use share::CreateShareEndpointReply;
 pub use share::CreateShareEndpointReq;
 pub use share::CreateShareReply;
@@ -123,6 +123,8 @@ pub struct AddShareAccountsReq {
     pub if_exists: bool,
     pub accounts: Vec<String>,
     pub share_on: DateTime<Utc>,
+    // restrict the accounts to read-only access
+    pub read_only: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -144,6 +146,19 @@ pub struct RemoveShareAccountsReply {
     pub spec_vec: Option<Vec<ShareSpec>>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterShareCommentReq {
+    pub share_name: ShareNameIdent,
+    pub if_exists: bool,
+    pub comment: Option<String>,
+    pub update_on: DateTime<Utc>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterShareCommentReply {
+    pub share_id: Option<u64>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ShowShareOfReq {
     pub share_name: ShareNameIdent,
@@ -543,6 +558,9 @@ pub struct ShareMeta {
 
     // save db ids which created from this share
     pub share_from_db_ids: BTreeSet<u64>,
+
+    // save accounts which are restricted to read-only access, a subset of `accounts`.
+    pub read_only_accounts: BTreeSet<String>,
 }
 
 impl ShareMeta {
@@ -568,6 +586,20 @@ impl ShareMeta {
 
     pub fn del_account(&mut self, account: &String) {
         self.accounts.remove(account);
+        self.read_only_accounts.remove(account);
+    }
+
+    pub fn is_read_only_account(&self, account: &String) -> bool {
+        self.read_only_accounts.contains(account)
+    }
+
+    /// Set the read-only restriction of a granted account, return true if it is changed.
+    pub fn set_account_read_only(&mut self, account: &String, read_only: bool) -> bool {
+        if read_only {
+            self.read_only_accounts.insert(account.clone())
+        } else {
+            self.read_only_accounts.remove(account)
+        }
     }
 
     pub fn has_share_from_db_id(&self, db_id: u64) -> bool {
@@ -754,6 +786,8 @@ pub struct ShareSpec {
     pub database: Option<ShareDatabaseSpec>,
     pub tables: Vec<ShareTableSpec>,
     pub tenants: Vec<String>,
+    #[serde(default)]
+    pub read_only_tenants: Vec<String>,
 }
 
 mod kvapi_key_impl {
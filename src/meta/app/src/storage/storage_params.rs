@@ -358,10 +358,25 @@ impl Debug for StorageS3Config {
 }
 
 /// Config for storage backend http.
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StorageHttpConfig {
     pub endpoint_url: String,
     pub paths: Vec<String>,
+    /// Bearer token sent in the `Authorization` header, empty for no auth.
+    pub token: String,
+    /// URL prefixes that the redirects may go to, empty for any.
+    pub allowed_url_prefixes: Vec<String>,
+}
+
+impl Debug for StorageHttpConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StorageHttpConfig")
+            .field("endpoint_url", &self.endpoint_url)
+            .field("paths", &self.paths)
+            .field("token", &mask_string(&self.token, 3))
+            .field("allowed_url_prefixes", &self.allowed_url_prefixes)
+            .finish()
+    }
 }
 
 pub const STORAGE_IPFS_DEFAULT_ENDPOINT: &str = "https://ipfs.io";
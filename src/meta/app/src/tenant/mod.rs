@@ -14,4 +14,8 @@
 
 mod quota;
 
+pub use quota::DatabaseQuota;
+pub use quota::DatabaseQuotaIdent;
+pub use quota::DatabaseUsage;
+pub use quota::DatabaseUsageIdent;
 pub use quota::TenantQuota;
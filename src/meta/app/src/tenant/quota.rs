@@ -46,3 +46,113 @@ impl TryFrom<Vec<u8>> for TenantQuota {
         }
     }
 }
+
+/// Per-database limits. A zero value means unlimited.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct DatabaseQuota {
+    // The max tables can be created in the database.
+    pub max_tables: u64,
+
+    // The max total size of data in bytes of all tables in the database.
+    pub max_bytes: u64,
+
+    // The max total number of rows of all tables in the database.
+    pub max_rows: u64,
+}
+
+impl DatabaseQuota {
+    /// Returns true if no limit is set on the size of data.
+    pub fn is_size_unlimited(&self) -> bool {
+        self.max_bytes == 0 && self.max_rows == 0
+    }
+}
+
+impl TryFrom<Vec<u8>> for DatabaseQuota {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> common_exception::Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(quota) => Ok(quota),
+            Err(err) => Err(ErrorCode::IllegalTenantQuotaFormat(format!(
+                "Cannot deserialize database quota from bytes. cause {}",
+                err
+            ))),
+        }
+    }
+}
+
+/// "__fd_database_quota/<db_id>" -> DatabaseQuota, encoded in json.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DatabaseQuotaIdent {
+    pub db_id: u64,
+}
+
+/// The size of all the tables of a database with a size quota, as of the last commit that
+/// grew one of them.
+///
+/// Such a commit rewrites it in the same txn as the table meta, on the condition that its seq
+/// is unchanged, so that the commits growing the tables of one database are checked against
+/// the quota one at a time.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct DatabaseUsage {
+    pub data_bytes: u64,
+    pub number_of_rows: u64,
+}
+
+/// "__fd_database_usage/<db_id>" -> DatabaseUsage, encoded in json.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DatabaseUsageIdent {
+    pub db_id: u64,
+}
+
+mod kvapi_key_impl {
+    use common_meta_kvapi::kvapi;
+
+    use super::DatabaseQuotaIdent;
+    use super::DatabaseUsageIdent;
+
+    const PREFIX_DATABASE_QUOTA: &str = "__fd_database_quota";
+    const PREFIX_DATABASE_USAGE: &str = "__fd_database_usage";
+
+    /// "__fd_database_quota/<db_id>"
+    impl kvapi::Key for DatabaseQuotaIdent {
+        const PREFIX: &'static str = PREFIX_DATABASE_QUOTA;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_u64(self.db_id)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let db_id = p.next_u64()?;
+            p.done()?;
+
+            Ok(DatabaseQuotaIdent { db_id })
+        }
+    }
+
+    /// "__fd_database_usage/<db_id>"
+    impl kvapi::Key for DatabaseUsageIdent {
+        const PREFIX: &'static str = PREFIX_DATABASE_USAGE;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_u64(self.db_id)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let db_id = p.next_u64()?;
+            p.done()?;
+
+            Ok(DatabaseUsageIdent { db_id })
+        }
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use common_meta_app::principal::ExternalFunctionProtocol;
+
+#[test]
+fn test_external_function_protocol() {
+    let protocol = ExternalFunctionProtocol::from_str("arrow").unwrap();
+    assert_eq!(protocol, ExternalFunctionProtocol::Arrow);
+    assert_eq!(protocol.to_string(), "ARROW");
+
+    let protocol = ExternalFunctionProtocol::from_str("Json").unwrap();
+    assert_eq!(protocol, ExternalFunctionProtocol::Json);
+    assert_eq!(protocol.to_string(), "JSON");
+
+    assert_eq!(
+        ExternalFunctionProtocol::default(),
+        ExternalFunctionProtocol::Arrow
+    );
+    assert!(ExternalFunctionProtocol::from_str("grpc").is_err());
+}
@@ -12,6 +12,9 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod external_function;
+mod pipe;
+mod task;
 mod user_defined_function;
 mod user_grant;
 mod user_info;
@@ -0,0 +1,64 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::Utc;
+use common_meta_app::principal::PipePartitionStatus;
+use common_meta_app::principal::PipeSource;
+use common_meta_app::principal::PipeStatus;
+
+#[test]
+fn test_pipe_source() {
+    let source = PipeSource::Kafka {
+        brokers: vec!["k1:9092".to_string(), "k2:9092".to_string()],
+        topic: "events".to_string(),
+        group: "g1".to_string(),
+    };
+    assert_eq!(source.offsets_key(), "kafka/g1/events");
+    assert_eq!(
+        source.to_string(),
+        "KAFKA (brokers = 'k1:9092,k2:9092', topic = 'events', group = 'g1')"
+    );
+}
+
+#[test]
+fn test_pipe_status_lag() {
+    let status = PipeStatus {
+        pipe_name: "p1".to_string(),
+        holder: "node1".to_string(),
+        partitions: vec![
+            PipePartitionStatus {
+                partition: 0,
+                offset: 10,
+                high_watermark: 15,
+            },
+            PipePartitionStatus {
+                partition: 1,
+                offset: 7,
+                high_watermark: 7,
+            },
+            // The high watermark is older than the committed offset.
+            PipePartitionStatus {
+                partition: 2,
+                offset: 9,
+                high_watermark: 8,
+            },
+        ],
+        rows_loaded: 100,
+        rows_rejected: 1,
+        rows_per_second: 20,
+        last_committed_on: None,
+        last_error: None,
+        updated_on: Utc::now(),
+    };
+    assert_eq!(status.lag(), 5);
+}
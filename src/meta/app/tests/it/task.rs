@@ -0,0 +1,114 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::principal::TaskInfo;
+use common_meta_app::principal::TaskSchedule;
+use common_meta_app::principal::TaskState;
+use common_meta_app::principal::UserIdentity;
+
+#[test]
+fn test_task_schedule_interval() {
+    let schedule = TaskSchedule::from_str("5 MINUTE").unwrap();
+    assert_eq!(schedule, TaskSchedule::Interval { secs: 300 });
+    assert_eq!(schedule.to_string(), "5 MINUTE");
+
+    let schedule = TaskSchedule::from_str(" 2  hours ").unwrap();
+    assert_eq!(schedule, TaskSchedule::Interval { secs: 7200 });
+    assert_eq!(schedule.to_string(), "2 HOUR");
+
+    let after = Utc.with_ymd_and_hms(2023, 6, 1, 10, 7, 30).unwrap();
+    assert_eq!(
+        schedule.next_after(after),
+        Some(Utc.with_ymd_and_hms(2023, 6, 1, 12, 7, 30).unwrap())
+    );
+
+    assert!(TaskSchedule::from_str("0 MINUTE").is_err());
+    assert!(TaskSchedule::from_str("5 WEEK").is_err());
+    assert!(TaskSchedule::from_str("five MINUTE").is_err());
+}
+
+#[test]
+fn test_task_schedule_cron() {
+    let after = Utc.with_ymd_and_hms(2023, 6, 1, 10, 7, 30).unwrap();
+
+    let schedule = TaskSchedule::from_str("USING CRON */15 * * * *").unwrap();
+    assert_eq!(schedule.to_string(), "USING CRON */15 * * * *");
+    assert_eq!(
+        schedule.next_after(after),
+        Some(Utc.with_ymd_and_hms(2023, 6, 1, 10, 15, 0).unwrap())
+    );
+
+    // 2023-06-01 is a Thursday, the next Monday is 2023-06-05.
+    let schedule = TaskSchedule::from_str("0 2 * * 1").unwrap();
+    assert_eq!(schedule.to_string(), "USING CRON 0 2 * * 1");
+    assert_eq!(
+        schedule.next_after(after),
+        Some(Utc.with_ymd_and_hms(2023, 6, 5, 2, 0, 0).unwrap())
+    );
+
+    // Either of the restricted day fields matches.
+    let schedule = TaskSchedule::from_str("USING CRON 0 0 3 * 1").unwrap();
+    assert_eq!(
+        schedule.next_after(after),
+        Some(Utc.with_ymd_and_hms(2023, 6, 3, 0, 0, 0).unwrap())
+    );
+
+    let schedule = TaskSchedule::from_str("USING CRON 30 1 1,15 1-3 *").unwrap();
+    assert_eq!(
+        schedule.next_after(after),
+        Some(Utc.with_ymd_and_hms(2024, 1, 1, 1, 30, 0).unwrap())
+    );
+
+    let schedule = TaskSchedule::from_str("USING CRON 0 0 30 2 *").unwrap();
+    assert_eq!(schedule.next_after(after), None);
+
+    assert!(TaskSchedule::from_str("USING CRON * * * *").is_err());
+    assert!(TaskSchedule::from_str("USING CRON 60 * * * *").is_err());
+    assert!(TaskSchedule::from_str("USING CRON */0 * * * *").is_err());
+    assert!(TaskSchedule::from_str("USING CRON 5-1 * * * *").is_err());
+}
+
+#[test]
+fn test_task_next_scheduled_on() {
+    let created_on = Utc.with_ymd_and_hms(2023, 6, 1, 10, 0, 0).unwrap();
+    let mut task = TaskInfo {
+        name: "t1".to_string(),
+        owner: UserIdentity::new("root", "%"),
+        database: "default".to_string(),
+        schedule: TaskSchedule::Interval { secs: 300 },
+        query_text: "DELETE FROM t".to_string(),
+        state: TaskState::Started,
+        comment: "".to_string(),
+        created_on,
+        updated_on: created_on,
+        last_scheduled_on: None,
+    };
+    assert_eq!(
+        task.next_scheduled_on(),
+        Some(Utc.with_ymd_and_hms(2023, 6, 1, 10, 5, 0).unwrap())
+    );
+
+    task.last_scheduled_on = Some(Utc.with_ymd_and_hms(2023, 6, 1, 11, 0, 0).unwrap());
+    assert_eq!(
+        task.next_scheduled_on(),
+        Some(Utc.with_ymd_and_hms(2023, 6, 1, 11, 5, 0).unwrap())
+    );
+
+    task.state = TaskState::Suspended;
+    assert_eq!(task.next_scheduled_on(), None);
+}
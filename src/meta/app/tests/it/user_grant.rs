@@ -77,6 +77,56 @@ fn test_grant_object_contains() -> Result<()> {
             rhs: GrantObject::Database("default".into(), "db1".into()),
             expect: false,
         },
+        Test {
+            lhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "a".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "d".into(), "a".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Database("default".into(), "db1".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "a".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "a".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "a".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "a".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "b".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "a".into()),
+            rhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Global,
+            rhs: GrantObject::Stage("s1".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Stage("s1".into()),
+            rhs: GrantObject::Stage("s1".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Stage("s1".into()),
+            rhs: GrantObject::Stage("s2".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Database("default".into(), "s1".into()),
+            rhs: GrantObject::Stage("s1".into()),
+            expect: false,
+        },
     ];
     for t in tests {
         assert_eq!(
@@ -200,3 +250,61 @@ fn test_user_grant_set() -> Result<()> {
     ));
     Ok(())
 }
+
+#[test]
+fn test_user_grant_set_granted_columns() -> Result<()> {
+    let mut grants = UserGrantSet::empty();
+    assert_eq!(
+        None,
+        grants.granted_columns("default", "db1", "t1", UserPrivilegeType::Select)
+    );
+
+    grants.grant_privileges(
+        &GrantObject::Column("default".into(), "db1".into(), "t1".into(), "a".into()),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    grants.grant_privileges(
+        &GrantObject::Column("default".into(), "db1".into(), "t1".into(), "b".into()),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    grants.grant_privileges(
+        &GrantObject::Column("default".into(), "db1".into(), "t2".into(), "c".into()),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    assert!(grants.verify_privilege(
+        &GrantObject::Column("default".into(), "db1".into(), "t1".into(), "a".into()),
+        vec![UserPrivilegeType::Select]
+    ));
+    assert!(!grants.verify_privilege(
+        &GrantObject::Column("default".into(), "db1".into(), "t1".into(), "c".into()),
+        vec![UserPrivilegeType::Select]
+    ));
+    assert!(!grants.verify_privilege(
+        &GrantObject::Table("default".into(), "db1".into(), "t1".into()),
+        vec![UserPrivilegeType::Select]
+    ));
+    assert_eq!(
+        Some(["a".to_string(), "b".to_string()].into_iter().collect()),
+        grants.granted_columns("default", "db1", "t1", UserPrivilegeType::Select)
+    );
+
+    // A grant on the whole table means all columns.
+    grants.grant_privileges(
+        &GrantObject::Table("default".into(), "db1".into(), "t1".into()),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    assert_eq!(
+        None,
+        grants.granted_columns("default", "db1", "t1", UserPrivilegeType::Select)
+    );
+
+    grants.revoke_privileges(
+        &GrantObject::Column("default".into(), "db1".into(), "t2".into(), "c".into()),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    assert_eq!(
+        None,
+        grants.granted_columns("default", "db1", "t2", UserPrivilegeType::Select)
+    );
+    Ok(())
+}
@@ -25,9 +25,11 @@ use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::protobuf::meta_service_client::MetaServiceClient;
 use common_meta_types::protobuf::ClientInfo;
+use common_meta_types::protobuf::ClusterStatus;
 use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
+use common_meta_types::MatchSeq;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use tonic::codegen::InterceptedService;
@@ -37,6 +39,7 @@ use tonic::Request;
 use crate::grpc_client::AuthInterceptor;
 use crate::message::ExportReq;
 use crate::message::GetClientInfo;
+use crate::message::GetClusterStatus;
 use crate::message::GetEndpoints;
 use crate::message::MakeClient;
 
@@ -54,6 +57,20 @@ pub enum MetaGrpcReq {
     ListKV(ListKVReq),
 }
 
+impl MetaGrpcReq {
+    /// Whether sending this request more than once has the same effect as sending it once.
+    ///
+    /// Reads are idempotent, and so is an upsert with an exact seq:
+    /// if the first attempt has been applied, a retried one does not match the seq any more.
+    /// An upsert with `GE(n)` still matches after being applied, it is not idempotent.
+    pub fn is_idempotent(&self) -> bool {
+        match self {
+            MetaGrpcReq::UpsertKV(a) => matches!(a.seq, MatchSeq::Exact(_)),
+            MetaGrpcReq::GetKV(_) | MetaGrpcReq::MGetKV(_) | MetaGrpcReq::ListKV(_) => true,
+        }
+    }
+}
+
 impl TryInto<MetaGrpcReq> for Request<RaftRequest> {
     type Error = tonic::Status;
 
@@ -119,3 +136,7 @@ impl RequestFor for TxnRequest {
 impl RequestFor for GetClientInfo {
     type Reply = ClientInfo;
 }
+
+impl RequestFor for GetClusterStatus {
+    type Reply = ClusterStatus;
+}
@@ -14,6 +14,7 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
@@ -42,6 +43,7 @@ use common_meta_api::reply::reply_to_api_result;
 use common_meta_types::anyerror::AnyError;
 use common_meta_types::protobuf::meta_service_client::MetaServiceClient;
 use common_meta_types::protobuf::ClientInfo;
+use common_meta_types::protobuf::ClusterStatus;
 use common_meta_types::protobuf::Empty;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeRequest;
@@ -51,8 +53,10 @@ use common_meta_types::protobuf::RaftReply;
 use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
+use common_meta_types::txn_op;
 use common_meta_types::ConnectionError;
 use common_meta_types::InvalidArgument;
+use common_meta_types::MetaAPIError;
 use common_meta_types::MetaClientError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaHandshakeError;
@@ -66,6 +70,7 @@ use common_metrics::label_increment_gauge_with_val_and_labels;
 use futures::stream::StreamExt;
 use parking_lot::Mutex;
 use prost::Message;
+use rand::Rng;
 use semver::Version;
 use serde::de::DeserializeOwned;
 use tonic::async_trait;
@@ -100,6 +105,12 @@ const LABEL_ENDPOINT: &str = "endpoint";
 const LABEL_REQUEST: &str = "request";
 const LABEL_ERROR: &str = "error";
 
+/// Max number of attempts to send a kv or transaction request, including the first one.
+const RETRY_MAX_ATTEMPTS: u32 = 5;
+
+/// The delay before the first retry. It doubles for every following retry.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(50);
+
 #[derive(Debug)]
 struct MetaChannelManager {
     timeout: Option<Duration>,
@@ -230,6 +241,10 @@ impl ClientHandle {
         self.request(message::GetClientInfo {}).await
     }
 
+    pub async fn get_cluster_status(&self) -> Result<ClusterStatus, MetaError> {
+        self.request(message::GetClusterStatus {}).await
+    }
+
     pub async fn make_client(
         &self,
     ) -> Result<MetaServiceClient<InterceptedService<Channel, AuthInterceptor>>, MetaClientError>
@@ -430,6 +445,10 @@ impl MetaGrpcClient {
                     let resp = self.get_client_info().await;
                     message::Response::GetClientInfo(resp)
                 }
+                message::Request::GetClusterStatus(_) => {
+                    let resp = self.get_cluster_status().await;
+                    message::Response::GetClusterStatus(resp)
+                }
             };
 
             debug!(
@@ -503,9 +522,20 @@ impl MetaGrpcClient {
         debug_assert!(!eps.is_empty());
 
         if eps.len() > 1 {
-            // remove unhealthy endpoints
+            // remove unhealthy endpoints.
+            // If all of them are unhealthy, e.g., after a leader switch, just avoid the last used one.
+            let current = self.current_endpoint.lock().clone();
             let ues = self.unhealthy_endpoints.lock();
-            eps.retain(|e| !ues.contains_key(e));
+            let healthy = eps
+                .iter()
+                .filter(|e| !ues.contains_key(*e))
+                .cloned()
+                .collect::<Vec<_>>();
+            if !healthy.is_empty() {
+                eps = healthy;
+            } else {
+                eps.retain(|e| Some(e) != current.as_ref());
+            }
         }
 
         for (addr, is_last) in eps.iter().enumerate().map(|(i, a)| (a, i == eps.len() - 1)) {
@@ -827,6 +857,15 @@ impl MetaGrpcClient {
         Ok(res.into_inner())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub(crate) async fn get_cluster_status(&self) -> Result<ClusterStatus, MetaError> {
+        debug!("MetaGrpcClient::get_cluster_status");
+
+        let mut client = self.make_client().await?;
+        let res = client.get_cluster_status(Empty {}).await?;
+        Ok(res.into_inner())
+    }
+
     #[tracing::instrument(level = "debug", skip(self, v))]
     pub(crate) async fn kv_api<T, R>(&self, v: T) -> Result<R, MetaError>
     where
@@ -838,8 +877,19 @@ impl MetaGrpcClient {
 
         debug!(req = debug(&read_req), "MetaGrpcClient::kv_api request");
 
+        let idempotent = read_req.is_idempotent();
+        self.send_with_retry("kv_api", idempotent, || self.kv_api_once(&read_req))
+            .await
+    }
+
+    async fn kv_api_once<R>(&self, read_req: &MetaGrpcReq) -> Result<R, RpcFailure>
+    where R: DeserializeOwned {
         let req: Request<RaftRequest> = read_req.clone().try_into().map_err(|e| {
-            MetaNetworkError::InvalidArgument(InvalidArgument::new(e, "fail to encode request"))
+            let net_err = MetaNetworkError::InvalidArgument(InvalidArgument::new(
+                e,
+                "fail to encode request",
+            ));
+            RpcFailure::Fatal(net_err.into())
         })?;
 
         debug!(
@@ -849,33 +899,17 @@ impl MetaGrpcClient {
 
         let req = common_tracing::inject_span_to_tonic_request(req);
 
-        let mut client = self.make_client().await?;
+        let mut client = self
+            .make_client()
+            .await
+            .map_err(|e| RpcFailure::NotSent(e.into()))?;
         let result = client.kv_api(req).await;
 
         debug!(reply = debug(&result), "MetaGrpcClient::kv_api reply");
 
-        let rpc_res: Result<RaftReply, Status> = match result {
-            Ok(r) => Ok(r.into_inner()),
-            Err(s) => {
-                if status_is_retryable(&s) {
-                    self.mark_as_unhealthy().await;
-                    let mut client = self.make_client().await?;
-                    let req: Request<RaftRequest> = read_req.try_into().map_err(|e| {
-                        MetaNetworkError::InvalidArgument(InvalidArgument::new(
-                            e,
-                            "fail to encode request",
-                        ))
-                    })?;
-                    let req = common_tracing::inject_span_to_tonic_request(req);
-                    Ok(client.kv_api(req).await?.into_inner())
-                } else {
-                    Err(s)
-                }
-            }
-        };
-        let raft_reply = rpc_res?;
+        let raft_reply = result.map_err(RpcFailure::from_status)?.into_inner();
 
-        let resp: R = reply_to_api_result(raft_reply)?;
+        let resp: R = reply_to_api_result(raft_reply).map_err(RpcFailure::from_api_error)?;
         Ok(resp)
     }
 
@@ -885,41 +919,158 @@ impl MetaGrpcClient {
 
         debug!(req = display(&txn), "MetaGrpcClient::transaction request");
 
+        let idempotent = txn_is_read_only(&txn);
+        let reply = self
+            .send_with_retry("transaction", idempotent, || self.transaction_once(&txn))
+            .await?;
+
+        debug!(reply = display(&reply), "MetaGrpcClient::transaction reply");
+
+        Ok(reply)
+    }
+
+    async fn transaction_once(&self, txn: &TxnRequest) -> Result<TxnReply, RpcFailure> {
         let req: Request<TxnRequest> = Request::new(txn.clone());
         let req = common_tracing::inject_span_to_tonic_request(req);
 
-        let mut client = self.make_client().await?;
-        let result = client.transaction(req).await;
+        let mut client = self
+            .make_client()
+            .await
+            .map_err(|e| RpcFailure::NotSent(e.into()))?;
+        let reply = client
+            .transaction(req)
+            .await
+            .map_err(RpcFailure::from_status)?;
 
-        let result: Result<TxnReply, Status> = match result {
-            Ok(r) => return Ok(r.into_inner()),
-            Err(s) => {
-                if status_is_retryable(&s) {
-                    self.mark_as_unhealthy().await;
-                    let mut client = self.make_client().await?;
-                    let req: Request<TxnRequest> = Request::new(txn);
-                    let req = common_tracing::inject_span_to_tonic_request(req);
-                    let ret = client.transaction(req).await?.into_inner();
-                    return Ok(ret);
-                } else {
-                    Err(s)
-                }
+        Ok(reply.into_inner())
+    }
+
+    /// Send a request with `f` and retry on failures that are safe to retry.
+    ///
+    /// A request that is not `idempotent` is retried only if it is known not to have been applied:
+    /// when no connection could be made, or the server could not reach the leader.
+    /// When the leader is unreachable, the endpoints are refreshed before the next attempt,
+    /// so that the request will be sent to the new leader or one of its followers.
+    async fn send_with_retry<T, F, Fut>(
+        &self,
+        name: &str,
+        idempotent: bool,
+        f: F,
+    ) -> Result<T, MetaError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, RpcFailure>>,
+    {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+
+            let failure = match f().await {
+                Ok(x) => return Ok(x),
+                Err(failure) => failure,
+            };
+
+            let retryable = match &failure {
+                RpcFailure::NotSent(_) | RpcFailure::NoLeader(_) => true,
+                RpcFailure::Unknown(_) => idempotent,
+                RpcFailure::Fatal(_) => false,
+            };
+
+            if !retryable || attempt >= RETRY_MAX_ATTEMPTS {
+                return Err(failure.into_error());
             }
-        };
 
-        let reply = result?;
+            warn!(
+                "MetaGrpcClient::{} attempt {}/{} failed, retry: {:?}",
+                name, attempt, RETRY_MAX_ATTEMPTS, failure
+            );
 
-        debug!(reply = display(&reply), "MetaGrpcClient::transaction reply");
+            self.mark_as_unhealthy().await;
 
-        Ok(reply)
+            if let RpcFailure::NoLeader(_) = failure {
+                if let Err(e) = self.sync_endpoints().await {
+                    warn!("MetaGrpcClient failed to refresh endpoints: {:?}", e);
+                }
+            }
+
+            sleep(retry_backoff(attempt)).await;
+        }
     }
+
     async fn mark_as_unhealthy(&self) {
         let ca = self.current_endpoint.lock();
-        let mut ue = self.unhealthy_endpoints.lock();
-        ue.insert((*ca).as_ref().unwrap().clone(), ());
+        if let Some(addr) = &*ca {
+            let mut ue = self.unhealthy_endpoints.lock();
+            ue.insert(addr.clone(), ());
+        }
     }
 }
 
+/// Why an attempt to send a request failed, which decides whether it is safe to retry.
+#[derive(Debug)]
+enum RpcFailure {
+    /// No connection could be made, thus the request was not sent.
+    NotSent(MetaError),
+
+    /// The server can not reach the leader, e.g., during a leader change.
+    /// The request has not been applied.
+    NoLeader(MetaError),
+
+    /// The request may or may not have been applied, e.g., the connection broke after sending it.
+    Unknown(MetaError),
+
+    /// Retrying does not change the result.
+    Fatal(MetaError),
+}
+
+impl RpcFailure {
+    fn from_status(status: Status) -> Self {
+        match status.code() {
+            // The token is checked before handling a request.
+            Code::Unauthenticated => RpcFailure::NotSent(status.into()),
+            Code::Unavailable | Code::Internal => RpcFailure::Unknown(status.into()),
+            _ => RpcFailure::Fatal(status.into()),
+        }
+    }
+
+    fn from_api_error(api_err: MetaAPIError) -> Self {
+        match api_err {
+            MetaAPIError::ForwardToLeader(_) | MetaAPIError::CanNotForward(_) => {
+                RpcFailure::NoLeader(api_err.into())
+            }
+            // Error when forwarding the request to the leader.
+            MetaAPIError::NetworkError(_) => RpcFailure::Unknown(api_err.into()),
+            MetaAPIError::DataError(_) | MetaAPIError::RemoteError(_) => {
+                RpcFailure::Fatal(api_err.into())
+            }
+        }
+    }
+
+    fn into_error(self) -> MetaError {
+        match self {
+            RpcFailure::NotSent(e)
+            | RpcFailure::NoLeader(e)
+            | RpcFailure::Unknown(e)
+            | RpcFailure::Fatal(e) => e,
+        }
+    }
+}
+
+/// Exponential backoff with jitter: a random duration in `[d/2, d]`, where `d` doubles for every attempt.
+fn retry_backoff(attempt: u32) -> Duration {
+    let delay = RETRY_BASE_DELAY * 2u32.pow(attempt.saturating_sub(1).min(6));
+    let half = delay / 2;
+    half + rand::thread_rng().gen_range(Duration::ZERO..=half)
+}
+
+/// A transaction that does not write can be retried freely.
+fn txn_is_read_only(txn: &TxnRequest) -> bool {
+    txn.if_then
+        .iter()
+        .chain(txn.else_then.iter())
+        .all(|op| matches!(op.request, Some(txn_op::Request::Get(_))))
+}
+
 fn status_is_retryable(status: &Status) -> bool {
     matches!(
         status.code(),
@@ -23,6 +23,7 @@ use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::protobuf::meta_service_client::MetaServiceClient;
 use common_meta_types::protobuf::ClientInfo;
+use common_meta_types::protobuf::ClusterStatus;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
@@ -77,6 +78,9 @@ pub enum Request {
 
     /// Get info about the client
     GetClientInfo(GetClientInfo),
+
+    /// Get raft status of the connected meta-service node
+    GetClusterStatus(GetClusterStatus),
 }
 
 impl Request {
@@ -92,6 +96,7 @@ impl Request {
             Request::MakeClient(_) => "MakeClient",
             Request::GetEndpoints(_) => "GetEndpoints",
             Request::GetClientInfo(_) => "GetClientInfo",
+            Request::GetClusterStatus(_) => "GetClusterStatus",
         }
     }
 }
@@ -111,6 +116,7 @@ pub enum Response {
     ),
     GetEndpoints(Result<Vec<String>, MetaError>),
     GetClientInfo(Result<ClientInfo, MetaError>),
+    GetClusterStatus(Result<ClusterStatus, MetaError>),
 }
 
 impl Response {
@@ -126,6 +132,7 @@ impl Response {
             Response::MakeClient(res) => res.is_err(),
             Response::GetEndpoints(res) => res.is_err(),
             Response::GetClientInfo(res) => res.is_err(),
+            Response::GetClusterStatus(res) => res.is_err(),
         }
     }
 
@@ -171,6 +178,10 @@ impl Response {
                 .as_ref()
                 .err()
                 .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::GetClusterStatus(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
         };
         e
     }
@@ -193,3 +204,7 @@ pub struct GetEndpoints {}
 /// Get info about client
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub struct GetClientInfo {}
+
+/// Get raft status of the connected meta-service node
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct GetClusterStatus {}
@@ -0,0 +1,63 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_client::MetaGrpcReq;
+use common_meta_kvapi::kvapi::GetKVReq;
+use common_meta_kvapi::kvapi::ListKVReq;
+use common_meta_kvapi::kvapi::MGetKVReq;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+
+fn upsert(seq: MatchSeq) -> MetaGrpcReq {
+    MetaGrpcReq::UpsertKV(UpsertKVReq::new(
+        "foo",
+        seq,
+        Operation::Update(b"bar".to_vec()),
+        None,
+    ))
+}
+
+#[test]
+fn test_meta_grpc_req_is_idempotent() {
+    // Only an upsert on an exact seq can not be applied twice.
+    assert!(upsert(MatchSeq::Exact(0)).is_idempotent());
+    assert!(upsert(MatchSeq::Exact(5)).is_idempotent());
+    assert!(!upsert(MatchSeq::Any).is_idempotent());
+    assert!(!upsert(MatchSeq::GE(0)).is_idempotent());
+    assert!(!upsert(MatchSeq::GE(1)).is_idempotent());
+    assert!(!upsert(MatchSeq::GE(5)).is_idempotent());
+
+    let delete = UpsertKVReq::new("foo", MatchSeq::GE(1), Operation::Delete, None);
+    assert!(!MetaGrpcReq::UpsertKV(delete).is_idempotent());
+
+    assert!(
+        MetaGrpcReq::GetKV(GetKVReq {
+            key: "foo".to_string()
+        })
+        .is_idempotent()
+    );
+    assert!(
+        MetaGrpcReq::MGetKV(MGetKVReq {
+            keys: vec!["foo".to_string()]
+        })
+        .is_idempotent()
+    );
+    assert!(
+        MetaGrpcReq::ListKV(ListKVReq {
+            prefix: "foo".to_string()
+        })
+        .is_idempotent()
+    );
+}
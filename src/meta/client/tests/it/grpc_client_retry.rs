@@ -0,0 +1,258 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::pin::Pin;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_meta_client::to_digit_ver;
+use common_meta_client::MetaGrpcClient;
+use common_meta_client::MIN_METASRV_SEMVER;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReply;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::protobuf::meta_service_server::MetaService;
+use common_meta_types::protobuf::BatchTxnReply;
+use common_meta_types::protobuf::BatchTxnRequest;
+use common_meta_types::protobuf::ClientInfo;
+use common_meta_types::protobuf::ClusterStatus;
+use common_meta_types::protobuf::Empty;
+use common_meta_types::protobuf::ExportedChunk;
+use common_meta_types::protobuf::HandshakeResponse;
+use common_meta_types::protobuf::MemberListReply;
+use common_meta_types::protobuf::MemberListRequest;
+use common_meta_types::protobuf::RaftReply;
+use common_meta_types::protobuf::RaftRequest;
+use common_meta_types::protobuf::TxnReply;
+use common_meta_types::protobuf::TxnRequest;
+use common_meta_types::protobuf::WatchRequest;
+use common_meta_types::protobuf::WatchResponse;
+use common_meta_types::Change;
+use common_meta_types::ForwardToLeader;
+use common_meta_types::MetaAPIError;
+use common_meta_types::SeqV;
+use futures::Stream;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status;
+use tonic::Streaming;
+
+use crate::grpc_server::start_grpc_server_with_service;
+
+/// State shared by all the mock meta-service nodes in a test.
+#[derive(Default)]
+struct MockCluster {
+    /// Id of the current leader.
+    leader: AtomicU64,
+
+    /// Addresses of all nodes, indexed by node id.
+    endpoints: Mutex<Vec<String>>,
+
+    /// If true, every kv_api call fails with `Unavailable`, as if the connection broke.
+    unavailable: Mutex<bool>,
+}
+
+/// A meta-service node that only handles kv requests when it is the leader.
+struct MockNode {
+    id: u64,
+    cluster: Arc<MockCluster>,
+
+    /// Number of kv_api calls received.
+    kv_calls: Arc<AtomicU64>,
+}
+
+#[tonic::async_trait]
+impl MetaService for MockNode {
+    type HandshakeStream =
+        Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send + Sync + 'static>>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<common_meta_types::protobuf::HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        let output = futures::stream::once(async {
+            Ok(HandshakeResponse {
+                protocol_version: to_digit_ver(&MIN_METASRV_SEMVER),
+                payload: vec![],
+            })
+        });
+        Ok(Response::new(Box::pin(output)))
+    }
+
+    async fn kv_api(&self, _request: Request<RaftRequest>) -> Result<Response<RaftReply>, Status> {
+        self.kv_calls.fetch_add(1, Ordering::SeqCst);
+
+        if *self.cluster.unavailable.lock().unwrap() {
+            return Err(Status::unavailable("connection reset"));
+        }
+
+        let leader = self.cluster.leader.load(Ordering::SeqCst);
+        let res: Result<UpsertKVReply, MetaAPIError> = if leader == self.id {
+            // Use the node id as seq to tell which node served the request.
+            Ok(Change::new(None, Some(SeqV::new(self.id, b"v".to_vec()))))
+        } else {
+            Err(MetaAPIError::ForwardToLeader(ForwardToLeader {
+                leader_id: Some(leader),
+                leader_node: None,
+            }))
+        };
+        Ok(Response::new(RaftReply::from(res)))
+    }
+
+    type ExportStream =
+        Pin<Box<dyn Stream<Item = Result<ExportedChunk, tonic::Status>> + Send + Sync + 'static>>;
+
+    async fn export(
+        &self,
+        _request: Request<common_meta_types::protobuf::Empty>,
+    ) -> Result<Response<Self::ExportStream>, Status> {
+        todo!()
+    }
+
+    type WatchStream =
+        Pin<Box<dyn Stream<Item = Result<WatchResponse, tonic::Status>> + Send + Sync + 'static>>;
+
+    async fn watch(
+        &self,
+        _request: Request<WatchRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        todo!()
+    }
+
+    async fn transaction(
+        &self,
+        _request: Request<TxnRequest>,
+    ) -> Result<Response<TxnReply>, Status> {
+        todo!()
+    }
+
+    async fn batch_txn(
+        &self,
+        _request: Request<BatchTxnRequest>,
+    ) -> Result<Response<BatchTxnReply>, Status> {
+        todo!()
+    }
+
+    async fn member_list(
+        &self,
+        _request: Request<MemberListRequest>,
+    ) -> Result<Response<MemberListReply>, Status> {
+        let data = self.cluster.endpoints.lock().unwrap().clone();
+        Ok(Response::new(MemberListReply { data }))
+    }
+
+    async fn get_client_info(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<ClientInfo>, Status> {
+        todo!()
+    }
+
+    async fn get_cluster_status(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<ClusterStatus>, Status> {
+        todo!()
+    }
+}
+
+/// Start a mock node for every id in `ids`; returns the kv_api call counter of each.
+fn start_mock_cluster(cluster: &Arc<MockCluster>, ids: &[u64]) -> Vec<Arc<AtomicU64>> {
+    let mut counters = vec![];
+    for id in ids {
+        let kv_calls = Arc::new(AtomicU64::new(0));
+        let addr = start_grpc_server_with_service(MockNode {
+            id: *id,
+            cluster: cluster.clone(),
+            kv_calls: kv_calls.clone(),
+        });
+        cluster.endpoints.lock().unwrap().push(addr);
+        counters.push(kv_calls);
+    }
+    counters
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_grpc_client_retry_on_leader_switch() -> anyhow::Result<()> {
+    // - Start 2 nodes, node 0 is the leader.
+    // - Switch leadership to node 1 between two writes.
+    // - The second write is rejected by node 0 and is retried on node 1.
+
+    let cluster = Arc::new(MockCluster::default());
+    let counters = start_mock_cluster(&cluster, &[0, 1]);
+    let endpoints = cluster.endpoints.lock().unwrap().clone();
+
+    let timeout = Some(Duration::from_secs(3));
+    let client = MetaGrpcClient::try_create(endpoints, "root", "xxx", timeout, None, None)?;
+
+    // Whichever node receives it first, the write ends up on node 0.
+    let res = client.upsert_kv(UpsertKVReq::update("a", b"v")).await?;
+    assert_eq!(Some(0), res.result.map(|x| x.seq));
+
+    cluster.leader.store(1, Ordering::SeqCst);
+
+    let calls_before = counters[1].load(Ordering::SeqCst);
+
+    let res = client.upsert_kv(UpsertKVReq::update("a", b"v")).await?;
+    assert_eq!(
+        Some(1),
+        res.result.map(|x| x.seq),
+        "served by the new leader"
+    );
+    assert!(counters[1].load(Ordering::SeqCst) > calls_before);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_grpc_client_retry_respects_idempotency() -> anyhow::Result<()> {
+    // When the outcome of a request is unknown,
+    // only an idempotent one is retried.
+
+    let cluster = Arc::new(MockCluster::default());
+    *cluster.unavailable.lock().unwrap() = true;
+
+    let counters = start_mock_cluster(&cluster, &[0]);
+    let endpoints = cluster.endpoints.lock().unwrap().clone();
+
+    let timeout = Some(Duration::from_secs(3));
+    let client = MetaGrpcClient::try_create(endpoints, "root", "xxx", timeout, None, None)?;
+
+    // Unconditional upsert: not retried.
+    {
+        let res = client.upsert_kv(UpsertKVReq::update("a", b"v")).await;
+        assert!(res.is_err());
+        assert_eq!(1, counters[0].swap(0, Ordering::SeqCst));
+    }
+
+    // Upsert with exact seq: retried.
+    {
+        let res = client.upsert_kv(UpsertKVReq::insert("a", b"v")).await;
+        assert!(res.is_err());
+        assert!(counters[0].swap(0, Ordering::SeqCst) > 1);
+    }
+
+    // Read: retried.
+    {
+        let res = client.get_kv("a").await;
+        assert!(res.is_err());
+        assert!(counters[0].swap(0, Ordering::SeqCst) > 1);
+    }
+
+    Ok(())
+}
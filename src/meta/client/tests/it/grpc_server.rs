@@ -21,7 +21,10 @@ use common_meta_client::to_digit_ver;
 use common_meta_client::MIN_METASRV_SEMVER;
 use common_meta_types::protobuf::meta_service_server::MetaService;
 use common_meta_types::protobuf::meta_service_server::MetaServiceServer;
+use common_meta_types::protobuf::BatchTxnReply;
+use common_meta_types::protobuf::BatchTxnRequest;
 use common_meta_types::protobuf::ClientInfo;
+use common_meta_types::protobuf::ClusterStatus;
 use common_meta_types::protobuf::Empty;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeResponse;
@@ -95,6 +98,13 @@ impl MetaService for GrpcServiceForTestImpl {
         todo!()
     }
 
+    async fn batch_txn(
+        &self,
+        _request: Request<BatchTxnRequest>,
+    ) -> Result<Response<BatchTxnReply>, Status> {
+        todo!()
+    }
+
     async fn member_list(
         &self,
         _request: Request<MemberListRequest>,
@@ -108,6 +118,13 @@ impl MetaService for GrpcServiceForTestImpl {
     ) -> Result<Response<ClientInfo>, Status> {
         todo!()
     }
+
+    async fn get_cluster_status(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<ClusterStatus>, Status> {
+        todo!()
+    }
 }
 
 pub fn start_grpc_server() -> String {
@@ -13,7 +13,9 @@
 // limitations under the License.
 #![allow(clippy::uninlined_format_args)]
 
+mod grpc_action;
 mod grpc_client;
+mod grpc_client_retry;
 mod grpc_server;
 
 pub use grpc_server::start_grpc_server;
@@ -62,6 +62,10 @@ impl kvapi::TestSuite {
         self.kv_mget(&builder.build().await).await?;
         self.kv_txn_absent_seq_0(&builder.build().await).await?;
         self.kv_transaction(&builder.build().await).await?;
+        self.kv_transaction_value_and_exists_condition(&builder.build().await)
+            .await?;
+        self.kv_transaction_concurrent_conflict(&builder.build().await)
+            .await?;
         self.kv_delete_by_prefix_transaction(&builder.build().await)
             .await?;
 
@@ -619,6 +623,123 @@ impl kvapi::TestSuite {
         Ok(())
     }
 
+    pub async fn kv_transaction_value_and_exists_condition<KV: kvapi::KVApi>(
+        &self,
+        kv: &KV,
+    ) -> anyhow::Result<()> {
+        info!("--- kvapi::KVApiTestSuite::kv_transaction_value_and_exists_condition() start");
+
+        let k1 = "txn_value_K1";
+        let k2 = "txn_value_K2";
+
+        kv.upsert_kv(UpsertKVReq::update(k1, b"v1")).await?;
+
+        let txn = |value: &[u8]| TxnRequest {
+            condition: vec![
+                TxnCondition {
+                    key: k1.to_string(),
+                    expected: ConditionResult::Gt as i32,
+                    target: Some(txn_condition::Target::Seq(0)),
+                },
+                TxnCondition {
+                    key: k1.to_string(),
+                    expected: ConditionResult::Eq as i32,
+                    target: Some(txn_condition::Target::Value(value.to_vec())),
+                },
+                TxnCondition {
+                    key: k2.to_string(),
+                    expected: ConditionResult::Eq as i32,
+                    target: Some(txn_condition::Target::Exists(false)),
+                },
+            ],
+            if_then: vec![TxnOp {
+                request: Some(txn_op::Request::Put(TxnPutRequest {
+                    key: k2.to_string(),
+                    value: b"v2".to_vec(),
+                    prev_value: true,
+                    expire_at: None,
+                })),
+            }],
+            else_then: vec![],
+        };
+
+        info!("--- value mismatch: the 2nd condition fails");
+        {
+            let resp = kv.transaction(txn(b"v0")).await?;
+            assert!(!resp.success);
+            assert_eq!(Some(1), resp.failed_condition);
+            assert!(kv.get_kv(k2).await?.is_none());
+        }
+
+        info!("--- all conditions hold: seq, value and existence");
+        {
+            let resp = kv.transaction(txn(b"v1")).await?;
+            assert!(resp.success);
+            assert_eq!(None, resp.failed_condition);
+            assert_eq!(b"v2".to_vec(), kv.get_kv(k2).await?.unwrap().data);
+        }
+
+        info!("--- k2 exists now: the 3rd condition fails");
+        {
+            let resp = kv.transaction(txn(b"v1")).await?;
+            assert!(!resp.success);
+            assert_eq!(Some(2), resp.failed_condition);
+        }
+
+        Ok(())
+    }
+
+    /// Concurrent txns guarded by the same absent key: exactly one of them succeeds.
+    pub async fn kv_transaction_concurrent_conflict<KV: kvapi::KVApi>(
+        &self,
+        kv: &KV,
+    ) -> anyhow::Result<()> {
+        info!("--- kvapi::KVApiTestSuite::kv_transaction_concurrent_conflict() start");
+
+        let lock_key = "txn_conflict_lock";
+
+        let txn = |owner: &str| TxnRequest {
+            condition: vec![TxnCondition {
+                key: lock_key.to_string(),
+                expected: ConditionResult::Eq as i32,
+                target: Some(txn_condition::Target::Exists(false)),
+            }],
+            if_then: vec![TxnOp {
+                request: Some(txn_op::Request::Put(TxnPutRequest {
+                    key: lock_key.to_string(),
+                    value: owner.as_bytes().to_vec(),
+                    prev_value: true,
+                    expire_at: None,
+                })),
+            }],
+            else_then: vec![],
+        };
+
+        let (r1, r2, r3) = tokio::join!(
+            kv.transaction(txn("a")),
+            kv.transaction(txn("b")),
+            kv.transaction(txn("c")),
+        );
+
+        let replies = vec![r1?, r2?, r3?];
+        let winners = replies
+            .iter()
+            .zip(["a", "b", "c"])
+            .filter(|(reply, _)| reply.success)
+            .map(|(_, owner)| owner)
+            .collect::<Vec<_>>();
+
+        assert_eq!(1, winners.len(), "exactly one txn acquires the lock");
+        for reply in replies.iter().filter(|reply| !reply.success) {
+            assert_eq!(Some(0), reply.failed_condition);
+        }
+
+        let got = kv.get_kv(lock_key).await?.unwrap();
+        assert_eq!(winners[0].as_bytes().to_vec(), got.data);
+
+        Ok(())
+    }
+
     pub async fn kv_transaction<KV: kvapi::KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
         info!("--- kvapi::KVApiTestSuite::kv_transaction() start");
         // first case: get and set one key transaction
@@ -710,6 +831,7 @@ impl kvapi::TestSuite {
             // k2 not exists, so the resp MUST return false
             assert!(!resp.success);
             assert_eq!(resp.responses.len(), 0);
+            assert_eq!(Some(1), resp.failed_condition);
         }
 
         // 3rd case: get two key and set both key transaction
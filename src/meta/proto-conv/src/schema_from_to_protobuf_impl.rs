@@ -79,7 +79,16 @@ impl FromToProto for ex::TableField {
             p.column_id,
         )
         .with_default_expr(p.default_expr);
-        Ok(v)
+
+        let mut origins = Vec::with_capacity(p.origins.len());
+        for origin in p.origins {
+            reader_check_msg(origin.ver, origin.min_reader_ver)?;
+            let data_type = origin.data_type.ok_or_else(|| Incompatible {
+                reason: "DataFieldOrigin.data_type can not be None".to_string(),
+            })?;
+            origins.push((origin.column_id, ex::TableDataType::from_pb(data_type)?));
+        }
+        Ok(v.with_origins(origins))
     }
 
     fn to_pb(&self) -> Result<pb::DataField, Incompatible> {
@@ -90,6 +99,18 @@ impl FromToProto for ex::TableField {
             default_expr: self.default_expr().cloned(),
             data_type: Some(self.data_type().to_pb()?),
             column_id: self.column_id(),
+            origins: self
+                .origins()
+                .iter()
+                .map(|(column_id, data_type)| {
+                    Ok(pb::DataFieldOrigin {
+                        ver: VER,
+                        min_reader_ver: MIN_READER_VER,
+                        column_id: *column_id,
+                        data_type: Some(data_type.to_pb()?),
+                    })
+                })
+                .collect::<Result<_, Incompatible>>()?,
         };
         Ok(p)
     }
@@ -210,6 +231,9 @@ impl FromToProto for ex::TableDataType {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
                     Dt24::EmptyMapT(_) => ex::TableDataType::EmptyMap,
+                    Dt24::BitmapT(_) => ex::TableDataType::Bitmap,
+                    Dt24::IntervalT(_) => ex::TableDataType::Interval,
+                    Dt24::BinaryT(_) => ex::TableDataType::Binary,
                 };
                 Ok(x)
             }
@@ -269,6 +293,9 @@ impl FromToProto for ex::TableDataType {
                 new_pb_dt24(Dt24::TupleT(x))
             }
             TableDataType::Variant => new_pb_dt24(Dt24::VariantT(pb::Empty {})),
+            TableDataType::Bitmap => new_pb_dt24(Dt24::BitmapT(pb::Empty {})),
+            TableDataType::Interval => new_pb_dt24(Dt24::IntervalT(pb::Empty {})),
+            TableDataType::Binary => new_pb_dt24(Dt24::BinaryT(pb::Empty {})),
         };
         Ok(x)
     }
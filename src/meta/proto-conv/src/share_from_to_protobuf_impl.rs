@@ -185,6 +185,7 @@ impl FromToProto for mt::ShareMeta {
             comment: p.comment.clone(),
             accounts: BTreeSet::from_iter(p.accounts.clone().into_iter()),
             share_from_db_ids: BTreeSet::from_iter(p.share_from_db_ids.clone().into_iter()),
+            read_only_accounts: BTreeSet::from_iter(p.read_only_accounts.clone().into_iter()),
             share_on: DateTime::<Utc>::from_pb(p.share_on)?,
             update_on: match p.update_on {
                 Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
@@ -209,6 +210,7 @@ impl FromToProto for mt::ShareMeta {
             entries,
             accounts: Vec::from_iter(self.accounts.clone().into_iter()),
             share_from_db_ids: Vec::from_iter(self.share_from_db_ids.clone().into_iter()),
+            read_only_accounts: Vec::from_iter(self.read_only_accounts.clone().into_iter()),
             comment: self.comment.clone(),
             share_on: self.share_on.to_pb()?,
             update_on: match &self.update_on {
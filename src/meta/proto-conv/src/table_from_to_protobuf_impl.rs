@@ -219,6 +219,12 @@ impl FromToProto for mt::TableMeta {
                 .map(mt::TableStatistics::from_pb)
                 .transpose()?
                 .unwrap_or_default(),
+            column_mask_policy: p.column_mask_policy,
+            row_access_policies: p
+                .row_access_policies
+                .into_iter()
+                .map(|(policy, columns)| (policy, columns.columns))
+                .collect(),
         };
         Ok(v)
     }
@@ -253,6 +259,16 @@ impl FromToProto for mt::TableMeta {
             comment: self.comment.clone(),
             field_comments: self.field_comments.clone(),
             statistics: Some(self.statistics.to_pb()?),
+            column_mask_policy: self.column_mask_policy.clone(),
+            row_access_policies: self
+                .row_access_policies
+                .iter()
+                .map(|(policy, columns)| {
+                    (policy.clone(), pb::RowAccessPolicyColumns {
+                        columns: columns.clone(),
+                    })
+                })
+                .collect(),
         };
         Ok(p)
     }
@@ -99,7 +99,8 @@ impl FromToProto for mt::principal::UserOption {
 
         Ok(mt::principal::UserOption::default()
             .with_flags(flags)
-            .with_default_role(p.default_role))
+            .with_default_role(p.default_role)
+            .with_network_policy(p.network_policy))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -108,6 +109,7 @@ impl FromToProto for mt::principal::UserOption {
             min_reader_ver: MIN_READER_VER,
             flags: self.flags().bits(),
             default_role: self.default_role().cloned(),
+            network_policy: self.network_policy().cloned(),
         })
     }
 }
@@ -161,6 +163,17 @@ impl FromToProto for mt::principal::GrantObject {
                 db,
                 table,
             })) => Ok(mt::principal::GrantObject::Table(catalog, db, table)),
+            Some(pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                catalog,
+                db,
+                table,
+                column,
+            })) => Ok(mt::principal::GrantObject::Column(
+                catalog, db, table, column,
+            )),
+            Some(pb::grant_object::Object::Stage(pb::grant_object::GrantStageObject { stage })) => {
+                Ok(mt::principal::GrantObject::Stage(stage))
+            }
             _ => Err(Incompatible {
                 reason: "GrantObject cannot be None".to_string(),
             }),
@@ -185,6 +198,19 @@ impl FromToProto for mt::principal::GrantObject {
                     table: table.clone(),
                 }),
             ),
+            mt::principal::GrantObject::Column(catalog, db, table, column) => Some(
+                pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                    catalog: catalog.clone(),
+                    db: db.clone(),
+                    table: table.clone(),
+                    column: column.clone(),
+                }),
+            ),
+            mt::principal::GrantObject::Stage(stage) => Some(pb::grant_object::Object::Stage(
+                pb::grant_object::GrantStageObject {
+                    stage: stage.clone(),
+                },
+            )),
         };
         Ok(pb::GrantObject {
             ver: VER,
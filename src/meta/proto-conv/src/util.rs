@@ -60,6 +60,16 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (28, "2023-02-13: Add: user.proto/UserDefinedFileFormat"),
     (29, "2023-02-23: Add: metadata.proto/DataType EmptyMap types", ),
     (30, "2023-02-21: Add: config.proto/WebhdfsStorageConfig; Modify: user.proto/UserStageInfo::StageStorage", ),
+    (31, "2023-02-28: Add: metadata.proto/DataType Bitmap type"),
+    (32, "2023-03-02: Add: user.proto/GrantObject::GrantColumnObject"),
+    (33, "2023-03-06: Add: table.proto/TableMeta::column_mask_policy"),
+    (34, "2023-03-08: Add: user.proto/UserOption::network_policy"),
+    (35, "2023-03-10: Add: metadata.proto/DataType Interval type"),
+    (36, "2023-03-14: Add: table.proto/TableMeta::row_access_policies"),
+    (37, "2023-03-16: Add: metadata.proto/DataField::origins"),
+    (38, "2023-03-20: Add: share.proto/ShareMeta::read_only_accounts"),
+    (39, "2023-03-22: Add: metadata.proto/DataType Binary type"),
+    (40, "2023-03-24: Add: user.proto/GrantObject::GrantStageObject"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -37,3 +37,13 @@ mod v027_schema;
 mod v028_schema;
 mod v029_schema;
 mod v030_user_stage;
+mod v031_schema;
+mod v032_grant_column;
+mod v033_table_meta;
+mod v034_user_option;
+mod v035_schema;
+mod v036_table_meta;
+mod v037_schema;
+mod v038_share_meta;
+mod v039_schema;
+mod v040_grant_stage;
@@ -90,6 +90,7 @@ fn new_share_meta_share_from_db_ids() -> share::ShareMeta {
         entries,
         accounts: BTreeSet::from_iter(vec![s("a"), s("b")].into_iter()),
         share_from_db_ids: BTreeSet::from_iter(vec![1, 2].into_iter()),
+        read_only_accounts: BTreeSet::new(),
         comment: Some(s("comment")),
         share_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
         update_on: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap()),
@@ -118,6 +119,7 @@ fn new_share_meta() -> share::ShareMeta {
         entries,
         accounts: BTreeSet::from_iter(vec![s("a"), s("b")].into_iter()),
         share_from_db_ids: BTreeSet::new(),
+        read_only_accounts: BTreeSet::new(),
         comment: Some(s("comment")),
         share_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
         update_on: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap()),
@@ -195,6 +197,8 @@ fn new_table_meta() -> mt::TableMeta {
         field_comments: vec!["c".to_string(); 21],
         drop_on: None,
         statistics: Default::default(),
+        column_mask_policy: btreemap! {},
+        row_access_policies: btreemap! {},
     }
 }
 
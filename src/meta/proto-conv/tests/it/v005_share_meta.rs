@@ -66,6 +66,7 @@ fn test_decode_v5_share_meta() -> anyhow::Result<()> {
             entries,
             accounts: BTreeSet::from_iter(vec![s("a"), s("b")].into_iter()),
             share_from_db_ids: BTreeSet::from_iter(vec![1, 2].into_iter()),
+            read_only_accounts: BTreeSet::new(),
             comment: Some(s("comment")),
             share_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
             update_on: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap()),
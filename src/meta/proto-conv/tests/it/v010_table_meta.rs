@@ -141,6 +141,8 @@ fn test_decode_v10_table_meta() -> anyhow::Result<()> {
         field_comments: vec!["c".to_string(); 21],
         drop_on: None,
         statistics: Default::default(),
+        column_mask_policy: btreemap! {},
+        row_access_policies: btreemap! {},
     };
 
     common::test_pb_from_to(func_name!(), want())?;
@@ -0,0 +1,73 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_expression as ex;
+use common_meta_app::schema as mt;
+use maplit::btreemap;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v33_table_meta() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 34, 10, 24, 10, 5, 101, 109, 97, 105, 108, 26, 9, 146, 2, 0, 160, 6, 33, 168, 6, 24,
+        160, 6, 33, 168, 6, 24, 24, 1, 160, 6, 33, 168, 6, 24, 50, 4, 70, 85, 83, 69, 82, 7, 100,
+        101, 102, 97, 117, 108, 116, 162, 1, 23, 50, 48, 50, 51, 45, 48, 51, 45, 48, 54, 32, 49,
+        50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 170, 1, 23, 50, 48, 50, 51, 45, 48, 51, 45, 48,
+        54, 32, 49, 50, 58, 48, 48, 58, 49, 48, 32, 85, 84, 67, 186, 1, 6, 160, 6, 33, 168, 6, 24,
+        226, 1, 19, 10, 5, 101, 109, 97, 105, 108, 18, 10, 101, 109, 97, 105, 108, 95, 109, 97,
+        115, 107, 160, 6, 33, 168, 6, 24,
+    ];
+
+    let want = || mt::TableMeta {
+        schema: Arc::new(ex::TableSchema::new(vec![ex::TableField::new(
+            "email",
+            ex::TableDataType::String,
+        )])),
+        catalog: "default".to_string(),
+        engine: "FUSE".to_string(),
+        engine_options: btreemap! {},
+        storage_params: None,
+        part_prefix: "".to_string(),
+        options: btreemap! {},
+        default_cluster_key: None,
+        cluster_keys: vec![],
+        default_cluster_key_id: None,
+        created_on: Utc.with_ymd_and_hms(2023, 3, 6, 12, 0, 9).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2023, 3, 6, 12, 0, 10).unwrap(),
+        comment: "".to_string(),
+        field_comments: vec![],
+        drop_on: None,
+        statistics: Default::default(),
+        column_mask_policy: btreemap! {"email".to_string() => "email_mask".to_string()},
+        row_access_policies: btreemap! {},
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 33, want())
+}
@@ -0,0 +1,43 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_meta_app as mt;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v34_user_option() -> anyhow::Result<()> {
+    let user_option_v34 = vec![
+        8, 1, 18, 5, 114, 111, 108, 101, 49, 26, 3, 110, 112, 49, 160, 6, 34, 168, 6, 24,
+    ];
+
+    let want = || {
+        mt::principal::UserOption::default()
+            .with_set_flag(mt::principal::UserOptionFlag::TenantSetting)
+            .with_default_role(Some("role1".to_string()))
+            .with_network_policy(Some("np1".to_string()))
+    };
+    common::test_load_old(func_name!(), user_option_v34.as_slice(), 34, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
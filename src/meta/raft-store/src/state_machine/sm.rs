@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 use std::convert::TryInto;
 use std::fmt::Debug;
+use std::ops::RangeBounds;
 use std::time::Duration;
 use std::time::Instant;
 use std::time::SystemTime;
@@ -228,35 +229,45 @@ impl StateMachine {
         match entry.payload {
             EntryPayload::Normal(ref data) => match &data.cmd {
                 Cmd::Transaction(txn) => {
-                    let kvs = self.kvs();
                     let mut if_map = BTreeMap::new();
                     let mut else_map = BTreeMap::new();
-                    for op in txn.if_then.iter() {
-                        if let Some(txn_op::Request::DeleteByPrefix(delete_by_prefix)) = &op.request
-                        {
-                            if_map.insert(
-                                delete_by_prefix.clone(),
-                                kvs.scan_prefix(&delete_by_prefix.prefix)?,
-                            );
-                        }
-                    }
-                    for op in txn.else_then.iter() {
-                        if let Some(txn_op::Request::DeleteByPrefix(delete_by_prefix)) = &op.request
-                        {
-                            else_map.insert(
-                                delete_by_prefix.clone(),
-                                kvs.scan_prefix(&delete_by_prefix.prefix)?,
-                            );
-                        }
-                    }
+                    self.scan_prefix_of_ops(&txn.if_then, &mut if_map)?;
+                    self.scan_prefix_of_ops(&txn.else_then, &mut else_map)?;
                     Ok(Some((if_map, else_map)))
                 }
+                Cmd::BatchTransaction(txns) => {
+                    // The `else_then` of a batch is never executed.
+                    // All prefixes are scanned on the state before the batch, a put by an
+                    // earlier txn in the batch is not seen by a later `DeleteByPrefix`.
+                    let mut if_map = BTreeMap::new();
+                    for txn in txns.iter() {
+                        self.scan_prefix_of_ops(&txn.if_then, &mut if_map)?;
+                    }
+                    Ok(Some((if_map, BTreeMap::new())))
+                }
                 _ => Ok(None),
             },
             _ => Ok(None),
         }
     }
 
+    fn scan_prefix_of_ops(
+        &self,
+        ops: &[TxnOp],
+        kv_map: &mut DeleteByPrefixKeyMap,
+    ) -> Result<(), MetaStorageError> {
+        let kvs = self.kvs();
+        for op in ops.iter() {
+            if let Some(txn_op::Request::DeleteByPrefix(delete_by_prefix)) = &op.request {
+                kv_map.insert(
+                    delete_by_prefix.clone(),
+                    kvs.scan_prefix(&delete_by_prefix.prefix)?,
+                );
+            }
+        }
+        Ok(())
+    }
+
     /// Apply an log entry to state machine.
     ///
     /// If a duplicated log entry is detected by checking data.txid, no update
@@ -460,6 +471,19 @@ impl StateMachine {
         }
     }
 
+    fn return_exists_condition_result(
+        &self,
+        expected: i32,
+        target_exists: bool,
+        exists: bool,
+    ) -> bool {
+        match FromPrimitive::from_i32(expected) {
+            Some(ConditionResult::Eq) => exists == target_exists,
+            Some(ConditionResult::Ne) => exists != target_exists,
+            _ => false,
+        }
+    }
+
     pub fn return_seq_condition_result(
         &self,
         expected: i32,
@@ -513,27 +537,37 @@ impl StateMachine {
                         return Ok(false);
                     }
                 }
+                txn_condition::Target::Exists(target_exists) => {
+                    return Ok(self.return_exists_condition_result(
+                        cond.expected,
+                        *target_exists,
+                        sv.is_some(),
+                    ));
+                }
             }
         };
 
         Ok(false)
     }
 
+    /// Evaluate all conditions of a txn.
+    ///
+    /// It returns the index of the first condition that does not hold, or `None` if all of them hold.
     #[tracing::instrument(level = "debug", skip(self, txn_tree, condition))]
     fn txn_execute_condition(
         &self,
         txn_tree: &TransactionSledTree,
-        condition: &Vec<TxnCondition>,
-    ) -> Result<bool, MetaStorageError> {
-        for cond in condition {
+        condition: &[TxnCondition],
+    ) -> Result<Option<usize>, MetaStorageError> {
+        for (i, cond) in condition.iter().enumerate() {
             debug!(condition = display(cond), "txn_execute_condition");
 
             if !self.txn_execute_one_condition(txn_tree, cond)? {
-                return Ok(false);
+                return Ok(Some(i));
             }
         }
 
-        Ok(true)
+        Ok(None)
     }
 
     fn txn_execute_get_operation(
@@ -711,7 +745,8 @@ impl StateMachine {
 
         let ops: &Vec<TxnOp>;
         let kv_op_pairs: Option<&DeleteByPrefixKeyMap>;
-        let success = if self.txn_execute_condition(txn_tree, condition)? {
+        let failed_condition = self.txn_execute_condition(txn_tree, condition)?;
+        let success = if failed_condition.is_none() {
             ops = &req.if_then;
             kv_op_pairs = if let Some(kv_pairs) = kv_pairs {
                 Some(&kv_pairs.0)
@@ -733,6 +768,7 @@ impl StateMachine {
             success,
             error: "".to_string(),
             responses: vec![],
+            failed_condition: failed_condition.map(|i| i as u32),
         };
 
         for op in ops {
@@ -742,6 +778,48 @@ impl StateMachine {
         Ok(AppliedState::TxnReply(resp))
     }
 
+    /// Apply a batch of transactions, either all of them take effect or none.
+    ///
+    /// The conditions of every transaction are evaluated before executing any operation.
+    /// If all of them hold, the `if_then` of every transaction is executed in order.
+    /// Otherwise no operation is executed, and the replies tell which transactions failed.
+    ///
+    /// A `DeleteByPrefix` deletes the keys listed by `scan_prefix_if_needed` before the batch is
+    /// applied: keys put under the prefix by an earlier transaction of the same batch are not
+    /// deleted, the same as keys put earlier in the `if_then` of a single transaction.
+    #[tracing::instrument(level = "debug", skip(self, txn_tree, reqs))]
+    fn apply_batch_txn_cmd(
+        &self,
+        reqs: &[TxnRequest],
+        txn_tree: &mut TransactionSledTree,
+        kv_pairs: Option<&(DeleteByPrefixKeyMap, DeleteByPrefixKeyMap)>,
+        log_time_ms: u64,
+    ) -> Result<AppliedState, MetaStorageError> {
+        debug!(txns = reqs.len(), "apply batch txn cmd");
+
+        let mut replies = Vec::with_capacity(reqs.len());
+        for req in reqs {
+            let failed_condition = self.txn_execute_condition(txn_tree, &req.condition)?;
+            replies.push(TxnReply {
+                success: failed_condition.is_none(),
+                error: "".to_string(),
+                responses: vec![],
+                failed_condition: failed_condition.map(|i| i as u32),
+            });
+        }
+
+        if replies.iter().all(|reply| reply.success) {
+            let kv_op_pairs = kv_pairs.map(|kv_pairs| &kv_pairs.0);
+            for (req, reply) in reqs.iter().zip(replies.iter_mut()) {
+                for op in &req.if_then {
+                    self.txn_execute_operation(txn_tree, op, kv_op_pairs, reply, log_time_ms)?;
+                }
+            }
+        }
+
+        Ok(AppliedState::BatchTxnReply(replies))
+    }
+
     /// Apply a `Cmd` to state machine.
     ///
     /// Already applied log should be filtered out before passing into this function.
@@ -773,6 +851,10 @@ impl StateMachine {
             }
 
             Cmd::Transaction(txn) => self.apply_txn_cmd(txn, txn_tree, kv_pairs, log_time_ms),
+
+            Cmd::BatchTransaction(txns) => {
+                self.apply_batch_txn_cmd(txns, txn_tree, kv_pairs, log_time_ms)
+            }
         };
 
         let elapsed = now.elapsed().as_micros();
@@ -943,17 +1025,22 @@ impl StateMachine {
             return Ok((expired, prev.clone(), prev));
         }
 
+        // With `keep_ttl`, the expiration of a living record survives a value update.
+        let value_meta = if upsert_kv.keep_ttl {
+            prev.as_ref().and_then(|p| p.meta.clone())
+        } else {
+            upsert_kv.value_meta.clone()
+        };
+
         let mut new_seq_v = match &upsert_kv.value {
-            Operation::Update(v) => SeqV::with_meta(0, upsert_kv.value_meta.clone(), v.clone()),
+            Operation::Update(v) => SeqV::with_meta(0, value_meta, v.clone()),
             Operation::Delete => {
                 kvs.remove(&upsert_kv.key)?;
                 return Ok((expired, prev, None));
             }
             Operation::AsIs => match prev {
                 None => return Ok((expired, prev, None)),
-                Some(ref prev_kv_value) => {
-                    prev_kv_value.clone().set_meta(upsert_kv.value_meta.clone())
-                }
+                Some(ref prev_kv_value) => prev_kv_value.clone().set_meta(value_meta),
             },
         };
 
@@ -1042,6 +1129,22 @@ impl StateMachine {
         sm_nodes.range_values(..)
     }
 
+    /// List the key-values in a range, expired ones are not included.
+    pub fn range_kvs<R>(&self, range: R) -> Result<Vec<(String, SeqV)>, MetaStorageError>
+    where R: RangeBounds<String> {
+        let local_now_ms = SeqV::<()>::now_ms();
+
+        let mut res = vec![];
+        for item in self.kvs().range(range)? {
+            let (k, seq_v) = item?.kv()?;
+            if let (_, Some(seq_v)) = Self::expire_seq_v(Some(seq_v), local_now_ms) {
+                res.push((k, seq_v));
+            }
+        }
+
+        Ok(res)
+    }
+
     /// Expire an `SeqV` and returns the value discarded by expiration and the unexpired value:
     /// - `(Some, None)` if it expires.
     /// - `(None, Some)` if it does not.
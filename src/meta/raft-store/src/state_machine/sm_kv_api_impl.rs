@@ -38,6 +38,7 @@ impl kvapi::KVApi for StateMachine {
             seq: act.seq,
             value: act.value,
             value_meta: act.value_meta,
+            keep_ttl: act.keep_ttl,
         });
 
         let res = self.sm_tree.txn(true, |mut txn_sled_tree| {
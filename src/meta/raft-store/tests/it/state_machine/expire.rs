@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+use std::sync::Mutex;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
@@ -20,13 +22,16 @@ use common_meta_raft_store::key_spaces::Expire;
 use common_meta_raft_store::key_spaces::GenericKV;
 use common_meta_raft_store::state_machine::ExpireKey;
 use common_meta_raft_store::state_machine::StateMachine;
+use common_meta_raft_store::state_machine::StateMachineSubscriber;
 use common_meta_sled_store::AsKeySpace;
 use common_meta_types::new_log_id;
+use common_meta_types::Change;
 use common_meta_types::Cmd;
 use common_meta_types::Entry;
 use common_meta_types::EntryPayload;
 use common_meta_types::KVMeta;
 use common_meta_types::LogEntry;
+use common_meta_types::SeqV;
 use common_meta_types::UpsertKV;
 use common_meta_types::With;
 
@@ -141,6 +146,167 @@ async fn test_state_machine_list_expired() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[async_entry::test(
+    worker_threads = 3,
+    init = "init_raft_store_ut!()",
+    tracing_span = "debug"
+)]
+async fn test_state_machine_expire_notify_subscriber() -> anyhow::Result<()> {
+    // - Feed a log with an expired kv.
+    // - Apply another log with a log time later than the expiration.
+    // - The subscriber receives a delete event for the expired key.
+
+    let tc = new_raft_test_context();
+    let mut sm = StateMachine::open(&tc.raft_config, 0).await?;
+
+    let changes = Arc::new(Mutex::new(vec![]));
+    sm.set_subscriber(Box::new(ChangeCollector(changes.clone())));
+
+    let now = now();
+
+    sm.apply(&ent(3, "a", Some(now - 1), None)).await?;
+    changes.lock().unwrap().clear();
+
+    sm.apply(&ent(4, "b", None, Some(now * 1000))).await?;
+
+    let got = changes.lock().unwrap().clone();
+    assert_eq!(
+        vec![
+            Change::new(
+                Some(SeqV::with_meta(
+                    1,
+                    Some(KVMeta {
+                        expire_at: Some(now - 1)
+                    }),
+                    b"a".to_vec()
+                )),
+                None
+            )
+            .with_id("a".to_string()),
+            Change::new(
+                None,
+                Some(SeqV::with_meta(
+                    2,
+                    Some(KVMeta { expire_at: None }),
+                    b"b".to_vec()
+                ))
+            )
+            .with_id("b".to_string()),
+        ],
+        got
+    );
+
+    Ok(())
+}
+
+#[async_entry::test(
+    worker_threads = 3,
+    init = "init_raft_store_ut!()",
+    tracing_span = "debug"
+)]
+async fn test_state_machine_upsert_keep_ttl() -> anyhow::Result<()> {
+    // - Write a kv with expiration.
+    // - Update its value with `keep_ttl`: the expiration index follows the new seq.
+
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 0).await?;
+    let expires = sm.sm_tree.key_space::<Expire>();
+
+    let now = now();
+
+    sm.apply(&ent(3, "a", Some(now + 10), None)).await?;
+
+    let upsert = UpsertKV::update("a", b"a2").keep_ttl();
+    sm.apply(&Entry {
+        log_id: new_log_id(1, 0, 4),
+        payload: EntryPayload::Normal(LogEntry {
+            txid: None,
+            time_ms: Some(now * 1000),
+            cmd: Cmd::UpsertKV(upsert),
+        }),
+    })
+    .await?;
+
+    let kvs = sm.sm_tree.key_space::<GenericKV>();
+    let got = kvs.get(&"a".to_string())?.unwrap();
+    assert_eq!(b"a2".to_vec(), got.data);
+    assert_eq!(Some(now + 10), got.meta.unwrap().expire_at);
+
+    let keys = expires
+        .range(..)?
+        .map(|item_res| item_res.unwrap().key().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![ExpireKey::new((now + 10) * 1000, 2)], keys);
+
+    Ok(())
+}
+
+#[async_entry::test(
+    worker_threads = 3,
+    init = "init_raft_store_ut!()",
+    tracing_span = "debug"
+)]
+async fn test_state_machine_snapshot_keeps_expiration() -> anyhow::Result<()> {
+    // - Write kvs with expiration and take a snapshot.
+    // - Restore the snapshot into another state machine.
+    // - The pending expiration survives and is cleaned by a later log.
+
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 0).await?;
+
+    let now = now();
+
+    sm.apply(&ent(3, "a", Some(now + 5), None)).await?;
+    sm.apply(&ent(4, "b", Some(now + 20), None)).await?;
+
+    let (snap, _, _, _) = sm.build_snapshot()?;
+
+    let mut restored = StateMachine::open(&tc.raft_config, 1).await?;
+    for kv in snap.kvs.into_iter() {
+        restored.sm_tree.tree.insert(&kv[0], kv[1].clone())?;
+    }
+
+    assert_eq!(
+        vec![
+            expired_item("a", now + 5, 1), //
+            expired_item("b", now + 20, 2),
+        ],
+        restored.list_expired_kvs((now + 30) * 1000)?
+    );
+
+    let changes = Arc::new(Mutex::new(vec![]));
+    restored.set_subscriber(Box::new(ChangeCollector(changes.clone())));
+
+    restored
+        .apply(&ent(5, "c", None, Some((now + 10) * 1000)))
+        .await?;
+
+    let kvs = restored.sm_tree.key_space::<GenericKV>();
+    assert!(kvs.get(&"a".to_string())?.is_none());
+    assert!(kvs.get(&"b".to_string())?.is_some());
+
+    let deleted = changes
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|c| c.result.is_none())
+        .map(|c| c.ident.clone().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["a".to_string()], deleted);
+
+    Ok(())
+}
+
+/// Collects every change event emitted by a state machine.
+#[derive(Debug)]
+struct ChangeCollector(Arc<Mutex<Vec<Change<Vec<u8>, String>>>>);
+
+impl StateMachineSubscriber for ChangeCollector {
+    fn kv_changed(&self, change: Change<Vec<u8>, String>) {
+        self.0.lock().unwrap().push(change);
+    }
+}
+
 fn now() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -19,9 +19,12 @@ use common_base::base::tokio;
 use common_meta_kvapi::kvapi::KVApi;
 use common_meta_raft_store::state_machine::StateMachine;
 use common_meta_types::new_log_id;
+use common_meta_types::txn_condition::Target;
+use common_meta_types::txn_op::Request;
 use common_meta_types::AppliedState;
 use common_meta_types::Change;
 use common_meta_types::Cmd;
+use common_meta_types::ConditionResult;
 use common_meta_types::Endpoint;
 use common_meta_types::Entry;
 use common_meta_types::EntryPayload;
@@ -31,6 +34,11 @@ use common_meta_types::MatchSeq;
 use common_meta_types::Node;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
+use common_meta_types::TxnCondition;
+use common_meta_types::TxnOp;
+use common_meta_types::TxnPutRequest;
+use common_meta_types::TxnReply;
+use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKV;
 use common_meta_types::With;
 use pretty_assertions::assert_eq;
@@ -223,6 +231,7 @@ async fn test_state_machine_apply_non_dup_generic_kv_upsert_get() -> anyhow::Res
                         seq: c.seq,
                         value: Operation::Update(c.value.clone()),
                         value_meta: c.value_meta.clone(),
+                        keep_ttl: false,
                     }),
                     &mut t,
                     None,
@@ -290,6 +299,7 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
                     value_meta: Some(KVMeta {
                         expire_at: Some(now + 10),
                     }),
+                    keep_ttl: false,
                 }),
                 &mut t,
                 None,
@@ -317,6 +327,7 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
                     value_meta: Some(KVMeta {
                         expire_at: Some(now + 10),
                     }),
+                    keep_ttl: false,
                 }),
                 &mut t,
                 None,
@@ -336,6 +347,7 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
                     value_meta: Some(KVMeta {
                         expire_at: Some(now + 20),
                     }),
+                    keep_ttl: false,
                 }),
                 &mut t,
                 None,
@@ -447,3 +459,87 @@ async fn test_state_machine_apply_non_dup_generic_kv_delete() -> anyhow::Result<
 
     Ok(())
 }
+
+#[async_entry::test(
+    worker_threads = 3,
+    init = "init_raft_store_ut!()",
+    tracing_span = "debug"
+)]
+async fn test_state_machine_apply_batch_txn() -> anyhow::Result<()> {
+    // - A batch takes effect if the conditions of all its transactions hold.
+    // - A batch with any failed condition takes no effect.
+
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 1).await?;
+
+    let put_if_absent = |key: &str| TxnRequest {
+        condition: vec![TxnCondition {
+            key: key.to_string(),
+            expected: ConditionResult::Eq as i32,
+            target: Some(Target::Exists(false)),
+        }],
+        if_then: vec![TxnOp {
+            request: Some(Request::Put(TxnPutRequest {
+                key: key.to_string(),
+                value: key.as_bytes().to_vec(),
+                prev_value: false,
+                expire_at: None,
+            })),
+        }],
+        else_then: vec![TxnOp {
+            request: Some(Request::Put(TxnPutRequest {
+                key: "else".to_string(),
+                value: b"else".to_vec(),
+                prev_value: false,
+                expire_at: None,
+            })),
+        }],
+    };
+
+    let apply = |index: u64, txns: Vec<TxnRequest>| {
+        let ss = &sm;
+        async move {
+            let resp = ss
+                .apply(&Entry {
+                    log_id: new_log_id(1, 0, index),
+                    payload: EntryPayload::Normal(LogEntry {
+                        txid: None,
+                        time_ms: None,
+                        cmd: Cmd::BatchTransaction(txns),
+                    }),
+                })
+                .await?;
+            let replies: Vec<TxnReply> = resp.try_into().unwrap();
+            Ok::<_, anyhow::Error>(replies)
+        }
+    };
+
+    info!("--- all conditions hold");
+    {
+        let replies = apply(1, vec![put_if_absent("a"), put_if_absent("b")]).await?;
+        assert_eq!(2, replies.len());
+        for reply in replies {
+            assert!(reply.success);
+            assert_eq!(None, reply.failed_condition);
+            assert_eq!(1, reply.responses.len());
+        }
+
+        assert_eq!(b"a".to_vec(), sm.get_kv("a").await?.unwrap().data);
+        assert_eq!(b"b".to_vec(), sm.get_kv("b").await?.unwrap().data);
+    }
+
+    info!("--- one of the conditions fails");
+    {
+        let replies = apply(2, vec![put_if_absent("c"), put_if_absent("a")]).await?;
+        assert_eq!(2, replies.len());
+        assert!(replies[0].success);
+        assert!(!replies[1].success);
+        assert_eq!(Some(0), replies[1].failed_condition);
+        assert!(replies.iter().all(|r| r.responses.is_empty()));
+
+        assert!(sm.get_kv("c").await?.is_none());
+        assert!(sm.get_kv("else").await?.is_none());
+    }
+
+    Ok(())
+}
@@ -16,6 +16,7 @@ use std::pin::Pin;
 use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Instant;
 
 use common_arrow::arrow_format::flight::data::BasicAuth;
 use common_base::base::tokio::sync::mpsc;
@@ -24,7 +25,10 @@ use common_grpc::GrpcToken;
 use common_meta_client::MetaGrpcReq;
 use common_meta_kvapi::kvapi::KVApi;
 use common_meta_types::protobuf::meta_service_server::MetaService;
+use common_meta_types::protobuf::BatchTxnReply;
+use common_meta_types::protobuf::BatchTxnRequest;
 use common_meta_types::protobuf::ClientInfo;
+use common_meta_types::protobuf::ClusterStatus;
 use common_meta_types::protobuf::Empty;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeRequest;
@@ -33,6 +37,7 @@ use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftReply;
 use common_meta_types::protobuf::RaftRequest;
+use common_meta_types::protobuf::SlowRequest;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
 use common_meta_types::TxnReply;
@@ -58,6 +63,7 @@ use crate::version::from_digit_ver;
 use crate::version::to_digit_ver;
 use crate::version::METASRV_SEMVER;
 use crate::version::MIN_METACLI_SEMVER;
+use crate::watcher::initial_state_responses;
 use crate::watcher::WatchStream;
 
 pub struct MetaServiceImpl {
@@ -160,22 +166,34 @@ impl MetaService for MetaServiceImpl {
         let req: MetaGrpcReq = r.try_into()?;
         info!("Received MetaGrpcReq: {:?}", req);
 
+        let start = Instant::now();
+        let op = req_op_name(&req);
+
+        // The detail of a request is only built if it is slow.
         let m = &self.meta_node;
         let reply = match req {
             MetaGrpcReq::UpsertKV(a) => {
+                // upsert consumes the request, only the key is kept for the detail.
+                let key = a.key.clone();
                 let res = m.upsert_kv(a).await;
+                m.slow_requests.record(op, start.elapsed(), || key);
                 RaftReply::from(res)
             }
             MetaGrpcReq::GetKV(a) => {
                 let res = m.get_kv(&a.key).await;
+                m.slow_requests.record(op, start.elapsed(), || a.key);
                 RaftReply::from(res)
             }
             MetaGrpcReq::MGetKV(a) => {
                 let res = m.mget_kv(&a.keys).await;
+                m.slow_requests
+                    .record(op, start.elapsed(), || a.keys.join(","));
                 RaftReply::from(res)
             }
             MetaGrpcReq::ListKV(a) => {
                 let res = m.prefix_list_kv(&a.prefix).await;
+                m.slow_requests
+                    .record(op, start.elapsed(), || format!("{}*", a.prefix));
                 RaftReply::from(res)
             }
         };
@@ -200,7 +218,16 @@ impl MetaService for MetaServiceImpl {
 
         info!("Receive txn_request: {}", request);
 
+        let start = Instant::now();
+        // The txn is consumed by the meta node, it is formatted only if it is slow.
+        let txn = request.clone();
+
         let ret = self.meta_node.transaction(request).await;
+
+        self.meta_node
+            .slow_requests
+            .record("Transaction", start.elapsed(), || txn.to_string());
+
         network_metrics::incr_request_result(ret.is_ok());
 
         let body = match ret {
@@ -208,11 +235,59 @@ impl MetaService for MetaServiceImpl {
                 success: resp.success,
                 error: "".to_string(),
                 responses: resp.responses,
+                failed_condition: resp.failed_condition,
             },
             Err(err) => TxnReply {
                 success: false,
                 error: serde_json::to_string(&err).expect("fail to serialize"),
                 responses: vec![],
+                failed_condition: None,
+            },
+        };
+
+        network_metrics::incr_sent_bytes(body.encoded_len() as u64);
+
+        Ok(Response::new(body))
+    }
+
+    async fn batch_txn(
+        &self,
+        request: Request<BatchTxnRequest>,
+    ) -> Result<Response<BatchTxnReply>, Status> {
+        self.check_token(request.metadata())?;
+        network_metrics::incr_recv_bytes(request.get_ref().encoded_len() as u64);
+        let _guard = RequestInFlight::guard();
+
+        common_tracing::extract_remote_span_as_parent(&request);
+
+        let request = request.into_inner();
+
+        info!("Receive batch_txn_request: {} txns", request.txns.len());
+
+        let start = Instant::now();
+        let detail = request
+            .txns
+            .iter()
+            .map(|txn| txn.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let ret = self.meta_node.batch_transaction(request.txns).await;
+
+        self.meta_node
+            .slow_requests
+            .record("BatchTxn", start.elapsed(), || detail);
+
+        network_metrics::incr_request_result(ret.is_ok());
+
+        let body = match ret {
+            Ok(replies) => BatchTxnReply {
+                replies,
+                error: "".to_string(),
+            },
+            Err(err) => BatchTxnReply {
+                replies: vec![],
+                error: serde_json::to_string(&err).expect("fail to serialize"),
             },
         };
 
@@ -255,7 +330,28 @@ impl MetaService for MetaServiceImpl {
 
         let mn = &self.meta_node;
 
-        let add_res = mn.add_watcher(request.into_inner(), tx).await;
+        let request = request.into_inner();
+
+        if request.need_initial_state {
+            let filter = request.filter_type();
+
+            let add_res = mn
+                .add_watcher_with_initial_state(request, tx)
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?;
+
+            return match add_res {
+                Ok((watcher, kvs)) => {
+                    let initial = initial_state_responses(filter, kvs).into_iter().map(Ok);
+                    let stream = WatchStream::new(rx, watcher, mn.dispatcher_handle.clone());
+                    let stream = futures::stream::iter(initial).chain(stream);
+                    Ok(Response::new(Box::pin(stream) as Self::WatchStream))
+                }
+                Err(e) => Err(Status::invalid_argument(e)),
+            };
+        }
+
+        let add_res = mn.add_watcher(request, tx).await;
 
         match add_res {
             Ok(watcher) => {
@@ -303,6 +399,58 @@ impl MetaService for MetaServiceImpl {
         }
         Err(Status::unavailable("can not get client ip address"))
     }
+
+    async fn get_cluster_status(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<ClusterStatus>, Status> {
+        let _guard = RequestInFlight::guard();
+
+        let status = self
+            .meta_node
+            .get_status()
+            .await
+            .map_err(|e| Status::internal(format!("get meta node status failed: {}", e)))?;
+
+        let resp = ClusterStatus {
+            id: status.id,
+            endpoint: status.endpoint,
+            db_size: status.db_size,
+            state: status.state,
+            is_leader: status.is_leader,
+            current_term: status.current_term,
+            last_log_index: status.last_log_index,
+            last_applied: status.last_applied.to_string(),
+            snapshot_last_log_id: status.snapshot_last_log_id.map(|x| x.to_string()),
+            leader: status.leader.map(|x| x.to_string()),
+            voters: status.voters.iter().map(|x| x.to_string()).collect(),
+            non_voters: status.non_voters.iter().map(|x| x.to_string()).collect(),
+            last_seq: status.last_seq,
+            proposals_pending: status.proposals_pending,
+            replication_lag: status.replication_lag.into_iter().collect(),
+            slow_requests: status
+                .slow_requests
+                .into_iter()
+                .map(|x| SlowRequest {
+                    op: x.op,
+                    detail: x.detail,
+                    elapsed_ms: x.elapsed_ms,
+                    finished_at_ms: x.finished_at_ms,
+                })
+                .collect(),
+        };
+
+        Ok(Response::new(resp))
+    }
+}
+
+fn req_op_name(req: &MetaGrpcReq) -> &'static str {
+    match req {
+        MetaGrpcReq::UpsertKV(_) => "UpsertKV",
+        MetaGrpcReq::GetKV(_) => "GetKV",
+        MetaGrpcReq::MGetKV(_) => "MGetKV",
+        MetaGrpcReq::ListKV(_) => "ListKV",
+    }
 }
 
 pub struct ExportStream {
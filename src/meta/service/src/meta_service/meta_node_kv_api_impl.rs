@@ -48,6 +48,7 @@ impl kvapi::KVApi for MetaNode {
             seq: act.seq,
             value: act.value,
             value_meta: act.value_meta,
+            keep_ttl: act.keep_ttl,
         }));
         let rst = self.write(ent).await?;
 
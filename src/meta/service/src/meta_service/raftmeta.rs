@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::fmt::Debug;
 use std::net::Ipv4Addr;
@@ -61,6 +62,9 @@ use common_meta_types::MetaStartupError;
 use common_meta_types::Node;
 use common_meta_types::NodeId;
 use common_meta_types::RaftMetrics;
+use common_meta_types::SeqV;
+use common_meta_types::TxnReply;
+use common_meta_types::TxnRequest;
 use common_meta_types::TypeConfig;
 use futures::channel::oneshot;
 use itertools::Itertools;
@@ -85,6 +89,8 @@ use crate::meta_service::errors::grpc_error_to_network_err;
 use crate::meta_service::meta_leader::MetaLeader;
 use crate::meta_service::RaftServiceImpl;
 use crate::metrics::server_metrics;
+use crate::metrics::SlowRequest;
+use crate::metrics::SlowRequests;
 use crate::network::Network;
 use crate::store::RaftStore;
 use crate::store::RaftStoreBare;
@@ -123,6 +129,20 @@ pub struct MetaNodeStatus {
     ///
     /// `seq` is a monotonically incremental integer for every value that is inserted or updated.
     pub last_seq: u64,
+
+    /// The last log id included in the latest snapshot.
+    pub snapshot_last_log_id: Option<LogId>,
+
+    /// The number of proposals submitted on this node but not yet applied.
+    pub proposals_pending: u64,
+
+    /// Number of logs each follower or learner lags behind this node.
+    ///
+    /// It is only filled when this node is the leader.
+    pub replication_lag: BTreeMap<NodeId, u64>,
+
+    /// The most recent requests that took longer than the slow-request threshold, oldest first.
+    pub slow_requests: Vec<SlowRequest>,
 }
 
 /// MetaRaft is a implementation of the generic Raft handling meta data R/W.
@@ -137,6 +157,7 @@ pub struct MetaNode {
     pub running_rx: watch::Receiver<()>,
     pub join_handles: Mutex<Vec<JoinHandle<Result<(), AnyError>>>>,
     pub joined_tasks: AtomicI32,
+    pub slow_requests: SlowRequests,
 }
 
 impl Opened for MetaNode {
@@ -192,6 +213,7 @@ impl MetaNodeBuilder {
             running_rx: rx,
             join_handles: Mutex::new(Vec::new()),
             joined_tasks: AtomicI32::new(1),
+            slow_requests: SlowRequests::default(),
         });
 
         if self.monitor_metrics {
@@ -880,6 +902,19 @@ impl MetaNode {
 
         let last_seq = self.get_last_seq().await?;
 
+        let last_log_index = metrics.last_log_index.unwrap_or(0);
+
+        let mut replication_lag = BTreeMap::new();
+        if let Some(replication) = &metrics.replication {
+            for (node_id, matched) in replication.iter() {
+                if *node_id == self.sto.id {
+                    continue;
+                }
+                let matched_index = matched.map(|log_id| log_id.index).unwrap_or(0);
+                replication_lag.insert(*node_id, last_log_index.saturating_sub(matched_index));
+            }
+        }
+
         Ok(MetaNodeStatus {
             id: self.sto.id,
             endpoint: endpoint.to_string(),
@@ -887,7 +922,7 @@ impl MetaNode {
             state: format!("{:?}", metrics.state),
             is_leader: metrics.state == openraft::ServerState::Leader,
             current_term: metrics.current_term,
-            last_log_index: metrics.last_log_index.unwrap_or(0),
+            last_log_index,
             last_applied: match metrics.last_applied {
                 Some(id) => id,
                 None => LogId::new(CommittedLeaderId::new(0, 0), 0),
@@ -896,6 +931,10 @@ impl MetaNode {
             voters,
             non_voters: learners,
             last_seq,
+            snapshot_last_log_id: metrics.snapshot,
+            proposals_pending: server_metrics::proposals_pending(),
+            replication_lag,
+            slow_requests: self.slow_requests.list(),
         })
     }
 
@@ -1081,6 +1120,27 @@ impl MetaNode {
         Ok(res)
     }
 
+    /// Run a batch of transactions with a single raft log, either all of them take effect or none.
+    ///
+    /// The replies are in the same order as `requests`. The batch takes effect only if every reply
+    /// is `success`.
+    #[tracing::instrument(level = "debug", skip(self, requests))]
+    pub async fn batch_transaction(
+        &self,
+        requests: Vec<TxnRequest>,
+    ) -> Result<Vec<TxnReply>, MetaAPIError> {
+        info!("MetaNode::batch_transaction(): {} txns", requests.len());
+        let ent = LogEntry::new(Cmd::BatchTransaction(requests));
+        let rst = self.write(ent).await?;
+
+        match rst {
+            AppliedState::BatchTxnReply(x) => Ok(x),
+            _ => {
+                unreachable!("expect type {}", "AppliedState::BatchTxnReply")
+            }
+        }
+    }
+
     /// Try to get the leader from the latest metrics of the local raft node.
     /// If leader is absent, wait for an metrics update in which a leader is set.
     #[tracing::instrument(level = "debug", skip(self))]
@@ -1160,4 +1220,47 @@ impl MetaNode {
             Err(_e) => Err("dispatcher closed"),
         }
     }
+
+    /// Add a watcher and return the key-values in the watched range at the time it is added.
+    ///
+    /// Change events are sent to the dispatcher while the state machine is write-locked.
+    /// Thus by holding the read lock until the watcher is queued to the dispatcher,
+    /// every change before the returned state is dispatched before the watcher is added,
+    /// and every change after it is delivered to the watcher.
+    ///
+    /// The outer error is a storage error, the inner error is an invalid watch request.
+    #[tracing::instrument(level = "debug", skip(self, tx))]
+    pub(crate) async fn add_watcher_with_initial_state(
+        &self,
+        request: WatchRequest,
+        tx: WatcherSender,
+    ) -> Result<Result<(Watcher, Vec<(String, SeqV)>), &'static str>, MetaStorageError> {
+        let range = match EventDispatcher::build_key_range(request.key.clone(), &request.key_end) {
+            Ok(range) => range,
+            Err(e) => return Ok(Err(e)),
+        };
+
+        let (resp_tx, resp_rx) = oneshot::channel();
+
+        {
+            let sm = self.sto.state_machine.read().await;
+
+            let kvs = if request.key_end.is_some() {
+                sm.range_kvs(range)?
+            } else {
+                sm.range_kvs(range.start..=range.end)?
+            };
+
+            self.dispatcher_handle.request(|d: &mut EventDispatcher| {
+                let add_res = d.add_watcher(request, tx);
+                let _ = resp_tx.send(add_res.map(|watcher| (watcher, kvs)));
+            });
+        }
+
+        let recv_res = resp_rx.await;
+        match recv_res {
+            Ok(add_res) => Ok(add_res),
+            Err(_e) => Ok(Err("dispatcher closed")),
+        }
+    }
 }
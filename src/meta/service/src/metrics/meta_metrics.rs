@@ -34,6 +34,9 @@ fn f64_of(b: bool) -> f64 {
 }
 
 pub mod server_metrics {
+    use std::sync::atomic::AtomicI64;
+    use std::sync::atomic::Ordering;
+
     use common_meta_types::NodeId;
     use metrics::counter;
     use metrics::gauge;
@@ -47,6 +50,9 @@ pub mod server_metrics {
         };
     }
 
+    /// A readable copy of the `proposals_pending` gauge, which can not be read back.
+    static PROPOSALS_PENDING: AtomicI64 = AtomicI64::new(0);
+
     pub fn set_current_leader(current_leader: NodeId) {
         gauge!(key!("current_leader_id"), current_leader as f64);
     }
@@ -85,9 +91,15 @@ pub mod server_metrics {
     }
 
     pub fn incr_proposals_pending(cnt: i64) {
+        PROPOSALS_PENDING.fetch_add(cnt, Ordering::Relaxed);
         increment_gauge!(key!("proposals_pending"), cnt as f64);
     }
 
+    /// The number of proposals submitted to raft on this node but not yet applied.
+    pub fn proposals_pending() -> u64 {
+        PROPOSALS_PENDING.load(Ordering::Relaxed).max(0) as u64
+    }
+
     pub fn incr_proposals_failed() {
         counter!(key!("proposals_failed"), 1);
     }
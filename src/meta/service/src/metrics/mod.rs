@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod meta_metrics;
+mod slow_requests;
 
 pub use meta_metrics::meta_metrics_to_prometheus_string;
 pub use meta_metrics::network_metrics;
@@ -20,3 +21,5 @@ pub use meta_metrics::raft_metrics;
 pub use meta_metrics::server_metrics;
 pub(crate) use meta_metrics::ProposalPending;
 pub(crate) use meta_metrics::RequestInFlight;
+pub use slow_requests::SlowRequest;
+pub use slow_requests::SlowRequests;
@@ -0,0 +1,116 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use tracing::warn;
+
+/// A request that took longer than the slow-request threshold to handle.
+#[derive(serde::Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct SlowRequest {
+    /// Name of the operation, such as `UpsertKV` or `Transaction`.
+    pub op: String,
+
+    /// A brief description of the request, usually the keys it accesses.
+    pub detail: String,
+
+    /// Time spent handling the request in milliseconds.
+    pub elapsed_ms: u64,
+
+    /// When the request finished, in milliseconds since the unix epoch.
+    pub finished_at_ms: u64,
+}
+
+/// Keeps the most recent slow requests in a bounded ring buffer.
+///
+/// When it is full, the oldest record is evicted.
+pub struct SlowRequests {
+    threshold_ms: AtomicU64,
+    capacity: usize,
+    records: Mutex<VecDeque<SlowRequest>>,
+}
+
+impl Default for SlowRequests {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_THRESHOLD, Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl SlowRequests {
+    pub const DEFAULT_THRESHOLD: Duration = Duration::from_millis(1_000);
+    pub const DEFAULT_CAPACITY: usize = 64;
+
+    pub fn new(threshold: Duration, capacity: usize) -> Self {
+        Self {
+            threshold_ms: AtomicU64::new(threshold.as_millis() as u64),
+            capacity,
+            records: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    pub fn threshold(&self) -> Duration {
+        Duration::from_millis(self.threshold_ms.load(Ordering::Relaxed))
+    }
+
+    pub fn set_threshold(&self, threshold: Duration) {
+        self.threshold_ms
+            .store(threshold.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Record a request if it took no less than the threshold.
+    ///
+    /// `detail` is only evaluated when the request is slow.
+    pub fn record(&self, op: &str, elapsed: Duration, detail: impl FnOnce() -> String) {
+        if elapsed < self.threshold() || self.capacity == 0 {
+            return;
+        }
+
+        let detail = detail();
+
+        warn!(
+            "slow request: op: {}, elapsed: {:?}, detail: {}",
+            op, elapsed, detail
+        );
+
+        let finished_at_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+
+        let rec = SlowRequest {
+            op: op.to_string(),
+            detail,
+            elapsed_ms: elapsed.as_millis() as u64,
+            finished_at_ms,
+        };
+
+        let mut records = self.records.lock().unwrap();
+        if records.len() >= self.capacity {
+            records.pop_front();
+        }
+        records.push_back(rec);
+    }
+
+    /// Returns the recorded slow requests, oldest first.
+    pub fn list(&self) -> Vec<SlowRequest> {
+        let records = self.records.lock().unwrap();
+        records.iter().cloned().collect()
+    }
+}
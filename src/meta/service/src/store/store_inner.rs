@@ -229,7 +229,7 @@ impl StoreInner {
             .write_state_machine_id(&(sm_id, new_sm_id))
             .await?;
 
-        let new_sm = StateMachine::open(&self.config, new_sm_id).await?;
+        let mut new_sm = StateMachine::open(&self.config, new_sm_id).await?;
         info!(
             "insert all key-value into new state machine, n={}",
             snap.kvs.len()
@@ -271,6 +271,12 @@ impl StoreInner {
 
         // TODO(xp): use checksum to check consistency?
 
+        // Watchers must keep receiving change events, e.g., deletes caused by expiration,
+        // from the state machine built from the snapshot.
+        if let Some(subscriber) = sm.subscriber.take() {
+            new_sm.set_subscriber(subscriber);
+        }
+
         *sm = new_sm;
         Ok(())
     }
@@ -44,6 +44,19 @@ pub static METASRV_SEMVER: Lazy<Version> = Lazy::new(|| {
 });
 
 /// Oldest compatible nightly meta-client version
+///
+/// The handshake only checks the client against the node it connects to, not against the other
+/// nodes of the cluster. A request is replicated as a raft log to every node, thus a log that an
+/// older databend-meta can not apply the same way breaks the cluster if it is proposed before all
+/// nodes are upgraded:
+///
+/// - `Cmd::BatchTransaction` can not be deserialized by an older node, which then stops applying
+///   logs.
+/// - `UpsertKV::keep_ttl` is ignored by an older node, which then drops the expiration of the
+///   record while the upgraded nodes keep it, and the state machines diverge.
+///
+/// Therefore all databend-meta nodes must be upgraded before any databend-query that sends a
+/// batch transaction or a keep-ttl upsert. See `Query-Meta Compatibility` in the deploy docs.
 pub static MIN_METACLI_SEMVER: Version = Version {
     major: 0,
     minor: 9,
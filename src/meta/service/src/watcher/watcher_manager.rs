@@ -165,6 +165,7 @@ impl EventDispatcher {
                     current: current.clone().map(to_pb_seq_v),
                     prev: prev.clone().map(to_pb_seq_v),
                 }),
+                is_initialization: false,
             };
 
             network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
@@ -225,7 +226,7 @@ impl EventDispatcher {
         server_metrics::incr_watchers(-1);
     }
 
-    fn build_key_range(
+    pub(crate) fn build_key_range(
         key: String,
         key_end: &Option<String>,
     ) -> Result<Range<String>, &'static str> {
@@ -251,6 +252,40 @@ impl StateMachineSubscriber for DispatcherSender {
     }
 }
 
+/// Build the responses of the initial state of a watcher:
+/// an event for every key-value, followed by a boundary response without event.
+pub(crate) fn initial_state_responses(
+    filter: FilterType,
+    kvs: Vec<(String, SeqV)>,
+) -> Vec<WatchResponse> {
+    let mut responses = Vec::with_capacity(kvs.len() + 1);
+
+    // The initial state consists of only update events.
+    if filter != FilterType::Delete {
+        for (key, seq_v) in kvs {
+            responses.push(WatchResponse {
+                event: Some(Event {
+                    key,
+                    current: Some(to_pb_seq_v(seq_v)),
+                    prev: None,
+                }),
+                is_initialization: true,
+            });
+        }
+    }
+
+    responses.push(WatchResponse {
+        event: None,
+        is_initialization: true,
+    });
+
+    for resp in responses.iter() {
+        network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
+    }
+
+    responses
+}
+
 /// Convert SeqV defined in rust types to SeqV defined in protobuf.
 fn to_pb_seq_v(seq_v: SeqV) -> pb::SeqV {
     pb::SeqV {
@@ -0,0 +1,73 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_meta::init_meta_ut;
+use pretty_assertions::assert_eq;
+
+use crate::tests::service::start_metasrv_cluster;
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_get_cluster_status() -> anyhow::Result<()> {
+    // - Start a cluster of 2 nodes.
+    // - Record every request as a slow request on the leader and write some data.
+    // - Get status from both the leader and the follower.
+
+    let tcs = start_metasrv_cluster(&[0, 1]).await?;
+
+    tcs[0]
+        .meta_node()
+        .slow_requests
+        .set_threshold(Duration::from_millis(0));
+
+    let client0 = tcs[0].grpc_client().await?;
+    let client1 = tcs[1].grpc_client().await?;
+
+    client0
+        .upsert_kv(UpsertKVReq::update("foo", b"bar"))
+        .await?;
+
+    let status0 = client0.get_cluster_status().await?;
+    let status1 = client1.get_cluster_status().await?;
+
+    // Leader
+
+    assert_eq!(0, status0.id);
+    assert!(status0.is_leader);
+    assert!(status0.replication_lag.contains_key(&1));
+    assert!(!status0.replication_lag.contains_key(&0));
+    assert_eq!(2, status0.voters.len());
+
+    let slow = status0
+        .slow_requests
+        .iter()
+        .find(|x| x.op == "UpsertKV")
+        .expect("upsert is recorded as a slow request");
+    assert_eq!("foo", slow.detail);
+
+    // Follower
+
+    assert_eq!(1, status1.id);
+    assert!(!status1.is_leader);
+    assert!(status1.leader.is_some());
+    assert!(status1.replication_lag.is_empty());
+    assert!(status1.slow_requests.is_empty());
+    assert_eq!(status0.current_term, status1.current_term);
+
+    Ok(())
+}
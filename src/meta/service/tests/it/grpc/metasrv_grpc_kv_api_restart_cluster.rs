@@ -59,6 +59,7 @@ async fn test_kv_api_restart_cluster_write_read() -> anyhow::Result<()> {
                     seq: MatchSeq::GE(0),
                     value: Operation::Update(k.clone().into_bytes()),
                     value_meta: None,
+                    keep_ttl: false,
                 })
                 .await?;
 
@@ -151,6 +152,7 @@ async fn test_kv_api_restart_cluster_token_expired() -> anyhow::Result<()> {
                         seq: MatchSeq::GE(0),
                         value: Operation::Update(k.clone().into_bytes()),
                         value_meta: None,
+                        keep_ttl: false,
                     })
                     .await?;
                 info!("--- upsert res: {:?}", res);
@@ -162,6 +164,7 @@ async fn test_kv_api_restart_cluster_token_expired() -> anyhow::Result<()> {
                         seq: MatchSeq::GE(0),
                         value: Operation::Update(k.clone().into_bytes()),
                         value_meta: None,
+                        keep_ttl: false,
                     })
                     .await?;
                 info!("--- upsert res: {:?}", res);
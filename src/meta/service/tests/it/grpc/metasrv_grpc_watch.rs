@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::SystemTime;
@@ -129,6 +130,7 @@ async fn test_watch() -> anyhow::Result<()> {
             key: "a".to_string(),
             key_end: Some("z".to_string()),
             filter_type: FilterType::All.into(),
+            need_initial_state: false,
         };
 
         let key_a = "a".to_string();
@@ -202,6 +204,7 @@ async fn test_watch() -> anyhow::Result<()> {
             key_end: None,
             // filter only delete events
             filter_type: FilterType::Delete.into(),
+            need_initial_state: false,
         };
 
         let key = key_str.to_string();
@@ -281,6 +284,7 @@ async fn test_watch() -> anyhow::Result<()> {
             key: start,
             key_end: Some(end),
             filter_type: FilterType::All.into(),
+            need_initial_state: false,
         };
 
         let conditions = vec![TxnCondition {
@@ -432,6 +436,7 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
         key: start,
         key_end: Some(end),
         filter_type: FilterType::All.into(),
+        need_initial_state: false,
     };
 
     let txn = TxnRequest {
@@ -500,6 +505,118 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_watch_initial_state() -> anyhow::Result<()> {
+    // - Write some keys.
+    // - Keep updating and deleting keys while a watcher with initial state attaches.
+    // - Assert the initial state merged with the following events equals a plain read,
+    //   i.e., no event is lost or duplicated across the boundary.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let watch_prefix = "w_init_";
+    let client = make_client(&addr)?;
+
+    for i in 0..10 {
+        let k = format!("{}{}", watch_prefix, i);
+        client.upsert_kv(UpsertKVReq::update(&k, &b(&k))).await?;
+    }
+
+    info!("--- keep updating while a watcher attaches");
+    let writer = {
+        let client = make_client(&addr)?;
+        tokio::spawn(async move {
+            for i in 0..200 {
+                let k = format!("{}{}", watch_prefix, i % 10);
+                let req = if i % 3 == 0 {
+                    UpsertKVReq::delete(&k)
+                } else {
+                    UpsertKVReq::update(&k, &b(&i.to_string()))
+                };
+                client.upsert_kv(req).await.unwrap();
+            }
+        })
+    };
+
+    sleep(Duration::from_millis(10)).await;
+
+    let (start, end) = kvapi::prefix_to_range(watch_prefix)?;
+    let watch = WatchRequest {
+        key: start,
+        key_end: Some(end),
+        filter_type: FilterType::All.into(),
+        need_initial_state: true,
+    };
+    let mut watch_stream = client.request(watch).await?;
+
+    info!("--- collect initial state");
+    let mut cache: BTreeMap<String, SeqV> = BTreeMap::new();
+    loop {
+        let resp = watch_stream.message().await?.unwrap();
+        assert!(resp.is_initialization);
+
+        match resp.event {
+            Some(ev) => {
+                assert!(ev.prev.is_none());
+                cache.insert(ev.key, ev.current.unwrap());
+            }
+            // The boundary
+            None => break,
+        }
+    }
+
+    writer.await?;
+
+    info!("--- apply change events until the end mark");
+    let end_key = format!("{}end", watch_prefix);
+    client
+        .upsert_kv(UpsertKVReq::update(&end_key, &b("end")))
+        .await?;
+
+    loop {
+        let resp = watch_stream.message().await?.unwrap();
+        assert!(!resp.is_initialization);
+        let ev = resp.event.unwrap();
+
+        // Every event continues from the cached state.
+        assert_eq!(
+            cache.get(&ev.key).map(|x| x.seq),
+            ev.prev.as_ref().map(|x| x.seq),
+            "event: {:?}",
+            ev
+        );
+
+        match ev.current {
+            Some(current) => {
+                cache.insert(ev.key.clone(), current);
+            }
+            None => {
+                cache.remove(&ev.key);
+            }
+        }
+
+        if ev.key == end_key {
+            break;
+        }
+    }
+
+    info!("--- the merged view equals a plain read");
+    let want = client
+        .prefix_list_kv(watch_prefix)
+        .await?
+        .into_iter()
+        .map(|(k, v)| {
+            (k, SeqV {
+                seq: v.seq,
+                data: v.data,
+            })
+        })
+        .collect::<BTreeMap<_, _>>();
+    assert_eq!(want, cache);
+
+    Ok(())
+}
+
 #[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
 async fn test_watch_stream_count() -> anyhow::Result<()> {
     // When the client drops the stream, databend-meta should reclaim the resources.
@@ -510,6 +627,7 @@ async fn test_watch_stream_count() -> anyhow::Result<()> {
         key: "a".to_string(),
         key_end: Some("z".to_string()),
         filter_type: FilterType::All.into(),
+        need_initial_state: false,
     };
 
     let client1 = make_client(&addr)?;
@@ -42,6 +42,9 @@ pub enum AppliedState {
 
     TxnReply(TxnReply),
 
+    /// The replies of the transactions in a `Cmd::BatchTransaction`, in the same order.
+    BatchTxnReply(Vec<TxnReply>),
+
     #[try_into(ignore)]
     None,
 }
@@ -59,6 +62,16 @@ impl fmt::Display for AppliedState {
             AppliedState::TxnReply(txnreply) => {
                 write!(f, "Txn: {}", txnreply)
             }
+            AppliedState::BatchTxnReply(replies) => {
+                write!(f, "BatchTxn: [")?;
+                for (i, reply) in replies.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", reply)?;
+                }
+                write!(f, "]")
+            }
             AppliedState::None => {
                 write!(f, "None")
             }
@@ -77,6 +90,7 @@ impl AppliedState {
             AppliedState::KV(ref ch) => ch.is_changed(),
             AppliedState::None => false,
             AppliedState::TxnReply(txn) => txn.success,
+            AppliedState::BatchTxnReply(replies) => replies.iter().all(|r| r.success),
         }
     }
 
@@ -102,6 +116,7 @@ impl AppliedState {
             AppliedState::KV(Change { ref prev, .. }) => prev.is_none(),
             AppliedState::None => true,
             AppliedState::TxnReply(_txn) => true,
+            AppliedState::BatchTxnReply(_replies) => true,
         }
     }
 
@@ -111,6 +126,7 @@ impl AppliedState {
             AppliedState::KV(Change { ref result, .. }) => result.is_none(),
             AppliedState::None => true,
             AppliedState::TxnReply(txn) => !txn.success,
+            AppliedState::BatchTxnReply(replies) => !replies.iter().all(|r| r.success),
         }
     }
 }
@@ -46,6 +46,12 @@ pub enum Cmd {
 
     /// Update one or more kv with a transaction.
     Transaction(TxnRequest),
+
+    /// Update kv with a batch of transactions, in which either all of them take effect or none.
+    ///
+    /// A databend-meta that does not know this variant can not apply it, all nodes in a cluster
+    /// have to be upgraded before it is proposed. See `MIN_METACLI_SEMVER`.
+    BatchTransaction(Vec<TxnRequest>),
 }
 
 /// Update or insert a general purpose kv store
@@ -64,6 +70,16 @@ pub struct UpsertKV {
 
     /// Meta data of a value.
     pub value_meta: Option<KVMeta>,
+
+    /// Whether to keep the expiration time of the existing record.
+    ///
+    /// If it is `true`, `value_meta` is ignored and the meta of the previous value,
+    /// including its expiration time, is carried over to the updated value.
+    ///
+    /// A databend-meta that does not know this field ignores it and applies `value_meta` instead,
+    /// all nodes in a cluster have to be upgraded before it is set. See `MIN_METACLI_SEMVER`.
+    #[serde(default)]
+    pub keep_ttl: bool,
 }
 
 impl fmt::Display for Cmd {
@@ -89,6 +105,16 @@ impl fmt::Display for Cmd {
             Cmd::Transaction(txn) => {
                 write!(f, "txn:{}", txn)
             }
+            Cmd::BatchTransaction(txns) => {
+                write!(f, "batch_txn:[")?;
+                for (i, txn) in txns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", txn)?;
+                }
+                write!(f, "]")
+            }
         }
     }
 }
@@ -97,8 +123,8 @@ impl fmt::Display for UpsertKV {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{}({:?}) = {:?} ({:?})",
-            self.key, self.seq, self.value, self.value_meta
+            "{}({:?}) = {:?} ({:?}, keep_ttl: {})",
+            self.key, self.seq, self.value, self.value_meta, self.keep_ttl
         )
     }
 }
@@ -115,6 +141,7 @@ impl UpsertKV {
             seq,
             value,
             value_meta,
+            keep_ttl: false,
         }
     }
 
@@ -124,6 +151,7 @@ impl UpsertKV {
             seq: MatchSeq::Exact(0),
             value: Operation::Update(value.to_vec()),
             value_meta: None,
+            keep_ttl: false,
         }
     }
 
@@ -133,6 +161,7 @@ impl UpsertKV {
             seq: MatchSeq::GE(0),
             value: Operation::Update(value.to_vec()),
             value_meta: None,
+            keep_ttl: false,
         }
     }
 
@@ -142,8 +171,15 @@ impl UpsertKV {
             seq: MatchSeq::GE(0),
             value: Operation::Delete,
             value_meta: None,
+            keep_ttl: false,
         }
     }
+
+    /// Keep the expiration time of the existing record when updating its value.
+    pub fn keep_ttl(mut self) -> Self {
+        self.keep_ttl = true;
+        self
+    }
 }
 
 impl With<MatchSeq> for UpsertKV {
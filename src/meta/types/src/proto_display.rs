@@ -177,6 +177,9 @@ impl Display for Target {
             Target::Seq(seq) => {
                 write!(f, "seq({})", seq)
             }
+            Target::Exists(exists) => {
+                write!(f, "exists({})", exists)
+            }
         }
     }
 }
@@ -185,12 +188,13 @@ impl Display for TxnReply {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "TxnReply{{ success: {}, responses: {}, error: {}}}",
+            "TxnReply{{ success: {}, responses: {}, error: {}, failed_condition: {:?}}}",
             self.success,
             VecDisplay {
                 vec: &self.responses
             },
-            self.error
+            self.error,
+            self.failed_condition
         )
     }
 }
@@ -145,6 +145,8 @@ pub enum Expr {
     Literal { span: Span, lit: Literal },
     /// `COUNT(*)` expression
     CountAll { span: Span },
+    /// User-defined session variable, such as `@foo`
+    Variable { span: Span, name: String },
     /// `(foo, bar)`
     Tuple { span: Span, exprs: Vec<Expr> },
     /// Scalar/Agg/Window function call
@@ -156,6 +158,24 @@ pub enum Expr {
         args: Vec<Expr>,
         params: Vec<Literal>,
         window: Option<WindowSpec>,
+        /// The lambda of higher-order functions, like `ARRAY_MAP(arr, x -> x + 1)`
+        lambda: Option<Lambda>,
+    },
+    /// `LISTAGG([DISTINCT] <expr> [, <delimiter>] [ON OVERFLOW ...]) [WITHIN GROUP (ORDER BY <expr> [ASC | DESC])]`
+    ListAgg {
+        span: Span,
+        distinct: bool,
+        expr: Box<Expr>,
+        delimiter: Option<String>,
+        on_overflow: Option<ListAggOverflow>,
+        within_group: Option<Box<OrderByExpr>>,
+    },
+    /// `PERCENTILE_CONT | PERCENTILE_DISC (<level>) WITHIN GROUP (ORDER BY <expr> [ASC | DESC])`
+    Percentile {
+        span: Span,
+        kind: PercentileKind,
+        level: Literal,
+        within_group: Box<OrderByExpr>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -438,6 +458,9 @@ pub enum TypeName {
         fields_type: Vec<TypeName>,
     },
     Variant,
+    Bitmap,
+    Binary,
+    Interval,
     Nullable(Box<TypeName>),
 }
 
@@ -458,11 +481,41 @@ pub enum TrimWhere {
     Trailing,
 }
 
+/// The `ON OVERFLOW` clause of `LISTAGG`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListAggOverflow {
+    /// `ON OVERFLOW ERROR`
+    Error,
+    /// `ON OVERFLOW TRUNCATE ['<filler>'] [WITH | WITHOUT COUNT]`
+    Truncate {
+        filler: Option<String>,
+        with_count: bool,
+    },
+}
+
+/// The ordered-set aggregate functions computing a percentile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PercentileKind {
+    /// `PERCENTILE_CONT`, interpolates between the two nearest values
+    Cont,
+    /// `PERCENTILE_DISC`, picks the first value reaching the percentile
+    Disc,
+}
+
+/// `x -> x + 1` or `(acc, x) -> acc + x`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lambda {
+    pub params: Vec<Identifier>,
+    pub expr: Box<Expr>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct WindowSpec {
     pub partition_by: Vec<Expr>,
     pub order_by: Vec<OrderByExpr>,
     pub window_frame: Option<WindowFrame>,
+    /// `IGNORE NULLS` (true) or `RESPECT NULLS` (false) written before `OVER`.
+    pub ignore_nulls: Option<bool>,
 }
 
 /// `RANGE UNBOUNDED PRECEDING` or `ROWS BETWEEN 5 PRECEDING AND CURRENT ROW`.
@@ -471,12 +524,23 @@ pub struct WindowFrame {
     pub units: WindowFrameUnits,
     pub start_bound: WindowFrameBound,
     pub end_bound: WindowFrameBound,
+    pub exclusion: Option<WindowFrameExclusion>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum WindowFrameUnits {
     Rows,
     Range,
+    Groups,
+}
+
+/// `EXCLUDE CURRENT ROW`, `EXCLUDE GROUP`, `EXCLUDE TIES` or `EXCLUDE NO OTHERS`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WindowFrameExclusion {
+    CurrentRow,
+    Group,
+    Ties,
+    NoOthers,
 }
 
 /// Specifies [WindowFrame]'s `start_bound` and `end_bound`
@@ -571,6 +635,8 @@ pub enum UnaryOperator {
     CubeRoot,
     Abs,
     BitwiseNot,
+    /// `PRIOR expr` refers to the parent row in a `CONNECT BY` condition.
+    Prior,
 }
 
 impl UnaryOperator {
@@ -606,8 +672,11 @@ impl Expr {
             | Expr::Trim { span, .. }
             | Expr::Literal { span, .. }
             | Expr::CountAll { span }
+            | Expr::Variable { span, .. }
             | Expr::Tuple { span, .. }
             | Expr::FunctionCall { span, .. }
+            | Expr::ListAgg { span, .. }
+            | Expr::Percentile { span, .. }
             | Expr::Case { span, .. }
             | Expr::Exists { span, .. }
             | Expr::Subquery { span, .. }
@@ -676,6 +745,9 @@ impl Display for UnaryOperator {
             UnaryOperator::BitwiseNot => {
                 write!(f, "~")
             }
+            UnaryOperator::Prior => {
+                write!(f, "PRIOR")
+            }
         }
     }
 }
@@ -856,6 +928,15 @@ impl Display for TypeName {
             TypeName::Variant => {
                 write!(f, "VARIANT")?;
             }
+            TypeName::Bitmap => {
+                write!(f, "BITMAP")?;
+            }
+            TypeName::Binary => {
+                write!(f, "BINARY")?;
+            }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -874,6 +955,34 @@ impl Display for TrimWhere {
     }
 }
 
+impl Display for ListAggOverflow {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ListAggOverflow::Error => write!(f, "ON OVERFLOW ERROR"),
+            ListAggOverflow::Truncate { filler, with_count } => {
+                write!(f, "ON OVERFLOW TRUNCATE")?;
+                if let Some(filler) = filler {
+                    write!(f, " '{filler}'")?;
+                }
+                if *with_count {
+                    write!(f, " WITH COUNT")
+                } else {
+                    write!(f, " WITHOUT COUNT")
+                }
+            }
+        }
+    }
+}
+
+impl Display for PercentileKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            PercentileKind::Cont => write!(f, "PERCENTILE_CONT"),
+            PercentileKind::Disc => write!(f, "PERCENTILE_DISC"),
+        }
+    }
+}
+
 impl Display for Literal {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -912,6 +1021,30 @@ impl Display for Literal {
     }
 }
 
+impl Display for Lambda {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.params.len() == 1 {
+            write!(f, "{}", self.params[0])?;
+        } else {
+            write!(f, "(")?;
+            write_comma_separated_list(f, &self.params)?;
+            write!(f, ")")?;
+        }
+        write!(f, " -> {}", self.expr)
+    }
+}
+
+impl Display for WindowFrameExclusion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowFrameExclusion::CurrentRow => write!(f, "CURRENT ROW"),
+            WindowFrameExclusion::Group => write!(f, "GROUP"),
+            WindowFrameExclusion::Ties => write!(f, "TIES"),
+            WindowFrameExclusion::NoOthers => write!(f, "NO OTHERS"),
+        }
+    }
+}
+
 impl Display for WindowSpec {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut first = true;
@@ -951,6 +1084,9 @@ impl Display for WindowSpec {
                 WindowFrameUnits::Range => {
                     write!(f, "RANGE")?;
                 }
+                WindowFrameUnits::Groups => {
+                    write!(f, "GROUPS")?;
+                }
             }
             match (&frame.start_bound, &frame.end_bound) {
                 (WindowFrameBound::CurrentRow, WindowFrameBound::CurrentRow) => {
@@ -974,6 +1110,9 @@ impl Display for WindowSpec {
                     )?
                 }
             }
+            if let Some(exclusion) = &frame.exclusion {
+                write!(f, " EXCLUDE {exclusion}")?;
+            }
         }
         Ok(())
     }
@@ -1122,6 +1261,9 @@ impl Display for Expr {
             Expr::CountAll { .. } => {
                 write!(f, "COUNT(*)")?;
             }
+            Expr::Variable { name, .. } => {
+                write!(f, "@{name}")?;
+            }
             Expr::Tuple { exprs, .. } => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, exprs)?;
@@ -1136,6 +1278,7 @@ impl Display for Expr {
                 args,
                 params,
                 window,
+                lambda,
                 ..
             } => {
                 write!(f, "{name}")?;
@@ -1149,12 +1292,52 @@ impl Display for Expr {
                     write!(f, "DISTINCT ")?;
                 }
                 write_comma_separated_list(f, args)?;
+                if let Some(lambda) = lambda {
+                    write!(f, ", {lambda}")?;
+                }
                 write!(f, ")")?;
 
                 if let Some(window) = window {
+                    match window.ignore_nulls {
+                        Some(true) => write!(f, " IGNORE NULLS")?,
+                        Some(false) => write!(f, " RESPECT NULLS")?,
+                        None => {}
+                    }
                     write!(f, " OVER ({window})")?;
                 }
             }
+            Expr::ListAgg {
+                distinct,
+                expr,
+                delimiter,
+                on_overflow,
+                within_group,
+                ..
+            } => {
+                write!(f, "LISTAGG(")?;
+                if *distinct {
+                    write!(f, "DISTINCT ")?;
+                }
+                write!(f, "{expr}")?;
+                if let Some(delimiter) = delimiter {
+                    write!(f, ", '{delimiter}'")?;
+                }
+                if let Some(on_overflow) = on_overflow {
+                    write!(f, " {on_overflow}")?;
+                }
+                write!(f, ")")?;
+                if let Some(order_by) = within_group {
+                    write!(f, " WITHIN GROUP (ORDER BY {order_by})")?;
+                }
+            }
+            Expr::Percentile {
+                kind,
+                level,
+                within_group,
+                ..
+            } => {
+                write!(f, "{kind}({level}) WITHIN GROUP (ORDER BY {within_group})")?;
+            }
             Expr::Case {
                 operand,
                 conditions,
@@ -411,6 +411,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_variable(&mut self, _span: Span, name: &'ast str) {
+        let name = format!("Variable @{}", name);
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_tuple(&mut self, _span: Span, elements: &'ast [Expr]) {
         let mut children = Vec::with_capacity(elements.len());
         for element in elements.iter() {
@@ -431,12 +438,21 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         args: &'ast [Expr],
         _params: &'ast [Literal],
         _over: &'ast Option<WindowSpec>,
+        lambda: &'ast Option<Lambda>,
     ) {
-        let mut children = Vec::with_capacity(args.len());
+        let mut children = Vec::with_capacity(args.len() + 1);
         for arg in args.iter() {
             self.visit_expr(arg);
             children.push(self.children.pop().unwrap());
         }
+        if let Some(lambda) = lambda {
+            self.visit_expr(&lambda.expr);
+            let lambda_child = self.children.pop().unwrap();
+            let lambda_name = format!("Lambda {}", lambda);
+            let lambda_format_ctx = AstFormatContext::with_children(lambda_name, 1);
+            let lambda_node = FormatTreeNode::with_children(lambda_format_ctx, vec![lambda_child]);
+            children.push(lambda_node);
+        }
         let node_name = if distinct {
             format!("Function {name}Distinct")
         } else {
@@ -447,6 +463,52 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_list_agg(
+        &mut self,
+        _span: Span,
+        distinct: bool,
+        expr: &'ast Expr,
+        _delimiter: &'ast Option<String>,
+        _on_overflow: &'ast Option<ListAggOverflow>,
+        within_group: &'ast Option<Box<OrderByExpr>>,
+    ) {
+        let mut children = Vec::with_capacity(2);
+        self.visit_expr(expr);
+        children.push(self.children.pop().unwrap());
+        if let Some(order_by) = within_group {
+            self.visit_order_by(order_by);
+            children.push(self.children.pop().unwrap());
+        }
+        let node_name = if distinct {
+            "Function ListAggDistinct".to_string()
+        } else {
+            "Function ListAgg".to_string()
+        };
+        let format_ctx = AstFormatContext::with_children(node_name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_percentile(
+        &mut self,
+        _span: Span,
+        kind: PercentileKind,
+        level: &'ast Literal,
+        within_group: &'ast OrderByExpr,
+    ) {
+        self.visit_literal(None, level);
+        let level_child = self.children.pop().unwrap();
+        self.visit_order_by(within_group);
+        let order_by_child = self.children.pop().unwrap();
+        let node_name = match kind {
+            PercentileKind::Cont => "Function PercentileCont".to_string(),
+            PercentileKind::Disc => "Function PercentileDisc".to_string(),
+        };
+        let format_ctx = AstFormatContext::with_children(node_name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![level_child, order_by_child]);
+        self.children.push(node);
+    }
+
     fn visit_case_when(
         &mut self,
         _span: Span,
@@ -687,10 +749,10 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             ExplainKind::Ast(_) => "Ast",
             ExplainKind::Syntax(_) => "Syntax",
             ExplainKind::Graph => "Graph",
-            ExplainKind::Pipeline => "Pipeline",
+            ExplainKind::Pipeline(_) => "Pipeline",
             ExplainKind::Fragments => "Fragments",
             ExplainKind::Raw => "Raw",
-            ExplainKind::Plan => "Plan",
+            ExplainKind::Plan(_) => "Plan",
             ExplainKind::Memo(_) => "Memo",
             ExplainKind::JOIN => "JOIN",
             ExplainKind::AnalyzePlan => "Analyze",
@@ -792,6 +854,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_show_copy_history(&mut self, stmt: &'ast ShowCopyHistoryStmt) {
+        let mut children = Vec::new();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        children.push(self.children.pop().unwrap());
+        if let Some(since) = &stmt.since {
+            let since_format_ctx = AstFormatContext::new(format!("Since {}", since));
+            children.push(FormatTreeNode::new(since_format_ctx));
+        }
+        let name = "ShowCopyHistory".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_call(&mut self, call: &'ast CallStmt) {
         let mut children = Vec::new();
         for arg in call.args.iter() {
@@ -906,6 +982,36 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_set_user_variable(&mut self, variable: &'ast str, value: &'ast Expr) {
+        self.visit_expr(value);
+        let children = vec![self.children.pop().unwrap()];
+        let name = format!("Set @{}", variable);
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_reset_user_variable(&mut self, variable: &'ast str) {
+        let name = format!("Reset @{}", variable);
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_show_variables(&mut self, like: &'ast Option<String>) {
+        let mut children = Vec::new();
+        if let Some(like) = like {
+            let like_name = format!("Like {}", like);
+            let like_format_ctx = AstFormatContext::new(like_name);
+            let like_node = FormatTreeNode::new(like_format_ctx);
+            children.push(like_node);
+        }
+        let name = "ShowVariables".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_insert(&mut self, insert: &'ast InsertStmt) {
         let mut children = Vec::new();
         self.visit_table_ref(&insert.catalog, &insert.database, &insert.table);
@@ -1095,6 +1201,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterDatabaseAction::SetQuota { quota } => {
+                let action_name = format!("Action SetQuota {}", quota);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterDatabase".to_string();
@@ -1283,6 +1394,48 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_attach_table(&mut self, stmt: &'ast AttachTableStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let location_name = format!("Location {}", stmt.uri_location);
+        let location_format_ctx = AstFormatContext::new(location_name);
+        let location_child = FormatTreeNode::new(location_format_ctx);
+
+        let name = "AttachTable".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![table_child, location_child]);
+        self.children.push(node);
+    }
+
+    fn visit_export_table_snapshot(&mut self, stmt: &'ast ExportTableSnapshotStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let location_name = format!("Location {}", stmt.location);
+        let location_format_ctx = AstFormatContext::new(location_name);
+        let location_child = FormatTreeNode::new(location_format_ctx);
+
+        let name = "ExportTableSnapshot".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![table_child, location_child]);
+        self.children.push(node);
+    }
+
+    fn visit_import_table_snapshot(&mut self, stmt: &'ast ImportTableSnapshotStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let location_name = format!("Location {}", stmt.location);
+        let location_format_ctx = AstFormatContext::new(location_name);
+        let location_child = FormatTreeNode::new(location_format_ctx);
+
+        let name = "ImportTableSnapshot".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![table_child, location_child]);
+        self.children.push(node);
+    }
+
     fn visit_drop_table(&mut self, stmt: &'ast DropTableStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let child = self.children.pop().unwrap();
@@ -1313,8 +1466,8 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
-            AlterTableAction::AddColumn { column } => {
-                let action_name = format!("Action Add column {}", column);
+            AlterTableAction::AddColumn { column, option } => {
+                let action_name = format!("Action Add column {}{}", column, option);
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
@@ -1323,6 +1476,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                let action_name = format!("Action Rename column {} to {}", old_column, new_column);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::ModifyColumn { column, action } => {
+                let action_name = format!("Action Modify column {} {}", column, action);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
                 let mut cluster_by_children = Vec::with_capacity(cluster_by.len());
                 for cluster_by_expr in cluster_by.iter() {
@@ -1339,12 +1505,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
-            AlterTableAction::ReclusterTable { selection, .. } => {
+            AlterTableAction::ReclusterTable {
+                selection, limit, ..
+            } => {
                 let mut children = Vec::new();
                 if let Some(selection) = selection {
                     self.visit_expr(selection);
                     children.push(self.children.pop().unwrap());
                 }
+                if let Some(limit) = limit {
+                    let limit_name = format!("Limit {limit}");
+                    let limit_format_ctx = AstFormatContext::new(limit_name);
+                    children.push(FormatTreeNode::new(limit_format_ctx));
+                }
                 let action_name = "Action Recluster".to_string();
                 let action_format_ctx =
                     AstFormatContext::with_children(action_name, children.len());
@@ -1357,6 +1530,39 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::with_children(action_name, 1);
                 FormatTreeNode::with_children(action_format_ctx, vec![point_node])
             }
+            AlterTableAction::SetAnalyzeInterval { interval_secs } => {
+                let action_name = format!("Action SetAnalyzeInterval {}", interval_secs);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let action_name = format!(
+                    "Action AddRowAccessPolicy {} ON ({})",
+                    policy,
+                    columns
+                        .iter()
+                        .map(|column| column.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                let action_name = format!("Action DropRowAccessPolicy {}", policy);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::AddBloomFilterIndex => {
+                let action_name = "Action AddBloomFilterIndex".to_string();
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::SetConnection { .. } => {
+                let action_name = "Action SetConnection".to_string();
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1456,6 +1662,46 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let stream_child = self.children.pop().unwrap();
+        self.visit_table_ref(&None, &stmt.table_database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "CreateStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![stream_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_stream(&mut self, stmt: &'ast DropStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_streams(&mut self, stmt: &'ast ShowStreamsStmt) {
+        let mut children = Vec::new();
+        if let Some(database) = &stmt.database {
+            let database_name = format!("Database {}", database);
+            let database_format_ctx = AstFormatContext::new(database_name);
+            let database_node = FormatTreeNode::new(database_format_ctx);
+            children.push(database_node);
+        }
+        if let Some(limit) = &stmt.limit {
+            self.visit_show_limit(limit);
+            children.push(self.children.pop().unwrap());
+        }
+        let name = "ShowStreams".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_show_users(&mut self) {
         let name = "ShowUsers".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -1561,6 +1807,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_show_role_hierarchy(&mut self) {
+        let name = "ShowRoleHierarchy".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_create_role(&mut self, _if_not_exists: bool, role_name: &'ast str) {
         let role_name = format!("Role {}", role_name);
         let role_format_ctx = AstFormatContext::new(role_name);
@@ -1602,6 +1855,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::with_children(privileges_name, privileges_children.len());
                 FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
             }
+            AccountMgrSource::ColumnPrivs {
+                privilege, columns, ..
+            } => {
+                let columns_children = columns
+                    .iter()
+                    .map(|column| {
+                        FormatTreeNode::new(AstFormatContext::new(format!("Column {}", column)))
+                    })
+                    .collect::<Vec<_>>();
+                let privilege_name = format!("Privilege {}", privilege);
+                let privilege_format_ctx =
+                    AstFormatContext::with_children(privilege_name, columns_children.len());
+                FormatTreeNode::with_children(privilege_format_ctx, columns_children)
+            }
             AccountMgrSource::ALL { .. } => {
                 let all_name = "All".to_string();
                 let all_format_ctx = AstFormatContext::new(all_name);
@@ -1656,6 +1923,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::with_children(privileges_name, privileges_children.len());
                 FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
             }
+            AccountMgrSource::ColumnPrivs {
+                privilege, columns, ..
+            } => {
+                let columns_children = columns
+                    .iter()
+                    .map(|column| {
+                        FormatTreeNode::new(AstFormatContext::new(format!("Column {}", column)))
+                    })
+                    .collect::<Vec<_>>();
+                let privilege_name = format!("Privilege {}", privilege);
+                let privilege_format_ctx =
+                    AstFormatContext::with_children(privilege_name, columns_children.len());
+                FormatTreeNode::with_children(privilege_format_ctx, columns_children)
+            }
             AccountMgrSource::ALL { .. } => {
                 let all_name = "All".to_string();
                 let all_format_ctx = AstFormatContext::new(all_name);
@@ -1729,6 +2010,41 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_external_function(&mut self, stmt: &'ast CreateExternalFunctionStmt) {
+        let mut children = Vec::new();
+        let name_format_ctx =
+            AstFormatContext::new(format!("ExternalFunctionIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(name_format_ctx));
+        for (name, data_type) in stmt.arguments.iter() {
+            let argument_format_ctx =
+                AstFormatContext::new(format!("Argument {} {}", name, data_type));
+            children.push(FormatTreeNode::new(argument_format_ctx));
+        }
+        let return_type_format_ctx =
+            AstFormatContext::new(format!("ReturnType {}", stmt.return_type));
+        children.push(FormatTreeNode::new(return_type_format_ctx));
+        let handler_format_ctx = AstFormatContext::new(format!("Handler {}", stmt.handler));
+        children.push(FormatTreeNode::new(handler_format_ctx));
+        let address_format_ctx = AstFormatContext::new(format!("Address {}", stmt.address));
+        children.push(FormatTreeNode::new(address_format_ctx));
+
+        let name = "CreateExternalFunction".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_external_function(&mut self, stmt: &'ast DropExternalFunctionStmt) {
+        let name_format_ctx =
+            AstFormatContext::new(format!("ExternalFunctionIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(name_format_ctx);
+
+        let name = "DropExternalFunction".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_alter_udf(
         &mut self,
         udf_name: &'ast Identifier,
@@ -1772,6 +2088,342 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_describe_function(&mut self, name: &'ast Identifier) {
+        let function_name_format_ctx =
+            AstFormatContext::new(format!("FunctionIdentifier {}", name));
+        let child = FormatTreeNode::new(function_name_format_ctx);
+
+        let name = "DescribeFunction".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_masking_policy(&mut self, stmt: &'ast CreateMaskingPolicyStmt) {
+        let mut children = Vec::new();
+        let name_format_ctx = AstFormatContext::new(format!("PolicyIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(name_format_ctx));
+        let args_children = stmt
+            .args
+            .iter()
+            .map(|arg| FormatTreeNode::new(AstFormatContext::new(format!("PolicyArg {}", arg))))
+            .collect::<Vec<_>>();
+        let args_format_ctx =
+            AstFormatContext::with_children("PolicyArgs".to_string(), args_children.len());
+        children.push(FormatTreeNode::with_children(
+            args_format_ctx,
+            args_children,
+        ));
+        let return_type_format_ctx =
+            AstFormatContext::new(format!("PolicyReturnType {}", stmt.return_type));
+        children.push(FormatTreeNode::new(return_type_format_ctx));
+        self.visit_expr(&stmt.body);
+        let body_child = self.children.pop().unwrap();
+        let body_format_ctx = AstFormatContext::with_children("PolicyBody".to_string(), 1);
+        children.push(FormatTreeNode::with_children(body_format_ctx, vec![
+            body_child,
+        ]));
+        if let Some(comment) = &stmt.comment {
+            let comment_format_ctx = AstFormatContext::new(format!("Comment {}", comment));
+            children.push(FormatTreeNode::new(comment_format_ctx));
+        }
+
+        let name = "CreateMaskingPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_masking_policy(&mut self, stmt: &'ast DropMaskingPolicyStmt) {
+        let name_format_ctx = AstFormatContext::new(format!("PolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(name_format_ctx);
+
+        let name = "DropMaskingPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_row_access_policy(&mut self, stmt: &'ast CreateRowAccessPolicyStmt) {
+        let mut children = Vec::new();
+        let name_format_ctx = AstFormatContext::new(format!("PolicyIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(name_format_ctx));
+        let args_children = stmt
+            .args
+            .iter()
+            .map(|arg| FormatTreeNode::new(AstFormatContext::new(format!("PolicyArg {}", arg))))
+            .collect::<Vec<_>>();
+        let args_format_ctx =
+            AstFormatContext::with_children("PolicyArgs".to_string(), args_children.len());
+        children.push(FormatTreeNode::with_children(
+            args_format_ctx,
+            args_children,
+        ));
+        let return_type_format_ctx =
+            AstFormatContext::new(format!("PolicyReturnType {}", stmt.return_type));
+        children.push(FormatTreeNode::new(return_type_format_ctx));
+        self.visit_expr(&stmt.body);
+        let body_child = self.children.pop().unwrap();
+        let body_format_ctx = AstFormatContext::with_children("PolicyBody".to_string(), 1);
+        children.push(FormatTreeNode::with_children(body_format_ctx, vec![
+            body_child,
+        ]));
+        if let Some(comment) = &stmt.comment {
+            let comment_format_ctx = AstFormatContext::new(format!("Comment {}", comment));
+            children.push(FormatTreeNode::new(comment_format_ctx));
+        }
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(&mut self, stmt: &'ast DropRowAccessPolicyStmt) {
+        let name_format_ctx = AstFormatContext::new(format!("PolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(name_format_ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_row_access_policies(&mut self) {
+        let name = "ShowRowAccessPolicies".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_create_sequence(&mut self, stmt: &'ast CreateSequenceStmt) {
+        let mut children = Vec::new();
+        let name_format_ctx = AstFormatContext::new(format!("SequenceIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(name_format_ctx));
+        let options = [
+            ("Start", stmt.start),
+            ("Increment", stmt.increment),
+            ("MinValue", stmt.min_value),
+            ("MaxValue", stmt.max_value),
+        ];
+        for (option, value) in options {
+            if let Some(value) = value {
+                let option_format_ctx = AstFormatContext::new(format!("{} {}", option, value));
+                children.push(FormatTreeNode::new(option_format_ctx));
+            }
+        }
+        if let Some(cycle) = stmt.cycle {
+            let cycle_format_ctx = AstFormatContext::new(format!("Cycle {}", cycle));
+            children.push(FormatTreeNode::new(cycle_format_ctx));
+        }
+
+        let name = "CreateSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_sequence(&mut self, stmt: &'ast DropSequenceStmt) {
+        let name_format_ctx = AstFormatContext::new(format!("SequenceIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(name_format_ctx);
+
+        let name = "DropSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_sequences(&mut self) {
+        let name = "ShowSequences".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_show_index_build_status(&mut self) {
+        let name = "ShowIndexBuildStatus".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_create_network_policy(&mut self, stmt: &'ast CreateNetworkPolicyStmt) {
+        let mut children = Vec::new();
+        let name_format_ctx = AstFormatContext::new(format!("PolicyIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(name_format_ctx));
+        let allowed_format_ctx =
+            AstFormatContext::new(format!("AllowedIpList {}", stmt.allowed_ip_list.join(", ")));
+        children.push(FormatTreeNode::new(allowed_format_ctx));
+        if !stmt.blocked_ip_list.is_empty() {
+            let blocked_format_ctx =
+                AstFormatContext::new(format!("BlockedIpList {}", stmt.blocked_ip_list.join(", ")));
+            children.push(FormatTreeNode::new(blocked_format_ctx));
+        }
+        if let Some(comment) = &stmt.comment {
+            let comment_format_ctx = AstFormatContext::new(format!("Comment {}", comment));
+            children.push(FormatTreeNode::new(comment_format_ctx));
+        }
+
+        let name = "CreateNetworkPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_network_policy(&mut self, stmt: &'ast DropNetworkPolicyStmt) {
+        let name_format_ctx = AstFormatContext::new(format!("PolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(name_format_ctx);
+
+        let name = "DropNetworkPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_network_policy(&mut self, stmt: &'ast DescNetworkPolicyStmt) {
+        let name_format_ctx = AstFormatContext::new(format!("PolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(name_format_ctx);
+
+        let name = "DescNetworkPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_network_policies(&mut self) {
+        let name = "ShowNetworkPolicies".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_create_task(&mut self, stmt: &'ast CreateTaskStmt) {
+        let mut children = Vec::new();
+        let name_format_ctx = AstFormatContext::new(format!("TaskIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(name_format_ctx));
+        let schedule_format_ctx = AstFormatContext::new(format!("Schedule {}", stmt.schedule));
+        children.push(FormatTreeNode::new(schedule_format_ctx));
+        if let Some(comment) = &stmt.comment {
+            let comment_format_ctx = AstFormatContext::new(format!("Comment {}", comment));
+            children.push(FormatTreeNode::new(comment_format_ctx));
+        }
+        self.visit_statement(&stmt.sql);
+        children.push(self.children.pop().unwrap());
+
+        let name = "CreateTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_alter_task(&mut self, stmt: &'ast AlterTaskStmt) {
+        let name_format_ctx = AstFormatContext::new(format!("TaskIdentifier {}", stmt.name));
+        let action_format_ctx = AstFormatContext::new(format!("Action {}", stmt.action));
+        let children = vec![
+            FormatTreeNode::new(name_format_ctx),
+            FormatTreeNode::new(action_format_ctx),
+        ];
+
+        let name = "AlterTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_task(&mut self, stmt: &'ast DropTaskStmt) {
+        let name_format_ctx = AstFormatContext::new(format!("TaskIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(name_format_ctx);
+
+        let name = "DropTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_execute_task(&mut self, stmt: &'ast ExecuteTaskStmt) {
+        let name_format_ctx = AstFormatContext::new(format!("TaskIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(name_format_ctx);
+
+        let name = "ExecuteTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_pipe(&mut self, stmt: &'ast CreatePipeStmt) {
+        let mut children = Vec::new();
+        let name_format_ctx = AstFormatContext::new(format!("PipeIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(name_format_ctx));
+        if let Some(comment) = &stmt.comment {
+            let comment_format_ctx = AstFormatContext::new(format!("Comment {}", comment));
+            children.push(FormatTreeNode::new(comment_format_ctx));
+        }
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        children.push(self.children.pop().unwrap());
+        let options = stmt
+            .kafka_options
+            .iter()
+            .map(|(k, v)| format!("{k} = '{v}'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let options_format_ctx = AstFormatContext::new(format!("KafkaOptions {}", options));
+        children.push(FormatTreeNode::new(options_format_ctx));
+        if let Some(on_error) = &stmt.on_error {
+            let on_error_format_ctx = AstFormatContext::new(format!("OnError {}", on_error));
+            children.push(FormatTreeNode::new(on_error_format_ctx));
+        }
+
+        let name = "CreatePipe".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_pipe(&mut self, stmt: &'ast DropPipeStmt) {
+        let name_format_ctx = AstFormatContext::new(format!("PipeIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(name_format_ctx);
+
+        let name = "DropPipe".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_pipes(&mut self) {
+        let name = "ShowPipes".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_drop_result_cache(&mut self) {
+        let name = "DropResultCache".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_begin(&mut self) {
+        let name = "Begin".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_commit(&mut self) {
+        let name = "Commit".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_abort(&mut self) {
+        let name = "Abort".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_create_stage(&mut self, stmt: &'ast CreateStageStmt) {
         let mut children = Vec::new();
         let stage_name_format_ctx = AstFormatContext::new(format!("StageName {}", stmt.stage_name));
@@ -1850,6 +2502,40 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_alter_stage(&mut self, stmt: &'ast AlterStageStmt) {
+        let stage_name_format_ctx = AstFormatContext::new(format!("StageName {}", stmt.stage_name));
+        let child = FormatTreeNode::new(stage_name_format_ctx);
+
+        let name = "AlterStage".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_rename_stage(&mut self, stage_name: &'ast str, new_stage_name: &'ast str) {
+        let stage_name_format_ctx = AstFormatContext::new(format!("StageName {}", stage_name));
+        let stage_name_child = FormatTreeNode::new(stage_name_format_ctx);
+        let new_stage_name_format_ctx =
+            AstFormatContext::new(format!("NewStageName {}", new_stage_name));
+        let new_stage_name_child = FormatTreeNode::new(new_stage_name_format_ctx);
+
+        let name = "RenameStage".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node =
+            FormatTreeNode::with_children(format_ctx, vec![stage_name_child, new_stage_name_child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_create_stage(&mut self, stage_name: &'ast str) {
+        let stage_name_format_ctx = AstFormatContext::new(format!("StageName {}", stage_name));
+        let child = FormatTreeNode::new(stage_name_format_ctx);
+
+        let name = "ShowCreateStage".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_remove_stage(&mut self, location: &'ast str, pattern: &'ast str) {
         let location_format_ctx = AstFormatContext::new(format!("Location {}", location));
         let location_child = FormatTreeNode::new(location_format_ctx);
@@ -1874,6 +2560,28 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_show_stage_files(
+        &mut self,
+        location: &'ast str,
+        pattern: &'ast str,
+        limit: &'ast Option<u64>,
+    ) {
+        let mut children = Vec::with_capacity(3);
+        let location_format_ctx = AstFormatContext::new(format!("Location {}", location));
+        children.push(FormatTreeNode::new(location_format_ctx));
+        let pattern_format_ctx = AstFormatContext::new(format!("Pattern {}", pattern));
+        children.push(FormatTreeNode::new(pattern_format_ctx));
+        if let Some(limit) = limit {
+            let limit_format_ctx = AstFormatContext::new(format!("Limit {}", limit));
+            children.push(FormatTreeNode::new(limit_format_ctx));
+        }
+
+        let name = "ShowStageFiles".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_presign(&mut self, presign: &'ast PresignStmt) {
         let mut children = Vec::with_capacity(3);
         let action_format_ctx = AstFormatContext::new(format!("Action {}", presign.action));
@@ -1991,12 +2699,29 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             children.push(tenants_node);
         }
 
+        if stmt.read_only {
+            let read_only_format_ctx = AstFormatContext::new("ReadOnly".to_string());
+            children.push(FormatTreeNode::new(read_only_format_ctx));
+        }
+
         let name = "AlterShareTenants".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
         self.children.push(node);
     }
 
+    fn visit_alter_share_comment(&mut self, stmt: &'ast AlterShareCommentStmt) {
+        let share_format_ctx = AstFormatContext::new(format!("ShareIdentifier {}", stmt.share));
+        let share_child = FormatTreeNode::new(share_format_ctx);
+        let comment_format_ctx = AstFormatContext::new(format!("Comment {}", stmt.comment));
+        let comment_child = FormatTreeNode::new(comment_format_ctx);
+
+        let name = "AlterShareComment".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![share_child, comment_child]);
+        self.children.push(node);
+    }
+
     fn visit_desc_share(&mut self, stmt: &'ast DescShareStmt) {
         let share_format_ctx = AstFormatContext::new(format!("ShareIdentifier {}", stmt.share));
         let child = FormatTreeNode::new(share_format_ctx);
@@ -2007,10 +2732,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
-    fn visit_show_shares(&mut self, _stmt: &'ast ShowSharesStmt) {
+    fn visit_show_shares(&mut self, stmt: &'ast ShowSharesStmt) {
         let name = "ShowShares".to_string();
-        let format_ctx = AstFormatContext::new(name);
-        let node = FormatTreeNode::new(format_ctx);
+        let node = if let Some(tenant) = &stmt.tenant {
+            let tenant_format_ctx = AstFormatContext::new(format!("Tenant {}", tenant));
+            let child = FormatTreeNode::new(tenant_format_ctx);
+            let format_ctx = AstFormatContext::with_children(name, 1);
+            FormatTreeNode::with_children(format_ctx, vec![child])
+        } else {
+            let format_ctx = AstFormatContext::new(name);
+            FormatTreeNode::new(format_ctx)
+        };
         self.children.push(node);
     }
 
@@ -2129,6 +2861,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 FormatTreeNode::with_children(selection_format_ctx, vec![selection_child]);
             children.push(selection_node);
         }
+        if let Some(connect_by) = &stmt.connect_by {
+            let mut connect_by_children = Vec::with_capacity(2);
+            if let Some(start_with) = &connect_by.start_with {
+                self.visit_expr(start_with);
+                let start_with_child = self.children.pop().unwrap();
+                let start_with_format_ctx =
+                    AstFormatContext::with_children("StartWith".to_string(), 1);
+                connect_by_children
+                    .push(FormatTreeNode::with_children(start_with_format_ctx, vec![
+                        start_with_child,
+                    ]));
+            }
+            self.visit_expr(&connect_by.condition);
+            connect_by_children.push(self.children.pop().unwrap());
+            let connect_by_format_ctx =
+                AstFormatContext::with_children("ConnectBy".to_string(), connect_by_children.len());
+            children.push(FormatTreeNode::with_children(
+                connect_by_format_ctx,
+                connect_by_children,
+            ));
+        }
         if let Some(group_by) = &stmt.group_by {
             match group_by {
                 GroupBy::Normal(exprs) => {
@@ -2248,8 +3001,10 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 table,
                 alias,
                 travel_point,
+                sample,
                 pivot,
                 unpivot,
+                match_recognize,
             } => {
                 let mut name = String::new();
                 name.push_str("TableIdentifier ");
@@ -2263,6 +3018,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 }
                 name.push_str(&table.to_string());
 
+                if let Some(sample) = sample {
+                    name.push(' ');
+                    name.push_str(&sample.to_string());
+                }
+
                 if let Some(pivot) = pivot {
                     name.push(' ');
                     name.push_str(&pivot.to_string());
@@ -2273,6 +3033,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     name.push_str(&unpivot.to_string());
                 }
 
+                if let Some(match_recognize) = match_recognize {
+                    name.push(' ');
+                    name.push_str(&match_recognize.to_string());
+                }
+
                 let mut children = Vec::new();
                 if let Some(travel_point) = travel_point {
                     self.visit_time_travel_point(travel_point);
@@ -2292,12 +3057,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             }
             TableReference::Subquery {
                 span: _,
+                lateral,
                 subquery,
                 alias,
             } => {
                 self.visit_query(subquery);
                 let child = self.children.pop().unwrap();
-                let name = "Subquery".to_string();
+                let name = if *lateral {
+                    "LateralSubquery".to_string()
+                } else {
+                    "Subquery".to_string()
+                };
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(name, 1, Some(format!("{}", alias)))
                 } else {
@@ -2308,6 +3078,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
                 named_params,
@@ -2327,7 +3098,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     );
                     children.push(node);
                 }
-                let func_name = format!("TableFunction {}", name);
+                let func_name = if *lateral {
+                    format!("LateralTableFunction {}", name)
+                } else {
+                    format!("TableFunction {}", name)
+                };
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(
                         func_name,
@@ -2353,6 +3128,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 location,
                 options,
                 alias,
+                sample,
             } => {
                 let mut children = Vec::new();
                 if let Some(files) = &options.files {
@@ -2364,7 +3140,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     let pattern = format!("pattern = {}", pattern);
                     children.push(FormatTreeNode::new(AstFormatContext::new(pattern)))
                 }
-                let stage_name = format!("Stage {:?}", location);
+                let mut stage_name = format!("Stage {:?}", location);
+                if let Some(sample) = sample {
+                    stage_name.push(' ');
+                    stage_name.push_str(&sample.to_string());
+                }
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(
                         stage_name,
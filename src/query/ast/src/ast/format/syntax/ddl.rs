@@ -17,6 +17,7 @@ use pretty::RcDoc;
 use super::expr::pretty_expr;
 use super::query::pretty_query;
 use super::query::pretty_table;
+use crate::ast::format::syntax::inline_comma;
 use crate::ast::format::syntax::interweave_comma;
 use crate::ast::format::syntax::parenthenized;
 use crate::ast::format::syntax::NEST_FACTOR;
@@ -154,12 +155,26 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::RenameTable { new_table } => RcDoc::line()
             .append(RcDoc::text("RENAME TO "))
             .append(RcDoc::text(new_table.to_string())),
-        AlterTableAction::AddColumn { column } => RcDoc::line()
+        AlterTableAction::AddColumn { column, option } => RcDoc::line()
             .append(RcDoc::text("ADD COLUMN "))
-            .append(RcDoc::text(column.to_string())),
+            .append(RcDoc::text(column.to_string()))
+            .append(RcDoc::text(option.to_string())),
         AlterTableAction::DropColumn { column } => RcDoc::line()
             .append(RcDoc::text("DROP COLUMN "))
             .append(RcDoc::text(column.to_string())),
+        AlterTableAction::RenameColumn {
+            old_column,
+            new_column,
+        } => RcDoc::line()
+            .append(RcDoc::text("RENAME COLUMN "))
+            .append(RcDoc::text(old_column.to_string()))
+            .append(RcDoc::text(" TO "))
+            .append(RcDoc::text(new_column.to_string())),
+        AlterTableAction::ModifyColumn { column, action } => RcDoc::line()
+            .append(RcDoc::text("MODIFY COLUMN "))
+            .append(RcDoc::text(column.to_string()))
+            .append(RcDoc::space())
+            .append(RcDoc::text(action.to_string())),
         AlterTableAction::AlterTableClusterKey { cluster_by } => RcDoc::line()
             .append(RcDoc::text("CLUSTER BY "))
             .append(parenthenized(
@@ -171,6 +186,7 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::ReclusterTable {
             is_final,
             selection,
+            limit,
         } => RcDoc::line()
             .append(RcDoc::text("RECLUSTER"))
             .append(if is_final {
@@ -186,11 +202,36 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
                 )
             } else {
                 RcDoc::nil()
+            })
+            .append(if let Some(limit) = limit {
+                RcDoc::line().append(RcDoc::text(format!("LIMIT {limit}")))
+            } else {
+                RcDoc::nil()
             }),
         AlterTableAction::RevertTo { point } => match point {
             TimeTravelPoint::Snapshot(sid) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
             TimeTravelPoint::Timestamp(ts) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
         },
+        AlterTableAction::SetAnalyzeInterval { interval_secs } => RcDoc::line().append(
+            RcDoc::text(format!("SET ANALYZE_INTERVAL = {interval_secs}")),
+        ),
+        AlterTableAction::AddRowAccessPolicy { policy, columns } => RcDoc::line()
+            .append(RcDoc::text(format!("ADD ROW ACCESS POLICY {policy} ON (")))
+            .append(inline_comma(
+                columns
+                    .into_iter()
+                    .map(|column| RcDoc::text(column.to_string())),
+            ))
+            .append(RcDoc::text(")")),
+        AlterTableAction::DropRowAccessPolicy { policy } => {
+            RcDoc::line().append(RcDoc::text(format!("DROP ROW ACCESS POLICY {policy}")))
+        }
+        AlterTableAction::AddBloomFilterIndex => {
+            RcDoc::line().append(RcDoc::text("ADD BLOOM FILTER INDEX"))
+        }
+        action @ AlterTableAction::SetConnection { .. } => {
+            RcDoc::line().append(RcDoc::text(action.to_string()))
+        }
     }
 }
 
@@ -221,6 +221,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(RcDoc::text(")")),
         Expr::Literal { lit, .. } => RcDoc::text(lit.to_string()),
         Expr::CountAll { .. } => RcDoc::text("COUNT(*)"),
+        Expr::Variable { name, .. } => RcDoc::text(format!("@{name}")),
         Expr::Tuple { exprs, .. } => RcDoc::text("(")
             .append(inline_comma(exprs.into_iter().map(pretty_expr)))
             .append(RcDoc::text(")")),
@@ -229,6 +230,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             name,
             args,
             params,
+            lambda,
             ..
         } => RcDoc::text(name.to_string())
             .append(if !params.is_empty() {
@@ -249,6 +251,60 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(inline_comma(args.into_iter().map(pretty_expr)))
+            .append(if let Some(lambda) = lambda {
+                RcDoc::text(",")
+                    .append(RcDoc::space())
+                    .append(RcDoc::text(lambda.to_string()))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(")")),
+        Expr::ListAgg {
+            distinct,
+            expr,
+            delimiter,
+            on_overflow,
+            within_group,
+            ..
+        } => RcDoc::text("LISTAGG(")
+            .append(if distinct {
+                RcDoc::text("DISTINCT").append(RcDoc::space())
+            } else {
+                RcDoc::nil()
+            })
+            .append(pretty_expr(*expr))
+            .append(if let Some(delimiter) = delimiter {
+                RcDoc::text(",")
+                    .append(RcDoc::space())
+                    .append(RcDoc::text(format!("'{delimiter}'")))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(on_overflow) = on_overflow {
+                RcDoc::space().append(RcDoc::text(on_overflow.to_string()))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(")"))
+            .append(if let Some(order_by) = within_group {
+                RcDoc::space()
+                    .append(RcDoc::text("WITHIN GROUP (ORDER BY"))
+                    .append(RcDoc::space())
+                    .append(RcDoc::text(order_by.to_string()))
+                    .append(RcDoc::text(")"))
+            } else {
+                RcDoc::nil()
+            }),
+        Expr::Percentile {
+            kind,
+            level,
+            within_group,
+            ..
+        } => RcDoc::text(format!("{kind}({level})"))
+            .append(RcDoc::space())
+            .append(RcDoc::text("WITHIN GROUP (ORDER BY"))
+            .append(RcDoc::space())
+            .append(RcDoc::text(within_group.to_string()))
             .append(RcDoc::text(")")),
         Expr::Case {
             operand,
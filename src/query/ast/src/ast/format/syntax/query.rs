@@ -20,6 +20,7 @@ use crate::ast::format::syntax::inline_dot;
 use crate::ast::format::syntax::interweave_comma;
 use crate::ast::format::syntax::parenthenized;
 use crate::ast::format::syntax::NEST_FACTOR;
+use crate::ast::ConnectBy;
 use crate::ast::Expr;
 use crate::ast::GroupBy;
 use crate::ast::JoinCondition;
@@ -64,9 +65,14 @@ fn pretty_with(with: Option<With>) -> RcDoc<'static> {
 }
 
 fn pretty_cte(cte: CTE) -> RcDoc<'static> {
+    let materialized = match cte.materialized {
+        Some(true) => RcDoc::text("MATERIALIZED").append(RcDoc::space()),
+        Some(false) => RcDoc::text("NOT MATERIALIZED").append(RcDoc::space()),
+        None => RcDoc::nil(),
+    };
     RcDoc::text(format!("{} AS", cte.alias))
         .append(RcDoc::softline())
-        .append(parenthenized(pretty_query(cte.query)))
+        .append(parenthenized(materialized.append(pretty_query(cte.query))))
 }
 
 fn pretty_body(body: SetExpr) -> RcDoc<'static> {
@@ -79,6 +85,7 @@ fn pretty_body(body: SetExpr) -> RcDoc<'static> {
         .append(pretty_select_list(select_stmt.select_list))
         .append(pretty_from(select_stmt.from))
         .append(pretty_selection(select_stmt.selection))
+        .append(pretty_connect_by(select_stmt.connect_by))
         .append(pretty_group_by(select_stmt.group_by))
         .append(pretty_having(select_stmt.having)),
         SetExpr::Query(query) => parenthenized(pretty_query(*query)),
@@ -195,6 +202,30 @@ fn pretty_selection(selection: Option<Expr>) -> RcDoc<'static> {
     }
 }
 
+fn pretty_connect_by(connect_by: Option<ConnectBy>) -> RcDoc<'static> {
+    if let Some(connect_by) = connect_by {
+        let start_with = if let Some(start_with) = connect_by.start_with {
+            RcDoc::line().append(RcDoc::text("START WITH")).append(
+                RcDoc::line()
+                    .nest(NEST_FACTOR)
+                    .append(pretty_expr(start_with).nest(NEST_FACTOR).group()),
+            )
+        } else {
+            RcDoc::nil()
+        };
+        start_with
+            .append(RcDoc::line())
+            .append(RcDoc::text("CONNECT BY"))
+            .append(
+                RcDoc::line()
+                    .nest(NEST_FACTOR)
+                    .append(pretty_expr(connect_by.condition).nest(NEST_FACTOR).group()),
+            )
+    } else {
+        RcDoc::nil()
+    }
+}
+
 fn pretty_group_set(set: Vec<Expr>) -> RcDoc<'static> {
     RcDoc::nil()
         .append(RcDoc::text("("))
@@ -275,8 +306,10 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             table,
             alias,
             travel_point,
+            sample,
             pivot,
             unpivot,
+            match_recognize,
         } => if let Some(catalog) = catalog {
             RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
         } else {
@@ -305,22 +338,36 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
+        .append(if let Some(match_recognize) = match_recognize {
+            RcDoc::text(format!(" {match_recognize}"))
+        } else {
+            RcDoc::nil()
+        })
         .append(if let Some(alias) = alias {
             RcDoc::text(format!(" AS {alias}"))
         } else {
             RcDoc::nil()
+        })
+        .append(if let Some(sample) = sample {
+            RcDoc::text(format!(" {sample}"))
+        } else {
+            RcDoc::nil()
         }),
         TableReference::Subquery {
             span: _,
+            lateral,
             subquery,
             alias,
-        } => parenthenized(pretty_query(*subquery)).append(if let Some(alias) = alias {
-            RcDoc::text(format!(" AS {alias}"))
-        } else {
-            RcDoc::nil()
-        }),
+        } => RcDoc::text(if lateral { "LATERAL " } else { "" })
+            .append(parenthenized(pretty_query(*subquery)))
+            .append(if let Some(alias) = alias {
+                RcDoc::text(format!(" AS {alias}"))
+            } else {
+                RcDoc::nil()
+            }),
         TableReference::TableFunction {
             span: _,
+            lateral,
             name,
             params,
             named_params,
@@ -331,7 +378,8 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             };
-            RcDoc::text(name.to_string())
+            RcDoc::text(if lateral { "LATERAL " } else { "" })
+                .append(RcDoc::text(name.to_string()))
                 .append(RcDoc::text("("))
                 .append(inline_comma(params.into_iter().map(pretty_expr)))
                 .append(separator)
@@ -384,12 +432,18 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             location,
             options,
             alias,
+            sample,
         } => RcDoc::text(location.to_string())
             .append(options.to_string())
             .append(if let Some(a) = alias {
                 RcDoc::text(format!(" AS {a}"))
             } else {
                 RcDoc::nil()
+            })
+            .append(if let Some(sample) = sample {
+                RcDoc::text(format!(" {sample}"))
+            } else {
+                RcDoc::nil()
             }),
     }
 }
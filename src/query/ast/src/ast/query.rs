@@ -56,6 +56,9 @@ pub struct With {
 pub struct CTE {
     pub span: Span,
     pub alias: TableAlias,
+    /// `Some(true)` for `MATERIALIZED`, `Some(false)` for `NOT MATERIALIZED`, and `None`
+    /// if it's left to the planner.
+    pub materialized: Option<bool>,
     pub query: Query,
 }
 
@@ -85,6 +88,18 @@ pub struct SelectStmt {
     pub group_by: Option<GroupBy>,
     // `HAVING` clause
     pub having: Option<Expr>,
+    // `START WITH ... CONNECT BY ...` clause
+    pub connect_by: Option<ConnectBy>,
+}
+
+/// Hierarchical query clause: `[START WITH condition] CONNECT BY condition`.
+///
+/// The `CONNECT BY` condition refers to the columns of the parent row with `PRIOR`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectBy {
+    pub span: Span,
+    pub start_with: Option<Expr>,
+    pub condition: Expr,
 }
 
 /// Group by Clause.
@@ -184,6 +199,23 @@ pub enum TimeTravelPoint {
     Timestamp(Box<Expr>),
 }
 
+/// `TABLESAMPLE <method> (<percent>) [REPEATABLE (<seed>)]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableSample {
+    pub method: SampleMethod,
+    /// Percentage of the blocks (`SYSTEM`) or rows (`BERNOULLI`) to keep.
+    pub percent: f64,
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleMethod {
+    /// Keep whole blocks, decided before they are read.
+    System,
+    /// Keep each row independently, decided after the rows are read.
+    Bernoulli,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Pivot {
     pub aggregate: Expr,
@@ -198,6 +230,59 @@ pub struct Unpivot {
     pub names: Vec<Identifier>,
 }
 
+/// `MATCH_RECOGNIZE` detects row patterns in the ordered rows of each partition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchRecognize {
+    pub partition_by: Vec<Expr>,
+    pub order_by: Vec<OrderByExpr>,
+    pub measures: Vec<MatchMeasure>,
+    pub rows_per_match: RowsPerMatch,
+    pub pattern: MatchPattern,
+    pub definitions: Vec<MatchDefinition>,
+}
+
+/// `<expr> AS <alias>` in `MEASURES`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchMeasure {
+    pub expr: Expr,
+    pub alias: Identifier,
+}
+
+/// `<variable> AS <predicate>` in `DEFINE`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchDefinition {
+    pub variable: Identifier,
+    pub predicate: Expr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowsPerMatch {
+    OneRow,
+    AllRows,
+}
+
+/// Row pattern of `PATTERN (...)`, a regular expression over pattern variables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchPattern {
+    Variable(Identifier),
+    Concat(Vec<MatchPattern>),
+    Alternation(Vec<MatchPattern>),
+    Quantified {
+        pattern: Box<MatchPattern>,
+        quantifier: PatternQuantifier,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternQuantifier {
+    /// `*`
+    ZeroOrMore,
+    /// `+`
+    OneOrMore,
+    /// `?`
+    ZeroOrOne,
+}
+
 /// A table name or a parenthesized subquery with an optional alias
 #[derive(Debug, Clone, PartialEq)]
 pub enum TableReference {
@@ -209,12 +294,16 @@ pub enum TableReference {
         table: Identifier,
         alias: Option<TableAlias>,
         travel_point: Option<TimeTravelPoint>,
+        sample: Option<TableSample>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        match_recognize: Option<Box<MatchRecognize>>,
     },
-    // `TABLE(expr)[ AS alias ]`
+    // `[LATERAL] TABLE(expr)[ AS alias ]`
     TableFunction {
         span: Span,
+        // Whether the table function can reference columns of the preceding tables
+        lateral: bool,
         name: Identifier,
         params: Vec<Expr>,
         named_params: Vec<(String, Expr)>,
@@ -223,6 +312,8 @@ pub enum TableReference {
     // Derived table, which can be a subquery or joined tables or combination of them
     Subquery {
         span: Span,
+        // Whether the subquery can reference columns of the preceding tables
+        lateral: bool,
         subquery: Box<Query>,
         alias: Option<TableAlias>,
     },
@@ -235,6 +326,7 @@ pub enum TableReference {
         location: FileLocation,
         options: SelectStageOptions,
         alias: Option<TableAlias>,
+        sample: Option<TableSample>,
     },
 }
 
@@ -252,6 +344,15 @@ impl TableReference {
             _ => None,
         }
     }
+
+    pub fn match_recognize(&self) -> Option<&MatchRecognize> {
+        match self {
+            TableReference::Table {
+                match_recognize, ..
+            } => match_recognize.as_ref().map(|b| b.as_ref()),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -334,6 +435,34 @@ impl Display for TableAlias {
     }
 }
 
+impl Display for ConnectBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(start_with) = &self.start_with {
+            write!(f, "START WITH {start_with} ")?;
+        }
+        write!(f, "CONNECT BY {}", self.condition)
+    }
+}
+
+impl Display for TableSample {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TABLESAMPLE {} ({})", self.method, self.percent)?;
+        if let Some(seed) = self.seed {
+            write!(f, " REPEATABLE ({seed})")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for SampleMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SampleMethod::System => write!(f, "SYSTEM"),
+            SampleMethod::Bernoulli => write!(f, "BERNOULLI"),
+        }
+    }
+}
+
 impl Display for Pivot {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "PIVOT({} FOR {} IN (", self.aggregate, self.value_column)?;
@@ -356,6 +485,103 @@ impl Display for Unpivot {
     }
 }
 
+impl Display for MatchRecognize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MATCH_RECOGNIZE(")?;
+        if !self.partition_by.is_empty() {
+            write!(f, "PARTITION BY ")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+            write!(f, " ")?;
+        }
+        if !self.order_by.is_empty() {
+            write!(f, "ORDER BY ")?;
+            write_comma_separated_list(f, &self.order_by)?;
+            write!(f, " ")?;
+        }
+        if !self.measures.is_empty() {
+            write!(f, "MEASURES ")?;
+            write_comma_separated_list(f, &self.measures)?;
+            write!(f, " ")?;
+        }
+        write!(f, "{} ", self.rows_per_match)?;
+        write!(f, "PATTERN ({}) ", self.pattern)?;
+        write!(f, "DEFINE ")?;
+        write_comma_separated_list(f, &self.definitions)?;
+        write!(f, ")")
+    }
+}
+
+impl Display for MatchMeasure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} AS {}", self.expr, self.alias)
+    }
+}
+
+impl Display for MatchDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} AS {}", self.variable, self.predicate)
+    }
+}
+
+impl Display for RowsPerMatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RowsPerMatch::OneRow => write!(f, "ONE ROW PER MATCH"),
+            RowsPerMatch::AllRows => write!(f, "ALL ROWS PER MATCH"),
+        }
+    }
+}
+
+impl Display for MatchPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchPattern::Variable(variable) => write!(f, "{variable}"),
+            MatchPattern::Concat(patterns) => {
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    if let MatchPattern::Alternation(_) = pattern {
+                        write!(f, "({pattern})")?;
+                    } else {
+                        write!(f, "{pattern}")?;
+                    }
+                }
+                Ok(())
+            }
+            MatchPattern::Alternation(patterns) => {
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{pattern}")?;
+                }
+                Ok(())
+            }
+            MatchPattern::Quantified {
+                pattern,
+                quantifier,
+            } => {
+                if let MatchPattern::Variable(_) = pattern.as_ref() {
+                    write!(f, "{pattern}{quantifier}")
+                } else {
+                    write!(f, "({pattern}){quantifier}")
+                }
+            }
+        }
+    }
+}
+
+impl Display for PatternQuantifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PatternQuantifier::ZeroOrMore => write!(f, "*"),
+            PatternQuantifier::OneOrMore => write!(f, "+"),
+            PatternQuantifier::ZeroOrOne => write!(f, "?"),
+        }
+    }
+}
+
 impl Display for TableReference {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -366,8 +592,10 @@ impl Display for TableReference {
                 table,
                 alias,
                 travel_point,
+                sample,
                 pivot,
                 unpivot,
+                match_recognize,
             } => {
                 write_period_separated_list(
                     f,
@@ -382,9 +610,16 @@ impl Display for TableReference {
                     write!(f, " AT (TIMESTAMP => {ts})")?;
                 }
 
+                if let Some(match_recognize) = match_recognize {
+                    write!(f, " {match_recognize}")?;
+                }
+
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
+                if let Some(sample) = sample {
+                    write!(f, " {sample}")?;
+                }
                 if let Some(pivot) = pivot {
                     write!(f, " {pivot}")?;
                 }
@@ -395,11 +630,15 @@ impl Display for TableReference {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
                 named_params,
                 alias,
             } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
                 write!(f, "{name}(")?;
                 write_comma_separated_list(f, params)?;
                 if !params.is_empty() && !named_params.is_empty() {
@@ -418,9 +657,13 @@ impl Display for TableReference {
             }
             TableReference::Subquery {
                 span: _,
+                lateral,
                 subquery,
                 alias,
             } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
                 write!(f, "({subquery})")?;
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
@@ -478,12 +721,16 @@ impl Display for TableReference {
                 location,
                 options,
                 alias,
+                sample,
             } => {
                 write!(f, "{location}")?;
                 write!(f, "{options}")?;
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
+                if let Some(sample) = sample {
+                    write!(f, " {sample}")?;
+                }
             }
         }
         Ok(())
@@ -549,6 +796,11 @@ impl Display for SelectStmt {
             write!(f, " WHERE {expr}")?;
         }
 
+        // START WITH ... CONNECT BY ... clause
+        if let Some(connect_by) = &self.connect_by {
+            write!(f, " {connect_by}")?;
+        }
+
         // GROUP BY clause
         if self.group_by.is_some() {
             write!(f, " GROUP BY ")?;
@@ -624,7 +876,13 @@ impl Display for SetExpr {
 
 impl Display for CTE {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} AS ({})", self.alias, self.query)?;
+        write!(f, "{} AS (", self.alias)?;
+        match self.materialized {
+            Some(true) => write!(f, "MATERIALIZED ")?,
+            Some(false) => write!(f, "NOT MATERIALIZED ")?,
+            None => {}
+        }
+        write!(f, "{})", self.query)?;
         Ok(())
     }
 }
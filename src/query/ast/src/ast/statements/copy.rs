@@ -22,6 +22,7 @@ use std::io::Result;
 
 use url::Url;
 
+use crate::ast::write_period_separated_list;
 use crate::ast::write_quoted_comma_separated_list;
 use crate::ast::write_space_separated_map;
 use crate::ast::Identifier;
@@ -95,7 +96,7 @@ impl Display for CopyStmt {
         }
 
         if !self.validation_mode.is_empty() {
-            write!(f, "VALIDATION_MODE = {}", self.validation_mode)?;
+            write!(f, " VALIDATION_MODE = {}", self.validation_mode)?;
         }
 
         if self.size_limit != 0 {
@@ -386,3 +387,29 @@ pub enum CopyOption {
     Force(bool),
     OnError(String),
 }
+
+/// `SHOW COPY HISTORY FOR TABLE [<database>.]<table> [SINCE => '<timestamp>']`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShowCopyHistoryStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub since: Option<String>,
+}
+
+impl Display for ShowCopyHistoryStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SHOW COPY HISTORY FOR TABLE ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        if let Some(since) = &self.since {
+            write!(f, " SINCE => '{since}'")?;
+        }
+        Ok(())
+    }
+}
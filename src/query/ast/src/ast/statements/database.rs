@@ -145,6 +145,9 @@ impl Display for AlterDatabaseStmt {
             AlterDatabaseAction::RenameDatabase { new_db } => {
                 write!(f, " RENAME TO {new_db}")?;
             }
+            AlterDatabaseAction::SetQuota { quota } => {
+                write!(f, " SET QUOTA ({quota})")?;
+            }
         }
 
         Ok(())
@@ -154,6 +157,36 @@ impl Display for AlterDatabaseStmt {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AlterDatabaseAction {
     RenameDatabase { new_db: Identifier },
+    SetQuota { quota: DatabaseQuotaOptions },
+}
+
+/// Quota limits to set on a database; a limit that is not specified is left unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DatabaseQuotaOptions {
+    pub max_tables: Option<u64>,
+    pub max_bytes: Option<u64>,
+    pub max_rows: Option<u64>,
+}
+
+impl Display for DatabaseQuotaOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let options = [
+            ("MAX_TABLES", self.max_tables),
+            ("MAX_BYTES", self.max_bytes),
+            ("MAX_ROWS", self.max_rows),
+        ];
+        let mut first = true;
+        for (name, value) in options {
+            if let Some(value) = value {
+                if !first {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{name} = {value}")?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
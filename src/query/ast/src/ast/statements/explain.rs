@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ExplainKind {
     Ast(String),
@@ -20,13 +23,33 @@ pub enum ExplainKind {
     // don't want to expose `Memo` to other crates.
     Memo(String),
     Graph,
-    Pipeline,
+    Pipeline(ExplainFormat),
     Fragments,
     Raw,
-    Plan,
+    Plan(ExplainFormat),
 
     JOIN,
 
     // Explain analyze plan
     AnalyzePlan,
 }
+
+/// Output format of `EXPLAIN (FORMAT <format>)` and `EXPLAIN PIPELINE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplainFormat {
+    Text,
+    Mermaid,
+    Dot,
+    Json,
+}
+
+impl Display for ExplainFormat {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ExplainFormat::Text => write!(f, "TEXT"),
+            ExplainFormat::Mermaid => write!(f, "MERMAID"),
+            ExplainFormat::Dot => write!(f, "DOT"),
+            ExplainFormat::Json => write!(f, "JSON"),
+        }
+    }
+}
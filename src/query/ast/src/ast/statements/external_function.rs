@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::Identifier;
+use crate::ast::TypeName;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateExternalFunctionStmt {
+    pub if_not_exists: bool,
+    pub name: Identifier,
+    pub arguments: Vec<(Identifier, TypeName)>,
+    pub return_type: TypeName,
+    pub language: Identifier,
+    pub handler: String,
+    pub address: String,
+    pub headers: BTreeMap<String, String>,
+    /// `PROTOCOL`, `CONNECTION_TIMEOUT_MS`, `REQUEST_TIMEOUT_MS`, `MAX_RETRIES` and `COMMENT`,
+    /// the keys are in lowercase.
+    pub options: BTreeMap<String, String>,
+}
+
+impl Display for CreateExternalFunctionStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE EXTERNAL FUNCTION ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}(", self.name)?;
+        for (i, (name, data_type)) in self.arguments.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{name} {data_type}")?;
+        }
+        write!(
+            f,
+            ") RETURNS {} LANGUAGE {} HANDLER = '{}' ADDRESS = '{}'",
+            self.return_type, self.language, self.handler, self.address
+        )?;
+        if !self.headers.is_empty() {
+            write!(f, " HEADERS = (")?;
+            for (i, (k, v)) in self.headers.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "'{k}' = '{v}'")?;
+            }
+            write!(f, ")")?;
+        }
+        for (k, v) in self.options.iter() {
+            write!(f, " {} = '{v}'", k.to_uppercase())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropExternalFunctionStmt {
+    pub if_exists: bool,
+    pub name: Identifier,
+}
+
+impl Display for DropExternalFunctionStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP EXTERNAL FUNCTION ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
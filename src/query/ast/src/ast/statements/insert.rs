@@ -18,6 +18,7 @@ use std::fmt::Formatter;
 
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_period_separated_list;
+use crate::ast::Expr;
 use crate::ast::Identifier;
 use crate::ast::Query;
 
@@ -94,3 +95,89 @@ impl Display for InsertSource {
         }
     }
 }
+
+/// `INSERT { ALL | FIRST } WHEN <condition> THEN INTO ... [ ELSE INTO ... ] <query>`
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertMultiTableStmt {
+    pub kind: InsertMultiTableKind,
+    pub when_clauses: Vec<InsertWhenClause>,
+    pub else_clause: Option<Vec<InsertIntoClause>>,
+    pub source: Box<Query>,
+}
+
+impl Display for InsertMultiTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "INSERT {}", self.kind)?;
+        for when_clause in &self.when_clauses {
+            write!(f, " WHEN {} THEN", when_clause.condition)?;
+            for into_clause in &when_clause.into_clauses {
+                write!(f, " {into_clause}")?;
+            }
+        }
+        if let Some(else_clause) = &self.else_clause {
+            write!(f, " ELSE")?;
+            for into_clause in else_clause {
+                write!(f, " {into_clause}")?;
+            }
+        }
+        write!(f, " {}", self.source)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertMultiTableKind {
+    /// Insert a row into the targets of all the matching `WHEN` clauses.
+    All,
+    /// Insert a row into the targets of the first matching `WHEN` clause.
+    First,
+}
+
+impl Display for InsertMultiTableKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            InsertMultiTableKind::All => write!(f, "ALL"),
+            InsertMultiTableKind::First => write!(f, "FIRST"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertWhenClause {
+    pub condition: Expr,
+    pub into_clauses: Vec<InsertIntoClause>,
+}
+
+/// `INTO <table> [ ( <column>, ... ) ] [ VALUES ( <expr>, ... ) ]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertIntoClause {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub columns: Vec<Identifier>,
+    /// Empty if the columns of the source are inserted as they are.
+    pub values: Vec<Expr>,
+}
+
+impl Display for InsertIntoClause {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "INTO ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        if !self.columns.is_empty() {
+            write!(f, " (")?;
+            write_comma_separated_list(f, &self.columns)?;
+            write!(f, ")")?;
+        }
+        if !self.values.is_empty() {
+            write!(f, " VALUES (")?;
+            write_comma_separated_list(f, &self.values)?;
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
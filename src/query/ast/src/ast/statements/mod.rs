@@ -18,15 +18,23 @@ mod columns;
 mod copy;
 mod database;
 mod explain;
+mod external_function;
 mod insert;
 mod kill;
+mod masking_policy;
+mod network_policy;
+mod pipe;
 mod presign;
 mod replace;
+mod row_access_policy;
+mod sequence;
 mod share;
 mod show;
 mod stage;
 mod statement;
+mod stream;
 mod table;
+mod task;
 mod unset;
 mod update;
 mod user;
@@ -38,15 +46,23 @@ pub use columns::*;
 pub use copy::*;
 pub use database::*;
 pub use explain::*;
+pub use external_function::*;
 pub use insert::*;
 pub use kill::*;
+pub use masking_policy::*;
+pub use network_policy::*;
+pub use pipe::*;
 pub use presign::*;
 pub use replace::*;
+pub use row_access_policy::*;
+pub use sequence::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
+pub use stream::*;
 pub use table::*;
+pub use task::*;
 pub use unset::*;
 pub use update::*;
 pub use user::*;
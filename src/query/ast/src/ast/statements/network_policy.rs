@@ -0,0 +1,75 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_quoted_comma_separated_list;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateNetworkPolicyStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub allowed_ip_list: Vec<String>,
+    pub blocked_ip_list: Vec<String>,
+    pub comment: Option<String>,
+}
+
+impl Display for CreateNetworkPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE NETWORK POLICY ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{} ALLOWED_IP_LIST = (", self.name)?;
+        write_quoted_comma_separated_list(f, &self.allowed_ip_list)?;
+        write!(f, ")")?;
+        if !self.blocked_ip_list.is_empty() {
+            write!(f, " BLOCKED_IP_LIST = (")?;
+            write_quoted_comma_separated_list(f, &self.blocked_ip_list)?;
+            write!(f, ")")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropNetworkPolicyStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropNetworkPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP NETWORK POLICY ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DescNetworkPolicyStmt {
+    pub name: String,
+}
+
+impl Display for DescNetworkPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DESCRIBE NETWORK POLICY {}", self.name)
+    }
+}
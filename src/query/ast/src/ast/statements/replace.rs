@@ -40,17 +40,14 @@ impl Display for ReplaceStmt {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
-        if !self.columns.is_empty() {
-            write!(f, " (")?;
-            write_comma_separated_list(f, &self.columns)?;
-            write!(f, ") ")?;
-        }
-        write!(f, "ON CONFLICT ")?;
         if !self.columns.is_empty() {
             write!(f, " (")?;
             write_comma_separated_list(f, &self.columns)?;
             write!(f, ")")?;
         }
+        write!(f, " ON CONFLICT (")?;
+        write_comma_separated_list(f, &self.on_conflict_columns)?;
+        write!(f, ")")?;
 
         write!(f, " {}", self.source)
     }
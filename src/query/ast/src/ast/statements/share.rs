@@ -136,6 +136,8 @@ pub struct AlterShareTenantsStmt {
     pub if_exists: bool,
     pub tenants: Vec<Identifier>,
     pub is_add: bool,
+    // restrict the added tenants to read-only access
+    pub read_only: bool,
 }
 
 impl Display for AlterShareTenantsStmt {
@@ -155,6 +157,28 @@ impl Display for AlterShareTenantsStmt {
             "{}",
             self.tenants.iter().map(|v| v.to_string()).join(",")
         )?;
+        if self.read_only {
+            write!(f, " SHARE_RESTRICTIONS = (READ_ONLY = true)")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterShareCommentStmt {
+    pub share: Identifier,
+    pub if_exists: bool,
+    pub comment: String,
+}
+
+impl Display for AlterShareCommentStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER SHARE ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} SET COMMENT = '{}'", self.share, self.comment)?;
 
         Ok(())
     }
@@ -174,11 +198,16 @@ impl Display for DescShareStmt {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ShowSharesStmt {}
+pub struct ShowSharesStmt {
+    pub tenant: Option<String>,
+}
 
 impl Display for ShowSharesStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "SHOW SHARES")?;
+        if let Some(tenant) = &self.tenant {
+            write!(f, " OF TENANT '{tenant}'")?;
+        }
 
         Ok(())
     }
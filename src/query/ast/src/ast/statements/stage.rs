@@ -17,6 +17,7 @@ use std::default::Default;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use crate::ast::write_space_separated_map;
 use crate::ast::UriLocation;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -74,6 +75,49 @@ impl Display for CreateStageStmt {
     }
 }
 
+/// `ALTER STAGE <name> SET ...`, only the given properties are changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterStageStmt {
+    pub stage_name: String,
+
+    pub location: Option<UriLocation>,
+    pub connection: BTreeMap<String, String>,
+    pub file_format_options: BTreeMap<String, String>,
+    pub copy_options: BTreeMap<String, String>,
+}
+
+impl Display for AlterStageStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ALTER STAGE {} SET", self.stage_name)?;
+
+        if let Some(ul) = &self.location {
+            write!(f, " URL = {ul}")?;
+        }
+
+        if !self.connection.is_empty() {
+            write!(f, " CONNECTION = ( ")?;
+            write_space_separated_map(f, &self.connection)?;
+            write!(f, " )")?;
+        }
+
+        if !self.file_format_options.is_empty() {
+            write!(f, " FILE_FORMAT = (")?;
+            for (k, v) in self.file_format_options.iter() {
+                write!(f, " {} = '{}'", k, v)?;
+            }
+            write!(f, " )")?;
+        }
+
+        if !self.copy_options.is_empty() {
+            write!(f, " COPY_OPTIONS = ( ")?;
+            write_space_separated_map(f, &self.copy_options)?;
+            write!(f, " )")?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum SelectStageOption {
     Files(Vec<String>),
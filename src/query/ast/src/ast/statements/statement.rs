@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
@@ -40,6 +41,7 @@ pub enum Statement {
     },
 
     Copy(CopyStmt),
+    ShowCopyHistory(ShowCopyHistoryStmt),
     Call(CallStmt),
 
     ShowSettings {
@@ -67,6 +69,18 @@ pub enum Statement {
     },
 
     UnSetVariable(UnSetStmt),
+    // `SET @var = <expr>`, user-defined variable of the session
+    SetUserVariable {
+        variable: String,
+        value: Box<Expr>,
+    },
+    // `RESET @var`
+    ResetUserVariable {
+        variable: String,
+    },
+    ShowVariables {
+        like: Option<String>,
+    },
 
     SetRole {
         is_default: bool,
@@ -74,6 +88,7 @@ pub enum Statement {
     },
 
     Insert(InsertStmt),
+    InsertMultiTable(InsertMultiTableStmt),
     Replace(ReplaceStmt),
 
     Delete {
@@ -106,6 +121,9 @@ pub enum Statement {
     DescribeTable(DescribeTableStmt),
     ShowTablesStatus(ShowTablesStatusStmt),
     CreateTable(CreateTableStmt),
+    AttachTable(AttachTableStmt),
+    ExportTableSnapshot(ExportTableSnapshotStmt),
+    ImportTableSnapshot(ImportTableSnapshotStmt),
     DropTable(DropTableStmt),
     UndropTable(UndropTableStmt),
     AlterTable(AlterTableStmt),
@@ -122,6 +140,11 @@ pub enum Statement {
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
 
+    // Streams
+    CreateStream(CreateStreamStmt),
+    DropStream(DropStreamStmt),
+    ShowStreams(ShowStreamsStmt),
+
     // User
     ShowUsers,
     CreateUser(CreateUserStmt),
@@ -131,6 +154,7 @@ pub enum Statement {
         user: UserIdentity,
     },
     ShowRoles,
+    ShowRoleHierarchy,
     CreateRole {
         if_not_exists: bool,
         role_name: String,
@@ -157,12 +181,59 @@ pub enum Statement {
         if_exists: bool,
         udf_name: Identifier,
     },
+    CreateExternalFunction(CreateExternalFunctionStmt),
+    DropExternalFunction(DropExternalFunctionStmt),
     AlterUDF {
         udf_name: Identifier,
         parameters: Vec<Identifier>,
         definition: Box<Expr>,
         description: Option<String>,
     },
+    DescribeFunction {
+        name: Identifier,
+    },
+
+    // Masking policies
+    CreateMaskingPolicy(CreateMaskingPolicyStmt),
+    DropMaskingPolicy(DropMaskingPolicyStmt),
+
+    // Row access policies
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt),
+    DropRowAccessPolicy(DropRowAccessPolicyStmt),
+    ShowRowAccessPolicies,
+
+    // Sequences
+    CreateSequence(CreateSequenceStmt),
+    DropSequence(DropSequenceStmt),
+    ShowSequences,
+
+    // Indexes
+    ShowIndexBuildStatus,
+
+    // Network policies
+    CreateNetworkPolicy(CreateNetworkPolicyStmt),
+    DropNetworkPolicy(DropNetworkPolicyStmt),
+    DescNetworkPolicy(DescNetworkPolicyStmt),
+    ShowNetworkPolicies,
+
+    // Tasks
+    CreateTask(CreateTaskStmt),
+    AlterTask(AlterTaskStmt),
+    DropTask(DropTaskStmt),
+    ExecuteTask(ExecuteTaskStmt),
+
+    // Pipes
+    CreatePipe(CreatePipeStmt),
+    DropPipe(DropPipeStmt),
+    ShowPipes,
+
+    // Result cache
+    DropResultCache,
+
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
 
     // Stages
     CreateStage(CreateStageStmt),
@@ -174,6 +245,14 @@ pub enum Statement {
     DescribeStage {
         stage_name: String,
     },
+    AlterStage(AlterStageStmt),
+    RenameStage {
+        stage_name: String,
+        new_stage_name: String,
+    },
+    ShowCreateStage {
+        stage_name: String,
+    },
     RemoveStage {
         location: String,
         pattern: String,
@@ -182,6 +261,11 @@ pub enum Statement {
         location: String,
         pattern: String,
     },
+    ShowStageFiles {
+        location: String,
+        pattern: String,
+        limit: Option<u64>,
+    },
 
     // UserDefinedFileFormat
     CreateFileFormat {
@@ -205,6 +289,7 @@ pub enum Statement {
     GrantShareObject(GrantShareObjectStmt),
     RevokeShareObject(RevokeShareObjectStmt),
     AlterShareTenants(AlterShareTenantsStmt),
+    AlterShareComment(AlterShareCommentStmt),
     DescShare(DescShareStmt),
     ShowShares(ShowSharesStmt),
     ShowObjectGrantPrivileges(ShowObjectGrantPrivilegesStmt),
@@ -239,11 +324,40 @@ impl Statement {
                 }
                 format!("{}", Statement::CreateStage(stage_clone))
             }
+            Statement::CreateTable(stmt) => {
+                let mut stmt_clone = stmt.clone();
+                if let Some(location) = &mut stmt_clone.uri_location {
+                    location.connection = location.connection.mask()
+                }
+                format!("{}", Statement::CreateTable(stmt_clone))
+            }
+            Statement::AlterTable(stmt) => {
+                let mut stmt_clone = stmt.clone();
+                if let AlterTableAction::SetConnection { connection } = &mut stmt_clone.action {
+                    *connection = mask_connection(connection);
+                }
+                format!("{}", Statement::AlterTable(stmt_clone))
+            }
+            Statement::AlterStage(stmt) => {
+                let mut stmt_clone = stmt.clone();
+                if let Some(location) = &mut stmt_clone.location {
+                    location.connection = location.connection.mask()
+                }
+                stmt_clone.connection = mask_connection(&stmt_clone.connection);
+                format!("{}", Statement::AlterStage(stmt_clone))
+            }
             _ => format!("{}", self),
         }
     }
 }
 
+fn mask_connection(connection: &BTreeMap<String, String>) -> BTreeMap<String, String> {
+    connection
+        .keys()
+        .map(|k| (k.clone(), "********".to_string()))
+        .collect()
+}
+
 impl Display for Statement {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -253,10 +367,12 @@ impl Display for Statement {
                     ExplainKind::Ast(_) => write!(f, " AST")?,
                     ExplainKind::Syntax(_) => write!(f, " SYNTAX")?,
                     ExplainKind::Graph => write!(f, " GRAPH")?,
-                    ExplainKind::Pipeline => write!(f, " PIPELINE")?,
+                    ExplainKind::Pipeline(ExplainFormat::Text) => write!(f, " PIPELINE")?,
+                    ExplainKind::Pipeline(format) => write!(f, " PIPELINE FORMAT = {format}")?,
                     ExplainKind::Fragments => write!(f, " FRAGMENTS")?,
                     ExplainKind::Raw => write!(f, " RAW")?,
-                    ExplainKind::Plan => (),
+                    ExplainKind::Plan(ExplainFormat::Text) => (),
+                    ExplainKind::Plan(format) => write!(f, " (FORMAT {format})")?,
                     ExplainKind::AnalyzePlan => write!(f, " ANALYZE")?,
                     ExplainKind::JOIN => write!(f, " JOIN")?,
                     ExplainKind::Memo(_) => write!(f, " MEMO")?,
@@ -268,6 +384,7 @@ impl Display for Statement {
             }
             Statement::Query(query) => write!(f, "{query}")?,
             Statement::Insert(insert) => write!(f, "{insert}")?,
+            Statement::InsertMultiTable(insert) => write!(f, "{insert}")?,
             Statement::Replace(replace) => write!(f, "{replace}")?,
             Statement::Delete {
                 table_reference,
@@ -281,6 +398,7 @@ impl Display for Statement {
             }
             Statement::Update(update) => write!(f, "{update}")?,
             Statement::Copy(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowCopyHistory(stmt) => write!(f, "{stmt}")?,
             Statement::ShowSettings { like } => {
                 write!(f, "SHOW SETTINGS")?;
                 if like.is_some() {
@@ -325,6 +443,16 @@ impl Display for Statement {
                 write!(f, "{variable} = {value}")?;
             }
             Statement::UnSetVariable(unset) => write!(f, "{unset}")?,
+            Statement::SetUserVariable { variable, value } => {
+                write!(f, "SET @{variable} = {value}")?;
+            }
+            Statement::ResetUserVariable { variable } => write!(f, "RESET @{variable}")?,
+            Statement::ShowVariables { like } => {
+                write!(f, "SHOW VARIABLES")?;
+                if let Some(like) = like {
+                    write!(f, " LIKE '{like}'")?;
+                }
+            }
             Statement::SetRole {
                 is_default,
                 role_name,
@@ -353,6 +481,9 @@ impl Display for Statement {
             Statement::DescribeTable(stmt) => write!(f, "{stmt}")?,
             Statement::ShowTablesStatus(stmt) => write!(f, "{stmt}")?,
             Statement::CreateTable(stmt) => write!(f, "{stmt}")?,
+            Statement::AttachTable(stmt) => write!(f, "{stmt}")?,
+            Statement::ExportTableSnapshot(stmt) => write!(f, "{stmt}")?,
+            Statement::ImportTableSnapshot(stmt) => write!(f, "{stmt}")?,
             Statement::DropTable(stmt) => write!(f, "{stmt}")?,
             Statement::UndropTable(stmt) => write!(f, "{stmt}")?,
             Statement::AlterTable(stmt) => write!(f, "{stmt}")?,
@@ -364,8 +495,12 @@ impl Display for Statement {
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStream(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowStreams(stmt) => write!(f, "{stmt}")?,
             Statement::ShowUsers => write!(f, "SHOW USERS")?,
             Statement::ShowRoles => write!(f, "SHOW ROLES")?,
+            Statement::ShowRoleHierarchy => write!(f, "SHOW ROLE HIERARCHY")?,
             Statement::CreateUser(stmt) => write!(f, "{stmt}")?,
             Statement::AlterUser(stmt) => write!(f, "{stmt}")?,
             Statement::DropUser { if_exists, user } => {
@@ -432,6 +567,8 @@ impl Display for Statement {
                 }
                 write!(f, " {udf_name}")?;
             }
+            Statement::CreateExternalFunction(stmt) => write!(f, "{stmt}")?,
+            Statement::DropExternalFunction(stmt) => write!(f, "{stmt}")?,
             Statement::AlterUDF {
                 udf_name,
                 parameters,
@@ -445,12 +582,50 @@ impl Display for Statement {
                     write!(f, " DESC = '{description}'")?;
                 }
             }
+            Statement::DescribeFunction { name } => write!(f, "DESCRIBE FUNCTION {name}")?,
+            Statement::CreateMaskingPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropMaskingPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowRowAccessPolicies => write!(f, "SHOW ROW ACCESS POLICIES")?,
+            Statement::CreateSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::DropSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowSequences => write!(f, "SHOW SEQUENCES")?,
+            Statement::ShowIndexBuildStatus => write!(f, "SHOW INDEX BUILD STATUS")?,
+            Statement::CreateNetworkPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropNetworkPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescNetworkPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowNetworkPolicies => write!(f, "SHOW NETWORK POLICIES")?,
+            Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterTask(stmt) => write!(f, "{stmt}")?,
+            Statement::DropTask(stmt) => write!(f, "{stmt}")?,
+            Statement::ExecuteTask(stmt) => write!(f, "{stmt}")?,
+            Statement::CreatePipe(stmt) => write!(f, "{stmt}")?,
+            Statement::DropPipe(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowPipes => write!(f, "SHOW PIPES")?,
+            Statement::DropResultCache => write!(f, "DROP RESULT CACHE")?,
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Abort => write!(f, "ABORT")?,
             Statement::ListStage { location, pattern } => {
                 write!(f, "LIST @{location}")?;
                 if !pattern.is_empty() {
                     write!(f, " PATTERN = '{pattern}'")?;
                 }
             }
+            Statement::ShowStageFiles {
+                location,
+                pattern,
+                limit,
+            } => {
+                write!(f, "SHOW STAGE FILES IN @{location}")?;
+                if !pattern.is_empty() {
+                    write!(f, " PATTERN = '{pattern}'")?;
+                }
+                if let Some(limit) = limit {
+                    write!(f, " LIMIT {limit}")?;
+                }
+            }
             Statement::ShowStages => write!(f, "SHOW STAGES")?,
             Statement::DropStage {
                 if_exists,
@@ -470,6 +645,14 @@ impl Display for Statement {
                 }
             }
             Statement::DescribeStage { stage_name } => write!(f, "DESC STAGE {stage_name}")?,
+            Statement::AlterStage(stmt) => write!(f, "{stmt}")?,
+            Statement::RenameStage {
+                stage_name,
+                new_stage_name,
+            } => write!(f, "RENAME STAGE {stage_name} TO {new_stage_name}")?,
+            Statement::ShowCreateStage { stage_name } => {
+                write!(f, "SHOW CREATE STAGE {stage_name}")?
+            }
             Statement::CreateFileFormat {
                 if_not_exists,
                 name,
@@ -500,6 +683,7 @@ impl Display for Statement {
             Statement::GrantShareObject(stmt) => write!(f, "{stmt}")?,
             Statement::RevokeShareObject(stmt) => write!(f, "{stmt}")?,
             Statement::AlterShareTenants(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterShareComment(stmt) => write!(f, "{stmt}")?,
             Statement::DescShare(stmt) => write!(f, "{stmt}")?,
             Statement::ShowShares(stmt) => write!(f, "{stmt}")?,
             Statement::ShowObjectGrantPrivileges(stmt) => write!(f, "{stmt}")?,
@@ -190,6 +190,78 @@ impl Display for CreateTableSource {
     }
 }
 
+/// `ATTACH TABLE <table> FROM '<location>' READ_ONLY`, creates a table reading the data of
+/// the fuse table stored at the location, without copying it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachTableStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub uri_location: UriLocation,
+}
+
+impl Display for AttachTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ATTACH TABLE ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " FROM {} READ_ONLY", self.uri_location)
+    }
+}
+
+/// `EXPORT SNAPSHOT TABLE <table> TO LOCATION = '@<stage>/<path>'`, copies the files of the
+/// current snapshot of a fuse table to the stage, along with a manifest of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportTableSnapshotStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub location: String,
+}
+
+impl Display for ExportTableSnapshotStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "EXPORT SNAPSHOT TABLE ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " TO LOCATION = '{}'", self.location)
+    }
+}
+
+/// `IMPORT SNAPSHOT TABLE <table> FROM LOCATION = '@<stage>/<path>'`, creates a table reading
+/// the snapshot exported to the stage, without copying it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportTableSnapshotStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub location: String,
+}
+
+impl Display for ImportTableSnapshotStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "IMPORT SNAPSHOT TABLE ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " FROM LOCATION = '{}'", self.location)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DescribeTableStmt {
     pub catalog: Option<Identifier>,
@@ -284,10 +356,19 @@ pub enum AlterTableAction {
     },
     AddColumn {
         column: ColumnDefinition,
+        option: AddColumnOption,
     },
     DropColumn {
         column: Identifier,
     },
+    RenameColumn {
+        old_column: Identifier,
+        new_column: Identifier,
+    },
+    ModifyColumn {
+        column: Identifier,
+        action: ModifyColumnAction,
+    },
     AlterTableClusterKey {
         cluster_by: Vec<Expr>,
     },
@@ -295,10 +376,27 @@ pub enum AlterTableAction {
     ReclusterTable {
         is_final: bool,
         selection: Option<Expr>,
+        limit: Option<u64>,
     },
     RevertTo {
         point: TimeTravelPoint,
     },
+    SetAnalyzeInterval {
+        interval_secs: u64,
+    },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier,
+    },
+    /// Builds the bloom filter index of the blocks already in the table.
+    AddBloomFilterIndex,
+    /// Replaces the credentials of a table created with its own storage location.
+    SetConnection {
+        connection: BTreeMap<String, String>,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -307,12 +405,21 @@ impl Display for AlterTableAction {
             AlterTableAction::RenameTable { new_table } => {
                 write!(f, "RENAME TO {new_table}")
             }
-            AlterTableAction::AddColumn { column } => {
-                write!(f, "ADD COLUMN {column}")
+            AlterTableAction::AddColumn { column, option } => {
+                write!(f, "ADD COLUMN {column}{option}")
             }
             AlterTableAction::DropColumn { column } => {
                 write!(f, "DROP COLUMN {column}")
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                write!(f, "RENAME COLUMN {old_column} TO {new_column}")
+            }
+            AlterTableAction::ModifyColumn { column, action } => {
+                write!(f, "MODIFY COLUMN {column} {action}")
+            }
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
                 write!(f, "CLUSTER BY ")?;
                 write_comma_separated_list(f, cluster_by)
@@ -323,6 +430,7 @@ impl Display for AlterTableAction {
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
+                limit,
             } => {
                 write!(f, "RECLUSTER")?;
                 if *is_final {
@@ -331,12 +439,90 @@ impl Display for AlterTableAction {
                 if let Some(conditions) = selection {
                     write!(f, " WHERE {conditions}")?;
                 }
+                if let Some(limit) = limit {
+                    write!(f, " LIMIT {limit}")?;
+                }
                 Ok(())
             }
             AlterTableAction::RevertTo { point } => {
                 write!(f, "REVERT TO {}", point)?;
                 Ok(())
             }
+            AlterTableAction::SetAnalyzeInterval { interval_secs } => {
+                write!(f, "SET ANALYZE_INTERVAL = {interval_secs}")
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")
+            }
+            AlterTableAction::AddBloomFilterIndex => {
+                write!(f, "ADD BLOOM FILTER INDEX")
+            }
+            AlterTableAction::SetConnection { connection } => {
+                write!(f, "CONNECTION = ( ")?;
+                write_space_separated_map(f, connection)?;
+                write!(f, " )")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModifyColumnAction {
+    SetDataType {
+        data_type: TypeName,
+        /// Rewrites the existing data with the expression, required by lossy conversions.
+        using: Option<Expr>,
+        /// Also drops the cluster key and bloom index depending on the column.
+        cascade: bool,
+    },
+    SetMaskingPolicy(Identifier),
+    UnsetMaskingPolicy,
+}
+
+impl Display for ModifyColumnAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ModifyColumnAction::SetDataType {
+                data_type,
+                using,
+                cascade,
+            } => {
+                write!(f, "{data_type}")?;
+                if let Some(using) = using {
+                    write!(f, " USING {using}")?;
+                }
+                if *cascade {
+                    write!(f, " CASCADE")?;
+                }
+                Ok(())
+            }
+            ModifyColumnAction::SetMaskingPolicy(policy) => {
+                write!(f, "SET MASKING POLICY {policy}")
+            }
+            ModifyColumnAction::UnsetMaskingPolicy => write!(f, "UNSET MASKING POLICY"),
+        }
+    }
+}
+
+/// The position of a column added by `ALTER TABLE ... ADD COLUMN`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddColumnOption {
+    First,
+    After(Identifier),
+    End,
+}
+
+impl Display for AddColumnOption {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AddColumnOption::First => write!(f, " FIRST"),
+            AddColumnOption::After(ident) => write!(f, " AFTER {ident}"),
+            AddColumnOption::End => Ok(()),
         }
     }
 }
@@ -0,0 +1,100 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::Statement;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateTaskStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub schedule: String,
+    pub comment: Option<String>,
+    pub sql: Box<Statement>,
+}
+
+impl Display for CreateTaskStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE TASK ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{} SCHEDULE = '{}'", self.name, self.schedule)?;
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+        write!(f, " AS {}", self.sql)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlterTaskAction {
+    Suspend,
+    Resume,
+}
+
+impl Display for AlterTaskAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AlterTaskAction::Suspend => write!(f, "SUSPEND"),
+            AlterTaskAction::Resume => write!(f, "RESUME"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterTaskStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub action: AlterTaskAction,
+}
+
+impl Display for AlterTaskStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER TASK ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} {}", self.name, self.action)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropTaskStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropTaskStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP TASK ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecuteTaskStmt {
+    pub name: String,
+}
+
+impl Display for ExecuteTaskStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "EXECUTE TASK {}", self.name)
+    }
+}
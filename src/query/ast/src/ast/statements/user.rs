@@ -142,6 +142,12 @@ pub enum AccountMgrSource {
         privileges: Vec<UserPrivilegeType>,
         level: AccountMgrLevel,
     },
+    /// A privilege on some columns of a table, e.g. `SELECT(a, b) ON db.t`.
+    ColumnPrivs {
+        privilege: UserPrivilegeType,
+        columns: Vec<String>,
+        level: AccountMgrLevel,
+    },
     ALL {
         level: AccountMgrLevel,
     },
@@ -152,12 +158,15 @@ pub enum AccountMgrLevel {
     Global,
     Database(Option<String>),
     Table(Option<String>, String),
+    Stage(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UserOptionItem {
     TenantSetting(bool),
     DefaultRole(String),
+    SetNetworkPolicy(String),
+    UnsetNetworkPolicy,
 }
 
 impl UserOptionItem {
@@ -167,6 +176,8 @@ impl UserOptionItem {
                 option.switch_option_flag(UserOptionFlag::TenantSetting, *enabled);
             }
             Self::DefaultRole(v) => option.set_default_role(Some(v.clone())),
+            Self::SetNetworkPolicy(v) => option.set_network_policy(Some(v.clone())),
+            Self::UnsetNetworkPolicy => option.set_network_policy(None),
         }
     }
 }
@@ -178,57 +189,57 @@ impl Display for AccountMgrSource {
             AccountMgrSource::Privs { privileges, level } => {
                 write!(f, " ")?;
                 write_comma_separated_list(f, privileges.iter().map(|p| p.to_string()))?;
-                write!(f, " ON")?;
-                match level {
-                    AccountMgrLevel::Global => write!(f, " *.*")?,
-                    AccountMgrLevel::Database(database_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.*")?;
-                        } else {
-                            write!(f, " *")?;
-                        }
-                    }
-                    AccountMgrLevel::Table(database_name, table_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.{table_name}")?;
-                        } else {
-                            write!(f, " {table_name}")?;
-                        }
-                    }
-                }
+                write!(f, " ON {level}")?;
+            }
+            AccountMgrSource::ColumnPrivs {
+                privilege,
+                columns,
+                level,
+            } => {
+                write!(f, " {privilege}(")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") ON {level}")?;
             }
             AccountMgrSource::ALL { level, .. } => {
                 write!(f, " ALL PRIVILEGES")?;
-                write!(f, " ON")?;
-                match level {
-                    AccountMgrLevel::Global => write!(f, " *.*")?,
-                    AccountMgrLevel::Database(database_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.*")?;
-                        } else {
-                            write!(f, " *")?;
-                        }
-                    }
-                    AccountMgrLevel::Table(database_name, table_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.{table_name}")?;
-                        } else {
-                            write!(f, " {table_name}")?;
-                        }
-                    }
-                }
+                write!(f, " ON {level}")?;
             }
         }
         Ok(())
     }
 }
 
+impl Display for AccountMgrLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccountMgrLevel::Global => write!(f, "*.*"),
+            AccountMgrLevel::Database(database_name) => {
+                if let Some(database_name) = database_name {
+                    write!(f, "{database_name}.*")
+                } else {
+                    write!(f, "*")
+                }
+            }
+            AccountMgrLevel::Table(database_name, table_name) => {
+                if let Some(database_name) = database_name {
+                    write!(f, "{database_name}.{table_name}")
+                } else {
+                    write!(f, "{table_name}")
+                }
+            }
+            AccountMgrLevel::Stage(stage_name) => write!(f, "STAGE {stage_name}"),
+        }
+    }
+}
+
 impl Display for UserOptionItem {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
             UserOptionItem::TenantSetting(true) => write!(f, "TENANTSETTING"),
             UserOptionItem::TenantSetting(false) => write!(f, "NOTENANTSETTING"),
             UserOptionItem::DefaultRole(v) => write!(f, "DEFAULT_ROLE = '{}'", v),
+            UserOptionItem::SetNetworkPolicy(v) => write!(f, "SET NETWORK POLICY {}", v),
+            UserOptionItem::UnsetNetworkPolicy => write!(f, "UNSET NETWORK POLICY"),
         }
     }
 }
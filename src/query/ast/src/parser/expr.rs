@@ -274,6 +274,10 @@ pub enum ExprElement {
     },
     /// `Count(*)` expression
     CountAll,
+    /// `@foo` expression
+    Variable {
+        name: String,
+    },
     /// `(foo, bar)`
     Tuple {
         exprs: Vec<Expr>,
@@ -286,6 +290,21 @@ pub enum ExprElement {
         args: Vec<Expr>,
         window: Option<WindowSpec>,
         params: Vec<Literal>,
+        lambda: Option<Lambda>,
+    },
+    /// `LISTAGG(...) WITHIN GROUP (ORDER BY ...)` expression
+    ListAgg {
+        distinct: bool,
+        expr: Box<Expr>,
+        delimiter: Option<String>,
+        on_overflow: Option<ListAggOverflow>,
+        within_group: Option<Box<OrderByExpr>>,
+    },
+    /// `PERCENTILE_CONT(...) WITHIN GROUP (ORDER BY ...)` expression
+    Percentile {
+        kind: PercentileKind,
+        level: Literal,
+        within_group: Box<OrderByExpr>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -370,6 +389,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 UnaryOperator::Plus => Affix::Prefix(Precedence(50)),
                 UnaryOperator::Minus => Affix::Prefix(Precedence(50)),
                 UnaryOperator::BitwiseNot => Affix::Prefix(Precedence(50)),
+                UnaryOperator::Prior => Affix::Prefix(Precedence(50)),
                 UnaryOperator::SquareRoot => Affix::Prefix(Precedence(60)),
                 UnaryOperator::CubeRoot => Affix::Prefix(Precedence(60)),
                 UnaryOperator::Abs => Affix::Prefix(Precedence(60)),
@@ -480,6 +500,10 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
             ExprElement::CountAll => Expr::CountAll {
                 span: transform_span(elem.span.0),
             },
+            ExprElement::Variable { name } => Expr::Variable {
+                span: transform_span(elem.span.0),
+                name,
+            },
             ExprElement::Tuple { exprs } => Expr::Tuple {
                 span: transform_span(elem.span.0),
                 exprs,
@@ -490,6 +514,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 args,
                 params,
                 window,
+                lambda,
             } => Expr::FunctionCall {
                 span: transform_span(elem.span.0),
                 distinct,
@@ -497,6 +522,31 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 args,
                 params,
                 window,
+                lambda,
+            },
+            ExprElement::ListAgg {
+                distinct,
+                expr,
+                delimiter,
+                on_overflow,
+                within_group,
+            } => Expr::ListAgg {
+                span: transform_span(elem.span.0),
+                distinct,
+                expr,
+                delimiter,
+                on_overflow,
+                within_group,
+            },
+            ExprElement::Percentile {
+                kind,
+                level,
+                within_group,
+            } => Expr::Percentile {
+                span: transform_span(elem.span.0),
+                kind,
+                level,
+                within_group,
             },
             ExprElement::Case {
                 operand,
@@ -835,6 +885,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     let count_all = value(ExprElement::CountAll, rule! {
         COUNT ~ "(" ~ "*" ~ ^")"
     });
+    let variable = map(user_variable, |name| ExprElement::Variable { name });
     let tuple = map(
         rule! {
             "(" ~ #comma_separated_list0_ignore_trailing(subexpr(0)) ~ ","? ~ ^")"
@@ -853,8 +904,9 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             rule! { BETWEEN ~ #window_frame_bound ~ AND ~ #window_frame_bound },
             |(_, s, _, e)| (s, e),
         ),
+        // `ROWS <start>` is short for `ROWS BETWEEN <start> AND CURRENT ROW`
         map(rule! {#window_frame_bound}, |s| {
-            (s, WindowFrameBound::Following(None))
+            (s, WindowFrameBound::CurrentRow)
         }),
     ));
 
@@ -862,24 +914,26 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         rule! {
             (PARTITION ~ ^BY ~ #comma_separated_list1(subexpr(0)))?
             ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
-            ~ ((ROWS | RANGE) ~ #window_frame_between)?
+            ~ ((ROWS | RANGE | GROUPS) ~ #window_frame_between ~ #window_frame_exclusion?)?
         },
-        |(opt_partition, opt_order, between)| WindowSpec {
+        |(opt_partition, opt_order, frame)| WindowSpec {
             partition_by: opt_partition.map(|x| x.2).unwrap_or_default(),
             order_by: opt_order.map(|x| x.2).unwrap_or_default(),
-            window_frame: between.map(|x| {
-                let unit = match x.0.kind {
+            window_frame: frame.map(|(unit, (start_bound, end_bound), exclusion)| {
+                let units = match unit.kind {
                     ROWS => WindowFrameUnits::Rows,
                     RANGE => WindowFrameUnits::Range,
+                    GROUPS => WindowFrameUnits::Groups,
                     _ => unreachable!(),
                 };
-                let bw = x.1;
                 WindowFrame {
-                    units: unit,
-                    start_bound: bw.0,
-                    end_bound: bw.1,
+                    units,
+                    start_bound,
+                    end_bound,
+                    exclusion,
                 }
             }),
+            ignore_nulls: None,
         },
     );
 
@@ -894,6 +948,25 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             args: opt_args.unwrap_or_default(),
             params: vec![],
             window: None,
+            lambda: None,
+        },
+    );
+
+    let function_call_with_lambda = map(
+        rule! {
+            #function_name
+            ~ "(" ~ #subexpr(0) ~ "," ~ #lambda_params ~ "->" ~ #subexpr(0) ~ ")"
+        },
+        |(name, _, arg, _, params, _, expr, _)| ExprElement::FunctionCall {
+            distinct: false,
+            name,
+            args: vec![arg],
+            params: vec![],
+            window: None,
+            lambda: Some(Lambda {
+                params,
+                expr: Box::new(expr),
+            }),
         },
     );
 
@@ -901,14 +974,20 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         rule! {
             #function_name
             ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ ((IGNORE | RESPECT) ~ NULLS)?
             ~ (OVER ~ "(" ~ #window_spec ~ ")")
         },
-        |(name, _, opt_distinct, opt_args, _, window)| ExprElement::FunctionCall {
-            distinct: opt_distinct.is_some(),
-            name,
-            args: opt_args.unwrap_or_default(),
-            params: vec![],
-            window: Some(window.2),
+        |(name, _, opt_distinct, opt_args, _, opt_null_treatment, window)| {
+            let mut window = window.2;
+            window.ignore_nulls = opt_null_treatment.map(|(token, _)| token.kind == IGNORE);
+            ExprElement::FunctionCall {
+                distinct: opt_distinct.is_some(),
+                name,
+                args: opt_args.unwrap_or_default(),
+                params: vec![],
+                window: Some(window),
+                lambda: None,
+            }
         },
     );
 
@@ -924,6 +1003,67 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             args: opt_args.unwrap_or_default(),
             params: params.map(|x| x.1).unwrap_or_default(),
             window: None,
+            lambda: None,
+        },
+    );
+
+    // LISTAGG([DISTINCT] <expr> [, '<delimiter>'] [ON OVERFLOW ...]) [WITHIN GROUP (ORDER BY <expr> [ASC | DESC])]
+    let list_agg_overflow = alt((
+        value(ListAggOverflow::Error, rule! { ON ~ OVERFLOW ~ ERROR }),
+        map(
+            rule! {
+                ON ~ OVERFLOW ~ TRUNCATE ~ #literal_string? ~ ( ( WITH | WITHOUT ) ~ ^COUNT )?
+            },
+            |(_, _, _, filler, opt_with_count)| ListAggOverflow::Truncate {
+                filler,
+                with_count: opt_with_count
+                    .map(|(with, _)| with.kind == WITH)
+                    .unwrap_or(true),
+            },
+        ),
+    ));
+    let list_agg = map(
+        rule! {
+            LISTAGG ~ "(" ~ DISTINCT? ~ #subexpr(0)
+            ~ ( "," ~ #literal_string )?
+            ~ #list_agg_overflow? ~ ^")"
+            ~ ( WITHIN ~ ^GROUP ~ ^"(" ~ ^ORDER ~ ^BY ~ ^#subexpr(0) ~ ( ASC | DESC )? ~ ^")" )?
+        },
+        |(_, _, opt_distinct, expr, opt_delimiter, on_overflow, _, opt_within_group)| {
+            ExprElement::ListAgg {
+                distinct: opt_distinct.is_some(),
+                expr: Box::new(expr),
+                delimiter: opt_delimiter.map(|(_, delimiter)| delimiter),
+                on_overflow,
+                within_group: opt_within_group.map(|(_, _, _, _, _, expr, opt_asc, _)| {
+                    Box::new(OrderByExpr {
+                        expr,
+                        asc: opt_asc.map(|asc| asc.kind == ASC),
+                        nulls_first: None,
+                    })
+                }),
+            }
+        },
+    );
+
+    // PERCENTILE_CONT | PERCENTILE_DISC (<level>) WITHIN GROUP (ORDER BY <expr> [ASC | DESC])
+    let percentile = map(
+        rule! {
+            ( PERCENTILE_CONT | PERCENTILE_DISC ) ~ "(" ~ #literal ~ ")"
+            ~ WITHIN ~ ^GROUP ~ ^"(" ~ ^ORDER ~ ^BY ~ ^#subexpr(0) ~ ( ASC | DESC )? ~ ^")"
+        },
+        |(func, _, level, _, _, _, _, _, _, expr, opt_asc, _)| ExprElement::Percentile {
+            kind: if func.kind == PERCENTILE_CONT {
+                PercentileKind::Cont
+            } else {
+                PercentileKind::Disc
+            },
+            level,
+            within_group: Box::new(OrderByExpr {
+                expr,
+                asc: opt_asc.map(|asc| asc.kind == ASC),
+                nulls_first: None,
+            }),
         },
     );
 
@@ -1041,6 +1181,18 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             unit,
         },
     );
+    let interval_expr = map(
+        rule! {
+            INTERVAL ~ #consumed(literal_string)
+        },
+        |(_, (span, interval))| ExprElement::Cast {
+            expr: Box::new(Expr::Literal {
+                span: transform_span(span.0),
+                lit: Literal::String(interval),
+            }),
+            target_type: TypeName::Interval,
+        },
+    );
     let date_trunc = map(
         rule! {
             DATE_TRUNC ~ "(" ~ #interval_kind ~ "," ~ #subexpr(0) ~ ")"
@@ -1097,17 +1249,21 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #date_expr: "`DATE <str_literal>`"
             | #timestamp_expr: "`TIMESTAMP <str_literal>`"
             | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
+            | #interval_expr: "`INTERVAL <str_literal>`"
             | #pg_cast : "`::<type_name>`"
             | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND) FROM ...)`"
+            | #position : "`POSITION(... IN ...)`"
         ),
         rule!(
-            #position : "`POSITION(... IN ...)`"
-            | #substring : "`SUBSTRING(... [FROM ...] [FOR ...])`"
+            #substring : "`SUBSTRING(... [FROM ...] [FOR ...])`"
             | #array_sort : "`ARRAY_SORT([...], 'ASC' | 'DESC', 'NULLS FIRST' | 'NULLS LAST')`"
             | #trim : "`TRIM(...)`"
             | #trim_from : "`TRIM([(BOTH | LEADEING | TRAILING) ... FROM ...)`"
             | #is_distinct_from: "`... IS [NOT] DISTINCT FROM ...`"
             | #count_all : "COUNT(*)"
+            | #list_agg : "`LISTAGG(... [, '<delimiter>'] [ON OVERFLOW ...]) [WITHIN GROUP (ORDER BY ...)]`"
+            | #percentile : "`PERCENTILE_CONT | PERCENTILE_DISC (<level>) WITHIN GROUP (ORDER BY ...)`"
+            | #function_call_with_lambda : "<function>"
             | #function_call_with_window : "<function>"
             | #function_call_with_params : "<function>"
             | #function_call : "<function>"
@@ -1117,6 +1273,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #column_ref : "<column>"
             | #map_access : "[<key>] | .<key> | :<key>"
             | #literal : "<literal>"
+            | #variable : "`@<variable>`"
             | #array : "`[...]`"
             | #map_expr : "`{...}`"
         ),
@@ -1125,26 +1282,50 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     Ok((rest, WithSpan { span, elem }))
 }
 
+pub fn lambda_params(i: Input) -> IResult<Vec<Identifier>> {
+    let single_param = map(ident, |param| vec![param]);
+    let multi_params = map(
+        rule! { "(" ~ #comma_separated_list1(ident) ~ ")" },
+        |(_, params, _)| params,
+    );
+    rule!(
+        #single_param
+        | #multi_params
+    )(i)
+}
+
 pub fn window_frame_bound(i: Input) -> IResult<WindowFrameBound> {
+    // `UNBOUNDED` is not reserved, so it must be tried before `<expr>` to
+    // avoid being parsed as a column reference.
     alt((
         value(WindowFrameBound::CurrentRow, rule! { CURRENT ~ ROW }),
-        map(rule! { #subexpr(0) ~ PRECEDING }, |(expr, _)| {
-            WindowFrameBound::Preceding(Some(Box::new(expr)))
-        }),
         value(
             WindowFrameBound::Preceding(None),
             rule! { UNBOUNDED ~ PRECEDING },
         ),
-        map(rule! { #subexpr(0) ~ FOLLOWING }, |(expr, _)| {
-            WindowFrameBound::Following(Some(Box::new(expr)))
-        }),
         value(
             WindowFrameBound::Following(None),
             rule! { UNBOUNDED ~ FOLLOWING },
         ),
+        map(rule! { #subexpr(0) ~ PRECEDING }, |(expr, _)| {
+            WindowFrameBound::Preceding(Some(Box::new(expr)))
+        }),
+        map(rule! { #subexpr(0) ~ FOLLOWING }, |(expr, _)| {
+            WindowFrameBound::Following(Some(Box::new(expr)))
+        }),
     ))(i)
 }
 
+pub fn window_frame_exclusion(i: Input) -> IResult<WindowFrameExclusion> {
+    let kind = alt((
+        value(WindowFrameExclusion::CurrentRow, rule! { CURRENT ~ ^ROW }),
+        value(WindowFrameExclusion::Group, rule! { GROUP }),
+        value(WindowFrameExclusion::Ties, rule! { TIES }),
+        value(WindowFrameExclusion::NoOthers, rule! { NO ~ ^OTHERS }),
+    ));
+    map(rule! { EXCLUDE ~ ^#kind }, |(_, exclusion)| exclusion)(i)
+}
+
 pub fn unary_op(i: Input) -> IResult<UnaryOperator> {
     // Plus and Minus are parsed as binary op at first.
     alt((
@@ -1154,6 +1335,7 @@ pub fn unary_op(i: Input) -> IResult<UnaryOperator> {
         value(UnaryOperator::BitwiseNot, rule! {BitWiseNot}),
         value(UnaryOperator::CubeRoot, rule! { CubeRoot}),
         value(UnaryOperator::Abs, rule! { Abs}),
+        value(UnaryOperator::Prior, rule! { PRIOR }),
     ))(i)
 }
 
@@ -1251,6 +1433,19 @@ pub fn literal_u64(i: Input) -> IResult<u64> {
     )(i)
 }
 
+pub fn literal_i64(i: Input) -> IResult<i64> {
+    map_res(
+        rule! {
+            "-"? ~ #literal_u64
+        },
+        |(minus, value)| {
+            let value = value as i128;
+            let value = if minus.is_some() { -value } else { value };
+            i64::try_from(value).map_err(|_| ErrorKind::Other("integer out of range"))
+        },
+    )(i)
+}
+
 pub fn literal_f64(i: Input) -> IResult<f64> {
     map_res(
         rule! {
@@ -1344,6 +1539,17 @@ pub fn at_string(i: Input) -> IResult<String> {
         .map(|(i2, token)| (i2, token.text()[1..token.text().len()].to_string()))
 }
 
+/// The name of a user-defined session variable, such as `@foo`.
+pub fn user_variable(i: Input) -> IResult<String> {
+    map_res(at_string, |name| {
+        if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            Ok(name.to_lowercase())
+        } else {
+            Err(ErrorKind::Other("invalid variable name"))
+        }
+    })(i)
+}
+
 pub fn type_name(i: Input) -> IResult<TypeName> {
     let ty_boolean = value(TypeName::Boolean, rule! { BOOLEAN | BOOL });
     let ty_uint8 = value(
@@ -1439,31 +1645,46 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT  ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
+    let ty_bitmap = value(TypeName::Bitmap, rule! { BITMAP });
+    let ty_binary = value(
+        TypeName::Binary,
+        rule! { ( BINARY | VARBINARY ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
+    );
+    let ty_interval = value(TypeName::Interval, rule! { INTERVAL });
     map(
-        rule! {
-            ( #ty_boolean
-            | #ty_uint8
-            | #ty_uint16
-            | #ty_uint32
-            | #ty_uint64
-            | #ty_int8
-            | #ty_int16
-            | #ty_int32
-            | #ty_int64
-            | #ty_float32
-            | #ty_float64
-            | #ty_decimal
-            | #ty_array
-            | #ty_map
-            | #ty_tuple : "TUPLE(<type>, ...)"
-            | #ty_named_tuple : "TUPLE(<name> <type>, ...)"
-            | #ty_date
-            | #ty_datetime
-            | #ty_string
-            | #ty_variant
-            | #ty_nullable
-            ) ~ NULL? : "type name"
-        },
+        alt((
+            // Note: each `alt` call supports maximum of 21 parsers
+            rule! {
+                ( #ty_boolean
+                | #ty_uint8
+                | #ty_uint16
+                | #ty_uint32
+                | #ty_uint64
+                | #ty_int8
+                | #ty_int16
+                | #ty_int32
+                | #ty_int64
+                | #ty_float32
+                | #ty_float64
+                | #ty_decimal
+                ) ~ NULL? : "type name"
+            },
+            rule! {
+                ( #ty_array
+                | #ty_map
+                | #ty_tuple : "TUPLE(<type>, ...)"
+                | #ty_named_tuple : "TUPLE(<name> <type>, ...)"
+                | #ty_date
+                | #ty_datetime
+                | #ty_string
+                | #ty_variant
+                | #ty_bitmap
+                | #ty_binary
+                | #ty_interval
+                | #ty_nullable
+                ) ~ NULL? : "type name"
+            },
+        )),
         |(ty, null_opt)| {
             if null_opt.is_some() && !matches!(ty, TypeName::Nullable(_)) {
                 TypeName::Nullable(Box::new(ty))
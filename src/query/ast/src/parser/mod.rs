@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod expr;
+mod odbc_escape;
 #[allow(clippy::module_inception)]
 mod parser;
 pub mod query;
@@ -22,8 +23,10 @@ pub mod statement;
 pub mod token;
 pub mod unescape;
 
+pub use odbc_escape::rewrite_odbc_escapes;
 pub use parser::parse_comma_separated_exprs;
 pub use parser::parse_expr;
+pub use parser::parse_settings_hint;
 pub use parser::parse_sql;
 pub use parser::parser_values_with_placeholder;
 pub use parser::tokenize_sql;
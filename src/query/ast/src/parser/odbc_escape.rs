@@ -0,0 +1,119 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::parser::token::*;
+use crate::parser::unescape::unescape;
+
+/// Rewrite the ODBC-style escape clauses into the SQL understood by the parser:
+///
+/// - `{d 'YYYY-MM-DD'}` into `DATE 'YYYY-MM-DD'`
+/// - `{ts 'YYYY-MM-DD hh:mm:ss'}` into `TIMESTAMP 'YYYY-MM-DD hh:mm:ss'`
+/// - `{t 'hh:mm:ss'}` into the number of seconds since midnight
+/// - `{fn func(args)}` into `func(args)`
+///
+/// The text after the first unrecognized token is kept as is, it may be the data of
+/// an `INSERT` statement.
+pub fn rewrite_odbc_escapes(source: &str) -> Result<Cow<'_, str>> {
+    let tokens = Tokenizer::new(source)
+        .map_while(|token| token.ok())
+        .filter(|token| token.kind != EOI)
+        .collect::<Vec<_>>();
+
+    let mut output = String::with_capacity(source.len());
+    let mut last = 0;
+    let mut rewritten = false;
+    // Whether each of the open braces starts a `{fn ...}` escape.
+    let mut braces = vec![];
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = &tokens[i];
+        match token.kind {
+            LBrace => {
+                let escape = tokens.get(i + 1).map(|t| t.text().to_ascii_lowercase());
+                match (escape.as_deref(), tokens.get(i + 2), tokens.get(i + 3)) {
+                    (Some(escape @ ("d" | "ts" | "t")), Some(literal), Some(close))
+                        if literal.kind == QuotedString && close.kind == RBrace =>
+                    {
+                        let replacement = match escape {
+                            "d" => format!("DATE {}", literal.text()),
+                            "ts" => format!("TIMESTAMP {}", literal.text()),
+                            _ => seconds_since_midnight(literal)?.to_string(),
+                        };
+                        output.push_str(&source[last..token.span.start]);
+                        output.push_str(&replacement);
+                        last = close.span.end;
+                        rewritten = true;
+                        i += 4;
+                        continue;
+                    }
+                    (Some("fn"), _, _) => {
+                        output.push_str(&source[last..token.span.start]);
+                        last = tokens[i + 1].span.end;
+                        rewritten = true;
+                        braces.push(true);
+                        i += 2;
+                        continue;
+                    }
+                    _ => braces.push(false),
+                }
+            }
+            RBrace => {
+                if braces.pop() == Some(true) {
+                    output.push_str(&source[last..token.span.start]);
+                    last = token.span.end;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    if !rewritten {
+        return Ok(Cow::Borrowed(source));
+    }
+    output.push_str(&source[last..]);
+    Ok(Cow::Owned(output))
+}
+
+fn seconds_since_midnight(literal: &Token) -> Result<i64> {
+    let text = literal.text();
+    let invalid = || {
+        ErrorCode::SyntaxException(format!("invalid time literal {text}, expected 'hh:mm:ss'"))
+            .set_span(Some(literal.span))
+    };
+
+    let quote = text.chars().next().ok_or_else(invalid)?;
+    let time = unescape(&text[1..text.len() - 1], quote).ok_or_else(invalid)?;
+    let parts = time
+        .trim()
+        .split(':')
+        .map(|part| part.parse::<i64>().ok())
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(invalid)?;
+    match parts[..] {
+        [hour, minute, second]
+            if (0..24).contains(&hour)
+                && (0..60).contains(&minute)
+                && (0..60).contains(&second) =>
+        {
+            Ok(hour * 3600 + minute * 60 + second)
+        }
+        _ => Err(invalid()),
+    }
+}
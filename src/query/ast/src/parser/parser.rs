@@ -23,6 +23,7 @@ use crate::input::Input;
 use crate::parser::expr;
 use crate::parser::expr::subexpr;
 use crate::parser::expr::values_with_placeholder;
+use crate::parser::statement::settings_hint;
 use crate::parser::statement::statement;
 use crate::parser::token::Token;
 use crate::parser::token::TokenKind;
@@ -57,6 +58,38 @@ pub fn parse_sql<'a>(
     }
 }
 
+/// Parse the settings of the `/*+ SET_VAR(<name> = <value>, ...) */` hint leading the SQL, which
+/// the tokenizer skips as a comment.
+///
+/// Returns an empty list if the SQL doesn't start with a hint.
+pub fn parse_settings_hint(sql: &str, dialect: Dialect) -> Result<Vec<(String, String)>> {
+    let hint = match sql
+        .trim_start()
+        .strip_prefix("/*+")
+        .and_then(|rest| rest.split_once("*/"))
+    {
+        Some((hint, _)) => hint,
+        None => return Ok(vec![]),
+    };
+
+    let hint_tokens = tokenize_sql(hint)?;
+    let backtrace = Backtrace::new();
+    match settings_hint(Input(&hint_tokens, dialect, &backtrace)) {
+        Ok((rest, settings)) if rest[0].kind == TokenKind::EOI => Ok(settings),
+        Ok((rest, _)) => Err(ErrorCode::SyntaxException(
+            "unable to parse rest of the hint".to_string(),
+        )
+        .set_span(transform_span(&rest[..1]))),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            let source = hint_tokens[0].source;
+            Err(ErrorCode::SyntaxException(display_parser_error(
+                err, source,
+            )))
+        }
+        Err(nom::Err::Incomplete(_)) => unreachable!(),
+    }
+}
+
 /// Parse udf function into Expr
 pub fn parse_expr<'a>(sql_tokens: &'a [Token<'a>], dialect: Dialect) -> Result<Expr> {
     let backtrace = Backtrace::new();
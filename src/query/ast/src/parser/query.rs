@@ -15,7 +15,9 @@
 use nom::branch::alt;
 use nom::combinator::consumed;
 use nom::combinator::map;
+use nom::combinator::not;
 use nom::combinator::value;
+use nom::sequence::preceded;
 use pratt::Affix;
 use pratt::Associativity;
 use pratt::PrattParser;
@@ -64,11 +66,12 @@ pub fn query(i: Input) -> IResult<Query> {
 pub fn with(i: Input) -> IResult<With> {
     let cte = map(
         consumed(rule! {
-            #table_alias ~ AS ~ "(" ~ #query ~ ")"
+            #table_alias ~ AS ~ "(" ~ ( NOT? ~ MATERIALIZED )? ~ #query ~ ")"
         }),
-        |(span, (table_alias, _, _, query, _))| CTE {
+        |(span, (table_alias, _, _, materialized, query, _))| CTE {
             span: transform_span(span.0),
             alias: table_alias,
+            materialized: materialized.map(|(not, _)| not.is_none()),
             query,
         },
     );
@@ -166,11 +169,33 @@ pub fn travel_point(i: Input) -> IResult<TimeTravelPoint> {
     )(i)
 }
 
+pub fn table_sample(i: Input) -> IResult<TableSample> {
+    let method = alt((
+        value(SampleMethod::System, rule! { SYSTEM }),
+        value(SampleMethod::Bernoulli, rule! { BERNOULLI }),
+    ));
+    let percent = alt((literal_f64, map(literal_u64, |v| v as f64)));
+
+    map(
+        rule! {
+            TABLESAMPLE ~ ^#method ~ ^"(" ~ ^#percent ~ ^")" ~ ( REPEATABLE ~ ^"(" ~ ^#literal_u64 ~ ^")" )?
+        },
+        |(_, method, _, percent, _, opt_seed)| TableSample {
+            method,
+            percent,
+            seed: opt_seed.map(|(_, _, seed, _)| seed),
+        },
+    )(i)
+}
+
 pub fn alias_name(i: Input) -> IResult<Identifier> {
     let as_alias = map(rule! { AS ~ #ident_after_as }, |(_, name)| name);
+    // `START WITH` and `CONNECT BY` begin a hierarchical query clause rather than an alias.
+    let hierarchical_clause = rule! { START ~ WITH | CONNECT ~ BY };
+    let implicit_alias = preceded(not(hierarchical_clause), ident);
 
     rule!(
-        #ident
+        #implicit_alias
         | #as_alias
     )(i)
 }
@@ -228,6 +253,108 @@ pub fn order_by_expr(i: Input) -> IResult<OrderByExpr> {
     )(i)
 }
 
+pub fn match_recognize(i: Input) -> IResult<MatchRecognize> {
+    let measure = map(rule! { #expr ~ AS ~ #ident }, |(expr, _, alias)| {
+        MatchMeasure { expr, alias }
+    });
+    let definition = map(rule! { #ident ~ AS ~ #expr }, |(variable, _, predicate)| {
+        MatchDefinition {
+            variable,
+            predicate,
+        }
+    });
+    let rows_per_match = alt((
+        value(RowsPerMatch::OneRow, rule! { ONE ~ ROW ~ PER ~ MATCH }),
+        value(RowsPerMatch::AllRows, rule! { ALL ~ ROWS ~ PER ~ MATCH }),
+    ));
+
+    map(
+        rule! {
+            MATCH_RECOGNIZE ~ "("
+            ~ ( PARTITION ~ ^BY ~ ^#comma_separated_list1(expr) )?
+            ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
+            ~ ( MEASURES ~ ^#comma_separated_list1(measure) )?
+            ~ #rows_per_match?
+            ~ PATTERN ~ ^"(" ~ ^#match_pattern ~ ^")"
+            ~ ^DEFINE ~ ^#comma_separated_list1(definition)
+            ~ ^")"
+        },
+        |(
+            _,
+            _,
+            opt_partition_by,
+            opt_order_by,
+            opt_measures,
+            opt_rows_per_match,
+            _,
+            _,
+            pattern,
+            _,
+            _,
+            definitions,
+            _,
+        )| MatchRecognize {
+            partition_by: opt_partition_by.map(|x| x.2).unwrap_or_default(),
+            order_by: opt_order_by.map(|x| x.2).unwrap_or_default(),
+            measures: opt_measures.map(|x| x.1).unwrap_or_default(),
+            rows_per_match: opt_rows_per_match.unwrap_or(RowsPerMatch::OneRow),
+            pattern,
+            definitions,
+        },
+    )(i)
+}
+
+/// Parse a row pattern, e.g., `A B+ (C | D)*`.
+pub fn match_pattern(i: Input) -> IResult<MatchPattern> {
+    map(
+        rule! { #match_pattern_concat ~ ( "|" ~ ^#match_pattern_concat )* },
+        |(first, rest)| {
+            if rest.is_empty() {
+                first
+            } else {
+                let mut patterns = vec![first];
+                patterns.extend(rest.into_iter().map(|(_, pattern)| pattern));
+                MatchPattern::Alternation(patterns)
+            }
+        },
+    )(i)
+}
+
+fn match_pattern_concat(i: Input) -> IResult<MatchPattern> {
+    map(rule! { #match_pattern_term+ }, |mut terms| {
+        if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            MatchPattern::Concat(terms)
+        }
+    })(i)
+}
+
+fn match_pattern_term(i: Input) -> IResult<MatchPattern> {
+    let primary = alt((
+        map(ident, MatchPattern::Variable),
+        map(rule! { "(" ~ #match_pattern ~ ^")" }, |(_, pattern, _)| {
+            pattern
+        }),
+    ));
+    let quantifier = alt((
+        value(PatternQuantifier::ZeroOrMore, rule! { "*" }),
+        value(PatternQuantifier::OneOrMore, rule! { "+" }),
+        value(PatternQuantifier::ZeroOrOne, rule! { "?" }),
+    ));
+
+    map(
+        rule! { #primary ~ #quantifier? },
+        |(pattern, quantifier)| match quantifier {
+            Some(quantifier) => MatchPattern::Quantified {
+                pattern: Box::new(pattern),
+                quantifier,
+            },
+            None => pattern,
+        },
+    )(i)
+}
+
 pub fn table_reference(i: Input) -> IResult<TableReference> {
     let (rest, table_reference_elements) = rule!(#table_reference_element+)(i)?;
     let iter = &mut table_reference_elements.into_iter();
@@ -264,17 +391,21 @@ pub enum TableReferenceElement {
         table: Identifier,
         alias: Option<TableAlias>,
         travel_point: Option<TimeTravelPoint>,
+        sample: Option<TableSample>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        match_recognize: Option<Box<MatchRecognize>>,
     },
     // `TABLE(expr)[ AS alias ]`
     TableFunction {
+        lateral: bool,
         name: Identifier,
         params: Vec<TableFunctionParam>,
         alias: Option<TableAlias>,
     },
     // Derived table, which can be a subquery or joined tables or combination of them
     Subquery {
+        lateral: bool,
         subquery: Box<Query>,
         alias: Option<TableAlias>,
     },
@@ -290,6 +421,7 @@ pub enum TableReferenceElement {
         location: FileLocation,
         options: Vec<SelectStageOption>,
         alias: Option<TableAlias>,
+        sample: Option<TableSample>,
     },
 }
 
@@ -318,25 +450,36 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let aliased_table = map(
         rule! {
-            #period_separated_idents_1_to_3 ~ (AT ~ #travel_point)? ~ #table_alias? ~ #pivot? ~ #unpivot?
+            #period_separated_idents_1_to_3 ~ (AT ~ #travel_point)? ~ #match_recognize? ~ #table_alias? ~ #table_sample? ~ #pivot? ~ #unpivot?
         },
-        |((catalog, database, table), travel_point_opt, alias, pivot, unpivot)| {
+        |(
+            (catalog, database, table),
+            travel_point_opt,
+            match_recognize,
+            alias,
+            sample,
+            pivot,
+            unpivot,
+        )| {
             TableReferenceElement::Table {
                 catalog,
                 database,
                 table,
                 alias,
                 travel_point: travel_point_opt.map(|p| p.1),
+                sample,
                 pivot: pivot.map(Box::new),
                 unpivot: unpivot.map(Box::new),
+                match_recognize: match_recognize.map(Box::new),
             }
         },
     );
     let table_function = map(
         rule! {
-            #ident ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
+            LATERAL? ~ #ident ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
         },
-        |(name, _, params, _, alias)| TableReferenceElement::TableFunction {
+        |(lateral, name, _, params, _, alias)| TableReferenceElement::TableFunction {
+            lateral: lateral.is_some(),
             name,
             params,
             alias,
@@ -344,9 +487,10 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let subquery = map(
         rule! {
-            ( #parenthesized_query | #query ) ~ #table_alias?
+            LATERAL? ~ ( #parenthesized_query | #query ) ~ #table_alias?
         },
-        |(subquery, alias)| TableReferenceElement::Subquery {
+        |(lateral, subquery, alias)| TableReferenceElement::Subquery {
+            lateral: lateral.is_some(),
             subquery: Box::new(subquery),
             alias,
         },
@@ -399,9 +543,9 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
 
     let aliased_stage = map(
         rule! {
-            (#stage_location | #uri_location) ~  ("(" ~ ^#comma_separated_list1(select_stage_option) ~")")? ~ #table_alias?
+            (#stage_location | #uri_location) ~  ("(" ~ ^#comma_separated_list1(select_stage_option) ~")")? ~ #table_alias? ~ #table_sample?
         },
-        |(location, options, alias)| {
+        |(location, options, alias, sample)| {
             let options = match options {
                 None => vec![],
                 Some((_, v, _)) => v,
@@ -410,6 +554,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
                 location,
                 alias,
                 options,
+                sample,
             }
         },
     );
@@ -454,8 +599,10 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 table,
                 alias,
                 travel_point,
+                sample,
                 pivot,
                 unpivot,
+                match_recognize,
             } => TableReference::Table {
                 span: transform_span(input.span.0),
                 catalog,
@@ -463,10 +610,13 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 table,
                 alias,
                 travel_point,
+                sample,
                 pivot,
                 unpivot,
+                match_recognize,
             },
             TableReferenceElement::TableFunction {
+                lateral,
                 name,
                 params,
                 alias,
@@ -487,14 +637,20 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     .collect();
                 TableReference::TableFunction {
                     span: transform_span(input.span.0),
+                    lateral,
                     name,
                     params: normal_params,
                     named_params,
                     alias,
                 }
             }
-            TableReferenceElement::Subquery { subquery, alias } => TableReference::Subquery {
+            TableReferenceElement::Subquery {
+                lateral,
+                subquery,
+                alias,
+            } => TableReference::Subquery {
                 span: transform_span(input.span.0),
+                lateral,
                 subquery,
                 alias,
             },
@@ -502,6 +658,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 location,
                 options,
                 alias,
+                sample,
             } => {
                 let options = SelectStageOptions::from(options);
                 TableReference::Stage {
@@ -509,6 +666,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     location,
                     options,
                     alias,
+                    sample,
                 }
             }
             _ => unreachable!(),
@@ -590,6 +748,7 @@ pub enum SetOperationElement {
         select_list: Box<Vec<SelectTarget>>,
         from: Box<Vec<TableReference>>,
         selection: Box<Option<Expr>>,
+        connect_by: Box<Option<ConnectBy>>,
         group_by: Option<GroupBy>,
         having: Box<Option<Expr>>,
     },
@@ -627,6 +786,20 @@ pub fn group_by_items(i: Input) -> IResult<GroupBy> {
     rule!(#group_sets | #cube | #rollup | #normal)(i)
 }
 
+pub fn connect_by(i: Input) -> IResult<ConnectBy> {
+    map(
+        consumed(rule! {
+            ( START ~ ^WITH ~ ^#expr )?
+            ~ CONNECT ~ ^BY ~ ^#expr
+        }),
+        |(span, (opt_start_with, _, _, condition))| ConnectBy {
+            span: transform_span(span.0),
+            start_with: opt_start_with.map(|(_, _, start_with)| start_with),
+            condition,
+        },
+    )(i)
+}
+
 pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>> {
     let set_operator = map(
         rule! {
@@ -650,6 +823,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
              SELECT ~ DISTINCT? ~ ^#comma_separated_list1(select_target)
                 ~ ( FROM ~ ^#comma_separated_list1(table_reference) )?
                 ~ ( WHERE ~ ^#expr )?
+                ~ #connect_by?
                 ~ ( GROUP ~ ^BY ~ ^#group_by_items )?
                 ~ ( HAVING ~ ^#expr )?
         },
@@ -659,6 +833,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             select_list,
             opt_from_block,
             opt_where_block,
+            opt_connect_by,
             opt_group_by_block,
             opt_having_block,
         )| {
@@ -671,6 +846,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                         .unwrap_or_default(),
                 ),
                 selection: Box::new(opt_where_block.map(|(_, selection)| selection)),
+                connect_by: Box::new(opt_connect_by),
                 group_by: opt_group_by_block.map(|(_, _, group_by)| group_by),
                 having: Box::new(opt_having_block.map(|(_, having)| having)),
             }
@@ -717,6 +893,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 select_list,
                 from,
                 selection,
+                connect_by,
                 group_by,
                 having,
             } => SetExpr::Select(Box::new(SelectStmt {
@@ -727,6 +904,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 selection: *selection,
                 group_by,
                 having: *having,
+                connect_by: *connect_by,
             })),
             _ => unreachable!(),
         };
@@ -27,6 +27,7 @@ use common_meta_app::share::ShareNameIdent;
 use nom::branch::alt;
 use nom::combinator::consumed;
 use nom::combinator::map;
+use nom::combinator::map_res;
 use nom::combinator::value;
 use nom::Slice;
 
@@ -57,7 +58,7 @@ pub enum CreateDatabaseOption {
 pub fn statement(i: Input) -> IResult<StatementMsg> {
     let explain = map_res(
         rule! {
-            EXPLAIN ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | MEMO )? ~ #statement
+            EXPLAIN ~ ( AST | SYNTAX | JOIN | GRAPH | FRAGMENTS | RAW | MEMO )? ~ #statement
         },
         |(_, opt_kind, statement)| {
             Ok(Statement::Explain {
@@ -72,19 +73,40 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                             .map_err(|_| ErrorKind::Other("invalid statement"))?;
                         ExplainKind::Syntax(pretty_stmt)
                     }
-                    Some(TokenKind::PIPELINE) => ExplainKind::Pipeline,
                     Some(TokenKind::JOIN) => ExplainKind::JOIN,
                     Some(TokenKind::GRAPH) => ExplainKind::Graph,
                     Some(TokenKind::FRAGMENTS) => ExplainKind::Fragments,
                     Some(TokenKind::RAW) => ExplainKind::Raw,
                     Some(TokenKind::MEMO) => ExplainKind::Memo("".to_string()),
-                    None => ExplainKind::Plan,
+                    None => ExplainKind::Plan(ExplainFormat::Text),
                     _ => unreachable!(),
                 },
                 query: Box::new(statement.stmt),
             })
         },
     );
+    let explain_pipeline = map(
+        rule! {
+            EXPLAIN ~ PIPELINE ~ ( FORMAT ~ "=" ~ #explain_format )? ~ #statement
+        },
+        |(_, _, opt_format, statement)| Statement::Explain {
+            kind: ExplainKind::Pipeline(
+                opt_format
+                    .map(|(_, _, format)| format)
+                    .unwrap_or(ExplainFormat::Text),
+            ),
+            query: Box::new(statement.stmt),
+        },
+    );
+    let explain_with_format = map(
+        rule! {
+            EXPLAIN ~ "(" ~ FORMAT ~ #explain_format ~ ")" ~ #statement
+        },
+        |(_, _, _, format, _, statement)| Statement::Explain {
+            kind: ExplainKind::Plan(format),
+            query: Box::new(statement.stmt),
+        },
+    );
     let explain_analyze = map(
         rule! {
             EXPLAIN ~ ANALYZE ~ #statement
@@ -115,6 +137,27 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let insert_multi_table = map(
+        rule! {
+            INSERT ~ ( ALL | FIRST )
+            ~ #insert_when_clause+
+            ~ ( ELSE ~ #insert_into_clause+ )?
+            ~ #query
+        },
+        |(_, kind, when_clauses, opt_else, source)| {
+            Statement::InsertMultiTable(InsertMultiTableStmt {
+                kind: if kind.kind == FIRST {
+                    InsertMultiTableKind::First
+                } else {
+                    InsertMultiTableKind::All
+                },
+                when_clauses,
+                else_clause: opt_else.map(|(_, into_clauses)| into_clauses),
+                source: Box::new(source),
+            })
+        },
+    );
+
     let replace = map(
         rule! {
             REPLACE ~ INTO?
@@ -217,6 +260,32 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let set_user_variable = map(
+        rule! {
+            SET ~ #user_variable ~ "=" ~ #subexpr(0)
+        },
+        |(_, variable, _, value)| Statement::SetUserVariable {
+            variable,
+            value: Box::new(value),
+        },
+    );
+
+    let reset_user_variable = map(
+        rule! {
+            RESET ~ #user_variable
+        },
+        |(_, variable)| Statement::ResetUserVariable { variable },
+    );
+
+    let show_variables = map(
+        rule! {
+            SHOW ~ VARIABLES ~ (LIKE ~ #literal_string)?
+        },
+        |(_, _, opt_like)| Statement::ShowVariables {
+            like: opt_like.map(|(_, like)| like),
+        },
+    );
+
     let unset_variable = map(
         rule! {
             UNSET ~ #unset_source
@@ -474,7 +543,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             ~ #create_table_source?
             ~ ( #engine )?
             ~ ( #uri_location )?
-            ~ ( CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ ( CLUSTER ~ ^BY ~ ^#cluster_by )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
         },
@@ -500,7 +569,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 engine,
                 uri_location,
                 cluster_by: opt_cluster_by
-                    .map(|(_, _, _, exprs, _)| exprs)
+                    .map(|(_, _, exprs)| exprs)
                     .unwrap_or_default(),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
@@ -508,6 +577,45 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let attach_table = map(
+        rule! {
+            ATTACH ~ TABLE ~ #period_separated_idents_1_to_3 ~ FROM ~ #uri_location ~ READ_ONLY
+        },
+        |(_, _, (catalog, database, table), _, uri_location, _)| {
+            Statement::AttachTable(AttachTableStmt {
+                catalog,
+                database,
+                table,
+                uri_location,
+            })
+        },
+    );
+    let export_table_snapshot = map(
+        rule! {
+            EXPORT ~ SNAPSHOT ~ TABLE ~ #period_separated_idents_1_to_3 ~ TO ~ LOCATION ~ "=" ~ #literal_string
+        },
+        |(_, _, _, (catalog, database, table), _, _, _, location)| {
+            Statement::ExportTableSnapshot(ExportTableSnapshotStmt {
+                catalog,
+                database,
+                table,
+                location,
+            })
+        },
+    );
+    let import_table_snapshot = map(
+        rule! {
+            IMPORT ~ SNAPSHOT ~ TABLE ~ #period_separated_idents_1_to_3 ~ FROM ~ LOCATION ~ "=" ~ #literal_string
+        },
+        |(_, _, _, (catalog, database, table), _, _, _, location)| {
+            Statement::ImportTableSnapshot(ImportTableSnapshotStmt {
+                catalog,
+                database,
+                table,
+                location,
+            })
+        },
+    );
     let drop_table = map(
         rule! {
             DROP ~ TABLE ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3 ~ ( ALL )?
@@ -671,6 +779,53 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_stream = map(
+        rule! {
+            CREATE ~ STREAM ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ ON ~ TABLE ~ #period_separated_idents_1_to_2
+        },
+        |(_, _, opt_if_not_exists, (catalog, database, stream), _, _, (table_database, table))| {
+            Statement::CreateStream(CreateStreamStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                table_database,
+                table,
+            })
+        },
+    );
+    let drop_stream = map(
+        rule! {
+            DROP ~ STREAM ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream))| {
+            Statement::DropStream(DropStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+            })
+        },
+    );
+    let show_streams = map(
+        rule! {
+            SHOW ~ STREAMS ~ ( ( FROM | IN ) ~ #period_separated_idents_1_to_2 )? ~ #show_limit?
+        },
+        |(_, _, ctl_db, limit)| {
+            let (catalog, database) = match ctl_db {
+                Some((_, (Some(c), d))) => (Some(c), Some(d)),
+                Some((_, (None, d))) => (None, Some(d)),
+                _ => (None, None),
+            };
+            Statement::ShowStreams(ShowStreamsStmt {
+                catalog,
+                database,
+                limit,
+            })
+        },
+    );
     let show_users = value(Statement::ShowUsers, rule! { SHOW ~ USERS });
     let create_user = map(
         rule! {
@@ -722,6 +877,10 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
     let show_roles = value(Statement::ShowRoles, rule! { SHOW ~ ROLES });
+    let show_role_hierarchy = value(
+        Statement::ShowRoleHierarchy,
+        rule! { SHOW ~ ROLE ~ HIERARCHY },
+    );
     let create_role = map(
         rule! {
             CREATE ~ ROLE ~ ( IF ~ NOT ~ EXISTS )? ~ #literal_string
@@ -818,6 +977,77 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             udf_name,
         },
     );
+    let create_external_function = map(
+        rule! {
+            CREATE ~ EXTERNAL ~ FUNCTION ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ "(" ~ #comma_separated_list0(external_function_arg) ~ ")"
+            ~ RETURNS ~ #type_name
+            ~ LANGUAGE ~ #ident
+            ~ HANDLER ~ ^"=" ~ ^#literal_string
+            ~ ADDRESS ~ ^"=" ~ ^#literal_string
+            ~ ( HEADERS ~ ^"=" ~ ^"(" ~ ( #literal_string ~ ^"=" ~ ^#literal_string )* ~ ^")" )?
+            ~ ( #ident_to_string ~ ^"=" ~ ^#parameter_to_string )*
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            arguments,
+            _,
+            _,
+            return_type,
+            _,
+            language,
+            _,
+            _,
+            handler,
+            _,
+            _,
+            address,
+            opt_headers,
+            options,
+        )| {
+            Statement::CreateExternalFunction(CreateExternalFunctionStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                arguments,
+                return_type,
+                language,
+                handler,
+                address,
+                headers: opt_headers
+                    .map(|(_, _, _, headers, _)| {
+                        headers.into_iter().map(|(k, _, v)| (k, v)).collect()
+                    })
+                    .unwrap_or_default(),
+                options: options
+                    .into_iter()
+                    .map(|(k, _, v)| (k.to_lowercase(), v))
+                    .collect(),
+            })
+        },
+    );
+    let drop_external_function = map(
+        rule! {
+            DROP ~ EXTERNAL ~ FUNCTION ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropExternalFunction(DropExternalFunctionStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let describe_function = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ FUNCTION ~ #ident
+        },
+        |(_, _, name)| Statement::DescribeFunction { name },
+    );
     let alter_udf = map(
         rule! {
             ALTER ~ FUNCTION
@@ -836,6 +1066,208 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    // masking policies
+    let create_masking_policy = map(
+        rule! {
+            CREATE ~ MASKING ~ POLICY ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ AS ~ "(" ~ #comma_separated_list1(masking_policy_arg) ~ ")"
+            ~ RETURNS ~ #type_name
+            ~ "->" ~ #expr
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            args,
+            _,
+            _,
+            return_type,
+            _,
+            body,
+            opt_comment,
+        )| {
+            Statement::CreateMaskingPolicy(CreateMaskingPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                args,
+                return_type,
+                body,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_masking_policy = map(
+        rule! {
+            DROP ~ MASKING ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropMaskingPolicy(DropMaskingPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
+    // row access policies
+    let create_row_access_policy = map(
+        rule! {
+            CREATE ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ AS ~ "(" ~ #comma_separated_list1(row_access_policy_arg) ~ ")"
+            ~ RETURNS ~ #type_name
+            ~ "->" ~ #expr
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            args,
+            _,
+            _,
+            return_type,
+            _,
+            body,
+            opt_comment,
+        )| {
+            Statement::CreateRowAccessPolicy(CreateRowAccessPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                args,
+                return_type,
+                body,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            Statement::DropRowAccessPolicy(DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let show_row_access_policies = value(
+        Statement::ShowRowAccessPolicies,
+        rule! { SHOW ~ ROW ~ ACCESS ~ ^POLICIES },
+    );
+
+    // sequences
+    let create_sequence = map(
+        rule! {
+            CREATE ~ SEQUENCE ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ ( START ~ ^"=" ~ ^#literal_i64 )?
+            ~ ( INCREMENT ~ ^"=" ~ ^#literal_i64 )?
+            ~ ( MINVALUE ~ ^"=" ~ ^#literal_i64 )?
+            ~ ( MAXVALUE ~ ^"=" ~ ^#literal_i64 )?
+            ~ #sequence_cycle?
+        },
+        |(_, _, opt_if_not_exists, name, opt_start, opt_increment, opt_min, opt_max, cycle)| {
+            Statement::CreateSequence(CreateSequenceStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                start: opt_start.map(|(_, _, v)| v),
+                increment: opt_increment.map(|(_, _, v)| v),
+                min_value: opt_min.map(|(_, _, v)| v),
+                max_value: opt_max.map(|(_, _, v)| v),
+                cycle,
+            })
+        },
+    );
+    let drop_sequence = map(
+        rule! {
+            DROP ~ SEQUENCE ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropSequence(DropSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let show_sequences = value(Statement::ShowSequences, rule! { SHOW ~ SEQUENCES });
+    let show_index_build_status = value(
+        Statement::ShowIndexBuildStatus,
+        rule! { SHOW ~ INDEX ~ BUILD ~ STATUS },
+    );
+
+    // network policies
+    let create_network_policy = map(
+        rule! {
+            CREATE ~ NETWORK ~ ^POLICY ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ ALLOWED_IP_LIST ~ ^"=" ~ ^"(" ~ ^#comma_separated_list1(literal_string) ~ ^")"
+            ~ ( BLOCKED_IP_LIST ~ ^"=" ~ ^"(" ~ ^#comma_separated_list0(literal_string) ~ ^")" )?
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            _,
+            allowed_ip_list,
+            _,
+            opt_blocked_ip_list,
+            opt_comment,
+        )| {
+            Statement::CreateNetworkPolicy(CreateNetworkPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.name,
+                allowed_ip_list,
+                blocked_ip_list: opt_blocked_ip_list
+                    .map(|(_, _, _, blocked_ip_list, _)| blocked_ip_list)
+                    .unwrap_or_default(),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_network_policy = map(
+        rule! {
+            DROP ~ NETWORK ~ ^POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropNetworkPolicy(DropNetworkPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.name,
+            })
+        },
+    );
+    let desc_network_policy = map(
+        rule! {
+            (DESC | DESCRIBE) ~ NETWORK ~ ^POLICY ~ #ident
+        },
+        |(_, _, _, name)| Statement::DescNetworkPolicy(DescNetworkPolicyStmt { name: name.name }),
+    );
+    let show_network_policies = value(
+        Statement::ShowNetworkPolicies,
+        rule! { SHOW ~ NETWORK ~ ^POLICIES },
+    );
+
+    let drop_result_cache = value(Statement::DropResultCache, rule! { DROP ~ RESULT ~ ^CACHE });
+
+    // transactions
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT });
+    let abort = value(Statement::Abort, rule! { ABORT | ROLLBACK });
+
     // stages
     let create_stage = map_res(
         rule! {
@@ -885,6 +1317,18 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let show_stage_files = map(
+        rule! {
+            SHOW ~ STAGE ~ FILES ~ IN ~ #at_string ~ (PATTERN ~ "=" ~ #literal_string)?
+            ~ ( LIMIT ~ #literal_u64 )?
+        },
+        |(_, _, _, _, location, opt_pattern, opt_limit)| Statement::ShowStageFiles {
+            location,
+            pattern: opt_pattern.map(|v| v.2).unwrap_or_default(),
+            limit: opt_limit.map(|v| v.1),
+        },
+    );
+
     let remove_stage = map(
         rule! {
             REMOVE ~ #at_string ~ (PATTERN ~ "=" ~ #literal_string)?
@@ -914,6 +1358,68 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let alter_stage_connection = map(
+        rule! {
+            ALTER ~ STAGE ~ #ident ~ CONNECTION ~ "=" ~ #connection_options
+        },
+        |(_, _, stage_name, _, _, connection)| {
+            Statement::AlterStage(AlterStageStmt {
+                stage_name: stage_name.to_string(),
+                location: None,
+                connection,
+                file_format_options: BTreeMap::new(),
+                copy_options: BTreeMap::new(),
+            })
+        },
+    );
+
+    let alter_stage = map_res(
+        rule! {
+            ALTER ~ STAGE ~ #ident ~ SET
+            ~ ( URL ~ "=" ~ #uri_location )?
+            ~ ( ( CONNECTION | CREDENTIALS ) ~ "=" ~ #connection_options )?
+            ~ ( #file_format_clause )?
+            ~ ( COPY_OPTIONS ~ "=" ~ #options )?
+        },
+        |(_, _, stage_name, _, url_opt, connection_opt, file_format_opt, copy_options_opt)| {
+            if url_opt.is_none()
+                && connection_opt.is_none()
+                && file_format_opt.is_none()
+                && copy_options_opt.is_none()
+            {
+                return Err(ErrorKind::Other(
+                    "expected at least one stage option to set",
+                ));
+            }
+            Ok(Statement::AlterStage(AlterStageStmt {
+                stage_name: stage_name.to_string(),
+                location: url_opt.map(|v| v.2),
+                connection: connection_opt.map(|v| v.2).unwrap_or_default(),
+                file_format_options: file_format_opt.unwrap_or_default(),
+                copy_options: copy_options_opt.map(|v| v.2).unwrap_or_default(),
+            }))
+        },
+    );
+
+    let rename_stage = map(
+        rule! {
+            RENAME ~ STAGE ~ #ident ~ TO ~ #ident
+        },
+        |(_, _, stage_name, _, new_stage_name)| Statement::RenameStage {
+            stage_name: stage_name.to_string(),
+            new_stage_name: new_stage_name.to_string(),
+        },
+    );
+
+    let show_create_stage = map(
+        rule! {
+            SHOW ~ CREATE ~ EXTERNAL? ~ STAGE ~ #ident
+        },
+        |(_, _, _, _, stage_name)| Statement::ShowCreateStage {
+            stage_name: stage_name.to_string(),
+        },
+    );
+
     let copy_into = map(
         rule! {
             COPY
@@ -944,6 +1450,21 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let show_copy_history = map(
+        rule! {
+            SHOW ~ COPY ~ HISTORY ~ FOR ~ TABLE ~ #period_separated_idents_1_to_3
+            ~ ( SINCE ~ "=>" ~ #literal_string )?
+        },
+        |(_, _, _, _, _, (catalog, database, table), opt_since)| {
+            Statement::ShowCopyHistory(ShowCopyHistoryStmt {
+                catalog,
+                database,
+                table,
+                since: opt_since.map(|(_, _, since)| since),
+            })
+        },
+    );
+
     let call = map(
         rule! {
             CALL ~ #ident ~ "(" ~ #comma_separated_list0(parameter_to_string) ~ ")"
@@ -1083,16 +1604,34 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
-    let alter_share_tenants = map(
+    let alter_share_tenants = map_res(
         rule! {
-            ALTER ~ SHARE ~ (IF ~ EXISTS )? ~ #ident ~ #alter_add_share_accounts ~ TENANTS ~ Eq ~ #comma_separated_list1(ident)
+            ALTER ~ SHARE ~ (IF ~ EXISTS )? ~ #ident ~ #alter_add_share_accounts ~ ( TENANTS | ACCOUNTS ) ~ Eq ~ #share_account_list ~ #share_restrictions?
         },
-        |(_, _, opt_if_exists, share, is_add, _, _, tenants)| {
-            Statement::AlterShareTenants(AlterShareTenantsStmt {
+        |(_, _, opt_if_exists, share, is_add, _, _, tenants, opt_read_only)| {
+            if !is_add && opt_read_only.is_some() {
+                return Err(ErrorKind::Other(
+                    "SHARE_RESTRICTIONS can only be specified when adding accounts",
+                ));
+            }
+            Ok(Statement::AlterShareTenants(AlterShareTenantsStmt {
                 share,
                 if_exists: opt_if_exists.is_some(),
                 is_add,
                 tenants,
+                read_only: opt_read_only.unwrap_or(false),
+            }))
+        },
+    );
+    let alter_share_comment = map(
+        rule! {
+            ALTER ~ SHARE ~ (IF ~ EXISTS )? ~ #ident ~ SET ~ COMMENT ~ Eq ~ #literal_string
+        },
+        |(_, _, opt_if_exists, share, _, _, _, comment)| {
+            Statement::AlterShareComment(AlterShareCommentStmt {
+                share,
+                if_exists: opt_if_exists.is_some(),
+                comment,
             })
         },
     );
@@ -1104,9 +1643,13 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     );
     let show_shares = map(
         rule! {
-            SHOW ~ SHARES
+            SHOW ~ SHARES ~ ( OF ~ TENANT ~ #literal_string )?
+        },
+        |(_, _, opt_tenant)| {
+            Statement::ShowShares(ShowSharesStmt {
+                tenant: opt_tenant.map(|(_, _, tenant)| tenant),
+            })
         },
-        |(_, _)| Statement::ShowShares(ShowSharesStmt {}),
     );
 
     let create_file_format = map_res(
@@ -1137,10 +1680,109 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
 
     let show_file_formats = value(Statement::ShowFileFormats, rule! { SHOW ~ FILE ~ FORMATS });
 
+    // tasks
+    let create_task = map(
+        rule! {
+            CREATE ~ TASK ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ SCHEDULE ~ ^"=" ~ ^#literal_string
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+            ~ AS ~ ^#statement
+        },
+        |(_, _, opt_if_not_exists, name, _, _, schedule, opt_comment, _, statement)| {
+            Statement::CreateTask(CreateTaskStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.name,
+                schedule,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+                sql: Box::new(statement.stmt),
+            })
+        },
+    );
+    let alter_task = map(
+        rule! {
+            ALTER ~ TASK ~ ( IF ~ EXISTS )? ~ #ident ~ ^#alter_task_action
+        },
+        |(_, _, opt_if_exists, name, action)| {
+            Statement::AlterTask(AlterTaskStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.name,
+                action,
+            })
+        },
+    );
+    let drop_task = map(
+        rule! {
+            DROP ~ TASK ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropTask(DropTaskStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.name,
+            })
+        },
+    );
+    let execute_task = map(
+        rule! {
+            EXECUTE ~ TASK ~ #ident
+        },
+        |(_, _, name)| Statement::ExecuteTask(ExecuteTaskStmt { name: name.name }),
+    );
+
+    // pipes
+    let create_pipe = map(
+        rule! {
+            CREATE ~ PIPE ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+            ~ AS ~ ^COPY ~ ^INTO ~ ^#period_separated_idents_1_to_3
+            ~ ^FROM ~ ^KAFKA ~ ^#kafka_options
+            ~ ( ON_ERROR ~ ^"=" ~ ^#ident )?
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            opt_comment,
+            _,
+            _,
+            _,
+            (catalog, database, table),
+            _,
+            _,
+            kafka_options,
+            opt_on_error,
+        )| {
+            Statement::CreatePipe(CreatePipeStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.name,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+                catalog,
+                database,
+                table,
+                kafka_options,
+                on_error: opt_on_error.map(|(_, _, on_error)| on_error.to_string()),
+            })
+        },
+    );
+    let drop_pipe = map(
+        rule! {
+            DROP ~ PIPE ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropPipe(DropPipeStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.name,
+            })
+        },
+    );
+    let show_pipes = value(Statement::ShowPipes, rule! { SHOW ~ PIPES });
+
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
-            | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
+            | #explain_pipeline : "`EXPLAIN PIPELINE [FORMAT = (TEXT | MERMAID | DOT | JSON)] <statement>`"
+            | #explain_with_format : "`EXPLAIN (FORMAT (TEXT | JSON)) <statement>`"
+            | #explain : "`EXPLAIN [GRAPH] <statement>`"
             | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
             | #delete : "`DELETE FROM <table> [WHERE ...]`"
             | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
@@ -1162,20 +1804,28 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         ),
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
+            | #insert_multi_table : "`INSERT (ALL | FIRST) (WHEN <condition> THEN (INTO <table> [(<column>, ...)] [VALUES (<expr>, ...)])+)+ [ELSE (INTO <table> ...)+] <query>`"
             | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
         ),
         rule!(
-            #set_variable : "`SET <variable> = <value>`"
+            #set_user_variable : "`SET @<variable> = <value>`"
+            | #set_variable : "`SET <variable> = <value>`"
             | #unset_variable : "`UNSET <variable>`"
+            | #reset_user_variable : "`RESET @<variable>`"
+            | #show_variables : "`SHOW VARIABLES [LIKE '<pattern>']`"
         ),
         rule!(
             #show_tables : "`SHOW [FULL] TABLES [FROM <database>] [<show_limit>]`"
             | #show_columns : "`SHOW [FULL] COLUMNS FROM <table> [FROM|IN <catalog>.<database>] [<show_limit>]`"
             | #show_create_table : "`SHOW CREATE TABLE [<database>.]<table>`"
+            | #describe_function : "`DESCRIBE FUNCTION <function_name>`"
             | #describe_table : "`DESCRIBE [<database>.]<table>`"
             | #show_fields : "`SHOW FIELDS FROM [<database>.]<table>`"
             | #show_tables_status : "`SHOW TABLES STATUS [FROM <database>] [<show_limit>]`"
             | #create_table : "`CREATE TABLE [IF NOT EXISTS] [<database>.]<table> [<source>] [<table_options>]`"
+            | #attach_table : "`ATTACH TABLE [<database>.]<table> FROM <uri_location> READ_ONLY`"
+            | #export_table_snapshot : "`EXPORT SNAPSHOT TABLE [<database>.]<table> TO LOCATION = '@<stage>/<path>'`"
+            | #import_table_snapshot : "`IMPORT SNAPSHOT TABLE [<database>.]<table> FROM LOCATION = '@<stage>/<path>'`"
             | #drop_table : "`DROP TABLE [IF EXISTS] [<database>.]<table>`"
             | #undrop_table : "`UNDROP TABLE [<database>.]<table>`"
             | #alter_table : "`ALTER TABLE [<database>.]<table> <action>`"
@@ -1191,17 +1841,51 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
         ),
+        rule!(
+            #create_stream : "`CREATE STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table>`"
+            | #drop_stream : "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
+            | #show_streams : "`SHOW STREAMS [FROM <database>] [<show_limit>]`"
+        ),
+        rule!(
+            #create_task : "`CREATE TASK [IF NOT EXISTS] <name> SCHEDULE = '<schedule>' [COMMENT = '<comment>'] AS <statement>`"
+            | #alter_task : "`ALTER TASK [IF EXISTS] <name> (SUSPEND | RESUME)`"
+            | #drop_task : "`DROP TASK [IF EXISTS] <name>`"
+            | #execute_task : "`EXECUTE TASK <name>`"
+        ),
+        rule!(
+            #create_pipe : "`CREATE PIPE [IF NOT EXISTS] <name> [COMMENT = '<comment>'] AS COPY INTO [<database>.]<table> FROM KAFKA (<option> = '<value>', ...) [ON_ERROR = <on_error>]`"
+            | #drop_pipe : "`DROP PIPE [IF EXISTS] <name>`"
+            | #show_pipes : "`SHOW PIPES`"
+        ),
         rule!(
             #show_users : "`SHOW USERS`"
             | #create_user : "`CREATE USER [IF NOT EXISTS] '<username>'@'hostname' IDENTIFIED [WITH <auth_type>] [BY <password>] [WITH <user_option>, ...]`"
             | #alter_user : "`ALTER USER ('<username>'@'hostname' | USER()) [IDENTIFIED [WITH <auth_type>] [BY <password>]] [WITH <user_option>, ...]`"
             | #drop_user : "`DROP USER [IF EXISTS] '<username>'@'hostname'`"
             | #show_roles : "`SHOW ROLES`"
+            | #show_role_hierarchy : "`SHOW ROLE HIERARCHY`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] '<role_name>']`"
             | #drop_role : "`DROP ROLE [IF EXISTS] '<role_name>'`"
             | #create_udf : "`CREATE FUNCTION [IF NOT EXISTS] <udf_name> (<parameter>, ...) -> <definition expr> [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
+            | #create_external_function : "`CREATE EXTERNAL FUNCTION [IF NOT EXISTS] <name> (<arg> <type>, ...) RETURNS <type> LANGUAGE <language> HANDLER = '<handler>' ADDRESS = '<address>' [HEADERS = ('<key>' = '<value>' ...)] [<option> = <value> ...]`"
+            | #drop_external_function : "`DROP EXTERNAL FUNCTION [IF EXISTS] <name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
+            | #create_masking_policy : "`CREATE MASKING POLICY [IF NOT EXISTS] <name> AS (<arg> <type>, ...) RETURNS <type> -> <expr> [COMMENT = '<comment>']`"
+            | #drop_masking_policy : "`DROP MASKING POLICY [IF EXISTS] <name>`"
+            | #create_network_policy : "`CREATE NETWORK POLICY [IF NOT EXISTS] <name> ALLOWED_IP_LIST = ('<ip>', ...) [BLOCKED_IP_LIST = ('<ip>', ...)] [COMMENT = '<comment>']`"
+            | #drop_network_policy : "`DROP NETWORK POLICY [IF EXISTS] <name>`"
+            | #desc_network_policy : "`DESC NETWORK POLICY <name>`"
+            | #show_network_policies : "`SHOW NETWORK POLICIES`"
+        ),
+        rule!(
+            #create_row_access_policy : "`CREATE ROW ACCESS POLICY [IF NOT EXISTS] <name> AS (<arg> <type>, ...) RETURNS BOOLEAN -> <expr> [COMMENT = '<comment>']`"
+            | #drop_row_access_policy : "`DROP ROW ACCESS POLICY [IF EXISTS] <name>`"
+            | #show_row_access_policies : "`SHOW ROW ACCESS POLICIES`"
+            | #create_sequence : "`CREATE SEQUENCE [IF NOT EXISTS] <name> [START = <n>] [INCREMENT = <n>] [MINVALUE = <n>] [MAXVALUE = <n>] [CYCLE | NO CYCLE]`"
+            | #drop_sequence : "`DROP SEQUENCE [IF EXISTS] <name>`"
+            | #show_sequences : "`SHOW SEQUENCES`"
+            | #show_index_build_status : "`SHOW INDEX BUILD STATUS`"
         ),
         rule!(
             #create_stage: "`CREATE STAGE [ IF NOT EXISTS ] <stage_name>
@@ -1209,7 +1893,12 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 [ COPY_OPTIONS = ( copyOptions ) ]
                 [ COMMENT = '<string_literal>' ]`"
             | #desc_stage: "`DESC STAGE <stage_name>`"
+            | #alter_stage_connection: "`ALTER STAGE <stage_name> CONNECTION = ( <connection_options> )`"
+            | #alter_stage: "`ALTER STAGE <stage_name> SET [ URL = '<url>' ] [ CONNECTION = ( <connection_options> ) ] [ FILE_FORMAT = ( <format_options> ) ] [ COPY_OPTIONS = ( <copy_options> ) ]`"
+            | #rename_stage: "`RENAME STAGE <stage_name> TO <new_stage_name>`"
+            | #show_create_stage: "`SHOW CREATE [ EXTERNAL ] STAGE <stage_name>`"
             | #list_stage: "`LIST @<stage_name> [pattern = '<pattern>']`"
+            | #show_stage_files: "`SHOW STAGE FILES IN @<stage_name> [PATTERN = '<pattern>'] [LIMIT <limit>]`"
             | #remove_stage: "`REMOVE @<stage_name> [pattern = '<pattern>']`"
             | #drop_stage: "`DROP STAGE <stage_name>`"
         ),
@@ -1227,6 +1916,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 [ PATTERN = '<regex_pattern>' ]
                 [ VALIDATION_MODE = RETURN_ROWS ]
                 [ copyOptions ]`"
+            | #show_copy_history: "`SHOW COPY HISTORY FOR TABLE [<database>.]<table> [SINCE => '<timestamp>']`"
         ),
         rule!(
             #call: "`CALL <procedure_name>(<parameter>, ...)`"
@@ -1248,7 +1938,8 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #drop_share: "`DROP SHARE [IF EXISTS] <share_name>`"
             | #grant_share_object: "`GRANT { USAGE | SELECT | REFERENCE_USAGE } ON { DATABASE db | TABLE db.table } TO SHARE <share_name>`"
             | #revoke_share_object: "`REVOKE { USAGE | SELECT | REFERENCE_USAGE } ON { DATABASE db | TABLE db.table } FROM SHARE <share_name>`"
-            | #alter_share_tenants: "`ALTER SHARE [IF EXISTS] <share_name> { ADD | REMOVE } TENANTS = tenant [, tenant, ...]`"
+            | #alter_share_tenants: "`ALTER SHARE [IF EXISTS] <share_name> { ADD | REMOVE } { TENANTS | ACCOUNTS } = [(] tenant [, tenant, ...] [)] [SHARE_RESTRICTIONS = (READ_ONLY = <bool>)]`"
+            | #alter_share_comment: "`ALTER SHARE [IF EXISTS] <share_name> SET COMMENT = '<string_literal>'`"
             | #desc_share: "`{DESC | DESCRIBE} SHARE <share_name>`"
             | #show_shares: "`SHOW SHARES`"
         ),
@@ -1259,6 +1950,16 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         | #create_catalog: "`CREATE CATALOG [IF NOT EXISTS] <catalog> TYPE=<catalog_type> CONNECTION=<catalog_options>`"
         | #drop_catalog: "`DROP CATALOG [IF EXISTS] <catalog>`"
         ),
+        // result cache
+        rule!(
+            #drop_result_cache: "`DROP RESULT CACHE`"
+        ),
+        // transaction
+        rule!(
+            #begin : "`BEGIN [TRANSACTION]`"
+            | #commit : "`COMMIT`"
+            | #abort : "`(ABORT | ROLLBACK)`"
+        ),
     ));
 
     map(
@@ -1314,6 +2015,39 @@ pub fn insert_source(i: Input) -> IResult<InsertSource> {
     )(i)
 }
 
+pub fn insert_when_clause(i: Input) -> IResult<InsertWhenClause> {
+    map(
+        rule! {
+            WHEN ~ ^#expr ~ ^THEN ~ #insert_into_clause+
+        },
+        |(_, condition, _, into_clauses)| InsertWhenClause {
+            condition,
+            into_clauses,
+        },
+    )(i)
+}
+
+pub fn insert_into_clause(i: Input) -> IResult<InsertIntoClause> {
+    map(
+        rule! {
+            INTO ~ ^#period_separated_idents_1_to_3
+            ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
+            ~ ( VALUES ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+        },
+        |(_, (catalog, database, table), opt_columns, opt_values)| InsertIntoClause {
+            catalog,
+            database,
+            table,
+            columns: opt_columns
+                .map(|(_, columns, _)| columns)
+                .unwrap_or_default(),
+            values: opt_values
+                .map(|(_, _, values, _)| values)
+                .unwrap_or_default(),
+        },
+    )(i)
+}
+
 pub fn unset_source(i: Input) -> IResult<UnSetSource> {
     //#ident ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ")")?
     let var = map(
@@ -1405,6 +2139,40 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     )(i)
 }
 
+pub fn masking_policy_arg(i: Input) -> IResult<MaskingPolicyArg> {
+    map(
+        rule! {
+            #ident ~ #type_name
+        },
+        |(name, data_type)| MaskingPolicyArg { name, data_type },
+    )(i)
+}
+
+pub fn row_access_policy_arg(i: Input) -> IResult<RowAccessPolicyArg> {
+    map(
+        rule! {
+            #ident ~ #type_name
+        },
+        |(name, data_type)| RowAccessPolicyArg { name, data_type },
+    )(i)
+}
+
+pub fn sequence_cycle(i: Input) -> IResult<bool> {
+    alt((
+        value(true, rule! { CYCLE }),
+        value(false, rule! { NO ~ CYCLE }),
+    ))(i)
+}
+
+pub fn external_function_arg(i: Input) -> IResult<(Identifier, TypeName)> {
+    map(
+        rule! {
+            #ident ~ ":"? ~ #type_name
+        },
+        |(name, _, data_type)| (name, data_type),
+    )(i)
+}
+
 pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
     let role = map(
         rule! {
@@ -1421,6 +2189,16 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
             level,
         },
     );
+    let column_privs = map(
+        rule! {
+            #priv_type ~ "(" ~ #comma_separated_list1(ident) ~ ")" ~ ON ~ #grant_level
+        },
+        |(privilege, _, columns, _, _, level)| AccountMgrSource::ColumnPrivs {
+            privilege,
+            columns: columns.into_iter().map(|c| c.name).collect(),
+            level,
+        },
+    );
     let all = map(
         rule! { ALL ~ PRIVILEGES? ~ ON ~ #grant_level },
         |(_, _, _, level)| AccountMgrSource::ALL { level },
@@ -1428,6 +2206,7 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
 
     rule!(
         #role : "ROLE <role_name>"
+        | #column_privs : "<privilege>(<column>, ...) ON <table>"
         | #privs : "<privileges> ON <privileges_level>"
         | #all : "ALL [ PRIVILEGES ] ON <privileges_level>"
     )(i)
@@ -1449,6 +2228,13 @@ pub fn priv_type(i: Input) -> IResult<UserPrivilegeType> {
         value(UserPrivilegeType::Grant, rule! { GRANT }),
         value(UserPrivilegeType::CreateStage, rule! { CREATE ~ STAGE }),
         value(UserPrivilegeType::Set, rule! { SET }),
+        value(UserPrivilegeType::Execute, rule! { EXECUTE }),
+        value(UserPrivilegeType::Read, rule! { READ }),
+        value(UserPrivilegeType::Write, rule! { WRITE }),
+        value(
+            UserPrivilegeType::BypassRowAccessPolicy,
+            rule! { BYPASS ~ ROW ~ ACCESS ~ POLICY },
+        ),
         value(UserPrivilegeType::Drop, rule! { DROP }),
         value(UserPrivilegeType::Create, rule! { CREATE }),
     ))(i)
@@ -1469,6 +2255,25 @@ pub fn alter_add_share_accounts(i: Input) -> IResult<bool> {
     alt((value(true, rule! { ADD }), value(false, rule! { REMOVE })))(i)
 }
 
+pub fn share_account_list(i: Input) -> IResult<Vec<Identifier>> {
+    alt((
+        map(
+            rule! { "(" ~ #comma_separated_list1(ident) ~ ")" },
+            |(_, accounts, _)| accounts,
+        ),
+        comma_separated_list1(ident),
+    ))(i)
+}
+
+pub fn share_restrictions(i: Input) -> IResult<bool> {
+    map(
+        rule! {
+            SHARE_RESTRICTIONS ~ Eq ~ "(" ~ READ_ONLY ~ Eq ~ #literal_bool ~ ")"
+        },
+        |(_, _, _, _, _, read_only, _)| read_only,
+    )(i)
+}
+
 pub fn grant_share_object_name(i: Input) -> IResult<ShareGrantObjectName> {
     let database = map(
         rule! {
@@ -1515,8 +2320,14 @@ pub fn grant_level(i: Input) -> IResult<AccountMgrLevel> {
         },
     );
 
+    // STAGE stage1
+    let stage = map(rule! { STAGE ~ #ident }, |(_, stage)| {
+        AccountMgrLevel::Stage(stage.name)
+    });
+
     rule!(
         #global : "*.*"
+        | #stage : "STAGE <stage>"
         | #db : "<database>.*"
         | #table : "<database>.<table>"
     )(i)
@@ -1603,9 +2414,43 @@ pub fn alter_database_action(i: Input) -> IResult<AlterDatabaseAction> {
         },
         |(_, _, new_db)| AlterDatabaseAction::RenameDatabase { new_db },
     );
+    let set_quota = map_res(
+        rule! {
+            SET ~ QUOTA ~ ^"(" ~ ^#comma_separated_list1(database_quota_option) ~ ^")"
+        },
+        |(_, _, _, opts, _)| {
+            let mut quota = DatabaseQuotaOptions::default();
+            for (name, value) in opts {
+                let field = match name.name.to_lowercase().as_str() {
+                    "max_tables" => &mut quota.max_tables,
+                    "max_bytes" => &mut quota.max_bytes,
+                    "max_rows" => &mut quota.max_rows,
+                    _ => {
+                        return Err(ErrorKind::Other(
+                            "expected MAX_TABLES, MAX_BYTES or MAX_ROWS",
+                        ));
+                    }
+                };
+                if field.replace(value).is_some() {
+                    return Err(ErrorKind::Other("duplicated quota option"));
+                }
+            }
+            Ok(AlterDatabaseAction::SetQuota { quota })
+        },
+    );
 
     rule!(
         #rename_database
+        | #set_quota
+    )(i)
+}
+
+pub fn database_quota_option(i: Input) -> IResult<(Identifier, u64)> {
+    map(
+        rule! {
+            #ident ~ "=" ~ #literal_u64
+        },
+        |(name, _, value)| (name, value),
     )(i)
 }
 
@@ -1618,9 +2463,12 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     );
     let add_column = map(
         rule! {
-            ADD ~ COLUMN ~ #column_def
+            ADD ~ COLUMN ~ #column_def ~ ( #add_column_option )?
+        },
+        |(_, _, column, option)| AlterTableAction::AddColumn {
+            column,
+            option: option.unwrap_or(AddColumnOption::End),
         },
-        |(_, _, column)| AlterTableAction::AddColumn { column },
     );
     let drop_column = map(
         rule! {
@@ -1628,11 +2476,50 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
         |(_, _, column)| AlterTableAction::DropColumn { column },
     );
+    let rename_column = map(
+        rule! {
+            RENAME ~ COLUMN ~ #ident ~ TO ~ #ident
+        },
+        |(_, _, old_column, _, new_column)| AlterTableAction::RenameColumn {
+            old_column,
+            new_column,
+        },
+    );
+    let modify_column_action = alt((
+        map(
+            rule! {
+                SET ~ MASKING ~ POLICY ~ #ident
+            },
+            |(_, _, _, policy)| ModifyColumnAction::SetMaskingPolicy(policy),
+        ),
+        map(
+            rule! {
+                UNSET ~ MASKING ~ POLICY
+            },
+            |(_, _, _)| ModifyColumnAction::UnsetMaskingPolicy,
+        ),
+        map(
+            rule! {
+                #type_name ~ ( USING ~ ^#expr )? ~ CASCADE?
+            },
+            |(data_type, using, cascade)| ModifyColumnAction::SetDataType {
+                data_type,
+                using: using.map(|(_, using)| using),
+                cascade: cascade.is_some(),
+            },
+        ),
+    ));
+    let modify_column = map(
+        rule! {
+            MODIFY ~ COLUMN ~ #ident ~ #modify_column_action
+        },
+        |(_, _, column, action)| AlterTableAction::ModifyColumn { column, action },
+    );
     let alter_table_cluster_key = map(
         rule! {
-            CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+            CLUSTER ~ ^BY ~ ^#cluster_by
         },
-        |(_, _, _, cluster_by, _)| AlterTableAction::AlterTableClusterKey { cluster_by },
+        |(_, _, cluster_by)| AlterTableAction::AlterTableClusterKey { cluster_by },
     );
 
     let drop_table_cluster_key = map(
@@ -1644,11 +2531,12 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
 
     let recluster_table = map(
         rule! {
-            RECLUSTER ~ FINAL? ~ ( WHERE ~ ^#expr )?
+            RECLUSTER ~ FINAL? ~ ( WHERE ~ ^#expr )? ~ ( LIMIT ~ #literal_u64 )?
         },
-        |(_, opt_is_final, opt_selection)| AlterTableAction::ReclusterTable {
+        |(_, opt_is_final, opt_selection, opt_limit)| AlterTableAction::ReclusterTable {
             is_final: opt_is_final.is_some(),
             selection: opt_selection.map(|(_, selection)| selection),
+            limit: opt_limit.map(|(_, limit)| limit),
         },
     );
 
@@ -1659,17 +2547,68 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, point)| AlterTableAction::RevertTo { point },
     );
 
+    let set_analyze_interval = map(
+        rule! {
+            SET ~ ANALYZE_INTERVAL ~ "=" ~ #literal_u64
+        },
+        |(_, _, _, interval_secs)| AlterTableAction::SetAnalyzeInterval { interval_secs },
+    );
+
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ POLICY ~ #ident ~ ON ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
+    let add_bloom_filter_index = value(AlterTableAction::AddBloomFilterIndex, rule! {
+        ADD ~ BLOOM ~ FILTER ~ INDEX
+    });
+
+    let set_connection = map(
+        rule! {
+            CONNECTION ~ "=" ~ #connection_options
+        },
+        |(_, _, connection)| AlterTableAction::SetConnection { connection },
+    );
+
     rule!(
         #rename_table
+        | #rename_column
         | #add_column
         | #drop_column
+        | #modify_column
         | #alter_table_cluster_key
         | #drop_table_cluster_key
         | #recluster_table
         | #revert_table
+        | #set_analyze_interval
+        | #add_row_access_policy
+        | #drop_row_access_policy
+        | #add_bloom_filter_index
+        | #set_connection
     )(i)
 }
 
+pub fn add_column_option(i: Input) -> IResult<AddColumnOption> {
+    alt((
+        value(AddColumnOption::First, rule! { FIRST }),
+        map(rule! { AFTER ~ #ident }, |(_, ident)| {
+            AddColumnOption::After(ident)
+        }),
+    ))(i)
+}
+
 pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
     alt((
         value(OptimizeTableAction::All, rule! { ALL }),
@@ -1771,6 +2710,28 @@ pub fn show_limit(i: Input) -> IResult<ShowLimit> {
     )(i)
 }
 
+/// The keys of `CLUSTER BY`, either `(<expr>, ...)` for the linear clustering, or
+/// `ZORDER(<column>, ...)` for the clustering by the Z-order key of the columns.
+pub fn cluster_by(i: Input) -> IResult<Vec<Expr>> {
+    let linear = map(
+        rule! {
+            "(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, exprs, _)| exprs,
+    );
+    let zorder = map_res(expr, |expr| match &expr {
+        Expr::FunctionCall { name, .. } if name.name.eq_ignore_ascii_case("zorder") => {
+            Ok(vec![expr])
+        }
+        _ => Err(ErrorKind::Other("expecting `ZORDER(<column>, ...)`")),
+    });
+
+    rule!(
+        #linear
+        | #zorder
+    )(i)
+}
+
 pub fn table_option(i: Input) -> IResult<BTreeMap<String, String>> {
     map(
         rule! {
@@ -1853,6 +2814,16 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, role)| UserOptionItem::DefaultRole(role),
     );
+    let set_network_policy_option = map(
+        rule! {
+            SET ~ NETWORK ~ ^POLICY ~ "="? ~ ^#ident
+        },
+        |(_, _, _, _, policy)| UserOptionItem::SetNetworkPolicy(policy.name),
+    );
+    let unset_network_policy_option = value(
+        UserOptionItem::UnsetNetworkPolicy,
+        rule! { UNSET ~ NETWORK ~ ^POLICY },
+    );
     alt((
         value(UserOptionItem::TenantSetting(true), rule! { TENANTSETTING }),
         value(
@@ -1860,6 +2831,8 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
             rule! { NOTENANTSETTING },
         ),
         default_role_option,
+        set_network_policy_option,
+        unset_network_policy_option,
     ))(i)
 }
 
@@ -1903,6 +2876,10 @@ pub fn copy_option(i: Input) -> IResult<CopyOption> {
             rule! { VALIDATION_MODE ~ "=" ~ #literal_string },
             |(_, _, validation_mode)| CopyOption::ValidationMode(validation_mode),
         ),
+        map(
+            rule! { VALIDATION_MODE ~ "=" ~ #ident },
+            |(_, _, validation_mode)| CopyOption::ValidationMode(validation_mode.to_string()),
+        ),
         map(
             rule! { SIZE_LIMIT ~ "=" ~ #literal_u64 },
             |(_, _, size_limit)| CopyOption::SizeLimit(size_limit as usize),
@@ -1930,6 +2907,29 @@ pub fn copy_option(i: Input) -> IResult<CopyOption> {
     ))(i)
 }
 
+pub fn alter_task_action(i: Input) -> IResult<AlterTaskAction> {
+    alt((
+        value(AlterTaskAction::Suspend, rule! { SUSPEND }),
+        value(AlterTaskAction::Resume, rule! { RESUME }),
+    ))(i)
+}
+
+// parse: (brokers = '<brokers>', topic = '<topic>', ...) into a map
+pub fn kafka_options(i: Input) -> IResult<BTreeMap<String, String>> {
+    // `GROUP` and `FORMAT` are keywords rather than identifiers.
+    let key = alt((
+        map(rule! { GROUP | FORMAT }, |token| {
+            token.text().to_lowercase()
+        }),
+        map(ident, |key| key.name.to_lowercase()),
+    ));
+    let option = map(rule! { #key ~ "=" ~ #literal_string }, |(k, _, v)| (k, v));
+    map(
+        rule! { "(" ~ #comma_separated_list1(option) ~ ")" },
+        |(_, opts, _)| BTreeMap::from_iter(opts),
+    )(i)
+}
+
 pub fn presign_action(i: Input) -> IResult<PresignAction> {
     alt((
         value(PresignAction::Download, rule! { DOWNLOAD }),
@@ -1970,8 +2970,10 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
             table,
             alias: None,
             travel_point: None,
+            sample: None,
             pivot: None,
             unpivot: None,
+            match_recognize: None,
         },
     )(i)
 }
@@ -1981,3 +2983,46 @@ pub fn update_expr(i: Input) -> IResult<UpdateExpr> {
         UpdateExpr { name, expr }
     })(i)
 }
+
+pub fn explain_format(i: Input) -> IResult<ExplainFormat> {
+    alt((
+        value(ExplainFormat::Text, rule! { TEXT }),
+        value(ExplainFormat::Mermaid, rule! { MERMAID }),
+        value(ExplainFormat::Dot, rule! { DOT }),
+        value(ExplainFormat::Json, rule! { JSON }),
+    ))(i)
+}
+
+/// The settings of the `SET_VAR(<name> = <value>, ...)` items of a hint.
+pub fn settings_hint(i: Input) -> IResult<Vec<(String, String)>> {
+    let set_var = map_res(rule! { #ident }, |name| {
+        if name.name.eq_ignore_ascii_case("SET_VAR") {
+            Ok(())
+        } else {
+            Err(ErrorKind::Other("expected SET_VAR"))
+        }
+    });
+    let setting_value = alt((
+        literal_string,
+        map(literal_u64, |v| v.to_string()),
+        map(ident, |v| v.name),
+    ));
+    let setting = map(
+        rule! {
+            #ident ~ "=" ~ ^#setting_value
+        },
+        |(name, _, value)| (name.name.to_lowercase(), value),
+    );
+
+    map(
+        rule! {
+            ( #set_var ~ ^"(" ~ ^#comma_separated_list1(setting) ~ ^")" )+
+        },
+        |set_vars| {
+            set_vars
+                .into_iter()
+                .flat_map(|(_, _, settings, _)| settings)
+                .collect()
+        },
+    )(i)
+}
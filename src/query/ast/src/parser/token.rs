@@ -113,7 +113,7 @@ pub enum TokenKind {
     #[regex(r#"'([^'\\]|\\.|'')*'"#)]
     QuotedString,
 
-    #[regex(r#"@([^\s`;'"])+"#)]
+    #[regex(r#"@([^\s`;'"(),])+"#)]
     AtString,
 
     #[regex(r"[xX]'[a-fA-F0-9]*'")]
@@ -241,10 +241,22 @@ pub enum TokenKind {
     // 2. Search in this file to see if the new keyword is a commented
     //    out reserved keyword. If so, uncomment the keyword in the
     //    reserved list.
+    #[token("ABORT", ignore(ascii_case))]
+    ABORT,
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
+    #[token("ACCOUNTS", ignore(ascii_case))]
+    ACCOUNTS,
     #[token("ALL", ignore(ascii_case))]
     ALL,
+    #[token("ALLOWED_IP_LIST", ignore(ascii_case))]
+    ALLOWED_IP_LIST,
     #[token("ADD", ignore(ascii_case))]
     ADD,
+    #[token("ADDRESS", ignore(ascii_case))]
+    ADDRESS,
+    #[token("AFTER", ignore(ascii_case))]
+    AFTER,
     #[token("ANY", ignore(ascii_case))]
     ANY,
     #[token("ARGS", ignore(ascii_case))]
@@ -257,6 +269,8 @@ pub enum TokenKind {
     ALTER,
     #[token("ANALYZE", ignore(ascii_case))]
     ANALYZE,
+    #[token("ANALYZE_INTERVAL", ignore(ascii_case))]
+    ANALYZE_INTERVAL,
     #[token("AND", ignore(ascii_case))]
     AND,
     #[token("ARRAY", ignore(ascii_case))]
@@ -267,16 +281,30 @@ pub enum TokenKind {
     AST,
     #[token("AT", ignore(ascii_case))]
     AT,
+    #[token("ATTACH", ignore(ascii_case))]
+    ATTACH,
     #[token("ASC", ignore(ascii_case))]
     ASC,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
+    #[token("BERNOULLI", ignore(ascii_case))]
+    BERNOULLI,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
     BIGINT,
+    #[token("BINARY", ignore(ascii_case))]
+    BINARY,
+    #[token("BITMAP", ignore(ascii_case))]
+    BITMAP,
+    #[token("BLOCKED_IP_LIST", ignore(ascii_case))]
+    BLOCKED_IP_LIST,
+    #[token("BLOOM", ignore(ascii_case))]
+    BLOOM,
     #[token("BOOL", ignore(ascii_case))]
     BOOL,
     #[token("BOOLEAN", ignore(ascii_case))]
@@ -285,12 +313,20 @@ pub enum TokenKind {
     BOTH,
     #[token("BY", ignore(ascii_case))]
     BY,
+    #[token("BUILD", ignore(ascii_case))]
+    BUILD,
+    #[token("BYPASS", ignore(ascii_case))]
+    BYPASS,
     #[token("BROTLI", ignore(ascii_case))]
     BROTLI,
     #[token("BZ2", ignore(ascii_case))]
     BZ2,
     #[token("CALL", ignore(ascii_case))]
     CALL,
+    #[token("CACHE", ignore(ascii_case))]
+    CACHE,
+    #[token("CASCADE", ignore(ascii_case))]
+    CASCADE,
     #[token("CASE", ignore(ascii_case))]
     CASE,
     #[token("CAST", ignore(ascii_case))]
@@ -307,8 +343,12 @@ pub enum TokenKind {
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
+    #[token("CONNECT", ignore(ascii_case))]
+    CONNECT,
     #[token("CONNECTION", ignore(ascii_case))]
     CONNECTION,
     #[token("CONTENT_TYPE", ignore(ascii_case))]
@@ -343,6 +383,8 @@ pub enum TokenKind {
     CURRENT,
     #[token("CURRENT_TIMESTAMP", ignore(ascii_case))]
     CURRENT_TIMESTAMP,
+    #[token("CYCLE", ignore(ascii_case))]
+    CYCLE,
     #[token("DATABASE", ignore(ascii_case))]
     DATABASE,
     #[token("DATABASES", ignore(ascii_case))]
@@ -365,6 +407,8 @@ pub enum TokenKind {
     DECIMAL,
     #[token("DEFAULT", ignore(ascii_case))]
     DEFAULT,
+    #[token("DEFINE", ignore(ascii_case))]
+    DEFINE,
     #[token("DEFLATE", ignore(ascii_case))]
     DEFLATE,
     #[token("DELETE", ignore(ascii_case))]
@@ -377,6 +421,8 @@ pub enum TokenKind {
     DISTINCT,
     #[token("DIV", ignore(ascii_case))]
     DIV,
+    #[token("DOT", ignore(ascii_case))]
+    DOT,
     #[token("DOUBLE_SHA1_PASSWORD", ignore(ascii_case))]
     DOUBLE_SHA1_PASSWORD,
     #[token("DOUBLE", ignore(ascii_case))]
@@ -407,14 +453,22 @@ pub enum TokenKind {
     ENGINES,
     #[token("EPOCH", ignore(ascii_case))]
     EPOCH,
+    #[token("ERROR", ignore(ascii_case))]
+    ERROR,
     #[token("ESCAPE", ignore(ascii_case))]
     ESCAPE,
     #[token("EXISTS", ignore(ascii_case))]
     EXISTS,
+    #[token("EXECUTE", ignore(ascii_case))]
+    EXECUTE,
     #[token("EXPLAIN", ignore(ascii_case))]
     EXPLAIN,
     #[token("EXPIRE", ignore(ascii_case))]
     EXPIRE,
+    #[token("EXPORT", ignore(ascii_case))]
+    EXPORT,
+    #[token("EXTERNAL", ignore(ascii_case))]
+    EXTERNAL,
     #[token("EXTRACT", ignore(ascii_case))]
     EXTRACT,
     #[token("FALSE", ignore(ascii_case))]
@@ -431,6 +485,8 @@ pub enum TokenKind {
     FILE,
     #[token("FILES", ignore(ascii_case))]
     FILES,
+    #[token("FILTER", ignore(ascii_case))]
+    FILTER,
     #[token("FINAL", ignore(ascii_case))]
     FINAL,
     #[token("FLASHBACK", ignore(ascii_case))]
@@ -473,10 +529,18 @@ pub enum TokenKind {
     GRAPH,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
+    #[token("GROUPS", ignore(ascii_case))]
+    GROUPS,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
     #[token("HAVING", ignore(ascii_case))]
     HAVING,
+    #[token("HANDLER", ignore(ascii_case))]
+    HANDLER,
+    #[token("HEADERS", ignore(ascii_case))]
+    HEADERS,
+    #[token("HIERARCHY", ignore(ascii_case))]
+    HIERARCHY,
     #[token("HISTORY", ignore(ascii_case))]
     HISTORY,
     #[token("HIVE", ignore(ascii_case))]
@@ -491,8 +555,16 @@ pub enum TokenKind {
     IDENTIFIED,
     #[token("IF", ignore(ascii_case))]
     IF,
+    #[token("IGNORE", ignore(ascii_case))]
+    IGNORE,
+    #[token("IMPORT", ignore(ascii_case))]
+    IMPORT,
     #[token("IN", ignore(ascii_case))]
     IN,
+    #[token("INCREMENT", ignore(ascii_case))]
+    INCREMENT,
+    #[token("INDEX", ignore(ascii_case))]
+    INDEX,
     #[token("INNER", ignore(ascii_case))]
     INNER,
     #[token("INSERT", ignore(ascii_case))]
@@ -527,14 +599,20 @@ pub enum TokenKind {
     JULIAN,
     #[token("JWT", ignore(ascii_case))]
     JWT,
+    #[token("KAFKA", ignore(ascii_case))]
+    KAFKA,
     #[token("KEY", ignore(ascii_case))]
     KEY,
     #[token("KILL", ignore(ascii_case))]
     KILL,
+    #[token("LANGUAGE", ignore(ascii_case))]
+    LANGUAGE,
     #[token("LOCATION_PREFIX", ignore(ascii_case))]
     LOCATION_PREFIX,
     #[token("ROLES", ignore(ascii_case))]
     ROLES,
+    #[token("LATERAL", ignore(ascii_case))]
+    LATERAL,
     #[token("LEADING", ignore(ascii_case))]
     LEADING,
     #[token("LEFT", ignore(ascii_case))]
@@ -545,18 +623,36 @@ pub enum TokenKind {
     LIMIT,
     #[token("LIST", ignore(ascii_case))]
     LIST,
+    #[token("LISTAGG", ignore(ascii_case))]
+    LISTAGG,
+    #[token("LOCATION", ignore(ascii_case))]
+    LOCATION,
     #[token("LZO", ignore(ascii_case))]
     LZO,
     #[token("MAP", ignore(ascii_case))]
     MAP,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MASKING", ignore(ascii_case))]
+    MASKING,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
+    #[token("MATCH", ignore(ascii_case))]
+    MATCH,
+    #[token("MATCH_RECOGNIZE", ignore(ascii_case))]
+    MATCH_RECOGNIZE,
+    #[token("MATERIALIZED", ignore(ascii_case))]
+    MATERIALIZED,
+    #[token("MAXVALUE", ignore(ascii_case))]
+    MAXVALUE,
+    #[token("MEASURES", ignore(ascii_case))]
+    MEASURES,
     #[token("MEMO", ignore(ascii_case))]
     MEMO,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("MERMAID", ignore(ascii_case))]
+    MERMAID,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
@@ -567,6 +663,10 @@ pub enum TokenKind {
     MILLISECONDS,
     #[token("MINUTE", ignore(ascii_case))]
     MINUTE,
+    #[token("MINVALUE", ignore(ascii_case))]
+    MINVALUE,
+    #[token("MODIFY", ignore(ascii_case))]
+    MODIFY,
     #[token("MONTH", ignore(ascii_case))]
     MONTH,
     #[token("NON_DISPLAY", ignore(ascii_case))]
@@ -575,6 +675,10 @@ pub enum TokenKind {
     NATURAL,
     #[token("NDJSON", ignore(ascii_case))]
     NDJSON,
+    #[token("NETWORK", ignore(ascii_case))]
+    NETWORK,
+    #[token("NO", ignore(ascii_case))]
+    NO,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
     #[token("NONE", ignore(ascii_case))]
@@ -595,18 +699,24 @@ pub enum TokenKind {
     OFFSET,
     #[token("ON", ignore(ascii_case))]
     ON,
+    #[token("ONE", ignore(ascii_case))]
+    ONE,
     #[token("OPTIMIZE", ignore(ascii_case))]
     OPTIMIZE,
     #[token("OR", ignore(ascii_case))]
     OR,
     #[token("ORDER", ignore(ascii_case))]
     ORDER,
+    #[token("OTHERS", ignore(ascii_case))]
+    OTHERS,
     #[token("OUTER", ignore(ascii_case))]
     OUTER,
     #[token("ON_ERROR", ignore(ascii_case))]
     ON_ERROR,
     #[token("OVER", ignore(ascii_case))]
     OVER,
+    #[token("OVERFLOW", ignore(ascii_case))]
+    OVERFLOW,
     #[token("OVERWRITE", ignore(ascii_case))]
     OVERWRITE,
     #[token("PARTITION", ignore(ascii_case))]
@@ -615,10 +725,24 @@ pub enum TokenKind {
     PARQUET,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
+    #[token("PER", ignore(ascii_case))]
+    PER,
+    #[token("PERCENTILE_CONT", ignore(ascii_case))]
+    PERCENTILE_CONT,
+    #[token("PERCENTILE_DISC", ignore(ascii_case))]
+    PERCENTILE_DISC,
+    #[token("PIPE", ignore(ascii_case))]
+    PIPE,
+    #[token("PIPES", ignore(ascii_case))]
+    PIPES,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
     PLAINTEXT_PASSWORD,
+    #[token("POLICY", ignore(ascii_case))]
+    POLICY,
+    #[token("POLICIES", ignore(ascii_case))]
+    POLICIES,
     #[token("POSITION", ignore(ascii_case))]
     POSITION,
     #[token("PROCESSLIST", ignore(ascii_case))]
@@ -631,10 +755,16 @@ pub enum TokenKind {
     QUERY,
     #[token("QUOTE", ignore(ascii_case))]
     QUOTE,
+    #[token("QUOTA", ignore(ascii_case))]
+    QUOTA,
     #[token("RANGE", ignore(ascii_case))]
     RANGE,
     #[token("RAWDEFLATE", ignore(ascii_case))]
     RAWDEFLATE,
+    #[token("READ", ignore(ascii_case))]
+    READ,
+    #[token("READ_ONLY", ignore(ascii_case))]
+    READ_ONLY,
     #[token("RECLUSTER", ignore(ascii_case))]
     RECLUSTER,
     #[token("RECORD_DELIMITER", ignore(ascii_case))]
@@ -647,6 +777,18 @@ pub enum TokenKind {
     RENAME,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
+    #[token("REPEATABLE", ignore(ascii_case))]
+    REPEATABLE,
+    #[token("RESET", ignore(ascii_case))]
+    RESET,
+    #[token("RESPECT", ignore(ascii_case))]
+    RESPECT,
+    #[token("RESULT", ignore(ascii_case))]
+    RESULT,
+    #[token("RESUME", ignore(ascii_case))]
+    RESUME,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
     #[token("ROW", ignore(ascii_case))]
     ROW,
     #[token("ROWS", ignore(ascii_case))]
@@ -663,10 +805,14 @@ pub enum TokenKind {
     PRECISION,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PRIOR", ignore(ascii_case))]
+    PRIOR,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("REMOVE", ignore(ascii_case))]
     REMOVE,
+    #[token("RETURNS", ignore(ascii_case))]
+    RETURNS,
     #[token("REVOKE", ignore(ascii_case))]
     REVOKE,
     #[token("RECURSIVE", ignore(ascii_case))]
@@ -679,6 +825,8 @@ pub enum TokenKind {
     RLIKE,
     #[token("RAW", ignore(ascii_case))]
     RAW,
+    #[token("SCHEDULE", ignore(ascii_case))]
+    SCHEDULE,
     #[token("SCHEMA", ignore(ascii_case))]
     SCHEMA,
     #[token("SCHEMAS", ignore(ascii_case))]
@@ -693,6 +841,10 @@ pub enum TokenKind {
     UNPIVOT,
     #[token("SEGMENT", ignore(ascii_case))]
     SEGMENT,
+    #[token("SEQUENCE", ignore(ascii_case))]
+    SEQUENCE,
+    #[token("SEQUENCES", ignore(ascii_case))]
+    SEQUENCES,
     #[token("SET", ignore(ascii_case))]
     SET,
     #[token("UNSET", ignore(ascii_case))]
@@ -709,6 +861,8 @@ pub enum TokenKind {
     SHOW,
     #[token("SIGNED", ignore(ascii_case))]
     SIGNED,
+    #[token("SINCE", ignore(ascii_case))]
+    SINCE,
     #[token("SINGLE", ignore(ascii_case))]
     SINGLE,
     #[token("SIZE_LIMIT", ignore(ascii_case))]
@@ -725,6 +879,8 @@ pub enum TokenKind {
     SPLIT_SIZE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
     #[token("USAGE", ignore(ascii_case))]
@@ -737,22 +893,36 @@ pub enum TokenKind {
     SHARE,
     #[token("SHARES", ignore(ascii_case))]
     SHARES,
+    #[token("SHARE_RESTRICTIONS", ignore(ascii_case))]
+    SHARE_RESTRICTIONS,
     #[token("SUPER", ignore(ascii_case))]
     SUPER,
+    #[token("SUSPEND", ignore(ascii_case))]
+    SUSPEND,
     #[token("STATUS", ignore(ascii_case))]
     STATUS,
+    #[token("STREAM", ignore(ascii_case))]
+    STREAM,
+    #[token("STREAMS", ignore(ascii_case))]
+    STREAMS,
     #[token("STRING", ignore(ascii_case))]
     STRING,
     #[token("SUBSTRING", ignore(ascii_case))]
     SUBSTRING,
     #[token("SUBSTR", ignore(ascii_case))]
     SUBSTR,
+    #[token("SYSTEM", ignore(ascii_case))]
+    SYSTEM,
     #[token("SEMI", ignore(ascii_case))]
     SEMI,
     #[token("TABLE", ignore(ascii_case))]
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TABLESAMPLE", ignore(ascii_case))]
+    TABLESAMPLE,
+    #[token("TASK", ignore(ascii_case))]
+    TASK,
     #[token("TEXT", ignore(ascii_case))]
     TEXT,
     #[token("TENANTSETTING", ignore(ascii_case))]
@@ -763,6 +933,8 @@ pub enum TokenKind {
     TENANT,
     #[token("THEN", ignore(ascii_case))]
     THEN,
+    #[token("TIES", ignore(ascii_case))]
+    TIES,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
@@ -779,6 +951,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -827,10 +1001,14 @@ pub enum TokenKind {
     VALUES,
     #[token("VALIDATION_MODE", ignore(ascii_case))]
     VALIDATION_MODE,
+    #[token("VARBINARY", ignore(ascii_case))]
+    VARBINARY,
     #[token("VARCHAR", ignore(ascii_case))]
     VARCHAR,
     #[token("VARIANT", ignore(ascii_case))]
     VARIANT,
+    #[token("VARIABLES", ignore(ascii_case))]
+    VARIABLES,
     #[token("VIEW", ignore(ascii_case))]
     VIEW,
     #[token("WEEK", ignore(ascii_case))]
@@ -841,6 +1019,12 @@ pub enum TokenKind {
     WHERE,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WITHIN", ignore(ascii_case))]
+    WITHIN,
+    #[token("WITHOUT", ignore(ascii_case))]
+    WITHOUT,
+    #[token("WRITE", ignore(ascii_case))]
+    WRITE,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -1150,13 +1334,14 @@ impl TokenKind {
             | TokenKind::SELECT
             | TokenKind::PIVOT
             | TokenKind::UNPIVOT
+            | TokenKind::MATCH_RECOGNIZE
             // | TokenKind::SESSION_USER
             // | TokenKind::SIMILAR
             | TokenKind::SOME
             | TokenKind::SEMI
             // | TokenKind::SYMMETRIC
             // | TokenKind::TABLE
-            // | TokenKind::TABLESAMPLE
+            | TokenKind::TABLESAMPLE
             | TokenKind::THEN
             | TokenKind::TRAILING
             | TokenKind::TRUE
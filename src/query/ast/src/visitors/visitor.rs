@@ -194,6 +194,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_count_all(&mut self, _span: Span) {}
 
+    fn visit_variable(&mut self, _span: Span, _name: &'ast str) {}
+
     fn visit_tuple(&mut self, _span: Span, elements: &'ast [Expr]) {
         for element in elements {
             walk_expr(self, element);
@@ -208,11 +210,16 @@ pub trait Visitor<'ast>: Sized {
         args: &'ast [Expr],
         _params: &'ast [Literal],
         over: &'ast Option<WindowSpec>,
+        lambda: &'ast Option<Lambda>,
     ) {
         for arg in args {
             walk_expr(self, arg);
         }
 
+        if let Some(lambda) = lambda {
+            walk_expr(self, &lambda.expr);
+        }
+
         if let Some(over) = over {
             over.partition_by
                 .iter()
@@ -228,6 +235,31 @@ pub trait Visitor<'ast>: Sized {
         }
     }
 
+    fn visit_list_agg(
+        &mut self,
+        _span: Span,
+        _distinct: bool,
+        expr: &'ast Expr,
+        _delimiter: &'ast Option<String>,
+        _on_overflow: &'ast Option<ListAggOverflow>,
+        within_group: &'ast Option<Box<OrderByExpr>>,
+    ) {
+        walk_expr(self, expr);
+        if let Some(order_by) = within_group {
+            walk_expr(self, &order_by.expr);
+        }
+    }
+
+    fn visit_percentile(
+        &mut self,
+        _span: Span,
+        _kind: PercentileKind,
+        _level: &'ast Literal,
+        within_group: &'ast OrderByExpr,
+    ) {
+        walk_expr(self, &within_group.expr);
+    }
+
     fn visit_frame_bound(&mut self, bound: &'ast WindowFrameBound) {
         match bound {
             WindowFrameBound::Preceding(Some(expr)) => walk_expr(self, expr.as_ref()),
@@ -336,6 +368,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_copy_unit(&mut self, _copy_unit: &'ast CopyUnit) {}
 
+    fn visit_show_copy_history(&mut self, _stmt: &'ast ShowCopyHistoryStmt) {}
+
     fn visit_call(&mut self, _call: &'ast CallStmt) {}
 
     fn visit_show_settings(&mut self, _like: &'ast Option<String>) {}
@@ -364,9 +398,16 @@ pub trait Visitor<'ast>: Sized {
     ) {
     }
 
+    fn visit_set_user_variable(&mut self, _variable: &'ast str, _value: &'ast Expr) {}
+
+    fn visit_reset_user_variable(&mut self, _variable: &'ast str) {}
+
+    fn visit_show_variables(&mut self, _like: &'ast Option<String>) {}
+
     fn visit_set_role(&mut self, _is_default: bool, _role_name: &'ast str) {}
 
     fn visit_insert(&mut self, _insert: &'ast InsertStmt) {}
+    fn visit_insert_multi_table(&mut self, _insert: &'ast InsertMultiTableStmt) {}
     fn visit_replace(&mut self, _replace: &'ast ReplaceStmt) {}
 
     fn visit_insert_source(&mut self, _insert_source: &'ast InsertSource) {}
@@ -416,6 +457,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_create_table_source(&mut self, _source: &'ast CreateTableSource) {}
 
+    fn visit_attach_table(&mut self, _stmt: &'ast AttachTableStmt) {}
+
+    fn visit_export_table_snapshot(&mut self, _stmt: &'ast ExportTableSnapshotStmt) {}
+
+    fn visit_import_table_snapshot(&mut self, _stmt: &'ast ImportTableSnapshotStmt) {}
+
     fn visit_column_definition(&mut self, _column_definition: &'ast ColumnDefinition) {}
 
     fn visit_drop_table(&mut self, _stmt: &'ast DropTableStmt) {}
@@ -440,6 +487,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
+
+    fn visit_show_streams(&mut self, _stmt: &'ast ShowStreamsStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &'ast CreateUserStmt) {}
@@ -450,6 +503,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_roles(&mut self) {}
 
+    fn visit_show_role_hierarchy(&mut self) {}
+
     fn visit_create_role(&mut self, _if_not_exists: bool, _role_name: &'ast str) {}
 
     fn visit_drop_role(&mut self, _if_exists: bool, _role_name: &'ast str) {}
@@ -472,6 +527,10 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_udf(&mut self, _if_exists: bool, _udf_name: &'ast Identifier) {}
 
+    fn visit_create_external_function(&mut self, _stmt: &'ast CreateExternalFunctionStmt) {}
+
+    fn visit_drop_external_function(&mut self, _stmt: &'ast DropExternalFunctionStmt) {}
+
     fn visit_alter_udf(
         &mut self,
         _udf_name: &'ast Identifier,
@@ -481,6 +540,56 @@ pub trait Visitor<'ast>: Sized {
     ) {
     }
 
+    fn visit_describe_function(&mut self, _name: &'ast Identifier) {}
+
+    fn visit_create_masking_policy(&mut self, _stmt: &'ast CreateMaskingPolicyStmt) {}
+
+    fn visit_drop_masking_policy(&mut self, _stmt: &'ast DropMaskingPolicyStmt) {}
+
+    fn visit_create_row_access_policy(&mut self, _stmt: &'ast CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &'ast DropRowAccessPolicyStmt) {}
+
+    fn visit_show_row_access_policies(&mut self) {}
+
+    fn visit_create_sequence(&mut self, _stmt: &'ast CreateSequenceStmt) {}
+
+    fn visit_drop_sequence(&mut self, _stmt: &'ast DropSequenceStmt) {}
+
+    fn visit_show_sequences(&mut self) {}
+
+    fn visit_show_index_build_status(&mut self) {}
+
+    fn visit_create_network_policy(&mut self, _stmt: &'ast CreateNetworkPolicyStmt) {}
+
+    fn visit_drop_network_policy(&mut self, _stmt: &'ast DropNetworkPolicyStmt) {}
+
+    fn visit_desc_network_policy(&mut self, _stmt: &'ast DescNetworkPolicyStmt) {}
+
+    fn visit_show_network_policies(&mut self) {}
+
+    fn visit_create_task(&mut self, _stmt: &'ast CreateTaskStmt) {}
+
+    fn visit_alter_task(&mut self, _stmt: &'ast AlterTaskStmt) {}
+
+    fn visit_drop_task(&mut self, _stmt: &'ast DropTaskStmt) {}
+
+    fn visit_execute_task(&mut self, _stmt: &'ast ExecuteTaskStmt) {}
+
+    fn visit_create_pipe(&mut self, _stmt: &'ast CreatePipeStmt) {}
+
+    fn visit_drop_pipe(&mut self, _stmt: &'ast DropPipeStmt) {}
+
+    fn visit_show_pipes(&mut self) {}
+
+    fn visit_drop_result_cache(&mut self) {}
+
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
+
     fn visit_create_stage(&mut self, _stmt: &'ast CreateStageStmt) {}
 
     fn visit_show_stages(&mut self) {}
@@ -489,10 +598,24 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_describe_stage(&mut self, _stage_name: &'ast str) {}
 
+    fn visit_alter_stage(&mut self, _stmt: &'ast AlterStageStmt) {}
+
+    fn visit_rename_stage(&mut self, _stage_name: &'ast str, _new_stage_name: &'ast str) {}
+
+    fn visit_show_create_stage(&mut self, _stage_name: &'ast str) {}
+
     fn visit_remove_stage(&mut self, _location: &'ast str, _pattern: &'ast str) {}
 
     fn visit_list_stage(&mut self, _location: &'ast str, _pattern: &'ast str) {}
 
+    fn visit_show_stage_files(
+        &mut self,
+        _location: &'ast str,
+        _pattern: &'ast str,
+        _limit: &'ast Option<u64>,
+    ) {
+    }
+
     fn visit_create_file_format(
         &mut self,
         _if_not_exists: bool,
@@ -523,6 +646,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_alter_share_tenants(&mut self, _stmt: &'ast AlterShareTenantsStmt) {}
 
+    fn visit_alter_share_comment(&mut self, _stmt: &'ast AlterShareCommentStmt) {}
+
     fn visit_desc_share(&mut self, _stmt: &'ast DescShareStmt) {}
 
     fn visit_show_shares(&mut self, _stmt: &'ast ShowSharesStmt) {}
@@ -561,6 +686,7 @@ pub trait Visitor<'ast>: Sized {
             selection,
             group_by,
             having,
+            connect_by,
             ..
         } = stmt;
 
@@ -569,13 +695,20 @@ pub trait Visitor<'ast>: Sized {
         }
 
         for table_ref in from.iter() {
-            walk_table_reference(self, table_ref);
+            self.visit_table_reference(table_ref);
         }
 
         if let Some(selection) = selection {
             walk_expr(self, selection);
         }
 
+        if let Some(connect_by) = connect_by {
+            if let Some(start_with) = &connect_by.start_with {
+                walk_expr(self, start_with);
+            }
+            walk_expr(self, &connect_by.condition);
+        }
+
         match group_by {
             Some(GroupBy::Normal(exprs)) => {
                 for expr in exprs {
@@ -617,8 +750,8 @@ pub trait Visitor<'ast>: Sized {
             ..
         } = join;
 
-        walk_table_reference(self, left);
-        walk_table_reference(self, right);
+        self.visit_table_reference(left);
+        self.visit_table_reference(right);
 
         walk_join_condition(self, condition);
     }
@@ -195,6 +195,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_count_all(&mut self, _span: Span) {}
 
+    fn visit_variable(&mut self, _span: Span, _name: &mut String) {}
+
     fn visit_tuple(&mut self, _span: Span, elements: &mut [Expr]) {
         for elem in elements.iter_mut() {
             walk_expr_mut(self, elem);
@@ -209,11 +211,16 @@ pub trait VisitorMut: Sized {
         args: &mut [Expr],
         _params: &mut [Literal],
         over: &mut Option<WindowSpec>,
+        lambda: &mut Option<Lambda>,
     ) {
         for arg in args.iter_mut() {
             walk_expr_mut(self, arg);
         }
 
+        if let Some(lambda) = lambda {
+            walk_expr_mut(self, &mut lambda.expr);
+        }
+
         if let Some(over) = over {
             over.partition_by
                 .iter_mut()
@@ -229,6 +236,31 @@ pub trait VisitorMut: Sized {
         }
     }
 
+    fn visit_list_agg(
+        &mut self,
+        _span: Span,
+        _distinct: bool,
+        expr: &mut Expr,
+        _delimiter: &mut Option<String>,
+        _on_overflow: &mut Option<ListAggOverflow>,
+        within_group: &mut Option<Box<OrderByExpr>>,
+    ) {
+        walk_expr_mut(self, expr);
+        if let Some(order_by) = within_group {
+            walk_expr_mut(self, &mut order_by.expr);
+        }
+    }
+
+    fn visit_percentile(
+        &mut self,
+        _span: Span,
+        _kind: PercentileKind,
+        _level: &mut Literal,
+        within_group: &mut OrderByExpr,
+    ) {
+        walk_expr_mut(self, &mut within_group.expr);
+    }
+
     fn visit_frame_bound(&mut self, bound: &mut WindowFrameBound) {
         match bound {
             WindowFrameBound::Preceding(Some(expr)) => walk_expr_mut(self, expr.as_mut()),
@@ -342,6 +374,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_copy_unit(&mut self, _copy_unit: &mut CopyUnit) {}
 
+    fn visit_show_copy_history(&mut self, _stmt: &mut ShowCopyHistoryStmt) {}
+
     fn visit_call(&mut self, _call: &mut CallStmt) {}
 
     fn visit_show_settings(&mut self, _like: &mut Option<String>) {}
@@ -370,9 +404,16 @@ pub trait VisitorMut: Sized {
 
     fn visit_unset_variable(&mut self, _stmt: &mut UnSetStmt) {}
 
+    fn visit_set_user_variable(&mut self, _variable: &mut String, _value: &mut Box<Expr>) {}
+
+    fn visit_reset_user_variable(&mut self, _variable: &mut String) {}
+
+    fn visit_show_variables(&mut self, _like: &mut Option<String>) {}
+
     fn visit_set_role(&mut self, _is_default: bool, _role_name: &mut String) {}
 
     fn visit_insert(&mut self, _insert: &mut InsertStmt) {}
+    fn visit_insert_multi_table(&mut self, _insert: &mut InsertMultiTableStmt) {}
     fn visit_replace(&mut self, _replace: &mut ReplaceStmt) {}
 
     fn visit_insert_source(&mut self, _insert_source: &mut InsertSource) {}
@@ -422,6 +463,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_create_table_source(&mut self, _source: &mut CreateTableSource) {}
 
+    fn visit_attach_table(&mut self, _stmt: &mut AttachTableStmt) {}
+
+    fn visit_export_table_snapshot(&mut self, _stmt: &mut ExportTableSnapshotStmt) {}
+
+    fn visit_import_table_snapshot(&mut self, _stmt: &mut ImportTableSnapshotStmt) {}
+
     fn visit_column_definition(&mut self, _column_definition: &mut ColumnDefinition) {}
 
     fn visit_drop_table(&mut self, _stmt: &mut DropTableStmt) {}
@@ -446,6 +493,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
+
+    fn visit_show_streams(&mut self, _stmt: &mut ShowStreamsStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &mut CreateUserStmt) {}
@@ -456,6 +509,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_roles(&mut self) {}
 
+    fn visit_show_role_hierarchy(&mut self) {}
+
     fn visit_create_role(&mut self, _if_not_exists: bool, _role_name: &mut String) {}
 
     fn visit_drop_role(&mut self, _if_exists: bool, _role_name: &mut String) {}
@@ -478,6 +533,10 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_udf(&mut self, _if_exists: bool, _udf_name: &mut Identifier) {}
 
+    fn visit_create_external_function(&mut self, _stmt: &mut CreateExternalFunctionStmt) {}
+
+    fn visit_drop_external_function(&mut self, _stmt: &mut DropExternalFunctionStmt) {}
+
     fn visit_alter_udf(
         &mut self,
         _udf_name: &mut Identifier,
@@ -487,6 +546,56 @@ pub trait VisitorMut: Sized {
     ) {
     }
 
+    fn visit_describe_function(&mut self, _name: &mut Identifier) {}
+
+    fn visit_create_masking_policy(&mut self, _stmt: &mut CreateMaskingPolicyStmt) {}
+
+    fn visit_drop_masking_policy(&mut self, _stmt: &mut DropMaskingPolicyStmt) {}
+
+    fn visit_create_row_access_policy(&mut self, _stmt: &mut CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &mut DropRowAccessPolicyStmt) {}
+
+    fn visit_show_row_access_policies(&mut self) {}
+
+    fn visit_create_sequence(&mut self, _stmt: &mut CreateSequenceStmt) {}
+
+    fn visit_drop_sequence(&mut self, _stmt: &mut DropSequenceStmt) {}
+
+    fn visit_show_sequences(&mut self) {}
+
+    fn visit_show_index_build_status(&mut self) {}
+
+    fn visit_create_network_policy(&mut self, _stmt: &mut CreateNetworkPolicyStmt) {}
+
+    fn visit_drop_network_policy(&mut self, _stmt: &mut DropNetworkPolicyStmt) {}
+
+    fn visit_desc_network_policy(&mut self, _stmt: &mut DescNetworkPolicyStmt) {}
+
+    fn visit_show_network_policies(&mut self) {}
+
+    fn visit_create_task(&mut self, _stmt: &mut CreateTaskStmt) {}
+
+    fn visit_alter_task(&mut self, _stmt: &mut AlterTaskStmt) {}
+
+    fn visit_drop_task(&mut self, _stmt: &mut DropTaskStmt) {}
+
+    fn visit_execute_task(&mut self, _stmt: &mut ExecuteTaskStmt) {}
+
+    fn visit_create_pipe(&mut self, _stmt: &mut CreatePipeStmt) {}
+
+    fn visit_drop_pipe(&mut self, _stmt: &mut DropPipeStmt) {}
+
+    fn visit_show_pipes(&mut self) {}
+
+    fn visit_drop_result_cache(&mut self) {}
+
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
+
     fn visit_create_stage(&mut self, _stmt: &mut CreateStageStmt) {}
 
     fn visit_show_stages(&mut self) {}
@@ -495,10 +604,24 @@ pub trait VisitorMut: Sized {
 
     fn visit_describe_stage(&mut self, _stage_name: &mut String) {}
 
+    fn visit_alter_stage(&mut self, _stmt: &mut AlterStageStmt) {}
+
+    fn visit_rename_stage(&mut self, _stage_name: &mut String, _new_stage_name: &mut String) {}
+
+    fn visit_show_create_stage(&mut self, _stage_name: &mut String) {}
+
     fn visit_remove_stage(&mut self, _location: &mut String, _pattern: &mut String) {}
 
     fn visit_list_stage(&mut self, _location: &mut String, _pattern: &mut String) {}
 
+    fn visit_show_stage_files(
+        &mut self,
+        _location: &mut String,
+        _pattern: &mut String,
+        _limit: &mut Option<u64>,
+    ) {
+    }
+
     fn visit_create_file_format(
         &mut self,
         _if_not_exists: bool,
@@ -529,6 +652,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_alter_share_tenants(&mut self, _stmt: &mut AlterShareTenantsStmt) {}
 
+    fn visit_alter_share_comment(&mut self, _stmt: &mut AlterShareCommentStmt) {}
+
     fn visit_desc_share(&mut self, _stmt: &mut DescShareStmt) {}
 
     fn visit_show_shares(&mut self, _stmt: &mut ShowSharesStmt) {}
@@ -567,6 +692,7 @@ pub trait VisitorMut: Sized {
             selection,
             group_by,
             having,
+            connect_by,
             ..
         } = stmt;
 
@@ -582,6 +708,13 @@ pub trait VisitorMut: Sized {
             walk_expr_mut(self, selection);
         }
 
+        if let Some(connect_by) = connect_by {
+            if let Some(start_with) = &mut connect_by.start_with {
+                walk_expr_mut(self, start_with);
+            }
+            walk_expr_mut(self, &mut connect_by.condition);
+        }
+
         match group_by {
             Some(GroupBy::Normal(exprs)) => {
                 for expr in exprs {
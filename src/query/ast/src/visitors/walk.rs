@@ -86,6 +86,7 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expr: &'a Expr) {
         } => visitor.visit_trim(*span, expr, trim_where),
         Expr::Literal { span, lit } => visitor.visit_literal(*span, lit),
         Expr::CountAll { span } => visitor.visit_count_all(*span),
+        Expr::Variable { span, name } => visitor.visit_variable(*span, name),
         Expr::Tuple { span, exprs } => visitor.visit_tuple(*span, exprs),
         Expr::FunctionCall {
             span,
@@ -94,7 +95,22 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expr: &'a Expr) {
             args,
             params,
             window,
-        } => visitor.visit_function_call(*span, *distinct, name, args, params, window),
+            lambda,
+        } => visitor.visit_function_call(*span, *distinct, name, args, params, window, lambda),
+        Expr::ListAgg {
+            span,
+            distinct,
+            expr,
+            delimiter,
+            on_overflow,
+            within_group,
+        } => visitor.visit_list_agg(*span, *distinct, expr, delimiter, on_overflow, within_group),
+        Expr::Percentile {
+            span,
+            kind,
+            level,
+            within_group,
+        } => visitor.visit_percentile(*span, *kind, level, within_group),
         Expr::Case {
             span,
             operand,
@@ -304,6 +320,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::ExplainAnalyze { query } => visitor.visit_statement(query),
         Statement::Query(query) => visitor.visit_query(query),
         Statement::Insert(insert) => visitor.visit_insert(insert),
+        Statement::InsertMultiTable(insert) => visitor.visit_insert_multi_table(insert),
         Statement::Replace(replace) => visitor.visit_replace(replace),
         Statement::Delete {
             table_reference,
@@ -312,6 +329,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         } => visitor.visit_delete(table_reference, selection),
         Statement::Update(update) => visitor.visit_update(update),
         Statement::Copy(stmt) => visitor.visit_copy(stmt),
+        Statement::ShowCopyHistory(stmt) => visitor.visit_show_copy_history(stmt),
         Statement::ShowSettings { like } => visitor.visit_show_settings(like),
         Statement::ShowProcessList => visitor.visit_show_process_list(),
         Statement::ShowMetrics => visitor.visit_show_metrics(),
@@ -328,6 +346,11 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
             value,
         } => visitor.visit_set_variable(*is_global, variable, value),
         Statement::UnSetVariable(stmt) => visitor.visit_unset_variable(stmt),
+        Statement::SetUserVariable { variable, value } => {
+            visitor.visit_set_user_variable(variable, value)
+        }
+        Statement::ResetUserVariable { variable } => visitor.visit_reset_user_variable(variable),
+        Statement::ShowVariables { like } => visitor.visit_show_variables(like),
         Statement::SetRole {
             is_default,
             role_name,
@@ -349,6 +372,9 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::DescribeTable(stmt) => visitor.visit_describe_table(stmt),
         Statement::ShowTablesStatus(stmt) => visitor.visit_show_tables_status(stmt),
         Statement::CreateTable(stmt) => visitor.visit_create_table(stmt),
+        Statement::AttachTable(stmt) => visitor.visit_attach_table(stmt),
+        Statement::ExportTableSnapshot(stmt) => visitor.visit_export_table_snapshot(stmt),
+        Statement::ImportTableSnapshot(stmt) => visitor.visit_import_table_snapshot(stmt),
         Statement::DropTable(stmt) => visitor.visit_drop_table(stmt),
         Statement::UndropTable(stmt) => visitor.visit_undrop_table(stmt),
         Statement::AlterTable(stmt) => visitor.visit_alter_table(stmt),
@@ -360,8 +386,12 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::CreateView(stmt) => visitor.visit_create_view(stmt),
         Statement::AlterView(stmt) => visitor.visit_alter_view(stmt),
         Statement::DropView(stmt) => visitor.visit_drop_view(stmt),
+        Statement::CreateStream(stmt) => visitor.visit_create_stream(stmt),
+        Statement::DropStream(stmt) => visitor.visit_drop_stream(stmt),
+        Statement::ShowStreams(stmt) => visitor.visit_show_streams(stmt),
         Statement::ShowUsers => visitor.visit_show_users(),
         Statement::ShowRoles => visitor.visit_show_roles(),
+        Statement::ShowRoleHierarchy => visitor.visit_show_role_hierarchy(),
         Statement::CreateUser(stmt) => visitor.visit_create_user(stmt),
         Statement::AlterUser(stmt) => visitor.visit_alter_user(stmt),
         Statement::DropUser { if_exists, user } => visitor.visit_drop_user(*if_exists, user),
@@ -393,13 +423,45 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
             if_exists,
             udf_name,
         } => visitor.visit_drop_udf(*if_exists, udf_name),
+        Statement::CreateExternalFunction(stmt) => visitor.visit_create_external_function(stmt),
+        Statement::DropExternalFunction(stmt) => visitor.visit_drop_external_function(stmt),
         Statement::AlterUDF {
             udf_name,
             parameters,
             definition,
             description,
         } => visitor.visit_alter_udf(udf_name, parameters, definition, description),
+        Statement::DescribeFunction { name } => visitor.visit_describe_function(name),
+        Statement::CreateMaskingPolicy(stmt) => visitor.visit_create_masking_policy(stmt),
+        Statement::DropMaskingPolicy(stmt) => visitor.visit_drop_masking_policy(stmt),
+        Statement::CreateRowAccessPolicy(stmt) => visitor.visit_create_row_access_policy(stmt),
+        Statement::DropRowAccessPolicy(stmt) => visitor.visit_drop_row_access_policy(stmt),
+        Statement::ShowRowAccessPolicies => visitor.visit_show_row_access_policies(),
+        Statement::CreateSequence(stmt) => visitor.visit_create_sequence(stmt),
+        Statement::DropSequence(stmt) => visitor.visit_drop_sequence(stmt),
+        Statement::ShowSequences => visitor.visit_show_sequences(),
+        Statement::ShowIndexBuildStatus => visitor.visit_show_index_build_status(),
+        Statement::CreateNetworkPolicy(stmt) => visitor.visit_create_network_policy(stmt),
+        Statement::DropNetworkPolicy(stmt) => visitor.visit_drop_network_policy(stmt),
+        Statement::DescNetworkPolicy(stmt) => visitor.visit_desc_network_policy(stmt),
+        Statement::ShowNetworkPolicies => visitor.visit_show_network_policies(),
+        Statement::CreateTask(stmt) => visitor.visit_create_task(stmt),
+        Statement::AlterTask(stmt) => visitor.visit_alter_task(stmt),
+        Statement::DropTask(stmt) => visitor.visit_drop_task(stmt),
+        Statement::ExecuteTask(stmt) => visitor.visit_execute_task(stmt),
+        Statement::CreatePipe(stmt) => visitor.visit_create_pipe(stmt),
+        Statement::DropPipe(stmt) => visitor.visit_drop_pipe(stmt),
+        Statement::ShowPipes => visitor.visit_show_pipes(),
+        Statement::DropResultCache => visitor.visit_drop_result_cache(),
+        Statement::Begin => visitor.visit_begin(),
+        Statement::Commit => visitor.visit_commit(),
+        Statement::Abort => visitor.visit_abort(),
         Statement::ListStage { location, pattern } => visitor.visit_list_stage(location, pattern),
+        Statement::ShowStageFiles {
+            location,
+            pattern,
+            limit,
+        } => visitor.visit_show_stage_files(location, pattern, limit),
         Statement::ShowStages => visitor.visit_show_stages(),
         Statement::DropStage {
             if_exists,
@@ -419,6 +481,12 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         }
         Statement::ShowFileFormats => visitor.visit_show_file_formats(),
         Statement::DescribeStage { stage_name } => visitor.visit_describe_stage(stage_name),
+        Statement::AlterStage(stmt) => visitor.visit_alter_stage(stmt),
+        Statement::RenameStage {
+            stage_name,
+            new_stage_name,
+        } => visitor.visit_rename_stage(stage_name, new_stage_name),
+        Statement::ShowCreateStage { stage_name } => visitor.visit_show_create_stage(stage_name),
         Statement::Call(stmt) => visitor.visit_call(stmt),
         Statement::Presign(stmt) => visitor.visit_presign(stmt),
         Statement::CreateShareEndpoint(stmt) => visitor.visit_create_share_endpoint(stmt),
@@ -429,6 +497,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::GrantShareObject(stmt) => visitor.visit_grant_share_object(stmt),
         Statement::RevokeShareObject(stmt) => visitor.visit_revoke_share_object(stmt),
         Statement::AlterShareTenants(stmt) => visitor.visit_alter_share_tenants(stmt),
+        Statement::AlterShareComment(stmt) => visitor.visit_alter_share_comment(stmt),
         Statement::DescShare(stmt) => visitor.visit_desc_share(stmt),
         Statement::ShowShares(stmt) => visitor.visit_show_shares(stmt),
         Statement::ShowObjectGrantPrivileges(stmt) => {
@@ -16,6 +16,7 @@ use std::io::Write;
 
 use common_ast::display_parser_error;
 use common_ast::parser::expr::*;
+use common_ast::parser::parse_settings_hint;
 use common_ast::parser::parse_sql;
 use common_ast::parser::query::*;
 use common_ast::parser::token::*;
@@ -77,8 +78,12 @@ fn test_statement() {
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
+        r#"explain pipeline format = mermaid select a from b;"#,
+        r#"explain (format json) select a from b;"#,
         r#"describe a;"#,
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
+        r#"describe function concat;"#,
+        r#"DESC FUNCTION my_udf;"#,
         r#"create table a (c decimal(38, 0))"#,
         r#"create table if not exists a.b (c integer not null default 1, b varchar);"#,
         r#"create table if not exists a.b (c integer default 1 not null, b varchar) as select * from t;"#,
@@ -107,6 +112,10 @@ fn test_statement() {
         r#"drop view v;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
+        r#"create stream if not exists db.s on table t;"#,
+        r#"drop stream s;"#,
+        r#"show streams from db like 's%';"#,
+        r#"show copy history for table db.t since => '2023-06-01 00:00:00';"#,
         r#"rename table d.t to e.s;"#,
         r#"truncate table test;"#,
         r#"truncate table test_db.test;"#,
@@ -118,6 +127,8 @@ fn test_statement() {
         r#"ALTER USER u1 IDENTIFIED BY '123456';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = 'role1';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = 'role1', TENANTSETTING;"#,
+        r#"ALTER USER u1 WITH SET NETWORK POLICY np1;"#,
+        r#"ALTER USER u1 WITH UNSET NETWORK POLICY;"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"DROP database if exists db1;"#,
         r#"select distinct a, count(*) from t where a = 1 and b - 1 < a group by a having a = 1;"#,
@@ -146,9 +157,11 @@ fn test_statement() {
         r#"select * from a where a.a > (select b.a from b);"#,
         r#"select 1 from numbers(1) where ((1 = 1) or 1)"#,
         r#"select * from read_parquet('p1', 'p2', 'p3', prune_page => true, refresh_meta_cache => true);"#,
+        r#"select * from t, lateral flatten(t.c)"#,
         r#"insert into t (c1, c2) values (1, 2), (3, 4);"#,
         r#"insert into table t format json;"#,
         r#"insert into table t select * from t2;"#,
+        r#"insert first when a > 1 then into t1 else into t2 (b) values (a) select a from t;"#,
         r#"select parse_json('{"k1": [0, 1, 2]}').k1[0];"#,
         r#"CREATE STAGE ~"#,
         r#"CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z') file_format=(type = CSV compression = GZIP record_delimiter=',')"#,
@@ -157,6 +170,7 @@ fn test_statement() {
         r#"DROP STAGE ~"#,
         r#"list @stage_a;"#,
         r#"list @~;"#,
+        r#"show stage files in @stage_a/dir pattern = '%.csv' limit 10;"#,
         r#"create user 'test-e'@'localhost' identified by 'password';"#,
         r#"drop user if exists 'test-j'@'localhost';"#,
         r#"alter user 'test-e'@'localhost' identified by 'new-password';"#,
@@ -165,11 +179,61 @@ fn test_statement() {
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0;"#,
+        r#"ALTER TABLE t RECLUSTER LIMIT 10;"#,
         r#"ALTER TABLE t ADD COLUMN a float default 101 COMMENT 'hello';"#,
         r#"ALTER TABLE t DROP COLUMN b;"#,
+        r#"ALTER TABLE t ADD COLUMN c int AFTER b;"#,
+        r#"ALTER TABLE t RENAME COLUMN a TO b;"#,
+        r#"ALTER TABLE t MODIFY COLUMN a int;"#,
+        r#"ALTER TABLE t MODIFY COLUMN a bigint CASCADE;"#,
+        r#"ALTER TABLE t MODIFY COLUMN a int USING a % 100 CASCADE;"#,
+        r#"ALTER TABLE t MODIFY COLUMN a SET MASKING POLICY mask;"#,
+        r#"ALTER TABLE t MODIFY COLUMN a UNSET MASKING POLICY;"#,
+        r#"ALTER TABLE t SET ANALYZE_INTERVAL = 3600;"#,
+        r#"ALTER TABLE t ADD ROW ACCESS POLICY region_policy ON (region);"#,
+        r#"ALTER TABLE t DROP ROW ACCESS POLICY region_policy;"#,
+        r#"ALTER TABLE t ADD BLOOM FILTER INDEX;"#,
+        r#"ALTER TABLE t CONNECTION = (access_key_id = 'ak' secret_access_key = 'sk');"#,
+        r#"ALTER STAGE s CONNECTION = (access_key_id = 'ak' secret_access_key = 'sk');"#,
+        r#"ALTER STAGE s SET URL = 's3://bucket/path/' CONNECTION = (region = 'us-east-2') FILE_FORMAT = (type = CSV) COPY_OPTIONS = (on_error = 'continue' size_limit = 10);"#,
+        r#"RENAME STAGE s TO s1;"#,
+        r#"SHOW CREATE EXTERNAL STAGE s;"#,
+        r#"CREATE MASKING POLICY IF NOT EXISTS email_mask AS (val STRING) RETURNS STRING -> regexp_replace(val, '.+@', '***@') COMMENT = 'hide email';"#,
+        r#"DROP MASKING POLICY IF EXISTS email_mask;"#,
+        r#"CREATE ROW ACCESS POLICY IF NOT EXISTS region_policy AS (region STRING) RETURNS BOOLEAN -> region = 'eu' COMMENT = 'eu only';"#,
+        r#"DROP ROW ACCESS POLICY IF EXISTS region_policy;"#,
+        r#"SHOW ROW ACCESS POLICIES;"#,
+        r#"CREATE SEQUENCE IF NOT EXISTS seq START = 10 INCREMENT = -2 MINVALUE = 0 MAXVALUE = 10 CYCLE;"#,
+        r#"CREATE SEQUENCE seq NO CYCLE;"#,
+        r#"DROP SEQUENCE IF EXISTS seq;"#,
+        r#"SHOW SEQUENCES;"#,
+        r#"SHOW INDEX BUILD STATUS;"#,
+        r#"CREATE NETWORK POLICY np1 ALLOWED_IP_LIST = ('10.0.0.0/8', '192.168.1.0/24') BLOCKED_IP_LIST = ('10.0.0.1') COMMENT = 'office';"#,
+        r#"DROP NETWORK POLICY IF EXISTS np1;"#,
+        r#"DESC NETWORK POLICY np1;"#,
+        r#"SHOW NETWORK POLICIES;"#,
+        r#"CREATE TASK IF NOT EXISTS t1 SCHEDULE = '5 MINUTE' COMMENT = 'daily cleanup' AS TRUNCATE TABLE a;"#,
+        r#"ALTER TASK IF EXISTS t1 SUSPEND;"#,
+        r#"DROP TASK t1;"#,
+        r#"EXECUTE TASK t1;"#,
+        r#"CREATE PIPE IF NOT EXISTS p1 COMMENT = 'events' AS COPY INTO db.t FROM KAFKA (brokers = 'k1:9092,k2:9092', topic = 'events', group = 'g1', format = 'ndjson') ON_ERROR = continue;"#,
+        r#"DROP PIPE IF EXISTS p1;"#,
+        r#"SHOW PIPES;"#,
+        r#"DROP RESULT CACHE;"#,
+        r#"BEGIN;"#,
+        r#"BEGIN TRANSACTION;"#,
+        r#"COMMIT;"#,
+        r#"ROLLBACK;"#,
+        r#"ABORT;"#,
+        r#"CREATE EXTERNAL FUNCTION IF NOT EXISTS sentiment(review STRING, score: INT) RETURNS FLOAT LANGUAGE python HANDLER = 'sentiment' ADDRESS = 'http://localhost:8815' HEADERS = ('Authorization' = 'Bearer xyz') MAX_RETRIES = 5;"#,
+        r#"DROP EXTERNAL FUNCTION IF EXISTS sentiment;"#,
+        r#"ATTACH TABLE db1.t2 FROM 's3://testbucket/admin/data/1/2/' CONNECTION = (endpoint_url = 'http://127.0.0.1:9900') READ_ONLY;"#,
+        r#"EXPORT SNAPSHOT TABLE db1.t1 TO LOCATION = '@s1/export/t1';"#,
+        r#"IMPORT SNAPSHOT TABLE t2 FROM LOCATION = '@s1/export/t1';"#,
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c RENAME TO a;"#,
+        r#"ALTER DATABASE db SET QUOTA (max_tables = 10, MAX_BYTES = 1024);"#,
         r#"CREATE TABLE t (a INT COMMENT 'col comment') COMMENT='table comment';"#,
         r#"GRANT CREATE, CREATE USER ON * TO 'test-grant'@'localhost';"#,
         r#"GRANT SELECT, CREATE ON * TO 'test-grant'@'localhost';"#,
@@ -191,13 +255,18 @@ fn test_statement() {
         r#"GRANT SELECT ON db01.tb1 TO ROLE 'role1';"#,
         r#"GRANT SELECT ON tb1 TO ROLE 'role1';"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
+        r#"GRANT SELECT(a, b) ON db01.tb1 TO ROLE 'role1';"#,
+        r#"GRANT BYPASS ROW ACCESS POLICY ON *.* TO ROLE 'admin';"#,
+        r#"GRANT READ, WRITE ON STAGE s1 TO ROLE 'role1';"#,
         r#"SHOW GRANTS;"#,
         r#"SHOW GRANTS FOR 'test-grant'@'localhost';"#,
         r#"SHOW GRANTS FOR USER 'test-grant'@'localhost';"#,
         r#"SHOW GRANTS FOR ROLE 'role1';"#,
+        r#"SHOW ROLE HIERARCHY;"#,
         r#"REVOKE SELECT, CREATE ON * FROM 'test-grant'@'localhost';"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE 'role1';"#,
         r#"REVOKE ALL ON tb1 FROM 'u1';"#,
+        r#"REVOKE SELECT(a) ON tb1 FROM ROLE 'role1';"#,
         r#"COPY INTO mytable
                 FROM @~/mybucket/data.csv
                 FILE_FORMAT = (
@@ -320,6 +389,10 @@ fn test_statement() {
                     skip_header = 1
                 )
                 size_limit=10;"#,
+        r#"COPY INTO mytable
+                FROM @my_stage
+                PATTERN = 'dir/**/.*[.]parquet'
+                VALIDATION_MODE = RETURN_ERRORS;"#,
         // We used to support COPY FROM a quoted at string
         // r#"COPY INTO mytable
         //         FROM '@external_stage/path/to/file.csv'
@@ -352,9 +425,13 @@ fn test_statement() {
         r#"ALTER SHARE a ADD TENANTS = b,c;"#,
         r#"ALTER SHARE IF EXISTS a ADD TENANTS = b,c;"#,
         r#"ALTER SHARE IF EXISTS a REMOVE TENANTS = b,c;"#,
+        r#"ALTER SHARE a ADD ACCOUNTS = (b, c) SHARE_RESTRICTIONS = (READ_ONLY = true);"#,
+        r#"ALTER SHARE IF EXISTS a REMOVE ACCOUNTS = (b);"#,
+        r#"ALTER SHARE a SET COMMENT = 'new comment';"#,
         r#"DESC SHARE b;"#,
         r#"DESCRIBE SHARE b;"#,
         r#"SHOW SHARES;"#,
+        r#"SHOW SHARES OF TENANT 'tenant1';"#,
         r#"SHOW GRANTS ON TABLE db1.tb1;"#,
         r#"SHOW GRANTS ON DATABASE db;"#,
         r#"SHOW GRANTS OF SHARE t;"#,
@@ -363,6 +440,9 @@ fn test_statement() {
         r#"SET max_threads = 10*2;"#,
         r#"UNSET max_threads;"#,
         r#"UNSET (max_threads, sql_dialect);"#,
+        r#"SET @a = @b + 1;"#,
+        r#"RESET @a;"#,
+        r#"SHOW VARIABLES LIKE 'max%';"#,
         r#"SELECT t.c1 FROM @stage1/dir/file
         ( file_format => 'PARQUET', FILES => ('file1', 'file2')) t;"#,
         r#"select table0.c1, table1.c2 from
@@ -491,6 +571,10 @@ fn test_query() {
         r#"select * from t1 union (select * from t2 union select * from t3)"#,
         r#"select * from monthly_sales pivot(sum(amount) for month in ('JAN', 'FEB', 'MAR', 'APR')) order by empid"#,
         r#"select * from monthly_sales_1 unpivot(sales for month in (jan, feb, mar, april)) order by empid"#,
+        r#"select * from t match_recognize(partition by a order by b measures b as c all rows per match pattern (x y+ | z) define y as b > 1)"#,
+        r#"select * from t tablesample system (10)"#,
+        r#"select * from t1 as t tablesample bernoulli (0.5) repeatable (42)"#,
+        r#"select id, level from t start with parent_id is null connect by prior id = parent_id"#,
     ];
 
     for case in cases {
@@ -591,6 +675,10 @@ fn test_expr() {
         r#"a is distinct from b"#,
         r#"1 is not distinct from null"#,
         r#"{'k1':1,'k2':2}"#,
+        r#"LISTAGG(name, ', ') WITHIN GROUP (ORDER BY id DESC)"#,
+        r#"listagg(DISTINCT name ON OVERFLOW TRUNCATE '~' WITHOUT COUNT)"#,
+        r#"PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY salary)"#,
+        r#"percentile_disc(0.9) within group (order by price desc)"#,
         // window expr
         r#"ROW_NUMBER() OVER (ORDER BY salary DESC)"#,
         r#"SUM(salary) OVER ()"#,
@@ -601,6 +689,11 @@ fn test_expr() {
         r#"COUNT() OVER (ORDER BY hire_date ROWS UNBOUNDED PRECEDING)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS CURRENT ROW)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS 3 PRECEDING)"#,
+        r#"SUM(salary) OVER (ORDER BY hire_date GROUPS BETWEEN 1 PRECEDING AND 1 FOLLOWING EXCLUDE TIES)"#,
+        r#"COUNT() OVER (ORDER BY hire_date ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW EXCLUDE CURRENT ROW)"#,
+        r#"LAG(salary, 1, 0) IGNORE NULLS OVER (ORDER BY hire_date)"#,
+        r#"NTH_VALUE(salary, 2) RESPECT NULLS OVER (PARTITION BY department ORDER BY salary)"#,
+        r#"ARRAY_MAP(arr, x -> x + 1)"#,
     ];
 
     for case in cases {
@@ -628,3 +721,31 @@ fn test_expr_error() {
         run_parser!(file, expr, case);
     }
 }
+
+#[test]
+fn test_settings_hint() {
+    let settings = |sql: &str| parse_settings_hint(sql, Dialect::PostgreSQL).unwrap();
+
+    assert_eq!(settings("SELECT 1"), vec![]);
+    assert_eq!(settings("SELECT /*+ SET_VAR(max_threads = 4) */ 1"), vec![]);
+    assert_eq!(settings("/* SET_VAR(max_threads = 4) */ SELECT 1"), vec![]);
+    assert_eq!(
+        settings("/*+ SET_VAR(max_threads=4, timezone='UTC') */ SELECT 1"),
+        vec![
+            ("max_threads".to_string(), "4".to_string()),
+            ("timezone".to_string(), "UTC".to_string()),
+        ]
+    );
+    assert_eq!(
+        settings("  /*+ set_var(Max_Threads = 4) SET_VAR(sql_dialect = MySQL) */ SELECT 1"),
+        vec![
+            ("max_threads".to_string(), "4".to_string()),
+            ("sql_dialect".to_string(), "MySQL".to_string()),
+        ]
+    );
+
+    assert!(
+        parse_settings_hint("/*+ SET_VAR(max_threads) */ SELECT 1", Dialect::PostgreSQL).is_err()
+    );
+    assert!(parse_settings_hint("/*+ BROADCAST(t) */ SELECT 1", Dialect::PostgreSQL).is_err());
+}
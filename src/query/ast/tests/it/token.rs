@@ -15,7 +15,9 @@
 use std::fs::File;
 use std::io::Write;
 
+use common_ast::parser::rewrite_odbc_escapes;
 use common_ast::parser::token::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use goldenfile::Mint;
 
@@ -74,3 +76,33 @@ fn test_lexer_error() {
         run_lexer(&mut file, case);
     }
 }
+
+#[test]
+fn test_odbc_escapes() {
+    let cases = vec![
+        ("SELECT 1", "SELECT 1"),
+        ("SELECT {d '2024-01-01'}", "SELECT DATE '2024-01-01'"),
+        (
+            "SELECT { TS '2024-01-01 12:00:00' } FROM t",
+            "SELECT TIMESTAMP '2024-01-01 12:00:00' FROM t",
+        ),
+        ("SELECT {t '12:30:15'}", "SELECT 45015"),
+        ("SELECT {fn NOW()}", "SELECT NOW()"),
+        (
+            "SELECT {fn DATE_ADD(DAY, 1, {d '2024-01-31'})}, {'d': 1}",
+            "SELECT DATE_ADD(DAY, 1, DATE '2024-01-31'), {'d': 1}",
+        ),
+        (
+            "INSERT INTO t VALUES ({d '2024-01-01'}, {t '00:00:01'}) †",
+            "INSERT INTO t VALUES (DATE '2024-01-01', 1) †",
+        ),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(rewrite_odbc_escapes(source).unwrap(), expected, "{source}");
+    }
+
+    for source in ["SELECT {t '24:00:00'}", "SELECT {t '12:30'}"] {
+        let err = rewrite_odbc_escapes(source).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::SYNTAX_EXCEPTION, "{source}");
+    }
+}
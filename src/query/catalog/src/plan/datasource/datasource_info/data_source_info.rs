@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_expression::TableSchema;
 use common_meta_app::schema::TableInfo;
 
+use crate::plan::IcebergTableInfo;
 use crate::plan::ParquetTableInfo;
 use crate::plan::ResultScanTableInfo;
 use crate::plan::StageTableInfo;
@@ -31,6 +32,8 @@ pub enum DataSourceInfo {
     ParquetSource(ParquetTableInfo),
     // Table Function Result_Scan
     ResultScanSource(ResultScanTableInfo),
+    // Iceberg table, read from the data files of one of its snapshots.
+    IcebergSource(IcebergTableInfo),
 }
 
 impl DataSourceInfo {
@@ -40,6 +43,7 @@ impl DataSourceInfo {
             DataSourceInfo::StageSource(table_info) => table_info.schema(),
             DataSourceInfo::ParquetSource(table_info) => table_info.schema(),
             DataSourceInfo::ResultScanSource(table_info) => table_info.schema(),
+            DataSourceInfo::IcebergSource(table_info) => table_info.schema(),
         }
     }
 
@@ -49,6 +53,7 @@ impl DataSourceInfo {
             DataSourceInfo::StageSource(table_info) => table_info.desc(),
             DataSourceInfo::ParquetSource(table_info) => table_info.desc(),
             DataSourceInfo::ResultScanSource(table_info) => table_info.desc(),
+            DataSourceInfo::IcebergSource(table_info) => table_info.desc(),
         }
     }
 }
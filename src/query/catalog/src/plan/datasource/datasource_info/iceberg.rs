@@ -0,0 +1,39 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_expression::TableSchema;
+use common_meta_app::schema::TableInfo;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct IcebergTableInfo {
+    pub table_info: TableInfo,
+    /// The snapshot to read, `None` if the table has no snapshot yet.
+    pub snapshot_id: Option<i64>,
+    /// Iceberg field ids of the top-level fields in `table_info`'s schema.
+    ///
+    /// Data files are matched against them instead of column names or positions,
+    /// so files written before a schema evolution are still projected correctly.
+    pub field_ids: Vec<i32>,
+}
+
+impl IcebergTableInfo {
+    pub fn schema(&self) -> Arc<TableSchema> {
+        self.table_info.schema()
+    }
+
+    pub fn desc(&self) -> String {
+        self.table_info.desc.clone()
+    }
+}
@@ -13,12 +13,14 @@
 // limitations under the License.
 
 mod data_source_info;
+mod iceberg;
 mod parquet;
 mod parquet_read_options;
 mod result_scan;
 mod stage;
 
 pub use data_source_info::DataSourceInfo;
+pub use iceberg::IcebergTableInfo;
 pub use parquet::ParquetTableInfo;
 pub use parquet_read_options::ParquetReadOptions;
 pub use result_scan::ResultScanTableInfo;
@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::DefaultHasher;
 use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
 
 use common_expression::types::DataType;
 use common_expression::RemoteExpr;
@@ -62,6 +67,69 @@ pub struct PushDownInfo {
     pub limit: Option<usize>,
     /// Optional order_by expression plan, asc, null_first
     pub order_by: Vec<(RemoteExpr<String>, bool, bool)>,
+    /// Optional sampling of the scan, `TABLESAMPLE`.
+    pub sample: Option<SampleInfo>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SampleMethod {
+    /// Keep whole blocks, they are chosen while pruning.
+    System,
+    /// Keep each row independently, rows are filtered after they are read.
+    Bernoulli,
+}
+
+/// Information about `TABLESAMPLE <method> (<percent>) [REPEATABLE (<seed>)]`.
+///
+/// Without `REPEATABLE`, the seed is chosen randomly when the query is planned,
+/// so that all the nodes of a cluster sample with the same seed.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct SampleInfo {
+    pub method: SampleMethod,
+    /// Percentage to keep, in `[0, 100]`.
+    pub percent: f64,
+    pub seed: u64,
+    pub repeatable: bool,
+}
+
+// `percent` is checked to be in `[0, 100]` by the binder, so it is never NaN.
+impl Eq for SampleInfo {}
+
+impl SampleInfo {
+    pub fn fraction(&self) -> f64 {
+        self.percent / 100.0
+    }
+
+    /// Whether the block identified by `key` is kept by `SYSTEM` sampling.
+    ///
+    /// The decision only depends on the seed and the key, so the same blocks
+    /// are kept wherever and whenever they are pruned.
+    pub fn keep_block<K: Hash + ?Sized>(&self, key: &K) -> bool {
+        if self.percent >= 100.0 {
+            return true;
+        }
+        if self.percent <= 0.0 {
+            return false;
+        }
+        let mut hasher = DefaultHasher::new();
+        self.seed.hash(&mut hasher);
+        key.hash(&mut hasher);
+        (hasher.finish() as f64) < self.fraction() * u64::MAX as f64
+    }
+}
+
+impl Display for SampleInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let method = match self.method {
+            SampleMethod::System => "SYSTEM",
+            SampleMethod::Bernoulli => "BERNOULLI",
+        };
+        write!(f, "{method} ({}%)", self.percent)?;
+        if self.repeatable {
+            write!(f, " REPEATABLE ({})", self.seed)?;
+        }
+        Ok(())
+    }
 }
 
 /// TopK is a wrapper for topk push down items.
@@ -97,6 +165,11 @@ impl PushDownInfo {
                     return None;
                 }
 
+                // Blocks written before the type of the column was changed have no statistics of it.
+                if !field.origins().is_empty() {
+                    return None;
+                }
+
                 // Only do topk in storage for cluster key.
 
                 if let Some(cluster_key) = cluster_key.as_ref() {
@@ -14,6 +14,7 @@
 
 use std::any::Any;
 use std::collections::BTreeMap;
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 
 use chrono::DateTime;
@@ -100,6 +101,11 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// whether table rejects all the writes, like the tables attached read-only to the data of others
+    fn is_read_only(&self) -> bool {
+        false
+    }
+
     fn cluster_keys(&self, _ctx: Arc<dyn TableContext>) -> Vec<RemoteExpr<String>> {
         vec![]
     }
@@ -110,6 +116,12 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// Whether the table engine keeps a part of its blocks for `TABLESAMPLE SYSTEM`.
+    /// only Fuse and Parquet Engines support this.
+    fn support_block_sample(&self) -> bool {
+        false
+    }
+
     async fn alter_table_cluster_keys(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -188,8 +200,9 @@ pub trait Table: Sync + Send {
         ctx: Arc<dyn TableContext>,
         pipeline: &mut Pipeline,
         on_conflict_fields: Vec<TableField>,
+        statistics: Arc<ReplaceIntoStatistics>,
     ) -> Result<()> {
-        let (_, _, _) = (ctx, pipeline, on_conflict_fields);
+        let (_, _, _, _) = (ctx, pipeline, on_conflict_fields, statistics);
 
         Err(ErrorCode::Unimplemented(format!(
             "replace_into operation for table {} is not implemented. table engine : {}",
@@ -343,6 +356,11 @@ pub trait TableExt: Table {
         let name = table_info.name.clone();
         let tid = table_info.ident.table_id;
         let catalog = ctx.get_catalog(table_info.catalog())?;
+        // The table mutated in an explicit transaction is refreshed to its uncommitted version.
+        let txn_table_info = ctx.get_txn_manager().lock().get_table_info(tid);
+        if let Some(txn_table_info) = txn_table_info {
+            return catalog.get_table_by_info(&txn_table_info);
+        }
         let (ident, meta) = catalog.get_table_meta_by_id(tid).await?;
         let table_info: TableInfo = TableInfo {
             ident,
@@ -358,7 +376,7 @@ pub trait TableExt: Table {
 
 impl<T: ?Sized> TableExt for T where T: Table {}
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
@@ -392,6 +410,15 @@ pub enum AppendMode {
     Copy,
 }
 
+/// Numbers of rows of a REPLACE INTO operation, updated while the pipeline runs.
+#[derive(Debug, Default)]
+pub struct ReplaceIntoStatistics {
+    // Rows of the source, after the rows with duplicated conflict keys are removed
+    pub source_rows: AtomicU64,
+    // Rows of the table that are replaced by the rows of the source
+    pub replaced_rows: AtomicU64,
+}
+
 pub trait ColumnStatisticsProvider {
     // returns the statistics of the given column, if any.
     // column_id is just the index of the column in table's schema
@@ -26,11 +26,15 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::FunctionContext;
+use common_expression::Scalar;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatOptions;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeType;
+use common_meta_app::tenant::DatabaseQuota;
 use common_settings::Settings;
+use common_storage::CopyStatus;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StorageMetrics;
@@ -40,7 +44,9 @@ use crate::cluster_info::Cluster;
 use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
+use crate::table::NavigationPoint;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 #[derive(Debug)]
 pub struct ProcessInfo {
@@ -105,6 +111,15 @@ pub trait TableContext: Send + Sync {
     fn get_current_database(&self) -> String;
     fn get_current_user(&self) -> Result<UserInfo>;
     fn get_current_role(&self) -> Option<RoleInfo>;
+    /// Returns the columns of the table on which the privilege is granted to the current user and
+    /// roles, `None` if the privilege is granted on the whole table or on none of its columns.
+    async fn get_granted_columns(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        privilege: UserPrivilegeType,
+    ) -> Result<Option<HashSet<String>>>;
     fn get_fuse_version(&self) -> String;
     fn get_format_settings(&self) -> Result<FormatSettings>;
     fn get_tenant(&self) -> String;
@@ -121,6 +136,18 @@ pub trait TableContext: Send + Sync {
     fn get_result_cache_key(&self, query_id: &str) -> Option<String>;
     fn set_query_id_result_cache(&self, query_id: String, result_cache_key: String);
     fn set_on_error_map(&self, map: Option<HashMap<String, ErrorCode>>);
+    fn get_copy_status(&self) -> Arc<CopyStatus>;
+    fn get_txn_manager(&self) -> TxnManagerRef;
+
+    /// User-defined variables of the session, set by `SET @variable = <expr>`.
+    fn set_variable(&self, key: String, value: Scalar);
+    fn unset_variable(&self, key: &str);
+    fn get_variable(&self, key: &str) -> Option<Scalar>;
+    fn get_all_variables(&self) -> HashMap<String, Scalar>;
+
+    /// The last value `NEXTVAL` generated for a sequence in the session, read by `CURRVAL`.
+    fn set_sequence_value(&self, name: String, value: i64);
+    fn get_sequence_value(&self, name: &str) -> Option<i64>;
 
     fn apply_changed_settings(&self, changed_settings: Arc<Settings>) -> Result<()>;
     fn get_changed_settings(&self) -> Arc<Settings>;
@@ -130,11 +157,28 @@ pub trait TableContext: Send + Sync {
     fn push_precommit_block(&self, block: DataBlock);
     fn consume_precommit_blocks(&self) -> Vec<DataBlock>;
 
+    /// Overrides the settings of this query with the settings hint of its SQL, the settings of
+    /// the session are not changed.
+    async fn apply_settings_hint(&self, hint: Vec<(String, String)>) -> Result<()>;
+
     async fn get_file_format(&self, name: &str) -> Result<FileFormatOptions>;
 
+    /// Get the quotas of the databases with ids `db_ids`, in the same order.
+    async fn get_database_quotas(&self, db_ids: &[u64]) -> Result<Vec<DatabaseQuota>>;
+
     async fn get_table(&self, catalog: &str, database: &str, table: &str)
     -> Result<Arc<dyn Table>>;
 
+    /// Fetch a table as of the navigation point of an `AT` clause. The same point
+    /// returns the same table instance in a query, like `get_table`.
+    async fn get_table_at(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        point: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>>;
+
     async fn color_copied_files(
         &self,
         catalog_name: &str,
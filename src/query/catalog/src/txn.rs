@@ -0,0 +1,123 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+/// The explicit transaction of a session, started by `BEGIN`.
+///
+/// While a transaction is active, the new table metas the statements commit are buffered
+/// here instead of being written to the meta service, `COMMIT` writes them altogether.
+#[derive(Default)]
+pub struct TxnManager {
+    state: Option<TxnState>,
+}
+
+pub struct TxnState {
+    pub begin_at: Instant,
+    /// The mutated tables, by table id.
+    pub tables: BTreeMap<u64, TxnTable>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TxnTable {
+    /// The table when it's first mutated in the transaction, the commit conflicts
+    /// if the version of the table is changed since then.
+    pub base: TableInfo,
+    /// The table meta pointing to the uncommitted snapshot of the table.
+    pub meta: TableMeta,
+}
+
+impl TxnTable {
+    /// The table as seen by the statements of the transaction.
+    pub fn table_info(&self) -> TableInfo {
+        TableInfo {
+            meta: self.meta.clone(),
+            ..self.base.clone()
+        }
+    }
+}
+
+impl TxnManager {
+    pub fn create() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager::default()))
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state.is_some()
+    }
+
+    pub fn begin(&mut self) -> Result<()> {
+        if self.is_active() {
+            return Err(ErrorCode::TransactionNotSupported(
+                "a transaction is already in progress, nested transactions are not supported",
+            ));
+        }
+        self.state = Some(TxnState {
+            begin_at: Instant::now(),
+            tables: BTreeMap::new(),
+        });
+        Ok(())
+    }
+
+    /// Ends the transaction, returns the buffered mutations if any. The mutations are
+    /// rolled back if they are dropped without being committed.
+    pub fn end(&mut self) -> Option<TxnState> {
+        self.state.take()
+    }
+
+    pub fn is_timeout(&self, timeout: Duration) -> bool {
+        self.state
+            .as_ref()
+            .map(|state| state.begin_at.elapsed() > timeout)
+            .unwrap_or(false)
+    }
+
+    /// Returns the uncommitted version of the table, if it's mutated in the transaction.
+    pub fn get_table_info(&self, table_id: u64) -> Option<TableInfo> {
+        self.state
+            .as_ref()
+            .and_then(|state| state.tables.get(&table_id))
+            .map(|table| table.table_info())
+    }
+
+    /// Buffers the new meta of the table, `table_info` is the version the meta is based on.
+    pub fn update_table_meta(&mut self, table_info: &TableInfo, meta: TableMeta) -> Result<()> {
+        let state = self
+            .state
+            .as_mut()
+            .ok_or_else(|| ErrorCode::Internal("update table meta of a transaction not started"))?;
+        match state.tables.entry(table_info.ident.table_id) {
+            Entry::Occupied(mut v) => v.get_mut().meta = meta,
+            Entry::Vacant(v) => {
+                v.insert(TxnTable {
+                    base: table_info.clone(),
+                    meta,
+                });
+            }
+        }
+        Ok(())
+    }
+}
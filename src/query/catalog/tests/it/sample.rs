@@ -0,0 +1,62 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::plan::SampleInfo;
+use common_catalog::plan::SampleMethod;
+
+fn system(percent: f64, seed: u64) -> SampleInfo {
+    SampleInfo {
+        method: SampleMethod::System,
+        percent,
+        seed,
+        repeatable: true,
+    }
+}
+
+fn kept_blocks(sample: &SampleInfo) -> Vec<usize> {
+    (0..10000)
+        .filter(|i| sample.keep_block(&format!("1/{i}_v2.parquet")))
+        .collect()
+}
+
+#[test]
+fn test_sample_keep_block() {
+    assert_eq!(kept_blocks(&system(0.0, 1)).len(), 0);
+    assert_eq!(kept_blocks(&system(100.0, 1)).len(), 10000);
+
+    for percent in [1.0, 10.0, 50.0] {
+        let kept = kept_blocks(&system(percent, 1)).len() as f64;
+        let expected = percent * 100.0;
+        assert!(
+            (kept - expected).abs() < expected * 0.3,
+            "percent: {percent}, kept: {kept}"
+        );
+    }
+
+    // The same seed keeps the same blocks, another seed keeps other blocks.
+    assert_eq!(kept_blocks(&system(10.0, 1)), kept_blocks(&system(10.0, 1)));
+    assert_ne!(kept_blocks(&system(10.0, 1)), kept_blocks(&system(10.0, 2)));
+}
+
+#[test]
+fn test_sample_display() {
+    assert_eq!(system(10.0, 42).to_string(), "SYSTEM (10%) REPEATABLE (42)");
+    let sample = SampleInfo {
+        method: SampleMethod::Bernoulli,
+        percent: 0.5,
+        seed: 42,
+        repeatable: false,
+    };
+    assert_eq!(sample.to_string(), "BERNOULLI (0.5%)");
+}
@@ -222,6 +222,11 @@ pub struct StorageConfig {
     #[clap(long = "storage-allow-insecure")]
     pub allow_insecure: bool,
 
+    /// Max number of requests sent to the storage at the same time by this node, 0 means not limited.
+    #[clap(long, default_value_t)]
+    #[serde(rename = "max_inflight_requests")]
+    pub storage_max_inflight_requests: u64,
+
     // Fs storage backend config.
     #[clap(flatten)]
     pub fs: FsStorageConfig,
@@ -267,6 +272,7 @@ impl From<InnerStorageConfig> for StorageConfig {
             storage_num_cpus: inner.num_cpus,
             storage_type: "".to_string(),
             allow_insecure: inner.allow_insecure,
+            storage_max_inflight_requests: inner.max_inflight_requests,
             // use default for each config instead of using `..Default::default`
             // using `..Default::default` is calling `Self::default`
             // and `Self::default` relies on `InnerStorage::into()`
@@ -332,6 +338,7 @@ impl TryInto<InnerStorageConfig> for StorageConfig {
         Ok(InnerStorageConfig {
             num_cpus: self.storage_num_cpus,
             allow_insecure: self.allow_insecure,
+            max_inflight_requests: self.storage_max_inflight_requests,
             params: {
                 match self.storage_type.as_str() {
                     "azblob" => StorageParams::Azblob(self.azblob.try_into()?),
@@ -374,6 +381,8 @@ pub struct CatalogsHiveConfig {
     #[serde(alias = "meta_store_address")]
     pub address: String,
     pub protocol: String,
+    pub warehouse: String,
+    pub user: String,
 }
 
 /// this is the legacy version of external catalog configuration
@@ -418,6 +427,8 @@ impl TryInto<InnerCatalogHiveConfig> for CatalogsHiveConfig {
         Ok(InnerCatalogHiveConfig {
             address: self.address,
             protocol: self.protocol.parse()?,
+            warehouse: self.warehouse,
+            user: self.user,
         })
     }
 }
@@ -427,6 +438,8 @@ impl From<InnerCatalogHiveConfig> for CatalogsHiveConfig {
         Self {
             address: inner.address,
             protocol: inner.protocol.to_string(),
+            warehouse: inner.warehouse,
+            user: inner.user,
         }
     }
 }
@@ -443,6 +456,7 @@ impl TryInto<InnerCatalogHiveConfig> for HiveCatalogConfig {
         Ok(InnerCatalogHiveConfig {
             address: self.meta_store_address,
             protocol: self.protocol.parse()?,
+            ..Default::default()
         })
     }
 }
@@ -1227,12 +1241,21 @@ pub struct QueryConfig {
     #[clap(skip)]
     pub jwt_key_files: Vec<String>,
 
+    /// Addresses of the proxies (in CIDR) in front of query, whose forwarded client
+    /// addresses are trusted when checking network policies
+    #[clap(skip)]
+    pub trusted_proxy_ip_list: Vec<String>,
+
     #[clap(long, default_value = "auto")]
     pub default_storage_format: String,
 
     #[clap(long, default_value = "auto")]
     pub default_compression: String,
 
+    /// Local directory of the temporary files spilled by queries, each query node on the host uses its own subdirectory
+    #[clap(long, default_value = "./.databend/_spill")]
+    pub spill_local_path: String,
+
     #[clap(skip)]
     users: Vec<UserConfig>,
 
@@ -1361,8 +1384,10 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             management_mode: self.management_mode,
             jwt_key_file: self.jwt_key_file,
             jwt_key_files: self.jwt_key_files,
+            trusted_proxy_ip_list: self.trusted_proxy_ip_list,
             default_storage_format: self.default_storage_format,
             default_compression: self.default_compression,
+            spill_local_path: self.spill_local_path,
             idm: InnerIDMConfig {
                 users: users_to_inner(self.users)?,
             },
@@ -1424,8 +1449,10 @@ impl From<InnerQueryConfig> for QueryConfig {
             management_mode: inner.management_mode,
             jwt_key_file: inner.jwt_key_file,
             jwt_key_files: inner.jwt_key_files,
+            trusted_proxy_ip_list: inner.trusted_proxy_ip_list,
             default_storage_format: inner.default_storage_format,
             default_compression: inner.default_compression,
+            spill_local_path: inner.spill_local_path,
 
             users: users_from_inner(inner.idm.users),
             share_endpoint_address: inner.share_endpoint_address,
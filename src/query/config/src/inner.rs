@@ -164,8 +164,11 @@ pub struct QueryConfig {
     pub management_mode: bool,
     pub jwt_key_file: String,
     pub jwt_key_files: Vec<String>,
+    pub trusted_proxy_ip_list: Vec<String>,
     pub default_storage_format: String,
     pub default_compression: String,
+    /// Local directory of the temporary files spilled by queries.
+    pub spill_local_path: String,
     pub idm: IDMConfig,
     pub share_endpoint_address: String,
     pub share_endpoint_auth_token_file: String,
@@ -216,8 +219,10 @@ impl Default for QueryConfig {
             management_mode: false,
             jwt_key_file: "".to_string(),
             jwt_key_files: Vec::new(),
+            trusted_proxy_ip_list: Vec::new(),
             default_storage_format: "auto".to_string(),
             default_compression: "auto".to_string(),
+            spill_local_path: "./.databend/_spill".to_string(),
             idm: IDMConfig::default(),
             share_endpoint_address: "".to_string(),
             share_endpoint_auth_token_file: "".to_string(),
@@ -387,6 +392,13 @@ impl Display for ThriftProtocol {
 pub struct CatalogHiveConfig {
     pub address: String,
     pub protocol: ThriftProtocol,
+    /// Location of the warehouse, such as `hdfs://namenode:8020/user/hive/warehouse`.
+    ///
+    /// The storage of the location is used to read the data of hive tables,
+    /// the default storage is used if it's empty.
+    pub warehouse: String,
+    /// User name reported to hive meta store, the metastore is accessed anonymously if it's empty.
+    pub user: String,
 }
 
 impl Default for CatalogHiveConfig {
@@ -394,6 +406,8 @@ impl Default for CatalogHiveConfig {
         Self {
             address: "127.0.0.1:9083".to_string(),
             protocol: ThriftProtocol::Binary,
+            warehouse: "".to_string(),
+            user: "".to_string(),
         }
     }
 }
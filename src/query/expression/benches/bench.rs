@@ -0,0 +1,84 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use criterion::Criterion;
+
+/// Concatenating the blocks should be linear in the number of blocks, compare the time of
+/// 1k, 5k and 10k blocks of 1k rows.
+fn bench_concat(c: &mut Criterion) {
+    let data_types = vec![
+        DataType::Number(NumberDataType::Int64),
+        DataType::Boolean,
+        DataType::String,
+        DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int32))),
+        DataType::Array(Box::new(DataType::Number(NumberDataType::UInt8))),
+        DataType::Tuple(vec![
+            DataType::Number(NumberDataType::Float64),
+            DataType::Nullable(Box::new(DataType::String)),
+        ]),
+    ];
+
+    let mut group = c.benchmark_group("concat");
+    group.sample_size(10);
+
+    for num_blocks in [1000, 5000, 10000] {
+        let blocks = (0..num_blocks)
+            .map(|_| {
+                DataBlock::new_from_columns(
+                    data_types
+                        .iter()
+                        .map(|ty| Column::random(ty, 1000))
+                        .collect(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        group.bench_function(format!("data_block/{num_blocks}"), |b| {
+            b.iter(|| DataBlock::concat(&blocks).unwrap())
+        });
+
+        // How a sink accumulates the blocks it receives one by one.
+        group.bench_function(format!("column_builder/{num_blocks}"), |b| {
+            b.iter(|| {
+                let mut builders = data_types
+                    .iter()
+                    .map(|ty| ColumnBuilder::with_capacity(ty, 0))
+                    .collect::<Vec<_>>();
+                for builder in builders.iter_mut() {
+                    builder.reserve(num_blocks * 1000);
+                }
+                for block in &blocks {
+                    for (builder, entry) in builders.iter_mut().zip(block.columns()) {
+                        builder.append_column(entry.value.as_column().unwrap());
+                    }
+                }
+                builders
+                    .into_iter()
+                    .map(|builder| builder.build())
+                    .collect::<Vec<_>>()
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench_concat);
+criterion_main!(benches);
@@ -84,7 +84,7 @@ impl From<&DataType> for ArrowDataType {
 
             DataType::EmptyArray => ArrowDataType::Null,
             DataType::EmptyMap => ArrowDataType::Null,
-            DataType::Variant => ArrowDataType::LargeBinary,
+            DataType::Variant | DataType::Bitmap | DataType::Binary => ArrowDataType::LargeBinary,
 
             _ => unreachable!(),
         }
@@ -38,8 +38,11 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
         Scalar::Decimal(_) => unimplemented!("decimal type is not supported"),
         Scalar::Timestamp(x) => DataValue::Int64(*x),
         Scalar::Date(x) => DataValue::Int64(*x as i64),
+        Scalar::Interval(x) => DataValue::String(x.to_string().into_bytes()),
         Scalar::Boolean(x) => DataValue::Boolean(*x),
-        Scalar::String(x) | Scalar::Variant(x) => DataValue::String(x.clone()),
+        Scalar::String(x) | Scalar::Variant(x) | Scalar::Bitmap(x) | Scalar::Binary(x) => {
+            DataValue::String(x.clone())
+        }
         Scalar::Array(x) => {
             let values = (0..x.len())
                 .map(|idx| scalar_to_datavalue(&x.index(idx).unwrap().to_owned()))
@@ -17,6 +17,7 @@ use std::ops::Not;
 
 use common_arrow::arrow::bitmap;
 use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
@@ -25,6 +26,7 @@ use tracing::error;
 
 use crate::block::DataBlock;
 use crate::expression::Expr;
+use crate::expression::RemoteExpr;
 use crate::function::EvalContext;
 use crate::property::Domain;
 use crate::type_check::check_function;
@@ -33,9 +35,16 @@ use crate::types::any::AnyType;
 use crate::types::array::ArrayColumn;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableDomain;
+use crate::types::string::StringColumn;
+use crate::types::ArgType;
+use crate::types::ArrayType;
 use crate::types::BooleanType;
 use crate::types::DataType;
+use crate::types::MapType;
 use crate::types::NullableType;
+use crate::types::StringType;
+use crate::types::ValueType;
+use crate::types::VariantType;
 use crate::utils::arrow::constant_bitmap;
 use crate::values::Column;
 use crate::values::ColumnBuilder;
@@ -180,12 +189,30 @@ impl<'a> Evaluator<'a> {
                     validity,
                     errors: None,
                     tz: self.func_ctx.tz,
+                    now: self.func_ctx.now,
                 };
                 let (_, eval) = function.eval.as_scalar().unwrap();
                 let result = (eval)(cols_ref.as_slice(), &mut ctx);
                 ctx.render_error(*span, &args, &function.signature.name)?;
                 Ok(result)
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_expr,
+                return_type,
+                ..
+            } => {
+                let args_type = args
+                    .iter()
+                    .map(|expr| expr.data_type().clone())
+                    .collect::<Vec<_>>();
+                let args = args
+                    .iter()
+                    .map(|expr| self.partial_run(expr, validity.clone()))
+                    .collect::<Result<Vec<_>>>()?;
+                self.run_lambda(name, &args, &args_type, lambda_expr, return_type)
+            }
         };
 
         #[cfg(debug_assertions)]
@@ -436,6 +463,40 @@ impl<'a> Evaluator<'a> {
                 }
                 other => unreachable!("source: {}", other),
             },
+            (DataType::Variant, DataType::Map(_)) => {
+                let column = value.convert_to_full_column(src_type, 1);
+                let (map_column, _) = cast_variant_to_map(
+                    span,
+                    column.as_variant().unwrap(),
+                    validity.as_ref(),
+                    false,
+                )?;
+                let map_value = match value {
+                    Value::Scalar(_) => Value::Scalar(map_column.index(0).unwrap().to_owned()),
+                    Value::Column(_) => Value::Column(map_column),
+                };
+                self.run_cast(span, &variant_map_type(), dest_type, map_value, validity)
+            }
+            (DataType::Variant, DataType::Array(_)) => {
+                let column = value.convert_to_full_column(src_type, 1);
+                let (array_column, _) = cast_variant_to_array(
+                    span,
+                    column.as_variant().unwrap(),
+                    validity.as_ref(),
+                    false,
+                )?;
+                let array_value = match value {
+                    Value::Scalar(_) => Value::Scalar(array_column.index(0).unwrap().to_owned()),
+                    Value::Column(_) => Value::Column(array_column),
+                };
+                self.run_cast(
+                    span,
+                    &variant_array_type(),
+                    dest_type,
+                    array_value,
+                    validity,
+                )
+            }
             (DataType::Tuple(fields_src_ty), DataType::Tuple(fields_dest_ty))
                 if fields_src_ty.len() == fields_dest_ty.len() =>
             {
@@ -625,6 +686,44 @@ impl<'a> Evaluator<'a> {
                 }
                 _ => unreachable!(),
             },
+            // Only `Map(String, Variant)` is supported, values are not converted by `TRY_CAST`.
+            (DataType::Variant, map_ty @ DataType::Map(_)) if *map_ty == variant_map_type() => {
+                let column = value.convert_to_full_column(src_type, 1);
+                let (map_column, map_validity) =
+                    cast_variant_to_map(span, column.as_variant().unwrap(), None, true)?;
+                match value {
+                    Value::Scalar(_) if map_validity.get_bit(0) => {
+                        Ok(Value::Scalar(map_column.index(0).unwrap().to_owned()))
+                    }
+                    Value::Scalar(_) => Ok(Value::Scalar(Scalar::Null)),
+                    Value::Column(_) => {
+                        Ok(Value::Column(Column::Nullable(Box::new(NullableColumn {
+                            column: map_column,
+                            validity: map_validity,
+                        }))))
+                    }
+                }
+            }
+            // Only `Array(Variant)` is supported, items are not converted by `TRY_CAST`.
+            (DataType::Variant, array_ty @ DataType::Array(_))
+                if *array_ty == variant_array_type() =>
+            {
+                let column = value.convert_to_full_column(src_type, 1);
+                let (array_column, array_validity) =
+                    cast_variant_to_array(span, column.as_variant().unwrap(), None, true)?;
+                match value {
+                    Value::Scalar(_) if array_validity.get_bit(0) => {
+                        Ok(Value::Scalar(array_column.index(0).unwrap().to_owned()))
+                    }
+                    Value::Scalar(_) => Ok(Value::Scalar(Scalar::Null)),
+                    Value::Column(_) => {
+                        Ok(Value::Column(Column::Nullable(Box::new(NullableColumn {
+                            column: array_column,
+                            validity: array_validity,
+                        }))))
+                    }
+                }
+            }
             (DataType::Tuple(fields_src_ty), DataType::Tuple(fields_dest_ty))
                 if fields_src_ty.len() == fields_dest_ty.len() =>
             {
@@ -800,6 +899,197 @@ impl<'a> Evaluator<'a> {
         }
     }
 
+    /// Evaluate a higher-order function. The array is flattened into its values column so that
+    /// `array_map` and `array_filter` evaluate the lambda only once for the whole block, while
+    /// `array_reduce` evaluates it once for each element position.
+    fn run_lambda(
+        &self,
+        func_name: &str,
+        args: &[Value<AnyType>],
+        args_type: &[DataType],
+        lambda_expr: &RemoteExpr,
+        return_type: &DataType,
+    ) -> Result<Value<AnyType>> {
+        let is_scalar = args.iter().all(|arg| arg.as_scalar().is_some());
+        let num_rows = if is_scalar {
+            1
+        } else {
+            self.input_columns.num_rows()
+        };
+        let (array, validity) = match args[0].convert_to_full_column(&args_type[0], num_rows) {
+            Column::Nullable(box NullableColumn { column, validity }) => (column, Some(validity)),
+            column => (column, None),
+        };
+        let (array, elem_type) = match (array, args_type[0].remove_nullable()) {
+            (Column::Array(array), DataType::Array(box elem_type)) => (array, elem_type),
+            (_, ty) => {
+                return Err(ErrorCode::Internal(format!(
+                    "{func_name} expects an array argument, but got {ty}"
+                )));
+            }
+        };
+        // Rebase the offsets in case the array column has been sliced.
+        let start = array.offsets[0] as usize;
+        let end = array.offsets[num_rows] as usize;
+        let values = array.values.slice(start..end);
+        let offsets = array
+            .offsets
+            .iter()
+            .map(|offset| *offset - start as u64)
+            .collect::<Vec<_>>();
+        let captured = args[1..]
+            .iter()
+            .zip(&args_type[1..])
+            .map(|(arg, ty)| BlockEntry {
+                data_type: ty.clone(),
+                value: Value::Column(arg.convert_to_full_column(ty, num_rows)),
+            })
+            .collect::<Vec<_>>();
+        let lambda_expr = lambda_expr.as_expr(self.fn_registry);
+
+        let column = match func_name {
+            "array_map" | "array_filter" => {
+                // Replicate the captured columns across the elements of each row.
+                let indices = offsets
+                    .windows(2)
+                    .enumerate()
+                    .flat_map(|(row, w)| std::iter::repeat(row as u32).take((w[1] - w[0]) as usize))
+                    .collect::<Vec<_>>();
+                let mut entries = vec![BlockEntry {
+                    data_type: elem_type.clone(),
+                    value: Value::Column(values.clone()),
+                }];
+                entries.extend(
+                    captured
+                        .into_iter()
+                        .map(|entry| take_entry(entry, &indices)),
+                );
+                let result = self.run_lambda_expr(entries, values.len(), &lambda_expr)?;
+
+                let array = if func_name == "array_map" {
+                    ArrayColumn {
+                        values: result,
+                        offsets: offsets.into(),
+                    }
+                } else {
+                    let filter = match result {
+                        Column::Boolean(bitmap) => bitmap,
+                        Column::Nullable(box NullableColumn {
+                            column: Column::Boolean(bitmap),
+                            validity,
+                        }) => bitmap::and(&bitmap, &validity),
+                        _ => constant_bitmap(false, values.len()).into(),
+                    };
+                    let mut new_offsets = Vec::with_capacity(offsets.len());
+                    new_offsets.push(0);
+                    for w in offsets.windows(2) {
+                        let selected = (w[0] as usize..w[1] as usize)
+                            .filter(|i| filter.get_bit(*i))
+                            .count();
+                        new_offsets.push(new_offsets.last().unwrap() + selected as u64);
+                    }
+                    ArrayColumn {
+                        values: values.filter(&filter),
+                        offsets: new_offsets.into(),
+                    }
+                };
+                let column = Column::Array(Box::new(array));
+                match validity {
+                    Some(validity) => {
+                        Column::Nullable(Box::new(NullableColumn { column, validity }))
+                    }
+                    None => column,
+                }
+            }
+            "array_reduce" => {
+                let lens = offsets
+                    .windows(2)
+                    .enumerate()
+                    .map(|(row, w)| match &validity {
+                        Some(validity) if !validity.get_bit(row) => 0,
+                        _ => (w[1] - w[0]) as usize,
+                    })
+                    .collect::<Vec<_>>();
+                let mut rows = (0..num_rows as u32)
+                    .filter(|row| lens[*row as usize] > 0)
+                    .collect::<Vec<_>>();
+                let first_indices = rows
+                    .iter()
+                    .map(|row| offsets[*row as usize] as u32)
+                    .collect::<Vec<_>>();
+                let mut acc = values.take(&first_indices);
+                let mut results = vec![Scalar::Null; num_rows];
+                let mut pos = 1;
+                loop {
+                    // Save the accumulators of the arrays that have no more elements.
+                    let mut keep = MutableBitmap::with_capacity(rows.len());
+                    for (i, row) in rows.iter().enumerate() {
+                        let row = *row as usize;
+                        if lens[row] == pos {
+                            results[row] = acc.index(i).unwrap().to_owned();
+                        }
+                        keep.push(lens[row] > pos);
+                    }
+                    let keep: Bitmap = keep.into();
+                    if keep.unset_bits() > 0 {
+                        acc = acc.filter(&keep);
+                        rows.retain(|row| lens[*row as usize] > pos);
+                    }
+                    if rows.is_empty() {
+                        break;
+                    }
+
+                    let elem_indices = rows
+                        .iter()
+                        .map(|row| (offsets[*row as usize] as usize + pos) as u32)
+                        .collect::<Vec<_>>();
+                    let mut entries = vec![
+                        BlockEntry {
+                            data_type: elem_type.clone(),
+                            value: Value::Column(acc),
+                        },
+                        BlockEntry {
+                            data_type: elem_type.clone(),
+                            value: Value::Column(values.take(&elem_indices)),
+                        },
+                    ];
+                    entries.extend(
+                        captured
+                            .iter()
+                            .map(|entry| take_entry(entry.clone(), &rows)),
+                    );
+                    acc = self.run_lambda_expr(entries, rows.len(), &lambda_expr)?;
+                    pos += 1;
+                }
+
+                let mut builder = ColumnBuilder::with_capacity(return_type, num_rows);
+                for result in &results {
+                    builder.push(result.as_ref());
+                }
+                builder.build()
+            }
+            _ => unreachable!("unknown lambda function: {func_name}"),
+        };
+
+        if is_scalar {
+            Ok(Value::Scalar(column.index(0).unwrap().to_owned()))
+        } else {
+            Ok(Value::Column(column))
+        }
+    }
+
+    fn run_lambda_expr(
+        &self,
+        entries: Vec<BlockEntry>,
+        num_rows: usize,
+        lambda_expr: &Expr,
+    ) -> Result<Column> {
+        let block = DataBlock::new(entries, num_rows);
+        let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+        let result = evaluator.run(lambda_expr)?;
+        Ok(result.convert_to_full_column(lambda_expr.data_type(), num_rows))
+    }
+
     /// Evaluate a set returning function. Return multiple chunks of results, and the repeat times of each of the result.
     pub fn run_srf(&self, expr: &Expr) -> Result<Vec<(Value<AnyType>, usize)>> {
         if let Expr::FunctionCall {
@@ -824,6 +1114,108 @@ impl<'a> Evaluator<'a> {
     }
 }
 
+fn take_entry(entry: BlockEntry, indices: &[u32]) -> BlockEntry {
+    let value = match entry.value {
+        Value::Scalar(scalar) => Value::Scalar(scalar),
+        Value::Column(column) => Value::Column(column.take(indices)),
+    };
+    BlockEntry {
+        data_type: entry.data_type,
+        value,
+    }
+}
+
+fn variant_map_type() -> DataType {
+    DataType::Map(Box::new(DataType::Tuple(vec![
+        DataType::String,
+        DataType::Variant,
+    ])))
+}
+
+/// Converts jsonb objects into `Map(String, Variant)`. A row that is not an object is
+/// an error for `CAST`, and becomes `NULL` in the returned validity for `TRY_CAST`.
+fn cast_variant_to_map(
+    span: Span,
+    column: &StringColumn,
+    validity: Option<&Bitmap>,
+    is_try: bool,
+) -> Result<(Column, Bitmap)> {
+    let mut builder = MapType::<StringType, VariantType>::create_builder(column.len(), &[]);
+    let mut map_validity = MutableBitmap::with_capacity(column.len());
+    for (idx, val) in column.iter().enumerate() {
+        match jsonb::from_slice(val) {
+            Ok(jsonb::Value::Object(fields)) => {
+                for (key, field) in fields {
+                    let mut buf = Vec::new();
+                    field.write_to_vec(&mut buf);
+                    builder.put_item((key.as_bytes(), &buf));
+                }
+                map_validity.push(true);
+            }
+            _ if is_try || validity.map(|v| !v.get_bit(idx)).unwrap_or(false) => {
+                map_validity.push(false);
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "unable to cast variant `{}` to type `{}`",
+                    jsonb::to_string(val),
+                    variant_map_type()
+                ))
+                .set_span(span));
+            }
+        }
+        builder.commit_row();
+    }
+    Ok((
+        MapType::<StringType, VariantType>::upcast_column(builder.build()),
+        map_validity.into(),
+    ))
+}
+
+fn variant_array_type() -> DataType {
+    DataType::Array(Box::new(DataType::Variant))
+}
+
+/// Converts jsonb arrays into `Array(Variant)`. A row that is not an array is
+/// an error for `CAST`, and becomes `NULL` in the returned validity for `TRY_CAST`.
+fn cast_variant_to_array(
+    span: Span,
+    column: &StringColumn,
+    validity: Option<&Bitmap>,
+    is_try: bool,
+) -> Result<(Column, Bitmap)> {
+    let mut builder = ArrayType::<VariantType>::create_builder(column.len(), &[]);
+    let mut array_validity = MutableBitmap::with_capacity(column.len());
+    for (idx, val) in column.iter().enumerate() {
+        match jsonb::from_slice(val) {
+            Ok(jsonb::Value::Array(items)) => {
+                for item in items {
+                    let mut buf = Vec::new();
+                    item.write_to_vec(&mut buf);
+                    builder.put_item(&buf);
+                }
+                array_validity.push(true);
+            }
+            _ if is_try || validity.map(|v| !v.get_bit(idx)).unwrap_or(false) => {
+                array_validity.push(false);
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "unable to cast variant `{}` to type `{}`",
+                    jsonb::to_string(val),
+                    variant_array_type()
+                ))
+                .set_span(span));
+            }
+        }
+        builder.commit_row();
+    }
+    Ok((
+        ArrayType::<VariantType>::upcast_column(builder.build()),
+        array_validity.into(),
+    ))
+}
+
 pub struct ConstantFolder<'a, Index: ColumnIndex> {
     input_domains: HashMap<Index, Domain>,
     func_ctx: FunctionContext,
@@ -1025,7 +1417,13 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
                     );
                 }
 
-                if all_args_is_scalar {
+                let is_per_row = self
+                    .fn_registry
+                    .get_property(&function.signature.name)
+                    .map(|property| property.per_row)
+                    .unwrap_or(false);
+
+                if all_args_is_scalar && !is_per_row {
                     let block = DataBlock::empty();
                     let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
                     // Since we know the expression is constant, it'll be safe to change its column index type.
@@ -1044,6 +1442,48 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
 
                 (func_expr, func_domain)
             }
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => {
+                let args_expr = args
+                    .iter()
+                    .map(|arg| self.fold_once(arg).0)
+                    .collect::<Vec<_>>();
+                let all_args_is_scalar = args_expr.iter().all(|arg| arg.as_constant().is_some());
+
+                let func_expr = Expr::LambdaFunctionCall {
+                    span: *span,
+                    name: name.clone(),
+                    args: args_expr,
+                    lambda_expr: lambda_expr.clone(),
+                    lambda_display: lambda_display.clone(),
+                    return_type: return_type.clone(),
+                };
+
+                if all_args_is_scalar {
+                    let block = DataBlock::empty();
+                    let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+                    // Since we know the expression is constant, it'll be safe to change its column index type.
+                    let func_expr = func_expr.project_column_ref(|_| unreachable!());
+                    if let Ok(Value::Scalar(scalar)) = evaluator.run(&func_expr) {
+                        return (
+                            Expr::Constant {
+                                span: *span,
+                                scalar: scalar.clone(),
+                                data_type: return_type.clone(),
+                            },
+                            Some(scalar.as_ref().domain(return_type)),
+                        );
+                    }
+                }
+
+                (func_expr, None)
+            }
         };
 
         debug_assert_eq!(expr.data_type(), new_expr.data_type());
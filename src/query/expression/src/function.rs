@@ -18,6 +18,7 @@ use std::ops::BitOr;
 use std::ops::Not;
 use std::sync::Arc;
 
+use chrono::Utc;
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_exception::ErrorCode;
@@ -30,6 +31,7 @@ use serde::Serialize;
 
 use crate::date_helper::TzLUT;
 use crate::property::Domain;
+use crate::property::FunctionMeta;
 use crate::property::FunctionProperty;
 use crate::type_check::try_unify_signature;
 use crate::types::nullable::NullableColumn;
@@ -84,6 +86,9 @@ pub enum FunctionEval {
 #[derive(Clone, Copy, Default)]
 pub struct FunctionContext {
     pub tz: TzLUT,
+    /// Start time of the statement in microseconds. If set, `now()` and `today()` are
+    /// evaluated against it, so they return the same value for the whole statement.
+    pub now: Option<i64>,
 }
 
 #[derive(Clone)]
@@ -91,12 +96,13 @@ pub struct EvalContext<'a> {
     pub generics: &'a GenericMap,
     pub num_rows: usize,
     pub tz: TzLUT,
+    pub now: Option<i64>,
 
     /// Validity bitmap of outer nullable column. This is an optimization
     /// to avoid recording errors on the NULL value which has a corresponding
     /// default value in nullable's inner column.
     pub validity: Option<Bitmap>,
-    pub errors: Option<(MutableBitmap, String)>,
+    pub errors: Option<(MutableBitmap, ErrorCode)>,
 }
 
 /// `FunctionID` is a unique identifier for a function in the registry. It's used to
@@ -131,6 +137,16 @@ pub struct FunctionRegistry {
     pub auto_try_cast_rules: Vec<(DataType, DataType)>,
 
     pub properties: HashMap<String, FunctionProperty>,
+    pub metas: HashMap<String, FunctionMeta>,
+}
+
+/// A function overload as shown by `DESCRIBE FUNCTION`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionDescription {
+    pub signature: String,
+    pub return_type: String,
+    pub description: String,
+    pub example: String,
 }
 
 impl Function {
@@ -170,6 +186,26 @@ impl FunctionRegistry {
             .collect()
     }
 
+    /// All the registered overloads, excluding the ones built by function factories,
+    /// ordered by name and id.
+    pub fn registered_functions(&self) -> Vec<(FunctionID, Arc<Function>)> {
+        self.funcs
+            .iter()
+            .flat_map(|(name, funcs)| {
+                funcs.iter().map(|(func, id)| {
+                    (
+                        FunctionID::Builtin {
+                            name: name.clone(),
+                            id: *id,
+                        },
+                        func.clone(),
+                    )
+                })
+            })
+            .sorted_by(|(a, _), (b, _)| (a.name(), a.id()).cmp(&(b.name(), b.id())))
+            .collect()
+    }
+
     pub fn contains(&self, func_name: &str) -> bool {
         self.funcs.contains_key(func_name)
             || self.factories.contains_key(func_name)
@@ -279,6 +315,43 @@ impl FunctionRegistry {
         }
     }
 
+    /// Describe all the overloads of a function. Overloads built by function
+    /// factories depend on the argument types, so they are described by the
+    /// function name only, as are functions that only have documentation
+    /// because they are rewritten by the planner, e.g. `date_add`.
+    pub fn describe(&self, func_name: &str) -> Vec<FunctionDescription> {
+        let func_name = func_name.to_lowercase();
+        let name = self.aliases.get(&func_name).unwrap_or(&func_name);
+        let meta = self.metas.get(name).copied().unwrap_or_default();
+        let describe = |signature: String, return_type: String| FunctionDescription {
+            signature,
+            return_type,
+            description: meta.description.to_string(),
+            example: meta.example.to_string(),
+        };
+
+        let mut descriptions = self
+            .funcs
+            .get(name)
+            .into_iter()
+            .flatten()
+            .sorted_by_key(|(_, id)| *id)
+            .map(|(func, _)| {
+                let signature = &func.signature;
+                describe(
+                    format!("{}({})", name, signature.args_type.iter().join(", ")),
+                    signature.return_type.to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        if self.factories.contains_key(name)
+            || (descriptions.is_empty() && self.metas.contains_key(name))
+        {
+            descriptions.push(describe(format!("{name}(...)"), String::new()));
+        }
+        descriptions
+    }
+
     pub fn register_function(&mut self, func: Function) {
         let name = func.signature.name.clone();
         let id = self.next_function_id(&name);
@@ -300,6 +373,10 @@ impl FunctionRegistry {
             .push((Box::new(factory), id));
     }
 
+    pub fn register_meta(&mut self, fn_name: &str, meta: FunctionMeta) {
+        self.metas.insert(fn_name.to_string(), meta);
+    }
+
     pub fn register_aliases(&mut self, fn_name: &str, aliases: &[&str]) {
         for alias in aliases {
             self.aliases.insert(alias.to_string(), fn_name.to_string());
@@ -410,8 +487,24 @@ impl FunctionID {
 }
 
 impl<'a> EvalContext<'a> {
+    /// The current time in microseconds, which is the start time of the statement if known.
+    #[inline]
+    pub fn now(&self) -> i64 {
+        self.now.unwrap_or_else(|| Utc::now().timestamp_micros())
+    }
+
     #[inline]
     pub fn set_error(&mut self, row: usize, error_msg: impl Into<String>) {
+        self.set_error_with(row, || ErrorCode::Internal(error_msg.into()))
+    }
+
+    /// Sets the error of the row like [`EvalContext::set_error`], but the error is reported
+    /// with its own code instead of as an internal error, e.g. the errors of the meta service.
+    pub fn set_error_code(&mut self, row: usize, error: ErrorCode) {
+        self.set_error_with(row, || error)
+    }
+
+    fn set_error_with(&mut self, row: usize, error: impl FnOnce() -> ErrorCode) {
         // If the row is NULL, we don't need to set error.
         if self
             .validity
@@ -429,7 +522,7 @@ impl<'a> EvalContext<'a> {
             None => {
                 let mut valids = constant_bitmap(true, self.num_rows.max(1));
                 valids.set(row, false);
-                self.errors = Some((valids, error_msg.into()));
+                self.errors = Some((valids, error()));
             }
         }
     }
@@ -453,10 +546,10 @@ impl<'a> EvalContext<'a> {
                     })
                     .join(", ");
 
-                Err(ErrorCode::Internal(format!(
-                    "{error} while evaluating function `{func_name}({args})`"
-                ))
-                .set_span(span))
+                Err(error
+                    .clone()
+                    .add_message_back(format!(" while evaluating function `{func_name}({args})`"))
+                    .set_span(span))
             }
             None => Ok(()),
         }
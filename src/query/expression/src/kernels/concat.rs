@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use itertools::Itertools;
@@ -25,10 +26,13 @@ use crate::types::string::StringColumnBuilder;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::DateType;
 use crate::types::EmptyArrayType;
 use crate::types::EmptyMapType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NullType;
 use crate::types::NullableType;
@@ -124,7 +128,10 @@ impl Column {
                     Column::Decimal(DecimalColumn::DECIMAL_TYPE(builder.into(), *size))
                 }
             }),
-            Column::Boolean(_) => Self::concat_arg_types::<BooleanType>(columns),
+            Column::Boolean(_) => {
+                let builder = MutableBitmap::with_capacity(capacity);
+                Self::concat_value_types::<BooleanType>(builder, columns)
+            }
             Column::String(_) => {
                 let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
                 let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
@@ -138,6 +145,10 @@ impl Column {
                 let builder = Vec::with_capacity(capacity);
                 Self::concat_value_types::<DateType>(builder, columns)
             }
+            Column::Interval(_) => {
+                let builder = Vec::with_capacity(capacity);
+                Self::concat_value_types::<IntervalType>(builder, columns)
+            }
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
@@ -172,7 +183,10 @@ impl Column {
                 }
 
                 let column = Self::concat(&inners);
-                let validity = Self::concat_arg_types::<BooleanType>(&bitmaps);
+                let validity = Self::concat_value_types::<BooleanType>(
+                    MutableBitmap::with_capacity(capacity),
+                    &bitmaps,
+                );
                 let validity = BooleanType::try_downcast_column(&validity).unwrap();
 
                 Column::Nullable(Box::new(NullableColumn { column, validity }))
@@ -194,6 +208,16 @@ impl Column {
                 let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
                 Self::concat_value_types::<VariantType>(builder, columns)
             }
+            Column::Bitmap(_) => {
+                let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
+                let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
+                Self::concat_value_types::<BitmapType>(builder, columns)
+            }
+            Column::Binary(_) => {
+                let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
+                let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
+                Self::concat_value_types::<BinaryType>(builder, columns)
+            }
         }
     }
 
@@ -29,6 +29,8 @@ use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::AnyType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::MapType;
 use crate::types::ValueType;
@@ -134,6 +136,10 @@ impl Column {
                 let d = Self::filter_primitive_types(column, filter);
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let i = Self::filter_primitive_types(column, filter);
+                Column::Interval(i)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -185,6 +191,26 @@ impl Column {
                     filter,
                 )
             }
+            Column::Bitmap(column) => {
+                let bytes_per_row = column.data.len() / filter.len().max(1);
+                let data_capacity = (filter.len() - filter.unset_bits()) * bytes_per_row;
+
+                Self::filter_scalar_types::<BitmapType>(
+                    column,
+                    StringColumnBuilder::with_capacity(length, data_capacity),
+                    filter,
+                )
+            }
+            Column::Binary(column) => {
+                let bytes_per_row = column.data.len() / filter.len().max(1);
+                let data_capacity = (filter.len() - filter.unset_bits()) * bytes_per_row;
+
+                Self::filter_scalar_types::<BinaryType>(
+                    column,
+                    StringColumnBuilder::with_capacity(length, data_capacity),
+                    filter,
+                )
+            }
         }
     }
 
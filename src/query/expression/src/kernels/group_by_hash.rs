@@ -36,6 +36,7 @@ use crate::types::number::Number;
 use crate::types::number::NumberColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::string::StringIterator;
+use crate::types::variant::canonical_variant;
 use crate::types::DataType;
 use crate::types::DecimalDataType;
 use crate::types::NumberDataType;
@@ -162,15 +163,28 @@ impl HashMethod for HashMethodSingleString {
     fn build_keys_state(
         &self,
         group_columns: &[(Column, DataType)],
-        _rows: usize,
+        rows: usize,
     ) -> Result<KeysState> {
-        Ok(KeysState::Column(group_columns[0].0.clone()))
+        match &group_columns[0].0 {
+            // The equal variants may be encoded differently, e.g. `1` and `1.0`.
+            Column::Variant(col) => {
+                let mut builder = StringColumnBuilder::with_capacity(rows, col.data.len());
+                for value in col.iter() {
+                    canonical_variant(value, &mut builder.data);
+                    builder.commit_row();
+                }
+                Ok(KeysState::Column(Column::Variant(builder.build())))
+            }
+            col => Ok(KeysState::Column(col.clone())),
+        }
     }
 
     fn build_keys_iter<'a>(&self, key_state: &'a KeysState) -> Result<Self::HashKeyIter<'a>> {
         match key_state {
             KeysState::Column(Column::String(col)) => Ok(col.iter()),
             KeysState::Column(Column::Variant(col)) => Ok(col.iter()),
+            KeysState::Column(Column::Bitmap(col)) => Ok(col.iter()),
+            KeysState::Column(Column::Binary(col)) => Ok(col.iter()),
             _ => unreachable!(),
         }
     }
@@ -538,6 +552,11 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
         }
         Column::Timestamp(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
         Column::Date(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
+        Column::Interval(v) => {
+            vec.extend_from_slice(v[row].months.to_le_bytes().as_ref());
+            vec.extend_from_slice(v[row].days.to_le_bytes().as_ref());
+            vec.extend_from_slice(v[row].micros.to_le_bytes().as_ref());
+        }
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
             BinaryWrite::write_uvarint(vec, data.len() as u64).unwrap();
@@ -558,6 +577,11 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
             }
         }
         Column::Variant(v) => {
+            let mut value = Vec::new();
+            canonical_variant(unsafe { v.index_unchecked(row) }, &mut value);
+            BinaryWrite::write_binary(vec, &value).unwrap()
+        }
+        Column::Bitmap(v) | Column::Binary(v) => {
             BinaryWrite::write_binary(vec, unsafe { v.index_unchecked(row) }).unwrap()
         }
     }
@@ -18,6 +18,7 @@ mod group_by;
 mod group_by_hash;
 mod scatter;
 mod sort;
+mod sort_key;
 mod take;
 mod take_chunks;
 mod topk;
@@ -25,5 +26,6 @@ mod topk;
 pub use group_by::*;
 pub use group_by_hash::*;
 pub use sort::*;
+pub use sort_key::*;
 pub use take_chunks::*;
 pub use topk::*;
@@ -28,8 +28,8 @@ use common_arrow::arrow::error::Result as ArrowResult;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::sort_key::sort_column_to_arrow_array;
 use crate::types::DataType;
-use crate::utils::arrow::column_to_arrow_array;
 use crate::Column;
 use crate::DataBlock;
 
@@ -54,7 +54,7 @@ impl DataBlock {
         }
         let order_columns = descriptions
             .iter()
-            .map(|d| column_to_arrow_array(block.get_by_offset(d.offset), num_rows))
+            .map(|d| sort_column_to_arrow_array(block.get_by_offset(d.offset), num_rows))
             .collect::<Vec<_>>();
 
         let order_arrays = descriptions
@@ -99,8 +99,8 @@ impl DataBlock {
         let sort_arrays = descriptions
             .iter()
             .map(|d| {
-                let left = column_to_arrow_array(lhs.get_by_offset(d.offset), lhs_len);
-                let right = column_to_arrow_array(rhs.get_by_offset(d.offset), rhs_len);
+                let left = sort_column_to_arrow_array(lhs.get_by_offset(d.offset), lhs_len);
+                let right = sort_column_to_arrow_array(rhs.get_by_offset(d.offset), rhs_len);
                 sort_options.push(arrow_sort::SortOptions {
                     descending: !d.asc,
                     nulls_first: d.nulls_first,
@@ -184,27 +184,27 @@ impl DataBlock {
     }
 }
 
-fn compare_variant(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
-    let left = Column::from_arrow(left, &DataType::Variant)
-        .as_variant()
+fn compare_interval(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = Column::from_arrow(left, &DataType::Interval)
+        .as_interval()
         .cloned()
         .unwrap();
-    let right = Column::from_arrow(right, &DataType::Variant)
-        .as_variant()
+    let right = Column::from_arrow(right, &DataType::Interval)
+        .as_interval()
         .cloned()
         .unwrap();
     Ok(Box::new(move |i, j| {
-        let l = unsafe { left.index_unchecked(i) };
-        let r = unsafe { right.index_unchecked(j) };
-        jsonb::compare(l, r).unwrap()
+        let l = unsafe { left.get_unchecked(i) };
+        let r = unsafe { right.get_unchecked(j) };
+        l.cmp(r)
     }))
 }
 
 fn build_compare(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
     match left.data_type() {
         ArrowType::Extension(name, _, _) => {
-            if name == "Variant" {
-                compare_variant(left, right)
+            if name == "Interval" {
+                compare_interval(left, right)
             } else {
                 Err(ArrowError::NotYetImplemented(format!(
                     "Sort not supported for data type {:?}",
@@ -0,0 +1,272 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sort keys are the binary encoding of values whose byte-wise order is the order of the values,
+//! two values are equal if and only if their sort keys are the same bytes.
+//!
+//! They give an ordering to the types which arrow can't compare, i.e. `Variant` and the
+//! nested types. The encoding of each value is self-delimiting, so the keys of the items of an
+//! array concatenated compare as the array, in lexicographic order.
+//!
+//! The ordering of variants is defined by the JSON type first:
+//!
+//! `boolean < number < string < object < array < null`
+//!
+//! and then by the value:
+//!
+//! - `false < true`.
+//! - Numbers compare by their numeric value, no matter how they are stored, so `1` and `1.0`
+//!   are equal. `NaN` is greater than all the other numbers, `-0.0` is equal to `0`.
+//! - Strings compare by their UTF-8 bytes.
+//! - Arrays compare their items in lexicographic order, a prefix is less than the longer array.
+//! - Objects compare their entries sorted by key in lexicographic order, as the pairs of key
+//!   and value.
+//!
+//! The sort keys are only meant to be compared in memory, the encoding may change between
+//! versions, don't persist them.
+
+use common_arrow::arrow::array::Array;
+use jsonb::Number;
+use jsonb::Value as JsonbValue;
+
+use crate::types::decimal::DecimalColumn;
+use crate::types::nullable::NullableColumn;
+use crate::types::number::NumberColumn;
+use crate::types::string::StringColumn;
+use crate::types::string::StringColumnBuilder;
+use crate::types::DataType;
+use crate::utils::arrow::column_to_arrow_array;
+use crate::with_integer_mapped_type;
+use crate::BlockEntry;
+use crate::Column;
+
+const VARIANT_BOOLEAN: u8 = 0x01;
+const VARIANT_NUMBER: u8 = 0x02;
+const VARIANT_STRING: u8 = 0x03;
+const VARIANT_OBJECT: u8 = 0x04;
+const VARIANT_ARRAY: u8 = 0x05;
+const VARIANT_NULL: u8 = 0x06;
+
+/// Marks an item of an array, or an entry of an object.
+const ITEM: u8 = 0x01;
+/// Marks the end of an array or an object, it's less than `ITEM` so a prefix sorts first.
+const END: u8 = 0x00;
+
+const VALID: u8 = 0x01;
+/// Nulls in nested values sort last, like the nulls in a variant.
+const NULL: u8 = 0x02;
+
+/// Whether the values of the type are sorted by their sort keys instead of by arrow.
+pub fn need_sort_key(data_type: &DataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        DataType::Variant | DataType::Array(_) | DataType::Map(_) | DataType::Tuple(_)
+    )
+}
+
+/// The type to sort the values of the type by, see [`sort_column_to_arrow_array`].
+pub fn sort_key_data_type(data_type: &DataType) -> DataType {
+    if !need_sort_key(data_type) {
+        return data_type.clone();
+    }
+    if data_type.is_nullable() {
+        DataType::Nullable(Box::new(DataType::String))
+    } else {
+        DataType::String
+    }
+}
+
+/// Converts the column to the arrow array to sort by. The values that arrow can't compare are
+/// replaced by their sort keys, which compare as binary.
+pub fn sort_column_to_arrow_array(entry: &BlockEntry, num_rows: usize) -> Box<dyn Array> {
+    if !need_sort_key(&entry.data_type) {
+        return column_to_arrow_array(entry, num_rows);
+    }
+    let keys = match entry
+        .value
+        .convert_to_full_column(&entry.data_type, num_rows)
+    {
+        Column::Nullable(c) => Column::Nullable(Box::new(NullableColumn {
+            column: Column::String(sort_key_column(&c.column)),
+            validity: c.validity,
+        })),
+        column => Column::String(sort_key_column(&column)),
+    };
+    keys.as_arrow()
+}
+
+/// Encodes the sort keys of all the rows of the column.
+pub fn sort_key_column(column: &Column) -> StringColumn {
+    let mut builder = StringColumnBuilder::with_capacity(column.len(), column.len() * 8);
+    for row in 0..column.len() {
+        encode_sort_key(column, row, &mut builder.data);
+        builder.commit_row();
+    }
+    builder.build()
+}
+
+/// Appends the sort key of the value at `row` of the column to `buf`.
+pub fn encode_sort_key(column: &Column, row: usize, buf: &mut Vec<u8>) {
+    match column {
+        Column::Null { .. } => {}
+        Column::EmptyArray { .. } | Column::EmptyMap { .. } => buf.push(END),
+        Column::Number(col) => with_integer_mapped_type!(|NUM_TYPE| match col {
+            NumberColumn::NUM_TYPE(c) => put_i128(buf, c[row] as i128),
+            NumberColumn::Float32(c) => put_f64(buf, c[row].0 as f64),
+            NumberColumn::Float64(c) => put_f64(buf, c[row].0),
+        }),
+        Column::Decimal(DecimalColumn::Decimal128(c, _)) => put_i128(buf, c[row]),
+        Column::Decimal(DecimalColumn::Decimal256(c, _)) => {
+            let mut bytes = c[row].to_be_bytes();
+            bytes[0] ^= 0x80;
+            buf.extend_from_slice(&bytes);
+        }
+        Column::Boolean(c) => buf.push(c.get_bit(row) as u8),
+        Column::String(c) | Column::Bitmap(c) | Column::Binary(c) => {
+            put_bytes(buf, unsafe { c.index_unchecked(row) })
+        }
+        Column::Timestamp(c) => put_i64(buf, c[row]),
+        Column::Date(c) => put_i64(buf, c[row] as i64),
+        Column::Interval(c) => {
+            // Same as the ordering of intervals: the total length, then the parts.
+            let interval = c[row];
+            put_i128(buf, interval.total_micros());
+            put_i64(buf, interval.months as i64);
+            put_i64(buf, interval.days as i64);
+        }
+        Column::Array(array) | Column::Map(array) => {
+            let items = array.index(row).unwrap();
+            for i in 0..items.len() {
+                buf.push(ITEM);
+                encode_sort_key(&items, i, buf);
+            }
+            buf.push(END);
+        }
+        Column::Nullable(c) => {
+            if c.validity.get_bit(row) {
+                buf.push(VALID);
+                encode_sort_key(&c.column, row, buf);
+            } else {
+                buf.push(NULL);
+            }
+        }
+        Column::Tuple(fields) => {
+            for field in fields.iter() {
+                encode_sort_key(field, row, buf);
+            }
+        }
+        Column::Variant(c) => encode_variant_sort_key(unsafe { c.index_unchecked(row) }, buf),
+    }
+}
+
+/// Appends the sort key of the JSONB value to `buf`, see the module documentation for the
+/// ordering of variants.
+pub fn encode_variant_sort_key(value: &[u8], buf: &mut Vec<u8>) {
+    match jsonb::from_slice(value) {
+        Ok(value) => encode_jsonb_value(&value, buf),
+        // Can't happen to the values built by databend, order them as strings of the raw bytes.
+        Err(_) => {
+            buf.push(VARIANT_STRING);
+            put_bytes(buf, value);
+        }
+    }
+}
+
+fn encode_jsonb_value(value: &JsonbValue, buf: &mut Vec<u8>) {
+    match value {
+        JsonbValue::Null => buf.push(VARIANT_NULL),
+        JsonbValue::Bool(v) => {
+            buf.push(VARIANT_BOOLEAN);
+            buf.push(*v as u8);
+        }
+        JsonbValue::Number(n) => {
+            buf.push(VARIANT_NUMBER);
+            // The number is encoded as the nearest `f64` and the exact difference from it, so
+            // that the integers which can't be represented by a `f64` are still ordered, and
+            // `1` and `1.0` have the same key.
+            let (approx, delta) = match *n {
+                Number::Int64(v) => {
+                    let approx = v as f64;
+                    (approx, (v as i128 - approx as i128) as i64)
+                }
+                Number::UInt64(v) => {
+                    let approx = v as f64;
+                    (approx, (v as i128 - approx as i128) as i64)
+                }
+                Number::Float64(v) => (v, 0),
+            };
+            put_f64(buf, approx);
+            put_i64(buf, delta);
+        }
+        JsonbValue::String(s) => {
+            buf.push(VARIANT_STRING);
+            put_bytes(buf, s.as_bytes());
+        }
+        JsonbValue::Object(object) => {
+            buf.push(VARIANT_OBJECT);
+            for (key, value) in object.iter() {
+                buf.push(ITEM);
+                put_bytes(buf, key.as_bytes());
+                encode_jsonb_value(value, buf);
+            }
+            buf.push(END);
+        }
+        JsonbValue::Array(items) => {
+            buf.push(VARIANT_ARRAY);
+            for item in items {
+                buf.push(ITEM);
+                encode_jsonb_value(item, buf);
+            }
+            buf.push(END);
+        }
+    }
+}
+
+fn put_i64(buf: &mut Vec<u8>, v: i64) {
+    buf.extend_from_slice(&((v as u64) ^ (1 << 63)).to_be_bytes());
+}
+
+fn put_i128(buf: &mut Vec<u8>, v: i128) {
+    buf.extend_from_slice(&((v as u128) ^ (1 << 127)).to_be_bytes());
+}
+
+fn put_f64(buf: &mut Vec<u8>, v: f64) {
+    let v = if v.is_nan() {
+        f64::NAN
+    } else if v == 0.0 {
+        0.0
+    } else {
+        v
+    };
+    let bits = v.to_bits();
+    // Flip all the bits of the negative numbers, and only the sign bit of the positive ones.
+    let bits = if bits >> 63 == 1 {
+        !bits
+    } else {
+        bits | (1 << 63)
+    };
+    buf.extend_from_slice(&bits.to_be_bytes());
+}
+
+/// The bytes are terminated by `0x00 0x00`, and the `0x00` in them is escaped as `0x00 0xFF`,
+/// so a prefix sorts first.
+fn put_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+    for &b in bytes {
+        buf.push(b);
+        if b == 0 {
+            buf.push(0xFF);
+        }
+    }
+    buf.extend_from_slice(&[0x00, 0x00]);
+}
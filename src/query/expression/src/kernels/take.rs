@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use itertools::Itertools;
 
@@ -21,15 +22,15 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
+use crate::types::string::StringColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
 use crate::types::BooleanType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberType;
-use crate::types::StringType;
 use crate::types::ValueType;
-use crate::types::VariantType;
 use crate::with_decimal_type;
 use crate::with_number_mapped_type;
 use crate::BlockEntry;
@@ -39,6 +40,8 @@ use crate::DataBlock;
 use crate::Value;
 
 impl DataBlock {
+    /// Gather rows by `indices`. Indices are not bounds checked, callers must
+    /// guarantee every index is less than `self.num_rows()`.
     pub fn take<I>(&self, indices: &[I]) -> Result<Self>
     where I: common_arrow::arrow::types::Index {
         if indices.is_empty() {
@@ -62,9 +65,29 @@ impl DataBlock {
 
         Ok(DataBlock::new(after_columns, indices.len()))
     }
+
+    /// Same as [`DataBlock::take`], but returns an error if any index is out of bounds.
+    pub fn take_checked<I>(&self, indices: &[I]) -> Result<Self>
+    where I: common_arrow::arrow::types::Index {
+        check_take_indices(indices, self.num_rows())?;
+        self.take(indices)
+    }
+}
+
+fn check_take_indices<I>(indices: &[I], len: usize) -> Result<()>
+where I: common_arrow::arrow::types::Index {
+    match indices.iter().find(|index| index.to_usize() >= len) {
+        Some(index) => Err(ErrorCode::BadArguments(format!(
+            "take index out of bounds: the len is {len} but the index is {}",
+            index.to_usize()
+        ))),
+        None => Ok(()),
+    }
 }
 
 impl Column {
+    /// Gather values by `indices`. Indices are not bounds checked, callers must
+    /// guarantee every index is less than `self.len()`.
     pub fn take<I>(&self, indices: &[I]) -> Self
     where I: common_arrow::arrow::types::Index {
         let length = indices.len();
@@ -86,7 +109,7 @@ impl Column {
                 }
             }),
             Column::Boolean(bm) => Self::take_arg_types::<BooleanType, _>(bm, indices),
-            Column::String(column) => Self::take_arg_types::<StringType, _>(column, indices),
+            Column::String(column) => Column::String(Self::take_string_types(column, indices)),
             Column::Timestamp(column) => {
                 let ts = Self::take_arg_types::<NumberType<i64>, _>(column, indices)
                     .into_number()
@@ -103,6 +126,7 @@ impl Column {
                     .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => Self::take_arg_types::<IntervalType, _>(column, indices),
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -140,10 +164,19 @@ impl Column {
                 let fields = fields.iter().map(|c| c.take(indices)).collect();
                 Column::Tuple(fields)
             }
-            Column::Variant(column) => Self::take_arg_types::<VariantType, _>(column, indices),
+            Column::Variant(column) => Column::Variant(Self::take_string_types(column, indices)),
+            Column::Bitmap(column) => Column::Bitmap(Self::take_string_types(column, indices)),
+            Column::Binary(column) => Column::Binary(Self::take_string_types(column, indices)),
         }
     }
 
+    /// Same as [`Column::take`], but returns an error if any index is out of bounds.
+    pub fn take_checked<I>(&self, indices: &[I]) -> Result<Self>
+    where I: common_arrow::arrow::types::Index {
+        check_take_indices(indices, self.len())?;
+        Ok(self.take(indices))
+    }
+
     fn take_arg_types<T: ArgType, I>(col: &T::Column, indices: &[I]) -> Column
     where I: common_arrow::arrow::types::Index {
         let col = T::column_from_ref_iter(
@@ -155,6 +188,33 @@ impl Column {
         T::upcast_column(col)
     }
 
+    /// Gather string-like values, computing the total data length up front so that
+    /// the output buffer is allocated only once.
+    fn take_string_types<I>(col: &StringColumn, indices: &[I]) -> StringColumn
+    where I: common_arrow::arrow::types::Index {
+        let data_len = indices
+            .iter()
+            .map(|index| {
+                let index = index.to_usize();
+                (col.offsets[index + 1] - col.offsets[index]) as usize
+            })
+            .sum();
+
+        let mut data = Vec::with_capacity(data_len);
+        let mut offsets = Vec::with_capacity(indices.len() + 1);
+        offsets.push(0u64);
+        for index in indices {
+            let value = unsafe { col.index_unchecked(index.to_usize()) };
+            data.extend_from_slice(value);
+            offsets.push(data.len() as u64);
+        }
+
+        StringColumn {
+            data: data.into(),
+            offsets: offsets.into(),
+        }
+    }
+
     fn take_value_types<T: ValueType, I>(
         col: &T::Column,
         mut builder: T::ColumnBuilder,
@@ -42,6 +42,9 @@ use crate::Scalar;
 #[derive(Debug, Clone, Copy)]
 pub struct FunctionProperty {
     pub non_deterministic: bool,
+    /// The function produces a different value for each row, so it can't be folded
+    /// into a constant even within a single statement, e.g. `uuid()`.
+    pub per_row: bool,
     pub kind: FunctionKind,
 }
 
@@ -51,6 +54,11 @@ impl FunctionProperty {
         self
     }
 
+    pub fn per_row(mut self) -> Self {
+        self.per_row = true;
+        self
+    }
+
     pub fn kind(mut self, kind: FunctionKind) -> Self {
         self.kind = kind;
         self
@@ -61,11 +69,19 @@ impl Default for FunctionProperty {
     fn default() -> Self {
         FunctionProperty {
             non_deterministic: false,
+            per_row: false,
             kind: FunctionKind::Scalar,
         }
     }
 }
 
+/// User-facing documentation of a function, shared by all of its overloads.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FunctionMeta {
+    pub description: &'static str,
+    pub example: &'static str,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FunctionKind {
     Scalar,
@@ -201,7 +217,9 @@ impl Domain {
                 };
                 Domain::Map(Some(inner_domain))
             }
-            DataType::Variant => Domain::Undefined,
+            DataType::Interval | DataType::Variant | DataType::Bitmap | DataType::Binary => {
+                Domain::Undefined
+            }
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -34,8 +34,11 @@ use crate::types::DataType;
 use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::Scalar;
+use crate::ARROW_EXT_TYPE_BINARY;
+use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_INTERVAL;
 use crate::ARROW_EXT_TYPE_VARIANT;
 
 // Column id of TableField
@@ -76,6 +79,10 @@ pub struct TableField {
     data_type: TableDataType,
     #[serde(default = "uninit_column_id")]
     column_id: ColumnId,
+    /// The column ids and types the column was stored with before its type was changed
+    /// in place, the latest first. Blocks written before the change are cast on read.
+    #[serde(default)]
+    origins: Vec<(ColumnId, TableDataType)>,
 }
 
 /// DataType with more information that is only available for table field, e.g, the
@@ -91,6 +98,7 @@ pub enum TableDataType {
     Decimal(DecimalDataType),
     Timestamp,
     Date,
+    Interval,
     Nullable(Box<TableDataType>),
     Array(Box<TableDataType>),
     Map(Box<TableDataType>),
@@ -99,6 +107,8 @@ pub enum TableDataType {
         fields_type: Vec<TableDataType>,
     },
     Variant,
+    Bitmap,
+    Binary,
 }
 
 impl DataSchema {
@@ -319,7 +329,9 @@ impl TableSchema {
 
     pub fn is_column_deleted(&self, column_id: ColumnId) -> bool {
         for field in &self.fields {
-            if field.contain_column_id(column_id) {
+            if field.contain_column_id(column_id)
+                || field.origins.iter().any(|(id, _)| *id == column_id)
+            {
                 return false;
             }
         }
@@ -327,6 +339,15 @@ impl TableSchema {
         true
     }
 
+    // The column ids and types a column was stored with before its type was changed.
+    pub fn origins_of_column(&self, column_id: ColumnId) -> &[(ColumnId, TableDataType)] {
+        self.fields
+            .iter()
+            .find(|f| f.column_id == column_id)
+            .map(|f| f.origins())
+            .unwrap_or(&[])
+    }
+
     pub fn add_columns(&mut self, fields: &[TableField]) -> Result<()> {
         for f in fields {
             if self.index_of(f.name()).is_ok() {
@@ -364,6 +385,72 @@ impl TableSchema {
         Ok(())
     }
 
+    /// Add a column at the given position, `index` equal to the number of fields appends it.
+    pub fn add_column(&mut self, field: &TableField, index: usize) -> Result<()> {
+        if self.index_of(field.name()).is_ok() {
+            return Err(ErrorCode::AddColumnExistError(format!(
+                "add column {} already exist",
+                field.name(),
+            )));
+        }
+        if index > self.fields.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "add column {} at position {} out of range",
+                field.name(),
+                index
+            )));
+        }
+        let field = field.build_column_id(&mut self.next_column_id);
+        self.fields.insert(index, field);
+        Ok(())
+    }
+
+    // Rename keeps the column id, so the existing data of the column is still readable.
+    pub fn rename_column(&mut self, column: &str, new_column: &str) -> Result<()> {
+        let i = self.index_of(column)?;
+        if column != new_column && self.index_of(new_column).is_ok() {
+            return Err(ErrorCode::AddColumnExistError(format!(
+                "rename column {} to {} already exist",
+                column, new_column,
+            )));
+        }
+        let field = &mut self.fields[i];
+        field.name = new_column.to_string();
+        Ok(())
+    }
+
+    // Change the type of a column, the column gets new column ids,
+    // so the existing data of the column is not readable after modified.
+    pub fn modify_column(&mut self, column: &str, data_type: TableDataType) -> Result<()> {
+        let i = self.index_of(column)?;
+        let old_field = &self.fields[i];
+        let field = TableField::new(old_field.name(), data_type)
+            .with_default_expr(old_field.default_expr().cloned())
+            .build_column_id(&mut self.next_column_id);
+        self.fields[i] = field;
+        Ok(())
+    }
+
+    /// Changes the type of a column without rewriting the existing data, the column gets a
+    /// new column id and remembers the old one, so that old blocks are cast on read.
+    pub fn modify_column_with_origin(
+        &mut self,
+        column: &str,
+        data_type: TableDataType,
+    ) -> Result<()> {
+        let i = self.index_of(column)?;
+        let old_field = &self.fields[i];
+        let mut origins = Vec::with_capacity(old_field.origins.len() + 1);
+        origins.push((old_field.column_id, old_field.data_type.clone()));
+        origins.extend(old_field.origins.iter().cloned());
+        let field = TableField::new(old_field.name(), data_type)
+            .with_default_expr(old_field.default_expr().cloned())
+            .with_origins(origins)
+            .build_column_id(&mut self.next_column_id);
+        self.fields[i] = field;
+        Ok(())
+    }
+
     pub fn to_leaf_column_id_set(&self) -> HashSet<ColumnId> {
         HashSet::from_iter(self.to_leaf_column_ids().iter().cloned())
     }
@@ -826,6 +913,7 @@ impl TableField {
             default_expr: None,
             data_type,
             column_id: 0,
+            origins: vec![],
         }
     }
 
@@ -835,6 +923,7 @@ impl TableField {
             default_expr: None,
             data_type,
             column_id,
+            origins: vec![],
         }
     }
 
@@ -880,6 +969,7 @@ impl TableField {
             default_expr: self.default_expr.clone(),
             data_type: self.data_type.clone(),
             column_id,
+            origins: self.origins.clone(),
         }
     }
 
@@ -930,6 +1020,16 @@ impl TableField {
         self.default_expr.as_ref()
     }
 
+    #[must_use]
+    pub fn with_origins(mut self, origins: Vec<(ColumnId, TableDataType)>) -> Self {
+        self.origins = origins;
+        self
+    }
+
+    pub fn origins(&self) -> &[(ColumnId, TableDataType)] {
+        &self.origins
+    }
+
     #[inline]
     pub fn is_nullable(&self) -> bool {
         self.data_type.is_nullable()
@@ -953,6 +1053,7 @@ impl From<&TableDataType> for DataType {
             TableDataType::Decimal(ty) => DataType::Decimal(*ty),
             TableDataType::Timestamp => DataType::Timestamp,
             TableDataType::Date => DataType::Date,
+            TableDataType::Interval => DataType::Interval,
             TableDataType::Nullable(ty) => DataType::Nullable(Box::new((&**ty).into())),
             TableDataType::Array(ty) => DataType::Array(Box::new((&**ty).into())),
             TableDataType::Map(ty) => DataType::Map(Box::new((&**ty).into())),
@@ -960,6 +1061,8 @@ impl From<&TableDataType> for DataType {
                 DataType::Tuple(fields_type.iter().map(Into::into).collect())
             }
             TableDataType::Variant => DataType::Variant,
+            TableDataType::Bitmap => DataType::Bitmap,
+            TableDataType::Binary => DataType::Binary,
         }
     }
 }
@@ -1150,6 +1253,7 @@ impl From<&ArrowField> for TableField {
             data_type: f.into(),
             default_expr: None,
             column_id: 0,
+            origins: vec![],
         }
     }
 }
@@ -1189,10 +1293,9 @@ impl From<&ArrowField> for TableDataType {
             | ArrowDataType::FixedSizeList(f, _) =>
                 TableDataType::Array(Box::new(f.as_ref().into())),
 
-            ArrowDataType::Binary
-            | ArrowDataType::LargeBinary
-            | ArrowDataType::Utf8
-            | ArrowDataType::LargeUtf8 => TableDataType::String,
+            ArrowDataType::Binary => TableDataType::Binary,
+            ArrowDataType::LargeBinary | ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 =>
+                TableDataType::String,
 
             ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
@@ -1210,6 +1313,9 @@ impl From<&ArrowField> for TableDataType {
             }
             ArrowDataType::Extension(custom_name, _, _) => match custom_name.as_str() {
                 ARROW_EXT_TYPE_VARIANT => TableDataType::Variant,
+                ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
+                ARROW_EXT_TYPE_BINARY => TableDataType::Binary,
+                ARROW_EXT_TYPE_INTERVAL => TableDataType::Interval,
                 ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
                 ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
                 _ => unimplemented!("data_type: {:?}", f.data_type()),
@@ -1298,6 +1404,11 @@ impl From<&DataType> for ArrowDataType {
             }
             DataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             DataType::Date => ArrowDataType::Date32,
+            DataType::Interval => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_INTERVAL.to_string(),
+                Box::new(ArrowDataType::Decimal(38, 0)),
+                None,
+            ),
             DataType::Nullable(ty) => ty.as_ref().into(),
             DataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -1340,6 +1451,16 @@ impl From<&DataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            DataType::Bitmap => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BITMAP.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            DataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
 
             _ => unreachable!(),
         }
@@ -1373,6 +1494,11 @@ impl From<&TableDataType> for ArrowDataType {
             }
             TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             TableDataType::Date => ArrowDataType::Date32,
+            TableDataType::Interval => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_INTERVAL.to_string(),
+                Box::new(ArrowDataType::Decimal(38, 0)),
+                None,
+            ),
             TableDataType::Nullable(ty) => ty.as_ref().into(),
             TableDataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -1419,6 +1545,16 @@ impl From<&TableDataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            TableDataType::Bitmap => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BITMAP.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            TableDataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
         }
     }
 }
@@ -1441,6 +1577,7 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Timestamp => Ok(TableDataType::Timestamp),
         DataType::Decimal(x) => Ok(TableDataType::Decimal(*x)),
         DataType::Date => Ok(TableDataType::Date),
+        DataType::Interval => Ok(TableDataType::Interval),
         DataType::Nullable(inner_type) => Ok(TableDataType::Nullable(Box::new(infer_schema_type(
             inner_type,
         )?))),
@@ -1451,6 +1588,8 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
             Ok(TableDataType::Map(Box::new(infer_schema_type(inner_type)?)))
         }
         DataType::Variant => Ok(TableDataType::Variant),
+        DataType::Bitmap => Ok(TableDataType::Bitmap),
+        DataType::Binary => Ok(TableDataType::Binary),
         DataType::Tuple(fields) => {
             let fields_type = fields
                 .iter()
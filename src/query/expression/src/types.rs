@@ -14,12 +14,15 @@
 
 pub mod any;
 pub mod array;
+pub mod binary;
+pub mod bitmap;
 pub mod boolean;
 pub mod date;
 pub mod decimal;
 pub mod empty_array;
 pub mod empty_map;
 pub mod generic;
+pub mod interval;
 pub mod map;
 pub mod null;
 pub mod nullable;
@@ -39,12 +42,15 @@ use serde::Serialize;
 
 pub use self::any::AnyType;
 pub use self::array::ArrayType;
+pub use self::binary::BinaryType;
+pub use self::bitmap::BitmapType;
 pub use self::boolean::BooleanType;
 pub use self::date::DateType;
 pub use self::decimal::DecimalDataType;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
 pub use self::generic::GenericType;
+pub use self::interval::IntervalType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableType;
@@ -72,11 +78,14 @@ pub enum DataType {
     Decimal(DecimalDataType),
     Timestamp,
     Date,
+    Interval,
     Nullable(Box<DataType>),
     Array(Box<DataType>),
     Map(Box<DataType>),
     Tuple(Vec<DataType>),
     Variant,
+    Bitmap,
+    Binary,
     Generic(usize),
 }
 
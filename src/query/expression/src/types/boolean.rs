@@ -22,6 +22,7 @@ use crate::types::ArgType;
 use crate::types::DataType;
 use crate::types::GenericMap;
 use crate::types::ValueType;
+use crate::utils::arrow::append_bitmap;
 use crate::utils::arrow::bitmap_into_mut;
 use crate::values::Column;
 use crate::values::Scalar;
@@ -131,7 +132,7 @@ impl ValueType for BooleanType {
     }
 
     fn append_column(builder: &mut Self::ColumnBuilder, bitmap: &Self::Column) {
-        builder.extend_from_bitmap(bitmap)
+        append_bitmap(builder, bitmap)
     }
 
     fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
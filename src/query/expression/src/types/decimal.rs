@@ -349,13 +349,11 @@ impl Decimal for i128 {
     }
 
     fn min_for_precision(to_precision: u8) -> Self {
-        9_i128
-            .saturating_pow(1 + to_precision as u32)
-            .saturating_neg()
+        -Self::max_for_precision(to_precision)
     }
 
     fn max_for_precision(to_precision: u8) -> Self {
-        9_i128.saturating_pow(1 + to_precision as u32)
+        10_i128.saturating_pow(to_precision as u32) - 1
     }
 
     fn default_decimal_size() -> DecimalSize {
@@ -494,13 +492,11 @@ impl Decimal for i256 {
     }
 
     fn min_for_precision(to_precision: u8) -> Self {
-        (i256::ONE * 9)
-            .saturating_pow(1 + to_precision as u32)
-            .saturating_neg()
+        -Self::max_for_precision(to_precision)
     }
 
     fn max_for_precision(to_precision: u8) -> Self {
-        (i256::ONE * 9).saturating_pow(1 + to_precision as u32)
+        (i256::ONE * 10).saturating_pow(to_precision as u32) - i256::ONE
     }
 
     fn default_decimal_size() -> DecimalSize {
@@ -667,6 +663,17 @@ impl DecimalDataType {
         other.max_precision()
     }
 
+    /// The result type of the arithmetic of two decimals, `is_plus_minus` is also used by the
+    /// comparisons to get the common type of both sides:
+    ///
+    /// - `+`, `-`: `Decimal(max(p1 - s1, p2 - s2) + max(s1, s2) + 1, max(s1, s2))`
+    /// - `*`: `Decimal(p1 + p2, s1 + s2)`
+    /// - `/`: the scale of the quotient is `max(s1, s2, min(s1 + 6, 12))`, and the precision
+    ///   is widened by the digits the divisor may add to the integral part.
+    ///
+    /// The precision is limited to the max precision of the operands, the values exceeding it are
+    /// reported as overflow when evaluated. The result is an overflow error if the scale can't be
+    /// represented.
     pub fn binary_result_type(
         a: &Self,
         b: &Self,
@@ -674,25 +681,30 @@ impl DecimalDataType {
         is_divide: bool,
         is_plus_minus: bool,
     ) -> Result<Self> {
-        let mut scale = a.scale().max(b.scale());
-        let mut precision = a.max_result_precision(b);
-
-        let multiply_precision = a.precision() + b.precision();
-        let divide_precision = a.precision() + b.scale();
-
-        if is_multiply {
-            scale = a.scale() + b.scale();
-            precision = precision.min(multiply_precision);
+        let max_precision = a.max_result_precision(b);
+        let (precision, scale) = if is_multiply {
+            (a.precision() + b.precision(), a.scale() + b.scale())
         } else if is_divide {
-            scale = a.scale();
-            precision = precision.min(divide_precision);
-        } else if is_plus_minus {
-            scale = std::cmp::max(a.scale(), b.scale());
-            // for addition/subtraction, we add 1 to the width to ensure we don't overflow
-            let plus_min_precision = a.leading_digits().max(b.leading_digits()) + scale + 1;
-            precision = precision.min(plus_min_precision);
+            let scale = a.scale().max(b.scale()).max((a.scale() + 6).min(12));
+            (a.leading_digits() + b.scale() + scale, scale)
+        } else {
+            debug_assert!(is_plus_minus);
+            let scale = a.scale().max(b.scale());
+            (
+                a.leading_digits().max(b.leading_digits()) + scale + 1,
+                scale,
+            )
+        };
+
+        if scale > max_precision {
+            return Err(ErrorCode::Overflow(format!(
+                "Decimal overflow: the result of {a} and {b} needs a scale of {scale}, which exceeds the max precision {max_precision}, use CAST to Float64 on the operands instead"
+            )));
         }
-        Self::from_size(DecimalSize { precision, scale })
+        Self::from_size(DecimalSize {
+            precision: precision.min(max_precision),
+            scale,
+        })
     }
 
     // Decimal X Number or Nunmber X Decimal
@@ -823,6 +835,12 @@ impl DecimalColumnBuilder {
         })
     }
 
+    pub fn reserve(&mut self, additional: usize) {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumnBuilder::DECIMAL_TYPE(builder, _) => builder.reserve(additional),
+        })
+    }
+
     pub fn append_column(&mut self, other: &DecimalColumn) {
         crate::with_decimal_type!(|DECIMAL_TYPE| match (self, other) {
             (
@@ -0,0 +1,461 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use common_arrow::arrow::buffer::Buffer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::property::Domain;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_IN_A_MINUTE: i64 = 60 * MICROS_IN_A_SEC;
+pub const MICROS_IN_AN_HOUR: i64 = 60 * MICROS_IN_A_MINUTE;
+pub const MICROS_IN_A_DAY: i64 = 24 * MICROS_IN_AN_HOUR;
+/// The number of days in a month when comparing intervals, same as PostgreSQL.
+pub const DAYS_IN_A_MONTH: i64 = 30;
+
+/// An interval of time, kept as three independent parts because the length
+/// of a month and a day is only known once the interval is applied to a
+/// date or a timestamp.
+///
+/// Two intervals are equal only if all of their parts are equal, so that
+/// `'1 month'` and `'30 days'` are distinct values (and distinct group keys).
+/// Ordering compares the total length first, taking a month as 30 days,
+/// and falls back to the parts to stay consistent with equality.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MonthsDaysMicros {
+    pub months: i32,
+    pub days: i32,
+    pub micros: i64,
+}
+
+impl MonthsDaysMicros {
+    pub fn new(months: i32, days: i32, micros: i64) -> Self {
+        MonthsDaysMicros {
+            months,
+            days,
+            micros,
+        }
+    }
+
+    /// The total length of the interval in microseconds, taking a month as 30 days.
+    pub fn total_micros(&self) -> i128 {
+        (self.months as i128 * DAYS_IN_A_MONTH as i128 + self.days as i128)
+            * MICROS_IN_A_DAY as i128
+            + self.micros as i128
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(MonthsDaysMicros {
+            months: self.months.checked_add(other.months)?,
+            days: self.days.checked_add(other.days)?,
+            micros: self.micros.checked_add(other.micros)?,
+        })
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(MonthsDaysMicros {
+            months: self.months.checked_sub(other.months)?,
+            days: self.days.checked_sub(other.days)?,
+            micros: self.micros.checked_sub(other.micros)?,
+        })
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(MonthsDaysMicros {
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            micros: self.micros.checked_neg()?,
+        })
+    }
+
+    /// Pack the interval into an `i128` for arrow storage,
+    /// laid out as `months (32 bits) | days (32 bits) | micros (64 bits)`.
+    pub fn to_i128(&self) -> i128 {
+        (((self.months as u32 as u128) << 96)
+            | ((self.days as u32 as u128) << 64)
+            | (self.micros as u64 as u128)) as i128
+    }
+
+    pub fn from_i128(value: i128) -> Self {
+        let value = value as u128;
+        MonthsDaysMicros {
+            months: (value >> 96) as u32 as i32,
+            days: (value >> 64) as u32 as i32,
+            micros: value as u64 as i64,
+        }
+    }
+}
+
+impl PartialOrd for MonthsDaysMicros {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MonthsDaysMicros {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_micros()
+            .cmp(&other.total_micros())
+            .then_with(|| {
+                (self.months, self.days, self.micros).cmp(&(other.months, other.days, other.micros))
+            })
+    }
+}
+
+impl Display for MonthsDaysMicros {
+    /// Format the interval in the style of PostgreSQL, e.g. `1 year 2 mons 3 days 04:05:06.7`.
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let mut parts = vec![];
+        let years = self.months / 12;
+        let months = self.months % 12;
+        if years != 0 {
+            parts.push(format!("{} {}", years, plural(years as i64, "year")));
+        }
+        if months != 0 {
+            parts.push(format!("{} {}", months, plural(months as i64, "mon")));
+        }
+        if self.days != 0 {
+            parts.push(format!("{} {}", self.days, plural(self.days as i64, "day")));
+        }
+        if self.micros != 0 || parts.is_empty() {
+            let sign = if self.micros < 0 { "-" } else { "" };
+            let micros = self.micros.unsigned_abs();
+            let hours = micros / MICROS_IN_AN_HOUR as u64;
+            let minutes = micros % MICROS_IN_AN_HOUR as u64 / MICROS_IN_A_MINUTE as u64;
+            let seconds = micros % MICROS_IN_A_MINUTE as u64 / MICROS_IN_A_SEC as u64;
+            let fraction = micros % MICROS_IN_A_SEC as u64;
+            let mut time = format!("{sign}{hours:02}:{minutes:02}:{seconds:02}");
+            if fraction != 0 {
+                let fraction = format!("{fraction:06}");
+                time.push('.');
+                time.push_str(fraction.trim_end_matches('0'));
+            }
+            parts.push(time);
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+fn plural(n: i64, unit: &str) -> String {
+    if n == 1 {
+        unit.to_string()
+    } else {
+        format!("{unit}s")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = MonthsDaysMicros;
+    type ScalarRef<'a> = MonthsDaysMicros;
+    type Column = Buffer<MonthsDaysMicros>;
+    type Domain = ();
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, MonthsDaysMicros>>;
+    type ColumnBuilder = Vec<MonthsDaysMicros>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: MonthsDaysMicros) -> MonthsDaysMicros {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Parse an interval from strings like `1 year 2 months`, `-3 days 04:05:06.5` or `90 minutes`.
+pub fn string_to_interval(buf: &[u8]) -> Result<MonthsDaysMicros, String> {
+    let s = std::str::from_utf8(buf).map_err(|_| "invalid utf8 interval".to_string())?;
+    let invalid = || format!("invalid interval: '{s}'");
+    let out_of_range = || format!("interval is out of range: '{s}'");
+
+    let mut result = MonthsDaysMicros::default();
+    let mut tokens = s.split_whitespace();
+    let mut is_empty = true;
+    while let Some(token) = tokens.next() {
+        is_empty = false;
+        if token.contains(':') {
+            let micros = parse_time(token).ok_or_else(invalid)?;
+            result.micros = result.micros.checked_add(micros).ok_or_else(out_of_range)?;
+            continue;
+        }
+
+        let unit_start = token
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '+' | '-' | '.')))
+            .unwrap_or(token.len());
+        let (number, unit) = token.split_at(unit_start);
+        let unit = if unit.is_empty() {
+            tokens.next().ok_or_else(invalid)?
+        } else {
+            unit
+        };
+        let (whole, fraction) = parse_number(number).ok_or_else(invalid)?;
+
+        let unit = unit.to_ascii_lowercase();
+        // Only seconds can have a fractional part.
+        let is_second = matches!(unit.as_str(), "s" | "sec" | "secs" | "second" | "seconds");
+        if fraction != 0 && !is_second {
+            return Err(invalid());
+        }
+        let (months, days, micros) = (result.months, result.days, result.micros);
+        let updated = match unit.as_str() {
+            "y" | "yr" | "yrs" | "year" | "years" => {
+                add_scaled(months, whole, 12).map(|months| result.months = months)
+            }
+            "quarter" | "quarters" => {
+                add_scaled(months, whole, 3).map(|months| result.months = months)
+            }
+            "mon" | "mons" | "month" | "months" => {
+                add_scaled(months, whole, 1).map(|months| result.months = months)
+            }
+            "w" | "week" | "weeks" => add_scaled(days, whole, 7).map(|days| result.days = days),
+            "d" | "day" | "days" => add_scaled(days, whole, 1).map(|days| result.days = days),
+            "h" | "hr" | "hrs" | "hour" | "hours" => {
+                add_scaled(micros, whole, MICROS_IN_AN_HOUR).map(|micros| result.micros = micros)
+            }
+            "m" | "min" | "mins" | "minute" | "minutes" => {
+                add_scaled(micros, whole, MICROS_IN_A_MINUTE).map(|micros| result.micros = micros)
+            }
+            "s" | "sec" | "secs" | "second" | "seconds" => {
+                add_scaled(micros, whole, MICROS_IN_A_SEC)
+                    .and_then(|micros| micros.checked_add(fraction))
+                    .map(|micros| result.micros = micros)
+            }
+            "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => {
+                add_scaled(micros, whole, 1_000).map(|micros| result.micros = micros)
+            }
+            "us" | "usec" | "usecs" | "microsecond" | "microseconds" => {
+                add_scaled(micros, whole, 1).map(|micros| result.micros = micros)
+            }
+            _ => return Err(invalid()),
+        };
+        updated.ok_or_else(out_of_range)?;
+    }
+
+    if is_empty {
+        return Err(invalid());
+    }
+    Ok(result)
+}
+
+fn add_scaled<T: TryFrom<i64> + num_traits::CheckedAdd>(
+    acc: T,
+    value: i64,
+    factor: i64,
+) -> Option<T> {
+    let value = T::try_from(value.checked_mul(factor)?).ok()?;
+    acc.checked_add(&value)
+}
+
+/// Parse a signed number with an optional fractional part of at most 6 digits,
+/// returns the whole part and the fractional part in microseconds.
+fn parse_number(s: &str) -> Option<(i64, i64)> {
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (whole, fraction) = match s.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (s, ""),
+    };
+    if whole.is_empty() || fraction.len() > 6 {
+        return None;
+    }
+    let whole = whole.parse::<i64>().ok()?;
+    let fraction = if fraction.is_empty() {
+        0
+    } else {
+        format!("{fraction:0<6}").parse::<i64>().ok()?
+    };
+    if negative {
+        Some((-whole, -fraction))
+    } else {
+        Some((whole, fraction))
+    }
+}
+
+/// Parse a time in the form of `[-]HH:MM[:SS[.ffffff]]` to microseconds.
+fn parse_time(s: &str) -> Option<i64> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    let mut parts = s.split(':');
+    let hours = parts.next()?.parse::<i64>().ok()?;
+    let minutes = parts.next()?.parse::<i64>().ok()?;
+    let (seconds, fraction) = match parts.next() {
+        Some(seconds) => parse_number(seconds)?,
+        None => (0, 0),
+    };
+    if parts.next().is_some()
+        || hours < 0
+        || !(0..60).contains(&minutes)
+        || !(0..60).contains(&seconds)
+        || fraction < 0
+    {
+        return None;
+    }
+    let micros = hours
+        .checked_mul(MICROS_IN_AN_HOUR)?
+        .checked_add(minutes * MICROS_IN_A_MINUTE + seconds * MICROS_IN_A_SEC + fraction)?;
+    if negative {
+        Some(-micros)
+    } else {
+        Some(micros)
+    }
+}
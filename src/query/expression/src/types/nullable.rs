@@ -25,6 +25,7 @@ use crate::types::ArgType;
 use crate::types::DataType;
 use crate::types::GenericMap;
 use crate::types::ValueType;
+use crate::utils::arrow::append_bitmap;
 use crate::utils::arrow::bitmap_into_mut;
 use crate::values::Column;
 use crate::values::Scalar;
@@ -319,7 +320,7 @@ impl<T: ValueType> NullableColumnBuilder<T> {
 
     pub fn append_column(&mut self, other: &NullableColumn<T>) {
         T::append_column(&mut self.builder, &other.column);
-        self.validity.extend_from_bitmap(&other.validity)
+        append_bitmap(&mut self.validity, &other.validity)
     }
 
     pub fn build(self) -> NullableColumn<T> {
@@ -513,6 +513,12 @@ impl NumberColumnBuilder {
         })
     }
 
+    pub fn reserve(&mut self, additional: usize) {
+        crate::with_number_type!(|NUM_TYPE| match self {
+            NumberColumnBuilder::NUM_TYPE(builder) => builder.reserve(additional),
+        })
+    }
+
     pub fn append_column(&mut self, other: &NumberColumn) {
         crate::with_number_type!(|NUM_TYPE| match (self, other) {
             (NumberColumnBuilder::NUM_TYPE(builder), NumberColumn::NUM_TYPE(other)) => {
@@ -397,6 +397,90 @@ impl StringColumnBuilder {
     }
 }
 
+/// Builds a string column whose values are sub-slices of the values of a source column, like the
+/// output of `trim`.
+///
+/// While the values are contiguous in the data buffer of the source column, only their offsets
+/// are recorded. If they also cover the same bytes as the source column, the built column shares
+/// the data buffer of the source column. Otherwise the values are copied.
+pub struct StringRangeBuilder<'a> {
+    source: &'a StringColumn,
+    offsets: Vec<u64>,
+    // The copied values, once a value doesn't start where the previous one ends.
+    copied: Option<StringColumnBuilder>,
+}
+
+impl<'a> StringRangeBuilder<'a> {
+    pub fn with_capacity(source: &'a StringColumn, len: usize) -> Self {
+        let mut offsets = Vec::with_capacity(len + 1);
+        offsets.push(source.offsets[0]);
+        StringRangeBuilder {
+            source,
+            offsets,
+            copied: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.copied {
+            Some(builder) => builder.len(),
+            None => self.offsets.len() - 1,
+        }
+    }
+
+    /// Pushes the part `range` of the value at `row` of the source column, `range` is relative
+    /// to the start of the value.
+    pub fn put_row_range(&mut self, row: usize, range: Range<usize>) {
+        let start = self.source.offsets[row] as usize;
+        debug_assert!(start + range.end <= self.source.offsets[row + 1] as usize);
+        self.put_range(start + range.start..start + range.end);
+    }
+
+    /// Pushes a value by its range in the data buffer of the source column.
+    pub fn put_range(&mut self, range: Range<usize>) {
+        let source = self.source;
+        if let Some(builder) = &mut self.copied {
+            builder.put_slice(&source.data[range]);
+            builder.commit_row();
+            return;
+        }
+
+        if range.start as u64 == *self.offsets.last().unwrap() {
+            self.offsets.push(range.end as u64);
+            return;
+        }
+
+        let mut builder = StringColumnBuilder::with_capacity(self.offsets.capacity() - 1, 0);
+        for value in self.offsets.windows(2) {
+            builder.put_slice(&source.data[(value[0] as usize)..(value[1] as usize)]);
+            builder.commit_row();
+        }
+        builder.put_slice(&source.data[range]);
+        builder.commit_row();
+        self.copied = Some(builder);
+    }
+
+    pub fn build(self) -> StringColumn {
+        if let Some(builder) = self.copied {
+            return builder.build();
+        }
+
+        if self.offsets.last() == self.source.offsets.last() {
+            return StringColumn {
+                data: self.source.data.clone(),
+                offsets: self.offsets.into(),
+            };
+        }
+
+        let mut builder = StringColumnBuilder::with_capacity(self.offsets.len() - 1, 0);
+        for value in self.offsets.windows(2) {
+            builder.put_slice(&self.source.data[(value[0] as usize)..(value[1] as usize)]);
+            builder.commit_row();
+        }
+        builder.build()
+    }
+}
+
 impl<'a> FromIterator<&'a [u8]> for StringColumnBuilder {
     fn from_iter<T: IntoIterator<Item = &'a [u8]>>(iter: T) -> Self {
         let iter = iter.into_iter();
@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::ops::Range;
 
+use super::bitmap::deserialize_bitmap;
 use super::date::date_to_string;
 use super::number::NumberScalar;
 use super::timestamp::timestamp_to_string;
 use crate::date_helper::TzLUT;
+use crate::kernels::encode_variant_sort_key;
 use crate::property::Domain;
 use crate::types::map::KvPair;
 use crate::types::string::StringColumn;
@@ -194,6 +197,7 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
         ScalarRef::String(s) => jsonb::Value::String(String::from_utf8_lossy(s)),
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
+        ScalarRef::Interval(i) => i.to_string().into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             jsonb::build_array(items.iter(), buf).expect("failed to build jsonb array");
@@ -238,6 +242,11 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
             buf.extend_from_slice(bytes);
             return;
         }
+        ScalarRef::Bitmap(bytes) => match deserialize_bitmap(bytes) {
+            Ok(bitmap) => jsonb::Value::Array(bitmap.iter().map(jsonb::Value::from).collect()),
+            Err(_) => jsonb::Value::Null,
+        },
+        ScalarRef::Binary(bytes) => jsonb::Value::String(hex::encode(bytes).into()),
     };
     value.write_to_vec(buf);
 }
@@ -254,3 +263,56 @@ pub fn cast_scalars_to_variants(
     }
     builder.build()
 }
+
+/// Compares two variant values in the total ordering of variants, see
+/// [`encode_variant_sort_key`] for the ordering.
+pub fn compare_variant(left: &[u8], right: &[u8]) -> Ordering {
+    let mut left_key = Vec::new();
+    let mut right_key = Vec::new();
+    encode_variant_sort_key(left, &mut left_key);
+    encode_variant_sort_key(right, &mut right_key);
+    left_key.cmp(&right_key)
+}
+
+/// Writes the canonical JSONB encoding of the variant value to `buf`, two variant values are
+/// equal if and only if their canonical encodings are the same bytes, so they can be hashed
+/// as group keys. The numbers are stored as integers if they are integral, e.g. `1.0` as `1`.
+pub fn canonical_variant(value: &[u8], buf: &mut Vec<u8>) {
+    match jsonb::from_slice(value) {
+        Ok(mut value) => {
+            canonicalize_jsonb_value(&mut value);
+            value.write_to_vec(buf);
+        }
+        Err(_) => buf.extend_from_slice(value),
+    }
+}
+
+fn canonicalize_jsonb_value(value: &mut jsonb::Value) {
+    match value {
+        jsonb::Value::Number(n) => *n = canonical_number(n),
+        jsonb::Value::Array(items) => items.iter_mut().for_each(canonicalize_jsonb_value),
+        jsonb::Value::Object(object) => object.values_mut().for_each(canonicalize_jsonb_value),
+        _ => {}
+    }
+}
+
+fn canonical_number(n: &jsonb::Number) -> jsonb::Number {
+    match *n {
+        jsonb::Number::Int64(v) => jsonb::Number::Int64(v),
+        jsonb::Number::UInt64(v) => match i64::try_from(v) {
+            Ok(v) => jsonb::Number::Int64(v),
+            Err(_) => jsonb::Number::UInt64(v),
+        },
+        jsonb::Number::Float64(v) if v.is_nan() => jsonb::Number::Float64(f64::NAN),
+        // `-0.0` is also converted to `0`.
+        jsonb::Number::Float64(v)
+            if v.fract() == 0.0 && v >= i64::MIN as f64 && v < i64::MAX as f64 =>
+        {
+            jsonb::Number::Int64(v as i64)
+        }
+        jsonb::Number::Float64(v) if v.fract() == 0.0 && v >= 0.0 && v < u64::MAX as f64 => {
+            jsonb::Number::UInt64(v as u64)
+        }
+        jsonb::Number::Float64(v) => jsonb::Number::Float64(v),
+    }
+}
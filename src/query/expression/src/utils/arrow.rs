@@ -50,7 +50,13 @@ pub fn repeat_bitmap(bitmap: &mut Bitmap, n: usize) -> MutableBitmap {
 }
 
 pub fn append_bitmap(bitmap: &mut MutableBitmap, other: &Bitmap) {
-    bitmap.extend_from_bitmap(other)
+    // Most of the validities are fully set, filling them by bytes is much cheaper
+    // than copying the bits when the bitmap is not byte aligned.
+    if other.unset_bits() == 0 {
+        bitmap.extend_constant(other.len(), true)
+    } else {
+        bitmap.extend_from_bitmap(other)
+    }
 }
 
 pub fn constant_bitmap(value: bool, len: usize) -> MutableBitmap {
@@ -56,7 +56,10 @@ macro_rules! for_common_scalar_values {
             { StringType },
             { DateType },
             { TimestampType },
-            { VariantType }
+            { IntervalType },
+            { VariantType },
+            { BitmapType },
+            { BinaryType }
         }
     };
 }
@@ -22,7 +22,6 @@ use chrono::NaiveTime;
 use chrono::Offset;
 use chrono::TimeZone;
 use chrono::Timelike;
-use chrono::Utc;
 use chrono_tz::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -30,7 +29,10 @@ use num_traits::AsPrimitive;
 use once_cell::sync::Lazy;
 
 use crate::types::date::check_date;
+use crate::types::interval::MonthsDaysMicros;
+use crate::types::interval::MICROS_IN_A_DAY;
 use crate::types::timestamp::check_timestamp;
+use crate::types::timestamp::MICROS_IN_A_MILLI;
 use crate::types::timestamp::MICROS_IN_A_SEC;
 
 #[derive(Debug, Clone, Copy)]
@@ -378,11 +380,89 @@ impl AddTimesImpl {
     ) -> Result<i64, String> {
         check_timestamp(us.wrapping_add(delta.as_() * factor * MICROS_IN_A_SEC))
     }
+
+    pub fn eval_timestamp_millis(us: i64, delta: impl AsPrimitive<i64>) -> Result<i64, String> {
+        check_timestamp(us.wrapping_add(delta.as_() * MICROS_IN_A_MILLI))
+    }
+}
+
+/// Counts the unit boundaries crossed from `start` to `end`, negative if `end` is earlier.
+/// Years, quarters, months and days are counted on the calendar of the timezone, hours,
+/// minutes and seconds on the elapsed time, so they are not shifted by DST transitions.
+pub struct DiffImpl;
+
+impl DiffImpl {
+    /// `months` is the number of months in the unit, e.g. 12 for years.
+    pub fn eval_date_months(start: i32, end: i32, tz: TzLUT, months: i64) -> i64 {
+        let start = start.to_date(tz.tz);
+        let end = end.to_date(tz.tz);
+        month_index(end).div_euclid(months) - month_index(start).div_euclid(months)
+    }
+
+    pub fn eval_timestamp_months(start: i64, end: i64, tz: TzLUT, months: i64) -> i64 {
+        let start = start.to_timestamp(tz.tz).date_naive();
+        let end = end.to_timestamp(tz.tz).date_naive();
+        month_index(end).div_euclid(months) - month_index(start).div_euclid(months)
+    }
+
+    pub fn eval_timestamp_days(start: i64, end: i64, tz: TzLUT) -> i64 {
+        let start = start.to_timestamp(tz.tz).date_naive();
+        let end = end.to_timestamp(tz.tz).date_naive();
+        end.signed_duration_since(start).num_days()
+    }
+
+    pub fn eval_timestamp_times(start: i64, end: i64, factor: i64) -> i64 {
+        let unit = factor * MICROS_IN_A_SEC;
+        end.div_euclid(unit) - start.div_euclid(unit)
+    }
+
+    /// Milliseconds are not truncated, the result keeps the microseconds as a fraction.
+    pub fn eval_timestamp_millis(start: i64, end: i64) -> f64 {
+        (end - start) as f64 / MICROS_IN_A_MILLI as f64
+    }
+}
+
+fn month_index(date: NaiveDate) -> i64 {
+    date.year() as i64 * 12 + date.month0() as i64
+}
+
+pub struct AddIntervalImpl;
+
+impl AddIntervalImpl {
+    /// Months are applied first with the same clamping as `add_months`, then days, then micros.
+    /// A date is treated as midnight, so the result is always a timestamp.
+    pub fn eval_date(date: i32, tz: TzLUT, interval: MonthsDaysMicros) -> Result<i64, String> {
+        let date = if interval.months != 0 {
+            AddMonthsImpl::eval_date(date, tz, interval.months)?
+        } else {
+            date
+        };
+        let date = AddDaysImpl::eval_date(date, interval.days)?;
+        (date as i64 * MICROS_IN_A_DAY)
+            .checked_add(interval.micros)
+            .ok_or_else(|| "timestamp is out of range".to_string())
+            .and_then(check_timestamp)
+    }
+
+    pub fn eval_timestamp(us: i64, tz: TzLUT, interval: MonthsDaysMicros) -> Result<i64, String> {
+        let us = if interval.months != 0 {
+            AddMonthsImpl::eval_timestamp(us, tz, interval.months)?
+        } else {
+            us
+        };
+        (interval.days as i64)
+            .checked_mul(MICROS_IN_A_DAY)
+            .and_then(|days| us.checked_add(days))
+            .and_then(|us| us.checked_add(interval.micros))
+            .ok_or_else(|| "timestamp is out of range".to_string())
+            .and_then(check_timestamp)
+    }
 }
 
+/// The date of `now` in microseconds, in UTC.
 #[inline]
-pub fn today_date() -> i32 {
-    let now = Utc::now();
+pub fn today_date(now: i64) -> i32 {
+    let now = now.to_timestamp(Tz::UTC);
     NaiveDate::from_ymd_opt(now.year(), now.month(), now.day())
         .unwrap()
         .signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap())
@@ -33,6 +33,7 @@ use crate::function::Function;
 use crate::function::FunctionSignature;
 use crate::property::Domain;
 use crate::property::FunctionProperty;
+use crate::types::bitmap::deserialize_bitmap;
 use crate::types::boolean::BooleanDomain;
 use crate::types::date::date_to_string;
 use crate::types::decimal::DecimalColumn;
@@ -124,6 +125,7 @@ impl<'a> Debug for ScalarRef<'a> {
             },
             ScalarRef::Timestamp(t) => write!(f, "{t:?}"),
             ScalarRef::Date(d) => write!(f, "{d:?}"),
+            ScalarRef::Interval(i) => write!(f, "INTERVAL '{i}'"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -151,7 +153,9 @@ impl<'a> Debug for ScalarRef<'a> {
                 }
                 write!(f, ")")
             }
-            ScalarRef::Variant(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Variant(s) | ScalarRef::Bitmap(s) | ScalarRef::Binary(s) => {
+                write!(f, "0x{}", &hex::encode(s))
+            }
         }
     }
 }
@@ -168,11 +172,17 @@ impl Debug for Column {
             Column::String(col) => write!(f, "{col:?}"),
             Column::Timestamp(col) => write!(f, "{col:?}"),
             Column::Date(col) => write!(f, "{col:?}"),
+            Column::Interval(col) => {
+                let values = col.iter().map(|i| i.to_string()).collect::<Vec<_>>();
+                f.debug_tuple("Interval").field(&values).finish()
+            }
             Column::Array(col) => write!(f, "{col:?}"),
             Column::Map(col) => write!(f, "{col:?}"),
             Column::Nullable(col) => write!(f, "{col:?}"),
             Column::Tuple(fields) => f.debug_tuple("Tuple").field(fields).finish(),
             Column::Variant(col) => write!(f, "{col:?}"),
+            Column::Bitmap(col) => write!(f, "{col:?}"),
+            Column::Binary(col) => write!(f, "{col:?}"),
         }
     }
 }
@@ -198,6 +208,7 @@ impl<'a> Display for ScalarRef<'a> {
             },
             ScalarRef::Timestamp(t) => write!(f, "{}", timestamp_to_string(*t, Tz::UTC)),
             ScalarRef::Date(d) => write!(f, "{}", date_to_string(*d as i64, Tz::UTC)),
+            ScalarRef::Interval(i) => write!(f, "{i}"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -229,6 +240,11 @@ impl<'a> Display for ScalarRef<'a> {
                 let value = jsonb::to_string(s);
                 write!(f, "{value}")
             }
+            ScalarRef::Bitmap(s) => match deserialize_bitmap(s) {
+                Ok(bitmap) => write!(f, "{{{}}}", bitmap.iter().join(",")),
+                Err(_) => write!(f, "0x{}", &hex::encode(s)),
+            },
+            ScalarRef::Binary(s) => write!(f, "{}", &hex::encode(s)),
         }
     }
 }
@@ -420,6 +436,19 @@ impl<Index: ColumnIndex> Display for RawExpr<Index> {
                 }
                 write!(f, ")")
             }
+            RawExpr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                write!(f, "{name}")?;
+                write!(f, "(")?;
+                write!(f, "{}", args[0])?;
+                write!(f, ", ")?;
+                write!(f, "{lambda_display}")?;
+                write!(f, ")")
+            }
         }
     }
 }
@@ -433,6 +462,7 @@ impl Display for DataType {
             DataType::Decimal(decimal) => write!(f, "{decimal}"),
             DataType::Timestamp => write!(f, "Timestamp"),
             DataType::Date => write!(f, "Date"),
+            DataType::Interval => write!(f, "Interval"),
             DataType::Null => write!(f, "NULL"),
             DataType::Nullable(inner) => write!(f, "{inner} NULL"),
             DataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -458,6 +488,8 @@ impl Display for DataType {
                 write!(f, ")")
             }
             DataType::Variant => write!(f, "Variant"),
+            DataType::Bitmap => write!(f, "Bitmap"),
+            DataType::Binary => write!(f, "Binary"),
             DataType::Generic(index) => write!(f, "T{index}"),
         }
     }
@@ -472,6 +504,7 @@ impl Display for TableDataType {
             TableDataType::Decimal(decimal) => write!(f, "{decimal}"),
             TableDataType::Timestamp => write!(f, "Timestamp"),
             TableDataType::Date => write!(f, "Date"),
+            TableDataType::Interval => write!(f, "Interval"),
             TableDataType::Null => write!(f, "NULL"),
             TableDataType::Nullable(inner) => write!(f, "{inner} NULL"),
             TableDataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -503,6 +536,8 @@ impl Display for TableDataType {
                 write!(f, ")")
             }
             TableDataType::Variant => write!(f, "Variant"),
+            TableDataType::Bitmap => write!(f, "Bitmap"),
+            TableDataType::Binary => write!(f, "Binary"),
         }
     }
 }
@@ -588,6 +623,19 @@ impl<Index: ColumnIndex> Display for Expr<Index> {
                 }
                 write!(f, ")")
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                write!(f, "{name}")?;
+                write!(f, "(")?;
+                write!(f, "{}", args[0])?;
+                write!(f, ", ")?;
+                write!(f, "{lambda_display}")?;
+                write!(f, ")")
+            }
         }
     }
 }
@@ -707,6 +755,14 @@ impl<Index: ColumnIndex> Expr<Index> {
                         }
                     }
                 }
+                Expr::LambdaFunctionCall {
+                    name,
+                    args,
+                    lambda_display,
+                    ..
+                } => {
+                    format!("{name}({}, {lambda_display})", args[0].sql_display())
+                }
             }
         }
 
@@ -765,6 +821,9 @@ impl Display for FunctionProperty {
         if self.non_deterministic {
             properties.push("non_deterministic");
         }
+        if self.per_row {
+            properties.push("per_row");
+        }
         if !properties.is_empty() {
             write!(f, "{{{}}}", properties.join(", "))?;
         }
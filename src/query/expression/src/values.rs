@@ -35,15 +35,19 @@ use enum_as_inner::EnumAsInner;
 use ethnum::i256;
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
+use roaring::RoaringBitmap;
 use serde::de::Visitor;
 use serde::Deserialize;
 use serde::Deserializer;
 use serde::Serialize;
 use serde::Serializer;
 
+use crate::kernels::encode_sort_key;
+use crate::kernels::encode_variant_sort_key;
 use crate::property::Domain;
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::bitmap::serialize_bitmap;
 use crate::types::boolean::BooleanDomain;
 use crate::types::date::DATE_MAX;
 use crate::types::date::DATE_MIN;
@@ -53,6 +57,8 @@ use crate::types::decimal::DecimalColumnBuilder;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalSize;
+use crate::types::interval::MonthsDaysMicros;
+use crate::types::interval::MICROS_IN_A_DAY;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableDomain;
@@ -68,6 +74,7 @@ use crate::types::string::StringDomain;
 use crate::types::timestamp::check_timestamp;
 use crate::types::timestamp::TIMESTAMP_MAX;
 use crate::types::timestamp::TIMESTAMP_MIN;
+use crate::types::variant::compare_variant;
 use crate::types::variant::JSONB_NULL;
 use crate::types::*;
 use crate::utils::arrow::append_bitmap;
@@ -103,12 +110,15 @@ pub enum Scalar {
     Decimal(DecimalScalar),
     Timestamp(i64),
     Date(i32),
+    Interval(MonthsDaysMicros),
     Boolean(bool),
     String(Vec<u8>),
     Array(Column),
     Map(Column),
     Tuple(Vec<Scalar>),
     Variant(Vec<u8>),
+    Bitmap(Vec<u8>),
+    Binary(Vec<u8>),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -123,10 +133,13 @@ pub enum ScalarRef<'a> {
     String(&'a [u8]),
     Timestamp(i64),
     Date(i32),
+    Interval(MonthsDaysMicros),
     Array(Column),
     Map(Column),
     Tuple(Vec<ScalarRef<'a>>),
     Variant(&'a [u8]),
+    Bitmap(&'a [u8]),
+    Binary(&'a [u8]),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -140,11 +153,14 @@ pub enum Column {
     String(StringColumn),
     Timestamp(Buffer<i64>),
     Date(Buffer<i32>),
+    Interval(Buffer<MonthsDaysMicros>),
     Array(Box<ArrayColumn<AnyType>>),
     Map(Box<ArrayColumn<AnyType>>),
     Nullable(Box<NullableColumn<AnyType>>),
     Tuple(Vec<Column>),
     Variant(StringColumn),
+    Bitmap(StringColumn),
+    Binary(StringColumn),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -158,11 +174,14 @@ pub enum ColumnBuilder {
     String(StringColumnBuilder),
     Timestamp(Vec<i64>),
     Date(Vec<i32>),
+    Interval(Vec<MonthsDaysMicros>),
     Array(Box<ArrayColumnBuilder<AnyType>>),
     Map(Box<ArrayColumnBuilder<AnyType>>),
     Nullable(Box<NullableColumnBuilder<AnyType>>),
     Tuple(Vec<ColumnBuilder>),
     Variant(StringColumnBuilder),
+    Bitmap(StringColumnBuilder),
+    Binary(StringColumnBuilder),
 }
 
 impl<'a, T: ValueType> ValueRef<'a, T> {
@@ -272,6 +291,45 @@ impl Value<AnyType> {
             scalar => scalar,
         }
     }
+
+    /// Generates a sample value of type `ty` for a block of `num_rows` rows, it's how
+    /// the function kernels are exercised with arbitrary arguments.
+    ///
+    /// Returns `None` if the type can not be sampled, i.e. it contains generic types.
+    pub fn sample(ty: &DataType, num_rows: usize, kind: SampleKind) -> Option<Self> {
+        if ty.has_generic() {
+            return None;
+        }
+
+        let value = match kind {
+            SampleKind::Random => Value::Column(Column::random(ty, num_rows)),
+            SampleKind::AllNull if ty.is_nullable_or_null() => {
+                Value::Column(ColumnBuilder::repeat(&ScalarRef::Null, num_rows, ty).build())
+            }
+            SampleKind::AllNull => Value::Column(Column::random(ty, num_rows)),
+            SampleKind::Constant => Value::Scalar(Column::random(ty, 1).index(0)?.to_owned()),
+        };
+        Some(value)
+    }
+}
+
+/// The shapes of the sample values generated by [`Value::sample`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleKind {
+    /// A column of random values, some of them are null if the type is nullable.
+    Random,
+    /// A column of nulls if the type is nullable, otherwise the same as `Random`.
+    AllNull,
+    /// A random constant.
+    Constant,
+}
+
+impl SampleKind {
+    pub const ALL: [SampleKind; 3] = [
+        SampleKind::Random,
+        SampleKind::AllNull,
+        SampleKind::Constant,
+    ];
 }
 
 impl<'a> ValueRef<'a, AnyType> {
@@ -302,10 +360,13 @@ impl Scalar {
             Scalar::String(s) => ScalarRef::String(s.as_slice()),
             Scalar::Timestamp(t) => ScalarRef::Timestamp(*t),
             Scalar::Date(d) => ScalarRef::Date(*d),
+            Scalar::Interval(i) => ScalarRef::Interval(*i),
             Scalar::Array(col) => ScalarRef::Array(col.clone()),
             Scalar::Map(col) => ScalarRef::Map(col.clone()),
             Scalar::Tuple(fields) => ScalarRef::Tuple(fields.iter().map(Scalar::as_ref).collect()),
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
+            Scalar::Bitmap(s) => ScalarRef::Bitmap(s.as_slice()),
+            Scalar::Binary(s) => ScalarRef::Binary(s.as_slice()),
         }
     }
 
@@ -331,6 +392,7 @@ impl Scalar {
             DataType::Decimal(ty) => Scalar::Decimal(ty.default_scalar()),
             DataType::Timestamp => Scalar::Timestamp(0),
             DataType::Date => Scalar::Date(0),
+            DataType::Interval => Scalar::Interval(MonthsDaysMicros::default()),
             DataType::Nullable(_) => Scalar::Null,
             DataType::Array(ty) => {
                 let builder = ColumnBuilder::with_capacity(ty, 0);
@@ -344,6 +406,8 @@ impl Scalar {
             }
             DataType::Tuple(tys) => Scalar::Tuple(tys.iter().map(Scalar::default_value).collect()),
             DataType::Variant => Scalar::Variant(vec![]),
+            DataType::Bitmap => Scalar::Bitmap(vec![]),
+            DataType::Binary => Scalar::Binary(vec![]),
 
             _ => unimplemented!(),
         }
@@ -362,12 +426,15 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(s) => Scalar::String(s.to_vec()),
             ScalarRef::Timestamp(t) => Scalar::Timestamp(*t),
             ScalarRef::Date(d) => Scalar::Date(*d),
+            ScalarRef::Interval(i) => Scalar::Interval(*i),
             ScalarRef::Array(col) => Scalar::Array(col.clone()),
             ScalarRef::Map(col) => Scalar::Map(col.clone()),
             ScalarRef::Tuple(fields) => {
                 Scalar::Tuple(fields.iter().map(ScalarRef::to_owned).collect())
             }
             ScalarRef::Variant(s) => Scalar::Variant(s.to_vec()),
+            ScalarRef::Bitmap(s) => Scalar::Bitmap(s.to_vec()),
+            ScalarRef::Binary(s) => Scalar::Binary(s.to_vec()),
         }
     }
 
@@ -436,7 +503,10 @@ impl<'a> ScalarRef<'a> {
                         .collect(),
                 )
             }
-            ScalarRef::Variant(_) => Domain::Undefined,
+            ScalarRef::Interval(_)
+            | ScalarRef::Variant(_)
+            | ScalarRef::Bitmap(_)
+            | ScalarRef::Binary(_) => Domain::Undefined,
         }
     }
 
@@ -459,10 +529,11 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(s) => s.len(),
             ScalarRef::Timestamp(_) => 8,
             ScalarRef::Date(_) => 4,
+            ScalarRef::Interval(_) => 16,
             ScalarRef::Array(col) => col.memory_size(),
             ScalarRef::Map(col) => col.memory_size(),
             ScalarRef::Tuple(scalars) => scalars.iter().map(|s| s.memory_size()).sum(),
-            ScalarRef::Variant(buf) => buf.len(),
+            ScalarRef::Variant(buf) | ScalarRef::Bitmap(buf) | ScalarRef::Binary(buf) => buf.len(),
         }
     }
 
@@ -485,6 +556,7 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(_) => DataType::String,
             ScalarRef::Timestamp(_) => DataType::Timestamp,
             ScalarRef::Date(_) => DataType::Date,
+            ScalarRef::Interval(_) => DataType::Interval,
             ScalarRef::Array(array) => DataType::Array(Box::new(array.data_type())),
             ScalarRef::Map(col) => DataType::Map(Box::new(col.data_type())),
             ScalarRef::Tuple(fields) => {
@@ -495,6 +567,8 @@ impl<'a> ScalarRef<'a> {
                 DataType::Tuple(inner)
             }
             ScalarRef::Variant(_) => DataType::Variant,
+            ScalarRef::Bitmap(_) => DataType::Bitmap,
+            ScalarRef::Binary(_) => DataType::Binary,
         }
     }
 }
@@ -511,12 +585,13 @@ impl PartialOrd for Scalar {
             (Scalar::String(s1), Scalar::String(s2)) => s1.partial_cmp(s2),
             (Scalar::Timestamp(t1), Scalar::Timestamp(t2)) => t1.partial_cmp(t2),
             (Scalar::Date(d1), Scalar::Date(d2)) => d1.partial_cmp(d2),
+            (Scalar::Interval(i1), Scalar::Interval(i2)) => i1.partial_cmp(i2),
             (Scalar::Array(a1), Scalar::Array(a2)) => a1.partial_cmp(a2),
             (Scalar::Map(m1), Scalar::Map(m2)) => m1.partial_cmp(m2),
             (Scalar::Tuple(t1), Scalar::Tuple(t2)) => t1.partial_cmp(t2),
-            (Scalar::Variant(v1), Scalar::Variant(v2)) => {
-                jsonb::compare(v1.as_slice(), v2.as_slice()).ok()
-            }
+            (Scalar::Variant(v1), Scalar::Variant(v2)) => Some(compare_variant(v1, v2)),
+            (Scalar::Bitmap(b1), Scalar::Bitmap(b2)) => b1.partial_cmp(b2),
+            (Scalar::Binary(b1), Scalar::Binary(b2)) => b1.partial_cmp(b2),
             _ => None,
         }
     }
@@ -546,10 +621,13 @@ impl PartialOrd for ScalarRef<'_> {
             (ScalarRef::String(s1), ScalarRef::String(s2)) => s1.partial_cmp(s2),
             (ScalarRef::Timestamp(t1), ScalarRef::Timestamp(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Date(d1), ScalarRef::Date(d2)) => d1.partial_cmp(d2),
+            (ScalarRef::Interval(i1), ScalarRef::Interval(i2)) => i1.partial_cmp(i2),
             (ScalarRef::Array(a1), ScalarRef::Array(a2)) => a1.partial_cmp(a2),
             (ScalarRef::Map(m1), ScalarRef::Map(m2)) => m1.partial_cmp(m2),
             (ScalarRef::Tuple(t1), ScalarRef::Tuple(t2)) => t1.partial_cmp(t2),
-            (ScalarRef::Variant(v1), ScalarRef::Variant(v2)) => jsonb::compare(v1, v2).ok(),
+            (ScalarRef::Variant(v1), ScalarRef::Variant(v2)) => Some(compare_variant(v1, v2)),
+            (ScalarRef::Bitmap(b1), ScalarRef::Bitmap(b2)) => b1.partial_cmp(b2),
+            (ScalarRef::Binary(b1), ScalarRef::Binary(b2)) => b1.partial_cmp(b2),
             _ => None,
         }
     }
@@ -585,18 +663,24 @@ impl Hash for ScalarRef<'_> {
             ScalarRef::String(v) => v.hash(state),
             ScalarRef::Timestamp(v) => v.hash(state),
             ScalarRef::Date(v) => v.hash(state),
-            ScalarRef::Array(v) => {
-                let str = serialize_column(v);
-                str.hash(state);
-            }
-            ScalarRef::Map(v) => {
-                let str = serialize_column(v);
-                str.hash(state);
+            ScalarRef::Interval(v) => v.hash(state),
+            // Hash the sort keys, which are the same if and only if the values are equal.
+            ScalarRef::Array(v) | ScalarRef::Map(v) => {
+                let mut key = Vec::new();
+                for row in 0..v.len() {
+                    encode_sort_key(v, row, &mut key);
+                }
+                key.hash(state);
             }
             ScalarRef::Tuple(v) => {
                 v.hash(state);
             }
-            ScalarRef::Variant(v) => v.hash(state),
+            ScalarRef::Variant(v) => {
+                let mut key = Vec::new();
+                encode_variant_sort_key(v, &mut key);
+                key.hash(state);
+            }
+            ScalarRef::Bitmap(v) | ScalarRef::Binary(v) => v.hash(state),
         }
     }
 }
@@ -625,6 +709,9 @@ impl PartialOrd for Column {
                 col1.iter().partial_cmp(col2.iter())
             }
             (Column::Date(col1), Column::Date(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Interval(col1), Column::Interval(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Array(col1), Column::Array(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Map(col1), Column::Map(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Nullable(col1), Column::Nullable(col2)) => {
@@ -633,7 +720,9 @@ impl PartialOrd for Column {
             (Column::Tuple(fields1), Column::Tuple(fields2)) => fields1.partial_cmp(fields2),
             (Column::Variant(col1), Column::Variant(col2)) => col1
                 .iter()
-                .partial_cmp_by(col2.iter(), |v1, v2| jsonb::compare(v1, v2).ok()),
+                .partial_cmp_by(col2.iter(), |v1, v2| Some(compare_variant(v1, v2))),
+            (Column::Bitmap(col1), Column::Bitmap(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Binary(col1), Column::Binary(col2)) => col1.iter().partial_cmp(col2.iter()),
             _ => None,
         }
     }
@@ -648,6 +737,9 @@ impl PartialEq for Column {
 pub const ARROW_EXT_TYPE_EMPTY_ARRAY: &str = "EmptyArray";
 pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
+pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
+pub const ARROW_EXT_TYPE_BINARY: &str = "Binary";
+pub const ARROW_EXT_TYPE_INTERVAL: &str = "Interval";
 
 impl Column {
     pub fn len(&self) -> usize {
@@ -661,11 +753,12 @@ impl Column {
             Column::String(col) => col.len(),
             Column::Timestamp(col) => col.len(),
             Column::Date(col) => col.len(),
+            Column::Interval(col) => col.len(),
             Column::Array(col) => col.len(),
             Column::Map(col) => col.len(),
             Column::Nullable(col) => col.len(),
             Column::Tuple(fields) => fields[0].len(),
-            Column::Variant(col) => col.len(),
+            Column::Variant(col) | Column::Bitmap(col) | Column::Binary(col) => col.len(),
         }
     }
 
@@ -680,6 +773,7 @@ impl Column {
             Column::String(col) => Some(ScalarRef::String(col.index(index)?)),
             Column::Timestamp(col) => Some(ScalarRef::Timestamp(col.get(index).cloned()?)),
             Column::Date(col) => Some(ScalarRef::Date(col.get(index).cloned()?)),
+            Column::Interval(col) => Some(ScalarRef::Interval(col.get(index).cloned()?)),
             Column::Array(col) => Some(ScalarRef::Array(col.index(index)?)),
             Column::Map(col) => Some(ScalarRef::Map(col.index(index)?)),
             Column::Nullable(col) => Some(col.index(index)?.unwrap_or(ScalarRef::Null)),
@@ -690,6 +784,8 @@ impl Column {
                     .collect::<Option<Vec<_>>>()?,
             )),
             Column::Variant(col) => Some(ScalarRef::Variant(col.index(index)?)),
+            Column::Bitmap(col) => Some(ScalarRef::Bitmap(col.index(index)?)),
+            Column::Binary(col) => Some(ScalarRef::Binary(col.index(index)?)),
         }
     }
 
@@ -707,6 +803,7 @@ impl Column {
             Column::String(col) => ScalarRef::String(col.index_unchecked(index)),
             Column::Timestamp(col) => ScalarRef::Timestamp(*col.get_unchecked(index)),
             Column::Date(col) => ScalarRef::Date(*col.get_unchecked(index)),
+            Column::Interval(col) => ScalarRef::Interval(*col.get_unchecked(index)),
             Column::Array(col) => ScalarRef::Array(col.index_unchecked(index)),
             Column::Map(col) => ScalarRef::Map(col.index_unchecked(index)),
             Column::Nullable(col) => col.index_unchecked(index).unwrap_or(ScalarRef::Null),
@@ -717,6 +814,8 @@ impl Column {
                     .collect::<Vec<_>>(),
             ),
             Column::Variant(col) => ScalarRef::Variant(col.index_unchecked(index)),
+            Column::Bitmap(col) => ScalarRef::Bitmap(col.index_unchecked(index)),
+            Column::Binary(col) => ScalarRef::Binary(col.index_unchecked(index)),
         }
     }
 
@@ -755,6 +854,9 @@ impl Column {
             Column::Date(col) => {
                 Column::Date(col.clone().sliced(range.start, range.end - range.start))
             }
+            Column::Interval(col) => {
+                Column::Interval(col.clone().sliced(range.start, range.end - range.start))
+            }
             Column::Array(col) => Column::Array(Box::new(col.slice(range))),
             Column::Map(col) => Column::Map(Box::new(col.slice(range))),
             Column::Nullable(col) => Column::Nullable(Box::new(col.slice(range))),
@@ -765,6 +867,8 @@ impl Column {
                     .collect(),
             ),
             Column::Variant(col) => Column::Variant(col.slice(range)),
+            Column::Bitmap(col) => Column::Bitmap(col.slice(range)),
+            Column::Binary(col) => Column::Binary(col.slice(range)),
         }
     }
 
@@ -845,7 +949,9 @@ impl Column {
                 let domains = fields.iter().map(|col| col.domain()).collect::<Vec<_>>();
                 Domain::Tuple(domains)
             }
-            Column::Variant(_) => Domain::Undefined,
+            Column::Interval(_) | Column::Variant(_) | Column::Bitmap(_) | Column::Binary(_) => {
+                Domain::Undefined
+            }
         }
     }
 
@@ -865,6 +971,7 @@ impl Column {
             Column::String(_) => DataType::String,
             Column::Timestamp(_) => DataType::Timestamp,
             Column::Date(_) => DataType::Date,
+            Column::Interval(_) => DataType::Interval,
             Column::Array(array) => {
                 let inner = array.values.data_type();
                 DataType::Array(Box::new(inner))
@@ -882,6 +989,8 @@ impl Column {
                 DataType::Tuple(inner)
             }
             Column::Variant(_) => DataType::Variant,
+            Column::Bitmap(_) => DataType::Bitmap,
+            Column::Binary(_) => DataType::Binary,
         }
     }
 
@@ -1050,6 +1159,15 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::Interval(col) => {
+                let values: Buffer<i128> = col.iter().map(|v| v.to_i128()).collect();
+                Box::new(
+                    common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
             Column::Array(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
@@ -1102,7 +1220,7 @@ impl Column {
                 )
                 .unwrap(),
             ),
-            Column::Variant(col) => {
+            Column::Variant(col) | Column::Bitmap(col) | Column::Binary(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
                 Box::new(
@@ -1297,10 +1415,15 @@ impl Column {
                     .map(|x| *x as u64)
                     .collect::<Vec<_>>();
 
-                Column::String(StringColumn {
+                let column = StringColumn {
                     data: arrow_col.values().clone(),
                     offsets: offsets.into(),
-                })
+                };
+                // non-UTF8 byte arrays (e.g. parquet BYTE_ARRAY) are read as raw bytes
+                match data_type {
+                    DataType::Binary => Column::Binary(column),
+                    _ => Column::String(column),
+                }
             }
             // TODO: deprecate it and use LargeBinary instead
             ArrowDataType::Utf8 => {
@@ -1386,6 +1509,49 @@ impl Column {
                     offsets: offsets.into(),
                 })
             }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_INTERVAL => {
+                let values = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::PrimitiveArray<i128>>()
+                    .expect("fail to read from arrow: array should be `PrimitiveArray<i128>`")
+                    .values()
+                    .iter()
+                    .map(|v| MonthsDaysMicros::from_i128(*v))
+                    .collect();
+                Column::Interval(values)
+            }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_BITMAP => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                    .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                let offsets = arrow_col
+                    .offsets()
+                    .buffer()
+                    .iter()
+                    .map(|x| *x as u64)
+                    .collect::<Vec<_>>();
+                Column::Bitmap(StringColumn {
+                    data: arrow_col.values().clone(),
+                    offsets: offsets.into(),
+                })
+            }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_BINARY => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                    .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                let offsets = arrow_col
+                    .offsets()
+                    .buffer()
+                    .iter()
+                    .map(|x| *x as u64)
+                    .collect::<Vec<_>>();
+                Column::Binary(StringColumn {
+                    data: arrow_col.values().clone(),
+                    offsets: offsets.into(),
+                })
+            }
             ArrowDataType::List(f) => {
                 let array_list = arrow_cast::cast(
                     arrow_col,
@@ -1560,6 +1726,18 @@ impl Column {
                     .map(|_| SmallRng::from_entropy().gen_range(DATE_MIN..=DATE_MAX))
                     .collect::<Vec<i32>>(),
             ),
+            DataType::Interval => IntervalType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let mut rng = SmallRng::from_entropy();
+                        MonthsDaysMicros::new(
+                            rng.gen_range(-1200..=1200),
+                            rng.gen_range(-3650..=3650),
+                            rng.gen_range(-MICROS_IN_A_DAY..=MICROS_IN_A_DAY),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
             DataType::Nullable(ty) => Column::Nullable(Box::new(NullableColumn {
                 column: Column::random(ty, len),
                 validity: Bitmap::from(
@@ -1643,6 +1821,28 @@ impl Column {
                 }
                 VariantType::from_data(data)
             }
+            DataType::Bitmap => BitmapType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let mut rng = SmallRng::from_entropy();
+                        let bitmap = (0..rng.gen_range(0..=5))
+                            .map(|_| rng.gen::<u32>())
+                            .collect::<RoaringBitmap>();
+                        let mut buf = vec![];
+                        serialize_bitmap(&bitmap, &mut buf);
+                        buf
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            DataType::Binary => BinaryType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let mut rng = SmallRng::from_entropy();
+                        let len = rng.gen_range(0..=10);
+                        (0..len).map(|_| rng.gen::<u8>()).collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>(),
+            ),
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -1689,11 +1889,14 @@ impl Column {
             Column::String(col) => col.data.len() + col.offsets.len() * 8,
             Column::Timestamp(col) => col.len() * 8,
             Column::Date(col) => col.len() * 4,
+            Column::Interval(col) => col.len() * 16,
             Column::Array(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Map(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Nullable(c) => c.column.memory_size() + c.validity.as_slice().0.len(),
             Column::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
-            Column::Variant(col) => col.data.len() + col.offsets.len() * 8,
+            Column::Variant(col) | Column::Bitmap(col) | Column::Binary(col) => {
+                col.data.len() + col.offsets.len() * 8
+            }
         }
     }
 
@@ -1763,6 +1966,7 @@ impl ColumnBuilder {
             Column::String(col) => ColumnBuilder::String(StringColumnBuilder::from_column(col)),
             Column::Timestamp(col) => ColumnBuilder::Timestamp(buffer_into_mut(col)),
             Column::Date(col) => ColumnBuilder::Date(buffer_into_mut(col)),
+            Column::Interval(col) => ColumnBuilder::Interval(buffer_into_mut(col)),
             Column::Array(box col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::from_column(col)))
             }
@@ -1779,6 +1983,8 @@ impl ColumnBuilder {
                     .collect(),
             ),
             Column::Variant(col) => ColumnBuilder::Variant(StringColumnBuilder::from_column(col)),
+            Column::Bitmap(col) => ColumnBuilder::Bitmap(StringColumnBuilder::from_column(col)),
+            Column::Binary(col) => ColumnBuilder::Binary(StringColumnBuilder::from_column(col)),
         }
     }
 
@@ -1821,6 +2027,7 @@ impl ColumnBuilder {
             ScalarRef::String(s) => ColumnBuilder::String(StringColumnBuilder::repeat(s, n)),
             ScalarRef::Timestamp(d) => ColumnBuilder::Timestamp(vec![*d; n]),
             ScalarRef::Date(d) => ColumnBuilder::Date(vec![*d; n]),
+            ScalarRef::Interval(i) => ColumnBuilder::Interval(vec![*i; n]),
             ScalarRef::Array(col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::repeat(col, n)))
             }
@@ -1839,6 +2046,8 @@ impl ColumnBuilder {
                 )
             }
             ScalarRef::Variant(s) => ColumnBuilder::Variant(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Bitmap(s) => ColumnBuilder::Bitmap(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Binary(s) => ColumnBuilder::Binary(StringColumnBuilder::repeat(s, n)),
         }
     }
 
@@ -1853,11 +2062,48 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.len(),
             ColumnBuilder::Timestamp(builder) => builder.len(),
             ColumnBuilder::Date(builder) => builder.len(),
+            ColumnBuilder::Interval(builder) => builder.len(),
             ColumnBuilder::Array(builder) => builder.len(),
             ColumnBuilder::Map(builder) => builder.len(),
             ColumnBuilder::Nullable(builder) => builder.len(),
             ColumnBuilder::Tuple(fields) => fields[0].len(),
-            ColumnBuilder::Variant(builder) => builder.len(),
+            ColumnBuilder::Variant(builder)
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Binary(builder) => builder.len(),
+        }
+    }
+
+    /// Reserves the capacity of at least `additional` more rows, so that appending many small
+    /// columns to the builder doesn't reallocate it again and again.
+    ///
+    /// Only the rows are reserved, the data of the strings and the values of the arrays grow
+    /// as they are appended.
+    pub fn reserve(&mut self, additional: usize) {
+        match self {
+            ColumnBuilder::Null { .. }
+            | ColumnBuilder::EmptyArray { .. }
+            | ColumnBuilder::EmptyMap { .. } => {}
+            ColumnBuilder::Number(builder) => builder.reserve(additional),
+            ColumnBuilder::Decimal(builder) => builder.reserve(additional),
+            ColumnBuilder::Boolean(builder) => builder.reserve(additional),
+            ColumnBuilder::String(builder)
+            | ColumnBuilder::Variant(builder)
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Binary(builder) => builder.offsets.reserve(additional),
+            ColumnBuilder::Timestamp(builder) => builder.reserve(additional),
+            ColumnBuilder::Date(builder) => builder.reserve(additional),
+            ColumnBuilder::Interval(builder) => builder.reserve(additional),
+            ColumnBuilder::Array(builder) => builder.reserve(additional),
+            ColumnBuilder::Map(builder) => builder.reserve(additional),
+            ColumnBuilder::Nullable(builder) => {
+                builder.builder.reserve(additional);
+                builder.validity.reserve(additional);
+            }
+            ColumnBuilder::Tuple(fields) => {
+                for field in fields {
+                    field.reserve(additional);
+                }
+            }
         }
     }
 
@@ -1886,11 +2132,14 @@ impl ColumnBuilder {
             ColumnBuilder::String(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Timestamp(col) => col.len() * 8,
             ColumnBuilder::Date(col) => col.len() * 4,
+            ColumnBuilder::Interval(col) => col.len() * 16,
             ColumnBuilder::Array(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Map(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Nullable(c) => c.builder.memory_size() + c.validity.as_slice().len(),
             ColumnBuilder::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
-            ColumnBuilder::Variant(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::Variant(col)
+            | ColumnBuilder::Bitmap(col)
+            | ColumnBuilder::Binary(col) => col.data.len() + col.offsets.len() * 8,
         }
     }
 
@@ -1910,6 +2159,7 @@ impl ColumnBuilder {
             ColumnBuilder::String(_) => DataType::String,
             ColumnBuilder::Timestamp(_) => DataType::Timestamp,
             ColumnBuilder::Date(_) => DataType::Date,
+            ColumnBuilder::Interval(_) => DataType::Interval,
             ColumnBuilder::Array(col) => {
                 let inner = col.builder.data_type();
                 DataType::Array(Box::new(inner))
@@ -1923,6 +2173,8 @@ impl ColumnBuilder {
                 DataType::Tuple(fields.iter().map(|f| f.data_type()).collect::<Vec<_>>())
             }
             ColumnBuilder::Variant(_) => DataType::Variant,
+            ColumnBuilder::Bitmap(_) => DataType::Bitmap,
+            ColumnBuilder::Binary(_) => DataType::Binary,
         }
     }
 
@@ -1954,6 +2206,7 @@ impl ColumnBuilder {
             }
             DataType::Timestamp => ColumnBuilder::Timestamp(Vec::with_capacity(capacity)),
             DataType::Date => ColumnBuilder::Date(Vec::with_capacity(capacity)),
+            DataType::Interval => ColumnBuilder::Interval(Vec::with_capacity(capacity)),
             DataType::Nullable(ty) => ColumnBuilder::Nullable(Box::new(NullableColumnBuilder {
                 builder: Self::with_capacity_hint(ty, capacity, enable_datasize_hint),
                 validity: MutableBitmap::with_capacity(capacity),
@@ -1989,6 +2242,14 @@ impl ColumnBuilder {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::Variant(StringColumnBuilder::with_capacity(capacity, data_capacity))
             }
+            DataType::Bitmap => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Bitmap(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
+            DataType::Binary => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Binary(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
             DataType::Generic(_) => {
                 unreachable!("unable to initialize column builder for generic type")
             }
@@ -2011,6 +2272,9 @@ impl ColumnBuilder {
                 builder.push(value);
             }
             (ColumnBuilder::Date(builder), ScalarRef::Date(value)) => builder.push(value),
+            (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => {
+                builder.push(value);
+            }
             (ColumnBuilder::Array(builder), ScalarRef::Array(value)) => {
                 builder.push(value);
             }
@@ -2029,7 +2293,9 @@ impl ColumnBuilder {
                     field.push(scalar.clone());
                 }
             }
-            (ColumnBuilder::Variant(builder), ScalarRef::Variant(value)) => {
+            (ColumnBuilder::Variant(builder), ScalarRef::Variant(value))
+            | (ColumnBuilder::Bitmap(builder), ScalarRef::Bitmap(value))
+            | (ColumnBuilder::Binary(builder), ScalarRef::Binary(value)) => {
                 builder.put_slice(value);
                 builder.commit_row();
             }
@@ -2048,6 +2314,7 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.commit_row(),
             ColumnBuilder::Timestamp(builder) => builder.push(0),
             ColumnBuilder::Date(builder) => builder.push(0),
+            ColumnBuilder::Interval(builder) => builder.push(MonthsDaysMicros::default()),
             ColumnBuilder::Array(builder) => builder.push_default(),
             ColumnBuilder::Map(builder) => builder.push_default(),
             ColumnBuilder::Nullable(builder) => builder.push_null(),
@@ -2060,6 +2327,8 @@ impl ColumnBuilder {
                 builder.put_slice(JSONB_NULL);
                 builder.commit_row();
             }
+            ColumnBuilder::Bitmap(builder) => builder.commit_row(),
+            ColumnBuilder::Binary(builder) => builder.commit_row(),
         }
     }
 
@@ -2084,7 +2353,10 @@ impl ColumnBuilder {
                 let v: bool = reader.read_scalar()?;
                 builder.push(v);
             }
-            ColumnBuilder::String(builder) | ColumnBuilder::Variant(builder) => {
+            ColumnBuilder::String(builder)
+            | ColumnBuilder::Variant(builder)
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Binary(builder) => {
                 let offset: u64 = reader.read_uvarint()?;
                 builder.data.resize(offset as usize + builder.data.len(), 0);
                 let last = *builder.offsets.last().unwrap() as usize;
@@ -2100,6 +2372,12 @@ impl ColumnBuilder {
                 let value: i32 = reader.read_scalar()?;
                 builder.push(value);
             }
+            ColumnBuilder::Interval(builder) => {
+                let months: i32 = reader.read_scalar()?;
+                let days: i32 = reader.read_scalar()?;
+                let micros: i64 = reader.read_scalar()?;
+                builder.push(MonthsDaysMicros::new(months, days, micros));
+            }
             ColumnBuilder::Array(builder) => {
                 let len = reader.read_uvarint()?;
                 for _ in 0..len {
@@ -2168,7 +2446,10 @@ impl ColumnBuilder {
                     builder.push(v);
                 }
             }
-            ColumnBuilder::String(builder) | ColumnBuilder::Variant(builder) => {
+            ColumnBuilder::String(builder)
+            | ColumnBuilder::Variant(builder)
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Binary(builder) => {
                 for row in 0..rows {
                     let reader = &reader[step * row..];
                     builder.put_slice(reader);
@@ -2190,6 +2471,15 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Interval(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let months: i32 = reader.read_scalar()?;
+                    let days: i32 = reader.read_scalar()?;
+                    let micros: i64 = reader.read_scalar()?;
+                    builder.push(MonthsDaysMicros::new(months, days, micros));
+                }
+            }
             ColumnBuilder::Array(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2262,6 +2552,7 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.pop().map(Scalar::String),
             ColumnBuilder::Timestamp(builder) => builder.pop().map(Scalar::Timestamp),
             ColumnBuilder::Date(builder) => builder.pop().map(Scalar::Date),
+            ColumnBuilder::Interval(builder) => builder.pop().map(Scalar::Interval),
             ColumnBuilder::Array(builder) => builder.pop().map(Scalar::Array),
             ColumnBuilder::Map(builder) => builder.pop().map(Scalar::Map),
             ColumnBuilder::Nullable(builder) => Some(builder.pop()?.unwrap_or(Scalar::Null)),
@@ -2278,6 +2569,8 @@ impl ColumnBuilder {
                 }
             }
             ColumnBuilder::Variant(builder) => builder.pop().map(Scalar::Variant),
+            ColumnBuilder::Bitmap(builder) => builder.pop().map(Scalar::Bitmap),
+            ColumnBuilder::Binary(builder) => builder.pop().map(Scalar::Binary),
         }
     }
 
@@ -2304,7 +2597,9 @@ impl ColumnBuilder {
             (ColumnBuilder::String(builder), Column::String(other)) => {
                 builder.append_column(other);
             }
-            (ColumnBuilder::Variant(builder), Column::Variant(other)) => {
+            (ColumnBuilder::Variant(builder), Column::Variant(other))
+            | (ColumnBuilder::Bitmap(builder), Column::Bitmap(other))
+            | (ColumnBuilder::Binary(builder), Column::Binary(other)) => {
                 builder.append_column(other);
             }
             (ColumnBuilder::Timestamp(builder), Column::Timestamp(other)) => {
@@ -2313,6 +2608,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Date(builder), Column::Date(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::Interval(builder), Column::Interval(other)) => {
+                builder.extend_from_slice(other);
+            }
             (ColumnBuilder::Array(builder), Column::Array(other)) => {
                 builder.append_column(other.as_ref());
             }
@@ -2343,6 +2641,7 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => Column::String(builder.build()),
             ColumnBuilder::Timestamp(builder) => Column::Timestamp(builder.into()),
             ColumnBuilder::Date(builder) => Column::Date(builder.into()),
+            ColumnBuilder::Interval(builder) => Column::Interval(builder.into()),
             ColumnBuilder::Array(builder) => Column::Array(Box::new(builder.build())),
             ColumnBuilder::Map(builder) => Column::Map(Box::new(builder.build())),
             ColumnBuilder::Nullable(builder) => Column::Nullable(Box::new(builder.build())),
@@ -2351,6 +2650,8 @@ impl ColumnBuilder {
                 Column::Tuple(fields.into_iter().map(|field| field.build()).collect())
             }
             ColumnBuilder::Variant(builder) => Column::Variant(builder.build()),
+            ColumnBuilder::Bitmap(builder) => Column::Bitmap(builder.build()),
+            ColumnBuilder::Binary(builder) => Column::Binary(builder.build()),
         }
     }
 
@@ -2366,6 +2667,7 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => Scalar::String(builder.build_scalar()),
             ColumnBuilder::Timestamp(builder) => Scalar::Timestamp(builder[0]),
             ColumnBuilder::Date(builder) => Scalar::Date(builder[0]),
+            ColumnBuilder::Interval(builder) => Scalar::Interval(builder[0]),
             ColumnBuilder::Array(builder) => Scalar::Array(builder.build_scalar()),
             ColumnBuilder::Map(builder) => Scalar::Map(builder.build_scalar()),
             ColumnBuilder::Nullable(builder) => builder.build_scalar().unwrap_or(Scalar::Null),
@@ -2376,6 +2678,8 @@ impl ColumnBuilder {
                     .collect(),
             ),
             ColumnBuilder::Variant(builder) => Scalar::Variant(builder.build_scalar()),
+            ColumnBuilder::Bitmap(builder) => Scalar::Bitmap(builder.build_scalar()),
+            ColumnBuilder::Binary(builder) => Scalar::Binary(builder.build_scalar()),
         }
     }
 }
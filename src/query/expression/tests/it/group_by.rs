@@ -14,11 +14,13 @@
 
 use common_exception::Result;
 use common_expression::types::number::*;
+use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
 use common_expression::*;
 
 use crate::common::new_block;
+use crate::sort::variant_column;
 
 #[test]
 fn test_group_by_hash() -> Result<()> {
@@ -64,3 +66,42 @@ fn test_group_by_hash() -> Result<()> {
     ]);
     Ok(())
 }
+
+#[test]
+fn test_group_by_hash_variant() -> Result<()> {
+    let block = new_block(&[variant_column(&[
+        "1",
+        "1.0",
+        r#"{"k":"v"}"#,
+        r#"{"k":"v"}"#,
+        r#""1""#,
+        "[1,2.0]",
+        "[1.0,2]",
+    ])]);
+
+    let method = DataBlock::choose_hash_method(&block, &[0])?;
+    assert_eq!(method.name(), HashMethodSingleString::default().name());
+
+    // The equal variants have the same key, no matter how the numbers are stored.
+    let hash = HashMethodSingleString::default();
+    let group_columns = vec![(
+        block.get_by_offset(0).value.as_column().unwrap().clone(),
+        DataType::Variant,
+    )];
+    let state = hash.build_keys_state(&group_columns, block.num_rows())?;
+    let keys = hash.build_keys_iter(&state)?.collect::<Vec<_>>();
+    assert_eq!(keys[0], keys[1]);
+    assert_eq!(keys[2], keys[3]);
+    assert_ne!(keys[0], keys[4]);
+    assert_eq!(keys[5], keys[6]);
+
+    // So are the serialized keys.
+    let hash = HashMethodSerializer::default();
+    let state = hash.build_keys_state(&group_columns, block.num_rows())?;
+    let keys = hash.build_keys_iter(&state)?.collect::<Vec<_>>();
+    assert_eq!(keys[0], keys[1]);
+    assert_ne!(keys[0], keys[4]);
+    assert_eq!(keys[5], keys[6]);
+
+    Ok(())
+}
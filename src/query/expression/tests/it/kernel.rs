@@ -13,15 +13,19 @@
 // limitations under the License.
 
 use common_expression::types::number::*;
+use common_expression::types::string::StringRangeBuilder;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
+use common_expression::types::ValueType;
 use common_expression::BlockEntry;
 use common_expression::Column;
+use common_expression::ColumnBuilder;
 use common_expression::DataBlock;
 use common_expression::FromData;
 use common_expression::Value;
 use goldenfile::Mint;
+use rand::Rng;
 
 use crate::common::*;
 
@@ -198,3 +202,107 @@ pub fn test_pass() {
         3,
     );
 }
+
+#[test]
+pub fn test_take_random() {
+    let mut rng = rand::thread_rng();
+    let data_types = vec![
+        DataType::Boolean,
+        DataType::Number(NumberDataType::Int32),
+        DataType::Number(NumberDataType::Float64),
+        DataType::String,
+        DataType::Variant,
+        DataType::Bitmap,
+        DataType::Binary,
+        DataType::Date,
+        DataType::Timestamp,
+        DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt8))),
+        DataType::Nullable(Box::new(DataType::String)),
+        DataType::Array(Box::new(DataType::Number(NumberDataType::Int16))),
+        DataType::Array(Box::new(DataType::Nullable(Box::new(DataType::String)))),
+        DataType::Tuple(vec![
+            DataType::Number(NumberDataType::Int64),
+            DataType::Nullable(Box::new(DataType::String)),
+        ]),
+    ];
+
+    for ty in data_types {
+        for _ in 0..20 {
+            let len = rng.gen_range(1..100);
+            let column = Column::random(&ty, len);
+            let indices = (0..rng.gen_range(0..200))
+                .map(|_| rng.gen_range(0..len) as u32)
+                .collect::<Vec<_>>();
+
+            let mut builder = ColumnBuilder::with_capacity(&ty, indices.len());
+            for index in &indices {
+                builder.push(column.index(*index as usize).unwrap());
+            }
+            let expected = builder.build();
+
+            assert_eq!(column.take(&indices), expected);
+            assert_eq!(column.take_checked(&indices).unwrap(), expected);
+        }
+    }
+}
+
+#[test]
+pub fn test_take_checked_out_of_bounds() {
+    let column = StringType::from_data_with_validity(vec!["a", "b", "c"], vec![true, false, true]);
+    assert!(column.take_checked(&[0u32, 2]).is_ok());
+    assert!(column.take_checked(&[0u32, 3]).is_err());
+
+    let block = new_block(&[Int32Type::from_data(vec![1i32, 2, 3]), column]);
+    assert_eq!(block.take_checked(&[2u32, 1]).unwrap().num_rows(), 2);
+    assert!(block.take_checked(&[1u32, 5]).is_err());
+}
+
+#[test]
+pub fn test_string_range_builder() {
+    let column = StringType::from_data(vec!["ab", "cd", "ef"]);
+    let source = StringType::try_downcast_column(&column).unwrap();
+
+    // Values covering the whole source share its data buffer.
+    let mut builder = StringRangeBuilder::with_capacity(&source, 3);
+    builder.put_row_range(0, 0..2);
+    builder.put_row_range(1, 0..2);
+    builder.put_row_range(2, 0..2);
+    let result = builder.build();
+    assert_eq!(result.data.as_ptr(), source.data.as_ptr());
+    assert_eq!(Column::String(result), column);
+
+    // Contiguous values not covering the whole source are copied.
+    let mut builder = StringRangeBuilder::with_capacity(&source, 3);
+    builder.put_row_range(0, 0..2);
+    builder.put_row_range(1, 0..2);
+    builder.put_row_range(2, 0..1);
+    let result = builder.build();
+    assert_ne!(result.data.as_ptr(), source.data.as_ptr());
+    assert_eq!(
+        Column::String(result),
+        StringType::from_data(vec!["ab", "cd", "e"])
+    );
+
+    // Non-contiguous values are copied.
+    let mut builder = StringRangeBuilder::with_capacity(&source, 3);
+    builder.put_row_range(0, 1..2);
+    builder.put_row_range(1, 0..2);
+    builder.put_row_range(2, 0..1);
+    assert_eq!(builder.len(), 3);
+    assert_eq!(
+        Column::String(builder.build()),
+        StringType::from_data(vec!["b", "cd", "e"])
+    );
+
+    // A sliced source keeps its offsets.
+    let sliced = source.slice(1..3);
+    let mut builder = StringRangeBuilder::with_capacity(&sliced, 2);
+    builder.put_row_range(0, 0..2);
+    builder.put_row_range(1, 0..2);
+    let result = builder.build();
+    assert_eq!(result.data.as_ptr(), source.data.as_ptr());
+    assert_eq!(
+        Column::String(result),
+        StringType::from_data(vec!["cd", "ef"])
+    );
+}
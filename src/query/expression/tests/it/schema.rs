@@ -527,6 +527,41 @@ fn test_schema_modify_field() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_schema_modify_column_with_origin() -> Result<()> {
+    let mut schema = TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("b", TableDataType::Number(NumberDataType::Int32)),
+    ]);
+
+    schema.modify_column_with_origin("b", TableDataType::Number(NumberDataType::Int64))?;
+    assert_eq!(schema.column_id_of("b").unwrap(), 2);
+    assert_eq!(schema.origins_of_column(2), &[(
+        1,
+        TableDataType::Number(NumberDataType::Int32)
+    )]);
+    assert_eq!(schema.is_column_deleted(1), false);
+    assert_eq!(schema.to_leaf_column_ids(), vec![0, 2]);
+
+    schema.modify_column_with_origin("b", TableDataType::Number(NumberDataType::Float64))?;
+    assert_eq!(schema.column_id_of("b").unwrap(), 3);
+    assert_eq!(schema.origins_of_column(3), &[
+        (2, TableDataType::Number(NumberDataType::Int64)),
+        (1, TableDataType::Number(NumberDataType::Int32)),
+    ]);
+    assert_eq!(schema.is_column_deleted(1), false);
+    assert_eq!(schema.is_column_deleted(2), false);
+
+    // a rewritten column forgets its origins
+    schema.modify_column("b", TableDataType::String)?;
+    assert_eq!(schema.column_id_of("b").unwrap(), 4);
+    assert!(schema.origins_of_column(4).is_empty());
+    assert_eq!(schema.is_column_deleted(3), true);
+    assert_eq!(schema.next_column_id(), 5);
+
+    Ok(())
+}
+
 #[test]
 fn test_leaf_columns_of() -> Result<()> {
     let fields = vec![
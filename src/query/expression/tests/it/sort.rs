@@ -16,9 +16,11 @@ use std::sync::Arc;
 use std::vec;
 
 use common_exception::Result;
+use common_expression::types::array::ArrayColumn;
 use common_expression::types::decimal::*;
 use common_expression::types::number::*;
 use common_expression::types::StringType;
+use common_expression::types::VariantType;
 use common_expression::Column;
 use common_expression::DataBlock;
 use common_expression::FromData;
@@ -301,3 +303,65 @@ fn test_blocks_merge_sort() -> Result<()> {
 
     Ok(())
 }
+
+pub(crate) fn variant_column(values: &[&str]) -> Column {
+    VariantType::from_data(
+        values
+            .iter()
+            .map(|v| {
+                let mut buf = Vec::new();
+                jsonb::parse_value(v.as_bytes())
+                    .unwrap()
+                    .write_to_vec(&mut buf);
+                buf
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[test]
+fn test_block_sort_variant_and_array() -> Result<()> {
+    let block = new_block(&[
+        variant_column(&[
+            "[1,2]",
+            "null",
+            r#"{"b":1}"#,
+            r#"{"a":2}"#,
+            r#""abc""#,
+            "1.5",
+            "-2",
+            "true",
+            "[1]",
+            "9223372036854775807",
+            "9223372036854775806",
+        ]),
+        Int32Type::from_data(vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]),
+    ]);
+
+    // Ordered by the type, then by the value.
+    let sort_descs = vec![SortColumnDescription {
+        offset: 0,
+        asc: true,
+        nulls_first: false,
+    }];
+    let res = DataBlock::sort(&block, &sort_descs, None)?;
+    assert_eq!(
+        res.get_by_offset(1).value.as_column().unwrap(),
+        &Int32Type::from_data(vec![7, 6, 5, 10, 9, 4, 3, 2, 8, 0, 1]),
+    );
+
+    // Arrays are ordered lexicographically.
+    let array = Column::Array(Box::new(ArrayColumn {
+        values: Int32Type::from_data(vec![2, 1, 2, 1, 1, 1, 3]),
+        offsets: vec![0, 1, 3, 4, 4, 6, 7].into(),
+    }));
+    let block = new_block(&[array, Int32Type::from_data(vec![0, 1, 2, 3, 4, 5])]);
+    let res = DataBlock::sort(&block, &sort_descs, None)?;
+    // [], [1], [1, 1], [1, 2], [2], [3]
+    assert_eq!(
+        res.get_by_offset(1).value.as_column().unwrap(),
+        &Int32Type::from_data(vec![3, 2, 4, 1, 0, 5]),
+    );
+
+    Ok(())
+}
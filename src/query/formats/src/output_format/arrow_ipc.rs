@@ -0,0 +1,69 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::ipc::write::default_ipc_fields;
+use common_arrow::arrow::io::ipc::write::StreamWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_arrow::arrow::io::ipc::IpcField;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
+
+use crate::output_format::OutputFormat;
+
+pub const ARROW_IPC_CONTENT_TYPE: &str = "application/vnd.apache.arrow.stream";
+
+/// Writes the Arrow IPC streaming format: the schema message as prefix,
+/// one record batch message per block, and the end-of-stream marker on finalize.
+pub struct ArrowIPCOutputFormat {
+    schema: ArrowSchema,
+    ipc_fields: Vec<IpcField>,
+}
+
+impl ArrowIPCOutputFormat {
+    pub fn create(schema: TableSchemaRef) -> Self {
+        let schema = schema.to_arrow();
+        let ipc_fields = default_ipc_fields(&schema.fields);
+        Self { schema, ipc_fields }
+    }
+
+    // Blocks never contain dictionary arrays, so each message can be written
+    // by a fresh writer without sharing dictionary state.
+    fn writer(buf: &mut Vec<u8>) -> StreamWriter<&mut Vec<u8>> {
+        StreamWriter::new(buf, WriteOptions { compression: None })
+    }
+}
+
+impl OutputFormat for ArrowIPCOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        let chunk = Chunk::try_from(block.clone())?;
+        let mut buf = Vec::new();
+        Self::writer(&mut buf).write(&chunk, Some(self.ipc_fields.as_slice()))?;
+        Ok(buf)
+    }
+
+    fn serialize_prefix(&self) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        Self::writer(&mut buf).start(&self.schema, Some(self.ipc_fields.clone()))?;
+        Ok(buf)
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        Self::writer(&mut buf).finish()?;
+        Ok(buf)
+    }
+}
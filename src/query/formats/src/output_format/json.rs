@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_expression::date_helper::DateConverter;
+use common_expression::types::bitmap::deserialize_bitmap;
 use common_expression::types::number::NumberScalar;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
@@ -66,7 +67,7 @@ impl JSONOutputFormat {
     }
 }
 
-fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
+pub fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
     match s {
         ScalarRef::Null => JsonValue::Null,
         ScalarRef::Boolean(v) => JsonValue::Bool(v),
@@ -95,6 +96,7 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Interval(x) => serde_json::to_value(x.to_string()).unwrap(),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::String(x) => JsonValue::String(String::from_utf8_lossy(x).to_string()),
@@ -131,6 +133,11 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let b = jsonb::from_slice(x).unwrap();
             b.into()
         }
+        ScalarRef::Bitmap(x) => match deserialize_bitmap(x) {
+            Ok(bitmap) => JsonValue::Array(bitmap.iter().map(JsonValue::from).collect()),
+            Err(_) => JsonValue::Null,
+        },
+        ScalarRef::Binary(x) => JsonValue::String(hex::encode(x)),
     }
 }
 
@@ -14,6 +14,7 @@
 
 use common_exception::Result;
 use common_expression::DataBlock;
+pub mod arrow_ipc;
 pub mod csv;
 pub mod json;
 pub mod ndjson;
@@ -21,9 +22,12 @@ pub mod parquet;
 pub mod tsv;
 pub mod values;
 
+pub use arrow_ipc::ArrowIPCOutputFormat;
+pub use arrow_ipc::ARROW_IPC_CONTENT_TYPE;
 pub use csv::CSVOutputFormat;
 pub use csv::CSVWithNamesAndTypesOutputFormat;
 pub use csv::CSVWithNamesOutputFormat;
+pub use json::scalar_to_json;
 pub use json::JSONOutputFormat;
 pub use ndjson::NDJSONOutputFormatBase;
 pub use parquet::ParquetOutputFormat;
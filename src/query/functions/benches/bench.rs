@@ -18,13 +18,26 @@ extern crate criterion;
 #[path = "../tests/it/scalars/parser.rs"]
 mod parser;
 
+use std::collections::HashMap;
+
 use common_expression::date_helper::TzLUT;
 use common_expression::type_check;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
 use common_expression::DataBlock;
 use common_expression::Evaluator;
+use common_expression::FromData;
 use common_expression::FunctionContext;
+use common_functions::scalars::geography_distance;
+use common_functions::scalars::H3DistanceFilter;
 use common_functions::BUILTIN_FUNCTIONS;
 use criterion::Criterion;
+use h3o::CellIndex;
+use h3o::LatLng;
+use h3o::Resolution;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
 
 fn bench(c: &mut Criterion) {
     let mut group = c.benchmark_group("bench_array");
@@ -44,6 +57,7 @@ fn bench(c: &mut Criterion) {
 
         let func_ctx = FunctionContext {
             tz: TzLUT::default(),
+            now: None,
         };
         let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS).unwrap();
         let block = DataBlock::new(vec![], 1);
@@ -53,5 +67,87 @@ fn bench(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, bench);
+/// `trim` over 1M strings, which shares the input buffer if no value is
+/// trimmed and copies the values otherwise.
+fn bench_trim(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench_trim");
+
+    let raw_expr = parser::parse_raw_expr("trim(a)", &[("a", DataType::String)]);
+    let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS).unwrap();
+    let func_ctx = FunctionContext {
+        tz: TzLUT::default(),
+        now: None,
+    };
+
+    for (name, value) in [("untrimmed", "databend"), ("trimmed", "  databend  ")] {
+        let column = StringType::from_data(vec![value; 1_000_000]);
+        let block = DataBlock::new_from_columns(vec![column]);
+        let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+
+        group.bench_function(name, |b| b.iter(|| evaluator.run(&expr)));
+    }
+}
+
+/// `geography_within_distance` over 1M points in a 10 x 10 degrees area, by a
+/// full scan and by the k-ring of [`H3DistanceFilter`] over the points indexed
+/// by their H3 cells.
+fn bench_geography_within_distance(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench_geography_within_distance");
+
+    let index_resolution = Resolution::Nine;
+    let mut rng = SmallRng::seed_from_u64(0);
+    let points = (0..1_000_000)
+        .map(|_| (rng.gen_range(30.0..40.0), rng.gen_range(-125.0..-115.0)))
+        .collect::<Vec<(f64, f64)>>();
+    let cells = points
+        .iter()
+        .map(|(lat, lon)| LatLng::new(*lat, *lon).unwrap().to_cell(index_resolution))
+        .collect::<Vec<CellIndex>>();
+
+    let (lat, lon) = (35.0, -120.0);
+    for distance in [1_000.0, 10_000.0] {
+        group.bench_function(format!("full_scan/{distance}"), |b| {
+            b.iter(|| {
+                points
+                    .iter()
+                    .filter(|(p_lat, p_lon)| {
+                        geography_distance(lat, lon, *p_lat, *p_lon).unwrap() <= distance
+                    })
+                    .count()
+            })
+        });
+
+        // The index groups the points by the ancestors of their cells at the
+        // resolution of the filter, like the ranges of a sorted H3 index.
+        let resolution = H3DistanceFilter::try_create(lat, lon, distance, index_resolution)
+            .unwrap()
+            .resolution();
+        let mut index: HashMap<CellIndex, Vec<(f64, f64)>> = HashMap::new();
+        for (point, cell) in points.iter().zip(cells.iter()) {
+            index
+                .entry(cell.parent(resolution).unwrap())
+                .or_default()
+                .push(*point);
+        }
+
+        group.bench_function(format!("h3_index/{distance}"), |b| {
+            b.iter(|| {
+                let filter =
+                    H3DistanceFilter::try_create(lat, lon, distance, index_resolution).unwrap();
+                filter
+                    .cells()
+                    .unwrap()
+                    .iter()
+                    .filter_map(|cell| index.get(cell))
+                    .flatten()
+                    .filter(|(p_lat, p_lon)| {
+                        geography_distance(lat, lon, *p_lat, *p_lon).unwrap() <= distance
+                    })
+                    .count()
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench, bench_trim, bench_geography_within_distance);
 criterion_main!(benches);
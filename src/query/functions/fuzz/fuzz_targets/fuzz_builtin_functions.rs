@@ -0,0 +1,42 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate afl;
+
+use common_expression::SampleKind;
+use common_functions::fuzz::check_function;
+use common_functions::fuzz::fuzzable_functions;
+
+fn main() {
+    let functions = fuzzable_functions();
+    loop {
+        // The input picks the function, the number of rows and the kind of each argument.
+        fuzz!(|data: &[u8]| {
+            if data.len() < 3 {
+                return;
+            }
+            let (id, function) =
+                &functions[u16::from_le_bytes([data[0], data[1]]) as usize % functions.len()];
+            let num_rows = data[2] as usize % 65;
+            let kinds = (0..function.signature.args_type.len())
+                .map(|i| {
+                    let kind = data.get(3 + i).copied().unwrap_or_default() as usize;
+                    SampleKind::ALL[kind % SampleKind::ALL.len()]
+                })
+                .collect::<Vec<_>>();
+            check_function(id, function, num_rows, &kinds);
+        });
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::bitmap::deserialize_bitmap;
+use common_expression::types::bitmap::serialize_bitmap;
+use common_expression::types::BitmapType;
+use common_expression::types::DataType;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use roaring::RoaringBitmap;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+
+#[derive(Default)]
+struct AggregateBitmapState {
+    bitmap: RoaringBitmap,
+}
+
+impl AggregateBitmapState {
+    fn add(&mut self, buf: &[u8]) -> Result<()> {
+        let bitmap = deserialize_bitmap(buf).map_err(ErrorCode::BadArguments)?;
+        self.bitmap |= bitmap;
+        Ok(())
+    }
+}
+
+/// Union of all the input bitmaps.
+#[derive(Clone)]
+pub struct AggregateBitmapUnionFunction {
+    display_name: String,
+}
+
+impl AggregateFunction for AggregateBitmapUnionFunction {
+    fn name(&self) -> &str {
+        "AggregateBitmapUnionFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Bitmap)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateBitmapState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateBitmapState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let column = BitmapType::try_downcast_column(&columns[0]).unwrap();
+        match validity {
+            Some(validity) => {
+                for (buf, valid) in column.iter().zip(validity.iter()) {
+                    if valid {
+                        state.add(buf)?;
+                    }
+                }
+            }
+            None => {
+                for buf in column.iter() {
+                    state.add(buf)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let column = BitmapType::try_downcast_column(&columns[0]).unwrap();
+        state.add(unsafe { column.index_unchecked(row) })
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        serialize_bitmap(&state.bitmap, writer);
+        Ok(())
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        state.bitmap = RoaringBitmap::deserialize_from(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateBitmapState>();
+        let state = place.get::<AggregateBitmapState>();
+        state.bitmap |= &rhs.bitmap;
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let mut buf = vec![];
+        serialize_bitmap(&state.bitmap, &mut buf);
+        builder.push(ScalarRef::Bitmap(&buf));
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateBitmapState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateBitmapUnionFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_bitmap_union_function(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    if arguments[0] != DataType::Bitmap {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} expects an argument of type Bitmap, but got {}",
+            display_name, arguments[0]
+        )));
+    }
+
+    Ok(Arc::new(AggregateBitmapUnionFunction {
+        display_name: display_name.to_owned(),
+    }))
+}
+
+pub fn aggregate_bitmap_union_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_bitmap_union_function))
+}
@@ -158,8 +158,9 @@ impl AggregateFunctionFactory {
         let name = name.as_ref();
         let mut features = AggregateFunctionFeatures::default();
         // The NULL value in the list function needs to be added to the returned array column,
+        // and string_agg/listagg must keep the rows whose sort key is NULL,
         // so handled separately.
-        if name == "list" {
+        if matches!(name, "list" | "string_agg" | "listagg") {
             let agg = self.get_impl(name, params, arguments, &mut features)?;
             return Ok(agg);
         }
@@ -257,6 +258,12 @@ impl AggregateFunctionFactory {
         self.case_insensitive_desc.keys().cloned().collect()
     }
 
+    pub fn get_features(&self, func_name: impl AsRef<str>) -> Option<AggregateFunctionFeatures> {
+        self.case_insensitive_desc
+            .get(&func_name.as_ref().to_lowercase())
+            .map(|desc| desc.features.clone())
+    }
+
     pub fn registered_features(&self) -> Vec<AggregateFunctionFeatures> {
         self.case_insensitive_desc
             .values()
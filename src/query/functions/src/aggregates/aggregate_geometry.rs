@@ -0,0 +1,237 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_io::prelude::*;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::scalars::geo_wkb::check_wkb;
+use crate::scalars::geo_wkb::extend_bounding_box;
+use crate::scalars::geo_wkb::write_bounding_box_polygon;
+use crate::scalars::geo_wkb::write_geometry_collection;
+
+/// Accumulates the WKB encoded input geometries.
+#[derive(Default)]
+struct AggregateGeometryState {
+    geoms: Vec<Vec<u8>>,
+}
+
+impl AggregateGeometryState {
+    fn add(&mut self, wkb: &[u8]) -> Result<()> {
+        check_wkb(wkb).map_err(|e| ErrorCode::BadArguments(format!("Invalid WKB: {}", e)))?;
+        self.geoms.push(wkb.to_vec());
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.geoms.extend(other.geoms.iter().cloned());
+    }
+
+    fn serialize(&self, writer: &mut Vec<u8>) -> Result<()> {
+        serialize_into_buf(writer, &self.geoms)
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.geoms = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum GeometryAggKind {
+    /// Collect all geometries into a GeometryCollection.
+    Collect,
+    /// The bounding box polygon of all geometries.
+    Extent,
+}
+
+#[derive(Clone)]
+pub struct AggregateGeometryFunction {
+    display_name: String,
+    kind: GeometryAggKind,
+}
+
+impl AggregateFunction for AggregateGeometryFunction {
+    fn name(&self) -> &str {
+        "AggregateGeometryFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        match self.kind {
+            GeometryAggKind::Collect => Ok(DataType::String),
+            // Empty geometries have no extent.
+            GeometryAggKind::Extent => Ok(DataType::Nullable(Box::new(DataType::String))),
+        }
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateGeometryState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateGeometryState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateGeometryState>();
+        let column = StringType::try_downcast_column(&columns[0]).unwrap();
+        match validity {
+            Some(validity) => {
+                for (wkb, valid) in column.iter().zip(validity.iter()) {
+                    if valid {
+                        state.add(wkb)?;
+                    }
+                }
+            }
+            None => {
+                for wkb in column.iter() {
+                    state.add(wkb)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<AggregateGeometryState>();
+        let column = StringType::try_downcast_column(&columns[0]).unwrap();
+        state.add(unsafe { column.index_unchecked(row) })
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateGeometryState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateGeometryState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateGeometryState>();
+        let state = place.get::<AggregateGeometryState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateGeometryState>();
+        let mut buf = vec![];
+        match self.kind {
+            GeometryAggKind::Collect => {
+                write_geometry_collection(state.geoms.iter().map(|g| g.as_slice()), &mut buf);
+            }
+            GeometryAggKind::Extent => {
+                let mut bbox = None;
+                for wkb in state.geoms.iter() {
+                    extend_bounding_box(wkb, &mut bbox).map_err(ErrorCode::BadArguments)?;
+                }
+                match bbox {
+                    Some(bbox) => write_bounding_box_polygon(&bbox, &mut buf),
+                    None => {
+                        builder.push(ScalarRef::Null);
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        builder.push(ScalarRef::String(&buf));
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateGeometryState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateGeometryFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateGeometryFunction {
+    fn try_create(
+        display_name: &str,
+        kind: GeometryAggKind,
+        arguments: Vec<DataType>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_unary_arguments(display_name, arguments.len())?;
+
+        if arguments[0] != DataType::String {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "{} expects a WKB encoded geometry of type String, but got {}",
+                display_name, arguments[0]
+            )));
+        }
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_owned(),
+            kind,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_st_collect_function(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    AggregateGeometryFunction::try_create(display_name, GeometryAggKind::Collect, arguments)
+}
+
+pub fn try_create_aggregate_st_extent_function(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    AggregateGeometryFunction::try_create(display_name, GeometryAggKind::Extent, arguments)
+}
+
+pub fn aggregate_st_collect_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_st_collect_function))
+}
+
+pub fn aggregate_st_extent_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_st_extent_function))
+}
@@ -0,0 +1,222 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_number;
+use common_expression::types::number::F64;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_io::prelude::deserialize_from_slice;
+use common_io::prelude::serialize_into_buf;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::assert_unary_arguments;
+use crate::BUILTIN_FUNCTIONS;
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct PercentileDiscState {
+    values: Vec<Scalar>,
+}
+
+/// `percentile_disc(<level>[, <descending>])(<expr>)`, the first value of the sorted
+/// values whose cumulative distribution reaches the level.
+#[derive(Clone)]
+pub struct AggregatePercentileDiscFunction {
+    display_name: String,
+    return_type: DataType,
+    level: f64,
+    descending: bool,
+}
+
+impl AggregatePercentileDiscFunction {
+    /// The position of the result in the values sorted in the order of `WITHIN GROUP`.
+    fn position(&self, len: usize) -> usize {
+        let position = ((self.level * len as f64).ceil() as usize).clamp(1, len) - 1;
+        if self.descending {
+            len - 1 - position
+        } else {
+            position
+        }
+    }
+}
+
+impl AggregateFunction for AggregatePercentileDiscFunction {
+    fn name(&self) -> &str {
+        "AggregatePercentileDiscFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(PercentileDiscState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<PercentileDiscState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<PercentileDiscState>();
+        for row in 0..input_rows {
+            if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+                state.values.push(columns[0].index(row).unwrap().to_owned());
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<PercentileDiscState>();
+        state.values.push(columns[0].index(row).unwrap().to_owned());
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<PercentileDiscState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<PercentileDiscState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<PercentileDiscState>();
+        let state = place.get::<PercentileDiscState>();
+        state.values.extend(rhs.values.iter().cloned());
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<PercentileDiscState>();
+        if state.values.is_empty() {
+            builder.push(ScalarRef::Null);
+            return Ok(());
+        }
+
+        let position = self.position(state.values.len());
+        let (_, value, _) = state.values.select_nth_unstable(position);
+        builder.push(value.as_ref());
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<PercentileDiscState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregatePercentileDiscFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_percentile_disc_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let (level, descending) = match params.as_slice() {
+        [level] => (level, false),
+        [level, Scalar::Boolean(descending)] => (level, *descending),
+        _ => {
+            return Err(ErrorCode::BadArguments(format!(
+                "{} expects a level and an optional sort direction as parameters, but got {:?}",
+                display_name, params
+            )));
+        }
+    };
+    let level: F64 = check_number(
+        None,
+        FunctionContext::default(),
+        &Expr::<usize>::Cast {
+            span: None,
+            is_try: false,
+            expr: Box::new(Expr::Constant {
+                span: None,
+                scalar: level.clone(),
+                data_type: level.as_ref().infer_data_type(),
+            }),
+            dest_type: DataType::Number(NumberDataType::Float64),
+        },
+        &BUILTIN_FUNCTIONS,
+    )?;
+    let level = level.0;
+    if !(0.0..=1.0).contains(&level) {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "level range between [0, 1], got: {:?}",
+            level
+        )));
+    }
+
+    if !matches!(
+        arguments[0].remove_nullable(),
+        DataType::Number(_)
+            | DataType::Decimal(_)
+            | DataType::String
+            | DataType::Date
+            | DataType::Timestamp
+            | DataType::Boolean
+    ) {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, arguments[0]
+        )));
+    }
+
+    Ok(Arc::new(AggregatePercentileDiscFunction {
+        display_name: display_name.to_owned(),
+        return_type: arguments[0].wrap_nullable(),
+        level,
+        descending,
+    }))
+}
+
+pub fn aggregate_percentile_disc_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_percentile_disc_function))
+}
@@ -48,6 +48,7 @@ use crate::BUILTIN_FUNCTIONS;
 
 const MEDIAN: u8 = 0;
 const QUANTILE_CONT: u8 = 1;
+const PERCENTILE_CONT: u8 = 2;
 
 #[derive(Default, Serialize, Deserialize)]
 pub struct QuantileState {
@@ -249,8 +250,9 @@ where T: Number + AsPrimitive<f64>
         return_type: DataType,
         params: Vec<Scalar>,
         arguments: Vec<DataType>,
+        descending: bool,
     ) -> Result<Arc<dyn AggregateFunction>> {
-        let levels = if params.len() == 1 {
+        let mut levels = if params.len() == 1 {
             let level: F64 = check_number(
                 None,
                 FunctionContext::default(),
@@ -305,6 +307,11 @@ where T: Number + AsPrimitive<f64>
             }
             levels
         };
+        // The percentile of the values sorted in descending order is the
+        // complementary percentile of the values sorted in ascending order.
+        if descending {
+            levels.iter_mut().for_each(|level| *level = 1.0 - *level);
+        }
 
         let func = AggregateQuantileContFunction::<T> {
             display_name: display_name.to_string(),
@@ -320,13 +327,23 @@ where T: Number + AsPrimitive<f64>
 
 pub fn try_create_aggregate_quantile_function<const TYPE: u8>(
     display_name: &str,
-    params: Vec<Scalar>,
+    mut params: Vec<Scalar>,
     arguments: Vec<DataType>,
 ) -> Result<AggregateFunctionRef> {
     if TYPE == MEDIAN {
         assert_params(display_name, params.len(), 0)?;
     }
 
+    // `percentile_cont(<level>[, <descending>])`, as `PERCENTILE_CONT ... WITHIN GROUP` is bound.
+    let mut descending = false;
+    if TYPE == PERCENTILE_CONT {
+        if let Some(Scalar::Boolean(desc)) = params.last() {
+            descending = *desc;
+            params.pop();
+        }
+        assert_params(display_name, params.len(), 1)?;
+    }
+
     assert_unary_arguments(display_name, arguments.len())?;
 
     with_number_mapped_type!(|NUM_TYPE| match &arguments[0] {
@@ -341,6 +358,7 @@ pub fn try_create_aggregate_quantile_function<const TYPE: u8>(
                 return_type,
                 params,
                 arguments,
+                descending,
             )
         }
 
@@ -362,3 +380,9 @@ pub fn aggregate_median_function_desc() -> AggregateFunctionDescription {
         try_create_aggregate_quantile_function::<MEDIAN>,
     ))
 }
+
+pub fn aggregate_percentile_cont_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_quantile_function::<PERCENTILE_CONT>,
+    ))
+}
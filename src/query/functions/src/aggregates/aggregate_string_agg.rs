@@ -0,0 +1,316 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_io::prelude::deserialize_from_slice;
+use common_io::prelude::serialize_into_buf;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_variadic_arguments;
+
+/// What LISTAGG does when the aggregated string grows beyond the maximum length.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OverflowMode {
+    /// `ON OVERFLOW ERROR`, raises `ErrorCode::ResultTooLarge`.
+    Error,
+    /// `ON OVERFLOW TRUNCATE 'filler' WITH | WITHOUT COUNT`, keeps the values that fit
+    /// and appends the filler, optionally followed by ` + N more`.
+    Truncate { filler: String, with_count: bool },
+}
+
+/// Parameters shared by `string_agg` and `listagg`.
+///
+/// `string_agg` only carries the delimiter, while `listagg` also carries the
+/// sort direction of `WITHIN GROUP (ORDER BY ...)` and its overflow behavior,
+/// which are captured when the plan is bound.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StringAggParams {
+    pub delimiter: String,
+    pub descending: bool,
+    /// The maximum byte length of the result and what to do when it is exceeded.
+    pub overflow: Option<(usize, OverflowMode)>,
+}
+
+impl StringAggParams {
+    pub fn try_from_params(display_name: &str, params: &[Scalar]) -> Result<Self> {
+        let invalid = || {
+            ErrorCode::BadArguments(format!(
+                "{} got invalid parameters {:?}",
+                display_name, params
+            ))
+        };
+        let string_param = |i: usize| match params.get(i) {
+            Some(Scalar::String(s)) => String::from_utf8(s.clone()).map_err(|_| invalid()),
+            _ => Err(invalid()),
+        };
+        let bool_param = |i: usize| match params.get(i) {
+            Some(Scalar::Boolean(b)) => Ok(*b),
+            _ => Err(invalid()),
+        };
+
+        match params.len() {
+            0 => Ok(Self {
+                delimiter: String::new(),
+                descending: false,
+                overflow: None,
+            }),
+            1 => Ok(Self {
+                delimiter: string_param(0)?,
+                descending: false,
+                overflow: None,
+            }),
+            6 => {
+                let max_length = match params[2] {
+                    Scalar::Number(NumberScalar::UInt64(n)) => n as usize,
+                    _ => return Err(invalid()),
+                };
+                let mode = match string_param(3)?.as_str() {
+                    "error" => OverflowMode::Error,
+                    "truncate" => OverflowMode::Truncate {
+                        filler: string_param(4)?,
+                        with_count: bool_param(5)?,
+                    },
+                    _ => return Err(invalid()),
+                };
+                Ok(Self {
+                    delimiter: string_param(0)?,
+                    descending: bool_param(1)?,
+                    overflow: Some((max_length, mode)),
+                })
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    pub fn into_params(self) -> Vec<Scalar> {
+        let mut params = vec![Scalar::String(self.delimiter.into_bytes())];
+        if let Some((max_length, mode)) = self.overflow {
+            params.push(Scalar::Boolean(self.descending));
+            params.push(Scalar::Number(NumberScalar::UInt64(max_length as u64)));
+            match mode {
+                OverflowMode::Error => {
+                    params.push(Scalar::String(b"error".to_vec()));
+                    params.push(Scalar::String(vec![]));
+                    params.push(Scalar::Boolean(false));
+                }
+                OverflowMode::Truncate { filler, with_count } => {
+                    params.push(Scalar::String(b"truncate".to_vec()));
+                    params.push(Scalar::String(filler.into_bytes()));
+                    params.push(Scalar::Boolean(with_count));
+                }
+            }
+        }
+        params
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct StringAggState {
+    // Each value is paired with its `WITHIN GROUP (ORDER BY ...)` key, if any.
+    values: Vec<(Vec<u8>, Option<Scalar>)>,
+}
+
+impl StringAggState {
+    fn add(&mut self, columns: &[Column], row: usize) {
+        let value = match columns[0].index(row) {
+            Some(ScalarRef::String(s)) => s.to_vec(),
+            // NULL values are ignored.
+            _ => return,
+        };
+        let key = columns.get(1).map(|c| c.index(row).unwrap().to_owned());
+        self.values.push((value, key));
+    }
+}
+
+/// NULL keys sort after all other keys, so they come last in ascending order
+/// and first in descending order.
+fn compare_keys(l: &Option<Scalar>, r: &Option<Scalar>) -> Ordering {
+    match (l, r) {
+        (Some(Scalar::Null), Some(Scalar::Null)) => Ordering::Equal,
+        (Some(Scalar::Null), _) => Ordering::Greater,
+        (_, Some(Scalar::Null)) => Ordering::Less,
+        (l, r) => l.cmp(r),
+    }
+}
+
+/// The implementation behind both `string_agg` and `listagg`.
+#[derive(Clone)]
+pub struct AggregateStringAggFunction {
+    display_name: String,
+    params: StringAggParams,
+}
+
+impl AggregateFunction for AggregateStringAggFunction {
+    fn name(&self) -> &str {
+        "AggregateStringAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Nullable(Box::new(DataType::String)))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(StringAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<StringAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<StringAggState>();
+        for row in 0..input_rows {
+            if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+                state.add(columns, row);
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<StringAggState>();
+        state.add(columns, row);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<StringAggState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<StringAggState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<StringAggState>();
+        let state = place.get::<StringAggState>();
+        state.values.extend(rhs.values.iter().cloned());
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<StringAggState>();
+        if state.values.is_empty() {
+            builder.push(ScalarRef::Null);
+            return Ok(());
+        }
+
+        if self.params.descending {
+            state.values.sort_by(|l, r| compare_keys(&r.1, &l.1));
+        } else {
+            state.values.sort_by(|l, r| compare_keys(&l.1, &r.1));
+        }
+
+        let delimiter = self.params.delimiter.as_bytes();
+        let mut result = Vec::new();
+        for (i, (value, _)) in state.values.iter().enumerate() {
+            let delimiter_len = if i > 0 { delimiter.len() } else { 0 };
+            if let Some((max_length, mode)) = &self.params.overflow {
+                if result.len() + delimiter_len + value.len() > *max_length {
+                    match mode {
+                        OverflowMode::Error => {
+                            return Err(ErrorCode::ResultTooLarge(format!(
+                                "the result of {} exceeds the maximum length of {} bytes",
+                                self.display_name, max_length
+                            )));
+                        }
+                        OverflowMode::Truncate { filler, with_count } => {
+                            result.extend_from_slice(&delimiter[..delimiter_len]);
+                            result.extend_from_slice(filler.as_bytes());
+                            if *with_count {
+                                let remaining = state.values.len() - i;
+                                result.extend_from_slice(format!(" + {remaining} more").as_bytes());
+                            }
+                            break;
+                        }
+                    }
+                }
+            }
+            result.extend_from_slice(&delimiter[..delimiter_len]);
+            result.extend_from_slice(value);
+        }
+
+        builder.push(ScalarRef::String(&result));
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<StringAggState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateStringAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_string_agg_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_variadic_arguments(display_name, arguments.len(), (1, 2))?;
+
+    if !matches!(
+        arguments[0].remove_nullable(),
+        DataType::String | DataType::Null
+    ) {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} expects an argument of type String, but got {}",
+            display_name, arguments[0]
+        )));
+    }
+
+    Ok(Arc::new(AggregateStringAggFunction {
+        display_name: display_name.to_owned(),
+        params: StringAggParams::try_from_params(display_name, &params)?,
+    }))
+}
+
+pub fn aggregate_string_agg_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_string_agg_function))
+}
@@ -16,10 +16,13 @@ use super::aggregate_approx_count_distinct::aggregate_approx_count_distinct_func
 use super::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use super::aggregate_avg::aggregate_avg_function_desc;
+use super::aggregate_bitmap::aggregate_bitmap_union_function_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_distinct_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_uniq_desc;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
 use super::aggregate_covariance::aggregate_covariance_sample_desc;
+use super::aggregate_geometry::aggregate_st_collect_function_desc;
+use super::aggregate_geometry::aggregate_st_extent_function_desc;
 use super::aggregate_min_max_any::aggregate_any_function_desc;
 use super::aggregate_min_max_any::aggregate_max_function_desc;
 use super::aggregate_min_max_any::aggregate_min_function_desc;
@@ -30,9 +33,12 @@ use super::AggregateCountFunction;
 use super::AggregateFunctionFactory;
 use super::AggregateIfCombinator;
 use crate::aggregates::aggregate_list::aggregate_list_function_desc;
+use crate::aggregates::aggregate_percentile_disc::aggregate_percentile_disc_function_desc;
 use crate::aggregates::aggregate_quantile_cont::aggregate_median_function_desc;
+use crate::aggregates::aggregate_quantile_cont::aggregate_percentile_cont_function_desc;
 use crate::aggregates::aggregate_quantile_cont::aggregate_quantile_function_desc;
 use crate::aggregates::aggregate_retention::aggregate_retention_function_desc;
+use crate::aggregates::aggregate_string_agg::aggregate_string_agg_function_desc;
 use crate::aggregates::aggregate_sum::aggregate_sum_function_desc;
 
 pub struct Aggregators;
@@ -59,6 +65,8 @@ impl Aggregators {
         factory.register("std", aggregate_stddev_pop_function_desc());
         factory.register("quantile_cont", aggregate_quantile_function_desc());
         factory.register("median", aggregate_median_function_desc());
+        factory.register("percentile_cont", aggregate_percentile_cont_function_desc());
+        factory.register("percentile_disc", aggregate_percentile_disc_function_desc());
         factory.register("window_funnel", aggregate_window_funnel_function_desc());
         factory.register(
             "approx_count_distinct",
@@ -66,6 +74,13 @@ impl Aggregators {
         );
         factory.register("retention", aggregate_retention_function_desc());
         factory.register("list", aggregate_list_function_desc());
+        factory.register("string_agg", aggregate_string_agg_function_desc());
+        factory.register("listagg", aggregate_string_agg_function_desc());
+
+        factory.register("st_collect", aggregate_st_collect_function_desc());
+        factory.register("st_extent", aggregate_st_extent_function_desc());
+
+        factory.register("bitmap_union", aggregate_bitmap_union_function_desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
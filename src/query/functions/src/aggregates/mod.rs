@@ -22,17 +22,21 @@ mod adaptors;
 mod aggregate_approx_count_distinct;
 mod aggregate_arg_min_max;
 mod aggregate_avg;
+mod aggregate_bitmap;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
 mod aggregate_covariance;
 mod aggregate_distinct_state;
+mod aggregate_geometry;
 mod aggregate_list;
 mod aggregate_min_max_any;
 mod aggregate_null_result;
+mod aggregate_percentile_disc;
 mod aggregate_quantile_cont;
 mod aggregate_retention;
 mod aggregate_scalar_state;
 mod aggregate_stddev;
+mod aggregate_string_agg;
 mod aggregate_sum;
 mod aggregate_window_funnel;
 mod aggregator;
@@ -41,6 +45,7 @@ mod aggregator_common;
 pub use adaptors::*;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
 pub use aggregate_avg::AggregateAvgFunction;
+pub use aggregate_bitmap::AggregateBitmapUnionFunction;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
 pub use aggregate_count::AggregateCountFunction;
@@ -51,11 +56,16 @@ pub use aggregate_function_factory::AggregateFunctionFactory;
 pub use aggregate_function_state::get_layout_offsets;
 pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
+pub use aggregate_geometry::AggregateGeometryFunction;
 pub use aggregate_list::AggregateListFunction;
 pub use aggregate_min_max_any::AggregateMinMaxAnyFunction;
 pub use aggregate_null_result::AggregateNullResultFunction;
+pub use aggregate_percentile_disc::AggregatePercentileDiscFunction;
 pub use aggregate_quantile_cont::AggregateQuantileContFunction;
 pub use aggregate_retention::AggregateRetentionFunction;
+pub use aggregate_string_agg::AggregateStringAggFunction;
+pub use aggregate_string_agg::OverflowMode;
+pub use aggregate_string_agg::StringAggParams;
 pub use aggregate_sum::AggregateSumFunction;
 pub use aggregator::Aggregators;
 pub use aggregator_common::*;
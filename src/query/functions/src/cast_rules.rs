@@ -52,6 +52,15 @@ pub fn register(registry: &mut FunctionRegistry) {
         registry.register_additional_cast_rules(func_name, CAST_FROM_VARIANT_RULES());
     }
 
+    for func_name in ["concat", "concat_ws", "array_to_string"] {
+        // Allow arguments of any type to be casted to string, e.g., `concat_ws('-', 42, true)`
+        // or `array_to_string([1, 2], ',')`.
+        registry.register_additional_cast_rules(func_name, GENERAL_CAST_RULES.iter().cloned());
+        registry.register_additional_cast_rules(func_name, CAST_FROM_STRING_RULES.iter().cloned());
+        registry.register_additional_cast_rules(func_name, CAST_FROM_VARIANT_RULES());
+        registry.register_additional_cast_rules(func_name, CAST_TO_STRING_RULES());
+    }
+
     // Timestamp/Date --> other ints and floats
     // Now it only overload 'to_int64'
     for data_type in ALL_NUMERICS_TYPES
@@ -218,6 +227,23 @@ pub const CAST_FROM_STRING_RULES: AutoCastRules = &[
     (DataType::String, DataType::Number(NumberDataType::Float64)),
 ];
 
+/// The rules for automatic casting from other types to string. They are only enabled for
+/// string concatenation functions, e.g., `concat('a', 1)`.
+#[allow(non_snake_case)]
+pub fn CAST_TO_STRING_RULES() -> impl IntoIterator<Item = (DataType, DataType)> {
+    ALL_NUMERICS_TYPES
+        .iter()
+        .map(|ty| DataType::Number(*ty))
+        .chain([
+            DataType::Boolean,
+            DataType::Date,
+            DataType::Timestamp,
+            DataType::Interval,
+            DataType::Variant,
+        ])
+        .map(|ty| (ty, DataType::String))
+}
+
 #[allow(non_snake_case)]
 pub fn CAST_FROM_VARIANT_RULES() -> impl IntoIterator<Item = (DataType, DataType)> {
     [
@@ -277,6 +303,10 @@ pub fn CAST_FROM_VARIANT_RULES() -> impl IntoIterator<Item = (DataType, DataType
             DataType::Variant,
             DataType::Nullable(Box::new(DataType::Number(NumberDataType::Float64))),
         ),
+        (
+            DataType::Variant,
+            DataType::Nullable(Box::new(DataType::Array(Box::new(DataType::Variant)))),
+        ),
         (
             DataType::Nullable(Box::new(DataType::Variant)),
             DataType::Nullable(Box::new(DataType::Boolean)),
@@ -333,5 +363,9 @@ pub fn CAST_FROM_VARIANT_RULES() -> impl IntoIterator<Item = (DataType, DataType
             DataType::Nullable(Box::new(DataType::Variant)),
             DataType::Nullable(Box::new(DataType::Number(NumberDataType::Float64))),
         ),
+        (
+            DataType::Nullable(Box::new(DataType::Variant)),
+            DataType::Nullable(Box::new(DataType::Array(Box::new(DataType::Variant)))),
+        ),
     ]
 }
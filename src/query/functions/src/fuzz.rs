@@ -0,0 +1,198 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exercises the scalar functions of [`BUILTIN_FUNCTIONS`] with arbitrary arguments,
+//! shared by the fuzz test and the fuzz target.
+
+use std::panic::catch_unwind;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::Function;
+use common_expression::FunctionContext;
+use common_expression::FunctionID;
+use common_expression::SampleKind;
+use common_expression::Value;
+use itertools::Itertools;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+use crate::BUILTIN_FUNCTIONS;
+
+/// Functions that are not fuzzed.
+pub const FUZZ_DENYLIST: &[&str] = &[
+    // Blocks the thread for the given seconds.
+    "sleep",
+    // The size of the output is given by an argument, a random one allocates way too much.
+    "space", "lpad", "rpad",
+];
+
+/// The maximum number of rows of the sample blocks.
+const MAX_FUZZ_ROWS: usize = 64;
+
+/// All the scalar overloads of [`BUILTIN_FUNCTIONS`] with concrete argument types,
+/// except those in [`FUZZ_DENYLIST`].
+pub fn fuzzable_functions() -> Vec<(FunctionID, Arc<Function>)> {
+    BUILTIN_FUNCTIONS
+        .registered_functions()
+        .into_iter()
+        .filter(|(id, function)| {
+            function.eval.is_scalar()
+                && !FUZZ_DENYLIST.contains(&id.name().as_str())
+                && !function
+                    .signature
+                    .args_type
+                    .iter()
+                    .any(|ty| ty.has_generic())
+        })
+        .collect()
+}
+
+/// Evaluates every fuzzable function `rounds` times with random arguments of each
+/// [`SampleKind`], including empty and single-row blocks.
+///
+/// Returns the description of every failed evaluation.
+pub fn fuzz_builtin_functions(rounds: usize) -> Vec<String> {
+    let mut rng = SmallRng::from_entropy();
+    let mut failures = Vec::new();
+    for _ in 0..rounds {
+        for (id, function) in fuzzable_functions() {
+            let num_args = function.signature.args_type.len();
+            let mut cases = SampleKind::ALL
+                .iter()
+                .map(|kind| vec![*kind; num_args])
+                .collect::<Vec<_>>();
+            cases.push(
+                (0..num_args)
+                    .map(|_| SampleKind::ALL[rng.gen_range(0..SampleKind::ALL.len())])
+                    .collect(),
+            );
+
+            for kinds in cases {
+                for num_rows in [0, 1, rng.gen_range(2..=MAX_FUZZ_ROWS)] {
+                    let result = catch_unwind(AssertUnwindSafe(|| {
+                        check_function(&id, &function, num_rows, &kinds)
+                    }));
+                    if let Err(cause) = result {
+                        let cause = cause
+                            .downcast_ref::<String>()
+                            .cloned()
+                            .or_else(|| cause.downcast_ref::<&str>().map(|s| s.to_string()))
+                            .unwrap_or_default();
+                        failures.push(format!(
+                            "{}({}) with {:?} arguments of {} rows: {}",
+                            id.name(),
+                            function.signature.args_type.iter().join(", "),
+                            kinds,
+                            num_rows,
+                            cause
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    failures
+}
+
+/// Evaluates the function on a block of `num_rows` rows whose columns are sampled with
+/// `kinds`, and panics if the function misbehaves:
+///
+/// - the evaluation panics,
+/// - the output column doesn't have `num_rows` rows,
+/// - evaluating on a slice of the block is not the same as slicing the full evaluation.
+///
+/// Errors are fine, arbitrary arguments are usually invalid.
+pub fn check_function(
+    id: &FunctionID,
+    function: &Arc<Function>,
+    num_rows: usize,
+    kinds: &[SampleKind],
+) {
+    let args_type = &function.signature.args_type;
+    let entries = args_type
+        .iter()
+        .zip(kinds)
+        .map(|(ty, kind)| {
+            Some(BlockEntry {
+                data_type: ty.clone(),
+                value: Value::sample(ty, num_rows, *kind)?,
+            })
+        })
+        .collect::<Option<Vec<_>>>();
+    let block = match entries {
+        Some(entries) => DataBlock::new(entries, num_rows),
+        None => return,
+    };
+
+    let expr = Expr::FunctionCall {
+        span: None,
+        id: id.clone(),
+        function: function.clone(),
+        generics: vec![],
+        args: args_type
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| Expr::ColumnRef {
+                span: None,
+                id: i,
+                data_type: ty.clone(),
+                display_name: format!("arg{i}"),
+            })
+            .collect(),
+        return_type: function.signature.return_type.clone(),
+    };
+
+    let func_ctx = FunctionContext::default();
+    let full = match Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS).run(&expr) {
+        Ok(full) => full,
+        Err(_) => return,
+    };
+    if let Value::Column(column) = &full {
+        assert_eq!(column.len(), num_rows, "output has wrong number of rows");
+    }
+
+    let non_deterministic = BUILTIN_FUNCTIONS
+        .get_property(id.name())
+        .map(|property| property.non_deterministic)
+        .unwrap_or_default();
+    if num_rows < 2 || non_deterministic {
+        return;
+    }
+
+    let mut rng = SmallRng::from_entropy();
+    let start = rng.gen_range(0..num_rows);
+    let end = rng.gen_range(start + 1..=num_rows);
+    let sliced = Evaluator::new(&block.slice(start..end), func_ctx, &BUILTIN_FUNCTIONS)
+        .run(&expr)
+        .unwrap_or_else(|err| {
+            panic!(
+                "evaluation on rows {start}..{end} fails while the full evaluation succeeds: {}",
+                err.message()
+            )
+        });
+    let expected = match full {
+        Value::Column(column) => Value::Column(column.slice(start..end)),
+        scalar => scalar,
+    };
+    assert!(
+        expected.as_ref().semantically_eq(&sliced.as_ref()),
+        "evaluation on rows {start}..{end} is {sliced}, but the slice of the full evaluation is {expected}"
+    );
+}
@@ -23,11 +23,24 @@ use ctor::ctor;
 
 pub mod aggregates;
 mod cast_rules;
+pub mod fuzz;
 pub mod scalars;
 pub mod srfs;
+pub mod windows;
+
+/// Functions that take a lambda expression as the last argument,
+/// they are resolved by the binder instead of the function registry.
+pub const GENERAL_LAMBDA_FUNCTIONS: [&str; 3] = ["array_map", "array_filter", "array_reduce"];
+
+/// Window functions that are not aggregate functions, they can only be
+/// called with an `OVER` clause.
+pub const GENERAL_WINDOW_FUNCTIONS: [&str; 3] = ["lag", "lead", "nth_value"];
 
 pub fn is_builtin_function(name: &str) -> bool {
-    BUILTIN_FUNCTIONS.contains(name) || AggregateFunctionFactory::instance().contains(name)
+    BUILTIN_FUNCTIONS.contains(name)
+        || AggregateFunctionFactory::instance().contains(name)
+        || GENERAL_LAMBDA_FUNCTIONS.contains(&name)
+        || GENERAL_WINDOW_FUNCTIONS.contains(&name)
 }
 
 #[ctor]
@@ -19,6 +19,7 @@ use std::sync::Arc;
 use common_expression::types::array::ArrayColumnBuilder;
 use common_expression::types::boolean::BooleanDomain;
 use common_expression::types::nullable::NullableDomain;
+use common_expression::types::number::Int64Type;
 use common_expression::types::number::NumberScalar;
 use common_expression::types::number::SimpleDomain;
 use common_expression::types::number::UInt64Type;
@@ -40,6 +41,7 @@ use common_expression::types::ValueType;
 use common_expression::types::ALL_NUMERICS_TYPES;
 use common_expression::vectorize_1_arg;
 use common_expression::vectorize_2_arg;
+use common_expression::vectorize_3_arg;
 use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::vectorize_with_builder_3_arg;
@@ -629,6 +631,116 @@ pub fn register(registry: &mut FunctionRegistry) {
             }
         }),
     );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
+        "array_compact",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<EmptyArrayType, EmptyArrayType>(|arr, _| arr),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<NullType>, EmptyArrayType, _, _>(
+        "array_compact",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<ArrayType<NullType>, EmptyArrayType>(|_, output, _| {
+            *output += 1;
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<NullableType<GenericType<0>>>, ArrayType<GenericType<0>>, _, _>(
+        "array_compact",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<ArrayType<NullableType<GenericType<0>>>, ArrayType<GenericType<0>>>(
+            |arr, output, _| {
+                for item in arr.iter().flatten() {
+                    output.put_item(item);
+                }
+                output.commit_row();
+            }
+        ),
+    );
+
+    // The element to search may be NULL, so only the NULL array is passed through as NULL.
+    registry.register_2_arg_core::<ArrayType<GenericType<0>>, GenericType<0>, ArrayType<GenericType<0>>, _, _>(
+        "array_remove",
+        |domain, _| FunctionDomain::Domain(domain.clone()),
+        vectorize_2_arg::<ArrayType<GenericType<0>>, GenericType<0>, ArrayType<GenericType<0>>>(
+            |arr, val, _| array_remove_items(arr, val)
+        ),
+    );
+
+    registry.register_2_arg_core::<NullableType<ArrayType<GenericType<0>>>, GenericType<0>, NullableType<ArrayType<GenericType<0>>>, _, _>(
+        "array_remove",
+        |domain, _| FunctionDomain::Domain(domain.clone()),
+        vectorize_2_arg::<NullableType<ArrayType<GenericType<0>>>, GenericType<0>, NullableType<ArrayType<GenericType<0>>>>(
+            |arr, val, _| arr.map(|arr| array_remove_items(arr, val))
+        ),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<EmptyArrayType, Int64Type, EmptyArrayType, _, _>(
+            "array_remove_at",
+            |_, _| FunctionDomain::Full,
+            vectorize_with_builder_2_arg::<EmptyArrayType, Int64Type, EmptyArrayType>(
+                |_, _, output, _| {
+                    *output += 1;
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<ArrayType<GenericType<0>>, Int64Type, ArrayType<GenericType<0>>, _, _>(
+        "array_remove_at",
+        |domain, _| FunctionDomain::Domain(domain.clone()),
+        vectorize_2_arg::<ArrayType<GenericType<0>>, Int64Type, ArrayType<GenericType<0>>>(
+            |arr, index, _| match array_offset(arr.len(), index) {
+                Some(offset) => {
+                    let data_type = arr.data_type();
+                    let mut builder = ColumnBuilder::with_capacity(&data_type, arr.len() - 1);
+                    for (i, item) in arr.iter().enumerate() {
+                        if i != offset {
+                            builder.push(item);
+                        }
+                    }
+                    builder.build()
+                }
+                None => arr,
+            }
+        ),
+    );
+
+    registry.register_3_arg_core::<ArrayType<GenericType<0>>, GenericType<0>, GenericType<0>, ArrayType<GenericType<0>>, _, _>(
+        "array_replace",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_3_arg::<ArrayType<GenericType<0>>, GenericType<0>, GenericType<0>, ArrayType<GenericType<0>>>(
+            |arr, search, replacement, _| array_replace_items(arr, search, replacement)
+        ),
+    );
+
+    registry.register_3_arg_core::<NullableType<ArrayType<GenericType<0>>>, GenericType<0>, GenericType<0>, NullableType<ArrayType<GenericType<0>>>, _, _>(
+        "array_replace",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_3_arg::<NullableType<ArrayType<GenericType<0>>>, GenericType<0>, GenericType<0>, NullableType<ArrayType<GenericType<0>>>>(
+            |arr, search, replacement, _| arr.map(|arr| array_replace_items(arr, search, replacement))
+        ),
+    );
+
+    registry.register_3_arg_core::<ArrayType<GenericType<0>>, Int64Type, GenericType<0>, ArrayType<GenericType<0>>, _, _>(
+        "array_replace_at",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_3_arg::<ArrayType<GenericType<0>>, Int64Type, GenericType<0>, ArrayType<GenericType<0>>>(
+            |arr, index, replacement, _| array_replace_at(arr, index, replacement)
+        ),
+    );
+
+    registry.register_3_arg_core::<NullableType<ArrayType<GenericType<0>>>, NullableType<Int64Type>, GenericType<0>, NullableType<ArrayType<GenericType<0>>>, _, _>(
+        "array_replace_at",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_3_arg::<NullableType<ArrayType<GenericType<0>>>, NullableType<Int64Type>, GenericType<0>, NullableType<ArrayType<GenericType<0>>>>(
+            |arr, index, replacement, _| match (arr, index) {
+                (Some(arr), Some(index)) => Some(array_replace_at(arr, index, replacement)),
+                _ => None,
+            }
+        ),
+    );
 }
 
 fn register_array_aggr(registry: &mut FunctionRegistry) {
@@ -750,3 +862,59 @@ fn register_array_aggr(registry: &mut FunctionRegistry) {
         );
     }
 }
+
+/// Converts the 1-based `index` into the offset of an array with `len` elements,
+/// a negative `index` counts from the end. Returns `None` if it is out of bounds.
+fn array_offset(len: usize, index: i64) -> Option<usize> {
+    let abs = index.unsigned_abs() as usize;
+    if index == 0 || abs > len {
+        None
+    } else if index > 0 {
+        Some(abs - 1)
+    } else {
+        Some(len - abs)
+    }
+}
+
+/// Removes the elements equal to `val`, a NULL `val` removes the NULL elements.
+fn array_remove_items(arr: Column, val: ScalarRef) -> Column {
+    let data_type = arr.data_type();
+    let mut builder = ColumnBuilder::with_capacity(&data_type, arr.len());
+    for item in arr.iter() {
+        if item != val {
+            builder.push(item);
+        }
+    }
+    builder.build()
+}
+
+fn array_replace_items(arr: Column, search: ScalarRef, replacement: ScalarRef) -> Column {
+    let data_type = arr.data_type();
+    let mut builder = ColumnBuilder::with_capacity(&data_type, arr.len());
+    for item in arr.iter() {
+        if item == search {
+            builder.push(replacement.clone());
+        } else {
+            builder.push(item);
+        }
+    }
+    builder.build()
+}
+
+fn array_replace_at(arr: Column, index: i64, replacement: ScalarRef) -> Column {
+    match array_offset(arr.len(), index) {
+        Some(offset) => {
+            let data_type = arr.data_type();
+            let mut builder = ColumnBuilder::with_capacity(&data_type, arr.len());
+            for (i, item) in arr.iter().enumerate() {
+                if i == offset {
+                    builder.push(replacement.clone());
+                } else {
+                    builder.push(item);
+                }
+            }
+            builder.build()
+        }
+        None => arr,
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_expression::types::BinaryType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("hex_decode_binary", &["from_hex"]);
+
+    // `String` and `Binary` share the same layout, so casting between them is a no-op on
+    // the underlying bytes.
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "to_binary",
+        |_| FunctionDomain::Full,
+        eval_string_to_binary,
+    );
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_to_binary",
+        |_| FunctionDomain::Full,
+        eval_string_to_binary,
+    );
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::Full,
+        eval_binary_to_string,
+    );
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "try_to_string",
+        |_| FunctionDomain::Full,
+        eval_binary_to_string,
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "hex",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            let old_len = output.data.len();
+            output.data.resize(old_len + val.len() * 2, 0);
+            hex::encode_to_slice(val, &mut output.data[old_len..]).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "hex_decode_binary",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, ctx| {
+            match hex::decode(val) {
+                Ok(bytes) => output.put_slice(&bytes),
+                Err(err) => ctx.set_error(output.len(), err.to_string()),
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_base64",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            base64::write::EncoderWriter::new(&mut output.data, &general_purpose::STANDARD)
+                .write_all(val)
+                .unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "base64_decode_binary",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, ctx| {
+            if let Err(err) = general_purpose::STANDARD.decode_vec(val, &mut output.data) {
+                ctx.set_error(output.len(), err.to_string());
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_1_arg::<BinaryType, UInt64Type, _, _>(
+        "length",
+        |_| FunctionDomain::Full,
+        |val, _| val.len() as u64,
+    );
+}
+
+fn eval_string_to_binary(val: ValueRef<StringType>, _: &mut EvalContext) -> Value<BinaryType> {
+    match val {
+        ValueRef::Scalar(val) => Value::Scalar(val.to_vec()),
+        ValueRef::Column(col) => Value::Column(col),
+    }
+}
+
+fn eval_binary_to_string(val: ValueRef<BinaryType>, _: &mut EvalContext) -> Value<StringType> {
+    match val {
+        ValueRef::Scalar(val) => Value::Scalar(val.to_vec()),
+        ValueRef::Column(col) => Value::Column(col),
+    }
+}
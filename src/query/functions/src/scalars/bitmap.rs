@@ -0,0 +1,129 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::bitmap::deserialize_bitmap;
+use common_expression::types::bitmap::serialize_bitmap;
+use common_expression::types::ArrayType;
+use common_expression::types::BitmapType;
+use common_expression::types::BooleanType;
+use common_expression::types::UInt32Type;
+use common_expression::types::UInt64Type;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use roaring::RoaringBitmap;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<ArrayType<UInt32Type>, BitmapType, _, _>(
+        "to_bitmap",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<ArrayType<UInt32Type>, BitmapType>(|arr, builder, _| {
+            let bitmap = arr.iter().copied().collect::<RoaringBitmap>();
+            serialize_bitmap(&bitmap, &mut builder.data);
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BitmapType, UInt64Type, _, _>(
+        "bitmap_count",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BitmapType, UInt64Type>(|b, builder, ctx| {
+            match deserialize_bitmap(b) {
+                Ok(bitmap) => builder.push(bitmap.len()),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(0);
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<BitmapType, UInt32Type, BooleanType, _, _>(
+        "bitmap_contains",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BitmapType, UInt32Type, BooleanType>(
+            |b, n, builder, ctx| match deserialize_bitmap(b) {
+                Ok(bitmap) => builder.push(bitmap.contains(n)),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BitmapType, ArrayType<UInt32Type>, _, _>(
+        "bitmap_to_array",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BitmapType, ArrayType<UInt32Type>>(|b, builder, ctx| {
+            match deserialize_bitmap(b) {
+                Ok(bitmap) => {
+                    for n in bitmap.iter() {
+                        builder.put_item(n);
+                    }
+                }
+                Err(e) => ctx.set_error(builder.len(), e),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    register_bitmap_binary_op(registry, "bitmap_and", |a, b| *a &= b);
+    register_bitmap_binary_op(registry, "bitmap_or", |a, b| *a |= b);
+    register_bitmap_binary_op(registry, "bitmap_xor", |a, b| *a ^= b);
+
+    registry.register_passthrough_nullable_2_arg::<BitmapType, UInt32Type, BitmapType, _, _>(
+        "bitmap_not",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BitmapType, UInt32Type, BitmapType>(
+            |b, universe_size, builder, ctx| {
+                match deserialize_bitmap(b) {
+                    Ok(bitmap) => {
+                        // The complement within `[0, universe_size)`.
+                        let mut universe = RoaringBitmap::new();
+                        universe.insert_range(0..universe_size);
+                        universe -= bitmap;
+                        serialize_bitmap(&universe, &mut builder.data);
+                    }
+                    Err(e) => ctx.set_error(builder.len(), e),
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+}
+
+fn register_bitmap_binary_op(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    op: fn(&mut RoaringBitmap, &RoaringBitmap),
+) {
+    registry.register_passthrough_nullable_2_arg::<BitmapType, BitmapType, BitmapType, _, _>(
+        name,
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BitmapType, BitmapType, BitmapType>(
+            move |a, b, builder, ctx| {
+                match (deserialize_bitmap(a), deserialize_bitmap(b)) {
+                    (Ok(mut a), Ok(b)) => {
+                        op(&mut a, &b);
+                        serialize_bitmap(&a, &mut builder.data);
+                    }
+                    (Err(e), _) | (_, Err(e)) => ctx.set_error(builder.len(), e),
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+}
@@ -18,14 +18,19 @@ use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_expression::types::boolean::BooleanDomain;
+use common_expression::types::variant::compare_variant;
 use common_expression::types::AnyType;
 use common_expression::types::ArgType;
 use common_expression::types::ArrayType;
+use common_expression::types::BinaryType;
 use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
 use common_expression::types::EmptyArrayType;
 use common_expression::types::GenericType;
+use common_expression::types::IntervalType;
+use common_expression::types::NullType;
+use common_expression::types::NullableType;
 use common_expression::types::NumberClass;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -34,6 +39,7 @@ use common_expression::types::ValueType;
 use common_expression::types::VariantType;
 use common_expression::types::ALL_NUMBER_CLASSES;
 use common_expression::values::Value;
+use common_expression::vectorize_2_arg;
 use common_expression::with_number_mapped_type;
 use common_expression::Column;
 use common_expression::EvalContext;
@@ -54,16 +60,29 @@ use crate::scalars::string_multi_args::regexp;
 pub fn register(registry: &mut FunctionRegistry) {
     register_variant_cmp(registry);
     register_string_cmp(registry);
+    register_binary_cmp(registry);
     register_date_cmp(registry);
     register_timestamp_cmp(registry);
+    register_interval_cmp(registry);
     register_number_cmp(registry);
     register_boolean_cmp(registry);
     register_array_cmp(registry);
     register_tuple_cmp(registry);
+    register_null_safe_cmp(registry);
     register_like(registry);
 }
 
-pub const ALL_COMP_FUNC_NAMES: &[&str] = &["eq", "noteq", "lt", "lte", "gt", "gte", "contains"];
+pub const ALL_COMP_FUNC_NAMES: &[&str] = &[
+    "eq",
+    "noteq",
+    "lt",
+    "lte",
+    "gt",
+    "gte",
+    "contains",
+    "is_not_distinct_from",
+    "is_distinct_from",
+];
 
 const ALL_TRUE_DOMAIN: BooleanDomain = BooleanDomain {
     has_true: true,
@@ -79,44 +98,32 @@ fn register_variant_cmp(registry: &mut FunctionRegistry) {
     registry.register_2_arg::<VariantType, VariantType, BooleanType, _, _>(
         "eq",
         |_, _| FunctionDomain::Full,
-        |lhs, rhs, _| {
-            jsonb::compare(lhs, rhs).expect("unable to parse jsonb value") == Ordering::Equal
-        },
+        |lhs, rhs, _| compare_variant(lhs, rhs) == Ordering::Equal,
     );
     registry.register_2_arg::<VariantType, VariantType, BooleanType, _, _>(
         "noteq",
         |_, _| FunctionDomain::Full,
-        |lhs, rhs, _| {
-            jsonb::compare(lhs, rhs).expect("unable to parse jsonb value") != Ordering::Equal
-        },
+        |lhs, rhs, _| compare_variant(lhs, rhs) != Ordering::Equal,
     );
     registry.register_2_arg::<VariantType, VariantType, BooleanType, _, _>(
         "gt",
         |_, _| FunctionDomain::Full,
-        |lhs, rhs, _| {
-            jsonb::compare(lhs, rhs).expect("unable to parse jsonb value") == Ordering::Greater
-        },
+        |lhs, rhs, _| compare_variant(lhs, rhs) == Ordering::Greater,
     );
     registry.register_2_arg::<VariantType, VariantType, BooleanType, _, _>(
         "gte",
         |_, _| FunctionDomain::Full,
-        |lhs, rhs, _| {
-            jsonb::compare(lhs, rhs).expect("unable to parse jsonb value") != Ordering::Less
-        },
+        |lhs, rhs, _| compare_variant(lhs, rhs) != Ordering::Less,
     );
     registry.register_2_arg::<VariantType, VariantType, BooleanType, _, _>(
         "lt",
         |_, _| FunctionDomain::Full,
-        |lhs, rhs, _| {
-            jsonb::compare(lhs, rhs).expect("unable to parse jsonb value") == Ordering::Less
-        },
+        |lhs, rhs, _| compare_variant(lhs, rhs) == Ordering::Less,
     );
     registry.register_2_arg::<VariantType, VariantType, BooleanType, _, _>(
         "lte",
         |_, _| FunctionDomain::Full,
-        |lhs, rhs, _| {
-            jsonb::compare(lhs, rhs).expect("unable to parse jsonb value") != Ordering::Greater
-        },
+        |lhs, rhs, _| compare_variant(lhs, rhs) != Ordering::Greater,
     );
 }
 
@@ -155,6 +162,40 @@ macro_rules! register_simple_domain_type_cmp {
     };
 }
 
+fn register_binary_cmp(registry: &mut FunctionRegistry) {
+    // `&[u8]` compares lexicographically, i.e. byte-wise.
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "eq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "noteq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
 fn register_string_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, StringType);
 }
@@ -167,6 +208,41 @@ fn register_timestamp_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, TimestampType);
 }
 
+// Intervals are ordered by their length assuming 30-day months, ties are broken field by field,
+// so `INTERVAL '1 month'` and `INTERVAL '30 days'` are ordered but never equal.
+fn register_interval_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "eq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "noteq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
 fn register_boolean_cmp(registry: &mut FunctionRegistry) {
     registry.register_2_arg::<BooleanType, BooleanType, BooleanType, _, _>(
         "eq",
@@ -436,37 +512,73 @@ fn register_tuple_cmp(registry: &mut FunctionRegistry) {
     });
 }
 
+/// `is_not_distinct_from` and `is_distinct_from` treat NULL as an ordinary value:
+/// two NULLs are not distinct, a NULL and a non-NULL value are distinct, and
+/// two non-NULL values are compared with `=`. The result is never NULL.
+fn register_null_safe_cmp(registry: &mut FunctionRegistry) {
+    fn register_null_safe_cmp_op(registry: &mut FunctionRegistry, name: &str, distinct: bool) {
+        registry.register_2_arg_core::<NullType, NullType, BooleanType, _, _>(
+            name,
+            move |_, _| {
+                if distinct {
+                    FunctionDomain::Domain(ALL_FALSE_DOMAIN)
+                } else {
+                    FunctionDomain::Domain(ALL_TRUE_DOMAIN)
+                }
+            },
+            vectorize_2_arg::<NullType, NullType, BooleanType>(move |_, _, _| !distinct),
+        );
+        registry.register_2_arg_core::<
+            NullableType<VariantType>,
+            NullableType<VariantType>,
+            BooleanType,
+            _,
+            _,
+        >(
+            name,
+            |_, _| FunctionDomain::Full,
+            vectorize_2_arg::<NullableType<VariantType>, NullableType<VariantType>, BooleanType>(
+                move |lhs, rhs, _| {
+                    let equal = match (lhs, rhs) {
+                        (Some(lhs), Some(rhs)) => {
+                            compare_variant(lhs, rhs)
+                                == Ordering::Equal
+                        }
+                        (None, None) => true,
+                        _ => false,
+                    };
+                    equal != distinct
+                },
+            ),
+        );
+        registry.register_2_arg_core::<
+            NullableType<GenericType<0>>,
+            NullableType<GenericType<0>>,
+            BooleanType,
+            _,
+            _,
+        >(
+            name,
+            |_, _| FunctionDomain::Full,
+            vectorize_2_arg::<
+                NullableType<GenericType<0>>,
+                NullableType<GenericType<0>>,
+                BooleanType,
+            >(move |lhs, rhs, _| (lhs == rhs) != distinct),
+        );
+    }
+
+    register_null_safe_cmp_op(registry, "is_not_distinct_from", false);
+    register_null_safe_cmp_op(registry, "is_distinct_from", true);
+}
+
 fn register_like(registry: &mut FunctionRegistry) {
     registry.register_aliases("regexp", &["rlike"]);
 
     registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
         "like",
         |_, _| FunctionDomain::Full,
-        vectorize_like(|str, pat, _, pattern_type| {
-            match pattern_type {
-                PatternType::OrdinalStr => str == pat,
-                PatternType::EndOfPercent => {
-                    // fast path, can use starts_with
-                    let starts_with = &pat[..pat.len() - 1];
-                    str.starts_with(starts_with)
-                }
-                PatternType::StartOfPercent => {
-                    // fast path, can use ends_with
-                    str.ends_with(&pat[1..])
-                }
-
-                PatternType::SurroundByPercent => {
-                    if pat.len() > 2 {
-                        memmem::find(str, &pat[1..pat.len() - 1]).is_some()
-                    } else {
-                        // true for empty '%%' pattern, which follows pg/mysql way
-                        true
-                    }
-                }
-
-                PatternType::PatternStr => like(str, pat),
-            }
-        }),
+        vectorize_like(|str, pat, _, pattern_type| like_with_pattern_type(str, pat, pattern_type)),
     );
 
     registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
@@ -672,6 +784,34 @@ pub fn check_pattern_type(pattern: &[u8], is_pruning: bool) -> PatternType {
     }
 }
 
+/// Matches `haystack` against the LIKE `pattern`, whose type is checked by [`check_pattern_type`].
+#[inline]
+pub fn like_with_pattern_type(haystack: &[u8], pattern: &[u8], pattern_type: PatternType) -> bool {
+    match pattern_type {
+        PatternType::OrdinalStr => haystack == pattern,
+        PatternType::EndOfPercent => {
+            // fast path, can use starts_with
+            let starts_with = &pattern[..pattern.len() - 1];
+            haystack.starts_with(starts_with)
+        }
+        PatternType::StartOfPercent => {
+            // fast path, can use ends_with
+            haystack.ends_with(&pattern[1..])
+        }
+
+        PatternType::SurroundByPercent => {
+            if pattern.len() > 2 {
+                memmem::find(haystack, &pattern[1..pattern.len() - 1]).is_some()
+            } else {
+                // true for empty '%%' pattern, which follows pg/mysql way
+                true
+            }
+        }
+
+        PatternType::PatternStr => like(haystack, pattern),
+    }
+}
+
 #[inline]
 fn decode_one(data: &[u8]) -> Option<(u8, usize)> {
     if data.is_empty() {
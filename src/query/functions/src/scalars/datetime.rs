@@ -15,7 +15,6 @@
 use std::io::Write;
 
 use chrono::Datelike;
-use chrono::Utc;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use common_expression::error_to_null;
 use common_expression::types::date::check_date;
@@ -23,14 +22,20 @@ use common_expression::types::date::date_to_string;
 use common_expression::types::date::string_to_date;
 use common_expression::types::date::DATE_MAX;
 use common_expression::types::date::DATE_MIN;
+use common_expression::types::interval::string_to_interval;
+use common_expression::types::interval::MonthsDaysMicros;
+use common_expression::types::interval::MICROS_IN_AN_HOUR;
+use common_expression::types::interval::MICROS_IN_A_MINUTE;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::nullable::NullableDomain;
+use common_expression::types::number::Float64Type;
 use common_expression::types::number::Int64Type;
 use common_expression::types::number::SimpleDomain;
 use common_expression::types::number::UInt16Type;
 use common_expression::types::number::UInt32Type;
 use common_expression::types::number::UInt64Type;
 use common_expression::types::number::UInt8Type;
+use common_expression::types::number::F64;
 use common_expression::types::string::StringDomain;
 use common_expression::types::timestamp::check_timestamp;
 use common_expression::types::timestamp::microseconds_to_days;
@@ -40,6 +45,7 @@ use common_expression::types::timestamp::MICROS_IN_A_MILLI;
 use common_expression::types::timestamp::MICROS_IN_A_SEC;
 use common_expression::types::DateType;
 use common_expression::types::Int32Type;
+use common_expression::types::IntervalType;
 use common_expression::types::NullableType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -52,6 +58,7 @@ use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::EvalContext;
 use common_expression::FunctionDomain;
+use common_expression::FunctionMeta;
 use common_expression::FunctionProperty;
 use common_expression::FunctionRegistry;
 use common_expression::Value;
@@ -71,19 +78,29 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_timestamp_to_date(registry);
     register_number_to_date(registry);
 
-    // cast([date | timestamp] AS string)
-    // to_string([date | timestamp])
+    // cast(xx AS interval)
+    // to_interval(xx)
+    register_string_to_interval(registry);
+
+    // cast([date | timestamp | interval] AS string)
+    // to_string([date | timestamp | interval])
     register_to_string(registry);
 
     // cast([date | timestamp] AS [uint8 | int8 | ...])
     // to_[uint8 | int8 | ...]([date | timestamp])
     register_to_number(registry);
 
-    // [add | subtract]_[years | months | days | hours | minutes | seconds]([date | timestamp], number)
+    // [add | subtract]_[years | months | days | hours | minutes | seconds | milliseconds]([date | timestamp], number)
     // date_[add | sub]([year | quarter | month | week | day | hour | minute | second], [date | timstamp], number)
+    // dateadd(<unit>, number, [date | timestamp])
     // [date | timestamp] [+ | -] interval number [year | quarter | month | week | day | hour | minute | second]
     register_add_functions(registry);
     register_sub_functions(registry);
+    register_date_add_sub_metas(registry);
+
+    // diff_[years | quarters | months | days | hours | minutes | seconds | milliseconds]([date | timestamp], [date | timestamp])
+    // datediff(<unit>, [date | timestamp], [date | timestamp])
+    register_diff_functions(registry);
 
     // now, today, yesterday, tomorrow
     register_real_time_functions(registry);
@@ -96,6 +113,12 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     // [date | timestamp] +/- number
     register_timestamp_add_sub(registry);
+
+    // to_[years | quarters | months | weeks | days | hours | minutes | seconds](number) -> interval
+    register_to_interval_functions(registry);
+
+    // [date | timestamp | interval] +/- interval
+    register_interval_add_sub(registry);
 }
 
 /// Check if timestamp is within range, and return the timestamp in micros.
@@ -325,6 +348,34 @@ fn register_number_to_date(registry: &mut FunctionRegistry) {
     }
 }
 
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(interval) => output.push(interval),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(MonthsDaysMicros::default());
+                }
+            }
+        })(val, ctx)
+    }
+}
+
 fn register_to_string(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<DateType, StringType, _, _>(
         "to_string",
@@ -386,6 +437,33 @@ fn register_to_string(registry: &mut FunctionRegistry) {
             },
         ),
     );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.data, "{val}").unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "try_to_string",
+        |_| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: vec![],
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<IntervalType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.data, "{val}").unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
 }
 
 fn register_to_number(registry: &mut FunctionRegistry) {
@@ -705,6 +783,41 @@ macro_rules! impl_register_arith_functions {
                     },
                 ),
             );
+
+            registry.register_passthrough_nullable_2_arg::<DateType, Int64Type, TimestampType, _, _>(
+                concat!($op, "_milliseconds"),
+
+                |_, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<DateType, Int64Type, TimestampType>(
+                    |ts, delta, builder, ctx| {
+                        let val = (ts as i64) * 24 * 3600 * MICROS_IN_A_SEC;
+
+                        match AddTimesImpl::eval_timestamp_millis(val, $signed_wrapper!{delta}) {
+                            Ok(t) => builder.push(t),
+                            Err(e) => {
+                                ctx.set_error(builder.len(), e);
+                                builder.push(0);
+                            },
+                        }
+                    },
+                ),
+            );
+            registry.register_passthrough_nullable_2_arg::<TimestampType, Int64Type, TimestampType, _, _>(
+                concat!($op, "_milliseconds"),
+
+                |_, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<TimestampType, Int64Type, TimestampType>(
+                    |ts, delta, builder, ctx| {
+                        match AddTimesImpl::eval_timestamp_millis(ts, $signed_wrapper!{delta}) {
+                            Ok(t) => builder.push(t),
+                            Err(e) => {
+                                ctx.set_error(builder.len(), e);
+                                builder.push(0);
+                            },
+                        }
+                    },
+                ),
+            );
         }
     };
 }
@@ -712,6 +825,82 @@ macro_rules! impl_register_arith_functions {
 impl_register_arith_functions!(register_add_functions, "add", unsigned_ident);
 impl_register_arith_functions!(register_sub_functions, "subtract", signed_ident);
 
+// `date_add` and `date_sub` are rewritten to the functions above by the planner,
+// so they are only registered for `DESCRIBE FUNCTION`.
+fn register_date_add_sub_metas(registry: &mut FunctionRegistry) {
+    registry.register_meta("date_add", FunctionMeta {
+        description: "DATE_ADD(<unit>, <value>, <date_or_time_expr>): adds the specified number of units (YEAR, QUARTER, MONTH, WEEK, DAY, HOUR, MINUTE or SECOND) to a date or timestamp.",
+        example: "SELECT DATE_ADD(YEAR, 1, to_date('2018-01-02')); -- 2019-01-02",
+    });
+    registry.register_meta("date_sub", FunctionMeta {
+        description: "DATE_SUB(<unit>, <value>, <date_or_time_expr>): subtracts the specified number of units (YEAR, QUARTER, MONTH, WEEK, DAY, HOUR, MINUTE or SECOND) from a date or timestamp.",
+        example: "SELECT DATE_SUB(YEAR, 1, to_date('2018-01-02')); -- 2017-01-02",
+    });
+}
+
+macro_rules! register_diff_months {
+    ($registry: ident, $name: literal, $months: literal) => {
+        $registry.register_2_arg::<DateType, DateType, Int64Type, _, _>(
+            $name,
+            |_, _| FunctionDomain::Full,
+            |start, end, ctx| DiffImpl::eval_date_months(start, end, ctx.tz, $months),
+        );
+        $registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
+            $name,
+            |_, _| FunctionDomain::Full,
+            |start, end, ctx| DiffImpl::eval_timestamp_months(start, end, ctx.tz, $months),
+        );
+    };
+}
+
+macro_rules! register_diff_times {
+    ($registry: ident, $name: literal, $factor: ident) => {
+        $registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
+            $name,
+            |_, _| FunctionDomain::Full,
+            |start, end, _| DiffImpl::eval_timestamp_times(start, end, $factor),
+        );
+    };
+}
+
+// The dates are implicitly cast to timestamps for the units smaller than a day.
+fn register_diff_functions(registry: &mut FunctionRegistry) {
+    register_diff_months!(registry, "diff_years", 12);
+    register_diff_months!(registry, "diff_quarters", 3);
+    register_diff_months!(registry, "diff_months", 1);
+
+    registry.register_2_arg::<DateType, DateType, Int64Type, _, _>(
+        "diff_days",
+        |_, _| FunctionDomain::Full,
+        |start, end, _| end as i64 - start as i64,
+    );
+    registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
+        "diff_days",
+        |_, _| FunctionDomain::Full,
+        |start, end, ctx| DiffImpl::eval_timestamp_days(start, end, ctx.tz),
+    );
+
+    register_diff_times!(registry, "diff_hours", FACTOR_HOUR);
+    register_diff_times!(registry, "diff_minutes", FACTOR_MINUTE);
+    register_diff_times!(registry, "diff_seconds", FACTOR_SECOND);
+
+    registry.register_2_arg::<TimestampType, TimestampType, Float64Type, _, _>(
+        "diff_milliseconds",
+        |_, _| FunctionDomain::Full,
+        |start, end, _| F64::from(DiffImpl::eval_timestamp_millis(start, end)),
+    );
+
+    // `dateadd` and `datediff` are rewritten to the functions above by the planner.
+    registry.register_meta("dateadd", FunctionMeta {
+        description: "DATEADD(<unit>, <value>, <date_or_time_expr>): adds the specified number of units to a date or timestamp, the unit is a name or abbreviation such as 'day', 'dd', 'millisecond' or 'ms'.",
+        example: "SELECT DATEADD('ms', -500, to_timestamp('2023-01-01 00:00:01')); -- 2023-01-01 00:00:00.500000",
+    });
+    registry.register_meta("datediff", FunctionMeta {
+        description: "DATEDIFF(<unit>, <start>, <end>): returns the number of unit boundaries crossed from start to end, milliseconds are returned as a Float64 with the microseconds as a fraction.",
+        example: "SELECT DATEDIFF('month', to_date('2023-01-31'), to_date('2023-02-01')); -- 1",
+    });
+}
+
 fn register_real_time_functions(registry: &mut FunctionRegistry) {
     registry.properties.insert(
         "now".to_string(),
@@ -733,25 +922,25 @@ fn register_real_time_functions(registry: &mut FunctionRegistry) {
     registry.register_0_arg_core::<TimestampType, _, _>(
         "now",
         || FunctionDomain::Full,
-        |_| Value::Scalar(Utc::now().timestamp_micros()),
+        |ctx| Value::Scalar(ctx.now()),
     );
 
     registry.register_0_arg_core::<DateType, _, _>(
         "today",
         || FunctionDomain::Full,
-        |_| Value::Scalar(today_date()),
+        |ctx| Value::Scalar(today_date(ctx.now())),
     );
 
     registry.register_0_arg_core::<DateType, _, _>(
         "yesterday",
         || FunctionDomain::Full,
-        |_| Value::Scalar(today_date() - 1),
+        |ctx| Value::Scalar(today_date(ctx.now()) - 1),
     );
 
     registry.register_0_arg_core::<DateType, _, _>(
         "tomorrow",
         || FunctionDomain::Full,
-        |_| Value::Scalar(today_date() + 1),
+        |ctx| Value::Scalar(today_date(ctx.now()) + 1),
     );
 }
 
@@ -1040,6 +1229,213 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
     );
 }
 
+fn register_to_interval_functions(registry: &mut FunctionRegistry) {
+    fn register_to_interval(
+        registry: &mut FunctionRegistry,
+        name: &str,
+        to_interval: fn(i64) -> Option<MonthsDaysMicros>,
+    ) {
+        registry.register_passthrough_nullable_1_arg::<Int64Type, IntervalType, _, _>(
+            name,
+            |_| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<Int64Type, IntervalType>(move |val, output, ctx| {
+                match to_interval(val) {
+                    Some(interval) => output.push(interval),
+                    None => {
+                        ctx.set_error(output.len(), "interval is out of range");
+                        output.push(MonthsDaysMicros::default());
+                    }
+                }
+            }),
+        );
+    }
+
+    register_to_interval(registry, "to_years", |n| {
+        let months = n.checked_mul(12)?.try_into().ok()?;
+        Some(MonthsDaysMicros::new(months, 0, 0))
+    });
+    register_to_interval(registry, "to_quarters", |n| {
+        let months = n.checked_mul(3)?.try_into().ok()?;
+        Some(MonthsDaysMicros::new(months, 0, 0))
+    });
+    register_to_interval(registry, "to_months", |n| {
+        Some(MonthsDaysMicros::new(n.try_into().ok()?, 0, 0))
+    });
+    register_to_interval(registry, "to_weeks", |n| {
+        let days = n.checked_mul(7)?.try_into().ok()?;
+        Some(MonthsDaysMicros::new(0, days, 0))
+    });
+    register_to_interval(registry, "to_days", |n| {
+        Some(MonthsDaysMicros::new(0, n.try_into().ok()?, 0))
+    });
+    register_to_interval(registry, "to_hours", |n| {
+        Some(MonthsDaysMicros::new(
+            0,
+            0,
+            n.checked_mul(MICROS_IN_AN_HOUR)?,
+        ))
+    });
+    register_to_interval(registry, "to_minutes", |n| {
+        Some(MonthsDaysMicros::new(
+            0,
+            0,
+            n.checked_mul(MICROS_IN_A_MINUTE)?,
+        ))
+    });
+    register_to_interval(registry, "to_seconds", |n| {
+        Some(MonthsDaysMicros::new(0, 0, n.checked_mul(MICROS_IN_A_SEC)?))
+    });
+}
+
+fn register_interval_add_sub(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, builder, ctx| match AddIntervalImpl::eval_timestamp(
+                    ts, ctx.tz, interval,
+                ) {
+                    Ok(t) => builder.push(t),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, builder, ctx| match AddIntervalImpl::eval_timestamp(
+                    ts, ctx.tz, interval,
+                ) {
+                    Ok(t) => builder.push(t),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, builder, ctx| {
+                    let result = interval
+                        .checked_neg()
+                        .ok_or_else(|| "interval is out of range".to_string())
+                        .and_then(|interval| AddIntervalImpl::eval_timestamp(ts, ctx.tz, interval));
+                    match result {
+                        Ok(t) => builder.push(t),
+                        Err(e) => {
+                            ctx.set_error(builder.len(), e);
+                            builder.push(0);
+                        }
+                    }
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, builder, ctx| match AddIntervalImpl::eval_date(date, ctx.tz, interval)
+            {
+                Ok(t) => builder.push(t),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(0);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, DateType, TimestampType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, DateType, TimestampType>(
+            |interval, date, builder, ctx| match AddIntervalImpl::eval_date(date, ctx.tz, interval)
+            {
+                Ok(t) => builder.push(t),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(0);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, builder, ctx| {
+                let result = interval
+                    .checked_neg()
+                    .ok_or_else(|| "interval is out of range".to_string())
+                    .and_then(|interval| AddIntervalImpl::eval_date(date, ctx.tz, interval));
+                match result {
+                    Ok(t) => builder.push(t),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.push(0);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |lhs, rhs, builder, ctx| match lhs.checked_add(&rhs) {
+                Some(interval) => builder.push(interval),
+                None => {
+                    ctx.set_error(builder.len(), "interval is out of range");
+                    builder.push(MonthsDaysMicros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |lhs, rhs, builder, ctx| match lhs.checked_sub(&rhs) {
+                Some(interval) => builder.push(interval),
+                None => {
+                    ctx.set_error(builder.len(), "interval is out of range");
+                    builder.push(MonthsDaysMicros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|val, builder, ctx| {
+            match val.checked_neg() {
+                Some(interval) => builder.push(interval),
+                None => {
+                    ctx.set_error(builder.len(), "interval is out of range");
+                    builder.push(MonthsDaysMicros::default());
+                }
+            }
+        }),
+    );
+}
+
 fn register_rounder_functions(registry: &mut FunctionRegistry) {
     // timestamp -> timestamp
     registry.register_passthrough_nullable_1_arg::<TimestampType, TimestampType, _, _>(
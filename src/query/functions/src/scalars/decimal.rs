@@ -116,35 +116,45 @@ macro_rules! compare_decimal {
 
 macro_rules! binary_decimal {
     ($a: expr, $b: expr, $ctx: expr, $op: ident, $size: expr, $scale_a: expr, $scale_b: expr, $type_name: ty, $decimal_type: tt, $is_divide: expr) => {{
-        let scale_a = <$type_name>::e($scale_a);
-        let scale_b = <$type_name>::e($scale_b);
+        let multiplier = <$type_name>::e($scale_a);
+        let divisor = <$type_name>::e($scale_b);
 
         let zero = <$type_name>::zero();
         let one = <$type_name>::one();
         let min_for_precision = <$type_name>::min_for_precision($size.precision);
         let max_for_precision = <$type_name>::max_for_precision($size.precision);
 
+        // The intermediate results are checked as well, they must not wrap around
+        // even if the result is in the range of the precision.
+        let eval = |a: $type_name, b: $type_name, row: usize, ctx: &mut EvalContext| {
+            if $is_divide && std::intrinsics::unlikely(b == zero) {
+                ctx.set_error(row, "divided by zero");
+                return one;
+            }
+            match a
+                .checked_mul(multiplier)
+                .and_then(|t| t.$op(b))
+                .and_then(|t| t.checked_div(divisor))
+            {
+                Some(t) if t >= min_for_precision && t <= max_for_precision => t,
+                _ => {
+                    ctx.set_error(row, "Decimal overflow");
+                    one
+                }
+            }
+        };
+
         match ($a, $b) {
             (
                 ValueRef::Column(Column::Decimal(DecimalColumn::$decimal_type(buffer_a, _))),
                 ValueRef::Column(Column::Decimal(DecimalColumn::$decimal_type(buffer_b, _))),
             ) => {
-                let mut result = Vec::with_capacity(buffer_a.len());
-
-                for (a, b) in buffer_a.iter().zip(buffer_b.iter()) {
-                    if $is_divide && std::intrinsics::unlikely(*b == zero) {
-                        $ctx.set_error(result.len(), "divided by zero");
-                        result.push(one);
-                    } else {
-                        let t = (a * scale_a).$op(b) / scale_b;
-                        if t < min_for_precision || t > max_for_precision {
-                            $ctx.set_error(result.len(), "Decimal overflow");
-                            result.push(one);
-                        } else {
-                            result.push(t);
-                        }
-                    }
-                }
+                let result: Vec<_> = buffer_a
+                    .iter()
+                    .zip(buffer_b.iter())
+                    .enumerate()
+                    .map(|(row, (a, b))| eval(*a, *b, row, $ctx))
+                    .collect();
                 Value::Column(Column::Decimal(DecimalColumn::$decimal_type(
                     result.into(),
                     $size,
@@ -155,23 +165,11 @@ macro_rules! binary_decimal {
                 ValueRef::Column(Column::Decimal(DecimalColumn::$decimal_type(buffer, _))),
                 ValueRef::Scalar(ScalarRef::Decimal(DecimalScalar::$decimal_type(b, _))),
             ) => {
-                let mut result = Vec::with_capacity(buffer.len());
-
-                if $is_divide && std::intrinsics::unlikely(*b == zero) {
-                    $ctx.set_error(result.len(), "divided by zero");
-                    result.push(one);
-                } else {
-                    for a in buffer.iter() {
-                        let t = (a * scale_a).$op(b) / scale_b;
-                        if t < min_for_precision || t > max_for_precision {
-                            $ctx.set_error(result.len(), "Decimal overflow");
-                            result.push(one);
-                        } else {
-                            result.push(t);
-                        }
-                    }
-                }
-
+                let result: Vec<_> = buffer
+                    .iter()
+                    .enumerate()
+                    .map(|(row, a)| eval(*a, *b, row, $ctx))
+                    .collect();
                 Value::Column(Column::Decimal(DecimalColumn::$decimal_type(
                     result.into(),
                     $size,
@@ -182,22 +180,11 @@ macro_rules! binary_decimal {
                 ValueRef::Scalar(ScalarRef::Decimal(DecimalScalar::$decimal_type(a, _))),
                 ValueRef::Column(Column::Decimal(DecimalColumn::$decimal_type(buffer, _))),
             ) => {
-                let mut result = Vec::with_capacity(buffer.len());
-
-                for b in buffer.iter() {
-                    if $is_divide && std::intrinsics::unlikely(*b == zero) {
-                        $ctx.set_error(result.len(), "divided by zero");
-                        result.push(one);
-                    } else {
-                        let t = (a * scale_a).$op(b) / scale_b;
-                        if t < min_for_precision || t > max_for_precision {
-                            $ctx.set_error(result.len(), "Decimal overflow");
-                            result.push(one);
-                        } else {
-                            result.push(t);
-                        }
-                    }
-                }
+                let result: Vec<_> = buffer
+                    .iter()
+                    .enumerate()
+                    .map(|(row, b)| eval(*a, *b, row, $ctx))
+                    .collect();
                 Value::Column(Column::Decimal(DecimalColumn::$decimal_type(
                     result.into(),
                     $size,
@@ -208,15 +195,7 @@ macro_rules! binary_decimal {
                 ValueRef::Scalar(ScalarRef::Decimal(DecimalScalar::$decimal_type(a, _))),
                 ValueRef::Scalar(ScalarRef::Decimal(DecimalScalar::$decimal_type(b, _))),
             ) => {
-                let mut t = zero;
-                if $is_divide && std::intrinsics::unlikely(*b == zero) {
-                    $ctx.set_error(0, "divided by zero");
-                } else {
-                    t = (a * scale_a).$op(b) / scale_b;
-                    if t < min_for_precision || t > max_for_precision {
-                        $ctx.set_error(0, "Decimal overflow");
-                    }
-                }
+                let t = eval(*a, *b, 0, $ctx);
                 Value::Scalar(Scalar::Decimal(DecimalScalar::$decimal_type(t, $size)))
             }
 
@@ -306,42 +285,65 @@ macro_rules! register_decimal_binary_op {
             let is_divide = $name == "divide";
             let is_plus_minus = !is_multiply && !is_divide;
 
-            let return_type = if args_type[0].is_decimal() && args_type[1].is_decimal() {
-                let lhs_type = args_type[0].as_decimal().unwrap();
-                let rhs_type = args_type[1].as_decimal().unwrap();
-
-                DecimalDataType::binary_result_type(
-                    &lhs_type,
-                    &rhs_type,
-                    is_multiply,
-                    is_divide,
-                    is_plus_minus,
-                )
-            } else if args_type[0].is_decimal() {
-                let lhs_type = args_type[0].as_decimal().unwrap();
-                lhs_type.binary_upgrade_to_max_precision()
-            } else {
-                let rhs_type = args_type[1].as_decimal().unwrap();
-                rhs_type.binary_upgrade_to_max_precision()
-            }
-            .ok()?;
+            let (return_type, lhs_type, rhs_type) =
+                if args_type[0].is_decimal() && args_type[1].is_decimal() {
+                    let lhs_type = args_type[0].as_decimal().unwrap();
+                    let rhs_type = args_type[1].as_decimal().unwrap();
+
+                    let return_type = DecimalDataType::binary_result_type(
+                        lhs_type,
+                        rhs_type,
+                        is_multiply,
+                        is_divide,
+                        is_plus_minus,
+                    )
+                    .ok()?;
+                    if is_plus_minus {
+                        (return_type.clone(), return_type.clone(), return_type)
+                    } else {
+                        // The operands of multiply and divide keep their scales, so they
+                        // don't lose digits or overflow when casted to the result type.
+                        let precision = return_type.max_precision();
+                        let lhs_type = DecimalDataType::from_size(DecimalSize {
+                            precision,
+                            scale: lhs_type.scale(),
+                        })
+                        .ok()?;
+                        let rhs_type = DecimalDataType::from_size(DecimalSize {
+                            precision,
+                            scale: rhs_type.scale(),
+                        })
+                        .ok()?;
+                        (return_type, lhs_type, rhs_type)
+                    }
+                } else {
+                    let return_type = if args_type[0].is_decimal() {
+                        args_type[0].as_decimal().unwrap()
+                    } else {
+                        args_type[1].as_decimal().unwrap()
+                    }
+                    .binary_upgrade_to_max_precision()
+                    .ok()?;
+                    (return_type.clone(), return_type.clone(), return_type)
+                };
 
+            // The lhs is multiplied by `10^scale_a` and the result is divided by `10^scale_b`
+            // to get the scale of the result type.
             let mut scale_a = 0;
             let mut scale_b = 0;
 
             if is_multiply {
-                scale_b = return_type.scale() as u32;
+                scale_b =
+                    lhs_type.scale() as u32 + rhs_type.scale() as u32 - return_type.scale() as u32;
             } else if is_divide {
-                scale_a = return_type.scale() as u32;
+                scale_a =
+                    return_type.scale() as u32 + rhs_type.scale() as u32 - lhs_type.scale() as u32;
             }
 
             let function = Function {
                 signature: FunctionSignature {
                     name: $name.to_string(),
-                    args_type: vec![
-                        DataType::Decimal(return_type.clone()),
-                        DataType::Decimal(return_type.clone()),
-                    ],
+                    args_type: vec![DataType::Decimal(lhs_type), DataType::Decimal(rhs_type)],
                     return_type: DataType::Decimal(return_type.clone()),
                 },
                 eval: FunctionEval::Scalar {
@@ -382,11 +384,10 @@ pub(crate) fn register_decimal_compare_op(registry: &mut FunctionRegistry) {
 }
 
 pub(crate) fn register_decimal_arithmetic(registry: &mut FunctionRegistry) {
-    // TODO checked overflow by default
-    register_decimal_binary_op!(registry, "plus", add);
-    register_decimal_binary_op!(registry, "minus", sub);
-    register_decimal_binary_op!(registry, "divide", div);
-    register_decimal_binary_op!(registry, "multiply", mul);
+    register_decimal_binary_op!(registry, "plus", checked_add);
+    register_decimal_binary_op!(registry, "minus", checked_sub);
+    register_decimal_binary_op!(registry, "divide", checked_div);
+    register_decimal_binary_op!(registry, "multiply", checked_mul);
 }
 
 pub fn register(registry: &mut FunctionRegistry) {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::mem::MaybeUninit;
 use std::num::Wrapping;
 use std::sync::Arc;
@@ -24,6 +25,8 @@ use common_expression::types::number::NumberScalar;
 use common_expression::types::number::F32;
 use common_expression::types::number::F64;
 use common_expression::types::AnyType;
+use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
@@ -33,6 +36,8 @@ use common_expression::types::ValueType;
 use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::vectorize_with_builder_3_arg;
+use common_expression::vectorize_with_builder_4_arg;
+use common_expression::vectorize_with_builder_5_arg;
 use common_expression::Column;
 use common_expression::EvalContext;
 use common_expression::Function;
@@ -49,10 +54,14 @@ use geo::Contains;
 use geo::Coord;
 use geo::LineString;
 use geo::Polygon;
+use h3o::CellIndex;
 use h3o::LatLng;
 use h3o::Resolution;
 use once_cell::sync::OnceCell;
 
+use crate::scalars::geo_wkb::check_wkb;
+use crate::scalars::geo_wkb::write_geometry_collection;
+
 const PI: f64 = std::f64::consts::PI;
 const PI_F: f32 = std::f32::consts::PI;
 
@@ -68,6 +77,11 @@ const METRIC_LUT_SIZE: usize = 1024;
 /// We use this value to be consistent with Uber H3 library.
 const EARTH_RADIUS: f32 = 6371007.180918475f32;
 const EARTH_DIAMETER: f32 = 2f32 * EARTH_RADIUS;
+const EARTH_RADIUS_F64: f64 = 6371007.180918475f64;
+
+/// The largest k-ring of [`H3DistanceFilter`], a coarser resolution is used if
+/// the distance needs a larger one.
+const H3_MAX_K: u32 = 16;
 
 static COS_LUT: OnceCell<[f32; COS_LUT_SIZE + 1]> = OnceCell::new();
 static ASIN_SQRT_LUT: OnceCell<[f32; ASIN_SQRT_LUT_SIZE + 1]> = OnceCell::new();
@@ -140,6 +154,38 @@ pub fn register(registry: &mut FunctionRegistry) {
         },
     );
 
+    registry.register_passthrough_nullable_4_arg::<Float64Type, Float64Type, Float64Type, Float64Type, Float64Type, _, _>(
+        "geography_distance",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_4_arg::<Float64Type, Float64Type, Float64Type, Float64Type, Float64Type>(
+            |lat1, lon1, lat2, lon2, builder, ctx| {
+                match geography_distance(lat1.0, lon1.0, lat2.0, lon2.0) {
+                    Ok(distance) => builder.push(distance.into()),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.push(F64::from(0.0));
+                    }
+                }
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_5_arg::<Float64Type, Float64Type, Float64Type, Float64Type, Float64Type, BooleanType, _, _>(
+        "geography_within_distance",
+        |_, _, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_5_arg::<Float64Type, Float64Type, Float64Type, Float64Type, Float64Type, BooleanType>(
+            |lat1, lon1, lat2, lon2, distance, builder, ctx| {
+                match geography_distance(lat1.0, lon1.0, lat2.0, lon2.0) {
+                    Ok(d) => builder.push(d <= distance.0),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.push(false);
+                    }
+                }
+            }
+        ),
+    );
+
     registry.register_passthrough_nullable_2_arg::<Float64Type, Float64Type, StringType, _, _>(
         "geohash_encode",
         |_, _| FunctionDomain::Full,
@@ -195,6 +241,20 @@ pub fn register(registry: &mut FunctionRegistry) {
             ),
         );
 
+    // Collect the WKB encoded geometries in an array into a GeometryCollection.
+    // The aggregate version is registered in `Aggregators`.
+    registry.register_passthrough_nullable_1_arg::<ArrayType<StringType>, StringType, _, _>(
+        "st_collect",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<ArrayType<StringType>, StringType>(|geoms, output, ctx| {
+            match geoms.iter().try_for_each(check_wkb) {
+                Ok(()) => write_geometry_collection(geoms.iter(), &mut output.data),
+                Err(e) => ctx.set_error(output.len(), format!("Invalid WKB: {}", e)),
+            }
+            output.commit_row();
+        }),
+    );
+
     // point in ellipses
     registry.register_function_factory("point_in_ellipses", |_, args_type| {
         if args_type.len() < 6 {
@@ -331,6 +391,111 @@ pub fn register(registry: &mut FunctionRegistry) {
     });
 }
 
+fn check_lat_lon(lat: f64, lon: f64) -> Result<(), String> {
+    if !(-90.0..=90.0).contains(&lat) {
+        return Err(format!("Invalid latitude {lat}, it must be in [-90, 90]"));
+    }
+    if !(-180.0..=180.0).contains(&lon) {
+        return Err(format!(
+            "Invalid longitude {lon}, it must be in [-180, 180]"
+        ));
+    }
+    Ok(())
+}
+
+/// The haversine great-circle distance in meters between two WGS-84 points,
+/// with the same earth radius as H3.
+pub fn geography_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> Result<f64, String> {
+    check_lat_lon(lat1, lon1)?;
+    check_lat_lon(lat2, lon2)?;
+
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let half_d_lat = (lat2 - lat1) / 2.0;
+    let half_d_lon = (lon2 - lon1).to_radians() / 2.0;
+    let a = half_d_lat.sin().powi(2) + lat1.cos() * lat2.cos() * half_d_lon.sin().powi(2);
+    Ok(2.0 * EARTH_RADIUS_F64 * a.sqrt().min(1.0).asin())
+}
+
+/// A pre-filter for `geography_within_distance` over points indexed by their H3 cells.
+///
+/// All the points within the distance of the center are in the k-ring of H3
+/// cells around the cell of the center, so the points in the other cells can
+/// be skipped without computing their haversine distances.
+pub struct H3DistanceFilter {
+    resolution: Resolution,
+    /// `None` if the distance is too large for the cells of resolution 0, all
+    /// the points have to be checked then.
+    cells: Option<HashSet<CellIndex>>,
+}
+
+impl H3DistanceFilter {
+    /// `index_resolution` is the resolution of the cells the points are indexed
+    /// by, the k-ring is built at the finest resolution not finer than it whose
+    /// k-ring is not larger than [`H3_MAX_K`].
+    pub fn try_create(
+        lat: f64,
+        lon: f64,
+        distance: f64,
+        index_resolution: Resolution,
+    ) -> Result<Self, String> {
+        check_lat_lon(lat, lon)?;
+        let center = LatLng::new(lat, lon).map_err(|e| e.to_string())?;
+
+        for resolution in (0..=u8::from(index_resolution)).rev() {
+            let resolution = Resolution::try_from(resolution).unwrap();
+            let k = Self::k_ring_size(distance, resolution);
+            if k <= H3_MAX_K {
+                return Ok(H3DistanceFilter {
+                    resolution,
+                    cells: Some(center.to_cell(resolution).grid_disk(k)),
+                });
+            }
+        }
+        Ok(H3DistanceFilter {
+            resolution: Resolution::Zero,
+            cells: None,
+        })
+    }
+
+    /// The k of a k-ring that covers all the points within the distance.
+    ///
+    /// The edges of the cells of a resolution are between half and twice the
+    /// average length, so the cell of a point is at most `distance + 4 * edge`
+    /// away from the cell of the center, and each ring moves the centers of the
+    /// cells by at least `sqrt(3) * edge / 2`.
+    fn k_ring_size(distance: f64, resolution: Resolution) -> u32 {
+        let edge = resolution.edge_length_m();
+        let k = (distance.max(0.0) + 4.0 * edge) / (3f64.sqrt() * edge / 2.0);
+        if k.is_finite() && k < u32::MAX as f64 {
+            k.ceil() as u32
+        } else {
+            u32::MAX
+        }
+    }
+
+    /// The resolution of the cells of [`H3DistanceFilter::cells`].
+    pub fn resolution(&self) -> Resolution {
+        self.resolution
+    }
+
+    /// The cells that cover all the points within the distance, `None` if all
+    /// the points have to be checked.
+    pub fn cells(&self) -> Option<&HashSet<CellIndex>> {
+        self.cells.as_ref()
+    }
+
+    /// Whether a point in the cell may be within the distance, the cell must
+    /// not be coarser than the resolution of the filter.
+    pub fn may_contain(&self, cell: CellIndex) -> bool {
+        match &self.cells {
+            Some(cells) => cell
+                .parent(self.resolution)
+                .map_or(false, |parent| cells.contains(&parent)),
+            None => true,
+        }
+    }
+}
+
 fn get_coord(fields: &[ScalarRef]) -> Coord {
     let v = fields
         .iter()
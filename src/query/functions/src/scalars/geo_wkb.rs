@@ -0,0 +1,209 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Minimal reader and writer of 2D geometries in WKB (well-known binary) format.
+
+const WKB_POINT: u32 = 1;
+const WKB_LINE_STRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTI_POINT: u32 = 4;
+const WKB_MULTI_LINE_STRING: u32 = 5;
+const WKB_MULTI_POLYGON: u32 = 6;
+const WKB_GEOMETRY_COLLECTION: u32 = 7;
+
+/// Byte order mark of little endian encoded WKB.
+const WKB_NDR: u8 = 1;
+
+/// Axis-aligned bounding box of geometries.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl BoundingBox {
+    fn point(x: f64, y: f64) -> Self {
+        Self {
+            min_x: x,
+            min_y: y,
+            max_x: x,
+            max_y: y,
+        }
+    }
+
+    pub fn merge(&mut self, other: &BoundingBox) {
+        self.min_x = self.min_x.min(other.min_x);
+        self.min_y = self.min_y.min(other.min_y);
+        self.max_x = self.max_x.max(other.max_x);
+        self.max_y = self.max_y.max(other.max_y);
+    }
+}
+
+/// Check that `wkb` contains exactly one well-formed geometry.
+pub fn check_wkb(wkb: &[u8]) -> Result<(), String> {
+    extend_bounding_box(wkb, &mut None)
+}
+
+/// Extend `bbox` with all the coordinates of the geometry in `wkb`.
+///
+/// Empty geometries and empty points (encoded with NaN coordinates) do not change `bbox`.
+pub fn extend_bounding_box(wkb: &[u8], bbox: &mut Option<BoundingBox>) -> Result<(), String> {
+    let mut reader = WkbReader { buf: wkb };
+    reader.read_geometry(&mut |x, y| {
+        if x.is_nan() || y.is_nan() {
+            return;
+        }
+        let p = BoundingBox::point(x, y);
+        match bbox {
+            Some(b) => b.merge(&p),
+            None => *bbox = Some(p),
+        }
+    })?;
+
+    if !reader.buf.is_empty() {
+        return Err(format!(
+            "{} trailing bytes after the geometry",
+            reader.buf.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Write a GeometryCollection that contains all of `geoms`, each of which must be a valid WKB.
+pub fn write_geometry_collection<'a>(geoms: impl Iterator<Item = &'a [u8]>, buf: &mut Vec<u8>) {
+    write_header(WKB_GEOMETRY_COLLECTION, buf);
+
+    // The number of geometries is filled in after all of them are written.
+    let count_pos = buf.len();
+    buf.extend_from_slice(&0u32.to_le_bytes());
+
+    let mut count = 0u32;
+    for geom in geoms {
+        buf.extend_from_slice(geom);
+        count += 1;
+    }
+    buf[count_pos..count_pos + 4].copy_from_slice(&count.to_le_bytes());
+}
+
+/// Write the polygon that covers `bbox`.
+pub fn write_bounding_box_polygon(bbox: &BoundingBox, buf: &mut Vec<u8>) {
+    let ring = [
+        (bbox.min_x, bbox.min_y),
+        (bbox.min_x, bbox.max_y),
+        (bbox.max_x, bbox.max_y),
+        (bbox.max_x, bbox.min_y),
+        (bbox.min_x, bbox.min_y),
+    ];
+
+    write_header(WKB_POLYGON, buf);
+    // A single exterior ring.
+    buf.extend_from_slice(&1u32.to_le_bytes());
+    buf.extend_from_slice(&(ring.len() as u32).to_le_bytes());
+    for (x, y) in ring {
+        buf.extend_from_slice(&x.to_le_bytes());
+        buf.extend_from_slice(&y.to_le_bytes());
+    }
+}
+
+fn write_header(geometry_type: u32, buf: &mut Vec<u8>) {
+    buf.push(WKB_NDR);
+    buf.extend_from_slice(&geometry_type.to_le_bytes());
+}
+
+struct WkbReader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> WkbReader<'a> {
+    fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        if self.buf.len() < N {
+            return Err("unexpected end of WKB".to_string());
+        }
+        let (head, rest) = self.buf.split_at(N);
+        self.buf = rest;
+        Ok(head.try_into().unwrap())
+    }
+
+    fn read_u32(&mut self, little_endian: bool) -> Result<u32, String> {
+        let bytes = self.read_bytes::<4>()?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_f64(&mut self, little_endian: bool) -> Result<f64, String> {
+        let bytes = self.read_bytes::<8>()?;
+        Ok(if little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_coords(
+        &mut self,
+        n: u32,
+        little_endian: bool,
+        f: &mut impl FnMut(f64, f64),
+    ) -> Result<(), String> {
+        for _ in 0..n {
+            let x = self.read_f64(little_endian)?;
+            let y = self.read_f64(little_endian)?;
+            f(x, y);
+        }
+        Ok(())
+    }
+
+    /// Read a geometry including its header, and call `f` with every coordinate in it.
+    fn read_geometry(&mut self, f: &mut impl FnMut(f64, f64)) -> Result<(), String> {
+        let [byte_order] = self.read_bytes::<1>()?;
+        let little_endian = match byte_order {
+            0 => false,
+            1 => true,
+            _ => return Err(format!("invalid WKB byte order: {}", byte_order)),
+        };
+
+        let geometry_type = self.read_u32(little_endian)?;
+        match geometry_type {
+            WKB_POINT => self.read_coords(1, little_endian, f),
+            WKB_LINE_STRING => {
+                let n = self.read_u32(little_endian)?;
+                self.read_coords(n, little_endian, f)
+            }
+            WKB_POLYGON => {
+                let rings = self.read_u32(little_endian)?;
+                for _ in 0..rings {
+                    let n = self.read_u32(little_endian)?;
+                    self.read_coords(n, little_endian, f)?;
+                }
+                Ok(())
+            }
+            WKB_MULTI_POINT
+            | WKB_MULTI_LINE_STRING
+            | WKB_MULTI_POLYGON
+            | WKB_GEOMETRY_COLLECTION => {
+                let n = self.read_u32(little_endian)?;
+                for _ in 0..n {
+                    self.read_geometry(f)?;
+                }
+                Ok(())
+            }
+            _ => Err(format!("unsupported WKB geometry type: {}", geometry_type)),
+        }
+    }
+}
@@ -14,14 +14,18 @@
 
 use std::hash::Hash;
 
+use common_expression::types::boolean::BooleanDomain;
 use common_expression::types::nullable::NullableDomain;
+use common_expression::types::number::SimpleDomain;
 use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
 use common_expression::types::EmptyArrayType;
 use common_expression::types::EmptyMapType;
 use common_expression::types::GenericType;
 use common_expression::types::MapType;
 use common_expression::types::NullType;
 use common_expression::types::NullableType;
+use common_expression::types::UInt64Type;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::FunctionDomain;
 use common_expression::FunctionRegistry;
@@ -108,4 +112,63 @@ pub fn register(registry: &mut FunctionRegistry) {
             }
         ),
     );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_keys",
+        |_| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    registry
+        .register_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<0>>, _, _>(
+            "map_keys",
+            |domain| {
+                FunctionDomain::Domain(domain.as_ref().map(|(key_domain, _)| key_domain.clone()))
+            },
+            |map, _| map.keys,
+        );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_values",
+        |_| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    registry
+        .register_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<1>>, _, _>(
+            "map_values",
+            |domain| {
+                FunctionDomain::Domain(domain.as_ref().map(|(_, val_domain)| val_domain.clone()))
+            },
+            |map, _| map.values,
+        );
+
+    registry.register_1_arg::<EmptyMapType, UInt64Type, _, _>(
+        "map_size",
+        |_| FunctionDomain::Domain(SimpleDomain { min: 0, max: 0 }),
+        |_, _| 0u64,
+    );
+
+    registry.register_1_arg::<MapType<GenericType<0>, GenericType<1>>, UInt64Type, _, _>(
+        "map_size",
+        |_| FunctionDomain::Full,
+        |map, _| map.len() as u64,
+    );
+
+    registry.register_2_arg::<EmptyMapType, GenericType<0>, BooleanType, _, _>(
+        "map_contains_key",
+        |_, _| {
+            FunctionDomain::Domain(BooleanDomain {
+                has_false: true,
+                has_true: false,
+            })
+        },
+        |_, _, _| false,
+    );
+
+    registry.register_2_arg::<MapType<GenericType<0>, GenericType<1>>, GenericType<0>, BooleanType, _, _>(
+        "map_contains_key",
+        |_, _| FunctionDomain::Full,
+        |map, key, _| map.iter().any(|(k, _)| k == key),
+    );
 }
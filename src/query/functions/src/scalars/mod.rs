@@ -17,27 +17,36 @@ use common_expression::FunctionRegistry;
 mod arithmetic;
 mod arithmetic_modulo;
 mod array;
+mod binary;
+mod bitmap;
 mod boolean;
 mod control;
 mod datetime;
 mod geo;
+pub(crate) mod geo_wkb;
 mod map;
 mod math;
 mod tuple;
 mod variant;
 mod vector;
+pub mod zorder;
 
 mod comparison;
 mod decimal;
 mod hash;
 mod other;
+mod sequence;
 mod string;
 mod string_multi_args;
 
 pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
+pub use comparison::like_with_pattern_type;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use geo::geography_distance;
+pub use geo::H3DistanceFilter;
+pub use sequence::SequenceValueGenerator;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -55,6 +64,10 @@ pub fn register(registry: &mut FunctionRegistry) {
     geo::register(registry);
     hash::register(registry);
     other::register(registry);
+    sequence::register(registry);
     decimal::register(registry);
     vector::register(registry);
+    bitmap::register(registry);
+    binary::register(registry);
+    zorder::register(registry);
 }
@@ -14,8 +14,12 @@
 
 use std::io::Write;
 use std::net::Ipv4Addr;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use common_base::base::convert_byte_size;
 use common_base::base::convert_number_size;
@@ -67,6 +71,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     registry.register_aliases("inet_ntoa", &["ipv4_num_to_string"]);
     registry.register_aliases("try_inet_ntoa", &["try_ipv4_num_to_string"]);
     registry.register_aliases("assume_not_null", &["remove_nullable"]);
+    registry.register_aliases("grouping", &["grouping_id"]);
     registry.register_aliases("gen_random_uuid", &["uuid"]);
 
     register_inet_aton(registry);
@@ -76,7 +81,15 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     registry.properties.insert(
         "rand".to_string(),
-        FunctionProperty::default().non_deterministic(),
+        FunctionProperty::default().non_deterministic().per_row(),
+    );
+    registry.properties.insert(
+        "gen_random_uuid".to_string(),
+        FunctionProperty::default().non_deterministic().per_row(),
+    );
+    registry.properties.insert(
+        "uuid_v7".to_string(),
+        FunctionProperty::default().non_deterministic().per_row(),
     );
 
     registry.register_passthrough_nullable_1_arg::<Float64Type, StringType, _, _>(
@@ -235,6 +248,57 @@ pub fn register(registry: &mut FunctionRegistry) {
             Value::Column(col)
         },
     );
+
+    registry.register_0_arg_core::<StringType, _, _>(
+        "uuid_v7",
+        || FunctionDomain::Full,
+        |ctx| {
+            let mut rng = rand::rngs::SmallRng::from_entropy();
+            let mut values: Vec<u8> = Vec::with_capacity(ctx.num_rows * 36);
+            let mut offsets: Vec<u64> = Vec::with_capacity(ctx.num_rows);
+            offsets.push(0);
+
+            for _ in 0..ctx.num_rows {
+                let value = new_uuid_v7(&mut rng);
+                offsets.push(offsets.last().unwrap() + 36u64);
+                write!(&mut values, "{:x}", value).unwrap();
+            }
+
+            let col = StringColumn {
+                data: values.into(),
+                offsets: offsets.into(),
+            };
+            Value::Column(col)
+        },
+    );
+}
+
+/// The last UUIDv7 generated by this process, as its unix timestamp in milliseconds shifted
+/// left by 12 bits plus the counter within the millisecond.
+static UUID_V7_STATE: AtomicU64 = AtomicU64::new(0);
+
+/// Generates a UUIDv7 of RFC 9562: the unix timestamp in milliseconds in the first 48 bits,
+/// then a 12-bit counter and 62 random bits, so the UUIDs sort by the time they are generated.
+/// The counter keeps the UUIDs of this process strictly increasing, even within a millisecond
+/// or if the clock goes backwards.
+fn new_uuid_v7(rng: &mut impl Rng) -> Uuid {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default();
+    let next = |state: u64| (now << 12).max(state + 1);
+    let prev = UUID_V7_STATE
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |state| {
+            Some(next(state))
+        })
+        .unwrap();
+    let state = next(prev);
+
+    let millis = (state >> 12) & 0xFFFF_FFFF_FFFF;
+    let counter = state & 0xFFF;
+    let high = (millis << 16) | (0x7 << 12) | counter;
+    let low = (rng.gen::<u64>() >> 2) | (0b10 << 62);
+    Uuid::from_u128(((high as u128) << 64) | low as u128)
 }
 
 fn register_inet_aton(registry: &mut FunctionRegistry) {
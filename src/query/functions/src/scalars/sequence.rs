@@ -0,0 +1,96 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::GlobalInstance;
+use common_exception::Result;
+use common_expression::types::number::Int64Type;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::StringType;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionProperty;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+/// Generates the values of the sequences for `nextval`. The sequences live in the meta
+/// service which the functions can't reach, so the query service sets the generator into
+/// the [`GlobalInstance`].
+pub trait SequenceValueGenerator: Send + Sync {
+    /// Generates the next `count` values of the sequence, at least `cache_size` values are
+    /// reserved from the meta service at once. The `currval` of the session is set to the
+    /// last value if the session lives on this node.
+    fn next_values(
+        &self,
+        tenant: &str,
+        name: &str,
+        session_id: &str,
+        count: usize,
+        cache_size: u64,
+    ) -> Result<Vec<i64>>;
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.properties.insert(
+        "nextval".to_string(),
+        FunctionProperty::default().non_deterministic().per_row(),
+    );
+
+    // `nextval('seq')` is rewritten by the binder into `nextval(tenant, 'seq', session_id,
+    // cache_size)`, so the values can be generated on any node of the cluster.
+    registry
+        .register_4_arg_core::<StringType, StringType, StringType, UInt64Type, Int64Type, _, _>(
+            "nextval",
+            |_, _, _, _| FunctionDomain::MayThrow,
+            eval_nextval,
+        );
+}
+
+fn eval_nextval(
+    tenant: ValueRef<StringType>,
+    name: ValueRef<StringType>,
+    session_id: ValueRef<StringType>,
+    cache_size: ValueRef<UInt64Type>,
+    ctx: &mut EvalContext,
+) -> Value<Int64Type> {
+    let args = (
+        tenant.as_scalar(),
+        name.as_scalar(),
+        session_id.as_scalar(),
+        cache_size.as_scalar(),
+    );
+    let (tenant, name, session_id, cache_size) = match args {
+        (Some(tenant), Some(name), Some(session_id), Some(cache_size)) => (
+            String::from_utf8_lossy(tenant),
+            String::from_utf8_lossy(name),
+            String::from_utf8_lossy(session_id),
+            *cache_size,
+        ),
+        _ => {
+            ctx.set_error(0, "the arguments of nextval must be constant");
+            return Value::Scalar(0);
+        }
+    };
+
+    let generator: Arc<dyn SequenceValueGenerator> = GlobalInstance::get();
+    match generator.next_values(&tenant, &name, &session_id, ctx.num_rows, cache_size) {
+        Ok(values) => Value::Column(values.into()),
+        Err(e) => {
+            ctx.set_error_code(0, e);
+            Value::Column(vec![0; ctx.num_rows].into())
+        }
+    }
+}
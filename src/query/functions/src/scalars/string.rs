@@ -14,6 +14,7 @@
 
 use std::cmp::Ordering;
 use std::io::Write;
+use std::ops::Range;
 
 use base64::engine::general_purpose;
 use base64::prelude::*;
@@ -23,6 +24,7 @@ use common_expression::types::number::UInt64Type;
 use common_expression::types::string::StringColumn;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::string::StringDomain;
+use common_expression::types::string::StringRangeBuilder;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::vectorize_with_builder_1_arg;
@@ -383,47 +385,38 @@ pub fn register(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "ltrim",
         |_| FunctionDomain::Full,
-        vectorize_string_to_string(
-            |col| col.data.len(),
-            |val, output, _| {
-                let pos = val.iter().position(|ch| *ch != b' ' && *ch != b'\t');
-                if let Some(idx) = pos {
-                    output.put_slice(&val.as_bytes()[idx..]);
-                }
-                output.commit_row();
-            },
-        ),
+        vectorize_string_to_sub_slice(|val| {
+            let pos = val.iter().position(|ch| *ch != b' ' && *ch != b'\t');
+            match pos {
+                Some(idx) => idx..val.len(),
+                None => val.len()..val.len(),
+            }
+        }),
     );
 
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "rtrim",
         |_| FunctionDomain::Full,
-        vectorize_string_to_string(
-            |col| col.data.len(),
-            |val, output, _| {
-                let pos = val.iter().rev().position(|ch| *ch != b' ' && *ch != b'\t');
-                if let Some(idx) = pos {
-                    output.put_slice(&val.as_bytes()[..val.len() - idx]);
-                }
-                output.commit_row();
-            },
-        ),
+        vectorize_string_to_sub_slice(|val| {
+            let pos = val.iter().rev().position(|ch| *ch != b' ' && *ch != b'\t');
+            match pos {
+                Some(idx) => 0..val.len() - idx,
+                None => 0..0,
+            }
+        }),
     );
 
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "trim",
         |_| FunctionDomain::Full,
-        vectorize_string_to_string(
-            |col| col.data.len(),
-            |val, output, _| {
-                let start_pos = val.iter().position(|ch| *ch != b' ' && *ch != b'\t');
-                let end_pos = val.iter().rev().position(|ch| *ch != b' ' && *ch != b'\t');
-                if let (Some(start_idx), Some(end_idx)) = (start_pos, end_pos) {
-                    output.put_slice(&val.as_bytes()[start_idx..val.len() - end_idx]);
-                }
-                output.commit_row();
-            },
-        ),
+        vectorize_string_to_sub_slice(|val| {
+            let start_pos = val.iter().position(|ch| *ch != b' ' && *ch != b'\t');
+            let end_pos = val.iter().rev().position(|ch| *ch != b' ' && *ch != b'\t');
+            match (start_pos, end_pos) {
+                (Some(start_idx), Some(end_idx)) => start_idx..val.len() - end_idx,
+                _ => 0..0,
+            }
+        }),
     );
 
     registry.register_passthrough_nullable_2_arg::<StringType, StringType, StringType, _, _>(
@@ -838,6 +831,26 @@ pub fn vectorize_string_to_string(
     }
 }
 
+/// String to String scalar function whose output values are sub-slices of the input values, `func`
+/// returns the range of the output in the input value.
+///
+/// The output column shares the data buffer of the input column if no value is shortened, see
+/// [`StringRangeBuilder`].
+fn vectorize_string_to_sub_slice(
+    func: impl Fn(&[u8]) -> Range<usize> + Copy,
+) -> impl Fn(ValueRef<StringType>, &mut EvalContext) -> Value<StringType> + Copy {
+    move |arg1, _| match arg1 {
+        ValueRef::Scalar(val) => Value::Scalar(val[func(val)].to_vec()),
+        ValueRef::Column(col) => {
+            let mut builder = StringRangeBuilder::with_capacity(&col, col.len());
+            for (row, val) in col.iter().enumerate() {
+                builder.put_row_range(row, func(val));
+            }
+            Value::Column(builder.build())
+        }
+    }
+}
+
 /// (String, String) to String scalar function with estimiated output column capacity.
 fn vectorize_string_to_string_2_arg(
     estimate_bytes: impl Fn(&StringColumn, &StringColumn) -> usize + Copy,
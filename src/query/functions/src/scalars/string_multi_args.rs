@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::MutableBitmap;
+use common_expression::types::array::ArrayColumnBuilder;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::number::Int64Type;
 use common_expression::types::number::NumberScalar;
@@ -24,6 +25,8 @@ use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::string::StringDomain;
 use common_expression::types::NumberColumn;
 use common_expression::types::*;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
 use common_expression::wrap_nullable;
 use common_expression::Column;
 use common_expression::Domain;
@@ -31,6 +34,7 @@ use common_expression::EvalContext;
 use common_expression::Function;
 use common_expression::FunctionDomain;
 use common_expression::FunctionEval;
+use common_expression::FunctionMeta;
 use common_expression::FunctionRegistry;
 use common_expression::FunctionSignature;
 use common_expression::Scalar;
@@ -38,13 +42,29 @@ use common_expression::Value;
 use common_expression::ValueRef;
 
 pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_meta("concat", FunctionMeta {
+        description: "Concatenates one or more strings. NULL arguments are skipped, and NULL is returned only if all the arguments are NULL.",
+        example: "SELECT CONCAT('data', NULL, 'bend'); -- databend",
+    });
+    registry.register_meta("concat_ws", FunctionMeta {
+        description: "Concatenates the strings with the separator given as the first argument. NULL arguments are skipped, and NULL is returned if the separator is NULL.",
+        example: "SELECT CONCAT_WS(',', 'data', 'fuse', 'labs'); -- data,fuse,labs",
+    });
+    registry.register_meta("string_to_array", FunctionMeta {
+        description: "Splits the string by the delimiter into an array, the tokens equal to the null string (defaults to '') become NULL. An empty delimiter splits the string into characters.",
+        example: "SELECT STRING_TO_ARRAY('a,,b', ','); -- ['a',NULL,'b']",
+    });
+    registry.register_meta("array_to_string", FunctionMeta {
+        description: "Joins the elements of the array with the delimiter, NULL elements are replaced by the null string (defaults to '').",
+        example: "SELECT ARRAY_TO_STRING(['a', NULL, 'b'], ',', '*'); -- a,*,b",
+    });
+
     registry.register_function_factory("concat", |_, args_type| {
-        if args_type.is_empty() {
+        // Nullable arguments are handled by the nullable concat, which skips NULLs.
+        if args_type.is_empty() || args_type.iter().any(|t| t.is_nullable_or_null()) {
             return None;
         }
-        let has_null = args_type.iter().any(|t| t.is_nullable_or_null());
-
-        let f = Function {
+        Some(Arc::new(Function {
             signature: FunctionSignature {
                 name: "concat".to_string(),
                 args_type: vec![DataType::String; args_type.len()],
@@ -60,16 +80,10 @@ pub fn register(registry: &mut FunctionRegistry) {
                 }),
                 eval: Box::new(concat_fn),
             },
-        };
-
-        if has_null {
-            Some(Arc::new(f.wrap_nullable()))
-        } else {
-            Some(Arc::new(f))
-        }
+        }))
     });
 
-    // nullable concat
+    // nullable concat, NULL arguments are skipped and it returns NULL only if all arguments are NULL
     registry.register_function_factory("concat", |_, args_type| {
         if args_type.is_empty() {
             return None;
@@ -82,7 +96,7 @@ pub fn register(registry: &mut FunctionRegistry) {
             },
             eval: FunctionEval::Scalar {
                 calc_domain: Box::new(|_| FunctionDomain::Full),
-                eval: Box::new(wrap_nullable(concat_fn)),
+                eval: Box::new(nullable_concat_fn),
             },
         }))
     });
@@ -154,7 +168,8 @@ pub fn register(registry: &mut FunctionRegistry) {
         }))
     });
 
-    // nullable concat ws
+    // nullable concat ws, NULL arguments are skipped and it returns NULL if the separator
+    // or all the other arguments are NULL
     registry.register_function_factory("concat_ws", |_, args_type| {
         if args_type.len() < 2 {
             return None;
@@ -180,57 +195,35 @@ pub fn register(registry: &mut FunctionRegistry) {
                         .map(|arg| arg.try_downcast::<T>().unwrap())
                         .collect::<Vec<_>>();
 
-                    let mut nullable_builder = T::create_builder(size, &[]);
-                    match &new_args[0] {
-                        ValueRef::Scalar(None) => {
-                            return Value::Scalar(T::upcast_scalar(None));
-                        }
-                        ValueRef::Scalar(Some(v)) => {
-                            let builder = &mut nullable_builder.builder;
-                            nullable_builder.validity.extend_constant(size, true);
-
-                            for idx in 0..size {
-                                for (i, s) in new_args
-                                    .iter()
-                                    .skip(1)
-                                    .filter_map(|arg| unsafe { arg.index_unchecked(idx) })
-                                    .enumerate()
-                                {
-                                    if i != 0 {
-                                        builder.put_slice(v);
-                                    }
-                                    builder.put_slice(s);
-                                }
-                                builder.commit_row();
-                            }
-                        }
-                        ValueRef::Column(_) => {
-                            let builder = &mut nullable_builder.builder;
-                            let validity = &mut nullable_builder.validity;
+                    if let ValueRef::Scalar(None) = &new_args[0] {
+                        return Value::Scalar(T::upcast_scalar(None));
+                    }
 
-                            for idx in 0..size {
-                                unsafe {
-                                    match new_args[0].index_unchecked(idx) {
-                                        Some(sep) => {
-                                            for (i, str) in new_args
-                                                .iter()
-                                                .skip(1)
-                                                .filter_map(|arg| arg.index_unchecked(idx))
-                                                .enumerate()
-                                            {
-                                                if i != 0 {
-                                                    builder.put_slice(sep);
-                                                }
-                                                builder.put_slice(str);
-                                            }
-                                            builder.commit_row();
-                                            validity.push(true);
-                                        }
-                                        None => {
-                                            builder.commit_row();
-                                            validity.push(false);
+                    let mut nullable_builder = T::create_builder(size, &[]);
+                    let builder = &mut nullable_builder.builder;
+                    let validity = &mut nullable_builder.validity;
+                    for idx in 0..size {
+                        unsafe {
+                            match new_args[0].index_unchecked(idx) {
+                                Some(sep) => {
+                                    let mut is_null = true;
+                                    for str in new_args
+                                        .iter()
+                                        .skip(1)
+                                        .filter_map(|arg| arg.index_unchecked(idx))
+                                    {
+                                        if !is_null {
+                                            builder.put_slice(sep);
                                         }
+                                        builder.put_slice(str);
+                                        is_null = false;
                                     }
+                                    builder.commit_row();
+                                    validity.push(!is_null);
+                                }
+                                None => {
+                                    builder.commit_row();
+                                    validity.push(false);
                                 }
                             }
                         }
@@ -468,6 +461,85 @@ pub fn register(registry: &mut FunctionRegistry) {
             Some(Arc::new(f))
         }
     });
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, ArrayType<NullableType<StringType>>, _, _>(
+        "string_to_array",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, StringType, ArrayType<NullableType<StringType>>>(
+            |s, delimiter, output, ctx| string_to_array(s, delimiter, b"", output, ctx),
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, ArrayType<NullableType<StringType>>, _, _>(
+        "string_to_array",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, ArrayType<NullableType<StringType>>>(
+            string_to_array,
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<ArrayType<NullableType<StringType>>, StringType, StringType, _, _>(
+        "array_to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<ArrayType<NullableType<StringType>>, StringType, StringType>(
+            |arr, delimiter, output, _| array_to_string(arr, delimiter, b"", output),
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<ArrayType<NullableType<StringType>>, StringType, StringType, StringType, _, _>(
+        "array_to_string",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_3_arg::<ArrayType<NullableType<StringType>>, StringType, StringType, StringType>(
+            |arr, delimiter, null_string, output, _| array_to_string(arr, delimiter, null_string, output),
+        ),
+    );
+}
+
+fn string_to_array(
+    s: &[u8],
+    delimiter: &[u8],
+    null_string: &[u8],
+    output: &mut ArrayColumnBuilder<NullableType<StringType>>,
+    ctx: &mut EvalContext,
+) {
+    let (s, delimiter) = match (std::str::from_utf8(s), std::str::from_utf8(delimiter)) {
+        (Ok(s), Ok(delimiter)) => (s, delimiter),
+        (Err(e), _) | (_, Err(e)) => {
+            ctx.set_error(output.len(), e.to_string());
+            output.commit_row();
+            return;
+        }
+    };
+
+    let mut put_token = |token: &str| match token.as_bytes() == null_string {
+        true => output.put_item(None),
+        false => output.put_item(Some(token.as_bytes())),
+    };
+    if delimiter.is_empty() {
+        for (i, c) in s.char_indices() {
+            put_token(&s[i..i + c.len_utf8()]);
+        }
+    } else if !s.is_empty() {
+        for token in s.split(delimiter) {
+            put_token(token);
+        }
+    }
+    output.commit_row();
+}
+
+fn array_to_string(
+    arr: NullableColumn<StringType>,
+    delimiter: &[u8],
+    null_string: &[u8],
+    output: &mut StringColumnBuilder,
+) {
+    for (i, item) in arr.iter().enumerate() {
+        if i > 0 {
+            output.put_slice(delimiter);
+        }
+        output.put_slice(item.unwrap_or(null_string));
+    }
+    output.commit_row();
 }
 
 fn concat_fn(args: &[ValueRef<AnyType>], _: &mut EvalContext) -> Value<AnyType> {
@@ -495,6 +567,38 @@ fn concat_fn(args: &[ValueRef<AnyType>], _: &mut EvalContext) -> Value<AnyType>
     }
 }
 
+fn nullable_concat_fn(args: &[ValueRef<AnyType>], _: &mut EvalContext) -> Value<AnyType> {
+    type T = NullableType<StringType>;
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+    let args = args
+        .iter()
+        .map(|arg| arg.try_downcast::<T>().unwrap())
+        .collect::<Vec<_>>();
+
+    let size = len.unwrap_or(1);
+    let mut nullable_builder = T::create_builder(size, &[]);
+    for idx in 0..size {
+        let mut is_null = true;
+        for str in args
+            .iter()
+            .filter_map(|arg| unsafe { arg.index_unchecked(idx) })
+        {
+            nullable_builder.builder.put_slice(str);
+            is_null = false;
+        }
+        nullable_builder.builder.commit_row();
+        nullable_builder.validity.push(!is_null);
+    }
+
+    match len {
+        Some(_) => Value::Column(T::upcast_column(nullable_builder.build())),
+        _ => Value::Scalar(T::upcast_scalar(nullable_builder.build_scalar())),
+    }
+}
+
 fn char_fn(args: &[ValueRef<AnyType>], _: &mut EvalContext) -> Value<AnyType> {
     let args = args
         .iter()
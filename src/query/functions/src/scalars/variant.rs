@@ -21,14 +21,17 @@ use chrono::Datelike;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use common_expression::types::date::string_to_date;
 use common_expression::types::nullable::NullableColumn;
+use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::*;
+use common_expression::types::string::StringColumn;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::timestamp::string_to_timestamp;
 use common_expression::types::variant::cast_scalar_to_variant;
 use common_expression::types::variant::cast_scalars_to_variants;
 use common_expression::types::variant::JSONB_NULL;
 use common_expression::types::AnyType;
+use common_expression::types::ArrayType;
 use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
@@ -38,6 +41,7 @@ use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::types::TimestampType;
+use common_expression::types::ValueType;
 use common_expression::types::VariantType;
 use common_expression::types::ALL_NUMERICS_TYPES;
 use common_expression::utils::arrow::constant_bitmap;
@@ -52,6 +56,7 @@ use common_expression::EvalContext;
 use common_expression::Function;
 use common_expression::FunctionDomain;
 use common_expression::FunctionEval;
+use common_expression::FunctionMeta;
 use common_expression::FunctionRegistry;
 use common_expression::FunctionSignature;
 use common_expression::Scalar;
@@ -63,6 +68,7 @@ use jsonb::as_bool;
 use jsonb::as_f64;
 use jsonb::as_i64;
 use jsonb::as_str;
+use jsonb::build_array;
 use jsonb::build_object;
 use jsonb::get_by_name_ignore_case;
 use jsonb::get_by_path;
@@ -85,23 +91,18 @@ pub fn register(registry: &mut FunctionRegistry) {
         "parse_json",
         |_| FunctionDomain::MayThrow,
         vectorize_with_builder_1_arg::<StringType, VariantType>(|s, output, ctx| {
-            if s.trim().is_empty() {
-                output.put_slice(JSONB_NULL);
-                output.commit_row();
-                return;
-            }
-            match parse_value(s) {
-                Ok(value) => {
-                    value.write_to_vec(&mut output.data);
-                }
-                Err(err) => {
-                    ctx.set_error(output.len(), err.to_string());
-                }
-            }
-            output.commit_row();
+            parse_json_fn(s, false, output, ctx)
         }),
     );
 
+    registry.register_passthrough_nullable_2_arg::<StringType, BooleanType, VariantType, _, _>(
+        "parse_json",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, BooleanType, VariantType>(
+            |s, strict, output, ctx| parse_json_fn(s, strict, output, ctx),
+        ),
+    );
+
     registry.register_combine_nullable_1_arg::<StringType, VariantType, _, _>(
         "try_parse_json",
         |_| FunctionDomain::Full,
@@ -152,6 +153,42 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry
+        .register_combine_nullable_2_arg::<VariantType, ArrayType<StringType>, VariantType, _, _>(
+            "object_pick",
+            |_, _| FunctionDomain::Full,
+            vectorize_with_builder_2_arg::<
+                VariantType,
+                ArrayType<StringType>,
+                NullableType<VariantType>,
+            >(|val, keys, output, _| object_pick_or_omit(val, keys, true, output)),
+        );
+
+    registry
+        .register_combine_nullable_2_arg::<VariantType, ArrayType<StringType>, VariantType, _, _>(
+            "object_omit",
+            |_, _| FunctionDomain::Full,
+            vectorize_with_builder_2_arg::<
+                VariantType,
+                ArrayType<StringType>,
+                NullableType<VariantType>,
+            >(|val, keys, output, _| object_pick_or_omit(val, keys, false, output)),
+        );
+
+    registry.register_2_arg_core::<NullableType<VariantType>, NullableType<StringType>, NullableType<VariantType>, _, _>(
+        "object_pick_value",
+        |_, _| FunctionDomain::Full,
+        vectorize_2_arg::<NullableType<VariantType>, NullableType<StringType>, NullableType<VariantType>>(|val, key, _| {
+            match (val, key) {
+                (Some(val), Some(key)) if is_object(val) => {
+                    let key = std::str::from_utf8(key).ok()?;
+                    get_by_path(val, vec![JsonPathRef::String(Cow::Borrowed(key))])
+                }
+                (_, _) => None,
+            }
+        }),
+    );
+
     registry.register_2_arg_core::<NullableType<VariantType>, NullableType<StringType>, NullableType<VariantType>, _, _>(
         "get",
         |_, _| FunctionDomain::MayThrow,
@@ -243,6 +280,10 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.register_meta("json_extract_path_text", FunctionMeta {
+        description: "Extracts the value at the path from a JSON string and returns it as a string, or NULL if the path does not exist.",
+        example: r#"SELECT json_extract_path_text('{"k1":[0,1,2], "k2":{"k3":3}}', 'k2:k3'); -- 3"#,
+    });
     registry.register_combine_nullable_2_arg::<StringType, StringType, StringType, _, _>(
         "json_extract_path_text",
         |_, _| FunctionDomain::MayThrow,
@@ -659,6 +700,265 @@ pub fn register(registry: &mut FunctionRegistry) {
             },
         }))
     });
+
+    registry.register_function_factory("json_array", |_, args_type| {
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "json_array".to_string(),
+                args_type: (0..args_type.len()).map(DataType::Generic).collect(),
+                return_type: DataType::Variant,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_| FunctionDomain::MayThrow),
+                eval: Box::new(json_array_fn),
+            },
+        }))
+    });
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, StringType, _, _>(
+        "json_pretty",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<VariantType, StringType>(|val, output, ctx| {
+            match jsonb::from_slice(val) {
+                Ok(value) => write_pretty_json(&value, 0, &mut output.data),
+                Err(err) => ctx.set_error(output.len(), err.to_string()),
+            }
+            output.commit_row();
+        }),
+    );
+
+    for (name, mode) in [
+        ("json_set", JsonSetMode::Set),
+        ("json_insert", JsonSetMode::Insert),
+        ("json_replace", JsonSetMode::Replace),
+    ] {
+        registry.register_function_factory(name, move |_, args_type| {
+            if args_type.len() != 3 || args_type[..2].iter().any(|t| t.is_nullable_or_null()) {
+                return None;
+            }
+            Some(Arc::new(Function {
+                signature: FunctionSignature {
+                    name: name.to_string(),
+                    args_type: vec![DataType::Variant, DataType::String, args_type[2].clone()],
+                    return_type: DataType::Variant,
+                },
+                eval: FunctionEval::Scalar {
+                    calc_domain: Box::new(|_| FunctionDomain::MayThrow),
+                    eval: Box::new(move |args, ctx| json_set_fn(args, ctx, mode, false)),
+                },
+            }))
+        });
+
+        // NULL objects or paths produce NULL, while a NULL value is set as a JSON null.
+        registry.register_function_factory(name, move |_, args_type| {
+            if args_type.len() != 3 {
+                return None;
+            }
+            Some(Arc::new(Function {
+                signature: FunctionSignature {
+                    name: name.to_string(),
+                    args_type: vec![
+                        DataType::Nullable(Box::new(DataType::Variant)),
+                        DataType::Nullable(Box::new(DataType::String)),
+                        args_type[2].clone(),
+                    ],
+                    return_type: DataType::Nullable(Box::new(DataType::Variant)),
+                },
+                eval: FunctionEval::Scalar {
+                    calc_domain: Box::new(|_| FunctionDomain::MayThrow),
+                    eval: Box::new(move |args, ctx| json_set_fn(args, ctx, mode, true)),
+                },
+            }))
+        });
+    }
+}
+
+/// Keep (`pick`) or remove the top-level `keys` of a JSON object, non-object values produce NULL.
+fn object_pick_or_omit(
+    val: &[u8],
+    keys: StringColumn,
+    pick: bool,
+    output: &mut NullableColumnBuilder<VariantType>,
+) {
+    match jsonb::from_slice(val) {
+        Ok(jsonb::Value::Object(fields)) => {
+            let keys = keys.iter().collect::<HashSet<_>>();
+            let fields = fields
+                .into_iter()
+                .filter(|(key, _)| keys.contains(key.as_bytes()) == pick)
+                .collect();
+            let mut buf = Vec::new();
+            jsonb::Value::Object(fields).write_to_vec(&mut buf);
+            output.push(&buf);
+        }
+        _ => output.push_null(),
+    }
+}
+
+/// How `json_set`, `json_insert` and `json_replace` treat the value at the path.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum JsonSetMode {
+    /// Insert the value or replace the existing one.
+    Set,
+    /// Only insert the value if the path does not exist.
+    Insert,
+    /// Only replace the value if the path exists.
+    Replace,
+}
+
+/// Invalid JSON is an error only in strict mode, otherwise it is a JSON null.
+fn parse_json_fn(s: &[u8], strict: bool, output: &mut StringColumnBuilder, ctx: &mut EvalContext) {
+    if s.trim().is_empty() {
+        output.put_slice(JSONB_NULL);
+        output.commit_row();
+        return;
+    }
+    match parse_value(s) {
+        Ok(value) => {
+            value.write_to_vec(&mut output.data);
+        }
+        Err(err) => {
+            if strict {
+                ctx.set_error(output.len(), err.to_string());
+            } else {
+                output.put_slice(JSONB_NULL);
+            }
+        }
+    }
+    output.commit_row();
+}
+
+fn json_set_fn(
+    args: &[ValueRef<AnyType>],
+    ctx: &mut EvalContext,
+    mode: JsonSetMode,
+    nullable: bool,
+) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+
+    let mut builder = NullableColumnBuilder::<VariantType>::with_capacity(len.unwrap_or(1), &[]);
+    for idx in 0..len.unwrap_or(1) {
+        let val = unsafe { args[0].index_unchecked(idx) };
+        let path = unsafe { args[1].index_unchecked(idx) };
+        let (val, path) = match (val, path) {
+            (ScalarRef::Variant(val), ScalarRef::String(path)) => (val, path),
+            _ => {
+                builder.push_null();
+                continue;
+            }
+        };
+        let mut new_value = Vec::new();
+        cast_scalar_to_variant(
+            unsafe { args[2].index_unchecked(idx) },
+            ctx.tz,
+            &mut new_value,
+        );
+        match json_set_by_path(val, path, &new_value, mode) {
+            Ok(buf) => builder.push(&buf),
+            Err(err) => {
+                ctx.set_error(builder.len(), err);
+                builder.push(val);
+            }
+        }
+    }
+
+    match (len, nullable) {
+        (Some(_), true) => {
+            Value::Column(NullableType::<VariantType>::upcast_column(builder.build()))
+        }
+        (Some(_), false) => Value::Column(Column::Variant(builder.builder.build())),
+        (None, true) => Value::Scalar(NullableType::<VariantType>::upcast_scalar(
+            builder.build_scalar(),
+        )),
+        (None, false) => Value::Scalar(Scalar::Variant(builder.builder.build_scalar())),
+    }
+}
+
+/// Set `new_value` at `path` of the JSON value `val` according to `mode`.
+///
+/// The path is written like `$.items[0].price`, where the leading `$` is optional. Objects and
+/// arrays in the middle of the path are not created, so the value is returned unchanged if they
+/// are missing. An array index past the end of the array appends the new value.
+fn json_set_by_path(
+    val: &[u8],
+    path: &[u8],
+    new_value: &[u8],
+    mode: JsonSetMode,
+) -> Result<Vec<u8>, String> {
+    let json_path = path.strip_prefix(b"$").unwrap_or(path);
+    let json_path = json_path.strip_prefix(b".").unwrap_or(json_path);
+    let json_paths = if json_path.is_empty() {
+        vec![]
+    } else {
+        parse_json_path(json_path).map_err(|err| {
+            format!(
+                "Invalid JSON path '{}': {}",
+                &String::from_utf8_lossy(path),
+                err
+            )
+        })?
+    };
+
+    let mut value = jsonb::from_slice(val).map_err(|err| format!("Invalid JSON value: {err}"))?;
+    let new_value =
+        jsonb::from_slice(new_value).map_err(|err| format!("Invalid JSON value: {err}"))?;
+    if json_paths.is_empty() {
+        // `$` refers to the whole value, which always exists.
+        if mode != JsonSetMode::Insert {
+            value = new_value;
+        }
+    } else {
+        set_value_by_path(&mut value, &json_paths, new_value, mode);
+    }
+
+    let mut buf = Vec::new();
+    value.write_to_vec(&mut buf);
+    Ok(buf)
+}
+
+fn set_value_by_path<'a>(
+    value: &mut jsonb::Value<'a>,
+    json_paths: &[JsonPathRef],
+    new_value: jsonb::Value<'a>,
+    mode: JsonSetMode,
+) {
+    let (json_path, rest) = match json_paths.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    match (value, json_path) {
+        (jsonb::Value::Object(fields), JsonPathRef::String(key)) => {
+            if !rest.is_empty() {
+                if let Some(field) = fields.get_mut(key.as_ref()) {
+                    set_value_by_path(field, rest, new_value, mode);
+                }
+            } else if fields.contains_key(key.as_ref()) {
+                if mode != JsonSetMode::Insert {
+                    fields.insert(key.to_string(), new_value);
+                }
+            } else if mode != JsonSetMode::Replace {
+                fields.insert(key.to_string(), new_value);
+            }
+        }
+        (jsonb::Value::Array(items), JsonPathRef::UInt64(index)) => {
+            let index = *index as usize;
+            if !rest.is_empty() {
+                if let Some(item) = items.get_mut(index) {
+                    set_value_by_path(item, rest, new_value, mode);
+                }
+            } else if index < items.len() {
+                if mode != JsonSetMode::Insert {
+                    items[index] = new_value;
+                }
+            } else if mode != JsonSetMode::Replace {
+                items.push(new_value);
+            }
+        }
+        _ => {}
+    }
 }
 
 fn json_object_fn(
@@ -732,3 +1032,77 @@ fn json_object_fn(
         None => Value::Scalar(Scalar::Variant(builder.build_scalar())),
     }
 }
+
+fn json_array_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+
+    let cap = len.unwrap_or(1);
+    let mut builder = StringColumnBuilder::with_capacity(cap, cap * 50);
+    let mut items = Vec::with_capacity(args.len());
+    for idx in 0..cap {
+        items.clear();
+        for arg in args {
+            let v = match arg {
+                ValueRef::Scalar(s) => s.clone(),
+                ValueRef::Column(col) => unsafe { col.index_unchecked(idx) },
+            };
+            let mut val = vec![];
+            cast_scalar_to_variant(v, ctx.tz, &mut val);
+            items.push(val);
+        }
+        if let Err(err) = build_array(items.iter().map(|v| &v[..]), &mut builder.data) {
+            ctx.set_error(builder.len(), err.to_string());
+        }
+        builder.commit_row();
+    }
+    match len {
+        Some(_) => Value::Column(Column::Variant(builder.build())),
+        None => Value::Scalar(Scalar::Variant(builder.build_scalar())),
+    }
+}
+
+/// Writes the JSON text of the value with each array item and object field on its own
+/// line, indented by two spaces per level.
+fn write_pretty_json(value: &jsonb::Value, depth: usize, buf: &mut Vec<u8>) {
+    fn write_indent(depth: usize, buf: &mut Vec<u8>) {
+        buf.push(b'\n');
+        buf.extend(std::iter::repeat(b' ').take(depth * 2));
+    }
+
+    match value {
+        jsonb::Value::Array(items) if !items.is_empty() => {
+            buf.push(b'[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    buf.push(b',');
+                }
+                write_indent(depth + 1, buf);
+                write_pretty_json(item, depth + 1, buf);
+            }
+            write_indent(depth, buf);
+            buf.push(b']');
+        }
+        jsonb::Value::Object(fields) if !fields.is_empty() => {
+            buf.push(b'{');
+            for (i, (key, field)) in fields.iter().enumerate() {
+                if i > 0 {
+                    buf.push(b',');
+                }
+                write_indent(depth + 1, buf);
+                write_pretty_json(&jsonb::Value::String(Cow::Borrowed(key)), depth + 1, buf);
+                buf.extend_from_slice(b": ");
+                write_pretty_json(field, depth + 1, buf);
+            }
+            write_indent(depth, buf);
+            buf.push(b'}');
+        }
+        _ => {
+            let mut val = vec![];
+            value.write_to_vec(&mut val);
+            buf.extend_from_slice(jsonb::to_string(&val).as_bytes());
+        }
+    }
+}
@@ -0,0 +1,305 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Z-order (Morton code) keys.
+//!
+//! Every value is first normalized to an `u64` which keeps the order of the values, then
+//! the bits of the normalized values are interleaved, from the most significant bit down,
+//! so the rows close to each other in every dimension get close keys.
+
+use std::sync::Arc;
+
+use common_expression::types::boolean::BooleanDomain;
+use common_expression::types::nullable::NullableDomain;
+use common_expression::types::number::NumberDomain;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::SimpleDomain;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::string::StringDomain;
+use common_expression::types::AnyType;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::Domain;
+use common_expression::EvalContext;
+use common_expression::Function;
+use common_expression::FunctionDomain;
+use common_expression::FunctionEval;
+use common_expression::FunctionRegistry;
+use common_expression::FunctionSignature;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub const ZORDER_KEY_FUNC_NAME: &str = "zorder_key";
+
+/// The bits of the key are shared by the dimensions, more dimensions leave fewer
+/// bits to each of them.
+pub const MAX_ZORDER_DIMENSIONS: usize = 8;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases(ZORDER_KEY_FUNC_NAME, &["zorder"]);
+
+    registry.register_function_factory(ZORDER_KEY_FUNC_NAME, |_, args_type| {
+        if args_type.is_empty()
+            || args_type.len() > MAX_ZORDER_DIMENSIONS
+            || !args_type.iter().all(is_zorder_supported_type)
+        {
+            return None;
+        }
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: ZORDER_KEY_FUNC_NAME.to_string(),
+                args_type: args_type.to_vec(),
+                return_type: DataType::Number(NumberDataType::UInt64),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_| FunctionDomain::Full),
+                eval: Box::new(eval_zorder_key),
+            },
+        }))
+    });
+}
+
+fn eval_zorder_key(args: &[ValueRef<AnyType>], _: &mut EvalContext) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+
+    let mut values = vec![0; args.len()];
+    let mut keys = Vec::with_capacity(len.unwrap_or(1));
+    for row in 0..len.unwrap_or(1) {
+        for (value, arg) in values.iter_mut().zip(args) {
+            *value = match arg {
+                ValueRef::Scalar(scalar) => normalize(scalar.clone()),
+                ValueRef::Column(col) => normalize(unsafe { col.index_unchecked(row) }),
+            };
+        }
+        keys.push(interleave(&values));
+    }
+
+    match len {
+        Some(_) => Value::Column(UInt64Type::upcast_column(keys.into())),
+        None => Value::Scalar(Scalar::Number(NumberScalar::UInt64(keys[0]))),
+    }
+}
+
+pub fn is_zorder_supported_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        DataType::Boolean
+            | DataType::Number(_)
+            | DataType::String
+            | DataType::Date
+            | DataType::Timestamp
+    )
+}
+
+/// Maps the value to an `u64` keeping the order of the values, the significant bits
+/// are placed at the top. `NULL` is mapped to 0, the same as the smallest value.
+pub fn normalize(value: ScalarRef) -> u64 {
+    match value {
+        ScalarRef::Boolean(v) => (v as u64) << 63,
+        ScalarRef::Number(NumberScalar::UInt8(v)) => (v as u64) << 56,
+        ScalarRef::Number(NumberScalar::UInt16(v)) => (v as u64) << 48,
+        ScalarRef::Number(NumberScalar::UInt32(v)) => (v as u64) << 32,
+        ScalarRef::Number(NumberScalar::UInt64(v)) => v,
+        ScalarRef::Number(NumberScalar::Int8(v)) => (((v as u8) ^ (1 << 7)) as u64) << 56,
+        ScalarRef::Number(NumberScalar::Int16(v)) => (((v as u16) ^ (1 << 15)) as u64) << 48,
+        ScalarRef::Number(NumberScalar::Int32(v)) | ScalarRef::Date(v) => {
+            (((v as u32) ^ (1 << 31)) as u64) << 32
+        }
+        ScalarRef::Number(NumberScalar::Int64(v)) | ScalarRef::Timestamp(v) => {
+            (v as u64) ^ (1 << 63)
+        }
+        ScalarRef::Number(NumberScalar::Float32(v)) => {
+            let bits = v.0.to_bits();
+            let bits = if bits >> 31 == 1 {
+                !bits
+            } else {
+                bits | 1 << 31
+            };
+            (bits as u64) << 32
+        }
+        ScalarRef::Number(NumberScalar::Float64(v)) => {
+            let bits = v.0.to_bits();
+            if bits >> 63 == 1 {
+                !bits
+            } else {
+                bits | 1 << 63
+            }
+        }
+        ScalarRef::String(v) => {
+            let mut bytes = [0u8; 8];
+            let len = v.len().min(8);
+            bytes[..len].copy_from_slice(&v[..len]);
+            u64::from_be_bytes(bytes)
+        }
+        _ => 0,
+    }
+}
+
+/// Interleaves the bits of the normalized values, each of them takes the same number
+/// of bits, the remaining low bits of the key are zero.
+pub fn interleave(values: &[u64]) -> u64 {
+    let dims = values.len();
+    let bits_per_dim = 64 / dims;
+    let mut key = 0u64;
+    for bit in 0..bits_per_dim {
+        for value in values {
+            key = (key << 1) | ((value >> (63 - bit)) & 1);
+        }
+    }
+    key << (64 - bits_per_dim * dims)
+}
+
+/// Returns the bounding box of the keys which share the top `prefix_len` bits with `key`,
+/// as the inclusive range of the normalized values of every dimension.
+pub fn zorder_cell(key: u64, prefix_len: u32, dims: usize) -> Vec<(u64, u64)> {
+    let used_bits = (64 / dims * dims) as u32;
+    let mut cell = vec![(0u64, u64::MAX); dims];
+    let mut known_bits = vec![0u32; dims];
+    for pos in 0..prefix_len.min(used_bits) {
+        let dim = pos as usize % dims;
+        let bit = (key >> (63 - pos)) & 1;
+        let shift = 63 - known_bits[dim];
+        cell[dim].0 |= bit << shift;
+        if bit == 0 {
+            cell[dim].1 &= !(1 << shift);
+        }
+        known_bits[dim] += 1;
+    }
+    cell
+}
+
+/// Splits the keys in `[min, max]` into the cells of the Z-order curve, and returns
+/// the bounding box of each cell, see [`zorder_cell`].
+///
+/// As the Litwin-Lomet range check, the range is split at the first differing bit
+/// of its bounds, so that the cells cover the range tighter than a single bounding box.
+/// The range is split `max_depth` times at most, `min` must not be greater than `max`.
+pub fn zorder_cells(min: u64, max: u64, dims: usize, max_depth: usize) -> Vec<Vec<(u64, u64)>> {
+    let mut cells = vec![];
+    split_zorder_range(min, max, dims, max_depth, &mut cells);
+    cells
+}
+
+fn split_zorder_range(
+    min: u64,
+    max: u64,
+    dims: usize,
+    depth: usize,
+    cells: &mut Vec<Vec<(u64, u64)>>,
+) {
+    let prefix_len = (min ^ max).leading_zeros();
+    let low_mask = u64::MAX.checked_shr(prefix_len).unwrap_or(0);
+    let is_whole_cell = min & low_mask == 0 && max & low_mask == low_mask;
+    if depth == 0 || is_whole_cell {
+        cells.push(zorder_cell(min, prefix_len, dims));
+        return;
+    }
+
+    // `min` has 0 and `max` has 1 at the first differing bit.
+    let left_max = (min & !low_mask) | (low_mask >> 1);
+    split_zorder_range(min, left_max, dims, depth - 1, cells);
+    split_zorder_range(left_max + 1, max, dims, depth - 1, cells);
+}
+
+/// Returns the domain of the values whose normalized values are in `[min, max]`, the
+/// inverse of [`normalize`].
+pub fn denormalize_domain(data_type: &DataType, min: u64, max: u64) -> Domain {
+    macro_rules! number_domain {
+        ($variant:ident, $ty:ty, $bits:expr) => {{
+            let flip: u64 = if <$ty>::MIN == 0 as $ty {
+                0
+            } else {
+                1 << ($bits - 1)
+            };
+            let min = ((min >> (64 - $bits)) ^ flip) as $ty;
+            let max = ((max >> (64 - $bits)) ^ flip) as $ty;
+            Domain::Number(NumberDomain::$variant(SimpleDomain { min, max }))
+        }};
+    }
+
+    match data_type {
+        DataType::Nullable(box inner) => Domain::Nullable(NullableDomain {
+            has_null: min == 0,
+            value: Some(Box::new(denormalize_domain(inner, min, max))),
+        }),
+        DataType::Boolean => Domain::Boolean(BooleanDomain {
+            has_false: min >> 63 == 0,
+            has_true: max >> 63 == 1,
+        }),
+        DataType::Number(NumberDataType::UInt8) => number_domain!(UInt8, u8, 8),
+        DataType::Number(NumberDataType::UInt16) => number_domain!(UInt16, u16, 16),
+        DataType::Number(NumberDataType::UInt32) => number_domain!(UInt32, u32, 32),
+        DataType::Number(NumberDataType::UInt64) => number_domain!(UInt64, u64, 64),
+        DataType::Number(NumberDataType::Int8) => number_domain!(Int8, i8, 8),
+        DataType::Number(NumberDataType::Int16) => number_domain!(Int16, i16, 16),
+        DataType::Number(NumberDataType::Int32) => number_domain!(Int32, i32, 32),
+        DataType::Number(NumberDataType::Int64) => number_domain!(Int64, i64, 64),
+        DataType::Number(NumberDataType::Float32) => {
+            let denormalize = |v: u64| {
+                let bits = (v >> 32) as u32;
+                let bits = if bits >> 31 == 1 {
+                    bits & !(1 << 31)
+                } else {
+                    !bits
+                };
+                F32::from(f32::from_bits(bits))
+            };
+            Domain::Number(NumberDomain::Float32(SimpleDomain {
+                min: denormalize(min),
+                max: denormalize(max),
+            }))
+        }
+        DataType::Number(NumberDataType::Float64) => {
+            let denormalize = |v: u64| {
+                let bits = if v >> 63 == 1 { v & !(1 << 63) } else { !v };
+                F64::from(f64::from_bits(bits))
+            };
+            Domain::Number(NumberDomain::Float64(SimpleDomain {
+                min: denormalize(min),
+                max: denormalize(max),
+            }))
+        }
+        DataType::Date => Domain::Date(SimpleDomain {
+            min: ((min >> 32) ^ (1 << 31)) as i32,
+            max: ((max >> 32) ^ (1 << 31)) as i32,
+        }),
+        DataType::Timestamp => Domain::Timestamp(SimpleDomain {
+            min: (min ^ (1 << 63)) as i64,
+            max: (max ^ (1 << 63)) as i64,
+        }),
+        DataType::String => {
+            // Only the first 8 bytes are kept, the strings sharing the prefix of `max`
+            // are less than the prefix with the last byte increased.
+            let mut min = min.to_be_bytes().to_vec();
+            while min.last() == Some(&0) {
+                min.pop();
+            }
+            let mut max = max.to_be_bytes().to_vec();
+            while max.last() == Some(&u8::MAX) {
+                max.pop();
+            }
+            let max = max.last_mut().map(|last| *last += 1).map(|_| max);
+            Domain::String(StringDomain { min, max })
+        }
+        _ => Domain::full(data_type),
+    }
+}
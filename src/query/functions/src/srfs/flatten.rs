@@ -0,0 +1,255 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+
+use common_expression::date_helper::TzLUT;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::variant::cast_scalar_to_variant;
+use common_expression::types::AnyType;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Function;
+use common_expression::FunctionEval;
+use common_expression::FunctionKind;
+use common_expression::FunctionProperty;
+use common_expression::FunctionRegistry;
+use common_expression::FunctionSignature;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_expression::ValueRef;
+use jsonb::get_by_path;
+use jsonb::parse_json_path;
+
+/// Names of the columns returned by `flatten`, in the order of the fields of its tuple result.
+pub const FLATTEN_COLUMNS: [&str; 6] = ["seq", "key", "path", "index", "value", "this"];
+
+/// Names of the arguments of `flatten`, in positional order.
+pub const FLATTEN_ARGS: [&str; 4] = ["input", "path", "outer", "recursive"];
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.properties.insert(
+        "flatten".to_string(),
+        FunctionProperty::default().kind(FunctionKind::SRF),
+    );
+
+    let return_type = DataType::Tuple(vec![
+        DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64))),
+        DataType::Nullable(Box::new(DataType::String)),
+        DataType::Nullable(Box::new(DataType::String)),
+        DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64))),
+        DataType::Nullable(Box::new(DataType::Variant)),
+        DataType::Nullable(Box::new(DataType::Variant)),
+    ]);
+
+    let input_types = [
+        DataType::Variant,
+        DataType::Nullable(Box::new(DataType::Variant)),
+        DataType::Array(Box::new(DataType::Generic(0))),
+        DataType::Nullable(Box::new(DataType::Array(Box::new(DataType::Generic(0))))),
+    ];
+    let optional_args_type = [DataType::String, DataType::Boolean, DataType::Boolean];
+
+    for input_type in input_types {
+        for num_optional_args in 0..=optional_args_type.len() {
+            let mut args_type = vec![input_type.clone()];
+            args_type.extend_from_slice(&optional_args_type[..num_optional_args]);
+            let return_type = return_type.clone();
+            registry.register_function(Function {
+                signature: FunctionSignature {
+                    name: "flatten".to_string(),
+                    args_type,
+                    return_type: return_type.clone(),
+                },
+                eval: FunctionEval::SRF {
+                    eval: Box::new(move |args, num_rows| {
+                        flatten_impl(args, num_rows, &return_type)
+                    }),
+                },
+            });
+        }
+    }
+}
+
+fn flatten_impl(
+    args: &[ValueRef<AnyType>],
+    num_rows: usize,
+    return_type: &DataType,
+) -> Vec<(Value<AnyType>, usize)> {
+    let field_types = return_type.as_tuple().unwrap();
+
+    (0..num_rows)
+        .map(|row| {
+            let path = match args.get(1).and_then(|arg| arg.index(row)) {
+                Some(ScalarRef::String(path)) => path,
+                _ => &[],
+            };
+            let outer = matches!(
+                args.get(2).and_then(|arg| arg.index(row)),
+                Some(ScalarRef::Boolean(true))
+            );
+            let recursive = matches!(
+                args.get(3).and_then(|arg| arg.index(row)),
+                Some(ScalarRef::Boolean(true))
+            );
+
+            let mut builder = FlattenBuilder::new(field_types, row as u64 + 1, recursive);
+            if let Some(input) = input_to_variant(args[0].index(row).unwrap()) {
+                builder.flatten_variant(&input, path);
+            }
+            // With `outer`, an input that produces no rows still yields a single row of NULLs.
+            if builder.len == 0 && outer {
+                builder.push_null_row();
+            }
+            builder.build()
+        })
+        .collect()
+}
+
+/// Convert the input of `flatten` into a JSONB value, `None` if the input is NULL.
+///
+/// Variant inputs are borrowed, only the other types are encoded.
+fn input_to_variant(input: ScalarRef) -> Option<Cow<[u8]>> {
+    match input {
+        ScalarRef::Null => None,
+        ScalarRef::Variant(bytes) => Some(Cow::Borrowed(bytes)),
+        scalar => {
+            let mut buf = vec![];
+            cast_scalar_to_variant(scalar, TzLUT::default(), &mut buf);
+            Some(Cow::Owned(buf))
+        }
+    }
+}
+
+/// Builds the output columns of `flatten` for one input row.
+///
+/// The rows are pushed into the column builders directly, and every element is encoded only
+/// once: the encoded value of a nested container is reused as the `this` of its elements in
+/// the recursive mode.
+struct FlattenBuilder {
+    builders: Vec<ColumnBuilder>,
+    seq: u64,
+    recursive: bool,
+    len: usize,
+}
+
+impl FlattenBuilder {
+    fn new(field_types: &[DataType], seq: u64, recursive: bool) -> Self {
+        let builders = field_types
+            .iter()
+            .map(|ty| ColumnBuilder::with_capacity(ty, 1))
+            .collect();
+        FlattenBuilder {
+            builders,
+            seq,
+            recursive,
+            len: 0,
+        }
+    }
+
+    /// Flatten the JSON array or object found at `path` of `input`.
+    fn flatten_variant(&mut self, input: &[u8], path: &[u8]) {
+        if input.is_empty() {
+            return;
+        }
+        let target = if path.is_empty() {
+            Cow::Borrowed(input)
+        } else {
+            let json_path = match parse_json_path(path) {
+                Ok(json_path) => json_path,
+                Err(_) => return,
+            };
+            match get_by_path(input, json_path) {
+                Some(target) => Cow::Owned(target),
+                None => return,
+            }
+        };
+
+        let prefix = String::from_utf8_lossy(path).to_string();
+        if let Ok(value) = jsonb::from_slice(&target) {
+            self.flatten_value(&value, &target, &prefix);
+        }
+    }
+
+    fn flatten_value(&mut self, value: &jsonb::Value, this: &[u8], prefix: &str) {
+        match value {
+            jsonb::Value::Array(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    let path = format!("{prefix}[{i}]");
+                    self.push_item(None, Some(i as u64), &path, item, this);
+                }
+            }
+            jsonb::Value::Object(fields) => {
+                for (key, item) in fields.iter() {
+                    let path = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{prefix}.{key}")
+                    };
+                    self.push_item(Some(key.as_str()), None, &path, item, this);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn push_item(
+        &mut self,
+        key: Option<&str>,
+        index: Option<u64>,
+        path: &str,
+        item: &jsonb::Value,
+        this: &[u8],
+    ) {
+        let mut value = vec![];
+        item.write_to_vec(&mut value);
+
+        self.builders[0].push(ScalarRef::Number(NumberScalar::UInt64(self.seq)));
+        self.builders[1].push(match key {
+            Some(key) => ScalarRef::String(key.as_bytes()),
+            None => ScalarRef::Null,
+        });
+        self.builders[2].push(ScalarRef::String(path.as_bytes()));
+        self.builders[3].push(match index {
+            Some(index) => ScalarRef::Number(NumberScalar::UInt64(index)),
+            None => ScalarRef::Null,
+        });
+        self.builders[4].push(ScalarRef::Variant(&value));
+        self.builders[5].push(ScalarRef::Variant(this));
+        self.len += 1;
+
+        if self.recursive && matches!(item, jsonb::Value::Array(_) | jsonb::Value::Object(_)) {
+            self.flatten_value(item, &value, path);
+        }
+    }
+
+    fn push_null_row(&mut self) {
+        self.builders[0].push(ScalarRef::Number(NumberScalar::UInt64(self.seq)));
+        for builder in self.builders.iter_mut().skip(1) {
+            builder.push(ScalarRef::Null);
+        }
+        self.len += 1;
+    }
+
+    fn build(self) -> (Value<AnyType>, usize) {
+        let columns = self
+            .builders
+            .into_iter()
+            .map(|builder| builder.build())
+            .collect::<Vec<_>>();
+        (Value::Column(Column::Tuple(columns)), self.len)
+    }
+}
@@ -27,7 +27,14 @@ use common_expression::Scalar;
 use common_expression::Value;
 use common_expression::ValueRef;
 
+mod flatten;
+
+pub use flatten::FLATTEN_ARGS;
+pub use flatten::FLATTEN_COLUMNS;
+
 pub fn register(registry: &mut FunctionRegistry) {
+    flatten::register(registry);
+
     registry.properties.insert(
         "unnest".to_string(),
         FunctionProperty::default().kind(FunctionKind::SRF),
@@ -0,0 +1,24 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Evaluators of the window functions that are not aggregate functions.
+//!
+//! An evaluator is fed the rows of one partition in order, and is reset
+//! before the first row of the next partition.
+
+mod window_lag_lead;
+mod window_nth_value;
+
+pub use window_lag_lead::WindowLagLead;
+pub use window_nth_value::WindowNthValue;
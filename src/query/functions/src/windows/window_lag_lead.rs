@@ -0,0 +1,103 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use common_expression::Scalar;
+
+/// `LAG(<expr>, <offset>, <default>)` and `LEAD(<expr>, <offset>, <default>)`.
+///
+/// With `IGNORE NULLS`, the NULL values of `<expr>` are skipped when counting
+/// the offset, so the result is the offset-th non-NULL value before (`LAG`) or
+/// after (`LEAD`) the current row. The default is returned if there is no such
+/// row in the partition.
+///
+/// `LAG` returns the result of a row as soon as the row is pushed. `LEAD` has
+/// to wait for the following rows, so the results are returned later in the
+/// order of the rows, and the rest are returned by [`WindowLagLead::finish`].
+pub struct WindowLagLead {
+    is_lag: bool,
+    offset: usize,
+    ignore_nulls: bool,
+    /// `LAG`: the last `offset` values that can be returned, nearest at the back.
+    history: VecDeque<Scalar>,
+    /// `LEAD`: the rows waiting for their result, with their default and the
+    /// number of values that could be returned seen up to the row.
+    pending: VecDeque<(Scalar, usize)>,
+    /// `LEAD`: number of values that could be returned seen in the partition.
+    seen: usize,
+}
+
+impl WindowLagLead {
+    pub fn create(is_lag: bool, offset: usize, ignore_nulls: bool) -> Self {
+        WindowLagLead {
+            is_lag,
+            offset,
+            ignore_nulls,
+            history: VecDeque::with_capacity(offset),
+            pending: VecDeque::new(),
+            seen: 0,
+        }
+    }
+
+    /// Drop the state of the current partition.
+    pub fn reset(&mut self) {
+        self.history.clear();
+        self.pending.clear();
+        self.seen = 0;
+    }
+
+    /// Push the next row of the partition, the results that become known are
+    /// appended to `results`.
+    pub fn push(&mut self, value: Scalar, default: Scalar, results: &mut Vec<Scalar>) {
+        if self.offset == 0 {
+            results.push(value);
+            return;
+        }
+        let countable = !(self.ignore_nulls && value.is_null());
+
+        if self.is_lag {
+            if self.history.len() == self.offset {
+                results.push(self.history.front().unwrap().clone());
+            } else {
+                results.push(default);
+            }
+            if countable {
+                if self.history.len() == self.offset {
+                    self.history.pop_front();
+                }
+                self.history.push_back(value);
+            }
+            return;
+        }
+
+        if countable {
+            self.seen += 1;
+            while let Some((_, seen)) = self.pending.front() {
+                if self.seen - seen < self.offset {
+                    break;
+                }
+                self.pending.pop_front();
+                results.push(value.clone());
+            }
+        }
+        self.pending.push_back((default, self.seen));
+    }
+
+    /// End of the partition, the rows still waiting get their defaults.
+    pub fn finish(&mut self, results: &mut Vec<Scalar>) {
+        results.extend(self.pending.drain(..).map(|(default, _)| default));
+        self.reset();
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use common_expression::Scalar;
+
+/// `NTH_VALUE(<expr>, <n>)`, the value of the n-th row (counting from 1) of
+/// the window frame, or NULL if the frame has less than n rows.
+///
+/// With `IGNORE NULLS`, the rows whose value is NULL are not counted.
+///
+/// The frame is kept in a deque: rows entering the frame are pushed to the
+/// back, and rows leaving it are popped from the front.
+pub struct WindowNthValue {
+    n: usize,
+    ignore_nulls: bool,
+    /// Values of the rows in the frame.
+    frame: VecDeque<Scalar>,
+    /// `IGNORE NULLS`: the non-NULL values of the rows in the frame.
+    non_nulls: VecDeque<Scalar>,
+}
+
+impl WindowNthValue {
+    pub fn create(n: usize, ignore_nulls: bool) -> Self {
+        debug_assert!(n > 0);
+        WindowNthValue {
+            n,
+            ignore_nulls,
+            frame: VecDeque::new(),
+            non_nulls: VecDeque::new(),
+        }
+    }
+
+    /// Drop the state of the current partition.
+    pub fn reset(&mut self) {
+        self.frame.clear();
+        self.non_nulls.clear();
+    }
+
+    /// A row enters the frame.
+    pub fn push_back(&mut self, value: Scalar) {
+        if self.ignore_nulls && !value.is_null() {
+            self.non_nulls.push_back(value.clone());
+        }
+        self.frame.push_back(value);
+    }
+
+    /// The first row of the frame leaves it.
+    pub fn pop_front(&mut self) {
+        if let Some(value) = self.frame.pop_front() {
+            if self.ignore_nulls && !value.is_null() {
+                self.non_nulls.pop_front();
+            }
+        }
+    }
+
+    /// The result of the current frame.
+    pub fn result(&self) -> Scalar {
+        let candidates = if self.ignore_nulls {
+            &self.non_nulls
+        } else {
+            &self.frame
+        };
+        candidates.get(self.n - 1).cloned().unwrap_or(Scalar::Null)
+    }
+}
@@ -14,14 +14,28 @@
 
 use std::io::Write;
 
+use bumpalo::Bump;
+use common_expression::types::bitmap::deserialize_bitmap;
+use common_expression::types::bitmap::serialize_bitmap;
 use common_expression::types::number::Int64Type;
+use common_expression::types::number::NumberScalar;
 use common_expression::types::number::UInt64Type;
+use common_expression::types::BitmapType;
 use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
 use common_expression::types::TimestampType;
+use common_expression::types::ValueType;
 use common_expression::Column;
+use common_expression::ColumnBuilder;
 use common_expression::FromData;
+use common_expression::Scalar;
 use common_functions::aggregates::eval_aggr;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::aggregates::OverflowMode;
+use common_functions::aggregates::StringAggParams;
 use goldenfile::Mint;
+use roaring::RoaringBitmap;
 
 use super::run_agg_ast;
 use super::simulate_two_groups_group_by;
@@ -81,6 +95,205 @@ fn test_agg_group_by() {
     test_agg_list(file, simulate_two_groups_group_by);
 }
 
+#[test]
+fn test_agg_geometry_partial_merge() -> common_exception::Result<()> {
+    // WKB encoded POINT(1 2), POINT(3 -1), LINESTRING(0 5, 2 6) and POINT(-4 0)
+    let geoms = [
+        "0101000000000000000000f03f0000000000000040",
+        "01010000000000000000000840000000000000f0bf",
+        "0102000000020000000000000000000000000000000000144000000000000000400000000000001840",
+        "010100000000000000000010c00000000000000000",
+    ];
+    let column = StringType::from_data(
+        geoms
+            .iter()
+            .map(|g| hex::decode(g).unwrap())
+            .collect::<Vec<_>>(),
+    );
+    let rows = column.len();
+
+    for name in ["st_collect", "st_extent"] {
+        let (expected, _) = eval_aggr(name, vec![], &[column.clone()], rows)?;
+
+        // Aggregate the two halves separately, then merge the serialized partial states.
+        let factory = AggregateFunctionFactory::instance();
+        let func = factory.get(name, vec![], vec![column.data_type()])?;
+        let arena = Bump::new();
+
+        let mut partial_states = vec![];
+        for range in [0..2, 2..rows] {
+            let addr = arena.alloc_layout(func.state_layout()).into();
+            func.init_state(addr);
+            func.accumulate(addr, &[column.slice(range.clone())], None, range.len())?;
+
+            let mut buf = vec![];
+            func.serialize(addr, &mut buf)?;
+            partial_states.push(buf);
+        }
+
+        let final_addr = arena.alloc_layout(func.state_layout()).into();
+        func.init_state(final_addr);
+        for buf in partial_states {
+            let addr = arena.alloc_layout(func.state_layout()).into();
+            func.init_state(addr);
+            func.deserialize(addr, &mut buf.as_slice())?;
+            func.merge(final_addr, addr)?;
+        }
+
+        let data_type = func.return_type()?;
+        let mut builder = ColumnBuilder::with_capacity(&data_type, 1);
+        func.merge_result(final_addr, &mut builder)?;
+
+        assert_eq!(expected, builder.build(), "function: {}", name);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_agg_bitmap_union() -> common_exception::Result<()> {
+    let bitmaps = [vec![1u32, 3, 5], vec![], vec![3, 4], vec![100_000, 1]];
+    let column = BitmapType::from_data(
+        bitmaps
+            .iter()
+            .map(|values| {
+                let mut buf = vec![];
+                serialize_bitmap(&values.iter().copied().collect(), &mut buf);
+                buf
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    let (result, data_type) = eval_aggr("bitmap_union", vec![], &[column], bitmaps.len())?;
+    assert_eq!(data_type, DataType::Bitmap);
+
+    let result = BitmapType::try_downcast_column(&result).unwrap();
+    let bitmap = deserialize_bitmap(result.index(0).unwrap()).unwrap();
+    let expected = [1u32, 3, 4, 5, 100_000]
+        .into_iter()
+        .collect::<RoaringBitmap>();
+    assert_eq!(expected, bitmap);
+
+    Ok(())
+}
+
+#[test]
+fn test_agg_listagg() -> common_exception::Result<()> {
+    let values = StringType::from_data_with_validity(vec!["b", "a", "x", "c", "d"], vec![
+        true, true, false, true, true,
+    ]);
+    let keys = Int64Type::from_data(vec![2i64, 1, 0, 3, 4]);
+    let rows = values.len();
+
+    let eval = |name: &str, params: StringAggParams, columns: &[Column]| {
+        let (result, _) = eval_aggr(name, params.into_params(), columns, rows)?;
+        Ok::<_, common_exception::ErrorCode>(result.index(0).unwrap().to_owned())
+    };
+    let string = |s: &str| Scalar::String(s.as_bytes().to_vec());
+
+    let mut params = StringAggParams {
+        delimiter: ",".to_string(),
+        descending: false,
+        overflow: None,
+    };
+    assert_eq!(
+        eval("string_agg", params.clone(), &[values.clone()])?,
+        string("b,a,c,d")
+    );
+
+    params.overflow = Some((1024, OverflowMode::Error));
+    let columns = [values.clone(), keys];
+    assert_eq!(
+        eval("listagg", params.clone(), &columns)?,
+        string("a,b,c,d")
+    );
+
+    params.descending = true;
+    assert_eq!(
+        eval("listagg", params.clone(), &columns)?,
+        string("d,c,b,a")
+    );
+
+    params.overflow = Some((3, OverflowMode::Truncate {
+        filler: "...".to_string(),
+        with_count: true,
+    }));
+    assert_eq!(
+        eval("listagg", params.clone(), &columns)?,
+        string("d,c,... + 2 more")
+    );
+
+    params.overflow = Some((3, OverflowMode::Truncate {
+        filler: "...".to_string(),
+        with_count: false,
+    }));
+    assert_eq!(
+        eval("listagg", params.clone(), &columns)?,
+        string("d,c,...")
+    );
+
+    params.overflow = Some((3, OverflowMode::Error));
+    let err = eval("listagg", params, &columns).unwrap_err();
+    assert_eq!(err.code(), common_exception::ErrorCode::RESULT_TOO_LARGE);
+
+    Ok(())
+}
+
+#[test]
+fn test_agg_percentile() -> common_exception::Result<()> {
+    let values = Int64Type::from_data_with_validity(vec![30i64, 10, 0, 40, 20], vec![
+        true, true, false, true, true,
+    ]);
+    let rows = values.len();
+
+    let eval = |name: &str, params: Vec<Scalar>, column: &Column| {
+        let (result, _) = eval_aggr(name, params, &[column.clone()], rows)?;
+        Ok::<_, common_exception::ErrorCode>(result.index(0).unwrap().to_owned())
+    };
+    let level = |level: f64| Scalar::Number(NumberScalar::Float64(level.into()));
+    let float = |v: f64| Scalar::Number(NumberScalar::Float64(v.into()));
+    let int = |v: i64| Scalar::Number(NumberScalar::Int64(v));
+
+    let desc = Scalar::Boolean(true);
+    let cases = [
+        // The continuous percentile interpolates between the two nearest values.
+        ("percentile_cont", vec![level(0.5)], float(25.0)),
+        ("percentile_cont", vec![level(0.25)], float(17.5)),
+        ("percentile_cont", vec![level(1.0)], float(40.0)),
+        (
+            "percentile_cont",
+            vec![level(0.25), desc.clone()],
+            float(32.5),
+        ),
+        ("median", vec![], float(25.0)),
+        // The discrete percentile is the first value whose cumulative distribution reaches the level.
+        ("percentile_disc", vec![level(0.5)], int(20)),
+        ("percentile_disc", vec![level(0.3)], int(20)),
+        ("percentile_disc", vec![level(0.25)], int(10)),
+        ("percentile_disc", vec![level(0.0)], int(10)),
+        ("percentile_disc", vec![level(1.0)], int(40)),
+        ("percentile_disc", vec![level(0.5), desc], int(30)),
+    ];
+    for (name, params, expected) in cases {
+        assert_eq!(
+            eval(name, params.clone(), &values)?,
+            expected,
+            "{name}{params:?}"
+        );
+    }
+
+    let strings = StringType::from_data(vec!["b", "a", "c"]);
+    assert_eq!(
+        eval("percentile_disc", vec![level(0.5)], &strings)?,
+        Scalar::String(b"b".to_vec())
+    );
+
+    let err = eval("percentile_disc", vec![level(1.5)], &values).unwrap_err();
+    assert_eq!(err.code(), common_exception::ErrorCode::BAD_DATA_VALUE_TYPE);
+
+    Ok(())
+}
+
 fn get_example() -> Vec<(&'static str, Column)> {
     vec![
         ("a", Int64Type::from_data(vec![4i64, 3, 2, 1])),
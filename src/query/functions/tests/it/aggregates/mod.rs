@@ -151,6 +151,7 @@ pub fn run_scalar_expr(
     let expr = type_check::check(raw_expr, &BUILTIN_FUNCTIONS)?;
     let func_ctx = FunctionContext {
         tz: TzLUT::default(),
+        now: None,
     };
     let evaluator = Evaluator::new(block, func_ctx, &BUILTIN_FUNCTIONS);
     let result = evaluator.run(&expr)?;
@@ -20,3 +20,4 @@
 // We can generate new test files via using `env REGENERATE_GOLDENFILES=1 cargo test` and `git diff` to show differs
 mod aggregates;
 mod scalars;
+mod windows;
@@ -45,6 +45,11 @@ fn test_array() {
     test_array_min(file);
     test_array_any(file);
     test_array_sort(file);
+    test_array_compact(file);
+    test_array_remove(file);
+    test_array_remove_at(file);
+    test_array_replace(file);
+    test_array_replace_at(file);
 }
 
 fn test_create(file: &mut impl Write) {
@@ -525,3 +530,73 @@ fn test_array_sort(file: &mut impl Write) {
     );
     run_ast(file, "array_sort([], 'ASC', 'NULLS LAST')", &[]);
 }
+
+fn test_array_compact(file: &mut impl Write) {
+    run_ast(file, "array_compact([])", &[]);
+    run_ast(file, "array_compact(NULL)", &[]);
+    run_ast(file, "array_compact([NULL, NULL])", &[]);
+    run_ast(file, "array_compact([1, 2])", &[]);
+    run_ast(file, "array_compact([1, NULL, 2, NULL])", &[]);
+    run_ast(file, "array_compact([a, b])", &[
+        (
+            "a",
+            Int16Type::from_data_with_validity(vec![1i16, 2, 3], vec![true, false, true]),
+        ),
+        (
+            "b",
+            Int16Type::from_data_with_validity(vec![4i16, 5, 6], vec![false, false, true]),
+        ),
+    ]);
+}
+
+fn test_array_remove(file: &mut impl Write) {
+    run_ast(file, "array_remove([], 1)", &[]);
+    run_ast(file, "array_remove(NULL, 1)", &[]);
+    run_ast(file, "array_remove([1, 2, 1, 3], 1)", &[]);
+    run_ast(file, "array_remove([1, NULL, 2, NULL], NULL)", &[]);
+    run_ast(file, "array_remove(['a', 'b', 'a'], 'a')", &[]);
+    run_ast(file, "array_remove([a, b], c)", &[
+        ("a", Int16Type::from_data(vec![1i16, 2, 3])),
+        ("b", Int16Type::from_data(vec![2i16, 2, 4])),
+        ("c", Int16Type::from_data(vec![1i16, 2, 3])),
+    ]);
+}
+
+fn test_array_remove_at(file: &mut impl Write) {
+    run_ast(file, "array_remove_at([], 1)", &[]);
+    run_ast(file, "array_remove_at(NULL, 1)", &[]);
+    run_ast(file, "array_remove_at([1, 2, 3], 2)", &[]);
+    run_ast(file, "array_remove_at([1, 2, 3], -1)", &[]);
+    run_ast(file, "array_remove_at([1, 2, 3], 0)", &[]);
+    run_ast(file, "array_remove_at([1, 2, 3], 4)", &[]);
+    run_ast(file, "array_remove_at([1, 2, 3], -4)", &[]);
+    run_ast(file, "array_remove_at(['a', NULL, 'c'], 2)", &[]);
+    run_ast(file, "array_remove_at([a, b, c], idx)", &[
+        ("a", Int16Type::from_data(vec![0i16, 1, 2])),
+        ("b", Int16Type::from_data(vec![3i16, 4, 5])),
+        ("c", Int16Type::from_data(vec![6i16, 7, 8])),
+        ("idx", Int64Type::from_data(vec![1i64, -1, 4])),
+    ]);
+}
+
+fn test_array_replace(file: &mut impl Write) {
+    run_ast(file, "array_replace([], 1, 2)", &[]);
+    run_ast(file, "array_replace(NULL, 1, 2)", &[]);
+    run_ast(file, "array_replace([1, 2, 1], 1, 3)", &[]);
+    run_ast(file, "array_replace([1, NULL, 2], NULL, 0)", &[]);
+    run_ast(file, "array_replace(['a', 'b'], 'c', 'd')", &[]);
+}
+
+fn test_array_replace_at(file: &mut impl Write) {
+    run_ast(file, "array_replace_at([], 1, 9)", &[]);
+    run_ast(file, "array_replace_at(NULL, 1, 'a')", &[]);
+    run_ast(file, "array_replace_at([1, 2, 3], 1, 9)", &[]);
+    run_ast(file, "array_replace_at([1, 2, 3], -1, NULL)", &[]);
+    run_ast(file, "array_replace_at([1, 2, 3], 5, 9)", &[]);
+    run_ast(file, "array_replace_at([a, b], idx, c)", &[
+        ("a", Int16Type::from_data(vec![0i16, 1, 2])),
+        ("b", Int16Type::from_data(vec![3i16, 4, 5])),
+        ("c", Int16Type::from_data(vec![6i16, 7, 8])),
+        ("idx", Int64Type::from_data(vec![1i64, -1, 3])),
+    ]);
+}
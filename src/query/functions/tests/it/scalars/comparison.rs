@@ -31,6 +31,7 @@ fn test_comparison() {
     test_lte(file);
     test_gt(file);
     test_gte(file);
+    test_is_distinct_from(file);
     test_like(file);
     test_regexp(file);
 }
@@ -349,6 +350,32 @@ fn test_gte(file: &mut impl Write) {
     run_ast(file, "lhs >= rhs", &table);
 }
 
+fn test_is_distinct_from(file: &mut impl Write) {
+    run_ast(file, "null is not distinct from null", &[]);
+    run_ast(file, "1 is not distinct from null", &[]);
+    run_ast(file, "null is distinct from null", &[]);
+    run_ast(file, "'a' is distinct from 'b'", &[]);
+    run_ast(file, "[1, 2] is not distinct from [1, 2]", &[]);
+    run_ast(
+        file,
+        "parse_json('[1,2]') is not distinct from parse_json('[1,2]')",
+        &[],
+    );
+
+    let table = [
+        (
+            "lhs",
+            Int64Type::from_data_with_validity(vec![1i64, 2, 0, 0], vec![true, true, false, false]),
+        ),
+        (
+            "rhs",
+            Int64Type::from_data_with_validity(vec![1i64, 3, 0, 4], vec![true, true, false, true]),
+        ),
+    ];
+    run_ast(file, "lhs is not distinct from rhs", &table);
+    run_ast(file, "lhs is distinct from rhs", &table);
+}
+
 fn test_like(file: &mut impl Write) {
     run_ast(file, "'1' like '2'", &[]);
     run_ast(file, "'hello\n' like 'h%'", &[]);
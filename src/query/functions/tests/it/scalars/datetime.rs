@@ -36,6 +36,7 @@ fn test_datetime() {
     test_timestamp_arith(file);
     test_to_number(file);
     test_rounder_functions(file);
+    test_timestamp_millis_and_diff(file);
 }
 
 fn test_to_timestamp(file: &mut impl Write) {
@@ -595,3 +596,16 @@ fn test_rounder_functions(file: &mut impl Write) {
     run_ast(file, "date_trunc(minute, to_timestamp(1630812366))", &[]);
     run_ast(file, "date_trunc(second, to_timestamp(1630812366))", &[]);
 }
+
+fn test_timestamp_millis_and_diff(file: &mut impl Write) {
+    run_ast(file, "add_milliseconds(to_timestamp(0), 1500)", &[]);
+    run_ast(file, "subtract_milliseconds(to_timestamp(0), 500)", &[]);
+    run_ast(file, "diff_seconds(a, b)", &[
+        ("a", TimestampType::from_data(vec![0, 100, -100])),
+        ("b", TimestampType::from_data(vec![1500, -400, 2000000])),
+    ]);
+    run_ast(file, "diff_milliseconds(a, b)", &[
+        ("a", TimestampType::from_data(vec![0, 100, -100])),
+        ("b", TimestampType::from_data(vec![1500, -400, 2000000])),
+    ]);
+}
@@ -0,0 +1,32 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_functions::fuzz::fuzz_builtin_functions;
+
+// It takes a while, run it via `env FUZZ_BUILTIN_FUNCTIONS_ROUNDS=10 cargo test fuzz`.
+#[test]
+fn test_fuzz_builtin_functions() {
+    let rounds = match std::env::var("FUZZ_BUILTIN_FUNCTIONS_ROUNDS") {
+        Ok(rounds) => rounds.parse().unwrap(),
+        Err(_) => return,
+    };
+
+    let failures = fuzz_builtin_functions(rounds);
+    assert!(
+        failures.is_empty(),
+        "{} failures:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
@@ -16,7 +16,11 @@ use std::io::Write;
 
 use common_expression::types::*;
 use common_expression::FromData;
+use common_functions::scalars::geography_distance;
+use common_functions::scalars::H3DistanceFilter;
 use goldenfile::Mint;
+use h3o::LatLng;
+use h3o::Resolution;
 
 use super::run_ast;
 
@@ -28,6 +32,8 @@ fn test_geo() {
     test_geo_to_h3(file);
     test_great_circle_distance(file);
     test_geo_distance(file);
+    test_geography_distance(file);
+    test_geography_within_distance(file);
     test_great_circle_angle(file);
     test_point_in_ellipses(file);
     test_point_in_polygon(file);
@@ -35,6 +41,56 @@ fn test_geo() {
     test_geohash_decode(file);
 }
 
+#[test]
+fn test_geography_distance_invalid() {
+    assert!(geography_distance(90.5, 0.0, 0.0, 0.0).is_err());
+    assert!(geography_distance(0.0, 0.0, 0.0, -180.5).is_err());
+    assert!(geography_distance(0.0, f64::NAN, 0.0, 0.0).is_err());
+}
+
+#[test]
+fn test_h3_distance_filter() {
+    // The points on a grid of 0.001 degrees around the center, the far ones are
+    // expected to be skipped if the distance is small enough.
+    let cases = [
+        (37.77, -122.42, 100.0, Resolution::Twelve, true),
+        (37.77, -122.42, 5_000.0, Resolution::Nine, false),
+        (89.99, 0.0, 2_000.0, Resolution::Nine, false),
+        (0.0, 179.999, 2_000.0, Resolution::Nine, false),
+        (37.77, -122.42, 50_000.0, Resolution::Fifteen, false),
+    ];
+    for (lat, lon, distance, index_resolution, expect_skipped) in cases {
+        let filter = H3DistanceFilter::try_create(lat, lon, distance, index_resolution).unwrap();
+        let (mut within, mut skipped) = (0, 0);
+        for i in -100..=100 {
+            for j in -100..=100 {
+                let (p_lat, mut p_lon) = (lat + i as f64 * 0.001, lon + j as f64 * 0.001);
+                if !(-90.0..=90.0).contains(&p_lat) {
+                    continue;
+                }
+                if p_lon > 180.0 {
+                    p_lon -= 360.0;
+                }
+                let cell = LatLng::new(p_lat, p_lon).unwrap().to_cell(index_resolution);
+                let may_contain = filter.may_contain(cell);
+                if geography_distance(lat, lon, p_lat, p_lon).unwrap() <= distance {
+                    assert!(
+                        may_contain,
+                        "({p_lat}, {p_lon}) is within {distance}m of ({lat}, {lon})"
+                    );
+                    within += 1;
+                } else if !may_contain {
+                    skipped += 1;
+                }
+            }
+        }
+        assert!(within > 0);
+        if expect_skipped {
+            assert!(skipped > 0, "nothing is skipped around ({lat}, {lon})");
+        }
+    }
+}
+
 fn test_geo_to_h3(file: &mut impl Write) {
     run_ast(file, "geo_to_h3(37.79506683, 55.71290588, 15)", &[]);
     run_ast(file, "geo_to_h3(lon, lat, 15)", &[
@@ -107,6 +163,17 @@ fn test_geo_distance(file: &mut impl Write) {
     run_ast(file, "geo_distance(lon1, lat1, lon2, lat2)", &table);
 }
 
+fn test_geography_distance(file: &mut impl Write) {
+    run_ast(file, "geography_distance(0, 0, 0, 0)", &[]);
+    run_ast(file, "round(geography_distance(0, 0, 0, 1), 2)", &[]);
+    run_ast(file, "round(geography_distance(40, 116, 31, 121), 2)", &[]);
+}
+
+fn test_geography_within_distance(file: &mut impl Write) {
+    run_ast(file, "geography_within_distance(0, 0, 0, 1, 111196)", &[]);
+    run_ast(file, "geography_within_distance(0, 0, 0, 1, 111195)", &[]);
+}
+
 fn test_great_circle_angle(file: &mut impl Write) {
     run_ast(file, "great_circle_angle(0, 0, 45, 0)", &[]);
     run_ast(file, "great_circle_angle(0, 0, a, 0)", &[(
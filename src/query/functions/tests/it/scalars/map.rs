@@ -27,6 +27,10 @@ fn test_map() {
 
     test_create(file);
     test_get(file);
+    test_map_keys(file);
+    test_map_values(file);
+    test_map_size(file);
+    test_map_contains_key(file);
 }
 
 fn test_create(file: &mut impl Write) {
@@ -81,3 +85,24 @@ fn test_get(file: &mut impl Write) {
         ("v2", StringType::from_data(vec!["v3", "v4"])),
     ]);
 }
+
+fn test_map_keys(file: &mut impl Write) {
+    run_ast(file, "map_keys({})", &[]);
+    run_ast(file, "map_keys({'a':1,'b':2,'c':3})", &[]);
+}
+
+fn test_map_values(file: &mut impl Write) {
+    run_ast(file, "map_values({})", &[]);
+    run_ast(file, "map_values({'a':1,'b':2,'c':3})", &[]);
+}
+
+fn test_map_size(file: &mut impl Write) {
+    run_ast(file, "map_size({})", &[]);
+    run_ast(file, "map_size({'a':1,'b':2,'c':3})", &[]);
+}
+
+fn test_map_contains_key(file: &mut impl Write) {
+    run_ast(file, "map_contains_key({}, 'a')", &[]);
+    run_ast(file, "map_contains_key({'a':1,'b':2}, 'a')", &[]);
+    run_ast(file, "map_contains_key({'a':1,'b':2}, 'x')", &[]);
+}
@@ -36,6 +36,7 @@ mod cast;
 mod comparison;
 mod control;
 mod datetime;
+mod fuzz;
 mod geo;
 mod hash;
 mod map;
@@ -383,6 +383,27 @@ pub fn transform_expr(ast: AExpr, columns: &[(&str, DataType)]) -> RawExpr {
                 }
             }
         }
+        AExpr::IsDistinctFrom {
+            span,
+            left,
+            right,
+            not,
+        } => {
+            let name = if not {
+                "is_not_distinct_from"
+            } else {
+                "is_distinct_from"
+            };
+            RawExpr::FunctionCall {
+                span,
+                name: name.to_string(),
+                params: vec![],
+                args: vec![
+                    transform_expr(*left, columns),
+                    transform_expr(*right, columns),
+                ],
+            }
+        }
         AExpr::DateAdd {
             span,
             unit,
@@ -541,6 +562,9 @@ fn transform_data_type(target_type: common_ast::ast::TypeName) -> DataType {
             DataType::Nullable(Box::new(transform_data_type(*inner_type)))
         }
         common_ast::ast::TypeName::Variant => DataType::Variant,
+        common_ast::ast::TypeName::Bitmap => DataType::Bitmap,
+        common_ast::ast::TypeName::Binary => DataType::Binary,
+        common_ast::ast::TypeName::Interval => DataType::Interval,
     }
 }
 
@@ -15,7 +15,6 @@
 use std::io::Write;
 
 use common_expression::types::number::*;
-use common_expression::types::BooleanType;
 use common_expression::types::StringType;
 use common_expression::FromData;
 use goldenfile::Mint;
@@ -61,6 +60,8 @@ fn test_string() {
     test_left(file);
     test_right(file);
     test_substr(file);
+    test_string_to_array(file);
+    test_array_to_string(file);
 }
 
 fn test_upper(file: &mut impl Write) {
@@ -361,10 +362,11 @@ fn test_concat(file: &mut impl Write) {
         StringType::from_data_with_validity(&["a", "b", "c", "d"], vec![true, true, false, true]),
     )]);
 
-    run_ast(file, "concat_ws('', a, 2)", &[(
-        "a",
-        BooleanType::from_data(vec![false; 3]),
-    )]);
+    run_ast(file, "concat_ws('', true, 2)", &[]);
+    run_ast(file, "concat_ws('-', 42, true, NULL)", &[]);
+    run_ast(file, "concat_ws('-', NULL, NULL)", &[]);
+    run_ast(file, "concat('a', 1, NULL, true)", &[]);
+    run_ast(file, "concat(NULL, NULL)", &[]);
 }
 
 fn test_bin(file: &mut impl Write) {
@@ -645,3 +647,15 @@ fn test_substr(file: &mut impl Write) {
         ),
     ]);
 }
+
+fn test_string_to_array(file: &mut impl Write) {
+    run_ast(file, "string_to_array('a,,b', ',')", &[]);
+    run_ast(file, "string_to_array('a<>b<>c', '<>', 'b')", &[]);
+    run_ast(file, "string_to_array('abc', '')", &[]);
+}
+
+fn test_array_to_string(file: &mut impl Write) {
+    run_ast(file, "array_to_string(['a', NULL, 'b'], ',')", &[]);
+    run_ast(file, "array_to_string(['a', NULL, 'b'], '<>', '*')", &[]);
+    run_ast(file, "array_to_string([1, 2, 3], '-')", &[]);
+}
@@ -39,6 +39,8 @@ fn test_variant() {
     test_try_to_type(file);
     test_json_object(file);
     test_json_object_keep_null(file);
+    test_json_array(file);
+    test_json_pretty(file);
 }
 
 fn test_parse_json(file: &mut impl Write) {
@@ -75,6 +77,10 @@ fn test_parse_json(file: &mut impl Write) {
             true, true, false, true,
         ]),
     )]);
+
+    run_ast(file, "parse_json('nuLL', false)", &[]);
+    run_ast(file, "parse_json('[1,2,3,4]', true)", &[]);
+    run_ast(file, "parse_json('nuLL', true)", &[]);
 }
 
 fn test_try_parse_json(file: &mut impl Write) {
@@ -583,3 +589,31 @@ fn test_json_object_keep_null(file: &mut impl Write) {
         ),
     ]);
 }
+
+fn test_json_array(file: &mut impl Write) {
+    run_ast(file, "json_array()", &[]);
+    run_ast(file, "json_array('a', true, 1, null, [1,2], {'k':'v'})", &[
+    ]);
+
+    run_ast(file, "json_array(a, b)", &[
+        ("a", UInt8Type::from_data(vec![1u8, 2, 3])),
+        (
+            "b",
+            StringType::from_data_with_validity(&["x", "", "z"], vec![true, false, true]),
+        ),
+    ]);
+}
+
+fn test_json_pretty(file: &mut impl Write) {
+    run_ast(file, "json_pretty(parse_json('1'))", &[]);
+    run_ast(
+        file,
+        "json_pretty(parse_json('{\"a\":[1,{\"b\":true}],\"c\":{},\"d\":[]}'))",
+        &[],
+    );
+
+    run_ast(file, "json_pretty(parse_json(s))", &[(
+        "s",
+        StringType::from_data(vec![r#"true"#, r#"[1,"a"]"#, r#"{"k":"v"}"#]),
+    )]);
+}
@@ -0,0 +1,169 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::number::NumberScalar;
+use common_expression::Scalar;
+use common_functions::windows::WindowLagLead;
+use common_functions::windows::WindowNthValue;
+
+/// Parse `"NULL, 1, 2"` into scalars.
+fn scalars(values: &str) -> Vec<Scalar> {
+    values
+        .split(", ")
+        .map(|v| match v {
+            "NULL" => Scalar::Null,
+            v => Scalar::Number(NumberScalar::Int64(v.parse().unwrap())),
+        })
+        .collect()
+}
+
+fn run_lag_lead(is_lag: bool, offset: usize, ignore_nulls: bool, values: &str) -> Vec<Scalar> {
+    let mut evaluator = WindowLagLead::create(is_lag, offset, ignore_nulls);
+    let mut results = vec![];
+    for value in scalars(values) {
+        evaluator.push(value, Scalar::Number(NumberScalar::Int64(-1)), &mut results);
+    }
+    evaluator.finish(&mut results);
+    results
+}
+
+#[test]
+fn test_lag() {
+    let values = "NULL, 1, NULL, NULL, 2, 3, NULL";
+    let cases = [
+        (0, false, "NULL, 1, NULL, NULL, 2, 3, NULL"),
+        (1, false, "-1, NULL, 1, NULL, NULL, 2, 3"),
+        (1, true, "-1, -1, 1, 1, 1, 2, 3"),
+        (2, false, "-1, -1, NULL, 1, NULL, NULL, 2"),
+        (2, true, "-1, -1, -1, -1, -1, 1, 2"),
+        (3, true, "-1, -1, -1, -1, -1, -1, 1"),
+    ];
+    for (offset, ignore_nulls, expected) in cases {
+        assert_eq!(
+            run_lag_lead(true, offset, ignore_nulls, values),
+            scalars(expected),
+            "LAG(x, {offset}) ignore_nulls: {ignore_nulls}"
+        );
+    }
+}
+
+#[test]
+fn test_lead() {
+    let values = "NULL, 1, NULL, NULL, 2, 3, NULL";
+    let cases = [
+        (0, true, "NULL, 1, NULL, NULL, 2, 3, NULL"),
+        (1, false, "1, NULL, NULL, 2, 3, NULL, -1"),
+        (1, true, "1, 2, 2, 2, 3, -1, -1"),
+        (2, false, "NULL, NULL, 2, 3, NULL, -1, -1"),
+        (2, true, "2, 3, 3, 3, -1, -1, -1"),
+        (3, true, "3, -1, -1, -1, -1, -1, -1"),
+    ];
+    for (offset, ignore_nulls, expected) in cases {
+        assert_eq!(
+            run_lag_lead(false, offset, ignore_nulls, values),
+            scalars(expected),
+            "LEAD(x, {offset}) ignore_nulls: {ignore_nulls}"
+        );
+    }
+}
+
+#[test]
+fn test_lag_lead_default_per_row() {
+    // The default is evaluated for the current row, like `LAG(x, 1, x * 10)`.
+    for (is_lag, expected) in [(true, "10, 1, 1"), (false, "3, 3, 30")] {
+        let mut evaluator = WindowLagLead::create(is_lag, 1, true);
+        let mut results = vec![];
+        for value in scalars("1, NULL, 3") {
+            let default = match value {
+                Scalar::Number(NumberScalar::Int64(v)) => {
+                    Scalar::Number(NumberScalar::Int64(v * 10))
+                }
+                _ => Scalar::Null,
+            };
+            evaluator.push(value, default, &mut results);
+        }
+        evaluator.finish(&mut results);
+        assert_eq!(results, scalars(expected), "is_lag: {is_lag}");
+    }
+}
+
+#[test]
+fn test_lag_lead_reset_partition() {
+    for (is_lag, expected) in [(true, "NULL, NULL"), (false, "5, NULL")] {
+        let mut evaluator = WindowLagLead::create(is_lag, 1, true);
+        let mut results = vec![];
+        for value in scalars("1, 2, NULL") {
+            evaluator.push(value, Scalar::Null, &mut results);
+        }
+        evaluator.finish(&mut results);
+
+        // Nothing of the first partition is seen by the second one.
+        let mut results = vec![];
+        for value in scalars("NULL, 5") {
+            evaluator.push(value, Scalar::Null, &mut results);
+        }
+        evaluator.finish(&mut results);
+        assert_eq!(results, scalars(expected), "is_lag: {is_lag}");
+    }
+}
+
+#[test]
+fn test_nth_value() {
+    let values = "NULL, 1, NULL, 2, 3";
+
+    // ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+    let cases = [
+        (1, false, "NULL, NULL, NULL, NULL, NULL"),
+        (1, true, "NULL, 1, 1, 1, 1"),
+        (2, false, "NULL, 1, 1, 1, 1"),
+        (2, true, "NULL, NULL, NULL, 2, 2"),
+        (4, true, "NULL, NULL, NULL, NULL, NULL"),
+    ];
+    for (n, ignore_nulls, expected) in cases {
+        let mut evaluator = WindowNthValue::create(n, ignore_nulls);
+        let mut results = vec![];
+        for value in scalars(values) {
+            evaluator.push_back(value);
+            results.push(evaluator.result());
+        }
+        assert_eq!(
+            results,
+            scalars(expected),
+            "NTH_VALUE(x, {n}) ignore_nulls: {ignore_nulls}"
+        );
+    }
+
+    // ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+    let cases = [
+        (1, false, "NULL, NULL, 1, NULL, 2"),
+        (1, true, "NULL, 1, 1, 2, 2"),
+        (2, true, "NULL, NULL, NULL, NULL, 3"),
+    ];
+    for (n, ignore_nulls, expected) in cases {
+        let mut evaluator = WindowNthValue::create(n, ignore_nulls);
+        let mut results = vec![];
+        for (i, value) in scalars(values).into_iter().enumerate() {
+            evaluator.push_back(value);
+            if i >= 2 {
+                evaluator.pop_front();
+            }
+            results.push(evaluator.result());
+        }
+        assert_eq!(
+            results,
+            scalars(expected),
+            "NTH_VALUE(x, {n}) ignore_nulls: {ignore_nulls}"
+        );
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::schema::TableCopyHistoryInfo;
+
+#[async_trait::async_trait]
+pub trait CopyHistoryApi: Sync + Send {
+    // Get the load history of all the tables for a tenant, expired entries are purged by meta.
+    async fn get_copy_history(&self) -> Result<Vec<TableCopyHistoryInfo>>;
+
+    // Get the load history of a table.
+    async fn get_table_copy_history(&self, table_id: u64) -> Result<Vec<TableCopyHistoryInfo>>;
+}
@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableCopyHistoryIdent;
+use common_meta_app::schema::TableCopyHistoryInfo;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::Key;
+use common_meta_types::MetaError;
+
+use crate::copy_history::CopyHistoryApi;
+
+/// Reads the load history written by `COPY INTO` along with the table commit.
+pub struct CopyHistoryMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    copy_history_prefix: String,
+}
+
+impl CopyHistoryMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while copy history mgr create)",
+            ));
+        }
+
+        Ok(CopyHistoryMgr {
+            kv_api,
+            copy_history_prefix: kvapi::KeyBuilder::new_prefixed(TableCopyHistoryIdent::PREFIX)
+                .push_str(tenant)
+                .done(),
+        })
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<TableCopyHistoryInfo>> {
+        // Ends with '/' so that tenant 'a' does not see the history of tenant 'ab'.
+        let values = self.kv_api.prefix_list_kv(&format!("{}/", prefix)).await?;
+
+        let mut history = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            history.push(serde_json::from_slice::<TableCopyHistoryInfo>(&value.data)?);
+        }
+        history.sort_by(|a, b| b.last_load_time.cmp(&a.last_load_time));
+        Ok(history)
+    }
+}
+
+#[async_trait::async_trait]
+impl CopyHistoryApi for CopyHistoryMgr {
+    async fn get_copy_history(&self) -> Result<Vec<TableCopyHistoryInfo>> {
+        self.list(&self.copy_history_prefix).await
+    }
+
+    async fn get_table_copy_history(&self, table_id: u64) -> Result<Vec<TableCopyHistoryInfo>> {
+        self.list(&format!("{}/{}", self.copy_history_prefix, table_id))
+            .await
+    }
+}
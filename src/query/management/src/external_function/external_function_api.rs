@@ -0,0 +1,37 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::principal::ExternalFunctionInfo;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait ExternalFunctionApi: Sync + Send {
+    // Add an external function to /tenant/function-name.
+    async fn add_external_function(&self, info: ExternalFunctionInfo) -> Result<u64>;
+
+    // Get external function by name.
+    async fn get_external_function(
+        &self,
+        name: &str,
+        seq: MatchSeq,
+    ) -> Result<SeqV<ExternalFunctionInfo>>;
+
+    // Get all the external functions for a tenant.
+    async fn get_external_functions(&self) -> Result<Vec<ExternalFunctionInfo>>;
+
+    // Drop the tenant's external function by name.
+    async fn drop_external_function(&self, name: &str, seq: MatchSeq) -> Result<()>;
+}
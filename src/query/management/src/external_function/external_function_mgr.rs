@@ -0,0 +1,134 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::ExternalFunctionInfo;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::IntoSeqV;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::external_function::ExternalFunctionApi;
+
+static EXTERNAL_FUNCTION_API_KEY_PREFIX: &str = "__fd_external_functions";
+
+pub struct ExternalFunctionMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    external_function_prefix: String,
+}
+
+impl ExternalFunctionMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while external function mgr create)",
+            ));
+        }
+
+        Ok(ExternalFunctionMgr {
+            kv_api,
+            external_function_prefix: format!(
+                "{}/{}",
+                EXTERNAL_FUNCTION_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+        })
+    }
+
+    fn key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.external_function_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl ExternalFunctionApi for ExternalFunctionMgr {
+    async fn add_external_function(&self, info: ExternalFunctionInfo) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&info)?);
+        let key = self.key(&info.name)?;
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::ExternalFunctionAlreadyExists(format!(
+                "External function {} already exists, seq [{}]",
+                info.name, v.seq
+            ))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    async fn get_external_function(
+        &self,
+        name: &str,
+        seq: MatchSeq,
+    ) -> Result<SeqV<ExternalFunctionInfo>> {
+        let key = self.key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| {
+            ErrorCode::UnknownExternalFunction(format!("Unknown external function {}", name))
+        })?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownExternalFunction(format!(
+                "Unknown external function {}",
+                name
+            ))),
+        }
+    }
+
+    async fn get_external_functions(&self) -> Result<Vec<ExternalFunctionInfo>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.external_function_prefix)
+            .await?;
+
+        let mut functions = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            functions.push(serde_json::from_slice::<ExternalFunctionInfo>(&value.data)?);
+        }
+        Ok(functions)
+    }
+
+    async fn drop_external_function(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownExternalFunction(format!(
+                "Unknown external function {}",
+                name
+            )))
+        }
+    }
+}
@@ -15,29 +15,58 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod cluster;
+mod copy_history;
+mod external_function;
 mod file_format;
+mod masking_policy;
+mod network_policy;
+mod pipe;
 mod quota;
 mod role;
+mod row_access_policy;
+mod sequence;
 mod serde;
 mod setting;
 mod stage;
+mod table_statistics;
+mod task;
 mod udf;
 mod user;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
+pub use copy_history::CopyHistoryApi;
+pub use copy_history::CopyHistoryMgr;
+pub use external_function::ExternalFunctionApi;
+pub use external_function::ExternalFunctionMgr;
 pub use file_format::FileFormatApi;
 pub use file_format::FileFormatMgr;
+pub use masking_policy::MaskingPolicyApi;
+pub use masking_policy::MaskingPolicyMgr;
+pub use network_policy::NetworkPolicyApi;
+pub use network_policy::NetworkPolicyMgr;
+pub use pipe::PipeApi;
+pub use pipe::PipeLease;
+pub use pipe::PipeMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
+pub use row_access_policy::RowAccessPolicyApi;
+pub use row_access_policy::RowAccessPolicyMgr;
+pub use sequence::SequenceApi;
+pub use sequence::SequenceMgr;
 pub use serde::deserialize_struct;
 pub use serde::serialize_struct;
 pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use table_statistics::TableStatisticsApi;
+pub use table_statistics::TableStatisticsMgr;
+pub use task::TaskApi;
+pub use task::TaskLease;
+pub use task::TaskMgr;
 pub use udf::UdfApi;
 pub use udf::UdfMgr;
 pub use user::UserApi;
@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::Result;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeRejectedRecord;
+use common_meta_app::principal::PipeStatus;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+/// The seq of a pipe lease, which is needed to renew or release it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PipeLease {
+    pub seq: u64,
+}
+
+#[async_trait::async_trait]
+pub trait PipeApi: Sync + Send {
+    // Add a pipe to /tenant/pipe-name.
+    async fn add_pipe(&self, pipe: PipeInfo) -> Result<u64>;
+
+    // Get pipe by name.
+    async fn get_pipe(&self, name: &str, seq: MatchSeq) -> Result<SeqV<PipeInfo>>;
+
+    // Get all the pipes for a tenant.
+    async fn get_pipes(&self) -> Result<Vec<PipeInfo>>;
+
+    // Drop the tenant's pipe by name, along with its status.
+    async fn drop_pipe(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    // Take the lease of a pipe for `ttl`, None if somebody else holds it.
+    async fn acquire_pipe_lease(
+        &self,
+        name: &str,
+        holder: &str,
+        ttl: Duration,
+    ) -> Result<Option<PipeLease>>;
+
+    // Extend a lease by another `ttl`, None if it has expired in the meantime.
+    async fn renew_pipe_lease(
+        &self,
+        name: &str,
+        lease: PipeLease,
+        ttl: Duration,
+    ) -> Result<Option<PipeLease>>;
+
+    // Release a lease, it's fine if it has expired already.
+    async fn release_pipe_lease(&self, name: &str, lease: PipeLease) -> Result<()>;
+
+    // Replace the status of a pipe.
+    async fn set_pipe_status(&self, status: PipeStatus) -> Result<()>;
+
+    // Get the status of all the pipes for a tenant, the pipes never consumed have none.
+    async fn get_pipe_statuses(&self) -> Result<Vec<PipeStatus>>;
+
+    // Record the rejected records of a pipe, they're kept for `retention`.
+    async fn add_pipe_rejected_records(
+        &self,
+        records: Vec<PipeRejectedRecord>,
+        retention: Duration,
+    ) -> Result<()>;
+
+    // Get the recorded rejected records of all the pipes for a tenant.
+    async fn get_pipe_rejected_records(&self) -> Result<Vec<PipeRejectedRecord>>;
+}
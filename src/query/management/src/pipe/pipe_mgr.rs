@@ -0,0 +1,287 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Add;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeRejectedRecord;
+use common_meta_app::principal::PipeStatus;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::IntoSeqV;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::pipe::PipeApi;
+use crate::pipe::PipeLease;
+
+static PIPE_API_KEY_PREFIX: &str = "__fd_pipes";
+static PIPE_LEASE_API_KEY_PREFIX: &str = "__fd_pipe_leases";
+static PIPE_STATUS_API_KEY_PREFIX: &str = "__fd_pipe_status";
+static PIPE_REJECTED_API_KEY_PREFIX: &str = "__fd_pipe_rejected";
+
+pub struct PipeMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    pipe_prefix: String,
+    pipe_lease_prefix: String,
+    pipe_status_prefix: String,
+    pipe_rejected_prefix: String,
+}
+
+impl PipeMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while pipe mgr create)",
+            ));
+        }
+
+        let tenant = escape_for_key(tenant)?;
+        Ok(PipeMgr {
+            kv_api,
+            pipe_prefix: format!("{}/{}", PIPE_API_KEY_PREFIX, tenant),
+            pipe_lease_prefix: format!("{}/{}", PIPE_LEASE_API_KEY_PREFIX, tenant),
+            pipe_status_prefix: format!("{}/{}", PIPE_STATUS_API_KEY_PREFIX, tenant),
+            pipe_rejected_prefix: format!("{}/{}", PIPE_REJECTED_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn key(&self, name: &str) -> Result<String> {
+        Ok(format!("{}/{}", self.pipe_prefix, escape_for_key(name)?))
+    }
+
+    fn lease_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.pipe_lease_prefix,
+            escape_for_key(name)?
+        ))
+    }
+
+    fn status_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.pipe_status_prefix,
+            escape_for_key(name)?
+        ))
+    }
+
+    fn expire_after(ttl: Duration) -> KVMeta {
+        let expire_at = SystemTime::now()
+            .add(ttl)
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+
+        KVMeta {
+            expire_at: Some(expire_at.as_secs()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl PipeApi for PipeMgr {
+    async fn add_pipe(&self, pipe: PipeInfo) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&pipe)?);
+        let key = self.key(&pipe.name)?;
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::PipeAlreadyExists(format!(
+                "Pipe {} already exists, seq [{}]",
+                pipe.name, v.seq
+            ))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    async fn get_pipe(&self, name: &str, seq: MatchSeq) -> Result<SeqV<PipeInfo>> {
+        let key = self.key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownPipe(format!("Unknown pipe {}", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownPipe(format!("Unknown pipe {}", name))),
+        }
+    }
+
+    async fn get_pipes(&self) -> Result<Vec<PipeInfo>> {
+        let values = self.kv_api.prefix_list_kv(&self.pipe_prefix).await?;
+
+        let mut pipes = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let pipe = serde_json::from_slice::<PipeInfo>(&value.data)?;
+            pipes.push(pipe);
+        }
+        Ok(pipes)
+    }
+
+    async fn drop_pipe(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_none() || res.result.is_some() {
+            return Err(ErrorCode::UnknownPipe(format!("Unknown pipe {}", name)));
+        }
+
+        let status_key = self.status_key(name)?;
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &status_key,
+                MatchSeq::GE(0),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    async fn acquire_pipe_lease(
+        &self,
+        name: &str,
+        holder: &str,
+        ttl: Duration,
+    ) -> Result<Option<PipeLease>> {
+        // Only when nobody holds the lease, i.e. seq=0.
+        let key = self.lease_key(name)?;
+        let val = Operation::Update(holder.as_bytes().to_vec());
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(0),
+                val,
+                Some(Self::expire_after(ttl)),
+            ))
+            .await?;
+
+        match (res.prev, res.result) {
+            (None, Some(SeqV { seq, .. })) => Ok(Some(PipeLease { seq })),
+            _ => Ok(None),
+        }
+    }
+
+    async fn renew_pipe_lease(
+        &self,
+        name: &str,
+        lease: PipeLease,
+        ttl: Duration,
+    ) -> Result<Option<PipeLease>> {
+        let key = self.lease_key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(lease.seq),
+                Operation::AsIs,
+                Some(Self::expire_after(ttl)),
+            ))
+            .await?;
+
+        if !res.is_changed() {
+            return Ok(None);
+        }
+        Ok(res.result.map(|SeqV { seq, .. }| PipeLease { seq }))
+    }
+
+    async fn release_pipe_lease(&self, name: &str, lease: PipeLease) -> Result<()> {
+        let key = self.lease_key(name)?;
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(lease.seq),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    async fn set_pipe_status(&self, status: PipeStatus) -> Result<()> {
+        let key = self.status_key(&status.pipe_name)?;
+        let val = Operation::Update(serde_json::to_vec(&status)?);
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::GE(0), val, None))
+            .await?;
+        Ok(())
+    }
+
+    async fn get_pipe_statuses(&self) -> Result<Vec<PipeStatus>> {
+        let values = self.kv_api.prefix_list_kv(&self.pipe_status_prefix).await?;
+
+        let mut statuses = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            statuses.push(serde_json::from_slice::<PipeStatus>(&value.data)?);
+        }
+        Ok(statuses)
+    }
+
+    async fn add_pipe_rejected_records(
+        &self,
+        records: Vec<PipeRejectedRecord>,
+        retention: Duration,
+    ) -> Result<()> {
+        for record in records {
+            // Keyed by the position in the source, a replayed batch overwrites its records.
+            let key = format!(
+                "{}/{}/{}/{}",
+                self.pipe_rejected_prefix,
+                escape_for_key(&record.pipe_name)?,
+                record.partition,
+                record.offset
+            );
+            let val = Operation::Update(serde_json::to_vec(&record)?);
+            self.kv_api
+                .upsert_kv(UpsertKVReq::new(
+                    &key,
+                    MatchSeq::GE(0),
+                    val,
+                    Some(Self::expire_after(retention)),
+                ))
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn get_pipe_rejected_records(&self) -> Result<Vec<PipeRejectedRecord>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.pipe_rejected_prefix)
+            .await?;
+
+        let mut records = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            records.push(serde_json::from_slice::<PipeRejectedRecord>(&value.data)?);
+        }
+        Ok(records)
+    }
+}
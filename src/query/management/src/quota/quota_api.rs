@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_exception::Result;
+use common_meta_app::tenant::DatabaseQuota;
 use common_meta_app::tenant::TenantQuota;
 use common_meta_types::MatchSeq;
 use common_meta_types::SeqV;
@@ -22,4 +23,17 @@ pub trait QuotaApi: Sync + Send {
     async fn get_quota(&self, seq: MatchSeq) -> Result<SeqV<TenantQuota>>;
 
     async fn set_quota(&self, quota: &TenantQuota, seq: MatchSeq) -> Result<u64>;
+
+    /// Get the quota of the database with id `db_id`, or the default(unlimited) one if it is not set.
+    async fn get_database_quota(&self, db_id: u64) -> Result<SeqV<DatabaseQuota>>;
+
+    /// Get the quotas of the databases with ids `db_ids` in one read, in the same order.
+    async fn get_database_quotas(&self, db_ids: &[u64]) -> Result<Vec<DatabaseQuota>>;
+
+    async fn set_database_quota(
+        &self,
+        db_id: u64,
+        quota: &DatabaseQuota,
+        seq: MatchSeq,
+    ) -> Result<u64>;
 }
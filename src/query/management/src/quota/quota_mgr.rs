@@ -17,8 +17,11 @@ use std::sync::Arc;
 use common_base::base::escape_for_key;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::tenant::DatabaseQuota;
+use common_meta_app::tenant::DatabaseQuotaIdent;
 use common_meta_app::tenant::TenantQuota;
 use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::Key;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::IntoSeqV;
 use common_meta_types::MatchSeq;
@@ -48,6 +51,10 @@ impl QuotaMgr {
             key: format!("{}/{}", QUOTA_API_KEY_PREFIX, escape_for_key(tenant)?),
         })
     }
+
+    fn database_key(&self, db_id: u64) -> String {
+        DatabaseQuotaIdent { db_id }.to_string_key()
+    }
 }
 
 #[async_trait::async_trait]
@@ -82,4 +89,50 @@ impl QuotaApi for QuotaMgr {
             )),
         }
     }
+
+    async fn get_database_quota(&self, db_id: u64) -> Result<SeqV<DatabaseQuota>> {
+        let res = self.kv_api.get_kv(&self.database_key(db_id)).await?;
+        match res {
+            Some(seq_value) => Ok(seq_value.into_seqv()?),
+            None => Ok(SeqV::new(0, DatabaseQuota::default())),
+        }
+    }
+
+    async fn get_database_quotas(&self, db_ids: &[u64]) -> Result<Vec<DatabaseQuota>> {
+        let keys: Vec<String> = db_ids.iter().map(|id| self.database_key(*id)).collect();
+        let res = self.kv_api.mget_kv(&keys).await?;
+        let mut quotas = Vec::with_capacity(res.len());
+        for seq_value in res {
+            match seq_value {
+                Some(seq_value) => quotas.push(DatabaseQuota::try_from(seq_value.data)?),
+                None => quotas.push(DatabaseQuota::default()),
+            }
+        }
+        Ok(quotas)
+    }
+
+    async fn set_database_quota(
+        &self,
+        db_id: u64,
+        quota: &DatabaseQuota,
+        seq: MatchSeq,
+    ) -> Result<u64> {
+        let value = serde_json::to_vec(quota)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &self.database_key(db_id),
+                seq,
+                Operation::Update(value),
+                None,
+            ))
+            .await?;
+
+        match res.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::TenantQuotaUnknown(
+                "database quota not found, or seq not match",
+            )),
+        }
+    }
 }
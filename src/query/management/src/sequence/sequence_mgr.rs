@@ -0,0 +1,223 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::reply::txn_reply_to_api_result;
+use common_meta_api::txn_cond_seq;
+use common_meta_api::txn_op_put;
+use common_meta_app::app_error::TxnRetryMaxTimes;
+use common_meta_app::principal::SequenceInfo;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::ConditionResult::Eq;
+use common_meta_types::IntoSeqV;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use common_meta_types::TxnRequest;
+
+use crate::sequence::SequenceApi;
+
+static SEQUENCE_API_KEY_PREFIX: &str = "__fd_sequences";
+const TXN_MAX_RETRY_TIMES: u32 = 10;
+
+pub struct SequenceMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    sequence_prefix: String,
+}
+
+impl SequenceMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while sequence mgr create)",
+            ));
+        }
+
+        Ok(SequenceMgr {
+            kv_api,
+            sequence_prefix: format!("{}/{}", SEQUENCE_API_KEY_PREFIX, escape_for_key(tenant)?),
+        })
+    }
+
+    fn key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.sequence_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SequenceApi for SequenceMgr {
+    async fn add_sequence(&self, info: SequenceInfo) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&info)?);
+        let key = self.key(&info.name)?;
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::SequenceAlreadyExists(format!(
+                "Sequence {} already exists, seq [{}]",
+                info.name, v.seq
+            ))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    async fn get_sequence(&self, name: &str, seq: MatchSeq) -> Result<SeqV<SequenceInfo>> {
+        let key = self.key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownSequence(format!("Unknown sequence {}", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownSequence(format!(
+                "Unknown sequence {}",
+                name
+            ))),
+        }
+    }
+
+    async fn get_sequences(&self) -> Result<Vec<SequenceInfo>> {
+        let values = self.kv_api.prefix_list_kv(&self.sequence_prefix).await?;
+
+        let mut sequences = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let info = serde_json::from_slice::<SequenceInfo>(&value.data)?;
+            sequences.push(info);
+        }
+        Ok(sequences)
+    }
+
+    async fn drop_sequence(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownSequence(format!(
+                "Unknown sequence {}",
+                name
+            )))
+        }
+    }
+
+    async fn next_sequence_value(&self, name: &str) -> Result<i64> {
+        let values = self.reserve_sequence_values(name, 1).await?;
+        Ok(values[0])
+    }
+
+    async fn reserve_sequence_values(&self, name: &str, count: u64) -> Result<Vec<i64>> {
+        let key = self.key(name)?;
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let seq_v =
+                self.kv_api.get_kv(&key).await?.ok_or_else(|| {
+                    ErrorCode::UnknownSequence(format!("Unknown sequence {}", name))
+                })?;
+            let mut info = serde_json::from_slice::<SequenceInfo>(&seq_v.data)?;
+            let mut values = Vec::with_capacity(count as usize);
+            while (values.len() as u64) < count {
+                match info.next_value() {
+                    Some(next) => {
+                        info.current = Some(next);
+                        values.push(next);
+                    }
+                    None => break,
+                }
+            }
+            if values.is_empty() {
+                return Err(ErrorCode::SequenceExhausted(format!(
+                    "Sequence {} is exhausted, its value can not go beyond [{}, {}]",
+                    name, info.min_value, info.max_value
+                )));
+            }
+
+            // Only succeeds if nobody else advanced the sequence since we read it.
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(&key, Eq, seq_v.seq)],
+                if_then: vec![txn_op_put(&key, serde_json::to_vec(&info)?)],
+                else_then: vec![],
+            };
+
+            let tx_reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(tx_reply)?;
+
+            if succ {
+                return Ok(values);
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("reserve_sequence_values", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
+
+    async fn set_sequence_value(&self, name: &str, value: i64) -> Result<()> {
+        let key = self.key(name)?;
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let seq_v =
+                self.kv_api.get_kv(&key).await?.ok_or_else(|| {
+                    ErrorCode::UnknownSequence(format!("Unknown sequence {}", name))
+                })?;
+            let mut info = serde_json::from_slice::<SequenceInfo>(&seq_v.data)?;
+            if value < info.min_value || value > info.max_value {
+                return Err(ErrorCode::BadArguments(format!(
+                    "setval: value {} is out of bounds for sequence {} [{}, {}]",
+                    value, name, info.min_value, info.max_value
+                )));
+            }
+            info.current = Some(value);
+
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(&key, Eq, seq_v.seq)],
+                if_then: vec![txn_op_put(&key, serde_json::to_vec(&info)?)],
+                else_then: vec![],
+            };
+
+            let tx_reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(tx_reply)?;
+
+            if succ {
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("set_sequence_value", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
+}
@@ -23,11 +23,17 @@ pub trait StageApi: Sync + Send {
     // Add a stage info to /tenant/stage-name.
     async fn add_stage(&self, stage: StageInfo) -> Result<u64>;
 
+    // Update the stage info of /tenant/stage-name, e.g. to replace the connection of an external stage.
+    async fn update_stage(&self, stage: StageInfo, seq: MatchSeq) -> Result<u64>;
+
     async fn get_stage(&self, name: &str, seq: MatchSeq) -> Result<SeqV<StageInfo>>;
 
     // Get all the stages for a tenant.
     async fn get_stages(&self) -> Result<Vec<StageInfo>>;
 
+    // Rename the tenant's stage, along with the files of the stage.
+    async fn rename_stage(&self, name: &str, new_name: &str) -> Result<()>;
+
     // Drop the tenant's stage by name.
     async fn drop_stage(&self, name: &str) -> Result<()>;
 
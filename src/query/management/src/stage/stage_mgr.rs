@@ -90,6 +90,34 @@ impl StageApi for StageMgr {
         Ok(res.seq)
     }
 
+    async fn update_stage(&self, info: StageInfo, seq: MatchSeq) -> Result<u64> {
+        // Make sure the stage exists.
+        let _ = self.get_stage(&info.stage_name, seq).await?;
+
+        let val = Operation::Update(serialize_struct(
+            &info,
+            ErrorCode::IllegalUserStageFormat,
+            || "",
+        )?);
+        let key = format!(
+            "{}/{}",
+            self.stage_prefix,
+            escape_for_key(&info.stage_name)?
+        );
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?;
+        match res.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownStage(format!(
+                "Unknown stage, or seq not match {}",
+                info.stage_name
+            ))),
+        }
+    }
+
     async fn get_stage(&self, name: &str, seq: MatchSeq) -> Result<SeqV<StageInfo>> {
         let key = format!("{}/{}", self.stage_prefix, escape_for_key(name)?);
         let kv_api = self.kv_api.clone();
@@ -158,6 +186,71 @@ impl StageApi for StageMgr {
         ))
     }
 
+    async fn rename_stage(&self, name: &str, new_name: &str) -> Result<()> {
+        let stage_key = format!("{}/{}", self.stage_prefix, escape_for_key(name)?);
+        let new_stage_key = format!("{}/{}", self.stage_prefix, escape_for_key(new_name)?);
+        let file_key_prefix = format!("{}/{}/", self.stage_file_prefix, escape_for_key(name)?);
+        let new_file_key_prefix =
+            format!("{}/{}/", self.stage_file_prefix, escape_for_key(new_name)?);
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            if let Some(seq_v) = self.kv_api.get_kv(&new_stage_key).await? {
+                return Err(ErrorCode::StageAlreadyExists(format!(
+                    "Stage already exists, seq [{}]",
+                    seq_v.seq
+                )));
+            }
+            let (stage_seq, mut stage): (_, StageInfo) =
+                if let Some(seq_v) = self.kv_api.get_kv(&stage_key).await? {
+                    (
+                        seq_v.seq,
+                        deserialize_struct(&seq_v.data, ErrorCode::IllegalUserStageFormat, || "")?,
+                    )
+                } else {
+                    return Err(ErrorCode::UnknownStage(format!("Unknown stage {}", name)));
+                };
+            stage.stage_name = new_name.to_string();
+
+            // Move the stage files to the new stage.
+            let file_keys = self.kv_api.prefix_list_kv(&file_key_prefix).await?;
+            let mut if_then = Vec::with_capacity(file_keys.len() * 2 + 2);
+            for (key, seq_v) in file_keys {
+                let new_key = format!("{}{}", new_file_key_prefix, &key[file_key_prefix.len()..]);
+                if_then.push(txn_op_del(&key));
+                if_then.push(txn_op_put(&new_key, seq_v.data));
+            }
+            if_then.push(txn_op_del(&stage_key));
+            if_then.push(txn_op_put(
+                &new_stage_key,
+                serialize_struct(&stage, ErrorCode::IllegalUserStageFormat, || "")?,
+            ));
+
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // stage is not changed, prevent add file to stage
+                    txn_cond_seq(&stage_key, Eq, stage_seq),
+                    // new stage does not exist
+                    txn_cond_seq(&new_stage_key, Eq, 0),
+                ],
+                if_then,
+                else_then: vec![],
+            };
+            let tx_reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(tx_reply)?;
+
+            if succ {
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("rename_stage", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
+
     async fn add_file(&self, name: &str, file: StageFile) -> Result<u64> {
         let stage_key = format!("{}/{}", self.stage_prefix, escape_for_key(name)?);
         let file_key = format!(
@@ -0,0 +1,37 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::schema::ExternalTableStatistics;
+
+#[async_trait::async_trait]
+pub trait TableStatisticsApi: Sync + Send {
+    // Add or replace the statistics of /tenant/catalog/database/table.
+    async fn upsert_table_statistics(&self, statistics: ExternalTableStatistics) -> Result<u64>;
+
+    // Get the statistics of a table, None if the table is never analyzed.
+    async fn get_table_statistics(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Option<ExternalTableStatistics>>;
+
+    // Get the statistics of all the tables for a tenant.
+    async fn list_table_statistics(&self) -> Result<Vec<ExternalTableStatistics>>;
+
+    // Drop the statistics of a table, it's fine if there are none.
+    async fn drop_table_statistics(&self, catalog: &str, database: &str, table: &str)
+    -> Result<()>;
+}
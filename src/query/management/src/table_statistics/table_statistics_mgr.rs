@@ -0,0 +1,129 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::ExternalTableStatistics;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+
+use crate::table_statistics::TableStatisticsApi;
+
+static TABLE_STATISTICS_API_KEY_PREFIX: &str = "__fd_table_statistics";
+
+pub struct TableStatisticsMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    table_statistics_prefix: String,
+}
+
+impl TableStatisticsMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while table statistics mgr create)",
+            ));
+        }
+
+        Ok(TableStatisticsMgr {
+            kv_api,
+            table_statistics_prefix: format!(
+                "{}/{}",
+                TABLE_STATISTICS_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+        })
+    }
+
+    fn key(&self, catalog: &str, database: &str, table: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}/{}/{}",
+            self.table_statistics_prefix,
+            escape_for_key(catalog)?,
+            escape_for_key(database)?,
+            escape_for_key(table)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl TableStatisticsApi for TableStatisticsMgr {
+    async fn upsert_table_statistics(&self, statistics: ExternalTableStatistics) -> Result<u64> {
+        let key = self.key(&statistics.catalog, &statistics.database, &statistics.table)?;
+        let val = Operation::Update(serde_json::to_vec(&statistics)?);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::GE(0), val, None))
+            .await?;
+
+        match res.result {
+            Some(v) => Ok(v.seq),
+            None => Err(ErrorCode::Internal(format!(
+                "Cannot save the statistics of table {}.{}.{}",
+                statistics.catalog, statistics.database, statistics.table
+            ))),
+        }
+    }
+
+    async fn get_table_statistics(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Option<ExternalTableStatistics>> {
+        let key = self.key(catalog, database, table)?;
+        match self.kv_api.get_kv(&key).await? {
+            Some(value) => Ok(Some(serde_json::from_slice(&value.data)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn list_table_statistics(&self) -> Result<Vec<ExternalTableStatistics>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.table_statistics_prefix)
+            .await?;
+
+        let mut statistics = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            statistics.push(serde_json::from_slice::<ExternalTableStatistics>(
+                &value.data,
+            )?);
+        }
+        Ok(statistics)
+    }
+
+    async fn drop_table_statistics(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<()> {
+        let key = self.key(catalog, database, table)?;
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(0),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+}
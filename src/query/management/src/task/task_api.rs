@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::Result;
+use common_meta_app::principal::TaskInfo;
+use common_meta_app::principal::TaskRun;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+/// The seq of a task lease, which is needed to renew or release it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TaskLease {
+    pub seq: u64,
+}
+
+#[async_trait::async_trait]
+pub trait TaskApi: Sync + Send {
+    // Add a task to /tenant/task-name.
+    async fn add_task(&self, task: TaskInfo) -> Result<u64>;
+
+    // Update a task to /tenant/task-name.
+    async fn update_task(&self, task: TaskInfo, seq: MatchSeq) -> Result<u64>;
+
+    // Get task by name.
+    async fn get_task(&self, name: &str, seq: MatchSeq) -> Result<SeqV<TaskInfo>>;
+
+    // Get all the tasks for a tenant.
+    async fn get_tasks(&self) -> Result<Vec<TaskInfo>>;
+
+    // Drop the tenant's task by name.
+    async fn drop_task(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    // Take the lease of a task for `ttl`, None if somebody else holds it.
+    async fn acquire_task_lease(
+        &self,
+        name: &str,
+        holder: &str,
+        ttl: Duration,
+    ) -> Result<Option<TaskLease>>;
+
+    // Extend a lease by another `ttl`, None if it has expired in the meantime.
+    async fn renew_task_lease(
+        &self,
+        name: &str,
+        lease: TaskLease,
+        ttl: Duration,
+    ) -> Result<Option<TaskLease>>;
+
+    // Release a lease, it's fine if it has expired already.
+    async fn release_task_lease(&self, name: &str, lease: TaskLease) -> Result<()>;
+
+    // Record a run of a task, it's kept for `retention`.
+    async fn add_task_run(&self, run: TaskRun, retention: Duration) -> Result<()>;
+
+    // Get the recorded runs of all the tasks for a tenant.
+    async fn get_task_runs(&self) -> Result<Vec<TaskRun>>;
+}
@@ -0,0 +1,254 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Add;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::TaskInfo;
+use common_meta_app::principal::TaskRun;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::IntoSeqV;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::task::TaskApi;
+use crate::task::TaskLease;
+
+static TASK_API_KEY_PREFIX: &str = "__fd_tasks";
+static TASK_LEASE_API_KEY_PREFIX: &str = "__fd_task_leases";
+static TASK_RUN_API_KEY_PREFIX: &str = "__fd_task_runs";
+
+pub struct TaskMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    task_prefix: String,
+    task_lease_prefix: String,
+    task_run_prefix: String,
+}
+
+impl TaskMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while task mgr create)",
+            ));
+        }
+
+        let tenant = escape_for_key(tenant)?;
+        Ok(TaskMgr {
+            kv_api,
+            task_prefix: format!("{}/{}", TASK_API_KEY_PREFIX, tenant),
+            task_lease_prefix: format!("{}/{}", TASK_LEASE_API_KEY_PREFIX, tenant),
+            task_run_prefix: format!("{}/{}", TASK_RUN_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn key(&self, name: &str) -> Result<String> {
+        Ok(format!("{}/{}", self.task_prefix, escape_for_key(name)?))
+    }
+
+    fn lease_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.task_lease_prefix,
+            escape_for_key(name)?
+        ))
+    }
+
+    fn expire_after(ttl: Duration) -> KVMeta {
+        let expire_at = SystemTime::now()
+            .add(ttl)
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+
+        KVMeta {
+            expire_at: Some(expire_at.as_secs()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskApi for TaskMgr {
+    async fn add_task(&self, task: TaskInfo) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&task)?);
+        let key = self.key(&task.name)?;
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::TaskAlreadyExists(format!(
+                "Task {} already exists, seq [{}]",
+                task.name, v.seq
+            ))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    async fn update_task(&self, task: TaskInfo, seq: MatchSeq) -> Result<u64> {
+        let val = Operation::Update(serde_json::to_vec(&task)?);
+        let key = self.key(&task.name)?;
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        // A seq mismatch leaves the record as it is, the reply has `prev == result`.
+        let res = upsert_info.await?;
+        match res.result {
+            Some(SeqV { seq: s, .. }) if res.prev != res.result => Ok(s),
+            _ => Err(ErrorCode::UnknownTask(format!(
+                "Unknown task, or seq not match {}",
+                task.name
+            ))),
+        }
+    }
+
+    async fn get_task(&self, name: &str, seq: MatchSeq) -> Result<SeqV<TaskInfo>> {
+        let key = self.key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownTask(format!("Unknown task {}", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownTask(format!("Unknown task {}", name))),
+        }
+    }
+
+    async fn get_tasks(&self) -> Result<Vec<TaskInfo>> {
+        let values = self.kv_api.prefix_list_kv(&self.task_prefix).await?;
+
+        let mut tasks = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let task = serde_json::from_slice::<TaskInfo>(&value.data)?;
+            tasks.push(task);
+        }
+        Ok(tasks)
+    }
+
+    async fn drop_task(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownTask(format!("Unknown task {}", name)))
+        }
+    }
+
+    async fn acquire_task_lease(
+        &self,
+        name: &str,
+        holder: &str,
+        ttl: Duration,
+    ) -> Result<Option<TaskLease>> {
+        // Only when nobody holds the lease, i.e. seq=0.
+        let key = self.lease_key(name)?;
+        let val = Operation::Update(holder.as_bytes().to_vec());
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(0),
+                val,
+                Some(Self::expire_after(ttl)),
+            ))
+            .await?;
+
+        match (res.prev, res.result) {
+            (None, Some(SeqV { seq, .. })) => Ok(Some(TaskLease { seq })),
+            _ => Ok(None),
+        }
+    }
+
+    async fn renew_task_lease(
+        &self,
+        name: &str,
+        lease: TaskLease,
+        ttl: Duration,
+    ) -> Result<Option<TaskLease>> {
+        let key = self.lease_key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(lease.seq),
+                Operation::AsIs,
+                Some(Self::expire_after(ttl)),
+            ))
+            .await?;
+
+        if !res.is_changed() {
+            return Ok(None);
+        }
+        Ok(res.result.map(|SeqV { seq, .. }| TaskLease { seq }))
+    }
+
+    async fn release_task_lease(&self, name: &str, lease: TaskLease) -> Result<()> {
+        let key = self.lease_key(name)?;
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(lease.seq),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    async fn add_task_run(&self, run: TaskRun, retention: Duration) -> Result<()> {
+        let key = format!(
+            "{}/{}/{}",
+            self.task_run_prefix,
+            escape_for_key(&run.task_name)?,
+            escape_for_key(&run.query_id)?
+        );
+        let val = Operation::Update(serde_json::to_vec(&run)?);
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(0),
+                val,
+                Some(Self::expire_after(retention)),
+            ))
+            .await?;
+        Ok(())
+    }
+
+    async fn get_task_runs(&self) -> Result<Vec<TaskRun>> {
+        let values = self.kv_api.prefix_list_kv(&self.task_run_prefix).await?;
+
+        let mut runs = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            runs.push(serde_json::from_slice::<TaskRun>(&value.data)?);
+        }
+        Ok(runs)
+    }
+}
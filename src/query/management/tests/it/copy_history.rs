@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Duration;
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::schema::TableCopyHistoryIdent;
+use common_meta_app::schema::TableCopyHistoryInfo;
+use common_meta_app::schema::TableCopyStatus;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::Key;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_get_copy_history() -> Result<()> {
+    let kv_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let copy_history_api = CopyHistoryMgr::create(kv_api.clone(), "admin")?;
+
+    let older = create_test_history("a.csv", "t1", 1);
+    let newer = create_test_history("b.csv", "t2", 0);
+    put_history(&kv_api, "admin", 1, &older).await?;
+    put_history(&kv_api, "admin", 2, &newer).await?;
+    // Another tenant whose name starts with the same prefix.
+    put_history(&kv_api, "admin2", 1, &older).await?;
+
+    // Latest loads come first.
+    assert_eq!(copy_history_api.get_copy_history().await?, vec![
+        newer.clone(),
+        older.clone()
+    ]);
+    assert_eq!(copy_history_api.get_table_copy_history(1).await?, vec![
+        older
+    ]);
+    assert_eq!(copy_history_api.get_table_copy_history(3).await?, vec![]);
+    Ok(())
+}
+
+async fn put_history(
+    kv_api: &MetaEmbedded,
+    tenant: &str,
+    table_id: u64,
+    info: &TableCopyHistoryInfo,
+) -> Result<()> {
+    let key = TableCopyHistoryIdent {
+        tenant: tenant.to_string(),
+        table_id,
+        query_id: "q1".to_string(),
+        file: info.file_name.clone(),
+    };
+    kv_api
+        .upsert_kv(UpsertKVReq::new(
+            &key.to_string_key(),
+            MatchSeq::GE(0),
+            Operation::Update(serde_json::to_vec(info)?),
+            None,
+        ))
+        .await?;
+    Ok(())
+}
+
+fn create_test_history(file_name: &str, table_name: &str, hours_ago: i64) -> TableCopyHistoryInfo {
+    TableCopyHistoryInfo {
+        file_name: file_name.to_string(),
+        database_name: "default".to_string(),
+        table_name: table_name.to_string(),
+        stage_location: "@s1/".to_string(),
+        file_size: 10,
+        rows_parsed: 3,
+        rows_loaded: 2,
+        error_count: 1,
+        status: TableCopyStatus::PartiallyLoaded,
+        first_error_message: Some("bad value".to_string()),
+        first_error_line: Some(2),
+        first_error_column: Some(1),
+        last_load_time: Utc::now() - Duration::hours(hours_ago),
+    }
+}
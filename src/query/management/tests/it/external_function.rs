@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_management::*;
+use common_meta_app::principal::ExternalFunctionInfo;
+use common_meta_app::principal::ExternalFunctionProtocol;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_external_function() -> Result<()> {
+    let (kv_api, api) = new_external_function_api().await?;
+
+    let info = create_test_external_function();
+    api.add_external_function(info.clone()).await?;
+    let value = kv_api
+        .get_kv("__fd_external_functions/admin/predict")
+        .await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&info)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    match api.add_external_function(info).await {
+        Ok(_) => panic!("Already exists add external function must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2617),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_get_and_drop_external_function() -> Result<()> {
+    let (_, api) = new_external_function_api().await?;
+
+    let info = create_test_external_function();
+    api.add_external_function(info.clone()).await?;
+
+    let value = api
+        .get_external_function(&info.name, MatchSeq::GE(0))
+        .await?;
+    assert_eq!(value.data, info);
+    assert_eq!(api.get_external_functions().await?, vec![info.clone()]);
+
+    api.drop_external_function(&info.name, MatchSeq::GE(1))
+        .await?;
+    assert_eq!(api.get_external_functions().await?, vec![]);
+
+    match api.get_external_function(&info.name, MatchSeq::GE(0)).await {
+        Ok(_) => panic!("Unknown external function get must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2618),
+    }
+    match api
+        .drop_external_function(&info.name, MatchSeq::GE(1))
+        .await
+    {
+        Ok(_) => panic!("Unknown external function drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2618),
+    }
+    Ok(())
+}
+
+fn create_test_external_function() -> ExternalFunctionInfo {
+    ExternalFunctionInfo {
+        name: "predict".to_string(),
+        arguments: vec![(
+            "x".to_string(),
+            TableDataType::Number(NumberDataType::Int64),
+        )],
+        return_type: TableDataType::Number(NumberDataType::Float64),
+        language: "python".to_string(),
+        handler: "my_model".to_string(),
+        address: "http://127.0.0.1:8815".to_string(),
+        protocol: ExternalFunctionProtocol::Arrow,
+        headers: BTreeMap::from([("Authorization".to_string(), "Bearer xxx".to_string())]),
+        connection_timeout_ms: 1000,
+        request_timeout_ms: 5000,
+        max_retries: 3,
+        comment: "".to_string(),
+        created_on: Utc::now(),
+    }
+}
+
+async fn new_external_function_api() -> Result<(Arc<MetaEmbedded>, ExternalFunctionMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = ExternalFunctionMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -15,7 +15,16 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod cluster;
+mod copy_history;
+mod external_function;
+mod masking_policy;
+mod network_policy;
+mod pipe;
+mod quota;
+mod row_access_policy;
+mod sequence;
 mod setting;
 mod stage;
+mod task;
 mod udf;
 mod user;
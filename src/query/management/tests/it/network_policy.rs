@@ -0,0 +1,114 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::NetworkPolicy;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_network_policy() -> Result<()> {
+    let (kv_api, policy_api) = new_network_policy_api().await?;
+
+    let policy = create_test_network_policy();
+    policy_api.add_network_policy(policy.clone()).await?;
+    let value = kv_api.get_kv("__fd_network_policies/admin/np1").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&policy)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    match policy_api.add_network_policy(policy).await {
+        Ok(_) => panic!("Already exists add network policy must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2607),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_update_network_policy() -> Result<()> {
+    let (_, policy_api) = new_network_policy_api().await?;
+
+    let mut policy = create_test_network_policy();
+    policy_api.add_network_policy(policy.clone()).await?;
+
+    policy.blocked_ip_list = vec!["10.0.0.1".to_string()];
+    policy_api
+        .update_network_policy(policy.clone(), MatchSeq::GE(1))
+        .await?;
+
+    let value = policy_api
+        .get_network_policy(&policy.name, MatchSeq::GE(0))
+        .await?;
+    assert_eq!(value.data, policy);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_drop_network_policy() -> Result<()> {
+    let (_, policy_api) = new_network_policy_api().await?;
+
+    let policy = create_test_network_policy();
+    policy_api.add_network_policy(policy.clone()).await?;
+
+    let policies = policy_api.get_network_policies().await?;
+    assert_eq!(policies, vec![policy.clone()]);
+
+    policy_api
+        .drop_network_policy(&policy.name, MatchSeq::GE(1))
+        .await?;
+
+    let policies = policy_api.get_network_policies().await?;
+    assert_eq!(policies, vec![]);
+
+    match policy_api
+        .drop_network_policy(&policy.name, MatchSeq::GE(1))
+        .await
+    {
+        Ok(_) => panic!("Unknown network policy drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2608),
+    }
+    Ok(())
+}
+
+fn create_test_network_policy() -> NetworkPolicy {
+    NetworkPolicy {
+        name: "np1".to_string(),
+        allowed_ip_list: vec!["10.0.0.0/8".to_string(), "192.168.1.0/24".to_string()],
+        blocked_ip_list: vec![],
+        comment: "".to_string(),
+        create_on: Utc::now(),
+    }
+}
+
+async fn new_network_policy_api() -> Result<(Arc<MetaEmbedded>, NetworkPolicyMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = NetworkPolicyMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -0,0 +1,185 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::FileFormatOptions;
+use common_meta_app::principal::OnErrorMode;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipePartitionStatus;
+use common_meta_app::principal::PipeRejectedRecord;
+use common_meta_app::principal::PipeSource;
+use common_meta_app::principal::PipeStatus;
+use common_meta_app::principal::StageFileFormatType;
+use common_meta_app::principal::UserIdentity;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_pipe() -> Result<()> {
+    let (kv_api, pipe_api) = new_pipe_api().await?;
+
+    let pipe = create_test_pipe();
+    pipe_api.add_pipe(pipe.clone()).await?;
+    let value = kv_api.get_kv("__fd_pipes/admin/p1").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&pipe)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    match pipe_api.add_pipe(pipe).await {
+        Ok(_) => panic!("Already exists add pipe must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2626),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_drop_pipe() -> Result<()> {
+    let (kv_api, pipe_api) = new_pipe_api().await?;
+
+    let pipe = create_test_pipe();
+    pipe_api.add_pipe(pipe.clone()).await?;
+    pipe_api.set_pipe_status(create_test_status()).await?;
+    assert_eq!(pipe_api.get_pipes().await?, vec![pipe.clone()]);
+    assert_eq!(pipe_api.get_pipe_statuses().await?.len(), 1);
+
+    pipe_api.drop_pipe(&pipe.name, MatchSeq::GE(1)).await?;
+    assert_eq!(pipe_api.get_pipes().await?, vec![]);
+    // The status goes along with the pipe.
+    assert_eq!(kv_api.get_kv("__fd_pipe_status/admin/p1").await?, None);
+
+    match pipe_api.drop_pipe(&pipe.name, MatchSeq::GE(1)).await {
+        Ok(_) => panic!("Unknown pipe drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2627),
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_pipe_lease() -> Result<()> {
+    let (_, pipe_api) = new_pipe_api().await?;
+    let ttl = Duration::from_secs(60);
+
+    let lease = pipe_api.acquire_pipe_lease("p1", "node1", ttl).await?;
+    assert!(lease.is_some());
+    let lease = lease.unwrap();
+
+    // Only one holder at a time.
+    assert_eq!(pipe_api.acquire_pipe_lease("p1", "node2", ttl).await?, None);
+
+    let renewed = pipe_api.renew_pipe_lease("p1", lease, ttl).await?;
+    assert!(renewed.is_some());
+    // The old seq is not valid any more.
+    assert_eq!(pipe_api.renew_pipe_lease("p1", lease, ttl).await?, None);
+
+    pipe_api.release_pipe_lease("p1", renewed.unwrap()).await?;
+    assert!(
+        pipe_api
+            .acquire_pipe_lease("p1", "node2", ttl)
+            .await?
+            .is_some()
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_pipe_status_and_rejected_records() -> Result<()> {
+    let (_, pipe_api) = new_pipe_api().await?;
+
+    let mut status = create_test_status();
+    pipe_api.set_pipe_status(status.clone()).await?;
+    status.rows_loaded = 20;
+    pipe_api.set_pipe_status(status.clone()).await?;
+    assert_eq!(pipe_api.get_pipe_statuses().await?, vec![status]);
+
+    let record = PipeRejectedRecord {
+        pipe_name: "p1".to_string(),
+        partition: 0,
+        offset: 3,
+        error: "bad json".to_string(),
+        payload: "{\"a\": ".to_string(),
+        rejected_on: Utc::now(),
+    };
+    let retention = Duration::from_secs(3600);
+    pipe_api
+        .add_pipe_rejected_records(vec![record.clone()], retention)
+        .await?;
+    // The same position again, e.g. a batch replayed after a failed commit.
+    pipe_api
+        .add_pipe_rejected_records(vec![record.clone()], retention)
+        .await?;
+    assert_eq!(pipe_api.get_pipe_rejected_records().await?, vec![record]);
+    Ok(())
+}
+
+fn create_test_pipe() -> PipeInfo {
+    PipeInfo {
+        name: "p1".to_string(),
+        owner: UserIdentity::new("root", "%"),
+        database: "default".to_string(),
+        table: "t".to_string(),
+        source: PipeSource::Kafka {
+            brokers: vec!["localhost:9092".to_string()],
+            topic: "events".to_string(),
+            group: "p1".to_string(),
+        },
+        file_format_options: FileFormatOptions {
+            format: StageFileFormatType::NdJson,
+            ..Default::default()
+        },
+        on_error: OnErrorMode::Continue,
+        comment: "".to_string(),
+        created_on: Utc::now(),
+    }
+}
+
+fn create_test_status() -> PipeStatus {
+    PipeStatus {
+        pipe_name: "p1".to_string(),
+        holder: "node1".to_string(),
+        partitions: vec![PipePartitionStatus {
+            partition: 0,
+            offset: 10,
+            high_watermark: 12,
+        }],
+        rows_loaded: 10,
+        rows_rejected: 0,
+        rows_per_second: 5,
+        last_committed_on: Some(Utc::now()),
+        last_error: None,
+        updated_on: Utc::now(),
+    }
+}
+
+async fn new_pipe_api() -> Result<(Arc<MetaEmbedded>, PipeMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = PipeMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
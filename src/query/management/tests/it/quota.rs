@@ -0,0 +1,82 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::tenant::DatabaseQuota;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_database_quota() -> Result<()> {
+    let (kv_api, mgr) = new_quota_api().await?;
+
+    // Not set: unlimited.
+    {
+        let res = mgr.get_database_quota(1).await?;
+        assert_eq!(0, res.seq);
+        assert_eq!(DatabaseQuota::default(), res.data);
+    }
+
+    let quota = DatabaseQuota {
+        max_tables: 10,
+        max_bytes: 1024,
+        max_rows: 0,
+    };
+
+    // Set and get.
+    {
+        let seq = mgr
+            .set_database_quota(1, &quota, MatchSeq::Exact(0))
+            .await?;
+        let res = mgr.get_database_quota(1).await?;
+        assert_eq!(seq, res.seq);
+        assert_eq!(quota, res.data);
+
+        let value = kv_api.get_kv("__fd_database_quota/1").await?;
+        assert_eq!(serde_json::to_vec(&quota)?, value.unwrap().data);
+    }
+
+    // Other databases are not affected.
+    {
+        let res = mgr.get_database_quota(2).await?;
+        assert_eq!(DatabaseQuota::default(), res.data);
+    }
+
+    // Batch get, in the order of ids.
+    {
+        let res = mgr.get_database_quotas(&[2, 1]).await?;
+        assert_eq!(vec![DatabaseQuota::default(), quota.clone()], res);
+    }
+
+    // Seq mismatch.
+    {
+        let res = mgr
+            .set_database_quota(1, &DatabaseQuota::default(), MatchSeq::Exact(0))
+            .await;
+        assert!(res.is_err());
+    }
+
+    Ok(())
+}
+
+async fn new_quota_api() -> Result<(Arc<MetaEmbedded>, QuotaMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = QuotaMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -0,0 +1,251 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::SequenceInfo;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_sequence() -> Result<()> {
+    let (kv_api, sequence_api) = new_sequence_api().await?;
+
+    let info = create_test_sequence(1, 1, 3, false);
+    sequence_api.add_sequence(info.clone()).await?;
+    let value = kv_api.get_kv("__fd_sequences/admin/seq1").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&info)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    match sequence_api.add_sequence(info).await {
+        Ok(_) => panic!("Already exists add sequence must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2623),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_next_sequence_value() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+
+    sequence_api
+        .add_sequence(create_test_sequence(1, 1, 3, false))
+        .await?;
+    for expected in 1..=3 {
+        assert_eq!(sequence_api.next_sequence_value("seq1").await?, expected);
+    }
+    match sequence_api.next_sequence_value("seq1").await {
+        Ok(_) => panic!("Exhausted sequence must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2625),
+    }
+
+    let info = sequence_api.get_sequence("seq1", MatchSeq::GE(0)).await?;
+    assert_eq!(info.data.current, Some(3));
+
+    match sequence_api.next_sequence_value("unknown").await {
+        Ok(_) => panic!("Unknown sequence must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2624),
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_next_sequence_value_cycle() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+
+    let mut info = create_test_sequence(-2, 1, 3, true);
+    info.name = "seq2".to_string();
+    info.start = 3;
+    sequence_api.add_sequence(info).await?;
+
+    let mut values = vec![];
+    for _ in 0..4 {
+        values.push(sequence_api.next_sequence_value("seq2").await?);
+    }
+    assert_eq!(values, vec![3, 1, 3, 1]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_concurrent_next_sequence_value() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+    let sequence_api = Arc::new(sequence_api);
+
+    sequence_api
+        .add_sequence(create_test_sequence(1, 1, i64::MAX, false))
+        .await?;
+
+    let mut handles = vec![];
+    for _ in 0..5 {
+        let sequence_api = sequence_api.clone();
+        handles.push(tokio::spawn(async move {
+            sequence_api.next_sequence_value("seq1").await
+        }));
+    }
+
+    let mut values = vec![];
+    for handle in handles {
+        values.push(handle.await.unwrap()?);
+    }
+    values.sort();
+    assert_eq!(values, vec![1, 2, 3, 4, 5]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_reserve_sequence_values() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+
+    sequence_api
+        .add_sequence(create_test_sequence(1, 1, 10, false))
+        .await?;
+    assert_eq!(
+        sequence_api.reserve_sequence_values("seq1", 4).await?,
+        vec![1, 2, 3, 4]
+    );
+    assert_eq!(sequence_api.next_sequence_value("seq1").await?, 5);
+
+    // Fewer values are reserved if the sequence is exhausted in between.
+    assert_eq!(
+        sequence_api.reserve_sequence_values("seq1", 10).await?,
+        vec![6, 7, 8, 9, 10]
+    );
+    match sequence_api.reserve_sequence_values("seq1", 10).await {
+        Ok(_) => panic!("Exhausted sequence must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2625),
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_concurrent_reserve_sequence_values() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+    let sequence_api = Arc::new(sequence_api);
+
+    sequence_api
+        .add_sequence(create_test_sequence(1, 1, i64::MAX, false))
+        .await?;
+
+    let mut handles = vec![];
+    for _ in 0..5 {
+        let sequence_api = sequence_api.clone();
+        handles.push(tokio::spawn(async move {
+            sequence_api.reserve_sequence_values("seq1", 3).await
+        }));
+    }
+
+    let mut values = vec![];
+    for handle in handles {
+        let block = handle.await.unwrap()?;
+        assert_eq!(block.len(), 3);
+        assert!(block.windows(2).all(|w| w[0] + 1 == w[1]));
+        values.extend(block);
+    }
+    values.sort();
+    assert_eq!(values, (1..=15).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_set_sequence_value() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+
+    sequence_api
+        .add_sequence(create_test_sequence(1, 1, 10, false))
+        .await?;
+    sequence_api.set_sequence_value("seq1", 7).await?;
+    assert_eq!(sequence_api.next_sequence_value("seq1").await?, 8);
+
+    // The sequence can go back.
+    sequence_api.set_sequence_value("seq1", 2).await?;
+    assert_eq!(sequence_api.next_sequence_value("seq1").await?, 3);
+
+    match sequence_api.set_sequence_value("seq1", 11).await {
+        Ok(_) => panic!("Out of bounds value must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 1006),
+    }
+    match sequence_api.set_sequence_value("unknown", 1).await {
+        Ok(_) => panic!("Unknown sequence must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2624),
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_drop_sequence() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+
+    let info = create_test_sequence(1, 1, 3, false);
+    sequence_api.add_sequence(info.clone()).await?;
+
+    let sequences = sequence_api.get_sequences().await?;
+    assert_eq!(sequences, vec![info.clone()]);
+
+    sequence_api
+        .drop_sequence(&info.name, MatchSeq::GE(1))
+        .await?;
+
+    let sequences = sequence_api.get_sequences().await?;
+    assert_eq!(sequences, vec![]);
+
+    match sequence_api
+        .drop_sequence(&info.name, MatchSeq::GE(1))
+        .await
+    {
+        Ok(_) => panic!("Unknown sequence drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2624),
+    }
+    Ok(())
+}
+
+fn create_test_sequence(
+    increment: i64,
+    min_value: i64,
+    max_value: i64,
+    cycle: bool,
+) -> SequenceInfo {
+    SequenceInfo {
+        name: "seq1".to_string(),
+        start: min_value,
+        increment,
+        min_value,
+        max_value,
+        cycle,
+        current: None,
+        comment: "".to_string(),
+        created_on: Utc::now(),
+    }
+}
+
+async fn new_sequence_api() -> Result<(Arc<MetaEmbedded>, SequenceMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = SequenceMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -112,6 +112,86 @@ async fn test_unknown_stage_drop_stage() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_update_stage() -> Result<()> {
+    let (_, stage_api) = new_stage_api().await?;
+
+    let mut stage_info = create_test_stage_info();
+    match stage_api
+        .update_stage(stage_info.clone(), MatchSeq::GE(1))
+        .await
+    {
+        Ok(_) => panic!("Unknown stage update stage must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2501),
+    }
+
+    stage_api.add_stage(stage_info.clone()).await?;
+    stage_info.stage_params.storage = StorageParams::S3(StorageS3Config {
+        bucket: "mystage_bucket".to_string(),
+        access_key_id: "rotated_key_id".to_string(),
+        secret_access_key: "rotated_secret".to_string(),
+        ..Default::default()
+    });
+    let seq = stage_api
+        .update_stage(stage_info.clone(), MatchSeq::GE(1))
+        .await?;
+    assert_eq!(seq, 2);
+
+    let stage = stage_api.get_stage("mystage", MatchSeq::GE(0)).await?;
+    assert_eq!(stage.data, stage_info);
+
+    // The stage has been changed since seq 1.
+    match stage_api
+        .update_stage(stage_info.clone(), MatchSeq::Exact(1))
+        .await
+    {
+        Ok(_) => panic!("Update stage with a stale seq must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2501),
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_rename_stage() -> Result<()> {
+    let (_, stage_api) = new_stage_api().await?;
+
+    match stage_api.rename_stage("mystage", "newstage").await {
+        Ok(_) => panic!("Unknown stage rename stage must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2501),
+    }
+
+    let stage_info = create_test_stage_info();
+    stage_api.add_stage(stage_info.clone()).await?;
+    stage_api
+        .add_file("mystage", StageFile {
+            path: "books.csv".to_string(),
+            size: 100,
+            ..Default::default()
+        })
+        .await?;
+
+    stage_api.rename_stage("mystage", "newstage").await?;
+    match stage_api.get_stage("mystage", MatchSeq::GE(0)).await {
+        Ok(_) => panic!("Renamed stage must not exist."),
+        Err(cause) => assert_eq!(cause.code(), 2501),
+    }
+    let stage = stage_api.get_stage("newstage", MatchSeq::GE(0)).await?.data;
+    assert_eq!(stage.stage_name, "newstage");
+    assert_eq!(stage.number_of_files, 1);
+    assert!(stage_api.list_files("mystage").await?.is_empty());
+    let files = stage_api.list_files("newstage").await?;
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].path, "books.csv".to_string());
+
+    // Can't rename to an existing stage.
+    stage_api.add_stage(stage_info).await?;
+    match stage_api.rename_stage("mystage", "newstage").await {
+        Ok(_) => panic!("Rename stage to an existing stage must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2502),
+    }
+    Ok(())
+}
+
 fn create_test_stage_info() -> StageInfo {
     StageInfo {
         stage_name: "mystage".to_string(),
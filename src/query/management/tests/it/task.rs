@@ -0,0 +1,163 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::TaskInfo;
+use common_meta_app::principal::TaskRun;
+use common_meta_app::principal::TaskRunState;
+use common_meta_app::principal::TaskRunTrigger;
+use common_meta_app::principal::TaskSchedule;
+use common_meta_app::principal::TaskState;
+use common_meta_app::principal::UserIdentity;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_task() -> Result<()> {
+    let (kv_api, task_api) = new_task_api().await?;
+
+    let task = create_test_task();
+    task_api.add_task(task.clone()).await?;
+    let value = kv_api.get_kv("__fd_tasks/admin/t1").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&task)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    match task_api.add_task(task).await {
+        Ok(_) => panic!("Already exists add task must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2611),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_update_and_drop_task() -> Result<()> {
+    let (_, task_api) = new_task_api().await?;
+
+    let mut task = create_test_task();
+    task_api.add_task(task.clone()).await?;
+
+    task.state = TaskState::Suspended;
+    task_api.update_task(task.clone(), MatchSeq::GE(1)).await?;
+    let value = task_api.get_task(&task.name, MatchSeq::GE(0)).await?;
+    assert_eq!(value.data, task);
+
+    // A stale seq means somebody else updated the task in the meantime.
+    match task_api.update_task(task.clone(), MatchSeq::Exact(1)).await {
+        Ok(_) => panic!("Update task with a stale seq must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2612),
+    }
+
+    assert_eq!(task_api.get_tasks().await?, vec![task.clone()]);
+    task_api.drop_task(&task.name, MatchSeq::GE(1)).await?;
+    assert_eq!(task_api.get_tasks().await?, vec![]);
+
+    match task_api.drop_task(&task.name, MatchSeq::GE(1)).await {
+        Ok(_) => panic!("Unknown task drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2612),
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_lease() -> Result<()> {
+    let (_, task_api) = new_task_api().await?;
+    let ttl = Duration::from_secs(60);
+
+    let lease = task_api.acquire_task_lease("t1", "node1", ttl).await?;
+    assert!(lease.is_some());
+    let lease = lease.unwrap();
+
+    // Only one holder at a time.
+    assert_eq!(task_api.acquire_task_lease("t1", "node2", ttl).await?, None);
+    assert!(
+        task_api
+            .acquire_task_lease("t2", "node2", ttl)
+            .await?
+            .is_some()
+    );
+
+    let renewed = task_api.renew_task_lease("t1", lease, ttl).await?;
+    assert!(renewed.is_some());
+    // The old seq is not valid any more.
+    assert_eq!(task_api.renew_task_lease("t1", lease, ttl).await?, None);
+
+    task_api.release_task_lease("t1", renewed.unwrap()).await?;
+    assert!(
+        task_api
+            .acquire_task_lease("t1", "node2", ttl)
+            .await?
+            .is_some()
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_runs() -> Result<()> {
+    let (_, task_api) = new_task_api().await?;
+
+    let run = TaskRun {
+        task_name: "t1".to_string(),
+        query_id: "q1".to_string(),
+        trigger: TaskRunTrigger::Manual,
+        state: TaskRunState::Failed,
+        error: Some("Code: 1025, displayText = Unknown table t".to_string()),
+        started_on: Utc::now(),
+        duration_ms: 10,
+    };
+    task_api
+        .add_task_run(run.clone(), Duration::from_secs(3600))
+        .await?;
+
+    assert_eq!(task_api.get_task_runs().await?, vec![run]);
+    Ok(())
+}
+
+fn create_test_task() -> TaskInfo {
+    TaskInfo {
+        name: "t1".to_string(),
+        owner: UserIdentity::new("root", "%"),
+        database: "default".to_string(),
+        schedule: TaskSchedule::Interval { secs: 300 },
+        query_text: "DELETE FROM t WHERE a < 10".to_string(),
+        state: TaskState::Started,
+        comment: "".to_string(),
+        created_on: Utc::now(),
+        updated_on: Utc::now(),
+        last_scheduled_on: None,
+    }
+}
+
+async fn new_task_api() -> Result<(Arc<MetaEmbedded>, TaskMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = TaskMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -56,6 +56,8 @@ pub struct Pipe {
     pub items: Vec<PipeItem>,
     pub input_length: usize,
     pub output_length: usize,
+    /// Rows estimated by the optimizer for the plan which builds this pipe, used by `EXPLAIN PIPELINE`.
+    pub estimated_rows: Option<f64>,
 }
 
 impl Debug for Pipe {
@@ -70,6 +72,7 @@ impl Pipe {
             items,
             input_length: inputs,
             output_length: outputs,
+            estimated_rows: None,
         }
     }
 }
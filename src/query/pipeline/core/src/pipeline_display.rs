@@ -15,13 +15,47 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use serde_json::json;
+
 use crate::pipe::Pipe;
 use crate::Pipeline;
 
+/// Version of the document of `EXPLAIN PIPELINE FORMAT = JSON`, bump it on any
+/// incompatible change of the document.
+const PIPELINE_JSON_VERSION: u32 = 1;
+
 impl Pipeline {
     pub fn display_indent(&self) -> impl std::fmt::Display + '_ {
         PipelineIndentDisplayWrapper { pipeline: self }
     }
+
+    /// Display the pipelines as a Mermaid flowchart, each pipeline is a subgraph.
+    pub fn display_mermaid<'a>(pipelines: &'a [&'a Pipeline]) -> impl std::fmt::Display + 'a {
+        PipelinesGraphDisplayWrapper {
+            pipelines,
+            format: GraphFormat::Mermaid,
+        }
+    }
+
+    /// Display the pipelines as a Graphviz DOT digraph, each pipeline is a cluster.
+    pub fn display_dot<'a>(pipelines: &'a [&'a Pipeline]) -> impl std::fmt::Display + 'a {
+        PipelinesGraphDisplayWrapper {
+            pipelines,
+            format: GraphFormat::Dot,
+        }
+    }
+
+    /// Display the pipelines as a JSON document of the processors and the edges between them.
+    pub fn display_json<'a>(pipelines: &'a [&'a Pipeline]) -> impl std::fmt::Display + 'a {
+        PipelinesGraphDisplayWrapper {
+            pipelines,
+            format: GraphFormat::Json,
+        }
+    }
+}
+
+fn pipe_name(pipe: &Pipe) -> String {
+    unsafe { pipe.items[0].processor.name() }
 }
 
 struct PipelineIndentDisplayWrapper<'a> {
@@ -29,8 +63,22 @@ struct PipelineIndentDisplayWrapper<'a> {
 }
 
 impl<'a> PipelineIndentDisplayWrapper<'a> {
-    fn pipe_name(pipe: &Pipe) -> String {
-        unsafe { pipe.items[0].processor.name() }
+    fn pipe_details(pipe: &Pipe) -> String {
+        let mut details = vec![];
+        if pipe.input_length > 0 {
+            details.push(format!("inputs: {}", pipe.input_length));
+        }
+        if pipe.output_length > 0 {
+            details.push(format!("outputs: {}", pipe.output_length));
+        }
+        if let Some(rows) = pipe.estimated_rows {
+            details.push(format!("estimated rows: {rows:.2}"));
+        }
+        if details.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", details.join(", "))
+        }
     }
 }
 
@@ -52,19 +100,20 @@ impl<'a> Display for PipelineIndentDisplayWrapper<'a> {
             {
                 write!(
                     f,
-                    "{} × {} {}",
-                    Self::pipe_name(pipe),
+                    "{} × {} {}{}",
+                    pipe_name(pipe),
                     pipe.items.len(),
                     if pipe.items.len() == 1 {
                         "processor"
                     } else {
                         "processors"
                     },
+                    Self::pipe_details(pipe),
                 )?;
             } else {
-                let prev_name = Self::pipe_name(&pipes[pipes.len() - index - 2]);
+                let prev_name = pipe_name(&pipes[pipes.len() - index - 2]);
                 if index > 0 {
-                    let post_name = Self::pipe_name(&pipes[pipes.len() - index]);
+                    let post_name = pipe_name(&pipes[pipes.len() - index]);
                     write!(
                         f,
                         "Merge ({} × {} {}) to ({} × {})",
@@ -97,3 +146,118 @@ impl<'a> Display for PipelineIndentDisplayWrapper<'a> {
         Ok(())
     }
 }
+
+enum GraphFormat {
+    Mermaid,
+    Dot,
+    Json,
+}
+
+struct PipelinesGraphDisplayWrapper<'a> {
+    pipelines: &'a [&'a Pipeline],
+    format: GraphFormat,
+}
+
+impl<'a> PipelinesGraphDisplayWrapper<'a> {
+    fn node_label(pipe: &Pipe, line_break: &str) -> String {
+        let mut label = format!("{} × {}", pipe_name(pipe), pipe.items.len());
+        if let Some(rows) = pipe.estimated_rows {
+            label.push_str(&format!("{line_break}estimated rows: {rows:.2}"));
+        }
+        label
+    }
+
+    fn fmt_mermaid(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "flowchart TD")?;
+        for (pipeline_index, pipeline) in self.pipelines.iter().enumerate() {
+            writeln!(
+                f,
+                "    subgraph pipeline_{pipeline_index} [Pipeline {pipeline_index}]"
+            )?;
+            for (pipe_index, pipe) in pipeline.pipes.iter().enumerate() {
+                let label = Self::node_label(pipe, "<br/>").replace('"', "#quot;");
+                writeln!(f, "        p{pipeline_index}_{pipe_index}[\"{label}\"]")?;
+            }
+            for (pipe_index, pipe) in pipeline.pipes.iter().enumerate().skip(1) {
+                writeln!(
+                    f,
+                    "        p{pipeline_index}_{} -->|{}| p{pipeline_index}_{pipe_index}",
+                    pipe_index - 1,
+                    pipe.input_length
+                )?;
+            }
+            writeln!(f, "    end")?;
+        }
+        Ok(())
+    }
+
+    fn fmt_dot(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "digraph {{")?;
+        writeln!(f, "    node [shape = box];")?;
+        for (pipeline_index, pipeline) in self.pipelines.iter().enumerate() {
+            writeln!(f, "    subgraph cluster_{pipeline_index} {{")?;
+            writeln!(f, "        label = \"Pipeline {pipeline_index}\";")?;
+            for (pipe_index, pipe) in pipeline.pipes.iter().enumerate() {
+                let label = Self::node_label(pipe, "\n")
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n");
+                writeln!(
+                    f,
+                    "        p{pipeline_index}_{pipe_index} [label = \"{label}\"];"
+                )?;
+            }
+            for (pipe_index, pipe) in pipeline.pipes.iter().enumerate().skip(1) {
+                writeln!(
+                    f,
+                    "        p{pipeline_index}_{} -> p{pipeline_index}_{pipe_index} [label = \"{}\"];",
+                    pipe_index - 1,
+                    pipe.input_length
+                )?;
+            }
+            writeln!(f, "    }}")?;
+        }
+        write!(f, "}}")
+    }
+
+    fn fmt_json(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut nodes = vec![];
+        let mut edges = vec![];
+        for (pipeline_index, pipeline) in self.pipelines.iter().enumerate() {
+            for (pipe_index, pipe) in pipeline.pipes.iter().enumerate() {
+                nodes.push(json!({
+                    "id": format!("p{pipeline_index}_{pipe_index}"),
+                    "pipeline": pipeline_index,
+                    "name": pipe_name(pipe),
+                    "processors": pipe.items.len(),
+                    "inputs": pipe.input_length,
+                    "outputs": pipe.output_length,
+                    "estimated_rows": pipe.estimated_rows,
+                }));
+            }
+            for (pipe_index, pipe) in pipeline.pipes.iter().enumerate().skip(1) {
+                edges.push(json!({
+                    "from": format!("p{pipeline_index}_{}", pipe_index - 1),
+                    "to": format!("p{pipeline_index}_{pipe_index}"),
+                    "ports": pipe.input_length,
+                }));
+            }
+        }
+        let document = json!({
+            "version": PIPELINE_JSON_VERSION,
+            "nodes": nodes,
+            "edges": edges,
+        });
+        write!(f, "{:#}", document)
+    }
+}
+
+impl<'a> Display for PipelinesGraphDisplayWrapper<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.format {
+            GraphFormat::Mermaid => self.fmt_mermaid(f),
+            GraphFormat::Dot => self.fmt_dot(f),
+            GraphFormat::Json => self.fmt_json(f),
+        }
+    }
+}
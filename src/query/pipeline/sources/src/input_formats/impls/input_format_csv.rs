@@ -126,14 +126,28 @@ impl InputFormatTextBase for InputFormatCSV {
             ) {
                 match builder.ctx.on_error_mode {
                     OnErrorMode::Continue => {
-                        Self::on_error_continue(columns, num_rows, e.clone(), &mut error_map);
+                        Self::on_error_continue(
+                            columns,
+                            num_rows,
+                            e.clone(),
+                            batch.line_of_row(i),
+                            &mut error_map,
+                        );
                         start = *end;
                         field_end_idx += n_column;
                         continue;
                     }
                     OnErrorMode::AbortNum(n) => {
-                        Self::on_error_abort(columns, num_rows, n, &builder.ctx.on_error_count, e)
-                            .map_err(|e| batch.error(&e.message(), &builder.ctx, start, i))?;
+                        Self::on_error_abort(
+                            columns,
+                            num_rows,
+                            n,
+                            &builder.ctx.on_error_count,
+                            e,
+                            batch.line_of_row(i),
+                            &mut error_map,
+                        )
+                        .map_err(|e| batch.error(&e.message(), &builder.ctx, start, i))?;
                         start = *end;
                         field_end_idx += n_column;
                         continue;
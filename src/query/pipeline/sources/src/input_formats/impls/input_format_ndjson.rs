@@ -110,7 +110,13 @@ impl InputFormatTextBase for InputFormatNDJson {
                 if let Err(e) = Self::read_row(field_decoder, buf, columns, &builder.ctx.schema) {
                     match builder.ctx.on_error_mode {
                         OnErrorMode::Continue => {
-                            Self::on_error_continue(columns, num_rows, e.clone(), &mut error_map);
+                            Self::on_error_continue(
+                                columns,
+                                num_rows,
+                                e.clone(),
+                                batch.line_of_row(i),
+                                &mut error_map,
+                            );
                             start = *end;
                             continue;
                         }
@@ -121,6 +127,8 @@ impl InputFormatTextBase for InputFormatNDJson {
                                 n,
                                 &builder.ctx.on_error_count,
                                 e,
+                                batch.line_of_row(i),
+                                &mut error_map,
                             )
                             .map_err(|e| batch.error(&e.message(), &builder.ctx, start, i))?;
 
@@ -45,6 +45,7 @@ use common_meta_app::principal::StageInfo;
 use common_pipeline_core::Pipeline;
 use common_settings::Settings;
 use common_storage::read_parquet_metas_in_parallel;
+use common_storage::FileStatus;
 use common_storage::StageFileInfo;
 use futures::AsyncRead;
 use futures::AsyncReadExt;
@@ -220,6 +221,7 @@ impl DynData for SplitMeta {
 
 pub struct RowGroupInMemory {
     pub split_info: String,
+    pub path: String,
     pub meta: RowGroupMetaData,
     // for input, they are in the order of schema.
     // for select, they are the fields used in query.
@@ -242,6 +244,7 @@ impl RowBatchTrait for RowGroupInMemory {
 impl RowGroupInMemory {
     fn read<R: Read + Seek>(
         split_info: String,
+        path: String,
         reader: &mut R,
         meta: RowGroupMetaData,
         fields: Arc<Vec<Field>>,
@@ -256,6 +259,7 @@ impl RowGroupInMemory {
         }
         Ok(Self {
             split_info,
+            path,
             meta,
             field_meta_indexes,
             field_arrays: filed_arrays,
@@ -302,6 +306,7 @@ impl RowGroupInMemory {
 
         Ok(Self {
             split_info: split_info.to_string(),
+            path: split_info.file.path.clone(),
             meta,
             field_meta_indexes,
             field_arrays,
@@ -391,6 +396,10 @@ impl BlockBuilderTrait for ParquetBlockBuilder {
             let block = DataBlock::from_arrow_chunk(&chunk, &input_schema)?;
 
             let block_total_rows = block.num_rows();
+            self.ctx.copy_status.add_chunk(&rg.path, FileStatus {
+                rows_loaded: block_total_rows as u64,
+                ..Default::default()
+            });
             let num_rows_per_block = self.ctx.block_compact_thresholds.max_rows_per_block;
             let blocks: Vec<DataBlock> = (0..block_total_rows)
                 .step_by(num_rows_per_block)
@@ -454,6 +463,7 @@ impl AligningStateTrait for AligningState {
             for row_group in file_meta.row_groups.into_iter() {
                 row_batches.push(RowGroupInMemory::read(
                     split_info.clone(),
+                    self.split_info.file.path.clone(),
                     &mut cursor,
                     row_group,
                     fields.clone(),
@@ -160,13 +160,27 @@ impl InputFormatTextBase for InputFormatTSV {
             ) {
                 match builder.ctx.on_error_mode {
                     OnErrorMode::Continue => {
-                        Self::on_error_continue(columns, num_rows, e.clone(), &mut error_map);
+                        Self::on_error_continue(
+                            columns,
+                            num_rows,
+                            e.clone(),
+                            batch.line_of_row(i),
+                            &mut error_map,
+                        );
                         start = *end;
                         continue;
                     }
                     OnErrorMode::AbortNum(n) => {
-                        Self::on_error_abort(columns, num_rows, n, &builder.ctx.on_error_count, e)
-                            .map_err(|e| batch.error(&e.message(), &builder.ctx, start, i))?;
+                        Self::on_error_abort(
+                            columns,
+                            num_rows,
+                            n,
+                            &builder.ctx.on_error_count,
+                            e,
+                            batch.line_of_row(i),
+                            &mut error_map,
+                        )
+                        .map_err(|e| batch.error(&e.message(), &builder.ctx, start, i))?;
                         start = *end;
                         continue;
                     }
@@ -236,6 +236,7 @@ impl InputFormatTextBase for InputFormatXML {
                                             columns,
                                             num_rows,
                                             e.clone(),
+                                            None,
                                             &mut error_map,
                                         );
                                         continue;
@@ -247,6 +248,8 @@ impl InputFormatTextBase for InputFormatXML {
                                             n,
                                             &builder.ctx.on_error_count,
                                             e,
+                                            None,
+                                            &mut error_map,
                                         )
                                         .map_err(|e| xml_error(&e.message(), path, num_rows))?;
                                         continue;
@@ -36,6 +36,7 @@ use common_meta_app::principal::StageFileCompression;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::StageInfo;
 use common_settings::Settings;
+use common_storage::CopyStatus;
 use dashmap::DashMap;
 use opendal::Operator;
 
@@ -124,6 +125,7 @@ pub struct InputContext {
     pub on_error_mode: OnErrorMode,
     pub on_error_count: AtomicU64,
     pub on_error_map: Option<DashMap<String, HashMap<u16, InputError>>>,
+    pub copy_status: Arc<CopyStatus>,
 }
 
 impl Debug for InputContext {
@@ -160,6 +162,7 @@ impl InputContext {
         splits: Vec<Arc<SplitInfo>>,
         scan_progress: Arc<Progress>,
         block_compact_thresholds: BlockThresholds,
+        copy_status: Arc<CopyStatus>,
     ) -> Result<Self> {
         let on_error_mode = stage_info.copy_options.on_error.clone();
         let plan = Box::new(CopyIntoPlan { stage_info });
@@ -188,6 +191,7 @@ impl InputContext {
             on_error_mode,
             on_error_count: AtomicU64::new(0),
             on_error_map: Some(DashMap::new()),
+            copy_status,
         })
     }
 
@@ -230,6 +234,7 @@ impl InputContext {
             on_error_mode: OnErrorMode::AbortNum(1),
             on_error_count: AtomicU64::new(0),
             on_error_map: None,
+            copy_status: Arc::new(CopyStatus::default()),
         })
     }
 
@@ -269,6 +274,7 @@ impl InputContext {
             on_error_mode: OnErrorMode::AbortNum(1),
             on_error_count: AtomicU64::new(0),
             on_error_map: None,
+            copy_status: Arc::new(CopyStatus::default()),
         })
     }
 
@@ -18,4 +18,7 @@ use common_exception::ErrorCode;
 pub struct InputError {
     pub(crate) err: ErrorCode,
     pub(crate) num: usize,
+    /// 1-based position of the first row hitting this error, if known.
+    pub(crate) line: Option<usize>,
+    pub(crate) column: Option<usize>,
 }
@@ -33,6 +33,8 @@ use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::StageInfo;
 use common_pipeline_core::Pipeline;
 use common_settings::Settings;
+use common_storage::FileParseError;
+use common_storage::FileStatus;
 use common_storage::StageFileInfo;
 use opendal::Operator;
 
@@ -236,18 +238,19 @@ pub trait InputFormatTextBase: Sized + Send + Sync + 'static {
         columns: &mut Vec<ColumnBuilder>,
         num_rows: usize,
         e: ErrorCode,
+        line: Option<usize>,
         error_map: &mut HashMap<u16, InputError>,
     ) {
-        columns.iter_mut().for_each(|c| {
-            // check if parts of columns inserted data, if so, pop it.
-            if c.len() > num_rows {
-                c.pop().expect("must success");
-            }
-        });
+        let column = Self::pop_partial_row(columns, num_rows);
         error_map
             .entry(e.code())
             .and_modify(|input_error| input_error.num += 1)
-            .or_insert(InputError { err: e, num: 1 });
+            .or_insert(InputError {
+                err: e,
+                num: 1,
+                line,
+                column,
+            });
     }
 
     fn on_error_abort(
@@ -256,17 +259,32 @@ pub trait InputFormatTextBase: Sized + Send + Sync + 'static {
         abort_num: u64,
         error_count: &AtomicU64,
         e: ErrorCode,
+        line: Option<usize>,
+        error_map: &mut HashMap<u16, InputError>,
     ) -> Result<()> {
         if abort_num <= 1 || error_count.fetch_add(1, Ordering::Relaxed) >= abort_num - 1 {
             return Err(e);
         }
+        Self::on_error_continue(columns, num_rows, e, line, error_map);
+        Ok(())
+    }
+
+    /// Pop the values of a row failing to load, returns the 1-based column
+    /// which fails to decode. Columns are decoded in order, so it is the first one not pushed.
+    fn pop_partial_row(columns: &mut Vec<ColumnBuilder>, num_rows: usize) -> Option<usize> {
+        let mut num_decoded = 0;
         columns.iter_mut().for_each(|c| {
             // check if parts of columns inserted data, if so, pop it.
             if c.len() > num_rows {
                 c.pop().expect("must success");
+                num_decoded += 1;
             }
         });
-        Ok(())
+        if num_decoded < columns.len() {
+            Some(num_decoded + 1)
+        } else {
+            None
+        }
     }
 }
 
@@ -377,6 +395,12 @@ pub struct RowBatch {
 }
 
 impl RowBatch {
+    /// 1-based line of the row in the file, only known for the first split of a file.
+    pub fn line_of_row(&self, row: usize) -> Option<usize> {
+        self.start_row_of_split
+            .map(|start| start + self.start_row_in_split + row + 1)
+    }
+
     pub fn error(&self, msg: &str, ctx: &InputContext, offset: usize, row: usize) -> ErrorCode {
         ctx.parse_error_row_based(
             msg,
@@ -497,6 +521,27 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
                 .or_insert(error_map);
         }
     }
+
+    fn add_copy_status(
+        &self,
+        error_map: &HashMap<u16, InputError>,
+        file_name: &str,
+        rows_loaded: usize,
+    ) {
+        let first_error = error_map
+            .values()
+            .min_by_key(|e| e.line.unwrap_or(usize::MAX))
+            .map(|e| FileParseError {
+                message: e.err.message(),
+                line: e.line.map(|l| l as u64),
+                column: e.column.map(|c| c as u64),
+            });
+        self.ctx.copy_status.add_chunk(file_name, FileStatus {
+            rows_loaded: rows_loaded as u64,
+            error_count: error_map.values().map(|e| e.num as u64).sum(),
+            first_error,
+        });
+    }
 }
 
 impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
@@ -530,7 +575,10 @@ impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
         if let Some(b) = batch {
             let file_name = b.split_info.file.path.clone();
             self.num_rows += b.row_ends.len();
+            let rows_before = self.mutable_columns.first().map_or(0, |c| c.len());
             let r = T::deserialize(self, b)?;
+            let rows_loaded = self.mutable_columns.first().map_or(0, |c| c.len()) - rows_before;
+            self.add_copy_status(&r, &file_name, rows_loaded);
             self.merge_map(r, file_name);
             let mem = self.memory_size();
             tracing::debug!(
@@ -0,0 +1,120 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A loser tree to merge `k` sorted streams.
+///
+/// Each leaf holds the current item of a stream, `None` if the stream is exhausted. Each
+/// inner node remembers the loser of the match between its two subtrees, so replacing the
+/// winner only replays the matches on the path from its leaf to the root, which takes
+/// `log2(k)` comparisons, about half of a binary heap.
+///
+/// The winner among equal items is not defined, the order of the items should break the
+/// ties (e.g. [`super::Cursor`] is ordered by its input index at last) if the merge needs
+/// to be stable.
+pub struct LoserTree<T: Ord> {
+    /// `tree[0]` is the index of the winner, `tree[i]` is the index of the loser at node `i`.
+    tree: Vec<usize>,
+    items: Vec<Option<T>>,
+}
+
+impl<T: Ord> LoserTree<T> {
+    pub fn create(items: Vec<Option<T>>) -> Self {
+        let mut loser_tree = LoserTree {
+            tree: vec![0; items.len()],
+            items,
+        };
+
+        if !loser_tree.items.is_empty() {
+            loser_tree.tree[0] = loser_tree.build(1);
+        }
+        loser_tree
+    }
+
+    /// Returns the winner of the subtree rooted at `node`, and records the losers in it.
+    fn build(&mut self, node: usize) -> usize {
+        let k = self.items.len();
+        if node >= k {
+            return node - k;
+        }
+
+        let left = self.build(node * 2);
+        let right = self.build(node * 2 + 1);
+        let (winner, loser) = match self.less(right, left) {
+            true => (right, left),
+            false => (left, right),
+        };
+        self.tree[node] = loser;
+        winner
+    }
+
+    /// An exhausted stream loses to any others.
+    #[inline]
+    fn less(&self, a: usize, b: usize) -> bool {
+        match (&self.items[a], &self.items[b]) {
+            (Some(a), Some(b)) => a < b,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+
+    /// Returns true if all the streams are exhausted.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty() || self.items[self.tree[0]].is_none()
+    }
+
+    /// Returns the index of the stream holding the smallest item.
+    #[inline]
+    pub fn winner_index(&self) -> usize {
+        self.tree[0]
+    }
+
+    #[inline]
+    pub fn winner(&self) -> Option<&T> {
+        self.items.get(self.tree[0]).and_then(Option::as_ref)
+    }
+
+    /// The order of the winner may be changed through the returned reference, [`Self::adjust`]
+    /// must be called afterwards to find the new winner.
+    #[inline]
+    pub fn winner_mut(&mut self) -> Option<&mut T> {
+        self.items.get_mut(self.tree[0]).and_then(Option::as_mut)
+    }
+
+    /// Replaces the winner with the next item of its stream, and returns the replaced one.
+    pub fn replace_winner(&mut self, item: Option<T>) -> Option<T> {
+        let winner = self.tree[0];
+        let replaced = std::mem::replace(&mut self.items[winner], item);
+        self.adjust();
+        replaced
+    }
+
+    /// Replays the matches of the winner, after it has been changed.
+    pub fn adjust(&mut self) {
+        let k = self.items.len();
+        if k == 0 {
+            return;
+        }
+
+        let mut winner = self.tree[0];
+        let mut node = (winner + k) / 2;
+        while node > 0 {
+            if self.less(self.tree[node], winner) {
+                std::mem::swap(&mut self.tree[node], &mut winner);
+            }
+            node /= 2;
+        }
+        self.tree[0] = winner;
+    }
+}
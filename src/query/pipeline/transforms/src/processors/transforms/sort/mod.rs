@@ -13,7 +13,9 @@
 //  limitations under the License.
 
 mod cursor;
+mod loser_tree;
 mod rows;
 
 pub use cursor::*;
+pub use loser_tree::*;
 pub use rows::*;
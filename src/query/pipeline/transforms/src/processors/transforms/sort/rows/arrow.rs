@@ -18,7 +18,8 @@ use common_arrow::arrow::compute::sort::row::Rows as ArrowRows;
 use common_arrow::arrow::compute::sort::row::SortField as ArrowSortField;
 use common_arrow::arrow::compute::sort::SortOptions as ArrowSortOptions;
 use common_exception::Result;
-use common_expression::arrow::column_to_arrow_array;
+use common_expression::sort_column_to_arrow_array;
+use common_expression::sort_key_data_type;
 use common_expression::BlockEntry;
 use common_expression::DataSchemaRef;
 use common_expression::SortColumnDescription;
@@ -46,9 +47,9 @@ impl RowConverter<ArrowRows> for ArrowRowConverter {
         let sort_fields = sort_columns_descriptions
             .iter()
             .map(|d| {
-                let data_type = output_schema.field(d.offset).data_type();
+                let data_type = sort_key_data_type(output_schema.field(d.offset).data_type());
                 Ok(ArrowSortField::new_with_options(
-                    data_type.into(),
+                    (&data_type).into(),
                     ArrowSortOptions {
                         descending: !d.asc,
                         nulls_first: d.nulls_first,
@@ -62,7 +63,7 @@ impl RowConverter<ArrowRows> for ArrowRowConverter {
     fn convert(&mut self, columns: &[BlockEntry], num_rows: usize) -> Result<ArrowRows> {
         let arrays = columns
             .iter()
-            .map(|col| column_to_arrow_array(col, num_rows))
+            .map(|col| sort_column_to_arrow_array(col, num_rows))
             .collect::<Vec<_>>();
         Ok(self.convert_columns(&arrays)?)
     }
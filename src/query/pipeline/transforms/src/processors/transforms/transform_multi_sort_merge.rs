@@ -24,6 +24,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
+use common_expression::types::IntervalType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -147,6 +148,17 @@ fn create_processor(
                 limit,
                 sort_columns_descriptions,
             )?)),
+            DataType::Interval => ProcessorPtr::create(Box::new(MultiSortMergeProcessor::<
+                SimpleRows<IntervalType>,
+                SimpleRowConverter<IntervalType>,
+            >::create(
+                inputs,
+                output,
+                output_schema,
+                block_size,
+                limit,
+                sort_columns_descriptions,
+            )?)),
             _ => ProcessorPtr::create(Box::new(MultiSortMergeProcessor::<
                 ArrowRows,
                 ArrowRowConverter,
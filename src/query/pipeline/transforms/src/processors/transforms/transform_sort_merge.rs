@@ -26,6 +26,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
+use common_expression::types::IntervalType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -214,6 +215,10 @@ type SimpleStringCompactor =
     SortMergeCompactor<SimpleRows<StringType>, SimpleRowConverter<StringType>>;
 type SimpleStringSort = TransformCompact<SimpleStringCompactor>;
 
+type SimpleIntervalCompactor =
+    SortMergeCompactor<SimpleRows<IntervalType>, SimpleRowConverter<IntervalType>>;
+type SimpleIntervalSort = TransformCompact<SimpleIntervalCompactor>;
+
 type CommonCompactor = SortMergeCompactor<ArrowRows, ArrowRowConverter>;
 type CommonSort = TransformCompact<CommonCompactor>;
 
@@ -277,6 +282,16 @@ pub fn try_create_transform_sort_merge(
                     sort_columns_descriptions,
                 )?,
             ),
+            DataType::Interval => SimpleIntervalSort::try_create(
+                input,
+                output,
+                SimpleIntervalCompactor::try_create(
+                    output_schema,
+                    block_size,
+                    limit,
+                    sort_columns_descriptions,
+                )?,
+            ),
             _ => CommonSort::try_create(
                 input,
                 output,
@@ -16,6 +16,7 @@ use std::cell::SyncUnsafeCell;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::ops::Deref;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use async_channel::Receiver;
@@ -52,7 +53,10 @@ use crate::api::ExchangeInjector;
 use crate::api::FlightClient;
 use crate::api::FragmentPlanPacket;
 use crate::api::InitNodesChannelPacket;
+use crate::api::KillQueryPacket;
 use crate::api::QueryFragmentsPlanPacket;
+use crate::clusters::Cluster;
+use crate::clusters::ClusterHelper;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::PipelineBuildResult;
@@ -244,6 +248,53 @@ impl DataExchangeManager {
         }
     }
 
+    // Kill the query fragments running in this node, the query was killed on the request server.
+    pub fn kill_query(&self, query_id: &str) {
+        let queries_coordinator_guard = self.queries_coordinator.lock();
+        let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
+
+        if let Some(query_coordinator) = queries_coordinator.get_mut(query_id) {
+            query_coordinator.kill_query(ErrorCode::AbortedQuery(
+                "Aborted query, because the server is shutting down or the query was killed",
+            ));
+        }
+    }
+
+    // Propagate the kill of a distributed query to the other nodes of the cluster.
+    pub fn broadcast_kill_query(&self, query_id: &str, cluster: &Cluster, timeout: u64) {
+        {
+            let queries_coordinator_guard = self.queries_coordinator.lock();
+            let queries_coordinator = unsafe { &*queries_coordinator_guard.deref().get() };
+
+            // Only the distributed queries are registered in exchange manager.
+            if !queries_coordinator.contains_key(query_id) {
+                return;
+            }
+        }
+
+        let local_id = cluster.local_id();
+        let packets = cluster
+            .get_nodes()
+            .into_iter()
+            .filter(|node| node.id != local_id)
+            .map(|node| KillQueryPacket::create(query_id.to_string(), node))
+            .collect::<Vec<_>>();
+
+        let config = GlobalConfig::instance();
+        GlobalIORuntime::instance().spawn(async move {
+            for packet in packets {
+                if let Err(cause) = packet.commit(config.as_ref(), timeout).await {
+                    tracing::warn!(
+                        "Cannot kill query {} in node {}, cause: {:?}",
+                        packet.query_id,
+                        packet.executor.id,
+                        cause
+                    );
+                }
+            }
+        });
+    }
+
     pub fn on_finished_query(&self, query_id: &str) {
         let queries_coordinator_guard = self.queries_coordinator.lock();
         let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
@@ -637,6 +688,19 @@ impl QueryCoordinator {
         }
     }
 
+    pub fn kill_query(&mut self, cause: ErrorCode) {
+        if let Some(query_info) = &self.info {
+            query_info
+                .query_ctx
+                .get_aborting()
+                .store(true, Ordering::Release);
+
+            if let Some(query_executor) = &query_info.query_executor {
+                query_executor.finish(Some(cause));
+            }
+        }
+    }
+
     pub fn on_finished(self) {
         // Do something when query finished.
     }
@@ -81,6 +81,7 @@ pub enum FlightAction {
     InitQueryFragmentsPlan(InitQueryFragmentsPlan),
     InitNodesChannel(InitNodesChannel),
     ExecutePartialQuery(String),
+    KillQuery(String),
 }
 
 impl TryInto<FlightAction> for Action {
@@ -98,6 +99,10 @@ impl TryInto<FlightAction> for Action {
                     buf, length, capacity,
                 )))
             },
+            "KillQuery" => match String::from_utf8(self.body) {
+                Ok(query_id) => Ok(FlightAction::KillQuery(query_id)),
+                Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+            },
             un_implemented => Err(Status::unimplemented(format!(
                 "UnImplement action {}",
                 un_implemented
@@ -123,6 +128,10 @@ impl TryInto<Action> for FlightAction {
                 r#type: String::from("ExecutePartialQuery"),
                 body: query_id.into_bytes(),
             }),
+            FlightAction::KillQuery(query_id) => Ok(Action {
+                r#type: String::from("KillQuery"),
+                body: query_id.into_bytes(),
+            }),
         }
     }
 }
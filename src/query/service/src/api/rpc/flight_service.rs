@@ -169,6 +169,11 @@ impl FlightService for DatabendQueryFlightService {
             FlightAction::ExecutePartialQuery(query_id) => {
                 DataExchangeManager::instance().execute_partial_query(&query_id)?;
 
+                FlightResult { body: vec![] }
+            }
+            FlightAction::KillQuery(query_id) => {
+                DataExchangeManager::instance().kill_query(&query_id);
+
                 FlightResult { body: vec![] }
             }
         };
@@ -50,6 +50,7 @@ pub use packets::ExecutePartialQueryPacket;
 pub use packets::FragmentData;
 pub use packets::FragmentPlanPacket;
 pub use packets::InitNodesChannelPacket;
+pub use packets::KillQueryPacket;
 pub use packets::Packet;
 pub use packets::PrecommitBlock;
 pub use packets::QueryFragmentsPlanPacket;
@@ -19,6 +19,7 @@ mod packet_data_progressinfo;
 mod packet_execute;
 mod packet_executor;
 mod packet_fragment;
+mod packet_kill;
 mod packet_publisher;
 
 pub use packet::Packet;
@@ -29,5 +30,6 @@ pub use packet_data_progressinfo::ProgressInfo;
 pub use packet_execute::ExecutePartialQueryPacket;
 pub use packet_executor::QueryFragmentsPlanPacket;
 pub use packet_fragment::FragmentPlanPacket;
+pub use packet_kill::KillQueryPacket;
 pub use packet_publisher::ConnectionInfo;
 pub use packet_publisher::InitNodesChannelPacket;
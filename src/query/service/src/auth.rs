@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::IpAddr;
 use std::sync::Arc;
 
 use common_base::base::GlobalInstance;
@@ -20,6 +21,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::UserInfo;
+use common_users::network_policy::network_policy_allows;
+use common_users::network_policy::IpNetwork;
 use common_users::JwtAuthenticator;
 use common_users::UserApiProvider;
 
@@ -27,6 +30,7 @@ use crate::sessions::Session;
 
 pub struct AuthMgr {
     jwt_auth: Option<JwtAuthenticator>,
+    trusted_proxies: Vec<IpNetwork>,
 }
 
 pub enum Credential {
@@ -42,7 +46,7 @@ pub enum Credential {
 
 impl AuthMgr {
     pub fn init(cfg: &InnerConfig) -> Result<()> {
-        GlobalInstance::set(AuthMgr::create(cfg));
+        GlobalInstance::set(AuthMgr::create(cfg)?);
         Ok(())
     }
 
@@ -50,13 +54,82 @@ impl AuthMgr {
         GlobalInstance::get()
     }
 
-    fn create(cfg: &InnerConfig) -> Arc<AuthMgr> {
-        Arc::new(AuthMgr {
+    fn create(cfg: &InnerConfig) -> Result<Arc<AuthMgr>> {
+        let trusted_proxies = cfg
+            .query
+            .trusted_proxy_ip_list
+            .iter()
+            .map(|ip| IpNetwork::parse(ip))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Arc::new(AuthMgr {
             jwt_auth: JwtAuthenticator::create(
                 cfg.query.jwt_key_file.clone(),
                 cfg.query.jwt_key_files.clone(),
             ),
-        })
+            trusted_proxies,
+        }))
+    }
+
+    pub fn is_trusted_proxy(&self, ip: IpAddr) -> bool {
+        self.trusted_proxies.iter().any(|net| net.contains(ip))
+    }
+
+    /// Resolve the real client address of a connection from `peer`.
+    ///
+    /// Addresses forwarded by a proxy (e.g. the `X-Forwarded-For` header) are only
+    /// honored when the peer is a trusted proxy, the right-most forwarded address
+    /// which is not a trusted proxy itself is taken as the client.
+    pub fn resolve_client_ip(&self, peer: IpAddr, forwarded_for: Option<&str>) -> IpAddr {
+        let forwarded_for = match forwarded_for {
+            Some(v) if self.is_trusted_proxy(peer) => v,
+            _ => return peer,
+        };
+
+        let mut client_ip = peer;
+        for addr in forwarded_for.rsplit(',') {
+            match parse_forwarded_ip(addr) {
+                Some(ip) => {
+                    client_ip = ip;
+                    if !self.is_trusted_proxy(ip) {
+                        break;
+                    }
+                }
+                None => break,
+            }
+        }
+        client_ip
+    }
+
+    /// Check the client address against the network policy of the user, if any.
+    pub async fn check_network_policy(
+        &self,
+        tenant: &str,
+        user: &UserInfo,
+        client_ip: Option<IpAddr>,
+    ) -> Result<()> {
+        let policy_name = match user.option.network_policy() {
+            Some(name) => name,
+            None => return Ok(()),
+        };
+
+        let policy = UserApiProvider::instance()
+            .get_network_policy(tenant, policy_name)
+            .await?;
+        let allowed = match client_ip {
+            Some(ip) => network_policy_allows(&policy, ip)?,
+            None => false,
+        };
+
+        match allowed {
+            true => Ok(()),
+            false => Err(ErrorCode::NetworkPolicyViolation(format!(
+                "client address {} is not allowed by network policy '{}' of user '{}'",
+                client_ip.map_or_else(|| "unknown".to_string(), |ip| ip.to_string()),
+                policy_name,
+                user.name
+            ))),
+        }
     }
 
     pub async fn auth(&self, session: Arc<Session>, credential: &Credential) -> Result<()> {
@@ -125,9 +198,21 @@ impl AuthMgr {
                     },
                     _ => return Err(ErrorCode::AuthenticateFailure("wrong auth type")),
                 };
+
+                let client_ip = h.as_ref().and_then(|h| parse_forwarded_ip(h));
+                self.check_network_policy(&tenant, &user, client_ip).await?;
                 session.set_authed_user(user, None).await?;
             }
         };
         Ok(())
     }
 }
+
+/// Parse an address like `10.0.0.1`, `10.0.0.1:3307`, `::1` or `[::1]:3307`.
+pub fn parse_forwarded_ip(addr: &str) -> Option<IpAddr> {
+    let addr = addr.trim();
+    if let Ok(ip) = addr.parse::<IpAddr>() {
+        return Some(ip);
+    }
+    addr.parse::<std::net::SocketAddr>().ok().map(|s| s.ip())
+}
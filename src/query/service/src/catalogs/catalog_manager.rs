@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "hive")]
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
+#[cfg(feature = "hive")]
+use common_ast::ast::UriLocation;
 use common_base::base::GlobalInstance;
 use common_catalog::catalog::Catalog;
 pub use common_catalog::catalog::CatalogManager;
@@ -26,6 +30,8 @@ use common_meta_app::schema::CatalogOption;
 use common_meta_app::schema::CreateCatalogReq;
 use common_meta_app::schema::DropCatalogReq;
 use common_meta_app::schema::IcebergCatalogOption;
+#[cfg(feature = "hive")]
+use common_sql::binder::parse_uri_location;
 use common_storage::DataOperator;
 #[cfg(feature = "hive")]
 use common_storages_hive::HiveCatalog;
@@ -100,8 +106,21 @@ impl CatalogManagerHelper for CatalogManager {
                     #[cfg(feature = "hive")]
                     {
                         let hms_address = ctl.address.clone();
-                        let hive_catalog = Arc::new(HiveCatalog::try_create(hms_address)?);
-                        self.catalogs.insert(name.to_string(), hive_catalog);
+                        let mut hive_catalog = HiveCatalog::try_create(hms_address)?;
+                        if !ctl.user.is_empty() {
+                            hive_catalog = hive_catalog.with_user(ctl.user.clone());
+                        }
+                        if !ctl.warehouse.is_empty() {
+                            let mut location = UriLocation::from_uri(
+                                ctl.warehouse.clone(),
+                                "".to_string(),
+                                BTreeMap::new(),
+                            )?;
+                            let (storage_params, _) = parse_uri_location(&mut location)?;
+                            hive_catalog = hive_catalog.with_warehouse(storage_params);
+                        }
+                        self.catalogs
+                            .insert(name.to_string(), Arc::new(hive_catalog));
                     }
                 }
             }
@@ -27,6 +27,7 @@ use common_storages_system::ClustersTable;
 use common_storages_system::ColumnsTable;
 use common_storages_system::ConfigsTable;
 use common_storages_system::ContributorsTable;
+use common_storages_system::CopyHistoryTable;
 use common_storages_system::CreditsTable;
 use common_storages_system::DatabasesTable;
 use common_storages_system::EnginesTable;
@@ -35,16 +36,23 @@ use common_storages_system::MallocStatsTable;
 use common_storages_system::MallocStatsTotalsTable;
 use common_storages_system::MetricsTable;
 use common_storages_system::OneTable;
+use common_storages_system::PipeRejectedRecordsTable;
+use common_storages_system::PipeStatusTable;
 use common_storages_system::ProcessesTable;
 use common_storages_system::QueryCacheTable;
 use common_storages_system::QueryLogTable;
 use common_storages_system::RolesTable;
 use common_storages_system::SettingsTable;
+use common_storages_system::SlowQueryLogTable;
 use common_storages_system::StagesTable;
+use common_storages_system::StreamsTable;
 use common_storages_system::TableFunctionsTable;
 use common_storages_system::TablesTableWithHistory;
 use common_storages_system::TablesTableWithoutHistory;
+use common_storages_system::TaskHistoryTable;
+use common_storages_system::TasksTable;
 use common_storages_system::TracingTable;
+use common_storages_system::UserVariablesTable;
 use common_storages_system::UsersTable;
 
 use crate::catalogs::InMemoryMetas;
@@ -95,10 +103,21 @@ impl SystemDatabase {
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
+            StreamsTable::create(sys_db_meta.next_table_id()),
+            TasksTable::create(sys_db_meta.next_table_id()),
+            TaskHistoryTable::create(sys_db_meta.next_table_id()),
+            PipeStatusTable::create(sys_db_meta.next_table_id()),
+            PipeRejectedRecordsTable::create(sys_db_meta.next_table_id()),
+            CopyHistoryTable::create(sys_db_meta.next_table_id()),
             BuildOptionsTable::create(sys_db_meta.next_table_id()),
             CatalogsTable::create(sys_db_meta.next_table_id()),
             QueryCacheTable::create(sys_db_meta.next_table_id()),
             TableFunctionsTable::create(sys_db_meta.next_table_id()),
+            UserVariablesTable::create(sys_db_meta.next_table_id()),
+            Arc::new(SlowQueryLogTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
         ];
 
         let disable_tables = Self::disable_system_tables();
@@ -21,6 +21,8 @@ use common_config::InnerConfig;
 use common_exception::Result;
 use common_storage::DataOperator;
 use common_storage::ShareTableConfig;
+use common_storage::SpillOperator;
+use common_storage::StorageOperatorCache;
 use common_tracing::QueryLogger;
 use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
@@ -30,15 +32,23 @@ use crate::api::DataExchangeManager;
 use crate::auth::AuthMgr;
 use crate::catalogs::CatalogManagerHelper;
 use crate::clusters::ClusterDiscovery;
+use crate::pipes::PipeScheduler;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::sessions::ResultPersistJanitor;
+use crate::sessions::SequenceGenerator;
 use crate::sessions::SessionManager;
+use crate::statistics::AnalyzeDaemon;
+use crate::tasks::TaskScheduler;
 
 pub struct GlobalServices;
 
 impl GlobalServices {
     pub async fn init(config: InnerConfig) -> Result<()> {
         GlobalInstance::init_production();
-        GlobalServices::init_with(config).await
+        GlobalServices::init_with(config).await?;
+
+        // Clean up the spill files of the last run before serving any query.
+        SpillOperator::instance().sweep()
     }
 
     pub async fn init_with(config: InnerConfig) -> Result<()> {
@@ -55,6 +65,11 @@ impl GlobalServices {
         ClusterDiscovery::init(config.clone()).await?;
 
         DataOperator::init(&config.storage).await?;
+        SpillOperator::init(
+            &config.query.spill_local_path,
+            &config.query.flight_api_address,
+        )?;
+        StorageOperatorCache::init()?;
 
         ShareTableConfig::init(
             &config.query.share_endpoint_address,
@@ -76,6 +91,11 @@ impl GlobalServices {
         )
         .await?;
         RoleCacheManager::init()?;
+        SequenceGenerator::init()?;
+        AnalyzeDaemon::init(&config)?;
+        ResultPersistJanitor::init(&config)?;
+        TaskScheduler::init(&config)?;
+        PipeScheduler::init(&config)?;
 
         Ok(())
     }
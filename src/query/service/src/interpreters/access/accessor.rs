@@ -18,6 +18,7 @@ use std::sync::Arc;
 use common_exception::Result;
 
 use crate::interpreters::access::PrivilegeAccess;
+use crate::interpreters::access::TransactionAccess;
 use crate::interpreters::ManagementModeAccess;
 use crate::sessions::QueryContext;
 use crate::sql::plans::Plan;
@@ -36,7 +37,11 @@ impl Accessor {
     pub fn create(ctx: Arc<QueryContext>) -> Self {
         let mut accessors: HashMap<String, Box<dyn AccessChecker>> = Default::default();
         accessors.insert("management".to_string(), ManagementModeAccess::create());
-        accessors.insert("privilege".to_string(), PrivilegeAccess::create(ctx));
+        accessors.insert(
+            "privilege".to_string(),
+            PrivilegeAccess::create(ctx.clone()),
+        );
+        accessors.insert("transaction".to_string(), TransactionAccess::create(ctx));
         Accessor { accessors }
     }
 
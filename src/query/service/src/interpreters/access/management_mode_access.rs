@@ -40,9 +40,9 @@ impl AccessChecker for ManagementModeAccess {
                         Some(ref v) => matches!(v,
                             RewriteKind::ShowDatabases
                             | RewriteKind::ShowTables
-                            | RewriteKind::ShowColumns
                             | RewriteKind::ShowEngines
                             | RewriteKind::ShowSettings
+                            | RewriteKind::ShowVariables
                             | RewriteKind::ShowFunctions
                             | RewriteKind::ShowTableFunctions
                             | RewriteKind::ShowUsers
@@ -67,7 +67,10 @@ impl AccessChecker for ManagementModeAccess {
 
                 // Table.
                 | Plan::DescribeTable(_)
+                | Plan::ShowColumns(_)
                 | Plan::CreateTable(_)
+                | Plan::AttachTable(_)
+                | Plan::ImportTableSnapshot(_)
                 | Plan::DropTable(_)
 
                 // User.
@@ -79,14 +82,43 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::RevokePriv(_)
                 | Plan::GrantRole(_)
                 | Plan::RevokeRole(_)
+                | Plan::ShowRoleHierarchy(_)
                 // Stage.
                 | Plan::CreateStage(_)
+                | Plan::AlterStage(_)
+                | Plan::RenameStage(_)
+                | Plan::ShowCreateStage(_)
                 | Plan::DropStage(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
                 | Plan::AlterUDF(_)
                 | Plan::DropUDF(_)
+                | Plan::DescribeFunction(_)
+
+                // Masking policy
+                | Plan::CreateMaskingPolicy(_)
+                | Plan::DropMaskingPolicy(_)
+
+                // Row access policy
+                | Plan::CreateRowAccessPolicy(_)
+                | Plan::DropRowAccessPolicy(_)
+                | Plan::ShowRowAccessPolicies(_)
+
+                // Sequence
+                | Plan::CreateSequence(_)
+                | Plan::DropSequence(_)
+                | Plan::ShowSequences(_)
+
+                // External function
+                | Plan::CreateExternalFunction(_)
+                | Plan::DropExternalFunction(_)
+
+                // Network policy
+                | Plan::CreateNetworkPolicy(_)
+                | Plan::DropNetworkPolicy(_)
+                | Plan::DescNetworkPolicy(_)
+                | Plan::ShowNetworkPolicies(_)
                 | Plan::UseDatabase(_)
                 | Plan::Call(_) => true,
                 _ => false
@@ -17,8 +17,11 @@ use std::sync::Arc;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::StageType;
 use common_meta_app::principal::UserPrivilegeType;
 use common_sql::plans::CopyPlan;
+use common_sql::plans::PresignAction;
 
 use crate::interpreters::access::AccessChecker;
 use crate::sessions::QueryContext;
@@ -32,6 +35,69 @@ impl PrivilegeAccess {
     pub fn create(ctx: Arc<QueryContext>) -> Box<dyn AccessChecker> {
         Box::new(PrivilegeAccess { ctx })
     }
+
+    /// SELECT privilege on some columns of the table is enough to access the table, references to
+    /// the other columns are rejected in binding.
+    async fn validate_table_select_privilege(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<()> {
+        let session = self.ctx.get_current_session();
+        let result = session
+            .validate_privilege(
+                &GrantObject::Table(catalog.to_string(), database.to_string(), table.to_string()),
+                vec![UserPrivilegeType::Select],
+            )
+            .await;
+        if result.is_err()
+            && session
+                .get_granted_columns(catalog, database, table, UserPrivilegeType::Select)
+                .await?
+                .is_some()
+        {
+            return Ok(());
+        }
+        result
+    }
+
+    /// Only the user who created the stage, or the account admin, may alter the stage or see
+    /// how it is created.
+    async fn validate_stage_owner(&self, stage: &StageInfo) -> Result<()> {
+        let session = self.ctx.get_current_session();
+        if stage.creator.as_ref() == Some(&session.get_current_user()?.identity()) {
+            return Ok(());
+        }
+        session.validate_account_admin().await
+    }
+
+    /// Everyone can access the files of their own user stage. Named stages need the privilege
+    /// granted on the stage, SUPER keeps working as it did before stage privileges existed.
+    async fn validate_stage_access(
+        &self,
+        stage: &StageInfo,
+        privilege: UserPrivilegeType,
+    ) -> Result<()> {
+        if stage.stage_type == StageType::User {
+            return Ok(());
+        }
+        let session = self.ctx.get_current_session();
+        let result = session
+            .validate_privilege(&GrantObject::Stage(stage.stage_name.clone()), vec![
+                privilege,
+            ])
+            .await;
+        if result.is_err()
+            && session
+                .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
+                .await
+                .is_ok()
+        {
+            return Ok(());
+        }
+        result
+    }
 }
 
 #[async_trait::async_trait]
@@ -46,16 +112,12 @@ impl AccessChecker for PrivilegeAccess {
                     if table.is_source_of_view() {
                         continue;
                     }
-                    session
-                        .validate_privilege(
-                            &GrantObject::Table(
-                                table.catalog().to_string(),
-                                table.database().to_string(),
-                                table.name().to_string(),
-                            ),
-                            vec![UserPrivilegeType::Select],
-                        )
-                        .await?
+                    self.validate_table_select_privilege(
+                        table.catalog(),
+                        table.database(),
+                        table.name(),
+                    )
+                    .await?;
                 }
             }
             Plan::ExplainAnalyze { plan } | Plan::Explain { plan, .. } => self.check(plan).await?,
@@ -69,12 +131,21 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?
             }
-            Plan::CreateUDF(_) | Plan::CreateDatabase(_) => {
+            Plan::CreateUDF(_)
+            | Plan::CreateMaskingPolicy(_)
+            | Plan::CreateRowAccessPolicy(_)
+            | Plan::CreateSequence(_)
+            | Plan::CreateDatabase(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Create])
                     .await?;
             }
-            Plan::DropDatabase(_) | Plan::UndropDatabase(_) | Plan::DropUDF(_) => {
+            Plan::DropDatabase(_)
+            | Plan::UndropDatabase(_)
+            | Plan::DropUDF(_)
+            | Plan::DropMaskingPolicy(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::DropSequence(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Drop])
                     .await?;
@@ -103,18 +174,34 @@ impl AccessChecker for PrivilegeAccess {
                     .await?
             }
             Plan::DescribeTable(plan) => {
+                self.validate_table_select_privilege(&plan.catalog, &plan.database, &plan.table)
+                    .await?
+            }
+            Plan::ShowColumns(plan) => {
+                self.validate_table_select_privilege(&plan.catalog, &plan.database, &plan.table)
+                    .await?
+            }
+            Plan::CreateTable(plan) => {
                 session
                     .validate_privilege(
-                        &GrantObject::Table(
-                            plan.catalog.clone(),
-                            plan.database.clone(),
-                            plan.table.clone(),
-                        ),
-                        vec![UserPrivilegeType::Select],
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+            }
+            Plan::AttachTable(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
                     )
+                    .await?;
+            }
+            Plan::ExportTableSnapshot(plan) => {
+                self.validate_table_select_privilege(&plan.catalog, &plan.database, &plan.table)
                     .await?
             }
-            Plan::CreateTable(plan) => {
+            Plan::ImportTableSnapshot(plan) => {
                 session
                     .validate_privilege(
                         &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
@@ -186,6 +273,42 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::RenameTableColumn(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::ModifyTableColumn(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::BuildTableIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::AlterTableClusterKey(plan) => {
                 session
                     .validate_privilege(
@@ -210,6 +333,54 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::SetTableAnalyzeInterval(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::SetTableConnection(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::AddTableRowAccessPolicy(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::DropTableRowAccessPolicy(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::ReclusterTable(plan) => {
                 session
                     .validate_privilege(
@@ -271,6 +442,20 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::InsertMultiTable(plan) => {
+                for target in plan.targets() {
+                    session
+                        .validate_privilege(
+                            &GrantObject::Table(
+                                target.catalog.clone(),
+                                target.database.clone(),
+                                target.table.clone(),
+                            ),
+                            vec![UserPrivilegeType::Insert],
+                        )
+                        .await?;
+                }
+            }
             Plan::Replace(plan) => {
                 session
                     .validate_privilege(
@@ -331,6 +516,32 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.table_database.clone(),
+                            plan.table_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Select],
+                    )
+                    .await?;
+            }
+            Plan::DropStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
             Plan::CreateUser(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::CreateUser])
@@ -354,11 +565,13 @@ impl AccessChecker for PrivilegeAccess {
             Plan::GrantShareObject(_)
             | Plan::RevokeShareObject(_)
             | Plan::AlterShareTenants(_)
+            | Plan::AlterShareComment(_)
             | Plan::ShowObjectGrantPrivileges(_)
             | Plan::ShowGrantTenantsOfShare(_)
             | Plan::SetRole(_)
             | Plan::ShowGrants(_)
             | Plan::ShowRoles(_)
+            | Plan::ShowRoleHierarchy(_)
             | Plan::GrantRole(_)
             | Plan::GrantPriv(_)
             | Plan::RevokePriv(_)
@@ -375,6 +588,7 @@ impl AccessChecker for PrivilegeAccess {
             Plan::AlterUser(_)
             | Plan::AlterUDF(_)
             | Plan::RenameDatabase(_)
+            | Plan::SetDatabaseQuota(_)
             | Plan::RevertTable(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Alter])
@@ -427,23 +641,66 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::CreateShare(_)
             | Plan::DropShare(_)
             | Plan::DescShare(_)
-            | Plan::ShowShares(_)
             | Plan::Call(_)
             | Plan::ShowCreateCatalog(_)
             | Plan::CreateCatalog(_)
             | Plan::DropCatalog(_)
             | Plan::CreateStage(_)
             | Plan::DropStage(_)
-            | Plan::RemoveStage(_)
             | Plan::CreateFileFormat(_)
             | Plan::DropFileFormat(_)
-            | Plan::ShowFileFormats(_) => {
+            | Plan::ShowFileFormats(_)
+            | Plan::CreateNetworkPolicy(_)
+            | Plan::DropNetworkPolicy(_)
+            | Plan::DescNetworkPolicy(_)
+            | Plan::ShowNetworkPolicies(_)
+            | Plan::ShowRowAccessPolicies(_)
+            | Plan::ShowSequences(_)
+            | Plan::ShowIndexBuildStatus(_)
+            | Plan::CreateTask(_)
+            | Plan::AlterTask(_)
+            | Plan::DropTask(_)
+            | Plan::ExecuteTask(_)
+            | Plan::CreatePipe(_)
+            | Plan::DropPipe(_)
+            | Plan::DropResultCache(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
+                    .await?;
+            }
+            Plan::CreateExternalFunction(_) | Plan::DropExternalFunction(_) => {
+                session.validate_account_admin().await?;
+            }
+            Plan::ShowShares(plan) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
+                // Only the account admin can list the shares of another tenant.
+                if matches!(&plan.tenant, Some(tenant) if tenant != &self.ctx.get_tenant()) {
+                    session.validate_account_admin().await?;
+                }
+            }
+            Plan::AlterStage(plan) => self.validate_stage_owner(&plan.stage_info).await?,
+            Plan::RenameStage(plan) => self.validate_stage_owner(&plan.stage_info).await?,
+            Plan::ShowCreateStage(plan) => self.validate_stage_owner(&plan.stage_info).await?,
+            Plan::ShowStageFiles(plan) => {
+                self.validate_stage_access(&plan.stage, UserPrivilegeType::Write)
+                    .await?
+            }
+            Plan::RemoveStage(plan) => {
+                self.validate_stage_access(&plan.stage, UserPrivilegeType::Write)
+                    .await?
+            }
+            Plan::Presign(plan) => {
+                let privilege = match plan.action {
+                    PresignAction::Download => UserPrivilegeType::Read,
+                    PresignAction::Upload => UserPrivilegeType::Write,
+                };
+                self.validate_stage_access(&plan.stage, privilege).await?
             }
             // Note: No need to check privileges
-            Plan::Presign(_) => {}
+            Plan::Begin | Plan::Commit | Plan::Abort => {}
+            Plan::DescribeFunction(_) => {}
             Plan::ExplainAst { .. } => {}
             Plan::ExplainSyntax { .. } => {}
             // just used in clickhouse-sqlalchemy, no need to check
@@ -0,0 +1,130 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storages_fuse::FuseTable;
+use tracing::info;
+
+use crate::interpreters::access::AccessChecker;
+use crate::sessions::QueryContext;
+use crate::sql::plans::Plan;
+
+pub struct TransactionAccess {
+    ctx: Arc<QueryContext>,
+}
+
+impl TransactionAccess {
+    pub fn create(ctx: Arc<QueryContext>) -> Box<dyn AccessChecker> {
+        Box::new(TransactionAccess { ctx })
+    }
+
+    /// Only the mutations of fuse tables can be buffered in a transaction.
+    async fn check_table(&self, catalog: &str, database: &str, table: &str) -> Result<()> {
+        let table = self.ctx.get_table(catalog, database, table).await?;
+        if FuseTable::try_from_table(table.as_ref()).is_err() {
+            return Err(ErrorCode::TransactionNotSupported(format!(
+                "table {}.{} of engine {} can't be mutated in a transaction",
+                database,
+                table.name(),
+                table.engine()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AccessChecker for TransactionAccess {
+    // Check what we can do in an explicit transaction.
+    async fn check(&self, plan: &Plan) -> Result<()> {
+        let txn_mgr = self.ctx.get_txn_manager();
+        if !txn_mgr.lock().is_active() {
+            return Ok(());
+        }
+
+        let timeout_secs = self.ctx.get_settings().get_transaction_timeout_secs()?;
+        if !matches!(plan, Plan::Abort)
+            && txn_mgr.lock().is_timeout(Duration::from_secs(timeout_secs))
+        {
+            txn_mgr.lock().end();
+            info!(
+                "transaction timed out after {} seconds, rolled back",
+                timeout_secs
+            );
+            return Err(ErrorCode::TransactionTimeout(format!(
+                "transaction exceeded the timeout of {} seconds and has been rolled back",
+                timeout_secs
+            )));
+        }
+
+        match plan {
+            Plan::Insert(plan) => {
+                self.check_table(&plan.catalog, &plan.database, &plan.table)
+                    .await
+            }
+            Plan::InsertMultiTable(plan) => {
+                for target in plan
+                    .branches
+                    .iter()
+                    .flat_map(|branch| branch.targets.iter())
+                {
+                    self.check_table(&target.catalog, &target.database, &target.table)
+                        .await?;
+                }
+                Ok(())
+            }
+            Plan::Replace(plan) => {
+                self.check_table(&plan.catalog, &plan.database, &plan.table)
+                    .await
+            }
+            Plan::Delete(plan) => {
+                self.check_table(&plan.catalog_name, &plan.database_name, &plan.table_name)
+                    .await
+            }
+            Plan::Update(plan) => {
+                self.check_table(&plan.catalog, &plan.database, &plan.table)
+                    .await
+            }
+
+            Plan::Query { .. }
+            | Plan::Explain { .. }
+            | Plan::ExplainAst { .. }
+            | Plan::ExplainSyntax { .. }
+            | Plan::ExplainAnalyze { .. }
+            | Plan::ShowCreateDatabase(_)
+            | Plan::ShowCreateTable(_)
+            | Plan::DescribeTable(_)
+            | Plan::ShowColumns(_)
+            | Plan::ExistsTable(_)
+            | Plan::DescribeFunction(_)
+            | Plan::SetVariable(_)
+            | Plan::UnSetVariable(_)
+            | Plan::UseDatabase(_)
+            | Plan::Begin
+            | Plan::Commit
+            | Plan::Abort => Ok(()),
+
+            // DDL and the other statements take effect immediately, they can't be rolled back.
+            _ => Err(ErrorCode::TransactionNotSupported(format!(
+                "{} is not supported in a transaction, COMMIT or ABORT the transaction first",
+                plan
+            ))),
+        }
+    }
+}
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_meta_app::principal::GrantObject;
+use common_users::UserApiProvider;
 
 use crate::procedures::ProcedureFactory;
 use crate::sessions::QueryContext;
@@ -47,6 +48,18 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
+        GrantObject::Column(catalog_name, database_name, table_name, column_name) => {
+            let catalog = ctx.get_catalog(catalog_name)?;
+            let table = catalog
+                .get_table(tenant.as_str(), database_name, table_name)
+                .await?;
+            if table.schema().field_with_name(column_name).is_err() {
+                return Err(common_exception::ErrorCode::UnknownColumn(format!(
+                    "column {} not exists in table {}.{}",
+                    column_name, database_name, table_name,
+                )));
+            }
+        }
         GrantObject::Database(catalog_name, database_name) => {
             let catalog = ctx.get_catalog(catalog_name)?;
             if !catalog
@@ -59,6 +72,11 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
+        GrantObject::Stage(stage_name) => {
+            UserApiProvider::instance()
+                .get_stage(tenant.as_str(), stage_name)
+                .await?;
+        }
         GrantObject::Global => (),
     }
 
@@ -24,6 +24,7 @@ use common_expression::SendableDataBlockStream;
 
 use crate::interpreters::InterpreterMetrics;
 use crate::interpreters::InterpreterQueryLog;
+use crate::interpreters::InterpreterSlowQueryLog;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::executor::PipelinePullingExecutor;
@@ -139,6 +140,10 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) {
         SessionManager::instance().status.write().query_finish(now)
     }
 
+    if let Err(error) = InterpreterSlowQueryLog::log_finish(ctx, now, error.clone()) {
+        tracing::error!("interpreter.slow_query_log.error: {:?}", error)
+    }
+
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error) {
         tracing::error!("interpreter.finish.error: {:?}", error)
     }
@@ -29,15 +29,23 @@ use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::StageType;
 use common_meta_app::schema::TableCopiedFileInfo;
+use common_meta_app::schema::TableCopyHistoryInfo;
+use common_meta_app::schema::TableCopyStatus;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
+use common_meta_app::schema::UpsertTableCopyHistoryReq;
 use common_meta_types::MetaId;
 use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sinks::EmptySink;
 use common_sql::executor::table_read_plan::ToReadDataSourcePlan;
 use common_storage::StageFileInfo;
 use common_storage::StageFileStatus;
 use common_storage::StageFilesInfo;
 use common_storages_fuse::io::Files;
+use common_storages_fuse::metrics_inc_table_copy_files_failed;
+use common_storages_fuse::metrics_inc_table_copy_files_loaded;
+use common_storages_fuse::TableMetricLabels;
 use common_storages_stage::StageTable;
 use tracing::error;
 use tracing::info;
@@ -48,11 +56,13 @@ use crate::interpreters::SelectInterpreter;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
 use crate::pipelines::processors::TransformCastSchema;
 use crate::pipelines::processors::TransformLimit;
+use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 use crate::sql::plans::CopyPlan;
 use crate::sql::plans::Plan;
+use crate::sql::plans::ValidationMode;
 
 pub struct CopyInterpreter {
     ctx: Arc<QueryContext>,
@@ -170,15 +180,46 @@ impl CopyInterpreter {
         all_source_file_infos: Vec<StageFileInfo>,
         need_copy_file_infos: Vec<StageFileInfo>,
         force: bool,
+        validation_mode: &ValidationMode,
     ) -> Result<PipelineBuildResult> {
         let start = Instant::now();
         let ctx = self.ctx.clone();
-        let (mut build_res, source_schema) = self.build_query(query).await?;
+        let (mut build_res, mut source_schema) = self.build_query(query).await?;
         let to_table = ctx
             .get_table(catalog_name, database_name, table_name)
             .await?;
 
-        let dst_schema = Arc::new(to_table.schema().into());
+        let dst_schema: DataSchemaRef = Arc::new(to_table.schema().into());
+        // Columns not produced by the transform query are filled with their default values,
+        // the produced ones are matched by name.
+        if source_schema.num_fields() < dst_schema.num_fields()
+            && source_schema
+                .fields()
+                .iter()
+                .all(|f| dst_schema.has_field(f.name()))
+        {
+            build_res.main_pipeline.add_transform(
+                |transform_input_port, transform_output_port| {
+                    TransformResortAddOn::try_create(
+                        ctx.clone(),
+                        transform_input_port,
+                        transform_output_port,
+                        source_schema.clone(),
+                        to_table.clone(),
+                    )
+                },
+            )?;
+            let fields = dst_schema
+                .fields()
+                .iter()
+                .map(|f| match source_schema.field_with_name(f.name()) {
+                    Ok(source_field) => source_field.clone(),
+                    Err(_) => f.clone(),
+                })
+                .collect();
+            source_schema = DataSchemaRefExt::create(fields);
+        }
+
         if source_schema != dst_schema {
             let func_ctx = ctx.get_function_context()?;
             build_res.main_pipeline.add_transform(
@@ -194,6 +235,11 @@ impl CopyInterpreter {
             )?;
         }
 
+        if *validation_mode != ValidationMode::None {
+            Self::build_validation_sink(&mut build_res)?;
+            return Ok(build_res);
+        }
+
         // Build append data pipeline.
         to_table.append_data(
             ctx.clone(),
@@ -202,11 +248,13 @@ impl CopyInterpreter {
             false,
         )?;
 
+        let database_name = database_name.to_string();
         build_res.main_pipeline.set_on_finished(move |may_error| {
             if may_error.is_none() {
                 CopyInterpreter::commit_copy_into_table(
                     ctx.clone(),
                     to_table,
+                    database_name,
                     stage_info,
                     all_source_file_infos,
                     need_copy_file_infos,
@@ -232,6 +280,7 @@ impl CopyInterpreter {
         database_name: &str,
         table_name: &str,
         force: bool,
+        validation_mode: &ValidationMode,
         stage_table_info: &StageTableInfo,
     ) -> Result<PipelineBuildResult> {
         let start = Instant::now();
@@ -356,6 +405,11 @@ impl CopyInterpreter {
             )?;
         }
 
+        if *validation_mode != ValidationMode::None {
+            Self::build_validation_sink(&mut build_res)?;
+            return Ok(build_res);
+        }
+
         // Build append data pipeline.
         to_table.append_data(
             ctx.clone(),
@@ -365,11 +419,13 @@ impl CopyInterpreter {
         )?;
 
         let stage_table_info_clone = stage_table_info.clone();
+        let database_name = database_name.to_string();
         build_res.main_pipeline.set_on_finished(move |may_error| {
             if may_error.is_none() {
                 CopyInterpreter::commit_copy_into_table(
                     ctx.clone(),
                     to_table,
+                    database_name,
                     stage_table_info_clone.stage_info,
                     all_source_file_infos,
                     need_copy_file_infos,
@@ -388,6 +444,14 @@ impl CopyInterpreter {
         Ok(build_res)
     }
 
+    /// Validation mode only checks the files could be parsed, the data is discarded
+    /// and nothing is committed, neither the copied files are recorded.
+    fn build_validation_sink(build_res: &mut PipelineBuildResult) -> Result<()> {
+        build_res
+            .main_pipeline
+            .add_sink(|input| Ok(ProcessorPtr::create(EmptySink::create(input))))
+    }
+
     /// Pipeline finish.
     /// 1. commit the data.
     /// 2. update the NeedCopy file into to meta.
@@ -397,11 +461,25 @@ impl CopyInterpreter {
     fn commit_copy_into_table(
         ctx: Arc<QueryContext>,
         to_table: Arc<dyn Table>,
+        database_name: String,
         stage_info: StageInfo,
         all_source_files: Vec<StageFileInfo>,
         need_copy_files: Vec<StageFileInfo>,
         force: bool,
     ) -> Result<()> {
+        let copy_history = Self::upsert_copy_history_request(
+            &ctx,
+            &database_name,
+            to_table.name(),
+            &stage_info,
+            &need_copy_files,
+        )?;
+        let files_failed = copy_history
+            .history
+            .iter()
+            .filter(|h| matches!(h.status, TableCopyStatus::LoadFailed))
+            .count() as u64;
+        let files_loaded = copy_history.history.len() as u64 - files_failed;
         let mut copied_files = BTreeMap::new();
         for file in need_copy_files {
             // Short the etag to 7 bytes for less space in metasrv.
@@ -430,6 +508,7 @@ impl CopyInterpreter {
                 expire_hours,
                 copied_files,
                 fail_if_duplicated,
+                copy_history,
             );
 
             {
@@ -450,6 +529,12 @@ impl CopyInterpreter {
 
             info!("end of commit");
 
+            {
+                let labels = TableMetricLabels::create(&ctx.get_tenant(), table_id);
+                metrics_inc_table_copy_files_loaded(&labels, files_loaded);
+                metrics_inc_table_copy_files_failed(&labels, files_failed);
+            }
+
             // 3. log on_error mode errors.
             // todo(ariesdevil): persist errors with query_id
             if let Some(error_map) = ctx.get_on_error_map() {
@@ -494,6 +579,7 @@ impl CopyInterpreter {
         expire_hours: u64,
         copy_stage_files: BTreeMap<String, TableCopiedFileInfo>,
         fail_if_duplicated: bool,
+        copy_history: UpsertTableCopyHistoryReq,
     ) -> Option<UpsertTableCopiedFileReq> {
         if copy_stage_files.is_empty() {
             return None;
@@ -505,9 +591,58 @@ impl CopyInterpreter {
             file_info: copy_stage_files,
             expire_at: Some(expire_at),
             fail_if_duplicated,
+            copy_history: Some(copy_history),
         };
         Some(req)
     }
+
+    /// Build the load history of the copied files from the status collected while reading them,
+    /// which is kept for `copy_history_retention_days`.
+    fn upsert_copy_history_request(
+        ctx: &QueryContext,
+        database_name: &str,
+        table_name: &str,
+        stage_info: &StageInfo,
+        copied_files: &[StageFileInfo],
+    ) -> Result<UpsertTableCopyHistoryReq> {
+        let copy_status = ctx.get_copy_status();
+        let stage_location = match stage_info.stage_type {
+            StageType::User => "@~".to_string(),
+            _ => format!("@{}", stage_info.stage_name),
+        };
+        let now = Utc::now();
+
+        let history = copied_files
+            .iter()
+            .map(|file| {
+                let status = copy_status.get(&file.path).unwrap_or_default();
+                let first_error = status.first_error.as_ref();
+                TableCopyHistoryInfo {
+                    file_name: file.path.clone(),
+                    database_name: database_name.to_string(),
+                    table_name: table_name.to_string(),
+                    stage_location: stage_location.clone(),
+                    file_size: file.size,
+                    rows_parsed: status.rows_parsed(),
+                    rows_loaded: status.rows_loaded,
+                    error_count: status.error_count,
+                    status: TableCopyStatus::create(status.rows_loaded, status.error_count),
+                    first_error_message: first_error.map(|e| e.message.clone()),
+                    first_error_line: first_error.and_then(|e| e.line),
+                    first_error_column: first_error.and_then(|e| e.column),
+                    last_load_time: now,
+                }
+            })
+            .collect();
+
+        let retention_days = ctx.get_settings().get_copy_history_retention_days()?;
+        Ok(UpsertTableCopyHistoryReq {
+            tenant: ctx.get_tenant(),
+            query_id: ctx.get_id(),
+            history,
+            expire_at: Some(now.timestamp() as u64 + retention_days * 24 * 60 * 60),
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -525,6 +660,7 @@ impl Interpreter for CopyInterpreter {
                 table_name,
                 from,
                 force,
+                validation_mode,
                 ..
             } => match &from.source_info {
                 DataSourceInfo::StageSource(table_info) => {
@@ -533,6 +669,7 @@ impl Interpreter for CopyInterpreter {
                         database_name,
                         table_name,
                         *force,
+                        validation_mode,
                         table_info,
                     )
                     .await
@@ -551,6 +688,7 @@ impl Interpreter for CopyInterpreter {
                 all_source_file_infos,
                 need_copy_file_infos,
                 force,
+                validation_mode,
                 ..
             } => {
                 self.build_copy_into_table_with_transform_pipeline(
@@ -562,6 +700,7 @@ impl Interpreter for CopyInterpreter {
                     all_source_file_infos.clone(),
                     need_copy_file_infos.clone(),
                     *force,
+                    validation_mode,
                 )
                 .await
             }
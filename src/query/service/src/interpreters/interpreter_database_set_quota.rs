@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::MatchSeq;
+use common_sql::plans::SetDatabaseQuotaPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct SetDatabaseQuotaInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetDatabaseQuotaPlan,
+}
+
+impl SetDatabaseQuotaInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetDatabaseQuotaPlan) -> Result<Self> {
+        Ok(SetDatabaseQuotaInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetDatabaseQuotaInterpreter {
+    fn name(&self) -> &str {
+        "SetDatabaseQuotaInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let db = match catalog.get_database(&plan.tenant, &plan.database).await {
+            Ok(db) => db,
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_DATABASE => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        };
+        let db_id = db.get_db_info().ident.db_id;
+
+        let quota_api = UserApiProvider::instance().get_tenant_quota_api_client(&plan.tenant)?;
+        let current = quota_api.get_database_quota(db_id).await?;
+
+        let mut quota = current.data;
+        if let Some(v) = plan.max_tables {
+            quota.max_tables = v;
+        }
+        if let Some(v) = plan.max_bytes {
+            quota.max_bytes = v;
+        }
+        if let Some(v) = plan.max_rows {
+            quota.max_rows = v;
+        }
+
+        quota_api
+            .set_database_quota(db_id, &quota, MatchSeq::Exact(current.seq))
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 use std::sync::Arc;
 
+use common_ast::ast::ExplainFormat;
 use common_ast::ast::ExplainKind;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
@@ -24,6 +25,7 @@ use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_expression::FromData;
+use common_pipeline_core::Pipeline;
 use common_profile::ProfSpanSetRef;
 use common_sql::MetadataRef;
 
@@ -62,7 +64,7 @@ impl Interpreter for ExplainInterpreter {
         let blocks = match &self.kind {
             ExplainKind::Raw => self.explain_plan(&self.plan)?,
 
-            ExplainKind::Plan => match &self.plan {
+            ExplainKind::Plan(format) => match &self.plan {
                 Plan::Query {
                     s_expr, metadata, ..
                 } => {
@@ -77,9 +79,14 @@ impl Interpreter for ExplainInterpreter {
                     }
                     let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx);
                     let plan = builder.build(s_expr).await?;
-                    self.explain_physical_plan(&plan, metadata)?
+                    self.explain_physical_plan(&plan, metadata, *format)?
+                }
+                _ if *format == ExplainFormat::Text => self.explain_plan(&self.plan)?,
+                _ => {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "Unsupported EXPLAIN (FORMAT {format}) statement"
+                    )));
                 }
-                _ => self.explain_plan(&self.plan)?,
             },
 
             ExplainKind::JOIN => match &self.plan {
@@ -129,15 +136,20 @@ impl Interpreter for ExplainInterpreter {
                 ))?,
             },
 
-            ExplainKind::Pipeline => match &self.plan {
+            ExplainKind::Pipeline(format) => match &self.plan {
                 Plan::Query {
                     s_expr,
                     metadata,
                     ignore_result,
                     ..
                 } => {
-                    self.explain_pipeline(*s_expr.clone(), metadata.clone(), *ignore_result)
-                        .await?
+                    self.explain_pipeline(
+                        *s_expr.clone(),
+                        metadata.clone(),
+                        *ignore_result,
+                        *format,
+                    )
+                    .await?
                 }
                 _ => {
                     return Err(ErrorCode::Unimplemented("Unsupported EXPLAIN statement"));
@@ -171,6 +183,17 @@ impl Interpreter for ExplainInterpreter {
             }
         };
 
+        // Keep the JSON, Mermaid and DOT documents parsable.
+        let is_document = matches!(
+            self.kind,
+            ExplainKind::Plan(format) | ExplainKind::Pipeline(format) if format != ExplainFormat::Text
+        );
+        let blocks = match self.explain_hinted_settings() {
+            Some(block) if !is_document => {
+                blocks.into_iter().chain(std::iter::once(block)).collect()
+            }
+            _ => blocks,
+        };
         PipelineBuildResult::from_blocks(blocks)
     }
 }
@@ -187,6 +210,22 @@ impl ExplainInterpreter {
         })
     }
 
+    /// The settings that the settings hint of the SQL overrides, e.g. `hinted settings: max_threads = 4`.
+    fn explain_hinted_settings(&self) -> Option<DataBlock> {
+        let hinted_settings = self.ctx.get_hinted_settings();
+        if hinted_settings.is_empty() {
+            return None;
+        }
+        let settings = hinted_settings
+            .iter()
+            .map(|(name, value)| format!("{} = {}", name, value))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let line = format!("hinted settings: {}", settings);
+        let column = StringType::from_data(vec![line.as_str()]);
+        Some(DataBlock::new_from_columns(vec![column]))
+    }
+
     pub fn explain_plan(&self, plan: &Plan) -> Result<Vec<DataBlock>> {
         let result = plan.format_indent()?;
         let line_split_result: Vec<&str> = result.lines().collect();
@@ -198,10 +237,22 @@ impl ExplainInterpreter {
         &self,
         plan: &PhysicalPlan,
         metadata: &MetadataRef,
+        format: ExplainFormat,
     ) -> Result<Vec<DataBlock>> {
-        let result = plan
-            .format(metadata.clone(), ProfSpanSetRef::default())?
-            .format_pretty()?;
+        let result = match format {
+            ExplainFormat::Text => plan
+                .format(metadata.clone(), ProfSpanSetRef::default())?
+                .format_pretty()?,
+            ExplainFormat::Json => {
+                let document = plan.format_json(metadata.clone(), ProfSpanSetRef::default())?;
+                serde_json::to_string_pretty(&document)?
+            }
+            ExplainFormat::Mermaid | ExplainFormat::Dot => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Unsupported EXPLAIN (FORMAT {format}) statement, only TEXT and JSON are supported"
+                )));
+            }
+        };
         let line_split_result: Vec<&str> = result.lines().collect();
         let formatted_plan = StringType::from_data(line_split_result);
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
@@ -223,11 +274,26 @@ impl ExplainInterpreter {
         s_expr: SExpr,
         metadata: MetadataRef,
         ignore_result: bool,
+        format: ExplainFormat,
     ) -> Result<Vec<DataBlock>> {
         let mut builder = PhysicalPlanBuilder::new(metadata, self.ctx.clone());
         let plan = builder.build(&s_expr).await?;
         let build_res = build_query_pipeline(&self.ctx, &[], &plan, ignore_result, false).await?;
 
+        let mut pipelines = vec![&build_res.main_pipeline];
+        pipelines.extend(build_res.sources_pipelines.iter());
+        let graph = match format {
+            ExplainFormat::Text => None,
+            ExplainFormat::Mermaid => Some(Pipeline::display_mermaid(&pipelines).to_string()),
+            ExplainFormat::Dot => Some(Pipeline::display_dot(&pipelines).to_string()),
+            ExplainFormat::Json => Some(Pipeline::display_json(&pipelines).to_string()),
+        };
+        if let Some(graph) = graph {
+            let line_split_result: Vec<&str> = graph.lines().collect();
+            let column = StringType::from_data(line_split_result);
+            return Ok(vec![DataBlock::new_from_columns(vec![column])]);
+        }
+
         let mut blocks = Vec::with_capacity(1 + build_res.sources_pipelines.len());
         // Format root pipeline
         let line_split_result = format!("{}", build_res.main_pipeline.display_indent())
@@ -31,6 +31,7 @@ use crate::interpreters::interpreter_file_format_drop::DropFileFormatInterpreter
 use crate::interpreters::interpreter_file_format_show::ShowFileFormatsInterpreter;
 use crate::interpreters::interpreter_presign::PresignInterpreter;
 use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
+use crate::interpreters::interpreter_role_show_hierarchy::ShowRoleHierarchyInterpreter;
 use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
 use crate::interpreters::interpreter_table_revert::RevertTableInterpreter;
 use crate::interpreters::AlterUserInterpreter;
@@ -146,6 +147,10 @@ impl InterpreterFactory {
                 RenameDatabaseInterpreter::try_create(ctx, *rename_database.clone())?,
             )),
 
+            Plan::SetDatabaseQuota(set_quota) => Ok(Arc::new(
+                SetDatabaseQuotaInterpreter::try_create(ctx, *set_quota.clone())?,
+            )),
+
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(Arc::new(
                 ShowCreateTableInterpreter::try_create(ctx, *show_create_table.clone())?,
@@ -153,6 +158,20 @@ impl InterpreterFactory {
             Plan::DescribeTable(describe_table) => Ok(Arc::new(
                 DescribeTableInterpreter::try_create(ctx, *describe_table.clone())?,
             )),
+            Plan::ShowColumns(show_columns) => Ok(Arc::new(ShowColumnsInterpreter::try_create(
+                ctx,
+                *show_columns.clone(),
+            )?)),
+            Plan::AttachTable(attach_table) => Ok(Arc::new(AttachTableInterpreter::try_create(
+                ctx,
+                *attach_table.clone(),
+            )?)),
+            Plan::ExportTableSnapshot(export) => Ok(Arc::new(
+                ExportTableSnapshotInterpreter::try_create(ctx, *export.clone())?,
+            )),
+            Plan::ImportTableSnapshot(import) => Ok(Arc::new(
+                ImportTableSnapshotInterpreter::try_create(ctx, *import.clone())?,
+            )),
             Plan::CreateTable(create_table) => Ok(Arc::new(CreateTableInterpreter::try_create(
                 ctx,
                 *create_table.clone(),
@@ -175,12 +194,30 @@ impl InterpreterFactory {
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
+            Plan::RenameTableColumn(rename_table_column) => Ok(Arc::new(
+                RenameTableColumnInterpreter::try_create(ctx, *rename_table_column.clone())?,
+            )),
+            Plan::ModifyTableColumn(modify_table_column) => Ok(Arc::new(
+                ModifyTableColumnInterpreter::try_create(ctx, *modify_table_column.clone())?,
+            )),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => Ok(Arc::new(
                 AlterTableClusterKeyInterpreter::try_create(ctx, *alter_table_cluster_key.clone())?,
             )),
             Plan::DropTableClusterKey(drop_table_cluster_key) => Ok(Arc::new(
                 DropTableClusterKeyInterpreter::try_create(ctx, *drop_table_cluster_key.clone())?,
             )),
+            Plan::SetTableAnalyzeInterval(plan) => Ok(Arc::new(
+                SetTableAnalyzeIntervalInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::SetTableConnection(plan) => Ok(Arc::new(
+                SetTableConnectionInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::AddTableRowAccessPolicy(plan) => Ok(Arc::new(
+                AddTableRowAccessPolicyInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::DropTableRowAccessPolicy(plan) => Ok(Arc::new(
+                DropTableRowAccessPolicyInterpreter::try_create(ctx, *plan.clone())?,
+            )),
             Plan::ReclusterTable(recluster_table) => Ok(Arc::new(
                 ReclusterTableInterpreter::try_create(ctx, *recluster_table.clone())?,
             )),
@@ -190,6 +227,9 @@ impl InterpreterFactory {
             Plan::OptimizeTable(optimize_table) => Ok(Arc::new(
                 OptimizeTableInterpreter::try_create(ctx, *optimize_table.clone())?,
             )),
+            Plan::BuildTableIndex(build_index) => Ok(Arc::new(
+                BuildTableIndexInterpreter::try_create(ctx, *build_index.clone())?,
+            )),
             Plan::AnalyzeTable(analyze_table) => Ok(Arc::new(AnalyzeTableInterpreter::try_create(
                 ctx,
                 *analyze_table.clone(),
@@ -213,6 +253,44 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
+                ctx,
+                *create_stream.clone(),
+            )?)),
+            Plan::DropStream(drop_stream) => Ok(Arc::new(DropStreamInterpreter::try_create(
+                ctx,
+                *drop_stream.clone(),
+            )?)),
+
+            // Tasks
+            Plan::CreateTask(create_task) => Ok(Arc::new(CreateTaskInterpreter::try_create(
+                ctx,
+                *create_task.clone(),
+            )?)),
+            Plan::AlterTask(alter_task) => Ok(Arc::new(AlterTaskInterpreter::try_create(
+                ctx,
+                *alter_task.clone(),
+            )?)),
+            Plan::DropTask(drop_task) => Ok(Arc::new(DropTaskInterpreter::try_create(
+                ctx,
+                *drop_task.clone(),
+            )?)),
+            Plan::ExecuteTask(execute_task) => Ok(Arc::new(ExecuteTaskInterpreter::try_create(
+                ctx,
+                *execute_task.clone(),
+            )?)),
+
+            // Pipes
+            Plan::CreatePipe(create_pipe) => Ok(Arc::new(CreatePipeInterpreter::try_create(
+                ctx,
+                *create_pipe.clone(),
+            )?)),
+            Plan::DropPipe(drop_pipe) => Ok(Arc::new(DropPipeInterpreter::try_create(
+                ctx,
+                *drop_pipe.clone(),
+            )?)),
+
             // Users
             Plan::CreateUser(create_user) => Ok(Arc::new(CreateUserInterpreter::try_create(
                 ctx,
@@ -229,6 +307,10 @@ impl InterpreterFactory {
 
             Plan::Insert(insert) => InsertInterpreter::try_create(ctx, *insert.clone()),
 
+            Plan::InsertMultiTable(insert) => Ok(Arc::new(
+                InsertMultiTableInterpreter::try_create(ctx, *insert.clone())?,
+            )),
+
             Plan::Replace(replace) => ReplaceInterpreter::try_create(ctx, *replace.clone()),
 
             Plan::Delete(delete) => Ok(Arc::new(DeleteInterpreter::try_create(
@@ -258,11 +340,26 @@ impl InterpreterFactory {
                 ctx,
                 *show_roles.clone(),
             )?)),
+            Plan::ShowRoleHierarchy(show_role_hierarchy) => Ok(Arc::new(
+                ShowRoleHierarchyInterpreter::try_create(ctx, *show_role_hierarchy.clone())?,
+            )),
 
             // Stages
             Plan::CreateStage(create_stage) => Ok(Arc::new(
                 CreateUserStageInterpreter::try_create(ctx, *create_stage.clone())?,
             )),
+            Plan::AlterStage(s) => Ok(Arc::new(AlterUserStageInterpreter::try_create(
+                ctx,
+                *s.clone(),
+            )?)),
+            Plan::RenameStage(s) => Ok(Arc::new(RenameUserStageInterpreter::try_create(
+                ctx,
+                *s.clone(),
+            )?)),
+            Plan::ShowCreateStage(s) => Ok(Arc::new(ShowCreateStageInterpreter::try_create(
+                ctx,
+                *s.clone(),
+            )?)),
             Plan::DropStage(s) => Ok(Arc::new(DropUserStageInterpreter::try_create(
                 ctx,
                 *s.clone(),
@@ -271,6 +368,10 @@ impl InterpreterFactory {
                 ctx,
                 *s.clone(),
             )?)),
+            Plan::ShowStageFiles(s) => Ok(Arc::new(ShowStageFilesInterpreter::try_create(
+                ctx,
+                *s.clone(),
+            )?)),
 
             // FileFormats
             Plan::CreateFileFormat(create_file_format) => Ok(Arc::new(
@@ -312,10 +413,61 @@ impl InterpreterFactory {
                 ctx,
                 *alter_udf.clone(),
             )?)),
+            Plan::DescribeFunction(describe_function) => Ok(Arc::new(
+                DescribeFunctionInterpreter::try_create(ctx, *describe_function.clone())?,
+            )),
             Plan::DropUDF(drop_udf) => Ok(Arc::new(DropUserUDFInterpreter::try_create(
                 ctx,
                 *drop_udf.clone(),
             )?)),
+            Plan::CreateMaskingPolicy(plan) => Ok(Arc::new(
+                CreateMaskingPolicyInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::DropMaskingPolicy(plan) => Ok(Arc::new(
+                DropMaskingPolicyInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::CreateRowAccessPolicy(plan) => Ok(Arc::new(
+                CreateRowAccessPolicyInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::DropRowAccessPolicy(plan) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::ShowRowAccessPolicies(plan) => Ok(Arc::new(
+                ShowRowAccessPoliciesInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::CreateSequence(plan) => Ok(Arc::new(CreateSequenceInterpreter::try_create(
+                ctx,
+                *plan.clone(),
+            )?)),
+            Plan::DropSequence(plan) => Ok(Arc::new(DropSequenceInterpreter::try_create(
+                ctx,
+                *plan.clone(),
+            )?)),
+            Plan::ShowSequences(plan) => Ok(Arc::new(ShowSequencesInterpreter::try_create(
+                ctx,
+                *plan.clone(),
+            )?)),
+            Plan::ShowIndexBuildStatus(plan) => Ok(Arc::new(
+                ShowIndexBuildStatusInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::CreateExternalFunction(plan) => Ok(Arc::new(
+                CreateExternalFunctionInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::DropExternalFunction(plan) => Ok(Arc::new(
+                DropExternalFunctionInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::CreateNetworkPolicy(plan) => Ok(Arc::new(
+                CreateNetworkPolicyInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::DropNetworkPolicy(plan) => Ok(Arc::new(
+                DropNetworkPolicyInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::DescNetworkPolicy(plan) => Ok(Arc::new(
+                DescNetworkPolicyInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::ShowNetworkPolicies(plan) => Ok(Arc::new(
+                ShowNetworkPoliciesInterpreter::try_create(ctx, *plan.clone())?,
+            )),
 
             Plan::Presign(presign) => Ok(Arc::new(PresignInterpreter::try_create(
                 ctx,
@@ -335,6 +487,15 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropResultCache(p) => Ok(Arc::new(DropResultCacheInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+
+            // transactions
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
 
             // share plans
             Plan::CreateShareEndpoint(p) => Ok(Arc::new(
@@ -365,6 +526,10 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::AlterShareComment(p) => Ok(Arc::new(AlterShareCommentInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
             Plan::DescShare(p) => Ok(Arc::new(DescShareInterpreter::try_create(ctx, *p.clone())?)),
             Plan::ShowShares(p) => Ok(Arc::new(ShowSharesInterpreter::try_create(
                 ctx,
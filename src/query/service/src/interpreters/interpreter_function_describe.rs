@@ -0,0 +1,113 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_expression::FunctionDescription;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::plans::DescribeFunctionPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DescribeFunctionInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescribeFunctionPlan,
+}
+
+impl DescribeFunctionInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescribeFunctionPlan) -> Result<Self> {
+        Ok(DescribeFunctionInterpreter { ctx, plan })
+    }
+
+    async fn describe(&self) -> Result<Vec<FunctionDescription>> {
+        let name = self.plan.name.as_str();
+
+        let descriptions = BUILTIN_FUNCTIONS.describe(name);
+        if !descriptions.is_empty() {
+            return Ok(descriptions);
+        }
+
+        if let Some(features) = AggregateFunctionFactory::instance().get_features(name) {
+            return Ok(vec![FunctionDescription {
+                signature: format!("{}(...)", name.to_lowercase()),
+                return_type: String::new(),
+                description: features.description.to_string(),
+                example: features.example.to_string(),
+            }]);
+        }
+
+        let tenant = self.ctx.get_tenant();
+        match UserApiProvider::instance().get_udf(&tenant, name).await {
+            Ok(udf) => Ok(vec![FunctionDescription {
+                signature: format!(
+                    "{}({}) -> {}",
+                    udf.name,
+                    udf.parameters.join(", "),
+                    udf.definition
+                ),
+                return_type: String::new(),
+                description: udf.description,
+                example: String::new(),
+            }]),
+            Err(_) => Err(ErrorCode::UnknownFunction(format!(
+                "Unknown function {name}"
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescribeFunctionInterpreter {
+    fn name(&self) -> &str {
+        "DescribeFunctionInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let descriptions = self.describe().await?;
+
+        let mut signatures: Vec<Vec<u8>> = vec![];
+        let mut return_types: Vec<Vec<u8>> = vec![];
+        let mut comments: Vec<Vec<u8>> = vec![];
+        let mut examples: Vec<Vec<u8>> = vec![];
+
+        for desc in descriptions {
+            signatures.push(desc.signature.into_bytes());
+            return_types.push(desc.return_type.into_bytes());
+            comments.push(desc.description.into_bytes());
+            examples.push(desc.example.into_bytes());
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(signatures),
+            StringType::from_data(return_types),
+            StringType::from_data(comments),
+            StringType::from_data(examples),
+        ])])
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_sql::plans::ShowIndexBuildStatusPlan;
+use common_storages_fuse::operations::list_index_builds;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowIndexBuildStatusInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowIndexBuildStatusPlan,
+}
+
+impl ShowIndexBuildStatusInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowIndexBuildStatusPlan) -> Result<Self> {
+        Ok(ShowIndexBuildStatusInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowIndexBuildStatusInterpreter {
+    fn name(&self) -> &str {
+        "ShowIndexBuildStatusInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let builds = list_index_builds(&self.plan.tenant);
+
+        let query_ids: Vec<Vec<u8>> = builds
+            .iter()
+            .map(|x| x.query_id.as_bytes().to_vec())
+            .collect();
+        let databases: Vec<Vec<u8>> = builds
+            .iter()
+            .map(|x| x.database.as_bytes().to_vec())
+            .collect();
+        let tables: Vec<Vec<u8>> = builds.iter().map(|x| x.table.as_bytes().to_vec()).collect();
+        let statuses: Vec<Vec<u8>> = builds
+            .iter()
+            .map(|x| x.status().as_bytes().to_vec())
+            .collect();
+        let built_blocks: Vec<u64> = builds.iter().map(|x| x.built_blocks()).collect();
+        let total_blocks: Vec<u64> = builds.iter().map(|x| x.total_blocks).collect();
+        let started_ons: Vec<i64> = builds
+            .iter()
+            .map(|x| x.started_on.timestamp_micros())
+            .collect();
+        let finished_ons: Vec<Option<i64>> = builds
+            .iter()
+            .map(|x| x.finished_on().map(|t| t.timestamp_micros()))
+            .collect();
+        let errors: Vec<Option<Vec<u8>>> = builds
+            .iter()
+            .map(|x| x.error().map(|e| e.into_bytes()))
+            .collect();
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(query_ids),
+            StringType::from_data(databases),
+            StringType::from_data(tables),
+            StringType::from_data(statuses),
+            UInt64Type::from_data(built_blocks),
+            UInt64Type::from_data(total_blocks),
+            TimestampType::from_data(started_ons),
+            TimestampType::from_opt_data(finished_ons),
+            StringType::from_opt_data(errors),
+        ])])
+    }
+}
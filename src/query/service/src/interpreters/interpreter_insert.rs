@@ -75,6 +75,8 @@ use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
 use common_storages_factory::Table;
 use common_storages_fuse::io::Files;
+use common_storages_fuse::stream_table::StreamTable;
+use common_storages_fuse::stream_table::STREAM_ENGINE;
 use common_storages_stage::StageTable;
 use common_users::UserApiProvider;
 use parking_lot::Mutex;
@@ -433,16 +435,31 @@ impl Interpreter for InsertInterpreter {
             }
             InsertInputSource::SelectPlan(plan) => {
                 let table1 = table.clone();
-                let (mut select_plan, select_column_bindings) = match plan.as_ref() {
+                let (mut select_plan, select_column_bindings, streams) = match plan.as_ref() {
                     Plan::Query {
                         s_expr,
                         metadata,
                         bind_context,
                         ..
                     } => {
+                        // The offsets of the streams read by the query advance once the
+                        // insertion is committed.
+                        let mut streams: Vec<Arc<dyn Table>> = vec![];
+                        for entry in metadata.read().tables() {
+                            let table = entry.table();
+                            if table.engine() == STREAM_ENGINE
+                                && !streams.iter().any(|s| s.get_id() == table.get_id())
+                            {
+                                streams.push(table);
+                            }
+                        }
                         let mut builder1 =
                             PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone());
-                        (builder1.build(s_expr).await?, bind_context.columns.clone())
+                        (
+                            builder1.build(s_expr).await?,
+                            bind_context.columns.clone(),
+                            streams,
+                        )
                     }
                     _ => unreachable!(),
                 };
@@ -490,6 +507,7 @@ impl Interpreter for InsertInterpreter {
                     let overwrite = overwrite;
                     let ctx = ctx.clone();
                     let table = table.clone();
+                    let streams = streams.clone();
 
                     if may_error.is_none() {
                         let append_entries = ctx.consume_precommit_blocks();
@@ -498,8 +516,19 @@ impl Interpreter for InsertInterpreter {
                             // TODO doc this
                             let copied_files = None;
                             table
-                                .commit_insertion(ctx, append_entries, copied_files, overwrite)
-                                .await
+                                .commit_insertion(
+                                    ctx.clone(),
+                                    append_entries,
+                                    copied_files,
+                                    overwrite,
+                                )
+                                .await?;
+                            for stream in streams {
+                                StreamTable::try_from_table(stream.as_ref())?
+                                    .advance_offset(ctx.clone())
+                                    .await?;
+                            }
+                            Ok(())
                         });
                     }
 
@@ -868,9 +897,22 @@ async fn exprs_to_scalar(
             }
         }
 
-        let (mut scalar, _) = scalar_binder.bind(expr).await?;
-        let field_data_type = schema.field(i).data_type();
-        scalar = wrap_cast(&scalar, field_data_type);
+        let (mut scalar, data_type) = scalar_binder.bind(expr).await?;
+        let field = schema.field(i);
+        let field_data_type = field.data_type();
+        // A bare `NULL` literal takes the type of the target column, which is only
+        // possible when the column is nullable.
+        if data_type == DataType::Null && !field_data_type.is_nullable_or_null() {
+            return Err(ErrorCode::BadArguments(format!(
+                "cannot insert NULL into non-nullable column `{}` of type {}",
+                field.name(),
+                field_data_type
+            ))
+            .set_span(expr.span()));
+        }
+        if data_type != *field_data_type {
+            scalar = wrap_cast(&scalar, field_data_type);
+        }
         let expr = scalar
             .as_expr_with_col_index()?
             .project_column_ref(|index| schema.index_of(&index.to_string()).unwrap());
@@ -0,0 +1,290 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_catalog::table::AppendMode;
+use common_catalog::table::NavigationDescriptor;
+use common_catalog::table::NavigationPoint;
+use common_catalog::table::Table;
+use common_catalog::table::TableExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSourcer;
+use common_sql::plans::InsertMultiTable;
+use common_sql::plans::Plan;
+use common_storages_fuse::operations::AppendOperationLogEntry;
+use common_storages_fuse::FuseTable;
+use tracing::error;
+
+use crate::interpreters::common::append2table;
+use crate::interpreters::Interpreter;
+use crate::interpreters::SelectInterpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::processors::transforms::MultiTableInsertBranch;
+use crate::pipelines::processors::transforms::MultiTableInsertRouter;
+use crate::pipelines::processors::transforms::MultiTableInsertSource;
+use crate::pipelines::processors::transforms::MultiTableInsertTarget;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Interprets `INSERT { ALL | FIRST } ...`.
+///
+/// The rows of the source query are routed to a pipeline appending to each target, see
+/// `MultiTableInsertRouter`. The targets are committed after all the pipelines finish,
+/// and the committed ones are reverted if any of the commits fails.
+pub struct InsertMultiTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: InsertMultiTable,
+}
+
+/// A distinct table among the targets, multiple targets may insert into the same table.
+struct TargetTable {
+    database: String,
+    table: Arc<dyn Table>,
+    // Prefix of the locations of the segments appended to the table.
+    segment_prefix: String,
+    append_entries: Vec<DataBlock>,
+}
+
+impl InsertMultiTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: InsertMultiTable) -> Result<Self> {
+        Ok(InsertMultiTableInterpreter { ctx, plan })
+    }
+
+    async fn build_source(&self) -> Result<PipelineBuildResult> {
+        match self.plan.source.as_ref() {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                formatted_ast,
+                ..
+            } => {
+                let select_interpreter = SelectInterpreter::try_create(
+                    self.ctx.clone(),
+                    *(bind_context.clone()),
+                    *s_expr.clone(),
+                    metadata.clone(),
+                    formatted_ast.clone(),
+                    false,
+                )?;
+                select_interpreter.execute2().await
+            }
+            v => unreachable!("Input plan must be Query, but it's {}", v),
+        }
+    }
+
+    /// Commit the tables one by one, the committed tables are reverted to their previous
+    /// snapshots if a commit fails.
+    async fn commit(&self, tables: Vec<TargetTable>) -> Result<()> {
+        let mut committed = Vec::with_capacity(tables.len());
+        for target_table in tables {
+            let table = target_table.table.refresh(self.ctx.as_ref()).await?;
+            let previous_snapshot = FuseTable::try_from_table(table.as_ref())?
+                .read_table_snapshot()
+                .await?
+                .map(|snapshot| snapshot.snapshot_id.simple().to_string());
+            if let Err(cause) = table
+                .commit_insertion(self.ctx.clone(), target_table.append_entries, None, false)
+                .await
+            {
+                for (database, table, previous_snapshot) in committed.into_iter().rev() {
+                    let table_name = table.name().to_string();
+                    if let Err(revert_cause) =
+                        self.revert(&database, table, previous_snapshot).await
+                    {
+                        error!(
+                            "failed to revert table {}.{} after multi-table insert failed: {:?}",
+                            database, table_name, revert_cause
+                        );
+                    }
+                }
+                return Err(cause);
+            }
+            committed.push((target_table.database, table, previous_snapshot));
+        }
+        Ok(())
+    }
+
+    async fn revert(
+        &self,
+        database: &str,
+        table: Arc<dyn Table>,
+        previous_snapshot: Option<String>,
+    ) -> Result<()> {
+        let table = table.refresh(self.ctx.as_ref()).await?;
+        match previous_snapshot {
+            Some(snapshot_id) => {
+                let navigation_descriptor = NavigationDescriptor {
+                    database_name: database.to_string(),
+                    point: NavigationPoint::SnapshotID(snapshot_id),
+                };
+                table
+                    .revert_to(self.ctx.clone(), navigation_descriptor)
+                    .await
+            }
+            // The table had no data before.
+            None => table.truncate(self.ctx.clone(), false).await,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for InsertMultiTableInterpreter {
+    fn name(&self) -> &str {
+        "InsertMultiTableInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", name = "insert_multi_table_interpreter_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let settings = self.ctx.get_settings();
+        let max_threads = settings.get_max_threads()? as usize;
+        let mut build_res = self.build_source().await?;
+
+        let mut tables: Vec<TargetTable> = vec![];
+        let mut inserted_rows = vec![];
+        let mut branches = Vec::with_capacity(self.plan.branches.len());
+        for branch in self.plan.branches.iter() {
+            let mut targets = Vec::with_capacity(branch.targets.len());
+            for target in branch.targets.iter() {
+                let table = self
+                    .ctx
+                    .get_table(&target.catalog, &target.database, &target.table)
+                    .await?;
+                // The appended segments are told apart by the location of the table.
+                let segment_prefix = format!(
+                    "{}/",
+                    FuseTable::try_from_table(table.as_ref())?
+                        .meta_location_generator()
+                        .prefix()
+                );
+                if !tables
+                    .iter()
+                    .any(|target_table| target_table.segment_prefix == segment_prefix)
+                {
+                    tables.push(TargetTable {
+                        database: target.database.clone(),
+                        table: table.clone(),
+                        segment_prefix,
+                        append_entries: vec![],
+                    });
+                }
+
+                let (sender, receiver) = async_channel::bounded(max_threads);
+                let mut target_res = PipelineBuildResult::create();
+                target_res.main_pipeline.add_source(
+                    |output| {
+                        AsyncSourcer::create(
+                            self.ctx.clone(),
+                            output,
+                            MultiTableInsertSource::create(receiver.clone()),
+                        )
+                    },
+                    max_threads,
+                )?;
+                append2table(
+                    self.ctx.clone(),
+                    table,
+                    Arc::new(target.schema.clone().into()),
+                    &mut target_res,
+                    false,
+                    false,
+                    AppendMode::Normal,
+                )?;
+                build_res.sources_pipelines.push(target_res.main_pipeline);
+
+                let rows = Arc::new(AtomicU64::new(0));
+                inserted_rows.push(rows.clone());
+                targets.push(MultiTableInsertTarget {
+                    values: target
+                        .values
+                        .iter()
+                        .map(|value| value.as_expr(&BUILTIN_FUNCTIONS))
+                        .collect(),
+                    sender,
+                    inserted_rows: rows,
+                });
+            }
+            branches.push(MultiTableInsertBranch {
+                condition: branch
+                    .condition
+                    .as_ref()
+                    .map(|condition| condition.as_expr(&BUILTIN_FUNCTIONS)),
+                targets,
+            });
+        }
+
+        let is_first = self.plan.is_first;
+        let func_ctx = self.ctx.get_function_context()?;
+        build_res.main_pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(MultiTableInsertRouter::create(
+                input,
+                is_first,
+                branches.clone(),
+                func_ctx,
+            )))
+        })?;
+        // The targets finish once all the routers drop their senders.
+        drop(branches);
+
+        build_res.set_max_threads(max_threads);
+        let executor_settings = ExecutorSettings::try_create(&settings, self.ctx.get_id())?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        self.ctx.set_executor(Arc::downgrade(&executor.get_inner()));
+        executor.execute()?;
+        drop(executor);
+
+        for block in self.ctx.consume_precommit_blocks() {
+            let entry = AppendOperationLogEntry::try_from(&block)?;
+            let target_table = tables
+                .iter_mut()
+                .find(|target_table| {
+                    entry
+                        .segment_location
+                        .starts_with(&target_table.segment_prefix)
+                })
+                .ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "segment {} doesn't belong to any target of multi-table insert",
+                        entry.segment_location
+                    ))
+                })?;
+            target_table.append_entries.push(block);
+        }
+        self.commit(tables).await?;
+
+        let columns = inserted_rows
+            .iter()
+            .map(|rows| UInt64Type::from_data(vec![rows.load(Ordering::Relaxed)]))
+            .collect();
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(columns)])
+    }
+}
@@ -13,14 +13,19 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_sql::plans::KillPlan;
+use tracing::warn;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
 
 pub struct KillInterpreter {
     ctx: Arc<QueryContext>,
@@ -43,13 +48,46 @@ impl KillInterpreter {
                 Ok(PipelineBuildResult::create())
             }
             Some(kill_session) => {
+                let killed_query_id = kill_session.get_current_query_id();
                 kill_session.force_kill_query(ErrorCode::AbortedQuery(
                     "Aborted query, because the server is shutting down or the query was killed",
                 ));
+
+                if let Some(killed_query_id) = killed_query_id {
+                    self.wait_query_stopped(&kill_session, &killed_query_id)
+                        .await?;
+                }
+
                 Ok(PipelineBuildResult::create())
             }
         }
     }
+
+    // Wait until the killed query released its context, or kill_query_timeout_ms is reached.
+    async fn wait_query_stopped(&self, session: &Arc<Session>, query_id: &str) -> Result<()> {
+        // Kill the query itself.
+        if query_id == self.ctx.get_id() {
+            return Ok(());
+        }
+
+        let timeout_ms = self.ctx.get_settings().get_kill_query_timeout_ms()?;
+        let timeout = Duration::from_millis(timeout_ms);
+        let instant = Instant::now();
+
+        while session.get_current_query_id().as_deref() == Some(query_id) {
+            if instant.elapsed() >= timeout {
+                warn!(
+                    "Query {} is still running {}ms after it was killed",
+                    query_id, timeout_ms
+                );
+                break;
+            }
+
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -0,0 +1,65 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_sql::plans::DescNetworkPolicyPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DescNetworkPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescNetworkPolicyPlan,
+}
+
+impl DescNetworkPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescNetworkPolicyPlan) -> Result<Self> {
+        Ok(DescNetworkPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescNetworkPolicyInterpreter {
+    fn name(&self) -> &str {
+        "DescNetworkPolicyInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let policy = UserApiProvider::instance()
+            .get_network_policy(&self.plan.tenant, &self.plan.name)
+            .await?;
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![policy.name.as_bytes().to_vec()]),
+            StringType::from_data(vec![policy.allowed_ip_list.join(",").as_bytes().to_vec()]),
+            StringType::from_data(vec![policy.blocked_ip_list.join(",").as_bytes().to_vec()]),
+            StringType::from_data(vec![policy.comment.as_bytes().to_vec()]),
+        ])])
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PipeInfo;
+use common_sql::plans::CreatePipePlan;
+use common_storages_fuse::FuseTable;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreatePipeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreatePipePlan,
+}
+
+impl CreatePipeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreatePipePlan) -> Result<Self> {
+        Ok(CreatePipeInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreatePipeInterpreter {
+    fn name(&self) -> &str {
+        "CreatePipeInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        // The offsets of the source are committed in the snapshots of the table.
+        let table = self
+            .ctx
+            .get_table(CATALOG_DEFAULT, &plan.database, &plan.table)
+            .await?;
+        FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "can't create pipe into {}.{} of engine {}, only FUSE tables are supported",
+                plan.database,
+                plan.table,
+                table.engine()
+            ))
+        })?;
+
+        // The pipe loads as the user who created it.
+        let owner = self.ctx.get_current_user()?.identity();
+        let pipe = PipeInfo {
+            name: plan.name,
+            owner,
+            database: plan.database,
+            table: plan.table,
+            source: plan.source,
+            file_format_options: plan.file_format_options,
+            on_error: plan.on_error,
+            comment: plan.comment,
+            created_on: Utc::now(),
+        };
+        UserApiProvider::instance()
+            .add_pipe(&plan.tenant, pipe, plan.if_not_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -59,9 +59,10 @@ impl Interpreter for PresignInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let op = StageTable::get_op(&self.plan.stage)?;
         if !op.info().can_presign() {
-            return Err(ErrorCode::StorageUnsupported(
-                "storage doesn't support presign operation",
-            ));
+            return Err(ErrorCode::StorageUnsupported(format!(
+                "storage {} doesn't support presign operation",
+                op.info().scheme()
+            )));
         }
 
         let presigned_req = match self.plan.action {
@@ -105,6 +106,7 @@ impl Interpreter for PresignInterpreter {
                     data_type: DataType::Variant,
                     value: Value::Scalar(Scalar::Variant(header.to_vec())),
                 },
+                // The url carries the signature, which must never be logged.
                 BlockEntry {
                     data_type: DataType::String,
                     value: Value::Scalar(Scalar::String(
@@ -19,6 +19,7 @@ use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::PrincipalIdentity;
 use common_meta_app::principal::UserPrivilegeSet;
 use common_sql::plans::GrantPrivilegePlan;
+use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
 
 use crate::interpreters::common::validate_grant_object_exists;
@@ -49,26 +50,33 @@ impl Interpreter for GrantPrivilegeInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let plan = self.plan.clone();
 
-        validate_grant_privileges(&plan.on, plan.priv_types)?;
-        validate_grant_object_exists(&self.ctx, &plan.on).await?;
+        for object in plan.on.iter() {
+            validate_grant_privileges(object, plan.priv_types)?;
+            validate_grant_object_exists(&self.ctx, object).await?;
+        }
 
         // TODO: check user existence
         // TODO: check privilege on granting on the grant object
 
         let tenant = self.ctx.get_tenant();
         let user_mgr = UserApiProvider::instance();
-        match plan.principal {
-            PrincipalIdentity::User(user) => {
-                user_mgr
-                    .grant_privileges_to_user(&tenant, user, plan.on, plan.priv_types)
-                    .await?;
-            }
-            PrincipalIdentity::Role(role) => {
-                user_mgr
-                    .grant_privileges_to_role(&tenant, &role, plan.on, plan.priv_types)
-                    .await?;
+        for object in plan.on {
+            match &plan.principal {
+                PrincipalIdentity::User(user) => {
+                    user_mgr
+                        .grant_privileges_to_user(&tenant, user.clone(), object, plan.priv_types)
+                        .await?;
+                }
+                PrincipalIdentity::Role(role) => {
+                    user_mgr
+                        .grant_privileges_to_role(&tenant, role, object, plan.priv_types)
+                        .await?;
+                }
             }
         }
+        if matches!(plan.principal, PrincipalIdentity::Role(_)) {
+            RoleCacheManager::instance().force_reload(&tenant).await?;
+        }
 
         Ok(PipelineBuildResult::create())
     }
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_meta_app::principal::PrincipalIdentity;
 use common_sql::plans::RevokePrivilegePlan;
+use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
 
 use crate::interpreters::common::validate_grant_object_exists;
@@ -47,7 +48,9 @@ impl Interpreter for RevokePrivilegeInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let plan = self.plan.clone();
 
-        validate_grant_object_exists(&self.ctx, &plan.on).await?;
+        for object in plan.on.iter() {
+            validate_grant_object_exists(&self.ctx, object).await?;
+        }
 
         // TODO: check user existence
         // TODO: check privilege on granting on the grant object
@@ -55,18 +58,23 @@ impl Interpreter for RevokePrivilegeInterpreter {
         let tenant = self.ctx.get_tenant();
         let user_mgr = UserApiProvider::instance();
 
-        match plan.principal {
-            PrincipalIdentity::User(user) => {
-                user_mgr
-                    .revoke_privileges_from_user(&tenant, user, plan.on, plan.priv_types)
-                    .await?;
-            }
-            PrincipalIdentity::Role(role) => {
-                user_mgr
-                    .revoke_privileges_from_role(&tenant, &role, plan.on, plan.priv_types)
-                    .await?;
+        for object in plan.on {
+            match &plan.principal {
+                PrincipalIdentity::User(user) => {
+                    user_mgr
+                        .revoke_privileges_from_user(&tenant, user.clone(), object, plan.priv_types)
+                        .await?;
+                }
+                PrincipalIdentity::Role(role) => {
+                    user_mgr
+                        .revoke_privileges_from_role(&tenant, role, object, plan.priv_types)
+                        .await?;
+                }
             }
         }
+        if matches!(plan.principal, PrincipalIdentity::Role(_)) {
+            RoleCacheManager::instance().force_reload(&tenant).await?;
+        }
 
         Ok(PipelineBuildResult::create())
     }
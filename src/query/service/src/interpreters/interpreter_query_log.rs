@@ -294,7 +294,7 @@ impl InterpreterQueryLog {
     }
 }
 
-fn convert_query_log_timestamp(time: SystemTime) -> i64 {
+pub(crate) fn convert_query_log_timestamp(time: SystemTime) -> i64 {
     time.duration_since(UNIX_EPOCH)
         .unwrap_or(Duration::new(0, 0))
         .as_micros() as i64
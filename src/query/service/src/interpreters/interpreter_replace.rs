@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use common_catalog::table::ReplaceIntoStatistics;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -32,6 +34,7 @@ use crate::interpreters::SelectInterpreter;
 use crate::pipelines::processors::TransformCastSchema;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryAffect;
 use crate::sessions::QueryContext;
 
 #[allow(dead_code)]
@@ -84,13 +87,29 @@ impl Interpreter for ReplaceInterpreter {
             })?;
 
         let on_conflict_fields = plan.on_conflict_fields.clone();
+        let statistics = Arc::new(ReplaceIntoStatistics::default());
         table
             .replace_into(
                 self.ctx.clone(),
                 &mut pipeline.main_pipeline,
                 on_conflict_fields,
+                statistics.clone(),
             )
             .await?;
+
+        let ctx = self.ctx.clone();
+        pipeline.main_pipeline.set_on_finished(move |may_error| {
+            if may_error.is_none() {
+                // Rows of the source that don't replace any row of the table are inserted.
+                let source_rows = statistics.source_rows.load(Ordering::Relaxed);
+                let replaced_rows = statistics.replaced_rows.load(Ordering::Relaxed);
+                ctx.set_affect(QueryAffect::Replace {
+                    inserted_rows: source_rows.saturating_sub(replaced_rows),
+                    replaced_rows,
+                });
+            }
+            Ok(())
+        });
         Ok(pipeline)
     }
 }
@@ -0,0 +1,58 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::DropResultCachePlan;
+use common_storage::DataOperator;
+use common_storages_result_cache::gen_result_cache_prefix;
+use common_storages_result_cache::ResultCacheMetaManager;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct DropResultCacheInterpreter {
+    plan: DropResultCachePlan,
+}
+
+impl DropResultCacheInterpreter {
+    pub fn try_create(_ctx: Arc<QueryContext>, plan: DropResultCachePlan) -> Result<Self> {
+        Ok(DropResultCacheInterpreter { plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropResultCacheInterpreter {
+    fn name(&self) -> &str {
+        "DropResultCacheInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let kv_store = UserApiProvider::instance().get_meta_store_client();
+        let meta_mgr = ResultCacheMetaManager::create(kv_store, 0);
+        let operator = DataOperator::instance().operator();
+
+        let prefix = gen_result_cache_prefix(&self.plan.tenant);
+        for (key, value) in meta_mgr.list(&prefix).await? {
+            // Remove the meta first, so the cache is not visible even if removing the file fails.
+            meta_mgr.delete(key).await?;
+            operator.delete(&value.location).await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_sql::plans::ShowRoleHierarchyPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowRoleHierarchyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowRoleHierarchyPlan,
+}
+
+impl ShowRoleHierarchyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowRoleHierarchyPlan) -> Result<Self> {
+        Ok(ShowRoleHierarchyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowRoleHierarchyInterpreter {
+    fn name(&self) -> &str {
+        "ShowRoleHierarchyInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let hierarchy = UserApiProvider::instance()
+            .get_role_hierarchy(&tenant)
+            .await?;
+
+        let parents: Vec<Vec<u8>> = hierarchy
+            .iter()
+            .map(|x| x.parent.as_bytes().to_vec())
+            .collect();
+        let children: Vec<Vec<u8>> = hierarchy
+            .iter()
+            .map(|x| x.child.as_bytes().to_vec())
+            .collect();
+        let paths: Vec<Vec<u8>> = hierarchy
+            .iter()
+            .map(|x| x.path.join(" -> ").into_bytes())
+            .collect();
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(parents),
+            StringType::from_data(children),
+            StringType::from_data(paths),
+        ])])
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::DropRowAccessPolicyPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DropRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropRowAccessPolicyPlan,
+}
+
+impl DropRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropRowAccessPolicyPlan) -> Result<Self> {
+        Ok(DropRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "DropRowAccessPolicyInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let user_mgr = UserApiProvider::instance();
+        let policy = match user_mgr
+            .get_row_access_policy(&plan.tenant, &plan.name)
+            .await
+        {
+            Ok(policy) => policy,
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_ROW_ACCESS_POLICY => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        };
+
+        // Dropped tables are kept in `referenced_tables`, they must not block the drop.
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
+        for table_id in policy.referenced_tables.iter() {
+            if let Ok((_, meta)) = catalog.get_table_meta_by_id(*table_id).await {
+                if meta.drop_on.is_none() && meta.row_access_policies.contains_key(&plan.name) {
+                    return Err(ErrorCode::RowAccessPolicyIsReferenced(format!(
+                        "row access policy {} is still added to table {}, drop it from the table first",
+                        plan.name, table_id
+                    )));
+                }
+            }
+        }
+
+        user_mgr
+            .drop_row_access_policy(&plan.tenant, &plan.name, plan.if_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
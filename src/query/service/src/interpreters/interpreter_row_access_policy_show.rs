@@ -0,0 +1,83 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_sql::plans::ShowRowAccessPoliciesPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowRowAccessPoliciesInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowRowAccessPoliciesPlan,
+}
+
+impl ShowRowAccessPoliciesInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowRowAccessPoliciesPlan) -> Result<Self> {
+        Ok(ShowRowAccessPoliciesInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowRowAccessPoliciesInterpreter {
+    fn name(&self) -> &str {
+        "ShowRowAccessPoliciesInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let mut policies = UserApiProvider::instance()
+            .get_row_access_policies(&self.plan.tenant)
+            .await?;
+        policies.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut names = Vec::with_capacity(policies.len());
+        let mut signatures = Vec::with_capacity(policies.len());
+        let mut bodies = Vec::with_capacity(policies.len());
+        let mut comments = Vec::with_capacity(policies.len());
+        for policy in policies {
+            let signature = policy
+                .args
+                .iter()
+                .map(|(name, data_type)| format!("{name} {data_type}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            names.push(policy.name.as_bytes().to_vec());
+            signatures.push(format!("({signature})").as_bytes().to_vec());
+            bodies.push(policy.body.as_bytes().to_vec());
+            comments.push(policy.comment.as_bytes().to_vec());
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(signatures),
+            StringType::from_data(bodies),
+            StringType::from_data(comments),
+        ])])
+    }
+}
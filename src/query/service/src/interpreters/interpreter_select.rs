@@ -25,19 +25,24 @@ use common_pipeline_core::pipe::Pipe;
 use common_pipeline_core::pipe::PipeItem;
 use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
 use common_pipeline_transforms::processors::transforms::TransformDummy;
 use common_sql::executor::PhysicalPlan;
 use common_sql::parse_result_scan_args;
 use common_sql::MetadataRef;
+use common_storages_result_cache::gen_result_cache_dependencies;
 use common_storages_result_cache::gen_result_cache_key;
+use common_storages_result_cache::ResultCacheDependency;
 use common_storages_result_cache::ResultCacheReader;
 use common_storages_result_cache::WriteResultCacheSink;
+use common_storages_result_cache::WriteResultPersistSink;
 use common_users::UserApiProvider;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline;
+use crate::sessions::ExecutedPlan;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 use crate::sql::executor::PhysicalPlanBuilder;
@@ -80,14 +85,27 @@ impl SelectInterpreter {
     }
 
     pub async fn build_pipeline(&self, physical_plan: PhysicalPlan) -> Result<PipelineBuildResult> {
-        build_query_pipeline(
+        // Profile the operators of local queries, so that the slow query log can tell
+        // where the time was spent.
+        let enable_profiling = self.ctx.get_settings().get_long_query_time()? > 0
+            && !physical_plan.is_distributed_plan();
+        let build_res = build_query_pipeline(
             &self.ctx,
             &self.bind_context.columns,
             &physical_plan,
             self.ignore_result,
-            false,
+            enable_profiling,
         )
-        .await
+        .await?;
+
+        if enable_profiling {
+            self.ctx.set_executed_plan(ExecutedPlan {
+                plan: physical_plan,
+                metadata: self.metadata.clone(),
+                prof_span_set: build_res.prof_span_set.clone(),
+            });
+        }
+        Ok(build_res)
     }
 
     /// Add pipelines for writing query result cache.
@@ -97,6 +115,31 @@ impl SelectInterpreter {
         schema: TableSchemaRef,
         pipeline: &mut Pipeline,
         kv_store: Arc<MetaStore>,
+        dependencies: Vec<ResultCacheDependency>,
+    ) -> Result<()> {
+        Self::add_result_sink(pipeline, |inputs| {
+            WriteResultCacheSink::try_create(
+                self.ctx.clone(),
+                key,
+                schema,
+                inputs,
+                kv_store,
+                dependencies,
+            )
+        })
+    }
+
+    /// Add pipelines for persisting the query result, which outlives the result cache.
+    fn add_result_persist(&self, schema: TableSchemaRef, pipeline: &mut Pipeline) -> Result<()> {
+        Self::add_result_sink(pipeline, |inputs| {
+            WriteResultPersistSink::try_create(self.ctx.clone(), schema, inputs)
+        })
+    }
+
+    /// Duplicate the result to a sink, and keep passing it downstream.
+    fn add_result_sink(
+        pipeline: &mut Pipeline,
+        create_sink: impl FnOnce(Vec<Arc<InputPort>>) -> Result<ProcessorPtr>,
     ) -> Result<()> {
         //              ┌─────────┐ 1  ┌─────────┐ 1
         //              │         ├───►│         ├───►Dummy───►Downstream
@@ -107,7 +150,7 @@ impl SelectInterpreter {
         //              ┌─────────┐ 3  │         │ 2  ┌─────────┐
         //              │         ├───►│         ├───►│  Write  │
         // Upstream────►│Duplicate│ 4  │         │ 4  │ Result  │
-        //              │         ├───►│         ├───►│  Cache  │
+        //              │         ├───►│         ├───►│  Sink   │
         //              └─────────┘    └─────────┘    └─────────┘
 
         // 1. Duplicate the pipes.
@@ -125,7 +168,7 @@ impl SelectInterpreter {
         }
         pipeline.reorder_inputs(rule);
 
-        // `output_len` / 2 for `TransformDummy`; 1 for `WriteResultCacheSink`.
+        // `output_len` / 2 for `TransformDummy`; 1 for the sink.
         let mut items = Vec::with_capacity(output_len / 2 + 1);
         // 3. Add `TransformDummy` to the front half pipes.
         for _ in 0..output_len / 2 {
@@ -138,19 +181,13 @@ impl SelectInterpreter {
             ));
         }
 
-        // 4. Add `WriteResultCacheSink` (`AsyncMpscSinker`) to the back half pipes.
+        // 4. Add the sink (`AsyncMpscSinker`) to the back half pipes.
         let mut sink_inputs = Vec::with_capacity(output_len / 2);
         for _ in 0..output_len / 2 {
             sink_inputs.push(InputPort::create());
         }
         items.push(PipeItem::create(
-            WriteResultCacheSink::try_create(
-                self.ctx.clone(),
-                key,
-                schema,
-                sink_inputs.clone(),
-                kv_store,
-            )?,
+            create_sink(sink_inputs.clone())?,
             sink_inputs,
             vec![],
         ));
@@ -160,38 +197,8 @@ impl SelectInterpreter {
         Ok(())
     }
 
-    fn result_scan_table(&self) -> Result<Option<Arc<dyn Table>>> {
-        let r_lock = self.metadata.read();
-        let tables = r_lock.tables();
-        for t in tables {
-            if t.name().eq_ignore_ascii_case("result_scan") {
-                return if tables.len() > 1 {
-                    Err(ErrorCode::Unimplemented(
-                        "The current `RESULT_SCAN` only supports single table queries",
-                    ))
-                } else {
-                    Ok(Some(t.table()))
-                };
-            }
-        }
-        Ok(None)
-    }
-}
-
-#[async_trait::async_trait]
-impl Interpreter for SelectInterpreter {
-    fn name(&self) -> &str {
-        "SelectInterpreterV2"
-    }
-
-    fn schema(&self) -> DataSchemaRef {
-        self.bind_context.output_schema()
-    }
-
-    /// This method will create a new pipeline
-    /// The QueryPipelineBuilder will use the optimized plan to generate a Pipeline
-    #[tracing::instrument(level = "debug", name = "select_interpreter_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
-    async fn execute2(&self) -> Result<PipelineBuildResult> {
+    /// Build the pipeline of the query, reading the result from the cache if possible.
+    async fn build_result_pipeline(&self) -> Result<PipelineBuildResult> {
         // 0. Need to build physical plan first to get the partitions.
         let physical_plan = self.build_physical_plan().await?;
         if self.ctx.get_settings().get_enable_query_result_cache()? && self.ctx.get_cacheable() {
@@ -219,10 +226,19 @@ impl Interpreter for SelectInterpreter {
                 return self.build_pipeline(physical_plan).await;
             }
 
+            let tables = self
+                .metadata
+                .read()
+                .tables()
+                .iter()
+                .map(|t| t.table())
+                .collect::<Vec<_>>();
+            let dependencies = gen_result_cache_dependencies(&tables);
             let cache_reader = ResultCacheReader::create(
                 self.ctx.clone(),
                 &key,
                 kv_store.clone(),
+                dependencies.clone(),
                 self.ctx
                     .get_settings()
                     .get_query_result_cache_allow_inconsistent()?,
@@ -241,7 +257,13 @@ impl Interpreter for SelectInterpreter {
                     let mut build_res = self.build_pipeline(physical_plan).await?;
                     // 2.2 If not found result in cache, add pipelines to write the result to cache.
                     let schema = infer_table_schema(&self.schema())?;
-                    self.add_result_cache(&key, schema, &mut build_res.main_pipeline, kv_store)?;
+                    self.add_result_cache(
+                        &key,
+                        schema,
+                        &mut build_res.main_pipeline,
+                        kv_store,
+                        dependencies,
+                    )?;
                     return Ok(build_res);
                 }
                 Err(e) => {
@@ -253,4 +275,45 @@ impl Interpreter for SelectInterpreter {
         // Not use query cache.
         self.build_pipeline(physical_plan).await
     }
+
+    fn result_scan_table(&self) -> Result<Option<Arc<dyn Table>>> {
+        let r_lock = self.metadata.read();
+        let tables = r_lock.tables();
+        for t in tables {
+            if t.name().eq_ignore_ascii_case("result_scan") {
+                return if tables.len() > 1 {
+                    Err(ErrorCode::Unimplemented(
+                        "The current `RESULT_SCAN` only supports single table queries",
+                    ))
+                } else {
+                    Ok(Some(t.table()))
+                };
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SelectInterpreter {
+    fn name(&self) -> &str {
+        "SelectInterpreterV2"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.bind_context.output_schema()
+    }
+
+    /// This method will create a new pipeline
+    /// The QueryPipelineBuilder will use the optimized plan to generate a Pipeline
+    #[tracing::instrument(level = "debug", name = "select_interpreter_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let mut build_res = self.build_result_pipeline().await?;
+        if !self.ignore_result && self.ctx.get_settings().get_enable_query_result_persist()? {
+            // 3. Persist the result, so that `RESULT_SCAN` can read it after the cache expires.
+            let schema = infer_table_schema(&self.schema())?;
+            self.add_result_persist(schema, &mut build_res.main_pipeline)?;
+        }
+        Ok(build_res)
+    }
 }
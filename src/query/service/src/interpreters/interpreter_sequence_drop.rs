@@ -0,0 +1,135 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Lambda;
+use common_ast::ast::Literal;
+use common_ast::ast::WindowSpec;
+use common_ast::parser::parse_expr;
+use common_ast::parser::tokenize_sql;
+use common_ast::walk_expr;
+use common_ast::Dialect;
+use common_ast::Visitor;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_sql::plans::DropSequencePlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DropSequenceInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropSequencePlan,
+}
+
+impl DropSequenceInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropSequencePlan) -> Result<Self> {
+        Ok(DropSequenceInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropSequenceInterpreter {
+    fn name(&self) -> &str {
+        "DropSequenceInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+
+        // The column defaults would fail on each insert once the sequence is gone.
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
+        for database in catalog.list_databases(&plan.tenant).await? {
+            for table in catalog.list_tables(&plan.tenant, database.name()).await? {
+                for field in table.schema().fields() {
+                    let uses_sequence = match field.default_expr() {
+                        Some(default_expr) => calls_nextval(default_expr, &plan.name)?,
+                        None => false,
+                    };
+                    if uses_sequence {
+                        return Err(ErrorCode::SequenceIsReferenced(format!(
+                            "sequence {} is still used by the default of column {} of table {}.{}, change the default first",
+                            plan.name,
+                            field.name(),
+                            database.name(),
+                            table.name()
+                        )));
+                    }
+                }
+            }
+        }
+
+        UserApiProvider::instance()
+            .drop_sequence(&plan.tenant, &plan.name, plan.if_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+/// Whether the expression calls `nextval` on the sequence.
+fn calls_nextval(expr: &str, sequence: &str) -> Result<bool> {
+    let tokens = tokenize_sql(expr)?;
+    let expr = parse_expr(&tokens, Dialect::MySQL)?;
+    let mut finder = NextvalFinder {
+        sequence,
+        found: false,
+    };
+    walk_expr(&mut finder, &expr);
+    Ok(finder.found)
+}
+
+struct NextvalFinder<'a> {
+    sequence: &'a str,
+    found: bool,
+}
+
+impl<'a, 'ast> Visitor<'ast> for NextvalFinder<'a> {
+    fn visit_function_call(
+        &mut self,
+        _span: Span,
+        _distinct: bool,
+        name: &'ast Identifier,
+        args: &'ast [Expr],
+        _params: &'ast [Literal],
+        _over: &'ast Option<WindowSpec>,
+        _lambda: &'ast Option<Lambda>,
+    ) {
+        if name.name.eq_ignore_ascii_case("nextval") {
+            if let [
+                Expr::Literal {
+                    lit: Literal::String(sequence),
+                    ..
+                },
+            ] = args
+            {
+                self.found |= sequence == self.sequence;
+            }
+        }
+
+        for arg in args {
+            walk_expr(self, arg);
+        }
+    }
+}
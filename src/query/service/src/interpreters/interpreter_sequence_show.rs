@@ -0,0 +1,89 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::types::Int64Type;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_sql::plans::ShowSequencesPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowSequencesInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowSequencesPlan,
+}
+
+impl ShowSequencesInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowSequencesPlan) -> Result<Self> {
+        Ok(ShowSequencesInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowSequencesInterpreter {
+    fn name(&self) -> &str {
+        "ShowSequencesInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let mut sequences = UserApiProvider::instance()
+            .get_sequences(&self.plan.tenant)
+            .await?;
+        sequences.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let names: Vec<Vec<u8>> = sequences
+            .iter()
+            .map(|x| x.name.as_bytes().to_vec())
+            .collect();
+        let starts: Vec<i64> = sequences.iter().map(|x| x.start).collect();
+        let increments: Vec<i64> = sequences.iter().map(|x| x.increment).collect();
+        let min_values: Vec<i64> = sequences.iter().map(|x| x.min_value).collect();
+        let max_values: Vec<i64> = sequences.iter().map(|x| x.max_value).collect();
+        let cycles: Vec<bool> = sequences.iter().map(|x| x.cycle).collect();
+        let currents: Vec<Option<i64>> = sequences.iter().map(|x| x.current).collect();
+        let created_ons: Vec<i64> = sequences
+            .iter()
+            .map(|x| x.created_on.timestamp_micros())
+            .collect();
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            Int64Type::from_data(starts),
+            Int64Type::from_data(increments),
+            Int64Type::from_data(min_values),
+            Int64Type::from_data(max_values),
+            BooleanType::from_data(cycles),
+            Int64Type::from_opt_data(currents),
+            TimestampType::from_data(created_ons),
+        ])])
+    }
+}
@@ -79,6 +79,9 @@ impl Interpreter for SettingInterpreter {
                 is_globals.push(var.is_global);
             }
         }
+        for var in plan.user_vars {
+            self.ctx.set_variable(var.variable, var.value);
+        }
         self.ctx.set_affect(QueryAffect::ChangeSettings {
             keys,
             values,
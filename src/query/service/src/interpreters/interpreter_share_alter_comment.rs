@@ -0,0 +1,62 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::Result;
+use common_meta_api::ShareApi;
+use common_meta_app::share::AlterShareCommentReq;
+use common_meta_app::share::ShareNameIdent;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::share::AlterShareCommentPlan;
+
+pub struct AlterShareCommentInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterShareCommentPlan,
+}
+
+impl AlterShareCommentInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterShareCommentPlan) -> Result<Self> {
+        Ok(AlterShareCommentInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterShareCommentInterpreter {
+    fn name(&self) -> &str {
+        "AlterShareCommentInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let req = AlterShareCommentReq {
+            share_name: ShareNameIdent {
+                tenant: self.ctx.get_tenant(),
+                share_name: self.plan.share.clone(),
+            },
+            if_exists: self.plan.if_exists,
+            comment: Some(self.plan.comment.clone()),
+            update_on: Utc::now(),
+        };
+        meta_api.alter_share_comment(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -58,6 +58,7 @@ impl Interpreter for AlterShareTenantsInterpreter {
                 if_exists: self.plan.if_exists,
                 accounts: self.plan.accounts.clone(),
                 share_on: Utc::now(),
+                read_only: self.plan.read_only,
             };
             let resp = meta_api.add_share_tenants(req).await?;
 
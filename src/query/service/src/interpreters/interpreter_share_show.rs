@@ -52,7 +52,10 @@ impl Interpreter for ShowSharesInterpreter {
 
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let meta_api = UserApiProvider::instance().get_meta_store_client();
-        let tenant = self.ctx.get_tenant();
+        let tenant = match &self.plan.tenant {
+            Some(tenant) => tenant.clone(),
+            None => self.ctx.get_tenant(),
+        };
         let req = ShowSharesReq {
             tenant: tenant.clone(),
         };
@@ -0,0 +1,184 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::SystemTime;
+
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::token::Tokenizer;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::executor::PhysicalPlan;
+use common_storages_system::SlowQueryLogElement;
+use common_storages_system::SlowQueryLogQueue;
+use common_tracing::QueryLogger;
+use tracing::error;
+use tracing::info;
+use tracing::subscriber;
+
+use crate::interpreters::interpreter_query_log::convert_query_log_timestamp;
+use crate::sessions::ExecutedPlan;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct InterpreterSlowQueryLog;
+
+impl InterpreterSlowQueryLog {
+    /// Records the query in `system.slow_queries` if it ran for `long_query_time` or longer.
+    ///
+    /// Formatting the plan is deferred to the IO runtime, so that the client is not kept
+    /// waiting for the log.
+    pub fn log_finish(ctx: &QueryContext, now: SystemTime, err: Option<ErrorCode>) -> Result<()> {
+        let settings = ctx.get_settings();
+        let long_query_time = settings.get_long_query_time()?;
+        let duration = now
+            .duration_since(ctx.get_created_time())
+            .unwrap_or(Duration::new(0, 0));
+        if long_query_time == 0 || duration < Duration::from_secs(long_query_time) {
+            return Ok(());
+        }
+
+        let redact_literals = settings.get_slow_query_log_redact_literals()?;
+        let to_tracing = settings.get_slow_query_log_to_tracing()?;
+
+        // Query.
+        let query_text = ctx.get_query_str();
+        let query_text = match redact_literals {
+            true => redact_sql_literals(&query_text),
+            false => query_text,
+        };
+
+        // Exception.
+        let (exception_code, exception_text) = match err {
+            None => (0, "".to_string()),
+            Some(e) => (e.code().into(), e.to_string()),
+        };
+
+        let mut element = SlowQueryLogElement {
+            tenant_id: GlobalConfig::instance().query.tenant_id.clone(),
+            sql_user: ctx.get_current_user()?.name,
+            query_id: ctx.get_id(),
+            query_kind: ctx.get_query_kind(),
+            query_text,
+            query_start_time: convert_query_log_timestamp(ctx.get_created_time()),
+            query_duration_ms: duration.as_millis() as u64,
+            scan_rows: ctx.get_scan_progress_value().rows as u64,
+            scan_bytes: ctx.get_scan_progress_value().bytes as u64,
+            result_rows: ctx.get_result_progress_value().rows as u64,
+            peak_memory_usage: ctx.get_peak_memory_usage(),
+            exception_code,
+            exception_text,
+            plan: "".to_string(),
+            slowest_operators: "".to_string(),
+        };
+
+        let executed_plan = ctx.get_executed_plan();
+        GlobalIORuntime::instance().spawn(async move {
+            if let Some(executed_plan) = executed_plan {
+                match executed_plan.plan.format(
+                    executed_plan.metadata.clone(),
+                    executed_plan.prof_span_set.clone(),
+                ) {
+                    Ok(plan) => element.plan = plan.format_pretty().unwrap_or_default(),
+                    Err(cause) => error!("slow_query_log.format_plan.error: {:?}", cause),
+                }
+                element.slowest_operators = format_slowest_operators(&executed_plan, 3);
+            }
+
+            if let Err(cause) = Self::write_log(element, to_tracing) {
+                error!("slow_query_log.write.error: {:?}", cause);
+            }
+        });
+        Ok(())
+    }
+
+    fn write_log(event: SlowQueryLogElement, to_tracing: bool) -> Result<()> {
+        if to_tracing {
+            let event_str = serde_json::to_string(&event)?;
+            if let Some(logger) = QueryLogger::instance().get_subscriber() {
+                subscriber::with_default(logger, || {
+                    info!("{}", event_str);
+                });
+            } else {
+                info!("{}", event_str);
+            };
+        }
+        SlowQueryLogQueue::instance()?.append_data(event)
+    }
+}
+
+/// Replaces the string and number literals of the SQL with `?`, the SQL is kept as it is if
+/// it can't be tokenized.
+fn redact_sql_literals(sql: &str) -> String {
+    let mut redacted = String::with_capacity(sql.len());
+    let mut last = 0;
+    for token in Tokenizer::new(sql) {
+        let token = match token {
+            Ok(token) => token,
+            Err(_) => return sql.to_string(),
+        };
+        let is_literal = match token.kind {
+            // Quoted with backticks or double quotes, it is an identifier.
+            TokenKind::QuotedString => token.text().starts_with('\''),
+            TokenKind::PGLiteralHex
+            | TokenKind::MySQLLiteralHex
+            | TokenKind::LiteralInteger
+            | TokenKind::LiteralFloat => true,
+            _ => false,
+        };
+        if is_literal {
+            redacted.push_str(&sql[last..token.span.start]);
+            redacted.push('?');
+            last = token.span.end;
+        }
+    }
+    redacted.push_str(&sql[last..]);
+    redacted
+}
+
+/// Formats the `n` operators that took the most processing time, e.g. `Sort: 1200ms, TableScan: 800ms`.
+fn format_slowest_operators(executed_plan: &ExecutedPlan, n: usize) -> String {
+    fn collect(plan: &PhysicalPlan, operators: &mut Vec<(String, u32)>) {
+        if let Some(plan_id) = plan.plan_id() {
+            operators.push((plan.name(), plan_id));
+        }
+        for child in plan.children() {
+            collect(child, operators);
+        }
+    }
+
+    let mut operators = vec![];
+    collect(&executed_plan.plan, &mut operators);
+
+    let prof_span_set = executed_plan.prof_span_set.lock().unwrap();
+    let mut operators = operators
+        .into_iter()
+        .filter_map(|(name, plan_id)| {
+            prof_span_set
+                .get(&plan_id)
+                .map(|span| (name, span.process_time / 1_000_000))
+        })
+        .collect::<Vec<_>>();
+    operators.sort_by(|a, b| b.1.cmp(&a.1));
+
+    operators
+        .into_iter()
+        .take(n)
+        .map(|(name, ms)| format!("{}: {}ms", name, ms))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
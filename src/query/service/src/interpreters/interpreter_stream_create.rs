@@ -0,0 +1,111 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CreateStreamPlan;
+use common_storages_fuse::stream_table::StreamTable;
+use common_storages_fuse::stream_table::OPT_KEY_OFFSET_SNAPSHOT_ID;
+use common_storages_fuse::stream_table::OPT_KEY_OFFSET_SNAPSHOT_LOCATION;
+use common_storages_fuse::stream_table::OPT_KEY_TABLE_DATABASE;
+use common_storages_fuse::stream_table::OPT_KEY_TABLE_ID;
+use common_storages_fuse::stream_table::OPT_KEY_TABLE_NAME;
+use common_storages_fuse::stream_table::STREAM_ENGINE;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateStreamInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateStreamPlan,
+}
+
+impl CreateStreamInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateStreamPlan) -> Result<Self> {
+        Ok(CreateStreamInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateStreamInterpreter {
+    fn name(&self) -> &str {
+        "CreateStreamInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.table_database, &plan.table_name)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "can't create stream on {}.{} of engine {}, only FUSE tables are supported",
+                plan.table_database,
+                plan.table_name,
+                table.engine()
+            ))
+        })?;
+
+        // The offset starts from the current snapshot of the table.
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_TABLE_DATABASE.to_string(),
+            plan.table_database.clone(),
+        );
+        options.insert(OPT_KEY_TABLE_NAME.to_string(), plan.table_name.clone());
+        options.insert(OPT_KEY_TABLE_ID.to_string(), table.get_id().to_string());
+        if let (Some(snapshot), Some(location)) = (
+            fuse_table.read_table_snapshot().await?,
+            fuse_table.snapshot_loc().await?,
+        ) {
+            options.insert(
+                OPT_KEY_OFFSET_SNAPSHOT_ID.to_string(),
+                snapshot.snapshot_id.simple().to_string(),
+            );
+            options.insert(OPT_KEY_OFFSET_SNAPSHOT_LOCATION.to_string(), location);
+        }
+
+        let req = CreateTableReq {
+            if_not_exists: plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: plan.tenant.clone(),
+                db_name: plan.database.clone(),
+                table_name: plan.stream_name.clone(),
+            },
+            table_meta: TableMeta {
+                schema: Arc::new(StreamTable::schema_of(&table.schema())?),
+                engine: STREAM_ENGINE.to_string(),
+                catalog: plan.catalog.clone(),
+                options,
+                ..Default::default()
+            },
+        };
+        self.ctx
+            .get_catalog(&plan.catalog)?
+            .create_table(req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
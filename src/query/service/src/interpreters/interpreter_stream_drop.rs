@@ -0,0 +1,76 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DropTableByIdReq;
+use common_sql::plans::DropStreamPlan;
+use common_storages_fuse::stream_table::STREAM_ENGINE;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropStreamInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropStreamPlan,
+}
+
+impl DropStreamInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropStreamPlan) -> Result<Self> {
+        Ok(DropStreamInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropStreamInterpreter {
+    fn name(&self) -> &str {
+        "DropStreamInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let table = match self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.stream_name)
+            .await
+        {
+            Ok(table) => table,
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_TABLE => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        };
+
+        if table.engine() != STREAM_ENGINE {
+            return Err(ErrorCode::Internal(format!(
+                "{}.{} is not STREAM, please use `DROP TABLE {}.{}`",
+                plan.database, plan.stream_name, plan.database, plan.stream_name
+            )));
+        }
+
+        self.ctx
+            .get_catalog(&plan.catalog)?
+            .drop_table_by_id(DropTableByIdReq {
+                if_exists: plan.if_exists,
+                tb_id: table.get_id(),
+            })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
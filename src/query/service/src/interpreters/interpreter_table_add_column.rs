@@ -16,10 +16,13 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::schema::DatabaseType;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
 use common_sql::binder::INTERNAL_COLUMN_FACTORY;
+use common_sql::parse_exprs;
+use common_sql::plans::AddColumnOption;
 use common_sql::plans::AddTableColumnPlan;
 use common_storages_view::view_table::VIEW_ENGINE;
 
@@ -71,17 +74,38 @@ impl Interpreter for AddTableColumnInterpreter {
                     &self.plan.database, &self.plan.table
                 )));
             }
+            if table.is_read_only() {
+                return Err(ErrorCode::TableNotWritable(format!(
+                    "{}.{} is read-only that doesn't support alter",
+                    &self.plan.database, &self.plan.table
+                )));
+            }
 
             let catalog = self.ctx.get_catalog(catalog_name)?;
             let mut new_table_meta = table.get_table_info().meta.clone();
             let mut fields = Vec::with_capacity(self.plan.schema.num_fields());
             for (idx, field) in self.plan.schema.fields().clone().into_iter().enumerate() {
-                let field =
-                    if let Some(Some(default_expr)) = &self.plan.field_default_exprs.get(idx) {
-                        field.with_default_expr(Some(default_expr.clone()))
-                    } else {
-                        field
-                    };
+                let field = if let Some(Some(default_expr)) =
+                    &self.plan.field_default_exprs.get(idx)
+                {
+                    // Old blocks get the default computed lazily at read time, which is only
+                    // correct if the default evaluates to the same value on every read.
+                    if table_info.meta.statistics.number_of_rows > 0 {
+                        let expr = parse_exprs(self.ctx.clone(), table.clone(), default_expr)?;
+                        if !expr[0].is_deterministic(&BUILTIN_FUNCTIONS) {
+                            return Err(ErrorCode::Unimplemented(format!(
+                                "add column {} with non-deterministic default {} to non-empty table {}.{} is not supported yet",
+                                field.name(),
+                                default_expr,
+                                &self.plan.database,
+                                &self.plan.table
+                            )));
+                        }
+                    }
+                    field.with_default_expr(Some(default_expr.clone()))
+                } else {
+                    field
+                };
 
                 if INTERNAL_COLUMN_FACTORY.exist(field.name()) {
                     return Err(ErrorCode::TableWithInternalColumnName(format!(
@@ -92,7 +116,25 @@ impl Interpreter for AddTableColumnInterpreter {
 
                 fields.push(field)
             }
-            new_table_meta.add_columns(&fields, &self.plan.field_comments)?;
+            let position = match &self.plan.option {
+                AddColumnOption::First => Some(0),
+                AddColumnOption::After(column) => Some(new_table_meta.schema.index_of(column)? + 1),
+                AddColumnOption::End => None,
+            };
+            if let Some(mut index) = position {
+                for (idx, field) in fields.iter().enumerate() {
+                    let comment = self
+                        .plan
+                        .field_comments
+                        .get(idx)
+                        .cloned()
+                        .unwrap_or_default();
+                    new_table_meta.add_column(field, &comment, index)?;
+                    index += 1;
+                }
+            } else {
+                new_table_meta.add_columns(&fields, &self.plan.field_comments)?;
+            }
 
             let table_id = table_info.ident.table_id;
             let table_version = table_info.ident.seq;
@@ -0,0 +1,145 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::AddTableRowAccessPolicyPlan;
+use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AddTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableRowAccessPolicyPlan,
+}
+
+impl AddTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(AddTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+/// Row access policies are only supported by the tables that can be altered.
+pub(crate) fn check_row_access_policy_supported(
+    table: &dyn Table,
+    database: &str,
+    table_name: &str,
+) -> Result<()> {
+    let table_info = table.get_table_info();
+    if table_info.engine() == VIEW_ENGINE {
+        return Err(ErrorCode::TableEngineNotSupported(format!(
+            "{}.{} engine is VIEW that doesn't support alter",
+            database, table_name
+        )));
+    }
+    if table_info.db_type != DatabaseType::NormalDB {
+        return Err(ErrorCode::TableEngineNotSupported(format!(
+            "{}.{} doesn't support alter",
+            database, table_name
+        )));
+    }
+    if table.is_read_only() {
+        return Err(ErrorCode::TableNotWritable(format!(
+            "{}.{} is read-only that doesn't support alter",
+            database, table_name
+        )));
+    }
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AddTableRowAccessPolicyInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+        check_row_access_policy_supported(table.as_ref(), &plan.database, &plan.table)?;
+
+        let table_info = table.get_table_info();
+        if table_info
+            .meta
+            .row_access_policies
+            .contains_key(&plan.policy)
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "row access policy {} is already added to table {}.{}",
+                plan.policy, plan.database, plan.table
+            )));
+        }
+
+        let user_mgr = UserApiProvider::instance();
+        let mut policy = user_mgr
+            .get_row_access_policy(&plan.tenant, &plan.policy)
+            .await?;
+        if policy.args.len() != plan.columns.len() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "row access policy {} accepts {} arguments, but got {} columns",
+                plan.policy,
+                policy.args.len(),
+                plan.columns.len()
+            )));
+        }
+        let schema = table.schema();
+        for ((arg_name, arg_type), column) in policy.args.iter().zip(plan.columns.iter()) {
+            let field = schema.field_with_name(column)?;
+            let column_type = DataType::from(field.data_type())
+                .remove_nullable()
+                .to_string();
+            if arg_type != &column_type {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "argument {} of row access policy {} is of type {}, but column {} is of type {}",
+                    arg_name, plan.policy, arg_type, column, column_type
+                )));
+            }
+        }
+
+        let table_id = table_info.ident.table_id;
+        if policy.referenced_tables.insert(table_id) {
+            user_mgr
+                .update_row_access_policy(&plan.tenant, policy)
+                .await?;
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta
+            .row_access_policies
+            .insert(plan.policy.clone(), plan.columns.clone());
+        let req = UpdateTableMetaReq {
+            table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+        };
+        catalog.update_table_meta(table_info, req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Engine;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::AttachTablePlan;
+use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::FuseTable;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Creates a table attached read-only to the data of another fuse table, nothing is copied.
+///
+/// The schema and the storage format of the table are the ones of the latest snapshot of the
+/// table attached to, the data it reads are resolved again by every query.
+pub struct AttachTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AttachTablePlan,
+}
+
+impl AttachTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AttachTablePlan) -> Result<Self> {
+        Ok(AttachTableInterpreter { ctx, plan })
+    }
+
+    /// The storage format is not kept in the snapshots, it's told by the magic number
+    /// at the beginning of the first block, which is `PAR1` for parquet.
+    async fn storage_format(fuse_table: &FuseTable, snapshot: &TableSnapshot) -> Result<String> {
+        let operator = fuse_table.get_operator();
+        let reader =
+            MetaReaders::segment_info_reader(operator.clone(), Arc::new(snapshot.schema.clone()));
+        for (location, ver) in snapshot.segments.iter() {
+            let params = LoadParams {
+                location: location.clone(),
+                len_hint: None,
+                ver: *ver,
+                put_cache: false,
+            };
+            let segment = reader.read(&params).await?;
+            if let Some(block) = segment.blocks.first() {
+                let magic = operator.range_read(&block.location.0, 0..4).await?;
+                let storage_format = if magic == b"PAR1" {
+                    "parquet"
+                } else {
+                    "native"
+                };
+                return Ok(storage_format.to_string());
+            }
+        }
+        Err(ErrorCode::BadArguments(format!(
+            "the table at {} has no data to attach to",
+            fuse_table.meta_location_generator().prefix()
+        )))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AttachTableInterpreter {
+    fn name(&self) -> &str {
+        "AttachTableInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let mut table_meta = TableMeta {
+            engine: Engine::Fuse.to_string(),
+            storage_params: Some(plan.storage_params.clone()),
+            options: plan.options.clone(),
+            ..Default::default()
+        };
+
+        let fuse_table = FuseTable::do_create(TableInfo {
+            desc: format!("'{}'.'{}'", plan.database, plan.table),
+            name: plan.table.clone(),
+            meta: table_meta.clone(),
+            ..Default::default()
+        })?;
+        let snapshot = fuse_table.read_table_snapshot().await?.ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "no fuse table found at {}, the last snapshot hint is missing",
+                fuse_table.meta_location_generator().prefix()
+            ))
+        })?;
+        table_meta.schema = Arc::new(snapshot.schema.clone());
+        table_meta.options.insert(
+            OPT_KEY_STORAGE_FORMAT.to_string(),
+            Self::storage_format(&fuse_table, &snapshot).await?,
+        );
+
+        let req = CreateTableReq {
+            if_not_exists: false,
+            name_ident: TableNameIdent {
+                tenant: plan.tenant.clone(),
+                db_name: plan.database.clone(),
+                table_name: plan.table.clone(),
+            },
+            table_meta,
+        };
+        self.ctx
+            .get_catalog(&plan.catalog)?
+            .create_table(req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
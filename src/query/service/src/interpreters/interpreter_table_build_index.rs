@@ -0,0 +1,88 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::BuildTableIndexPlan;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::Pipeline;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct BuildTableIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: BuildTableIndexPlan,
+}
+
+impl BuildTableIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: BuildTableIndexPlan) -> Result<Self> {
+        Ok(BuildTableIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for BuildTableIndexInterpreter {
+    fn name(&self) -> &str {
+        "BuildTableIndexInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let ctx = self.ctx.clone();
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "can't build bloom filter index on {}.{} of engine {}, only FUSE tables are supported",
+                plan.database,
+                plan.table,
+                table.engine()
+            ))
+        })?;
+
+        let mut pipeline = Pipeline::create();
+        let progress = match fuse_table
+            .do_build_bloom_index(ctx.clone(), &plan.database, &mut pipeline)
+            .await?
+        {
+            Some(progress) => progress,
+            None => return Ok(PipelineBuildResult::create()),
+        };
+
+        let result = (|| -> Result<()> {
+            let settings = ctx.get_settings();
+            pipeline.set_max_threads(settings.get_max_threads()? as usize);
+            let query_id = ctx.get_id();
+            let executor_settings = ExecutorSettings::try_create(&settings, query_id)?;
+            let executor = PipelineCompleteExecutor::try_create(pipeline, executor_settings)?;
+
+            ctx.set_executor(Arc::downgrade(&executor.get_inner()));
+            executor.execute()
+        })();
+        progress.finish(result.as_ref().err());
+        result?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -77,6 +77,22 @@ impl Interpreter for CreateTableInterpreter {
                 quota.max_tables_per_database
             )));
         };
+        let db = catalog
+            .get_database(&self.plan.tenant, &self.plan.database)
+            .await?;
+        let db_quota = self
+            .ctx
+            .get_database_quotas(&[db.get_db_info().ident.db_id])
+            .await?
+            .remove(0);
+        if db_quota.max_tables != 0 && tables.len() as u64 >= db_quota.max_tables {
+            return Err(ErrorCode::DatabaseQuotaExceeded(format!(
+                "Max tables quota of database '{}' exceeded: {} >= {}",
+                self.plan.database,
+                tables.len(),
+                db_quota.max_tables
+            )));
+        };
         let name_not_duplicate = tables
             .iter()
             .all(|table| table.name() != self.plan.table.as_str());
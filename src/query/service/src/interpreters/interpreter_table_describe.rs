@@ -14,14 +14,18 @@
 
 use std::sync::Arc;
 
+use common_catalog::table::Table;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::infer_table_schema;
 use common_expression::types::StringType;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
+use common_expression::FieldIndex;
 use common_expression::FromData;
 use common_expression::Scalar;
+use common_expression::TableField;
+use common_meta_app::principal::UserPrivilegeType;
 use common_sql::plans::DescribeTablePlan;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
@@ -41,28 +45,25 @@ impl DescribeTableInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: DescribeTablePlan) -> Result<Self> {
         Ok(DescribeTableInterpreter { ctx, plan })
     }
-}
 
-#[async_trait::async_trait]
-impl Interpreter for DescribeTableInterpreter {
-    fn name(&self) -> &str {
-        "DescribeTableInterpreter"
-    }
-
-    fn schema(&self) -> DataSchemaRef {
-        self.plan.schema()
-    }
-
-    async fn execute2(&self) -> Result<PipelineBuildResult> {
-        let catalog = self.plan.catalog.as_str();
-        let database = self.plan.database.as_str();
-        let table = self.plan.table.as_str();
-        let table = self.ctx.get_table(catalog, database, table).await?;
+    /// Returns the table and its fields granted to the current user, along with the
+    /// index of each field in the schema. The schema of a view is inferred from its query.
+    pub(crate) async fn describe_fields(
+        ctx: &Arc<QueryContext>,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<(Arc<dyn Table>, Vec<(FieldIndex, TableField)>)> {
+        let granted_columns = ctx
+            .get_current_session()
+            .get_granted_columns(catalog, database, table, UserPrivilegeType::Select)
+            .await?;
+        let table = ctx.get_table(catalog, database, table).await?;
         let tbl_info = table.get_table_info();
 
         let schema = if tbl_info.engine() == VIEW_ENGINE {
             if let Some(query) = tbl_info.options().get(QUERY) {
-                let mut planner = Planner::new(self.ctx.clone());
+                let mut planner = Planner::new(ctx.clone());
                 let (plan, _) = planner.plan_sql(query).await?;
                 infer_table_schema(&plan.schema())
             } else {
@@ -74,32 +75,62 @@ impl Interpreter for DescribeTableInterpreter {
             Ok(table.schema())
         }?;
 
+        let fields = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| match &granted_columns {
+                Some(granted_columns) => granted_columns.contains(field.name()),
+                None => true,
+            })
+            .map(|(index, field)| (index, field.clone()))
+            .collect();
+        Ok((table, fields))
+    }
+
+    /// Returns the type, the nullability and the default value of the field.
+    pub(crate) fn describe_field(field: &TableField) -> [String; 3] {
+        let non_null_type = field.data_type().remove_recursive_nullable();
+        let null = if field.is_nullable() { "YES" } else { "NO" };
+        let default_expr = match field.default_expr() {
+            Some(expr) => expr.clone(),
+            None => Scalar::default_value(&field.data_type().into()).to_string(),
+        };
+        [non_null_type.sql_name(), null.to_string(), default_expr]
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescribeTableInterpreter {
+    fn name(&self) -> &str {
+        "DescribeTableInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let (_, fields) = Self::describe_fields(
+            &self.ctx,
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.table,
+        )
+        .await?;
+
         let mut names: Vec<Vec<u8>> = vec![];
         let mut types: Vec<Vec<u8>> = vec![];
         let mut nulls: Vec<Vec<u8>> = vec![];
         let mut default_exprs: Vec<Vec<u8>> = vec![];
         let mut extras: Vec<Vec<u8>> = vec![];
 
-        for field in schema.fields().iter() {
+        for (_, field) in fields.iter() {
+            let [data_type, null, default_expr] = Self::describe_field(field);
             names.push(field.name().to_string().as_bytes().to_vec());
-
-            let non_null_type = field.data_type().remove_recursive_nullable();
-            types.push(non_null_type.sql_name().as_bytes().to_vec());
-            nulls.push(if field.is_nullable() {
-                "YES".to_string().as_bytes().to_vec()
-            } else {
-                "NO".to_string().as_bytes().to_vec()
-            });
-            match field.default_expr() {
-                Some(expr) => {
-                    default_exprs.push(expr.as_bytes().to_vec());
-                }
-
-                None => {
-                    let value = Scalar::default_value(&field.data_type().into());
-                    default_exprs.push(value.to_string().as_bytes().to_vec());
-                }
-            }
+            types.push(data_type.as_bytes().to_vec());
+            nulls.push(null.as_bytes().to_vec());
+            default_exprs.push(default_expr.as_bytes().to_vec());
             extras.push("".to_string().as_bytes().to_vec());
         }
 
@@ -19,6 +19,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::DropTableByIdReq;
 use common_sql::plans::DropTablePlan;
+use common_storages_share::save_share_spec;
 use common_storages_view::view_table::VIEW_ENGINE;
 
 use crate::interpreters::Interpreter;
@@ -62,15 +63,27 @@ impl Interpreter for DropTableInterpreter {
             }
             let catalog = self.ctx.get_catalog(catalog_name)?;
 
-            catalog
+            let resp = catalog
                 .drop_table_by_id(DropTableByIdReq {
                     if_exists: self.plan.if_exists,
                     tb_id: tbl.get_table_info().ident.table_id,
                 })
                 .await?;
 
-            // if `plan.all`, truncate, then purge the historical data
-            if self.plan.all {
+            // the dropped table is no longer accessible through the shares it is granted to.
+            if let Some(spec_vec) = resp.spec_vec {
+                save_share_spec(
+                    &self.ctx.get_tenant(),
+                    self.ctx.get_data_operator()?.operator(),
+                    Some(spec_vec),
+                    None,
+                )
+                .await?;
+            }
+
+            // if `plan.all`, truncate, then purge the historical data, except for the read-only
+            // tables, like the attached ones, whose data is shared with other tables.
+            if self.plan.all && !tbl.is_read_only() {
                 let purge = true;
                 // the above `catalog.drop_table` operation changed the table meta version,
                 // thus if we do not refresh the table instance, `truncate` will fail
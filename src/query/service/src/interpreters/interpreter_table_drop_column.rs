@@ -69,6 +69,12 @@ impl Interpreter for DropTableColumnInterpreter {
                     &self.plan.database, &self.plan.table
                 )));
             }
+            if table.is_read_only() {
+                return Err(ErrorCode::TableNotWritable(format!(
+                    "{}.{} is read-only that doesn't support alter",
+                    &self.plan.database, &self.plan.table
+                )));
+            }
 
             let catalog = self.ctx.get_catalog(catalog_name)?;
             let mut new_table_meta = table.get_table_info().meta.clone();
@@ -0,0 +1,95 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::DropTableRowAccessPolicyPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::interpreter_table_add_row_access_policy::check_row_access_policy_supported;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTableRowAccessPolicyPlan,
+}
+
+impl DropTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(DropTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "DropTableRowAccessPolicyInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+        check_row_access_policy_supported(table.as_ref(), &plan.database, &plan.table)?;
+
+        let table_info = table.get_table_info();
+        let mut new_table_meta = table_info.meta.clone();
+        if new_table_meta
+            .row_access_policies
+            .remove(&plan.policy)
+            .is_none()
+        {
+            return Err(ErrorCode::UnknownRowAccessPolicy(format!(
+                "row access policy {} is not added to table {}.{}",
+                plan.policy, plan.database, plan.table
+            )));
+        }
+
+        let table_id = table_info.ident.table_id;
+        let user_mgr = UserApiProvider::instance();
+        match user_mgr
+            .get_row_access_policy(&plan.tenant, &plan.policy)
+            .await
+        {
+            Ok(mut policy) => {
+                if policy.referenced_tables.remove(&table_id) {
+                    user_mgr
+                        .update_row_access_policy(&plan.tenant, policy)
+                        .await?;
+                }
+            }
+            Err(e) if e.code() == ErrorCode::UNKNOWN_ROW_ACCESS_POLICY => {}
+            Err(e) => return Err(e),
+        }
+
+        let req = UpdateTableMetaReq {
+            table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+        };
+        catalog.update_table_meta(table_info, req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_sql::plans::ExportTableSnapshotPlan;
+use common_storage::init_operator;
+use common_storage::stage_path_storage_params;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Exports the current snapshot of a fuse table to a stage, to be imported by another cluster.
+///
+/// All the files of the snapshot are copied to the stage with a manifest of them, the table
+/// can be created from it by `IMPORT SNAPSHOT TABLE` without copying them again.
+pub struct ExportTableSnapshotInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ExportTableSnapshotPlan,
+}
+
+impl ExportTableSnapshotInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ExportTableSnapshotPlan) -> Result<Self> {
+        Ok(ExportTableSnapshotInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ExportTableSnapshotInterpreter {
+    fn name(&self) -> &str {
+        "ExportTableSnapshotInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let start = Instant::now();
+
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "can't export snapshot of {}.{} of engine {}, only FUSE tables are supported",
+                plan.database,
+                plan.table,
+                table.engine()
+            ))
+        })?;
+
+        let target = init_operator(&stage_path_storage_params(&plan.stage_info, &plan.path))?;
+        let ctx: Arc<dyn TableContext> = self.ctx.clone();
+        let manifest = fuse_table.do_export_snapshot(&ctx, target).await?;
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            UInt64Type::from_data(vec![manifest.files.len() as u64]),
+            UInt64Type::from_data(vec![manifest.total_bytes()]),
+            UInt64Type::from_data(vec![start.elapsed().as_millis() as u64]),
+        ])])
+    }
+}
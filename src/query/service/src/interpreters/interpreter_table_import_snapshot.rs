@@ -0,0 +1,139 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_ast::ast::Engine;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_meta_app::schema::TableStatistics;
+use common_sql::plans::ImportTableSnapshotPlan;
+use common_storage::init_operator;
+use common_storage::stage_path_storage_params;
+use common_storages_fuse::operations::SnapshotManifest;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Creates a table from the snapshot exported to a stage by `EXPORT SNAPSHOT TABLE`.
+///
+/// Nothing is copied, the storage of the table is the folder of the stage the snapshot is
+/// exported to, and the data written to the table later are kept there too.
+pub struct ImportTableSnapshotInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ImportTableSnapshotPlan,
+}
+
+impl ImportTableSnapshotInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ImportTableSnapshotPlan) -> Result<Self> {
+        Ok(ImportTableSnapshotInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ImportTableSnapshotInterpreter {
+    fn name(&self) -> &str {
+        "ImportTableSnapshotInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let start = Instant::now();
+
+        let storage_params = stage_path_storage_params(&plan.stage_info, &plan.path);
+        let operator = init_operator(&storage_params)?;
+        let manifest = SnapshotManifest::read(&operator).await?;
+        let ctx: Arc<dyn TableContext> = self.ctx.clone();
+        manifest.check_files(&ctx, &operator).await?;
+
+        let mut options = plan.options.clone();
+        options.extend(manifest.options.clone());
+        options.insert(
+            OPT_KEY_STORAGE_PREFIX.to_string(),
+            manifest.storage_prefix.clone(),
+        );
+        options.insert(
+            OPT_KEY_SNAPSHOT_LOCATION.to_string(),
+            manifest.snapshot_location.clone(),
+        );
+        let mut table_meta = TableMeta {
+            schema: Arc::new(manifest.schema.clone()),
+            engine: Engine::Fuse.to_string(),
+            storage_params: Some(storage_params),
+            options,
+            ..Default::default()
+        };
+
+        // The statistics of the table are the ones summarized in the snapshot, as if the
+        // snapshot was committed to the table.
+        let fuse_table = FuseTable::do_create(TableInfo {
+            desc: format!("'{}'.'{}'", plan.database, plan.table),
+            name: plan.table.clone(),
+            meta: table_meta.clone(),
+            ..Default::default()
+        })?;
+        let snapshot = fuse_table.read_table_snapshot().await?.ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "the snapshot {} of the manifest is missing",
+                manifest.snapshot_location
+            ))
+        })?;
+        let summary = &snapshot.summary;
+        table_meta.statistics = TableStatistics {
+            number_of_rows: summary.row_count,
+            data_bytes: summary.uncompressed_byte_size,
+            compressed_data_bytes: summary.compressed_byte_size,
+            index_data_bytes: summary.index_size,
+        };
+
+        let req = CreateTableReq {
+            if_not_exists: false,
+            name_ident: TableNameIdent {
+                tenant: plan.tenant.clone(),
+                db_name: plan.database.clone(),
+                table_name: plan.table.clone(),
+            },
+            table_meta,
+        };
+        self.ctx
+            .get_catalog(&plan.catalog)?
+            .create_table(req)
+            .await?;
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            UInt64Type::from_data(vec![manifest.files.len() as u64]),
+            UInt64Type::from_data(vec![manifest.total_bytes()]),
+            UInt64Type::from_data(vec![start.elapsed().as_millis() as u64]),
+        ])])
+    }
+}
@@ -0,0 +1,377 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::TableDataType;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::ModifyColumnAction;
+use common_sql::plans::ModifyTableColumnPlan;
+use common_sql::plans::Plan;
+use common_sql::Planner;
+use common_storages_fuse::FuseTable;
+use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
+use storages_common_index::filters::Xor8Filter;
+use storages_common_index::Index;
+
+use crate::interpreters::common::append2table;
+use crate::interpreters::Interpreter;
+use crate::interpreters::SelectInterpreter;
+use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct ModifyTableColumnInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ModifyTableColumnPlan,
+}
+
+impl ModifyTableColumnInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ModifyTableColumnPlan) -> Result<Self> {
+        Ok(ModifyTableColumnInterpreter { ctx, plan })
+    }
+}
+
+impl ModifyTableColumnInterpreter {
+    /// Change the type of a column of a non-empty table.
+    ///
+    /// Widening conversions only change the table meta: the column gets a new id and
+    /// remembers the previous one, blocks written before are cast when read. Other
+    /// conversions rewrite the whole table with the `USING` expression.
+    async fn modify_column_type(
+        &self,
+        mut table: Arc<dyn Table>,
+        data_type: &TableDataType,
+        using: Option<&str>,
+        cascade: bool,
+    ) -> Result<PipelineBuildResult> {
+        let column = self.plan.column.as_str();
+        let schema = table.schema();
+        let old_type = DataType::from(schema.field_with_name(column)?.data_type());
+        let new_type = DataType::from(data_type);
+
+        let in_cluster_key = table.cluster_keys(self.ctx.clone()).iter().any(|key| {
+            key.as_expr(&BUILTIN_FUNCTIONS)
+                .column_refs()
+                .contains_key(column)
+        });
+        if in_cluster_key && !cascade {
+            return Err(ErrorCode::BadArguments(format!(
+                "column {} is referenced by the cluster key of table {}.{}, use CASCADE to drop the cluster key",
+                column, &self.plan.database, &self.plan.table
+            )));
+        }
+        if Xor8Filter::supported_type(&old_type)
+            && !Xor8Filter::supported_type(&new_type)
+            && !cascade
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "column {} has bloom filter index which is not supported by type {}, use CASCADE to drop it",
+                column, new_type
+            )));
+        }
+        if in_cluster_key {
+            table.drop_table_cluster_keys(self.ctx.clone()).await?;
+            table = table.refresh(self.ctx.as_ref()).await?;
+        }
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let table_info = table.get_table_info();
+        let mut new_table_meta = table_info.meta.clone();
+
+        let using = match using {
+            Some(using) => using,
+            None => {
+                let cast_on_read = FuseTable::try_from_table(table.as_ref())
+                    .map(|fuse_table| !fuse_table.is_native())
+                    .unwrap_or(false);
+                if !cast_on_read || !is_widening_cast(&old_type, &new_type) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "can not change column {} from {} to {} without rewriting the data, add USING <expr> to convert the existing rows",
+                        column, old_type, new_type
+                    )));
+                }
+                new_table_meta.modify_column_with_origin(column, data_type.clone())?;
+                let req = UpdateTableMetaReq {
+                    table_id: table_info.ident.table_id,
+                    seq: MatchSeq::Exact(table_info.ident.seq),
+                    new_table_meta,
+                    copied_files: None,
+                };
+                catalog.update_table_meta(table_info, req).await?;
+                return Ok(PipelineBuildResult::create());
+            }
+        };
+
+        // Rewrite the table: select the existing rows with the column replaced by the
+        // `USING` expression, cast them to the new schema and overwrite the table.
+        let select_list = schema
+            .fields()
+            .iter()
+            .map(|f| {
+                if f.name() == column {
+                    format!("({}) AS `{}`", using, column)
+                } else {
+                    format!("`{}`", f.name())
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sql = format!(
+            "SELECT {} FROM `{}`.`{}`.`{}`",
+            select_list, &self.plan.catalog, &self.plan.database, &self.plan.table
+        );
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(&sql).await?;
+        let (s_expr, metadata, bind_context, formatted_ast) = match &plan {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                formatted_ast,
+                ..
+            } => (s_expr, metadata, bind_context, formatted_ast),
+            v => unreachable!("Input plan must be Query, but it's {}", v),
+        };
+        let select_interpreter = SelectInterpreter::try_create(
+            self.ctx.clone(),
+            *(bind_context.clone()),
+            *s_expr.clone(),
+            metadata.clone(),
+            formatted_ast.clone(),
+            false,
+        )?;
+        let mut build_res = select_interpreter.execute2().await?;
+
+        new_table_meta.modify_column(column, data_type.clone())?;
+        let new_table_info = TableInfo {
+            meta: new_table_meta,
+            ..table_info.clone()
+        };
+        let new_table = catalog.get_table_by_info(&new_table_info)?;
+
+        let select_schema = plan.schema();
+        let target_schema: DataSchemaRef = Arc::new(DataSchema::from(new_table.schema().as_ref()));
+        let func_ctx = self.ctx.get_function_context()?;
+        let cast_schema = target_schema.clone();
+        build_res
+            .main_pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformCastSchema::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    select_schema.clone(),
+                    cast_schema.clone(),
+                    func_ctx,
+                )
+            })?;
+
+        append2table(
+            self.ctx.clone(),
+            new_table,
+            target_schema,
+            &mut build_res,
+            true,
+            true,
+            AppendMode::Normal,
+        )?;
+        Ok(build_res)
+    }
+}
+
+/// Whether the values of `from` can always be cast to `to` without loss, so that the
+/// existing data may be kept and cast when read.
+fn is_widening_cast(from: &DataType, to: &DataType) -> bool {
+    if from.is_nullable() && !to.is_nullable() {
+        return false;
+    }
+    let from = from.remove_nullable();
+    let to = to.remove_nullable();
+    // Old values of nested columns are spread over several leaf columns, they are not kept.
+    if matches!(
+        from,
+        DataType::Tuple(_) | DataType::Array(_) | DataType::Map(_)
+    ) {
+        return false;
+    }
+    if from == to {
+        return true;
+    }
+    // Casts from strings parse the values, and UInt64 does not fit into Int64.
+    if matches!(from, DataType::String)
+        || (from == DataType::Number(NumberDataType::UInt64)
+            && to == DataType::Number(NumberDataType::Int64))
+    {
+        return false;
+    }
+    BUILTIN_FUNCTIONS
+        .default_cast_rules
+        .iter()
+        .any(|(src, dest)| src == &from && dest == &to)
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ModifyTableColumnInterpreter {
+    fn name(&self) -> &str {
+        "ModifyTableColumnInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+        let tbl = self
+            .ctx
+            .get_catalog(catalog_name)?
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await
+            .ok();
+
+        if let Some(table) = &tbl {
+            let table_info = table.get_table_info();
+            if table_info.engine() == VIEW_ENGINE {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{}.{} engine is VIEW that doesn't support alter",
+                    &self.plan.database, &self.plan.table
+                )));
+            }
+            if table_info.db_type != DatabaseType::NormalDB {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{}.{} doesn't support alter",
+                    &self.plan.database, &self.plan.table
+                )));
+            }
+            if table.is_read_only() {
+                return Err(ErrorCode::TableNotWritable(format!(
+                    "{}.{} is read-only that doesn't support alter",
+                    &self.plan.database, &self.plan.table
+                )));
+            }
+
+            let schema = table.schema();
+            let field = schema.field_with_name(&self.plan.column)?;
+            let tenant = self.ctx.get_tenant();
+            let user_mgr = UserApiProvider::instance();
+            let table_id = table_info.ident.table_id;
+            let mut new_table_meta = table_info.meta.clone();
+            let current_policy = new_table_meta
+                .column_mask_policy
+                .get(&self.plan.column)
+                .cloned();
+
+            match &self.plan.action {
+                ModifyColumnAction::SetDataType {
+                    data_type,
+                    using,
+                    cascade,
+                } => {
+                    if field.data_type() == data_type && using.is_none() {
+                        return Ok(PipelineBuildResult::create());
+                    }
+                    if let Some(policy) = current_policy {
+                        return Err(ErrorCode::Unimplemented(format!(
+                            "modify column {} type is not supported while it is protected by masking policy {}",
+                            &self.plan.column, policy
+                        )));
+                    }
+                    if table_info.meta.statistics.number_of_rows > 0 {
+                        return self
+                            .modify_column_type(
+                                table.clone(),
+                                data_type,
+                                using.as_deref(),
+                                *cascade,
+                            )
+                            .await;
+                    }
+                    new_table_meta.modify_column(&self.plan.column, data_type.clone())?;
+                }
+                ModifyColumnAction::SetMaskingPolicy(name) => {
+                    if let Some(policy) = current_policy {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "column {} is already protected by masking policy {}, unset it first",
+                            &self.plan.column, policy
+                        )));
+                    }
+                    let mut policy = user_mgr.get_masking_policy(&tenant, name).await?;
+                    let column_type = DataType::from(field.data_type())
+                        .remove_nullable()
+                        .to_string();
+                    if policy.args[0].1 != column_type {
+                        return Err(ErrorCode::IllegalDataType(format!(
+                            "masking policy {} accepts {}, but column {} is of type {}",
+                            name, policy.args[0].1, &self.plan.column, column_type
+                        )));
+                    }
+                    if policy.referenced_tables.insert(table_id) {
+                        user_mgr.update_masking_policy(&tenant, policy).await?;
+                    }
+                    new_table_meta
+                        .column_mask_policy
+                        .insert(self.plan.column.clone(), name.clone());
+                }
+                ModifyColumnAction::UnsetMaskingPolicy => {
+                    let name = match new_table_meta.column_mask_policy.remove(&self.plan.column) {
+                        Some(name) => name,
+                        None => return Ok(PipelineBuildResult::create()),
+                    };
+                    // Keep the reference as long as another column of the table uses the policy.
+                    if !new_table_meta
+                        .column_mask_policy
+                        .values()
+                        .any(|p| p == &name)
+                    {
+                        match user_mgr.get_masking_policy(&tenant, &name).await {
+                            Ok(mut policy) => {
+                                if policy.referenced_tables.remove(&table_id) {
+                                    user_mgr.update_masking_policy(&tenant, policy).await?;
+                                }
+                            }
+                            Err(e) if e.code() == ErrorCode::UNKNOWN_MASKING_POLICY => {}
+                            Err(e) => return Err(e),
+                        }
+                    }
+                }
+            }
+
+            let catalog = self.ctx.get_catalog(catalog_name)?;
+            let table_version = table_info.ident.seq;
+
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+                copied_files: None,
+            };
+
+            catalog.update_table_meta(table_info, req).await?;
+        };
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -52,12 +52,15 @@ impl Interpreter for ReclusterTableInterpreter {
         let tenant = ctx.get_tenant();
         let start = SystemTime::now();
 
-        // Build extras via push down scalar
-        let extras = if let Some(scalar) = &plan.push_downs {
-            let filter = scalar.as_expr_with_col_name()?.as_remote_expr();
-
+        // Build extras via push down scalar and limit
+        let filter = match &plan.push_downs {
+            Some(scalar) => Some(scalar.as_expr_with_col_name()?.as_remote_expr()),
+            None => None,
+        };
+        let extras = if filter.is_some() || plan.limit.is_some() {
             Some(PushDownInfo {
-                filter: Some(filter),
+                filter,
+                limit: plan.limit,
                 ..PushDownInfo::default()
             })
         } else {
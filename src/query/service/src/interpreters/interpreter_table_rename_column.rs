@@ -0,0 +1,150 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::binder::INTERNAL_COLUMN_FACTORY;
+use common_sql::plans::RenameTableColumnPlan;
+use common_sql::rename_column_in_cluster_key;
+use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RenameTableColumnInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RenameTableColumnPlan,
+}
+
+impl RenameTableColumnInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RenameTableColumnPlan) -> Result<Self> {
+        Ok(RenameTableColumnInterpreter { ctx, plan })
+    }
+
+    /// The column grants are kept by the column name, they would not apply to the renamed
+    /// column, so renaming a column is rejected until its grants are revoked.
+    async fn check_column_grants(&self) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let object = GrantObject::Column(
+            self.plan.catalog.clone(),
+            self.plan.database.clone(),
+            self.plan.table.clone(),
+            self.plan.old_column.clone(),
+        );
+        let user_api = UserApiProvider::instance();
+        let users = user_api.get_users(&tenant).await?;
+        let roles = user_api.get_roles(&tenant).await?;
+        let granted = users
+            .iter()
+            .map(|user| &user.grants)
+            .chain(roles.iter().map(|role| &role.grants))
+            .any(|grants| {
+                grants
+                    .entries()
+                    .iter()
+                    .any(|entry| entry.matches_entry(&object))
+            });
+        if granted {
+            return Err(ErrorCode::ColumnGrantExists(format!(
+                "Cannot rename column {} of {}.{}, privileges are granted on it, revoke them first",
+                &self.plan.old_column, &self.plan.database, &self.plan.table
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RenameTableColumnInterpreter {
+    fn name(&self) -> &str {
+        "RenameTableColumnInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+        let tbl = self
+            .ctx
+            .get_catalog(catalog_name)?
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await
+            .ok();
+
+        if let Some(table) = &tbl {
+            let table_info = table.get_table_info();
+            if table_info.engine() == VIEW_ENGINE {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{}.{} engine is VIEW that doesn't support alter",
+                    &self.plan.database, &self.plan.table
+                )));
+            }
+            if table_info.db_type != DatabaseType::NormalDB {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{}.{} doesn't support alter",
+                    &self.plan.database, &self.plan.table
+                )));
+            }
+            if table.is_read_only() {
+                return Err(ErrorCode::TableNotWritable(format!(
+                    "{}.{} is read-only that doesn't support alter",
+                    &self.plan.database, &self.plan.table
+                )));
+            }
+
+            let catalog = self.ctx.get_catalog(catalog_name)?;
+            let mut new_table_meta = table.get_table_info().meta.clone();
+            if INTERNAL_COLUMN_FACTORY.exist(&self.plan.new_column) {
+                return Err(ErrorCode::TableWithInternalColumnName(format!(
+                    "Cannot rename table column to the same name as internal column: {}",
+                    &self.plan.new_column
+                )));
+            }
+            self.check_column_grants().await?;
+            new_table_meta.rename_column(&self.plan.old_column, &self.plan.new_column)?;
+            if let Some(cluster_key) = &new_table_meta.default_cluster_key {
+                if let Some(cluster_key) = rename_column_in_cluster_key(
+                    cluster_key,
+                    &self.plan.old_column,
+                    &self.plan.new_column,
+                )? {
+                    new_table_meta.replace_default_cluster_key(cluster_key);
+                }
+            }
+
+            let table_id = table_info.ident.table_id;
+            let table_version = table_info.ident.seq;
+
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+                copied_files: None,
+            };
+
+            catalog.update_table_meta(table_info, req).await?;
+        };
+
+        Ok(PipelineBuildResult::create())
+    }
+}
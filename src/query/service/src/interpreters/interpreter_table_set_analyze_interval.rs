@@ -0,0 +1,82 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::ExternalTableStatistics;
+use common_sql::plans::SetTableAnalyzeIntervalPlan;
+use common_users::UserApiProvider;
+
+use super::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct SetTableAnalyzeIntervalInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetTableAnalyzeIntervalPlan,
+}
+
+impl SetTableAnalyzeIntervalInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetTableAnalyzeIntervalPlan) -> Result<Self> {
+        Ok(SetTableAnalyzeIntervalInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetTableAnalyzeIntervalInterpreter {
+    fn name(&self) -> &str {
+        "SetTableAnalyzeIntervalInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        // Tables of the default catalog maintain their own statistics, or have none worth sampling.
+        if plan.catalog == CATALOG_DEFAULT {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "ANALYZE_INTERVAL is only supported by tables of external catalogs, but table {}.{} is in catalog {}",
+                plan.database, plan.table, plan.catalog
+            )));
+        }
+        // Make sure the table exists.
+        self.ctx
+            .get_table(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+
+        let user_mgr = UserApiProvider::instance();
+        let statistics = user_mgr
+            .get_table_statistics(&plan.tenant, &plan.catalog, &plan.database, &plan.table)
+            .await?;
+        let statistics = match statistics {
+            Some(statistics) => ExternalTableStatistics {
+                analyze_interval_secs: plan.interval_secs,
+                ..statistics
+            },
+            None => ExternalTableStatistics::new(
+                &plan.catalog,
+                &plan.database,
+                &plan.table,
+                plan.interval_secs,
+            ),
+        };
+        user_mgr
+            .upsert_table_statistics(&plan.tenant, statistics)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
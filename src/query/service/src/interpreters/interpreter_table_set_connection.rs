@@ -0,0 +1,74 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::SetTableConnectionPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct SetTableConnectionInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetTableConnectionPlan,
+}
+
+impl SetTableConnectionInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetTableConnectionPlan) -> Result<Self> {
+        Ok(SetTableConnectionInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetTableConnectionInterpreter {
+    fn name(&self) -> &str {
+        "SetTableConnectionInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+
+        let table_info = table.get_table_info();
+        if table_info.meta.storage_params.is_none() {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "table {}.{} is not created with its own location, there is no connection to alter",
+                plan.database, plan.table
+            )));
+        }
+
+        // The operators are cached by their params, the new connection is used by the next
+        // query that reads the table.
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.storage_params = Some(plan.storage_params.clone());
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+        };
+        catalog.update_table_meta(table_info, req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
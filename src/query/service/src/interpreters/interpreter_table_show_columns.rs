@@ -0,0 +1,236 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_expression::infer_table_schema;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt64Type;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_sql::filter_block_by_sql;
+use common_sql::plans::ShowColumnsPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
+
+use crate::interpreters::DescribeTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+const CHARACTER_SET: &str = "utf8mb4";
+
+/// `SHOW COLUMNS` extends the output of `DESCRIBE TABLE` with the comments, masking
+/// policies and statistics of the columns.
+pub struct ShowColumnsInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowColumnsPlan,
+}
+
+/// Statistics of a column, collected by `ANALYZE TABLE` for fuse tables or by the
+/// analyze daemon for tables of external catalogs.
+struct ColumnStats {
+    ndv: Option<u64>,
+    null_count: Option<u64>,
+    data_size: Option<u64>,
+}
+
+impl ShowColumnsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowColumnsPlan) -> Result<Self> {
+        Ok(ShowColumnsInterpreter { ctx, plan })
+    }
+
+    async fn column_stats(
+        &self,
+        table: &Arc<dyn Table>,
+    ) -> Result<(HashMap<ColumnId, ColumnStats>, Option<DateTime<Utc>>)> {
+        let mut stats: HashMap<ColumnId, ColumnStats> = HashMap::new();
+        let leaf_ids = table.schema().to_leaf_column_ids();
+
+        if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+            let Some(snapshot) = fuse_table.read_table_snapshot().await? else {
+                return Ok((stats, None));
+            };
+            let provider = table.column_statistics_provider().await?;
+            for id in leaf_ids {
+                let Some(col_stats) = provider.column_statistics(id) else {
+                    continue;
+                };
+                stats.insert(id, ColumnStats {
+                    ndv: Some(col_stats.number_of_distinct_values),
+                    null_count: Some(col_stats.null_count),
+                    data_size: snapshot
+                        .summary
+                        .col_stats
+                        .get(&id)
+                        .map(|s| s.in_memory_size),
+                });
+            }
+            let analyzed_on = fuse_table
+                .read_table_snapshot_statistics(Some(&snapshot))
+                .await?
+                .and_then(|s| s.analyzed_on);
+            return Ok((stats, analyzed_on));
+        }
+
+        if self.plan.catalog == CATALOG_DEFAULT {
+            return Ok((stats, None));
+        }
+        let Some(external) = UserApiProvider::instance()
+            .get_table_statistics(
+                &self.ctx.get_tenant(),
+                &self.plan.catalog,
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?
+        else {
+            return Ok((stats, None));
+        };
+        // The external statistics are keyed by the leaf index instead of the column id.
+        for (leaf_index, id) in leaf_ids.into_iter().enumerate() {
+            let Some(col_stats) = external.column_statistics.get(&(leaf_index as u32)) else {
+                continue;
+            };
+            stats.insert(id, ColumnStats {
+                ndv: Some(col_stats.number_of_distinct_values),
+                null_count: Some(col_stats.null_count),
+                data_size: None,
+            });
+        }
+        Ok((stats, external.updated_on))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowColumnsInterpreter {
+    fn name(&self) -> &str {
+        "ShowColumnsInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let (table, fields) = DescribeTableInterpreter::describe_fields(
+            &self.ctx,
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.table,
+        )
+        .await?;
+        let is_view = table.engine() == VIEW_ENGINE;
+        let meta = &table.get_table_info().meta;
+        let (stats, analyzed_on) = if is_view {
+            (HashMap::new(), None)
+        } else {
+            self.column_stats(&table).await?
+        };
+
+        let mut names: Vec<Vec<u8>> = vec![];
+        let mut types: Vec<Vec<u8>> = vec![];
+        let mut nulls: Vec<Vec<u8>> = vec![];
+        let mut default_exprs: Vec<Vec<u8>> = vec![];
+        let mut extras: Vec<Vec<u8>> = vec![];
+        let mut comments: Vec<Vec<u8>> = vec![];
+        let mut masking_policies: Vec<Option<Vec<u8>>> = vec![];
+        let mut ndvs: Vec<Option<u64>> = vec![];
+        let mut null_counts: Vec<Option<u64>> = vec![];
+        let mut data_sizes: Vec<Option<u64>> = vec![];
+        let mut analyzed_ons: Vec<Option<i64>> = vec![];
+        let mut positions: Vec<u64> = vec![];
+        let mut character_sets: Vec<Option<Vec<u8>>> = vec![];
+
+        for (index, field) in fields.iter() {
+            let [data_type, null, default_expr] = DescribeTableInterpreter::describe_field(field);
+            names.push(field.name().as_bytes().to_vec());
+            types.push(data_type.as_bytes().to_vec());
+            nulls.push(null.as_bytes().to_vec());
+            default_exprs.push(default_expr.as_bytes().to_vec());
+            extras.push(vec![]);
+
+            let comment = match is_view {
+                true => None,
+                false => meta.field_comments.get(*index),
+            };
+            comments.push(comment.map_or(vec![], |c| c.as_bytes().to_vec()));
+            masking_policies.push(
+                meta.column_mask_policy
+                    .get(field.name())
+                    .map(|policy| policy.as_bytes().to_vec()),
+            );
+
+            // Statistics are only kept for the leaf columns, not for the nested ones.
+            let column_stats = leaf_column_id(field).and_then(|id| stats.get(&id));
+            ndvs.push(column_stats.and_then(|s| s.ndv));
+            null_counts.push(column_stats.and_then(|s| s.null_count));
+            data_sizes.push(column_stats.and_then(|s| s.data_size));
+            analyzed_ons.push(analyzed_on.map(|t| t.timestamp_micros()));
+
+            positions.push(*index as u64 + 1);
+            character_sets.push(match field.data_type().remove_nullable() {
+                TableDataType::String => Some(CHARACTER_SET.as_bytes().to_vec()),
+                _ => None,
+            });
+        }
+
+        let mut columns = vec![
+            StringType::from_data(names),
+            StringType::from_data(types),
+            StringType::from_data(nulls),
+            StringType::from_data(default_exprs),
+            StringType::from_data(extras),
+            StringType::from_data(comments),
+            StringType::from_opt_data(masking_policies),
+            UInt64Type::from_opt_data(ndvs),
+            UInt64Type::from_opt_data(null_counts),
+            UInt64Type::from_opt_data(data_sizes),
+            TimestampType::from_opt_data(analyzed_ons),
+        ];
+        if self.plan.full {
+            columns.push(UInt64Type::from_data(positions));
+            columns.push(StringType::from_opt_data(character_sets));
+        }
+        let mut block = DataBlock::new_from_columns(columns);
+
+        if let Some(filter) = &self.plan.filter {
+            let schema = infer_table_schema(&self.plan.schema())?;
+            block = filter_block_by_sql(self.ctx.clone(), schema, block, filter)?;
+        }
+
+        PipelineBuildResult::from_blocks(vec![block])
+    }
+}
+
+fn leaf_column_id(field: &TableField) -> Option<ColumnId> {
+    match field.leaf_column_ids().as_slice() {
+        [id] => Some(*id),
+        _ => None,
+    }
+}
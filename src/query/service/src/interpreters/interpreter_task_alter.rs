@@ -0,0 +1,73 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_ast::ast::AlterTaskAction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::TaskState;
+use common_sql::plans::AlterTaskPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTaskPlan,
+}
+
+impl AlterTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterTaskPlan) -> Result<Self> {
+        Ok(AlterTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTaskInterpreter {
+    fn name(&self) -> &str {
+        "AlterTaskInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let user_mgr = UserApiProvider::instance();
+        let task = match user_mgr.get_task(&plan.tenant, &plan.name).await {
+            Ok(task) => task,
+            Err(cause) if plan.if_exists && cause.code() == ErrorCode::UNKNOWN_TASK => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(cause) => return Err(cause),
+        };
+
+        let mut task_info = task.data;
+        task_info.state = match plan.action {
+            AlterTaskAction::Suspend => TaskState::Suspended,
+            AlterTaskAction::Resume => TaskState::Started,
+        };
+        // A resumed task is scheduled from now on, the runs missed while suspended are skipped.
+        task_info.updated_on = Utc::now();
+        user_mgr
+            .update_task(&plan.tenant, task_info, task.seq)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
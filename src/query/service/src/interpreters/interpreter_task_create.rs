@@ -0,0 +1,71 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::Result;
+use common_meta_app::principal::TaskInfo;
+use common_meta_app::principal::TaskState;
+use common_sql::plans::CreateTaskPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateTaskPlan,
+}
+
+impl CreateTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTaskPlan) -> Result<Self> {
+        Ok(CreateTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTaskInterpreter {
+    fn name(&self) -> &str {
+        "CreateTaskInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        // The task runs as the user who created it.
+        let owner = self.ctx.get_current_user()?.identity();
+        let now = Utc::now();
+        let task = TaskInfo {
+            name: plan.name,
+            owner,
+            database: plan.database,
+            schedule: plan.schedule,
+            query_text: plan.query_text,
+            state: TaskState::Started,
+            comment: plan.comment,
+            created_on: now,
+            updated_on: now,
+            last_scheduled_on: None,
+        };
+        UserApiProvider::instance()
+            .add_task(&plan.tenant, task, plan.if_not_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
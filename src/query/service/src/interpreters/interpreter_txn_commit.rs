@@ -0,0 +1,139 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnTable;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use tracing::error;
+use tracing::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+/// Commits the transaction by writing the buffered table metas to the meta service.
+///
+/// The tables are committed one by one, a table conflicts if it has been changed by others
+/// since it's first mutated in the transaction. The committed tables are rolled back if any
+/// of the commits fails. `COMMIT` without a transaction in progress is a no-op.
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+
+    async fn commit(&self, tables: Vec<TxnTable>) -> Result<()> {
+        let mut committed: Vec<TxnTable> = Vec::with_capacity(tables.len());
+        for table in tables {
+            if let Err(cause) = self.commit_table(&table).await {
+                for table in committed.into_iter().rev() {
+                    if let Err(rollback_cause) = self.rollback_table(&table).await {
+                        error!(
+                            "failed to roll back table {} after transaction commit failed: {:?}",
+                            table.base.desc, rollback_cause
+                        );
+                    }
+                }
+                return Err(cause);
+            }
+            committed.push(table);
+        }
+        Ok(())
+    }
+
+    async fn commit_table(&self, table: &TxnTable) -> Result<()> {
+        let table_info = &table.base;
+        let catalog = self.ctx.get_catalog(table_info.catalog())?;
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta: table.meta.clone(),
+            copied_files: None,
+        };
+        match catalog.update_table_meta(table_info, req).await {
+            Ok(_) => {
+                // The tables attached to this table read its data by the hint of the last snapshot.
+                if let Some(location) = table.meta.options.get(OPT_KEY_SNAPSHOT_LOCATION) {
+                    let fuse_table = FuseTable::do_create(table.table_info())?;
+                    FuseTable::write_last_snapshot_hint(
+                        fuse_table.get_operator_ref(),
+                        fuse_table.meta_location_generator(),
+                        location.clone(),
+                    )
+                    .await;
+                }
+                Ok(())
+            }
+            Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => {
+                Err(ErrorCode::TransactionConflict(format!(
+                    "table {} has been changed since it was mutated in the transaction, transaction rolled back",
+                    table_info.desc
+                )))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Restores the committed table to the meta before the transaction, unless the table
+    /// has been changed again since the commit.
+    async fn rollback_table(&self, table: &TxnTable) -> Result<()> {
+        let table_info = &table.base;
+        let catalog = self.ctx.get_catalog(table_info.catalog())?;
+        let (ident, meta) = catalog
+            .get_table_meta_by_id(table_info.ident.table_id)
+            .await?;
+        if meta.as_ref() != &table.meta {
+            return Err(ErrorCode::TransactionConflict(format!(
+                "table {} has been changed since the transaction committed it",
+                table_info.desc
+            )));
+        }
+        let req = UpdateTableMetaReq {
+            table_id: ident.table_id,
+            seq: MatchSeq::Exact(ident.seq),
+            new_table_meta: table_info.meta.clone(),
+            copied_files: None,
+        };
+        catalog.update_table_meta(table_info, req).await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let state = self.ctx.get_txn_manager().lock().end();
+        if let Some(state) = state {
+            let tables = state.tables.into_values().collect::<Vec<_>>();
+            let num_tables = tables.len();
+            self.commit(tables).await?;
+            info!("transaction committed, {} tables mutated", num_tables);
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -89,6 +89,9 @@ impl Interpreter for UnSettingInterpreter {
                 is_globals.push(false);
             }
         }
+        for var in plan.user_vars {
+            self.ctx.unset_variable(&var);
+        }
         self.ctx.set_affect(QueryAffect::ChangeSettings {
             keys,
             values,
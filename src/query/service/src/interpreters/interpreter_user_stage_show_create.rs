@@ -0,0 +1,191 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_io::escape_string;
+use common_meta_app::principal::CopyOptions;
+use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::StageType;
+use common_meta_app::storage::StorageParams;
+use common_meta_app::storage::STORAGE_GCS_DEFAULT_ENDPOINT;
+use common_meta_app::storage::STORAGE_IPFS_DEFAULT_ENDPOINT;
+use common_meta_app::storage::STORAGE_S3_DEFAULT_ENDPOINT;
+use common_sql::plans::ShowCreateStagePlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+/// The credentials are never shown, only whether they are set.
+const MASKED_CREDENTIAL: &str = "****";
+
+pub struct ShowCreateStageInterpreter {
+    plan: ShowCreateStagePlan,
+}
+
+impl ShowCreateStageInterpreter {
+    pub fn try_create(_ctx: Arc<QueryContext>, plan: ShowCreateStagePlan) -> Result<Self> {
+        Ok(ShowCreateStageInterpreter { plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowCreateStageInterpreter {
+    fn name(&self) -> &str {
+        "ShowCreateStageInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let stage = &self.plan.stage_info;
+        let info = show_create_stage(stage);
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new(
+            vec![
+                BlockEntry {
+                    data_type: DataType::String,
+                    value: Value::Scalar(Scalar::String(stage.stage_name.as_bytes().to_vec())),
+                },
+                BlockEntry {
+                    data_type: DataType::String,
+                    value: Value::Scalar(Scalar::String(info.as_bytes().to_vec())),
+                },
+            ],
+            1,
+        )])
+    }
+}
+
+fn show_create_stage(stage: &StageInfo) -> String {
+    let mut info = format!("CREATE STAGE {}", stage.stage_name);
+    if stage.stage_type == StageType::External {
+        let (url, connection) = storage_location(&stage.stage_params.storage);
+        write!(info, " URL = '{}'", escape_string(&url)).expect("write to string must succeed");
+        if !connection.is_empty() {
+            info.push_str(" CONNECTION = (");
+            for (k, v) in connection {
+                write!(info, " {k} = '{}'", escape_string(&v))
+                    .expect("write to string must succeed");
+            }
+            info.push_str(" )");
+        }
+    }
+
+    write!(info, " FILE_FORMAT = ({})", stage.file_format_options)
+        .expect("write to string must succeed");
+
+    let default_copy_options = CopyOptions::default();
+    if stage.copy_options.on_error != default_copy_options.on_error {
+        write!(info, " ON_ERROR = {}", stage.copy_options.on_error)
+            .expect("write to string must succeed");
+    }
+    if stage.copy_options.size_limit != default_copy_options.size_limit {
+        write!(info, " SIZE_LIMIT = {}", stage.copy_options.size_limit)
+            .expect("write to string must succeed");
+    }
+
+    if !stage.comment.is_empty() {
+        write!(info, " COMMENT = '{}'", escape_string(&stage.comment))
+            .expect("write to string must succeed");
+    }
+    info
+}
+
+/// Returns the URL of the storage and the connection options to access it, the reverse of
+/// parsing the `URL` of `CREATE STAGE`.
+fn storage_location(sp: &StorageParams) -> (String, Vec<(&'static str, String)>) {
+    let mut connection = vec![];
+    let push_secret = |connection: &mut Vec<_>, key, secret: &str| {
+        if !secret.is_empty() {
+            connection.push((key, MASKED_CREDENTIAL.to_string()));
+        }
+    };
+
+    let url = match sp {
+        StorageParams::S3(cfg) => {
+            push_secret(&mut connection, "ACCESS_KEY_ID", &cfg.access_key_id);
+            push_secret(&mut connection, "SECRET_ACCESS_KEY", &cfg.secret_access_key);
+            push_secret(&mut connection, "SESSION_TOKEN", &cfg.security_token);
+            push_secret(&mut connection, "MASTER_KEY", &cfg.master_key);
+            push_secret(&mut connection, "EXTERNAL_ID", &cfg.external_id);
+            if cfg.endpoint_url != STORAGE_S3_DEFAULT_ENDPOINT {
+                connection.push(("ENDPOINT_URL", cfg.endpoint_url.clone()));
+            }
+            if !cfg.region.is_empty() {
+                connection.push(("REGION", cfg.region.clone()));
+            }
+            if !cfg.role_arn.is_empty() {
+                connection.push(("ROLE_ARN", cfg.role_arn.clone()));
+            }
+            if cfg.enable_virtual_host_style {
+                connection.push(("ENABLE_VIRTUAL_HOST_STYLE", "true".to_string()));
+            }
+            format!("s3://{}{}", cfg.bucket, cfg.root)
+        }
+        StorageParams::Gcs(cfg) => {
+            push_secret(&mut connection, "CREDENTIAL", &cfg.credential);
+            if cfg.endpoint_url != STORAGE_GCS_DEFAULT_ENDPOINT {
+                connection.push(("ENDPOINT_URL", cfg.endpoint_url.clone()));
+            }
+            format!("gcs://{}{}", cfg.bucket, cfg.root)
+        }
+        StorageParams::Azblob(cfg) => {
+            push_secret(&mut connection, "ACCOUNT_KEY", &cfg.account_key);
+            connection.push(("ENDPOINT_URL", cfg.endpoint_url.clone()));
+            if !cfg.account_name.is_empty() {
+                connection.push(("ACCOUNT_NAME", cfg.account_name.clone()));
+            }
+            format!("azblob://{}{}", cfg.container, cfg.root)
+        }
+        StorageParams::Oss(cfg) => {
+            push_secret(&mut connection, "ACCESS_KEY_ID", &cfg.access_key_id);
+            push_secret(&mut connection, "ACCESS_KEY_SECRET", &cfg.access_key_secret);
+            connection.push(("ENDPOINT_URL", cfg.endpoint_url.clone()));
+            format!("oss://{}{}", cfg.bucket, cfg.root)
+        }
+        StorageParams::Webhdfs(cfg) => {
+            push_secret(&mut connection, "DELEGATION", &cfg.delegation);
+            let host = match cfg.endpoint_url.strip_prefix("http://") {
+                Some(host) => {
+                    connection.push(("HTTPS", "false".to_string()));
+                    host
+                }
+                None => cfg.endpoint_url.trim_start_matches("https://"),
+            };
+            format!("webhdfs://{}{}", host, cfg.root)
+        }
+        StorageParams::Ipfs(cfg) => {
+            if cfg.endpoint_url != STORAGE_IPFS_DEFAULT_ENDPOINT {
+                connection.push(("ENDPOINT_URL", cfg.endpoint_url.clone()));
+            }
+            format!("ipfs://{}", cfg.root.trim_start_matches("/ipfs/"))
+        }
+        StorageParams::Fs(cfg) => format!("fs://{}", cfg.root),
+        // The other storages can't be the location of a stage, show what is known about them.
+        v => v.to_string(),
+    };
+    (url, connection)
+}
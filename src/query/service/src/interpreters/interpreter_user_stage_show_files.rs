@@ -0,0 +1,150 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_functions::scalars::check_pattern_type;
+use common_functions::scalars::like_with_pattern_type;
+use common_meta_app::principal::StageType;
+use common_meta_app::schema::TableCopyHistoryInfo;
+use common_sql::plans::ShowStageFilesPlan;
+use common_storage::StageFilesInfo;
+use common_storages_stage::StageTable;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The status of the files which are not in the copy history.
+const NOT_LOADED: &str = "NOT_LOADED";
+
+#[derive(Debug)]
+pub struct ShowStageFilesInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowStageFilesPlan,
+}
+
+impl ShowStageFilesInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowStageFilesPlan) -> Result<Self> {
+        Ok(ShowStageFilesInterpreter { ctx, plan })
+    }
+
+    /// The latest load history of the files in the stage, keyed by the file name.
+    async fn copy_history(&self) -> Result<HashMap<String, TableCopyHistoryInfo>> {
+        let stage_location = match self.plan.stage.stage_type {
+            StageType::User => "@~".to_string(),
+            _ => format!("@{}", self.plan.stage.stage_name),
+        };
+
+        let mut history: HashMap<String, TableCopyHistoryInfo> = HashMap::new();
+        for info in UserApiProvider::instance()
+            .get_copy_history(&self.plan.tenant)
+            .await?
+        {
+            if info.stage_location != stage_location {
+                continue;
+            }
+            match history.get(&info.file_name) {
+                Some(latest) if latest.last_load_time >= info.last_load_time => {}
+                _ => {
+                    history.insert(info.file_name.clone(), info);
+                }
+            }
+        }
+        Ok(history)
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowStageFilesInterpreter {
+    fn name(&self) -> &str {
+        "ShowStageFilesInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let op = StageTable::get_op(&self.plan.stage)?;
+        let files_info = StageFilesInfo {
+            path: self.plan.path.clone(),
+            files: None,
+            pattern: None,
+        };
+        let mut files = files_info.list(&op, false).await?;
+
+        if !self.plan.pattern.is_empty() {
+            let pattern = self.plan.pattern.as_bytes();
+            let pattern_type = check_pattern_type(pattern, false);
+            files
+                .retain(|file| like_with_pattern_type(file.path.as_bytes(), pattern, pattern_type));
+        }
+        if let Some(limit) = self.plan.limit {
+            files.truncate(limit);
+        }
+
+        let history = self.copy_history().await?;
+
+        let names: Vec<Vec<u8>> = files
+            .iter()
+            .map(|file| file.path.clone().into_bytes())
+            .collect();
+        let sizes: Vec<u64> = files.iter().map(|file| file.size).collect();
+        let md5s: Vec<Option<Vec<u8>>> = files
+            .iter()
+            .map(|file| file.md5.clone().map(String::into_bytes))
+            .collect();
+        let last_modifieds: Vec<Vec<u8>> = files
+            .iter()
+            .map(|file| {
+                file.last_modified
+                    .format("%Y-%m-%d %H:%M:%S.%3f %z")
+                    .to_string()
+                    .into_bytes()
+            })
+            .collect();
+        let etags: Vec<Option<Vec<u8>>> = files
+            .iter()
+            .map(|file| file.etag.clone().map(String::into_bytes))
+            .collect();
+        let statuses: Vec<Vec<u8>> = files
+            .iter()
+            .map(|file| match history.get(&file.path) {
+                Some(info) => info.status.to_string().into_bytes(),
+                None => NOT_LOADED.as_bytes().to_vec(),
+            })
+            .collect();
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(sizes),
+            StringType::from_opt_data(md5s),
+            StringType::from_data(last_modifieds),
+            StringType::from_opt_data(etags),
+            StringType::from_data(statuses),
+        ])])
+    }
+}
@@ -25,30 +25,53 @@ mod interpreter_copy;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_rename;
+mod interpreter_database_set_quota;
 mod interpreter_database_show_create;
 mod interpreter_database_undrop;
 mod interpreter_delete;
 mod interpreter_explain;
+mod interpreter_external_function_create;
+mod interpreter_external_function_drop;
 mod interpreter_factory;
 mod interpreter_file_format_create;
 mod interpreter_file_format_drop;
 mod interpreter_file_format_show;
+mod interpreter_function_describe;
+mod interpreter_index_build_status_show;
 mod interpreter_insert;
+mod interpreter_insert_multi_table;
 mod interpreter_kill;
+mod interpreter_masking_policy_create;
+mod interpreter_masking_policy_drop;
 mod interpreter_metrics;
+mod interpreter_network_policy_create;
+mod interpreter_network_policy_desc;
+mod interpreter_network_policy_drop;
+mod interpreter_network_policy_show;
+mod interpreter_pipe_create;
+mod interpreter_pipe_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
 mod interpreter_query_log;
 mod interpreter_replace;
+mod interpreter_result_cache_drop;
 mod interpreter_role_create;
 mod interpreter_role_drop;
 mod interpreter_role_grant;
 mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_show;
+mod interpreter_role_show_hierarchy;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_drop;
+mod interpreter_row_access_policy_show;
 mod interpreter_select;
+mod interpreter_sequence_create;
+mod interpreter_sequence_drop;
+mod interpreter_sequence_show;
 mod interpreter_setting;
+mod interpreter_share_alter_comment;
 mod interpreter_share_alter_tenants;
 mod interpreter_share_create;
 mod interpreter_share_desc;
@@ -62,29 +85,54 @@ mod interpreter_share_show;
 mod interpreter_share_show_grant_tenants;
 mod interpreter_show_grants;
 mod interpreter_show_object_grant_privileges;
+mod interpreter_slow_query_log;
+mod interpreter_stream_create;
+mod interpreter_stream_drop;
 mod interpreter_table_add_column;
+mod interpreter_table_add_row_access_policy;
 mod interpreter_table_analyze;
+mod interpreter_table_attach;
+mod interpreter_table_build_index;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_row_access_policy;
 mod interpreter_table_exists;
+mod interpreter_table_export_snapshot;
+mod interpreter_table_import_snapshot;
+mod interpreter_table_modify_column;
 mod interpreter_table_optimize;
 mod interpreter_table_recluster;
 mod interpreter_table_rename;
+mod interpreter_table_rename_column;
 mod interpreter_table_revert;
+mod interpreter_table_set_analyze_interval;
+mod interpreter_table_set_connection;
+mod interpreter_table_show_columns;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
+mod interpreter_task_alter;
+mod interpreter_task_create;
+mod interpreter_task_drop;
+mod interpreter_task_execute;
+mod interpreter_txn_abort;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
 mod interpreter_unsetting;
 mod interpreter_update;
 mod interpreter_use_database;
 mod interpreter_user_alter;
 mod interpreter_user_create;
 mod interpreter_user_drop;
+mod interpreter_user_stage_alter;
 mod interpreter_user_stage_create;
 mod interpreter_user_stage_drop;
 mod interpreter_user_stage_remove;
+mod interpreter_user_stage_rename;
+mod interpreter_user_stage_show_create;
+mod interpreter_user_stage_show_files;
 mod interpreter_user_udf_alter;
 mod interpreter_user_udf_create;
 mod interpreter_user_udf_drop;
@@ -103,25 +151,47 @@ pub use interpreter_clustering_history::InterpreterClusteringHistory;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
+pub use interpreter_database_set_quota::SetDatabaseQuotaInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
 pub use interpreter_database_undrop::UndropDatabaseInterpreter;
 pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
+pub use interpreter_external_function_create::CreateExternalFunctionInterpreter;
+pub use interpreter_external_function_drop::DropExternalFunctionInterpreter;
 pub use interpreter_factory::InterpreterFactory;
+pub use interpreter_function_describe::DescribeFunctionInterpreter;
+pub use interpreter_index_build_status_show::ShowIndexBuildStatusInterpreter;
 pub use interpreter_insert::InsertInterpreter;
+pub use interpreter_insert_multi_table::InsertMultiTableInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_masking_policy_create::CreateMaskingPolicyInterpreter;
+pub use interpreter_masking_policy_drop::DropMaskingPolicyInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
+pub use interpreter_network_policy_create::CreateNetworkPolicyInterpreter;
+pub use interpreter_network_policy_desc::DescNetworkPolicyInterpreter;
+pub use interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
+pub use interpreter_network_policy_show::ShowNetworkPoliciesInterpreter;
+pub use interpreter_pipe_create::CreatePipeInterpreter;
+pub use interpreter_pipe_drop::DropPipeInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_query_log::InterpreterQueryLog;
 pub use interpreter_replace::ReplaceInterpreter;
+pub use interpreter_result_cache_drop::DropResultCacheInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_show::ShowRowAccessPoliciesInterpreter;
 pub use interpreter_select::SelectInterpreter;
+pub use interpreter_sequence_create::CreateSequenceInterpreter;
+pub use interpreter_sequence_drop::DropSequenceInterpreter;
+pub use interpreter_sequence_show::ShowSequencesInterpreter;
 pub use interpreter_setting::SettingInterpreter;
+pub use interpreter_share_alter_comment::AlterShareCommentInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
 pub use interpreter_share_create::CreateShareInterpreter;
 pub use interpreter_share_drop::DropShareInterpreter;
@@ -134,28 +204,53 @@ pub use interpreter_share_show::ShowSharesInterpreter;
 pub use interpreter_share_show_grant_tenants::ShowGrantTenantsOfShareInterpreter;
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInterpreter;
+pub use interpreter_slow_query_log::InterpreterSlowQueryLog;
+pub use interpreter_stream_create::CreateStreamInterpreter;
+pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_add_row_access_policy::AddTableRowAccessPolicyInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
+pub use interpreter_table_attach::AttachTableInterpreter;
+pub use interpreter_table_build_index::BuildTableIndexInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_row_access_policy::DropTableRowAccessPolicyInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
+pub use interpreter_table_export_snapshot::ExportTableSnapshotInterpreter;
+pub use interpreter_table_import_snapshot::ImportTableSnapshotInterpreter;
+pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
+pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
+pub use interpreter_table_set_analyze_interval::SetTableAnalyzeIntervalInterpreter;
+pub use interpreter_table_set_connection::SetTableConnectionInterpreter;
+pub use interpreter_table_show_columns::ShowColumnsInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
+pub use interpreter_task_alter::AlterTaskInterpreter;
+pub use interpreter_task_create::CreateTaskInterpreter;
+pub use interpreter_task_drop::DropTaskInterpreter;
+pub use interpreter_task_execute::ExecuteTaskInterpreter;
+pub use interpreter_txn_abort::AbortInterpreter;
+pub use interpreter_txn_begin::BeginInterpreter;
+pub use interpreter_txn_commit::CommitInterpreter;
 pub use interpreter_unsetting::UnSettingInterpreter;
 pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
 pub use interpreter_user_create::CreateUserInterpreter;
 pub use interpreter_user_drop::DropUserInterpreter;
+pub use interpreter_user_stage_alter::AlterUserStageInterpreter;
 pub use interpreter_user_stage_create::CreateUserStageInterpreter;
 pub use interpreter_user_stage_drop::DropUserStageInterpreter;
 pub use interpreter_user_stage_remove::RemoveUserStageInterpreter;
+pub use interpreter_user_stage_rename::RenameUserStageInterpreter;
+pub use interpreter_user_stage_show_create::ShowCreateStageInterpreter;
+pub use interpreter_user_stage_show_files::ShowStageFilesInterpreter;
 pub use interpreter_user_udf_alter::AlterUserUDFInterpreter;
 pub use interpreter_user_udf_create::CreateUserUDFInterpreter;
 pub use interpreter_user_udf_drop::DropUserUDFInterpreter;
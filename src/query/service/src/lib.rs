@@ -39,12 +39,15 @@ pub mod databases;
 pub mod interpreters;
 pub mod metrics;
 pub mod pipelines;
+pub mod pipes;
 pub mod procedures;
 pub mod schedulers;
 pub mod servers;
 pub mod sessions;
+pub mod statistics;
 pub mod stream;
 pub mod table_functions;
+pub mod tasks;
 
 mod global_services;
 
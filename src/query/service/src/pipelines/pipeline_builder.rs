@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use async_channel::Receiver;
+use common_catalog::plan::SampleMethod;
 use common_catalog::table::AppendMode;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -33,11 +37,16 @@ use common_pipeline_core::pipe::Pipe;
 use common_pipeline_core::pipe::PipeItem;
 use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_sinks::AsyncSinker;
 use common_pipeline_sinks::EmptySink;
 use common_pipeline_sinks::Sinker;
 use common_pipeline_sinks::UnionReceiveSink;
+use common_pipeline_sources::BlocksSource;
 use common_pipeline_transforms::processors::transforms::try_add_multi_sort_merge;
 use common_pipeline_transforms::processors::transforms::try_create_transform_sort_merge;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransformer;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
 use common_profile::ProfSpanSetRef;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
@@ -45,25 +54,36 @@ use common_sql::executor::AggregateExpand;
 use common_sql::executor::AggregateFinal;
 use common_sql::executor::AggregateFunctionDesc;
 use common_sql::executor::AggregatePartial;
+use common_sql::executor::Apply;
 use common_sql::executor::DistributedInsertSelect;
 use common_sql::executor::EvalScalar;
 use common_sql::executor::ExchangeSink;
 use common_sql::executor::ExchangeSource;
+use common_sql::executor::ExternalFunction;
 use common_sql::executor::Filter;
 use common_sql::executor::HashJoin;
 use common_sql::executor::Limit;
+use common_sql::executor::MatchRecognize;
+use common_sql::executor::MaterializedCte;
+use common_sql::executor::MaterializedCteScan;
 use common_sql::executor::PhysicalPlan;
 use common_sql::executor::Project;
 use common_sql::executor::ProjectSet;
+use common_sql::executor::RecursiveCte;
+use common_sql::executor::RecursiveCteScan;
 use common_sql::executor::RuntimeFilterSource;
 use common_sql::executor::Sort;
 use common_sql::executor::TableScan;
 use common_sql::executor::UnionAll;
 use common_sql::plans::JoinType;
+use common_sql::plans::MatchMeasure;
+use common_sql::plans::MatchMeasureFunction;
 use common_sql::ColumnBinding;
 use common_sql::IndexType;
 use common_storage::DataOperator;
+use common_storage::SpillOperator;
 use common_storages_fuse::operations::FillInternalColumnProcessor;
+use parking_lot::Mutex;
 
 use super::processors::ProfileWrapper;
 use super::processors::TransformExpandGroupingSets;
@@ -77,27 +97,35 @@ use crate::pipelines::processors::transforms::PartialSingleStateAggregator;
 use crate::pipelines::processors::transforms::RightSemiAntiJoinCompactor;
 use crate::pipelines::processors::transforms::RuntimeFilterState;
 use crate::pipelines::processors::transforms::TransformAggregateSpillWriter;
+use crate::pipelines::processors::transforms::TransformApply;
+use crate::pipelines::processors::transforms::TransformBernoulliSample;
+use crate::pipelines::processors::transforms::TransformExternalFunction;
 use crate::pipelines::processors::transforms::TransformGroupBySpillWriter;
 use crate::pipelines::processors::transforms::TransformLeftJoin;
 use crate::pipelines::processors::transforms::TransformMarkJoin;
+use crate::pipelines::processors::transforms::TransformMatchRecognize;
 use crate::pipelines::processors::transforms::TransformMergeBlock;
 use crate::pipelines::processors::transforms::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::TransformPartialGroupBy;
 use crate::pipelines::processors::transforms::TransformRightJoin;
 use crate::pipelines::processors::transforms::TransformRightSemiAntiJoin;
 use crate::pipelines::processors::AggregatorParams;
+use crate::pipelines::processors::GraceHashJoinState;
 use crate::pipelines::processors::JoinHashTable;
 use crate::pipelines::processors::LeftJoinCompactor;
 use crate::pipelines::processors::MarkJoinCompactor;
 use crate::pipelines::processors::RightJoinCompactor;
 use crate::pipelines::processors::SinkBuildHashTable;
+use crate::pipelines::processors::SinkGraceHashJoinBuild;
 use crate::pipelines::processors::SinkRuntimeFilterSource;
 use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::processors::TransformGraceHashJoinProbe;
 use crate::pipelines::processors::TransformHashJoinProbe;
 use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::processors::TransformRuntimeFilter;
 use crate::pipelines::processors::TransformSortPartial;
+use crate::pipelines::processors::TransformSortSpill;
 use crate::pipelines::Pipeline;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -157,6 +185,21 @@ impl PipelineBuilder {
     }
 
     fn build_pipeline(&mut self, plan: &PhysicalPlan) -> Result<()> {
+        let pipes_before = self.main_pipeline.pipes.len();
+        self.build_plan_pipeline(plan)?;
+
+        // Pipes added by the plan inputs are already tagged with the estimation of the inputs.
+        if let Some(stat_info) = plan.stat_info() {
+            for pipe in self.main_pipeline.pipes.iter_mut().skip(pipes_before) {
+                if pipe.estimated_rows.is_none() {
+                    pipe.estimated_rows = Some(stat_info.estimated_rows);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn build_plan_pipeline(&mut self, plan: &PhysicalPlan) -> Result<()> {
         match plan {
             PhysicalPlan::TableScan(scan) => self.build_table_scan(scan),
             PhysicalPlan::Filter(filter) => self.build_filter(filter),
@@ -175,16 +218,37 @@ impl PipelineBuilder {
                 self.build_distributed_insert_select(insert_select)
             }
             PhysicalPlan::ProjectSet(project_set) => self.build_project_set(project_set),
+            PhysicalPlan::ExternalFunction(external_function) => {
+                self.build_external_function(external_function)
+            }
             PhysicalPlan::Exchange(_) => Err(ErrorCode::Internal(
                 "Invalid physical plan with PhysicalPlan::Exchange",
             )),
             PhysicalPlan::RuntimeFilterSource(runtime_filter_source) => {
                 self.build_runtime_filter_source(runtime_filter_source)
             }
+            PhysicalPlan::RecursiveCte(recursive_cte) => self.build_recursive_cte(recursive_cte),
+            PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
+            PhysicalPlan::MaterializedCte(materialized_cte) => {
+                self.build_materialized_cte(materialized_cte)
+            }
+            PhysicalPlan::MaterializedCteScan(scan) => self.build_materialized_cte_scan(scan),
+            PhysicalPlan::Apply(apply) => self.build_apply(apply),
+            PhysicalPlan::MatchRecognize(match_recognize) => {
+                self.build_match_recognize(match_recognize)
+            }
         }
     }
 
     fn build_join(&mut self, join: &HashJoin) -> Result<()> {
+        let spilling_bytes_threshold = self
+            .ctx
+            .get_settings()
+            .get_join_spilling_bytes_threshold()?;
+        if spilling_bytes_threshold != 0 && GraceHashJoinState::support(join) {
+            return self.build_grace_hash_join(join, spilling_bytes_threshold);
+        }
+
         let state = self.build_join_state(join)?;
         self.expand_build_side_pipeline(&join.build, join, state.clone())?;
         self.build_join_probe(join, state)
@@ -246,6 +310,76 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_grace_hash_join(
+        &mut self,
+        join: &HashJoin,
+        spilling_bytes_threshold: usize,
+    ) -> Result<()> {
+        let state = GraceHashJoinState::try_create(
+            self.ctx.clone(),
+            join,
+            spilling_bytes_threshold,
+            SpillOperator::instance().operator(),
+            format!(
+                "{}/{}",
+                SpillOperator::JOIN_SPILL_PREFIX,
+                self.ctx.get_tenant()
+            ),
+            self.enable_profiling.then(|| self.prof_span_set.clone()),
+        )?;
+
+        // Build side
+        let build_side_context = QueryContext::create_from(self.ctx.clone());
+        let build_side_builder = PipelineBuilder::create(
+            build_side_context,
+            self.enable_profiling,
+            self.prof_span_set.clone(),
+        );
+        let mut build_res = build_side_builder.finalize(&join.build)?;
+
+        assert!(build_res.main_pipeline.is_pulling_pipeline()?);
+
+        build_res.main_pipeline.add_sink(|input| {
+            let transform = AsyncSinker::<SinkGraceHashJoinBuild>::create(
+                input,
+                SinkGraceHashJoinBuild::try_create(state.clone())?,
+            );
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    join.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })?;
+
+        self.pipelines.push(build_res.main_pipeline);
+        self.pipelines
+            .extend(build_res.sources_pipelines.into_iter());
+
+        // Probe side
+        self.build_pipeline(&join.probe)?;
+
+        let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        self.main_pipeline.add_transform(|input, output| {
+            let transform =
+                TransformGraceHashJoinProbe::create(input, output, state.clone(), block_size)?;
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    join.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
     pub fn render_result_set(
         func_ctx: &FunctionContext,
         input_schema: DataSchemaRef,
@@ -291,6 +425,23 @@ impl PipelineBuilder {
             })?;
         }
 
+        // `TABLESAMPLE BERNOULLI` filters the rows that are read.
+        if let Some(sample) = scan
+            .source
+            .push_downs
+            .as_ref()
+            .and_then(|extras| extras.sample)
+            .filter(|sample| sample.method == SampleMethod::Bernoulli)
+        {
+            let index = AtomicU64::new(0);
+            self.main_pipeline.add_transform(|input, output| {
+                let seed = sample
+                    .seed
+                    .wrapping_add(index.fetch_add(1, Ordering::Relaxed));
+                TransformBernoulliSample::try_create(input, output, sample.fraction(), seed)
+            })?;
+        }
+
         let schema = scan.source.schema();
         let projection = scan
             .name_mapping
@@ -432,6 +583,128 @@ impl PipelineBuilder {
         })
     }
 
+    fn build_external_function(&mut self, external_function: &ExternalFunction) -> Result<()> {
+        self.build_pipeline(&external_function.input)?;
+
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = AsyncTransformer::create(
+                input,
+                output,
+                TransformExternalFunction::try_create(self.ctx.clone(), external_function)?,
+            );
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    external_function.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
+    fn build_apply(&mut self, apply: &Apply) -> Result<()> {
+        self.build_pipeline(&apply.input)?;
+
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = AsyncTransformer::create(
+                input,
+                output,
+                TransformApply::try_create(self.ctx.clone(), apply.clone())?,
+            );
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    apply.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
+    fn build_match_recognize(&mut self, match_recognize: &MatchRecognize) -> Result<()> {
+        self.build_pipeline(&match_recognize.input)?;
+
+        let input_schema = match_recognize.input.output_schema()?;
+        let offset_of = |index: &IndexType| input_schema.index_of(&index.to_string());
+        let partition_offsets = match_recognize
+            .partition_by
+            .iter()
+            .map(offset_of)
+            .collect::<Result<Vec<_>>>()?;
+        let predicate_offsets = match_recognize
+            .variables
+            .iter()
+            .map(|variable| variable.predicate.as_ref().map(offset_of).transpose())
+            .collect::<Result<Vec<_>>>()?;
+        let variable_names = match_recognize
+            .variables
+            .iter()
+            .map(|variable| variable.name.clone())
+            .collect::<Vec<_>>();
+        let measures = match_recognize
+            .measures
+            .iter()
+            .map(|measure| {
+                let function = match &measure.function {
+                    MatchMeasureFunction::First { variable, column } => {
+                        MatchMeasureFunction::First {
+                            variable: *variable,
+                            column: offset_of(column)?,
+                        }
+                    }
+                    MatchMeasureFunction::Last { variable, column } => MatchMeasureFunction::Last {
+                        variable: *variable,
+                        column: offset_of(column)?,
+                    },
+                    MatchMeasureFunction::Count { variable, column } => {
+                        MatchMeasureFunction::Count {
+                            variable: *variable,
+                            column: column.as_ref().map(offset_of).transpose()?,
+                        }
+                    }
+                    function => function.clone(),
+                };
+                Ok(MatchMeasure {
+                    function,
+                    ..measure.clone()
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // The rows of a partition are matched in order by a single processor.
+        self.main_pipeline.resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = AccumulatingTransformer::create(
+                input,
+                output,
+                TransformMatchRecognize::new(
+                    partition_offsets.clone(),
+                    predicate_offsets.clone(),
+                    variable_names.clone(),
+                    &match_recognize.pattern,
+                    measures.clone(),
+                    match_recognize.all_rows_per_match,
+                ),
+            );
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    match_recognize.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
     fn build_aggregate_expand(&mut self, expand: &AggregateExpand) -> Result<()> {
         self.build_pipeline(&expand.input)?;
         let input_schema = expand.input.output_schema()?;
@@ -728,6 +1001,10 @@ impl PipelineBuilder {
 
         let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
         let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        let spilling_bytes_threshold = self
+            .ctx
+            .get_settings()
+            .get_sort_spilling_bytes_threshold_per_proc()?;
 
         // TODO(Winter): the query will hang in MultiSortMergeProcessor when max_threads == 1 and output_len != 1
         if self.main_pipeline.output_len() == 1 || max_threads == 1 {
@@ -750,16 +1027,35 @@ impl PipelineBuilder {
             }
         })?;
 
-        // Merge
+        // Merge, the top-n of a LIMIT is always kept in memory.
+        let spill = sort.limit.is_none() && spilling_bytes_threshold != 0;
+        let operator = SpillOperator::instance().operator();
+        let location_prefix = format!(
+            "{}/{}",
+            SpillOperator::SORT_SPILL_PREFIX,
+            self.ctx.get_tenant()
+        );
         self.main_pipeline.add_transform(|input, output| {
-            let transform = try_create_transform_sort_merge(
-                input,
-                output,
-                input_schema.clone(),
-                block_size,
-                sort.limit,
-                sort_desc.clone(),
-            )?;
+            let transform: Box<dyn Processor> = match spill {
+                true => Box::new(TransformSortSpill::try_create(
+                    input,
+                    output,
+                    input_schema.clone(),
+                    block_size,
+                    sort_desc.clone(),
+                    spilling_bytes_threshold,
+                    operator.clone(),
+                    location_prefix.clone(),
+                )?),
+                false => try_create_transform_sort_merge(
+                    input,
+                    output,
+                    input_schema.clone(),
+                    block_size,
+                    sort.limit,
+                    sort_desc.clone(),
+                )?,
+            };
 
             if self.enable_profiling {
                 Ok(ProcessorPtr::create(ProfileWrapper::create(
@@ -1057,6 +1353,37 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    // The result of a recursive CTE is computed before the pipeline is built,
+    // see `execute_ctes`, so here we only need to emit it.
+    fn build_recursive_cte(&mut self, recursive_cte: &RecursiveCte) -> Result<()> {
+        let blocks = self.ctx.get_recursive_cte_result(&recursive_cte.table_name);
+        self.build_blocks_source(blocks)
+    }
+
+    fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        let blocks = self.ctx.get_recursive_cte_table(&scan.table_name);
+        self.build_blocks_source(blocks)
+    }
+
+    // The result of a materialized CTE is also computed and stored before the
+    // pipeline is built, only the input reading it is left here.
+    fn build_materialized_cte(&mut self, materialized_cte: &MaterializedCte) -> Result<()> {
+        self.build_pipeline(&materialized_cte.input)
+    }
+
+    fn build_materialized_cte_scan(&mut self, scan: &MaterializedCteScan) -> Result<()> {
+        let blocks = self.ctx.get_materialized_cte_result(&scan.table_name);
+        self.build_blocks_source(blocks)
+    }
+
+    fn build_blocks_source(&mut self, blocks: Vec<DataBlock>) -> Result<()> {
+        let blocks = Arc::new(Mutex::new(VecDeque::from_iter(blocks)));
+        self.main_pipeline.add_source(
+            |output| BlocksSource::create(self.ctx.clone(), output, blocks.clone()),
+            1,
+        )
+    }
+
     pub fn build_runtime_filter_source(
         &mut self,
         runtime_filter_source: &RuntimeFilterSource,
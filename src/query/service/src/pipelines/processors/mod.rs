@@ -15,18 +15,23 @@
 pub use common_pipeline_core::processors::*;
 pub(crate) mod transforms;
 
+pub use transforms::partition_indices;
 pub use transforms::AggregatorParams;
 pub use transforms::BlockCompactor;
+pub use transforms::GraceHashJoinState;
 pub use transforms::HashJoinDesc;
 pub use transforms::HashJoinState;
 pub use transforms::HashTable;
 pub use transforms::JoinHashTable;
 pub use transforms::LeftJoinCompactor;
 pub use transforms::MarkJoinCompactor;
+pub use transforms::MatchProgram;
+pub use transforms::MatchResult;
 pub use transforms::ProfileWrapper;
 pub use transforms::RightJoinCompactor;
 pub use transforms::SerializerHashTable;
 pub use transforms::SinkBuildHashTable;
+pub use transforms::SinkGraceHashJoinBuild;
 pub use transforms::SinkRuntimeFilterSource;
 pub use transforms::SortMergeCompactor;
 pub use transforms::TransformBlockCompact;
@@ -34,8 +39,12 @@ pub use transforms::TransformCastSchema;
 pub use transforms::TransformCompact;
 pub use transforms::TransformCreateSets;
 pub use transforms::TransformExpandGroupingSets;
+pub use transforms::TransformGraceHashJoinProbe;
 pub use transforms::TransformHashJoinProbe;
 pub use transforms::TransformLimit;
+pub use transforms::TransformMatchRecognize;
 pub use transforms::TransformResortAddOn;
 pub use transforms::TransformRuntimeFilter;
 pub use transforms::TransformSortPartial;
+pub use transforms::TransformSortSpill;
+pub use transforms::NUM_PARTITIONS;
@@ -73,6 +73,10 @@ impl Transform for TransformExpandGroupingSets {
                 .collect::<Vec<_>>();
             let bits = !id;
             for i in 0..num_group_bys {
+                if self.grouping_ids.iter().all(|other| other & (1 << i) == 0) {
+                    // The column is in every grouping set, so it is never NULL by grouping.
+                    continue;
+                }
                 let entry = unsafe {
                     let offset = self.group_bys.get_unchecked(i).0;
                     columns.get_unchecked_mut(offset)
@@ -119,6 +119,8 @@ impl<'a> GroupColumnsBuilder for SerializedKeysGroupColumnsBuilder<'a> {
             match self.group_data_types[0] {
                 DataType::String => return Ok(vec![Column::String(col)]),
                 DataType::Variant => return Ok(vec![Column::Variant(col)]),
+                DataType::Bitmap => return Ok(vec![Column::Bitmap(col)]),
+                DataType::Binary => return Ok(vec![Column::Binary(col)]),
                 _ => {}
             }
         }
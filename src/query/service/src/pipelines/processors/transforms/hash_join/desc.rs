@@ -61,6 +61,8 @@ impl JoinState {
 pub struct HashJoinDesc {
     pub(crate) build_keys: Vec<Expr>,
     pub(crate) probe_keys: Vec<Expr>,
+    /// Indexes of the keys whose NULL values are matched with each other.
+    pub(crate) is_null_equal: Vec<usize>,
     pub(crate) join_type: JoinType,
     pub(crate) other_predicate: Option<Expr>,
     pub(crate) marker_join_desc: MarkJoinDesc,
@@ -88,6 +90,7 @@ impl HashJoinDesc {
             join_type: join.join_type.clone(),
             build_keys,
             probe_keys,
+            is_null_equal: join.is_null_equal.clone(),
             other_predicate,
             marker_join_desc: MarkJoinDesc {
                 has_null: RwLock::new(false),
@@ -0,0 +1,729 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::ScalarRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_profile::ProfSpan;
+use common_profile::ProfSpanSetRef;
+use common_sql::executor::HashJoin;
+use opendal::Operator;
+use parking_lot::RwLock;
+use tracing::error;
+use tracing::info;
+
+use crate::pipelines::processors::transforms::hash_join::HashJoinDesc;
+use crate::pipelines::processors::transforms::hash_join::HashJoinState;
+use crate::pipelines::processors::transforms::hash_join::JoinHashTable;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::JoinType;
+
+/// The number of partitions that the rows are hashed into at each level.
+pub const NUM_PARTITIONS: usize = 16;
+
+/// A partition is repartitioned at most `MAX_SPILL_LEVEL - 1` times, the query fails if the
+/// build side of a partition still exceeds the threshold at the last level.
+pub const MAX_SPILL_LEVEL: usize = 4;
+
+/// Hashes the rows into [`NUM_PARTITIONS`] partitions by their join `keys`.
+///
+/// The keys are hashed by SipHash seeded with `level`, which is independent of the hash of
+/// the join hash table, so the rows of a partition are still spread in its hash table, and
+/// a partition is split again by the next level. The rows whose keys are NULL (except the
+/// `is_null_equal` ones) never match, they are put into the extra partition `NUM_PARTITIONS`
+/// if `drop_null_keys`, otherwise hashed like the others.
+pub fn partition_indices(
+    keys: &[Column],
+    is_null_equal: &[usize],
+    level: usize,
+    drop_null_keys: bool,
+    num_rows: usize,
+) -> Vec<u64> {
+    (0..num_rows)
+        .map(|row| {
+            let mut hasher = DefaultHasher::new();
+            hasher.write_usize(level);
+            for (idx, key) in keys.iter().enumerate() {
+                let scalar = key.index(row).unwrap();
+                if drop_null_keys
+                    && matches!(scalar, ScalarRef::Null)
+                    && !is_null_equal.contains(&idx)
+                {
+                    return NUM_PARTITIONS as u64;
+                }
+                scalar.hash(&mut hasher);
+            }
+            hasher.finish() % NUM_PARTITIONS as u64
+        })
+        .collect()
+}
+
+/// A block spilled to storage.
+pub struct SpilledBlock {
+    location: String,
+    columns_layout: Vec<usize>,
+}
+
+/// The spilled blocks of both sides of a partition.
+pub struct PartitionTask {
+    pub level: usize,
+    pub build: Vec<SpilledBlock>,
+    pub probe: Vec<SpilledBlock>,
+}
+
+pub enum LoadedPartition {
+    /// The build side fits in memory.
+    Loaded {
+        build: Vec<DataBlock>,
+        probe: Vec<SpilledBlock>,
+    },
+    /// The partition is split into the partitions of the next level.
+    Repartitioned(Vec<PartitionTask>),
+    /// No row of the partition can be in the join result.
+    Skipped,
+}
+
+#[derive(Default)]
+struct Partition {
+    // the buffered build blocks
+    blocks: Vec<DataBlock>,
+    bytes: usize,
+    spilled: bool,
+    build: Vec<SpilledBlock>,
+    probe: Vec<SpilledBlock>,
+}
+
+#[derive(Default)]
+struct BuildState {
+    // the build blocks before the build side is partitioned
+    blocks: Vec<DataBlock>,
+    // the bytes of the buffered build blocks
+    bytes: usize,
+    // empty until the build side exceeds the threshold
+    partitions: Vec<Partition>,
+    // the next spilled partition to join after the probe side is finished
+    next_partition: usize,
+}
+
+/// The state of a hash join whose build side may exceed the memory, a.k.a. grace hash join.
+///
+/// The build side is kept in memory until it exceeds the threshold, then both sides are hashed
+/// into partitions by [`partition_indices`]. The largest partitions of the build side are
+/// spilled to storage until half of the threshold is released, the others are joined in
+/// memory with the probe side as usual. The probe rows of the spilled partitions are spilled
+/// too, after the probe side is finished, the probe processors take the spilled partitions
+/// one by one, and join each of them with its own hash table. A spilled partition whose build
+/// side still exceeds the threshold is repartitioned by the next level.
+///
+/// Only the joins of which each probe row can be joined independently are supported, i.e.
+/// inner, left outer, left semi and left anti joins.
+pub struct GraceHashJoinState {
+    ctx: Arc<QueryContext>,
+    join: HashJoin,
+    build_keys: Vec<Expr>,
+    probe_keys: Vec<Expr>,
+    spilling_bytes_threshold: usize,
+
+    operator: Operator,
+    // the directory of the files spilled by this join
+    location_prefix: String,
+    // the files written, removed when the state is dropped in case of a failed query
+    spilled_files: Mutex<Vec<String>>,
+
+    build_state: Mutex<BuildState>,
+    // the partitions whose build side is spilled, set after the build side is finished
+    spilled_partitions: RwLock<Vec<bool>>,
+    memory_table: RwLock<Option<Arc<JoinHashTable>>>,
+
+    build_ref_count: Mutex<usize>,
+    build_finished: Mutex<bool>,
+    build_finished_notify: Arc<Notify>,
+    probe_ref_count: Mutex<usize>,
+    probe_finished: Mutex<bool>,
+    probe_finished_notify: Arc<Notify>,
+
+    plan_id: u32,
+    prof_span_set: Option<ProfSpanSetRef>,
+}
+
+impl GraceHashJoinState {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        join: &HashJoin,
+        spilling_bytes_threshold: usize,
+        operator: Operator,
+        location_prefix: String,
+        prof_span_set: Option<ProfSpanSetRef>,
+    ) -> Result<Arc<GraceHashJoinState>> {
+        let desc = HashJoinDesc::create(join)?;
+        Ok(Arc::new(GraceHashJoinState {
+            ctx,
+            join: join.clone(),
+            build_keys: desc.build_keys,
+            probe_keys: desc.probe_keys,
+            spilling_bytes_threshold,
+            operator,
+            location_prefix: format!("{}/{}", location_prefix, GlobalUniqName::unique()),
+            spilled_files: Mutex::new(vec![]),
+            build_state: Mutex::new(BuildState::default()),
+            spilled_partitions: RwLock::new(vec![]),
+            memory_table: RwLock::new(None),
+            build_ref_count: Mutex::new(0),
+            build_finished: Mutex::new(false),
+            build_finished_notify: Arc::new(Notify::new()),
+            probe_ref_count: Mutex::new(0),
+            probe_finished: Mutex::new(false),
+            probe_finished_notify: Arc::new(Notify::new()),
+            plan_id: join.plan_id,
+            prof_span_set,
+        }))
+    }
+
+    /// Returns true if the join can spill its build side.
+    pub fn support(join: &HashJoin) -> bool {
+        matches!(
+            join.join_type,
+            JoinType::Inner | JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti
+        ) && !join.from_correlated_subquery
+            && !join.contain_runtime_filter
+    }
+
+    pub fn join_type(&self) -> &JoinType {
+        &self.join.join_type
+    }
+
+    pub fn spilling_bytes_threshold(&self) -> usize {
+        self.spilling_bytes_threshold
+    }
+
+    /// Returns true if the unmatched probe rows are in the join result.
+    fn keep_unmatched_probe_rows(&self) -> bool {
+        matches!(self.join.join_type, JoinType::Left | JoinType::LeftAnti)
+    }
+
+    pub fn attach_build(&self) {
+        *self.build_ref_count.lock().unwrap() += 1;
+    }
+
+    pub fn attach_probe(&self) {
+        *self.probe_ref_count.lock().unwrap() += 1;
+    }
+
+    pub async fn build(&self, block: DataBlock) -> Result<()> {
+        let spilling = {
+            let mut state = self.build_state.lock().unwrap();
+            if state.partitions.is_empty() {
+                state.bytes += block.memory_size();
+                state.blocks.push(block);
+                if state.bytes <= self.spilling_bytes_threshold {
+                    return Ok(());
+                }
+
+                // The build side doesn't fit in memory, partition the buffered blocks.
+                state.bytes = 0;
+                state.partitions = (0..NUM_PARTITIONS).map(|_| Partition::default()).collect();
+                for block in std::mem::take(&mut state.blocks) {
+                    self.add_build_block(&mut state, block)?;
+                }
+            } else {
+                self.add_build_block(&mut state, block)?;
+            }
+            self.take_spilling_partitions(&mut state)
+        };
+
+        self.spill_build_partitions(spilling).await
+    }
+
+    fn add_build_block(&self, state: &mut BuildState, block: DataBlock) -> Result<()> {
+        for (idx, block) in self.scatter(block, 0, true)?.into_iter().enumerate() {
+            if !block.is_empty() {
+                let bytes = block.memory_size();
+                state.bytes += bytes;
+                state.partitions[idx].bytes += bytes;
+                state.partitions[idx].blocks.push(block);
+            }
+        }
+        Ok(())
+    }
+
+    /// Takes the largest partitions till half of the threshold is released, if the buffered
+    /// blocks exceed the threshold.
+    fn take_spilling_partitions(&self, state: &mut BuildState) -> Vec<(usize, Vec<DataBlock>)> {
+        let mut spilling = vec![];
+        if state.bytes <= self.spilling_bytes_threshold {
+            return spilling;
+        }
+
+        let mut new_spilled = 0;
+        while state.bytes > self.spilling_bytes_threshold / 2 {
+            let (idx, partition) = state
+                .partitions
+                .iter_mut()
+                .enumerate()
+                .max_by_key(|(_, partition)| partition.bytes)
+                .unwrap();
+            if !partition.spilled {
+                partition.spilled = true;
+                new_spilled += 1;
+            }
+            state.bytes -= partition.bytes;
+            partition.bytes = 0;
+            spilling.push((idx, std::mem::take(&mut partition.blocks)));
+        }
+        self.record_spill(0, new_spilled);
+        spilling
+    }
+
+    async fn spill_build_partitions(&self, partitions: Vec<(usize, Vec<DataBlock>)>) -> Result<()> {
+        for (idx, blocks) in partitions {
+            let spilled = self.spill_block(DataBlock::concat(&blocks)?).await?;
+            let mut state = self.build_state.lock().unwrap();
+            state.partitions[idx].build.push(spilled);
+        }
+        Ok(())
+    }
+
+    /// Spills the probe blocks of the spilled partitions.
+    pub async fn spill_probe_partitions(
+        &self,
+        partitions: Vec<(usize, Vec<DataBlock>)>,
+    ) -> Result<()> {
+        for (idx, blocks) in partitions {
+            let spilled = self.spill_block(DataBlock::concat(&blocks)?).await?;
+            let mut state = self.build_state.lock().unwrap();
+            state.partitions[idx].probe.push(spilled);
+        }
+        Ok(())
+    }
+
+    /// Finishes the build side, will be called only once as soon as all the build processors
+    /// have been detached.
+    async fn finish_build(&self) -> Result<()> {
+        let (memory_blocks, spilling) = {
+            let mut state = self.build_state.lock().unwrap();
+            let mut memory_blocks = std::mem::take(&mut state.blocks);
+            let mut spilling = vec![];
+            for (idx, partition) in state.partitions.iter_mut().enumerate() {
+                let blocks = std::mem::take(&mut partition.blocks);
+                match partition.spilled {
+                    true if !blocks.is_empty() => spilling.push((idx, blocks)),
+                    true => {}
+                    false => memory_blocks.extend(blocks),
+                }
+            }
+            *self.spilled_partitions.write() = state.partitions.iter().map(|p| p.spilled).collect();
+            (memory_blocks, spilling)
+        };
+
+        self.spill_build_partitions(spilling).await?;
+        *self.memory_table.write() = Some(self.create_hash_table(memory_blocks)?);
+        Ok(())
+    }
+
+    pub async fn detach_build(&self) -> Result<()> {
+        {
+            let mut count = self.build_ref_count.lock().unwrap();
+            *count -= 1;
+            if *count != 0 {
+                return Ok(());
+            }
+        }
+
+        self.finish_build().await?;
+        *self.build_finished.lock().unwrap() = true;
+        self.build_finished_notify.notify_waiters();
+        Ok(())
+    }
+
+    /// Wait until the build side is finished.
+    pub async fn wait_build_finish(&self) {
+        let notified = {
+            let finished_guard = self.build_finished.lock().unwrap();
+
+            match *finished_guard {
+                true => None,
+                false => Some(self.build_finished_notify.notified()),
+            }
+        };
+
+        if let Some(notified) = notified {
+            notified.await;
+        }
+    }
+
+    /// Detaches a probe processor after its probe blocks of the spilled partitions are spilled.
+    pub fn detach_probe(&self) {
+        let mut count = self.probe_ref_count.lock().unwrap();
+        *count -= 1;
+        if *count == 0 {
+            *self.probe_finished.lock().unwrap() = true;
+            self.probe_finished_notify.notify_waiters();
+        }
+    }
+
+    /// Wait until all the probe processors are detached.
+    pub async fn wait_probe_finish(&self) {
+        let notified = {
+            let finished_guard = self.probe_finished.lock().unwrap();
+
+            match *finished_guard {
+                true => None,
+                false => Some(self.probe_finished_notify.notified()),
+            }
+        };
+
+        if let Some(notified) = notified {
+            notified.await;
+        }
+    }
+
+    /// Returns true if any partition of the build side is spilled.
+    pub fn is_spilled(&self) -> bool {
+        self.spilled_partitions
+            .read()
+            .iter()
+            .any(|spilled| *spilled)
+    }
+
+    /// The hash table of the build side in memory, available after the build side is finished.
+    pub fn memory_table(&self) -> Arc<JoinHashTable> {
+        self.memory_table.read().clone().unwrap()
+    }
+
+    pub fn create_hash_table(&self, blocks: Vec<DataBlock>) -> Result<Arc<JoinHashTable>> {
+        let table = JoinHashTable::create_join_state(
+            self.ctx.clone(),
+            &self.join.build_keys,
+            self.join.build.output_schema()?,
+            self.join.probe.output_schema()?,
+            HashJoinDesc::create(&self.join)?,
+        )?;
+        for block in blocks {
+            table.build(block)?;
+        }
+        table.finish()?;
+        Ok(table)
+    }
+
+    /// Splits the probe block into the rows to join with the hash table in memory, and the
+    /// rows of each spilled partition.
+    pub fn partition_probe_block(
+        &self,
+        block: DataBlock,
+    ) -> Result<(Option<DataBlock>, Vec<(usize, DataBlock)>)> {
+        let spilled_partitions = self.spilled_partitions.read();
+        if spilled_partitions.is_empty() {
+            return Ok((Some(block), vec![]));
+        }
+
+        let mut memory_blocks = vec![];
+        let mut spilled_blocks = vec![];
+        for (idx, block) in self.scatter(block, 0, false)?.into_iter().enumerate() {
+            match block.is_empty() {
+                true => {}
+                false if spilled_partitions[idx] => spilled_blocks.push((idx, block)),
+                false => memory_blocks.push(block),
+            }
+        }
+
+        let memory_block = match memory_blocks.is_empty() {
+            true => None,
+            false => Some(DataBlock::concat(&memory_blocks)?),
+        };
+        Ok((memory_block, spilled_blocks))
+    }
+
+    /// Takes the next spilled partition to join, after all the probe processors are detached.
+    pub fn next_partition(&self) -> Option<PartitionTask> {
+        let mut state = self.build_state.lock().unwrap();
+        while state.next_partition < state.partitions.len() {
+            let idx = state.next_partition;
+            state.next_partition += 1;
+
+            let partition = &mut state.partitions[idx];
+            if partition.spilled {
+                return Some(PartitionTask {
+                    level: 0,
+                    build: std::mem::take(&mut partition.build),
+                    probe: std::mem::take(&mut partition.probe),
+                });
+            }
+        }
+        None
+    }
+
+    /// Loads the build side of the spilled partition, or repartitions it if it doesn't fit in
+    /// memory.
+    pub async fn load_partition(&self, task: PartitionTask) -> Result<LoadedPartition> {
+        if task.build.is_empty() && !self.keep_unmatched_probe_rows() {
+            self.delete_spilled_blocks(task.probe).await;
+            return Ok(LoadedPartition::Skipped);
+        }
+
+        let mut build = Vec::with_capacity(task.build.len());
+        for spilled in task.build {
+            build.push(self.read_spilled_block(spilled).await?);
+        }
+        let bytes = build.iter().map(DataBlock::memory_size).sum::<usize>();
+        if bytes <= self.spilling_bytes_threshold {
+            return Ok(LoadedPartition::Loaded {
+                build,
+                probe: task.probe,
+            });
+        }
+
+        let level = task.level + 1;
+        if level == MAX_SPILL_LEVEL {
+            return Err(ErrorCode::HashJoinSpillLimitExceeded(format!(
+                "The build side of a hash join partition is {} bytes after being repartitioned {} times, exceeds the join_spilling_bytes_threshold {}, the join keys may be skewed",
+                bytes, task.level, self.spilling_bytes_threshold
+            )));
+        }
+
+        let mut tasks = (0..NUM_PARTITIONS)
+            .map(|_| PartitionTask {
+                level,
+                build: vec![],
+                probe: vec![],
+            })
+            .collect::<Vec<_>>();
+        for (idx, block) in self
+            .scatter(DataBlock::concat(&build)?, level, true)?
+            .into_iter()
+            .enumerate()
+        {
+            if !block.is_empty() {
+                tasks[idx].build.push(self.spill_block(block).await?);
+            }
+        }
+        drop(build);
+
+        for spilled in task.probe {
+            let block = self.read_spilled_block(spilled).await?;
+            for (idx, block) in self.scatter(block, level, false)?.into_iter().enumerate() {
+                if !block.is_empty()
+                    && (!tasks[idx].build.is_empty() || self.keep_unmatched_probe_rows())
+                {
+                    tasks[idx].probe.push(self.spill_block(block).await?);
+                }
+            }
+        }
+
+        tasks.retain(|task| !task.build.is_empty() || !task.probe.is_empty());
+        self.record_spill(0, tasks.len());
+        Ok(LoadedPartition::Repartitioned(tasks))
+    }
+
+    /// Scatters the rows of the block into [`NUM_PARTITIONS`] partitions of the `level`.
+    fn scatter(&self, block: DataBlock, level: usize, build_side: bool) -> Result<Vec<DataBlock>> {
+        let num_rows = block.num_rows();
+        let (keys, drop_null_keys) = match build_side {
+            true => (self.build_key_columns(&block)?, true),
+            false => (
+                self.probe_key_columns(&block)?,
+                !self.keep_unmatched_probe_rows(),
+            ),
+        };
+        let indices = partition_indices(
+            &keys,
+            &self.join.is_null_equal,
+            level,
+            drop_null_keys,
+            num_rows,
+        );
+
+        let mut blocks = block.scatter(&indices, NUM_PARTITIONS + 1)?;
+        blocks.truncate(NUM_PARTITIONS);
+        Ok(blocks)
+    }
+
+    fn build_key_columns(&self, block: &DataBlock) -> Result<Vec<Column>> {
+        // The build keys of the left join are evaluated on the nullable columns,
+        // see `JoinHashTable::add_build_block`.
+        let block = match self.join.join_type {
+            JoinType::Left => {
+                let mut validity = MutableBitmap::new();
+                validity.extend_constant(block.num_rows(), true);
+                let validity: Bitmap = validity.into();
+                let nullable_columns = block
+                    .columns()
+                    .iter()
+                    .map(|c| JoinHashTable::set_validity(c, validity.len(), &validity))
+                    .collect::<Vec<_>>();
+                DataBlock::new(nullable_columns, block.num_rows())
+            }
+            _ => block.clone(),
+        };
+        self.key_columns(&block, &self.build_keys)
+    }
+
+    fn probe_key_columns(&self, block: &DataBlock) -> Result<Vec<Column>> {
+        self.key_columns(block, &self.probe_keys)
+    }
+
+    fn key_columns(&self, block: &DataBlock, keys: &[Expr]) -> Result<Vec<Column>> {
+        let func_ctx = self.ctx.get_function_context()?;
+        let evaluator = Evaluator::new(block, func_ctx, &BUILTIN_FUNCTIONS);
+        keys.iter()
+            .map(|expr| {
+                Ok(evaluator
+                    .run(expr)?
+                    .convert_to_full_column(expr.data_type(), block.num_rows()))
+            })
+            .collect()
+    }
+
+    async fn spill_block(&self, block: DataBlock) -> Result<SpilledBlock> {
+        let instant = Instant::now();
+        let num_rows = block.num_rows();
+        let columns = block
+            .columns()
+            .iter()
+            .map(|entry| {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                serialize_column(&column)
+            })
+            .collect::<Vec<_>>();
+
+        let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+        let columns_layout = columns.iter().map(Vec::len).collect::<Vec<_>>();
+        let data = columns.concat();
+        let bytes = data.len();
+
+        // Record the location before writing, so that a partially written file is removed too.
+        self.spilled_files.lock().unwrap().push(location.clone());
+        self.operator.write(&location, data).await?;
+        self.record_spill(bytes, 0);
+
+        info!(
+            "Write hash join spill {} of {} rows, {} bytes successfully, elapsed: {:?}",
+            location,
+            num_rows,
+            bytes,
+            instant.elapsed()
+        );
+        Ok(SpilledBlock {
+            location,
+            columns_layout,
+        })
+    }
+
+    pub async fn read_spilled_block(&self, block: SpilledBlock) -> Result<DataBlock> {
+        let instant = Instant::now();
+        let data = self.operator.read(&block.location).await?;
+        if let Err(cause) = self.operator.delete(&block.location).await {
+            error!(
+                "Cannot delete spill file {}, cause: {:?}",
+                &block.location, cause
+            );
+        }
+
+        info!(
+            "Read hash join spill {} successfully, elapsed: {:?}",
+            &block.location,
+            instant.elapsed()
+        );
+
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(block.columns_layout.len());
+        for column_layout in block.columns_layout {
+            let column =
+                deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "Cannot deserialize hash join spill {}",
+                        block.location
+                    ))
+                })?;
+            columns.push(column);
+            begin += column_layout;
+        }
+        Ok(DataBlock::new_from_columns(columns))
+    }
+
+    async fn delete_spilled_blocks(&self, blocks: Vec<SpilledBlock>) {
+        for block in blocks {
+            if let Err(cause) = self.operator.delete(&block.location).await {
+                error!(
+                    "Cannot delete spill file {}, cause: {:?}",
+                    &block.location, cause
+                );
+            }
+        }
+    }
+
+    /// Records the spilled bytes and partitions for `EXPLAIN ANALYZE`.
+    fn record_spill(&self, spilled_bytes: usize, spilled_partitions: usize) {
+        if spilled_bytes == 0 && spilled_partitions == 0 {
+            return;
+        }
+
+        if let Some(prof_span_set) = &self.prof_span_set {
+            prof_span_set
+                .lock()
+                .unwrap()
+                .update(self.plan_id, ProfSpan {
+                    spilled_bytes: spilled_bytes as u64,
+                    spilled_partitions: spilled_partitions as u64,
+                    ..Default::default()
+                });
+        }
+    }
+}
+
+impl Drop for GraceHashJoinState {
+    /// Removes the spilled files left by a failed or cancelled query.
+    fn drop(&mut self) {
+        let locations = std::mem::take(&mut *self.spilled_files.lock().unwrap());
+        if locations.is_empty() {
+            return;
+        }
+
+        let operator = self.operator.clone();
+        let dir = format!("{}/", self.location_prefix);
+        GlobalIORuntime::instance().spawn(async move {
+            // The files read have been removed, removing them again is a no-op.
+            for location in locations {
+                if let Err(cause) = operator.delete(&location).await {
+                    error!("Cannot delete spill file {}, cause: {:?}", location, cause);
+                }
+            }
+            if let Err(cause) = operator.delete(&dir).await {
+                error!("Cannot delete spill directory {}, cause: {:?}", dir, cause);
+            }
+        });
+    }
+}
@@ -264,12 +264,17 @@ impl JoinHashTable {
             probe_state.markers = Some(Self::init_markers(&probe_keys, input.num_rows()));
         }
 
-        if probe_keys
-            .iter()
-            .any(|(_, ty)| ty.is_nullable() || ty.is_null())
-        {
+        // Keys compared with `IS NOT DISTINCT FROM` keep their NULL values, which
+        // are hashed like any other value and match the NULL keys of build side.
+        let is_null_equal = &self.hash_join_desc.is_null_equal;
+        if probe_keys.iter().enumerate().any(|(idx, (_, ty))| {
+            !is_null_equal.contains(&idx) && (ty.is_nullable() || ty.is_null())
+        }) {
             let mut valids = None;
-            for (col, _) in probe_keys.iter() {
+            for (idx, (col, _)) in probe_keys.iter().enumerate() {
+                if is_null_equal.contains(&idx) {
+                    continue;
+                }
                 let (is_all_null, tmp_valids) = col.validity();
                 if is_all_null {
                     let mut m = MutableBitmap::with_capacity(input.num_rows());
@@ -14,6 +14,7 @@
 
 mod common;
 mod desc;
+mod grace_hash_join_state;
 mod hash_join_state;
 mod hash_join_state_impl;
 mod join_hash_table;
@@ -24,6 +25,13 @@ pub(crate) mod row;
 mod util;
 
 pub use desc::HashJoinDesc;
+pub use grace_hash_join_state::partition_indices;
+pub use grace_hash_join_state::GraceHashJoinState;
+pub use grace_hash_join_state::LoadedPartition;
+pub use grace_hash_join_state::PartitionTask;
+pub use grace_hash_join_state::SpilledBlock;
+pub use grace_hash_join_state::MAX_SPILL_LEVEL;
+pub use grace_hash_join_state::NUM_PARTITIONS;
 pub use hash_join_state::HashJoinState;
 pub use join_hash_table::FixedKeyHashTable;
 pub use join_hash_table::HashTable;
@@ -21,16 +21,23 @@ mod transform_hash_join;
 mod transform_left_join;
 mod transform_limit;
 mod transform_mark_join;
+mod transform_match_recognize;
 
 mod profile_wrapper;
 mod runtime_filter;
 mod transform_add_const_columns;
+mod transform_apply;
+mod transform_external_function;
+mod transform_grace_hash_join;
 mod transform_merge_block;
+mod transform_multi_table_insert;
 mod transform_resort_addon;
 mod transform_right_join;
 mod transform_right_semi_anti_join;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
+mod transform_sample;
+mod transform_sort_spill;
 
 pub use aggregator::build_partition_bucket;
 pub use aggregator::AggregateInjector;
@@ -56,15 +63,19 @@ use common_pipeline_transforms::processors::transforms::transform_block_compact;
 use common_pipeline_transforms::processors::transforms::transform_compact;
 use common_pipeline_transforms::processors::transforms::transform_sort_merge;
 use common_pipeline_transforms::processors::transforms::transform_sort_partial;
+pub use hash_join::partition_indices;
 pub use hash_join::FixedKeyHashTable;
+pub use hash_join::GraceHashJoinState;
 pub use hash_join::HashJoinDesc;
 pub use hash_join::HashJoinState;
 pub use hash_join::HashTable;
 pub use hash_join::JoinHashTable;
 pub use hash_join::SerializerHashTable;
+pub use hash_join::NUM_PARTITIONS;
 pub use profile_wrapper::ProfileWrapper;
 pub use runtime_filter::RuntimeFilterState;
 pub use transform_add_const_columns::TransformAddConstColumns;
+pub use transform_apply::TransformApply;
 pub use transform_block_compact::BlockCompactor;
 pub use transform_block_compact::TransformBlockCompact;
 pub use transform_cast_schema::TransformCastSchema;
@@ -72,6 +83,9 @@ pub use transform_compact::Compactor;
 pub use transform_compact::TransformCompact;
 pub use transform_create_sets::SubqueryReceiver;
 pub use transform_create_sets::TransformCreateSets;
+pub use transform_external_function::TransformExternalFunction;
+pub use transform_grace_hash_join::SinkGraceHashJoinBuild;
+pub use transform_grace_hash_join::TransformGraceHashJoinProbe;
 pub use transform_hash_join::SinkBuildHashTable;
 pub use transform_hash_join::TransformHashJoinProbe;
 pub use transform_left_join::LeftJoinCompactor;
@@ -79,7 +93,14 @@ pub use transform_left_join::TransformLeftJoin;
 pub use transform_limit::TransformLimit;
 pub use transform_mark_join::MarkJoinCompactor;
 pub use transform_mark_join::TransformMarkJoin;
+pub use transform_match_recognize::MatchProgram;
+pub use transform_match_recognize::MatchResult;
+pub use transform_match_recognize::TransformMatchRecognize;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_multi_table_insert::MultiTableInsertBranch;
+pub use transform_multi_table_insert::MultiTableInsertRouter;
+pub use transform_multi_table_insert::MultiTableInsertSource;
+pub use transform_multi_table_insert::MultiTableInsertTarget;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_right_join::RightJoinCompactor;
 pub use transform_right_join::TransformRightJoin;
@@ -88,5 +109,7 @@ pub use transform_right_semi_anti_join::TransformRightSemiAntiJoin;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_runtime_filter::SinkRuntimeFilterSource;
 pub use transform_runtime_filter::TransformRuntimeFilter;
+pub use transform_sample::TransformBernoulliSample;
 pub use transform_sort_merge::SortMergeCompactor;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_sort_spill::TransformSortSpill;
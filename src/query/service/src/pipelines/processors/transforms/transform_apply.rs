@@ -0,0 +1,200 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_sql::executor::Apply;
+use common_sql::executor::PhysicalPlanBuilder;
+use common_sql::optimizer::optimize_query;
+use common_sql::optimizer::OptimizerConfig;
+use common_sql::optimizer::OptimizerContext;
+use common_sql::plans::bind_outer_columns;
+use common_sql::plans::JoinType;
+use common_sql::BindContext;
+use common_sql::IndexType;
+use parking_lot::RwLock;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::schedulers::build_local_pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Evaluate the subquery of `Apply` for each row of the input, the columns of the row
+/// referenced by the subquery are bound to constants before the subquery is optimized.
+pub struct TransformApply {
+    ctx: Arc<QueryContext>,
+    apply: Apply,
+    // Offsets of the outer columns in the input.
+    outer_offsets: Vec<(IndexType, usize)>,
+    // Results of the subquery for the outer values seen so far.
+    results: HashMap<Vec<Scalar>, DataBlock>,
+}
+
+impl TransformApply {
+    pub fn try_create(ctx: Arc<QueryContext>, apply: Apply) -> Result<Self> {
+        let input_schema = apply.input.output_schema()?;
+        let outer_offsets = apply
+            .outer_columns
+            .iter()
+            .map(|index| Ok((*index, input_schema.index_of(&index.to_string())?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(TransformApply {
+            ctx,
+            apply,
+            outer_offsets,
+            results: HashMap::new(),
+        })
+    }
+
+    async fn execute_subquery(&self, values: Vec<Scalar>) -> Result<DataBlock> {
+        if self.ctx.get_aborting().load(Ordering::Acquire) {
+            return Err(ErrorCode::AbortedQuery(
+                "Aborted query, because the server is shutting down or the query was killed.",
+            ));
+        }
+
+        let values = self
+            .outer_offsets
+            .iter()
+            .map(|(index, _)| *index)
+            .zip(values)
+            .collect::<HashMap<_, _>>();
+        let s_expr = bind_outer_columns(&self.apply.subquery.s_expr, &values)?;
+
+        // The optimizer may add columns to the metadata, so each execution works on
+        // a copy of it.
+        let metadata = Arc::new(RwLock::new(self.apply.subquery.metadata.read().clone()));
+        let mut bind_context = BindContext::new();
+        for column in self.apply.output_columns.iter() {
+            bind_context.add_column_binding(column.clone());
+        }
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: false,
+        }));
+        let s_expr = optimize_query(
+            self.ctx.clone(),
+            opt_ctx,
+            metadata.clone(),
+            Box::new(bind_context),
+            s_expr,
+        )?;
+        let plan = PhysicalPlanBuilder::new(metadata, self.ctx.clone())
+            .build(&s_expr)
+            .await?;
+        let schema = plan.output_schema()?;
+
+        let build_res = build_local_pipeline(&self.ctx, &plan, false).await?;
+        let settings = ExecutorSettings::try_create(&self.ctx.get_settings(), self.ctx.get_id())?;
+        let mut executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        executor.start();
+        let mut blocks = vec![];
+        while let Some(block) = executor.pull_data()? {
+            if block.num_rows() > 0 {
+                blocks.push(block);
+            }
+        }
+
+        let offsets = self
+            .apply
+            .output_columns
+            .iter()
+            .map(|column| schema.index_of(&column.index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        let is_left = self.apply.join_type == JoinType::Left;
+        if blocks.is_empty() {
+            if !is_left {
+                return Ok(DataBlock::empty());
+            }
+            // Pad the row of the input with NULLs.
+            let entries = self
+                .apply
+                .output_columns
+                .iter()
+                .map(|column| BlockEntry {
+                    data_type: column.data_type.wrap_nullable(),
+                    value: Value::Scalar(Scalar::Null),
+                })
+                .collect();
+            return Ok(DataBlock::new(entries, 1));
+        }
+
+        let block = DataBlock::concat(&blocks)?;
+        let entries = offsets
+            .iter()
+            .map(|offset| {
+                let entry = block.get_by_offset(*offset).clone();
+                if is_left {
+                    BlockEntry {
+                        data_type: entry.data_type.wrap_nullable(),
+                        value: entry.value.wrap_nullable(),
+                    }
+                } else {
+                    entry
+                }
+            })
+            .collect();
+        Ok(DataBlock::new(entries, block.num_rows()))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformApply {
+    const NAME: &'static str = "TransformApply";
+
+    async fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let mut indices = vec![];
+        let mut results = vec![];
+        for row in 0..data.num_rows() {
+            let values = self
+                .outer_offsets
+                .iter()
+                .map(|(_, offset)| {
+                    let entry = data.get_by_offset(*offset);
+                    entry.value.index(row).unwrap().to_owned()
+                })
+                .collect::<Vec<_>>();
+            let result = match self.results.get(&values) {
+                Some(result) => result.clone(),
+                None => {
+                    let result = self.execute_subquery(values.clone()).await?;
+                    self.results.insert(values, result.clone());
+                    result
+                }
+            };
+            if result.num_rows() > 0 {
+                indices.extend(std::iter::repeat(row as u32).take(result.num_rows()));
+                results.push(result);
+            }
+        }
+
+        if results.is_empty() {
+            return Ok(DataBlock::empty_with_schema(self.apply.schema.clone()));
+        }
+        let left = data.take(&indices)?;
+        let right = DataBlock::concat(&results)?;
+        let mut entries = left.columns().to_vec();
+        entries.extend(right.columns().iter().cloned());
+        Ok(DataBlock::new(entries, indices.len()))
+    }
+}
@@ -0,0 +1,371 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_arrow::arrow::io::ipc::write::default_ipc_fields;
+use common_arrow::arrow::io::ipc::write::StreamWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_base::base::tokio::time::sleep;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_formats::output_format::scalar_to_json;
+use common_formats::output_format::ARROW_IPC_CONTENT_TYPE;
+use common_formats::FieldJsonAstDecoder;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_io::prelude::FormatSettings;
+use common_meta_app::principal::ExternalFunctionProtocol;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_sql::executor::ExternalFunction;
+use common_sql::plans::ExternalFunctionDesc;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+use tracing::warn;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The header telling the service which function to run.
+const HANDLER_HEADER: &str = "X-DATABEND-FUNCTION-HANDLER";
+
+/// Calls the external functions for each block of the input, the arguments of all the
+/// rows in the block are sent in a single request. The results are appended to the block.
+pub struct TransformExternalFunction {
+    func_ctx: FunctionContext,
+    format: FormatSettings,
+    functions: Vec<(ExternalFunctionDesc, Vec<Expr>, reqwest::Client)>,
+}
+
+impl TransformExternalFunction {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: &ExternalFunction) -> Result<Self> {
+        let functions = plan
+            .functions
+            .iter()
+            .map(|(desc, arguments, _)| {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| argument.as_expr(&BUILTIN_FUNCTIONS))
+                    .collect();
+                let client = reqwest::ClientBuilder::new()
+                    .connect_timeout(Duration::from_millis(desc.connection_timeout_ms))
+                    .timeout(Duration::from_millis(desc.request_timeout_ms))
+                    .build()
+                    .map_err(|e| {
+                        ErrorCode::ExternalFunctionError(format!(
+                            "failed to create the http client of external function {}: {}",
+                            desc.name, e
+                        ))
+                    })?;
+                Ok((desc.clone(), arguments, client))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(TransformExternalFunction {
+            func_ctx: ctx.get_function_context()?,
+            format: ctx.get_format_settings()?,
+            functions,
+        })
+    }
+
+    async fn call(
+        &self,
+        desc: &ExternalFunctionDesc,
+        client: &reqwest::Client,
+        args: DataBlock,
+    ) -> Result<Column> {
+        let num_rows = args.num_rows();
+        let (content_type, body) = match desc.protocol {
+            ExternalFunctionProtocol::Arrow => (ARROW_IPC_CONTENT_TYPE, encode_arrow(args)?),
+            ExternalFunctionProtocol::Json => ("application/json", self.encode_json(args)?),
+        };
+
+        let mut retries = 0;
+        let response = loop {
+            match post(desc, client, content_type, body.clone()).await {
+                Ok(response) => break response,
+                Err((retryable, cause)) if retryable && retries < desc.max_retries => {
+                    retries += 1;
+                    warn!(
+                        "call external function {} failed, retry {}/{}: {}",
+                        desc.name, retries, desc.max_retries, cause
+                    );
+                    sleep(Duration::from_millis(100 << retries.min(6))).await;
+                }
+                Err((_, cause)) => {
+                    return Err(ErrorCode::ExternalFunctionError(format!(
+                        "failed to call external function {} at {}: {}",
+                        desc.name, desc.address, cause
+                    )));
+                }
+            }
+        };
+
+        let column = match desc.protocol {
+            ExternalFunctionProtocol::Arrow => decode_arrow(desc, &response)?,
+            ExternalFunctionProtocol::Json => self.decode_json(desc, &response, num_rows)?,
+        };
+        if column.len() != num_rows {
+            return Err(ErrorCode::ExternalFunctionError(format!(
+                "external function {} returned {} rows, but {} rows are expected",
+                desc.name,
+                column.len(),
+                num_rows
+            )));
+        }
+        Ok(column)
+    }
+
+    /// `{"data": [[<row>, <arg>, ...], ...]}`
+    fn encode_json(&self, args: DataBlock) -> Result<Vec<u8>> {
+        let columns = args
+            .convert_to_full()
+            .columns()
+            .iter()
+            .map(|entry| entry.value.as_column().unwrap().clone())
+            .collect::<Vec<_>>();
+        let data = (0..args.num_rows())
+            .map(|row| {
+                let mut values = Vec::with_capacity(columns.len() + 1);
+                values.push(JsonValue::from(row));
+                for column in columns.iter() {
+                    values.push(scalar_to_json(column.index(row).unwrap(), &self.format));
+                }
+                JsonValue::Array(values)
+            })
+            .collect::<Vec<_>>();
+        Ok(serde_json::to_vec(&json!({ "data": data }))?)
+    }
+
+    /// `{"data": [[<row>, <result>], ...]}`, the rows can be in any order.
+    fn decode_json(
+        &self,
+        desc: &ExternalFunctionDesc,
+        response: &[u8],
+        num_rows: usize,
+    ) -> Result<Column> {
+        let invalid = |reason: &str| {
+            ErrorCode::ExternalFunctionError(format!(
+                "invalid response of external function {}: {}",
+                desc.name, reason
+            ))
+        };
+
+        let response: JsonValue =
+            serde_json::from_slice(response).map_err(|e| invalid(&e.to_string()))?;
+        let data = response
+            .get("data")
+            .and_then(|data| data.as_array())
+            .ok_or_else(|| invalid("expect an object with a `data` array"))?;
+        if data.len() != num_rows {
+            return Err(invalid(&format!(
+                "{} rows are returned, but {} rows are expected",
+                data.len(),
+                num_rows
+            )));
+        }
+
+        let mut results = vec![None; num_rows];
+        for item in data {
+            let (row, result) = match item.as_array().map(|item| item.as_slice()) {
+                Some([row, result]) => (row, result),
+                _ => return Err(invalid("expect [<row>, <result>] for each row")),
+            };
+            let row = row
+                .as_u64()
+                .filter(|row| (*row as usize) < num_rows)
+                .ok_or_else(|| invalid(&format!("invalid row number {}", row)))?;
+            results[row as usize] = Some(result);
+        }
+
+        let decoder = FieldJsonAstDecoder {
+            timezone: self.format.timezone,
+            ident_case_sensitive: false,
+        };
+        let mut builder = ColumnBuilder::with_capacity(&desc.return_type, num_rows);
+        for (row, result) in results.into_iter().enumerate() {
+            let result =
+                result.ok_or_else(|| invalid(&format!("the result of row {} is missing", row)))?;
+            decoder
+                .read_field(&mut builder, result)
+                .map_err(|e| invalid(&e.message()))?;
+        }
+        Ok(builder.build())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformExternalFunction {
+    const NAME: &'static str = "TransformExternalFunction";
+
+    async fn transform(&mut self, mut data: DataBlock) -> Result<DataBlock> {
+        for (desc, arguments, client) in self.functions.iter() {
+            if data.num_rows() == 0 {
+                data.add_column(BlockEntry {
+                    data_type: desc.return_type.clone(),
+                    value: Value::Scalar(Scalar::Null),
+                });
+                continue;
+            }
+
+            let evaluator = Evaluator::new(&data, self.func_ctx, &BUILTIN_FUNCTIONS);
+            let entries = arguments
+                .iter()
+                .map(|argument| {
+                    Ok(BlockEntry {
+                        data_type: argument.data_type().clone(),
+                        value: evaluator.run(argument)?,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let args = DataBlock::new(entries, data.num_rows());
+            let column = self.call(desc, client, args).await?;
+            data.add_column(BlockEntry {
+                data_type: desc.return_type.clone(),
+                value: Value::Column(column),
+            });
+        }
+        Ok(data)
+    }
+}
+
+/// POSTs the request, the error tells whether the request can be retried.
+async fn post(
+    desc: &ExternalFunctionDesc,
+    client: &reqwest::Client,
+    content_type: &str,
+    body: Vec<u8>,
+) -> std::result::Result<Vec<u8>, (bool, String)> {
+    let mut request = client
+        .post(&desc.address)
+        .header(reqwest::header::CONTENT_TYPE, content_type)
+        .header(HANDLER_HEADER, &desc.handler);
+    for (key, value) in desc.headers.iter() {
+        request = request.header(key, value);
+    }
+
+    let response = request
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| (e.is_connect() || e.is_timeout(), e.to_string()))?;
+    let status = response.status();
+    if !status.is_success() {
+        let retryable =
+            status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS;
+        let message = response.text().await.unwrap_or_default();
+        return Err((retryable, format!("{}: {}", status, message)));
+    }
+    let body = response
+        .bytes()
+        .await
+        .map_err(|e| (e.is_timeout(), e.to_string()))?;
+    Ok(body.to_vec())
+}
+
+/// An Arrow IPC stream with one column for each argument.
+fn encode_arrow(args: DataBlock) -> Result<Vec<u8>> {
+    let fields = args
+        .columns()
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| DataField::new(&format!("arg{}", i), entry.data_type.clone()))
+        .collect();
+    let schema = DataSchema::new(fields).to_arrow();
+    let ipc_fields = default_ipc_fields(&schema.fields);
+    let chunk = Chunk::try_from(args)?;
+
+    let mut buf = Vec::new();
+    let mut writer = StreamWriter::new(&mut buf, WriteOptions { compression: None });
+    writer.start(&schema, Some(ipc_fields.clone()))?;
+    writer.write(&chunk, Some(ipc_fields.as_slice()))?;
+    writer.finish()?;
+    Ok(buf)
+}
+
+/// An Arrow IPC stream, the first column of which holds the results.
+fn decode_arrow(desc: &ExternalFunctionDesc, response: &[u8]) -> Result<Column> {
+    let invalid = |reason: String| {
+        ErrorCode::ExternalFunctionError(format!(
+            "invalid response of external function {}: {}",
+            desc.name, reason
+        ))
+    };
+
+    let mut reader = Cursor::new(response);
+    let metadata = read_stream_metadata(&mut reader).map_err(|e| invalid(e.to_string()))?;
+    let expected_type = desc.return_type.remove_nullable();
+    let mut columns = vec![];
+    for state in StreamReader::new(reader, metadata, None) {
+        let chunk = match state.map_err(|e| invalid(e.to_string()))? {
+            StreamState::Some(chunk) => chunk,
+            StreamState::Waiting => break,
+        };
+        let array = chunk
+            .arrays()
+            .first()
+            .ok_or_else(|| invalid("the result column is missing".to_string()))?;
+        if !is_supported_arrow_type(array.data_type()) {
+            return Err(invalid(format!(
+                "unsupported arrow type {:?}",
+                array.data_type()
+            )));
+        }
+        let column = Column::from_arrow(array.as_ref(), &desc.return_type);
+        if column.data_type().remove_nullable() != expected_type {
+            return Err(invalid(format!(
+                "the result is of type {}, but {} is expected",
+                column.data_type().remove_nullable(),
+                expected_type
+            )));
+        }
+        columns.push(column);
+    }
+
+    if columns.is_empty() {
+        return Ok(ColumnBuilder::with_capacity(&desc.return_type, 0).build());
+    }
+    Ok(Column::concat(&columns))
+}
+
+fn is_supported_arrow_type(data_type: &ArrowDataType) -> bool {
+    !matches!(
+        data_type,
+        ArrowDataType::Float16
+            | ArrowDataType::Time32(_)
+            | ArrowDataType::Time64(_)
+            | ArrowDataType::Duration(_)
+            | ArrowDataType::Interval(_)
+            | ArrowDataType::FixedSizeBinary(_)
+            | ArrowDataType::FixedSizeList(_, _)
+            | ArrowDataType::Union(_, _, _)
+            | ArrowDataType::Dictionary(_, _, _)
+    )
+}
@@ -0,0 +1,337 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_sinks::AsyncSink;
+
+use super::hash_join::GraceHashJoinState;
+use super::hash_join::LoadedPartition;
+use super::hash_join::PartitionTask;
+use super::hash_join::ProbeState;
+use super::hash_join::SpilledBlock;
+use super::hash_join::NUM_PARTITIONS;
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::transforms::hash_join::HashJoinState;
+use crate::pipelines::processors::JoinHashTable;
+use crate::pipelines::processors::Processor;
+use crate::sql::plans::JoinType;
+
+pub struct SinkGraceHashJoinBuild {
+    join_state: Arc<GraceHashJoinState>,
+}
+
+impl SinkGraceHashJoinBuild {
+    pub fn try_create(join_state: Arc<GraceHashJoinState>) -> Result<Self> {
+        join_state.attach_build();
+        Ok(Self { join_state })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSink for SinkGraceHashJoinBuild {
+    const NAME: &'static str = "GraceHashJoinBuild";
+
+    async fn on_finish(&mut self) -> Result<()> {
+        self.join_state.detach_build().await
+    }
+
+    #[async_trait::unboxed_simple]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        self.join_state.build(data_block).await?;
+        Ok(false)
+    }
+}
+
+enum Step {
+    /// Waiting for the build side to be finished.
+    WaitBuild,
+    /// Probing the input blocks, the rows of the spilled partitions are buffered.
+    Probe,
+    /// Writing the buffered probe rows of the spilled partitions.
+    Spill(Vec<(usize, Vec<DataBlock>)>),
+    /// The input is finished, waiting for the other probe processors to spill their rows.
+    WaitProbe,
+    /// Loading the build side of the next spilled partition.
+    LoadPartition,
+    /// Building the hash table of the loaded partition.
+    BuildPartition(Vec<DataBlock>),
+    /// Reading the next probe block of the partition.
+    ReadProbe,
+    /// Probing the hash table of the partition.
+    ProbePartition(DataBlock),
+    Finished,
+}
+
+/// Probes the hash table in memory of [`GraceHashJoinState`], and joins the spilled partitions
+/// after the probe side is finished.
+pub struct TransformGraceHashJoinProbe {
+    input_port: Arc<InputPort>,
+    output_port: Arc<OutputPort>,
+    input_data: Option<DataBlock>,
+    output_data_blocks: VecDeque<DataBlock>,
+
+    step: Step,
+    input_finished: bool,
+    detached: bool,
+    join_state: Arc<GraceHashJoinState>,
+    probe_state: ProbeState,
+
+    // the probe rows of the spilled partitions
+    buffered_blocks: Vec<Vec<DataBlock>>,
+    buffered_bytes: usize,
+    // the partitions repartitioned by this processor
+    tasks: Vec<PartitionTask>,
+    // the hash table and the rest probe blocks of the partition being joined
+    partition_table: Option<Arc<JoinHashTable>>,
+    partition_probe: Vec<SpilledBlock>,
+}
+
+impl TransformGraceHashJoinProbe {
+    pub fn create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        join_state: Arc<GraceHashJoinState>,
+        block_size: usize,
+    ) -> Result<Box<dyn Processor>> {
+        join_state.attach_probe();
+        Ok(Box::new(TransformGraceHashJoinProbe {
+            input_port,
+            output_port,
+            input_data: None,
+            output_data_blocks: VecDeque::new(),
+            step: Step::WaitBuild,
+            input_finished: false,
+            detached: false,
+            join_state,
+            probe_state: ProbeState::with_capacity(block_size),
+            buffered_blocks: (0..NUM_PARTITIONS).map(|_| vec![]).collect(),
+            buffered_bytes: 0,
+            tasks: vec![],
+            partition_table: None,
+            partition_probe: vec![],
+        }))
+    }
+
+    fn probe(&mut self, table: &JoinHashTable, block: &DataBlock) -> Result<()> {
+        self.probe_state.clear();
+        self.output_data_blocks
+            .extend(table.probe(block, &mut self.probe_state)?);
+        Ok(())
+    }
+
+    /// Outputs the rest rows of the left join, which are kept by the hash table to make up
+    /// the blocks of the full size.
+    fn flush_left_join(&mut self, table: &JoinHashTable) -> Result<()> {
+        if *self.join_state.join_type() == JoinType::Left {
+            self.output_data_blocks.extend(table.left_join_blocks(&[])?);
+        }
+        Ok(())
+    }
+
+    fn take_buffered_blocks(&mut self) -> Vec<(usize, Vec<DataBlock>)> {
+        self.buffered_bytes = 0;
+        self.buffered_blocks
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, blocks)| !blocks.is_empty())
+            .map(|(idx, blocks)| (idx, std::mem::take(blocks)))
+            .collect()
+    }
+
+    fn detach(&mut self) {
+        if !self.detached {
+            self.detached = true;
+            self.join_state.detach_probe();
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformGraceHashJoinProbe {
+    fn name(&self) -> String {
+        "GraceHashJoin".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output_port.is_finished() {
+            self.input_port.finish();
+            self.detach();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output_port.can_push() {
+            self.input_port.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data) = self.output_data_blocks.pop_front() {
+            self.output_port.push_data(Ok(data));
+            return Ok(Event::NeedConsume);
+        }
+
+        match &self.step {
+            Step::WaitBuild => Ok(Event::Async),
+            Step::Probe => {
+                if self.input_data.is_some() {
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.has_data() {
+                    let data = self.input_port.pull_data().unwrap()?;
+                    self.input_data = Some(data);
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.is_finished() {
+                    // Spill the rest buffered rows.
+                    self.input_finished = true;
+                    return Ok(Event::Sync);
+                }
+
+                self.input_port.set_need_data();
+                Ok(Event::NeedData)
+            }
+            Step::Spill(_) | Step::WaitProbe | Step::LoadPartition | Step::ReadProbe => {
+                Ok(Event::Async)
+            }
+            Step::BuildPartition(_) | Step::ProbePartition(_) => Ok(Event::Sync),
+            Step::Finished => {
+                self.output_port.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.step, Step::Finished) {
+            Step::Probe if self.input_finished => {
+                let table = self.join_state.memory_table();
+                self.flush_left_join(&table)?;
+                self.step = match self.join_state.is_spilled() {
+                    true => Step::Spill(self.take_buffered_blocks()),
+                    false => {
+                        self.detach();
+                        Step::Finished
+                    }
+                };
+            }
+            Step::Probe => {
+                self.step = Step::Probe;
+                if let Some(data) = self.input_data.take() {
+                    let data = data.convert_to_full();
+                    let (memory_block, spilled_blocks) =
+                        self.join_state.partition_probe_block(data)?;
+                    if let Some(block) = memory_block {
+                        let table = self.join_state.memory_table();
+                        self.probe(&table, &block)?;
+                    }
+
+                    for (idx, block) in spilled_blocks {
+                        self.buffered_bytes += block.memory_size();
+                        self.buffered_blocks[idx].push(block);
+                    }
+                    if self.buffered_bytes > self.join_state.spilling_bytes_threshold() {
+                        self.step = Step::Spill(self.take_buffered_blocks());
+                    }
+                }
+            }
+            Step::BuildPartition(blocks) => {
+                self.partition_table = Some(self.join_state.create_hash_table(blocks)?);
+                self.step = Step::ReadProbe;
+            }
+            Step::ProbePartition(block) => {
+                let table = self.partition_table.clone().unwrap();
+                self.probe(&table, &block)?;
+                self.step = Step::ReadProbe;
+            }
+            _ => return Err(ErrorCode::Internal("It's a bug.")),
+        }
+        Ok(())
+    }
+
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.step, Step::Finished) {
+            Step::WaitBuild => {
+                self.join_state.wait_build_finish().await;
+                self.step = Step::Probe;
+            }
+            Step::Spill(partitions) => {
+                self.join_state.spill_probe_partitions(partitions).await?;
+                self.step = match self.input_finished {
+                    true => Step::WaitProbe,
+                    false => Step::Probe,
+                };
+            }
+            Step::WaitProbe => {
+                // The spilled partitions are joined after all the probe rows are spilled.
+                self.detach();
+                self.join_state.wait_probe_finish().await;
+                self.step = Step::LoadPartition;
+            }
+            Step::LoadPartition => {
+                let task = match self.tasks.pop() {
+                    Some(task) => task,
+                    None => match self.join_state.next_partition() {
+                        Some(task) => task,
+                        None => return Ok(()),
+                    },
+                };
+
+                self.step = match self.join_state.load_partition(task).await? {
+                    LoadedPartition::Loaded { build, probe } => {
+                        self.partition_probe = probe;
+                        Step::BuildPartition(build)
+                    }
+                    LoadedPartition::Repartitioned(tasks) => {
+                        self.tasks.extend(tasks);
+                        Step::LoadPartition
+                    }
+                    LoadedPartition::Skipped => Step::LoadPartition,
+                };
+            }
+            Step::ReadProbe => match self.partition_probe.pop() {
+                Some(spilled) => {
+                    let block = self.join_state.read_spilled_block(spilled).await?;
+                    self.step = Step::ProbePartition(block);
+                }
+                None => {
+                    let table = self.partition_table.take().unwrap();
+                    self.flush_left_join(&table)?;
+                    self.step = Step::LoadPartition;
+                }
+            },
+            _ => return Err(ErrorCode::Internal("It's a bug.")),
+        }
+        Ok(())
+    }
+}
+
+impl Drop for TransformGraceHashJoinProbe {
+    fn drop(&mut self) {
+        // Don't block the other probe processors if the query is cancelled.
+        self.detach();
+    }
+}
@@ -0,0 +1,473 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::BlockEntry;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransform;
+use common_sql::plans::MatchMeasure;
+use common_sql::plans::MatchMeasureFunction;
+use common_sql::plans::RowPattern;
+use common_sql::plans::RowPatternQuantifier;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Inst {
+    /// Consume a row classified as the variable.
+    Variable(usize),
+    /// Continue at both, the first one is preferred.
+    Split(usize, usize),
+    Jump(usize),
+    Match,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MatchResult {
+    /// The variables of the rows of the match, which starts at the first row.
+    Matched(Vec<usize>),
+    NoMatch,
+    /// The match depends on the rows that haven't arrived yet.
+    NeedMoreRows,
+}
+
+struct Thread {
+    pc: usize,
+    path: Vec<usize>,
+}
+
+/// The NFA of a row pattern, simulated like a Pike VM. The threads are kept in priority order,
+/// so the quantifiers are greedy and the alternatives are tried from left to right.
+pub struct MatchProgram {
+    insts: Vec<Inst>,
+}
+
+impl MatchProgram {
+    pub fn compile(pattern: &RowPattern) -> Self {
+        let mut insts = vec![];
+        Self::compile_pattern(pattern, &mut insts);
+        insts.push(Inst::Match);
+        MatchProgram { insts }
+    }
+
+    fn compile_pattern(pattern: &RowPattern, insts: &mut Vec<Inst>) {
+        match pattern {
+            RowPattern::Variable(variable) => insts.push(Inst::Variable(*variable)),
+            RowPattern::Concat(patterns) => {
+                for pattern in patterns {
+                    Self::compile_pattern(pattern, insts);
+                }
+            }
+            RowPattern::Alternation(patterns) => {
+                let mut jumps = vec![];
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i + 1 == patterns.len() {
+                        Self::compile_pattern(pattern, insts);
+                    } else {
+                        let split = insts.len();
+                        insts.push(Inst::Split(split + 1, 0));
+                        Self::compile_pattern(pattern, insts);
+                        jumps.push(insts.len());
+                        insts.push(Inst::Jump(0));
+                        insts[split] = Inst::Split(split + 1, insts.len());
+                    }
+                }
+                for jump in jumps {
+                    insts[jump] = Inst::Jump(insts.len());
+                }
+            }
+            RowPattern::Quantified {
+                pattern,
+                quantifier: RowPatternQuantifier::ZeroOrMore,
+            } => {
+                let split = insts.len();
+                insts.push(Inst::Split(split + 1, 0));
+                Self::compile_pattern(pattern, insts);
+                insts.push(Inst::Jump(split));
+                insts[split] = Inst::Split(split + 1, insts.len());
+            }
+            RowPattern::Quantified {
+                pattern,
+                quantifier: RowPatternQuantifier::OneOrMore,
+            } => {
+                let start = insts.len();
+                Self::compile_pattern(pattern, insts);
+                insts.push(Inst::Split(start, insts.len() + 1));
+            }
+            RowPattern::Quantified {
+                pattern,
+                quantifier: RowPatternQuantifier::ZeroOrOne,
+            } => {
+                let split = insts.len();
+                insts.push(Inst::Split(split + 1, 0));
+                Self::compile_pattern(pattern, insts);
+                insts[split] = Inst::Split(split + 1, insts.len());
+            }
+        }
+    }
+
+    /// Find the match starting at the first of `num_rows` rows, `classify(row, variable)` tells
+    /// whether the row can be classified as the variable. Unless `complete`, more rows may
+    /// follow, and a match that could still be extended by them is not returned.
+    pub fn find(
+        &self,
+        num_rows: usize,
+        classify: impl Fn(usize, usize) -> bool,
+        complete: bool,
+    ) -> MatchResult {
+        let mut visited = vec![false; self.insts.len()];
+        let mut threads = vec![];
+        self.add_thread(&mut threads, &mut visited, 0, vec![]);
+
+        let mut matched = None;
+        let mut row = 0;
+        while !threads.is_empty() {
+            let mut next = vec![];
+            visited.fill(false);
+            for thread in threads {
+                match self.insts[thread.pc] {
+                    // The threads after the match have lower priorities.
+                    Inst::Match => {
+                        matched = Some(thread.path);
+                        break;
+                    }
+                    Inst::Variable(variable) => {
+                        if row == num_rows {
+                            if !complete {
+                                return MatchResult::NeedMoreRows;
+                            }
+                        } else if classify(row, variable) {
+                            let mut path = thread.path;
+                            path.push(variable);
+                            self.add_thread(&mut next, &mut visited, thread.pc + 1, path);
+                        }
+                    }
+                    Inst::Split(..) | Inst::Jump(_) => unreachable!(),
+                }
+            }
+            threads = next;
+            row += 1;
+        }
+
+        match matched {
+            Some(path) => MatchResult::Matched(path),
+            None => MatchResult::NoMatch,
+        }
+    }
+
+    fn add_thread(
+        &self,
+        threads: &mut Vec<Thread>,
+        visited: &mut [bool],
+        pc: usize,
+        path: Vec<usize>,
+    ) {
+        if visited[pc] {
+            return;
+        }
+        visited[pc] = true;
+        match self.insts[pc] {
+            Inst::Split(x, y) => {
+                self.add_thread(threads, visited, x, path.clone());
+                self.add_thread(threads, visited, y, path);
+            }
+            Inst::Jump(x) => self.add_thread(threads, visited, x, path),
+            Inst::Variable(_) | Inst::Match => threads.push(Thread { pc, path }),
+        }
+    }
+}
+
+#[derive(Default)]
+struct MeasureState {
+    first: Option<usize>,
+    last: Option<usize>,
+    count: u64,
+}
+
+/// `MATCH_RECOGNIZE` over the input sorted by the partition keys and the `ORDER BY` keys.
+///
+/// The rows of the current partition from the start of the next match are kept across blocks,
+/// until the match is found or the partition ends. After a match, the next match starts at the
+/// row following it, and a row without a match is skipped.
+pub struct TransformMatchRecognize {
+    partition_offsets: Vec<usize>,
+    /// Offsets of the `DEFINE` predicates, a variable without predicate matches any row.
+    predicate_offsets: Vec<Option<usize>>,
+    variable_names: Vec<String>,
+    program: MatchProgram,
+    /// The measures, with the offsets of the columns in the input as their columns.
+    measures: Vec<MatchMeasure>,
+    all_rows_per_match: bool,
+
+    partition: Option<Vec<Scalar>>,
+    pending: Option<DataBlock>,
+    match_number: u64,
+}
+
+impl TransformMatchRecognize {
+    pub fn new(
+        partition_offsets: Vec<usize>,
+        predicate_offsets: Vec<Option<usize>>,
+        variable_names: Vec<String>,
+        pattern: &RowPattern,
+        measures: Vec<MatchMeasure>,
+        all_rows_per_match: bool,
+    ) -> Self {
+        TransformMatchRecognize {
+            partition_offsets,
+            predicate_offsets,
+            variable_names,
+            program: MatchProgram::compile(pattern),
+            measures,
+            all_rows_per_match,
+            partition: None,
+            pending: None,
+            match_number: 0,
+        }
+    }
+
+    fn in_partition(&self, block: &DataBlock, row: usize, partition: &[Scalar]) -> bool {
+        self.partition_offsets
+            .iter()
+            .zip(partition.iter())
+            .all(|(offset, value)| {
+                block.get_by_offset(*offset).value.index(row) == Some(value.as_ref())
+            })
+    }
+
+    fn classify(&self, block: &DataBlock, row: usize, variable: usize) -> bool {
+        match self.predicate_offsets[variable] {
+            Some(offset) => matches!(
+                block.get_by_offset(offset).value.index(row),
+                Some(ScalarRef::Boolean(true))
+            ),
+            None => true,
+        }
+    }
+
+    fn append(&mut self, block: DataBlock) -> Result<()> {
+        self.pending = Some(match self.pending.take() {
+            Some(pending) => DataBlock::concat(&[pending, block])?,
+            None => block,
+        });
+        Ok(())
+    }
+
+    fn finish_partition(&mut self, output: &mut Vec<DataBlock>) -> Result<()> {
+        self.find_matches(true, output)?;
+        self.partition = None;
+        self.pending = None;
+        self.match_number = 0;
+        Ok(())
+    }
+
+    fn find_matches(&mut self, complete: bool, output: &mut Vec<DataBlock>) -> Result<()> {
+        let pending = match self.pending.take() {
+            Some(pending) => pending,
+            None => return Ok(()),
+        };
+
+        let num_rows = pending.num_rows();
+        let mut start = 0;
+        while start < num_rows {
+            let result = self.program.find(
+                num_rows - start,
+                |row, variable| self.classify(&pending, start + row, variable),
+                complete,
+            );
+            match result {
+                MatchResult::Matched(path) if !path.is_empty() => {
+                    self.match_number += 1;
+                    output.push(self.build_match(&pending, start, &path));
+                    start += path.len();
+                }
+                // Empty matches are ignored.
+                MatchResult::Matched(_) | MatchResult::NoMatch => start += 1,
+                MatchResult::NeedMoreRows => break,
+            }
+        }
+        self.pending = Some(pending.slice(start..num_rows));
+        Ok(())
+    }
+
+    fn build_match(&self, block: &DataBlock, start: usize, path: &[usize]) -> DataBlock {
+        let num_rows = if self.all_rows_per_match {
+            path.len()
+        } else {
+            1
+        };
+        let mut states = Vec::with_capacity(self.measures.len());
+        states.resize_with(self.measures.len(), MeasureState::default);
+        let mut builders = self
+            .measures
+            .iter()
+            .map(|measure| ColumnBuilder::with_capacity(&measure.data_type, num_rows))
+            .collect::<Vec<_>>();
+
+        for (i, variable) in path.iter().enumerate() {
+            let row = start + i;
+            for (measure, state) in self.measures.iter().zip(states.iter_mut()) {
+                Self::update_measure(&measure.function, state, block, row, *variable);
+            }
+            if self.all_rows_per_match || i + 1 == path.len() {
+                for ((measure, state), builder) in self
+                    .measures
+                    .iter()
+                    .zip(states.iter())
+                    .zip(builders.iter_mut())
+                {
+                    let value = self.measure_value(&measure.function, state, block, *variable);
+                    builder.push(value.as_ref());
+                }
+            }
+        }
+
+        let mut result = if self.all_rows_per_match {
+            block.slice(start..start + path.len())
+        } else {
+            let columns = self
+                .partition_offsets
+                .iter()
+                .map(|offset| {
+                    let entry = block.get_by_offset(*offset);
+                    BlockEntry {
+                        data_type: entry.data_type.clone(),
+                        value: Value::Scalar(entry.value.index(start).unwrap().to_owned()),
+                    }
+                })
+                .collect();
+            DataBlock::new(columns, 1)
+        };
+        for (measure, builder) in self.measures.iter().zip(builders) {
+            result.add_column(BlockEntry {
+                data_type: measure.data_type.clone(),
+                value: Value::Column(builder.build()),
+            });
+        }
+        result
+    }
+
+    fn update_measure(
+        function: &MatchMeasureFunction,
+        state: &mut MeasureState,
+        block: &DataBlock,
+        row: usize,
+        row_variable: usize,
+    ) {
+        let classified = |variable: &Option<usize>| variable.map_or(true, |v| v == row_variable);
+        match function {
+            MatchMeasureFunction::First { variable, .. }
+            | MatchMeasureFunction::Last { variable, .. }
+                if classified(variable) =>
+            {
+                state.first.get_or_insert(row);
+                state.last = Some(row);
+            }
+            MatchMeasureFunction::Count { variable, column } if classified(variable) => {
+                let is_null = column.map_or(false, |column| {
+                    block.get_by_offset(column).value.index(row) == Some(ScalarRef::Null)
+                });
+                if !is_null {
+                    state.count += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn measure_value(
+        &self,
+        function: &MatchMeasureFunction,
+        state: &MeasureState,
+        block: &DataBlock,
+        row_variable: usize,
+    ) -> Scalar {
+        let value_at = |column: usize, row: Option<usize>| match row {
+            Some(row) => block
+                .get_by_offset(column)
+                .value
+                .index(row)
+                .unwrap()
+                .to_owned(),
+            None => Scalar::Null,
+        };
+        match function {
+            MatchMeasureFunction::MatchNumber => {
+                Scalar::Number(NumberScalar::UInt64(self.match_number))
+            }
+            MatchMeasureFunction::Classifier => {
+                Scalar::String(self.variable_names[row_variable].as_bytes().to_vec())
+            }
+            MatchMeasureFunction::First { column, .. } => value_at(*column, state.first),
+            MatchMeasureFunction::Last { column, .. } => value_at(*column, state.last),
+            MatchMeasureFunction::Count { .. } => Scalar::Number(NumberScalar::UInt64(state.count)),
+        }
+    }
+}
+
+impl AccumulatingTransform for TransformMatchRecognize {
+    const NAME: &'static str = "TransformMatchRecognize";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let num_rows = data.num_rows();
+        let mut output = vec![];
+        let mut begin = 0;
+        while begin < num_rows {
+            let partition = self
+                .partition_offsets
+                .iter()
+                .map(|offset| {
+                    let value = &data.get_by_offset(*offset).value;
+                    value.index(begin).unwrap().to_owned()
+                })
+                .collect::<Vec<_>>();
+            let mut end = begin + 1;
+            while end < num_rows && self.in_partition(&data, end, &partition) {
+                end += 1;
+            }
+
+            if self.partition.as_ref() != Some(&partition) {
+                self.finish_partition(&mut output)?;
+                self.partition = Some(partition);
+            }
+            self.append(data.slice(begin..end))?;
+            if end < num_rows {
+                self.finish_partition(&mut output)?;
+            } else {
+                // The partition may continue in the next block.
+                self.find_matches(false, &mut output)?;
+            }
+            begin = end;
+        }
+
+        if output.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![DataBlock::concat(&output)?])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        let mut blocks = vec![];
+        if output {
+            self.finish_partition(&mut blocks)?;
+        }
+        if blocks.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![DataBlock::concat(&blocks)?])
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use async_channel::Receiver;
+use async_channel::Sender;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::filter_helper::FilterHelpers;
+use common_expression::types::BooleanType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_sinks::AsyncSinker;
+use common_pipeline_sources::AsyncSource;
+
+#[derive(Clone)]
+pub struct MultiTableInsertBranch {
+    /// `None` for the `ELSE` branch, which takes the rows that satisfy no other branch.
+    pub condition: Option<Expr>,
+    pub targets: Vec<MultiTableInsertTarget>,
+}
+
+#[derive(Clone)]
+pub struct MultiTableInsertTarget {
+    /// Columns of the rows sent to the target, evaluated on the input rows.
+    pub values: Vec<Expr>,
+    pub sender: Sender<DataBlock>,
+    pub inserted_rows: Arc<AtomicU64>,
+}
+
+/// Route the rows of the source of a multi-table insert to the pipelines appending to
+/// the targets, see `MultiTableInsertSource`. The conditions of the branches are
+/// evaluated once for each row; a row is taken by the first satisfied branch if
+/// `is_first` is set, or by all of them otherwise.
+pub struct MultiTableInsertRouter {
+    is_first: bool,
+    branches: Vec<MultiTableInsertBranch>,
+    func_ctx: FunctionContext,
+}
+
+impl MultiTableInsertRouter {
+    pub fn create(
+        input: Arc<InputPort>,
+        is_first: bool,
+        branches: Vec<MultiTableInsertBranch>,
+        func_ctx: FunctionContext,
+    ) -> Box<dyn Processor> {
+        AsyncSinker::create(input, MultiTableInsertRouter {
+            is_first,
+            branches,
+            func_ctx,
+        })
+    }
+
+    /// Returns the rows of `data_block` routed to each target.
+    fn route(&self, data_block: &DataBlock) -> Result<Vec<(&MultiTableInsertTarget, DataBlock)>> {
+        let num_rows = data_block.num_rows();
+        let mut outputs = vec![];
+        let evaluator = Evaluator::new(data_block, self.func_ctx, &BUILTIN_FUNCTIONS);
+        let mut unmatched = vec![true; num_rows];
+        for branch in self.branches.iter() {
+            let selection = match &branch.condition {
+                Some(condition) => {
+                    let predicate = evaluator
+                        .run(condition)?
+                        .try_downcast::<BooleanType>()
+                        .unwrap();
+                    let satisfied = FilterHelpers::filter_to_bitmap(predicate, num_rows);
+                    (0..num_rows)
+                        .map(|row| satisfied.get(row) && (!self.is_first || unmatched[row]))
+                        .collect::<Bitmap>()
+                }
+                None => unmatched.iter().copied().collect::<Bitmap>(),
+            };
+            for (row, unmatched) in unmatched.iter_mut().enumerate() {
+                *unmatched &= !selection.get_bit(row);
+            }
+            if selection.unset_bits() == num_rows {
+                continue;
+            }
+
+            let block = data_block.clone().filter_with_bitmap(&selection)?;
+            let block_evaluator = Evaluator::new(&block, self.func_ctx, &BUILTIN_FUNCTIONS);
+            for target in branch.targets.iter() {
+                let entries = target
+                    .values
+                    .iter()
+                    .map(|expr| {
+                        Ok(BlockEntry {
+                            data_type: expr.data_type().clone(),
+                            value: block_evaluator.run(expr)?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                outputs.push((target, DataBlock::new(entries, block.num_rows())));
+            }
+        }
+
+        Ok(outputs)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSink for MultiTableInsertRouter {
+    const NAME: &'static str = "MultiTableInsertRouter";
+
+    async fn on_finish(&mut self) -> Result<()> {
+        // The sources of the targets finish once all the senders are dropped.
+        self.branches.clear();
+        Ok(())
+    }
+
+    #[async_trait::unboxed_simple]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if data_block.num_rows() == 0 {
+            return Ok(false);
+        }
+
+        for (target, block) in self.route(&data_block)? {
+            target
+                .inserted_rows
+                .fetch_add(block.num_rows() as u64, Ordering::Relaxed);
+            target
+                .sender
+                .send(block)
+                .await
+                .map_err(|_| ErrorCode::Internal("target of multi-table insert is closed"))?;
+        }
+        Ok(false)
+    }
+}
+
+/// Receive the rows routed to a target by `MultiTableInsertRouter`.
+pub struct MultiTableInsertSource {
+    receiver: Receiver<DataBlock>,
+}
+
+impl MultiTableInsertSource {
+    pub fn create(receiver: Receiver<DataBlock>) -> Self {
+        MultiTableInsertSource { receiver }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for MultiTableInsertSource {
+    const NAME: &'static str = "MultiTableInsertSource";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        // The channel is closed once the router finishes.
+        Ok(self.receiver.recv().await.ok())
+    }
+}
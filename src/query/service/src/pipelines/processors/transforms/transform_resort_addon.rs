@@ -16,11 +16,13 @@ use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::ConstantFolder;
 use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::Expr;
 use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
 use common_sql::parse_exprs;
@@ -55,6 +57,7 @@ where Self: Transform
             .map(DataField::from)
             .collect::<Vec<_>>();
 
+        let func_ctx = ctx.get_function_context()?;
         let mut exprs = Vec::with_capacity(fields.len());
         for f in fields.iter() {
             let expr = if !input_schema.has_field(f.name()) {
@@ -69,6 +72,10 @@ where Self: Transform
                             dest_type: f.data_type().clone(),
                         };
                     }
+                    // Fold the default once per statement, so `now()` gets the same value for
+                    // all rows, while per-row functions like `uuid()` are kept and evaluated
+                    // for each block.
+                    let (expr, _) = ConstantFolder::fold(&expr, func_ctx, &BUILTIN_FUNCTIONS);
                     expr
                 } else {
                     let default_value = Scalar::default_value(f.data_type());
@@ -91,7 +98,6 @@ where Self: Transform
             exprs.push(expr);
         }
 
-        let func_ctx = ctx.get_function_context()?;
         let expression_transform = CompoundBlockOperator {
             ctx: func_ctx,
             operators: vec![BlockOperator::Map { exprs }],
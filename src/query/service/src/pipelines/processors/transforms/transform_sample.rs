@@ -0,0 +1,71 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use rand::distributions::Bernoulli;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::transforms::transform::Transform;
+use crate::pipelines::processors::transforms::transform::Transformer;
+
+/// `TABLESAMPLE BERNOULLI`, keep each row with the probability `fraction`.
+///
+/// Each processor draws from its own generator, seeded by the seed of the
+/// sample and the index of the processor.
+pub struct TransformBernoulliSample {
+    rng: StdRng,
+    distribution: Bernoulli,
+}
+
+impl TransformBernoulliSample {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        fraction: f64,
+        seed: u64,
+    ) -> Result<ProcessorPtr> {
+        let distribution = Bernoulli::new(fraction)
+            .map_err(|e| ErrorCode::Internal(format!("Invalid sample fraction: {e}")))?;
+        Ok(ProcessorPtr::create(Transformer::create(
+            input,
+            output,
+            TransformBernoulliSample {
+                rng: StdRng::seed_from_u64(seed),
+                distribution,
+            },
+        )))
+    }
+}
+
+impl Transform for TransformBernoulliSample {
+    const NAME: &'static str = "BernoulliSampleTransform";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let bitmap: Bitmap = (&mut self.rng)
+            .sample_iter(self.distribution)
+            .take(data.num_rows())
+            .collect();
+        data.filter_with_bitmap(&bitmap)
+    }
+}
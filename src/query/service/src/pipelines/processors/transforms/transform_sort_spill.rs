@@ -0,0 +1,572 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_arrow::arrow::compute::sort::row::RowConverter as ArrowRowConverter;
+use common_arrow::arrow::compute::sort::row::Rows as ArrowRows;
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SortColumnDescription;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::sort::Cursor;
+use common_pipeline_transforms::processors::transforms::sort::LoserTree;
+use common_pipeline_transforms::processors::transforms::sort::RowConverter;
+use common_pipeline_transforms::processors::transforms::Compactor;
+use common_pipeline_transforms::processors::transforms::SortMergeCompactor;
+use opendal::Operator;
+use tracing::error;
+use tracing::info;
+
+type RunSorter = SortMergeCompactor<ArrowRows, ArrowRowConverter>;
+
+/// Sorts the sorted blocks of its input with the memory limited.
+///
+/// The input blocks are buffered until their size reaches the threshold, then they are
+/// sorted into a run which is spilled to storage, one file for each block of the run. At
+/// last, the runs are merged by a loser tree, the blocks of each run are read back one by
+/// one while merging. No data is spilled if the whole input fits in the threshold.
+///
+/// The merge is stable, the rows of the earlier input blocks come first among the equal ones.
+pub struct TransformSortSpill {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    schema: DataSchemaRef,
+    block_size: usize,
+    sort_desc: Vec<SortColumnDescription>,
+    spilling_bytes_threshold: usize,
+
+    operator: Operator,
+    // the directory of the files spilled by this processor
+    location_prefix: String,
+
+    state: State,
+    buffered_blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+    runs: Vec<SortedRun>,
+    num_spilled_runs: usize,
+    merger: Option<RunMerger>,
+    output_blocks: VecDeque<DataBlock>,
+    aborting: Arc<AtomicBool>,
+}
+
+enum State {
+    /// Collecting the input blocks.
+    Collect,
+    /// The buffered blocks exceed the threshold, or the input is finished.
+    Sort,
+    /// Writing the serialized blocks of the last run.
+    Spill(Vec<SpillingBlock>),
+    /// Merging the runs.
+    Merge,
+    /// Removing the directory of the spilled files.
+    Cleanup,
+    /// Pushing the output blocks.
+    Output,
+}
+
+struct SpillingBlock {
+    location: String,
+    columns_layout: Vec<usize>,
+    data: Vec<u8>,
+}
+
+struct SpilledBlock {
+    location: String,
+    columns_layout: Vec<usize>,
+}
+
+#[derive(Default)]
+struct SortedRun {
+    // the blocks of the run in memory
+    blocks: VecDeque<DataBlock>,
+    // the blocks of the run in storage, they come after the ones in memory
+    spilled: VecDeque<SpilledBlock>,
+}
+
+struct RunMerger {
+    converter: ArrowRowConverter,
+    tree: LoserTree<Cursor<ArrowRows>>,
+    // the blocks referred by `indices`, and the current blocks of the runs
+    blocks: Vec<DataBlock>,
+    // the index in `blocks` of the current block of each run
+    block_index: Vec<usize>,
+    // the merged rows to output, (index in `blocks`, row index)
+    indices: Vec<(usize, usize)>,
+    // the run whose next block has to be read from storage
+    pending_run: Option<usize>,
+}
+
+impl TransformSortSpill {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        block_size: usize,
+        sort_desc: Vec<SortColumnDescription>,
+        spilling_bytes_threshold: usize,
+        operator: Operator,
+        location_prefix: String,
+    ) -> Result<Self> {
+        Ok(TransformSortSpill {
+            input,
+            output,
+            schema,
+            block_size,
+            sort_desc,
+            spilling_bytes_threshold,
+            operator,
+            location_prefix: format!("{}/{}", location_prefix, GlobalUniqName::unique()),
+            state: State::Collect,
+            buffered_blocks: vec![],
+            buffered_bytes: 0,
+            runs: vec![],
+            num_spilled_runs: 0,
+            merger: None,
+            output_blocks: VecDeque::new(),
+            aborting: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    /// Returns the number of the runs spilled to storage.
+    pub fn num_spilled_runs(&self) -> usize {
+        self.num_spilled_runs
+    }
+
+    fn sort_buffered_blocks(&mut self) -> Result<Vec<DataBlock>> {
+        let blocks = std::mem::take(&mut self.buffered_blocks);
+        self.buffered_bytes = 0;
+
+        let sorter = RunSorter::try_create(
+            self.schema.clone(),
+            self.block_size,
+            None,
+            self.sort_desc.clone(),
+        )?;
+        sorter.compact_final(&blocks)
+    }
+
+    fn serialize_run(&self, blocks: Vec<DataBlock>) -> Vec<SpillingBlock> {
+        blocks
+            .into_iter()
+            .map(|block| {
+                let num_rows = block.num_rows();
+                let columns = block
+                    .columns()
+                    .iter()
+                    .map(|entry| {
+                        let column = entry
+                            .value
+                            .convert_to_full_column(&entry.data_type, num_rows);
+                        serialize_column(&column)
+                    })
+                    .collect::<Vec<_>>();
+
+                SpillingBlock {
+                    location: format!("{}/{}", self.location_prefix, GlobalUniqName::unique()),
+                    columns_layout: columns.iter().map(Vec::len).collect(),
+                    data: columns.concat(),
+                }
+            })
+            .collect()
+    }
+
+    async fn spill_run(&mut self, blocks: Vec<SpillingBlock>) -> Result<()> {
+        let instant = Instant::now();
+        let num_blocks = blocks.len();
+        let mut total_size = 0;
+
+        self.runs.push(SortedRun::default());
+        self.num_spilled_runs += 1;
+        for block in blocks {
+            total_size += block.data.len();
+            // Record the location before writing, so that a partially written file is removed too.
+            let run = self.runs.last_mut().unwrap();
+            run.spilled.push_back(SpilledBlock {
+                location: block.location.clone(),
+                columns_layout: block.columns_layout,
+            });
+            self.operator.write(&block.location, block.data).await?;
+        }
+
+        info!(
+            "Write sort spill run of {} blocks, {} bytes successfully, elapsed: {:?}",
+            num_blocks,
+            total_size,
+            instant.elapsed()
+        );
+        Ok(())
+    }
+
+    async fn read_spilled_block(operator: &Operator, block: SpilledBlock) -> Result<DataBlock> {
+        let instant = Instant::now();
+        let data = operator.read(&block.location).await?;
+
+        if let Err(cause) = operator.delete(&block.location).await {
+            error!(
+                "Cannot delete spill file {}, cause: {:?}",
+                &block.location, cause
+            );
+        }
+
+        info!(
+            "Read sort spill {} successfully, elapsed: {:?}",
+            &block.location,
+            instant.elapsed()
+        );
+
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(block.columns_layout.len());
+        for column_layout in block.columns_layout {
+            let column =
+                deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                    ErrorCode::Internal(format!("Cannot deserialize sort spill {}", block.location))
+                })?;
+            columns.push(column);
+            begin += column_layout;
+        }
+        Ok(DataBlock::new_from_columns(columns))
+    }
+
+    fn create_cursor(
+        &self,
+        converter: &mut ArrowRowConverter,
+        run: usize,
+        block: &DataBlock,
+    ) -> Result<Cursor<ArrowRows>> {
+        let columns = self
+            .sort_desc
+            .iter()
+            .map(|desc| block.get_by_offset(desc.offset).clone())
+            .collect::<Vec<_>>();
+        let rows = converter.convert(&columns, block.num_rows())?;
+        Ok(Cursor::try_create(run, rows))
+    }
+
+    fn create_merger(&mut self) -> Result<()> {
+        let mut converter = ArrowRowConverter::create(self.sort_desc.clone(), self.schema.clone())?;
+        let mut blocks = Vec::with_capacity(self.runs.len());
+        let mut block_index = Vec::with_capacity(self.runs.len());
+        let mut cursors = Vec::with_capacity(self.runs.len());
+
+        for run in 0..self.runs.len() {
+            match self.runs[run].blocks.pop_front() {
+                Some(block) => {
+                    cursors.push(Some(self.create_cursor(&mut converter, run, &block)?));
+                    block_index.push(blocks.len());
+                    blocks.push(block);
+                }
+                None => {
+                    cursors.push(None);
+                    block_index.push(usize::MAX);
+                }
+            }
+        }
+
+        self.merger = Some(RunMerger {
+            converter,
+            tree: LoserTree::create(cursors),
+            blocks,
+            block_index,
+            indices: Vec::with_capacity(self.block_size),
+            pending_run: None,
+        });
+        Ok(())
+    }
+
+    /// Merges the runs till an output block is full, or the next block of a run has to be
+    /// read from storage.
+    fn merge(&mut self) -> Result<()> {
+        let mut merger = self.merger.take().unwrap();
+
+        while merger.indices.len() < self.block_size {
+            let Some(cursor) = merger.tree.winner_mut() else {
+                break;
+            };
+
+            let run = cursor.input_index;
+            merger
+                .indices
+                .push((merger.block_index[run], cursor.advance()));
+            if !cursor.is_finished() {
+                merger.tree.adjust();
+                continue;
+            }
+
+            match self.runs[run].blocks.pop_front() {
+                Some(block) => {
+                    let cursor = self.create_cursor(&mut merger.converter, run, &block)?;
+                    merger.block_index[run] = merger.blocks.len();
+                    merger.blocks.push(block);
+                    merger.tree.replace_winner(Some(cursor));
+                }
+                None if !self.runs[run].spilled.is_empty() => {
+                    merger.pending_run = Some(run);
+                    break;
+                }
+                None => {
+                    merger.block_index[run] = usize::MAX;
+                    merger.tree.replace_winner(None);
+                }
+            }
+        }
+
+        if self.aborting.load(Ordering::Relaxed) {
+            return Err(ErrorCode::AbortedQuery(
+                "Aborted query, because the server is shutting down or the query was killed.",
+            ));
+        }
+
+        if merger.indices.len() >= self.block_size
+            || (merger.tree.is_empty() && !merger.indices.is_empty())
+        {
+            self.output_blocks.push_back(merger.build_block());
+        }
+
+        self.merger = Some(merger);
+        Ok(())
+    }
+
+    async fn load_pending_run(&mut self) -> Result<()> {
+        let run = self.merger.as_ref().unwrap().pending_run.unwrap();
+        let spilled = self.runs[run].spilled.pop_front().unwrap();
+        let block = Self::read_spilled_block(&self.operator, spilled).await?;
+
+        let mut merger = self.merger.take().unwrap();
+        debug_assert_eq!(merger.tree.winner_index(), run);
+        let cursor = self.create_cursor(&mut merger.converter, run, &block)?;
+        merger.block_index[run] = merger.blocks.len();
+        merger.blocks.push(block);
+        merger.tree.replace_winner(Some(cursor));
+        merger.pending_run = None;
+
+        self.merger = Some(merger);
+        Ok(())
+    }
+}
+
+impl RunMerger {
+    fn is_finished(&self) -> bool {
+        self.tree.is_empty() && self.indices.is_empty()
+    }
+
+    fn build_block(&mut self) -> DataBlock {
+        let mut merge_slices: Vec<(usize, usize, usize)> = Vec::with_capacity(self.indices.len());
+        for (block_idx, row_idx) in self.indices.drain(..) {
+            match merge_slices.last_mut() {
+                // The rows of the same block are merged in order.
+                Some(slice) if slice.0 == block_idx => slice.2 += 1,
+                _ => merge_slices.push((block_idx, row_idx, 1)),
+            }
+        }
+        let block = DataBlock::take_by_slices_limit_from_blocks(&self.blocks, &merge_slices, None);
+
+        // Only the current blocks of the runs are still referred.
+        let mut blocks = Vec::with_capacity(self.block_index.len());
+        for index in self.block_index.iter_mut() {
+            if *index != usize::MAX {
+                blocks.push(self.blocks[*index].clone());
+                *index = blocks.len() - 1;
+            }
+        }
+        self.blocks = blocks;
+
+        block
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSortSpill {
+    fn name(&self) -> String {
+        String::from("TransformSortSpill")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_blocks.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match &self.state {
+            State::Collect => {
+                if self.input.has_data() {
+                    let block = self.input.pull_data().unwrap()?;
+                    if !block.is_empty() {
+                        self.buffered_bytes += block.memory_size();
+                        self.buffered_blocks.push(block);
+                    }
+
+                    if self.buffered_bytes >= self.spilling_bytes_threshold {
+                        self.input.set_not_need_data();
+                        self.state = State::Sort;
+                        return Ok(Event::Sync);
+                    }
+                }
+
+                if self.input.is_finished() {
+                    self.state = State::Sort;
+                    return Ok(Event::Sync);
+                }
+
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+            State::Sort => Ok(Event::Sync),
+            State::Spill(_) => Ok(Event::Async),
+            State::Merge => match &self.merger {
+                None => match self
+                    .runs
+                    .iter()
+                    .any(|run| run.blocks.is_empty() && !run.spilled.is_empty())
+                {
+                    true => Ok(Event::Async),
+                    false => Ok(Event::Sync),
+                },
+                Some(merger) if merger.pending_run.is_some() => Ok(Event::Async),
+                Some(merger) if merger.is_finished() => {
+                    self.state = State::Cleanup;
+                    Ok(Event::Async)
+                }
+                Some(_) => Ok(Event::Sync),
+            },
+            State::Cleanup => Ok(Event::Async),
+            State::Output => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn interrupt(&self) {
+        self.aborting.store(true, Ordering::Release);
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match &self.state {
+            State::Sort if !self.input.is_finished() => {
+                let blocks = self.sort_buffered_blocks()?;
+                self.state = State::Spill(self.serialize_run(blocks));
+            }
+            State::Sort if self.runs.is_empty() => {
+                // Everything fits in memory.
+                self.output_blocks = self.sort_buffered_blocks()?.into();
+                self.state = State::Output;
+            }
+            State::Sort => {
+                // The last run is merged without spilling.
+                let blocks = self.sort_buffered_blocks()?;
+                self.runs.push(SortedRun {
+                    blocks: blocks.into(),
+                    spilled: VecDeque::new(),
+                });
+                self.state = State::Merge;
+            }
+            State::Merge if self.merger.is_none() => self.create_merger()?,
+            State::Merge => self.merge()?,
+            _ => return Err(ErrorCode::Internal("It's a bug.")),
+        }
+        Ok(())
+    }
+
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Output) {
+            State::Spill(blocks) => {
+                self.spill_run(blocks).await?;
+                self.state = State::Collect;
+            }
+            State::Merge if self.merger.is_none() => {
+                // Read the first block of the runs in storage.
+                for run in 0..self.runs.len() {
+                    if self.runs[run].blocks.is_empty() {
+                        if let Some(spilled) = self.runs[run].spilled.pop_front() {
+                            let block = Self::read_spilled_block(&self.operator, spilled).await?;
+                            self.runs[run].blocks.push_back(block);
+                        }
+                    }
+                }
+                self.state = State::Merge;
+            }
+            State::Merge => {
+                self.load_pending_run().await?;
+                self.state = State::Merge;
+            }
+            State::Cleanup => {
+                // All the spilled files have been removed after being read.
+                let dir = format!("{}/", self.location_prefix);
+                if let Err(cause) = self.operator.delete(&dir).await {
+                    error!("Cannot delete spill directory {}, cause: {:?}", dir, cause);
+                }
+            }
+            _ => return Err(ErrorCode::Internal("It's a bug.")),
+        }
+        Ok(())
+    }
+}
+
+impl Drop for TransformSortSpill {
+    /// Removes the spilled files left by a failed or cancelled query.
+    fn drop(&mut self) {
+        let locations = self
+            .runs
+            .iter()
+            .flat_map(|run| run.spilled.iter().map(|block| block.location.clone()))
+            .collect::<Vec<_>>();
+        if locations.is_empty() {
+            return;
+        }
+
+        let operator = self.operator.clone();
+        let dir = format!("{}/", self.location_prefix);
+        GlobalIORuntime::instance().spawn(async move {
+            for location in locations {
+                if let Err(cause) = operator.delete(&location).await {
+                    error!("Cannot delete spill file {}, cause: {:?}", location, cause);
+                }
+            }
+            if let Err(cause) = operator.delete(&dir).await {
+                error!("Cannot delete spill directory {}, cause: {:?}", dir, cause);
+            }
+        });
+    }
+}
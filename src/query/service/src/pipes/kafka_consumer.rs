@@ -0,0 +1,130 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use rskafka::client::partition::OffsetAt;
+use rskafka::client::Client;
+use rskafka::client::ClientBuilder;
+
+/// Reads the records of a kafka topic from the given offsets.
+///
+/// The offsets are not committed to kafka, they're kept by the pipe along with the
+/// data loaded.
+pub struct KafkaConsumer {
+    topic: String,
+    client: Client,
+}
+
+pub struct KafkaRecord {
+    pub partition: i32,
+    pub offset: i64,
+    pub payload: Vec<u8>,
+}
+
+pub struct KafkaFetch {
+    /// The records with a value, a deleted record is skipped.
+    pub records: Vec<KafkaRecord>,
+    pub start_offset: i64,
+    /// The offset to fetch next, after the records fetched.
+    pub next_offset: i64,
+    pub high_watermark: i64,
+}
+
+impl KafkaConsumer {
+    const MAX_WAIT_MS: i32 = 500;
+
+    pub async fn connect(brokers: &[String], topic: &str) -> Result<Self> {
+        let client = ClientBuilder::new(brokers.to_vec())
+            .build()
+            .await
+            .map_err(|cause| {
+                ErrorCode::PipeSourceError(format!(
+                    "Cannot connect to kafka brokers {}, cause: {}",
+                    brokers.join(","),
+                    cause
+                ))
+            })?;
+        Ok(KafkaConsumer {
+            topic: topic.to_string(),
+            client,
+        })
+    }
+
+    pub async fn partitions(&self) -> Result<Vec<i32>> {
+        let topics = self.client.list_topics().await.map_err(|cause| {
+            ErrorCode::PipeSourceError(format!("Cannot list kafka topics, cause: {}", cause))
+        })?;
+        match topics.into_iter().find(|topic| topic.name == self.topic) {
+            Some(topic) => Ok(topic.partitions.into_iter().collect()),
+            None => Err(ErrorCode::PipeSourceError(format!(
+                "Unknown kafka topic {}",
+                self.topic
+            ))),
+        }
+    }
+
+    /// Fetches up to about `max_bytes` of records of the partition, starting from `offset`,
+    /// or from the earliest record kept by kafka if the partition has never been read.
+    pub async fn fetch(
+        &self,
+        partition: i32,
+        offset: Option<i64>,
+        max_bytes: i32,
+    ) -> Result<KafkaFetch> {
+        let map_err = |cause: rskafka::client::error::Error| {
+            ErrorCode::PipeSourceError(format!(
+                "Cannot fetch partition {} of kafka topic {}, cause: {}",
+                partition, self.topic, cause
+            ))
+        };
+
+        let client = self
+            .client
+            .partition_client(self.topic.clone(), partition)
+            .map_err(map_err)?;
+        let start_offset = match offset {
+            Some(offset) => offset,
+            None => client
+                .get_offset(OffsetAt::Earliest)
+                .await
+                .map_err(map_err)?,
+        };
+        let (fetched, high_watermark) = client
+            .fetch_records(start_offset, 1..max_bytes, Self::MAX_WAIT_MS)
+            .await
+            .map_err(map_err)?;
+
+        let mut next_offset = start_offset;
+        let mut records = Vec::with_capacity(fetched.len());
+        // A compressed batch of records is fetched as a whole, so it might start before the offset.
+        for fetched in fetched.into_iter().filter(|r| r.offset >= start_offset) {
+            next_offset = next_offset.max(fetched.offset + 1);
+            if let Some(payload) = fetched.record.value {
+                records.push(KafkaRecord {
+                    partition,
+                    offset: fetched.offset,
+                    payload,
+                });
+            }
+        }
+
+        Ok(KafkaFetch {
+            records,
+            start_offset,
+            next_offset,
+            high_watermark,
+        })
+    }
+}
@@ -0,0 +1,402 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_base::base::tokio::time::sleep as tokio_async_sleep;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table::AppendMode;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_management::PipeLease;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipePartitionStatus;
+use common_meta_app::principal::PipeRejectedRecord;
+use common_meta_app::principal::PipeSource;
+use common_meta_app::principal::PipeStatus;
+use common_pipeline_sources::input_formats::InputContext;
+use common_pipeline_sources::input_formats::StreamingReadBatch;
+use common_storages_fuse::operations::AppendOperationLogEntry;
+use common_storages_fuse::operations::SourceOffsetsAdvance;
+use common_storages_fuse::FuseTable;
+use common_users::UserApiProvider;
+use dashmap::DashMap;
+use tracing::info;
+use tracing::warn;
+
+use crate::interpreters::append2table;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipes::KafkaConsumer;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// Consumes the source of a pipe into its table while holding the lease of the pipe.
+///
+/// Each batch of records is committed along with the offsets after it in the same
+/// snapshot of the table, so a batch is loaded exactly once, even if the lease moves
+/// to another node in the middle of it.
+pub struct PipeRunner {
+    tenant: String,
+    holder: String,
+}
+
+struct LoadedBatch {
+    records: usize,
+    rows_loaded: u64,
+    rejected: Vec<PipeRejectedRecord>,
+}
+
+impl PipeRunner {
+    const LEASE_TTL: Duration = Duration::from_secs(60);
+    const IDLE_INTERVAL: Duration = Duration::from_secs(1);
+    const RETRY_INTERVAL: Duration = Duration::from_secs(10);
+    const REJECTED_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+    const MAX_FETCH_BYTES: i32 = 1024 * 1024;
+    const MAX_REJECTED_PAYLOAD: usize = 1024;
+
+    pub fn create(tenant: &str, holder: &str) -> Self {
+        PipeRunner {
+            tenant: tenant.to_string(),
+            holder: holder.to_string(),
+        }
+    }
+
+    /// Consumes the pipe until it's dropped, its lease is lost or the node shuts down.
+    /// Returns right away if another node holds the lease.
+    pub async fn run(&self, name: &str, shutdown: Arc<AtomicBool>) -> Result<()> {
+        let client = UserApiProvider::instance().get_pipe_api_client(&self.tenant)?;
+        let Some(lease) = client
+            .acquire_pipe_lease(name, &self.holder, Self::LEASE_TTL)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        info!("Pipe {} starts consuming on {}", name, self.holder);
+        let (lease, res) = self.consume(name, lease, &shutdown).await;
+        if let Err(cause) = client.release_pipe_lease(name, lease).await {
+            warn!("Cannot release the lease of pipe {}: {:?}", name, cause);
+        }
+        info!("Pipe {} stops consuming on {}", name, self.holder);
+        res
+    }
+
+    // The lease is renewed between the batches, a batch takes far less than the lease.
+    async fn consume(
+        &self,
+        name: &str,
+        mut lease: PipeLease,
+        shutdown: &AtomicBool,
+    ) -> (PipeLease, Result<()>) {
+        let user_mgr = UserApiProvider::instance();
+        let mut status = match user_mgr.get_pipe_statuses(&self.tenant).await {
+            Ok(statuses) => statuses.into_iter().find(|s| s.pipe_name == name),
+            Err(cause) => return (lease, Err(cause)),
+        }
+        .unwrap_or_else(|| PipeStatus {
+            pipe_name: name.to_string(),
+            holder: String::new(),
+            partitions: vec![],
+            rows_loaded: 0,
+            rows_rejected: 0,
+            rows_per_second: 0,
+            last_committed_on: None,
+            last_error: None,
+            updated_on: Utc::now(),
+        });
+        status.holder = self.holder.clone();
+
+        let mut consumer: Option<(PipeSource, KafkaConsumer)> = None;
+        let mut renewed_on = Instant::now();
+        while !shutdown.load(Ordering::Relaxed) {
+            if renewed_on.elapsed() >= Self::LEASE_TTL / 3 {
+                let renewed = match user_mgr.get_pipe_api_client(&self.tenant) {
+                    Ok(client) => client.renew_pipe_lease(name, lease, Self::LEASE_TTL).await,
+                    Err(cause) => Err(cause),
+                };
+                match renewed {
+                    Ok(Some(renewed)) => lease = renewed,
+                    Ok(None) => {
+                        warn!("Pipe {} lost its lease while consuming", name);
+                        return (lease, Ok(()));
+                    }
+                    Err(cause) => return (lease, Err(cause)),
+                }
+                renewed_on = Instant::now();
+            }
+
+            let pipe = match user_mgr.get_pipe(&self.tenant, name).await {
+                Ok(pipe) => pipe,
+                Err(cause) if cause.code() == ErrorCode::UNKNOWN_PIPE => return (lease, Ok(())),
+                Err(cause) => return (lease, Err(cause)),
+            };
+            // Re-connects if the pipe has been re-created with another source.
+            if !matches!(&consumer, Some((source, _)) if source == &pipe.source) {
+                consumer = None;
+            }
+
+            let started = Instant::now();
+            let res = match &consumer {
+                Some((_, kafka)) => self.load_batch(&pipe, kafka, &mut status).await,
+                None => match Self::connect(&pipe.source).await {
+                    Ok(kafka) => {
+                        let res = self.load_batch(&pipe, &kafka, &mut status).await;
+                        consumer = Some((pipe.source.clone(), kafka));
+                        res
+                    }
+                    Err(cause) => Err(cause),
+                },
+            };
+
+            let wait = match res {
+                Ok(batch) if batch.records == 0 => Some(Self::IDLE_INTERVAL),
+                Ok(batch) => {
+                    let elapsed_ms = started.elapsed().as_millis().max(1) as u64;
+                    status.rows_loaded += batch.rows_loaded;
+                    status.rows_rejected += batch.rejected.len() as u64;
+                    status.rows_per_second = batch.rows_loaded * 1000 / elapsed_ms;
+                    status.last_committed_on = Some(Utc::now());
+                    status.last_error = None;
+                    if !batch.rejected.is_empty() {
+                        if let Err(cause) = user_mgr
+                            .add_pipe_rejected_records(
+                                &self.tenant,
+                                batch.rejected,
+                                Self::REJECTED_RETENTION,
+                            )
+                            .await
+                        {
+                            warn!(
+                                "Cannot record rejected records of pipe {}: {:?}",
+                                name, cause
+                            );
+                        }
+                    }
+                    None
+                }
+                Err(cause) => {
+                    warn!("Pipe {} failed to load a batch: {:?}", name, cause);
+                    // The batch is retried from the committed offsets.
+                    consumer = None;
+                    status.rows_per_second = 0;
+                    status.last_error = Some(cause.to_string());
+                    Some(Self::RETRY_INTERVAL)
+                }
+            };
+
+            status.updated_on = Utc::now();
+            if let Err(cause) = user_mgr.set_pipe_status(&self.tenant, status.clone()).await {
+                warn!("Cannot update the status of pipe {}: {:?}", name, cause);
+            }
+            if let Some(wait) = wait {
+                tokio_async_sleep(wait).await;
+            }
+        }
+        (lease, Ok(()))
+    }
+
+    async fn connect(source: &PipeSource) -> Result<KafkaConsumer> {
+        match source {
+            PipeSource::Kafka { brokers, topic, .. } => {
+                KafkaConsumer::connect(brokers, topic).await
+            }
+        }
+    }
+
+    // Fetches the records after the offsets in the latest snapshot of the table, loads them
+    // and commits them along with the offsets after them.
+    async fn load_batch(
+        &self,
+        pipe: &PipeInfo,
+        kafka: &KafkaConsumer,
+        status: &mut PipeStatus,
+    ) -> Result<LoadedBatch> {
+        let ctx = self.create_query_context(pipe).await?;
+        let table = ctx
+            .get_table(CATALOG_DEFAULT, &pipe.database, &pipe.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        fuse_table.check_mutable()?;
+
+        let source = pipe.source.offsets_key();
+        let from = fuse_table
+            .read_table_snapshot()
+            .await?
+            .and_then(|snapshot| snapshot.source_offsets.get(&source).cloned())
+            .unwrap_or_default();
+
+        let mut to = from.clone();
+        let mut partitions = vec![];
+        let mut records = vec![];
+        let mut num_records = 0;
+        for partition in kafka.partitions().await? {
+            let offset = from.get(&partition).copied();
+            let fetch = kafka
+                .fetch(partition, offset, Self::MAX_FETCH_BYTES)
+                .await?;
+            if fetch.next_offset != fetch.start_offset {
+                num_records += (fetch.next_offset - fetch.start_offset) as usize;
+                to.insert(partition, fetch.next_offset);
+            }
+            partitions.push(PipePartitionStatus {
+                partition,
+                offset: fetch.next_offset,
+                high_watermark: fetch.high_watermark,
+            });
+            records.extend(fetch.records);
+        }
+        status.partitions = partitions;
+        if to == from {
+            return Ok(LoadedBatch {
+                records: 0,
+                rows_loaded: 0,
+                rejected: vec![],
+            });
+        }
+
+        // Every record is a split of its own, so that the failures of the format can
+        // be traced back to the records.
+        let records: BTreeMap<String, _> = records
+            .into_iter()
+            .filter(|record| !record.payload.is_empty())
+            .map(|record| (format!("{}/{}", record.partition, record.offset), record))
+            .collect();
+        let mut operation_log = vec![];
+        let mut rows_loaded = 0;
+        let mut rejected = vec![];
+        if !records.is_empty() {
+            let (tx, rx) = tokio::sync::mpsc::channel(records.len());
+            for (path, record) in &records {
+                let batch = StreamingReadBatch {
+                    data: record.payload.clone(),
+                    path: path.clone(),
+                    is_start: true,
+                    compression: None,
+                };
+                tx.send(Ok(batch)).await.map_err(|cause| {
+                    ErrorCode::Internal(format!("Cannot send the records of pipe: {}", cause))
+                })?;
+            }
+            drop(tx);
+
+            let schema = table.schema();
+            let mut input_context = InputContext::try_create_from_insert_file_format(
+                rx,
+                ctx.get_settings(),
+                pipe.file_format_options.clone(),
+                schema.clone(),
+                ctx.get_scan_progress(),
+                false,
+                table.get_block_compact_thresholds(),
+            )
+            .await?;
+            input_context.on_error_mode = pipe.on_error.clone();
+            input_context.on_error_map = Some(DashMap::new());
+            let input_context = Arc::new(input_context);
+
+            let mut build_res = PipelineBuildResult::create();
+            input_context
+                .format
+                .exec_stream(input_context.clone(), &mut build_res.main_pipeline)?;
+            append2table(
+                ctx.clone(),
+                table.clone(),
+                Arc::new(DataSchema::from(schema.as_ref())),
+                &mut build_res,
+                false,
+                false,
+                AppendMode::Copy,
+            )?;
+            Self::execute_pipeline(&ctx, build_res)?;
+
+            for (path, record) in &records {
+                let Some(file_status) = input_context.copy_status.get(path) else {
+                    continue;
+                };
+                rows_loaded += file_status.rows_loaded;
+                if file_status.error_count == 0 {
+                    continue;
+                }
+                let payload =
+                    &record.payload[..record.payload.len().min(Self::MAX_REJECTED_PAYLOAD)];
+                rejected.push(PipeRejectedRecord {
+                    pipe_name: pipe.name.clone(),
+                    partition: record.partition,
+                    offset: record.offset,
+                    error: file_status
+                        .first_error
+                        .map(|e| e.message)
+                        .unwrap_or_default(),
+                    payload: String::from_utf8_lossy(payload).to_string(),
+                    rejected_on: Utc::now(),
+                });
+            }
+
+            operation_log = ctx
+                .consume_precommit_blocks()
+                .iter()
+                .map(AppendOperationLogEntry::try_from)
+                .collect::<Result<Vec<_>>>()?;
+        }
+
+        fuse_table
+            .do_commit_with_source_offsets(ctx.clone(), operation_log, SourceOffsetsAdvance {
+                source,
+                from,
+                to,
+            })
+            .await?;
+
+        Ok(LoadedBatch {
+            records: num_records,
+            rows_loaded,
+            rejected,
+        })
+    }
+
+    // The batch is loaded as the owner of the pipe, in a session of its own.
+    async fn create_query_context(&self, pipe: &PipeInfo) -> Result<Arc<QueryContext>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        let user = UserApiProvider::instance()
+            .get_user(&self.tenant, pipe.owner.clone())
+            .await?;
+        session.set_authed_user(user, None).await?;
+        session.set_current_database(pipe.database.clone());
+        session.create_query_context().await
+    }
+
+    fn execute_pipeline(ctx: &Arc<QueryContext>, mut build_res: PipelineBuildResult) -> Result<()> {
+        let settings = ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let executor_settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+        let executor =
+            PipelineCompleteExecutor::try_create(build_res.main_pipeline, executor_settings)?;
+        ctx.set_executor(Arc::downgrade(&executor.get_inner()));
+        executor.execute()
+    }
+}
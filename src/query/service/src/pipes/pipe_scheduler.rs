@@ -0,0 +1,143 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Mutex;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::base::tokio::time::sleep as tokio_async_sleep;
+use common_base::base::GlobalInstance;
+use common_base::base::GlobalUniqName;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_users::UserApiProvider;
+use futures::future::select;
+use futures::future::Either;
+use parking_lot::Mutex as SyncMutex;
+use tracing::warn;
+
+use crate::pipes::PipeRunner;
+
+/// Starts consuming the pipes no query node is consuming.
+///
+/// Every query node checks the pipes, the lease of a pipe makes sure that only
+/// one of them consumes it at a time.
+pub struct PipeScheduler {
+    tenant: String,
+    holder: String,
+    check_interval: Duration,
+    shutdown: Arc<AtomicBool>,
+    shutdown_notify: Arc<Notify>,
+    shutdown_handler: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl PipeScheduler {
+    const CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+    pub fn init(cfg: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(PipeScheduler {
+            tenant: cfg.query.tenant_id.clone(),
+            holder: GlobalUniqName::unique(),
+            check_interval: Self::CHECK_INTERVAL,
+            shutdown: Arc::new(AtomicBool::new(false)),
+            shutdown_notify: Arc::new(Notify::new()),
+            shutdown_handler: Mutex::new(None),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<PipeScheduler> {
+        GlobalInstance::get()
+    }
+
+    pub async fn start(&self) {
+        let runner = Arc::new(PipeRunner::create(&self.tenant, &self.holder));
+        let running = Arc::new(SyncMutex::new(HashSet::new()));
+        let tenant = self.tenant.clone();
+        let check_interval = self.check_interval;
+        let shutdown = self.shutdown.clone();
+        let shutdown_notify = self.shutdown_notify.clone();
+
+        let handler = tokio::spawn(async move {
+            let mut shutdown_notified = Box::pin(shutdown_notify.notified());
+
+            while !shutdown.load(Ordering::Relaxed) {
+                let sleep = tokio_async_sleep(check_interval);
+                match select(shutdown_notified, Box::pin(sleep)).await {
+                    Either::Left((_, _)) => {
+                        break;
+                    }
+                    Either::Right((_, new_shutdown_notified)) => {
+                        shutdown_notified = new_shutdown_notified;
+                        if let Err(cause) =
+                            Self::schedule_pipes(&tenant, &runner, &running, &shutdown).await
+                        {
+                            warn!("Pipe scheduler failed to check pipes: {:?}", cause);
+                        }
+                    }
+                }
+            }
+        });
+
+        *self.shutdown_handler.lock().await = Some(handler);
+    }
+
+    /// The runners stop after the batch they're loading.
+    pub async fn shutdown(&self) -> Result<()> {
+        if let Some(shutdown_handler) = self.shutdown_handler.lock().await.take() {
+            self.shutdown.store(true, Ordering::Relaxed);
+            self.shutdown_notify.notify_waiters();
+            if let Err(shutdown_failure) = shutdown_handler.await {
+                return Err(ErrorCode::TokioError(format!(
+                    "Cannot shutdown pipe scheduler, cause {:?}",
+                    shutdown_failure
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    // A runner keeps consuming its pipe, so it's only spawned if this node doesn't run
+    // the pipe already.
+    async fn schedule_pipes(
+        tenant: &str,
+        runner: &Arc<PipeRunner>,
+        running: &Arc<SyncMutex<HashSet<String>>>,
+        shutdown: &Arc<AtomicBool>,
+    ) -> Result<()> {
+        for pipe in UserApiProvider::instance().get_pipes(tenant).await? {
+            if !running.lock().insert(pipe.name.clone()) {
+                continue;
+            }
+
+            let runner = runner.clone();
+            let running = running.clone();
+            let shutdown = shutdown.clone();
+            tokio::spawn(async move {
+                if let Err(cause) = runner.run(&pipe.name, shutdown).await {
+                    warn!("Pipe {} failed: {:?}", pipe.name, cause);
+                }
+                running.lock().remove(&pipe.name);
+            });
+        }
+        Ok(())
+    }
+}
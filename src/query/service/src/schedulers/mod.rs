@@ -13,9 +13,11 @@
 // limitations under the License.
 
 mod fragments;
+mod recursive_cte;
 mod scheduler;
 
 pub use fragments::*;
+pub use recursive_cte::execute_ctes;
 pub use scheduler::build_distributed_pipeline;
 pub use scheduler::build_local_pipeline;
 pub use scheduler::build_query_pipeline;
@@ -0,0 +1,229 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::HashMethod;
+use common_expression::HashMethodSerializer;
+use common_profile::ProfSpanSetRef;
+use common_sql::executor::RecursiveCte;
+use common_sql::IndexType;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::executor::PhysicalPlan;
+
+/// Compute the results of all recursive and materialized CTEs in `plan`, so that
+/// the pipeline of `plan` can read them as plain sources.
+///
+/// Inner recursive CTEs are computed first, because the anchor or the recursive
+/// member of an outer one may read them. A materialized CTE is computed before
+/// its input, which reads it.
+pub fn execute_ctes(ctx: &Arc<QueryContext>, plan: &PhysicalPlan) -> Result<()> {
+    if let PhysicalPlan::MaterializedCte(materialized_cte) = plan {
+        let cte = &materialized_cte.cte;
+        execute_ctes(ctx, cte)?;
+        let blocks = execute_plan(ctx, cte)?;
+        let blocks = project_blocks(blocks, &cte.output_schema()?, &materialized_cte.cte_columns)?;
+        ctx.set_materialized_cte_result(&materialized_cte.table_name, blocks);
+        return execute_ctes(ctx, &materialized_cte.input);
+    }
+
+    for child in plan.children() {
+        execute_ctes(ctx, child)?;
+    }
+
+    if let PhysicalPlan::RecursiveCte(recursive_cte) = plan {
+        let results = RecursiveCteExecutor::create(ctx.clone(), recursive_cte)?.execute()?;
+        ctx.set_recursive_cte_result(&recursive_cte.table_name, results);
+    }
+
+    Ok(())
+}
+
+fn execute_plan(ctx: &Arc<QueryContext>, plan: &PhysicalPlan) -> Result<Vec<DataBlock>> {
+    if ctx.get_aborting().load(Ordering::Acquire) {
+        return Err(ErrorCode::AbortedQuery(
+            "Aborted query, because the server is shutting down or the query was killed.",
+        ));
+    }
+
+    let settings = ctx.get_settings();
+    let builder = PipelineBuilder::create(ctx.clone(), false, ProfSpanSetRef::default());
+    let mut build_res = builder.finalize(plan)?;
+    build_res.set_max_threads(settings.get_max_threads()? as usize);
+
+    let settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+    let mut executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+    ctx.set_executor(Arc::downgrade(&executor.get_inner()));
+    executor.start();
+
+    let mut blocks = vec![];
+    while let Some(block) = executor.pull_data()? {
+        if block.num_rows() > 0 {
+            blocks.push(block);
+        }
+    }
+    Ok(blocks)
+}
+
+// Reorder the columns of `blocks` to the order of `columns`, so that the outputs
+// of the anchor and the recursive member line up with the working table, and the
+// stored result of a materialized CTE lines up with its scans.
+fn project_blocks(
+    blocks: Vec<DataBlock>,
+    schema: &DataSchemaRef,
+    columns: &[IndexType],
+) -> Result<Vec<DataBlock>> {
+    let offsets = columns
+        .iter()
+        .map(|index| schema.index_of(&index.to_string()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(blocks
+        .into_iter()
+        .map(|block| {
+            let entries = offsets
+                .iter()
+                .map(|offset| block.get_by_offset(*offset).clone())
+                .collect();
+            DataBlock::new(entries, block.num_rows())
+        })
+        .collect())
+}
+
+struct RecursiveCteExecutor<'a> {
+    ctx: Arc<QueryContext>,
+    plan: &'a RecursiveCte,
+
+    max_iterations: u64,
+    max_rows: u64,
+    // Serialized rows seen so far, only used by `UNION`.
+    seen: HashSet<Vec<u8>>,
+    num_rows: u64,
+}
+
+impl<'a> RecursiveCteExecutor<'a> {
+    fn create(ctx: Arc<QueryContext>, plan: &'a RecursiveCte) -> Result<Self> {
+        let settings = ctx.get_settings();
+        Ok(RecursiveCteExecutor {
+            max_iterations: settings.get_max_recursive_cte_iterations()?,
+            max_rows: settings.get_max_recursive_cte_rows()?,
+            ctx,
+            plan,
+            seen: HashSet::new(),
+            num_rows: 0,
+        })
+    }
+
+    /// Run the anchor once, then run the recursive member over the rows produced
+    /// by the previous iteration until it produces no new rows.
+    fn execute(mut self) -> Result<Vec<DataBlock>> {
+        let anchor = execute_plan(&self.ctx, &self.plan.anchor)?;
+        let anchor = project_blocks(
+            anchor,
+            &self.plan.anchor.output_schema()?,
+            &self.plan.anchor_columns,
+        )?;
+        let mut working_table = self.append_rows(anchor)?;
+        let mut results = working_table.clone();
+
+        let recursive_schema = self.plan.recursive.output_schema()?;
+        let mut iterations = 0;
+        while !working_table.is_empty() {
+            if iterations >= self.max_iterations {
+                return Err(ErrorCode::RecursiveCteLimitExceeded(format!(
+                    "Recursive CTE still produces rows after {} iterations, the limit is set by max_recursive_cte_iterations",
+                    self.max_iterations
+                )));
+            }
+            iterations += 1;
+
+            self.ctx
+                .set_recursive_cte_table(&self.plan.table_name, working_table);
+            let blocks = execute_plan(&self.ctx, &self.plan.recursive)?;
+            let blocks = project_blocks(blocks, &recursive_schema, &self.plan.recursive_columns)?;
+            working_table = self.append_rows(blocks)?;
+            results.extend(working_table.iter().cloned());
+        }
+
+        self.ctx
+            .set_recursive_cte_table(&self.plan.table_name, vec![]);
+        Ok(results)
+    }
+
+    // Count the new rows against `max_recursive_cte_rows`. With `UNION`, rows
+    // that have been produced before are dropped, which also ends cycles.
+    fn append_rows(&mut self, blocks: Vec<DataBlock>) -> Result<Vec<DataBlock>> {
+        let blocks = if self.plan.distinct {
+            blocks
+                .into_iter()
+                .map(|block| self.remove_seen_rows(block))
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .filter(|block| block.num_rows() > 0)
+                .collect()
+        } else {
+            blocks
+        };
+
+        self.num_rows += blocks.iter().map(|b| b.num_rows() as u64).sum::<u64>();
+        if self.max_rows > 0 && self.num_rows > self.max_rows {
+            return Err(ErrorCode::RecursiveCteLimitExceeded(format!(
+                "Recursive CTE produces more than {} rows, the limit is set by max_recursive_cte_rows",
+                self.max_rows
+            )));
+        }
+        Ok(blocks)
+    }
+
+    fn remove_seen_rows(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let columns = block
+            .columns()
+            .iter()
+            .map(|entry| {
+                (
+                    entry
+                        .value
+                        .convert_to_full_column(&entry.data_type, num_rows),
+                    entry.data_type.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let method = HashMethodSerializer::default();
+        let keys_state = method.build_keys_state(&columns, num_rows)?;
+        let mut indices = Vec::with_capacity(num_rows);
+        for (row, key) in method.build_keys_iter(&keys_state)?.enumerate() {
+            if self.seen.insert(key.to_vec()) {
+                indices.push(row as u32);
+            }
+        }
+
+        if indices.len() == num_rows {
+            return Ok(block);
+        }
+        block.take(&indices)
+    }
+}
@@ -20,6 +20,7 @@ use common_profile::ProfSpanSetRef;
 
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
+use crate::schedulers::execute_ctes;
 use crate::schedulers::Fragmenter;
 use crate::schedulers::QueryFragmentsActions;
 use crate::sessions::QueryContext;
@@ -65,6 +66,8 @@ pub async fn build_local_pipeline(
     plan: &PhysicalPlan,
     enable_profiling: bool,
 ) -> Result<PipelineBuildResult> {
+    execute_ctes(ctx, plan)?;
+
     let pipeline =
         PipelineBuilder::create(ctx.clone(), enable_profiling, ProfSpanSetRef::default());
     let mut build_res = pipeline.finalize(plan)?;
@@ -28,7 +28,7 @@ use tonic::transport::Server;
 use tonic::transport::ServerTlsConfig;
 use tracing::info;
 
-use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use crate::servers::flight_sql::flight_sql_service::FlightServiceImpl;
 use crate::servers::Server as DatabendQueryServer;
 
 pub struct FlightSQLServer {
@@ -69,7 +69,7 @@ impl FlightSQLServer {
     }
 
     pub async fn start_with_incoming(&mut self, listener_stream: TcpListenerStream) -> Result<()> {
-        let flight_sql_service = FlightSqlServiceImpl::create();
+        let flight_service = FlightServiceImpl::create();
         let builder = Server::builder();
         let mut builder = if self.config.flight_sql_tls_server_enabled() {
             info!("databend query tls flight sql enabled");
@@ -87,7 +87,7 @@ impl FlightSQLServer {
         };
 
         let server = builder
-            .add_service(FlightServiceServer::new(flight_sql_service))
+            .add_service(FlightServiceServer::new(flight_service))
             .serve_with_incoming_shutdown(listener_stream, self.shutdown_notify());
 
         tokio::spawn(server);
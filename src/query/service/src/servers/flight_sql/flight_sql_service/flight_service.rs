@@ -0,0 +1,150 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow_flight::flight_service_server::FlightService;
+use arrow_flight::Action;
+use arrow_flight::Criteria;
+use arrow_flight::Empty;
+use arrow_flight::FlightData;
+use arrow_flight::FlightDescriptor;
+use arrow_flight::FlightInfo;
+use arrow_flight::HandshakeRequest;
+use arrow_flight::SchemaResult;
+use arrow_flight::Ticket;
+use prost::Message;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status;
+use tonic::Streaming;
+
+use super::session_options::GetSessionOptionsRequest;
+use super::session_options::SetSessionOptionsRequest;
+use super::session_options::GET_SESSION_OPTIONS;
+use super::session_options::SET_SESSION_OPTIONS;
+use super::FlightSqlServiceImpl;
+
+/// The Flight service of the query node: Flight SQL, plus the session options actions of
+/// Flight, which the Flight SQL server of arrow-flight doesn't dispatch.
+#[derive(Clone)]
+pub struct FlightServiceImpl {
+    sql: FlightSqlServiceImpl,
+}
+
+impl FlightServiceImpl {
+    pub fn create() -> Self {
+        FlightServiceImpl {
+            sql: FlightSqlServiceImpl::create(),
+        }
+    }
+
+    fn action_result(body: Vec<u8>) -> Response<<Self as FlightService>::DoActionStream> {
+        let result = arrow_flight::Result { body: body.into() };
+        let stream: <Self as FlightService>::DoActionStream =
+            Box::pin(futures::stream::iter(vec![Ok(result)]));
+        Response::new(stream)
+    }
+}
+
+#[tonic::async_trait]
+impl FlightService for FlightServiceImpl {
+    type HandshakeStream = <FlightSqlServiceImpl as FlightService>::HandshakeStream;
+    type ListFlightsStream = <FlightSqlServiceImpl as FlightService>::ListFlightsStream;
+    type DoGetStream = <FlightSqlServiceImpl as FlightService>::DoGetStream;
+    type DoPutStream = <FlightSqlServiceImpl as FlightService>::DoPutStream;
+    type DoExchangeStream = <FlightSqlServiceImpl as FlightService>::DoExchangeStream;
+    type DoActionStream = <FlightSqlServiceImpl as FlightService>::DoActionStream;
+    type ListActionsStream = <FlightSqlServiceImpl as FlightService>::ListActionsStream;
+
+    async fn handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        self.sql.handshake(request).await
+    }
+
+    async fn list_flights(
+        &self,
+        request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        self.sql.list_flights(request).await
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        self.sql.get_flight_info(request).await
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        self.sql.get_schema(request).await
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        self.sql.do_get(request).await
+    }
+
+    async fn do_put(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        self.sql.do_put(request).await
+    }
+
+    async fn do_exchange(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        self.sql.do_exchange(request).await
+    }
+
+    async fn do_action(
+        &self,
+        request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        match request.get_ref().r#type.as_str() {
+            SET_SESSION_OPTIONS => {
+                let session = self.sql.get_session(&request)?;
+                let options = SetSessionOptionsRequest::decode(request.get_ref().body.as_ref())
+                    .map_err(|e| {
+                        Status::invalid_argument(format!("Invalid {SET_SESSION_OPTIONS}: {e}"))
+                    })?;
+                let result = FlightSqlServiceImpl::set_session_options(&session, options);
+                Ok(Self::action_result(result.encode_to_vec()))
+            }
+            GET_SESSION_OPTIONS => {
+                let session = self.sql.get_session(&request)?;
+                GetSessionOptionsRequest::decode(request.get_ref().body.as_ref()).map_err(|e| {
+                    Status::invalid_argument(format!("Invalid {GET_SESSION_OPTIONS}: {e}"))
+                })?;
+                let result = FlightSqlServiceImpl::get_session_options(&session);
+                Ok(Self::action_result(result.encode_to_vec()))
+            }
+            _ => self.sql.do_action(request).await,
+        }
+    }
+
+    async fn list_actions(
+        &self,
+        request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        self.sql.list_actions(request).await
+    }
+}
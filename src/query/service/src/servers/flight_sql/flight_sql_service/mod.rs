@@ -14,10 +14,24 @@
 
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
+pub use flight_service::FlightServiceImpl;
+pub use session_options::session_option_value;
+pub use session_options::set_session_options_result;
+pub use session_options::GetSessionOptionsRequest;
+pub use session_options::GetSessionOptionsResult;
+pub use session_options::SessionOptionValue;
+pub use session_options::SetSessionOptionsRequest;
+pub use session_options::SetSessionOptionsResult;
+pub use session_options::GET_SESSION_OPTIONS;
+pub use session_options::SET_SESSION_OPTIONS;
+
+mod flight_service;
 mod query;
 mod service;
 mod session;
+mod session_options;
 
+use std::collections::HashSet;
 use std::pin::Pin;
 use std::sync::Arc;
 
@@ -43,10 +57,18 @@ pub(crate) use status;
 
 type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send + 'static>>;
 
+/// A statement planned by `GetFlightInfo`, until all of its endpoints are fetched.
+struct PendingQuery {
+    plan: Plan,
+    plan_extras: PlanExtras,
+    pending_endpoints: HashSet<u32>,
+}
+
 #[derive(Clone)]
 pub struct FlightSqlServiceImpl {
     sessions: Arc<DashMap<String, Arc<Session>>>,
     statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    queries: Arc<DashMap<Uuid, PendingQuery>>,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
@@ -55,6 +77,7 @@ impl FlightSqlServiceImpl {
         FlightSqlServiceImpl {
             sessions: Arc::new(Default::default()),
             statements: Arc::new(Default::default()),
+            queries: Arc::new(Default::default()),
         }
     }
 }
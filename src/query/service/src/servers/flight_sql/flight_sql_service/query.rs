@@ -14,8 +14,12 @@
 
 use std::sync::Arc;
 
+use arrow_flight::sql::ProstMessageExt;
+use arrow_flight::sql::TicketStatementQuery;
 use arrow_flight::utils::batches_to_flight_data;
 use arrow_flight::FlightData;
+use arrow_flight::FlightEndpoint;
+use arrow_flight::Ticket;
 use async_stream::stream;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -26,15 +30,107 @@ use common_sql::PlanExtras;
 use common_sql::Planner;
 use common_storages_fuse::TableContext;
 use futures_util::StreamExt;
+use prost::Message;
 use tonic::Status;
+use uuid::Uuid;
 
 use super::status;
 use super::DoGetStream;
 use super::FlightSqlServiceImpl;
+use super::PendingQuery;
 use crate::interpreters::InterpreterFactory;
 use crate::sessions::Session;
 
+/// The handle of a `TicketStatementQuery`, it addresses an endpoint of the result of a statement.
+pub(super) struct StatementTicket {
+    pub handle: Uuid,
+    pub endpoint: u32,
+}
+
+impl StatementTicket {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = self.handle.as_bytes().to_vec();
+        buf.extend_from_slice(&self.endpoint.to_be_bytes());
+        buf
+    }
+
+    pub fn decode(buf: &[u8]) -> std::result::Result<Self, Status> {
+        if buf.len() != 20 {
+            return Err(Status::invalid_argument(format!(
+                "Invalid statement handle of {} bytes",
+                buf.len()
+            )));
+        }
+        let handle = Uuid::from_slice(&buf[..16])
+            .map_err(|e| Status::invalid_argument(format!("Error decoding handle: {e}")))?;
+        let endpoint = u32::from_be_bytes(buf[16..].try_into().expect("4 bytes"));
+        Ok(StatementTicket { handle, endpoint })
+    }
+}
+
 impl FlightSqlServiceImpl {
+    /// Plans the statement and keeps it until all the endpoints of its result are fetched.
+    ///
+    /// The result is a single endpoint on this node for now, the endpoints of a distributed
+    /// query could be fetched from the nodes owning them later on.
+    pub(super) fn add_query(&self, plan: Plan, plan_extras: PlanExtras) -> Vec<FlightEndpoint> {
+        let handle = Uuid::new_v4();
+        let endpoints: Vec<u32> = vec![0];
+        let flight_endpoints = endpoints
+            .iter()
+            .map(|endpoint| {
+                let ticket = TicketStatementQuery {
+                    statement_handle: StatementTicket {
+                        handle,
+                        endpoint: *endpoint,
+                    }
+                    .encode()
+                    .into(),
+                };
+                FlightEndpoint {
+                    ticket: Some(Ticket {
+                        ticket: ticket.as_any().encode_to_vec().into(),
+                    }),
+                    // Empty means the endpoint is fetched from this service.
+                    location: vec![],
+                }
+            })
+            .collect();
+        self.queries.insert(handle, PendingQuery {
+            plan,
+            plan_extras,
+            pending_endpoints: endpoints.into_iter().collect(),
+        });
+        flight_endpoints
+    }
+
+    /// An endpoint can be fetched once, the statement is dropped with its last endpoint.
+    pub(super) fn take_query_endpoint(
+        &self,
+        ticket: &StatementTicket,
+    ) -> std::result::Result<(Plan, PlanExtras), Status> {
+        let (plan, plan_extras, done) = {
+            let mut query = self.queries.get_mut(&ticket.handle).ok_or_else(|| {
+                Status::not_found(format!("Unknown statement handle {}", ticket.handle))
+            })?;
+            if !query.pending_endpoints.remove(&ticket.endpoint) {
+                return Err(Status::not_found(format!(
+                    "Endpoint {} of statement {} is fetched already",
+                    ticket.endpoint, ticket.handle
+                )));
+            }
+            (
+                query.plan.clone(),
+                query.plan_extras.clone(),
+                query.pending_endpoints.is_empty(),
+            )
+        };
+        if done {
+            self.queries.remove(&ticket.handle);
+        }
+        Ok((plan, plan_extras))
+    }
+
     pub(super) fn block_to_flight_data(
         block: DataBlock,
         data_schema: &DataSchema,
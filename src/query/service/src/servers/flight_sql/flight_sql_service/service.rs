@@ -65,6 +65,7 @@ use tonic::Response;
 use tonic::Status;
 use tonic::Streaming;
 
+use super::query::StatementTicket;
 use super::status;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 
@@ -79,10 +80,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
         Response<Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>>,
         Status,
     > {
-        let remote_addr = request.remote_addr();
+        let client_ip =
+            FlightSqlServiceImpl::get_client_ip(request.metadata(), request.remote_addr());
         let (user, password) = FlightSqlServiceImpl::get_user_password(request.metadata())
             .map_err(Status::invalid_argument)?;
-        let session = FlightSqlServiceImpl::auth_user_password(user, password, remote_addr).await?;
+        let session = FlightSqlServiceImpl::auth_user_password(user, password, client_ip).await?;
         let token = session.get_id();
         let result = HandshakeResponse {
             protocol_version: 0,
@@ -130,12 +132,36 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
     async fn get_flight_info_statement(
         &self,
-        _query: CommandStatementQuery,
-        _request: Request<FlightDescriptor>,
+        query: CommandStatementQuery,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_statement not implemented",
-        ))
+        let session = self.get_session(&request)?;
+        tracing::info!("get_flight_info_statement with query = {}", query.query);
+
+        let (plan, plan_extras) = self
+            .plan_sql(&session, &query.query)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))?;
+        let data_schema = if plan.has_result_set() {
+            plan.schema()
+        } else {
+            Arc::new(DataSchema::empty())
+        };
+        let schema = (&*data_schema).into();
+        let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e| status!("Unable to serialize schema", e))?;
+        let IpcMessage(schema_bytes) = message;
+
+        let endpoints = self.add_query(plan, plan_extras);
+        let info = FlightInfo {
+            schema: schema_bytes,
+            flight_descriptor: Some(request.into_inner()),
+            endpoint: endpoints,
+            total_records: -1,
+            total_bytes: -1,
+        };
+        Ok(Response::new(info))
     }
 
     async fn get_flight_info_prepared_statement(
@@ -279,10 +305,23 @@ impl FlightSqlService for FlightSqlServiceImpl {
     // do_get
     async fn do_get_statement(
         &self,
-        _ticket: TicketStatementQuery,
-        _request: Request<Ticket>,
+        ticket: TicketStatementQuery,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        Err(Status::unimplemented("do_get_statement not implemented"))
+        let session = self.get_session(&request)?;
+        let ticket = StatementTicket::decode(ticket.statement_handle.as_ref())?;
+        tracing::info!(
+            "do_get_statement with handle={} endpoint={}",
+            ticket.handle,
+            ticket.endpoint
+        );
+
+        let (plan, plan_extras) = self.take_query_endpoint(&ticket)?;
+        let stream = self
+            .execute_query(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     async fn do_get_prepared_statement(
@@ -12,18 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::IpAddr;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
-use common_meta_app::principal::AuthInfo;
-use common_users::UserApiProvider;
+use common_exception::ErrorCode;
 use tonic::metadata::MetadataMap;
 use tonic::Request;
 use tonic::Status;
 
 use super::status;
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
@@ -78,48 +80,42 @@ impl FlightSqlServiceImpl {
         Ok((user.to_string(), pass.to_string()))
     }
 
+    pub(super) fn get_client_ip(
+        metadata: &MetadataMap,
+        remote_addr: Option<SocketAddr>,
+    ) -> Option<IpAddr> {
+        let forwarded_for = metadata
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .collect::<Vec<_>>();
+        let forwarded_for = (!forwarded_for.is_empty()).then(|| forwarded_for.join(","));
+        remote_addr.map(|a| AuthMgr::instance().resolve_client_ip(a.ip(), forwarded_for.as_deref()))
+    }
+
     pub(super) async fn auth_user_password(
         user: String,
         password: String,
-        remote_addr: Option<SocketAddr>,
+        client_ip: Option<IpAddr>,
     ) -> Result<Arc<Session>, Status> {
-        let client_ip = remote_addr
-            .map(|a| a.ip().to_string())
-            .unwrap_or("%".to_string());
         let session = SessionManager::instance()
             .create_session(SessionType::FlightSQL)
             .await
             .map_err(|e| status!("Could not create session", e))?;
-        let tenant = session.get_current_tenant();
 
-        let user = UserApiProvider::instance()
-            .get_user_with_client_ip(&tenant, &user, &client_ip)
-            .await
-            .map_err(|e| status!("get_user_with_client_ip fail {}", e))?;
         let password = password.as_bytes().to_vec();
-        let password = (!password.is_empty()).then_some(password);
-
-        let user = match &user.auth_info {
-            AuthInfo::None => user,
-            AuthInfo::Password {
-                hash_value: h,
-                hash_method: t,
-            } => match password {
-                None => return Err(Status::unauthenticated("password required")),
-                Some(p) => {
-                    if *h == t.hash(&p) {
-                        user
-                    } else {
-                        return Err(Status::unauthenticated("wrong password"));
-                    }
-                }
-            },
-            _ => return Err(Status::unauthenticated("wrong auth type")),
+        let credential = Credential::Password {
+            name: user,
+            password: (!password.is_empty()).then_some(password),
+            hostname: client_ip.map(|ip| ip.to_string()),
         };
-        session
-            .set_authed_user(user, None)
+        AuthMgr::instance()
+            .auth(session.clone(), &credential)
             .await
-            .map_err(|e| status!("set_authed_user fail {}", e))?;
+            .map_err(|e| match e.code() {
+                ErrorCode::NETWORK_POLICY_VIOLATION => Status::permission_denied(e.to_string()),
+                _ => Status::unauthenticated(e.to_string()),
+            })?;
         Ok(session)
     }
 }
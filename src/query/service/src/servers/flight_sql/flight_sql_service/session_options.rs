@@ -0,0 +1,156 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// The session options of Flight, `SetSessionOptions` and `GetSessionOptions`, are the
+// settings of the session.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_meta_app::principal::UserSettingValue;
+
+use super::FlightSqlServiceImpl;
+use crate::sessions::Session;
+
+pub const SET_SESSION_OPTIONS: &str = "SetSessionOptions";
+pub const GET_SESSION_OPTIONS: &str = "GetSessionOptions";
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SessionOptionValue {
+    #[prost(oneof = "session_option_value::OptionValue", tags = "1, 2, 3, 4, 5")]
+    pub option_value: Option<session_option_value::OptionValue>,
+}
+
+pub mod session_option_value {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StringListValue {
+        #[prost(string, repeated, tag = "1")]
+        pub values: Vec<String>,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum OptionValue {
+        #[prost(string, tag = "1")]
+        StringValue(String),
+        #[prost(bool, tag = "2")]
+        BoolValue(bool),
+        #[prost(sfixed64, tag = "3")]
+        Int64Value(i64),
+        #[prost(double, tag = "4")]
+        DoubleValue(f64),
+        #[prost(message, tag = "5")]
+        StringListValue(StringListValue),
+    }
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetSessionOptionsRequest {
+    #[prost(map = "string, message", tag = "1")]
+    pub session_options: HashMap<String, SessionOptionValue>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetSessionOptionsResult {
+    #[prost(map = "string, message", tag = "1")]
+    pub errors: HashMap<String, set_session_options_result::Error>,
+}
+
+pub mod set_session_options_result {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Error {
+        #[prost(enumeration = "ErrorValue", tag = "1")]
+        pub value: i32,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ErrorValue {
+        Unspecified = 0,
+        InvalidName = 1,
+        InvalidValue = 2,
+        Error = 3,
+    }
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionOptionsRequest {}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionOptionsResult {
+    #[prost(map = "string, message", tag = "1")]
+    pub session_options: HashMap<String, SessionOptionValue>,
+}
+
+impl FlightSqlServiceImpl {
+    /// Sets the options that are settings, the others are reported back as errors
+    /// rather than failing the whole request.
+    pub(super) fn set_session_options(
+        session: &Arc<Session>,
+        request: SetSessionOptionsRequest,
+    ) -> SetSessionOptionsResult {
+        use session_option_value::OptionValue;
+        use set_session_options_result::Error;
+        use set_session_options_result::ErrorValue;
+
+        let settings = session.get_settings();
+        let mut errors = HashMap::new();
+        for (name, value) in request.session_options {
+            if !settings.has_setting(&name) {
+                errors.insert(name, Error {
+                    value: ErrorValue::InvalidName as i32,
+                });
+                continue;
+            }
+            let value = match value.option_value {
+                Some(OptionValue::StringValue(v)) => Some(v),
+                Some(OptionValue::BoolValue(v)) => Some((v as u64).to_string()),
+                Some(OptionValue::Int64Value(v)) => Some(v.to_string()),
+                Some(OptionValue::DoubleValue(v)) => Some(v.to_string()),
+                Some(OptionValue::StringListValue(_)) | None => None,
+            };
+            let res = match value {
+                Some(value) => settings.set_settings(name.clone(), value, false),
+                None => Err(ErrorCode::BadArguments("a setting can't be a list")),
+            };
+            if let Err(cause) = res {
+                tracing::info!("set_session_options {name} failed: {cause}");
+                errors.insert(name, Error {
+                    value: ErrorValue::InvalidValue as i32,
+                });
+            }
+        }
+        SetSessionOptionsResult { errors }
+    }
+
+    pub(super) fn get_session_options(session: &Arc<Session>) -> GetSessionOptionsResult {
+        use session_option_value::OptionValue;
+
+        let session_options = session
+            .get_settings()
+            .get_setting_values_short()
+            .into_iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    UserSettingValue::UInt64(v) => OptionValue::Int64Value(v as i64),
+                    UserSettingValue::String(v) => OptionValue::StringValue(v),
+                };
+                (name, SessionOptionValue {
+                    option_value: Some(value),
+                })
+            })
+            .collect();
+        GetSessionOptionsResult { session_options }
+    }
+}
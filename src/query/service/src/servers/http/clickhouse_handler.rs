@@ -25,6 +25,9 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use common_expression::infer_table_schema;
+use common_formats::output_format::ArrowIPCOutputFormat;
+use common_formats::output_format::OutputFormat;
+use common_formats::output_format::ARROW_IPC_CONTENT_TYPE;
 use common_formats::ClickhouseFormatType;
 use common_formats::FileFormatOptionsExt;
 use common_formats::FileFormatTypeExt;
@@ -103,10 +106,15 @@ async fn execute(
     ctx: Arc<QueryContext>,
     interpreter: InterpreterPtr,
     format: ClickhouseFormatType,
+    arrow_ipc: bool,
     params: StatementHandlerParams,
     handle: Option<JoinHandle<()>>,
 ) -> Result<WithContentType<Body>> {
-    let format_typ = format.typ.clone();
+    let content_type = if arrow_ipc {
+        ARROW_IPC_CONTENT_TYPE.to_string()
+    } else {
+        format.typ.get_content_type()
+    };
     let schema = interpreter.schema();
 
     // the reason of spawning new task to execute the interpreter:
@@ -146,11 +154,15 @@ async fn execute(
         async move {
             let mut data_stream = interpreter.execute(ctx.clone()).await?;
             let table_schema = infer_table_schema(&schema)?;
-            let mut output_format = FileFormatOptionsExt::get_output_format_from_clickhouse_format(
-                format,
-                table_schema,
-                &ctx.get_settings(),
-            )?;
+            let mut output_format: Box<dyn OutputFormat> = if arrow_ipc {
+                Box::new(ArrowIPCOutputFormat::create(table_schema))
+            } else {
+                FileFormatOptionsExt::get_output_format_from_clickhouse_format(
+                    format,
+                    table_schema,
+                    &ctx.get_settings(),
+                )?
+            };
 
             let prefix = Ok(output_format.serialize_prefix()?);
 
@@ -205,7 +217,7 @@ async fn execute(
                 handle.await.expect("must")
             }
 
-            Ok(Body::from_bytes_stream(stream).with_content_type(format_typ.get_content_type()))
+            Ok(Body::from_bytes_stream(stream).with_content_type(content_type))
         }
     })?
     .await
@@ -244,6 +256,7 @@ pub async fn clickhouse_handler_get(
         .await
         .map_err(|err| err.display_with_sql(&sql))
         .map_err(BadRequest)?;
+    let arrow_ipc = extras.format.is_none() && accept_arrow_ipc(headers);
     let format = get_format_with_default(extras.format, default_format)?;
 
     context.attach_query_str(plan.to_string(), extras.stament.to_mask_sql());
@@ -251,7 +264,7 @@ pub async fn clickhouse_handler_get(
         .await
         .map_err(|err| err.display_with_sql(&sql))
         .map_err(BadRequest)?;
-    execute(context, interpreter, format, params, None)
+    execute(context, interpreter, format, arrow_ipc, params, None)
         .await
         .map_err(|err| err.display_with_sql(&sql))
         .map_err(InternalServerError)
@@ -404,13 +417,14 @@ pub async fn clickhouse_handler_post(
         }
     };
 
+    let arrow_ipc = extras.format.is_none() && accept_arrow_ipc(headers);
     let format = get_format_with_default(extras.format, default_format)?;
     let interpreter = InterpreterFactory::get(ctx.clone(), &plan)
         .await
         .map_err(|err| err.display_with_sql(&sql))
         .map_err(BadRequest)?;
 
-    execute(ctx, interpreter, format, params, handle)
+    execute(ctx, interpreter, format, arrow_ipc, params, handle)
         .await
         .map_err(|err| err.display_with_sql(&sql))
         .map_err(InternalServerError)
@@ -481,6 +495,16 @@ fn get_default_format(
     ClickhouseFormatType::parse_clickhouse_format(name)
 }
 
+// Whether the client asks for an Arrow IPC stream, only honored when the query has no `FORMAT` clause.
+fn accept_arrow_ipc(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(http::header::ACCEPT)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|v| v.split(';').next().unwrap_or_default().trim() == ARROW_IPC_CONTENT_TYPE)
+}
+
 fn get_format_with_default(
     format: Option<String>,
     default_format: ClickhouseFormatType,
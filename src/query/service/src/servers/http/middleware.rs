@@ -52,17 +52,40 @@ impl HTTPSessionMiddleware {
     }
 }
 
-fn get_credential(req: &Request, kind: HttpHandlerKind) -> Result<Credential> {
+const X_FORWARDED_FOR: &str = "X-Forwarded-For";
+
+fn get_client_ip(req: &Request, auth_manager: &AuthMgr) -> Option<String> {
+    match req.remote_addr().0 {
+        Addr::SocketAddr(addr) => {
+            let forwarded_for = req
+                .headers()
+                .get_all(X_FORWARDED_FOR)
+                .iter()
+                .filter_map(|v| v.to_str().ok())
+                .collect::<Vec<_>>();
+            let forwarded_for = match forwarded_for.is_empty() {
+                true => None,
+                false => Some(forwarded_for.join(",")),
+            };
+            let client_ip = auth_manager.resolve_client_ip(addr.ip(), forwarded_for.as_deref());
+            Some(client_ip.to_string())
+        }
+        Addr::Custom(..) => Some("127.0.0.1".to_string()),
+        _ => None,
+    }
+}
+
+fn get_credential(
+    req: &Request,
+    kind: HttpHandlerKind,
+    auth_manager: &AuthMgr,
+) -> Result<Credential> {
     let std_auth_headers: Vec<_> = req.headers().get_all(AUTHORIZATION).iter().collect();
     if std_auth_headers.len() > 1 {
         let msg = &format!("Multiple {} headers detected", AUTHORIZATION);
         return Err(ErrorCode::AuthenticateFailure(msg));
     }
-    let client_ip = match req.remote_addr().0 {
-        Addr::SocketAddr(addr) => Some(addr.ip().to_string()),
-        Addr::Custom(..) => Some("127.0.0.1".to_string()),
-        _ => None,
-    };
+    let client_ip = get_client_ip(req, auth_manager);
     if std_auth_headers.is_empty() {
         if matches!(kind, HttpHandlerKind::Clickhouse) {
             auth_clickhouse_name_password(req, client_ip)
@@ -157,7 +180,7 @@ pub struct HTTPSessionEndpoint<E> {
 }
 impl<E> HTTPSessionEndpoint<E> {
     async fn auth(&self, req: &Request) -> Result<HttpQueryContext> {
-        let credential = get_credential(req, self.kind)?;
+        let credential = get_credential(req, self.kind, &self.auth_manager)?;
         let session_manager = SessionManager::instance();
         let session = session_manager.create_session(SessionType::Dummy).await?;
         let ctx = session.create_query_context().await?;
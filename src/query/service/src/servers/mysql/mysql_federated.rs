@@ -185,10 +185,6 @@ impl MySQLFederated {
                 Regex::new("(?i)^(show collation where(.*))").unwrap(),
                 MySQLFederated::show_variables_block("", ""),
             ),
-            (
-                Regex::new("(?i)^(SHOW VARIABLES(.*))").unwrap(),
-                MySQLFederated::show_variables_block("", ""),
-            ),
         ];
 
         FederatedHelper::block_match_rule(query, &SHOW_VARIABLES_RULES)
@@ -43,6 +43,8 @@ use tracing::error;
 use tracing::info;
 use tracing::Instrument;
 
+use crate::auth::parse_forwarded_ip;
+use crate::auth::AuthMgr;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
@@ -245,15 +247,20 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
 impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
     async fn authenticate(&self, salt: &[u8], info: CertifiedInfo) -> Result<bool> {
         let user_name = &info.user_name;
-        let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
+        let client_ip = parse_forwarded_ip(&info.user_client_address);
+        let client_host = client_ip.map_or_else(|| "%".to_string(), |ip| ip.to_string());
 
         let ctx = self.session.create_query_context().await?;
+        let tenant = ctx.get_tenant();
         let user_info = UserApiProvider::instance()
-            .get_user_with_client_ip(&ctx.get_tenant(), user_name, client_ip)
+            .get_user_with_client_ip(&tenant, user_name, &client_host)
             .await?;
 
         let authed = user_info.auth_info.auth_mysql(&info.user_password, salt)?;
         if authed {
+            AuthMgr::instance()
+                .check_network_policy(&tenant, &user_info, client_ip)
+                .await?;
             self.session.set_authed_user(user_info, None).await?;
         }
         Ok(authed)
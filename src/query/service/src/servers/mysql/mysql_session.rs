@@ -12,11 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::IpAddr;
 use std::net::Shutdown;
 use std::sync::Arc;
+use std::time::Duration;
 
+use common_base::base::tokio::io::AsyncReadExt;
 use common_base::base::tokio::io::BufWriter;
 use common_base::base::tokio::net::TcpStream;
+use common_base::base::tokio::time::timeout;
 use common_base::runtime::Runtime;
 use common_base::runtime::Thread;
 use common_base::runtime::TrySpawn;
@@ -28,12 +32,17 @@ use opensrv_mysql::IntermediaryOptions;
 use tracing::error;
 use tracing::warn;
 
+use crate::auth::AuthMgr;
 use crate::servers::mysql::mysql_interactive_worker::InteractiveWorker;
 use crate::sessions::Session;
 
 // default size of resultset write buffer: 100KB
 const DEFAULT_RESULT_SET_WRITE_BUFFER_SIZE: usize = 100 * 1024;
 
+// max length of a PROXY protocol v1 header, including the CRLF
+const PROXY_PROTOCOL_V1_MAX_LEN: usize = 107;
+const PROXY_PROTOCOL_WAIT_TIMEOUT: Duration = Duration::from_millis(500);
+
 pub struct MySQLConnection;
 
 impl MySQLConnection {
@@ -46,7 +55,18 @@ impl MySQLConnection {
             Runtime::with_worker_threads(1, Some("mysql-query-executor".to_string()))?;
         Thread::spawn(move || {
             let join_handle = query_executor.spawn(async move {
+                let mut non_blocking_stream = non_blocking_stream;
                 let client_addr = match non_blocking_stream.peer_addr() {
+                    Ok(addr) if AuthMgr::instance().is_trusted_proxy(addr.ip()) => {
+                        match Self::read_proxy_header(&mut non_blocking_stream).await {
+                            Ok(Some(ip)) => ip.to_string(),
+                            Ok(None) => addr.to_string(),
+                            Err(e) => {
+                                warn!("Invalid PROXY protocol header from {}: {}", addr, e);
+                                return Ok(());
+                            }
+                        }
+                    }
                     Ok(addr) => addr.to_string(),
                     Err(e) => {
                         warn!(
@@ -82,6 +102,49 @@ impl MySQLConnection {
         Ok(())
     }
 
+    /// Consume the PROXY protocol v1 header sent by a trusted proxy, and return the
+    /// source address in it. Returns `None` if the proxy doesn't send the header.
+    async fn read_proxy_header(stream: &mut TcpStream) -> Result<Option<IpAddr>> {
+        // The client waits for the server greeting, so the proxy is the only one
+        // who may send data before the handshake.
+        let mut buf = [0u8; PROXY_PROTOCOL_V1_MAX_LEN];
+        let len = match timeout(PROXY_PROTOCOL_WAIT_TIMEOUT, stream.peek(&mut buf)).await {
+            Ok(res) => res?,
+            Err(_) => return Ok(None),
+        };
+        if !buf[..len].starts_with(b"PROXY ") {
+            return Ok(None);
+        }
+
+        let header_len = match buf[..len].windows(2).position(|w| w == b"\r\n") {
+            Some(pos) => pos + 2,
+            None => {
+                return Err(ErrorCode::BadBytes(
+                    "PROXY protocol header is incomplete or too long",
+                ));
+            }
+        };
+        stream.read_exact(&mut buf[..header_len]).await?;
+
+        let header = String::from_utf8_lossy(&buf[..header_len - 2]);
+        let parts = header.split(' ').collect::<Vec<_>>();
+        match parts.as_slice() {
+            ["PROXY", "UNKNOWN", ..] => Ok(None),
+            ["PROXY", "TCP4" | "TCP6", src, _dst, _src_port, _dst_port] => {
+                src.parse::<IpAddr>().map(Some).map_err(|_| {
+                    ErrorCode::BadBytes(format!(
+                        "Invalid source address in PROXY protocol header: {}",
+                        src
+                    ))
+                })
+            }
+            _ => Err(ErrorCode::BadBytes(format!(
+                "Invalid PROXY protocol header: {}",
+                header
+            ))),
+        }
+    }
+
     // TODO: move to ToBlockingStream trait
     fn convert_stream(stream: TcpStream) -> Result<std::net::TcpStream> {
         let stream = stream.into_std().map_err_to_code(
@@ -29,7 +29,11 @@ use tracing::error;
 use tracing::info;
 
 use crate::clusters::ClusterDiscovery;
+use crate::pipes::PipeScheduler;
+use crate::sessions::ResultPersistJanitor;
 use crate::sessions::SessionManager;
+use crate::statistics::AnalyzeDaemon;
+use crate::tasks::TaskScheduler;
 
 pub type ListeningStream = Abortable<TcpListenerStream>;
 
@@ -63,6 +67,18 @@ impl ShutdownHandle {
 
     pub async fn shutdown(&mut self, mut signal: SignalStream) {
         self.shutdown_services(true).await;
+        if let Err(cause) = AnalyzeDaemon::instance().shutdown().await {
+            error!("Cannot shutdown analyze daemon, {:?}", cause);
+        }
+        if let Err(cause) = ResultPersistJanitor::instance().shutdown().await {
+            error!("Cannot shutdown result persist janitor, {:?}", cause);
+        }
+        if let Err(cause) = TaskScheduler::instance().shutdown().await {
+            error!("Cannot shutdown task scheduler, {:?}", cause);
+        }
+        if let Err(cause) = PipeScheduler::instance().shutdown().await {
+            error!("Cannot shutdown pipe scheduler, {:?}", cause);
+        }
         ClusterDiscovery::instance()
             .unregister_to_metastore(&mut signal)
             .await;
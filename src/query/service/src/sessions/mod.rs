@@ -15,6 +15,8 @@
 mod query_affect;
 pub mod query_ctx;
 mod query_ctx_shared;
+mod result_persist_janitor;
+mod sequence_generator;
 mod session;
 mod session_ctx;
 mod session_info;
@@ -26,7 +28,10 @@ mod session_type;
 pub use common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
 pub use query_ctx::QueryContext;
+pub use query_ctx_shared::ExecutedPlan;
 pub use query_ctx_shared::QueryContextShared;
+pub use result_persist_janitor::ResultPersistJanitor;
+pub use sequence_generator::SequenceGenerator;
 pub use session::Session;
 pub use session_ctx::SessionContext;
 pub use session_info::ProcessInfo;
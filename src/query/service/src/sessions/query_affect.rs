@@ -32,4 +32,8 @@ pub enum QueryAffect {
         values: Vec<String>,
         is_globals: Vec<bool>,
     },
+    Replace {
+        inserted_rows: u64,
+        replaced_rows: u64,
+    },
 }
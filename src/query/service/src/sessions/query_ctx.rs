@@ -25,7 +25,9 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
+use chrono_tz::Tz;
 use common_base::base::tokio::task::JoinHandle;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
@@ -35,39 +37,50 @@ use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::StageTableInfo;
+use common_catalog::table::NavigationPoint;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
+use common_config::GlobalConfig;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::date_helper::TzFactory;
 use common_expression::DataBlock;
 use common_expression::FunctionContext;
+use common_expression::Scalar;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatOptions;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::tenant::DatabaseQuota;
 use common_settings::Settings;
+use common_storage::CopyStatus;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StageFileStatus;
 use common_storage::StorageMetrics;
 use common_storages_fuse::TableContext;
+use common_storages_iceberg::IcebergTable;
 use common_storages_parquet::ParquetTable;
 use common_storages_result_cache::ResultScan;
 use common_storages_stage::StageTable;
 use common_users::UserApiProvider;
 use parking_lot::RwLock;
 use tracing::debug;
+use tracing::warn;
 
 use crate::api::DataExchangeManager;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
+use crate::sessions::ExecutedPlan;
 use crate::sessions::ProcessInfo;
 use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
@@ -211,9 +224,50 @@ impl QueryContext {
         self.shared.created_time
     }
 
+    pub fn set_executed_plan(&self, plan: ExecutedPlan) {
+        self.shared.set_executed_plan(plan)
+    }
+
+    pub fn get_executed_plan(&self) -> Option<Arc<ExecutedPlan>> {
+        self.shared.get_executed_plan()
+    }
+
+    /// The settings applied by the settings hint of the SQL.
+    pub fn get_hinted_settings(&self) -> Vec<(String, String)> {
+        self.shared.get_hinted_settings()
+    }
+
+    pub fn get_peak_memory_usage(&self) -> u64 {
+        self.shared.get_peak_memory_usage()
+    }
+
     pub fn get_on_error_map(&self) -> Option<HashMap<String, ErrorCode>> {
         self.shared.get_on_error_map()
     }
+
+    pub fn set_recursive_cte_table(&self, name: &str, blocks: Vec<DataBlock>) {
+        self.shared.set_recursive_cte_table(name, blocks)
+    }
+
+    pub fn get_recursive_cte_table(&self, name: &str) -> Vec<DataBlock> {
+        self.shared.get_recursive_cte_table(name)
+    }
+
+    pub fn set_recursive_cte_result(&self, name: &str, blocks: Vec<DataBlock>) {
+        self.shared.set_recursive_cte_result(name, blocks)
+    }
+
+    pub fn get_recursive_cte_result(&self, name: &str) -> Vec<DataBlock> {
+        self.shared.get_recursive_cte_result(name)
+    }
+
+    pub fn set_materialized_cte_result(&self, name: &str, blocks: Vec<DataBlock>) {
+        self.shared.set_materialized_cte_result(name, blocks)
+    }
+
+    pub fn get_materialized_cte_result(&self, name: &str) -> Vec<DataBlock> {
+        self.shared.get_materialized_cte_result(name)
+    }
 }
 
 #[async_trait::async_trait]
@@ -232,6 +286,7 @@ impl TableContext for QueryContext {
             }
             DataSourceInfo::ParquetSource(table_info) => ParquetTable::from_info(table_info),
             DataSourceInfo::ResultScanSource(table_info) => ResultScan::from_info(table_info),
+            DataSourceInfo::IcebergSource(table_info) => IcebergTable::from_info(table_info),
         }
     }
 
@@ -365,6 +420,18 @@ impl TableContext for QueryContext {
         self.shared.get_current_role()
     }
 
+    async fn get_granted_columns(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        privilege: UserPrivilegeType,
+    ) -> Result<Option<HashSet<String>>> {
+        self.get_current_session()
+            .get_granted_columns(catalog, database, table, privilege)
+            .await
+    }
+
     fn get_fuse_version(&self) -> String {
         let session = self.get_current_session();
         match session.get_type() {
@@ -389,7 +456,12 @@ impl TableContext for QueryContext {
     fn get_function_context(&self) -> Result<FunctionContext> {
         let tz = self.get_settings().get_timezone()?;
         let tz = TzFactory::instance().get_by_name(&tz)?;
-        Ok(FunctionContext { tz })
+        let now = self
+            .get_created_time()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_micros() as i64)
+            .ok();
+        Ok(FunctionContext { tz, now })
     }
 
     fn get_connection_id(&self) -> String {
@@ -440,6 +512,41 @@ impl TableContext for QueryContext {
         self.shared.set_on_error_map(map);
     }
 
+    fn get_copy_status(&self) -> Arc<CopyStatus> {
+        self.shared.get_copy_status()
+    }
+
+    fn get_txn_manager(&self) -> TxnManagerRef {
+        self.shared.session.get_txn_manager()
+    }
+
+    fn set_variable(&self, key: String, value: Scalar) {
+        self.shared.session.session_ctx.set_variable(key, value)
+    }
+
+    fn unset_variable(&self, key: &str) {
+        self.shared.session.session_ctx.unset_variable(key)
+    }
+
+    fn get_variable(&self, key: &str) -> Option<Scalar> {
+        self.shared.session.session_ctx.get_variable(key)
+    }
+
+    fn get_all_variables(&self) -> HashMap<String, Scalar> {
+        self.shared.session.session_ctx.get_all_variables()
+    }
+
+    fn set_sequence_value(&self, name: String, value: i64) {
+        self.shared
+            .session
+            .session_ctx
+            .set_sequence_value(name, value)
+    }
+
+    fn get_sequence_value(&self, name: &str) -> Option<i64> {
+        self.shared.session.session_ctx.get_sequence_value(name)
+    }
+
     fn apply_changed_settings(&self, changed_settings: Arc<Settings>) -> Result<()> {
         self.shared.apply_changed_settings(changed_settings)
     }
@@ -461,6 +568,49 @@ impl TableContext for QueryContext {
         self.shared.consume_precommit_blocks()
     }
 
+    async fn apply_settings_hint(&self, hint: Vec<(String, String)>) -> Result<()> {
+        if hint.is_empty() {
+            return Ok(());
+        }
+
+        let settings = Settings::default_settings(&self.get_tenant(), GlobalConfig::instance())?;
+        settings.apply_changed_settings(self.shared.session.get_changed_settings())?;
+
+        let mut hinted = Vec::with_capacity(hint.len());
+        for (name, value) in hint {
+            if !settings.has_setting(&name) {
+                warn!("Ignore unknown setting {} = {} in the hint", name, value);
+                continue;
+            }
+            if Settings::is_admin_only(&name) {
+                self.shared
+                    .session
+                    .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
+                    .await
+                    .map_err(|_| {
+                        ErrorCode::PermissionDenied(format!(
+                            "Permission denied, setting {} can only be hinted by users with SUPER privilege",
+                            name
+                        ))
+                    })?;
+            }
+            let value = match name.as_str() {
+                "timezone" => {
+                    value.parse::<Tz>().map_err(|_| {
+                        ErrorCode::InvalidTimezone(format!("Invalid Timezone: {}", value))
+                    })?;
+                    value
+                }
+                _ => value,
+            };
+            settings.set_settings(name.clone(), value.clone(), false)?;
+            hinted.push((name, value));
+        }
+
+        self.shared.set_query_settings(settings, hinted);
+        Ok(())
+    }
+
     async fn get_file_format(&self, name: &str) -> Result<FileFormatOptions> {
         let opt = match StageFileFormatType::from_str(name) {
             Ok(typ) => FileFormatOptions::default_by_type(typ),
@@ -476,6 +626,12 @@ impl TableContext for QueryContext {
         Ok(opt)
     }
 
+    async fn get_database_quotas(&self, db_ids: &[u64]) -> Result<Vec<DatabaseQuota>> {
+        let tenant = self.get_tenant();
+        let quota_api = UserApiProvider::instance().get_tenant_quota_api_client(&tenant)?;
+        quota_api.get_database_quotas(db_ids).await
+    }
+
     /// Fetch a Table by db and table name.
     ///
     /// It guaranteed to return a consistent result for multiple calls, in a same query.
@@ -483,6 +639,8 @@ impl TableContext for QueryContext {
     /// ```sql
     /// SELECT * FROM (SELECT * FROM db.table_name) as subquery_1, (SELECT * FROM db.table_name) AS subquery_2
     /// ```
+    /// References with an `AT` clause are fetched by `get_table_at` instead, which caches
+    /// them by the navigation point, so each `AT` clause reads its own snapshot.
     async fn get_table(
         &self,
         catalog: &str,
@@ -492,6 +650,18 @@ impl TableContext for QueryContext {
         self.shared.get_table(catalog, database, table).await
     }
 
+    async fn get_table_at(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        point: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>> {
+        self.shared
+            .get_table_at(catalog, database, table, Some(point))
+            .await
+    }
+
     async fn color_copied_files(
         &self,
         catalog_name: &str,
@@ -22,19 +22,25 @@ use std::time::SystemTime;
 
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
+use common_catalog::table::NavigationPoint;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_profile::ProfSpanSetRef;
 use common_settings::Settings;
+use common_sql::executor::PhysicalPlan;
+use common_sql::MetadataRef;
+use common_storage::CopyStatus;
 use common_storage::DataOperator;
 use common_storage::StorageMetrics;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 use uuid::Uuid;
 
+use crate::api::DataExchangeManager;
 use crate::catalogs::CatalogManager;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
@@ -42,7 +48,15 @@ use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
 use crate::storages::Table;
 
-type DatabaseAndTable = (String, String, String);
+/// A table reference of a query, with the navigation point of its `AT` clause if any.
+type DatabaseAndTable = (String, String, String, Option<NavigationPoint>);
+
+/// The physical plan a query is executed with, and the profile of its operators.
+pub struct ExecutedPlan {
+    pub plan: PhysicalPlan,
+    pub metadata: MetadataRef,
+    pub prof_span_set: ProfSpanSetRef,
+}
 
 /// Data that needs to be shared in a query context.
 /// This is very useful, for example, for queries:
@@ -77,11 +91,24 @@ pub struct QueryContextShared {
     pub(in crate::sessions) stage_attachment: Arc<RwLock<Option<StageAttachment>>>,
     pub(in crate::sessions) created_time: SystemTime,
     pub(in crate::sessions) on_error_map: Arc<RwLock<Option<HashMap<String, ErrorCode>>>>,
+    /// Per-file load status of COPY INTO.
+    pub(in crate::sessions) copy_status: Arc<CopyStatus>,
     /// partitions_sha for each table in the query. Not empty only when enabling query result cache.
     pub(in crate::sessions) partitions_shas: Arc<RwLock<Vec<String>>>,
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
+    /// Working tables and final results of recursive CTEs, keyed by the working table name.
+    pub(in crate::sessions) recursive_cte_tables: Arc<RwLock<HashMap<String, Vec<DataBlock>>>>,
+    pub(in crate::sessions) recursive_cte_results: Arc<RwLock<HashMap<String, Vec<DataBlock>>>>,
+    /// Results of materialized CTEs, keyed by the name of the stored result.
+    pub(in crate::sessions) materialized_cte_results: Arc<RwLock<HashMap<String, Vec<DataBlock>>>>,
     // Status info.
     pub(in crate::sessions) status: Arc<RwLock<String>>,
+    /// The plan of the query, kept for the slow query log.
+    pub(in crate::sessions) executed_plan: Arc<RwLock<Option<Arc<ExecutedPlan>>>>,
+    /// The settings of the query if its SQL has a settings hint, a copy of the settings of the
+    /// session with the hinted settings applied.
+    pub(in crate::sessions) query_settings: Arc<RwLock<Option<Arc<Settings>>>>,
+    pub(in crate::sessions) hinted_settings: Arc<RwLock<Vec<(String, String)>>>,
 }
 
 impl QueryContextShared {
@@ -110,9 +137,16 @@ impl QueryContextShared {
             stage_attachment: Arc::new(RwLock::new(None)),
             created_time: SystemTime::now(),
             on_error_map: Arc::new(RwLock::new(None)),
+            copy_status: Arc::new(CopyStatus::default()),
             partitions_shas: Arc::new(RwLock::new(vec![])),
             cacheable: Arc::new(AtomicBool::new(true)),
+            recursive_cte_tables: Arc::new(RwLock::new(HashMap::new())),
+            recursive_cte_results: Arc::new(RwLock::new(HashMap::new())),
+            materialized_cte_results: Arc::new(RwLock::new(HashMap::new())),
             status: Arc::new(RwLock::new("null".to_string())),
+            executed_plan: Arc::new(RwLock::new(None)),
+            query_settings: Arc::new(RwLock::new(None)),
+            hinted_settings: Arc::new(RwLock::new(vec![])),
         }))
     }
 
@@ -130,6 +164,10 @@ impl QueryContextShared {
         self.on_error_map.read().as_ref().cloned()
     }
 
+    pub fn get_copy_status(&self) -> Arc<CopyStatus> {
+        self.copy_status.clone()
+    }
+
     pub fn kill(&self, cause: ErrorCode) {
         self.set_error(cause.clone());
         self.aborting.store(true, Ordering::Release);
@@ -138,7 +176,17 @@ impl QueryContextShared {
             executor.finish(Some(cause));
         }
 
-        // TODO: Wait for the query to be processed (write out the last error)
+        // The fragments of a distributed query are running in the other nodes.
+        let query_id = self.init_query_id.read().clone();
+        let timeout = self
+            .get_settings()
+            .get_flight_client_timeout()
+            .unwrap_or(60);
+        DataExchangeManager::instance().broadcast_kill_query(
+            &query_id,
+            &self.cluster_cache,
+            timeout,
+        );
     }
 
     pub fn get_cluster(&self) -> Arc<Cluster> {
@@ -191,11 +239,23 @@ impl QueryContextShared {
     }
 
     pub fn get_settings(&self) -> Arc<Settings> {
-        self.session.get_settings()
+        match self.query_settings.read().as_ref() {
+            Some(settings) => settings.clone(),
+            None => self.session.get_settings(),
+        }
     }
 
     pub fn get_changed_settings(&self) -> Arc<Settings> {
-        self.session.get_changed_settings()
+        Arc::new(self.get_settings().get_changed_settings())
+    }
+
+    pub fn set_query_settings(&self, settings: Arc<Settings>, hinted: Vec<(String, String)>) {
+        *self.query_settings.write() = Some(settings);
+        *self.hinted_settings.write() = hinted;
+    }
+
+    pub fn get_hinted_settings(&self) -> Vec<(String, String)> {
+        self.hinted_settings.read().clone()
     }
 
     pub fn apply_changed_settings(&self, changed_settings: Arc<Settings>) -> Result<()> {
@@ -208,13 +268,32 @@ impl QueryContextShared {
         database: &str,
         table: &str,
     ) -> Result<Arc<dyn Table>> {
-        // Always get same table metadata in the same query
+        self.get_table_at(catalog, database, table, None).await
+    }
 
-        let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
+    /// Tables referenced with distinct navigation points are cached separately, so each
+    /// `AT` clause reads its own snapshot, while plain references share one.
+    pub async fn get_table_at(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        point: Option<&NavigationPoint>,
+    ) -> Result<Arc<dyn Table>> {
+        // Always get same table metadata in the same query
+        let table_meta_key = (
+            catalog.to_string(),
+            database.to_string(),
+            table.to_string(),
+            point.cloned(),
+        );
 
         let already_in_cache = { self.tables_refs.lock().contains_key(&table_meta_key) };
         match already_in_cache {
-            false => self.get_table_to_cache(catalog, database, table).await,
+            false => {
+                self.get_table_to_cache(catalog, database, table, point)
+                    .await
+            }
             true => Ok(self
                 .tables_refs
                 .lock()
@@ -229,11 +308,29 @@ impl QueryContextShared {
         catalog: &str,
         database: &str,
         table: &str,
+        point: Option<&NavigationPoint>,
     ) -> Result<Arc<dyn Table>> {
         let tenant = self.get_tenant();
-        let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
+        let table_meta_key = (
+            catalog.to_string(),
+            database.to_string(),
+            table.to_string(),
+            point.cloned(),
+        );
         let catalog = self.catalog_manager.get_catalog(catalog)?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        let mut cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // The statements of an explicit transaction see the uncommitted mutations of it.
+        let txn_table_info = self
+            .session
+            .get_txn_manager()
+            .lock()
+            .get_table_info(cache_table.get_id());
+        if let Some(table_info) = txn_table_info {
+            cache_table = catalog.get_table_by_info(&table_info)?;
+        }
+        if let Some(point) = point {
+            cache_table = cache_table.navigate_to(point).await?;
+        }
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -308,6 +405,15 @@ impl QueryContextShared {
     pub fn set_executor(&self, weak_ptr: Weak<PipelineExecutor>) {
         let mut executor = self.executor.write();
         *executor = weak_ptr;
+
+        // The query may be killed before the executor was created.
+        if self.aborting.load(Ordering::Acquire) {
+            if let Some(executor) = executor.upgrade() {
+                executor.finish(Some(ErrorCode::AbortedQuery(
+                    "Aborted query, because the server is shutting down or the query was killed",
+                )));
+            }
+        }
     }
 
     pub fn push_precommit_block(&self, block: DataBlock) {
@@ -315,6 +421,36 @@ impl QueryContextShared {
         blocks.push(block);
     }
 
+    pub fn set_recursive_cte_table(&self, name: &str, blocks: Vec<DataBlock>) {
+        let mut tables = self.recursive_cte_tables.write();
+        tables.insert(name.to_string(), blocks);
+    }
+
+    pub fn get_recursive_cte_table(&self, name: &str) -> Vec<DataBlock> {
+        let tables = self.recursive_cte_tables.read();
+        tables.get(name).cloned().unwrap_or_default()
+    }
+
+    pub fn set_recursive_cte_result(&self, name: &str, blocks: Vec<DataBlock>) {
+        let mut results = self.recursive_cte_results.write();
+        results.insert(name.to_string(), blocks);
+    }
+
+    pub fn get_recursive_cte_result(&self, name: &str) -> Vec<DataBlock> {
+        let results = self.recursive_cte_results.read();
+        results.get(name).cloned().unwrap_or_default()
+    }
+
+    pub fn set_materialized_cte_result(&self, name: &str, blocks: Vec<DataBlock>) {
+        let mut results = self.materialized_cte_results.write();
+        results.insert(name.to_string(), blocks);
+    }
+
+    pub fn get_materialized_cte_result(&self, name: &str) -> Vec<DataBlock> {
+        let results = self.materialized_cte_results.read();
+        results.get(name).cloned().unwrap_or_default()
+    }
+
     pub fn consume_precommit_blocks(&self) -> Vec<DataBlock> {
         let mut blocks = self.precommit_blocks.write();
 
@@ -340,6 +476,21 @@ impl QueryContextShared {
         let status = self.status.read();
         status.clone()
     }
+
+    pub fn set_executed_plan(&self, plan: ExecutedPlan) {
+        let mut executed_plan = self.executed_plan.write();
+        *executed_plan = Some(Arc::new(plan));
+    }
+
+    pub fn get_executed_plan(&self) -> Option<Arc<ExecutedPlan>> {
+        self.executed_plan.read().clone()
+    }
+
+    pub fn get_peak_memory_usage(&self) -> u64 {
+        self.get_runtime()
+            .map(|runtime| runtime.get_tracker().get_peak_memory_usage().max(0) as u64)
+            .unwrap_or(0)
+    }
 }
 
 impl Drop for QueryContextShared {
@@ -0,0 +1,145 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Mutex;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::base::tokio::time::sleep as tokio_async_sleep;
+use common_base::base::GlobalInstance;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storage::DataOperator;
+use common_storages_result_cache::gen_result_persist_prefix;
+use common_storages_result_cache::ResultPersistManifest;
+use futures::future::select;
+use futures::future::Either;
+use futures::TryStreamExt;
+use opendal::Operator;
+use tracing::info;
+use tracing::warn;
+
+/// Removes the persisted query results whose TTL has expired in the background.
+pub struct ResultPersistJanitor {
+    tenant: String,
+    check_interval: Duration,
+    shutdown: Arc<AtomicBool>,
+    shutdown_notify: Arc<Notify>,
+    shutdown_handler: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl ResultPersistJanitor {
+    const CHECK_INTERVAL: Duration = Duration::from_secs(300);
+
+    pub fn init(cfg: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(ResultPersistJanitor {
+            tenant: cfg.query.tenant_id.clone(),
+            check_interval: Self::CHECK_INTERVAL,
+            shutdown: Arc::new(AtomicBool::new(false)),
+            shutdown_notify: Arc::new(Notify::new()),
+            shutdown_handler: Mutex::new(None),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<ResultPersistJanitor> {
+        GlobalInstance::get()
+    }
+
+    pub async fn start(&self) {
+        let tenant = self.tenant.clone();
+        let check_interval = self.check_interval;
+        let shutdown = self.shutdown.clone();
+        let shutdown_notify = self.shutdown_notify.clone();
+
+        let handler = tokio::spawn(async move {
+            let mut shutdown_notified = Box::pin(shutdown_notify.notified());
+
+            while !shutdown.load(Ordering::Relaxed) {
+                let sleep = tokio_async_sleep(check_interval);
+                match select(shutdown_notified, Box::pin(sleep)).await {
+                    Either::Left((_, _)) => {
+                        break;
+                    }
+                    Either::Right((_, new_shutdown_notified)) => {
+                        shutdown_notified = new_shutdown_notified;
+                        let operator = DataOperator::instance().operator();
+                        let now = Utc::now().timestamp() as u64;
+                        match Self::remove_expired(&operator, &tenant, now).await {
+                            Ok(0) => {}
+                            Ok(removed) => {
+                                info!("Removed {} expired persisted query results", removed);
+                            }
+                            Err(cause) => {
+                                warn!("Failed to remove expired query results: {:?}", cause);
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        *self.shutdown_handler.lock().await = Some(handler);
+    }
+
+    pub async fn shutdown(&self) -> Result<()> {
+        if let Some(shutdown_handler) = self.shutdown_handler.lock().await.take() {
+            self.shutdown.store(true, Ordering::Relaxed);
+            self.shutdown_notify.notify_waiters();
+            if let Err(shutdown_failure) = shutdown_handler.await {
+                return Err(ErrorCode::TokioError(format!(
+                    "Cannot shutdown result persist janitor, cause {:?}",
+                    shutdown_failure
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the persisted results of the tenant which have expired at `now` (in seconds),
+    /// returns the number of removed results.
+    ///
+    /// The results without a manifest are still being written, they are left alone.
+    pub async fn remove_expired(operator: &Operator, tenant: &str, now: u64) -> Result<usize> {
+        let prefix = gen_result_persist_prefix(tenant);
+        let mut lister = match operator.list(&prefix).await {
+            Ok(lister) => lister,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut removed = 0;
+        while let Some(entry) = lister.try_next().await? {
+            let dir = entry.path();
+            if !dir.ends_with('/') {
+                continue;
+            }
+            match ResultPersistManifest::read(operator, dir).await? {
+                Some(manifest) if manifest.is_expired(now) => {
+                    operator.remove_all(dir).await?;
+                    removed += 1;
+                }
+                _ => {}
+            }
+        }
+        Ok(removed)
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::SequenceValueGenerator;
+use common_users::UserApiProvider;
+
+use crate::sessions::SessionManager;
+
+/// Generates the values of `nextval` from the values this node has reserved for the sequence.
+pub struct SequenceGenerator;
+
+impl SequenceGenerator {
+    pub fn init() -> Result<()> {
+        let generator: Arc<dyn SequenceValueGenerator> = Arc::new(SequenceGenerator);
+        GlobalInstance::set(generator);
+        Ok(())
+    }
+}
+
+impl SequenceValueGenerator for SequenceGenerator {
+    fn next_values(
+        &self,
+        tenant: &str,
+        name: &str,
+        session_id: &str,
+        count: usize,
+        cache_size: u64,
+    ) -> Result<Vec<i64>> {
+        let (tenant, sequence) = (tenant.to_string(), name.to_string());
+        let handle = GlobalIORuntime::instance().spawn(async move {
+            UserApiProvider::instance()
+                .next_sequence_values(&tenant, &sequence, count, cache_size)
+                .await
+        });
+        let values = futures::executor::block_on(handle)
+            .map_err(|e| ErrorCode::Internal(format!("nextval of {name} failed: {e}")))??;
+
+        // The session is on another node if the values are generated by a remote query
+        // fragment, the `currval` of it is not updated then.
+        if let (Some(value), Some(session)) = (
+            values.last(),
+            SessionManager::instance().get_session_by_id(session_id),
+        ) {
+            session
+                .session_ctx
+                .set_sequence_value(name.to_string(), *value);
+        }
+        Ok(values)
+    }
+}
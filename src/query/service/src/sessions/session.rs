@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
 use chrono_tz::Tz;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -26,6 +28,7 @@ use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserPrivilegeType;
 use common_settings::Settings;
 use common_users::RoleCacheManager;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
 use common_users::BUILTIN_ROLE_PUBLIC;
 use parking_lot::RwLock;
 
@@ -321,6 +324,48 @@ impl Session {
         )))
     }
 
+    /// Some statements, like creating external functions, are reserved for the account admin.
+    pub async fn validate_account_admin(self: &Arc<Self>) -> Result<()> {
+        self.ensure_current_role().await?;
+        let is_account_admin = self
+            .get_current_role()
+            .map(|r| r.name == BUILTIN_ROLE_ACCOUNT_ADMIN)
+            .unwrap_or(false);
+        if is_account_admin {
+            return Ok(());
+        }
+
+        Err(ErrorCode::PermissionDenied(format!(
+            "Permission denied, user {} requires the role {}",
+            &self.get_current_user()?.identity(),
+            BUILTIN_ROLE_ACCOUNT_ADMIN
+        )))
+    }
+
+    /// Returns the columns of the table on which the privilege is granted to the user, the
+    /// current role or the roles granted to it, `None` if the privilege is granted on the whole
+    /// table or on none of its columns.
+    pub async fn get_granted_columns(
+        self: &Arc<Self>,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        privilege: UserPrivilegeType,
+    ) -> Result<Option<HashSet<String>>> {
+        let mut grants = self.get_current_user()?.grants;
+        self.ensure_current_role().await?;
+        if let Some(current_role) = self.get_current_role() {
+            let tenant = self.get_current_tenant();
+            let related_roles = RoleCacheManager::instance()
+                .find_related_roles(&tenant, &[current_role.name])
+                .await?;
+            for role in related_roles {
+                grants |= role.grants;
+            }
+        }
+        Ok(grants.granted_columns(catalog, database, table, privilege))
+    }
+
     pub fn get_settings(self: &Arc<Self>) -> Arc<Settings> {
         self.session_ctx.get_settings()
     }
@@ -338,6 +383,10 @@ impl Session {
         0
     }
 
+    pub fn get_txn_manager(self: &Arc<Self>) -> TxnManagerRef {
+        self.session_ctx.get_txn_manager()
+    }
+
     pub fn get_status(self: &Arc<Self>) -> Arc<RwLock<SessionStatus>> {
         self.status.clone()
     }
@@ -355,6 +404,12 @@ impl Session {
 impl Drop for Session {
     fn drop(&mut self) {
         tracing::debug!("Drop session {}", self.id.clone());
+        if self.session_ctx.get_txn_manager().lock().end().is_some() {
+            tracing::info!(
+                "Session {} dropped with a transaction in progress, rolled back",
+                self.id
+            );
+        }
         SessionManager::instance().destroy_session(&self.id.clone());
     }
 }
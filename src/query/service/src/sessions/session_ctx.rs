@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::net::SocketAddr;
 use std::sync::atomic::AtomicBool;
@@ -19,8 +20,11 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use common_catalog::txn::TxnManager;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::Result;
+use common_expression::Scalar;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
 use common_settings::Settings;
@@ -54,6 +58,13 @@ pub struct SessionContext {
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    // The explicit transaction started by `BEGIN`, the table mutations in the transaction
+    // are buffered until `COMMIT`.
+    txn_mgr: TxnManagerRef,
+    // User-defined variables set by `SET @variable = <expr>`, they live as long as the session.
+    variables: RwLock<HashMap<String, Scalar>>,
+    // The last value generated by `NEXTVAL` of each sequence, returned by `CURRVAL`.
+    sequence_values: RwLock<HashMap<String, i64>>,
 }
 
 impl SessionContext {
@@ -71,6 +82,9 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            txn_mgr: TxnManager::create(),
+            variables: Default::default(),
+            sequence_values: Default::default(),
         }))
     }
 
@@ -271,4 +285,32 @@ impl SessionContext {
         let lock = self.query_ids_results.read();
         HashSet::from_iter(lock.iter().map(|result| result.clone().0))
     }
+
+    pub fn get_txn_manager(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
+
+    pub fn set_variable(&self, key: String, value: Scalar) {
+        self.variables.write().insert(key, value);
+    }
+
+    pub fn unset_variable(&self, key: &str) {
+        self.variables.write().remove(key);
+    }
+
+    pub fn get_variable(&self, key: &str) -> Option<Scalar> {
+        self.variables.read().get(key).cloned()
+    }
+
+    pub fn get_all_variables(&self) -> HashMap<String, Scalar> {
+        self.variables.read().clone()
+    }
+
+    pub fn set_sequence_value(&self, name: String, value: i64) {
+        self.sequence_values.write().insert(name, value);
+    }
+
+    pub fn get_sequence_value(&self, name: &str) -> Option<i64> {
+        self.sequence_values.read().get(name).copied()
+    }
 }
@@ -0,0 +1,346 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Mutex;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::base::tokio::time::sleep as tokio_async_sleep;
+use common_base::base::GlobalInstance;
+use common_catalog::plan::PartInfoPtr;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_meta_app::schema::ExternalColumnStatistics;
+use common_meta_app::schema::ExternalTableStatistics;
+use common_sql::executor::table_read_plan::ToReadDataSourcePlan;
+use common_users::UserApiProvider;
+use futures::future::select;
+use futures::future::Either;
+use futures::StreamExt;
+use tracing::info;
+use tracing::warn;
+
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+use crate::stream::ReadDataBlockStream;
+
+/// Refreshes the statistics of external tables in the background.
+///
+/// Only the tables with an `ANALYZE_INTERVAL` are refreshed, each of them is sampled
+/// once its statistics are older than the interval.
+pub struct AnalyzeDaemon {
+    tenant: String,
+    check_interval: Duration,
+    shutdown: Arc<AtomicBool>,
+    shutdown_notify: Arc<Notify>,
+    shutdown_handler: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl AnalyzeDaemon {
+    const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+    pub fn init(cfg: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(AnalyzeDaemon {
+            tenant: cfg.query.tenant_id.clone(),
+            check_interval: Self::CHECK_INTERVAL,
+            shutdown: Arc::new(AtomicBool::new(false)),
+            shutdown_notify: Arc::new(Notify::new()),
+            shutdown_handler: Mutex::new(None),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<AnalyzeDaemon> {
+        GlobalInstance::get()
+    }
+
+    pub async fn start(&self) {
+        let tenant = self.tenant.clone();
+        let check_interval = self.check_interval;
+        let shutdown = self.shutdown.clone();
+        let shutdown_notify = self.shutdown_notify.clone();
+
+        let handler = tokio::spawn(async move {
+            let mut shutdown_notified = Box::pin(shutdown_notify.notified());
+
+            while !shutdown.load(Ordering::Relaxed) {
+                let sleep = tokio_async_sleep(check_interval);
+                match select(shutdown_notified, Box::pin(sleep)).await {
+                    Either::Left((_, _)) => {
+                        break;
+                    }
+                    Either::Right((_, new_shutdown_notified)) => {
+                        shutdown_notified = new_shutdown_notified;
+                        if let Err(cause) = Self::refresh_tables(&tenant).await {
+                            warn!("Analyze daemon failed to refresh statistics: {:?}", cause);
+                        }
+                    }
+                }
+            }
+        });
+
+        *self.shutdown_handler.lock().await = Some(handler);
+    }
+
+    pub async fn shutdown(&self) -> Result<()> {
+        if let Some(shutdown_handler) = self.shutdown_handler.lock().await.take() {
+            self.shutdown.store(true, Ordering::Relaxed);
+            self.shutdown_notify.notify_waiters();
+            if let Err(shutdown_failure) = shutdown_handler.await {
+                return Err(ErrorCode::TokioError(format!(
+                    "Cannot shutdown analyze daemon, cause {:?}",
+                    shutdown_failure
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    async fn refresh_tables(tenant: &str) -> Result<()> {
+        let user_mgr = UserApiProvider::instance();
+        for statistics in user_mgr.list_table_statistics(tenant).await? {
+            if !statistics.need_refresh(Utc::now()) {
+                continue;
+            }
+
+            let (catalog, database, table) = (
+                statistics.catalog.clone(),
+                statistics.database.clone(),
+                statistics.table.clone(),
+            );
+            match Self::analyze_table(statistics).await {
+                Ok(statistics) => {
+                    info!(
+                        "Analyze daemon refreshed statistics of table {}.{}.{}",
+                        catalog, database, table
+                    );
+                    user_mgr.upsert_table_statistics(tenant, statistics).await?;
+                }
+                Err(cause)
+                    if cause.code() == ErrorCode::UNKNOWN_TABLE
+                        || cause.code() == ErrorCode::UNKNOWN_DATABASE
+                        || cause.code() == ErrorCode::UNKNOWN_CATALOG =>
+                {
+                    // The table is gone, so are its statistics.
+                    user_mgr
+                        .drop_table_statistics(tenant, &catalog, &database, &table)
+                        .await?;
+                }
+                Err(cause) => {
+                    warn!(
+                        "Analyze daemon failed to analyze table {}.{}.{}: {:?}",
+                        catalog, database, table, cause
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn analyze_table(statistics: ExternalTableStatistics) -> Result<ExternalTableStatistics> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        let ctx = session.create_query_context().await?;
+        let table = ctx
+            .get_table(&statistics.catalog, &statistics.database, &statistics.table)
+            .await?;
+        let sample_percent = ctx.get_settings().get_analyze_sample_percent()?;
+
+        let mut plan = table.read_plan(ctx.clone(), None).await?;
+        let total_parts = plan.parts.partitions.len();
+        plan.parts.partitions = sample_partitions(&plan.parts.partitions, sample_percent);
+        let sampled_parts = plan.parts.partitions.len();
+
+        // Leaf index of the primitive top-level columns, which is how the optimizer
+        // looks up column statistics.
+        let leaf_indexes = leaf_indexes(table.schema().fields());
+        let fields = plan.schema().fields().clone();
+        let mut collectors = fields
+            .iter()
+            .map(|field| {
+                let data_type = DataType::from(field.data_type());
+                match leaf_indexes.get(field.name()) {
+                    Some(leaf_index) if is_comparable(&data_type) => {
+                        Some((*leaf_index, ColumnStatisticsCollector::default()))
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut sampled_rows = 0;
+        let mut stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            sampled_rows += block.num_rows() as u64;
+            for (entry, collector) in block.columns().iter().zip(collectors.iter_mut()) {
+                if let Some((_, collector)) = collector {
+                    let column = entry
+                        .value
+                        .convert_to_full_column(&entry.data_type, block.num_rows());
+                    for value in column.iter() {
+                        collector.add(value);
+                    }
+                }
+            }
+        }
+
+        // Extrapolate the sample to the whole table, unless the partitions already tell.
+        let scale = if sampled_parts == 0 {
+            1.0
+        } else {
+            total_parts as f64 / sampled_parts as f64
+        };
+        let number_of_rows = if plan.statistics.is_exact && plan.statistics.read_rows > 0 {
+            plan.statistics.read_rows as u64
+        } else {
+            (sampled_rows as f64 * scale) as u64
+        };
+        let data_bytes = match plan.statistics.read_bytes {
+            0 => None,
+            bytes => Some(bytes as u64),
+        };
+
+        let column_statistics = collectors
+            .into_iter()
+            .flatten()
+            .filter_map(|(leaf_index, collector)| {
+                collector
+                    .finish(scale, number_of_rows)
+                    .map(|column_statistics| (leaf_index, column_statistics))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        Ok(ExternalTableStatistics {
+            number_of_rows: Some(number_of_rows),
+            data_bytes,
+            column_statistics,
+            updated_on: Some(Utc::now()),
+            ..statistics
+        })
+    }
+}
+
+/// Picks `sample_percent` percent (at least one) of the partitions, evenly spread
+/// so that the sample is not biased toward the first files.
+fn sample_partitions(partitions: &[PartInfoPtr], sample_percent: u64) -> Vec<PartInfoPtr> {
+    let total = partitions.len();
+    let sample_percent = sample_percent.clamp(1, 100) as usize;
+    let sample = ((total * sample_percent + 99) / 100).min(total);
+    (0..sample)
+        .map(|i| partitions[i * total / sample].clone())
+        .collect()
+}
+
+fn leaf_indexes(fields: &[TableField]) -> HashMap<String, u32> {
+    fn num_leaves(data_type: &TableDataType) -> u32 {
+        match data_type.remove_nullable() {
+            TableDataType::Tuple { fields_type, .. } => fields_type.iter().map(num_leaves).sum(),
+            _ => 1,
+        }
+    }
+
+    let mut leaf_index = 0;
+    let mut leaf_indexes = HashMap::new();
+    for field in fields {
+        if !matches!(
+            field.data_type().remove_nullable(),
+            TableDataType::Tuple { .. }
+        ) {
+            leaf_indexes.insert(field.name().clone(), leaf_index);
+        }
+        leaf_index += num_leaves(field.data_type());
+    }
+    leaf_indexes
+}
+
+fn is_comparable(data_type: &DataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        DataType::Boolean
+            | DataType::String
+            | DataType::Number(_)
+            | DataType::Decimal(_)
+            | DataType::Timestamp
+            | DataType::Date
+    )
+}
+
+#[derive(Default)]
+struct ColumnStatisticsCollector {
+    min: Option<Scalar>,
+    max: Option<Scalar>,
+    null_count: u64,
+    non_null_count: u64,
+    distinct_hashes: HashSet<u64>,
+}
+
+impl ColumnStatisticsCollector {
+    fn add(&mut self, value: ScalarRef) {
+        if matches!(value, ScalarRef::Null) {
+            self.null_count += 1;
+            return;
+        }
+
+        self.non_null_count += 1;
+        if self.min.as_ref().map_or(true, |min| value < min.as_ref()) {
+            self.min = Some(value.to_owned());
+        }
+        if self.max.as_ref().map_or(true, |max| value > max.as_ref()) {
+            self.max = Some(value.to_owned());
+        }
+
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        self.distinct_hashes.insert(hasher.finish());
+    }
+
+    fn finish(self, scale: f64, number_of_rows: u64) -> Option<ExternalColumnStatistics> {
+        let (min, max) = (self.min?, self.max?);
+        let sampled_ndv = self.distinct_hashes.len() as u64;
+        // A column that is (almost) unique in the sample is likely unique in the table,
+        // otherwise the sample has probably seen most of the distinct values already.
+        let number_of_distinct_values = if sampled_ndv * 10 >= self.non_null_count * 9 {
+            (sampled_ndv as f64 * scale) as u64
+        } else {
+            sampled_ndv
+        };
+        Some(ExternalColumnStatistics {
+            min,
+            max,
+            null_count: (self.null_count as f64 * scale) as u64,
+            number_of_distinct_values: number_of_distinct_values.min(number_of_rows),
+        })
+    }
+}
@@ -21,6 +21,7 @@ use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table::Table;
 use common_catalog::table_args::TableArgs;
+use common_compress::DecompressReader;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::BooleanType;
@@ -33,21 +34,30 @@ use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRefExt;
+use common_meta_app::principal::FileFormatOptions;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::input_formats::InputContext;
 use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_sql::binder::parse_stage_location;
 use common_storage::init_stage_operator;
 use common_storage::read_parquet_schema_async;
 use common_storage::StageFilesInfo;
+use futures::io::BufReader;
+use futures::AsyncBufReadExt;
+use futures::AsyncRead;
+use futures::AsyncReadExt;
+use opendal::Operator;
+use serde_json::Value as JsonValue;
 
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::Pipeline;
 use crate::sessions::TableContext;
+use crate::table_functions::infer_schema::json_schema::JsonSchemaInferrer;
 use crate::table_functions::infer_schema::table_args::InferSchemaArgsParsed;
 use crate::table_functions::TableFunction;
 
@@ -192,9 +202,29 @@ impl AsyncSource for InferSchemaSource {
                 let arrow_schema = read_parquet_schema_async(&operator, &first_file.path).await?;
                 TableSchema::from(&arrow_schema)
             }
+            StageFileFormatType::NdJson | StageFileFormatType::Json => {
+                let rows = read_json_rows(
+                    &operator,
+                    &first_file.path,
+                    &file_format_options,
+                    self.args_parsed.sample_rows,
+                )
+                .await?;
+                let mut inferrer = JsonSchemaInferrer::new(self.args_parsed.flatten_nested);
+                for row in rows.iter() {
+                    inferrer.add_row(row)?;
+                }
+                if inferrer.num_rows() == 0 {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "infer_schema found no rows in file {}",
+                        first_file.path
+                    )));
+                }
+                inferrer.finish()
+            }
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, NDJSON and JSON",
                 ));
             }
         };
@@ -222,3 +252,44 @@ impl AsyncSource for InferSchemaSource {
         Ok(Some(block))
     }
 }
+
+/// Read at most `sample_rows` rows of the JSON file. The rows of NDJSON are the lines, and the
+/// rows of JSON are the elements of the top level array, or the top level object itself.
+async fn read_json_rows(
+    operator: &Operator,
+    path: &str,
+    file_format_options: &FileFormatOptions,
+    sample_rows: usize,
+) -> Result<Vec<JsonValue>> {
+    let reader = operator.reader(path).await?;
+    let reader: Box<dyn AsyncRead + Unpin + Send> =
+        match InputContext::get_compression_alg_copy(file_format_options.compression, path)? {
+            Some(algo) => Box::new(DecompressReader::new(reader, algo)),
+            None => Box::new(reader),
+        };
+    let mut reader = BufReader::new(reader);
+    let invalid_json =
+        |e: serde_json::Error| ErrorCode::BadBytes(format!("invalid JSON in file {}: {}", path, e));
+
+    let mut rows = vec![];
+    if file_format_options.format == StageFileFormatType::NdJson {
+        let mut line = String::new();
+        while rows.len() < sample_rows {
+            line.clear();
+            if reader.read_line(&mut line).await? == 0 {
+                break;
+            }
+            if !line.trim().is_empty() {
+                rows.push(serde_json::from_str(&line).map_err(invalid_json)?);
+            }
+        }
+    } else {
+        let mut buf = vec![];
+        reader.read_to_end(&mut buf).await?;
+        match serde_json::from_slice(&buf).map_err(invalid_json)? {
+            JsonValue::Array(values) => rows.extend(values.into_iter().take(sample_rows)),
+            value => rows.push(value),
+        }
+    }
+    Ok(rows)
+}
@@ -0,0 +1,250 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use serde_json::Value;
+
+/// The type of the JSON values of a column, which is widened as the rows are merged.
+///
+/// The scalar types are widened along `Int8 -> Int64 -> Float64 -> String`, and the values
+/// which can't be merged into one type, like an object and a number, are kept as `Variant`.
+#[derive(Clone, Debug, PartialEq)]
+enum JsonType {
+    /// Only NULLs are observed.
+    Null,
+    Boolean,
+    Int8,
+    Int64,
+    Float64,
+    String,
+    Array {
+        element: Box<JsonType>,
+        nullable: bool,
+    },
+    Object(Vec<JsonField>),
+    Variant,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct JsonField {
+    name: String,
+    data_type: JsonType,
+    nullable: bool,
+}
+
+impl JsonType {
+    fn infer(value: &Value) -> JsonType {
+        match value {
+            Value::Null => JsonType::Null,
+            Value::Bool(_) => JsonType::Boolean,
+            Value::Number(n) => match n.as_i64() {
+                Some(v) if i8::try_from(v).is_ok() => JsonType::Int8,
+                Some(_) => JsonType::Int64,
+                // Floats and the integers out of the range of Int64.
+                None => JsonType::Float64,
+            },
+            Value::String(_) => JsonType::String,
+            Value::Array(items) => JsonType::Array {
+                element: Box::new(
+                    items
+                        .iter()
+                        .fold(JsonType::Null, |ty, item| ty.merge(JsonType::infer(item))),
+                ),
+                nullable: items.iter().any(Value::is_null),
+            },
+            Value::Object(object) => JsonType::Object(JsonField::infer_fields(object)),
+        }
+    }
+
+    /// The rank of the scalar types in the widening lattice.
+    fn scalar_rank(&self) -> Option<u8> {
+        match self {
+            JsonType::Int8 => Some(0),
+            JsonType::Int64 => Some(1),
+            JsonType::Float64 => Some(2),
+            _ => None,
+        }
+    }
+
+    fn merge(self, other: JsonType) -> JsonType {
+        match (self, other) {
+            (JsonType::Null, ty) | (ty, JsonType::Null) => ty,
+            (JsonType::Variant, _) | (_, JsonType::Variant) => JsonType::Variant,
+            (
+                JsonType::Array { element, nullable },
+                JsonType::Array {
+                    element: other_element,
+                    nullable: other_nullable,
+                },
+            ) => JsonType::Array {
+                element: Box::new(element.merge(*other_element)),
+                nullable: nullable || other_nullable,
+            },
+            (JsonType::Object(fields), JsonType::Object(other_fields)) => {
+                JsonType::Object(JsonField::merge_fields(fields, other_fields))
+            }
+            (JsonType::Array { .. } | JsonType::Object(_), _)
+            | (_, JsonType::Array { .. } | JsonType::Object(_)) => JsonType::Variant,
+            (ty, other) if ty == other => ty,
+            (ty, other) => match (ty.scalar_rank(), other.scalar_rank()) {
+                (Some(rank), Some(other_rank)) if rank >= other_rank => ty,
+                (Some(_), Some(_)) => other,
+                // Booleans and strings are only merged into strings with the other types.
+                _ => JsonType::String,
+            },
+        }
+    }
+
+    fn to_table_type(&self) -> TableDataType {
+        match self {
+            // The type of the values is unknown, strings can hold any of them.
+            JsonType::Null | JsonType::String => TableDataType::String,
+            JsonType::Boolean => TableDataType::Boolean,
+            JsonType::Int8 => TableDataType::Number(NumberDataType::Int8),
+            JsonType::Int64 => TableDataType::Number(NumberDataType::Int64),
+            JsonType::Float64 => TableDataType::Number(NumberDataType::Float64),
+            JsonType::Variant => TableDataType::Variant,
+            JsonType::Array { element, nullable } => {
+                TableDataType::Array(Box::new(wrap_nullable(element.to_table_type(), *nullable)))
+            }
+            JsonType::Object(fields) if fields.is_empty() => TableDataType::Variant,
+            JsonType::Object(fields) => TableDataType::Tuple {
+                fields_name: fields.iter().map(|f| f.name.clone()).collect(),
+                fields_type: fields
+                    .iter()
+                    .map(|f| wrap_nullable(f.data_type.to_table_type(), f.nullable))
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl JsonField {
+    fn infer_fields(object: &serde_json::Map<String, Value>) -> Vec<JsonField> {
+        object
+            .iter()
+            .map(|(name, value)| JsonField {
+                name: name.clone(),
+                data_type: JsonType::infer(value),
+                nullable: value.is_null(),
+            })
+            .collect()
+    }
+
+    /// Merge the fields of two objects, the fields missing in either of them are nullable.
+    fn merge_fields(mut fields: Vec<JsonField>, other_fields: Vec<JsonField>) -> Vec<JsonField> {
+        for field in fields.iter_mut() {
+            if !other_fields.iter().any(|f| f.name == field.name) {
+                field.nullable = true;
+            }
+        }
+        for other in other_fields {
+            match fields.iter_mut().find(|f| f.name == other.name) {
+                Some(field) => {
+                    let data_type = std::mem::replace(&mut field.data_type, JsonType::Null);
+                    field.data_type = data_type.merge(other.data_type);
+                    field.nullable |= other.nullable;
+                }
+                None => fields.push(JsonField {
+                    nullable: true,
+                    ..other
+                }),
+            }
+        }
+        fields
+    }
+}
+
+fn wrap_nullable(data_type: TableDataType, nullable: bool) -> TableDataType {
+    if nullable {
+        TableDataType::Nullable(Box::new(data_type))
+    } else {
+        data_type
+    }
+}
+
+/// Infers the schema of JSON rows by merging the types of the values observed in every row.
+///
+/// The columns are ordered by their first appearance in the rows. Nested objects are inferred
+/// as tuples, or as columns named by the dot-separated path if `flatten_nested` is set.
+pub struct JsonSchemaInferrer {
+    flatten_nested: bool,
+    fields: Vec<JsonField>,
+    num_rows: usize,
+}
+
+impl JsonSchemaInferrer {
+    pub fn new(flatten_nested: bool) -> Self {
+        JsonSchemaInferrer {
+            flatten_nested,
+            fields: vec![],
+            num_rows: 0,
+        }
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn add_row(&mut self, row: &Value) -> Result<()> {
+        let object = row.as_object().ok_or_else(|| {
+            ErrorCode::BadBytes(format!(
+                "infer_schema expects JSON objects as rows, but got {}",
+                row
+            ))
+        })?;
+        let fields = JsonField::infer_fields(object);
+        self.fields = match self.num_rows {
+            0 => fields,
+            _ => JsonField::merge_fields(std::mem::take(&mut self.fields), fields),
+        };
+        self.num_rows += 1;
+        Ok(())
+    }
+
+    pub fn finish(self) -> TableSchema {
+        let mut table_fields = vec![];
+        for field in self.fields.iter() {
+            self.push_field(&mut table_fields, field.name.clone(), field, false);
+        }
+        TableSchema::new(table_fields)
+    }
+
+    fn push_field(
+        &self,
+        table_fields: &mut Vec<TableField>,
+        name: String,
+        field: &JsonField,
+        parent_nullable: bool,
+    ) {
+        let nullable = field.nullable || parent_nullable;
+        match &field.data_type {
+            JsonType::Object(fields) if self.flatten_nested && !fields.is_empty() => {
+                for child in fields {
+                    let child_name = format!("{}.{}", name, child.name);
+                    self.push_field(table_fields, child_name, child, nullable);
+                }
+            }
+            data_type => table_fields.push(TableField::new(
+                &name,
+                wrap_nullable(data_type.to_table_type(), nullable),
+            )),
+        }
+    }
+}
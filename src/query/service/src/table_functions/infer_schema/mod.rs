@@ -13,6 +13,8 @@
 //  limitations under the License.
 
 mod infer_schema_table;
+mod json_schema;
 mod table_args;
 
 pub use infer_schema_table::InferSchemaTable;
+pub use json_schema::JsonSchemaInferrer;
@@ -15,14 +15,22 @@
 use common_catalog::table_args::TableArgs;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::with_integer_mapped_type;
+use common_expression::Scalar;
 use common_storage::StageFilesInfo;
 use common_storages_fuse::table_functions::string_value;
 
+/// The number of rows read to infer the schema of the JSON files by default.
+const DEFAULT_SAMPLE_ROWS: usize = 1000;
+
 #[derive(Clone)]
 pub(crate) struct InferSchemaArgsParsed {
     pub(crate) location: String,
     pub(crate) file_format: Option<String>,
     pub(crate) files_info: StageFilesInfo,
+    pub(crate) sample_rows: usize,
+    pub(crate) flatten_nested: bool,
 }
 
 impl InferSchemaArgsParsed {
@@ -31,6 +39,8 @@ impl InferSchemaArgsParsed {
 
         let mut location = None;
         let mut file_format = None;
+        let mut sample_rows = DEFAULT_SAMPLE_ROWS;
+        let mut flatten_nested = false;
         let mut files_info = StageFilesInfo {
             path: "".to_string(),
             files: None,
@@ -48,6 +58,19 @@ impl InferSchemaArgsParsed {
                 "file_format" => {
                     file_format = Some(string_value(v)?);
                 }
+                "sample_rows" => {
+                    sample_rows = match u64_value(v)? {
+                        0 => {
+                            return Err(ErrorCode::BadArguments(
+                                "sample_rows of infer_schema must be positive",
+                            ));
+                        }
+                        n => n as usize,
+                    };
+                }
+                "flatten_nested" => {
+                    flatten_nested = bool_value(v)?;
+                }
                 _ => {
                     return Err(ErrorCode::BadArguments(format!(
                         "unknown param {} for infer_schema",
@@ -65,6 +88,29 @@ impl InferSchemaArgsParsed {
             location,
             file_format,
             files_info,
+            sample_rows,
+            flatten_nested,
         })
     }
 }
+
+fn u64_value(value: &Scalar) -> Result<u64> {
+    let v = match value {
+        Scalar::Number(n) => with_integer_mapped_type!(|NUM_TYPE| match n {
+            NumberScalar::NUM_TYPE(v) => u64::try_from(*v).ok(),
+            _ => None,
+        }),
+        _ => None,
+    };
+    v.ok_or_else(|| ErrorCode::BadArguments(format!("invalid unsigned integer {}.", value)))
+}
+
+fn bool_value(value: &Scalar) -> Result<bool> {
+    match value {
+        Scalar::Boolean(v) => Ok(*v),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "invalid boolean {}.",
+            value
+        ))),
+    }
+}
@@ -21,6 +21,7 @@ mod sync_crash_me;
 mod table_function;
 mod table_function_factory;
 
+pub use infer_schema::JsonSchemaInferrer;
 pub use numbers::generate_numbers_parts;
 pub use numbers::NumbersPartInfo;
 pub use numbers::NumbersTable;
@@ -27,6 +27,7 @@ use crate::catalogs::SYS_TBL_FUNC_ID_BEGIN;
 use crate::storages::fuse::table_functions::ClusteringInformationTable;
 use crate::storages::fuse::table_functions::FuseBlockTable;
 use crate::storages::fuse::table_functions::FuseSegmentTable;
+use crate::storages::fuse::table_functions::FuseSnapshotStatisticsTable;
 use crate::storages::fuse::table_functions::FuseSnapshotTable;
 use crate::storages::fuse::table_functions::FuseStatisticTable;
 use crate::table_functions::async_crash_me::AsyncCrashMeTable;
@@ -118,11 +119,19 @@ impl TableFunctionFactory {
             "fuse_statistic".to_string(),
             (next_id(), Arc::new(FuseStatisticTable::create)),
         );
+        creators.insert(
+            "fuse_snapshot_statistics".to_string(),
+            (next_id(), Arc::new(FuseSnapshotStatisticsTable::create)),
+        );
 
         creators.insert(
             "clustering_information".to_string(),
             (next_id(), Arc::new(ClusteringInformationTable::create)),
         );
+        creators.insert(
+            "system$clustering_information".to_string(),
+            (next_id(), Arc::new(ClusteringInformationTable::create)),
+        );
 
         creators.insert(
             "sync_crash_me".to_string(),
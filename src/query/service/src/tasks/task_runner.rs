@@ -0,0 +1,250 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::tokio::time::sleep as tokio_async_sleep;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::TaskLease;
+use common_meta_app::principal::TaskInfo;
+use common_meta_app::principal::TaskRun;
+use common_meta_app::principal::TaskRunState;
+use common_meta_app::principal::TaskRunTrigger;
+use common_sql::Planner;
+use common_users::UserApiProvider;
+use futures::future::select;
+use futures::future::Either;
+use futures::StreamExt;
+use tracing::info;
+use tracing::warn;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// Runs a task while holding its lease, so that a task never runs twice at the same
+/// time, no matter how many query nodes try, and records the run in the task history.
+pub struct TaskRunner {
+    tenant: String,
+    holder: String,
+}
+
+impl TaskRunner {
+    // The lease is renewed every third of it while the task is running, so that it's
+    // only released by the expiration if the node running the task is gone.
+    const LEASE_TTL: Duration = Duration::from_secs(60);
+    const RUN_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+    pub fn create(tenant: &str, holder: &str) -> Self {
+        TaskRunner {
+            tenant: tenant.to_string(),
+            holder: holder.to_string(),
+        }
+    }
+
+    /// Runs the task if it's due and no other run of it is in progress.
+    pub async fn run_scheduled(&self, name: &str) -> Result<()> {
+        let Some(lease) = self.acquire_lease(name).await? else {
+            return Ok(());
+        };
+
+        match self.claim_scheduled_run(name).await {
+            Ok(Some(task)) => self.run(task, TaskRunTrigger::Scheduled, lease).await,
+            Ok(None) => {
+                self.release_lease(name, lease).await;
+                Ok(())
+            }
+            Err(cause) => {
+                self.release_lease(name, lease).await;
+                Err(cause)
+            }
+        }
+    }
+
+    /// Runs the task right away, fails if another run of the task is in progress.
+    pub async fn run_manual(&self, name: &str) -> Result<()> {
+        let Some(lease) = self.acquire_lease(name).await? else {
+            return Err(ErrorCode::TaskIsRunning(format!(
+                "Task {} is running, try again later",
+                name
+            )));
+        };
+
+        match UserApiProvider::instance()
+            .get_task(&self.tenant, name)
+            .await
+        {
+            Ok(task) => self.run(task.data, TaskRunTrigger::Manual, lease).await,
+            Err(cause) => {
+                self.release_lease(name, lease).await;
+                Err(cause)
+            }
+        }
+    }
+
+    // Under the lease, re-check that the task is due and record that its run has started,
+    // so the other nodes, which might have seen the task due as well, skip this run.
+    async fn claim_scheduled_run(&self, name: &str) -> Result<Option<TaskInfo>> {
+        let user_mgr = UserApiProvider::instance();
+        let task = user_mgr.get_task(&self.tenant, name).await?;
+        let now = Utc::now();
+        match task.data.next_scheduled_on() {
+            Some(next_scheduled_on) if next_scheduled_on <= now => {}
+            _ => return Ok(None),
+        }
+
+        let mut task_info = task.data;
+        task_info.last_scheduled_on = Some(now);
+        match user_mgr
+            .update_task(&self.tenant, task_info.clone(), task.seq)
+            .await
+        {
+            Ok(_) => Ok(Some(task_info)),
+            // Altered or dropped in the meantime, it's up to the next check.
+            Err(cause) if cause.code() == ErrorCode::UNKNOWN_TASK => Ok(None),
+            Err(cause) => Err(cause),
+        }
+    }
+
+    async fn run(&self, task: TaskInfo, trigger: TaskRunTrigger, lease: TaskLease) -> Result<()> {
+        let stop_renew = Arc::new(Notify::new());
+        let renew_handler = tokio::spawn(Self::renew_lease(
+            self.tenant.clone(),
+            task.name.clone(),
+            lease,
+            stop_renew.clone(),
+        ));
+
+        let started_on = Utc::now();
+        let instant = Instant::now();
+        let res = self.execute_query(&task).await;
+        stop_renew.notify_one();
+        let lease = renew_handler.await.unwrap_or(lease);
+        self.release_lease(&task.name, lease).await;
+
+        let (query_id, res) = res?;
+        let run = TaskRun {
+            task_name: task.name.clone(),
+            query_id: query_id.clone(),
+            trigger,
+            state: match &res {
+                Ok(_) => TaskRunState::Succeeded,
+                Err(_) => TaskRunState::Failed,
+            },
+            error: res.as_ref().err().map(|cause| cause.to_string()),
+            started_on,
+            duration_ms: instant.elapsed().as_millis() as u64,
+        };
+        info!(
+            "Task {} finished {} run, query id {}, state {}",
+            task.name, trigger, query_id, run.state
+        );
+        UserApiProvider::instance()
+            .add_task_run(&self.tenant, run, Self::RUN_RETENTION)
+            .await?;
+        res
+    }
+
+    // Runs the statement of the task as its owner, in a session of its own.
+    // The outer result fails only if the query couldn't be started at all.
+    async fn execute_query(&self, task: &TaskInfo) -> Result<(String, Result<()>)> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        let user = UserApiProvider::instance()
+            .get_user(&self.tenant, task.owner.clone())
+            .await;
+        let authed = match user {
+            Ok(user) => session.set_authed_user(user, None).await,
+            Err(cause) => Err(cause),
+        };
+        session.set_current_database(task.database.clone());
+
+        let ctx = session.create_query_context().await?;
+        let query_id = ctx.get_id();
+        let res = match authed {
+            Ok(_) => Self::execute_sql(ctx, &task.query_text).await,
+            Err(cause) => Err(cause),
+        };
+        Ok((query_id, res))
+    }
+
+    async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<()> {
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, extras) = planner.plan_sql(sql).await?;
+        ctx.attach_query_str(plan.to_string(), extras.stament.to_mask_sql());
+
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let mut stream = interpreter.execute(ctx).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+        Ok(())
+    }
+
+    async fn acquire_lease(&self, name: &str) -> Result<Option<TaskLease>> {
+        let client = UserApiProvider::instance().get_task_api_client(&self.tenant)?;
+        client
+            .acquire_task_lease(name, &self.holder, Self::LEASE_TTL)
+            .await
+    }
+
+    // Keeps renewing the lease until stopped, returns the latest lease to release it.
+    async fn renew_lease(
+        tenant: String,
+        name: String,
+        mut lease: TaskLease,
+        stop: Arc<Notify>,
+    ) -> TaskLease {
+        let mut stopped = Box::pin(stop.notified());
+        loop {
+            let sleep = tokio_async_sleep(Self::LEASE_TTL / 3);
+            match select(stopped, Box::pin(sleep)).await {
+                Either::Left((_, _)) => return lease,
+                Either::Right((_, new_stopped)) => stopped = new_stopped,
+            }
+
+            let renewed = match UserApiProvider::instance().get_task_api_client(&tenant) {
+                Ok(client) => client.renew_task_lease(&name, lease, Self::LEASE_TTL).await,
+                Err(cause) => Err(cause),
+            };
+            match renewed {
+                Ok(Some(renewed)) => lease = renewed,
+                Ok(None) => {
+                    warn!("Task {} lost its lease while running", name);
+                    return lease;
+                }
+                Err(cause) => warn!("Cannot renew the lease of task {}: {:?}", name, cause),
+            }
+        }
+    }
+
+    async fn release_lease(&self, name: &str, lease: TaskLease) {
+        let res = match UserApiProvider::instance().get_task_api_client(&self.tenant) {
+            Ok(client) => client.release_task_lease(name, lease).await,
+            Err(cause) => Err(cause),
+        };
+        if let Err(cause) = res {
+            warn!("Cannot release the lease of task {}: {:?}", name, cause);
+        }
+    }
+}
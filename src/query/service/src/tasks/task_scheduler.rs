@@ -0,0 +1,136 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Mutex;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::base::tokio::time::sleep as tokio_async_sleep;
+use common_base::base::GlobalInstance;
+use common_base::base::GlobalUniqName;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_users::UserApiProvider;
+use futures::future::select;
+use futures::future::Either;
+use tracing::warn;
+
+use crate::tasks::TaskRunner;
+
+/// Starts the runs of the tasks that are due.
+///
+/// Every query node checks the tasks, the lease of a task makes sure that only
+/// one of them runs it.
+pub struct TaskScheduler {
+    tenant: String,
+    holder: String,
+    check_interval: Duration,
+    shutdown: Arc<AtomicBool>,
+    shutdown_notify: Arc<Notify>,
+    shutdown_handler: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl TaskScheduler {
+    const CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+    pub fn init(cfg: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(TaskScheduler {
+            tenant: cfg.query.tenant_id.clone(),
+            holder: GlobalUniqName::unique(),
+            check_interval: Self::CHECK_INTERVAL,
+            shutdown: Arc::new(AtomicBool::new(false)),
+            shutdown_notify: Arc::new(Notify::new()),
+            shutdown_handler: Mutex::new(None),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<TaskScheduler> {
+        GlobalInstance::get()
+    }
+
+    /// The runner of the manual runs, they hold the lease on behalf of this node as well.
+    pub fn runner(&self) -> TaskRunner {
+        TaskRunner::create(&self.tenant, &self.holder)
+    }
+
+    pub async fn start(&self) {
+        let runner = Arc::new(self.runner());
+        let tenant = self.tenant.clone();
+        let check_interval = self.check_interval;
+        let shutdown = self.shutdown.clone();
+        let shutdown_notify = self.shutdown_notify.clone();
+
+        let handler = tokio::spawn(async move {
+            let mut shutdown_notified = Box::pin(shutdown_notify.notified());
+
+            while !shutdown.load(Ordering::Relaxed) {
+                let sleep = tokio_async_sleep(check_interval);
+                match select(shutdown_notified, Box::pin(sleep)).await {
+                    Either::Left((_, _)) => {
+                        break;
+                    }
+                    Either::Right((_, new_shutdown_notified)) => {
+                        shutdown_notified = new_shutdown_notified;
+                        if let Err(cause) = Self::schedule_tasks(&tenant, &runner).await {
+                            warn!("Task scheduler failed to check tasks: {:?}", cause);
+                        }
+                    }
+                }
+            }
+        });
+
+        *self.shutdown_handler.lock().await = Some(handler);
+    }
+
+    pub async fn shutdown(&self) -> Result<()> {
+        if let Some(shutdown_handler) = self.shutdown_handler.lock().await.take() {
+            self.shutdown.store(true, Ordering::Relaxed);
+            self.shutdown_notify.notify_waiters();
+            if let Err(shutdown_failure) = shutdown_handler.await {
+                return Err(ErrorCode::TokioError(format!(
+                    "Cannot shutdown task scheduler, cause {:?}",
+                    shutdown_failure
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    // The runs are spawned, so a long run doesn't hold up the other tasks.
+    async fn schedule_tasks(tenant: &str, runner: &Arc<TaskRunner>) -> Result<()> {
+        let now = Utc::now();
+        for task in UserApiProvider::instance().get_tasks(tenant).await? {
+            match task.next_scheduled_on() {
+                Some(next_scheduled_on) if next_scheduled_on <= now => {}
+                _ => continue,
+            }
+
+            let runner = runner.clone();
+            tokio::spawn(async move {
+                if let Err(cause) = runner.run_scheduled(&task.name).await {
+                    warn!("Task {} failed: {:?}", task.name, cause);
+                }
+            });
+        }
+        Ok(())
+    }
+}
@@ -879,6 +879,7 @@ protocol = "binary"
                 CatalogConfig::Hive(CatalogHiveConfig {
                     address: "1.1.1.1:10000".to_string(),
                     protocol: ThriftProtocol::Binary,
+                    ..Default::default()
                 })
             );
         },
@@ -902,6 +903,8 @@ fn test_override_config_new_hive_catalog() -> Result<()> {
 type = "hive"
 address = "1.1.1.1:12000"
 protocol = "binary"
+warehouse = "hdfs://namenode:8020/user/hive/warehouse"
+user = "databend"
 "#
         .as_bytes(),
     )?;
@@ -919,6 +922,8 @@ protocol = "binary"
                 CatalogConfig::Hive(CatalogHiveConfig {
                     address: "1.1.1.1:12000".to_string(),
                     protocol: ThriftProtocol::Binary,
+                    warehouse: "hdfs://namenode:8020/user/hive/warehouse".to_string(),
+                    user: "databend".to_string(),
                 })
             );
         },
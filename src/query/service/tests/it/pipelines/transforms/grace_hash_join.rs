@@ -0,0 +1,101 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::Int32Type;
+use common_expression::types::StringType;
+use common_expression::Column;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use databend_query::pipelines::processors::partition_indices;
+use databend_query::pipelines::processors::NUM_PARTITIONS;
+
+const NUM_ROWS: usize = 1000;
+
+fn int_keys() -> Vec<Column> {
+    vec![Int32Type::from_data(
+        (0..NUM_ROWS as i32).collect::<Vec<_>>(),
+    )]
+}
+
+#[test]
+fn test_partition_indices_deterministic() {
+    let keys = int_keys();
+    let indices = partition_indices(&keys, &[], 0, false, NUM_ROWS);
+    assert_eq!(indices, partition_indices(&keys, &[], 0, false, NUM_ROWS));
+    assert!(indices.iter().all(|idx| *idx < NUM_PARTITIONS as u64));
+
+    // The equal keys are in the same partition.
+    let dup = vec![Int32Type::from_data(vec![7; 10])];
+    let indices = partition_indices(&dup, &[], 0, false, 10);
+    assert!(indices.iter().all(|idx| *idx == indices[0]));
+}
+
+#[test]
+fn test_partition_indices_nullable() {
+    // The build keys of LEFT JOIN are nullable, they must be partitioned like the probe keys.
+    let keys = int_keys();
+    let nullable = vec![Int32Type::from_opt_data(
+        (0..NUM_ROWS as i32).map(Some).collect::<Vec<_>>(),
+    )];
+    for level in 0..3 {
+        assert_eq!(
+            partition_indices(&keys, &[], level, true, NUM_ROWS),
+            partition_indices(&nullable, &[], level, true, NUM_ROWS)
+        );
+    }
+}
+
+#[test]
+fn test_partition_indices_levels() {
+    let keys = int_keys();
+    let level0 = partition_indices(&keys, &[], 0, false, NUM_ROWS);
+
+    // All the partitions are used.
+    for partition in 0..NUM_PARTITIONS as u64 {
+        assert!(level0.contains(&partition));
+    }
+
+    // The rows of a partition are spread again by the next level.
+    let rows = (0..NUM_ROWS)
+        .filter(|row| level0[*row] == 0)
+        .map(|row| row as i32)
+        .collect::<Vec<_>>();
+    let num_rows = rows.len();
+    let level1 = partition_indices(&[Int32Type::from_data(rows)], &[], 1, false, num_rows);
+    let mut used = level1.clone();
+    used.sort();
+    used.dedup();
+    assert!(used.len() > NUM_PARTITIONS / 2);
+}
+
+#[test]
+fn test_partition_indices_null_keys() {
+    let keys = vec![
+        Int32Type::from_opt_data(vec![Some(1), None, Some(3), None]),
+        StringType::from_opt_data(vec![Some(b"a".to_vec()), Some(b"b".to_vec()), None, None]),
+    ];
+
+    let indices = partition_indices(&keys, &[], 0, true, 4);
+    assert!(indices[0] < NUM_PARTITIONS as u64);
+    assert_eq!(indices[1..], [NUM_PARTITIONS as u64; 3]);
+
+    // The NULLs of the null-equal keys are hashed like the other values.
+    let indices = partition_indices(&keys, &[1], 0, true, 4);
+    assert!(indices[2] < NUM_PARTITIONS as u64);
+    assert_eq!(indices[1], NUM_PARTITIONS as u64);
+
+    // The rows whose keys are NULL are kept.
+    let indices = partition_indices(&keys, &[], 0, false, 4);
+    assert!(indices.iter().all(|idx| *idx < NUM_PARTITIONS as u64));
+}
@@ -0,0 +1,303 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::types::Int32Type;
+use common_expression::types::NumberDataType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::Scalar;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransform;
+use common_sql::plans::MatchMeasure;
+use common_sql::plans::MatchMeasureFunction;
+use common_sql::plans::RowPattern;
+use common_sql::plans::RowPatternQuantifier;
+use databend_query::pipelines::processors::MatchProgram;
+use databend_query::pipelines::processors::MatchResult;
+use databend_query::pipelines::processors::TransformMatchRecognize;
+
+const A: usize = 0;
+const B: usize = 1;
+const C: usize = 2;
+
+fn quantified(pattern: RowPattern, quantifier: RowPatternQuantifier) -> RowPattern {
+    RowPattern::Quantified {
+        pattern: Box::new(pattern),
+        quantifier,
+    }
+}
+
+/// Each row is given as the variables it can be classified as.
+fn find(pattern: &RowPattern, rows: &[&[usize]], complete: bool) -> MatchResult {
+    MatchProgram::compile(pattern).find(
+        rows.len(),
+        |row, variable| rows[row].contains(&variable),
+        complete,
+    )
+}
+
+#[test]
+fn test_match_program() {
+    use MatchResult::*;
+
+    // A+ B C?
+    let pattern = RowPattern::Concat(vec![
+        quantified(RowPattern::Variable(A), RowPatternQuantifier::OneOrMore),
+        RowPattern::Variable(B),
+        quantified(RowPattern::Variable(C), RowPatternQuantifier::ZeroOrOne),
+    ]);
+    assert_eq!(
+        find(&pattern, &[&[A], &[A], &[B], &[C]], true),
+        Matched(vec![A, A, B, C])
+    );
+    assert_eq!(
+        find(&pattern, &[&[A], &[B], &[A]], true),
+        Matched(vec![A, B])
+    );
+    assert_eq!(find(&pattern, &[&[A], &[A]], true), NoMatch);
+    assert_eq!(find(&pattern, &[&[B], &[A]], true), NoMatch);
+    // A row that is both A and B ends the match only if A+ can't continue to a match.
+    assert_eq!(
+        find(&pattern, &[&[A], &[A, B], &[B]], true),
+        Matched(vec![A, A, B])
+    );
+    assert_eq!(
+        find(&pattern, &[&[A], &[A, B], &[A]], true),
+        Matched(vec![A, B])
+    );
+    // The next rows may extend the match.
+    assert_eq!(find(&pattern, &[&[A], &[A]], false), NeedMoreRows);
+    assert_eq!(find(&pattern, &[&[A], &[B]], false), NeedMoreRows);
+    assert_eq!(
+        find(&pattern, &[&[A], &[B], &[A]], false),
+        Matched(vec![A, B])
+    );
+    assert_eq!(find(&pattern, &[&[B]], false), NoMatch);
+
+    // (A | B)* C
+    let pattern = RowPattern::Concat(vec![
+        quantified(
+            RowPattern::Alternation(vec![RowPattern::Variable(A), RowPattern::Variable(B)]),
+            RowPatternQuantifier::ZeroOrMore,
+        ),
+        RowPattern::Variable(C),
+    ]);
+    assert_eq!(
+        find(&pattern, &[&[A], &[B], &[A], &[C]], true),
+        Matched(vec![A, B, A, C])
+    );
+    assert_eq!(find(&pattern, &[&[C], &[C]], true), Matched(vec![C]));
+    assert_eq!(find(&pattern, &[&[A], &[B]], true), NoMatch);
+
+    // The first alternative is preferred.
+    let pattern = RowPattern::Alternation(vec![RowPattern::Variable(A), RowPattern::Variable(B)]);
+    assert_eq!(find(&pattern, &[&[A, B]], true), Matched(vec![A]));
+    assert_eq!(find(&pattern, &[&[B]], true), Matched(vec![B]));
+
+    // A* matches no rows before a row that isn't A.
+    let pattern = quantified(RowPattern::Variable(A), RowPatternQuantifier::ZeroOrMore);
+    assert_eq!(find(&pattern, &[&[B]], true), Matched(vec![]));
+}
+
+fn int(value: i32) -> Scalar {
+    Scalar::Number(NumberScalar::Int32(value))
+}
+
+fn uint(value: u64) -> Scalar {
+    Scalar::Number(NumberScalar::UInt64(value))
+}
+
+fn string(value: &str) -> Scalar {
+    Scalar::String(value.as_bytes().to_vec())
+}
+
+fn column_values(block: &DataBlock, offset: usize) -> Vec<Scalar> {
+    (0..block.num_rows())
+        .map(|row| {
+            let value = &block.get_by_offset(offset).value;
+            value.index(row).unwrap().to_owned()
+        })
+        .collect()
+}
+
+/// Columns: partition, price, is A, is B.
+fn input_blocks() -> Vec<DataBlock> {
+    let block = |rows: &[(i32, i32, bool, bool)]| {
+        DataBlock::new_from_columns(vec![
+            Int32Type::from_data(rows.iter().map(|row| row.0).collect::<Vec<_>>()),
+            Int32Type::from_data(rows.iter().map(|row| row.1).collect::<Vec<_>>()),
+            BooleanType::from_data(rows.iter().map(|row| row.2).collect::<Vec<_>>()),
+            BooleanType::from_data(rows.iter().map(|row| row.3).collect::<Vec<_>>()),
+        ])
+    };
+    vec![
+        block(&[(1, 10, true, false), (1, 11, true, false)]),
+        block(&[
+            (1, 12, false, true),
+            (1, 13, false, false),
+            (1, 14, true, false),
+            (1, 15, false, true),
+            (2, 20, true, false),
+            (2, 21, false, true),
+        ]),
+    ]
+}
+
+/// `PARTITION BY partition PATTERN (A+ B)` with `MATCH_NUMBER()`, `FIRST(A.price)`,
+/// `LAST(B.price)`, `COUNT(*)` and `CLASSIFIER()`.
+fn create_transform(all_rows_per_match: bool) -> TransformMatchRecognize {
+    let pattern = RowPattern::Concat(vec![
+        quantified(RowPattern::Variable(A), RowPatternQuantifier::OneOrMore),
+        RowPattern::Variable(B),
+    ]);
+    let price_type = DataType::Number(NumberDataType::Int32).wrap_nullable();
+    let measures = vec![
+        MatchMeasure {
+            function: MatchMeasureFunction::MatchNumber,
+            index: 0,
+            data_type: DataType::Number(NumberDataType::UInt64),
+        },
+        MatchMeasure {
+            function: MatchMeasureFunction::First {
+                variable: Some(A),
+                column: 1,
+            },
+            index: 1,
+            data_type: price_type.clone(),
+        },
+        MatchMeasure {
+            function: MatchMeasureFunction::Last {
+                variable: Some(B),
+                column: 1,
+            },
+            index: 2,
+            data_type: price_type,
+        },
+        MatchMeasure {
+            function: MatchMeasureFunction::Count {
+                variable: None,
+                column: None,
+            },
+            index: 3,
+            data_type: DataType::Number(NumberDataType::UInt64),
+        },
+        MatchMeasure {
+            function: MatchMeasureFunction::Classifier,
+            index: 4,
+            data_type: DataType::String,
+        },
+    ];
+    TransformMatchRecognize::new(
+        vec![0],
+        vec![Some(2), Some(3)],
+        vec!["A".to_string(), "B".to_string()],
+        &pattern,
+        measures,
+        all_rows_per_match,
+    )
+}
+
+fn run(mut transform: TransformMatchRecognize) -> Result<Vec<Vec<DataBlock>>> {
+    let mut outputs = vec![];
+    for block in input_blocks() {
+        outputs.push(transform.transform(block)?);
+    }
+    outputs.push(transform.on_finish(true)?);
+    Ok(outputs)
+}
+
+#[test]
+fn test_match_recognize_one_row_per_match() -> Result<()> {
+    let outputs = run(create_transform(false))?;
+
+    // The first match continues in the second block.
+    assert!(outputs[0].is_empty());
+    assert!(outputs[2].is_empty());
+    assert_eq!(outputs[1].len(), 1);
+    let block = &outputs[1][0];
+    assert_eq!(block.num_columns(), 6);
+    assert_eq!(column_values(block, 0), vec![int(1), int(1), int(2)]);
+    assert_eq!(column_values(block, 1), vec![uint(1), uint(2), uint(1)]);
+    assert_eq!(column_values(block, 2), vec![int(10), int(14), int(20)]);
+    assert_eq!(column_values(block, 3), vec![int(12), int(15), int(21)]);
+    assert_eq!(column_values(block, 4), vec![uint(3), uint(2), uint(2)]);
+    assert_eq!(column_values(block, 5), vec![
+        string("B"),
+        string("B"),
+        string("B")
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_match_recognize_all_rows_per_match() -> Result<()> {
+    let outputs = run(create_transform(true))?;
+
+    assert!(outputs[0].is_empty());
+    assert!(outputs[2].is_empty());
+    assert_eq!(outputs[1].len(), 1);
+    let block = &outputs[1][0];
+    // The row with the price 13 isn't in any match.
+    assert_eq!(block.num_columns(), 9);
+    assert_eq!(column_values(block, 1), vec![
+        int(10),
+        int(11),
+        int(12),
+        int(14),
+        int(15),
+        int(20),
+        int(21)
+    ]);
+    assert_eq!(column_values(block, 4), vec![
+        uint(1),
+        uint(1),
+        uint(1),
+        uint(2),
+        uint(2),
+        uint(1),
+        uint(1)
+    ]);
+    // The measures are computed over the rows up to the current row.
+    assert_eq!(column_values(block, 6), vec![
+        Scalar::Null,
+        Scalar::Null,
+        int(12),
+        Scalar::Null,
+        int(15),
+        Scalar::Null,
+        int(21)
+    ]);
+    assert_eq!(column_values(block, 7), vec![
+        uint(1),
+        uint(2),
+        uint(3),
+        uint(1),
+        uint(2),
+        uint(1),
+        uint(2)
+    ]);
+    assert_eq!(column_values(block, 8), vec![
+        string("A"),
+        string("A"),
+        string("B"),
+        string("A"),
+        string("B"),
+        string("A"),
+        string("B")
+    ]);
+    Ok(())
+}
@@ -0,0 +1,308 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::Int32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_expression::FromData;
+use common_expression::SortColumnDescription;
+use common_pipeline_core::processors::connect;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::sort::LoserTree;
+use databend_query::pipelines::processors::TransformSortSpill;
+use opendal::services;
+use opendal::Operator;
+use tempfile::TempDir;
+use walkdir::WalkDir;
+
+use crate::tests::create_query_context;
+
+const ROWS_PER_BLOCK: usize = 100;
+const OUTPUT_BLOCK_SIZE: usize = 256;
+
+struct SortHarness {
+    transform: TransformSortSpill,
+    upstream: Arc<OutputPort>,
+    downstream: Arc<InputPort>,
+}
+
+impl SortHarness {
+    fn create(operator: Operator, spilling_bytes_threshold: usize) -> Result<Self> {
+        let input = InputPort::create();
+        let output = OutputPort::create();
+        let upstream = OutputPort::create();
+        let downstream = InputPort::create();
+        unsafe {
+            connect(&input, &upstream);
+            connect(&downstream, &output);
+        }
+
+        // ORDER BY a, the column b keeps the input order of the rows.
+        let sort_desc = vec![SortColumnDescription {
+            offset: 0,
+            asc: true,
+            nulls_first: false,
+        }];
+        let transform = TransformSortSpill::try_create(
+            input,
+            output,
+            schema(),
+            OUTPUT_BLOCK_SIZE,
+            sort_desc,
+            spilling_bytes_threshold,
+            operator,
+            "spill".to_string(),
+        )?;
+
+        Ok(SortHarness {
+            transform,
+            upstream,
+            downstream,
+        })
+    }
+
+    /// Drives the transform till it is finished or `max_input_blocks` blocks are pushed.
+    async fn run(
+        &mut self,
+        blocks: &mut VecDeque<DataBlock>,
+        max_input_blocks: usize,
+    ) -> Result<Vec<DataBlock>> {
+        let mut pushed = 0;
+        let mut output_blocks = vec![];
+        loop {
+            if self.downstream.has_data() {
+                output_blocks.push(self.downstream.pull_data().unwrap()?);
+            }
+            self.downstream.set_need_data();
+
+            match self.transform.event()? {
+                Event::NeedData if pushed == max_input_blocks => break,
+                Event::NeedData => match blocks.pop_front() {
+                    Some(block) => {
+                        self.upstream.push_data(Ok(block));
+                        pushed += 1;
+                    }
+                    None => self.upstream.finish(),
+                },
+                Event::NeedConsume => {}
+                Event::Sync => self.transform.process()?,
+                Event::Async => self.transform.async_process().await?,
+                Event::Finished => break,
+            }
+        }
+        Ok(output_blocks)
+    }
+}
+
+fn schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Number(NumberDataType::Int32)),
+        DataField::new("b", DataType::Number(NumberDataType::UInt64)),
+    ])
+}
+
+/// Returns the blocks of `a` with many duplicated values, each block is sorted by `a`
+/// stably as the partial sort does.
+fn input_blocks(num_blocks: usize) -> VecDeque<DataBlock> {
+    (0..num_blocks)
+        .map(|i| {
+            let mut rows = (i * ROWS_PER_BLOCK..(i + 1) * ROWS_PER_BLOCK)
+                .map(|b| (((b * 7919) % 50) as i32, b as u64))
+                .collect::<Vec<_>>();
+            rows.sort_by_key(|(a, _)| *a);
+            DataBlock::new_from_columns(vec![
+                Int32Type::from_data(rows.iter().map(|(a, _)| *a).collect::<Vec<_>>()),
+                UInt64Type::from_data(rows.iter().map(|(_, b)| *b).collect::<Vec<_>>()),
+            ])
+        })
+        .collect()
+}
+
+fn collect_rows(blocks: &[DataBlock]) -> Vec<(i32, u64)> {
+    let mut rows = vec![];
+    for block in blocks {
+        let num_rows = block.num_rows();
+        let a = block.get_by_offset(0);
+        let a = a.value.convert_to_full_column(&a.data_type, num_rows);
+        let b = block.get_by_offset(1);
+        let b = b.value.convert_to_full_column(&b.data_type, num_rows);
+        let a = Int32Type::try_downcast_column(&a).unwrap();
+        let b = UInt64Type::try_downcast_column(&b).unwrap();
+        rows.extend(a.iter().copied().zip(b.iter().copied()));
+    }
+    rows
+}
+
+fn assert_sorted_stably(rows: &[(i32, u64)], num_rows: usize) {
+    assert_eq!(rows.len(), num_rows);
+    for pair in rows.windows(2) {
+        // Ordered by `a`, and the equal ones are in the input order.
+        assert!(
+            pair[0] < pair[1],
+            "{:?} should be before {:?}",
+            pair[0],
+            pair[1]
+        );
+    }
+}
+
+fn spill_operator(dir: &TempDir) -> Result<Operator> {
+    let mut builder = services::Fs::default();
+    builder.root(dir.path().to_str().unwrap());
+    Ok(Operator::new(builder)?.finish())
+}
+
+fn spilled_entries(dir: &Path) -> usize {
+    WalkDir::new(dir.join("spill"))
+        .min_depth(1)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .count()
+}
+
+#[test]
+fn test_loser_tree() {
+    let streams = vec![
+        vec![1, 4, 4, 9],
+        vec![],
+        vec![2, 3, 4, 10, 11],
+        vec![0, 4],
+        vec![5],
+    ];
+    let mut positions = vec![0; streams.len()];
+    let mut tree = LoserTree::create(
+        streams
+            .iter()
+            .enumerate()
+            .map(|(i, s)| s.first().map(|v| (*v, i)))
+            .collect(),
+    );
+
+    let mut merged = vec![];
+    while let Some(item) = tree.winner() {
+        merged.push(*item);
+        let stream = tree.winner_index();
+        positions[stream] += 1;
+        let next = streams[stream].get(positions[stream]).map(|v| (*v, stream));
+        tree.replace_winner(next);
+    }
+    assert!(tree.is_empty());
+
+    let mut expected = streams
+        .iter()
+        .enumerate()
+        .flat_map(|(i, s)| s.iter().map(move |v| (*v, i)))
+        .collect::<Vec<_>>();
+    expected.sort();
+    assert_eq!(merged, expected);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sort_spill_multi_runs() -> Result<()> {
+    let (_guard, _ctx) = create_query_context().await?;
+    let dir = TempDir::new()?;
+
+    let mut blocks = input_blocks(50);
+    // A run takes about 3 input blocks.
+    let threshold = blocks[0].memory_size() * 3;
+    let mut harness = SortHarness::create(spill_operator(&dir)?, threshold)?;
+    let output_blocks = harness.run(&mut blocks, usize::MAX).await?;
+
+    assert!(harness.transform.num_spilled_runs() > 10);
+    assert!(
+        output_blocks
+            .iter()
+            .all(|b| b.num_rows() <= OUTPUT_BLOCK_SIZE)
+    );
+    assert_sorted_stably(&collect_rows(&output_blocks), 50 * ROWS_PER_BLOCK);
+    // The spilled files are removed after being merged.
+    assert_eq!(spilled_entries(dir.path()), 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sort_spill_run_per_block() -> Result<()> {
+    let (_guard, _ctx) = create_query_context().await?;
+    let dir = TempDir::new()?;
+
+    // Every input block is spilled as a run.
+    let mut blocks = input_blocks(8);
+    let mut harness = SortHarness::create(spill_operator(&dir)?, 1)?;
+    let output_blocks = harness.run(&mut blocks, usize::MAX).await?;
+
+    assert_eq!(harness.transform.num_spilled_runs(), 8);
+    assert_sorted_stably(&collect_rows(&output_blocks), 8 * ROWS_PER_BLOCK);
+    assert_eq!(spilled_entries(dir.path()), 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sort_spill_in_memory() -> Result<()> {
+    let (_guard, _ctx) = create_query_context().await?;
+    let dir = TempDir::new()?;
+
+    let mut blocks = input_blocks(20);
+    let mut harness = SortHarness::create(spill_operator(&dir)?, usize::MAX)?;
+    let output_blocks = harness.run(&mut blocks, usize::MAX).await?;
+
+    assert_eq!(harness.transform.num_spilled_runs(), 0);
+    assert_sorted_stably(&collect_rows(&output_blocks), 20 * ROWS_PER_BLOCK);
+    assert!(!dir.path().join("spill").exists());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sort_spill_cancelled() -> Result<()> {
+    let (_guard, _ctx) = create_query_context().await?;
+    let dir = TempDir::new()?;
+
+    // Stop before the input is finished, as a cancelled query does.
+    let mut blocks = input_blocks(20);
+    let threshold = blocks[0].memory_size() * 3;
+    let mut harness = SortHarness::create(spill_operator(&dir)?, threshold)?;
+    let output_blocks = harness.run(&mut blocks, 10).await?;
+    assert!(output_blocks.is_empty());
+    assert!(harness.transform.num_spilled_runs() >= 2);
+    assert!(spilled_entries(dir.path()) > 0);
+
+    drop(harness);
+
+    // The spilled files are removed in background.
+    for _ in 0..100 {
+        if spilled_entries(dir.path()) == 0 {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    panic!("the spilled files of the cancelled sort are not removed");
+}
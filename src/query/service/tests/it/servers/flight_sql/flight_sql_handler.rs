@@ -14,29 +14,47 @@
 
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
+use std::collections::HashMap;
 use std::fs;
 use std::io::Write;
 
 use arrow_cast::pretty::pretty_format_batches;
+use arrow_flight::flight_service_client::FlightServiceClient;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
 use arrow_flight::utils::flight_data_to_batches;
+use arrow_flight::Action;
 use arrow_flight::FlightData;
+use arrow_flight::FlightInfo;
 use arrow_schema::ArrowError;
+use arrow_schema::DataType;
+use arrow_schema::TimeUnit;
 use common_base::base::tokio;
 use common_config::InnerConfig;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::PasswordHashMethod;
-use databend_query::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use databend_query::servers::flight_sql::flight_sql_service::session_option_value::OptionValue;
+use databend_query::servers::flight_sql::flight_sql_service::set_session_options_result::ErrorValue;
+use databend_query::servers::flight_sql::flight_sql_service::FlightServiceImpl;
+use databend_query::servers::flight_sql::flight_sql_service::GetSessionOptionsRequest;
+use databend_query::servers::flight_sql::flight_sql_service::GetSessionOptionsResult;
+use databend_query::servers::flight_sql::flight_sql_service::SessionOptionValue;
+use databend_query::servers::flight_sql::flight_sql_service::SetSessionOptionsRequest;
+use databend_query::servers::flight_sql::flight_sql_service::SetSessionOptionsResult;
+use databend_query::servers::flight_sql::flight_sql_service::GET_SESSION_OPTIONS;
+use databend_query::servers::flight_sql::flight_sql_service::SET_SESSION_OPTIONS;
 use futures::TryStreamExt;
 use goldenfile::Mint;
+use prost::Message;
 use tempfile::NamedTempFile;
 use tokio::net::UnixListener;
 use tokio::net::UnixStream;
 use tokio_stream::wrappers::UnixListenerStream;
+use tonic::transport::Channel;
 use tonic::transport::Endpoint;
 use tonic::transport::Server;
+use tonic::Request;
 use tower::service_fn;
 
 use crate::tests::ConfigBuilder;
@@ -45,14 +63,26 @@ use crate::tests::TestGlobalServices;
 const TEST_USER: &str = "test_user";
 const TEST_PASSWORD: &str = "test_password";
 
-async fn client_with_uds(path: String) -> FlightSqlServiceClient {
+async fn channel_with_uds(path: String) -> Channel {
     let connector = service_fn(move |_| UnixStream::connect(path.clone()));
-    let channel = Endpoint::try_from("http://example.com")
+    Endpoint::try_from("http://example.com")
         .unwrap()
         .connect_with_connector(connector)
         .await
-        .unwrap();
-    FlightSqlServiceClient::new(channel)
+        .unwrap()
+}
+
+async fn client_with_uds(path: String) -> FlightSqlServiceClient {
+    FlightSqlServiceClient::new(channel_with_uds(path).await)
+}
+
+fn bind_uds() -> (String, UnixListenerStream) {
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    (path, UnixListenerStream::new(uds))
 }
 
 async fn run_query(
@@ -75,6 +105,36 @@ async fn run_query(
     Ok(res)
 }
 
+async fn fetch_endpoints(
+    client: &mut FlightSqlServiceClient,
+    flight_info: &FlightInfo,
+) -> std::result::Result<String, ArrowError> {
+    let mut flight_data: Vec<FlightData> = vec![];
+    for endpoint in &flight_info.endpoint {
+        // The endpoints without locations are fetched from the service that planned the query.
+        assert!(endpoint.location.is_empty());
+        let ticket = endpoint.ticket.as_ref().unwrap().clone();
+        let stream = client.do_get(ticket).await?;
+        flight_data.extend(stream.try_collect::<Vec<_>>().await.unwrap());
+    }
+    let batches = flight_data_to_batches(&flight_data)?;
+    Ok(pretty_format_batches(batches.as_slice())?.to_string())
+}
+
+async fn do_action(
+    client: &mut FlightServiceClient<Channel>,
+    token: &str,
+    action: Action,
+) -> Vec<u8> {
+    let mut request = Request::new(action);
+    request
+        .metadata_mut()
+        .insert("authorization", format!("Bearer {token}").parse().unwrap());
+    let mut results = client.do_action(request).await.unwrap().into_inner();
+    let result = results.message().await.unwrap().unwrap();
+    result.body.to_vec()
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -92,15 +152,9 @@ fn prepare_config() -> InnerConfig {
 async fn test_query() -> Result<()> {
     let _guard = TestGlobalServices::setup(prepare_config()).await?;
 
-    let file = NamedTempFile::new().unwrap();
-    let path = file.into_temp_path().to_str().unwrap().to_string();
-    let _ = fs::remove_file(path.clone());
-
-    let uds = UnixListener::bind(path.clone()).unwrap();
-    let stream = UnixListenerStream::new(uds);
-
     // We would just listen on TCP, but it seems impossible to know when tonic is ready to serve
-    let service = FlightSqlServiceImpl::create();
+    let (path, stream) = bind_uds();
+    let service = FlightServiceImpl::create();
     let serve_future = Server::builder()
         .add_service(FlightServiceServer::new(service))
         .serve_with_incoming(stream);
@@ -136,3 +190,94 @@ async fn test_query() -> Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test]
+async fn test_statement_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config()).await?;
+
+    let (path, stream) = bind_uds();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(FlightServiceImpl::create()))
+        .serve_with_incoming(stream);
+
+    let request_future = async {
+        let channel = channel_with_uds(path).await;
+        let mut client = FlightSqlServiceClient::new(channel.clone());
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let token = String::from_utf8(token.to_vec()).unwrap();
+
+        let sql = "SELECT number::INT64 AS a, number::VARCHAR AS b, to_timestamp(number * 86400) AS c, \
+                   if(number % 2 = 0, NULL, number) AS d FROM numbers(3) ORDER BY a";
+        let flight_info = client.execute(sql.to_string()).await.unwrap();
+        let schema = arrow_schema::Schema::try_from(flight_info.clone()).unwrap();
+        assert_eq!(schema.fields().len(), 4);
+        assert_eq!(
+            schema.field(2).data_type(),
+            &DataType::Timestamp(TimeUnit::Microsecond, None)
+        );
+        assert!(schema.field(3).is_nullable());
+        assert_eq!(flight_info.endpoint.len(), 1);
+
+        let res = fetch_endpoints(&mut client, &flight_info).await.unwrap();
+        let expected = vec![
+            "+---+---+---------------------+---+",
+            "| a | b | c                   | d |",
+            "+---+---+---------------------+---+",
+            "| 0 | 0 | 1970-01-01T00:00:00 |   |",
+            "| 1 | 1 | 1970-01-02T00:00:00 | 1 |",
+            "| 2 | 2 | 1970-01-03T00:00:00 |   |",
+            "+---+---+---------------------+---+",
+        ];
+        assert_eq!(res, expected.join("\n"));
+
+        // Every endpoint can be fetched only once.
+        let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+        assert!(client.do_get(ticket).await.is_err());
+
+        // The session options are the settings of the session.
+        let mut flight_client = FlightServiceClient::new(channel);
+        let options = SetSessionOptionsRequest {
+            session_options: HashMap::from([
+                ("max_threads".to_string(), SessionOptionValue {
+                    option_value: Some(OptionValue::Int64Value(3)),
+                }),
+                ("no_such_setting".to_string(), SessionOptionValue {
+                    option_value: Some(OptionValue::StringValue("x".to_string())),
+                }),
+            ]),
+        };
+        let action = Action {
+            r#type: SET_SESSION_OPTIONS.to_string(),
+            body: options.encode_to_vec().into(),
+        };
+        let body = do_action(&mut flight_client, &token, action).await;
+        let result = SetSessionOptionsResult::decode(body.as_slice()).unwrap();
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors["no_such_setting"].value,
+            ErrorValue::InvalidName as i32
+        );
+
+        let action = Action {
+            r#type: GET_SESSION_OPTIONS.to_string(),
+            body: GetSessionOptionsRequest {}.encode_to_vec().into(),
+        };
+        let body = do_action(&mut flight_client, &token, action).await;
+        let result = GetSessionOptionsResult::decode(body.as_slice()).unwrap();
+        assert_eq!(
+            result.session_options["max_threads"].option_value,
+            Some(OptionValue::Int64Value(3))
+        );
+
+        let sql = "SELECT value FROM system.settings WHERE name = 'max_threads'";
+        let flight_info = client.execute(sql.to_string()).await.unwrap();
+        let res = fetch_endpoints(&mut client, &flight_info).await.unwrap();
+        assert!(res.contains("| 3 "), "{res}");
+    };
+
+    tokio::select! {
+        _ = serve_future => panic!("server returned first"),
+        _ = request_future => println!("Client finished!"),
+    }
+    Ok(())
+}
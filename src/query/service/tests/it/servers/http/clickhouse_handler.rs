@@ -13,7 +13,13 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::io::Cursor;
 
+use common_arrow::arrow::array::BinaryArray;
+use common_arrow::arrow::array::Int32Array;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
 use common_base::base::tokio;
 use databend_query::auth::AuthMgr;
 use databend_query::servers::http::middleware::HTTPSessionEndpoint;
@@ -186,6 +192,85 @@ async fn test_output_format_compress() -> PoemResult<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_output_format_arrow_ipc() -> PoemResult<()> {
+    let config = ConfigBuilder::create().build();
+    let _guard = TestGlobalServices::setup(config.clone()).await.unwrap();
+    let server = Server::new().await;
+    {
+        let (status, body) = server
+            .post("create table t1(a int, b string null)", "")
+            .await;
+        assert_ok!(status, body);
+    }
+
+    {
+        let (status, body) = server
+            .post(
+                "insert into table t1(a, b) format values",
+                "(0, 'a'), (1, NULL), (2, 'c')",
+            )
+            .await;
+        assert_ok!(status, body);
+    }
+
+    let response = server
+        .endpoint
+        .get_response(
+            QueryBuilder::new("select * from t1 order by a")
+                .accept("application/vnd.apache.arrow.stream")
+                .build(),
+        )
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.content_type(),
+        Some("application/vnd.apache.arrow.stream")
+    );
+    let body = response.into_body().into_vec().await.unwrap();
+
+    let mut reader = Cursor::new(body);
+    let metadata = read_stream_metadata(&mut reader).unwrap();
+    let names = metadata
+        .schema
+        .fields
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["a", "b"]);
+
+    let mut a = vec![];
+    let mut b = vec![];
+    for state in StreamReader::new(reader, metadata, None) {
+        let chunk = match state.unwrap() {
+            StreamState::Some(chunk) => chunk,
+            StreamState::Waiting => unreachable!(),
+        };
+        let arrays = chunk.arrays();
+        let col_a = arrays[0].as_any().downcast_ref::<Int32Array>().unwrap();
+        let col_b = arrays[1]
+            .as_any()
+            .downcast_ref::<BinaryArray<i64>>()
+            .unwrap();
+        a.extend(col_a.iter().map(|v| v.copied()));
+        b.extend(col_b.iter().map(|v| v.map(|s| s.to_vec())));
+    }
+    assert_eq!(a, vec![Some(0), Some(1), Some(2)]);
+    assert_eq!(b, vec![Some(b"a".to_vec()), None, Some(b"c".to_vec())]);
+
+    // an explicit FORMAT clause wins over the Accept header.
+    let (status, body) = server
+        .get_response(
+            QueryBuilder::new("select * from t1 order by a format TSV")
+                .accept("application/vnd.apache.arrow.stream")
+                .build(),
+        )
+        .await;
+    assert_ok!(status, body);
+    assert_eq!(&body, "0\ta\n1\t\\N\n2\tc\n");
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_insert_format_values() -> PoemResult<()> {
     let config = ConfigBuilder::create().build();
@@ -377,6 +462,7 @@ struct QueryBuilder {
     body: Option<Body>,
     settings: HashMap<String, String>,
     compress: bool,
+    accept: Option<String>,
 }
 
 impl QueryBuilder {
@@ -386,6 +472,7 @@ impl QueryBuilder {
             body: None,
             settings: HashMap::new(),
             compress: false,
+            accept: None,
         }
     }
 
@@ -404,6 +491,13 @@ impl QueryBuilder {
         Self { settings, ..self }
     }
 
+    pub fn accept(self, accept: &str) -> Self {
+        Self {
+            accept: Some(accept.to_string()),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Request {
         let mut uri = url::form_urlencoded::Serializer::new(String::new());
         uri.append_pair("query", &self.sql);
@@ -423,11 +517,14 @@ impl QueryBuilder {
         };
 
         let basic = Authorization::basic("root", "");
-        Request::builder()
+        let mut builder = Request::builder()
             .uri(uri)
             .method(method)
-            .typed_header(basic)
-            .body(body)
+            .typed_header(basic);
+        if let Some(accept) = self.accept {
+            builder = builder.header(http::header::ACCEPT, accept);
+        }
+        builder.body(body)
     }
 }
 
@@ -192,8 +192,8 @@ async fn test_show_databases() -> Result<()> {
     let (status, result) = post_sql(sql, 1).await?;
     assert_eq!(status, StatusCode::OK, "{:?}", result);
     assert!(result.error.is_none(), "{:?}", result);
-    // has two fields: catalog, name
-    assert_eq!(result.schema.len(), 2, "{:?}", result);
+    // has five fields: catalog, name, max_tables, max_bytes, max_rows
+    assert_eq!(result.schema.len(), 5, "{:?}", result);
     Ok(())
 }
 
@@ -1198,6 +1198,41 @@ async fn test_affect() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_settings_hint() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let route = create_endpoint().await?;
+
+    let json = serde_json::json!({"sql": "/*+ SET_VAR(timezone='Asia/Shanghai') */ select value from system.settings where name = 'timezone'", "session": {"keep_server_session_secs": 10}});
+    let (status, result) = post_json_to_endpoint(&route, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.data[0][0], "Asia/Shanghai", "{:?}", result);
+    // hinted settings are not written back to the session
+    assert_eq!(result.affect, None, "{:?}", result);
+    let session_id = &result.session_id.unwrap();
+
+    // the hint is reverted once the query finishes
+    let json = serde_json::json!({"sql": "select value from system.settings where name = 'timezone'", "session_id": session_id});
+    let (status, result) = post_json_to_endpoint(&route, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.data[0][0], "UTC", "{:?}", result);
+
+    // unknown settings are ignored
+    let sql = "/*+ SET_VAR(no_such_setting=1) */ select 1";
+    let (status, result) = post_sql(sql, 1).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result.error);
+
+    let sql = "/*+ SET_VAR(timezone='Mars/Olympus') */ select 1";
+    let (status, result) = post_sql(sql, 1).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_some(), "{:?}", result);
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_auth_configured_user() -> Result<()> {
     let user_name = "conf_user";
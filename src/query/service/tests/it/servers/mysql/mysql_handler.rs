@@ -50,6 +50,35 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_settings_hint_with_on_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs)?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    let sql = "/*+ SET_VAR(timezone='Asia/Shanghai') */ SELECT value FROM system.settings WHERE name = 'timezone'";
+    let value: Option<String> = connection
+        .query_first(sql)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    assert_eq!(value.as_deref(), Some("Asia/Shanghai"));
+
+    // the hint is scoped to the query above
+    let sql = "SELECT value FROM system.settings WHERE name = 'timezone'";
+    let value: Option<String> = connection
+        .query_first(sql)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    assert_eq!(value.as_deref(), Some("UTC"));
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     let _guard =
@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod presign;
+mod result_persist;
+mod sequence;
+
 #[test]
 pub fn test_format_field_name() {
     use databend_query::sql::executor::decode_field_name;
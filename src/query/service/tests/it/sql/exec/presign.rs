@@ -0,0 +1,95 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+/// Returns the (method, url) of the presigned request.
+async fn presign(fixture: &TestFixture, sql: &str) -> Result<(String, String)> {
+    let blocks: Vec<DataBlock> = execute_query(fixture.ctx(), sql)
+        .await?
+        .try_collect()
+        .await?;
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].num_rows(), 1);
+
+    let string_at = |offset: usize| {
+        let value = blocks[0].get_by_offset(offset).value.as_ref();
+        match value.index(0) {
+            Some(ScalarRef::String(s)) => String::from_utf8(s.to_vec()).unwrap(),
+            other => panic!("unexpected value {other:?}"),
+        }
+    };
+    Ok((string_at(0), string_at(2)))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_presign_s3_stage() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    // Presigning is computed locally, the endpoint is never requested.
+    execute_command(
+        fixture.ctx(),
+        "CREATE STAGE presign_s3 URL = 's3://testbucket/data/' \
+         CONNECTION = (endpoint_url = 'https://s3.example.com' region = 'us-east-1' \
+         access_key_id = 'access_key' secret_access_key = 'secret_key')",
+    )
+    .await?;
+
+    let (method, url) = presign(&fixture, "PRESIGN @presign_s3/books.csv").await?;
+    assert_eq!(method, "GET");
+    assert!(url.starts_with("https://s3.example.com/"), "{url}");
+    assert!(url.contains("data/books.csv"), "{url}");
+    assert!(url.contains("X-Amz-Expires=3600"), "{url}");
+
+    let (method, url) = presign(
+        &fixture,
+        "PRESIGN DOWNLOAD @presign_s3/books.csv EXPIRE = 600",
+    )
+    .await?;
+    assert_eq!(method, "GET");
+    assert!(url.contains("X-Amz-Expires=600"), "{url}");
+
+    let (method, url) = presign(
+        &fixture,
+        "PRESIGN UPLOAD @presign_s3/new/books.csv EXPIRE = 120",
+    )
+    .await?;
+    assert_eq!(method, "PUT");
+    assert!(url.contains("data/new/books.csv"), "{url}");
+    assert!(url.contains("X-Amz-Expires=120"), "{url}");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_presign_unsupported_storage() -> Result<()> {
+    // The fixture stores the internal stages on the local fs, which can't presign.
+    let fixture = TestFixture::new().await;
+
+    let res = presign(&fixture, "PRESIGN @~/books.csv").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::STORAGE_UNSUPPORTED);
+
+    let res = presign(&fixture, "PRESIGN UPLOAD @~/books.csv").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::STORAGE_UNSUPPORTED);
+
+    Ok(())
+}
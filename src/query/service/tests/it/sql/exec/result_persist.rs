@@ -0,0 +1,124 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_storage::DataOperator;
+use common_storages_result_cache::gen_result_persist_dir;
+use common_storages_result_cache::ResultPersistManifest;
+use databend_query::sessions::ResultPersistJanitor;
+use databend_query::sessions::TableContext;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+async fn num_rows(fixture: &TestFixture, sql: &str) -> Result<usize> {
+    let blocks: Vec<DataBlock> = execute_query(fixture.ctx(), sql)
+        .await?
+        .try_collect()
+        .await?;
+    Ok(blocks.iter().map(|b| b.num_rows()).sum())
+}
+
+/// Run the query with the result persisted, and return the manifest of the result.
+async fn persist_query(fixture: &TestFixture, sql: &str) -> Result<Option<ResultPersistManifest>> {
+    let ctx = fixture.ctx();
+    execute_command(ctx.clone(), "SET enable_query_result_persist = 1").await?;
+    assert_eq!(num_rows(fixture, sql).await?, 10);
+    // Don't persist the queries scanning the result, they share the query id in the tests.
+    execute_command(ctx.clone(), "SET enable_query_result_persist = 0").await?;
+
+    let dir = gen_result_persist_dir(&ctx.get_tenant(), &ctx.get_id());
+    ResultPersistManifest::read(&DataOperator::instance().operator(), &dir).await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_result_persist_scan() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let query_id = fixture.ctx().get_id();
+
+    let manifest = persist_query(&fixture, "SELECT number FROM numbers(10)").await?;
+    let manifest = manifest.expect("the result should be persisted");
+    assert_eq!(manifest.query_id, query_id);
+    assert_eq!(manifest.num_rows, 10);
+    assert_eq!(manifest.user, fixture.ctx().get_current_user()?.identity());
+    assert!(!manifest.blocks.is_empty());
+
+    // The result is not cached, so it is read from the persisted blocks.
+    let sql = format!("SELECT * FROM RESULT_SCAN('{query_id}') WHERE number > 4");
+    assert_eq!(num_rows(&fixture, &sql).await?, 5);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_result_persist_ttl() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let query_id = ctx.get_id();
+    let operator = DataOperator::instance().operator();
+
+    // The result expires right after it is persisted.
+    execute_command(ctx.clone(), "SET query_result_persist_ttl_secs = 0").await?;
+    let manifest = persist_query(&fixture, "SELECT number FROM numbers(10)").await?;
+    let manifest = manifest.expect("the result should be persisted");
+    assert_eq!(manifest.created_on, manifest.expire_at);
+
+    let sql = format!("SELECT * FROM RESULT_SCAN('{query_id}')");
+    let res = num_rows(&fixture, &sql).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::EMPTY_DATA);
+
+    // Not expired yet.
+    let tenant = ctx.get_tenant();
+    let removed =
+        ResultPersistJanitor::remove_expired(&operator, &tenant, manifest.expire_at - 1).await?;
+    assert_eq!(removed, 0);
+
+    let removed =
+        ResultPersistJanitor::remove_expired(&operator, &tenant, manifest.expire_at).await?;
+    assert_eq!(removed, 1);
+    let dir = gen_result_persist_dir(&tenant, &query_id);
+    assert!(
+        ResultPersistManifest::read(&operator, &dir)
+            .await?
+            .is_none()
+    );
+    for block in manifest.blocks {
+        assert!(!operator.is_exist(&block).await?);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_result_persist_size_limit() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let query_id = ctx.get_id();
+
+    // Results larger than the limit are not persisted at all.
+    execute_command(ctx.clone(), "SET query_result_persist_max_bytes = 1").await?;
+    let manifest = persist_query(&fixture, "SELECT number FROM numbers(10)").await?;
+    assert!(manifest.is_none());
+
+    let sql = format!("SELECT * FROM RESULT_SCAN('{query_id}')");
+    let res = num_rows(&fixture, &sql).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::EMPTY_DATA);
+
+    Ok(())
+}
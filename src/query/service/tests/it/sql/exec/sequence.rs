@@ -0,0 +1,115 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_expression::types::Int64Type;
+use common_expression::types::ValueType;
+use common_expression::DataBlock;
+use common_users::UserApiProvider;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+/// Creates the context of another session of the same user.
+async fn new_session_ctx(ctx: &Arc<QueryContext>) -> Result<Arc<QueryContext>> {
+    let session = SessionManager::instance()
+        .create_session(SessionType::Dummy)
+        .await?;
+    session
+        .set_authed_user(ctx.get_current_user()?, None)
+        .await?;
+    session.create_query_context().await
+}
+
+async fn nextvals(ctx: Arc<QueryContext>, sequence: &str, num: usize) -> Result<Vec<i64>> {
+    let sql = format!("SELECT nextval('{sequence}') FROM numbers({num})");
+    let blocks: Vec<DataBlock> = execute_query(ctx, &sql).await?.try_collect().await?;
+
+    let mut values = vec![];
+    for block in blocks {
+        let entry = block.get_by_offset(0);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, block.num_rows());
+        values.extend(Int64Type::try_downcast_column(&column).unwrap().iter());
+    }
+    Ok(values)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sequence_concurrent_sessions() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx1 = fixture.ctx();
+    let ctx2 = new_session_ctx(&ctx1).await?;
+
+    execute_command(ctx1.clone(), "CREATE SEQUENCE seq_sessions").await?;
+    // A small cache, so the values are reserved from the meta service many times.
+    for ctx in [&ctx1, &ctx2] {
+        execute_command(ctx.clone(), "SET sequence_cache_size = 4").await?;
+    }
+
+    let sequence_api = UserApiProvider::instance().get_sequence_api_client(&ctx1.get_tenant())?;
+    let mut values1 = vec![];
+    let mut values2 = vec![];
+    let mut reserved_elsewhere = vec![];
+    for _ in 0..10 {
+        let (v1, v2) = futures::try_join!(
+            nextvals(ctx1.clone(), "seq_sessions", 3),
+            nextvals(ctx2.clone(), "seq_sessions", 3)
+        )?;
+        values1.extend(v1);
+        values2.extend(v2);
+        // Like another node reserving a block of values in between.
+        reserved_elsewhere.extend(
+            sequence_api
+                .reserve_sequence_values("seq_sessions", 2)
+                .await?,
+        );
+    }
+
+    // Each session sees its values strictly increasing.
+    for values in [&values1, &values2] {
+        assert_eq!(values.len(), 30);
+        assert!(values.windows(2).all(|w| w[0] < w[1]), "{values:?}");
+    }
+
+    // No value is generated twice, and the values reserved by the other node are skipped.
+    let mut all = [values1.clone(), values2.clone()].concat();
+    all.sort();
+    all.dedup();
+    assert_eq!(all.len(), 60);
+    assert!(all.iter().all(|v| !reserved_elsewhere.contains(v)));
+    assert!(*all.last().unwrap() > 60);
+
+    // `currval` is the last value generated in the session.
+    assert_eq!(
+        ctx1.get_sequence_value("seq_sessions"),
+        values1.last().copied()
+    );
+    assert_eq!(
+        ctx2.get_sequence_value("seq_sessions"),
+        values2.last().copied()
+    );
+
+    Ok(())
+}
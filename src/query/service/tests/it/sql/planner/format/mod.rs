@@ -144,6 +144,7 @@ fn test_format() {
                 }
                 .into(),
             ],
+            is_null_equal: vec![],
             non_equi_conditions: vec![],
             join_type: JoinType::Inner,
             marker_index: None,
@@ -171,6 +172,7 @@ fn test_format() {
                     limit: None,
                     order_by: None,
                     prewhere: None,
+                    sample: None,
                     statistics: Statistics {
                         statistics: None,
                         col_stats: Default::default(),
@@ -188,6 +190,7 @@ fn test_format() {
                 limit: None,
                 order_by: None,
                 prewhere: None,
+                sample: None,
                 statistics: Statistics {
                     statistics: None,
                     col_stats: Default::default(),
@@ -22,5 +22,6 @@ mod pruning;
 mod statistics;
 mod table;
 mod table_functions;
+mod table_metrics;
 mod table_test_fixture;
 mod utils;
@@ -28,6 +28,7 @@ use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchemaRefExt;
+use common_sql::plans::AddColumnOption;
 use common_sql::plans::AddTableColumnPlan;
 use common_sql::plans::DropTableColumnPlan;
 use common_sql::Planner;
@@ -179,6 +180,7 @@ async fn test_fuse_table_optimize_alter_table() -> Result<()> {
         schema,
         field_default_exprs: vec![],
         field_comments: vec![],
+        option: AddColumnOption::End,
     };
     let interpreter = AddTableColumnInterpreter::try_create(ctx.clone(), add_table_column_plan)?;
     interpreter.execute(ctx.clone()).await?;
@@ -0,0 +1,129 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use common_base::base::tokio;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::storage::StorageParams;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_err;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_attach() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let qry = format!("insert into {}.{} values (1, (2, 3)), (2, (4, 6))", db, tbl);
+    execute_command(ctx.clone(), &qry).await?;
+
+    // 1. attach to the data of the table
+    let table = fixture.latest_default_table().await?;
+    let root = match &GlobalConfig::instance().storage.params {
+        StorageParams::Fs(v) => v.root.clone(),
+        _ => panic!("storage type is not fs"),
+    };
+    let location = format!(
+        "fs://{}/{}/{}/",
+        root.trim_end_matches('/'),
+        table.get_table_info().meta.options[OPT_KEY_DATABASE_ID],
+        table.get_table_info().ident.table_id
+    );
+    let qry = format!("attach table {}.attached from '{}' read_only", db, location);
+    execute_command(ctx.clone(), &qry).await?;
+
+    let qry = format!("select id, t from {}.attached order by id", db);
+    expects_ok(
+        "attached table",
+        execute_query(ctx.clone(), &qry).await,
+        vec![
+            "+----------+----------+",
+            "| Column 0 | Column 1 |",
+            "+----------+----------+",
+            "| 1        | (2, 3)   |",
+            "| 2        | (4, 6)   |",
+            "+----------+----------+",
+        ],
+    )
+    .await?;
+
+    // 2. the new commits of the table are visible to the attached table
+    let qry = format!("insert into {}.{} values (3, (6, 9))", db, tbl);
+    execute_command(ctx.clone(), &qry).await?;
+    let qry = format!("delete from {}.{} where id = 1", db, tbl);
+    execute_command(ctx.clone(), &qry).await?;
+
+    let qry = format!("select id, t from {}.attached order by id", db);
+    expects_ok(
+        "attached table after commits of the table",
+        execute_query(ctx.clone(), &qry).await,
+        vec![
+            "+----------+----------+",
+            "| Column 0 | Column 1 |",
+            "+----------+----------+",
+            "| 2        | (4, 6)   |",
+            "| 3        | (6, 9)   |",
+            "+----------+----------+",
+        ],
+    )
+    .await?;
+
+    // 3. the attached table can't be modified
+    let qry = format!("insert into {}.attached values (4, (8, 12))", db);
+    expects_err(
+        "insert into attached table",
+        ErrorCode::TABLE_NOT_WRITABLE,
+        execute_command(ctx.clone(), &qry).await,
+    );
+    let qry = format!("truncate table {}.attached", db);
+    expects_err(
+        "truncate attached table",
+        ErrorCode::TABLE_NOT_WRITABLE,
+        execute_command(ctx.clone(), &qry).await,
+    );
+    let qry = format!("alter table {}.attached add column c int", db);
+    expects_err(
+        "alter attached table",
+        ErrorCode::TABLE_NOT_WRITABLE,
+        execute_command(ctx.clone(), &qry).await,
+    );
+
+    // 4. dropping the attached table keeps the data of the table
+    let qry = format!("drop table {}.attached all", db);
+    execute_command(ctx.clone(), &qry).await?;
+
+    let qry = format!("select count(*) from {}.{}", db, tbl);
+    expects_ok(
+        "table after the attached table dropped",
+        execute_query(ctx.clone(), &qry).await,
+        vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            "| 2        |",
+            "+----------+",
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
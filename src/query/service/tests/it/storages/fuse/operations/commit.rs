@@ -12,6 +12,7 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::atomic::AtomicBool;
@@ -27,18 +28,22 @@ use common_catalog::database::Database;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
+use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::FunctionContext;
+use common_expression::Scalar;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatOptions;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
@@ -66,11 +71,14 @@ use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_app::tenant::DatabaseQuota;
 use common_meta_types::MetaId;
 use common_settings::Settings;
+use common_storage::CopyStatus;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storages_fuse::operations::AppendOperationLogEntry;
+use common_storages_fuse::operations::SourceOffsetsAdvance;
 use common_storages_fuse::FuseTable;
 use common_storages_fuse::FUSE_TBL_SNAPSHOT_PREFIX;
 use databend_query::sessions::QueryContext;
@@ -177,6 +185,54 @@ async fn test_last_snapshot_hint() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_commit_with_source_offsets() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    fixture.create_default_table().await?;
+    let ctx = fixture.ctx();
+
+    let advance = |from: &[(i32, i64)], to: &[(i32, i64)]| SourceOffsetsAdvance {
+        source: "kafka/g1/events".to_string(),
+        from: BTreeMap::from_iter(from.iter().cloned()),
+        to: BTreeMap::from_iter(to.iter().cloned()),
+    };
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    fuse_table
+        .do_commit_with_source_offsets(ctx.clone(), vec![], advance(&[], &[(0, 10), (1, 3)]))
+        .await?;
+
+    // The same data again, e.g. by a node which lost the lease of the pipe in the meantime.
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let r = fuse_table
+        .do_commit_with_source_offsets(ctx.clone(), vec![], advance(&[], &[(0, 10)]))
+        .await;
+    assert_eq!(r.unwrap_err().code(), ErrorCode::PIPE_OFFSETS_MISMATCH);
+
+    fuse_table
+        .do_commit_with_source_offsets(ctx.clone(), vec![], advance(&[(0, 10)], &[(0, 15)]))
+        .await?;
+
+    // The offsets are kept by the other commits.
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    fuse_table
+        .do_commit(ctx.clone(), vec![], None, false)
+        .await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    assert_eq!(
+        snapshot.source_offsets.get("kafka/g1/events"),
+        Some(&BTreeMap::from([(0, 15), (1, 3)]))
+    );
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_abort_on_error() -> Result<()> {
     struct Case {
@@ -209,7 +265,7 @@ async fn test_abort_on_error() -> Result<()> {
             };
             let ctx = Arc::new(CtxDelegation::new(ctx, faked_catalog));
             let r = fuse_table
-                .commit_with_max_retry_elapsed(ctx, log, None, self.max_retry_time, overwrite)
+                .commit_with_max_retry_elapsed(ctx, log, None, self.max_retry_time, overwrite, None)
                 .await;
             if self.update_meta_error.is_some() {
                 assert_eq!(
@@ -418,6 +474,16 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn get_granted_columns(
+        &self,
+        _catalog: &str,
+        _database: &str,
+        _table: &str,
+        _privilege: UserPrivilegeType,
+    ) -> Result<Option<HashSet<String>>> {
+        todo!()
+    }
+
     fn get_fuse_version(&self) -> String {
         todo!()
     }
@@ -475,6 +541,38 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_copy_status(&self) -> Arc<CopyStatus> {
+        todo!()
+    }
+
+    fn get_txn_manager(&self) -> TxnManagerRef {
+        self.ctx.get_txn_manager()
+    }
+
+    fn set_variable(&self, _key: String, _value: Scalar) {
+        todo!()
+    }
+
+    fn unset_variable(&self, _key: &str) {
+        todo!()
+    }
+
+    fn get_variable(&self, _key: &str) -> Option<Scalar> {
+        todo!()
+    }
+
+    fn get_all_variables(&self) -> HashMap<String, Scalar> {
+        todo!()
+    }
+
+    fn set_sequence_value(&self, _name: String, _value: i64) {
+        todo!()
+    }
+
+    fn get_sequence_value(&self, _name: &str) -> Option<i64> {
+        todo!()
+    }
+
     fn apply_changed_settings(&self, _changed_settings: Arc<Settings>) -> Result<()> {
         todo!()
     }
@@ -495,10 +593,18 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn apply_settings_hint(&self, _hint: Vec<(String, String)>) -> Result<()> {
+        todo!()
+    }
+
     async fn get_file_format(&self, _name: &str) -> Result<FileFormatOptions> {
         todo!()
     }
 
+    async fn get_database_quotas(&self, db_ids: &[u64]) -> Result<Vec<DatabaseQuota>> {
+        self.ctx.get_database_quotas(db_ids).await
+    }
+
     async fn get_table(
         &self,
         _catalog: &str,
@@ -508,6 +614,16 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn get_table_at(
+        &self,
+        _catalog: &str,
+        _database: &str,
+        _table: &str,
+        _point: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>> {
+        todo!()
+    }
+
     async fn color_copied_files(
         &self,
         _catalog_name: &str,
@@ -132,6 +132,22 @@ async fn test_recluster_mutator_block_select() -> Result<()> {
         }
     });
 
+    let mut mutator = ReclusterMutator::try_create(
+        ctx.clone(),
+        location_generator.clone(),
+        base_snapshot.clone(),
+        1.0,
+        BlockThresholds::default(),
+        blocks_map.clone(),
+        data_accessor.clone(),
+        None,
+    )?;
+
+    let need_recluster = mutator.target_select().await?;
+    assert!(need_recluster);
+    assert_eq!(mutator.selected_blocks().len(), 3);
+
+    // The number of selected blocks is capped by the limit.
     let mut mutator = ReclusterMutator::try_create(
         ctx,
         location_generator,
@@ -140,11 +156,12 @@ async fn test_recluster_mutator_block_select() -> Result<()> {
         BlockThresholds::default(),
         blocks_map,
         data_accessor,
+        Some(2),
     )?;
 
     let need_recluster = mutator.target_select().await?;
     assert!(need_recluster);
-    assert_eq!(mutator.selected_blocks().len(), 3);
+    assert_eq!(mutator.selected_blocks().len(), 2);
 
     Ok(())
 }
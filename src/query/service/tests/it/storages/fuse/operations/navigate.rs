@@ -13,9 +13,12 @@
 //  limitations under the License.
 
 use std::ops::Sub;
+use std::sync::Arc;
 use std::time::Duration;
 
 use common_base::base::tokio;
+use common_catalog::table::NavigationPoint;
+use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
@@ -25,7 +28,9 @@ use databend_query::storages::fuse::io::TableMetaLocationGenerator;
 use databend_query::storages::fuse::FuseTable;
 use futures::TryStreamExt;
 
+use crate::storages::fuse::table_test_fixture::execute_command;
 use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_ok;
 use crate::storages::fuse::table_test_fixture::TestFixture;
 
 #[tokio::test(flavor = "multi_thread")]
@@ -117,3 +122,84 @@ async fn test_fuse_navigate() -> Result<()> {
     };
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_navigate_self_join() -> Result<()> {
+    // - perform two insertions, which will left 2 snapshots
+    // - a self-join of the table at the two snapshots should read both of them
+
+    // 1. Setup
+    let fixture = TestFixture::new().await;
+    let catalog = fixture.default_catalog_name();
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // 1.1 first commit
+    let qry = format!(
+        "insert into {}.{} values (1, (2, 3)), (2, (4, 6)) ",
+        db, tbl
+    );
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    let table = fixture.latest_default_table().await?;
+    let first_snapshot = FuseTable::try_from_table(table.as_ref())?
+        .read_table_snapshot()
+        .await?
+        .unwrap()
+        .snapshot_id
+        .simple()
+        .to_string();
+
+    // 1.2 second commit
+    let qry = format!("insert into {}.{} values (3, (6, 9)) ", db, tbl);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    let table = fixture.latest_default_table().await?;
+    let second_snapshot = FuseTable::try_from_table(table.as_ref())?
+        .read_table_snapshot()
+        .await?
+        .unwrap()
+        .snapshot_id
+        .simple()
+        .to_string();
+    assert_ne!(first_snapshot, second_snapshot);
+
+    // 2. distinct navigation points resolve to distinct tables of the query
+    let first_point = NavigationPoint::SnapshotID(first_snapshot.clone());
+    let second_point = NavigationPoint::SnapshotID(second_snapshot.clone());
+    let first = ctx.get_table_at(&catalog, &db, &tbl, &first_point).await?;
+    let second = ctx.get_table_at(&catalog, &db, &tbl, &second_point).await?;
+    assert_ne!(
+        FuseTable::try_from_table(first.as_ref())?
+            .snapshot_loc()
+            .await?,
+        FuseTable::try_from_table(second.as_ref())?
+            .snapshot_loc()
+            .await?
+    );
+
+    // the same navigation point resolves to the same table
+    let first_again = ctx.get_table_at(&catalog, &db, &tbl, &first_point).await?;
+    assert!(Arc::ptr_eq(&first, &first_again));
+
+    // 3. the self-join reads the rows of both snapshots
+    let qry = format!(
+        "select count(distinct t1.id), count(distinct t2.id) \
+         from {db}.{tbl} at (snapshot => '{first_snapshot}') t1, \
+         {db}.{tbl} at (snapshot => '{second_snapshot}') t2"
+    );
+    expects_ok(
+        "self-join at two snapshots",
+        execute_query(ctx.clone(), &qry).await,
+        vec![
+            "+----------+----------+",
+            "| Column 0 | Column 1 |",
+            "+----------+----------+",
+            "| 2        | 3        |",
+            "+----------+----------+",
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
@@ -392,6 +392,35 @@ fn test_ft_stats_block_stats_string_columns_trimming() -> common_exception::Resu
     Ok(())
 }
 
+#[test]
+fn test_ft_stats_block_stats_binary_columns_trimming() -> common_exception::Result<()> {
+    let cases: Vec<(Vec<u8>, Option<Vec<u8>>)> = vec![
+        (vec![0x01, 0x02], Some(vec![0x01, 0x02])),
+        (vec![0xab; 20], Some([vec![0xab; 15], vec![0xac]].concat())),
+        (
+            [vec![0x00; 15], vec![0xff; 5]].concat(),
+            Some([vec![0x00; 14], vec![0x01]].concat()),
+        ),
+        (vec![0xff; 20], None),
+    ];
+
+    for (value, expected_max) in cases {
+        let data_value = Scalar::Binary(value.clone());
+
+        let trimmed_min = data_value.clone().trim_min().unwrap();
+        let trimmed_min = trimmed_min.as_binary().unwrap().clone();
+        assert!(trimmed_min.len() <= STATS_STRING_PREFIX_LEN);
+        assert!(Scalar::Binary(trimmed_min) <= data_value);
+
+        let trimmed_max = data_value.clone().trim_max();
+        assert_eq!(trimmed_max, expected_max.map(Scalar::Binary));
+        if let Some(trimmed_max) = trimmed_max {
+            assert!(trimmed_max >= data_value);
+        }
+    }
+    Ok(())
+}
+
 #[test]
 fn test_ft_stats_block_stats_string_columns_trimming_using_eval() -> common_exception::Result<()> {
     // verifies (randomly) the following assumptions:
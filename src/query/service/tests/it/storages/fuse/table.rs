@@ -17,12 +17,21 @@ use std::default::Default;
 use common_base::base::tokio;
 use common_exception::Result;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::storage::StorageFsConfig;
+use common_meta_app::storage::StorageParams;
 use common_sql::executor::table_read_plan::ToReadDataSourcePlan;
+use databend_query::interpreters::CreateTableInterpreter;
+use databend_query::interpreters::Interpreter;
 use databend_query::storages::fuse::FuseTable;
 use databend_query::stream::ReadDataBlockStream;
 use futures::TryStreamExt;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use tempfile::TempDir;
 
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_err;
+use crate::storages::fuse::table_test_fixture::expects_ok;
 use crate::storages::fuse::table_test_fixture::TestFixture;
 
 #[tokio::test(flavor = "multi_thread")]
@@ -127,6 +136,69 @@ async fn test_fuse_table_normal_case() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_with_own_storage() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    // Two tables, each one stored in its own location.
+    let dir_a = TempDir::new().unwrap();
+    let dir_b = TempDir::new().unwrap();
+    for (tbl, dir) in [("t_a", &dir_a), ("t_b", &dir_b)] {
+        let mut plan = fixture.normal_create_table_plan();
+        plan.table = tbl.to_string();
+        plan.storage_params = Some(StorageParams::Fs(StorageFsConfig {
+            root: dir.path().to_str().unwrap().to_string(),
+        }));
+        let interpreter = CreateTableInterpreter::try_create(ctx.clone(), plan)?;
+        interpreter.execute(ctx.clone()).await?;
+    }
+
+    let qry = format!("insert into {}.t_a values(1, (2, 3)),(2, (4, 6))", db);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    let qry = format!("insert into {}.t_b values(2, (4, 6)),(3, (6, 9))", db);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+
+    // The data of each table is written to its own location.
+    assert!(std::fs::read_dir(dir_a.path())?.next().is_some());
+    assert!(std::fs::read_dir(dir_b.path())?.next().is_some());
+
+    // A query reads the tables with different operators.
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 2        | 2        |",
+        "+----------+----------+",
+    ];
+    let qry = format!(
+        "select a.id, b.id from {}.t_a a join {}.t_b b on a.id = b.id",
+        db, db
+    );
+    expects_ok(
+        "join_tables_with_own_storage",
+        execute_query(ctx.clone(), qry.as_str()).await,
+        expected,
+    )
+    .await?;
+
+    // Only the tables created with their own location have a connection to alter.
+    fixture.create_normal_table().await?;
+    let qry = format!(
+        "alter table {}.{} connection = (access_key_id = 'ak')",
+        db,
+        fixture.default_table_name()
+    );
+    expects_err(
+        "alter_connection_of_table_without_location",
+        1301,
+        execute_query(ctx.clone(), qry.as_str()).await,
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_parse_storage_prefix() -> Result<()> {
     let mut tbl_info = TableInfo::default();
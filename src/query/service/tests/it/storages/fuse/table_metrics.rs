@@ -0,0 +1,84 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_metrics::dump_metric_samples;
+use common_metrics::init_default_metrics_recorder;
+use common_metrics::try_handle;
+use common_metrics::MetricValue;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+/// Sum of the counter `name` of the table, over the other labels.
+fn table_counter(name: &str, table_id: u64) -> Result<f64> {
+    let samples = dump_metric_samples(try_handle().unwrap())?;
+    let table_id = table_id.to_string();
+    Ok(samples
+        .iter()
+        .filter(|s| s.name == name && s.labels.get("table_id") == Some(&table_id))
+        .map(|s| match s.value {
+            MetricValue::Counter(v) => v,
+            _ => 0.0,
+        })
+        .sum())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_metrics() -> Result<()> {
+    init_default_metrics_recorder();
+
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let table = fixture.latest_default_table().await?;
+    let table_id = table.get_id();
+
+    // commit twice through the same table instance, the second commit is on a stale
+    // version of the table and has to be retried.
+    let retries_before = table_counter("fuse_table_commit_retries", table_id)?;
+    for value_start_from in [1, 5] {
+        let stream = TestFixture::gen_sample_blocks_stream_ex(1, 1, value_start_from);
+        let blocks = stream.try_collect().await?;
+        fixture
+            .append_commit_blocks(table.clone(), blocks, false, true)
+            .await?;
+    }
+    let retries_after = table_counter("fuse_table_commit_retries", table_id)?;
+    assert!(retries_after > retries_before);
+
+    // scan the table.
+    let read_bytes_before = table_counter("fuse_table_block_read_bytes", table_id)?;
+    let segments_before = table_counter("fuse_table_segments_scanned", table_id)?;
+    let qry = format!(
+        "select * from {}.{}",
+        fixture.default_db_name(),
+        fixture.default_table_name()
+    );
+    execute_query(ctx.clone(), qry.as_str())
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let read_bytes_after = table_counter("fuse_table_block_read_bytes", table_id)?;
+    let segments_after = table_counter("fuse_table_segments_scanned", table_id)?;
+    assert!(read_bytes_after > read_bytes_before);
+    assert_eq!(segments_after - segments_before, 2.0);
+
+    Ok(())
+}
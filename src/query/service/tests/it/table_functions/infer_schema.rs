@@ -0,0 +1,174 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use databend_query::table_functions::JsonSchemaInferrer;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use serde_json::Value;
+
+fn infer(rows: &[Value], flatten_nested: bool) -> Result<Vec<(String, TableDataType)>> {
+    let mut inferrer = JsonSchemaInferrer::new(flatten_nested);
+    for row in rows {
+        inferrer.add_row(row)?;
+    }
+    Ok(inferrer
+        .finish()
+        .fields()
+        .iter()
+        .map(|f| (f.name().clone(), f.data_type().clone()))
+        .collect())
+}
+
+fn nullable(ty: TableDataType) -> TableDataType {
+    TableDataType::Nullable(Box::new(ty))
+}
+
+fn tuple(fields: Vec<(&str, TableDataType)>) -> TableDataType {
+    TableDataType::Tuple {
+        fields_name: fields.iter().map(|(name, _)| name.to_string()).collect(),
+        fields_type: fields.into_iter().map(|(_, ty)| ty).collect(),
+    }
+}
+
+fn columns(columns: Vec<(&str, TableDataType)>) -> Vec<(String, TableDataType)> {
+    columns
+        .into_iter()
+        .map(|(name, ty)| (name.to_string(), ty))
+        .collect()
+}
+
+const INT8: TableDataType = TableDataType::Number(NumberDataType::Int8);
+const INT64: TableDataType = TableDataType::Number(NumberDataType::Int64);
+const FLOAT64: TableDataType = TableDataType::Number(NumberDataType::Float64);
+
+#[test]
+fn test_infer_json_schema_widening() -> Result<()> {
+    let rows = vec![
+        json!({"a": 1, "b": true, "c": "x", "d": 1, "f": 1}),
+        json!({"a": 300, "b": 1, "c": 2, "d": 1.5, "f": 1}),
+        json!({"a": -2, "e": null, "f": 9223372036854775808u64}),
+    ];
+    assert_eq!(
+        infer(&rows, false)?,
+        columns(vec![
+            ("a", INT64),
+            ("b", nullable(TableDataType::String)),
+            ("c", nullable(TableDataType::String)),
+            ("d", nullable(FLOAT64)),
+            ("f", FLOAT64),
+            ("e", nullable(TableDataType::String)),
+        ])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_infer_json_schema_nested() -> Result<()> {
+    let rows = vec![
+        json!({
+            "id": 1,
+            "user": {"name": "a", "age": 20, "address": {"city": "x"}},
+            "tags": ["x", "y"],
+            "scores": [1, 2.5, null],
+            "items": [{"k": 1}, {"k": 2, "v": [true]}],
+        }),
+        json!({
+            "id": 2,
+            "user": {"name": "b", "address": {"city": "y", "zip": 100}},
+            "tags": [],
+            "scores": [],
+            "items": [{"k": "s"}],
+        }),
+    ];
+
+    let address = tuple(vec![
+        ("city", TableDataType::String),
+        ("zip", nullable(INT8)),
+    ]);
+    let items = TableDataType::Array(Box::new(tuple(vec![
+        ("k", TableDataType::String),
+        (
+            "v",
+            nullable(TableDataType::Array(Box::new(TableDataType::Boolean))),
+        ),
+    ])));
+    assert_eq!(
+        infer(&rows, false)?,
+        columns(vec![
+            ("id", INT8),
+            (
+                "user",
+                tuple(vec![
+                    ("name", TableDataType::String),
+                    ("age", nullable(INT8)),
+                    ("address", address),
+                ])
+            ),
+            (
+                "tags",
+                TableDataType::Array(Box::new(TableDataType::String))
+            ),
+            ("scores", TableDataType::Array(Box::new(nullable(FLOAT64)))),
+            ("items", items.clone()),
+        ])
+    );
+
+    assert_eq!(
+        infer(&rows, true)?,
+        columns(vec![
+            ("id", INT8),
+            ("user.name", TableDataType::String),
+            ("user.age", nullable(INT8)),
+            ("user.address.city", TableDataType::String),
+            ("user.address.zip", nullable(INT8)),
+            (
+                "tags",
+                TableDataType::Array(Box::new(TableDataType::String))
+            ),
+            ("scores", TableDataType::Array(Box::new(nullable(FLOAT64)))),
+            ("items", items),
+        ])
+    );
+
+    // The fields of a missing object are nullable once flattened.
+    let rows = vec![json!({"o": {"a": 1}}), json!({})];
+    assert_eq!(infer(&rows, true)?, columns(vec![("o.a", nullable(INT8))]));
+    Ok(())
+}
+
+#[test]
+fn test_infer_json_schema_conflicts() -> Result<()> {
+    let rows = vec![
+        json!({"a": {"k": 1}, "b": [1], "c": {}, "d": null}),
+        json!({"a": 1, "b": {"k": 1}, "c": {}, "d": null}),
+    ];
+    assert_eq!(
+        infer(&rows, false)?,
+        columns(vec![
+            ("a", TableDataType::Variant),
+            ("b", TableDataType::Variant),
+            ("c", TableDataType::Variant),
+            ("d", nullable(TableDataType::String)),
+        ])
+    );
+
+    let mut inferrer = JsonSchemaInferrer::new(false);
+    let err = inferrer.add_row(&json!([1, 2])).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::BAD_BYTES);
+    Ok(())
+}
@@ -12,5 +12,6 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.W
 
+mod infer_schema;
 mod numbers_table;
 mod openai;
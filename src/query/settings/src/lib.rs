@@ -36,6 +36,24 @@ use common_users::UserApiProvider;
 use dashmap::DashMap;
 use itertools::Itertools;
 
+/// Settings that change the resources, the data retention or the security of the whole server, only users
+/// with the SUPER privilege may override them for a single query with a hint.
+const ADMIN_ONLY_SETTINGS: &[&str] = &[
+    "allowed_external_url_prefix",
+    "copy_history_retention_days",
+    "join_spilling_bytes_threshold",
+    "load_file_metadata_expire_hours",
+    "max_memory_usage",
+    "max_storage_io_requests",
+    "query_result_cache_max_bytes",
+    "query_result_persist_max_bytes",
+    "retention_period",
+    "sandbox_tenant",
+    "slow_query_log_to_tracing",
+    "sort_spilling_bytes_threshold_per_proc",
+    "spilling_bytes_threshold_per_proc",
+];
+
 #[derive(Clone)]
 pub enum ScopeLevel {
     #[allow(dead_code)]
@@ -309,6 +327,16 @@ impl Settings {
                 desc: "Sets the SQL dialect. Available values include \"PostgreSQL\", \"MySQL\", and \"Hive\".",
                 possible_values: Some(vec!["PostgreSQL", "MySQL", "Hive"]),
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_odbc_style_escapes",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Enables the ODBC-style escape clauses, such as {d '2024-01-01'}, {ts '2024-01-01 12:00:00'}, {t '12:00:00'} and {fn NOW()}.",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::UInt64(1),
                 user_setting: UserSetting::create("enable_cbo", UserSettingValue::UInt64(1)),
@@ -334,6 +362,50 @@ impl Settings {
                 desc: "Sets the maximum query execution time in seconds. Setting it to 0 means no limit.",
                 possible_values: None,
             },
+            // kill_query_timeout_ms
+            SettingValue {
+                default_value: UserSettingValue::UInt64(5000),
+                user_setting: UserSetting::create(
+                    "kill_query_timeout_ms",
+                    UserSettingValue::UInt64(5000),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum time in milliseconds that KILL QUERY waits for the killed query to stop.",
+                possible_values: None,
+            },
+            // max_recursive_cte_iterations
+            SettingValue {
+                default_value: UserSettingValue::UInt64(100),
+                user_setting: UserSetting::create(
+                    "max_recursive_cte_iterations",
+                    UserSettingValue::UInt64(100),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum number of iterations a recursive CTE may run before the query fails.",
+                possible_values: None,
+            },
+            // max_recursive_cte_rows
+            SettingValue {
+                default_value: UserSettingValue::UInt64(10000000),
+                user_setting: UserSetting::create(
+                    "max_recursive_cte_rows",
+                    UserSettingValue::UInt64(10000000),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum number of rows a recursive CTE may produce. Setting it to 0 means no limit.",
+                possible_values: None,
+            },
+            // max_auto_materialized_cte_rows
+            SettingValue {
+                default_value: UserSettingValue::UInt64(10000),
+                user_setting: UserSetting::create(
+                    "max_auto_materialized_cte_rows",
+                    UserSettingValue::UInt64(10000),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum estimated number of rows of a CTE referenced more than once to be materialized automatically. Setting it to 0 disables it.",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::String("binary".to_owned()),
                 user_setting: UserSetting::create(
@@ -383,6 +455,16 @@ impl Settings {
                 desc: "Enables evaluated indexes to be created and maintained across multiple nodes.",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_bloom_index_json_validity",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Tracks whether the strings of the written blocks are valid JSON in the bloom index, so that CHECK_JSON(<column>) IS NOT NULL skips the blocks without invalid JSON.",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::UInt64(1),
                 user_setting: UserSetting::create(
@@ -413,6 +495,16 @@ impl Settings {
                 desc: "Sets the hours that the metadata of files you load data from with COPY INTO will expire in.",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(14),
+                user_setting: UserSetting::create(
+                    "copy_history_retention_days",
+                    UserSettingValue::UInt64(14),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the days that the load history of COPY INTO is kept before being purged.",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::UInt64(1),
                 user_setting: UserSetting::create(
@@ -433,6 +525,16 @@ impl Settings {
                 desc: "Injects a custom 'sandbox_tenant' into this session. This is only for testing purposes and will take effect only when 'internal_enable_sandbox_tenant' is turned on.",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::String("".to_string()),
+                user_setting: UserSetting::create(
+                    "allowed_external_url_prefix",
+                    UserSettingValue::String("".to_string()),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets a comma-separated list of URL prefixes that files can be loaded from over HTTP(S), such as 'https://example.com/data/'. All URLs are allowed if empty.",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::UInt64(2 * 1024 * 1024),
                 user_setting: UserSetting::create(
@@ -491,6 +593,66 @@ impl Settings {
                 desc: "Determines whether Databend will return cached query results that are inconsistent with the underlying data.",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_query_result_persist",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Persists the result of each query, so that RESULT_SCAN can read it after the result cache expires.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(10485760), // 10MB
+                user_setting: UserSetting::create(
+                    "query_result_persist_max_bytes",
+                    UserSettingValue::UInt64(10485760),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum byte size of a persisted query result, larger results are not persisted.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(3600), // seconds
+                user_setting: UserSetting::create(
+                    "query_result_persist_ttl_secs",
+                    UserSettingValue::UInt64(3600),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the time-to-live (TTL) in seconds for persisted query results.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(10),
+                user_setting: UserSetting::create(
+                    "analyze_sample_percent",
+                    UserSettingValue::UInt64(10),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the percentage of files or blocks sampled when refreshing the statistics of external tables.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(86400), // seconds
+                user_setting: UserSetting::create(
+                    "max_statistics_age_secs",
+                    UserSettingValue::UInt64(86400),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum age in seconds of the sampled statistics of external tables, older statistics are ignored by the optimizer.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(3600), // seconds
+                user_setting: UserSetting::create(
+                    "transaction_timeout_secs",
+                    UserSettingValue::UInt64(3600),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum time in seconds a transaction can last, the transaction is rolled back once it's exceeded.",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::UInt64(0),
                 user_setting: UserSetting::create(
@@ -501,6 +663,73 @@ impl Settings {
                 desc: "Sets the maximum amount of memory in bytes that an aggregator can use before spilling data to storage during query execution.",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "sort_spilling_bytes_threshold_per_proc",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum amount of memory in bytes that a sorter can use before spilling sorted runs to storage during query execution.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "join_spilling_bytes_threshold",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum amount of memory in bytes that the build side of a hash join can use before spilling partitions to storage during query execution.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1048576), // 1MB
+                user_setting: UserSetting::create(
+                    "max_listagg_length",
+                    UserSettingValue::UInt64(1048576),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum byte length of the string returned by LISTAGG before its ON OVERFLOW clause takes effect.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1), // seconds
+                user_setting: UserSetting::create("long_query_time", UserSettingValue::UInt64(1)),
+                level: ScopeLevel::Session,
+                desc: "Sets the execution time in seconds from which a query is recorded in system.slow_queries. Setting it to 0 disables the slow query log.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "slow_query_log_redact_literals",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Replaces the literals in the SQL text of the slow query log with '?'.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "slow_query_log_to_tracing",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Writes the entries of the slow query log to the tracing log as well.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(100),
+                user_setting: UserSetting::create(
+                    "sequence_cache_size",
+                    UserSettingValue::UInt64(100),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the number of sequence values that a node reserves from the meta service at once for NEXTVAL.",
+                possible_values: None,
+            },
         ];
 
         let settings: Arc<DashMap<String, SettingValue>> = Arc::new(DashMap::default());
@@ -628,6 +857,30 @@ impl Settings {
         self.try_set_u64("max_execute_time", val, false)
     }
 
+    // Get kill query timeout in milliseconds.
+    pub fn get_kill_query_timeout_ms(&self) -> Result<u64> {
+        let key = "kill_query_timeout_ms";
+        self.try_get_u64(key)
+    }
+
+    // Get the max iterations of a recursive CTE.
+    pub fn get_max_recursive_cte_iterations(&self) -> Result<u64> {
+        let key = "max_recursive_cte_iterations";
+        self.try_get_u64(key)
+    }
+
+    // Get the max rows of a recursive CTE.
+    pub fn get_max_recursive_cte_rows(&self) -> Result<u64> {
+        let key = "max_recursive_cte_rows";
+        self.try_get_u64(key)
+    }
+
+    // Get the max estimated rows of a CTE to be materialized without the MATERIALIZED hint.
+    pub fn get_max_auto_materialized_cte_rows(&self) -> Result<u64> {
+        let key = "max_auto_materialized_cte_rows";
+        self.try_get_u64(key)
+    }
+
     // Get flight client timeout.
     pub fn get_flight_client_timeout(&self) -> Result<u64> {
         let key = "flight_client_timeout";
@@ -703,6 +956,12 @@ impl Settings {
         Ok(v != 0)
     }
 
+    pub fn get_enable_bloom_index_json_validity(&self) -> Result<bool> {
+        static KEY: &str = "enable_bloom_index_json_validity";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
     pub fn get_max_result_rows(&self) -> Result<u64> {
         static KEY: &str = "max_result_rows";
         let v = self.try_get_u64(KEY)?;
@@ -762,6 +1021,11 @@ impl Settings {
             })
     }
 
+    pub fn get_enable_odbc_style_escapes(&self) -> Result<bool> {
+        let key = "enable_odbc_style_escapes";
+        self.try_get_u64(key).map(|v| v != 0)
+    }
+
     pub fn get_collation(&self) -> Result<&str> {
         let key = "collation";
         self.check_and_get_setting_value(key)
@@ -792,12 +1056,28 @@ impl Settings {
         self.try_get_u64(key)
     }
 
+    pub fn get_copy_history_retention_days(&self) -> Result<u64> {
+        let key = "copy_history_retention_days";
+        self.try_get_u64(key)
+    }
+
     pub fn get_sandbox_tenant(&self) -> Result<String> {
         let key = "sandbox_tenant";
         self.check_and_get_setting_value(key)
             .and_then(|v| v.user_setting.value.as_string())
     }
 
+    pub fn get_allowed_external_url_prefix(&self) -> Result<Vec<String>> {
+        let key = "allowed_external_url_prefix";
+        let v = self
+            .check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())?;
+        Ok(v.split(',')
+            .map(|prefix| prefix.trim().to_string())
+            .filter(|prefix| !prefix.is_empty())
+            .collect())
+    }
+
     pub fn get_hide_options_in_show_create_table(&self) -> Result<bool> {
         let key = "hide_options_in_show_create_table";
         let v = self.try_get_u64(key)?;
@@ -824,6 +1104,36 @@ impl Settings {
         self.try_get_u64(key).map(|v| v != 0)
     }
 
+    pub fn get_enable_query_result_persist(&self) -> Result<bool> {
+        let key = "enable_query_result_persist";
+        self.try_get_u64(key).map(|v| v != 0)
+    }
+
+    pub fn get_query_result_persist_max_bytes(&self) -> Result<usize> {
+        let key = "query_result_persist_max_bytes";
+        self.try_get_u64(key).map(|v| v as usize)
+    }
+
+    pub fn get_query_result_persist_ttl_secs(&self) -> Result<u64> {
+        let key = "query_result_persist_ttl_secs";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_analyze_sample_percent(&self) -> Result<u64> {
+        let key = "analyze_sample_percent";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_max_statistics_age_secs(&self) -> Result<u64> {
+        let key = "max_statistics_age_secs";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_transaction_timeout_secs(&self) -> Result<u64> {
+        let key = "transaction_timeout_secs";
+        self.try_get_u64(key)
+    }
+
     pub fn get_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
         let key = "spilling_bytes_threshold_per_proc";
         self.try_get_u64(key).map(|v| v as usize)
@@ -834,10 +1144,59 @@ impl Settings {
         self.try_set_u64(key, value as u64, false)
     }
 
+    pub fn get_sort_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
+        let key = "sort_spilling_bytes_threshold_per_proc";
+        self.try_get_u64(key).map(|v| v as usize)
+    }
+
+    pub fn set_sort_spilling_bytes_threshold_per_proc(&self, value: usize) -> Result<()> {
+        let key = "sort_spilling_bytes_threshold_per_proc";
+        self.try_set_u64(key, value as u64, false)
+    }
+
+    pub fn get_join_spilling_bytes_threshold(&self) -> Result<usize> {
+        let key = "join_spilling_bytes_threshold";
+        self.try_get_u64(key).map(|v| v as usize)
+    }
+
+    pub fn set_join_spilling_bytes_threshold(&self, value: usize) -> Result<()> {
+        let key = "join_spilling_bytes_threshold";
+        self.try_set_u64(key, value as u64, false)
+    }
+
+    pub fn get_max_listagg_length(&self) -> Result<u64> {
+        let key = "max_listagg_length";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_long_query_time(&self) -> Result<u64> {
+        let key = "long_query_time";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_slow_query_log_redact_literals(&self) -> Result<bool> {
+        let key = "slow_query_log_redact_literals";
+        self.try_get_u64(key).map(|v| v != 0)
+    }
+
+    pub fn get_slow_query_log_to_tracing(&self) -> Result<bool> {
+        let key = "slow_query_log_to_tracing";
+        self.try_get_u64(key).map(|v| v != 0)
+    }
+
+    pub fn get_sequence_cache_size(&self) -> Result<u64> {
+        let key = "sequence_cache_size";
+        self.try_get_u64(key).map(|v| v.max(1))
+    }
+
     pub fn has_setting(&self, key: &str) -> bool {
         self.settings.get(key).is_some()
     }
 
+    pub fn is_admin_only(key: &str) -> bool {
+        ADMIN_ONLY_SETTINGS.contains(&key)
+    }
+
     pub fn check_and_get_setting_value(&self, key: &str) -> Result<SettingValue> {
         let setting = self
             .settings
@@ -19,10 +19,14 @@ use common_meta_app::share::TableInfoMap;
 use common_storages_share::share_table_info_location;
 
 use crate::accessor::SharingAccessor;
+use crate::models::SharingConfig;
 use crate::models::TableMetaLambdaInput;
 
 // Methods for access share table meta.
 impl SharingAccessor {
+    // read share table meta from S3 and check whether requester has permission on the share,
+    // the share spec is checked on each access, so the revoked tenants or dropped tables
+    // will not be visible any more.
     pub async fn get_share_table_meta(input: &TableMetaLambdaInput) -> Result<TableInfoMap> {
         let sharing_accessor = Self::instance();
         let share_table_meta_loc =
@@ -30,15 +34,20 @@ impl SharingAccessor {
         let data = sharing_accessor.op.read(&share_table_meta_loc).await?;
         let share_table_map: TableInfoMap = serde_json::from_slice(data.as_slice())?;
 
-        if input.request_tables.is_empty() {
-            Ok(share_table_map)
+        let share_table_map = if input.request_tables.is_empty() {
+            share_table_map
         } else {
-            Ok(BTreeMap::from_iter(
+            BTreeMap::from_iter(
                 share_table_map
                     .into_iter()
                     .filter(|(table_name, _table_info)| input.request_tables.contains(table_name))
                     .map(|(table_name, table_info)| (table_name, table_info)),
-            ))
-        }
+            )
+        };
+
+        let path = sharing_accessor.get_share_spec_location();
+        let data = sharing_accessor.op.read(&path).await?;
+        let share_specs: SharingConfig = serde_json::from_slice(data.as_slice())?;
+        share_specs.filter_table_meta(input, share_table_map)
     }
 }
@@ -19,6 +19,8 @@ use std::collections::HashSet;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableStatistics;
+use common_meta_app::share::TableInfoMap;
 use poem::async_trait;
 use poem::error::Result as PoemResult;
 use poem::http;
@@ -27,6 +29,7 @@ use poem::Request;
 use poem::RequestBody;
 use serde::Deserialize;
 use serde::Serialize;
+use storages_common_table_meta::table::OPT_KEY_TABLE_SHARED_READ_ONLY;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct LambdaInput {
@@ -222,6 +225,54 @@ impl SharingConfig {
             }
         }
     }
+
+    // filter table meta would only keep the tables still granted to the given share,
+    // the tables of a read-only tenant are restricted and their statistics are hidden.
+    // if requester tenant id is not permitted, it will return error
+    pub fn filter_table_meta(
+        &self,
+        input: &TableMetaLambdaInput,
+        table_info_map: TableInfoMap,
+    ) -> Result<TableInfoMap> {
+        let share_name = &input.share_name;
+        let tenant_id = &input.tenant_id;
+        let share = match self.share_specs.get(share_name) {
+            Some(share) => share,
+            None => {
+                return Err(ErrorCode::UnknownShare(format!(
+                    "share {} is unknown",
+                    share_name
+                )));
+            }
+        };
+        if !share.tenants.contains(tenant_id) {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "tenant {} is not allowed to access share {}",
+                tenant_id, share_name
+            )));
+        }
+        let read_only = share.read_only_tenants.contains(tenant_id);
+
+        Ok(table_info_map
+            .into_iter()
+            .filter(|(table_name, table_info)| {
+                share
+                    .tables
+                    .iter()
+                    .any(|t| &t.name == table_name && t.table_id == table_info.ident.table_id)
+            })
+            .map(|(table_name, mut table_info)| {
+                if read_only {
+                    table_info.meta.statistics = TableStatistics::default();
+                    table_info.meta.options.insert(
+                        OPT_KEY_TABLE_SHARED_READ_ONLY.to_string(),
+                        "true".to_string(),
+                    );
+                }
+                (table_name, table_info)
+            })
+            .collect())
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -232,6 +283,8 @@ pub struct ShareSpec {
     pub database: DatabaseSpec,
     pub tables: Vec<TableSpec>,
     pub tenants: Vec<String>,
+    #[serde(default)]
+    pub read_only_tenants: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
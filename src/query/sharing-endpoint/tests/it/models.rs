@@ -12,14 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use common_base::base::tokio;
 use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
 use sharing_endpoint::models::DatabaseSpec;
 use sharing_endpoint::models::LambdaInput;
 use sharing_endpoint::models::ShareSpec;
 use sharing_endpoint::models::SharingConfig;
+use sharing_endpoint::models::TableMetaLambdaInput;
 use sharing_endpoint::models::TableSpec;
 // mock some SharingConfig
 // and test on SharingConfig get_tables method
@@ -54,6 +58,7 @@ async fn test_get_tables() -> Result<()> {
             },
         ],
         tenants: vec!["t1".to_string()],
+        read_only_tenants: vec![],
     });
 
     let input = LambdaInput {
@@ -71,3 +76,77 @@ async fn test_get_tables() -> Result<()> {
 
     Ok(())
 }
+
+// test on SharingConfig filter_table_meta method, the tables no longer granted are filtered,
+// and the statistics are hidden from the read-only tenants.
+#[tokio::test(flavor = "multi_thread")]
+async fn test_filter_table_meta() -> Result<()> {
+    let mut config = SharingConfig {
+        share_specs: HashMap::new(),
+    };
+    config.share_specs.insert("share1".to_string(), ShareSpec {
+        name: "share1".to_string(),
+        share_id: 0,
+        version: 0,
+        database: DatabaseSpec {
+            name: "db1".to_string(),
+            location: "s3://db1".to_string(),
+            id: 0,
+        },
+        tables: vec![TableSpec {
+            name: "table1".to_string(),
+            location: "s3://db1/table1".to_string(),
+            database_id: 0,
+            table_id: 0,
+            presigned_url_timeout: "".to_string(),
+        }],
+        tenants: vec!["t1".to_string(), "t2".to_string()],
+        read_only_tenants: vec!["t2".to_string()],
+    });
+
+    let table_info_map = || {
+        let mut map = BTreeMap::new();
+        for (table_id, name) in ["table1", "table2"].into_iter().enumerate() {
+            let mut table_info = TableInfo {
+                ident: TableIdent::new(table_id as u64, 0),
+                name: name.to_string(),
+                ..Default::default()
+            };
+            table_info.meta.statistics.number_of_rows = 10;
+            map.insert(name.to_string(), table_info);
+        }
+        map
+    };
+    let input = |tenant_id: &str| {
+        TableMetaLambdaInput::new(
+            "".to_string(),
+            "share1".to_string(),
+            tenant_id.to_string(),
+            vec![],
+            None,
+        )
+    };
+
+    // table2 is not granted to the share any more.
+    let tables = config.filter_table_meta(&input("t1"), table_info_map())?;
+    assert_eq!(tables.len(), 1);
+    let table1 = tables.get("table1").unwrap();
+    assert_eq!(table1.meta.statistics.number_of_rows, 10);
+    assert!(!table1.meta.options.contains_key("read_only_shared"));
+
+    // t2 is restricted to read-only access.
+    let tables = config.filter_table_meta(&input("t2"), table_info_map())?;
+    assert_eq!(tables.len(), 1);
+    let table1 = tables.get("table1").unwrap();
+    assert_eq!(table1.meta.statistics.number_of_rows, 0);
+    assert!(table1.meta.options.contains_key("read_only_shared"));
+
+    // t3 is not granted.
+    assert!(
+        config
+            .filter_table_meta(&input("t3"), table_info_map())
+            .is_err()
+    );
+
+    Ok(())
+}
@@ -14,23 +14,33 @@
 
 use common_ast::ast::FormatTreeNode;
 use common_catalog::plan::PartStatistics;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_profile::ProfSpanSetRef;
 use itertools::Itertools;
+use serde_json::json;
+use serde_json::Value;
 
 use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregatePartial;
+use super::Apply;
 use super::EvalScalar;
 use super::Exchange;
+use super::ExternalFunction;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MatchRecognize;
+use super::MaterializedCte;
+use super::MaterializedCteScan;
 use super::PhysicalPlan;
 use super::Project;
 use super::ProjectSet;
+use super::RecursiveCte;
+use super::RecursiveCteScan;
 use super::Sort;
 use super::TableScan;
 use super::UnionAll;
@@ -45,8 +55,13 @@ use crate::planner::DUMMY_TABLE_INDEX;
 use crate::BaseTableColumn;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
+use crate::IndexType;
 use crate::TableInternalColumn;
 
+/// Version of the document of `EXPLAIN (FORMAT JSON)`, bump it on any
+/// incompatible change of the document.
+const EXPLAIN_JSON_VERSION: u32 = 1;
+
 impl PhysicalPlan {
     pub fn format(
         &self,
@@ -56,6 +71,30 @@ impl PhysicalPlan {
         to_format_tree(self, &metadata, &prof_span_set)
     }
 
+    /// Format the plan as a JSON document, the nodes are listed in pre-order and
+    /// refer to their children by id:
+    ///
+    /// ```json
+    /// {"version": 1, "root": 0, "nodes": [{"id": 0, "operator": "Filter", "children": [1], ...}, ...]}
+    /// ```
+    ///
+    /// The document is built from the tree of [`PhysicalPlan::format`], so that it
+    /// has the same nodes and details as the text.
+    pub fn format_json(
+        &self,
+        metadata: MetadataRef,
+        prof_span_set: ProfSpanSetRef,
+    ) -> Result<Value> {
+        let tree = to_format_tree(self, &metadata, &prof_span_set)?;
+        let mut nodes = vec![];
+        let root = to_json_nodes(self, &tree, &metadata, &mut nodes)?;
+        Ok(json!({
+            "version": EXPLAIN_JSON_VERSION,
+            "root": root,
+            "nodes": nodes,
+        }))
+    }
+
     pub fn format_join(&self, metadata: &MetadataRef) -> Result<FormatTreeNode<String>> {
         match self {
             PhysicalPlan::TableScan(plan) => {
@@ -142,9 +181,24 @@ fn to_format_tree(
             distributed_insert_to_format_tree(plan.as_ref(), metadata, prof_span_set)
         }
         PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::ExternalFunction(plan) => {
+            external_function_to_format_tree(plan, metadata, prof_span_set)
+        }
+        PhysicalPlan::MatchRecognize(plan) => {
+            match_recognize_to_format_tree(plan, metadata, prof_span_set)
+        }
         PhysicalPlan::RuntimeFilterSource(plan) => {
             runtime_filter_source_to_format_tree(plan, metadata, prof_span_set)
         }
+        PhysicalPlan::RecursiveCte(plan) => {
+            recursive_cte_to_format_tree(plan, metadata, prof_span_set)
+        }
+        PhysicalPlan::RecursiveCteScan(plan) => Ok(recursive_cte_scan_to_format_tree(plan)),
+        PhysicalPlan::MaterializedCte(plan) => {
+            materialized_cte_to_format_tree(plan, metadata, prof_span_set)
+        }
+        PhysicalPlan::MaterializedCteScan(plan) => Ok(materialized_cte_scan_to_format_tree(plan)),
+        PhysicalPlan::Apply(plan) => apply_to_format_tree(plan, metadata, prof_span_set),
     }
 }
 
@@ -187,6 +241,14 @@ fn table_scan_to_format_tree(
     children.push(FormatTreeNode::new(format!(
         "push downs: [filters: [{filters}], limit: {limit}]"
     )));
+    if let Some(sample) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.sample.as_ref())
+    {
+        children.push(FormatTreeNode::new(format!("sample: {sample}")));
+    }
 
     let output_columns = plan.source.output_schema.fields();
 
@@ -297,7 +359,14 @@ fn eval_scalar_to_format_tree(
     let scalars = plan
         .exprs
         .iter()
-        .map(|(expr, _)| expr.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .map(
+            |(expr, index)| match metadata.read().masked_column(*index) {
+                Some(masked) if !masked.body_visible => {
+                    format!("MASKING POLICY {}", masked.policy_name)
+                }
+                _ => expr.as_expr(&BUILTIN_FUNCTIONS).sql_display(),
+            },
+        )
         .collect::<Vec<_>>()
         .join(", ");
     let mut children = vec![FormatTreeNode::new(format!("expressions: [{scalars}]"))];
@@ -640,6 +709,16 @@ fn hash_join_to_format_tree(
         children.push(FormatTreeNode::new(format!(
             "total process time: {process_time}ms"
         )));
+        if prof_span.spilled_partitions > 0 {
+            children.push(FormatTreeNode::new(format!(
+                "spilled partitions: {}",
+                prof_span.spilled_partitions
+            )));
+            children.push(FormatTreeNode::new(format!(
+                "spilled bytes: {}",
+                prof_span.spilled_bytes
+            )));
+        }
     }
 
     children.push(build_child);
@@ -704,6 +783,113 @@ fn union_all_to_format_tree(
     ))
 }
 
+fn recursive_cte_to_format_tree(
+    plan: &RecursiveCte,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!("working table: {}", plan.table_name)),
+        FormatTreeNode::new(format!("distinct: {}", plan.distinct)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    children.extend(vec![
+        to_format_tree(&plan.anchor, metadata, prof_span_set)?,
+        to_format_tree(&plan.recursive, metadata, prof_span_set)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCte".to_string(),
+        children,
+    ))
+}
+
+fn recursive_cte_scan_to_format_tree(plan: &RecursiveCteScan) -> FormatTreeNode<String> {
+    FormatTreeNode::with_children("RecursiveCteScan".to_string(), vec![FormatTreeNode::new(
+        format!("working table: {}", plan.table_name),
+    )])
+}
+
+fn materialized_cte_to_format_tree(
+    plan: &MaterializedCte,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!("cte: {}", plan.table_name))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    children.extend(vec![
+        to_format_tree(&plan.cte, metadata, prof_span_set)?,
+        to_format_tree(&plan.input, metadata, prof_span_set)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        "MaterializedCte".to_string(),
+        children,
+    ))
+}
+
+fn materialized_cte_scan_to_format_tree(plan: &MaterializedCteScan) -> FormatTreeNode<String> {
+    FormatTreeNode::with_children("MaterializedCteScan".to_string(), vec![
+        FormatTreeNode::new(format!("cte: {}", plan.table_name)),
+    ])
+}
+
+fn apply_to_format_tree(
+    plan: &Apply,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let outer_columns = plan
+        .outer_columns
+        .iter()
+        .map(|column| {
+            format!(
+                "{} (#{})",
+                match metadata.read().column(*column) {
+                    ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) =>
+                        column_name,
+                    ColumnEntry::DerivedColumn(DerivedColumn { alias, .. }) => alias,
+                    ColumnEntry::InternalColumn(TableInternalColumn {
+                        internal_column, ..
+                    }) => internal_column.column_name(),
+                },
+                column
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut children = vec![
+        FormatTreeNode::new(format!("join type: {}", plan.join_type)),
+        FormatTreeNode::new(format!("outer columns: [{outer_columns}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children("Apply".to_string(), children))
+}
+
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
@@ -817,6 +1003,121 @@ fn project_set_to_format_tree(
     ))
 }
 
+fn external_function_to_format_tree(
+    plan: &ExternalFunction,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    children.extend(vec![FormatTreeNode::new(format!(
+        "external functions: {}",
+        plan.functions
+            .iter()
+            .map(|(desc, arguments, _)| {
+                let arguments = arguments
+                    .iter()
+                    .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{}({})", desc.name, arguments)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))]);
+
+    children.extend(vec![to_format_tree(&plan.input, metadata, prof_span_set)?]);
+
+    Ok(FormatTreeNode::with_children(
+        "ExternalFunction".to_string(),
+        children,
+    ))
+}
+
+fn match_recognize_to_format_tree(
+    plan: &MatchRecognize,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let column_name = |index: IndexType| match metadata.read().column(index) {
+        ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) => column_name.clone(),
+        ColumnEntry::DerivedColumn(DerivedColumn { alias, .. }) => alias.clone(),
+        ColumnEntry::InternalColumn(TableInternalColumn {
+            internal_column, ..
+        }) => internal_column.column_name().to_string(),
+    };
+    let partition_by = plan
+        .partition_by
+        .iter()
+        .map(|index| column_name(*index))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let definitions = plan
+        .variables
+        .iter()
+        .filter_map(|variable| {
+            let predicate = variable.predicate?;
+            Some(format!("{} AS {}", variable.name, column_name(predicate)))
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let measures = plan
+        .measures
+        .iter()
+        .map(|measure| measure.function.display(&plan.variables, column_name))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!("partition by: [{partition_by}]")),
+        FormatTreeNode::new(format!("measures: [{measures}]")),
+        FormatTreeNode::new(format!(
+            "rows per match: {}",
+            if plan.all_rows_per_match {
+                "ALL"
+            } else {
+                "ONE"
+            }
+        )),
+        FormatTreeNode::new(format!(
+            "pattern: ({})",
+            plan.pattern.display(&plan.variables)
+        )),
+        FormatTreeNode::new(format!("define: [{definitions}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "MatchRecognize".to_string(),
+        children,
+    ))
+}
+
 fn runtime_filter_source_to_format_tree(
     plan: &RuntimeFilterSource,
     metadata: &MetadataRef,
@@ -831,3 +1132,106 @@ fn runtime_filter_source_to_format_tree(
         children,
     ))
 }
+
+/// The children of the plan in the order [`to_format_tree`] formats them.
+fn format_children(plan: &PhysicalPlan) -> Vec<&PhysicalPlan> {
+    match plan {
+        PhysicalPlan::HashJoin(plan) => vec![plan.build.as_ref(), plan.probe.as_ref()],
+        _ => plan.children().collect(),
+    }
+}
+
+/// Append the JSON nodes of the plan to `nodes` and return the id of its root.
+///
+/// The formatted children of a plan are its details (`key: value`), followed by
+/// the formatted children plans.
+fn to_json_nodes(
+    plan: &PhysicalPlan,
+    tree: &FormatTreeNode<String>,
+    metadata: &MetadataRef,
+    nodes: &mut Vec<Value>,
+) -> Result<usize> {
+    let children_plans = format_children(plan);
+    let num_details = tree
+        .children
+        .len()
+        .checked_sub(children_plans.len())
+        .ok_or_else(|| ErrorCode::Internal(format!("Unexpected format tree of {}", plan.name())))?;
+
+    let id = nodes.len();
+    nodes.push(Value::Null);
+
+    let mut details = serde_json::Map::new();
+    for detail in &tree.children[..num_details] {
+        let (key, value) = detail
+            .payload
+            .split_once(": ")
+            .unwrap_or((detail.payload.as_str(), ""));
+        details.insert(key.to_string(), Value::String(value.to_string()));
+    }
+
+    let children = children_plans
+        .into_iter()
+        .zip(&tree.children[num_details..])
+        .map(|(child, child_tree)| to_json_nodes(child, child_tree, metadata, nodes))
+        .collect::<Result<Vec<_>>>()?;
+
+    // The children of a hash join are formatted as `TableScan(Build)` and `TableScan(Probe)`.
+    let operator = tree
+        .payload
+        .trim_end_matches("(Build)")
+        .trim_end_matches("(Probe)");
+    let mut node = json!({
+        "id": id,
+        "plan_id": plan.plan_id(),
+        "operator": operator,
+        "estimated_rows": plan.stat_info().map(|info| info.estimated_rows),
+        "output_columns": output_columns_to_json(plan, metadata)?,
+        "details": details,
+        "children": children,
+    });
+    if let PhysicalPlan::TableScan(scan) = plan {
+        let push_downs = scan.source.push_downs.as_ref();
+        node["push_downs"] = json!({
+            "filter": push_downs
+                .and_then(|extras| extras.filter.as_ref())
+                .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS).sql_display()),
+            "limit": push_downs.and_then(|extras| extras.limit),
+        });
+    }
+
+    nodes[id] = node;
+    Ok(id)
+}
+
+fn output_columns_to_json(plan: &PhysicalPlan, metadata: &MetadataRef) -> Result<Vec<Value>> {
+    let schema = plan.output_schema()?;
+    let metadata = metadata.read();
+    Ok(schema
+        .fields()
+        .iter()
+        .map(|field| {
+            // The fields of the physical plans are named by the column indexes.
+            let index = field
+                .name()
+                .parse::<IndexType>()
+                .ok()
+                .filter(|index| *index < metadata.columns().len());
+            let name = match index.map(|index| metadata.column(index)) {
+                Some(ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. })) => {
+                    column_name.clone()
+                }
+                Some(ColumnEntry::DerivedColumn(DerivedColumn { alias, .. })) => alias.clone(),
+                Some(ColumnEntry::InternalColumn(TableInternalColumn {
+                    internal_column, ..
+                })) => internal_column.column_name().to_string(),
+                None => field.name().clone(),
+            };
+            json!({
+                "name": name,
+                "index": index,
+                "type": field.data_type().to_string(),
+            })
+        })
+        .collect())
+}
@@ -31,10 +31,16 @@ use common_meta_app::schema::TableInfo;
 
 use crate::executor::explain::PlanStatsInfo;
 use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::ExternalFunctionDesc;
 use crate::plans::JoinType;
+use crate::plans::MatchMeasure;
+use crate::plans::MatchVariable;
+use crate::plans::RowPattern;
 use crate::plans::RuntimeFilterId;
 use crate::ColumnBinding;
 use crate::IndexType;
+use crate::MetadataRef;
 
 pub type ColumnID = String;
 
@@ -167,6 +173,72 @@ impl ProjectSet {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ExternalFunction {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub input: Box<PhysicalPlan>,
+
+    /// The functions, their arguments and the columns of the results.
+    pub functions: Vec<(ExternalFunctionDesc, Vec<RemoteExpr>, IndexType)>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl ExternalFunction {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        fields.extend(
+            self.functions.iter().map(|(desc, _, index)| {
+                DataField::new(&index.to_string(), desc.return_type.clone())
+            }),
+        );
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+/// See [`crate::plans::MatchRecognize`].
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MatchRecognize {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub input: Box<PhysicalPlan>,
+    pub partition_by: Vec<IndexType>,
+    pub variables: Vec<MatchVariable>,
+    pub pattern: RowPattern,
+    pub measures: Vec<MatchMeasure>,
+    pub all_rows_per_match: bool,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl MatchRecognize {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = if self.all_rows_per_match {
+            input_schema.fields().clone()
+        } else {
+            self.partition_by
+                .iter()
+                .map(|index| Ok(input_schema.field_with_name(&index.to_string())?.clone()))
+                .collect::<Result<Vec<_>>>()?
+        };
+        fields.extend(
+            self.measures.iter().map(|measure| {
+                DataField::new(&measure.index.to_string(), measure.data_type.clone())
+            }),
+        );
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct AggregateExpand {
     /// A unique id of operator in a `PhysicalPlan` tree.
@@ -186,12 +258,11 @@ impl AggregateExpand {
         let input_schema = self.input.output_schema()?;
         let mut output_fields = input_schema.fields().clone();
 
-        for group_by in self
-            .group_bys
-            .iter()
-            .filter(|&index| *index != self.grouping_id_index)
-        {
-            // All group by columns will wrap nullable.
+        for group_by in self.group_bys.iter().filter(|&index| {
+            *index != self.grouping_id_index
+                && self.grouping_sets.iter().any(|set| !set.contains(index))
+        }) {
+            // Group by columns omitted by some grouping set will wrap nullable.
             let i = input_schema.index_of(&group_by.to_string())?;
             let f = &mut output_fields[i];
             *f = DataField::new(f.name(), f.data_type().wrap_nullable())
@@ -333,6 +404,8 @@ pub struct HashJoin {
     pub probe: Box<PhysicalPlan>,
     pub build_keys: Vec<RemoteExpr>,
     pub probe_keys: Vec<RemoteExpr>,
+    // Indexes of the keys compared with `IS NOT DISTINCT FROM`.
+    pub is_null_equal: Vec<usize>,
     pub non_equi_conditions: Vec<RemoteExpr>,
     pub join_type: JoinType,
     pub marker_index: Option<IndexType>,
@@ -508,6 +581,142 @@ impl UnionAll {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCte {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub anchor: Box<PhysicalPlan>,
+    pub recursive: Box<PhysicalPlan>,
+    /// Name of the working table read by `RecursiveCteScan` in `recursive`.
+    pub table_name: String,
+    /// Output columns of `anchor` and `recursive`, in the order of `schema`.
+    pub anchor_columns: Vec<IndexType>,
+    pub recursive_columns: Vec<IndexType>,
+    pub distinct: bool,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCteScan {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub table_name: String,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MaterializedCte {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    /// The query of the cte, it's executed before `input` and the result is stored.
+    pub cte: Box<PhysicalPlan>,
+    pub input: Box<PhysicalPlan>,
+    /// Name of the stored result read by `MaterializedCteScan` in `input`.
+    pub table_name: String,
+    /// Output columns of `cte`, in the order of the stored result.
+    pub cte_columns: Vec<IndexType>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl MaterializedCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MaterializedCteScan {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub table_name: String,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl MaterializedCteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Apply {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub input: Box<PhysicalPlan>,
+    /// The correlated subquery, it's optimized and executed for each row of `input`.
+    pub subquery: ApplySubquery,
+    /// Columns of `input` that are referenced by the subquery.
+    pub outer_columns: Vec<IndexType>,
+    /// Output columns of the subquery, they follow the columns of `input` in `schema`.
+    pub output_columns: Vec<ColumnBinding>,
+    pub join_type: JoinType,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Apply {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+/// The logical plan of the subquery of `Apply`. `Apply` is always executed in the
+/// local node, so it's never serialized.
+#[derive(Clone, Debug)]
+pub struct ApplySubquery {
+    pub s_expr: SExpr,
+    pub metadata: MetadataRef,
+}
+
+impl serde::Serialize for ApplySubquery {
+    fn serialize<S>(&self, _: S) -> std::result::Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        unimplemented!("Unimplemented serialize ApplySubquery")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ApplySubquery {
+    fn deserialize<D>(_: D) -> std::result::Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        unimplemented!("Unimplemented deserialize ApplySubquery")
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct DistributedInsertSelect {
     pub input: Box<PhysicalPlan>,
@@ -556,6 +765,8 @@ pub enum PhysicalPlan {
     Project(Project),
     EvalScalar(EvalScalar),
     ProjectSet(ProjectSet),
+    ExternalFunction(ExternalFunction),
+    MatchRecognize(MatchRecognize),
     AggregateExpand(AggregateExpand),
     AggregatePartial(AggregatePartial),
     AggregateFinal(AggregateFinal),
@@ -565,6 +776,11 @@ pub enum PhysicalPlan {
     Exchange(Exchange),
     UnionAll(UnionAll),
     RuntimeFilterSource(RuntimeFilterSource),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
+    MaterializedCte(MaterializedCte),
+    MaterializedCteScan(MaterializedCteScan),
+    Apply(Apply),
 
     /// For insert into ... select ... in cluster
     DistributedInsertSelect(Box<DistributedInsertSelect>),
@@ -601,7 +817,14 @@ impl PhysicalPlan {
             PhysicalPlan::UnionAll(plan) => plan.output_schema(),
             PhysicalPlan::DistributedInsertSelect(plan) => plan.output_schema(),
             PhysicalPlan::ProjectSet(plan) => plan.output_schema(),
+            PhysicalPlan::ExternalFunction(plan) => plan.output_schema(),
+            PhysicalPlan::MatchRecognize(plan) => plan.output_schema(),
             PhysicalPlan::RuntimeFilterSource(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCte(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCteScan(plan) => plan.output_schema(),
+            PhysicalPlan::MaterializedCte(plan) => plan.output_schema(),
+            PhysicalPlan::MaterializedCteScan(plan) => plan.output_schema(),
+            PhysicalPlan::Apply(plan) => plan.output_schema(),
         }
     }
 
@@ -623,7 +846,74 @@ impl PhysicalPlan {
             PhysicalPlan::ExchangeSource(_) => "Exchange Source".to_string(),
             PhysicalPlan::ExchangeSink(_) => "Exchange Sink".to_string(),
             PhysicalPlan::ProjectSet(_) => "Unnest".to_string(),
+            PhysicalPlan::ExternalFunction(_) => "ExternalFunction".to_string(),
+            PhysicalPlan::MatchRecognize(_) => "MatchRecognize".to_string(),
             PhysicalPlan::RuntimeFilterSource(_) => "RuntimeFilterSource".to_string(),
+            PhysicalPlan::RecursiveCte(_) => "RecursiveCte".to_string(),
+            PhysicalPlan::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
+            PhysicalPlan::MaterializedCte(_) => "MaterializedCte".to_string(),
+            PhysicalPlan::MaterializedCteScan(_) => "MaterializedCteScan".to_string(),
+            PhysicalPlan::Apply(_) => "Apply".to_string(),
+        }
+    }
+
+    /// Statistics estimated by the optimizer, plans synthesized for distributed execution have none.
+    pub fn stat_info(&self) -> Option<&PlanStatsInfo> {
+        match self {
+            PhysicalPlan::TableScan(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::Filter(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::Project(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::EvalScalar(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::AggregateExpand(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::AggregatePartial(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::AggregateFinal(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::Sort(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::Limit(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::HashJoin(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::UnionAll(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::ProjectSet(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::ExternalFunction(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::MatchRecognize(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::RecursiveCte(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::RecursiveCteScan(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::MaterializedCte(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::MaterializedCteScan(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::Apply(plan) => plan.stat_info.as_ref(),
+            PhysicalPlan::Exchange(_)
+            | PhysicalPlan::ExchangeSource(_)
+            | PhysicalPlan::ExchangeSink(_)
+            | PhysicalPlan::DistributedInsertSelect(_)
+            | PhysicalPlan::RuntimeFilterSource(_) => None,
+        }
+    }
+
+    /// The id the profile of the plan is recorded with, plans synthesized for distributed execution have none.
+    pub fn plan_id(&self) -> Option<u32> {
+        match self {
+            PhysicalPlan::TableScan(plan) => Some(plan.plan_id),
+            PhysicalPlan::Filter(plan) => Some(plan.plan_id),
+            PhysicalPlan::Project(plan) => Some(plan.plan_id),
+            PhysicalPlan::EvalScalar(plan) => Some(plan.plan_id),
+            PhysicalPlan::AggregateExpand(plan) => Some(plan.plan_id),
+            PhysicalPlan::AggregatePartial(plan) => Some(plan.plan_id),
+            PhysicalPlan::AggregateFinal(plan) => Some(plan.plan_id),
+            PhysicalPlan::Sort(plan) => Some(plan.plan_id),
+            PhysicalPlan::Limit(plan) => Some(plan.plan_id),
+            PhysicalPlan::HashJoin(plan) => Some(plan.plan_id),
+            PhysicalPlan::UnionAll(plan) => Some(plan.plan_id),
+            PhysicalPlan::ProjectSet(plan) => Some(plan.plan_id),
+            PhysicalPlan::ExternalFunction(plan) => Some(plan.plan_id),
+            PhysicalPlan::MatchRecognize(plan) => Some(plan.plan_id),
+            PhysicalPlan::RuntimeFilterSource(plan) => Some(plan.plan_id),
+            PhysicalPlan::RecursiveCte(plan) => Some(plan.plan_id),
+            PhysicalPlan::RecursiveCteScan(plan) => Some(plan.plan_id),
+            PhysicalPlan::MaterializedCte(plan) => Some(plan.plan_id),
+            PhysicalPlan::MaterializedCteScan(plan) => Some(plan.plan_id),
+            PhysicalPlan::Apply(plan) => Some(plan.plan_id),
+            PhysicalPlan::Exchange(_)
+            | PhysicalPlan::ExchangeSource(_)
+            | PhysicalPlan::ExchangeSink(_)
+            | PhysicalPlan::DistributedInsertSelect(_) => None,
         }
     }
 
@@ -651,10 +941,22 @@ impl PhysicalPlan {
                 Box::new(std::iter::once(plan.input.as_ref()))
             }
             PhysicalPlan::ProjectSet(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::ExternalFunction(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::MatchRecognize(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::RuntimeFilterSource(plan) => Box::new(
                 std::iter::once(plan.left_side.as_ref())
                     .chain(std::iter::once(plan.right_side.as_ref())),
             ),
+            PhysicalPlan::RecursiveCte(plan) => Box::new(
+                std::iter::once(plan.anchor.as_ref())
+                    .chain(std::iter::once(plan.recursive.as_ref())),
+            ),
+            PhysicalPlan::RecursiveCteScan(_) => Box::new(std::iter::empty()),
+            PhysicalPlan::MaterializedCte(plan) => Box::new(
+                std::iter::once(plan.cte.as_ref()).chain(std::iter::once(plan.input.as_ref())),
+            ),
+            PhysicalPlan::MaterializedCteScan(_) => Box::new(std::iter::empty()),
+            PhysicalPlan::Apply(plan) => Box::new(std::iter::once(plan.input.as_ref())),
         }
     }
 }
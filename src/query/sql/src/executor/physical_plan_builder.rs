@@ -21,6 +21,8 @@ use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::PrewhereInfo;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::SampleInfo;
+use common_catalog::plan::SampleMethod;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -41,10 +43,14 @@ use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregateFunctionSignature;
 use super::AggregatePartial;
+use super::Apply;
+use super::ApplySubquery;
 use super::Exchange as PhysicalExchange;
+use super::ExternalFunction;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MatchRecognize;
 use super::ProjectSet;
 use super::Sort;
 use super::TableScan;
@@ -52,7 +58,11 @@ use crate::executor::explain::PlanStatsInfo;
 use crate::executor::table_read_plan::ToReadDataSourcePlan;
 use crate::executor::EvalScalar;
 use crate::executor::FragmentKind;
+use crate::executor::MaterializedCte;
+use crate::executor::MaterializedCteScan;
 use crate::executor::PhysicalPlan;
+use crate::executor::RecursiveCte;
+use crate::executor::RecursiveCteScan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::SortDesc;
 use crate::executor::UnionAll;
@@ -365,6 +375,7 @@ impl PhysicalPlanBuilder {
                             Ok(expr.as_remote_expr())
                         })
                         .collect::<Result<_>>()?,
+                    is_null_equal: join.is_null_equal.clone(),
                     non_equi_conditions: join
                         .non_equi_conditions
                         .iter()
@@ -759,6 +770,89 @@ impl PhysicalPlanBuilder {
                 }))
             }
 
+            RelOperator::RecursiveCte(op) => {
+                let anchor = self.build(s_expr.child(0)?).await?;
+                let recursive = self.build(s_expr.child(1)?).await?;
+                let anchor_schema = anchor.output_schema()?;
+                let fields = op
+                    .anchor_columns
+                    .iter()
+                    .map(|index| Ok(anchor_schema.field_with_name(&index.to_string())?.clone()))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+                    plan_id: self.next_plan_id(),
+                    anchor: Box::new(anchor),
+                    recursive: Box::new(recursive),
+                    table_name: op.table_name.clone(),
+                    anchor_columns: op.anchor_columns.clone(),
+                    recursive_columns: op.recursive_columns.clone(),
+                    distinct: op.distinct,
+                    schema: DataSchemaRefExt::create(fields),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+
+            RelOperator::RecursiveCteScan(op) => {
+                let metadata = self.metadata.read().clone();
+                let fields = op
+                    .columns
+                    .iter()
+                    .map(|index| match metadata.column(*index) {
+                        ColumnEntry::DerivedColumn(DerivedColumn { data_type, .. }) => {
+                            Ok(DataField::new(&index.to_string(), data_type.clone()))
+                        }
+                        _ => Err(ErrorCode::Internal(
+                            "Columns of recursive cte working table must be derived columns",
+                        )),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(PhysicalPlan::RecursiveCteScan(RecursiveCteScan {
+                    plan_id: self.next_plan_id(),
+                    table_name: op.table_name.clone(),
+                    schema: DataSchemaRefExt::create(fields),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+
+            RelOperator::MaterializedCte(op) => {
+                let cte = self.build(s_expr.child(0)?).await?;
+                let input = self.build(s_expr.child(1)?).await?;
+                Ok(PhysicalPlan::MaterializedCte(MaterializedCte {
+                    plan_id: self.next_plan_id(),
+                    cte: Box::new(cte),
+                    input: Box::new(input),
+                    table_name: op.table_name.clone(),
+                    cte_columns: op.columns.clone(),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+
+            RelOperator::MaterializedCteScan(op) => {
+                let metadata = self.metadata.read().clone();
+                let fields = op
+                    .columns
+                    .iter()
+                    .map(|index| match metadata.column(*index) {
+                        ColumnEntry::DerivedColumn(DerivedColumn { data_type, .. }) => {
+                            Ok(DataField::new(&index.to_string(), data_type.clone()))
+                        }
+                        _ => Err(ErrorCode::Internal(
+                            "Columns of materialized cte must be derived columns",
+                        )),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(PhysicalPlan::MaterializedCteScan(MaterializedCteScan {
+                    plan_id: self.next_plan_id(),
+                    table_name: op.table_name.clone(),
+                    schema: DataSchemaRefExt::create(fields),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+
             RelOperator::RuntimeFilterSource(op) => {
                 let left_side = Box::new(self.build(s_expr.child(0)?).await?);
                 let left_schema = left_side.output_schema()?;
@@ -829,6 +923,81 @@ impl PhysicalPlanBuilder {
                 }))
             }
 
+            RelOperator::ExternalFunction(external_function) => {
+                let input = self.build(s_expr.child(0)?).await?;
+                let input_schema = input.output_schema()?;
+                let functions = external_function
+                    .items
+                    .iter()
+                    .map(|item| {
+                        let arguments = item
+                            .arguments
+                            .iter()
+                            .map(|argument| {
+                                let expr = argument
+                                    .resolve_and_check(input_schema.as_ref())?
+                                    .project_column_ref(|index| {
+                                        input_schema.index_of(&index.to_string()).unwrap()
+                                    });
+                                let (expr, _) = ConstantFolder::fold(
+                                    &expr,
+                                    self.ctx.get_function_context()?,
+                                    &BUILTIN_FUNCTIONS,
+                                );
+                                Ok(expr.as_remote_expr())
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        Ok((item.desc.clone(), arguments, item.index))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(PhysicalPlan::ExternalFunction(ExternalFunction {
+                    plan_id: self.next_plan_id(),
+                    input: Box::new(input),
+                    functions,
+                    stat_info: Some(stat_info),
+                }))
+            }
+
+            RelOperator::MatchRecognize(match_recognize) => {
+                Ok(PhysicalPlan::MatchRecognize(MatchRecognize {
+                    plan_id: self.next_plan_id(),
+                    input: Box::new(self.build(s_expr.child(0)?).await?),
+                    partition_by: match_recognize.partition_by.clone(),
+                    variables: match_recognize.variables.clone(),
+                    pattern: match_recognize.pattern.clone(),
+                    measures: match_recognize.measures.clone(),
+                    all_rows_per_match: match_recognize.all_rows_per_match,
+                    stat_info: Some(stat_info),
+                }))
+            }
+
+            RelOperator::Apply(apply) => {
+                let input = self.build(s_expr.child(0)?).await?;
+                let mut fields = input.output_schema()?.fields().clone();
+                for column in apply.output_columns.iter() {
+                    let data_type = if apply.join_type == JoinType::Left {
+                        column.data_type.wrap_nullable()
+                    } else {
+                        *column.data_type.clone()
+                    };
+                    fields.push(DataField::new(&column.index.to_string(), data_type));
+                }
+
+                Ok(PhysicalPlan::Apply(Apply {
+                    plan_id: self.next_plan_id(),
+                    input: Box::new(input),
+                    subquery: ApplySubquery {
+                        s_expr: (*apply.subquery).clone(),
+                        metadata: self.metadata.clone(),
+                    },
+                    outer_columns: apply.outer_columns.clone(),
+                    output_columns: apply.output_columns.clone(),
+                    join_type: apply.join_type.clone(),
+                    schema: DataSchemaRefExt::create(fields),
+                    stat_info: Some(stat_info),
+                }))
+            }
+
             _ => Err(ErrorCode::Internal(format!(
                 "Unsupported physical plan: {:?}",
                 s_expr.plan()
@@ -983,12 +1152,23 @@ impl PhysicalPlanBuilder {
             })
             .transpose()?;
 
+        // `BERNOULLI` filters the rows after they are read, so the storage
+        // can't stop reading once it has read `limit` rows.
+        let (limit, order_by) = match &scan.sample {
+            Some(SampleInfo {
+                method: SampleMethod::Bernoulli,
+                ..
+            }) => (None, None),
+            _ => (scan.limit, order_by),
+        };
+
         Ok(PushDownInfo {
             projection: Some(projection),
             filter: push_down_filter,
             prewhere: prewhere_info,
-            limit: scan.limit,
+            limit,
             order_by: order_by.unwrap_or_default(),
+            sample: scan.sample,
         })
     }
 
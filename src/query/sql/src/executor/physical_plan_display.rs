@@ -20,9 +20,11 @@ use itertools::Itertools;
 
 use super::AggregateExpand;
 use super::DistributedInsertSelect;
+use super::ExternalFunction;
 use super::ProjectSet;
 use crate::executor::AggregateFinal;
 use crate::executor::AggregatePartial;
+use crate::executor::Apply;
 use crate::executor::EvalScalar;
 use crate::executor::Exchange;
 use crate::executor::ExchangeSink;
@@ -30,8 +32,13 @@ use crate::executor::ExchangeSource;
 use crate::executor::Filter;
 use crate::executor::HashJoin;
 use crate::executor::Limit;
+use crate::executor::MatchRecognize;
+use crate::executor::MaterializedCte;
+use crate::executor::MaterializedCteScan;
 use crate::executor::PhysicalPlan;
 use crate::executor::Project;
+use crate::executor::RecursiveCte;
+use crate::executor::RecursiveCteScan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::Sort;
 use crate::executor::TableScan;
@@ -70,7 +77,14 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::UnionAll(union_all) => write!(f, "{}", union_all)?,
             PhysicalPlan::DistributedInsertSelect(insert_select) => write!(f, "{}", insert_select)?,
             PhysicalPlan::ProjectSet(unnest) => write!(f, "{}", unnest)?,
+            PhysicalPlan::ExternalFunction(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::MatchRecognize(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::RuntimeFilterSource(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::RecursiveCte(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::RecursiveCteScan(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::MaterializedCte(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::MaterializedCteScan(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::Apply(plan) => write!(f, "{}", plan)?,
         }
 
         for node in self.node.children() {
@@ -315,6 +329,36 @@ impl Display for UnionAll {
     }
 }
 
+impl Display for RecursiveCte {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveCte: {}", self.table_name)
+    }
+}
+
+impl Display for RecursiveCteScan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveCteScan: {}", self.table_name)
+    }
+}
+
+impl Display for MaterializedCte {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MaterializedCte: {}", self.table_name)
+    }
+}
+
+impl Display for MaterializedCteScan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MaterializedCteScan: {}", self.table_name)
+    }
+}
+
+impl Display for Apply {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Apply: {:?}", self.join_type)
+    }
+}
+
 impl Display for DistributedInsertSelect {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DistributedInsertSelect")
@@ -327,6 +371,34 @@ impl Display for RuntimeFilterSource {
     }
 }
 
+impl Display for ExternalFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let functions = self
+            .functions
+            .iter()
+            .map(|(desc, arguments, _)| {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| argument.as_expr(&BUILTIN_FUNCTIONS).to_string())
+                    .collect::<Vec<String>>();
+                format!("{}({})", desc.name, arguments.join(", "))
+            })
+            .collect::<Vec<String>>();
+
+        write!(f, "ExternalFunction: functions : {}", functions.join(", "))
+    }
+}
+
+impl Display for MatchRecognize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "MatchRecognize: pattern : ({})",
+            self.pattern.display(&self.variables)
+        )
+    }
+}
+
 impl Display for ProjectSet {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let scalars = self
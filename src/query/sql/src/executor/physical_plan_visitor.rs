@@ -17,17 +17,24 @@ use common_exception::Result;
 use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregatePartial;
+use super::Apply;
 use super::DistributedInsertSelect;
 use super::EvalScalar;
 use super::Exchange;
 use super::ExchangeSink;
 use super::ExchangeSource;
+use super::ExternalFunction;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MatchRecognize;
+use super::MaterializedCte;
+use super::MaterializedCteScan;
 use super::PhysicalPlan;
 use super::Project;
 use super::ProjectSet;
+use super::RecursiveCte;
+use super::RecursiveCteScan;
 use super::Sort;
 use super::TableScan;
 use crate::executor::RuntimeFilterSource;
@@ -52,7 +59,14 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::UnionAll(plan) => self.replace_union(plan),
             PhysicalPlan::DistributedInsertSelect(plan) => self.replace_insert_select(plan),
             PhysicalPlan::ProjectSet(plan) => self.replace_project_set(plan),
+            PhysicalPlan::ExternalFunction(plan) => self.replace_external_function(plan),
+            PhysicalPlan::MatchRecognize(plan) => self.replace_match_recognize(plan),
             PhysicalPlan::RuntimeFilterSource(plan) => self.replace_runtime_filter_source(plan),
+            PhysicalPlan::RecursiveCte(plan) => self.replace_recursive_cte(plan),
+            PhysicalPlan::RecursiveCteScan(plan) => self.replace_recursive_cte_scan(plan),
+            PhysicalPlan::MaterializedCte(plan) => self.replace_materialized_cte(plan),
+            PhysicalPlan::MaterializedCteScan(plan) => self.replace_materialized_cte_scan(plan),
+            PhysicalPlan::Apply(plan) => self.replace_apply(plan),
         }
     }
 
@@ -143,6 +157,7 @@ pub trait PhysicalPlanReplacer {
             probe: Box::new(probe),
             build_keys: plan.build_keys.clone(),
             probe_keys: plan.probe_keys.clone(),
+            is_null_equal: plan.is_null_equal.clone(),
             non_equi_conditions: plan.non_equi_conditions.clone(),
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
@@ -243,6 +258,24 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_external_function(&mut self, plan: &ExternalFunction) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::ExternalFunction(ExternalFunction {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            functions: plan.functions.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_match_recognize(&mut self, plan: &MatchRecognize) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::MatchRecognize(MatchRecognize {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
     fn replace_runtime_filter_source(
         &mut self,
         plan: &RuntimeFilterSource,
@@ -257,6 +290,60 @@ pub trait PhysicalPlanReplacer {
             right_runtime_filters: plan.right_runtime_filters.clone(),
         }))
     }
+
+    fn replace_recursive_cte(&mut self, plan: &RecursiveCte) -> Result<PhysicalPlan> {
+        let anchor = self.replace(&plan.anchor)?;
+        let recursive = self.replace(&plan.recursive)?;
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            plan_id: plan.plan_id,
+            anchor: Box::new(anchor),
+            recursive: Box::new(recursive),
+            table_name: plan.table_name.clone(),
+            anchor_columns: plan.anchor_columns.clone(),
+            recursive_columns: plan.recursive_columns.clone(),
+            distinct: plan.distinct,
+            schema: plan.schema.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_recursive_cte_scan(&mut self, plan: &RecursiveCteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::RecursiveCteScan(plan.clone()))
+    }
+
+    fn replace_materialized_cte(&mut self, plan: &MaterializedCte) -> Result<PhysicalPlan> {
+        let cte = self.replace(&plan.cte)?;
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::MaterializedCte(MaterializedCte {
+            plan_id: plan.plan_id,
+            cte: Box::new(cte),
+            input: Box::new(input),
+            table_name: plan.table_name.clone(),
+            cte_columns: plan.cte_columns.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_materialized_cte_scan(
+        &mut self,
+        plan: &MaterializedCteScan,
+    ) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::MaterializedCteScan(plan.clone()))
+    }
+
+    fn replace_apply(&mut self, plan: &Apply) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Apply(Apply {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            subquery: plan.subquery.clone(),
+            outer_columns: plan.outer_columns.clone(),
+            output_columns: plan.output_columns.clone(),
+            join_type: plan.join_type.clone(),
+            schema: plan.schema.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
 }
 
 impl PhysicalPlan {
@@ -315,10 +402,29 @@ impl PhysicalPlan {
                 PhysicalPlan::ProjectSet(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit)
                 }
+                PhysicalPlan::ExternalFunction(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit)
+                }
+                PhysicalPlan::MatchRecognize(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit)
+                }
                 PhysicalPlan::RuntimeFilterSource(plan) => {
                     Self::traverse(&plan.left_side, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right_side, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::RecursiveCte(plan) => {
+                    Self::traverse(&plan.anchor, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.recursive, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::RecursiveCteScan(_) => {}
+                PhysicalPlan::MaterializedCte(plan) => {
+                    Self::traverse(&plan.cte, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::MaterializedCteScan(_) => {}
+                PhysicalPlan::Apply(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
             }
             post_visit(plan);
         }
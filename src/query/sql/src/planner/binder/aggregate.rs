@@ -120,7 +120,9 @@ impl<'a> AggregateRewriter<'a> {
             }
             .into()),
             ScalarExpr::FunctionCall(func) => {
-                if func.func_name.eq_ignore_ascii_case("grouping") {
+                if func.func_name.eq_ignore_ascii_case("grouping")
+                    || func.func_name.eq_ignore_ascii_case("grouping_id")
+                {
                     return self.replace_grouping(func);
                 }
                 let new_args = func
@@ -136,6 +138,14 @@ impl<'a> AggregateRewriter<'a> {
                 }
                 .into())
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let new_args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(lambda.with_args(new_args)?.into())
+            }
             ScalarExpr::CastExpr(cast) => Ok(CastExpr {
                 span: cast.span,
                 is_try: cast.is_try,
@@ -230,9 +240,18 @@ impl<'a> AggregateRewriter<'a> {
     fn replace_grouping(&mut self, function: &FunctionCall) -> Result<ScalarExpr> {
         let agg_info = &mut self.bind_context.aggregate_info;
         if agg_info.grouping_id_column.is_none() {
-            return Err(ErrorCode::SemanticError(
-                "grouping can only be called in GROUP BY GROUPING SETS clauses",
-            ));
+            return Err(ErrorCode::SemanticError(format!(
+                "{} can only be called in GROUP BY GROUPING SETS, ROLLUP or CUBE clauses",
+                function.func_name
+            ))
+            .set_span(function.span));
+        }
+        if function.arguments.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "{} requires at least one argument",
+                function.func_name
+            ))
+            .set_span(function.span));
         }
         let grouping_id_column = agg_info.grouping_id_column.clone().unwrap();
 
@@ -245,6 +264,8 @@ impl<'a> AggregateRewriter<'a> {
         // ba -> 00 -> 0
         // _a -> 01 -> 1
         // grouping(b, a) will be rewritten to grouping<1, 0>(grouping_id).
+        // `grouping_id` is the same as `grouping`, the bit of a column is set if the column
+        // is aggregated, the first argument is the most significant bit.
         let mut replaced_params = Vec::with_capacity(function.arguments.len());
         for arg in &function.arguments {
             if let Some(index) = agg_info.group_items_map.get(arg) {
@@ -258,7 +279,7 @@ impl<'a> AggregateRewriter<'a> {
 
         let replaced_func = FunctionCall {
             span: function.span,
-            func_name: function.func_name.clone(),
+            func_name: "grouping".to_string(),
             params: replaced_params,
             arguments: vec![ScalarExpr::BoundColumnRef(BoundColumnRef {
                 span: function.span,
@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::hash::Hash;
+use std::sync::Arc;
 
 use common_ast::ast::Query;
 use common_ast::ast::TableAlias;
@@ -27,6 +28,7 @@ use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use dashmap::DashMap;
+use parking_lot::RwLock;
 
 use super::AggregateInfo;
 use super::INTERNAL_COLUMN_FACTORY;
@@ -66,6 +68,9 @@ pub enum Visibility {
     // The result should only contain one `a` column.
     // So we need make `t.a` or `t1.a` invisible in unqualified
     UnqualifiedWildcardInVisible,
+    // The current user is not granted to select this column,
+    // it is skipped by wildcard and can not be referenced.
+    Denied,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -161,6 +166,10 @@ pub struct BindContext {
     /// The key is the `Expr::to_string` of the function.
     pub srfs: DashMap<String, ScalarExpr>,
 
+    /// Calls of external functions in current context, replaced by the columns of the results.
+    /// The key is the `Expr::to_string` of the call.
+    pub external_functions: DashMap<String, ScalarExpr>,
+
     pub expr_context: ExprContext,
 }
 
@@ -168,6 +177,35 @@ pub struct BindContext {
 pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
+    /// Whether the CTE is defined in a `WITH RECURSIVE` clause.
+    pub recursive: bool,
+    /// Only set while binding the recursive member of a recursive CTE,
+    /// references to the CTE inside it read the working table.
+    pub working_table: Option<WorkingTable>,
+    /// `Some(true)` for `MATERIALIZED`, `Some(false)` for `NOT MATERIALIZED`.
+    pub materialized: Option<bool>,
+    /// Number of references to the CTE in the query defining it.
+    pub reference_count: usize,
+    /// Set on the first reference if the CTE is materialized, it's shared by all the
+    /// copies of the `CteInfo`, so that the later references read the stored result.
+    pub materialized_cte: Arc<RwLock<Option<MaterializedCteInfo>>>,
+}
+
+/// The query of a materialized CTE, bound once for all the references.
+#[derive(Clone, Debug)]
+pub struct MaterializedCteInfo {
+    pub table_name: String,
+    pub s_expr: SExpr,
+    /// Output columns of the query, each reference has its own copies of them.
+    pub columns: Vec<ColumnBinding>,
+    pub estimated_rows: u64,
+}
+
+/// The rows produced by the previous iteration of a recursive CTE.
+#[derive(Clone, Debug)]
+pub struct WorkingTable {
+    pub table_name: String,
+    pub columns: Vec<ColumnBinding>,
 }
 
 impl BindContext {
@@ -182,6 +220,7 @@ impl BindContext {
             ctes_map: Box::new(DashMap::new()),
             view_info: None,
             srfs: DashMap::new(),
+            external_functions: DashMap::new(),
             expr_context: ExprContext::default(),
         }
     }
@@ -197,6 +236,7 @@ impl BindContext {
             ctes_map: parent.ctes_map.clone(),
             view_info: None,
             srfs: DashMap::new(),
+            external_functions: DashMap::new(),
             expr_context: ExprContext::default(),
         }
     }
@@ -320,7 +360,17 @@ impl BindContext {
             ))
             .set_span(span))
         } else {
-            Ok(result.remove(0))
+            match result.remove(0) {
+                NameResolutionResult::Column(column_binding)
+                    if column_binding.visibility == Visibility::Denied =>
+                {
+                    Err(ErrorCode::PermissionDenied(format!(
+                        "Permission denied, SELECT privilege on column {column} is not granted"
+                    ))
+                    .set_span(span))
+                }
+                result => Ok(result),
+            }
         }
     }
 
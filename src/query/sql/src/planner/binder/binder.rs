@@ -23,8 +23,11 @@ use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
+use common_expression::DataField;
+use common_expression::DataSchemaRefExt;
 use common_meta_app::principal::UserDefinedFunction;
 
 use crate::normalize_identifier;
@@ -34,15 +37,20 @@ use crate::plans::CallPlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::CreateUDFPlan;
+use crate::plans::DescribeFunctionPlan;
 use crate::plans::DropFileFormatPlan;
+use crate::plans::DropResultCachePlan;
 use crate::plans::DropRolePlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::Plan;
 use crate::plans::RewriteKind;
+use crate::plans::ScalarExpr;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantsPlan;
+use crate::plans::ShowIndexBuildStatusPlan;
+use crate::plans::ShowRoleHierarchyPlan;
 use crate::plans::ShowRolesPlan;
 use crate::plans::UseDatabasePlan;
 use crate::BindContext;
@@ -131,6 +139,9 @@ impl<'a> Binder {
             }
 
             Statement::Copy(stmt) => self.bind_copy(bind_context, stmt).await?,
+            Statement::ShowCopyHistory(stmt) => {
+                self.bind_show_copy_history(bind_context, stmt).await?
+            }
 
             Statement::ShowMetrics => {
                 self.bind_rewrite_to_query(
@@ -149,6 +160,7 @@ impl<'a> Binder {
                     .await?
             }
             Statement::ShowSettings { like } => self.bind_show_settings(bind_context, like).await?,
+            Statement::ShowVariables { like } => self.bind_show_variables(bind_context, like).await?,
             // Catalogs
             Statement::ShowCatalogs(stmt) => self.bind_show_catalogs(bind_context, stmt).await?,
             Statement::ShowCreateCatalog(stmt) => self.bind_show_create_catalogs(stmt).await?,
@@ -168,7 +180,7 @@ impl<'a> Binder {
                 }))
             }
             // Columns
-            Statement::ShowColumns(stmt) => self.bind_show_columns(bind_context, stmt).await?,
+            Statement::ShowColumns(stmt) => self.bind_show_columns(stmt).await?,
             // Tables
             Statement::ShowTables(stmt) => self.bind_show_tables(bind_context, stmt).await?,
             Statement::ShowCreateTable(stmt) => self.bind_show_create_table(stmt).await?,
@@ -177,6 +189,9 @@ impl<'a> Binder {
                 self.bind_show_tables_status(bind_context, stmt).await?
             }
             Statement::CreateTable(stmt) => self.bind_create_table(stmt).await?,
+            Statement::AttachTable(stmt) => self.bind_attach_table(stmt).await?,
+            Statement::ExportTableSnapshot(stmt) => self.bind_export_table_snapshot(stmt).await?,
+            Statement::ImportTableSnapshot(stmt) => self.bind_import_table_snapshot(stmt).await?,
             Statement::DropTable(stmt) => self.bind_drop_table(stmt).await?,
             Statement::UndropTable(stmt) => self.bind_undrop_table(stmt).await?,
             Statement::AlterTable(stmt) => self.bind_alter_table(bind_context, stmt).await?,
@@ -191,6 +206,22 @@ impl<'a> Binder {
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
 
+            // Streams
+            Statement::CreateStream(stmt) => self.bind_create_stream(stmt).await?,
+            Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
+            Statement::ShowStreams(stmt) => self.bind_show_streams(bind_context, stmt).await?,
+
+            // Tasks
+            Statement::CreateTask(stmt) => self.bind_create_task(stmt).await?,
+            Statement::AlterTask(stmt) => self.bind_alter_task(stmt).await?,
+            Statement::DropTask(stmt) => self.bind_drop_task(stmt).await?,
+            Statement::ExecuteTask(stmt) => self.bind_execute_task(stmt).await?,
+
+            // Pipes
+            Statement::CreatePipe(stmt) => self.bind_create_pipe(stmt).await?,
+            Statement::DropPipe(stmt) => self.bind_drop_pipe(stmt).await?,
+            Statement::ShowPipes => self.bind_rewrite_to_query(bind_context, "SELECT name, database, table, source, lag, rows_per_second, last_committed_on, last_error, comment FROM system.pipe_status ORDER BY name", RewriteKind::ShowPipes).await?,
+
             // Users
             Statement::CreateUser(stmt) => self.bind_create_user(stmt).await?,
             Statement::DropUser { if_exists, user } => Plan::DropUser(Box::new(DropUserPlan {
@@ -202,6 +233,9 @@ impl<'a> Binder {
 
             // Roles
             Statement::ShowRoles => Plan::ShowRoles(Box::new(ShowRolesPlan {})),
+            Statement::ShowRoleHierarchy => {
+                Plan::ShowRoleHierarchy(Box::new(ShowRoleHierarchyPlan {}))
+            }
             Statement::CreateRole {
                 if_not_exists,
                 role_name,
@@ -222,6 +256,14 @@ impl<'a> Binder {
             Statement::ListStage { location, pattern } => self.bind_rewrite_to_query(bind_context, format!("SELECT * FROM LIST_STAGE(location => '@{location}', pattern => '{pattern}')").as_str(), RewriteKind::ListStage).await?,
             Statement::DescribeStage { stage_name } => self.bind_rewrite_to_query(bind_context, format!("SELECT * FROM system.stages WHERE name = '{stage_name}'").as_str(), RewriteKind::DescribeStage).await?,
             Statement::CreateStage(stmt) => self.bind_create_stage(stmt).await?,
+            Statement::AlterStage(stmt) => self.bind_alter_stage(stmt).await?,
+            Statement::RenameStage {
+                stage_name,
+                new_stage_name,
+            } => self.bind_rename_stage(stage_name, new_stage_name).await?,
+            Statement::ShowCreateStage { stage_name } => {
+                self.bind_show_create_stage(stage_name).await?
+            }
             Statement::DropStage {
                 stage_name,
                 if_exists,
@@ -232,7 +274,15 @@ impl<'a> Binder {
             Statement::RemoveStage { location, pattern } => {
                 self.bind_remove_stage(location, pattern).await?
             }
+            Statement::ShowStageFiles {
+                location,
+                pattern,
+                limit,
+            } => self.bind_show_stage_files(location, pattern, *limit).await?,
             Statement::Insert(stmt) => self.bind_insert(bind_context, stmt).await?,
+            Statement::InsertMultiTable(stmt) => {
+                self.bind_insert_multi_table(bind_context, stmt).await?
+            }
             Statement::Replace(stmt) => self.bind_replace(bind_context, stmt).await?,
             Statement::Delete {
                 table_reference,
@@ -322,6 +372,73 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 name: udf_name.to_string(),
             })),
+            Statement::DescribeFunction { name } => {
+                Plan::DescribeFunction(Box::new(DescribeFunctionPlan {
+                    name: name.to_string(),
+                    schema: DataSchemaRefExt::create(vec![
+                        DataField::new("Signature", DataType::String),
+                        DataField::new("ReturnType", DataType::String),
+                        DataField::new("Description", DataType::String),
+                        DataField::new("Example", DataType::String),
+                    ]),
+                }))
+            }
+
+            // External functions
+            Statement::CreateExternalFunction(stmt) => {
+                self.bind_create_external_function(stmt).await?
+            }
+            Statement::DropExternalFunction(stmt) => {
+                self.bind_drop_external_function(stmt).await?
+            }
+
+            // Masking policies
+            Statement::CreateMaskingPolicy(stmt) => {
+                self.bind_create_masking_policy(stmt).await?
+            }
+            Statement::DropMaskingPolicy(stmt) => {
+                self.bind_drop_masking_policy(stmt).await?
+            }
+
+            // Row access policies
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy(stmt) => {
+                self.bind_drop_row_access_policy(stmt).await?
+            }
+            Statement::ShowRowAccessPolicies => self.bind_show_row_access_policies().await?,
+
+            // Sequences
+            Statement::CreateSequence(stmt) => self.bind_create_sequence(stmt).await?,
+            Statement::DropSequence(stmt) => self.bind_drop_sequence(stmt).await?,
+            Statement::ShowSequences => self.bind_show_sequences().await?,
+            Statement::ShowIndexBuildStatus => Plan::ShowIndexBuildStatus(Box::new(ShowIndexBuildStatusPlan {
+                tenant: self.ctx.get_tenant(),
+            })),
+
+            // Network policies
+            Statement::CreateNetworkPolicy(stmt) => {
+                self.bind_create_network_policy(stmt).await?
+            }
+            Statement::DropNetworkPolicy(stmt) => {
+                self.bind_drop_network_policy(stmt).await?
+            }
+            Statement::DescNetworkPolicy(stmt) => {
+                self.bind_desc_network_policy(stmt).await?
+            }
+            Statement::ShowNetworkPolicies => {
+                self.bind_show_network_policies().await?
+            }
+
+            Statement::DropResultCache => Plan::DropResultCache(Box::new(DropResultCachePlan {
+                tenant: self.ctx.get_tenant(),
+            })),
+
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Abort => Plan::Abort,
+
             Statement::Call(stmt) => Plan::Call(Box::new(CallPlan {
                 name: stmt.name.clone(),
                 args: stmt.args.clone(),
@@ -343,6 +460,13 @@ impl<'a> Binder {
                     .await?
             }
 
+            Statement::SetUserVariable { variable, value } => {
+                self.bind_set_user_variable(bind_context, variable, value)
+                    .await?
+            }
+
+            Statement::ResetUserVariable { variable } => self.bind_reset_user_variable(variable)?,
+
             Statement::SetRole {
                 is_default,
                 role_name,
@@ -380,6 +504,9 @@ impl<'a> Binder {
             Statement::AlterShareTenants(stmt) => {
                 self.bind_alter_share_accounts(stmt).await?
             }
+            Statement::AlterShareComment(stmt) => {
+                self.bind_alter_share_comment(stmt).await?
+            }
             Statement::DescShare(stmt) => {
                 self.bind_desc_share(stmt).await?
             }
@@ -434,6 +561,22 @@ impl<'a> Binder {
         }
     }
 
+    /// DML statements work on the raw data of a table, so they are not allowed to
+    /// reference the columns protected by masking policies.
+    pub(crate) fn check_masked_columns(&self, scalar: &ScalarExpr) -> Result<()> {
+        let metadata = self.metadata.read();
+        for index in scalar.used_columns() {
+            if let Some(masked_column) = metadata.masked_column(index) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "column {} is protected by masking policy {} and can't be referenced here",
+                    masked_column.column_name, masked_column.policy_name
+                ))
+                .set_span(scalar.span()));
+            }
+        }
+        Ok(())
+    }
+
     /// Normalize [[<catalog>].<database>].<object>
     /// object like table, view ...
     pub fn normalize_object_identifier_triple(
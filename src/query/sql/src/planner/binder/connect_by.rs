@@ -0,0 +1,750 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::BinaryOperator;
+use common_ast::ast::ConnectBy;
+use common_ast::ast::Expr;
+use common_ast::ast::GroupBy;
+use common_ast::ast::Identifier;
+use common_ast::ast::Indirection;
+use common_ast::ast::IntervalKind;
+use common_ast::ast::Join;
+use common_ast::ast::JoinCondition;
+use common_ast::ast::JoinOperator;
+use common_ast::ast::Lambda;
+use common_ast::ast::ListAggOverflow;
+use common_ast::ast::Literal;
+use common_ast::ast::MapAccessor;
+use common_ast::ast::OrderByExpr;
+use common_ast::ast::Query;
+use common_ast::ast::SelectStmt;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
+use common_ast::ast::SetOperation;
+use common_ast::ast::SetOperator;
+use common_ast::ast::SubqueryModifier;
+use common_ast::ast::TableAlias;
+use common_ast::ast::TableReference;
+use common_ast::ast::TrimWhere;
+use common_ast::ast::TypeName;
+use common_ast::ast::UnaryOperator;
+use common_ast::ast::WindowSpec;
+use common_ast::ast::With;
+use common_ast::ast::CTE;
+use common_ast::walk_expr_mut;
+use common_ast::VisitorMut;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+
+use crate::binder::Binder;
+use crate::optimizer::SExpr;
+use crate::BindContext;
+
+const CONNECT_BY_CTE_NAME: &str = "__connect_by";
+const PRIOR_TABLE_NAME: &str = "__prior";
+const LEVEL_COLUMN_NAME: &str = "__level";
+
+impl Binder {
+    /// Bind `SELECT ... FROM t [START WITH cond] CONNECT BY cond` by lowering it to a recursive cte:
+    ///
+    /// ```sql
+    /// WITH RECURSIVE __connect_by AS (
+    ///     SELECT t.*, 1 AS __level FROM t WHERE <start with>
+    ///     UNION ALL
+    ///     SELECT t.*, __prior.__level + 1 FROM t JOIN __connect_by AS __prior ON <connect by>
+    /// )
+    /// SELECT ... FROM __connect_by AS t
+    /// ```
+    ///
+    /// `PRIOR expr` in the CONNECT BY condition reads the parent row, i.e. `__prior`.
+    /// `CONNECT_BY_ROOT(expr)` and `SYS_CONNECT_BY_PATH(expr, sep)` are carried from the seed
+    /// rows to their descendants as extra columns of the cte, like `LEVEL`.
+    pub(super) async fn bind_connect_by(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &SelectStmt,
+        connect_by: &ConnectBy,
+        order_by: &[OrderByExpr],
+    ) -> Result<(SExpr, BindContext)> {
+        let (table, qualifier) = match stmt.from.as_slice() {
+            [
+                table @ TableReference::Table {
+                    table: name, alias, ..
+                },
+            ] => (
+                table,
+                alias
+                    .as_ref()
+                    .map(|alias| alias.name.clone())
+                    .unwrap_or_else(|| name.clone()),
+            ),
+            [
+                table @ TableReference::Subquery {
+                    alias: Some(alias), ..
+                },
+            ] => (table, alias.name.clone()),
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "CONNECT BY requires exactly one table or aliased subquery in FROM clause",
+                )
+                .set_span(connect_by.span));
+            }
+        };
+        let span = connect_by.span;
+
+        // Rewrite the pseudo columns of the outer query into the columns of the cte.
+        let mut rewriter = ConnectByRewriter::new(RewriteScope::Select, qualifier.clone());
+        let mut outer = SelectStmt {
+            from: vec![TableReference::Table {
+                span,
+                catalog: None,
+                database: None,
+                table: ident(CONNECT_BY_CTE_NAME, span),
+                alias: Some(TableAlias {
+                    name: qualifier.clone(),
+                    columns: vec![],
+                }),
+                travel_point: None,
+                sample: None,
+                pivot: None,
+                unpivot: None,
+                match_recognize: None,
+            }],
+            connect_by: None,
+            ..stmt.clone()
+        };
+        for target in outer.select_list.iter_mut() {
+            if let SelectTarget::AliasedExpr { expr, alias } = target {
+                let original = expr.clone();
+                rewriter.visit_expr(expr);
+                // Keep the name of the output column, e.g. `level` instead of `__level`.
+                if alias.is_none() && **expr != *original {
+                    *alias = Some(Identifier {
+                        name: format!("{:#}", original).to_lowercase(),
+                        quote: Some('"'),
+                        span: original.span(),
+                    });
+                }
+            }
+        }
+        if let Some(selection) = &mut outer.selection {
+            rewriter.visit_expr(selection);
+        }
+        if let Some(having) = &mut outer.having {
+            rewriter.visit_expr(having);
+        }
+        if let Some(group_by) = &mut outer.group_by {
+            rewriter.visit_group_by(group_by);
+        }
+        let mut order_by = order_by.to_vec();
+        for order_by in order_by.iter_mut() {
+            rewriter.visit_expr(&mut order_by.expr);
+        }
+
+        // The extra columns of the cte are not part of `*`.
+        let hidden_columns = rewriter.hidden_columns(span);
+        for target in outer.select_list.iter_mut() {
+            if let SelectTarget::QualifiedName { qualified, exclude } = target {
+                if matches!(qualified.last(), Some(Indirection::Star(_))) {
+                    exclude
+                        .get_or_insert_with(Vec::new)
+                        .extend(hidden_columns.iter().cloned());
+                }
+            }
+        }
+
+        let star = SelectTarget::QualifiedName {
+            qualified: vec![
+                Indirection::Identifier(qualifier.clone()),
+                Indirection::Star(span),
+            ],
+            exclude: None,
+        };
+        let prior_column = |name: &str| Expr::ColumnRef {
+            span,
+            database: None,
+            table: Some(ident(PRIOR_TABLE_NAME, span)),
+            column: ident(name, span),
+        };
+
+        let mut seed_list = vec![star.clone(), aliased(literal_one(span), LEVEL_COLUMN_NAME)];
+        let mut recursive_list = vec![
+            star,
+            aliased(
+                Expr::BinaryOp {
+                    span,
+                    op: BinaryOperator::Plus,
+                    left: Box::new(prior_column(LEVEL_COLUMN_NAME)),
+                    right: Box::new(literal_one(span)),
+                },
+                LEVEL_COLUMN_NAME,
+            ),
+        ];
+        let mut member_rewriter =
+            ConnectByRewriter::new(RewriteScope::Condition, qualifier.clone());
+        for (i, expr) in rewriter.roots.iter().enumerate() {
+            let name = format!("__root_{i}");
+            seed_list.push(aliased(expr.clone(), &name));
+            recursive_list.push(aliased(prior_column(&name), &name));
+        }
+        for (i, (expr, separator)) in rewriter.paths.iter().enumerate() {
+            let name = format!("__path_{i}");
+            seed_list.push(aliased(
+                concat(span, vec![separator.clone(), cast_to_string(expr.clone())]),
+                &name,
+            ));
+            let mut expr = expr.clone();
+            member_rewriter.visit_expr(&mut expr);
+            recursive_list.push(aliased(
+                concat(span, vec![
+                    prior_column(&name),
+                    separator.clone(),
+                    cast_to_string(expr),
+                ]),
+                &name,
+            ));
+        }
+
+        let mut condition = connect_by.condition.clone();
+        member_rewriter.visit_expr(&mut condition);
+
+        let seed = SelectStmt {
+            span,
+            distinct: false,
+            select_list: seed_list,
+            from: vec![table.clone()],
+            selection: connect_by.start_with.clone(),
+            group_by: None,
+            having: None,
+            connect_by: None,
+        };
+        let recursive = SelectStmt {
+            span,
+            distinct: false,
+            select_list: recursive_list,
+            from: vec![TableReference::Join {
+                span,
+                join: Join {
+                    op: JoinOperator::Inner,
+                    condition: JoinCondition::On(Box::new(condition)),
+                    left: Box::new(table.clone()),
+                    right: Box::new(TableReference::Table {
+                        span,
+                        catalog: None,
+                        database: None,
+                        table: ident(CONNECT_BY_CTE_NAME, span),
+                        alias: Some(TableAlias {
+                            name: ident(PRIOR_TABLE_NAME, span),
+                            columns: vec![],
+                        }),
+                        travel_point: None,
+                        sample: None,
+                        pivot: None,
+                        unpivot: None,
+                        match_recognize: None,
+                    }),
+                },
+            }],
+            selection: None,
+            group_by: None,
+            having: None,
+            connect_by: None,
+        };
+
+        let query = Query {
+            span: stmt.span,
+            with: Some(With {
+                span,
+                recursive: true,
+                ctes: vec![CTE {
+                    span,
+                    alias: TableAlias {
+                        name: ident(CONNECT_BY_CTE_NAME, span),
+                        columns: vec![],
+                    },
+                    materialized: None,
+                    query: Query {
+                        span,
+                        with: None,
+                        body: SetExpr::SetOperation(Box::new(SetOperation {
+                            span,
+                            op: SetOperator::Union,
+                            all: true,
+                            left: Box::new(SetExpr::Select(Box::new(seed))),
+                            right: Box::new(SetExpr::Select(Box::new(recursive))),
+                        })),
+                        order_by: vec![],
+                        limit: vec![],
+                        offset: None,
+                        ignore_result: false,
+                    },
+                }],
+            }),
+            body: SetExpr::Select(Box::new(outer)),
+            order_by,
+            limit: vec![],
+            offset: None,
+            ignore_result: false,
+        };
+
+        // Bind in a copy of the context, so the cte is only visible to the rewritten query.
+        let mut query_context = bind_context.clone();
+        self.bind_query(&mut query_context, &query).await
+    }
+}
+
+fn ident(name: &str, span: Span) -> Identifier {
+    Identifier {
+        name: name.to_string(),
+        quote: None,
+        span,
+    }
+}
+
+fn aliased(expr: Expr, alias: &str) -> SelectTarget {
+    let span = expr.span();
+    SelectTarget::AliasedExpr {
+        expr: Box::new(expr),
+        alias: Some(ident(alias, span)),
+    }
+}
+
+fn literal_one(span: Span) -> Expr {
+    Expr::Literal {
+        span,
+        lit: Literal::UInt64(1),
+    }
+}
+
+fn concat(span: Span, args: Vec<Expr>) -> Expr {
+    Expr::FunctionCall {
+        span,
+        distinct: false,
+        name: ident("concat", span),
+        args,
+        params: vec![],
+        window: None,
+        lambda: None,
+    }
+}
+
+fn cast_to_string(expr: Expr) -> Expr {
+    Expr::Cast {
+        span: expr.span(),
+        expr: Box::new(expr),
+        target_type: TypeName::String,
+        pg_style: false,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RewriteScope {
+    /// The outer query, which reads the rows of the cte.
+    Select,
+    /// The CONNECT BY condition, which joins the table with the rows of the previous level.
+    Condition,
+    /// The operand of `PRIOR` in the CONNECT BY condition.
+    Prior,
+}
+
+/// Rewrites `LEVEL`, `PRIOR`, `CONNECT_BY_ROOT` and `SYS_CONNECT_BY_PATH` into the columns of
+/// the recursive cte.
+///
+/// The pseudo columns can be nested anywhere in an expression, while the walker only visits the
+/// children of an expression through the hooks, so every hook with children is overridden to
+/// give `rewrite_expr` a chance to replace them. Subqueries are left as they are.
+struct ConnectByRewriter {
+    scope: RewriteScope,
+    qualifier: Identifier,
+    roots: Vec<Expr>,
+    paths: Vec<(Expr, Expr)>,
+}
+
+impl ConnectByRewriter {
+    fn new(scope: RewriteScope, qualifier: Identifier) -> Self {
+        Self {
+            scope,
+            qualifier,
+            roots: vec![],
+            paths: vec![],
+        }
+    }
+
+    fn hidden_columns(&self, span: Span) -> Vec<Identifier> {
+        let mut columns = vec![ident(LEVEL_COLUMN_NAME, span)];
+        columns.extend((0..self.roots.len()).map(|i| ident(&format!("__root_{i}"), span)));
+        columns.extend((0..self.paths.len()).map(|i| ident(&format!("__path_{i}"), span)));
+        columns
+    }
+
+    fn column(&self, table: Option<&str>, name: &str, span: Span) -> Expr {
+        Expr::ColumnRef {
+            span,
+            database: None,
+            table: table.map(|table| ident(table, span)),
+            column: ident(name, span),
+        }
+    }
+
+    fn rewrite_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::ColumnRef {
+                span,
+                database: None,
+                table: None,
+                column,
+            } if column.quote.is_none() && column.name.eq_ignore_ascii_case("level") => {
+                let span = *span;
+                *expr = match self.scope {
+                    RewriteScope::Select => self.column(None, LEVEL_COLUMN_NAME, span),
+                    // `LEVEL` in the CONNECT BY condition is the level of the child row.
+                    RewriteScope::Condition => Expr::BinaryOp {
+                        span,
+                        op: BinaryOperator::Plus,
+                        left: Box::new(self.column(
+                            Some(PRIOR_TABLE_NAME),
+                            LEVEL_COLUMN_NAME,
+                            span,
+                        )),
+                        right: Box::new(literal_one(span)),
+                    },
+                    RewriteScope::Prior => {
+                        self.column(Some(PRIOR_TABLE_NAME), LEVEL_COLUMN_NAME, span)
+                    }
+                };
+            }
+            Expr::UnaryOp {
+                op: UnaryOperator::Prior,
+                expr: operand,
+                ..
+            } if self.scope == RewriteScope::Condition => {
+                self.scope = RewriteScope::Prior;
+                self.rewrite_expr(operand);
+                self.scope = RewriteScope::Condition;
+                let operand = (**operand).clone();
+                *expr = operand;
+            }
+            Expr::FunctionCall {
+                span,
+                distinct: false,
+                name,
+                args,
+                window: None,
+                ..
+            } if self.scope == RewriteScope::Select
+                && args.len() == 1
+                && name.name.eq_ignore_ascii_case("connect_by_root") =>
+            {
+                let i = match self.roots.iter().position(|root| root == &args[0]) {
+                    Some(i) => i,
+                    None => {
+                        self.roots.push(args[0].clone());
+                        self.roots.len() - 1
+                    }
+                };
+                *expr = self.column(None, &format!("__root_{i}"), *span);
+            }
+            Expr::FunctionCall {
+                span,
+                distinct: false,
+                name,
+                args,
+                window: None,
+                ..
+            } if self.scope == RewriteScope::Select
+                && args.len() == 2
+                && name.name.eq_ignore_ascii_case("sys_connect_by_path") =>
+            {
+                let path = (args[0].clone(), args[1].clone());
+                let i = match self.paths.iter().position(|p| p == &path) {
+                    Some(i) => i,
+                    None => {
+                        self.paths.push(path);
+                        self.paths.len() - 1
+                    }
+                };
+                *expr = self.column(None, &format!("__path_{i}"), *span);
+            }
+            _ => walk_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_group_by(&mut self, group_by: &mut GroupBy) {
+        match group_by {
+            GroupBy::Normal(exprs) | GroupBy::Cube(exprs) | GroupBy::Rollup(exprs) => {
+                exprs.iter_mut().for_each(|expr| self.rewrite_expr(expr));
+            }
+            GroupBy::GroupingSets(sets) => sets
+                .iter_mut()
+                .flatten()
+                .for_each(|expr| self.rewrite_expr(expr)),
+        }
+    }
+}
+
+impl VisitorMut for ConnectByRewriter {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_column_ref(
+        &mut self,
+        span: Span,
+        database: &mut Option<Identifier>,
+        table: &mut Option<Identifier>,
+        _column: &mut Identifier,
+    ) {
+        match self.scope {
+            RewriteScope::Select => {}
+            // Both the table and the cte have the columns of the table, so qualify the columns
+            // to tell them apart.
+            RewriteScope::Condition => {
+                if table.is_none() {
+                    *table = Some(self.qualifier.clone());
+                }
+            }
+            RewriteScope::Prior => {
+                *database = None;
+                *table = Some(ident(PRIOR_TABLE_NAME, span));
+            }
+        }
+    }
+
+    fn visit_is_null(&mut self, _span: Span, expr: &mut Expr, _not: bool) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_is_distinct_from(
+        &mut self,
+        _span: Span,
+        left: &mut Expr,
+        right: &mut Expr,
+        _not: bool,
+    ) {
+        self.rewrite_expr(left);
+        self.rewrite_expr(right);
+    }
+
+    fn visit_in_list(&mut self, _span: Span, expr: &mut Expr, list: &mut [Expr], _not: bool) {
+        self.rewrite_expr(expr);
+        list.iter_mut().for_each(|expr| self.rewrite_expr(expr));
+    }
+
+    fn visit_in_subquery(
+        &mut self,
+        _span: Span,
+        expr: &mut Expr,
+        _subquery: &mut Query,
+        _not: bool,
+    ) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_between(
+        &mut self,
+        _span: Span,
+        expr: &mut Expr,
+        low: &mut Expr,
+        high: &mut Expr,
+        _not: bool,
+    ) {
+        self.rewrite_expr(expr);
+        self.rewrite_expr(low);
+        self.rewrite_expr(high);
+    }
+
+    fn visit_binary_op(
+        &mut self,
+        _span: Span,
+        _op: &mut BinaryOperator,
+        left: &mut Expr,
+        right: &mut Expr,
+    ) {
+        self.rewrite_expr(left);
+        self.rewrite_expr(right);
+    }
+
+    fn visit_unary_op(&mut self, _span: Span, _op: &mut UnaryOperator, expr: &mut Expr) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_cast(
+        &mut self,
+        _span: Span,
+        expr: &mut Expr,
+        _target_type: &mut TypeName,
+        _pg_style: bool,
+    ) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_try_cast(&mut self, _span: Span, expr: &mut Expr, _target_type: &mut TypeName) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_extract(&mut self, _span: Span, _kind: &mut IntervalKind, expr: &mut Expr) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_position(&mut self, _span: Span, substr_expr: &mut Expr, str_expr: &mut Expr) {
+        self.rewrite_expr(substr_expr);
+        self.rewrite_expr(str_expr);
+    }
+
+    fn visit_substring(
+        &mut self,
+        _span: Span,
+        expr: &mut Expr,
+        substring_from: &mut Box<Expr>,
+        substring_for: &mut Option<Box<Expr>>,
+    ) {
+        self.rewrite_expr(expr);
+        self.rewrite_expr(substring_from);
+        if let Some(substring_for) = substring_for {
+            self.rewrite_expr(substring_for);
+        }
+    }
+
+    fn visit_trim(
+        &mut self,
+        _span: Span,
+        expr: &mut Expr,
+        trim_where: &mut Option<(TrimWhere, Box<Expr>)>,
+    ) {
+        self.rewrite_expr(expr);
+        if let Some((_, trim_where_expr)) = trim_where {
+            self.rewrite_expr(trim_where_expr);
+        }
+    }
+
+    fn visit_tuple(&mut self, _span: Span, elements: &mut [Expr]) {
+        elements.iter_mut().for_each(|expr| self.rewrite_expr(expr));
+    }
+
+    fn visit_function_call(
+        &mut self,
+        _span: Span,
+        _distinct: bool,
+        _name: &mut Identifier,
+        args: &mut [Expr],
+        _params: &mut [Literal],
+        over: &mut Option<WindowSpec>,
+        _lambda: &mut Option<Lambda>,
+    ) {
+        args.iter_mut().for_each(|expr| self.rewrite_expr(expr));
+        if let Some(over) = over {
+            over.partition_by
+                .iter_mut()
+                .for_each(|expr| self.rewrite_expr(expr));
+            over.order_by
+                .iter_mut()
+                .for_each(|order_by| self.rewrite_expr(&mut order_by.expr));
+        }
+    }
+
+    fn visit_list_agg(
+        &mut self,
+        _span: Span,
+        _distinct: bool,
+        expr: &mut Expr,
+        _delimiter: &mut Option<String>,
+        _on_overflow: &mut Option<ListAggOverflow>,
+        within_group: &mut Option<Box<OrderByExpr>>,
+    ) {
+        self.rewrite_expr(expr);
+        if let Some(order_by) = within_group {
+            self.rewrite_expr(&mut order_by.expr);
+        }
+    }
+
+    fn visit_case_when(
+        &mut self,
+        _span: Span,
+        operand: &mut Option<Box<Expr>>,
+        conditions: &mut [Expr],
+        results: &mut [Expr],
+        else_result: &mut Option<Box<Expr>>,
+    ) {
+        if let Some(operand) = operand {
+            self.rewrite_expr(operand);
+        }
+        conditions
+            .iter_mut()
+            .for_each(|expr| self.rewrite_expr(expr));
+        results.iter_mut().for_each(|expr| self.rewrite_expr(expr));
+        if let Some(else_result) = else_result {
+            self.rewrite_expr(else_result);
+        }
+    }
+
+    fn visit_exists(&mut self, _span: Span, _not: bool, _subquery: &mut Query) {}
+
+    fn visit_subquery(
+        &mut self,
+        _span: Span,
+        _modifier: &mut Option<SubqueryModifier>,
+        _subquery: &mut Query,
+    ) {
+    }
+
+    fn visit_map_access(&mut self, _span: Span, expr: &mut Expr, _accessor: &mut MapAccessor) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_array(&mut self, _span: Span, elements: &mut [Expr]) {
+        elements.iter_mut().for_each(|expr| self.rewrite_expr(expr));
+    }
+
+    fn visit_array_sort(&mut self, _span: Span, expr: &mut Expr, _asc: bool, _null_first: bool) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_map(&mut self, _span: Span, kvs: &mut [(Expr, Expr)]) {
+        for (key_expr, val_expr) in kvs {
+            self.rewrite_expr(key_expr);
+            self.rewrite_expr(val_expr);
+        }
+    }
+
+    fn visit_interval(&mut self, _span: Span, expr: &mut Expr, _unit: &mut IntervalKind) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_date_add(
+        &mut self,
+        _span: Span,
+        _unit: &mut IntervalKind,
+        interval: &mut Expr,
+        date: &mut Expr,
+    ) {
+        self.rewrite_expr(date);
+        self.rewrite_expr(interval);
+    }
+
+    fn visit_date_sub(
+        &mut self,
+        _span: Span,
+        _unit: &mut IntervalKind,
+        interval: &mut Expr,
+        date: &mut Expr,
+    ) {
+        self.rewrite_expr(date);
+        self.rewrite_expr(interval);
+    }
+
+    fn visit_date_trunc(&mut self, _span: Span, _unit: &mut IntervalKind, date: &mut Expr) {
+        self.rewrite_expr(date);
+    }
+}
@@ -23,6 +23,7 @@ use common_ast::ast::FileLocation;
 use common_ast::ast::Query;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::SetExpr;
+use common_ast::ast::ShowCopyHistoryStmt;
 use common_ast::ast::Statement;
 use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
@@ -40,7 +41,9 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::storage::StorageParams;
 use common_storage::init_stage_operator;
+use common_storage::is_url_allowed;
 use common_storage::StageFileStatus;
 use common_storage::StageFilesInfo;
 use common_users::UserApiProvider;
@@ -49,10 +52,13 @@ use tracing::info;
 
 use crate::binder::location::parse_uri_location;
 use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
 use crate::plans::CopyPlan;
 use crate::plans::Plan;
+use crate::plans::RewriteKind;
 use crate::plans::ValidationMode;
 use crate::BindContext;
+use crate::SelectBuilder;
 
 impl<'a> Binder {
     pub(in crate::planner::binder) async fn bind_copy(
@@ -289,12 +295,13 @@ impl<'a> Binder {
             .get_table(dst_catalog_name, dst_database_name, dst_table_name)
             .await?;
 
-        let (storage_params, path) = parse_uri_location(src_uri_location)?;
+        let (mut storage_params, path) = parse_uri_location(src_uri_location)?;
         if !storage_params.is_secure() && !GlobalConfig::instance().storage.allow_insecure {
             return Err(ErrorCode::StorageInsecure(
                 "copy from insecure storage is not allowed",
             ));
         }
+        check_external_url(&self.ctx, &mut storage_params)?;
 
         let mut stage_info = StageInfo::new_external_stage(storage_params, &path);
         self.apply_stage_options(stmt, &mut stage_info).await?;
@@ -587,6 +594,7 @@ impl<'a> Binder {
                 stage_info.clone(),
                 files_info,
                 alias,
+                None,
                 Some(need_copy_file_infos.clone()),
             )
             .await?;
@@ -653,6 +661,58 @@ impl<'a> Binder {
 
         Ok(())
     }
+    pub(in crate::planner::binder) async fn bind_show_copy_history(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &ShowCopyHistoryStmt,
+    ) -> Result<Plan> {
+        let ShowCopyHistoryStmt {
+            catalog,
+            database,
+            table,
+            since,
+        } = stmt;
+
+        let database = self.check_database_exist(catalog, database).await?;
+        let catalog = match catalog {
+            Some(catalog) => normalize_identifier(catalog, &self.name_resolution_ctx).name,
+            None => self.ctx.get_current_catalog(),
+        };
+        let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+        // Make sure the table exists, history of dropped tables is not shown.
+        self.ctx.get_table(&catalog, &database, &table).await?;
+
+        let mut select_builder = SelectBuilder::from("information_schema.copy_history");
+        select_builder
+            .with_column("file_name")
+            .with_column("table_name")
+            .with_column("stage_location")
+            .with_column("file_size")
+            .with_column("rows_parsed")
+            .with_column("rows_loaded")
+            .with_column("error_count")
+            .with_column("status")
+            .with_column("first_error_message")
+            .with_column("first_error_line")
+            .with_column("first_error_column")
+            .with_column("last_load_time");
+
+        select_builder
+            .with_filter(format!("table_schema = '{database}'"))
+            .with_filter(format!("table_name = '{table}'"));
+        if let Some(since) = since {
+            select_builder.with_filter(format!("last_load_time >= to_timestamp('{since}')"));
+        }
+
+        select_builder
+            .with_order_by("last_load_time DESC")
+            .with_order_by("file_name");
+
+        let query = select_builder.build();
+        debug!("show copy history rewrite to: {:?}", query);
+        self.bind_rewrite_to_query(bind_context, query.as_str(), RewriteKind::ShowCopyHistory)
+            .await
+    }
 }
 
 // we can avoid this by specializing the parser.
@@ -677,8 +737,14 @@ fn check_transform_query(
                     location,
                     options,
                     alias,
+                    sample,
                 } = &select.from[0]
                 {
+                    if sample.is_some() {
+                        return Err(ErrorCode::SyntaxException(
+                            "stage table function inside copy not allow TABLESAMPLE",
+                        ));
+                    }
                     if options.is_empty() {
                         return Ok((&select.select_list, location, alias));
                     } else {
@@ -779,15 +845,43 @@ pub async fn parse_file_location(
         }
         FileLocation::Uri(uri) => {
             let mut location = UriLocation::from_uri(uri, "".to_string(), connection)?;
-            let (storage_params, path) = parse_uri_location(&mut location)?;
+            let (mut storage_params, path) = parse_uri_location(&mut location)?;
             if !storage_params.is_secure() && !GlobalConfig::instance().storage.allow_insecure {
                 Err(ErrorCode::StorageInsecure(
                     "copy from insecure storage is not allowed",
                 ))
             } else {
+                check_external_url(ctx, &mut storage_params)?;
                 let stage_info = StageInfo::new_external_stage(storage_params, &path);
                 Ok((stage_info, path))
             }
         }
     }
 }
+
+/// Files can only be loaded over HTTP from the urls that match one of the prefixes in the
+/// setting `allowed_external_url_prefix`, all urls are allowed if it's empty. The redirects
+/// are checked against the same prefixes when the files are read.
+fn check_external_url(
+    ctx: &Arc<dyn TableContext>,
+    storage_params: &mut StorageParams,
+) -> Result<()> {
+    let cfg = match storage_params {
+        StorageParams::Http(cfg) => cfg,
+        _ => return Ok(()),
+    };
+    let prefixes = ctx.get_settings().get_allowed_external_url_prefix()?;
+    if prefixes.is_empty() {
+        return Ok(());
+    }
+    for path in cfg.paths.iter() {
+        let url = format!("{}{}", cfg.endpoint_url, path);
+        if !is_url_allowed(&url, &prefixes) {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "loading from {url} is not allowed, it doesn't match any prefix in setting allowed_external_url_prefix"
+            )));
+        }
+    }
+    cfg.allowed_url_prefixes = prefixes;
+    Ok(())
+}
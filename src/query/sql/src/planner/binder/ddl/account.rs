@@ -18,6 +18,7 @@ use common_ast::ast::AlterUserStmt;
 use common_ast::ast::CreateUserStmt;
 use common_ast::ast::GrantStmt;
 use common_ast::ast::RevokeStmt;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::GrantObject;
@@ -56,7 +57,7 @@ impl Binder {
                 let priv_types = grant_object.available_privileges();
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
@@ -69,7 +70,21 @@ impl Binder {
                 }
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
+                    priv_types,
+                };
+                Ok(Plan::GrantPriv(Box::new(plan)))
+            }
+            AccountMgrSource::ColumnPrivs {
+                privilege,
+                columns,
+                level,
+            } => {
+                let mut priv_types = UserPrivilegeSet::empty();
+                priv_types.set_privilege(*privilege);
+                let plan = GrantPrivilegePlan {
+                    principal: principal.clone(),
+                    on: self.convert_to_column_grant_objects(columns, level)?,
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
@@ -98,7 +113,7 @@ impl Binder {
                 let priv_types = grant_object.available_privileges();
                 let plan = RevokePrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
@@ -111,7 +126,21 @@ impl Binder {
                 }
                 let plan = RevokePrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
+                    priv_types,
+                };
+                Ok(Plan::RevokePriv(Box::new(plan)))
+            }
+            AccountMgrSource::ColumnPrivs {
+                privilege,
+                columns,
+                level,
+            } => {
+                let mut priv_types = UserPrivilegeSet::empty();
+                priv_types.set_privilege(*privilege);
+                let plan = RevokePrivilegePlan {
+                    principal: principal.clone(),
+                    on: self.convert_to_column_grant_objects(columns, level)?,
                     priv_types,
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
@@ -139,6 +168,31 @@ impl Binder {
                     .unwrap_or_else(|| self.ctx.get_current_database());
                 GrantObject::Database(catalog_name, database_name)
             }
+            AccountMgrLevel::Stage(stage_name) => GrantObject::Stage(stage_name.clone()),
+        }
+    }
+
+    /// Converts `<privilege>(<column>, ...) ON <table>` into one grant object per column.
+    fn convert_to_column_grant_objects(
+        &self,
+        columns: &[String],
+        level: &AccountMgrLevel,
+    ) -> Result<Vec<GrantObject>> {
+        match self.convert_to_grant_object(level) {
+            GrantObject::Table(catalog_name, database_name, table_name) => Ok(columns
+                .iter()
+                .map(|column| {
+                    GrantObject::Column(
+                        catalog_name.clone(),
+                        database_name.clone(),
+                        table_name.clone(),
+                        column.clone(),
+                    )
+                })
+                .collect()),
+            _ => Err(ErrorCode::IllegalGrant(
+                "Column privileges can only be granted on a table",
+            )),
         }
     }
 
@@ -156,6 +210,11 @@ impl Binder {
         for option in user_options {
             option.apply(&mut user_option);
         }
+        if let Some(policy) = user_option.network_policy() {
+            UserApiProvider::instance()
+                .get_network_policy(&self.ctx.get_tenant(), policy)
+                .await?;
+        }
         let plan = CreateUserPlan {
             user: user.clone(),
             auth_info: AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?,
@@ -201,6 +260,13 @@ impl Binder {
         for option in user_options {
             option.apply(&mut user_option);
         }
+        if let Some(policy) = user_option.network_policy() {
+            if user_info.option.network_policy() != Some(policy) {
+                UserApiProvider::instance()
+                    .get_network_policy(&self.ctx.get_tenant(), policy)
+                    .await?;
+            }
+        }
         let new_user_option = if user_option == user_info.option {
             None
         } else {
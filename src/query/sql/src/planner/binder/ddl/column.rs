@@ -15,19 +15,18 @@
 use common_ast::ast::ShowColumnsStmt;
 use common_ast::ast::ShowLimit;
 use common_exception::Result;
-use tracing::debug;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchemaRefExt;
 
-use crate::normalize_identifier;
 use crate::plans::Plan;
-use crate::plans::RewriteKind;
-use crate::BindContext;
+use crate::plans::ShowColumnsPlan;
 use crate::Binder;
-use crate::SelectBuilder;
 
 impl Binder {
     pub(in crate::planner::binder) async fn bind_show_columns(
         &mut self,
-        bind_context: &mut BindContext,
         stmt: &ShowColumnsStmt,
     ) -> Result<Plan> {
         let ShowColumnsStmt {
@@ -38,73 +37,55 @@ impl Binder {
             limit,
         } = stmt;
 
-        let catalog_name = match catalog {
-            None => self.ctx.get_current_catalog(),
-            Some(ident) => {
-                let catalog = normalize_identifier(ident, &self.name_resolution_ctx).name;
-                self.ctx.get_catalog(&catalog)?;
-                catalog
-            }
-        };
-        let catalog = self.ctx.get_catalog(&catalog_name)?;
-        let database = match database {
-            None => self.ctx.get_current_database(),
-            Some(ident) => {
-                let database = normalize_identifier(ident, &self.name_resolution_ctx).name;
-                catalog
-                    .get_database(&self.ctx.get_tenant(), &database)
-                    .await?;
-                database
-            }
-        };
-
-        let table = {
-            let table = normalize_identifier(table, &self.name_resolution_ctx).name;
-            catalog
-                .get_table(&self.ctx.get_tenant(), database.as_str(), &table)
-                .await?;
-            table
-        };
-
-        let mut select_builder = SelectBuilder::from("information_schema.columns");
-
-        select_builder
-            .with_column("column_name AS `Field`")
-            .with_column("column_type AS `Type`")
-            .with_column("is_nullable AS `Null`")
-            .with_column("default AS `Default`")
-            .with_column("extra AS `Extra`")
-            .with_column("column_key AS `Key`");
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
 
+        // The leading columns are the same as `DESCRIBE TABLE`.
+        let mut fields = vec![
+            DataField::new("column_name", DataType::String),
+            DataField::new("data_type", DataType::String),
+            DataField::new("is_nullable", DataType::String),
+            DataField::new("default", DataType::String),
+            DataField::new("extra", DataType::String),
+            DataField::new("comment", DataType::String),
+            DataField::new("masking_policy", DataType::String.wrap_nullable()),
+            DataField::new(
+                "ndv_estimate",
+                DataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            DataField::new(
+                "null_count",
+                DataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            DataField::new(
+                "data_size_bytes",
+                DataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            DataField::new("last_analyzed_time", DataType::Timestamp.wrap_nullable()),
+        ];
         if *full {
-            select_builder
-                .with_column("collation_name AS `Collation`")
-                .with_column("privileges AS `Privileges`")
-                .with_column("column_comment AS Comment");
+            fields.push(DataField::new(
+                "ordinal_position",
+                DataType::Number(NumberDataType::UInt64),
+            ));
+            fields.push(DataField::new(
+                "character_set",
+                DataType::String.wrap_nullable(),
+            ));
         }
 
-        select_builder
-            .with_order_by("table_schema")
-            .with_order_by("table_name")
-            .with_order_by("column_name");
-
-        select_builder
-            .with_filter(format!("table_schema = '{database}'"))
-            .with_filter(format!("table_name = '{table}'"));
+        let filter = limit.as_ref().map(|limit| match limit {
+            ShowLimit::Like { pattern } => format!("column_name LIKE '{pattern}'"),
+            ShowLimit::Where { selection } => format!("({selection})"),
+        });
 
-        let query = match limit {
-            None => select_builder.build(),
-            Some(ShowLimit::Like { pattern }) => {
-                select_builder.with_filter(format!("column_name LIKE '{pattern}'"));
-                select_builder.build()
-            }
-            Some(ShowLimit::Where { selection }) => {
-                select_builder.with_filter(format!("({selection})"));
-                select_builder.build()
-            }
-        };
-        debug!("show columns rewrite to: {:?}", query);
-        self.bind_rewrite_to_query(bind_context, query.as_str(), RewriteKind::ShowColumns)
-            .await
+        Ok(Plan::ShowColumns(Box::new(ShowColumnsPlan {
+            catalog,
+            database,
+            table,
+            full: *full,
+            filter,
+            schema: DataSchemaRefExt::create(fields),
+        })))
     }
 }
@@ -40,6 +40,7 @@ use crate::plans::Plan;
 use crate::plans::RenameDatabaseEntity;
 use crate::plans::RenameDatabasePlan;
 use crate::plans::RewriteKind;
+use crate::plans::SetDatabaseQuotaPlan;
 use crate::plans::ShowCreateDatabasePlan;
 use crate::plans::UndropDatabasePlan;
 use crate::BindContext;
@@ -71,6 +72,11 @@ impl Binder {
             select_builder.with_column("catalog AS Catalog");
         }
         select_builder.with_column(format!("name AS databases_in_{ctl}"));
+        if *full {
+            select_builder.with_column("max_tables AS Max_tables");
+            select_builder.with_column("max_bytes AS Max_bytes");
+            select_builder.with_column("max_rows AS Max_rows");
+        }
         select_builder.with_order_by("catalog");
         select_builder.with_order_by("name");
         match limit {
@@ -146,6 +152,17 @@ impl Binder {
                     entities: vec![entry],
                 })))
             }
+            AlterDatabaseAction::SetQuota { quota } => {
+                Ok(Plan::SetDatabaseQuota(Box::new(SetDatabaseQuotaPlan {
+                    if_exists: *if_exists,
+                    tenant,
+                    catalog,
+                    database,
+                    max_tables: quota.max_tables,
+                    max_bytes: quota.max_bytes,
+                    max_rows: quota.max_rows,
+                })))
+            }
         }
     }
 
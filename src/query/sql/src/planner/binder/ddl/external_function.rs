@@ -0,0 +1,163 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use chrono::Utc;
+use common_ast::ast::CreateExternalFunctionStmt;
+use common_ast::ast::DropExternalFunctionStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::is_builtin_function;
+use common_meta_app::principal::ExternalFunctionInfo;
+use common_meta_app::principal::ExternalFunctionProtocol;
+use common_meta_app::principal::DEFAULT_EXTERNAL_FUNCTION_CONNECTION_TIMEOUT_MS;
+use common_meta_app::principal::DEFAULT_EXTERNAL_FUNCTION_MAX_RETRIES;
+use common_meta_app::principal::DEFAULT_EXTERNAL_FUNCTION_REQUEST_TIMEOUT_MS;
+use common_users::UserApiProvider;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::planner::semantic::resolve_type_name;
+use crate::plans::CreateExternalFunctionPlan;
+use crate::plans::DropExternalFunctionPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_create_external_function(
+        &mut self,
+        stmt: &CreateExternalFunctionStmt,
+    ) -> Result<Plan> {
+        let CreateExternalFunctionStmt {
+            if_not_exists,
+            name,
+            arguments,
+            return_type,
+            language,
+            handler,
+            address,
+            headers,
+            options,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        if is_builtin_function(&name) {
+            return Err(ErrorCode::ExternalFunctionAlreadyExists(format!(
+                "It's a builtin function: {}",
+                name
+            )));
+        }
+        if UserApiProvider::instance()
+            .get_udf(&tenant, &name)
+            .await
+            .is_ok()
+        {
+            return Err(ErrorCode::ExternalFunctionAlreadyExists(format!(
+                "a UDF named {} already exists",
+                name
+            )));
+        }
+        if !address.starts_with("http://") && !address.starts_with("https://") {
+            return Err(ErrorCode::BadArguments(format!(
+                "the address of external function {} must be an http:// or https:// url, but got '{}'",
+                name, address
+            )));
+        }
+
+        let arguments = arguments
+            .iter()
+            .map(|(arg_name, arg_type)| {
+                Ok((
+                    normalize_identifier(arg_name, &self.name_resolution_ctx).name,
+                    resolve_type_name(arg_type)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut info = ExternalFunctionInfo {
+            name,
+            arguments,
+            return_type: resolve_type_name(return_type)?,
+            language: language.name.to_lowercase(),
+            handler: handler.clone(),
+            address: address.clone(),
+            protocol: ExternalFunctionProtocol::default(),
+            headers: headers.clone(),
+            connection_timeout_ms: DEFAULT_EXTERNAL_FUNCTION_CONNECTION_TIMEOUT_MS,
+            request_timeout_ms: DEFAULT_EXTERNAL_FUNCTION_REQUEST_TIMEOUT_MS,
+            max_retries: DEFAULT_EXTERNAL_FUNCTION_MAX_RETRIES,
+            comment: String::new(),
+            created_on: Utc::now(),
+        };
+        for (key, value) in options.iter() {
+            match key.as_str() {
+                "protocol" => {
+                    info.protocol = ExternalFunctionProtocol::from_str(value)
+                        .map_err(ErrorCode::BadArguments)?
+                }
+                "connection_timeout_ms" => {
+                    info.connection_timeout_ms = parse_option_u64(key, value)?
+                }
+                "request_timeout_ms" => info.request_timeout_ms = parse_option_u64(key, value)?,
+                "max_retries" => info.max_retries = parse_option_u64(key, value)?,
+                "comment" => info.comment = value.clone(),
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Unknown external function option '{}', must be one of {{ PROTOCOL | CONNECTION_TIMEOUT_MS | REQUEST_TIMEOUT_MS | MAX_RETRIES | COMMENT }}",
+                        key
+                    )));
+                }
+            }
+        }
+        if info.connection_timeout_ms == 0 || info.request_timeout_ms == 0 {
+            return Err(ErrorCode::BadArguments(
+                "the timeouts of external function must be greater than 0",
+            ));
+        }
+
+        Ok(Plan::CreateExternalFunction(Box::new(
+            CreateExternalFunctionPlan {
+                if_not_exists: *if_not_exists,
+                tenant,
+                info,
+            },
+        )))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_external_function(
+        &mut self,
+        stmt: &DropExternalFunctionStmt,
+    ) -> Result<Plan> {
+        let DropExternalFunctionStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropExternalFunction(Box::new(
+            DropExternalFunctionPlan {
+                if_exists: *if_exists,
+                tenant: self.ctx.get_tenant(),
+                name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            },
+        )))
+    }
+}
+
+fn parse_option_u64(key: &str, value: &str) -> Result<u64> {
+    value.parse::<u64>().map_err(|_| {
+        ErrorCode::BadArguments(format!(
+            "external function option {} must be a non-negative integer, but got '{}'",
+            key.to_uppercase(),
+            value
+        ))
+    })
+}
@@ -0,0 +1,114 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateMaskingPolicyStmt;
+use common_ast::ast::DropMaskingPolicyStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_meta_app::principal::MaskingPolicy;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::planner::semantic::resolve_type_name;
+use crate::planner::semantic::TypeChecker;
+use crate::plans::CreateMaskingPolicyPlan;
+use crate::plans::DropMaskingPolicyPlan;
+use crate::plans::Plan;
+use crate::BindContext;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_create_masking_policy(
+        &mut self,
+        stmt: &CreateMaskingPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateMaskingPolicyStmt {
+            if_not_exists,
+            name,
+            args,
+            return_type,
+            body,
+            comment,
+        } = stmt;
+
+        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        if args.len() != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "masking policy {} must have exactly one argument, but got {}",
+                name,
+                args.len()
+            )));
+        }
+        let arg_name = normalize_identifier(&args[0].name, &self.name_resolution_ctx).name;
+        let arg_type = DataType::from(&resolve_type_name(&args[0].data_type)?);
+        let return_type = DataType::from(&resolve_type_name(return_type)?);
+        if arg_type.remove_nullable() != return_type.remove_nullable() {
+            return Err(ErrorCode::SemanticError(format!(
+                "the return type {} of masking policy {} must be the same as its argument type {}",
+                return_type, name, arg_type
+            )));
+        }
+
+        // Make sure the body is a valid expression over the argument.
+        let mut bind_context = BindContext::new();
+        let column = self.create_column_binding(None, None, arg_name.clone(), arg_type.clone());
+        bind_context.add_column_binding(column);
+        let mut type_checker = TypeChecker::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let box (_, data_type) = type_checker.resolve(body).await?;
+        if data_type != DataType::Null
+            && data_type.remove_nullable() != return_type.remove_nullable()
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "masking policy {} returns {}, but its body is of type {}",
+                name, return_type, data_type
+            ))
+            .set_span(body.span()));
+        }
+
+        let policy = MaskingPolicy {
+            name,
+            args: vec![(arg_name, arg_type.remove_nullable().to_string())],
+            return_type: return_type.to_string(),
+            body: body.to_string(),
+            comment: comment.clone().unwrap_or_default(),
+            referenced_tables: Default::default(),
+        };
+        Ok(Plan::CreateMaskingPolicy(Box::new(
+            CreateMaskingPolicyPlan {
+                if_not_exists: *if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                policy,
+            },
+        )))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_masking_policy(
+        &mut self,
+        stmt: &DropMaskingPolicyStmt,
+    ) -> Result<Plan> {
+        let DropMaskingPolicyStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropMaskingPolicy(Box::new(DropMaskingPolicyPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        })))
+    }
+}
@@ -16,8 +16,16 @@ mod account;
 mod catalog;
 mod column;
 mod database;
+mod external_function;
+mod masking_policy;
+mod network_policy;
+mod pipe;
 mod role;
+mod row_access_policy;
+mod sequence;
 mod share;
 mod stage;
+mod stream;
 mod table;
+mod task;
 mod view;
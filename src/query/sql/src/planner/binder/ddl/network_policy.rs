@@ -0,0 +1,84 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateNetworkPolicyStmt;
+use common_ast::ast::DescNetworkPolicyStmt;
+use common_ast::ast::DropNetworkPolicyStmt;
+use common_exception::Result;
+
+use crate::binder::Binder;
+use crate::plans::CreateNetworkPolicyPlan;
+use crate::plans::DescNetworkPolicyPlan;
+use crate::plans::DropNetworkPolicyPlan;
+use crate::plans::Plan;
+use crate::plans::ShowNetworkPoliciesPlan;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_create_network_policy(
+        &mut self,
+        stmt: &CreateNetworkPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateNetworkPolicyStmt {
+            if_not_exists,
+            name,
+            allowed_ip_list,
+            blocked_ip_list,
+            comment,
+        } = stmt;
+
+        Ok(Plan::CreateNetworkPolicy(Box::new(
+            CreateNetworkPolicyPlan {
+                if_not_exists: *if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                name: name.clone(),
+                allowed_ip_list: allowed_ip_list.clone(),
+                blocked_ip_list: blocked_ip_list.clone(),
+                comment: comment.clone().unwrap_or_default(),
+            },
+        )))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_network_policy(
+        &mut self,
+        stmt: &DropNetworkPolicyStmt,
+    ) -> Result<Plan> {
+        let DropNetworkPolicyStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropNetworkPolicy(Box::new(DropNetworkPolicyPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_desc_network_policy(
+        &mut self,
+        stmt: &DescNetworkPolicyStmt,
+    ) -> Result<Plan> {
+        let DescNetworkPolicyStmt { name } = stmt;
+
+        Ok(Plan::DescNetworkPolicy(Box::new(DescNetworkPolicyPlan {
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_show_network_policies(&mut self) -> Result<Plan> {
+        Ok(Plan::ShowNetworkPolicies(Box::new(
+            ShowNetworkPoliciesPlan {
+                tenant: self.ctx.get_tenant(),
+            },
+        )))
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use common_ast::ast::CreatePipeStmt;
+use common_ast::ast::DropPipeStmt;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::FileFormatOptions;
+use common_meta_app::principal::OnErrorMode;
+use common_meta_app::principal::PipeSource;
+use common_meta_app::principal::StageFileFormatType;
+
+use crate::binder::Binder;
+use crate::plans::CreatePipePlan;
+use crate::plans::DropPipePlan;
+use crate::plans::Plan;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_create_pipe(
+        &mut self,
+        stmt: &CreatePipeStmt,
+    ) -> Result<Plan> {
+        let CreatePipeStmt {
+            if_not_exists,
+            name,
+            comment,
+            catalog,
+            database,
+            table,
+            kafka_options,
+            on_error,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        if catalog != CATALOG_DEFAULT {
+            return Err(ErrorCode::SemanticError(format!(
+                "Pipe {name} can only load into the tables of the default catalog"
+            )));
+        }
+
+        let mut brokers = None;
+        let mut topic = None;
+        let mut group = name.clone();
+        let mut format = StageFileFormatType::NdJson;
+        for (key, value) in kafka_options {
+            match key.as_str() {
+                "brokers" => {
+                    brokers = Some(
+                        value
+                            .split(',')
+                            .map(|broker| broker.trim().to_string())
+                            .filter(|broker| !broker.is_empty())
+                            .collect::<Vec<_>>(),
+                    )
+                }
+                "topic" => topic = Some(value.clone()),
+                "group" => group = value.clone(),
+                "format" => {
+                    format =
+                        StageFileFormatType::from_str(value).map_err(ErrorCode::SemanticError)?;
+                }
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Unknown kafka option '{key}', must be one of ( brokers | topic | group | format )"
+                    )));
+                }
+            }
+        }
+        let (brokers, topic) = match (brokers, topic) {
+            (Some(brokers), Some(topic)) if !brokers.is_empty() && !topic.is_empty() => {
+                (brokers, topic)
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Pipe {name} needs both the kafka options brokers and topic"
+                )));
+            }
+        };
+        // Every record of the topic is parsed on its own, which only the row based
+        // formats can do.
+        if !matches!(
+            format,
+            StageFileFormatType::Csv | StageFileFormatType::Tsv | StageFileFormatType::NdJson
+        ) {
+            return Err(ErrorCode::SemanticError(format!(
+                "Pipe {name} can't load the format {format:?}, must be one of ( CSV | TSV | NDJSON )"
+            )));
+        }
+
+        let on_error = match on_error {
+            Some(on_error) => OnErrorMode::from_str(on_error).map_err(ErrorCode::SemanticError)?,
+            None => OnErrorMode::default(),
+        };
+        if let OnErrorMode::SkipFileNum(_) = on_error {
+            return Err(ErrorCode::SemanticError(format!(
+                "Pipe {name} loads records rather than files, ON_ERROR must be one of ( CONTINUE | ABORT | ABORT_<num> )"
+            )));
+        }
+
+        Ok(Plan::CreatePipe(Box::new(CreatePipePlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+            database,
+            table,
+            source: PipeSource::Kafka {
+                brokers,
+                topic,
+                group,
+            },
+            file_format_options: FileFormatOptions {
+                format,
+                ..Default::default()
+            },
+            on_error,
+            comment: comment.clone().unwrap_or_default(),
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_pipe(
+        &mut self,
+        stmt: &DropPipeStmt,
+    ) -> Result<Plan> {
+        let DropPipeStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropPipe(Box::new(DropPipePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+        })))
+    }
+}
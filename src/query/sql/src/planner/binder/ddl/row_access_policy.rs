@@ -0,0 +1,127 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateRowAccessPolicyStmt;
+use common_ast::ast::DropRowAccessPolicyStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_meta_app::principal::RowAccessPolicy;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::planner::semantic::resolve_type_name;
+use crate::planner::semantic::TypeChecker;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::Plan;
+use crate::plans::ShowRowAccessPoliciesPlan;
+use crate::BindContext;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_create_row_access_policy(
+        &mut self,
+        stmt: &CreateRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateRowAccessPolicyStmt {
+            if_not_exists,
+            name,
+            args,
+            return_type,
+            body,
+            comment,
+        } = stmt;
+
+        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let return_type = DataType::from(&resolve_type_name(return_type)?);
+        if return_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "row access policy {} must return BOOLEAN, but got {}",
+                name, return_type
+            )));
+        }
+
+        // Make sure the body is a valid predicate over the arguments.
+        let mut bind_context = BindContext::new();
+        let mut policy_args = Vec::with_capacity(args.len());
+        for arg in args {
+            let arg_name = normalize_identifier(&arg.name, &self.name_resolution_ctx).name;
+            if policy_args.iter().any(|(name, _)| name == &arg_name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicate argument {} of row access policy {}",
+                    arg_name, name
+                )));
+            }
+            let arg_type = DataType::from(&resolve_type_name(&arg.data_type)?);
+            let column = self.create_column_binding(None, None, arg_name.clone(), arg_type.clone());
+            bind_context.add_column_binding(column);
+            policy_args.push((arg_name, arg_type.remove_nullable().to_string()));
+        }
+        let mut type_checker = TypeChecker::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let box (_, data_type) = type_checker.resolve(body).await?;
+        if data_type != DataType::Null && data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "row access policy {} returns BOOLEAN, but its body is of type {}",
+                name, data_type
+            ))
+            .set_span(body.span()));
+        }
+
+        let policy = RowAccessPolicy {
+            name,
+            args: policy_args,
+            body: body.to_string(),
+            comment: comment.clone().unwrap_or_default(),
+            referenced_tables: Default::default(),
+        };
+        Ok(Plan::CreateRowAccessPolicy(Box::new(
+            CreateRowAccessPolicyPlan {
+                if_not_exists: *if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                policy,
+            },
+        )))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_row_access_policy(
+        &mut self,
+        stmt: &DropRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DropRowAccessPolicyStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropRowAccessPolicy(Box::new(
+            DropRowAccessPolicyPlan {
+                if_exists: *if_exists,
+                tenant: self.ctx.get_tenant(),
+                name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            },
+        )))
+    }
+
+    pub(in crate::planner::binder) async fn bind_show_row_access_policies(
+        &mut self,
+    ) -> Result<Plan> {
+        Ok(Plan::ShowRowAccessPolicies(Box::new(
+            ShowRowAccessPoliciesPlan {
+                tenant: self.ctx.get_tenant(),
+            },
+        )))
+    }
+}
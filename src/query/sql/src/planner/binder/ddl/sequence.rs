@@ -0,0 +1,112 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use common_ast::ast::CreateSequenceStmt;
+use common_ast::ast::DropSequenceStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::SequenceInfo;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::plans::CreateSequencePlan;
+use crate::plans::DropSequencePlan;
+use crate::plans::Plan;
+use crate::plans::ShowSequencesPlan;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_create_sequence(
+        &mut self,
+        stmt: &CreateSequenceStmt,
+    ) -> Result<Plan> {
+        let CreateSequenceStmt {
+            if_not_exists,
+            name,
+            start,
+            increment,
+            min_value,
+            max_value,
+            cycle,
+        } = stmt;
+
+        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let increment = increment.unwrap_or(1);
+        if increment == 0 {
+            return Err(ErrorCode::SemanticError(format!(
+                "INCREMENT of sequence {} must not be zero",
+                name
+            )));
+        }
+
+        // Ascending sequences default to [1, i64::MAX], descending ones to [i64::MIN, -1].
+        let (default_min, default_max) = if increment > 0 {
+            (1, i64::MAX)
+        } else {
+            (i64::MIN, -1)
+        };
+        let min_value = min_value.unwrap_or(default_min);
+        let max_value = max_value.unwrap_or(default_max);
+        if min_value >= max_value {
+            return Err(ErrorCode::SemanticError(format!(
+                "MINVALUE ({}) of sequence {} must be less than MAXVALUE ({})",
+                min_value, name, max_value
+            )));
+        }
+
+        let start = start.unwrap_or(if increment > 0 { min_value } else { max_value });
+        if start < min_value || start > max_value {
+            return Err(ErrorCode::SemanticError(format!(
+                "START ({}) of sequence {} must be within [{}, {}]",
+                start, name, min_value, max_value
+            )));
+        }
+
+        let info = SequenceInfo {
+            name,
+            start,
+            increment,
+            min_value,
+            max_value,
+            cycle: cycle.unwrap_or(false),
+            current: None,
+            comment: String::new(),
+            created_on: Utc::now(),
+        };
+        Ok(Plan::CreateSequence(Box::new(CreateSequencePlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            info,
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_sequence(
+        &mut self,
+        stmt: &DropSequenceStmt,
+    ) -> Result<Plan> {
+        let DropSequenceStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropSequence(Box::new(DropSequencePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_show_sequences(&mut self) -> Result<Plan> {
+        Ok(Plan::ShowSequences(Box::new(ShowSequencesPlan {
+            tenant: self.ctx.get_tenant(),
+        })))
+    }
+}
@@ -19,6 +19,7 @@ use itertools::Itertools;
 
 use crate::binder::Binder;
 use crate::normalize_identifier;
+use crate::plans::AlterShareCommentPlan;
 use crate::plans::AlterShareTenantsPlan;
 use crate::plans::CreateShareEndpointPlan;
 use crate::plans::CreateSharePlan;
@@ -175,6 +176,7 @@ impl Binder {
             if_exists,
             tenants,
             is_add,
+            read_only,
         } = stmt;
 
         let share = normalize_identifier(share, &self.name_resolution_ctx).name;
@@ -184,10 +186,31 @@ impl Binder {
             if_exists: *if_exists,
             is_add: *is_add,
             accounts: tenants.iter().map(|v| v.to_string()).collect_vec(),
+            read_only: *read_only,
         };
         Ok(Plan::AlterShareTenants(Box::new(plan)))
     }
 
+    pub(in crate::planner::binder) async fn bind_alter_share_comment(
+        &mut self,
+        stmt: &AlterShareCommentStmt,
+    ) -> Result<Plan> {
+        let AlterShareCommentStmt {
+            share,
+            if_exists,
+            comment,
+        } = stmt;
+
+        let share = normalize_identifier(share, &self.name_resolution_ctx).name;
+
+        let plan = AlterShareCommentPlan {
+            share,
+            if_exists: *if_exists,
+            comment: comment.clone(),
+        };
+        Ok(Plan::AlterShareComment(Box::new(plan)))
+    }
+
     pub(in crate::planner::binder) async fn bind_desc_share(
         &mut self,
         stmt: &DescShareStmt,
@@ -202,9 +225,13 @@ impl Binder {
 
     pub(in crate::planner::binder) async fn bind_show_shares(
         &mut self,
-        _stmt: &ShowSharesStmt,
+        stmt: &ShowSharesStmt,
     ) -> Result<Plan> {
-        Ok(Plan::ShowShares(Box::new(ShowSharesPlan {})))
+        let ShowSharesStmt { tenant } = stmt;
+
+        Ok(Plan::ShowShares(Box::new(ShowSharesPlan {
+            tenant: tenant.clone(),
+        })))
     }
 
     pub(in crate::planner::binder) async fn bind_show_object_grant_privileges(
@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
+use common_ast::ast::AlterStageStmt;
 use common_ast::ast::CreateStageStmt;
 use common_ast::ast::UriLocation;
 use common_exception::ErrorCode;
@@ -22,13 +23,21 @@ use common_exception::Result;
 use common_meta_app::principal::FileFormatOptions;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::StageType;
+use common_meta_types::SeqV;
+use common_users::UserApiProvider;
 
 use super::super::copy::parse_stage_location;
 use crate::binder::location::parse_uri_location;
+use crate::binder::location::replace_storage_connection;
 use crate::binder::Binder;
+use crate::plans::AlterStagePlan;
 use crate::plans::CreateStagePlan;
 use crate::plans::Plan;
 use crate::plans::RemoveStagePlan;
+use crate::plans::RenameStagePlan;
+use crate::plans::ShowCreateStagePlan;
+use crate::plans::ShowStageFilesPlan;
 
 impl Binder {
     pub(in crate::planner::binder) async fn bind_remove_stage(
@@ -47,6 +56,119 @@ impl Binder {
         Ok(Plan::RemoveStage(Box::new(plan_node)))
     }
 
+    pub(in crate::planner::binder) async fn bind_show_stage_files(
+        &mut self,
+        location: &str,
+        pattern: &str,
+        limit: Option<u64>,
+    ) -> Result<Plan> {
+        let stage_name = format!("@{location}");
+        let (stage, path) = parse_stage_location(&self.ctx, stage_name.as_str()).await?;
+
+        Ok(Plan::ShowStageFiles(Box::new(ShowStageFilesPlan {
+            tenant: self.ctx.get_tenant(),
+            stage,
+            path,
+            pattern: pattern.to_string(),
+            limit: limit.map(|v| v as usize),
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_alter_stage(
+        &mut self,
+        stmt: &AlterStageStmt,
+    ) -> Result<Plan> {
+        let AlterStageStmt {
+            stage_name,
+            location,
+            connection,
+            file_format_options,
+            copy_options,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let SeqV {
+            seq,
+            data: mut stage_info,
+            ..
+        } = UserApiProvider::instance()
+            .get_stage_with_seq(&tenant, stage_name)
+            .await?;
+
+        if location.is_some() || !connection.is_empty() {
+            if stage_info.stage_type != StageType::External {
+                return Err(ErrorCode::BadArguments(format!(
+                    "stage {stage_name} is not an external stage, there is no location to alter"
+                )));
+            }
+
+            // Like CREATE STAGE, the location is not checked against the storage.
+            if let Some(uri) = location {
+                let (stage_storage, path) = parse_uri_location(&mut uri.clone())?;
+                if !path.ends_with('/') {
+                    return Err(ErrorCode::SyntaxException(
+                        "URL's path must ends with `/` when do ALTER STAGE",
+                    ));
+                }
+                stage_info.stage_params.storage = stage_storage;
+            }
+            if !connection.is_empty() {
+                stage_info.stage_params.storage = replace_storage_connection(
+                    &stage_info.stage_params.storage,
+                    connection.clone(),
+                )?;
+            }
+        }
+
+        if !file_format_options.is_empty() {
+            stage_info.file_format_options =
+                self.try_resolve_file_format(file_format_options).await?;
+        }
+        stage_info.copy_options.apply(copy_options, false)?;
+
+        Ok(Plan::AlterStage(Box::new(AlterStagePlan {
+            tenant,
+            stage_info,
+            seq,
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_rename_stage(
+        &mut self,
+        stage_name: &str,
+        new_stage_name: &str,
+    ) -> Result<Plan> {
+        let tenant = self.ctx.get_tenant();
+        let stage_info = UserApiProvider::instance()
+            .get_stage(&tenant, stage_name)
+            .await?;
+        // The files of the internal stages are stored under the stage name.
+        if stage_info.stage_type != StageType::External {
+            return Err(ErrorCode::BadArguments(format!(
+                "stage {stage_name} is not an external stage, only external stages can be renamed"
+            )));
+        }
+
+        Ok(Plan::RenameStage(Box::new(RenameStagePlan {
+            tenant,
+            stage_info,
+            new_stage_name: new_stage_name.to_string(),
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_show_create_stage(
+        &mut self,
+        stage_name: &str,
+    ) -> Result<Plan> {
+        let stage_info = UserApiProvider::instance()
+            .get_stage(&self.ctx.get_tenant(), stage_name)
+            .await?;
+
+        Ok(Plan::ShowCreateStage(Box::new(ShowCreateStagePlan {
+            stage_info,
+        })))
+    }
+
     pub(in crate::planner::binder) async fn bind_create_stage(
         &mut self,
         stmt: &CreateStageStmt,
@@ -16,9 +16,11 @@ use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_ast::ast::AddColumnOption as AstAddColumnOption;
 use common_ast::ast::AlterTableAction;
 use common_ast::ast::AlterTableStmt;
 use common_ast::ast::AnalyzeTableStmt;
+use common_ast::ast::AttachTableStmt;
 use common_ast::ast::ColumnDefinition;
 use common_ast::ast::CompactTarget;
 use common_ast::ast::CreateTableSource;
@@ -27,9 +29,12 @@ use common_ast::ast::DescribeTableStmt;
 use common_ast::ast::DropTableStmt;
 use common_ast::ast::Engine;
 use common_ast::ast::ExistsTableStmt;
+use common_ast::ast::ExportTableSnapshotStmt;
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
+use common_ast::ast::ImportTableSnapshotStmt;
 use common_ast::ast::Literal;
+use common_ast::ast::ModifyColumnAction as AstModifyColumnAction;
 use common_ast::ast::OptimizeTableAction as AstOptimizeTableAction;
 use common_ast::ast::OptimizeTableStmt;
 use common_ast::ast::RenameTableStmt;
@@ -59,6 +64,7 @@ use common_expression::TableField;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::StageInfo;
 use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
 use common_storages_view::view_table::QUERY;
@@ -66,10 +72,14 @@ use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::is_reserved_opt_key;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use tracing::debug;
 
 use crate::binder::location::parse_uri_location;
+use crate::binder::location::replace_storage_connection;
+use crate::binder::parse_stage_location;
 use crate::binder::scalar::ScalarBinder;
 use crate::binder::Binder;
 use crate::binder::Visibility;
@@ -79,23 +89,35 @@ use crate::optimizer::OptimizerContext;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
+use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::AttachTablePlan;
+use crate::plans::BuildTableIndexPlan;
 use crate::plans::CastExpr;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
+use crate::plans::ExportTableSnapshotPlan;
+use crate::plans::ImportTableSnapshotPlan;
+use crate::plans::ModifyColumnAction;
+use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTableAction;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::Plan;
 use crate::plans::ReclusterTablePlan;
+use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RewriteKind;
+use crate::plans::SetTableAnalyzeIntervalPlan;
+use crate::plans::SetTableConnectionPlan;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UndropTablePlan;
@@ -276,7 +298,7 @@ impl Binder {
         self.bind_statement(bind_context, &stmt).await
     }
 
-    async fn check_database_exist(
+    pub(in crate::planner::binder) async fn check_database_exist(
         &mut self,
         catalog: &Option<Identifier>,
         database: &Option<Identifier>,
@@ -510,6 +532,138 @@ impl Binder {
         Ok(Plan::CreateTable(Box::new(plan)))
     }
 
+    pub(in crate::planner::binder) async fn bind_attach_table(
+        &mut self,
+        stmt: &AttachTableStmt,
+    ) -> Result<Plan> {
+        let AttachTableStmt {
+            catalog,
+            database,
+            table,
+            uri_location,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        // The data of a fuse table is under `<root>/<database_id>/<table_id>/`, and the locations
+        // in the meta of the table are relative to `<root>`, which the storage must be rooted at.
+        let (root, storage_prefix) =
+            split_fuse_table_location(&uri_location.path).ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "'{}://{}{}' is not the location of a fuse table, which ends with <database_id>/<table_id>/",
+                    uri_location.protocol, uri_location.name, uri_location.path
+                ))
+            })?;
+        let mut uri = uri_location.clone();
+        uri.path = root;
+        let (storage_params, _) = parse_uri_location(&mut uri)?;
+
+        // create a temporary op to check if params is correct
+        DataOperator::try_create(&storage_params).await?;
+
+        let db = self
+            .ctx
+            .get_catalog(&catalog)?
+            .get_database(&self.ctx.get_tenant(), &database)
+            .await?;
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_DATABASE_ID.to_owned(),
+            db.get_db_info().ident.db_id.to_string(),
+        );
+        options.insert(OPT_KEY_STORAGE_PREFIX.to_owned(), storage_prefix);
+        options.insert(
+            OPT_KEY_TABLE_ATTACHED_READ_ONLY.to_owned(),
+            "true".to_owned(),
+        );
+
+        Ok(Plan::AttachTable(Box::new(AttachTablePlan {
+            tenant: self.ctx.get_tenant(),
+            catalog,
+            database,
+            table,
+            storage_params,
+            options,
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_export_table_snapshot(
+        &mut self,
+        stmt: &ExportTableSnapshotStmt,
+    ) -> Result<Plan> {
+        let ExportTableSnapshotStmt {
+            catalog,
+            database,
+            table,
+            location,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let (stage_info, path) = self.bind_snapshot_stage_location(location).await?;
+
+        Ok(Plan::ExportTableSnapshot(Box::new(
+            ExportTableSnapshotPlan {
+                catalog,
+                database,
+                table,
+                stage_info,
+                path,
+            },
+        )))
+    }
+
+    pub(in crate::planner::binder) async fn bind_import_table_snapshot(
+        &mut self,
+        stmt: &ImportTableSnapshotStmt,
+    ) -> Result<Plan> {
+        let ImportTableSnapshotStmt {
+            catalog,
+            database,
+            table,
+            location,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let (stage_info, path) = self.bind_snapshot_stage_location(location).await?;
+
+        let db = self
+            .ctx
+            .get_catalog(&catalog)?
+            .get_database(&self.ctx.get_tenant(), &database)
+            .await?;
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_DATABASE_ID.to_owned(),
+            db.get_db_info().ident.db_id.to_string(),
+        );
+
+        Ok(Plan::ImportTableSnapshot(Box::new(
+            ImportTableSnapshotPlan {
+                tenant: self.ctx.get_tenant(),
+                catalog,
+                database,
+                table,
+                stage_info,
+                path,
+                options,
+            },
+        )))
+    }
+
+    /// The snapshots are exported to a folder of a stage, `@<stage>/<path>`.
+    async fn bind_snapshot_stage_location(&self, location: &str) -> Result<(StageInfo, String)> {
+        if !location.starts_with('@') || location.len() == 1 {
+            return Err(ErrorCode::SyntaxException(format!(
+                "location of snapshot must be a stage path like '@<stage>/<path>', but got '{}'",
+                location
+            )));
+        }
+        parse_stage_location(&self.ctx, location).await
+    }
+
     pub(in crate::planner::binder) async fn bind_drop_table(
         &mut self,
         stmt: &DropTableStmt,
@@ -597,10 +751,17 @@ impl Binder {
                     table,
                 })))
             }
-            AlterTableAction::AddColumn { column } => {
+            AlterTableAction::AddColumn { column, option } => {
                 let (schema, field_default_exprs, field_comments) = self
                     .analyze_create_table_schema_by_columns(&[column.clone()])
                     .await?;
+                let option = match option {
+                    AstAddColumnOption::First => AddColumnOption::First,
+                    AstAddColumnOption::After(ident) => AddColumnOption::After(
+                        normalize_identifier(ident, &self.name_resolution_ctx).name,
+                    ),
+                    AstAddColumnOption::End => AddColumnOption::End,
+                };
                 Ok(Plan::AddTableColumn(Box::new(AddTableColumnPlan {
                     catalog,
                     database,
@@ -608,6 +769,45 @@ impl Binder {
                     schema,
                     field_default_exprs,
                     field_comments,
+                    option,
+                })))
+            }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => Ok(Plan::RenameTableColumn(Box::new(RenameTableColumnPlan {
+                catalog,
+                database,
+                table,
+                old_column: normalize_identifier(old_column, &self.name_resolution_ctx).name,
+                new_column: normalize_identifier(new_column, &self.name_resolution_ctx).name,
+            }))),
+            AlterTableAction::ModifyColumn { column, action } => {
+                let action = match action {
+                    AstModifyColumnAction::SetDataType {
+                        data_type,
+                        using,
+                        cascade,
+                    } => ModifyColumnAction::SetDataType {
+                        data_type: resolve_type_name(data_type)?,
+                        using: using.as_ref().map(|using| using.to_string()),
+                        cascade: *cascade,
+                    },
+                    AstModifyColumnAction::SetMaskingPolicy(policy) => {
+                        ModifyColumnAction::SetMaskingPolicy(
+                            normalize_identifier(policy, &self.name_resolution_ctx).name,
+                        )
+                    }
+                    AstModifyColumnAction::UnsetMaskingPolicy => {
+                        ModifyColumnAction::UnsetMaskingPolicy
+                    }
+                };
+                Ok(Plan::ModifyTableColumn(Box::new(ModifyTableColumnPlan {
+                    catalog,
+                    database,
+                    table,
+                    column: normalize_identifier(column, &self.name_resolution_ctx).name,
+                    action,
                 })))
             }
             AlterTableAction::DropColumn { column } => {
@@ -647,7 +847,14 @@ impl Binder {
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
+                limit,
             } => {
+                if *limit == Some(0) {
+                    return Err(ErrorCode::SemanticError(
+                        "LIMIT of RECLUSTER must be greater than 0",
+                    ));
+                }
+
                 let (_, mut context) = self
                     .bind_table_reference(bind_context, table_reference)
                     .await?;
@@ -662,6 +869,7 @@ impl Binder {
 
                 let push_downs = if let Some(expr) = selection {
                     let (scalar, _) = scalar_binder.bind(expr).await?;
+                    self.check_masked_columns(&scalar)?;
                     Some(scalar)
                 } else {
                     None
@@ -675,6 +883,7 @@ impl Binder {
                     is_final: *is_final,
                     metadata: self.metadata.clone(),
                     push_downs,
+                    limit: limit.map(|limit| limit as usize),
                 })))
             }
             AlterTableAction::RevertTo { point } => {
@@ -687,6 +896,72 @@ impl Binder {
                     point,
                 })))
             }
+            AlterTableAction::SetAnalyzeInterval { interval_secs } => Ok(
+                Plan::SetTableAnalyzeInterval(Box::new(SetTableAnalyzeIntervalPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    interval_secs: *interval_secs,
+                })),
+            ),
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => Ok(
+                Plan::AddTableRowAccessPolicy(Box::new(AddTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: normalize_identifier(policy, &self.name_resolution_ctx).name,
+                    columns: columns
+                        .iter()
+                        .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                        .collect(),
+                })),
+            ),
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: normalize_identifier(policy, &self.name_resolution_ctx).name,
+                }),
+            )),
+            AlterTableAction::AddBloomFilterIndex => {
+                Ok(Plan::BuildTableIndex(Box::new(BuildTableIndexPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                })))
+            }
+            AlterTableAction::SetConnection { connection } => {
+                let tbl = self.ctx.get_table(&catalog, &database, &table).await?;
+                let storage_params = match &tbl.get_table_info().meta.storage_params {
+                    Some(sp) => replace_storage_connection(sp, connection.clone())?,
+                    None => {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "table {database}.{table} is not created with its own location, there is no connection to alter"
+                        )));
+                    }
+                };
+                if !storage_params.is_secure() && !GlobalConfig::instance().storage.allow_insecure {
+                    return Err(ErrorCode::StorageInsecure(
+                        "alter table to insecure storage is not allowed",
+                    ));
+                }
+
+                // create a temporary op to check if params is correct
+                DataOperator::try_create(&storage_params).await?;
+
+                Ok(Plan::SetTableConnection(Box::new(SetTableConnectionPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    storage_params,
+                })))
+            }
         }
     }
 
@@ -877,7 +1152,10 @@ impl Binder {
                         self.ctx.get_function_context()?,
                         &BUILTIN_FUNCTIONS,
                     );
-                    if let common_expression::Expr::Constant { .. } = fold_to_constant {
+                    // Per-row defaults such as `uuid()` are evaluated for each inserted row.
+                    if matches!(fold_to_constant, common_expression::Expr::Constant { .. })
+                        || fold_to_constant.contains_per_row_function(&BUILTIN_FUNCTIONS)
+                    {
                         Some(default_expr.to_string())
                     } else {
                         return Err(ErrorCode::SemanticError(format!(
@@ -1008,3 +1286,16 @@ impl Binder {
         Ok(cluster_keys)
     }
 }
+
+/// Splits the location `<root>/<database_id>/<table_id>/` of a fuse table into `<root>/`
+/// and `<database_id>/<table_id>`.
+fn split_fuse_table_location(path: &str) -> Option<(String, String)> {
+    let mut parts = path.trim_end_matches('/').rsplitn(3, '/');
+    let table_id = parts.next()?;
+    let db_id = parts.next()?;
+    let root = parts.next()?;
+    if table_id.parse::<u64>().is_err() || db_id.parse::<u64>().is_err() {
+        return None;
+    }
+    Some((format!("{}/", root), format!("{}/{}", db_id, table_id)))
+}
@@ -0,0 +1,112 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use common_ast::ast::AlterTaskStmt;
+use common_ast::ast::CreateTaskStmt;
+use common_ast::ast::DropTaskStmt;
+use common_ast::ast::ExecuteTaskStmt;
+use common_ast::ast::Statement;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::TaskSchedule;
+
+use crate::binder::Binder;
+use crate::plans::AlterTaskPlan;
+use crate::plans::CreateTaskPlan;
+use crate::plans::DropTaskPlan;
+use crate::plans::ExecuteTaskPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_create_task(
+        &mut self,
+        stmt: &CreateTaskStmt,
+    ) -> Result<Plan> {
+        let CreateTaskStmt {
+            if_not_exists,
+            name,
+            schedule,
+            comment,
+            sql,
+        } = stmt;
+
+        let schedule = TaskSchedule::from_str(schedule).map_err(ErrorCode::SemanticError)?;
+        if matches!(
+            sql.as_ref(),
+            Statement::CreateTask(_)
+                | Statement::AlterTask(_)
+                | Statement::DropTask(_)
+                | Statement::ExecuteTask(_)
+        ) {
+            return Err(ErrorCode::SemanticError(format!(
+                "Task {name} can't run a task statement"
+            )));
+        }
+
+        Ok(Plan::CreateTask(Box::new(CreateTaskPlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+            schedule,
+            database: self.ctx.get_current_database(),
+            comment: comment.clone().unwrap_or_default(),
+            query_text: sql.to_string(),
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_alter_task(
+        &mut self,
+        stmt: &AlterTaskStmt,
+    ) -> Result<Plan> {
+        let AlterTaskStmt {
+            if_exists,
+            name,
+            action,
+        } = stmt;
+
+        Ok(Plan::AlterTask(Box::new(AlterTaskPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+            action: *action,
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_task(
+        &mut self,
+        stmt: &DropTaskStmt,
+    ) -> Result<Plan> {
+        let DropTaskStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropTask(Box::new(DropTaskPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_execute_task(
+        &mut self,
+        stmt: &ExecuteTaskStmt,
+    ) -> Result<Plan> {
+        let ExecuteTaskStmt { name } = stmt;
+
+        Ok(Plan::ExecuteTask(Box::new(ExecuteTaskPlan {
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+        })))
+    }
+}
@@ -63,6 +63,7 @@ impl<'a> Binder {
 
         let (selection, input_expr) = if let Some(expr) = filter {
             let (scalar, _) = scalar_binder.bind(expr).await?;
+            self.check_masked_columns(&scalar)?;
             if let ScalarExpr::SubqueryExpr(_) = scalar {
                 let filter = Filter {
                     predicates: vec![scalar],
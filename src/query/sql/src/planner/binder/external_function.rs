@@ -0,0 +1,237 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Lambda;
+use common_ast::ast::Literal;
+use common_ast::ast::Query;
+use common_ast::ast::SubqueryModifier;
+use common_ast::ast::WindowSpec;
+use common_ast::walk_expr;
+use common_ast::Visitor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::DataType;
+use common_functions::is_builtin_function;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserPrivilegeType;
+use common_users::UserApiProvider;
+
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::planner::semantic::TypeChecker;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::ExternalFunction;
+use crate::plans::ExternalFunctionDesc;
+use crate::plans::ExternalFunctionItem;
+use crate::BindContext;
+use crate::Binder;
+use crate::ColumnBinding;
+use crate::ScalarBinder;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Collects the calls of functions that are not built-in, which may be external functions.
+/// The arguments of the calls are not visited, external functions can't be nested.
+pub struct ExternalFunctionCollector {
+    calls: Vec<Expr>,
+}
+
+impl<'a> Visitor<'a> for ExternalFunctionCollector {
+    fn visit_function_call(
+        &mut self,
+        span: Span,
+        distinct: bool,
+        name: &'a Identifier,
+        args: &'a [Expr],
+        params: &'a [Literal],
+        over: &'a Option<WindowSpec>,
+        lambda: &'a Option<Lambda>,
+    ) {
+        let func_name = name.name.to_lowercase();
+        if over.is_none()
+            && lambda.is_none()
+            && !is_builtin_function(&func_name)
+            && !TypeChecker::all_rewritable_scalar_function().contains(&func_name.as_str())
+        {
+            self.calls.push(Expr::FunctionCall {
+                span,
+                distinct,
+                name: name.clone(),
+                args: args.to_vec(),
+                params: params.to_vec(),
+                window: over.clone(),
+                lambda: lambda.clone(),
+            });
+            return;
+        }
+
+        for arg in args {
+            walk_expr(self, arg);
+        }
+    }
+
+    // The subqueries are bound with their own select lists.
+    fn visit_subquery(
+        &mut self,
+        _span: Span,
+        _modifier: &'a Option<SubqueryModifier>,
+        _subquery: &'a Query,
+    ) {
+    }
+}
+
+impl ExternalFunctionCollector {
+    pub fn new() -> Self {
+        ExternalFunctionCollector { calls: vec![] }
+    }
+
+    pub fn visit(&mut self, expr: &Expr) {
+        self.visit_expr(expr);
+    }
+
+    pub fn into_calls(self) -> Vec<Expr> {
+        self.calls
+    }
+}
+
+impl Binder {
+    /// Bind the calls of external functions in the select list on top of `s_expr`, the calls
+    /// are replaced by the result columns when the select list is bound.
+    pub async fn bind_external_functions(
+        &mut self,
+        bind_context: &mut BindContext,
+        calls: &[Expr],
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let tenant = self.ctx.get_tenant();
+        let mut items = vec![];
+        for call in calls {
+            let (span, name, args) = match call {
+                Expr::FunctionCall {
+                    span, name, args, ..
+                } => (*span, name, args),
+                // Should have been checked by ExternalFunctionCollector
+                _ => unreachable!(),
+            };
+            if bind_context
+                .external_functions
+                .contains_key(&call.to_string())
+            {
+                continue;
+            }
+
+            let func_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+            let info = match UserApiProvider::instance()
+                .get_external_function(&tenant, &func_name)
+                .await
+            {
+                Ok(info) => info,
+                // Not an external function, leave it to the type checker.
+                Err(e) if e.code() == ErrorCode::UNKNOWN_EXTERNAL_FUNCTION => continue,
+                Err(e) => return Err(e),
+            };
+            if !self.external_function_executable() {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied, privilege [Execute] is required to call external function {}",
+                    func_name
+                ))
+                .set_span(span));
+            }
+            if args.len() != info.arguments.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "external function {} requires {} arguments, but got {}",
+                    func_name,
+                    info.arguments.len(),
+                    args.len()
+                ))
+                .set_span(span));
+            }
+
+            let mut arguments = Vec::with_capacity(args.len());
+            for (arg, (_, arg_type)) in args.iter().zip(info.arguments.iter()) {
+                let mut scalar_binder = ScalarBinder::new(
+                    bind_context,
+                    self.ctx.clone(),
+                    &self.name_resolution_ctx,
+                    self.metadata.clone(),
+                    &[],
+                );
+                let (scalar, data_type) = scalar_binder.bind(arg).await?;
+                let arg_type = DataType::from(arg_type);
+                let scalar = if data_type == arg_type {
+                    scalar
+                } else {
+                    CastExpr {
+                        span: arg.span(),
+                        is_try: false,
+                        argument: Box::new(scalar),
+                        target_type: Box::new(arg_type),
+                    }
+                    .into()
+                };
+                arguments.push(scalar);
+            }
+
+            let desc = ExternalFunctionDesc::new(&info);
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(func_name.clone(), desc.return_type.clone());
+            let column = ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name: func_name,
+                index,
+                data_type: Box::new(desc.return_type.clone()),
+                visibility: Visibility::InVisible,
+            };
+            items.push(ExternalFunctionItem {
+                desc,
+                arguments,
+                index,
+            });
+
+            // Add the call to bind context, so we can replace it later.
+            bind_context
+                .external_functions
+                .insert(call.to_string(), BoundColumnRef { span, column }.into());
+        }
+
+        if items.is_empty() {
+            return Ok(s_expr);
+        }
+        Ok(SExpr::create_unary(
+            ExternalFunction { items }.into(),
+            s_expr,
+        ))
+    }
+
+    /// Calling external functions requires the global EXECUTE privilege, which the
+    /// account_admin role has.
+    fn external_function_executable(&self) -> bool {
+        let user = match self.ctx.get_current_user() {
+            Ok(user) => user,
+            Err(_) => return false,
+        };
+        let mut grants = user.grants;
+        if let Some(role) = self.ctx.get_current_role() {
+            grants |= role.grants;
+        }
+        grants.verify_privilege(&GrantObject::Global, vec![UserPrivilegeType::Execute])
+    }
+}
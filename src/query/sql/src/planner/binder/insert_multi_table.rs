@@ -0,0 +1,240 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::ast::InsertIntoClause;
+use common_ast::ast::InsertMultiTableKind;
+use common_ast::ast::InsertMultiTableStmt;
+use common_ast::ast::Statement;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::types::DataType;
+use common_expression::Expr;
+use common_expression::TableSchemaRefExt;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::Binder;
+use crate::binder::ScalarBinder;
+use crate::executor::cast_expr_to_non_null_boolean;
+use crate::normalize_identifier;
+use crate::optimizer::optimize;
+use crate::optimizer::OptimizerConfig;
+use crate::optimizer::OptimizerContext;
+use crate::plans::InsertBranch;
+use crate::plans::InsertMultiTable;
+use crate::plans::InsertTarget;
+use crate::plans::Plan;
+use crate::plans::ScalarExpr;
+use crate::BindContext;
+use crate::IndexType;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_insert_multi_table(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &InsertMultiTableStmt,
+    ) -> Result<Plan> {
+        let InsertMultiTableStmt {
+            kind,
+            when_clauses,
+            else_clause,
+            source,
+        } = stmt;
+
+        let source_plan = self
+            .bind_statement(bind_context, &Statement::Query(source.clone()))
+            .await?;
+        // The conditions and values are bound in the context of the output of the source
+        // query, and evaluated on its output columns.
+        let mut source_context = match &source_plan {
+            Plan::Query { bind_context, .. } => (**bind_context).clone(),
+            _ => {
+                return Err(ErrorCode::Internal(
+                    "source of multi-table insert must be a query",
+                ));
+            }
+        };
+        let offsets = source_context
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(offset, column)| (column.index, offset))
+            .collect::<HashMap<_, _>>();
+
+        let mut branches = Vec::with_capacity(when_clauses.len() + 1);
+        for when_clause in when_clauses {
+            let mut scalar_binder = ScalarBinder::new(
+                &mut source_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let (scalar, _) = scalar_binder.bind(&when_clause.condition).await?;
+            let condition = cast_expr_to_non_null_boolean(lower_source_scalar(&scalar, &offsets)?)?;
+            let targets = self
+                .bind_insert_targets(&mut source_context, &offsets, &when_clause.into_clauses)
+                .await?;
+            branches.push(InsertBranch {
+                condition: Some(condition.as_remote_expr()),
+                targets,
+            });
+        }
+        if let Some(into_clauses) = else_clause {
+            let targets = self
+                .bind_insert_targets(&mut source_context, &offsets, into_clauses)
+                .await?;
+            branches.push(InsertBranch {
+                condition: None,
+                targets,
+            });
+        }
+
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
+        }));
+        let source = optimize(self.ctx.clone(), opt_ctx, source_plan)?;
+
+        let plan = InsertMultiTable {
+            is_first: *kind == InsertMultiTableKind::First,
+            source: Box::new(source),
+            branches,
+        };
+        Ok(Plan::InsertMultiTable(Box::new(plan)))
+    }
+
+    async fn bind_insert_targets(
+        &mut self,
+        source_context: &mut BindContext,
+        offsets: &HashMap<IndexType, usize>,
+        into_clauses: &[InsertIntoClause],
+    ) -> Result<Vec<InsertTarget>> {
+        let mut targets = Vec::with_capacity(into_clauses.len());
+        for into_clause in into_clauses {
+            let InsertIntoClause {
+                catalog,
+                database,
+                table,
+                columns,
+                values,
+            } = into_clause;
+            let (catalog_name, database_name, table_name) =
+                self.normalize_object_identifier_triple(catalog, database, table);
+            let table = self
+                .ctx
+                .get_table(&catalog_name, &database_name, &table_name)
+                .await?;
+
+            let schema = if columns.is_empty() {
+                table.schema()
+            } else {
+                let schema = table.schema();
+                let fields = columns
+                    .iter()
+                    .map(|ident| {
+                        schema
+                            .field_with_name(
+                                &normalize_identifier(ident, &self.name_resolution_ctx).name,
+                            )
+                            .map(|v| v.clone())
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                TableSchemaRefExt::create(fields)
+            };
+
+            let num_values = if values.is_empty() {
+                source_context.columns.len()
+            } else {
+                values.len()
+            };
+            if num_values != schema.num_fields() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "INTO {database_name}.{table_name} expects {} values, but got {num_values}",
+                    schema.num_fields()
+                ))
+                .set_span(into_clause.table.span));
+            }
+
+            // Insert the output columns of the source query as they are if no values
+            // are given.
+            let exprs = if values.is_empty() {
+                source_context
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .map(|(offset, column)| Expr::ColumnRef {
+                        span: None,
+                        id: offset,
+                        data_type: *column.data_type.clone(),
+                        display_name: column.column_name.clone(),
+                    })
+                    .collect()
+            } else {
+                let mut scalar_binder = ScalarBinder::new(
+                    source_context,
+                    self.ctx.clone(),
+                    &self.name_resolution_ctx,
+                    self.metadata.clone(),
+                    &[],
+                );
+                let mut exprs = Vec::with_capacity(values.len());
+                for value in values {
+                    let (scalar, _) = scalar_binder.bind(value).await?;
+                    exprs.push(lower_source_scalar(&scalar, offsets)?);
+                }
+                exprs
+            };
+            let values = exprs
+                .into_iter()
+                .zip(schema.fields())
+                .map(|(expr, field)| {
+                    let data_type = DataType::from(field.data_type());
+                    Ok(
+                        check_cast(expr.span(), false, expr, &data_type, &BUILTIN_FUNCTIONS)?
+                            .as_remote_expr(),
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            targets.push(InsertTarget {
+                catalog: catalog_name,
+                database: database_name,
+                table: table_name,
+                schema,
+                values,
+            });
+        }
+        Ok(targets)
+    }
+}
+
+/// Lower `scalar` to an expression on the output columns of the source query, it can't
+/// reference any other columns, e.g. of subqueries or aggregate functions.
+fn lower_source_scalar(scalar: &ScalarExpr, offsets: &HashMap<IndexType, usize>) -> Result<Expr> {
+    let expr = scalar.as_expr_with_col_index()?;
+    if expr
+        .column_refs()
+        .keys()
+        .any(|index| !offsets.contains_key(index))
+    {
+        return Err(ErrorCode::SemanticError(
+            "conditions and values of multi-table insert can only reference the columns of the source query",
+        )
+        .set_span(scalar.span()));
+    }
+    Ok(expr.project_column_ref(|index| offsets[index]))
+}
@@ -27,6 +27,7 @@ use common_exception::Span;
 use common_expression::type_check::common_super_type;
 use common_functions::BUILTIN_FUNCTIONS;
 
+use crate::binder::ColumnBinding;
 use crate::binder::JoinPredicate;
 use crate::binder::Visibility;
 use crate::normalize_identifier;
@@ -48,6 +49,7 @@ use crate::MetadataRef;
 pub struct JoinConditions {
     pub(crate) left_conditions: Vec<ScalarExpr>,
     pub(crate) right_conditions: Vec<ScalarExpr>,
+    pub(crate) is_null_equal: Vec<usize>,
     pub(crate) non_equi_conditions: Vec<ScalarExpr>,
     pub(crate) other_conditions: Vec<ScalarExpr>,
 }
@@ -85,6 +87,7 @@ impl Binder {
 
         let mut left_join_conditions: Vec<ScalarExpr> = vec![];
         let mut right_join_conditions: Vec<ScalarExpr> = vec![];
+        let mut is_null_equal: Vec<usize> = vec![];
         let mut non_equi_conditions: Vec<ScalarExpr> = vec![];
         let mut other_conditions: Vec<ScalarExpr> = vec![];
         let mut join_condition_resolver = JoinConditionResolver::new(
@@ -101,6 +104,7 @@ impl Binder {
             .resolve(
                 &mut left_join_conditions,
                 &mut right_join_conditions,
+                &mut is_null_equal,
                 &mut non_equi_conditions,
                 &mut other_conditions,
                 &join.op,
@@ -110,6 +114,7 @@ impl Binder {
         let join_conditions = JoinConditions {
             left_conditions: left_join_conditions,
             right_conditions: right_join_conditions,
+            is_null_equal,
             non_equi_conditions,
             other_conditions,
         };
@@ -178,6 +183,7 @@ impl Binder {
     ) -> Result<SExpr> {
         let left_conditions = join_conditions.left_conditions;
         let right_conditions = join_conditions.right_conditions;
+        let is_null_equal = join_conditions.is_null_equal;
         let mut non_equi_conditions = join_conditions.non_equi_conditions;
         let other_conditions = join_conditions.other_conditions;
         if join_type == JoinType::Cross
@@ -196,6 +202,7 @@ impl Binder {
         let logical_join = Join {
             left_conditions,
             right_conditions,
+            is_null_equal,
             non_equi_conditions,
             join_type,
             marker_index: None,
@@ -273,6 +280,18 @@ impl Binder {
     }
 }
 
+// A denied column can't be used as a join key, as it can't be referenced by name.
+fn check_using_column_visibility(column: &ColumnBinding, span: Span) -> Result<()> {
+    if column.visibility == Visibility::Denied {
+        return Err(ErrorCode::PermissionDenied(format!(
+            "Permission denied, SELECT privilege on column {} is not granted",
+            column.column_name
+        ))
+        .set_span(span));
+    }
+    Ok(())
+}
+
 // Wrap nullable for column binding depending on join type.
 fn wrap_nullable_for_column(
     join_type: &JoinOperator,
@@ -396,6 +415,7 @@ impl<'a> JoinConditionResolver<'a> {
         &mut self,
         left_join_conditions: &mut Vec<ScalarExpr>,
         right_join_conditions: &mut Vec<ScalarExpr>,
+        is_null_equal: &mut Vec<usize>,
         non_equi_conditions: &mut Vec<ScalarExpr>,
         other_join_conditions: &mut Vec<ScalarExpr>,
         join_op: &JoinOperator,
@@ -406,6 +426,7 @@ impl<'a> JoinConditionResolver<'a> {
                     cond,
                     left_join_conditions,
                     right_join_conditions,
+                    is_null_equal,
                     non_equi_conditions,
                     other_join_conditions,
                 )
@@ -461,6 +482,7 @@ impl<'a> JoinConditionResolver<'a> {
         condition: &Expr,
         left_join_conditions: &mut Vec<ScalarExpr>,
         right_join_conditions: &mut Vec<ScalarExpr>,
+        is_null_equal: &mut Vec<usize>,
         non_equi_conditions: &mut Vec<ScalarExpr>,
         other_join_conditions: &mut Vec<ScalarExpr>,
     ) -> Result<()> {
@@ -470,6 +492,7 @@ impl<'a> JoinConditionResolver<'a> {
                 expr,
                 left_join_conditions,
                 right_join_conditions,
+                is_null_equal,
                 non_equi_conditions,
                 other_join_conditions,
             )
@@ -489,6 +512,7 @@ impl<'a> JoinConditionResolver<'a> {
         predicate: &Expr,
         left_join_conditions: &mut Vec<ScalarExpr>,
         right_join_conditions: &mut Vec<ScalarExpr>,
+        is_null_equal: &mut Vec<usize>,
         non_equi_conditions: &mut Vec<ScalarExpr>,
         other_join_conditions: &mut Vec<ScalarExpr>,
     ) -> Result<()> {
@@ -510,6 +534,7 @@ impl<'a> JoinConditionResolver<'a> {
         // A predicate can be regarded as an equi-predicate iff:
         //
         //   - The predicate is literally an equivalence expression, e.g. `t1.a = t2.a`
+        //     or `t1.a IS NOT DISTINCT FROM t2.a`
         //   - Each side of `=` only contains columns from one table and the both sides are disjoint.
         //     For example, `t1.a + t1.b = t2.a` is a valid one while `t1.a + t2.a = t2.b` isn't.
         //
@@ -519,6 +544,22 @@ impl<'a> JoinConditionResolver<'a> {
             let (left, _) = scalar_binder.bind(&left).await?;
             let (right, _) = scalar_binder.bind(&right).await?;
             self.add_equi_conditions(left, right, left_join_conditions, right_join_conditions)?
+        } else if let Expr::IsDistinctFrom {
+            left,
+            right,
+            not: true,
+            ..
+        } = predicate
+        {
+            let (left, _) = scalar_binder.bind(left).await?;
+            let (right, _) = scalar_binder.bind(right).await?;
+            let index = left_join_conditions.len();
+            let added =
+                self.add_equi_conditions(left, right, left_join_conditions, right_join_conditions)?;
+            if added {
+                is_null_equal.push(index);
+            }
+            added
         } else {
             false
         };
@@ -555,6 +596,7 @@ impl<'a> JoinConditionResolver<'a> {
                 .iter()
                 .find(|col_binding| col_binding.column_name == join_key_name)
             {
+                check_using_column_visibility(col_binding, *span)?;
                 ScalarExpr::BoundColumnRef(BoundColumnRef {
                     span: *span,
                     column: col_binding.clone(),
@@ -572,6 +614,7 @@ impl<'a> JoinConditionResolver<'a> {
                 .iter()
                 .find(|col_binding| col_binding.column_name == join_key_name)
             {
+                check_using_column_visibility(col_binding, *span)?;
                 ScalarExpr::BoundColumnRef(BoundColumnRef {
                     span: *span,
                     column: col_binding.clone(),
@@ -592,7 +635,10 @@ impl<'a> JoinConditionResolver<'a> {
                 .nth(idx)
             {
                 // Always make the second using column in the join_context invisible in unqualified wildcard.
-                col_binding.visibility = Visibility::UnqualifiedWildcardInVisible;
+                // A denied column stays denied, so it can't be referenced.
+                if col_binding.visibility != Visibility::Denied {
+                    col_binding.visibility = Visibility::UnqualifiedWildcardInVisible;
+                }
             }
 
             self.add_equi_conditions(
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Result;
@@ -348,8 +349,12 @@ pub fn parse_uri_location(l: &mut UriLocation) -> Result<(StorageParams, String)
                     })?
                     .iter()
                     .collect(),
+                token: l.connection.get("token").cloned().unwrap_or_default(),
+                allowed_url_prefixes: vec![],
             };
 
+            l.connection.check()?;
+
             // HTTP is special that we don't support dir, always return / instead.
             return Ok((StorageParams::Http(cfg), "/".to_string()));
         }
@@ -368,3 +373,40 @@ pub fn parse_uri_location(l: &mut UriLocation) -> Result<(StorageParams, String)
 
     Ok((sp, path))
 }
+
+/// replace_storage_connection will rebuild the storage params with the given connection, the
+/// location (bucket and root) is kept.
+///
+/// The connection is replaced as a whole, options that are not given fall back to their defaults.
+pub fn replace_storage_connection(
+    sp: &StorageParams,
+    connection: BTreeMap<String, String>,
+) -> Result<StorageParams> {
+    let (protocol, name, root) = match sp {
+        StorageParams::Azblob(cfg) => ("azblob", &cfg.container, &cfg.root),
+        StorageParams::Gcs(cfg) => ("gcs", &cfg.bucket, &cfg.root),
+        StorageParams::Oss(cfg) => ("oss", &cfg.bucket, &cfg.root),
+        StorageParams::S3(cfg) => ("s3", &cfg.bucket, &cfg.root),
+        v => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                anyhow!("connection of storage {v} is not allowed to be altered"),
+            ));
+        }
+    };
+
+    // The root is used as a directory.
+    let path = match root.trim_matches('/') {
+        "" => "/".to_string(),
+        root => format!("/{root}/"),
+    };
+    let mut l = UriLocation::new(
+        protocol.to_string(),
+        name.to_string(),
+        path,
+        "".to_string(),
+        connection,
+    );
+    let (sp, _) = parse_uri_location(&mut l)?;
+    Ok(sp)
+}
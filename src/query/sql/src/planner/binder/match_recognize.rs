@@ -0,0 +1,767 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::BinaryOperator;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::IntervalKind;
+use common_ast::ast::Lambda;
+use common_ast::ast::Literal;
+use common_ast::ast::MapAccessor;
+use common_ast::ast::MatchPattern;
+use common_ast::ast::MatchRecognize as AstMatchRecognize;
+use common_ast::ast::PatternQuantifier;
+use common_ast::ast::Query;
+use common_ast::ast::RowsPerMatch;
+use common_ast::ast::SubqueryModifier;
+use common_ast::ast::TrimWhere;
+use common_ast::ast::TypeName;
+use common_ast::ast::UnaryOperator;
+use common_ast::ast::WindowSpec;
+use common_ast::walk_expr_mut;
+use common_ast::VisitorMut;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+
+use crate::binder::find_scalars;
+use crate::binder::scalar::ScalarBinder;
+use crate::binder::Binder;
+use crate::binder::ColumnBinding;
+use crate::binder::Visibility;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::EvalScalar;
+use crate::plans::MatchMeasure;
+use crate::plans::MatchMeasureFunction;
+use crate::plans::MatchRecognize;
+use crate::plans::MatchVariable;
+use crate::plans::RowPattern;
+use crate::plans::RowPatternQuantifier;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::Sort;
+use crate::plans::SortItem;
+use crate::BindContext;
+use crate::NameResolutionContext;
+
+impl Binder {
+    /// Bind `t MATCH_RECOGNIZE (...)`, where `input` is the plan of `t`:
+    ///
+    /// ```text
+    /// EvalScalar: the measures
+    ///   MatchRecognize
+    ///     Sort: the partition keys, then the ORDER BY keys
+    ///       EvalScalar: the partition keys, the ORDER BY keys and the DEFINE predicates
+    ///         input
+    /// ```
+    ///
+    /// The measures are rewritten to expressions over the values computed by `MatchRecognize`,
+    /// e.g. `LAST(B.price) - FIRST(A.price)` reads the columns of `LAST(B.price)` and
+    /// `FIRST(A.price)`. A column without `FIRST` or `LAST` reads its last value.
+    pub(super) async fn bind_match_recognize(
+        &mut self,
+        input: SExpr,
+        input_context: &mut BindContext,
+        match_recognize: &AstMatchRecognize,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut variables = vec![];
+        let pattern = self.bind_row_pattern(&match_recognize.pattern, &mut variables);
+
+        let mut scalar_items = vec![];
+        for definition in match_recognize.definitions.iter() {
+            let name = normalize_identifier(&definition.variable, &self.name_resolution_ctx).name;
+            let variable = variables
+                .iter_mut()
+                .find(|variable| variable.name == name)
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "pattern variable {name} in DEFINE is not used in PATTERN"
+                    ))
+                    .set_span(definition.variable.span)
+                })?;
+            if variable.predicate.is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "pattern variable {name} is defined more than once"
+                ))
+                .set_span(definition.variable.span));
+            }
+
+            let (scalar, data_type) = self
+                .bind_match_recognize_expr(input_context, &definition.predicate)
+                .await?;
+            if data_type.remove_nullable() != DataType::Boolean {
+                return Err(ErrorCode::SemanticError(format!(
+                    "DEFINE predicate of {name} must be a boolean expression, but got {data_type}"
+                ))
+                .set_span(definition.predicate.span()));
+            }
+            let column =
+                self.column_of(scalar, data_type, &definition.predicate, &mut scalar_items);
+            variable.predicate = Some(column.index);
+        }
+
+        let default_nulls_first = !self.ctx.get_settings().get_sql_dialect()?.is_null_biggest();
+        let mut partition_by = vec![];
+        let mut partition_columns = vec![];
+        let mut sort_items = vec![];
+        for expr in match_recognize.partition_by.iter() {
+            let (scalar, data_type) = self.bind_match_recognize_expr(input_context, expr).await?;
+            let column = self.column_of(scalar, data_type, expr, &mut scalar_items);
+            partition_by.push(column.index);
+            sort_items.push(SortItem {
+                index: column.index,
+                asc: true,
+                nulls_first: default_nulls_first,
+            });
+            partition_columns.push(column);
+        }
+        for order in match_recognize.order_by.iter() {
+            let (scalar, data_type) = self
+                .bind_match_recognize_expr(input_context, &order.expr)
+                .await?;
+            let column = self.column_of(scalar, data_type, &order.expr, &mut scalar_items);
+            sort_items.push(SortItem {
+                index: column.index,
+                asc: order.asc.unwrap_or(true),
+                nulls_first: order.nulls_first.unwrap_or(default_nulls_first),
+            });
+        }
+
+        let mut s_expr = input;
+        if !scalar_items.is_empty() {
+            s_expr = SExpr::create_unary(
+                EvalScalar {
+                    items: scalar_items,
+                }
+                .into(),
+                s_expr,
+            );
+        }
+        if !sort_items.is_empty() {
+            s_expr = SExpr::create_unary(
+                Sort {
+                    items: sort_items,
+                    limit: None,
+                }
+                .into(),
+                s_expr,
+            );
+        }
+
+        let mut rewriter = MeasureRewriter::new(&variables, &self.name_resolution_ctx);
+        let mut measure_exprs = match_recognize
+            .measures
+            .iter()
+            .map(|measure| measure.expr.clone())
+            .collect::<Vec<_>>();
+        measure_exprs
+            .iter_mut()
+            .for_each(|expr| rewriter.rewrite_expr(expr));
+        let MeasureRewriter { calls, error, .. } = rewriter;
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        // The columns computed by `MatchRecognize`, which the rewritten measures read.
+        let mut measures = vec![];
+        let mut measure_context = BindContext::new();
+        for (i, call) in calls.iter().enumerate() {
+            let column = match &call.column {
+                Some(column) => {
+                    let (scalar, _) = self
+                        .bind_match_recognize_expr(input_context, column)
+                        .await?;
+                    match scalar {
+                        ScalarExpr::BoundColumnRef(column_ref) => Some(column_ref.column),
+                        _ => {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "{} in MEASURES must read a column of the input",
+                                call.expr
+                            ))
+                            .set_span(call.expr.span()));
+                        }
+                    }
+                }
+                None => None,
+            };
+            let (function, data_type) = match (call.kind, column) {
+                (MeasureCallKind::MatchNumber, _) => (
+                    MatchMeasureFunction::MatchNumber,
+                    DataType::Number(NumberDataType::UInt64),
+                ),
+                (MeasureCallKind::Classifier, _) => {
+                    (MatchMeasureFunction::Classifier, DataType::String)
+                }
+                (MeasureCallKind::First, Some(column)) => (
+                    MatchMeasureFunction::First {
+                        variable: call.variable,
+                        column: column.index,
+                    },
+                    column.data_type.wrap_nullable(),
+                ),
+                (MeasureCallKind::Last, Some(column)) => (
+                    MatchMeasureFunction::Last {
+                        variable: call.variable,
+                        column: column.index,
+                    },
+                    column.data_type.wrap_nullable(),
+                ),
+                (MeasureCallKind::Count, column) => (
+                    MatchMeasureFunction::Count {
+                        variable: call.variable,
+                        column: column.map(|column| column.index),
+                    },
+                    DataType::Number(NumberDataType::UInt64),
+                ),
+                (MeasureCallKind::First | MeasureCallKind::Last, None) => unreachable!(),
+            };
+            let column = self.create_column_binding(None, None, call.expr.to_string(), data_type);
+            measures.push(MatchMeasure {
+                function,
+                index: column.index,
+                data_type: *column.data_type.clone(),
+            });
+            measure_context.add_column_binding(ColumnBinding {
+                column_name: measure_column_name(i),
+                ..column
+            });
+        }
+
+        let all_rows_per_match = match_recognize.rows_per_match == RowsPerMatch::AllRows;
+        let mut output_context = input_context.replace();
+        if all_rows_per_match {
+            for column in input_context.columns.iter() {
+                output_context.add_column_binding(column.clone());
+            }
+        } else {
+            for column in partition_columns {
+                output_context.add_column_binding(column);
+            }
+        }
+
+        s_expr = SExpr::create_unary(
+            MatchRecognize {
+                partition_by,
+                variables,
+                pattern,
+                measures,
+                all_rows_per_match,
+            }
+            .into(),
+            s_expr,
+        );
+
+        let mut scalar_items = vec![];
+        for (measure, expr) in match_recognize.measures.iter().zip(measure_exprs.iter()) {
+            let (scalar, data_type) = self
+                .bind_match_recognize_expr(&mut measure_context, expr)
+                .await?;
+            let alias = normalize_identifier(&measure.alias, &self.name_resolution_ctx).name;
+            let index = match scalar {
+                ScalarExpr::BoundColumnRef(column_ref) => column_ref.column.index,
+                scalar => {
+                    let column =
+                        self.create_column_binding(None, None, alias.clone(), data_type.clone());
+                    scalar_items.push(ScalarItem {
+                        scalar,
+                        index: column.index,
+                    });
+                    column.index
+                }
+            };
+            output_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name: alias,
+                index,
+                data_type: Box::new(data_type),
+                visibility: Visibility::Visible,
+            });
+        }
+        if !scalar_items.is_empty() {
+            s_expr = SExpr::create_unary(
+                EvalScalar {
+                    items: scalar_items,
+                }
+                .into(),
+                s_expr,
+            );
+        }
+
+        Ok((s_expr, output_context))
+    }
+
+    /// Collect the variables of the pattern in the order they first appear.
+    fn bind_row_pattern(
+        &self,
+        pattern: &MatchPattern,
+        variables: &mut Vec<MatchVariable>,
+    ) -> RowPattern {
+        match pattern {
+            MatchPattern::Variable(ident) => {
+                let name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+                match variables.iter().position(|variable| variable.name == name) {
+                    Some(variable) => RowPattern::Variable(variable),
+                    None => {
+                        variables.push(MatchVariable {
+                            name,
+                            predicate: None,
+                        });
+                        RowPattern::Variable(variables.len() - 1)
+                    }
+                }
+            }
+            MatchPattern::Concat(patterns) => RowPattern::Concat(
+                patterns
+                    .iter()
+                    .map(|pattern| self.bind_row_pattern(pattern, variables))
+                    .collect(),
+            ),
+            MatchPattern::Alternation(patterns) => RowPattern::Alternation(
+                patterns
+                    .iter()
+                    .map(|pattern| self.bind_row_pattern(pattern, variables))
+                    .collect(),
+            ),
+            MatchPattern::Quantified {
+                pattern,
+                quantifier,
+            } => RowPattern::Quantified {
+                pattern: Box::new(self.bind_row_pattern(pattern, variables)),
+                quantifier: match quantifier {
+                    PatternQuantifier::ZeroOrMore => RowPatternQuantifier::ZeroOrMore,
+                    PatternQuantifier::OneOrMore => RowPatternQuantifier::OneOrMore,
+                    PatternQuantifier::ZeroOrOne => RowPatternQuantifier::ZeroOrOne,
+                },
+            },
+        }
+    }
+
+    /// Bind an expression of `MATCH_RECOGNIZE`, which is evaluated row by row.
+    async fn bind_match_recognize_expr(
+        &mut self,
+        bind_context: &mut BindContext,
+        expr: &Expr,
+    ) -> Result<(ScalarExpr, DataType)> {
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (scalar, data_type) = scalar_binder.bind(expr).await?;
+        if !find_scalars(&scalar, &|scalar: &ScalarExpr| {
+            matches!(
+                scalar,
+                ScalarExpr::AggregateFunction(_)
+                    | ScalarExpr::WindowFunction(_)
+                    | ScalarExpr::SubqueryExpr(_)
+            )
+        })?
+        .is_empty()
+        {
+            return Err(ErrorCode::SemanticError(
+                "aggregate functions, window functions and subqueries are not allowed in MATCH_RECOGNIZE",
+            )
+            .set_span(expr.span()));
+        }
+        Ok((scalar, data_type))
+    }
+
+    /// Return the column of a bound expression, evaluating it in `scalar_items` if it isn't a
+    /// column yet.
+    fn column_of(
+        &mut self,
+        scalar: ScalarExpr,
+        data_type: DataType,
+        expr: &Expr,
+        scalar_items: &mut Vec<ScalarItem>,
+    ) -> ColumnBinding {
+        match scalar {
+            ScalarExpr::BoundColumnRef(column_ref) => column_ref.column,
+            _ => {
+                let column = self.create_column_binding(None, None, expr.to_string(), data_type);
+                scalar_items.push(ScalarItem {
+                    scalar,
+                    index: column.index,
+                });
+                column
+            }
+        }
+    }
+}
+
+fn measure_column_name(i: usize) -> String {
+    format!("__match_measure_{i}")
+}
+
+#[derive(Clone, Copy)]
+enum MeasureCallKind {
+    MatchNumber,
+    Classifier,
+    First,
+    Last,
+    Count,
+}
+
+/// A value computed by `MatchRecognize` for the measures.
+struct MeasureCall {
+    kind: MeasureCallKind,
+    variable: Option<usize>,
+    /// The column without the variable qualifier.
+    column: Option<Expr>,
+    /// The call as written, which names the column.
+    expr: Expr,
+}
+
+/// Rewrites `MATCH_NUMBER()`, `CLASSIFIER()`, `FIRST`, `LAST`, `COUNT` and the column references
+/// in the measures into the columns of `MatchRecognize`.
+///
+/// Like `ConnectByRewriter`, every hook with children is overridden, as the walker only visits
+/// the children of an expression through the hooks. Subqueries are left as they are.
+struct MeasureRewriter<'a> {
+    variables: &'a [MatchVariable],
+    name_resolution_ctx: &'a NameResolutionContext,
+    calls: Vec<MeasureCall>,
+    error: Option<ErrorCode>,
+}
+
+impl<'a> MeasureRewriter<'a> {
+    fn new(variables: &'a [MatchVariable], name_resolution_ctx: &'a NameResolutionContext) -> Self {
+        Self {
+            variables,
+            name_resolution_ctx,
+            calls: vec![],
+            error: None,
+        }
+    }
+
+    fn rewrite_expr(&mut self, expr: &mut Expr) {
+        match self.measure_call(expr) {
+            Some(call) => {
+                let span = expr.span();
+                self.calls.push(call);
+                *expr = Expr::ColumnRef {
+                    span,
+                    database: None,
+                    table: None,
+                    column: Identifier {
+                        name: measure_column_name(self.calls.len() - 1),
+                        quote: None,
+                        span,
+                    },
+                };
+            }
+            None => walk_expr_mut(self, expr),
+        }
+    }
+
+    fn measure_call(&mut self, expr: &Expr) -> Option<MeasureCall> {
+        let (kind, args) = match expr {
+            Expr::ColumnRef { .. } => (MeasureCallKind::Last, std::slice::from_ref(expr)),
+            Expr::CountAll { .. } => (MeasureCallKind::Count, &[][..]),
+            Expr::FunctionCall {
+                distinct: false,
+                name,
+                args,
+                params,
+                window: None,
+                lambda: None,
+                ..
+            } if params.is_empty() => {
+                let kind = match name.name.to_lowercase().as_str() {
+                    "match_number" => MeasureCallKind::MatchNumber,
+                    "classifier" => MeasureCallKind::Classifier,
+                    "first" => MeasureCallKind::First,
+                    "last" => MeasureCallKind::Last,
+                    "count" => MeasureCallKind::Count,
+                    _ => return None,
+                };
+                (kind, args.as_slice())
+            }
+            _ => return None,
+        };
+
+        let expected_args = match kind {
+            MeasureCallKind::MatchNumber | MeasureCallKind::Classifier => 0,
+            MeasureCallKind::Count if args.is_empty() => 0,
+            _ => 1,
+        };
+        let (variable, column) = match args {
+            [] if expected_args == 0 => (None, None),
+            [column @ Expr::ColumnRef { .. }] if expected_args == 1 => {
+                let (variable, column) = self.column_argument(column);
+                (variable, Some(column))
+            }
+            _ => {
+                if self.error.is_none() {
+                    let message = if expected_args == 0 {
+                        format!("{expr} in MEASURES takes no arguments")
+                    } else {
+                        format!("{expr} in MEASURES takes a column, like LAST(A.price)")
+                    };
+                    self.error = Some(ErrorCode::SemanticError(message).set_span(expr.span()));
+                }
+                return None;
+            }
+        };
+        Some(MeasureCall {
+            kind,
+            variable,
+            column,
+            expr: expr.clone(),
+        })
+    }
+
+    /// Split `A.price` into the variable `A` and the column `price`. A qualifier that isn't a
+    /// variable is kept as a table name.
+    fn column_argument(&self, column: &Expr) -> (Option<usize>, Expr) {
+        if let Expr::ColumnRef {
+            span,
+            database: None,
+            table: Some(table),
+            column,
+        } = column
+        {
+            let name = normalize_identifier(table, self.name_resolution_ctx).name;
+            if let Some(variable) = self.variables.iter().position(|v| v.name == name) {
+                let column = Expr::ColumnRef {
+                    span: *span,
+                    database: None,
+                    table: None,
+                    column: column.clone(),
+                };
+                return (Some(variable), column);
+            }
+        }
+        (None, column.clone())
+    }
+}
+
+impl<'a> VisitorMut for MeasureRewriter<'a> {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_is_null(&mut self, _span: Span, expr: &mut Expr, _not: bool) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_is_distinct_from(
+        &mut self,
+        _span: Span,
+        left: &mut Expr,
+        right: &mut Expr,
+        _not: bool,
+    ) {
+        self.rewrite_expr(left);
+        self.rewrite_expr(right);
+    }
+
+    fn visit_in_list(&mut self, _span: Span, expr: &mut Expr, list: &mut [Expr], _not: bool) {
+        self.rewrite_expr(expr);
+        list.iter_mut().for_each(|expr| self.rewrite_expr(expr));
+    }
+
+    fn visit_in_subquery(
+        &mut self,
+        _span: Span,
+        expr: &mut Expr,
+        _subquery: &mut Query,
+        _not: bool,
+    ) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_between(
+        &mut self,
+        _span: Span,
+        expr: &mut Expr,
+        low: &mut Expr,
+        high: &mut Expr,
+        _not: bool,
+    ) {
+        self.rewrite_expr(expr);
+        self.rewrite_expr(low);
+        self.rewrite_expr(high);
+    }
+
+    fn visit_binary_op(
+        &mut self,
+        _span: Span,
+        _op: &mut BinaryOperator,
+        left: &mut Expr,
+        right: &mut Expr,
+    ) {
+        self.rewrite_expr(left);
+        self.rewrite_expr(right);
+    }
+
+    fn visit_unary_op(&mut self, _span: Span, _op: &mut UnaryOperator, expr: &mut Expr) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_cast(
+        &mut self,
+        _span: Span,
+        expr: &mut Expr,
+        _target_type: &mut TypeName,
+        _pg_style: bool,
+    ) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_try_cast(&mut self, _span: Span, expr: &mut Expr, _target_type: &mut TypeName) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_extract(&mut self, _span: Span, _kind: &mut IntervalKind, expr: &mut Expr) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_position(&mut self, _span: Span, substr_expr: &mut Expr, str_expr: &mut Expr) {
+        self.rewrite_expr(substr_expr);
+        self.rewrite_expr(str_expr);
+    }
+
+    fn visit_substring(
+        &mut self,
+        _span: Span,
+        expr: &mut Expr,
+        substring_from: &mut Box<Expr>,
+        substring_for: &mut Option<Box<Expr>>,
+    ) {
+        self.rewrite_expr(expr);
+        self.rewrite_expr(substring_from);
+        if let Some(substring_for) = substring_for {
+            self.rewrite_expr(substring_for);
+        }
+    }
+
+    fn visit_trim(
+        &mut self,
+        _span: Span,
+        expr: &mut Expr,
+        trim_where: &mut Option<(TrimWhere, Box<Expr>)>,
+    ) {
+        self.rewrite_expr(expr);
+        if let Some((_, trim_where_expr)) = trim_where {
+            self.rewrite_expr(trim_where_expr);
+        }
+    }
+
+    fn visit_tuple(&mut self, _span: Span, elements: &mut [Expr]) {
+        elements.iter_mut().for_each(|expr| self.rewrite_expr(expr));
+    }
+
+    fn visit_function_call(
+        &mut self,
+        _span: Span,
+        _distinct: bool,
+        _name: &mut Identifier,
+        args: &mut [Expr],
+        _params: &mut [Literal],
+        over: &mut Option<WindowSpec>,
+        _lambda: &mut Option<Lambda>,
+    ) {
+        args.iter_mut().for_each(|expr| self.rewrite_expr(expr));
+        if let Some(over) = over {
+            over.partition_by
+                .iter_mut()
+                .for_each(|expr| self.rewrite_expr(expr));
+            over.order_by
+                .iter_mut()
+                .for_each(|order_by| self.rewrite_expr(&mut order_by.expr));
+        }
+    }
+
+    fn visit_case_when(
+        &mut self,
+        _span: Span,
+        operand: &mut Option<Box<Expr>>,
+        conditions: &mut [Expr],
+        results: &mut [Expr],
+        else_result: &mut Option<Box<Expr>>,
+    ) {
+        if let Some(operand) = operand {
+            self.rewrite_expr(operand);
+        }
+        conditions
+            .iter_mut()
+            .for_each(|expr| self.rewrite_expr(expr));
+        results.iter_mut().for_each(|expr| self.rewrite_expr(expr));
+        if let Some(else_result) = else_result {
+            self.rewrite_expr(else_result);
+        }
+    }
+
+    fn visit_exists(&mut self, _span: Span, _not: bool, _subquery: &mut Query) {}
+
+    fn visit_subquery(
+        &mut self,
+        _span: Span,
+        _modifier: &mut Option<SubqueryModifier>,
+        _subquery: &mut Query,
+    ) {
+    }
+
+    fn visit_map_access(&mut self, _span: Span, expr: &mut Expr, _accessor: &mut MapAccessor) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_array(&mut self, _span: Span, elements: &mut [Expr]) {
+        elements.iter_mut().for_each(|expr| self.rewrite_expr(expr));
+    }
+
+    fn visit_map(&mut self, _span: Span, kvs: &mut [(Expr, Expr)]) {
+        for (key_expr, val_expr) in kvs {
+            self.rewrite_expr(key_expr);
+            self.rewrite_expr(val_expr);
+        }
+    }
+
+    fn visit_interval(&mut self, _span: Span, expr: &mut Expr, _unit: &mut IntervalKind) {
+        self.rewrite_expr(expr);
+    }
+
+    fn visit_date_add(
+        &mut self,
+        _span: Span,
+        _unit: &mut IntervalKind,
+        interval: &mut Expr,
+        date: &mut Expr,
+    ) {
+        self.rewrite_expr(date);
+        self.rewrite_expr(interval);
+    }
+
+    fn visit_date_sub(
+        &mut self,
+        _span: Span,
+        _unit: &mut IntervalKind,
+        interval: &mut Expr,
+        date: &mut Expr,
+    ) {
+        self.rewrite_expr(date);
+        self.rewrite_expr(interval);
+    }
+
+    fn visit_date_trunc(&mut self, _span: Span, _unit: &mut IntervalKind, date: &mut Expr) {
+        self.rewrite_expr(date);
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Query;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use parking_lot::RwLock;
+
+use crate::binder::Binder;
+use crate::binder::ColumnBinding;
+use crate::binder::CteInfo;
+use crate::binder::MaterializedCteInfo;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::MaterializedCte;
+use crate::plans::MaterializedCteScan;
+use crate::plans::RelOperator;
+use crate::BindContext;
+
+impl Binder {
+    /// Bind the query of a non-recursive cte.
+    ///
+    /// A cte is materialized with `MATERIALIZED`, or if it's referenced more than once and
+    /// its estimated number of rows doesn't exceed `max_auto_materialized_cte_rows`. The query
+    /// of a materialized cte is bound on the first reference, and all the references read
+    /// its stored result, see `MaterializedCteScan`.
+    pub(super) async fn bind_cte_query(
+        &mut self,
+        span: Span,
+        mut bind_context: BindContext,
+        cte_name: &str,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let scan_context = bind_context.replace();
+        let materialized_cte = cte_info.materialized_cte.read().clone();
+        if let Some(materialized_cte) = materialized_cte {
+            return Ok(self.bind_materialized_cte_scan(scan_context, &materialized_cte));
+        }
+
+        let (s_expr, bind_context) = self.bind_query(&mut bind_context, &cte_info.query).await?;
+        let rel_prop = RelExpr::with_s_expr(&s_expr).derive_relational_prop()?;
+        // The stored result is computed once, so the query must not depend on the
+        // referencing query or on the iterations of a recursive cte.
+        let materializable =
+            rel_prop.outer_columns.is_empty() && !contains_recursive_cte_scan(&s_expr);
+        let materialized = match cte_info.materialized {
+            Some(true) if !materializable => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "cte {cte_name} can't be materialized, it references the columns of an outer query or a recursive cte"
+                ))
+                .set_span(span));
+            }
+            Some(materialized) => materialized,
+            None => {
+                let max_rows = self
+                    .ctx
+                    .get_settings()
+                    .get_max_auto_materialized_cte_rows()?;
+                materializable
+                    && cte_info.reference_count > 1
+                    && max_rows > 0
+                    && rel_prop.cardinality <= max_rows as f64
+            }
+        };
+        if !materialized {
+            return Ok((s_expr, bind_context));
+        }
+
+        // Column indexes are unique in a query, so is the table name.
+        let table_name = format!("{cte_name}_{}", self.metadata.read().columns().len());
+        let materialized_cte = MaterializedCteInfo {
+            table_name,
+            s_expr,
+            columns: bind_context.columns,
+            estimated_rows: rel_prop.cardinality as u64,
+        };
+        *cte_info.materialized_cte.write() = Some(materialized_cte.clone());
+        Ok(self.bind_materialized_cte_scan(scan_context, &materialized_cte))
+    }
+
+    /// Bind a reference to a materialized cte, which reads the stored result into
+    /// columns of its own.
+    fn bind_materialized_cte_scan(
+        &self,
+        mut bind_context: BindContext,
+        materialized_cte: &MaterializedCteInfo,
+    ) -> (SExpr, BindContext) {
+        let mut metadata = self.metadata.write();
+        let columns = materialized_cte
+            .columns
+            .iter()
+            .map(|column| {
+                let index = metadata
+                    .add_derived_column(column.column_name.clone(), *column.data_type.clone());
+                ColumnBinding {
+                    index,
+                    ..column.clone()
+                }
+            })
+            .collect::<Vec<_>>();
+        for column in columns.iter() {
+            bind_context.add_column_binding(column.clone());
+        }
+
+        let scan = MaterializedCteScan {
+            table_name: materialized_cte.table_name.clone(),
+            columns: columns.iter().map(|column| column.index).collect(),
+            estimated_rows: materialized_cte.estimated_rows,
+        };
+        (SExpr::create_leaf(scan.into()), bind_context)
+    }
+
+    /// Returns the ctes defined by the `WITH` clause of `query`, whose materialization is
+    /// only known after the body of `query` is bound.
+    pub(super) fn collect_materialized_ctes(
+        &self,
+        bind_context: &BindContext,
+        query: &Query,
+    ) -> Vec<Arc<RwLock<Option<MaterializedCteInfo>>>> {
+        query
+            .with
+            .iter()
+            .flat_map(|with| with.ctes.iter())
+            .filter_map(|cte| {
+                bind_context
+                    .ctes_map
+                    .get(&cte.alias.name.name)
+                    .map(|cte_info| cte_info.materialized_cte.clone())
+            })
+            .collect()
+    }
+
+    /// Compute the materialized ctes before `s_expr`, which reads them. A cte can read
+    /// the ctes defined before it, so the first cte is computed first.
+    pub(super) fn bind_materialized_ctes(
+        &self,
+        materialized_ctes: &[Arc<RwLock<Option<MaterializedCteInfo>>>],
+        mut s_expr: SExpr,
+    ) -> SExpr {
+        for materialized_cte in materialized_ctes.iter().rev() {
+            if let Some(materialized_cte) = materialized_cte.read().as_ref() {
+                let plan = MaterializedCte {
+                    table_name: materialized_cte.table_name.clone(),
+                    columns: materialized_cte
+                        .columns
+                        .iter()
+                        .map(|column| column.index)
+                        .collect(),
+                };
+                s_expr = SExpr::create_binary(plan.into(), materialized_cte.s_expr.clone(), s_expr);
+            }
+        }
+        s_expr
+    }
+}
+
+fn contains_recursive_cte_scan(s_expr: &SExpr) -> bool {
+    matches!(s_expr.plan(), RelOperator::RecursiveCteScan(_))
+        || s_expr.children().iter().any(contains_recursive_cte_scan)
+}
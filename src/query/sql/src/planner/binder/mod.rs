@@ -18,20 +18,26 @@ mod bind_context;
 mod binder;
 /// SQL builders;
 mod builders;
+mod connect_by;
 mod copy;
 mod ddl;
 mod delete;
 mod distinct;
+mod external_function;
 mod having;
 mod insert;
+mod insert_multi_table;
 mod internal_column_factory;
 mod join;
 mod kill;
 mod limit;
 mod location;
+mod match_recognize;
+mod materialized_cte;
 mod presign;
 mod project;
 mod project_set;
+mod recursive_cte;
 mod replace;
 mod scalar;
 mod scalar_common;
@@ -52,6 +58,7 @@ pub use builders::*;
 pub use copy::parse_stage_location;
 pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
 pub use location::parse_uri_location;
+pub use location::replace_storage_connection;
 pub use scalar::ScalarBinder;
 pub use scalar_common::*;
 pub use table::parse_result_scan_args;
@@ -14,6 +14,7 @@
 
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
+use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
 use common_ast::ast::WindowSpec;
 use common_ast::Visitor;
@@ -21,14 +22,18 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
 use common_expression::FunctionKind;
+use common_functions::srfs::FLATTEN_ARGS;
+use common_functions::srfs::FLATTEN_COLUMNS;
 use common_functions::BUILTIN_FUNCTIONS;
 
 use crate::binder::ExprContext;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
 use crate::plans::ProjectSet;
+use crate::plans::ScalarItem;
 use crate::plans::SrfItem;
 use crate::BindContext;
 use crate::Binder;
@@ -50,6 +55,7 @@ impl<'a> Visitor<'a> for SrfCollector {
         args: &'a [Expr],
         params: &'a [Literal],
         over: &'a Option<WindowSpec>,
+        lambda: &'a Option<Lambda>,
     ) {
         if BUILTIN_FUNCTIONS
             .get_property(&name.name)
@@ -64,6 +70,7 @@ impl<'a> Visitor<'a> for SrfCollector {
                 args: args.to_vec(),
                 params: params.to_vec(),
                 window: over.clone(),
+                lambda: lambda.clone(),
             });
         }
     }
@@ -182,4 +189,176 @@ impl Binder {
 
         Ok(SExpr::create_unary(project_set.into(), s_expr))
     }
+
+    /// Bind a set-returning function in `FROM` clause on top of `input`, the columns of
+    /// which can be referenced by the arguments. Each field of the returned tuple is
+    /// exposed as a column.
+    pub(crate) async fn bind_srf_table_function(
+        &mut self,
+        input_context: &mut BindContext,
+        input: SExpr,
+        span: Span,
+        func_name: &str,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+    ) -> Result<(SExpr, Vec<ColumnBinding>)> {
+        let args = srf_table_function_args(span, func_name, params, named_params)?;
+
+        let original_context = input_context.expr_context.clone();
+        input_context.set_expr_context(ExprContext::InSetReturningFunction);
+
+        let mut arguments = Vec::with_capacity(args.len());
+        for arg in args.iter() {
+            let mut scalar_binder = ScalarBinder::new(
+                input_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let (scalar, _) = scalar_binder.bind(arg).await?;
+            arguments.push(scalar);
+        }
+
+        // Restore the original context
+        input_context.set_expr_context(original_context);
+
+        let srf_scalar = ScalarExpr::FunctionCall(FunctionCall {
+            span,
+            func_name: func_name.to_string(),
+            params: vec![],
+            arguments,
+        });
+        let srf_expr = srf_scalar.as_expr_with_col_index()?;
+        let srf_type = srf_expr.data_type().clone();
+        let return_types = srf_type.as_tuple().unwrap().clone();
+
+        let srf_index = self
+            .metadata
+            .write()
+            .add_derived_column(func_name.to_string(), srf_type.clone());
+        let srf_column = ColumnBinding {
+            database_name: None,
+            table_name: None,
+            column_name: func_name.to_string(),
+            index: srf_index,
+            data_type: Box::new(srf_type),
+            visibility: Visibility::InVisible,
+        };
+        let project_set = ProjectSet {
+            srfs: vec![SrfItem {
+                scalar: srf_scalar,
+                index: srf_index,
+            }],
+        };
+        let s_expr = SExpr::create_unary(project_set.into(), input);
+
+        // Flatten the tuple fields of the srf to the top level columns
+        let mut items = Vec::with_capacity(return_types.len());
+        let mut columns = Vec::with_capacity(return_types.len());
+        for (i, data_type) in return_types.into_iter().enumerate() {
+            let column_name = if func_name == "flatten" {
+                FLATTEN_COLUMNS[i].to_string()
+            } else {
+                func_name.to_string()
+            };
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), data_type.clone());
+            items.push(ScalarItem {
+                scalar: ScalarExpr::FunctionCall(FunctionCall {
+                    span,
+                    func_name: "get".to_string(),
+                    params: vec![i + 1],
+                    arguments: vec![ScalarExpr::BoundColumnRef(BoundColumnRef {
+                        span,
+                        column: srf_column.clone(),
+                    })],
+                }),
+                index,
+            });
+            columns.push(ColumnBinding {
+                database_name: None,
+                table_name: Some(func_name.to_string()),
+                column_name,
+                index,
+                data_type: Box::new(data_type),
+                visibility: Visibility::Visible,
+            });
+        }
+        let s_expr = SExpr::create_unary(EvalScalar { items }.into(), s_expr);
+
+        Ok((s_expr, columns))
+    }
+}
+
+/// Resolve the named arguments of a set-returning function in `FROM` clause into
+/// positional ones. Omitted arguments in between are filled with their defaults.
+fn srf_table_function_args(
+    span: Span,
+    func_name: &str,
+    params: &[Expr],
+    named_params: &[(String, Expr)],
+) -> Result<Vec<Expr>> {
+    if named_params.is_empty() {
+        return Ok(params.to_vec());
+    }
+    if func_name != "flatten" {
+        return Err(ErrorCode::SemanticError(format!(
+            "named arguments are not supported by {func_name}"
+        ))
+        .set_span(span));
+    }
+
+    let mut args: Vec<Option<Expr>> = vec![None; FLATTEN_ARGS.len()];
+    if params.len() > args.len() {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "flatten expects at most {} arguments, but got {}",
+            FLATTEN_ARGS.len(),
+            params.len()
+        ))
+        .set_span(span));
+    }
+    for (arg, param) in args.iter_mut().zip(params.iter()) {
+        *arg = Some(param.clone());
+    }
+    for (name, value) in named_params {
+        let pos = FLATTEN_ARGS
+            .iter()
+            .position(|arg| arg.eq_ignore_ascii_case(name))
+            .ok_or_else(|| {
+                ErrorCode::SemanticError(format!("unknown argument {name} of flatten"))
+                    .set_span(span)
+            })?;
+        if args[pos].is_some() {
+            return Err(ErrorCode::SemanticError(format!(
+                "argument {name} of flatten is specified more than once"
+            ))
+            .set_span(span));
+        }
+        args[pos] = Some(value.clone());
+    }
+
+    if args[0].is_none() {
+        return Err(
+            ErrorCode::SemanticError("argument input of flatten is required".to_string())
+                .set_span(span),
+        );
+    }
+    let num_args = args.iter().rposition(|arg| arg.is_some()).unwrap() + 1;
+    Ok(args
+        .into_iter()
+        .take(num_args)
+        .enumerate()
+        .map(|(i, arg)| {
+            arg.unwrap_or_else(|| Expr::Literal {
+                span,
+                lit: match FLATTEN_ARGS[i] {
+                    "path" => Literal::String(String::new()),
+                    _ => Literal::Boolean(false),
+                },
+            })
+        })
+        .collect())
 }
@@ -0,0 +1,287 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::SetExpr;
+use common_ast::ast::SetOperator;
+use common_ast::ast::TableReference;
+use common_ast::walk_query;
+use common_ast::walk_table_reference;
+use common_ast::Visitor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::type_check::common_super_type;
+use common_expression::types::DataType;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::Binder;
+use crate::binder::ColumnBinding;
+use crate::binder::CteInfo;
+use crate::binder::Visibility;
+use crate::binder::WorkingTable;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::RecursiveCte;
+use crate::plans::RecursiveCteScan;
+use crate::plans::RelOperator;
+use crate::BindContext;
+use crate::NameResolutionContext;
+
+impl Binder {
+    /// Returns true if the query of the cte refers to the cte itself.
+    pub(super) fn is_recursive_cte(&self, cte_name: &str, cte_info: &CteInfo) -> bool {
+        let mut counter = CteReferenceCounter::new(cte_name, &self.name_resolution_ctx);
+        walk_query(&mut counter, &cte_info.query);
+        counter.count > 0
+    }
+
+    /// Bind `anchor UNION [ALL] recursive` of a recursive cte.
+    ///
+    /// The recursive member can refer to the cte only once, and the reference reads the rows
+    /// produced by the previous iteration, see `WorkingTable`.
+    pub(super) async fn bind_recursive_cte(
+        &mut self,
+        span: Span,
+        mut bind_context: BindContext,
+        cte_name: &str,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let query = &cte_info.query;
+        let set_operation = match &query.body {
+            SetExpr::SetOperation(set_operation) if set_operation.op == SetOperator::Union => {
+                set_operation
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "recursive cte {cte_name} must be of the form `anchor UNION [ALL] recursive`"
+                ))
+                .set_span(span));
+            }
+        };
+        if !query.order_by.is_empty() || !query.limit.is_empty() || query.offset.is_some() {
+            return Err(ErrorCode::SemanticError(format!(
+                "ORDER BY, LIMIT and OFFSET are not supported in recursive cte {cte_name}"
+            ))
+            .set_span(span));
+        }
+        self.register_ctes(&mut bind_context, query)?;
+        let materialized_ctes = self.collect_materialized_ctes(&bind_context, query);
+
+        let mut counter = CteReferenceCounter::new(cte_name, &self.name_resolution_ctx);
+        counter.visit_set_expr(&set_operation.left);
+        if counter.count > 0 {
+            return Err(ErrorCode::SemanticError(format!(
+                "recursive reference to cte {cte_name} is not allowed in the anchor member"
+            ))
+            .set_span(set_operation.left.span()));
+        }
+        let mut counter = CteReferenceCounter::new(cte_name, &self.name_resolution_ctx);
+        counter.visit_set_expr(&set_operation.right);
+        if counter.count > 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "recursive reference to cte {cte_name} must not appear more than once"
+            ))
+            .set_span(set_operation.right.span()));
+        }
+
+        let (anchor_expr, anchor_context) = self
+            .bind_set_expr(&mut bind_context, &set_operation.left, &[])
+            .await?;
+
+        // The working table takes the types of the anchor member at first. If the recursive
+        // member produces wider types, e.g. `n + 1` of a `UInt8` column, bind it again with
+        // the wider types, so that the rows of later iterations are not truncated.
+        let mut working_types = anchor_context
+            .columns
+            .iter()
+            .map(|column| *column.data_type.clone())
+            .collect::<Vec<_>>();
+        let mut widened = false;
+        let (working_table, recursive_expr, recursive_context) = loop {
+            let working_table =
+                self.create_working_table(cte_name, cte_info, &anchor_context, &working_types);
+            let mut member_context = bind_context.clone();
+            member_context
+                .ctes_map
+                .insert(cte_name.to_string(), CteInfo {
+                    working_table: Some(working_table.clone()),
+                    ..cte_info.clone()
+                });
+            let (recursive_expr, recursive_context) = self
+                .bind_set_expr(&mut member_context, &set_operation.right, &[])
+                .await?;
+
+            if recursive_context.columns.len() != working_types.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "anchor and recursive members of recursive cte {cte_name} must have the same number of columns"
+                ))
+                .set_span(span));
+            }
+            if working_table_under_aggregate(&recursive_expr, false) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "recursive reference to cte {cte_name} is not allowed under aggregation"
+                ))
+                .set_span(set_operation.right.span()));
+            }
+
+            let coercion_types = working_types
+                .iter()
+                .zip(recursive_context.columns.iter())
+                .map(|(working_type, column)| {
+                    common_super_type(
+                        working_type.clone(),
+                        *column.data_type.clone(),
+                        &BUILTIN_FUNCTIONS.default_cast_rules,
+                    )
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(format!(
+                            "column {} of recursive cte {cte_name} has type {working_type} in the anchor member but {} in the recursive member",
+                            column.column_name, column.data_type
+                        ))
+                        .set_span(span)
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if widened || coercion_types == working_types {
+                break (working_table, recursive_expr, recursive_context);
+            }
+            widened = true;
+            working_types = coercion_types;
+        };
+
+        let (new_bind_context, pairs, anchor_expr, recursive_expr) = self.coercion_union_type(
+            set_operation.left.span(),
+            set_operation.right.span(),
+            anchor_context,
+            recursive_context,
+            anchor_expr,
+            recursive_expr,
+            working_types,
+        )?;
+        let (anchor_columns, recursive_columns) = pairs.into_iter().unzip();
+        let recursive_cte = RecursiveCte {
+            table_name: working_table.table_name,
+            anchor_columns,
+            recursive_columns,
+            distinct: !set_operation.all,
+        };
+
+        let s_expr = SExpr::create_binary(recursive_cte.into(), anchor_expr, recursive_expr);
+        Ok((
+            self.bind_materialized_ctes(&materialized_ctes, s_expr),
+            new_bind_context,
+        ))
+    }
+
+    /// Bind the recursive reference in the recursive member of a recursive cte.
+    pub(super) fn bind_working_table(
+        &self,
+        mut bind_context: BindContext,
+        working_table: &WorkingTable,
+    ) -> (SExpr, BindContext) {
+        for column in working_table.columns.iter() {
+            bind_context.add_column_binding(column.clone());
+        }
+        let scan = RecursiveCteScan {
+            table_name: working_table.table_name.clone(),
+            columns: working_table.columns.iter().map(|c| c.index).collect(),
+        };
+        (SExpr::create_leaf(scan.into()), bind_context)
+    }
+
+    fn create_working_table(
+        &self,
+        cte_name: &str,
+        cte_info: &CteInfo,
+        anchor_context: &BindContext,
+        data_types: &[DataType],
+    ) -> WorkingTable {
+        let mut metadata = self.metadata.write();
+        // Column indexes are unique in a query, so is the table name.
+        let table_name = format!("{cte_name}_{}", metadata.columns().len());
+        let columns = anchor_context
+            .columns
+            .iter()
+            .zip(data_types.iter())
+            .enumerate()
+            .map(|(idx, (column, data_type))| {
+                let column_name = cte_info
+                    .columns_alias
+                    .get(idx)
+                    .cloned()
+                    .unwrap_or_else(|| column.column_name.clone());
+                let index = metadata.add_derived_column(column_name.clone(), data_type.clone());
+                ColumnBinding {
+                    database_name: None,
+                    table_name: None,
+                    column_name,
+                    index,
+                    data_type: Box::new(data_type.clone()),
+                    visibility: Visibility::Visible,
+                }
+            })
+            .collect();
+
+        WorkingTable {
+            table_name,
+            columns,
+        }
+    }
+}
+
+fn working_table_under_aggregate(s_expr: &SExpr, under_aggregate: bool) -> bool {
+    let under_aggregate = match s_expr.plan() {
+        RelOperator::RecursiveCteScan(_) => return under_aggregate,
+        RelOperator::Aggregate(_) => true,
+        _ => under_aggregate,
+    };
+    s_expr
+        .children()
+        .iter()
+        .any(|child| working_table_under_aggregate(child, under_aggregate))
+}
+
+/// Counts the references to a cte in a query.
+pub(super) struct CteReferenceCounter<'a> {
+    cte_name: &'a str,
+    name_resolution_ctx: &'a NameResolutionContext,
+    pub(super) count: usize,
+}
+
+impl<'a> CteReferenceCounter<'a> {
+    pub(super) fn new(cte_name: &'a str, name_resolution_ctx: &'a NameResolutionContext) -> Self {
+        Self {
+            cte_name,
+            name_resolution_ctx,
+            count: 0,
+        }
+    }
+}
+
+impl<'a, 'ast> Visitor<'ast> for CteReferenceCounter<'a> {
+    fn visit_table_reference(&mut self, table: &'ast TableReference) {
+        if let TableReference::Table {
+            catalog: None,
+            database: None,
+            table,
+            ..
+        } = table
+        {
+            if normalize_identifier(table, self.name_resolution_ctx).name == self.cte_name {
+                self.count += 1;
+            }
+        }
+        walk_table_reference(self, table);
+    }
+}
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_ast::ast::InsertSource;
 use common_ast::ast::ReplaceStmt;
 use common_ast::ast::Statement;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRefExt;
 use common_meta_app::principal::FileFormatOptions;
@@ -81,9 +82,18 @@ impl Binder {
         let on_conflict_fields = on_conflict_columns
             .iter()
             .map(|ident| {
-                schema
-                    .field_with_name(&normalize_identifier(ident, &self.name_resolution_ctx).name)
-                    .map(|v| v.clone())
+                let field = schema.field_with_name(
+                    &normalize_identifier(ident, &self.name_resolution_ctx).name,
+                )?;
+                // NULL never equals to NULL, rows with NULL keys could not be replaced.
+                if field.is_nullable() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "conflict column {} must not be nullable",
+                        field.name()
+                    ))
+                    .set_span(ident.span));
+                }
+                Ok(field.clone())
             })
             .collect::<Result<Vec<_>>>()?;
 
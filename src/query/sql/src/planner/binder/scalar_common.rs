@@ -26,6 +26,7 @@ use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::ComparisonOp;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::ScalarExpr;
@@ -42,7 +43,6 @@ impl<'a, F> Finder<'a, F>
 where F: Fn(&ScalarExpr) -> bool
 {
     /// Create a new finder with the `test_fn`
-    #[allow(dead_code)]
     fn new(find_fn: &'a F) -> Self {
         Self {
             find_fn,
@@ -67,6 +67,13 @@ where F: Fn(&ScalarExpr) -> bool
     }
 }
 
+/// Find all the sub-expressions of `scalar` that match `find_fn`, the children of a
+/// matched expression will not be visited.
+pub fn find_scalars<F>(scalar: &ScalarExpr, find_fn: &F) -> Result<Vec<ScalarExpr>>
+where F: Fn(&ScalarExpr) -> bool {
+    Ok(Finder::new(find_fn).visit(scalar)?.scalars)
+}
+
 pub fn split_conjunctions(scalar: &ScalarExpr) -> Vec<ScalarExpr> {
     match scalar {
         ScalarExpr::AndExpr(AndExpr { left, right, .. }) => {
@@ -105,6 +112,8 @@ pub enum JoinPredicate<'a> {
     Both {
         left: &'a ScalarExpr,
         right: &'a ScalarExpr,
+        // Whether the predicate is `IS NOT DISTINCT FROM`, in which case NULL keys match each other.
+        is_null_equal: bool,
     },
     Other(&'a ScalarExpr),
 }
@@ -127,20 +136,26 @@ impl<'a> JoinPredicate<'a> {
         }
 
         if let ScalarExpr::ComparisonExpr(ComparisonExpr {
-            op: ComparisonOp::Equal,
+            op: op @ (ComparisonOp::Equal | ComparisonOp::NullSafeEqual),
             left,
             right,
             ..
         }) = scalar
         {
+            let is_null_equal = *op == ComparisonOp::NullSafeEqual;
             if satisfied_by(left, left_prop) && satisfied_by(right, right_prop) {
-                return Self::Both { left, right };
+                return Self::Both {
+                    left,
+                    right,
+                    is_null_equal,
+                };
             }
 
             if satisfied_by(right, left_prop) && satisfied_by(left, right_prop) {
                 return Self::Both {
                     left: right,
                     right: left,
+                    is_null_equal,
                 };
             }
         }
@@ -171,6 +186,7 @@ pub fn contain_subquery(scalar: &ScalarExpr) -> bool {
             arguments.iter().any(contain_subquery)
         }
         ScalarExpr::CastExpr(CastExpr { argument, .. }) => contain_subquery(argument),
+        ScalarExpr::LambdaFunction(LambdaFunc { args, .. }) => args.iter().any(contain_subquery),
         _ => false,
     }
 }
@@ -208,6 +224,10 @@ pub fn prune_by_children(scalar: &ScalarExpr, columns: &HashSet<ScalarExpr>) ->
             .arguments
             .iter()
             .all(|arg| prune_by_children(arg, columns)),
+        ScalarExpr::LambdaFunction(scalar) => scalar
+            .args
+            .iter()
+            .all(|arg| prune_by_children(arg, columns)),
         ScalarExpr::CastExpr(expr) => prune_by_children(expr.argument.as_ref(), columns),
         ScalarExpr::SubqueryExpr(_) => false,
     }
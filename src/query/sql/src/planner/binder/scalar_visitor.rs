@@ -19,6 +19,7 @@ use crate::plans::AndExpr;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::ScalarExpr;
@@ -88,6 +89,11 @@ pub trait ScalarVisitor: Sized {
                                         stack.push(RecursionProcessing::Call(arg));
                                     }
                                 }
+                                ScalarExpr::LambdaFunction(LambdaFunc { args, .. }) => {
+                                    for arg in args.iter() {
+                                        stack.push(RecursionProcessing::Call(arg));
+                                    }
+                                }
                                 ScalarExpr::BoundColumnRef(_)
                                 | ScalarExpr::BoundInternalColumnRef(_)
                                 | ScalarExpr::ConstantExpr(_) => {}
@@ -31,15 +31,19 @@ use common_ast::ast::SelectTarget;
 use common_ast::ast::SetExpr;
 use common_ast::ast::SetOperator;
 use common_ast::ast::TableReference;
+use common_ast::walk_query;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
 use common_expression::type_check::common_super_type;
 use common_expression::types::DataType;
 use common_functions::BUILTIN_FUNCTIONS;
+use tracing::warn;
 
+use crate::binder::external_function::ExternalFunctionCollector;
 use crate::binder::join::JoinConditions;
 use crate::binder::project_set::SrfCollector;
+use crate::binder::recursive_cte::CteReferenceCounter;
 use crate::binder::scalar_common::split_conjunctions;
 use crate::binder::CteInfo;
 use crate::binder::ExprContext;
@@ -79,6 +83,12 @@ impl Binder {
         stmt: &SelectStmt,
         order_by: &[OrderByExpr],
     ) -> Result<(SExpr, BindContext)> {
+        if let Some(connect_by) = &stmt.connect_by {
+            return self
+                .bind_connect_by(bind_context, stmt, connect_by, order_by)
+                .await;
+        }
+
         let (mut s_expr, mut from_context) = if stmt.from.is_empty() {
             self.bind_one_table(bind_context, stmt).await?
         } else {
@@ -129,6 +139,20 @@ impl Binder {
             .bind_project_set(&mut from_context, &set_returning_functions, s_expr)
             .await?;
 
+        // Collect and bind the calls of external functions
+        let external_function_calls = {
+            let mut collector = ExternalFunctionCollector::new();
+            stmt.select_list.iter().for_each(|item| {
+                if let SelectTarget::AliasedExpr { expr, .. } = item {
+                    collector.visit(expr);
+                }
+            });
+            collector.into_calls()
+        };
+        s_expr = self
+            .bind_external_functions(&mut from_context, &external_function_calls, s_expr)
+            .await?;
+
         // Generate a analyzed select list with from context
         let mut select_list = self
             .normalize_select_list(&mut from_context, &stmt.select_list)
@@ -269,27 +293,46 @@ impl Binder {
         }
     }
 
+    pub(super) fn register_ctes(
+        &self,
+        bind_context: &mut BindContext,
+        query: &Query,
+    ) -> Result<()> {
+        let with = match &query.with {
+            Some(with) => with,
+            None => return Ok(()),
+        };
+        for cte in with.ctes.iter() {
+            let table_name = cte.alias.name.name.clone();
+            if bind_context.ctes_map.contains_key(&table_name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicate cte {table_name}"
+                )));
+            }
+            let mut counter = CteReferenceCounter::new(&table_name, &self.name_resolution_ctx);
+            walk_query(&mut counter, query);
+            let cte_info = CteInfo {
+                columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
+                query: cte.query.clone(),
+                recursive: with.recursive,
+                working_table: None,
+                materialized: cte.materialized,
+                reference_count: counter.count,
+                materialized_cte: Default::default(),
+            };
+            bind_context.ctes_map.insert(table_name, cte_info);
+        }
+        Ok(())
+    }
+
     #[async_recursion]
     pub(crate) async fn bind_query(
         &mut self,
         bind_context: &mut BindContext,
         query: &Query,
     ) -> Result<(SExpr, BindContext)> {
-        if let Some(with) = &query.with {
-            for cte in with.ctes.iter() {
-                let table_name = cte.alias.name.name.clone();
-                if bind_context.ctes_map.contains_key(&table_name) {
-                    return Err(ErrorCode::SemanticError(format!(
-                        "duplicate cte {table_name}"
-                    )));
-                }
-                let cte_info = CteInfo {
-                    columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
-                    query: cte.query.clone(),
-                };
-                bind_context.ctes_map.insert(table_name, cte_info);
-            }
-        }
+        self.register_ctes(bind_context, query)?;
+        let materialized_ctes = self.collect_materialized_ctes(bind_context, query);
         let (mut s_expr, bind_context) = match query.body {
             SetExpr::Select(_) | SetExpr::Query(_) => {
                 self.bind_set_expr(bind_context, &query.body, &query.order_by)
@@ -328,6 +371,7 @@ impl Binder {
                 .await?;
         }
 
+        let s_expr = self.bind_materialized_ctes(&materialized_ctes, s_expr);
         Ok((s_expr, bind_context))
     }
 
@@ -382,17 +426,29 @@ impl Binder {
                 .iter()
                 .zip(right_bind_context.columns.iter())
             {
-                if left_col.data_type != right_col.data_type {
-                    let data_type = common_super_type(
-                        *left_col.data_type.clone(),
-                        *right_col.data_type.clone(),
-                        &BUILTIN_FUNCTIONS.default_cast_rules,
-                    )
-                    .expect("SetOperation's types cannot be matched");
-                    coercion_types.push(data_type);
+                let data_type = common_super_type(
+                    *left_col.data_type.clone(),
+                    *right_col.data_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                )
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "SetOperation's column {} has type {} on the left but {} on the right, which cannot be matched",
+                        left_col.column_name, left_col.data_type, right_col.data_type
+                    ))
+                    .set_span(right.span())
+                })?;
+                coercion_types.push(if data_type == DataType::Null {
+                    // Both branches only produce `NULL`, so there is no type to resolve
+                    // the column against. Fall back to `Nullable(String)`.
+                    warn!(
+                        "SetOperation's column {} is NULL in all branches, defaulting to Nullable(String)",
+                        left_col.column_name
+                    );
+                    DataType::String.wrap_nullable()
                 } else {
-                    coercion_types.push(*left_col.data_type.clone());
-                }
+                    data_type
+                });
             }
         }
         match (op, all) {
@@ -565,6 +621,7 @@ impl Binder {
         let join_conditions = JoinConditions {
             left_conditions,
             right_conditions,
+            is_null_equal: vec![],
             non_equi_conditions: vec![],
             other_conditions: vec![],
         };
@@ -574,7 +631,7 @@ impl Binder {
 
     #[allow(clippy::type_complexity)]
     #[allow(clippy::too_many_arguments)]
-    fn coercion_union_type(
+    pub(super) fn coercion_union_type(
         &self,
         left_span: Span,
         right_span: Span,
@@ -740,6 +797,7 @@ impl<'a> SelectRewriter<'a> {
                 args,
                 params: vec![],
                 window: None,
+                lambda: None,
             }),
             alias,
         }
@@ -29,6 +29,7 @@ use crate::planner::semantic::TypeChecker;
 use crate::plans::Plan;
 use crate::plans::SettingPlan;
 use crate::plans::UnSettingPlan;
+use crate::plans::UserVarValue;
 use crate::plans::VarValue;
 
 impl Binder {
@@ -62,7 +63,10 @@ impl Binder {
                     variable,
                     value,
                 }];
-                Ok(Plan::SetVariable(Box::new(SettingPlan { vars })))
+                Ok(Plan::SetVariable(Box::new(SettingPlan {
+                    vars,
+                    user_vars: vec![],
+                })))
             }
             _ => Err(ErrorCode::SemanticError("value must be constant value")),
         }
@@ -77,15 +81,65 @@ impl Binder {
             UnSetSource::Var { variable } => {
                 let variable = variable.name;
                 let vars = vec![variable];
-                Ok(Plan::UnSetVariable(Box::new(UnSettingPlan { vars })))
+                Ok(Plan::UnSetVariable(Box::new(UnSettingPlan {
+                    vars,
+                    user_vars: vec![],
+                })))
             }
             UnSetSource::Vars { variables } => {
                 let mut vars: Vec<String> = vec![];
                 for var in variables {
                     vars.push(var.name.clone());
                 }
-                Ok(Plan::UnSetVariable(Box::new(UnSettingPlan { vars })))
+                Ok(Plan::UnSetVariable(Box::new(UnSettingPlan {
+                    vars,
+                    user_vars: vec![],
+                })))
             }
         }
     }
+
+    pub(in crate::planner::binder) async fn bind_set_user_variable(
+        &mut self,
+        bind_context: &mut BindContext,
+        variable: &str,
+        value: &Expr,
+    ) -> Result<Plan> {
+        let mut type_checker = TypeChecker::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+
+        let (scalar, _) = *type_checker.resolve(value).await?;
+        let expr = scalar.as_expr_with_col_index()?;
+
+        let (new_expr, _) =
+            ConstantFolder::fold(&expr, self.ctx.get_function_context()?, &BUILTIN_FUNCTIONS);
+        match new_expr {
+            common_expression::Expr::Constant { scalar, .. } => {
+                let user_vars = vec![UserVarValue {
+                    variable: variable.to_string(),
+                    value: scalar,
+                }];
+                Ok(Plan::SetVariable(Box::new(SettingPlan {
+                    vars: vec![],
+                    user_vars,
+                })))
+            }
+            _ => Err(ErrorCode::SemanticError("value must be constant value")),
+        }
+    }
+
+    pub(in crate::planner::binder) fn bind_reset_user_variable(
+        &mut self,
+        variable: &str,
+    ) -> Result<Plan> {
+        Ok(Plan::UnSetVariable(Box::new(UnSettingPlan {
+            vars: vec![],
+            user_vars: vec![variable.to_string()],
+        })))
+    }
 }
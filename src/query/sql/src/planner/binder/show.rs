@@ -91,4 +91,27 @@ impl Binder {
         self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowSettings)
             .await
     }
+
+    pub(in crate::planner::binder) async fn bind_show_variables(
+        &mut self,
+        bind_context: &mut BindContext,
+        like: &Option<String>,
+    ) -> Result<Plan> {
+        let sub_query = like
+            .clone()
+            .map(|s| format!("WHERE name LIKE '{s}'"))
+            .unwrap_or_else(|| "".to_string());
+        // User-defined variables are listed with their `@` prefix, before the system settings.
+        let query = format!(
+            "SELECT name, value, type, kind FROM (\
+                SELECT concat('@', name) AS name, value, type, 'USER' AS kind FROM system.user_variables \
+                UNION ALL \
+                SELECT name, value, type, 'SYSTEM' AS kind FROM system.settings\
+            ) AS t {} ORDER BY kind DESC, name",
+            sub_query
+        );
+
+        self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowVariables)
+            .await
+    }
 }
@@ -462,6 +462,14 @@ impl Binder {
                         target_type: target_type.clone(),
                     }))
                 }
+                ScalarExpr::LambdaFunction(lambda) => {
+                    let args = lambda
+                        .args
+                        .iter()
+                        .map(|arg| self.rewrite_scalar_with_replacement(arg, replacement_fn))
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(ScalarExpr::LambdaFunction(lambda.with_args(args)?))
+                }
                 _ => Ok(original_scalar.clone()),
             },
         }
@@ -14,28 +14,43 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::default::Default;
 use std::sync::Arc;
 
 use async_recursion::async_recursion;
 use chrono::TimeZone;
 use chrono::Utc;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
 use common_ast::ast::Indirection;
 use common_ast::ast::Join;
+use common_ast::ast::JoinCondition;
+use common_ast::ast::JoinOperator;
+use common_ast::ast::Query;
+use common_ast::ast::SampleMethod;
 use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::Statement;
+use common_ast::ast::SubqueryModifier;
 use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
+use common_ast::ast::TableSample;
 use common_ast::ast::TimeTravelPoint;
+use common_ast::parser::parse_expr;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
+use common_ast::walk_expr;
 use common_ast::Dialect;
+use common_ast::Visitor;
 use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::SampleInfo;
+use common_catalog::plan::SampleMethod as SampleInfoMethod;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
+use common_catalog::table::TableStatistics;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_function::TableFunction;
 use common_exception::ErrorCode;
@@ -47,30 +62,44 @@ use common_expression::ConstantFolder;
 use common_expression::FunctionKind;
 use common_expression::Scalar;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::UserPrivilegeType;
+use common_meta_app::schema::ExternalTableStatistics;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
 use common_storages_parquet::ParquetTable;
+use common_storages_result_cache::gen_result_persist_dir;
 use common_storages_result_cache::ResultCacheMetaManager;
 use common_storages_result_cache::ResultCacheReader;
+use common_storages_result_cache::ResultPersistManifest;
 use common_storages_result_cache::ResultScan;
 use common_storages_view::view_table::QUERY;
 use common_users::UserApiProvider;
 use dashmap::DashMap;
 
 use crate::binder::copy::parse_file_location;
+use crate::binder::join::check_duplicate_join_tables;
 use crate::binder::scalar::ScalarBinder;
+use crate::binder::split_conjunctions;
 use crate::binder::table_args::bind_table_args;
+use crate::binder::wrap_cast;
 use crate::binder::Binder;
 use crate::binder::ColumnBinding;
 use crate::binder::CteInfo;
 use crate::binder::ExprContext;
 use crate::binder::Visibility;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::Apply;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::JoinType;
+use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Statistics;
 use crate::BaseTableColumn;
@@ -78,6 +107,8 @@ use crate::BindContext;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
 use crate::IndexType;
+use crate::MaskedColumn;
+use crate::TableEntry;
 use crate::TableInternalColumn;
 
 impl Binder {
@@ -101,6 +132,14 @@ impl Binder {
                 }
             }
         }
+        self.bind_dummy_table(bind_context).await
+    }
+
+    /// Bind `system.one`, which has a single row, as the input of a query without `FROM`.
+    async fn bind_dummy_table(
+        &mut self,
+        bind_context: &BindContext,
+    ) -> Result<(SExpr, BindContext)> {
         let catalog = CATALOG_DEFAULT;
         let database = "system";
         let tenant = self.ctx.get_tenant();
@@ -115,7 +154,7 @@ impl Binder {
             false,
         );
 
-        self.bind_base_table(bind_context, database, table_index)
+        self.bind_base_table(bind_context, database, table_index, None)
             .await
     }
 
@@ -152,9 +191,38 @@ impl Binder {
                 table,
                 alias,
                 travel_point,
+                sample,
                 pivot: _,
                 unpivot: _,
+                match_recognize,
             } => {
+                if let Some(match_recognize) = match_recognize {
+                    // The alias names the result of MATCH_RECOGNIZE, not the table.
+                    let mut input_ref = table_ref.clone();
+                    if let TableReference::Table {
+                        alias,
+                        match_recognize,
+                        ..
+                    } = &mut input_ref
+                    {
+                        *alias = None;
+                        *match_recognize = None;
+                    }
+                    let (s_expr, mut input_context) =
+                        self.bind_single_table(bind_context, &input_ref).await?;
+                    let (s_expr, mut bind_context) = self
+                        .bind_match_recognize(s_expr, &mut input_context, match_recognize)
+                        .await?;
+                    if let Some(alias) = alias {
+                        bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+                    }
+                    return Ok((s_expr, bind_context));
+                }
+                let sample = sample
+                    .as_ref()
+                    .map(|sample| Self::bind_table_sample(*span, sample))
+                    .transpose()?;
+
                 let (catalog, database, table_name) =
                     self.normalize_object_identifier_triple(catalog, database, table);
                 let table_alias_name = if let Some(table_alias) = alias {
@@ -164,6 +232,12 @@ impl Binder {
                 };
                 // Check and bind common table expression
                 if let Some(cte_info) = bind_context.ctes_map.get(&table_name) {
+                    if sample.is_some() {
+                        return Err(ErrorCode::SemanticError(
+                            "TABLESAMPLE is not supported for common table expressions",
+                        )
+                        .set_span(*span));
+                    }
                     return self
                         .bind_cte(*span, bind_context, &table_name, alias, &cte_info)
                         .await;
@@ -214,6 +288,12 @@ impl Binder {
 
                 match table_meta.engine() {
                     "VIEW" => {
+                        if sample.is_some() {
+                            return Err(ErrorCode::SemanticError(
+                                "TABLESAMPLE is not supported for views",
+                            )
+                            .set_span(*span));
+                        }
                         Self::check_view_dep(bind_context, &database, &table_name)?;
                         let query = table_meta
                             .options()
@@ -258,7 +338,19 @@ impl Binder {
                             .set_span(*span))
                         }
                     }
-                    _ => {
+                    engine => {
+                        if let Some(SampleInfo {
+                            method: SampleInfoMethod::System,
+                            ..
+                        }) = sample
+                        {
+                            if !table_meta.support_block_sample() {
+                                return Err(ErrorCode::Unimplemented(format!(
+                                    "TABLESAMPLE SYSTEM is not supported by the {engine} engine, use TABLESAMPLE BERNOULLI instead"
+                                ))
+                                .set_span(*span));
+                            }
+                        }
                         let table_index = self.metadata.write().add_table(
                             catalog,
                             database.clone(),
@@ -268,7 +360,7 @@ impl Binder {
                         );
 
                         let (s_expr, mut bind_context) = self
-                            .bind_base_table(bind_context, database.as_str(), table_index)
+                            .bind_base_table(bind_context, database.as_str(), table_index, sample)
                             .await?;
                         if let Some(alias) = alias {
                             bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
@@ -283,7 +375,35 @@ impl Binder {
                 params,
                 named_params,
                 alias,
+                ..
             } => {
+                let func_name = normalize_identifier(name, &self.name_resolution_ctx);
+
+                if func_name.name.eq_ignore_ascii_case("flatten") {
+                    // `flatten` returns multiple columns, so it's bound on top of a
+                    // dummy table instead of being rewritten into a subquery.
+                    let (input_expr, mut input_context) =
+                        self.bind_dummy_table(bind_context).await?;
+                    let (s_expr, columns) = self
+                        .bind_srf_table_function(
+                            &mut input_context,
+                            input_expr,
+                            *span,
+                            &func_name.name.to_lowercase(),
+                            params,
+                            named_params,
+                        )
+                        .await?;
+                    let mut bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+                    for column in columns {
+                        bind_context.add_column_binding(column);
+                    }
+                    if let Some(alias) = alias {
+                        bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+                    }
+                    return Ok((s_expr, bind_context));
+                }
+
                 let mut scalar_binder = ScalarBinder::new(
                     bind_context,
                     self.ctx.clone(),
@@ -293,8 +413,6 @@ impl Binder {
                 );
                 let table_args = bind_table_args(&mut scalar_binder, params, named_params).await?;
 
-                let func_name = normalize_identifier(name, &self.name_resolution_ctx);
-
                 if func_name.name.eq_ignore_ascii_case("result_scan") {
                     let query_id = parse_result_scan_args(&table_args)?;
                     if query_id.is_empty() {
@@ -303,32 +421,7 @@ impl Binder {
                         )
                         .set_span(*span));
                     }
-                    let kv_store = UserApiProvider::instance().get_meta_store_client();
-                    let meta_key = self.ctx.get_result_cache_key(&query_id);
-                    if meta_key.is_none() {
-                        return Err(ErrorCode::EmptyData(format!(
-                            "`RESULT_SCAN` could not find related cache key in current session for this query id: {query_id}"
-                        )).set_span(*span));
-                    }
-                    let result_cache_mgr = ResultCacheMetaManager::create(kv_store, 0);
-                    let meta_key = meta_key.unwrap();
-                    let (table_schema, block_raw_data) = match result_cache_mgr
-                        .get(meta_key.clone())
-                        .await?
-                    {
-                        Some(value) => {
-                            let op = DataOperator::instance().operator();
-                            ResultCacheReader::read_table_schema_and_data(op, &value.location)
-                                .await?
-                        }
-                        None => {
-                            return Err(ErrorCode::EmptyData(format!(
-                                "`RESULT_SCAN` could not fetch cache value, maybe the data has touched ttl and was cleaned up.\n\
-                            query id: {query_id}, cache key: {meta_key}"
-                            )).set_span(*span));
-                        }
-                    };
-                    let table = ResultScan::try_create(table_schema, query_id, block_raw_data)?;
+                    let table = self.result_scan_table(*span, query_id).await?;
 
                     let table_alias_name = if let Some(table_alias) = alias {
                         Some(
@@ -347,7 +440,7 @@ impl Binder {
                     );
 
                     let (s_expr, mut bind_context) = self
-                        .bind_base_table(bind_context, "system", table_index)
+                        .bind_base_table(bind_context, "system", table_index, None)
                         .await?;
                     if let Some(alias) = alias {
                         bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
@@ -377,6 +470,7 @@ impl Binder {
                                 params: vec![],
                                 args: params.clone(),
                                 window: None,
+                                lambda: None,
                             }),
                             alias: None,
                         }],
@@ -384,6 +478,7 @@ impl Binder {
                         selection: None,
                         group_by: None,
                         having: None,
+                        connect_by: None,
                     };
                     self.bind_select_stmt(&mut bind_context, &stmt, &[]).await
                 } else {
@@ -409,7 +504,7 @@ impl Binder {
                     );
 
                     let (s_expr, mut bind_context) = self
-                        .bind_base_table(bind_context, "system", table_index)
+                        .bind_base_table(bind_context, "system", table_index, None)
                         .await?;
                     if let Some(alias) = alias {
                         bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
@@ -418,9 +513,7 @@ impl Binder {
                 }
             }
             TableReference::Subquery {
-                span: _,
-                subquery,
-                alias,
+                subquery, alias, ..
             } => {
                 // For subquery, we need use a new context to bind it.
                 let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
@@ -432,11 +525,16 @@ impl Binder {
                 Ok((s_expr, new_bind_context))
             }
             TableReference::Stage {
-                span: _,
+                span,
                 location,
                 options,
                 alias,
+                sample,
             } => {
+                let sample = sample
+                    .as_ref()
+                    .map(|sample| Self::bind_table_sample(*span, sample))
+                    .transpose()?;
                 let (mut stage_info, path) =
                     parse_file_location(&self.ctx, location, options.connection.clone()).await?;
                 if let Some(f) = &options.file_format {
@@ -447,7 +545,7 @@ impl Binder {
                     pattern: options.pattern.clone(),
                     files: options.files.clone(),
                 };
-                self.bind_stage_table(bind_context, stage_info, files_info, alias, None)
+                self.bind_stage_table(bind_context, stage_info, files_info, alias, sample, None)
                     .await
             }
             TableReference::Join { .. } => unreachable!(),
@@ -460,6 +558,7 @@ impl Binder {
         stage_info: StageInfo,
         files_info: StageFilesInfo,
         alias: &Option<TableAlias>,
+        sample: Option<SampleInfo>,
         files_to_copy: Option<Vec<StageFileInfo>>,
     ) -> Result<(SExpr, BindContext)> {
         if matches!(
@@ -487,7 +586,7 @@ impl Binder {
             );
 
             let (s_expr, mut bind_context) = self
-                .bind_base_table(bind_context, "system", table_index)
+                .bind_base_table(bind_context, "system", table_index, sample)
                 .await?;
             if let Some(alias) = alias {
                 bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
@@ -551,6 +650,33 @@ impl Binder {
                     result_expr = join_expr;
                     result_ctx = ctx;
                 }
+                TableReference::TableFunction {
+                    lateral,
+                    name,
+                    params,
+                    named_params,
+                    ..
+                } if *lateral
+                    || self.references_columns(
+                        name,
+                        params
+                            .iter()
+                            .chain(named_params.iter().map(|(_, param)| param)),
+                    ) =>
+                {
+                    let (join_expr, ctx) = self
+                        .bind_lateral_table_function(result_ctx, result_expr, join)
+                        .await?;
+                    result_expr = join_expr;
+                    result_ctx = ctx;
+                }
+                TableReference::Subquery { lateral: true, .. } => {
+                    let (join_expr, ctx) = self
+                        .bind_lateral_subquery(result_ctx, result_expr, join)
+                        .await?;
+                    result_expr = join_expr;
+                    result_ctx = ctx;
+                }
                 _ => {
                     let (right_expr, right_ctx) =
                         self.bind_single_table(current_ctx, &join.right).await?;
@@ -573,6 +699,189 @@ impl Binder {
         Ok((result_expr, result_ctx))
     }
 
+    /// Bind a `LATERAL` table function on top of the left side of `join`, so that
+    /// the arguments of the function can reference the columns of the left side.
+    async fn bind_lateral_table_function(
+        &mut self,
+        mut left_context: BindContext,
+        left_expr: SExpr,
+        join: &Join,
+    ) -> Result<(SExpr, BindContext)> {
+        let (span, name, params, named_params, alias) = match &*join.right {
+            TableReference::TableFunction {
+                span,
+                name,
+                params,
+                named_params,
+                alias,
+                ..
+            } => (span, name, params, named_params, alias),
+            _ => unreachable!(),
+        };
+
+        if join.op != JoinOperator::CrossJoin || join.condition != JoinCondition::None {
+            return Err(ErrorCode::SemanticError(
+                "LATERAL table function can only be used in cross join".to_string(),
+            )
+            .set_span(*span));
+        }
+
+        let func_name = normalize_identifier(name, &self.name_resolution_ctx)
+            .name
+            .to_lowercase();
+        if !BUILTIN_FUNCTIONS
+            .get_property(&func_name)
+            .map(|p| p.kind == FunctionKind::SRF)
+            .unwrap_or(false)
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "LATERAL is not supported for table function {func_name}"
+            ))
+            .set_span(*span));
+        }
+
+        let (s_expr, columns) = self
+            .bind_srf_table_function(
+                &mut left_context,
+                left_expr,
+                *span,
+                &func_name,
+                params,
+                named_params,
+            )
+            .await?;
+
+        let mut srf_context = BindContext::new();
+        for column in columns {
+            srf_context.add_column_binding(column);
+        }
+        if let Some(alias) = alias {
+            srf_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+        for column in srf_context.columns {
+            left_context.add_column_binding(column);
+        }
+
+        Ok((s_expr, left_context))
+    }
+
+    /// Returns true if `name` is a set-returning function and its arguments reference
+    /// columns, e.g. `unnest(t.arr)`. Such a table function is implicitly `LATERAL`,
+    /// the columns can only come from the preceding tables.
+    fn references_columns<'a>(
+        &self,
+        name: &Identifier,
+        params: impl Iterator<Item = &'a Expr>,
+    ) -> bool {
+        let func_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let is_srf = BUILTIN_FUNCTIONS
+            .get_property(&func_name.to_lowercase())
+            .map(|p| p.kind == FunctionKind::SRF)
+            .unwrap_or(false);
+        if !is_srf {
+            return false;
+        }
+        let mut finder = ColumnRefFinder { found: false };
+        for param in params {
+            walk_expr(&mut finder, param);
+        }
+        finder.found
+    }
+
+    /// Bind a `LATERAL` subquery, which is evaluated for each row of the left side of
+    /// `join` with the referenced columns of the row, see `Apply`.
+    #[async_recursion]
+    async fn bind_lateral_subquery(
+        &mut self,
+        mut left_context: BindContext,
+        left_expr: SExpr,
+        join: &Join,
+    ) -> Result<(SExpr, BindContext)> {
+        let (span, subquery, alias) = match &*join.right {
+            TableReference::Subquery {
+                span,
+                subquery,
+                alias,
+                ..
+            } => (span, subquery, alias),
+            _ => unreachable!(),
+        };
+
+        let join_type = match (&join.op, &join.condition) {
+            (JoinOperator::CrossJoin, JoinCondition::None) => JoinType::Inner,
+            (JoinOperator::Inner, JoinCondition::On(_) | JoinCondition::None) => JoinType::Inner,
+            (JoinOperator::LeftOuter, JoinCondition::On(_)) => JoinType::Left,
+            (JoinOperator::LeftOuter, JoinCondition::None) => {
+                return Err(ErrorCode::SemanticError(
+                    "outer join should contain join conditions".to_string(),
+                )
+                .set_span(*span));
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "LATERAL subquery can only be used in inner join, cross join or left join with ON condition"
+                        .to_string(),
+                )
+                .set_span(*span));
+            }
+        };
+
+        let mut subquery_context = BindContext::with_parent(Box::new(left_context.clone()));
+        let (mut s_expr, mut subquery_context) =
+            self.bind_query(&mut subquery_context, subquery).await?;
+        if let Some(alias) = alias {
+            subquery_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+        check_duplicate_join_tables(&left_context, &subquery_context)?;
+
+        // The ON condition is evaluated with the rows of the subquery, so it's bound
+        // as a filter of the subquery.
+        if let JoinCondition::On(condition) = &join.condition {
+            let mut condition_context = BindContext::with_parent(Box::new(left_context.clone()));
+            for column in subquery_context.columns.iter() {
+                condition_context.add_column_binding(column.clone());
+            }
+            let mut scalar_binder = ScalarBinder::new(
+                &mut condition_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let (scalar, _) = scalar_binder.bind(condition).await?;
+            let filter = Filter {
+                predicates: split_conjunctions(&scalar),
+                is_having: false,
+            };
+            s_expr = SExpr::create_unary(filter.into(), s_expr);
+        }
+
+        let subquery_prop = RelExpr::with_s_expr(&s_expr).derive_relational_prop()?;
+        let mut outer_columns = left_context
+            .all_column_bindings()
+            .iter()
+            .map(|column| column.index)
+            .filter(|index| subquery_prop.outer_columns.contains(index))
+            .collect::<Vec<_>>();
+        outer_columns.sort();
+        outer_columns.dedup();
+
+        let apply = Apply {
+            subquery: Box::new(s_expr),
+            join_type: join_type.clone(),
+            outer_columns,
+            output_columns: subquery_context.columns.clone(),
+        };
+        for mut column in subquery_context.columns {
+            if join_type == JoinType::Left {
+                column.data_type = Box::new(column.data_type.wrap_nullable());
+            }
+            left_context.add_column_binding(column);
+        }
+
+        Ok((SExpr::create_unary(apply.into(), left_expr), left_context))
+    }
+
     async fn bind_cte(
         &mut self,
         span: Span,
@@ -591,11 +900,24 @@ impl Binder {
             ctes_map: Box::new(DashMap::new()),
             view_info: None,
             srfs: Default::default(),
+            external_functions: Default::default(),
             expr_context: ExprContext::default(),
         };
-        let (s_expr, mut new_bind_context) = self
-            .bind_query(&mut new_bind_context, &cte_info.query)
-            .await?;
+        let (s_expr, mut new_bind_context) = if let Some(working_table) = &cte_info.working_table {
+            self.bind_working_table(new_bind_context, working_table)
+        } else if cte_info.recursive && self.is_recursive_cte(table_name, cte_info) {
+            if cte_info.materialized == Some(true) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "recursive cte {table_name} can't be materialized"
+                ))
+                .set_span(span));
+            }
+            self.bind_recursive_cte(span, new_bind_context, table_name, cte_info)
+                .await?
+        } else {
+            self.bind_cte_query(span, new_bind_context, table_name, cte_info)
+                .await?
+        };
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
@@ -629,16 +951,141 @@ impl Binder {
         Ok((s_expr, new_bind_context))
     }
 
+    /// Returns the columns of the table that the current user is allowed to select, or `None` if
+    /// the user is not restricted to a subset of them. Like the table level privilege check, the
+    /// grants of the user, the current role and the roles granted to it are taken into account.
+    async fn granted_columns(&self, table: &TableEntry) -> Result<Option<HashSet<String>>> {
+        self.ctx
+            .get_granted_columns(
+                table.catalog(),
+                table.database(),
+                table.name(),
+                UserPrivilegeType::Select,
+            )
+            .await
+    }
+
+    /// Resolve the result of a previous query for `RESULT_SCAN`. The result cached in the
+    /// current session is preferred, otherwise the persisted result is read if it has not
+    /// expired yet.
+    async fn result_scan_table(&self, span: Span, query_id: String) -> Result<Arc<dyn Table>> {
+        let meta_key = self.ctx.get_result_cache_key(&query_id);
+        let op = DataOperator::instance().operator();
+        if let Some(meta_key) = &meta_key {
+            let kv_store = UserApiProvider::instance().get_meta_store_client();
+            let result_cache_mgr = ResultCacheMetaManager::create(kv_store, 0);
+            if let Some(value) = result_cache_mgr.get(meta_key.clone()).await? {
+                let (table_schema, block_raw_data) =
+                    ResultCacheReader::read_table_schema_and_data(op, &value.location).await?;
+                return ResultScan::try_create(table_schema, query_id, block_raw_data);
+            }
+        }
+
+        // The query id is a part of the storage path, it must not escape the directory.
+        let valid_query_id = query_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        let dir = gen_result_persist_dir(&self.ctx.get_tenant(), &query_id);
+        let manifest = match valid_query_id {
+            true => ResultPersistManifest::read(&op, &dir).await?,
+            false => None,
+        };
+        if let Some(manifest) = manifest {
+            if manifest.is_expired(Utc::now().timestamp() as u64) {
+                return Err(ErrorCode::EmptyData(format!(
+                    "`RESULT_SCAN` the persisted result of this query id has expired: {query_id}"
+                ))
+                .set_span(span));
+            }
+            if !self.result_scan_allowed(&manifest) {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied, only the user who ran the query {query_id} or a user with SUPER privilege can scan its result"
+                ))
+                .set_span(span));
+            }
+            return ResultScan::try_create_from_manifest(manifest);
+        }
+
+        match meta_key {
+            None => Err(ErrorCode::EmptyData(format!(
+                "`RESULT_SCAN` could not find related cache key in current session for this query id: {query_id}"
+            ))
+            .set_span(span)),
+            Some(meta_key) => Err(ErrorCode::EmptyData(format!(
+                "`RESULT_SCAN` could not fetch cache value, maybe the data has touched ttl and was cleaned up.\n\
+                query id: {query_id}, cache key: {meta_key}"
+            ))
+            .set_span(span)),
+        }
+    }
+
+    /// The persisted result of a query is only visible to the user who ran it and to
+    /// the users with the SUPER privilege.
+    fn result_scan_allowed(&self, manifest: &ResultPersistManifest) -> bool {
+        let user = match self.ctx.get_current_user() {
+            Ok(user) => user,
+            Err(_) => return false,
+        };
+        if user.identity() == manifest.user {
+            return true;
+        }
+        let mut grants = user.grants;
+        if let Some(role) = self.ctx.get_current_role() {
+            grants |= role.grants;
+        }
+        grants.verify_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
+    }
+
+    /// Only users with the SUPER privilege are allowed to see the body of masking policies.
+    fn masking_policy_body_visible(&self) -> bool {
+        let user = match self.ctx.get_current_user() {
+            Ok(user) => user,
+            Err(_) => return false,
+        };
+        let mut grants = user.grants;
+        if let Some(role) = self.ctx.get_current_role() {
+            grants |= role.grants;
+        }
+        grants.verify_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
+    }
+
+    fn bind_table_sample(span: Span, sample: &TableSample) -> Result<SampleInfo> {
+        if !(0.0..=100.0).contains(&sample.percent) {
+            return Err(ErrorCode::SemanticError(format!(
+                "TABLESAMPLE percentage must be between 0 and 100, but got {}",
+                sample.percent
+            ))
+            .set_span(span));
+        }
+        let method = match sample.method {
+            SampleMethod::System => SampleInfoMethod::System,
+            SampleMethod::Bernoulli => SampleInfoMethod::Bernoulli,
+        };
+        Ok(SampleInfo {
+            method,
+            percent: sample.percent,
+            seed: sample.seed.unwrap_or_else(rand::random),
+            repeatable: sample.seed.is_some(),
+        })
+    }
+
     async fn bind_base_table(
         &mut self,
         bind_context: &BindContext,
         database_name: &str,
         table_index: IndexType,
+        sample: Option<SampleInfo>,
     ) -> Result<(SExpr, BindContext)> {
         let mut bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
         let columns = self.metadata.read().columns_by_table_index(table_index);
         let table = self.metadata.read().table(table_index).clone();
         let statistics_provider = table.table().column_statistics_provider().await?;
+        let external_statistics = self.external_table_statistics(&table).await?;
+        let granted_columns = if table.is_source_of_view() {
+            None
+        } else {
+            self.granted_columns(&table).await?
+        };
 
         let mut col_stats: HashMap<IndexType, Option<ColumnStatistics>> = HashMap::new();
         for column in columns.iter() {
@@ -651,13 +1098,28 @@ impl Binder {
                     leaf_index,
                     ..
                 }) => {
+                    // Inner columns of struct are granted together with their outermost column.
+                    let denied = match &granted_columns {
+                        Some(granted_columns) => {
+                            let outermost_name = match path_indices {
+                                Some(path_indices) => {
+                                    table.table().schema().field(path_indices[0]).name().clone()
+                                }
+                                None => column_name.clone(),
+                            };
+                            !granted_columns.contains(&outermost_name)
+                        }
+                        None => false,
+                    };
                     let column_binding = ColumnBinding {
                         database_name: Some(database_name.to_string()),
                         table_name: Some(table.name().to_string()),
                         column_name: column_name.clone(),
                         index: *column_index,
                         data_type: Box::new(DataType::from(data_type)),
-                        visibility: if path_indices.is_some() {
+                        visibility: if denied {
+                            Visibility::Denied
+                        } else if path_indices.is_some() {
                             Visibility::InVisible
                         } else {
                             Visibility::Visible
@@ -666,8 +1128,20 @@ impl Binder {
                     bind_context.add_column_binding(column_binding);
                     if path_indices.is_none() {
                         if let Some(col_id) = *leaf_index {
-                            let col_stat =
-                                statistics_provider.column_statistics(col_id as ColumnId);
+                            let col_stat = statistics_provider
+                                .column_statistics(col_id as ColumnId)
+                                .or_else(|| {
+                                    let external = external_statistics.as_ref()?;
+                                    let col_stat =
+                                        external.column_statistics.get(&(col_id as u32))?;
+                                    Some(ColumnStatistics {
+                                        min: col_stat.min.clone(),
+                                        max: col_stat.max.clone(),
+                                        null_count: col_stat.null_count,
+                                        number_of_distinct_values: col_stat
+                                            .number_of_distinct_values,
+                                    })
+                                });
                             col_stats.insert(*column_index, col_stat);
                         }
                     }
@@ -679,43 +1153,266 @@ impl Binder {
         }
 
         let is_accurate = table.table().engine().to_lowercase() == "fuse";
-        let stat = table.table().table_statistics()?;
-
-        Ok((
-            SExpr::create_leaf(
-                Scan {
-                    table_index,
-                    columns: columns
-                        .into_iter()
-                        .map(|col| match col {
-                            ColumnEntry::BaseTableColumn(BaseTableColumn {
-                                column_index, ..
-                            }) => column_index,
-                            ColumnEntry::DerivedColumn(DerivedColumn { column_index, .. }) => {
-                                column_index
-                            }
-                            ColumnEntry::InternalColumn(TableInternalColumn {
-                                column_index,
-                                ..
-                            }) => column_index,
-                        })
-                        .collect(),
-                    push_down_predicates: None,
-                    limit: None,
-                    order_by: None,
-                    statistics: Statistics {
-                        statistics: stat,
-                        col_stats,
-                        is_accurate,
-                    },
-                    prewhere: None,
-                }
-                .into(),
-            ),
-            bind_context,
+        let stat = table.table().table_statistics()?.or_else(|| {
+            external_statistics.map(|external| TableStatistics {
+                num_rows: external.number_of_rows,
+                data_size: external.data_bytes,
+                data_size_compressed: None,
+                index_size: None,
+            })
+        });
+
+        let s_expr = SExpr::create_leaf(
+            Scan {
+                table_index,
+                columns: columns
+                    .into_iter()
+                    .map(|col| match col {
+                        ColumnEntry::BaseTableColumn(BaseTableColumn { column_index, .. }) => {
+                            column_index
+                        }
+                        ColumnEntry::DerivedColumn(DerivedColumn { column_index, .. }) => {
+                            column_index
+                        }
+                        ColumnEntry::InternalColumn(TableInternalColumn {
+                            column_index, ..
+                        }) => column_index,
+                    })
+                    .collect(),
+                push_down_predicates: None,
+                limit: None,
+                order_by: None,
+                statistics: Statistics {
+                    statistics: stat,
+                    col_stats,
+                    is_accurate,
+                },
+                prewhere: None,
+                sample,
+            }
+            .into(),
+        );
+        let s_expr = self
+            .bind_row_access_policies(&bind_context, &table, s_expr)
+            .await?;
+        let s_expr = self
+            .bind_masking_policies(&mut bind_context, &table, s_expr)
+            .await?;
+
+        Ok((s_expr, bind_context))
+    }
+
+    /// Statistics sampled by the analyze daemon, for tables of external catalogs only.
+    /// They are treated as absent once older than `max_statistics_age_secs`.
+    async fn external_table_statistics(
+        &self,
+        table: &TableEntry,
+    ) -> Result<Option<ExternalTableStatistics>> {
+        if table.catalog() == CATALOG_DEFAULT {
+            return Ok(None);
+        }
+
+        let statistics = UserApiProvider::instance()
+            .get_table_statistics(
+                &self.ctx.get_tenant(),
+                table.catalog(),
+                table.database(),
+                table.name(),
+            )
+            .await?;
+        let max_age_secs = self.ctx.get_settings().get_max_statistics_age_secs()?;
+        Ok(statistics.filter(|statistics| statistics.is_fresh(Utc::now(), max_age_secs)))
+    }
+
+    /// Filter the rows of the table by its row access policies, unless the current user or
+    /// role is granted `BYPASS ROW ACCESS POLICY`. The filter is placed right on top of the
+    /// scan and before the masking policies, so the policies see the original values.
+    async fn bind_row_access_policies(
+        &mut self,
+        bind_context: &BindContext,
+        table: &TableEntry,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let table_info = table.table().get_table_info().clone();
+        let row_access_policies = &table_info.meta.row_access_policies;
+        if row_access_policies.is_empty() || self.row_access_policies_bypassed() {
+            return Ok(s_expr);
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let mut predicates = Vec::with_capacity(row_access_policies.len());
+        for (policy_name, columns) in row_access_policies.iter() {
+            let policy = UserApiProvider::instance()
+                .get_row_access_policy(tenant.as_str(), policy_name)
+                .await?;
+            if policy.args.len() != columns.len() {
+                return Err(ErrorCode::Internal(format!(
+                    "Invalid row access policy {} of table {}: expect {} columns, but got {}",
+                    policy.name,
+                    table.name(),
+                    policy.args.len(),
+                    columns.len()
+                )));
+            }
+            let sql_tokens = tokenize_sql(&policy.body)?;
+            let body = parse_expr(&sql_tokens, sql_dialect)?;
+
+            // The arguments of the policy refer to the columns the policy is added on.
+            let mut policy_context = BindContext::new();
+            for ((arg_name, _), column_name) in policy.args.iter().zip(columns.iter()) {
+                let column = bind_context
+                    .columns
+                    .iter()
+                    .find(|column| {
+                        column.visibility != Visibility::InVisible
+                            && &column.column_name == column_name
+                    })
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "Invalid row access policy {} of table {}: unknown column {}",
+                            policy.name,
+                            table.name(),
+                            column_name
+                        ))
+                    })?;
+                policy_context.add_column_binding(ColumnBinding {
+                    database_name: None,
+                    table_name: None,
+                    column_name: arg_name.clone(),
+                    index: column.index,
+                    data_type: column.data_type.clone(),
+                    visibility: Visibility::Visible,
+                });
+            }
+            let mut type_checker = TypeChecker::new(
+                &mut policy_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let box (scalar, data_type) = type_checker.resolve(&body).await?;
+            if data_type != DataType::Null && data_type.remove_nullable() != DataType::Boolean {
+                return Err(ErrorCode::SemanticError(format!(
+                    "row access policy {} returns BOOLEAN, but its body is of type {}",
+                    policy.name, data_type
+                )));
+            }
+            predicates.push(scalar);
+        }
+
+        Ok(SExpr::create_unary(
+            Filter {
+                predicates,
+                is_having: false,
+            }
+            .into(),
+            s_expr,
         ))
     }
 
+    fn row_access_policies_bypassed(&self) -> bool {
+        let user = match self.ctx.get_current_user() {
+            Ok(user) => user,
+            Err(_) => return false,
+        };
+        let mut grants = user.grants;
+        if let Some(role) = self.ctx.get_current_role() {
+            grants |= role.grants;
+        }
+        grants.verify_privilege(&GrantObject::Global, vec![
+            UserPrivilegeType::BypassRowAccessPolicy,
+        ])
+    }
+
+    /// Rewrite the columns protected by masking policies into the policy expressions,
+    /// which are evaluated right on top of the scan so that every consumer of the table
+    /// only sees the masked values.
+    async fn bind_masking_policies(
+        &mut self,
+        bind_context: &mut BindContext,
+        table: &TableEntry,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let table_info = table.table().get_table_info().clone();
+        let column_mask_policy = &table_info.meta.column_mask_policy;
+        if column_mask_policy.is_empty() {
+            return Ok(s_expr);
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let body_visible = self.masking_policy_body_visible();
+        let mut items = Vec::with_capacity(column_mask_policy.len());
+        for column in bind_context.columns.iter_mut() {
+            if column.visibility != Visibility::Visible {
+                continue;
+            }
+            let policy_name = match column_mask_policy.get(&column.column_name) {
+                Some(policy_name) => policy_name,
+                None => continue,
+            };
+            let policy = UserApiProvider::instance()
+                .get_masking_policy(tenant.as_str(), policy_name)
+                .await?;
+            let arg_name = match policy.args.first() {
+                Some((arg_name, _)) => arg_name.clone(),
+                None => {
+                    return Err(ErrorCode::Internal(format!(
+                        "Invalid masking policy {}: missing argument",
+                        policy.name
+                    )));
+                }
+            };
+            let sql_tokens = tokenize_sql(&policy.body)?;
+            let body = parse_expr(&sql_tokens, sql_dialect)?;
+
+            // The argument of the policy refers to the original column.
+            let mut policy_context = BindContext::new();
+            policy_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name: arg_name,
+                index: column.index,
+                data_type: column.data_type.clone(),
+                visibility: Visibility::Visible,
+            });
+            let mut type_checker = TypeChecker::new(
+                &mut policy_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let box (scalar, data_type) = type_checker.resolve(&body).await?;
+            let scalar = if data_type != *column.data_type {
+                wrap_cast(&scalar, &column.data_type)
+            } else {
+                scalar
+            };
+
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column.column_name.clone(), *column.data_type.clone());
+            self.metadata
+                .write()
+                .add_masked_column(index, MaskedColumn {
+                    column_name: column.column_name.clone(),
+                    policy_name: policy.name,
+                    body_visible,
+                });
+            items.push(ScalarItem { scalar, index });
+            column.index = index;
+        }
+
+        if items.is_empty() {
+            return Ok(s_expr);
+        }
+        Ok(SExpr::create_unary(EvalScalar { items }.into(), s_expr))
+    }
+
     async fn resolve_data_source(
         &self,
         tenant: &str,
@@ -724,14 +1421,18 @@ impl Binder {
         table_name: &str,
         travel_point: &Option<NavigationPoint>,
     ) -> Result<Arc<dyn Table>> {
-        // Resolve table with catalog
-        let catalog = self.catalogs.get_catalog(catalog_name)?;
-        let mut table_meta = catalog.get_table(tenant, database_name, table_name).await?;
-
+        // Tables with an `AT` clause are cached in the query context by the navigation point,
+        // so references to the same point share one instance and distinct points do not.
         if let Some(tp) = travel_point {
-            table_meta = table_meta.navigate_to(tp).await?;
+            return self
+                .ctx
+                .get_table_at(catalog_name, database_name, table_name, tp)
+                .await;
         }
-        Ok(table_meta)
+
+        // Resolve table with catalog
+        let catalog = self.catalogs.get_catalog(catalog_name)?;
+        catalog.get_table(tenant, database_name, table_name).await
     }
 
     pub(crate) async fn resolve_data_travel_point(
@@ -791,3 +1492,28 @@ pub fn parse_result_scan_args(table_args: &TableArgs) -> Result<String> {
     let args = table_args.expect_all_positioned("RESULT_SCAN", Some(1))?;
     string_value(&args[0])
 }
+
+/// Finds the column references of an expression, the ones in subqueries are ignored.
+struct ColumnRefFinder {
+    found: bool,
+}
+
+impl<'ast> Visitor<'ast> for ColumnRefFinder {
+    fn visit_column_ref(
+        &mut self,
+        _span: Span,
+        _database: &'ast Option<Identifier>,
+        _table: &'ast Option<Identifier>,
+        _column: &'ast Identifier,
+    ) {
+        self.found = true;
+    }
+
+    fn visit_subquery(
+        &mut self,
+        _span: Span,
+        _modifier: &'ast Option<SubqueryModifier>,
+        _subquery: &'ast Query,
+    ) {
+    }
+}
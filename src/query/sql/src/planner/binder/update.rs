@@ -90,6 +90,7 @@ impl Binder {
 
             // TODO(zhyass): selection and update_list support subquery.
             let (scalar, _) = scalar_binder.bind(&update_expr.expr).await?;
+            self.check_masked_columns(&scalar)?;
             if matches!(scalar, ScalarExpr::SubqueryExpr(_)) {
                 return Err(ErrorCode::Internal(
                     "Update does not support subquery temporarily",
@@ -100,6 +101,7 @@ impl Binder {
 
         let push_downs = if let Some(expr) = selection {
             let (scalar, _) = scalar_binder.bind(expr).await?;
+            self.check_masked_columns(&scalar)?;
             if matches!(scalar, ScalarExpr::SubqueryExpr(_)) {
                 return Err(ErrorCode::Internal(
                     "Update does not support subquery temporarily",
@@ -14,9 +14,13 @@
 
 use std::sync::Arc;
 
+use common_ast::ast::Expr as AExpr;
+use common_ast::ast::Identifier;
 use common_ast::parser::parse_comma_separated_exprs;
 use common_ast::parser::tokenize_sql;
+use common_ast::walk_expr_mut;
 use common_ast::Dialect;
+use common_ast::VisitorMut;
 use common_base::base::tokio::runtime::Handle;
 use common_base::base::tokio::task::block_in_place;
 use common_catalog::catalog::CATALOG_DEFAULT;
@@ -25,20 +29,25 @@ use common_catalog::table_context::TableContext;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::DataBlock;
 use common_expression::Evaluator;
 use common_expression::Expr;
-use common_expression::FunctionContext;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableField;
+use common_expression::TableSchemaRef;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
 use common_settings::Settings;
 use parking_lot::RwLock;
 
+use crate::executor::cast_expr_to_non_null_boolean;
 use crate::planner::binder::BindContext;
+use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::NameResolutionContext;
 use crate::planner::semantic::TypeChecker;
 use crate::BaseTableColumn;
@@ -137,6 +146,98 @@ pub fn parse_to_remote_string_expr(
     }
 }
 
+/// Filter the rows of `block` by the predicate `sql`, resolving column references
+/// against `schema`.
+pub fn filter_block_by_sql(
+    ctx: Arc<dyn TableContext>,
+    schema: TableSchemaRef,
+    block: DataBlock,
+    sql: &str,
+) -> Result<DataBlock> {
+    let table: Arc<dyn Table> = Arc::new(DummyTable {
+        info: TableInfo {
+            meta: TableMeta {
+                schema,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    });
+    let mut exprs = parse_exprs(ctx.clone(), table, sql)?;
+    if exprs.len() != 1 {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "Expected single expr, but got {}",
+            exprs.len()
+        )));
+    }
+    let expr = cast_expr_to_non_null_boolean(exprs.remove(0))?;
+
+    let func_ctx = ctx.get_function_context()?;
+    let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+    let filter = evaluator.run(&expr)?.try_downcast::<BooleanType>().unwrap();
+    block.filter_boolean_value(&filter)
+}
+
+/// Renames the references to `column` in a cluster key like `(a, b + 1)`. Returns `None` if
+/// the cluster key doesn't reference the column.
+pub fn rename_column_in_cluster_key(
+    cluster_key: &str,
+    column: &str,
+    new_column: &str,
+) -> Result<Option<String>> {
+    struct ColumnRenamer<'a> {
+        column: &'a str,
+        new_column: &'a str,
+        name_resolution_ctx: NameResolutionContext,
+        renamed: bool,
+    }
+
+    impl<'a> VisitorMut for ColumnRenamer<'a> {
+        fn visit_column_ref(
+            &mut self,
+            _span: Span,
+            _database: &mut Option<Identifier>,
+            _table: &mut Option<Identifier>,
+            column: &mut Identifier,
+        ) {
+            if normalize_identifier(column, &self.name_resolution_ctx).name == self.column {
+                *column = Identifier {
+                    name: self.new_column.to_string(),
+                    quote: Some('`'),
+                    span: column.span,
+                };
+                self.renamed = true;
+            }
+        }
+    }
+
+    let tokens = tokenize_sql(cluster_key)?;
+    let mut exprs = parse_comma_separated_exprs(&tokens, Dialect::MySQL)?;
+    // The cluster key is stored as a tuple of the keys, see `analyze_cluster_keys`.
+    if let [AExpr::Tuple { exprs: keys, .. }] = exprs.as_mut_slice() {
+        exprs = std::mem::take(keys);
+    }
+
+    let mut renamer = ColumnRenamer {
+        column,
+        new_column,
+        name_resolution_ctx: NameResolutionContext::default(),
+        renamed: false,
+    };
+    for expr in exprs.iter_mut() {
+        walk_expr_mut(&mut renamer, expr);
+    }
+    if !renamer.renamed {
+        return Ok(None);
+    }
+
+    let keys = exprs
+        .iter()
+        .map(|expr| format!("{:#}", expr))
+        .collect::<Vec<_>>();
+    Ok(Some(format!("({})", keys.join(", "))))
+}
+
 #[derive(Default)]
 struct DummyTable {
     info: TableInfo,
@@ -171,8 +272,8 @@ pub fn field_default_value(ctx: Arc<dyn TableContext>, field: &TableField) -> Re
             }
 
             let dummy_block = DataBlock::new(vec![], 1);
-            let evaluator =
-                Evaluator::new(&dummy_block, FunctionContext::default(), &BUILTIN_FUNCTIONS);
+            let func_ctx = ctx.get_function_context()?;
+            let evaluator = Evaluator::new(&dummy_block, func_ctx, &BUILTIN_FUNCTIONS);
             let result = evaluator.run(&expr)?;
 
             match result {
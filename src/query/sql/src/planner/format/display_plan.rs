@@ -49,25 +49,43 @@ impl Plan {
             Plan::DropDatabase(drop_database) => Ok(format!("{:?}", drop_database)),
             Plan::UndropDatabase(undrop_database) => Ok(format!("{:?}", undrop_database)),
             Plan::RenameDatabase(rename_database) => Ok(format!("{:?}", rename_database)),
+            Plan::SetDatabaseQuota(set_quota) => Ok(format!("{:?}", set_quota)),
 
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(format!("{:?}", show_create_table)),
             Plan::CreateTable(create_table) => Ok(format!("{:?}", create_table)),
+            Plan::AttachTable(attach_table) => Ok(format!("{:?}", attach_table)),
+            Plan::ExportTableSnapshot(export) => Ok(format!("{:?}", export)),
+            Plan::ImportTableSnapshot(import) => Ok(format!("{:?}", import)),
             Plan::DropTable(drop_table) => Ok(format!("{:?}", drop_table)),
             Plan::UndropTable(undrop_table) => Ok(format!("{:?}", undrop_table)),
             Plan::DescribeTable(describe_table) => Ok(format!("{:?}", describe_table)),
+            Plan::ShowColumns(show_columns) => Ok(format!("{:?}", show_columns)),
             Plan::RenameTable(rename_table) => Ok(format!("{:?}", rename_table)),
             Plan::AddTableColumn(add_table_column) => Ok(format!("{:?}", add_table_column)),
             Plan::DropTableColumn(drop_table_column) => Ok(format!("{:?}", drop_table_column)),
+            Plan::RenameTableColumn(rename_table_column) => {
+                Ok(format!("{:?}", rename_table_column))
+            }
+            Plan::ModifyTableColumn(modify_table_column) => {
+                Ok(format!("{:?}", modify_table_column))
+            }
             Plan::AlterTableClusterKey(alter_table_cluster_key) => {
                 Ok(format!("{:?}", alter_table_cluster_key))
             }
             Plan::DropTableClusterKey(drop_table_cluster_key) => {
                 Ok(format!("{:?}", drop_table_cluster_key))
             }
+            Plan::SetTableAnalyzeInterval(set_table_analyze_interval) => {
+                Ok(format!("{:?}", set_table_analyze_interval))
+            }
+            Plan::SetTableConnection(p) => Ok(format!("{:?}", p)),
+            Plan::AddTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
             Plan::ReclusterTable(recluster_table) => Ok(format!("{:?}", recluster_table)),
             Plan::TruncateTable(truncate_table) => Ok(format!("{:?}", truncate_table)),
             Plan::OptimizeTable(optimize_table) => Ok(format!("{:?}", optimize_table)),
+            Plan::BuildTableIndex(build_index) => Ok(format!("{:?}", build_index)),
             Plan::AnalyzeTable(analyze_table) => Ok(format!("{:?}", analyze_table)),
             Plan::ExistsTable(exists_table) => Ok(format!("{:?}", exists_table)),
 
@@ -76,16 +94,35 @@ impl Plan {
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
 
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(format!("{:?}", create_stream)),
+            Plan::DropStream(drop_stream) => Ok(format!("{:?}", drop_stream)),
+
+            // Tasks
+            Plan::CreateTask(create_task) => Ok(format!("{:?}", create_task)),
+            Plan::AlterTask(alter_task) => Ok(format!("{:?}", alter_task)),
+            Plan::DropTask(drop_task) => Ok(format!("{:?}", drop_task)),
+            Plan::ExecuteTask(execute_task) => Ok(format!("{:?}", execute_task)),
+
+            // Pipes
+            Plan::CreatePipe(create_pipe) => Ok(format!("{:?}", create_pipe)),
+            Plan::DropPipe(drop_pipe) => Ok(format!("{:?}", drop_pipe)),
+
             // Insert
             Plan::Insert(insert) => Ok(format!("{:?}", insert)),
+            Plan::InsertMultiTable(insert) => Ok(format!("{:?}", insert)),
             Plan::Replace(replace) => Ok(format!("{:?}", replace)),
             Plan::Delete(delete) => Ok(format!("{:?}", delete)),
             Plan::Update(update) => Ok(format!("{:?}", update)),
 
             // Stages
             Plan::CreateStage(create_stage) => Ok(format!("{:?}", create_stage)),
+            Plan::AlterStage(s) => Ok(format!("{:?}", s)),
+            Plan::RenameStage(s) => Ok(format!("{:?}", s)),
+            Plan::ShowCreateStage(s) => Ok(format!("{:?}", s)),
             Plan::DropStage(s) => Ok(format!("{:?}", s)),
             Plan::RemoveStage(s) => Ok(format!("{:?}", s)),
+            Plan::ShowStageFiles(s) => Ok(format!("{:?}", s)),
 
             // FileFormat
             Plan::CreateFileFormat(create_file_format) => Ok(format!("{:?}", create_file_format)),
@@ -103,9 +140,25 @@ impl Plan {
             Plan::CreateUDF(create_user_udf) => Ok(format!("{:?}", create_user_udf)),
             Plan::AlterUDF(alter_user_udf) => Ok(format!("{alter_user_udf:?}")),
             Plan::DropUDF(drop_udf) => Ok(format!("{drop_udf:?}")),
+            Plan::DescribeFunction(describe_function) => Ok(format!("{describe_function:?}")),
             Plan::AlterUser(alter_user) => Ok(format!("{:?}", alter_user)),
             Plan::CreateRole(create_role) => Ok(format!("{:?}", create_role)),
             Plan::DropRole(drop_role) => Ok(format!("{:?}", drop_role)),
+            Plan::CreateExternalFunction(p) => Ok(format!("{:?}", p)),
+            Plan::DropExternalFunction(p) => Ok(format!("{:?}", p)),
+            Plan::CreateMaskingPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropMaskingPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::CreateRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::ShowRowAccessPolicies(p) => Ok(format!("{:?}", p)),
+            Plan::CreateSequence(p) => Ok(format!("{:?}", p)),
+            Plan::DropSequence(p) => Ok(format!("{:?}", p)),
+            Plan::ShowSequences(p) => Ok(format!("{:?}", p)),
+            Plan::ShowIndexBuildStatus(p) => Ok(format!("{:?}", p)),
+            Plan::CreateNetworkPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropNetworkPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DescNetworkPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::ShowNetworkPolicies(p) => Ok(format!("{:?}", p)),
 
             Plan::Presign(presign) => Ok(format!("{:?}", presign)),
 
@@ -114,6 +167,11 @@ impl Plan {
             Plan::SetRole(p) => Ok(format!("{:?}", p)),
             Plan::UseDatabase(p) => Ok(format!("{:?}", p)),
             Plan::Kill(p) => Ok(format!("{:?}", p)),
+            Plan::DropResultCache(p) => Ok(format!("{:?}", p)),
+
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Abort => Ok("Abort".to_string()),
 
             Plan::CreateShareEndpoint(p) => Ok(format!("{:?}", p)),
             Plan::ShowShareEndpoint(p) => Ok(format!("{:?}", p)),
@@ -123,9 +181,11 @@ impl Plan {
             Plan::GrantShareObject(p) => Ok(format!("{:?}", p)),
             Plan::RevokeShareObject(p) => Ok(format!("{:?}", p)),
             Plan::AlterShareTenants(p) => Ok(format!("{:?}", p)),
+            Plan::AlterShareComment(p) => Ok(format!("{:?}", p)),
             Plan::DescShare(p) => Ok(format!("{:?}", p)),
             Plan::ShowShares(p) => Ok(format!("{:?}", p)),
             Plan::ShowRoles(p) => Ok(format!("{:?}", p)),
+            Plan::ShowRoleHierarchy(p) => Ok(format!("{:?}", p)),
             Plan::ShowObjectGrantPrivileges(p) => Ok(format!("{:?}", p)),
             Plan::ShowGrantTenantsOfShare(p) => Ok(format!("{:?}", p)),
             Plan::RevertTable(p) => Ok(format!("{:?}", p)),
@@ -81,6 +81,13 @@ impl Display for FormatContext {
                 RelOperator::RuntimeFilterSource(_) => write!(f, "RuntimeFilterSource"),
                 RelOperator::Window(_) => write!(f, "WindowFunc"),
                 RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
+                RelOperator::RecursiveCte(_) => write!(f, "RecursiveCte"),
+                RelOperator::RecursiveCteScan(_) => write!(f, "RecursiveCteScan"),
+                RelOperator::Apply(_) => write!(f, "Apply"),
+                RelOperator::MaterializedCte(_) => write!(f, "MaterializedCte"),
+                RelOperator::MaterializedCteScan(_) => write!(f, "MaterializedCteScan"),
+                RelOperator::ExternalFunction(_) => write!(f, "ExternalFunction"),
+                RelOperator::MatchRecognize(_) => write!(f, "MatchRecognize"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -149,6 +156,14 @@ pub fn format_scalar(_metadata: &MetadataRef, scalar: &ScalarExpr) -> String {
                     .join(", ")
             )
         }
+        ScalarExpr::LambdaFunction(lambda) => {
+            format!(
+                "{}({}, {})",
+                &lambda.func_name,
+                format_scalar(_metadata, &lambda.args[0]),
+                &lambda.lambda_display
+            )
+        }
         ScalarExpr::CastExpr(cast) => {
             format!(
                 "CAST({} AS {})",
@@ -383,9 +398,14 @@ pub fn logical_join_to_format_tree(
         .left_conditions
         .iter()
         .zip(op.right_conditions.iter())
-        .map(|(left, right)| {
+        .enumerate()
+        .map(|(idx, (left, right))| {
             ComparisonExpr {
-                op: ComparisonOp::Equal,
+                op: if op.is_null_equal.contains(&idx) {
+                    ComparisonOp::NullSafeEqual
+                } else {
+                    ComparisonOp::Equal
+                },
                 left: Box::new(left.clone()),
                 right: Box::new(right.clone()),
             }
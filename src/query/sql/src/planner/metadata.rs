@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::fmt::Debug;
@@ -51,6 +52,8 @@ pub type MetadataRef = Arc<RwLock<Metadata>>;
 pub struct Metadata {
     tables: Vec<TableEntry>,
     columns: Vec<ColumnEntry>,
+    /// Columns produced by masking policies, keyed by the index of the masked column.
+    masked_columns: HashMap<IndexType, MaskedColumn>,
 }
 
 impl Metadata {
@@ -145,6 +148,14 @@ impl Metadata {
         column_index
     }
 
+    pub fn add_masked_column(&mut self, column_index: IndexType, masked_column: MaskedColumn) {
+        self.masked_columns.insert(column_index, masked_column);
+    }
+
+    pub fn masked_column(&self, column_index: IndexType) -> Option<&MaskedColumn> {
+        self.masked_columns.get(&column_index)
+    }
+
     pub fn add_internal_column(
         &mut self,
         table_index: IndexType,
@@ -338,6 +349,15 @@ pub struct DerivedColumn {
     pub data_type: DataType,
 }
 
+/// A column whose value is computed by a masking policy from a base table column.
+#[derive(Clone, Debug)]
+pub struct MaskedColumn {
+    pub column_name: String,
+    pub policy_name: String,
+    /// Whether the current user is allowed to see the policy body, e.g. in `EXPLAIN`.
+    pub body_visible: bool,
+}
+
 #[derive(Clone, Debug)]
 pub struct TableInternalColumn {
     pub table_index: IndexType,
@@ -39,15 +39,22 @@ impl CostModel for DefaultCostModel {
 fn compute_cost_impl(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
     match &m_expr.plan {
         RelOperator::Scan(plan) => compute_cost_scan(memo, m_expr, plan),
-        RelOperator::DummyTableScan(_) => Ok(Cost(0.0)),
+        RelOperator::DummyTableScan(_)
+        | RelOperator::RecursiveCteScan(_)
+        | RelOperator::MaterializedCteScan(_) => Ok(Cost(0.0)),
         RelOperator::Join(plan) => compute_cost_join(memo, m_expr, plan),
-        RelOperator::UnionAll(_) => compute_cost_union_all(memo, m_expr),
+        RelOperator::UnionAll(_)
+        | RelOperator::RecursiveCte(_)
+        | RelOperator::MaterializedCte(_) => compute_cost_union_all(memo, m_expr),
 
         RelOperator::EvalScalar(_)
         | RelOperator::Filter(_)
         | RelOperator::Aggregate(_)
         | RelOperator::Sort(_)
         | RelOperator::ProjectSet(_)
+        | RelOperator::ExternalFunction(_)
+        | RelOperator::Apply(_)
+        | RelOperator::MatchRecognize(_)
         | RelOperator::Limit(_) => compute_cost_unary_common_operator(memo, m_expr),
 
         _ => Err(ErrorCode::Internal("Cannot compute cost from logical plan")),
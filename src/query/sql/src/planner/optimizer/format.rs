@@ -49,6 +49,13 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::RuntimeFilterSource(_) => "RuntimeFilterSource".to_string(),
         RelOperator::ProjectSet(_) => "ProjectSet".to_string(),
         RelOperator::Window(_) => "WindowFunc".to_string(),
+        RelOperator::RecursiveCte(_) => "RecursiveCte".to_string(),
+        RelOperator::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
+        RelOperator::Apply(_) => "Apply".to_string(),
+        RelOperator::MaterializedCte(_) => "MaterializedCte".to_string(),
+        RelOperator::MaterializedCteScan(_) => "MaterializedCteScan".to_string(),
+        RelOperator::ExternalFunction(_) => "ExternalFunction".to_string(),
+        RelOperator::MatchRecognize(_) => "MatchRecognize".to_string(),
     }
 }
 
@@ -148,6 +148,7 @@ impl SubqueryRewriter {
         // This is not necessary, but it is a good heuristic for most cases.
         let mut left_conditions = vec![];
         let mut right_conditions = vec![];
+        let mut is_null_equal = vec![];
         let mut non_equi_conditions = vec![];
         let mut left_filters = vec![];
         let mut right_filters = vec![];
@@ -165,7 +166,14 @@ impl SubqueryRewriter {
                     non_equi_conditions.push(pred.clone());
                 }
 
-                JoinPredicate::Both { left, right } => {
+                JoinPredicate::Both {
+                    left,
+                    right,
+                    is_null_equal: null_equal,
+                } => {
+                    if null_equal {
+                        is_null_equal.push(left_conditions.len());
+                    }
                     if left.data_type()?.eq(&right.data_type()?) {
                         left_conditions.push(left.clone());
                         right_conditions.push(right.clone());
@@ -188,6 +196,7 @@ impl SubqueryRewriter {
         let join = Join {
             left_conditions,
             right_conditions,
+            is_null_equal,
             non_equi_conditions,
             join_type: match &subquery.typ {
                 SubqueryType::Any | SubqueryType::All | SubqueryType::Scalar => {
@@ -262,6 +271,7 @@ impl SubqueryRewriter {
                 let join_plan = Join {
                     left_conditions,
                     right_conditions,
+                    is_null_equal: vec![],
                     non_equi_conditions: vec![],
                     join_type: JoinType::Single,
                     marker_index: None,
@@ -301,6 +311,7 @@ impl SubqueryRewriter {
                 let join_plan = Join {
                     left_conditions: right_conditions,
                     right_conditions: left_conditions,
+                    is_null_equal: vec![],
                     non_equi_conditions: vec![],
                     join_type: JoinType::RightMark,
                     marker_index: Some(marker_index),
@@ -358,6 +369,7 @@ impl SubqueryRewriter {
                 let mark_join = Join {
                     left_conditions: right_conditions,
                     right_conditions: left_conditions,
+                    is_null_equal: vec![],
                     non_equi_conditions,
                     join_type: JoinType::RightMark,
                     marker_index: Some(marker_index),
@@ -428,6 +440,7 @@ impl SubqueryRewriter {
                         is_accurate: false,
                     },
                     prewhere: None,
+                    sample: None,
                 }
                 .into(),
             );
@@ -435,6 +448,7 @@ impl SubqueryRewriter {
             let cross_join = Join {
                 left_conditions: vec![],
                 right_conditions: vec![],
+                is_null_equal: vec![],
                 non_equi_conditions: vec![],
                 join_type: JoinType::Cross,
                 marker_index: None,
@@ -554,6 +568,7 @@ impl SubqueryRewriter {
                     Join {
                         left_conditions: join.left_conditions.clone(),
                         right_conditions: join.right_conditions.clone(),
+                        is_null_equal: join.is_null_equal.clone(),
                         non_equi_conditions: join.non_equi_conditions.clone(),
                         join_type: join.join_type.clone(),
                         marker_index: join.marker_index,
@@ -699,6 +714,26 @@ impl SubqueryRewriter {
                 ))
             }
 
+            RelOperator::MaterializedCte(_) => {
+                // The query of a materialized cte never references the outer query,
+                // see `Binder::bind_cte`.
+                let flatten_plan = self.flatten(
+                    plan.child(1)?,
+                    correlated_columns,
+                    flatten_info,
+                    need_cross_join,
+                )?;
+                Ok(SExpr::create_binary(
+                    plan.plan().clone(),
+                    plan.child(0)?.clone(),
+                    flatten_plan,
+                ))
+            }
+
+            RelOperator::Apply(_) => Err(ErrorCode::Unimplemented(
+                "LATERAL subquery referencing columns of an enclosing query is not supported in a correlated subquery",
+            )),
+
             _ => Err(ErrorCode::Internal(
                 "Invalid plan type for flattening subquery",
             )),
@@ -796,6 +831,13 @@ impl SubqueryRewriter {
                     target_type: cast_expr.target_type.clone(),
                 }))
             }
+            ScalarExpr::LambdaFunction(lambda_func) => {
+                let mut args = Vec::with_capacity(lambda_func.args.len());
+                for arg in &lambda_func.args {
+                    args.push(self.flatten_scalar(arg, correlated_columns)?);
+                }
+                Ok(ScalarExpr::LambdaFunction(lambda_func.with_args(args)?))
+            }
             _ => Err(ErrorCode::Internal(
                 "Invalid scalar for flattening subquery",
             )),
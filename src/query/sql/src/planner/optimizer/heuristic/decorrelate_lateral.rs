@@ -0,0 +1,207 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::common_super_type;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::JoinPredicate;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::planner::binder::wrap_cast;
+use crate::plans::Apply;
+use crate::plans::Filter;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+
+/// Rewrite `Apply` into `Join` if the subquery only references the columns of the input
+/// in equi-predicates, e.g.
+///
+/// ```sql
+/// SELECT * FROM t, LATERAL (SELECT * FROM s WHERE s.id = t.id) AS s
+/// ```
+///
+/// is rewritten into `t INNER JOIN s ON s.id = t.id`. The correlated predicates can be
+/// pulled up through `Filter` and `EvalScalar`. Other operators, e.g. `Limit`, are
+/// evaluated for each row of the input, so such subqueries are left to `Apply`.
+pub fn decorrelate_lateral(s_expr: &SExpr) -> Result<SExpr> {
+    let children = s_expr
+        .children()
+        .iter()
+        .map(decorrelate_lateral)
+        .collect::<Result<Vec<_>>>()?;
+    let s_expr = s_expr.replace_children(children);
+
+    if let RelOperator::Apply(apply) = s_expr.plan() {
+        let apply = Apply {
+            subquery: Box::new(decorrelate_lateral(&apply.subquery)?),
+            ..apply.clone()
+        };
+        if let Some(join) = try_decorrelate_apply(&apply, s_expr.child(0)?)? {
+            return Ok(join);
+        }
+        return Ok(SExpr::create_unary(apply.into(), s_expr.child(0)?.clone()));
+    }
+
+    Ok(s_expr)
+}
+
+fn try_decorrelate_apply(apply: &Apply, input: &SExpr) -> Result<Option<SExpr>> {
+    let input_prop = RelExpr::with_s_expr(input).derive_relational_prop()?;
+    let subquery_prop = RelExpr::with_s_expr(&apply.subquery).derive_relational_prop()?;
+    if subquery_prop.outer_columns.is_empty() {
+        if apply.join_type != JoinType::Inner {
+            // Keep the left apply, a left join without conditions is not supported.
+            return Ok(None);
+        }
+        let cross_join = Join {
+            left_conditions: vec![],
+            right_conditions: vec![],
+            is_null_equal: vec![],
+            non_equi_conditions: vec![],
+            join_type: JoinType::Cross,
+            marker_index: None,
+            from_correlated_subquery: false,
+            contain_runtime_filter: false,
+        };
+        return Ok(Some(SExpr::create_binary(
+            cross_join.into(),
+            input.clone(),
+            (*apply.subquery).clone(),
+        )));
+    }
+    if !subquery_prop
+        .outer_columns
+        .is_subset(&input_prop.output_columns)
+        || apply.subquery.contain_subquery()
+    {
+        return Ok(None);
+    }
+
+    let correlated_columns: ColumnSet = apply.outer_columns.iter().cloned().collect();
+    let mut correlated_predicates = vec![];
+    let subquery = match pull_up_correlated_predicates(
+        &apply.subquery,
+        &correlated_columns,
+        &mut correlated_predicates,
+    )? {
+        Some(subquery) => subquery,
+        None => return Ok(None),
+    };
+    let subquery_prop = RelExpr::with_s_expr(&subquery).derive_relational_prop()?;
+
+    let mut left_conditions = vec![];
+    let mut right_conditions = vec![];
+    let mut is_null_equal = vec![];
+    let mut non_equi_conditions = vec![];
+    for predicate in correlated_predicates.iter() {
+        match JoinPredicate::new(predicate, &input_prop, &subquery_prop) {
+            JoinPredicate::Both {
+                left,
+                right,
+                is_null_equal: null_equal,
+            } => {
+                if null_equal {
+                    is_null_equal.push(left_conditions.len());
+                }
+                let left_type = left.data_type()?;
+                let right_type = right.data_type()?;
+                if left_type == right_type {
+                    left_conditions.push(left.clone());
+                    right_conditions.push(right.clone());
+                    continue;
+                }
+                let join_type =
+                    common_super_type(left_type, right_type, &BUILTIN_FUNCTIONS.default_cast_rules)
+                        .ok_or_else(|| ErrorCode::Internal("Cannot find common type"))?;
+                left_conditions.push(wrap_cast(left, &join_type));
+                right_conditions.push(wrap_cast(right, &join_type));
+            }
+            _ => non_equi_conditions.push(predicate.clone()),
+        }
+    }
+    if left_conditions.is_empty() {
+        return Ok(None);
+    }
+
+    let join = Join {
+        left_conditions,
+        right_conditions,
+        is_null_equal,
+        non_equi_conditions,
+        join_type: apply.join_type.clone(),
+        marker_index: None,
+        from_correlated_subquery: false,
+        contain_runtime_filter: false,
+    };
+    Ok(Some(SExpr::create_binary(
+        join.into(),
+        input.clone(),
+        subquery,
+    )))
+}
+
+/// Remove the predicates referencing `correlated_columns` from the `Filter`s of `s_expr`
+/// and collect them into `predicates`. Returns `None` if the columns are referenced
+/// by other operators.
+fn pull_up_correlated_predicates(
+    s_expr: &SExpr,
+    correlated_columns: &ColumnSet,
+    predicates: &mut Vec<ScalarExpr>,
+) -> Result<Option<SExpr>> {
+    let prop = RelExpr::with_s_expr(s_expr).derive_relational_prop()?;
+    if prop.outer_columns.is_disjoint(correlated_columns) {
+        return Ok(Some(s_expr.clone()));
+    }
+
+    match s_expr.plan() {
+        RelOperator::Filter(filter) => {
+            let child = match pull_up_correlated_predicates(
+                s_expr.child(0)?,
+                correlated_columns,
+                predicates,
+            )? {
+                Some(child) => child,
+                None => return Ok(None),
+            };
+            let (correlated, uncorrelated): (Vec<_>, Vec<_>) =
+                filter.predicates.iter().cloned().partition(|predicate| {
+                    !predicate.used_columns().is_disjoint(correlated_columns)
+                });
+            predicates.extend(correlated);
+            if uncorrelated.is_empty() {
+                return Ok(Some(child));
+            }
+            let filter = Filter {
+                predicates: uncorrelated,
+                is_having: filter.is_having,
+            };
+            Ok(Some(SExpr::create_unary(filter.into(), child)))
+        }
+        RelOperator::EvalScalar(eval_scalar) => {
+            if !eval_scalar.used_columns()?.is_disjoint(correlated_columns) {
+                return Ok(None);
+            }
+            Ok(
+                pull_up_correlated_predicates(s_expr.child(0)?, correlated_columns, predicates)?
+                    .map(|child| s_expr.replace_children(vec![child])),
+            )
+        }
+        _ => Ok(None),
+    }
+}
@@ -20,6 +20,7 @@ use once_cell::sync::Lazy;
 
 use super::prune_unused_columns::UnusedColumnPruner;
 use crate::optimizer::heuristic::decorrelate::decorrelate_subquery;
+use crate::optimizer::heuristic::decorrelate_lateral::decorrelate_lateral;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::RuleFactory;
@@ -77,7 +78,7 @@ impl HeuristicOptimizer {
     }
 
     fn pre_optimize(&mut self, s_expr: SExpr) -> Result<SExpr> {
-        let mut s_expr = s_expr;
+        let mut s_expr = decorrelate_lateral(&s_expr)?;
         if s_expr.contain_subquery() {
             s_expr = decorrelate_subquery(self.metadata.clone(), s_expr)?;
         }
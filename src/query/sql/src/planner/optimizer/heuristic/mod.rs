@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod decorrelate;
+mod decorrelate_lateral;
 #[allow(clippy::module_inception)]
 mod heuristic;
 mod prune_unused_columns;
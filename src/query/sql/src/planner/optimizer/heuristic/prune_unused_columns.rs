@@ -19,6 +19,7 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::SExpr;
 use crate::plans::Aggregate;
 use crate::plans::EvalScalar;
+use crate::plans::ExternalFunction;
 use crate::plans::RelOperator;
 use crate::MetadataRef;
 
@@ -205,7 +206,75 @@ impl UnusedColumnPruner {
                 ))
             }
 
-            RelOperator::DummyTableScan(_) => Ok(expr.clone()),
+            RelOperator::ExternalFunction(op) => {
+                // The external functions are expensive, only call the ones needed by parent plan.
+                let mut used = vec![];
+                for item in op.items.iter() {
+                    if !required.contains(&item.index) {
+                        continue;
+                    }
+                    used.push(item.clone());
+                    item.arguments.iter().for_each(|argument| {
+                        required.extend(argument.used_columns());
+                    })
+                }
+                if used.is_empty() {
+                    return Self::keep_required_columns(expr.child(0)?, required);
+                }
+
+                Ok(SExpr::create_unary(
+                    RelOperator::ExternalFunction(ExternalFunction { items: used }),
+                    Self::keep_required_columns(expr.child(0)?, required)?,
+                ))
+            }
+
+            RelOperator::RecursiveCte(p) => {
+                // All the columns are required by the iterations, the recursive member
+                // reads them back from the working table.
+                let anchor_used = p.anchor_columns.iter().cloned().collect();
+                let recursive_used = p.recursive_columns.iter().cloned().collect();
+                Ok(SExpr::create_binary(
+                    RelOperator::RecursiveCte(p.clone()),
+                    Self::keep_required_columns(expr.child(0)?, anchor_used)?,
+                    Self::keep_required_columns(expr.child(1)?, recursive_used)?,
+                ))
+            }
+
+            RelOperator::MaterializedCte(p) => {
+                // All the columns of the cte are stored, the scans read them by position.
+                let cte_used = p.columns.iter().cloned().collect();
+                Ok(SExpr::create_binary(
+                    RelOperator::MaterializedCte(p.clone()),
+                    Self::keep_required_columns(expr.child(0)?, cte_used)?,
+                    Self::keep_required_columns(expr.child(1)?, required)?,
+                ))
+            }
+
+            RelOperator::Apply(p) => {
+                // Keep the columns bound to the outer references of the subquery.
+                required.extend(p.outer_columns.iter().cloned());
+                Ok(SExpr::create_unary(
+                    RelOperator::Apply(p.clone()),
+                    Self::keep_required_columns(expr.child(0)?, required)?,
+                ))
+            }
+
+            RelOperator::MatchRecognize(p) => {
+                // The measures are computed by `MatchRecognize`, and with `ALL ROWS PER MATCH`
+                // the required columns of the input are passed through.
+                for measure in p.measures.iter() {
+                    required.remove(&measure.index);
+                }
+                required.extend(p.used_columns());
+                Ok(SExpr::create_unary(
+                    RelOperator::MatchRecognize(p.clone()),
+                    Self::keep_required_columns(expr.child(0)?, required)?,
+                ))
+            }
+
+            RelOperator::DummyTableScan(_)
+            | RelOperator::RecursiveCteScan(_)
+            | RelOperator::MaterializedCteScan(_) => Ok(expr.clone()),
 
             _ => Err(ErrorCode::Internal(
                 "Attempting to prune columns of a physical plan is not allowed",
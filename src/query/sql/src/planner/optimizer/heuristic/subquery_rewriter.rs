@@ -40,6 +40,7 @@ use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::LambdaFunc;
 use crate::plans::Limit;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
@@ -90,6 +91,19 @@ impl SubqueryRewriter {
 
                 Ok(SExpr::create_unary(plan.into(), input))
             }
+            RelOperator::ExternalFunction(mut plan) => {
+                let mut input = self.rewrite(s_expr.child(0)?)?;
+
+                for item in plan.items.iter_mut() {
+                    for argument in item.arguments.iter_mut() {
+                        let res = self.try_rewrite_subquery(argument, &input, false)?;
+                        input = res.1;
+                        *argument = res.0;
+                    }
+                }
+
+                Ok(SExpr::create_unary(plan.into(), input))
+            }
             RelOperator::Filter(mut plan) => {
                 let mut input = self.rewrite(s_expr.child(0)?)?;
                 for pred in plan.predicates.iter_mut() {
@@ -118,18 +132,28 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(plan.into(), input))
             }
 
-            RelOperator::Join(_) | RelOperator::UnionAll(_) => Ok(SExpr::create_binary(
+            RelOperator::Join(_)
+            | RelOperator::UnionAll(_)
+            | RelOperator::RecursiveCte(_)
+            | RelOperator::MaterializedCte(_) => Ok(SExpr::create_binary(
                 s_expr.plan().clone(),
                 self.rewrite(s_expr.child(0)?)?,
                 self.rewrite(s_expr.child(1)?)?,
             )),
 
-            RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(SExpr::create_unary(
+            // The subquery of `Apply` is rewritten when it's executed.
+            RelOperator::Limit(_)
+            | RelOperator::Sort(_)
+            | RelOperator::Apply(_)
+            | RelOperator::MatchRecognize(_) => Ok(SExpr::create_unary(
                 s_expr.plan().clone(),
                 self.rewrite(s_expr.child(0)?)?,
             )),
 
-            RelOperator::DummyTableScan(_) | RelOperator::Scan(_) => Ok(s_expr.clone()),
+            RelOperator::DummyTableScan(_)
+            | RelOperator::Scan(_)
+            | RelOperator::RecursiveCteScan(_)
+            | RelOperator::MaterializedCteScan(_) => Ok(s_expr.clone()),
 
             _ => Err(ErrorCode::Internal("Invalid plan type")),
         }
@@ -227,6 +251,28 @@ impl SubqueryRewriter {
                 Ok((expr, s_expr))
             }
 
+            ScalarExpr::LambdaFunction(lambda) => {
+                let mut args = vec![];
+                let mut s_expr = s_expr.clone();
+                for arg in lambda.args.iter() {
+                    let res = self.try_rewrite_subquery(arg, &s_expr, false)?;
+                    s_expr = res.1;
+                    args.push(res.0);
+                }
+
+                let expr: ScalarExpr = LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into();
+
+                Ok((expr, s_expr))
+            }
+
             ScalarExpr::CastExpr(cast) => {
                 let (scalar, s_expr) = self.try_rewrite_subquery(&cast.argument, s_expr, false)?;
                 Ok((
@@ -368,6 +414,7 @@ impl SubqueryRewriter {
                 let join_plan = Join {
                     left_conditions: vec![],
                     right_conditions: vec![],
+                    is_null_equal: vec![],
                     non_equi_conditions: vec![],
                     join_type: JoinType::Single,
                     marker_index: None,
@@ -460,6 +507,7 @@ impl SubqueryRewriter {
                 let cross_join = Join {
                     left_conditions: vec![],
                     right_conditions: vec![],
+                    is_null_equal: vec![],
                     non_equi_conditions: vec![],
                     join_type: JoinType::Cross,
                     marker_index: None,
@@ -523,6 +571,7 @@ impl SubqueryRewriter {
                 let mark_join = Join {
                     left_conditions: right_conditions,
                     right_conditions: left_conditions,
+                    is_null_equal: vec![],
                     non_equi_conditions,
                     join_type: JoinType::RightMark,
                     marker_index: Some(marker_index),
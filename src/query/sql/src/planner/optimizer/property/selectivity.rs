@@ -111,7 +111,7 @@ impl<'a> SelectivityEstimator<'a> {
             };
 
             match &comp_expr.op {
-                ComparisonOp::Equal => {
+                ComparisonOp::Equal | ComparisonOp::NullSafeEqual => {
                     // For equal predicate, we just use cardinality of a single
                     // value to estimate the selectivity. This assumes that
                     // the column is in a uniform distribution.
@@ -361,6 +361,7 @@ fn remove_column_nullable(
                 },
                 partition_by: expr.partition_by.clone(),
                 frame: expr.frame.clone(),
+                ignore_nulls: expr.ignore_nulls,
             })
         }
         ScalarExpr::AggregateFunction(expr) => {
@@ -401,6 +402,19 @@ fn remove_column_nullable(
                 func_name: expr.func_name.clone(),
             })
         }
+        ScalarExpr::LambdaFunction(expr) => {
+            let mut args = Vec::with_capacity(expr.args.len());
+            for arg in expr.args.iter() {
+                args.push(remove_column_nullable(
+                    arg,
+                    left_prop,
+                    right_prop,
+                    join_type,
+                    metadata.clone(),
+                )?);
+            }
+            ScalarExpr::LambdaFunction(expr.with_args(args)?)
+        }
         ScalarExpr::CastExpr(expr) => {
             let new_expr =
                 remove_column_nullable(&expr.argument, left_prop, right_prop, join_type, metadata)?;
@@ -202,6 +202,7 @@ impl RulePushDownFilterEvalScalar {
                         agg_func,
                         partition_by,
                         frame: window.frame.clone(),
+                        ignore_nulls: window.ignore_nulls,
                     }))
                 }
                 ScalarExpr::AggregateFunction(agg_func) => {
@@ -262,6 +263,22 @@ impl RulePushDownFilterEvalScalar {
                         target_type: cast.target_type.clone(),
                     }))
                 }
+                ScalarExpr::LambdaFunction(lambda) => {
+                    let args = lambda
+                        .args
+                        .iter()
+                        .map(|arg| {
+                            Self::replace_predicate(
+                                arg,
+                                items,
+                                eval_scalar_columns,
+                                eval_scalar_child_columns,
+                            )
+                        })
+                        .collect::<Result<Vec<ScalarExpr>>>()?;
+
+                    Ok(ScalarExpr::LambdaFunction(lambda.with_args(args)?))
+                }
                 _ => Ok(predicate.clone()),
             }
         } else {
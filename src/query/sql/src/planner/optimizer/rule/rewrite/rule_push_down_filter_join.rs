@@ -168,7 +168,11 @@ pub fn try_push_down_filter_join(
             }
             JoinPredicate::Other(_) => original_predicates.push(predicate),
 
-            JoinPredicate::Both { left, right } => {
+            JoinPredicate::Both {
+                left,
+                right,
+                is_null_equal,
+            } => {
                 let left_type = left.data_type()?;
                 let right_type = right.data_type()?;
                 let join_key_type =
@@ -182,6 +186,9 @@ pub fn try_push_down_filter_join(
                         join.join_type = JoinType::Inner;
                     }
                     if join.join_type == JoinType::Inner {
+                        if is_null_equal {
+                            join.is_null_equal.push(join.left_conditions.len());
+                        }
                         if left.data_type()? != right.data_type()? {
                             let left = wrap_cast(left, &join_key_type);
                             let right = wrap_cast(right, &join_key_type);
@@ -186,6 +186,7 @@ fn replace_column_binding(
             },
             partition_by: expr.partition_by,
             frame: expr.frame,
+            ignore_nulls: expr.ignore_nulls,
         })),
         ScalarExpr::AggregateFunction(expr) => {
             Ok(ScalarExpr::AggregateFunction(AggregateFunction {
@@ -211,6 +212,14 @@ fn replace_column_binding(
                 .map(|arg| replace_column_binding(index_pairs, arg))
                 .collect::<Result<Vec<_>>>()?,
         })),
+        ScalarExpr::LambdaFunction(expr) => {
+            let args = expr
+                .args
+                .iter()
+                .map(|arg| replace_column_binding(index_pairs, arg.clone()))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(ScalarExpr::LambdaFunction(expr.with_args(args)?))
+        }
         ScalarExpr::CastExpr(expr) => Ok(ScalarExpr::CastExpr(CastExpr {
             span: expr.span,
             is_try: expr.is_try,
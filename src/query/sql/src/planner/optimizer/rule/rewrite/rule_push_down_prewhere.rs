@@ -82,6 +82,12 @@ impl RulePushDownPrewhere {
             ScalarExpr::CastExpr(cast) => {
                 Self::collect_columns_impl(cast.argument.as_ref(), columns)
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                for arg in lambda.args.iter() {
+                    Self::collect_columns_impl(arg, columns)?;
+                }
+                Some(())
+            }
             // 1. ConstantExpr is not collected.
             // 2. SubqueryExpr and AggregateFunction will not appear in Filter-LogicalGet
             _ => None,
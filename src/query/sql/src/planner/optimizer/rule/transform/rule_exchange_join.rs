@@ -170,7 +170,14 @@ impl Rule for RuleExchangeJoin {
                 JoinPredicate::Right(pred) => {
                     join_6_preds.push(pred.clone());
                 }
-                JoinPredicate::Both { left, right } => {
+                JoinPredicate::Both {
+                    left,
+                    right,
+                    is_null_equal,
+                } => {
+                    if is_null_equal {
+                        join_4.is_null_equal.push(join_4.left_conditions.len());
+                    }
                     join_4.left_conditions.push(left.clone());
                     join_4.right_conditions.push(right.clone());
                 }
@@ -192,7 +199,14 @@ impl Rule for RuleExchangeJoin {
                     // TODO(leiysky): push down the predicate
                     join_5.non_equi_conditions.push(predicate.clone());
                 }
-                JoinPredicate::Both { left, right } => {
+                JoinPredicate::Both {
+                    left,
+                    right,
+                    is_null_equal,
+                } => {
+                    if is_null_equal {
+                        join_5.is_null_equal.push(join_5.left_conditions.len());
+                    }
                     join_5.left_conditions.push(left.clone());
                     join_5.right_conditions.push(right.clone());
                 }
@@ -211,7 +225,14 @@ impl Rule for RuleExchangeJoin {
                     // TODO(leiysky): push down the predicate
                     join_6.non_equi_conditions.push(predicate.clone());
                 }
-                JoinPredicate::Both { left, right } => {
+                JoinPredicate::Both {
+                    left,
+                    right,
+                    is_null_equal,
+                } => {
+                    if is_null_equal {
+                        join_6.is_null_equal.push(join_6.left_conditions.len());
+                    }
                     join_6.left_conditions.push(left.clone());
                     join_6.right_conditions.push(right.clone());
                 }
@@ -144,7 +144,14 @@ impl Rule for RuleLeftExchangeJoin {
                     // TODO(leiysky): push down the predicate
                     join_3.non_equi_conditions.push(pred.clone());
                 }
-                JoinPredicate::Both { left, right } => {
+                JoinPredicate::Both {
+                    left,
+                    right,
+                    is_null_equal,
+                } => {
+                    if is_null_equal {
+                        join_3.is_null_equal.push(join_3.left_conditions.len());
+                    }
                     join_3.left_conditions.push(left.clone());
                     join_3.right_conditions.push(right.clone());
                 }
@@ -166,7 +173,14 @@ impl Rule for RuleLeftExchangeJoin {
                     // TODO(leiysky): push down the predicate
                     join_4.non_equi_conditions.push(predicate.clone());
                 }
-                JoinPredicate::Both { left, right } => {
+                JoinPredicate::Both {
+                    left,
+                    right,
+                    is_null_equal,
+                } => {
+                    if is_null_equal {
+                        join_4.is_null_equal.push(join_4.left_conditions.len());
+                    }
                     join_4.left_conditions.push(left.clone());
                     join_4.right_conditions.push(right.clone());
                 }
@@ -24,11 +24,17 @@ pub fn get_join_predicates(join: &Join) -> Result<Vec<ScalarExpr>> {
         .left_conditions
         .iter()
         .zip(join.right_conditions.iter())
-        .map(|(left_cond, right_cond)| {
+        .enumerate()
+        .map(|(idx, (left_cond, right_cond))| {
+            let op = if join.is_null_equal.contains(&idx) {
+                ComparisonOp::NullSafeEqual
+            } else {
+                ComparisonOp::Equal
+            };
             Ok(ScalarExpr::ComparisonExpr(ComparisonExpr {
                 left: Box::new(left_cond.clone()),
                 right: Box::new(right_cond.clone()),
-                op: ComparisonOp::Equal,
+                op,
             }))
         })
         .collect::<Result<Vec<_>>>()?
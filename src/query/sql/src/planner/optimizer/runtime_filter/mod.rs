@@ -40,6 +40,10 @@ fn create_runtime_filters(join: &Join) -> Result<RuntimeFilterResult> {
         .zip(join.left_conditions.iter())
         .enumerate()
     {
+        // NULL keys of `IS NOT DISTINCT FROM` can't be checked by runtime filters.
+        if join.is_null_equal.contains(&idx) {
+            continue;
+        }
         right_runtime_filters.insert(RuntimeFilterId::new(idx), exprs.0.clone());
         left_runtime_filters.insert(RuntimeFilterId::new(idx), exprs.1.clone());
     }
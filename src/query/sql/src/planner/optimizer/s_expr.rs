@@ -232,6 +232,12 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::Sort(_)
         | RelOperator::DummyTableScan(_)
         | RelOperator::RuntimeFilterSource(_)
+        | RelOperator::RecursiveCte(_)
+        | RelOperator::RecursiveCteScan(_)
+        | RelOperator::Apply(_)
+        | RelOperator::MaterializedCte(_)
+        | RelOperator::MaterializedCteScan(_)
+        | RelOperator::MatchRecognize(_)
         | RelOperator::Pattern(_) => false,
         RelOperator::Join(op) => {
             op.left_conditions.iter().any(find_subquery_in_expr)
@@ -262,6 +268,10 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
             .srfs
             .iter()
             .any(|expr| find_subquery_in_expr(&expr.scalar)),
+        RelOperator::ExternalFunction(op) => op
+            .items
+            .iter()
+            .any(|item| item.arguments.iter().any(find_subquery_in_expr)),
     }
 }
 
@@ -283,6 +293,7 @@ fn find_subquery_in_expr(expr: &ScalarExpr) -> bool {
         ScalarExpr::WindowFunction(expr) => expr.agg_func.args.iter().any(find_subquery_in_expr),
         ScalarExpr::AggregateFunction(expr) => expr.args.iter().any(find_subquery_in_expr),
         ScalarExpr::FunctionCall(expr) => expr.arguments.iter().any(find_subquery_in_expr),
+        ScalarExpr::LambdaFunction(expr) => expr.args.iter().any(find_subquery_in_expr),
         ScalarExpr::CastExpr(expr) => find_subquery_in_expr(&expr.argument),
         ScalarExpr::SubqueryExpr(_) => true,
     }
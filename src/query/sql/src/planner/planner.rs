@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use common_ast::ast::Expr;
 use common_ast::ast::Literal;
 use common_ast::ast::Statement;
+use common_ast::parser::parse_settings_hint;
 use common_ast::parser::parse_sql;
+use common_ast::parser::rewrite_odbc_escapes;
 use common_ast::parser::token::Token;
 use common_ast::parser::token::TokenKind;
 use common_ast::parser::token::Tokenizer;
@@ -59,9 +62,22 @@ impl Planner {
     }
 
     pub async fn plan_sql(&mut self, sql: &str) -> Result<(Plan, PlanExtras)> {
+        // Step 0: Apply the settings hint, the hinted settings are used to plan the SQL as well.
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let settings_hint = parse_settings_hint(sql, sql_dialect)?;
+        self.ctx.apply_settings_hint(settings_hint).await?;
+
         let settings = self.ctx.get_settings();
         let sql_dialect = settings.get_sql_dialect()?;
 
+        // The ODBC-style escape clauses are rewritten before tokenizing, so the parser
+        // only sees the plain SQL.
+        let rewritten_sql = match settings.get_enable_odbc_style_escapes()? {
+            true => rewrite_odbc_escapes(sql)?,
+            false => Cow::Borrowed(sql),
+        };
+        let sql = rewritten_sql.as_ref();
+
         // Step 1: Tokenize the SQL.
         let mut tokenizer = Tokenizer::new(sql).peekable();
 
@@ -0,0 +1,276 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::Scalar;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::SExpr;
+use crate::optimizer::Statistics;
+use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::JoinType;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::WindowFuncFrameBound;
+use crate::ColumnBinding;
+use crate::IndexType;
+
+/// Join the input with a `LATERAL` subquery, which is evaluated once for each row of
+/// the input, with the columns of the row bound to the outer references of the subquery.
+///
+/// The subquery is not a child of the operator, it can't be optimized until the outer
+/// references are bound to values. `Apply` is rewritten into a `Join` by the optimizer
+/// if the subquery can be decorrelated.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Apply {
+    pub subquery: Box<SExpr>,
+    /// `Inner` or `Left`, the rows of the input that the subquery returns no rows for
+    /// are padded with NULLs in a left apply.
+    pub join_type: JoinType,
+    /// Columns of the input that are referenced by the subquery.
+    pub outer_columns: Vec<IndexType>,
+    /// Output columns of the subquery.
+    pub output_columns: Vec<ColumnBinding>,
+}
+
+impl Apply {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        Ok(self.outer_columns.iter().cloned().collect())
+    }
+}
+
+impl Operator for Apply {
+    fn rel_op(&self) -> RelOp {
+        RelOp::Apply
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+        let subquery_prop = RelExpr::with_s_expr(&self.subquery).derive_relational_prop()?;
+
+        let mut output_columns = input_prop.output_columns.clone();
+        output_columns.extend(self.output_columns.iter().map(|column| column.index));
+
+        // The subquery may also reference the columns of an enclosing query.
+        let mut outer_columns = input_prop.outer_columns.clone();
+        outer_columns.extend(
+            subquery_prop
+                .outer_columns
+                .difference(&input_prop.output_columns)
+                .cloned(),
+        );
+
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(input_prop.used_columns);
+        used_columns.extend(subquery_prop.used_columns);
+
+        let cardinality = match self.join_type {
+            JoinType::Left => input_prop.cardinality * subquery_prop.cardinality.max(1.0),
+            _ => input_prop.cardinality * subquery_prop.cardinality,
+        };
+
+        Ok(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+            cardinality,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+                is_accurate: false,
+            },
+        })
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        // The subquery is executed in the current node.
+        let mut required = required.clone();
+        required.distribution = Distribution::Serial;
+        Ok(required)
+    }
+}
+
+/// Replace the outer references of the subquery of an `Apply` with `values`, the result
+/// can be optimized and executed as a plain query.
+pub fn bind_outer_columns(s_expr: &SExpr, values: &HashMap<IndexType, Scalar>) -> Result<SExpr> {
+    let children = s_expr
+        .children()
+        .iter()
+        .map(|child| bind_outer_columns(child, values))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut plan = s_expr.plan().clone();
+    match &mut plan {
+        RelOperator::Scan(scan) => {
+            if let Some(predicates) = &mut scan.push_down_predicates {
+                for predicate in predicates.iter_mut() {
+                    replace_scalar(predicate, values)?;
+                }
+            }
+        }
+        RelOperator::EvalScalar(eval_scalar) => {
+            for item in eval_scalar.items.iter_mut() {
+                replace_scalar(&mut item.scalar, values)?;
+            }
+        }
+        RelOperator::Filter(filter) => {
+            for predicate in filter.predicates.iter_mut() {
+                replace_scalar(predicate, values)?;
+            }
+        }
+        RelOperator::Join(join) => {
+            for condition in join
+                .left_conditions
+                .iter_mut()
+                .chain(join.right_conditions.iter_mut())
+                .chain(join.non_equi_conditions.iter_mut())
+            {
+                replace_scalar(condition, values)?;
+            }
+        }
+        RelOperator::Aggregate(aggregate) => {
+            for item in aggregate
+                .group_items
+                .iter_mut()
+                .chain(aggregate.aggregate_functions.iter_mut())
+            {
+                replace_scalar(&mut item.scalar, values)?;
+            }
+        }
+        RelOperator::Window(window) => {
+            replace_scalar(&mut window.aggregate_function.scalar, values)?;
+            for item in window.partition_by.iter_mut() {
+                replace_scalar(&mut item.scalar, values)?;
+            }
+        }
+        RelOperator::ProjectSet(project_set) => {
+            for srf in project_set.srfs.iter_mut() {
+                replace_scalar(&mut srf.scalar, values)?;
+            }
+        }
+        RelOperator::ExternalFunction(external_function) => {
+            for item in external_function.items.iter_mut() {
+                for argument in item.arguments.iter_mut() {
+                    replace_scalar(argument, values)?;
+                }
+            }
+        }
+        RelOperator::Apply(apply) => {
+            apply.subquery = Box::new(bind_outer_columns(&apply.subquery, values)?);
+        }
+        _ => {}
+    }
+
+    Ok(SExpr::create(plan, children, None, None))
+}
+
+fn replace_scalar(scalar: &mut ScalarExpr, values: &HashMap<IndexType, Scalar>) -> Result<()> {
+    match scalar {
+        ScalarExpr::BoundColumnRef(column) => {
+            if let Some(value) = values.get(&column.column.index) {
+                *scalar = ScalarExpr::CastExpr(CastExpr {
+                    span: column.span,
+                    is_try: false,
+                    argument: Box::new(ScalarExpr::ConstantExpr(ConstantExpr {
+                        span: column.span,
+                        value: value.clone(),
+                    })),
+                    target_type: column.column.data_type.clone(),
+                });
+            }
+        }
+        ScalarExpr::BoundInternalColumnRef(_) | ScalarExpr::ConstantExpr(_) => {}
+        ScalarExpr::AndExpr(expr) => {
+            replace_scalar(&mut expr.left, values)?;
+            replace_scalar(&mut expr.right, values)?;
+        }
+        ScalarExpr::OrExpr(expr) => {
+            replace_scalar(&mut expr.left, values)?;
+            replace_scalar(&mut expr.right, values)?;
+        }
+        ScalarExpr::NotExpr(expr) => {
+            replace_scalar(&mut expr.argument, values)?;
+        }
+        ScalarExpr::ComparisonExpr(expr) => {
+            replace_scalar(&mut expr.left, values)?;
+            replace_scalar(&mut expr.right, values)?;
+        }
+        ScalarExpr::WindowFunction(expr) => {
+            for arg in expr.agg_func.args.iter_mut() {
+                replace_scalar(arg, values)?;
+            }
+            for item in expr.partition_by.iter_mut() {
+                replace_scalar(item, values)?;
+            }
+            for bound in [&mut expr.frame.start, &mut expr.frame.end] {
+                if let WindowFuncFrameBound::Preceding(Some(offset))
+                | WindowFuncFrameBound::Following(Some(offset)) = bound
+                {
+                    replace_scalar(offset, values)?;
+                }
+            }
+        }
+        ScalarExpr::AggregateFunction(expr) => {
+            for arg in expr.args.iter_mut() {
+                replace_scalar(arg, values)?;
+            }
+        }
+        ScalarExpr::FunctionCall(expr) => {
+            for arg in expr.arguments.iter_mut() {
+                replace_scalar(arg, values)?;
+            }
+        }
+        ScalarExpr::LambdaFunction(expr) => {
+            for arg in expr.args.iter_mut() {
+                replace_scalar(arg, values)?;
+            }
+        }
+        ScalarExpr::CastExpr(expr) => {
+            replace_scalar(&mut expr.argument, values)?;
+        }
+        ScalarExpr::SubqueryExpr(expr) => {
+            if let Some(child_expr) = &mut expr.child_expr {
+                replace_scalar(child_expr, values)?;
+            }
+            expr.subquery = Box::new(bind_outer_columns(&expr.subquery, values)?);
+            expr.outer_columns
+                .retain(|index| !values.contains_key(index));
+        }
+    }
+    Ok(())
+}
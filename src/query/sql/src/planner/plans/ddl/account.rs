@@ -152,11 +152,24 @@ impl ShowRolesPlan {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowRoleHierarchyPlan {}
+
+impl ShowRoleHierarchyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("parent_role", DataType::String),
+            DataField::new("child_role", DataType::String),
+            DataField::new("inheritance_path", DataType::String),
+        ])
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GrantPrivilegePlan {
     pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
-    pub on: GrantObject,
+    pub on: Vec<GrantObject>,
 }
 
 impl GrantPrivilegePlan {
@@ -169,7 +182,7 @@ impl GrantPrivilegePlan {
 pub struct RevokePrivilegePlan {
     pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
-    pub on: GrantObject,
+    pub on: Vec<GrantObject>,
 }
 
 impl RevokePrivilegePlan {
@@ -124,6 +124,25 @@ impl RenameDatabasePlan {
     }
 }
 
+/// Set quota.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetDatabaseQuotaPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    // Limits that are `None` are left unchanged.
+    pub max_tables: Option<u64>,
+    pub max_bytes: Option<u64>,
+    pub max_rows: Option<u64>,
+}
+
+impl SetDatabaseQuotaPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// Undrop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UndropDatabasePlan {
@@ -14,17 +14,33 @@
 mod account;
 mod catalog;
 mod database;
+mod external_function;
 mod file_format;
+mod masking_policy;
+mod network_policy;
+mod pipe;
+mod row_access_policy;
+mod sequence;
 mod stage;
+mod stream;
 mod table;
+mod task;
 mod udf;
 mod view;
 
 pub use account::*;
 pub use catalog::*;
 pub use database::*;
+pub use external_function::*;
 pub use file_format::*;
+pub use masking_policy::*;
+pub use network_policy::*;
+pub use pipe::*;
+pub use row_access_policy::*;
+pub use sequence::*;
 pub use stage::*;
+pub use stream::*;
 pub use table::*;
+pub use task::*;
 pub use udf::*;
 pub use view::*;
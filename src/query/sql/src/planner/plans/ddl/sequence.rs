@@ -0,0 +1,70 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_meta_app::principal::SequenceInfo;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateSequencePlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub info: SequenceInfo,
+}
+
+impl CreateSequencePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropSequencePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DropSequencePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowSequencesPlan {
+    pub tenant: String,
+}
+
+impl ShowSequencesPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        let int64 = DataType::Number(NumberDataType::Int64);
+        DataSchemaRefExt::create(vec![
+            DataField::new("Name", DataType::String),
+            DataField::new("Start", int64.clone()),
+            DataField::new("Increment", int64.clone()),
+            DataField::new("MinValue", int64.clone()),
+            DataField::new("MaxValue", int64.clone()),
+            DataField::new("Cycle", DataType::Boolean),
+            DataField::new("Current", DataType::Nullable(Box::new(int64))),
+            DataField::new("CreatedOn", DataType::Timestamp),
+        ])
+    }
+}
@@ -15,8 +15,12 @@
 use std::fmt::Debug;
 use std::sync::Arc;
 
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
 use common_meta_app::principal::StageInfo;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -32,6 +36,51 @@ impl CreateStagePlan {
     }
 }
 
+/// Alter, the stage info carries the new properties.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterStagePlan {
+    pub tenant: String,
+    pub stage_info: StageInfo,
+    /// The seq of the stage the new properties are based on, the alter fails if the stage
+    /// has been changed since.
+    pub seq: u64,
+}
+
+impl AlterStagePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Rename.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenameStagePlan {
+    pub tenant: String,
+    pub stage_info: StageInfo,
+    pub new_stage_name: String,
+}
+
+impl RenameStagePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Show create.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowCreateStagePlan {
+    pub stage_info: StageInfo,
+}
+
+impl ShowCreateStagePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("Stage", DataType::String),
+            DataField::new("Create Stage", DataType::String),
+        ])
+    }
+}
+
 /// Drop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropStagePlan {
@@ -58,3 +107,27 @@ impl RemoveStagePlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+/// Show files.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowStageFilesPlan {
+    pub tenant: String,
+    pub stage: StageInfo,
+    pub path: String,
+    /// The LIKE pattern of the file names.
+    pub pattern: String,
+    pub limit: Option<usize>,
+}
+
+impl ShowStageFilesPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("name", DataType::String),
+            DataField::new("size", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("md5", DataType::Nullable(Box::new(DataType::String))),
+            DataField::new("last_modified", DataType::String),
+            DataField::new("etag", DataType::Nullable(Box::new(DataType::String))),
+            DataField::new("status", DataType::String),
+        ])
+    }
+}
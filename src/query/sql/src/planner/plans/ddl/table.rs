@@ -23,7 +23,9 @@ use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::TableDataType;
 use common_expression::TableSchemaRef;
+use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::storage::StorageParams;
@@ -57,6 +59,73 @@ impl CreateTablePlan {
     }
 }
 
+/// Attach a table to the data of a fuse table, by `ATTACH TABLE ... READ_ONLY`.
+#[derive(Clone, Debug)]
+pub struct AttachTablePlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+
+    /// The storage the data is in, the locations in the meta of the table attached to
+    /// are relative to its root.
+    pub storage_params: StorageParams,
+    /// Including the storage prefix of the data, `<database_id>/<table_id>` of the table attached to.
+    pub options: TableOptions,
+}
+
+impl AttachTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+/// Export the files of the current snapshot of a fuse table to a stage, by `EXPORT SNAPSHOT TABLE`.
+#[derive(Clone, Debug)]
+pub struct ExportTableSnapshotPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub stage_info: StageInfo,
+    /// Relative to the root of the stage.
+    pub path: String,
+}
+
+impl ExportTableSnapshotPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        let uint64 = DataType::Number(NumberDataType::UInt64);
+        DataSchemaRefExt::create(vec![
+            DataField::new("files_exported", uint64.clone()),
+            DataField::new("bytes_exported", uint64.clone()),
+            DataField::new("elapsed_ms", uint64),
+        ])
+    }
+}
+
+/// Create a table reading the snapshot exported to a stage, by `IMPORT SNAPSHOT TABLE`.
+#[derive(Clone, Debug)]
+pub struct ImportTableSnapshotPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub stage_info: StageInfo,
+    /// Relative to the root of the stage.
+    pub path: String,
+    pub options: TableOptions,
+}
+
+impl ImportTableSnapshotPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        let uint64 = DataType::Number(NumberDataType::UInt64);
+        DataSchemaRefExt::create(vec![
+            DataField::new("files_imported", uint64.clone()),
+            DataField::new("bytes_imported", uint64.clone()),
+            DataField::new("elapsed_ms", uint64),
+        ])
+    }
+}
+
 /// Desc.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeTablePlan {
@@ -74,6 +143,25 @@ impl DescribeTablePlan {
     }
 }
 
+/// Show columns, a `DESCRIBE` extended with statistics and masking policies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowColumnsPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub full: bool,
+    /// Predicate from `LIKE` or `WHERE`, evaluated on the output columns.
+    pub filter: Option<String>,
+    /// The schema description of the output.
+    pub schema: DataSchemaRef,
+}
+
+impl ShowColumnsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+}
+
 /// Drop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropTablePlan {
@@ -128,6 +216,46 @@ impl AnalyzeTablePlan {
     }
 }
 
+/// Build the bloom filter index of the blocks already in the table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BuildTableIndexPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+impl BuildTableIndexPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowIndexBuildStatusPlan {
+    pub tenant: String,
+}
+
+impl ShowIndexBuildStatusPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        let uint64 = DataType::Number(NumberDataType::UInt64);
+        DataSchemaRefExt::create(vec![
+            DataField::new("QueryId", DataType::String),
+            DataField::new("Database", DataType::String),
+            DataField::new("Table", DataType::String),
+            DataField::new("Status", DataType::String),
+            DataField::new("BuiltBlocks", uint64.clone()),
+            DataField::new("TotalBlocks", uint64),
+            DataField::new("StartedOn", DataType::Timestamp),
+            DataField::new(
+                "FinishedOn",
+                DataType::Nullable(Box::new(DataType::Timestamp)),
+            ),
+            DataField::new("Error", DataType::Nullable(Box::new(DataType::String))),
+        ])
+    }
+}
+
 /// Rename.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RenameTablePlan {
@@ -155,6 +283,14 @@ pub struct AddTableColumnPlan {
     pub schema: TableSchemaRef,
     pub field_default_exprs: Vec<Option<String>>,
     pub field_comments: Vec<String>,
+    pub option: AddColumnOption,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddColumnOption {
+    First,
+    After(String),
+    End,
 }
 
 impl AddTableColumnPlan {
@@ -178,6 +314,50 @@ impl DropTableColumnPlan {
     }
 }
 
+// Table rename column
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenameTableColumnPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub old_column: String,
+    pub new_column: String,
+}
+
+impl RenameTableColumnPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table modify column
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModifyTableColumnPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub column: String,
+    pub action: ModifyColumnAction,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ModifyColumnAction {
+    SetDataType {
+        data_type: TableDataType,
+        /// The expression rewriting the existing data, in SQL.
+        using: Option<String>,
+        cascade: bool,
+    },
+    SetMaskingPolicy(String),
+    UnsetMaskingPolicy,
+}
+
+impl ModifyTableColumnPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// Show.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ShowCreateTablePlan {
@@ -287,3 +467,67 @@ impl DropTableClusterKeyPlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+/// Statistics refresh interval of external tables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetTableAnalyzeIntervalPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub interval_secs: u64,
+}
+
+impl SetTableAnalyzeIntervalPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Replace the storage connection of a table created with its own location.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetTableConnectionPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub storage_params: StorageParams,
+}
+
+impl SetTableConnectionPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Add a row access policy to a table, binding the arguments of the policy to `columns`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddTableRowAccessPolicyPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+impl AddTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTableRowAccessPolicyPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+}
+
+impl DropTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
@@ -52,3 +52,17 @@ impl DropUDFPlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescribeFunctionPlan {
+    /// The function name, either a built-in function or a UDF.
+    pub name: String,
+    /// The schema description of the output.
+    pub schema: DataSchemaRef,
+}
+
+impl DescribeFunctionPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+}
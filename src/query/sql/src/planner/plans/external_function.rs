@@ -0,0 +1,112 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_meta_app::principal::ExternalFunctionInfo;
+use common_meta_app::principal::ExternalFunctionProtocol;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+use crate::ScalarExpr;
+
+/// How to call an external function, it's sent to the executors with the physical plan.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ExternalFunctionDesc {
+    pub name: String,
+    pub handler: String,
+    pub address: String,
+    pub protocol: ExternalFunctionProtocol,
+    pub headers: BTreeMap<String, String>,
+    pub connection_timeout_ms: u64,
+    pub request_timeout_ms: u64,
+    pub max_retries: u64,
+    /// Always nullable, the service may return NULL for any row.
+    pub return_type: DataType,
+}
+
+impl ExternalFunctionDesc {
+    pub fn new(info: &ExternalFunctionInfo) -> Self {
+        ExternalFunctionDesc {
+            name: info.name.clone(),
+            handler: info.handler.clone(),
+            address: info.address.clone(),
+            protocol: info.protocol,
+            headers: info.headers.clone(),
+            connection_timeout_ms: info.connection_timeout_ms,
+            request_timeout_ms: info.request_timeout_ms,
+            max_retries: info.max_retries,
+            return_type: DataType::from(&info.return_type).wrap_nullable(),
+        }
+    }
+}
+
+/// A call of an external function, the arguments are casted to the declared types.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExternalFunctionItem {
+    pub desc: ExternalFunctionDesc,
+    pub arguments: Vec<ScalarExpr>,
+    /// The column holding the results.
+    pub index: IndexType,
+}
+
+/// `ExternalFunction` calls the external functions in the select list for each block of the
+/// input, and appends the results to the block.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExternalFunction {
+    pub items: Vec<ExternalFunctionItem>,
+}
+
+impl Operator for ExternalFunction {
+    fn rel_op(&self) -> RelOp {
+        RelOp::ExternalFunction
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        let mut child_prop = rel_expr.derive_relational_prop_child(0)?;
+        for item in &self.items {
+            child_prop.output_columns.insert(item.index);
+            for argument in &item.arguments {
+                child_prop.used_columns.extend(argument.used_columns());
+            }
+        }
+
+        Ok(child_prop)
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(0)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::RemoteExpr;
+use common_expression::TableSchemaRef;
+
+use super::Plan;
+
+/// `INSERT { ALL | FIRST } ...`, the rows of `source` are routed to the targets of the
+/// branches whose condition is satisfied.
+#[derive(Clone)]
+pub struct InsertMultiTable {
+    /// Only the first satisfied branch takes the row if set, otherwise all of them do.
+    pub is_first: bool,
+    pub source: Box<Plan>,
+    /// Branches in the order of the `WHEN` clauses, the `ELSE` branch comes last and
+    /// takes the rows that satisfy no other branch.
+    pub branches: Vec<InsertBranch>,
+}
+
+#[derive(Clone, Debug)]
+pub struct InsertBranch {
+    /// Evaluated on the output columns of `source`, `None` for the `ELSE` branch.
+    pub condition: Option<RemoteExpr>,
+    pub targets: Vec<InsertTarget>,
+}
+
+#[derive(Clone, Debug)]
+pub struct InsertTarget {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// Columns of the table to insert into.
+    pub schema: TableSchemaRef,
+    /// Evaluated on the output columns of `source`, one for each field of `schema`.
+    pub values: Vec<RemoteExpr>,
+}
+
+impl InsertMultiTable {
+    pub fn targets(&self) -> impl Iterator<Item = &InsertTarget> {
+        self.branches
+            .iter()
+            .flat_map(|branch| branch.targets.iter())
+    }
+
+    /// One column for each target, with the number of rows inserted into it.
+    pub fn schema(&self) -> DataSchemaRef {
+        let fields = self
+            .targets()
+            .map(|target| {
+                DataField::new(
+                    &format!(
+                        "number of rows inserted into {}.{}",
+                        target.database, target.table
+                    ),
+                    DataType::Number(NumberDataType::UInt64),
+                )
+            })
+            .collect();
+        Arc::new(DataSchema::new(fields))
+    }
+}
+
+impl std::fmt::Debug for InsertMultiTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InsertMultiTable")
+            .field("is_first", &self.is_first)
+            .field("branches", &self.branches)
+            .finish()
+    }
+}
@@ -133,6 +133,9 @@ impl Display for JoinType {
 pub struct Join {
     pub left_conditions: Vec<ScalarExpr>,
     pub right_conditions: Vec<ScalarExpr>,
+    // Indexes of the equi-conditions compared with `IS NOT DISTINCT FROM`,
+    // whose NULL keys are matched with each other.
+    pub is_null_equal: Vec<usize>,
     pub non_equi_conditions: Vec<ScalarExpr>,
     pub join_type: JoinType,
     // marker_index is for MarkJoin only.
@@ -147,6 +150,7 @@ impl Default for Join {
         Self {
             left_conditions: Default::default(),
             right_conditions: Default::default(),
+            is_null_equal: Default::default(),
             non_equi_conditions: Default::default(),
             join_type: JoinType::Cross,
             marker_index: Default::default(),
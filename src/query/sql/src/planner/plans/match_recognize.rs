@@ -0,0 +1,257 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// Row pattern of `PATTERN (...)`, a regular expression over the pattern variables.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RowPattern {
+    /// Offset of the variable in `MatchRecognize::variables`.
+    Variable(usize),
+    Concat(Vec<RowPattern>),
+    Alternation(Vec<RowPattern>),
+    Quantified {
+        pattern: Box<RowPattern>,
+        quantifier: RowPatternQuantifier,
+    },
+}
+
+impl RowPattern {
+    /// Format the pattern with the names of the variables, like `PATTERN (...)`.
+    pub fn display(&self, variables: &[MatchVariable]) -> String {
+        match self {
+            RowPattern::Variable(variable) => variables[*variable].name.clone(),
+            RowPattern::Concat(patterns) => patterns
+                .iter()
+                .map(|pattern| match pattern {
+                    RowPattern::Alternation(_) => format!("({})", pattern.display(variables)),
+                    _ => pattern.display(variables),
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+            RowPattern::Alternation(patterns) => patterns
+                .iter()
+                .map(|pattern| pattern.display(variables))
+                .collect::<Vec<_>>()
+                .join(" | "),
+            RowPattern::Quantified {
+                pattern,
+                quantifier,
+            } => {
+                let quantifier = match quantifier {
+                    RowPatternQuantifier::ZeroOrMore => "*",
+                    RowPatternQuantifier::OneOrMore => "+",
+                    RowPatternQuantifier::ZeroOrOne => "?",
+                };
+                match pattern.as_ref() {
+                    RowPattern::Variable(_) => {
+                        format!("{}{quantifier}", pattern.display(variables))
+                    }
+                    _ => format!("({}){quantifier}", pattern.display(variables)),
+                }
+            }
+        }
+    }
+}
+
+/// The quantifiers are greedy, they match as many rows as possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RowPatternQuantifier {
+    /// `*`
+    ZeroOrMore,
+    /// `+`
+    OneOrMore,
+    /// `?`
+    ZeroOrOne,
+}
+
+/// A pattern variable and the boolean column of its `DEFINE` predicate, a variable without
+/// `DEFINE` matches any row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MatchVariable {
+    pub name: String,
+    pub predicate: Option<IndexType>,
+}
+
+/// The values computed over the rows of a match, `variable` restricts them to the rows
+/// classified as the variable. With `ALL ROWS PER MATCH` they are computed over the rows of
+/// the match up to the current row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MatchMeasureFunction {
+    /// `MATCH_NUMBER()`, the sequence number of the match in the partition, starting from 1.
+    MatchNumber,
+    /// `CLASSIFIER()`, the variable of the last row.
+    Classifier,
+    /// `FIRST(column)`, NULL if no row is classified as `variable`.
+    First {
+        variable: Option<usize>,
+        column: IndexType,
+    },
+    /// `LAST(column)`, NULL if no row is classified as `variable`.
+    Last {
+        variable: Option<usize>,
+        column: IndexType,
+    },
+    /// `COUNT(*)` or `COUNT(column)`, the latter doesn't count NULL values.
+    Count {
+        variable: Option<usize>,
+        column: Option<IndexType>,
+    },
+}
+
+impl MatchMeasureFunction {
+    /// Format the function with the names of the variables and the columns.
+    pub fn display(
+        &self,
+        variables: &[MatchVariable],
+        column_name: impl Fn(IndexType) -> String,
+    ) -> String {
+        let argument = |variable: &Option<usize>, column: Option<IndexType>| {
+            let column = column.map_or_else(|| "*".to_string(), &column_name);
+            match variable {
+                Some(variable) => format!("{}.{column}", variables[*variable].name),
+                None => column,
+            }
+        };
+        match self {
+            MatchMeasureFunction::MatchNumber => "match_number()".to_string(),
+            MatchMeasureFunction::Classifier => "classifier()".to_string(),
+            MatchMeasureFunction::First { variable, column } => {
+                format!("first({})", argument(variable, Some(*column)))
+            }
+            MatchMeasureFunction::Last { variable, column } => {
+                format!("last({})", argument(variable, Some(*column)))
+            }
+            MatchMeasureFunction::Count { variable, column } => {
+                format!("count({})", argument(variable, *column))
+            }
+        }
+    }
+
+    pub fn used_column(&self) -> Option<IndexType> {
+        match self {
+            MatchMeasureFunction::MatchNumber | MatchMeasureFunction::Classifier => None,
+            MatchMeasureFunction::First { column, .. }
+            | MatchMeasureFunction::Last { column, .. } => Some(*column),
+            MatchMeasureFunction::Count { column, .. } => *column,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MatchMeasure {
+    pub function: MatchMeasureFunction,
+    pub index: IndexType,
+    pub data_type: DataType,
+}
+
+/// `MatchRecognize` finds the matches of `pattern` in the rows of each partition. The input
+/// is sorted by the partition keys and the `ORDER BY` keys of `MATCH_RECOGNIZE`, and the
+/// `DEFINE` predicates are evaluated to boolean columns of the input.
+///
+/// With `ONE ROW PER MATCH` it returns the partition keys and the measures of each match,
+/// with `ALL ROWS PER MATCH` the rows of the matches and their measures.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MatchRecognize {
+    pub partition_by: Vec<IndexType>,
+    pub variables: Vec<MatchVariable>,
+    pub pattern: RowPattern,
+    pub measures: Vec<MatchMeasure>,
+    pub all_rows_per_match: bool,
+}
+
+impl MatchRecognize {
+    pub fn used_columns(&self) -> ColumnSet {
+        let mut used_columns = ColumnSet::new();
+        used_columns.extend(self.partition_by.iter().cloned());
+        used_columns.extend(
+            self.variables
+                .iter()
+                .filter_map(|variable| variable.predicate),
+        );
+        used_columns.extend(
+            self.measures
+                .iter()
+                .filter_map(|measure| measure.function.used_column()),
+        );
+        used_columns
+    }
+}
+
+impl Operator for MatchRecognize {
+    fn rel_op(&self) -> RelOp {
+        RelOp::MatchRecognize
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        let mut output_columns = if self.all_rows_per_match {
+            input_prop.output_columns.clone()
+        } else {
+            self.partition_by.iter().cloned().collect()
+        };
+        output_columns.extend(self.measures.iter().map(|measure| measure.index));
+
+        let mut used_columns = self.used_columns();
+        used_columns.extend(input_prop.used_columns);
+
+        // Each match has at least one row.
+        Ok(RelationalProperty {
+            output_columns,
+            outer_columns: input_prop.outer_columns,
+            used_columns,
+            cardinality: input_prop.cardinality,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: input_prop.statistics.column_stats,
+                is_accurate: false,
+            },
+        })
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(0)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        // The rows of a partition are matched in order by a single processor.
+        let mut required = required.clone();
+        required.distribution = Distribution::Serial;
+        Ok(required)
+    }
+}
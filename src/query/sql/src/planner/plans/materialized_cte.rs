@@ -0,0 +1,145 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// Materialized common table expression.
+///
+/// The first child is the query of the cte, it's executed only once and the result is
+/// stored. The second child is the query using the cte, which reads the stored result
+/// through `MaterializedCteScan`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MaterializedCte {
+    /// Name of the stored result, shared with the `MaterializedCteScan`s.
+    pub table_name: String,
+    /// Output columns of the query of the cte, in the order of the stored result.
+    pub columns: Vec<IndexType>,
+}
+
+impl MaterializedCte {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        Ok(self.columns.iter().cloned().collect())
+    }
+}
+
+impl Operator for MaterializedCte {
+    fn rel_op(&self) -> RelOp {
+        RelOp::MaterializedCte
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        let cte_prop = rel_expr.derive_relational_prop_child(0)?;
+        let input_prop = rel_expr.derive_relational_prop_child(1)?;
+
+        let mut outer_columns = cte_prop.outer_columns;
+        outer_columns.extend(input_prop.outer_columns);
+
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(cte_prop.used_columns);
+        used_columns.extend(input_prop.used_columns);
+
+        Ok(RelationalProperty {
+            output_columns: input_prop.output_columns,
+            outer_columns,
+            used_columns,
+            cardinality: input_prop.cardinality,
+            statistics: input_prop.statistics,
+        })
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        // The stored result is only visible to the current node.
+        let mut required = required.clone();
+        required.distribution = Distribution::Serial;
+        Ok(required)
+    }
+}
+
+/// Scan the stored result of a `MaterializedCte`. Each reference to the cte has its own
+/// columns, in the order of `MaterializedCte::columns`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MaterializedCteScan {
+    pub table_name: String,
+    pub columns: Vec<IndexType>,
+    /// Estimated number of rows of the cte.
+    pub estimated_rows: u64,
+}
+
+impl MaterializedCteScan {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        Ok(self.columns.iter().cloned().collect())
+    }
+}
+
+impl Operator for MaterializedCteScan {
+    fn rel_op(&self) -> RelOp {
+        RelOp::MaterializedCteScan
+    }
+
+    fn derive_relational_prop(&self, _rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        Ok(RelationalProperty {
+            output_columns: self.used_columns()?,
+            outer_columns: ColumnSet::new(),
+            used_columns: self.used_columns()?,
+            cardinality: self.estimated_rows as f64,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+                is_accurate: false,
+            },
+        })
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
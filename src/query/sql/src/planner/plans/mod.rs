@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod aggregate;
+mod apply;
 mod call;
 mod copy;
 mod ddl;
@@ -20,19 +21,25 @@ mod delete;
 mod dummy_table_scan;
 mod eval_scalar;
 mod exchange;
+mod external_function;
 mod filter;
 pub mod insert;
+mod insert_multi_table;
 mod join;
 mod kill;
 mod limit;
 mod list;
+mod match_recognize;
+mod materialized_cte;
 mod operator;
 mod pattern;
 mod plan;
 mod presign;
 mod project_set;
 mod recluster_table;
+mod recursive_cte;
 mod replace;
+mod result_cache;
 mod revert_table;
 mod runtime_filter_source;
 mod scalar_expr;
@@ -45,6 +52,8 @@ mod update;
 mod window;
 
 pub use aggregate::*;
+pub use apply::bind_outer_columns;
+pub use apply::Apply;
 pub use call::CallPlan;
 pub use copy::*;
 pub use ddl::*;
@@ -52,13 +61,20 @@ pub use delete::DeletePlan;
 pub use dummy_table_scan::DummyTableScan;
 pub use eval_scalar::*;
 pub use exchange::*;
+pub use external_function::*;
 pub use filter::*;
 pub use insert::Insert;
 pub use insert::InsertInputSource;
+pub use insert_multi_table::InsertBranch;
+pub use insert_multi_table::InsertMultiTable;
+pub use insert_multi_table::InsertTarget;
 pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
 pub use list::ListPlan;
+pub use match_recognize::*;
+pub use materialized_cte::MaterializedCte;
+pub use materialized_cte::MaterializedCteScan;
 pub use operator::*;
 pub use pattern::PatternPlan;
 pub use plan::Plan::*;
@@ -67,7 +83,10 @@ pub use plan::*;
 pub use presign::*;
 pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
+pub use recursive_cte::RecursiveCte;
+pub use recursive_cte::RecursiveCteScan;
 pub use replace::Replace;
+pub use result_cache::DropResultCachePlan;
 pub use revert_table::RevertTablePlan;
 pub use runtime_filter_source::RuntimeFilterId;
 pub use runtime_filter_source::RuntimeFilterSource;
@@ -33,8 +33,15 @@ use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
 use crate::optimizer::RequiredProperty;
 use crate::plans::runtime_filter_source::RuntimeFilterSource;
+use crate::plans::Apply;
 use crate::plans::Exchange;
+use crate::plans::ExternalFunction;
+use crate::plans::MatchRecognize;
+use crate::plans::MaterializedCte;
+use crate::plans::MaterializedCteScan;
 use crate::plans::ProjectSet;
+use crate::plans::RecursiveCte;
+use crate::plans::RecursiveCteScan;
 use crate::plans::Window;
 
 pub trait Operator {
@@ -73,6 +80,13 @@ pub enum RelOp {
     RuntimeFilterSource,
     Window,
     ProjectSet,
+    RecursiveCte,
+    RecursiveCteScan,
+    Apply,
+    MaterializedCte,
+    MaterializedCteScan,
+    ExternalFunction,
+    MatchRecognize,
 
     // Pattern
     Pattern,
@@ -94,6 +108,13 @@ pub enum RelOperator {
     RuntimeFilterSource(RuntimeFilterSource),
     Window(Window),
     ProjectSet(ProjectSet),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
+    Apply(Apply),
+    MaterializedCte(MaterializedCte),
+    MaterializedCteScan(MaterializedCteScan),
+    ExternalFunction(ExternalFunction),
+    MatchRecognize(MatchRecognize),
 
     Pattern(PatternPlan),
 }
@@ -115,6 +136,13 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.rel_op(),
             RelOperator::ProjectSet(rel_op) => rel_op.rel_op(),
             RelOperator::Window(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCte(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
+            RelOperator::Apply(rel_op) => rel_op.rel_op(),
+            RelOperator::MaterializedCte(rel_op) => rel_op.rel_op(),
+            RelOperator::MaterializedCteScan(rel_op) => rel_op.rel_op(),
+            RelOperator::ExternalFunction(rel_op) => rel_op.rel_op(),
+            RelOperator::MatchRecognize(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -134,6 +162,13 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::Apply(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::MaterializedCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::MaterializedCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::ExternalFunction(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::MatchRecognize(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -153,6 +188,13 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::Apply(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::MaterializedCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::MaterializedCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::ExternalFunction(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::MatchRecognize(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -206,6 +248,27 @@ impl Operator for RelOperator {
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::RecursiveCte(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::Apply(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::MaterializedCte(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::MaterializedCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::ExternalFunction(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::MatchRecognize(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -470,3 +533,141 @@ impl TryFrom<RelOperator> for ProjectSet {
         }
     }
 }
+
+impl From<RecursiveCte> for RelOperator {
+    fn from(value: RecursiveCte) -> Self {
+        Self::RecursiveCte(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCte {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::RecursiveCte(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCte",
+            ))
+        }
+    }
+}
+
+impl From<RecursiveCteScan> for RelOperator {
+    fn from(value: RecursiveCteScan) -> Self {
+        Self::RecursiveCteScan(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCteScan {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::RecursiveCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCteScan",
+            ))
+        }
+    }
+}
+
+impl From<Apply> for RelOperator {
+    fn from(value: Apply) -> Self {
+        Self::Apply(value)
+    }
+}
+
+impl TryFrom<RelOperator> for Apply {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::Apply(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal("Cannot downcast RelOperator to Apply"))
+        }
+    }
+}
+
+impl From<MaterializedCte> for RelOperator {
+    fn from(value: MaterializedCte) -> Self {
+        Self::MaterializedCte(value)
+    }
+}
+
+impl TryFrom<RelOperator> for MaterializedCte {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::MaterializedCte(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to MaterializedCte",
+            ))
+        }
+    }
+}
+
+impl From<MaterializedCteScan> for RelOperator {
+    fn from(value: MaterializedCteScan) -> Self {
+        Self::MaterializedCteScan(value)
+    }
+}
+
+impl TryFrom<RelOperator> for MaterializedCteScan {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::MaterializedCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to MaterializedCteScan",
+            ))
+        }
+    }
+}
+
+impl From<ExternalFunction> for RelOperator {
+    fn from(value: ExternalFunction) -> Self {
+        Self::ExternalFunction(value)
+    }
+}
+
+impl TryFrom<RelOperator> for ExternalFunction {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::ExternalFunction(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to ExternalFunction",
+            ))
+        }
+    }
+}
+
+impl From<MatchRecognize> for RelOperator {
+    fn from(value: MatchRecognize) -> Self {
+        Self::MatchRecognize(value)
+    }
+}
+
+impl TryFrom<RelOperator> for MatchRecognize {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::MatchRecognize(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to MatchRecognize",
+            ))
+        }
+    }
+}
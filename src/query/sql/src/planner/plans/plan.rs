@@ -29,6 +29,7 @@ use crate::plans::copy::CopyPlan;
 use crate::plans::insert::Insert;
 use crate::plans::presign::PresignPlan;
 use crate::plans::recluster_table::ReclusterTablePlan;
+use crate::plans::share::AlterShareCommentPlan;
 use crate::plans::share::AlterShareTenantsPlan;
 use crate::plans::share::CreateSharePlan;
 use crate::plans::share::DescSharePlan;
@@ -39,55 +40,97 @@ use crate::plans::share::ShowGrantTenantsOfSharePlan;
 use crate::plans::share::ShowObjectGrantPrivilegesPlan;
 use crate::plans::share::ShowSharesPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
+use crate::plans::AlterStagePlan;
 use crate::plans::AlterTableClusterKeyPlan;
+use crate::plans::AlterTaskPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::AttachTablePlan;
+use crate::plans::BuildTableIndexPlan;
 use crate::plans::CallPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
+use crate::plans::CreateExternalFunctionPlan;
 use crate::plans::CreateFileFormatPlan;
+use crate::plans::CreateMaskingPolicyPlan;
+use crate::plans::CreateNetworkPolicyPlan;
+use crate::plans::CreatePipePlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::CreateSequencePlan;
 use crate::plans::CreateStagePlan;
+use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTaskPlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::DeletePlan;
+use crate::plans::DescNetworkPolicyPlan;
+use crate::plans::DescribeFunctionPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropDatabasePlan;
+use crate::plans::DropExternalFunctionPlan;
 use crate::plans::DropFileFormatPlan;
+use crate::plans::DropMaskingPolicyPlan;
+use crate::plans::DropNetworkPolicyPlan;
+use crate::plans::DropPipePlan;
+use crate::plans::DropResultCachePlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::DropSequencePlan;
 use crate::plans::DropStagePlan;
+use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
+use crate::plans::ExecuteTaskPlan;
 use crate::plans::ExistsTablePlan;
+use crate::plans::ExportTableSnapshotPlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
+use crate::plans::ImportTableSnapshotPlan;
+use crate::plans::InsertMultiTable;
 use crate::plans::KillPlan;
+use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
+use crate::plans::RenameStagePlan;
+use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::Replace;
 use crate::plans::RevertTablePlan;
 use crate::plans::RevokePrivilegePlan;
 use crate::plans::RevokeRolePlan;
+use crate::plans::SetDatabaseQuotaPlan;
 use crate::plans::SetRolePlan;
+use crate::plans::SetTableAnalyzeIntervalPlan;
+use crate::plans::SetTableConnectionPlan;
 use crate::plans::SettingPlan;
+use crate::plans::ShowColumnsPlan;
 use crate::plans::ShowCreateCatalogPlan;
 use crate::plans::ShowCreateDatabasePlan;
+use crate::plans::ShowCreateStagePlan;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantsPlan;
+use crate::plans::ShowIndexBuildStatusPlan;
+use crate::plans::ShowNetworkPoliciesPlan;
+use crate::plans::ShowRoleHierarchyPlan;
 use crate::plans::ShowRolesPlan;
+use crate::plans::ShowRowAccessPoliciesPlan;
+use crate::plans::ShowSequencesPlan;
 use crate::plans::ShowShareEndpointPlan;
+use crate::plans::ShowStageFilesPlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UnSettingPlan;
 use crate::plans::UndropDatabasePlan;
@@ -141,28 +184,41 @@ pub enum Plan {
     DropDatabase(Box<DropDatabasePlan>),
     UndropDatabase(Box<UndropDatabasePlan>),
     RenameDatabase(Box<RenameDatabasePlan>),
+    SetDatabaseQuota(Box<SetDatabaseQuotaPlan>),
     UseDatabase(Box<UseDatabasePlan>),
 
     // Tables
     ShowCreateTable(Box<ShowCreateTablePlan>),
     DescribeTable(Box<DescribeTablePlan>),
+    ShowColumns(Box<ShowColumnsPlan>),
     CreateTable(Box<CreateTablePlan>),
+    AttachTable(Box<AttachTablePlan>),
+    ExportTableSnapshot(Box<ExportTableSnapshotPlan>),
+    ImportTableSnapshot(Box<ImportTableSnapshotPlan>),
     DropTable(Box<DropTablePlan>),
     UndropTable(Box<UndropTablePlan>),
     RenameTable(Box<RenameTablePlan>),
     AddTableColumn(Box<AddTableColumnPlan>),
     DropTableColumn(Box<DropTableColumnPlan>),
+    RenameTableColumn(Box<RenameTableColumnPlan>),
+    ModifyTableColumn(Box<ModifyTableColumnPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
+    SetTableAnalyzeInterval(Box<SetTableAnalyzeIntervalPlan>),
+    SetTableConnection(Box<SetTableConnectionPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
     RevertTable(Box<RevertTablePlan>),
     TruncateTable(Box<TruncateTablePlan>),
     OptimizeTable(Box<OptimizeTablePlan>),
+    BuildTableIndex(Box<BuildTableIndexPlan>),
     AnalyzeTable(Box<AnalyzeTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
 
     // Insert
     Insert(Box<Insert>),
+    InsertMultiTable(Box<InsertMultiTable>),
     Replace(Box<Replace>),
     Delete(Box<DeletePlan>),
     Update(Box<UpdatePlan>),
@@ -172,6 +228,20 @@ pub enum Plan {
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
 
+    // Streams
+    CreateStream(Box<CreateStreamPlan>),
+    DropStream(Box<DropStreamPlan>),
+
+    // Tasks
+    CreateTask(Box<CreateTaskPlan>),
+    AlterTask(Box<AlterTaskPlan>),
+    DropTask(Box<DropTaskPlan>),
+    ExecuteTask(Box<ExecuteTaskPlan>),
+
+    // Pipes
+    CreatePipe(Box<CreatePipePlan>),
+    DropPipe(Box<DropPipePlan>),
+
     // Account
     AlterUser(Box<AlterUserPlan>),
     CreateUser(Box<CreateUserPlan>),
@@ -181,9 +251,38 @@ pub enum Plan {
     CreateUDF(Box<CreateUDFPlan>),
     AlterUDF(Box<AlterUDFPlan>),
     DropUDF(Box<DropUDFPlan>),
+    DescribeFunction(Box<DescribeFunctionPlan>),
+
+    // External functions
+    CreateExternalFunction(Box<CreateExternalFunctionPlan>),
+    DropExternalFunction(Box<DropExternalFunctionPlan>),
+
+    // Masking policies
+    CreateMaskingPolicy(Box<CreateMaskingPolicyPlan>),
+    DropMaskingPolicy(Box<DropMaskingPolicyPlan>),
+
+    // Row access policies
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+    ShowRowAccessPolicies(Box<ShowRowAccessPoliciesPlan>),
+
+    // Sequences
+    CreateSequence(Box<CreateSequencePlan>),
+    DropSequence(Box<DropSequencePlan>),
+    ShowSequences(Box<ShowSequencesPlan>),
+
+    // Indexes
+    ShowIndexBuildStatus(Box<ShowIndexBuildStatusPlan>),
+
+    // Network policies
+    CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
+    DropNetworkPolicy(Box<DropNetworkPolicyPlan>),
+    DescNetworkPolicy(Box<DescNetworkPolicyPlan>),
+    ShowNetworkPolicies(Box<ShowNetworkPoliciesPlan>),
 
     // Role
     ShowRoles(Box<ShowRolesPlan>),
+    ShowRoleHierarchy(Box<ShowRoleHierarchyPlan>),
     CreateRole(Box<CreateRolePlan>),
     DropRole(Box<DropRolePlan>),
     GrantRole(Box<GrantRolePlan>),
@@ -200,8 +299,12 @@ pub enum Plan {
 
     // Stages
     CreateStage(Box<CreateStagePlan>),
+    AlterStage(Box<AlterStagePlan>),
+    RenameStage(Box<RenameStagePlan>),
+    ShowCreateStage(Box<ShowCreateStagePlan>),
     DropStage(Box<DropStagePlan>),
     RemoveStage(Box<RemoveStagePlan>),
+    ShowStageFiles(Box<ShowStageFilesPlan>),
 
     // Presign
     Presign(Box<PresignPlan>),
@@ -211,6 +314,14 @@ pub enum Plan {
     UnSetVariable(Box<UnSettingPlan>),
     Kill(Box<KillPlan>),
 
+    // Result cache
+    DropResultCache(Box<DropResultCachePlan>),
+
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
     // Share
     CreateShareEndpoint(Box<CreateShareEndpointPlan>),
     ShowShareEndpoint(Box<ShowShareEndpointPlan>),
@@ -220,6 +331,7 @@ pub enum Plan {
     GrantShareObject(Box<GrantShareObjectPlan>),
     RevokeShareObject(Box<RevokeShareObjectPlan>),
     AlterShareTenants(Box<AlterShareTenantsPlan>),
+    AlterShareComment(Box<AlterShareCommentPlan>),
     DescShare(Box<DescSharePlan>),
     ShowShares(Box<ShowSharesPlan>),
     ShowObjectGrantPrivileges(Box<ShowObjectGrantPrivilegesPlan>),
@@ -229,6 +341,7 @@ pub enum Plan {
 #[derive(Clone, Debug)]
 pub enum RewriteKind {
     ShowSettings,
+    ShowVariables,
     ShowMetrics,
     ShowProcessList,
     ShowEngines,
@@ -236,14 +349,16 @@ pub enum RewriteKind {
     ShowCatalogs,
     ShowDatabases,
     ShowTables,
-    ShowColumns,
     ShowTablesStatus,
+    ShowStreams,
+    ShowCopyHistory,
 
     ShowFunctions,
     ShowTableFunctions,
 
     ShowUsers,
     ShowStages,
+    ShowPipes,
     DescribeStage,
     ListStage,
     ShowRoles,
@@ -265,45 +380,87 @@ impl Display for Plan {
             Plan::UndropDatabase(_) => write!(f, "UndropDatabase"),
             Plan::UseDatabase(_) => write!(f, "UseDatabase"),
             Plan::RenameDatabase(_) => write!(f, "RenameDatabase"),
+            Plan::SetDatabaseQuota(_) => write!(f, "SetDatabaseQuota"),
             Plan::ShowCreateTable(_) => write!(f, "ShowCreateTable"),
             Plan::DescribeTable(_) => write!(f, "DescribeTable"),
+            Plan::ShowColumns(_) => write!(f, "ShowColumns"),
             Plan::CreateTable(_) => write!(f, "CreateTable"),
+            Plan::AttachTable(_) => write!(f, "AttachTable"),
+            Plan::ExportTableSnapshot(_) => write!(f, "ExportTableSnapshot"),
+            Plan::ImportTableSnapshot(_) => write!(f, "ImportTableSnapshot"),
             Plan::DropTable(_) => write!(f, "DropTable"),
             Plan::UndropTable(_) => write!(f, "UndropTable"),
             Plan::RenameTable(_) => write!(f, "RenameTable"),
             Plan::AddTableColumn(_) => write!(f, "AddTableColumn"),
             Plan::DropTableColumn(_) => write!(f, "DropTableColumn"),
+            Plan::RenameTableColumn(_) => write!(f, "RenameTableColumn"),
+            Plan::ModifyTableColumn(_) => write!(f, "ModifyTableColumn"),
             Plan::AlterTableClusterKey(_) => write!(f, "AlterTableClusterKey"),
             Plan::DropTableClusterKey(_) => write!(f, "DropTableClusterKey"),
+            Plan::SetTableAnalyzeInterval(_) => write!(f, "SetTableAnalyzeInterval"),
+            Plan::SetTableConnection(_) => write!(f, "SetTableConnection"),
+            Plan::AddTableRowAccessPolicy(_) => write!(f, "AddTableRowAccessPolicy"),
+            Plan::DropTableRowAccessPolicy(_) => write!(f, "DropTableRowAccessPolicy"),
             Plan::ReclusterTable(_) => write!(f, "ReclusterTable"),
             Plan::TruncateTable(_) => write!(f, "TruncateTable"),
             Plan::OptimizeTable(_) => write!(f, "OptimizeTable"),
+            Plan::BuildTableIndex(_) => write!(f, "BuildTableIndex"),
             Plan::AnalyzeTable(_) => write!(f, "AnalyzeTable"),
             Plan::ExistsTable(_) => write!(f, "ExistsTable"),
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateStream(_) => write!(f, "CreateStream"),
+            Plan::DropStream(_) => write!(f, "DropStream"),
+            Plan::CreateTask(_) => write!(f, "CreateTask"),
+            Plan::AlterTask(_) => write!(f, "AlterTask"),
+            Plan::DropTask(_) => write!(f, "DropTask"),
+            Plan::ExecuteTask(_) => write!(f, "ExecuteTask"),
+            Plan::CreatePipe(_) => write!(f, "CreatePipe"),
+            Plan::DropPipe(_) => write!(f, "DropPipe"),
             Plan::AlterUser(_) => write!(f, "AlterUser"),
             Plan::CreateUser(_) => write!(f, "CreateUser"),
             Plan::DropUser(_) => write!(f, "DropUser"),
             Plan::CreateRole(_) => write!(f, "CreateRole"),
             Plan::DropRole(_) => write!(f, "DropRole"),
             Plan::CreateStage(_) => write!(f, "CreateStage"),
+            Plan::AlterStage(_) => write!(f, "AlterStage"),
+            Plan::RenameStage(_) => write!(f, "RenameStage"),
+            Plan::ShowCreateStage(_) => write!(f, "ShowCreateStage"),
             Plan::DropStage(_) => write!(f, "DropStage"),
             Plan::CreateFileFormat(_) => write!(f, "CreateFileFormat"),
             Plan::DropFileFormat(_) => write!(f, "DropFileFormat"),
             Plan::ShowFileFormats(_) => write!(f, "ShowFileFormats"),
             Plan::RemoveStage(_) => write!(f, "RemoveStage"),
+            Plan::ShowStageFiles(_) => write!(f, "ShowStageFiles"),
             Plan::GrantRole(_) => write!(f, "GrantRole"),
             Plan::GrantPriv(_) => write!(f, "GrantPriv"),
             Plan::ShowGrants(_) => write!(f, "ShowGrants"),
             Plan::ShowRoles(_) => write!(f, "ShowRoles"),
+            Plan::ShowRoleHierarchy(_) => write!(f, "ShowRoleHierarchy"),
             Plan::RevokePriv(_) => write!(f, "RevokePriv"),
             Plan::RevokeRole(_) => write!(f, "RevokeRole"),
             Plan::CreateUDF(_) => write!(f, "CreateUDF"),
             Plan::AlterUDF(_) => write!(f, "AlterUDF"),
             Plan::DropUDF(_) => write!(f, "DropUDF"),
+            Plan::DescribeFunction(_) => write!(f, "DescribeFunction"),
+            Plan::CreateExternalFunction(_) => write!(f, "CreateExternalFunction"),
+            Plan::DropExternalFunction(_) => write!(f, "DropExternalFunction"),
+            Plan::CreateMaskingPolicy(_) => write!(f, "CreateMaskingPolicy"),
+            Plan::DropMaskingPolicy(_) => write!(f, "DropMaskingPolicy"),
+            Plan::CreateRowAccessPolicy(_) => write!(f, "CreateRowAccessPolicy"),
+            Plan::DropRowAccessPolicy(_) => write!(f, "DropRowAccessPolicy"),
+            Plan::ShowRowAccessPolicies(_) => write!(f, "ShowRowAccessPolicies"),
+            Plan::CreateSequence(_) => write!(f, "CreateSequence"),
+            Plan::DropSequence(_) => write!(f, "DropSequence"),
+            Plan::ShowSequences(_) => write!(f, "ShowSequences"),
+            Plan::ShowIndexBuildStatus(_) => write!(f, "ShowIndexBuildStatus"),
+            Plan::CreateNetworkPolicy(_) => write!(f, "CreateNetworkPolicy"),
+            Plan::DropNetworkPolicy(_) => write!(f, "DropNetworkPolicy"),
+            Plan::DescNetworkPolicy(_) => write!(f, "DescNetworkPolicy"),
+            Plan::ShowNetworkPolicies(_) => write!(f, "ShowNetworkPolicies"),
             Plan::Insert(_) => write!(f, "Insert"),
+            Plan::InsertMultiTable(_) => write!(f, "InsertMultiTable"),
             Plan::Replace(_) => write!(f, "Replace"),
             Plan::Delete(_) => write!(f, "Delete"),
             Plan::Update(_) => write!(f, "Update"),
@@ -313,6 +470,10 @@ impl Display for Plan {
             Plan::UnSetVariable(_) => write!(f, "UnSetVariable"),
             Plan::SetRole(_) => write!(f, "SetRole"),
             Plan::Kill(_) => write!(f, "Kill"),
+            Plan::DropResultCache(_) => write!(f, "DropResultCache"),
+            Plan::Begin => write!(f, "Begin"),
+            Plan::Commit => write!(f, "Commit"),
+            Plan::Abort => write!(f, "Abort"),
             Plan::CreateShareEndpoint(_) => write!(f, "CreateShareEndpoint"),
             Plan::ShowShareEndpoint(_) => write!(f, "ShowShareEndpoint"),
             Plan::DropShareEndpoint(_) => write!(f, "DropShareEndpoint"),
@@ -321,6 +482,7 @@ impl Display for Plan {
             Plan::GrantShareObject(_) => write!(f, "GrantShareObject"),
             Plan::RevokeShareObject(_) => write!(f, "RevokeShareObject"),
             Plan::AlterShareTenants(_) => write!(f, "AlterShareTenants"),
+            Plan::AlterShareComment(_) => write!(f, "AlterShareComment"),
             Plan::DescShare(_) => write!(f, "DescShare"),
             Plan::ShowShares(_) => write!(f, "ShowShares"),
             Plan::ShowObjectGrantPrivileges(_) => write!(f, "ShowObjectGrantPrivileges"),
@@ -359,36 +521,61 @@ impl Plan {
             Plan::DropDatabase(plan) => plan.schema(),
             Plan::UndropDatabase(plan) => plan.schema(),
             Plan::RenameDatabase(plan) => plan.schema(),
+            Plan::SetDatabaseQuota(plan) => plan.schema(),
             Plan::ShowCreateTable(plan) => plan.schema(),
             Plan::DescribeTable(plan) => plan.schema(),
+            Plan::ShowColumns(plan) => plan.schema(),
             Plan::CreateTable(plan) => plan.schema(),
+            Plan::AttachTable(plan) => plan.schema(),
+            Plan::ExportTableSnapshot(plan) => plan.schema(),
+            Plan::ImportTableSnapshot(plan) => plan.schema(),
             Plan::DropTable(plan) => plan.schema(),
             Plan::UndropTable(plan) => plan.schema(),
             Plan::RenameTable(plan) => plan.schema(),
             Plan::AddTableColumn(plan) => plan.schema(),
             Plan::DropTableColumn(plan) => plan.schema(),
+            Plan::RenameTableColumn(plan) => plan.schema(),
+            Plan::ModifyTableColumn(plan) => plan.schema(),
             Plan::AlterTableClusterKey(plan) => plan.schema(),
             Plan::DropTableClusterKey(plan) => plan.schema(),
+            Plan::SetTableAnalyzeInterval(plan) => plan.schema(),
+            Plan::SetTableConnection(plan) => plan.schema(),
+            Plan::AddTableRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropTableRowAccessPolicy(plan) => plan.schema(),
             Plan::ReclusterTable(plan) => plan.schema(),
             Plan::TruncateTable(plan) => plan.schema(),
             Plan::OptimizeTable(plan) => plan.schema(),
+            Plan::BuildTableIndex(plan) => plan.schema(),
             Plan::AnalyzeTable(plan) => plan.schema(),
             Plan::ExistsTable(plan) => plan.schema(),
             Plan::CreateView(plan) => plan.schema(),
             Plan::AlterView(plan) => plan.schema(),
             Plan::DropView(plan) => plan.schema(),
+            Plan::CreateStream(plan) => plan.schema(),
+            Plan::DropStream(plan) => plan.schema(),
+            Plan::CreateTask(plan) => plan.schema(),
+            Plan::AlterTask(plan) => plan.schema(),
+            Plan::DropTask(plan) => plan.schema(),
+            Plan::ExecuteTask(plan) => plan.schema(),
+            Plan::CreatePipe(plan) => plan.schema(),
+            Plan::DropPipe(plan) => plan.schema(),
             Plan::AlterUser(plan) => plan.schema(),
             Plan::CreateUser(plan) => plan.schema(),
             Plan::DropUser(plan) => plan.schema(),
             Plan::CreateRole(plan) => plan.schema(),
             Plan::DropRole(plan) => plan.schema(),
             Plan::ShowRoles(plan) => plan.schema(),
+            Plan::ShowRoleHierarchy(plan) => plan.schema(),
             Plan::GrantRole(plan) => plan.schema(),
             Plan::GrantPriv(plan) => plan.schema(),
             Plan::ShowGrants(plan) => plan.schema(),
             Plan::CreateStage(plan) => plan.schema(),
+            Plan::AlterStage(plan) => plan.schema(),
+            Plan::RenameStage(plan) => plan.schema(),
+            Plan::ShowCreateStage(plan) => plan.schema(),
             Plan::DropStage(plan) => plan.schema(),
             Plan::RemoveStage(plan) => plan.schema(),
+            Plan::ShowStageFiles(plan) => plan.schema(),
             Plan::CreateFileFormat(plan) => plan.schema(),
             Plan::DropFileFormat(plan) => plan.schema(),
             Plan::ShowFileFormats(plan) => plan.schema(),
@@ -397,7 +584,24 @@ impl Plan {
             Plan::CreateUDF(_) => Arc::new(DataSchema::empty()),
             Plan::AlterUDF(_) => Arc::new(DataSchema::empty()),
             Plan::DropUDF(_) => Arc::new(DataSchema::empty()),
+            Plan::DescribeFunction(plan) => plan.schema(),
+            Plan::CreateExternalFunction(plan) => plan.schema(),
+            Plan::DropExternalFunction(plan) => plan.schema(),
+            Plan::CreateMaskingPolicy(plan) => plan.schema(),
+            Plan::DropMaskingPolicy(plan) => plan.schema(),
+            Plan::CreateRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropRowAccessPolicy(plan) => plan.schema(),
+            Plan::ShowRowAccessPolicies(plan) => plan.schema(),
+            Plan::CreateSequence(plan) => plan.schema(),
+            Plan::DropSequence(plan) => plan.schema(),
+            Plan::ShowSequences(plan) => plan.schema(),
+            Plan::ShowIndexBuildStatus(plan) => plan.schema(),
+            Plan::CreateNetworkPolicy(plan) => plan.schema(),
+            Plan::DropNetworkPolicy(plan) => plan.schema(),
+            Plan::DescNetworkPolicy(plan) => plan.schema(),
+            Plan::ShowNetworkPolicies(plan) => plan.schema(),
             Plan::Insert(plan) => plan.schema(),
+            Plan::InsertMultiTable(plan) => plan.schema(),
             Plan::Replace(plan) => plan.schema(),
             Plan::Delete(_) => Arc::new(DataSchema::empty()),
             Plan::Update(_) => Arc::new(DataSchema::empty()),
@@ -407,6 +611,8 @@ impl Plan {
             Plan::UnSetVariable(plan) => plan.schema(),
             Plan::SetRole(plan) => plan.schema(),
             Plan::Kill(_) => Arc::new(DataSchema::empty()),
+            Plan::DropResultCache(plan) => plan.schema(),
+            Plan::Begin | Plan::Commit | Plan::Abort => Arc::new(DataSchema::empty()),
             Plan::CreateShareEndpoint(plan) => plan.schema(),
             Plan::ShowShareEndpoint(plan) => plan.schema(),
             Plan::DropShareEndpoint(plan) => plan.schema(),
@@ -415,6 +621,7 @@ impl Plan {
             Plan::GrantShareObject(plan) => plan.schema(),
             Plan::RevokeShareObject(plan) => plan.schema(),
             Plan::AlterShareTenants(plan) => plan.schema(),
+            Plan::AlterShareComment(plan) => plan.schema(),
             Plan::DescShare(plan) => plan.schema(),
             Plan::ShowShares(plan) => plan.schema(),
             Plan::ShowObjectGrantPrivileges(plan) => plan.schema(),
@@ -435,13 +642,23 @@ impl Plan {
                 | Plan::ShowCreateDatabase(_)
                 | Plan::ShowCreateTable(_)
                 | Plan::ShowFileFormats(_)
+                | Plan::DescNetworkPolicy(_)
+                | Plan::ShowNetworkPolicies(_)
+                | Plan::ShowRowAccessPolicies(_)
+                | Plan::ShowSequences(_)
+                | Plan::ShowIndexBuildStatus(_)
+                | Plan::ShowStageFiles(_)
+                | Plan::ShowCreateStage(_)
                 | Plan::ShowRoles(_)
+                | Plan::ShowRoleHierarchy(_)
                 | Plan::DescShare(_)
                 | Plan::ShowShares(_)
                 | Plan::ShowShareEndpoint(_)
                 | Plan::ShowObjectGrantPrivileges(_)
                 | Plan::ShowGrantTenantsOfShare(_)
                 | Plan::DescribeTable(_)
+                | Plan::ShowColumns(_)
+                | Plan::DescribeFunction(_)
                 | Plan::ShowGrants(_)
                 | Plan::Presign(_)
         )
@@ -28,6 +28,8 @@ pub struct ReclusterTablePlan {
     pub is_final: bool,
     pub metadata: MetadataRef,
     pub push_downs: Option<ScalarExpr>,
+    /// The maximum number of blocks to recluster in each round.
+    pub limit: Option<usize>,
 }
 
 impl ReclusterTablePlan {
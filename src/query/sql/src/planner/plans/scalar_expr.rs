@@ -20,6 +20,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
 use common_expression::types::DataType;
+use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use educe::Educe;
 
@@ -41,6 +42,7 @@ pub enum ScalarExpr {
     WindowFunction(WindowFunc),
     AggregateFunction(AggregateFunction),
     FunctionCall(FunctionCall),
+    LambdaFunction(LambdaFunc),
     // TODO(leiysky): maybe we don't need this variant any more
     // after making functions static typed?
     CastExpr(CastExpr),
@@ -94,6 +96,13 @@ impl ScalarExpr {
                 }
                 result
             }
+            ScalarExpr::LambdaFunction(scalar) => {
+                let mut result = ColumnSet::new();
+                for scalar in &scalar.args {
+                    result = result.union(&scalar.used_columns()).cloned().collect();
+                }
+                result
+            }
             ScalarExpr::CastExpr(scalar) => scalar.argument.used_columns(),
             ScalarExpr::SubqueryExpr(scalar) => scalar.outer_columns.clone(),
         }
@@ -104,6 +113,7 @@ impl ScalarExpr {
             ScalarExpr::BoundColumnRef(expr) => expr.span,
             ScalarExpr::ConstantExpr(expr) => expr.span,
             ScalarExpr::FunctionCall(expr) => expr.span,
+            ScalarExpr::LambdaFunction(expr) => expr.span,
             ScalarExpr::CastExpr(expr) => expr.span,
             ScalarExpr::SubqueryExpr(expr) => expr.span,
             _ => None,
@@ -281,6 +291,23 @@ impl TryFrom<ScalarExpr> for FunctionCall {
     }
 }
 
+impl From<LambdaFunc> for ScalarExpr {
+    fn from(v: LambdaFunc) -> Self {
+        Self::LambdaFunction(v)
+    }
+}
+
+impl TryFrom<ScalarExpr> for LambdaFunc {
+    type Error = ErrorCode;
+    fn try_from(value: ScalarExpr) -> Result<Self> {
+        if let ScalarExpr::LambdaFunction(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal("Cannot downcast Scalar to LambdaFunc"))
+        }
+    }
+}
+
 impl From<CastExpr> for ScalarExpr {
     fn from(v: CastExpr) -> Self {
         Self::CastExpr(v)
@@ -367,6 +394,8 @@ pub enum ComparisonOp {
     GTE,
     // Less or equal "<="
     LTE,
+    // NULL-safe equal "IS NOT DISTINCT FROM"
+    NullSafeEqual,
 }
 
 impl ComparisonOp {
@@ -392,6 +421,7 @@ impl ComparisonOp {
             ComparisonOp::LT => "lt",
             ComparisonOp::GTE => "gte",
             ComparisonOp::LTE => "lte",
+            ComparisonOp::NullSafeEqual => "is_not_distinct_from",
         }
     }
 }
@@ -424,9 +454,13 @@ pub struct AggregateFunction {
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct WindowFunc {
+    /// The aggregate function, or a general window function like `lag`, whose
+    /// offset or `n` is the only parameter.
     pub agg_func: AggregateFunction,
     pub partition_by: Vec<ScalarExpr>,
     pub frame: WindowFuncFrame,
+    /// `IGNORE NULLS`, only for `lag`, `lead` and `nth_value`.
+    pub ignore_nulls: bool,
 }
 
 impl WindowFunc {
@@ -456,6 +490,7 @@ pub enum WindowFuncFrameBound {
 pub enum WindowFuncFrameUnits {
     Rows,
     Range,
+    Groups,
 }
 
 #[derive(Clone, Debug, Educe)]
@@ -468,6 +503,56 @@ pub struct FunctionCall {
     pub arguments: Vec<ScalarExpr>,
 }
 
+/// A higher-order function call like `array_map(arr, x -> x + 1)`.
+#[derive(Clone, Debug, Educe)]
+#[educe(PartialEq, Eq, Hash)]
+pub struct LambdaFunc {
+    #[educe(Hash(ignore), PartialEq(ignore), Eq(ignore))]
+    pub span: Span,
+    pub func_name: String,
+    /// The array argument, followed by the outer columns captured by the lambda.
+    pub args: Vec<ScalarExpr>,
+    /// The lambda body, it refers to the lambda params and then to the captured
+    /// columns by position.
+    #[educe(Hash(ignore))]
+    pub lambda_expr: Box<RemoteExpr>,
+    pub lambda_display: String,
+    pub return_type: Box<DataType>,
+}
+
+impl LambdaFunc {
+    /// Replace the arguments. Since the lambda body has been type checked with the original
+    /// argument types, the new arguments are casted back to them if their types changed.
+    pub fn with_args(&self, args: Vec<ScalarExpr>) -> Result<Self> {
+        let args = self
+            .args
+            .iter()
+            .zip(args)
+            .map(|(old_arg, new_arg)| {
+                let data_type = old_arg.data_type()?;
+                if new_arg.data_type()? == data_type {
+                    Ok(new_arg)
+                } else {
+                    Ok(ScalarExpr::CastExpr(CastExpr {
+                        span: new_arg.span(),
+                        is_try: false,
+                        argument: Box::new(new_arg),
+                        target_type: Box::new(data_type),
+                    }))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(LambdaFunc {
+            span: self.span,
+            func_name: self.func_name.clone(),
+            args,
+            lambda_expr: self.lambda_expr.clone(),
+            lambda_display: self.lambda_display.clone(),
+            return_type: self.return_type.clone(),
+        })
+    }
+}
+
 #[derive(Clone, Debug, Educe)]
 #[educe(PartialEq, Eq, Hash)]
 pub struct CastExpr {
@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_catalog::plan::SampleInfo;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
@@ -66,6 +67,7 @@ pub struct Scan {
     pub limit: Option<usize>,
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
+    pub sample: Option<SampleInfo>,
 
     pub statistics: Statistics,
 }
@@ -92,6 +94,7 @@ impl Scan {
                 is_accurate: self.statistics.is_accurate,
             },
             prewhere,
+            sample: self.sample,
         }
     }
 }
@@ -101,6 +104,7 @@ impl PartialEq for Scan {
         self.table_index == other.table_index
             && self.columns == other.columns
             && self.push_down_predicates == other.push_down_predicates
+            && self.sample == other.sample
     }
 }
 
@@ -172,8 +176,8 @@ impl Operator for Scan {
             }
         }
 
-        // If prewhere is not none, we can't get precise cardinality
-        let precise_cardinality = if self.prewhere.is_none() {
+        // If prewhere or sample is not none, we can't get precise cardinality
+        let precise_cardinality = if self.prewhere.is_none() && self.sample.is_none() {
             self.statistics
                 .statistics
                 .as_ref()
@@ -182,15 +186,20 @@ impl Operator for Scan {
             None
         };
 
+        let mut cardinality = self
+            .statistics
+            .statistics
+            .as_ref()
+            .map_or(0.0, |stat| stat.num_rows.map_or(0.0, |num| num as f64));
+        if let Some(sample) = &self.sample {
+            cardinality *= sample.fraction();
+        }
+
         Ok(RelationalProperty {
             output_columns: self.columns.clone(),
             outer_columns: Default::default(),
             used_columns,
-            cardinality: self
-                .statistics
-                .statistics
-                .as_ref()
-                .map_or(0.0, |stat| stat.num_rows.map_or(0.0, |num| num as f64)),
+            cardinality,
             statistics: OpStatistics {
                 precise_cardinality,
                 column_stats,
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_expression::Scalar;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct VarValue {
@@ -24,9 +25,19 @@ pub struct VarValue {
     pub value: String,
 }
 
+/// A user-defined variable of the session, set by `SET @variable = <expr>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UserVarValue {
+    pub variable: String,
+    pub value: Scalar,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SettingPlan {
+    /// System settings.
     pub vars: Vec<VarValue>,
+    /// User-defined variables, they are kept in the session instead of the settings.
+    pub user_vars: Vec<UserVarValue>,
 }
 
 impl SettingPlan {
@@ -38,6 +49,7 @@ impl SettingPlan {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UnSettingPlan {
     pub vars: Vec<String>,
+    pub user_vars: Vec<String>,
 }
 
 impl UnSettingPlan {
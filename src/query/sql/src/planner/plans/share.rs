@@ -205,6 +205,7 @@ pub struct AlterShareTenantsPlan {
     pub if_exists: bool,
     pub accounts: Vec<String>,
     pub is_add: bool,
+    pub read_only: bool,
 }
 
 impl AlterShareTenantsPlan {
@@ -213,6 +214,20 @@ impl AlterShareTenantsPlan {
     }
 }
 
+// Alter Share Comment Plan
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterShareCommentPlan {
+    pub share: String,
+    pub if_exists: bool,
+    pub comment: String,
+}
+
+impl AlterShareCommentPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // desc share
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescSharePlan {
@@ -231,7 +246,10 @@ impl DescSharePlan {
 
 // show share
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct ShowSharesPlan {}
+pub struct ShowSharesPlan {
+    /// Tenant whose shares are listed, the current tenant if not specified.
+    pub tenant: Option<String>,
+}
 
 impl ShowSharesPlan {
     pub fn schema(&self) -> DataSchemaRef {
@@ -63,6 +63,7 @@ impl VisitorMut for DistinctToGroupBy {
                             selection: selection.clone(),
                             group_by: Some(GroupBy::Normal(args.clone())),
                             having: None,
+                            connect_by: None,
                         })),
                         order_by: vec![],
                         limit: vec![],
@@ -85,17 +86,20 @@ impl VisitorMut for DistinctToGroupBy {
                                 args: vec![],
                                 params: vec![],
                                 window: None,
+                                lambda: None,
                             }),
                             alias: alias.clone(),
                         }],
                         from: vec![TableReference::Subquery {
                             span: None,
+                            lateral: false,
                             subquery: Box::new(subquery),
                             alias: None,
                         }],
                         selection: None,
                         group_by: None,
                         having: having.clone(),
+                        connect_by: None,
                     };
 
                     *stmt = new_stmt;
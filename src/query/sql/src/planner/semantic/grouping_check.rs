@@ -57,8 +57,15 @@ impl<'a> GroupingChecker<'a> {
                 }
             };
 
-            if let Some(grouping_id) = &self.bind_context.aggregate_info.grouping_id_column {
-                if grouping_id.index != column_binding.index {
+            // A group item becomes nullable only if some grouping set omits it.
+            let agg_info = &self.bind_context.aggregate_info;
+            if let Some(grouping_id) = &agg_info.grouping_id_column {
+                if grouping_id.index != column_binding.index
+                    && agg_info
+                        .grouping_sets
+                        .iter()
+                        .any(|set| !set.contains(&column_binding.index))
+                {
                     column_binding.data_type = Box::new(column_binding.data_type.wrap_nullable());
                 }
             }
@@ -120,6 +127,14 @@ impl<'a> GroupingChecker<'a> {
                 }
                 .into())
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.resolve(arg, span))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+                Ok(lambda.with_args(args)?.into())
+            }
             ScalarExpr::CastExpr(cast) => Ok(CastExpr {
                 span: cast.span,
                 is_try: cast.is_try,
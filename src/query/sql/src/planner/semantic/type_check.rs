@@ -21,8 +21,12 @@ use common_ast::ast::BinaryOperator;
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::IntervalKind as ASTIntervalKind;
+use common_ast::ast::Lambda;
+use common_ast::ast::ListAggOverflow;
 use common_ast::ast::Literal;
 use common_ast::ast::MapAccessor;
+use common_ast::ast::OrderByExpr;
+use common_ast::ast::PercentileKind;
 use common_ast::ast::Query;
 use common_ast::ast::SubqueryModifier;
 use common_ast::ast::TrimWhere;
@@ -30,7 +34,9 @@ use common_ast::ast::TypeName;
 use common_ast::ast::UnaryOperator;
 use common_ast::ast::WindowFrame;
 use common_ast::ast::WindowFrameBound;
+use common_ast::ast::WindowFrameExclusion;
 use common_ast::ast::WindowFrameUnits;
+use common_ast::ast::WindowSpec;
 use common_ast::parser::parse_expr;
 use common_ast::parser::tokenize_sql;
 use common_catalog::catalog::CatalogManager;
@@ -54,13 +60,18 @@ use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_functions::aggregates::AggregateCountFunction;
 use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::aggregates::OverflowMode;
+use common_functions::aggregates::StringAggParams;
 use common_functions::is_builtin_function;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_functions::GENERAL_LAMBDA_FUNCTIONS;
+use common_functions::GENERAL_WINDOW_FUNCTIONS;
 use common_users::UserApiProvider;
 use simsearch::SimSearch;
 
 use super::name_resolution::NameResolutionContext;
 use super::normalize_identifier;
+use crate::binder::find_scalars;
 use crate::binder::wrap_cast;
 use crate::binder::Binder;
 use crate::binder::ExprContext;
@@ -76,6 +87,7 @@ use crate::plans::ComparisonExpr;
 use crate::plans::ComparisonOp;
 use crate::plans::ConstantExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::ScalarExpr;
@@ -90,6 +102,7 @@ use crate::BindContext;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
 use crate::MetadataRef;
+use crate::Visibility;
 
 /// A helper for type checking.
 ///
@@ -111,6 +124,9 @@ pub struct TypeChecker<'a> {
     // true if current expr is inside an aggregate function.
     // This is used to check if there is nested aggregate function.
     in_aggregate_function: bool,
+
+    // Parameters of the lambda functions being resolved, the innermost ones come last.
+    lambda_params: Vec<ColumnBinding>,
 }
 
 impl<'a> TypeChecker<'a> {
@@ -128,6 +144,7 @@ impl<'a> TypeChecker<'a> {
             metadata,
             aliases,
             in_aggregate_function: false,
+            lambda_params: vec![],
         }
     }
 
@@ -154,6 +171,18 @@ impl<'a> TypeChecker<'a> {
             return Ok(Box::new((scalar.clone(), scalar.data_type()?)));
         }
 
+        if let Some(scalar) = self.bind_context.external_functions.get(&expr.to_string()) {
+            if !matches!(self.bind_context.expr_context, ExprContext::SelectClause) {
+                return Err(ErrorCode::SemanticError(
+                    "external functions are only allowed in SELECT clause",
+                )
+                .set_span(expr.span()));
+            }
+            // Found a call of external function, return the result column directly.
+            // See `Binder::bind_external_functions` for more details.
+            return Ok(Box::new((scalar.clone(), scalar.data_type()?)));
+        }
+
         let box (scalar, data_type): Box<(ScalarExpr, DataType)> = match expr {
             Expr::ColumnRef {
                 span,
@@ -168,6 +197,24 @@ impl<'a> TypeChecker<'a> {
                     .as_ref()
                     .map(|ident| normalize_identifier(ident, self.name_resolution_ctx).name);
                 let column = normalize_identifier(ident, self.name_resolution_ctx).name;
+                if database.is_none() && table.is_none() {
+                    if let Some(param) = self
+                        .lambda_params
+                        .iter()
+                        .rev()
+                        .find(|param| param.column_name == column)
+                    {
+                        let data_type = *param.data_type.clone();
+                        return Ok(Box::new((
+                            BoundColumnRef {
+                                span: *span,
+                                column: param.clone(),
+                            }
+                            .into(),
+                            data_type,
+                        )));
+                    }
+                }
                 let result = self.bind_context.resolve_name(
                     database.as_deref(),
                     table.as_deref(),
@@ -218,53 +265,32 @@ impl<'a> TypeChecker<'a> {
                 right,
                 not,
             } => {
-                let left_null_expr = Box::new(Expr::IsNull {
-                    span: *span,
-                    expr: left.clone(),
-                    not: false,
-                });
-                let right_null_expr = Box::new(Expr::IsNull {
-                    span: *span,
-                    expr: right.clone(),
-                    not: false,
-                });
-                let op = if *not {
-                    BinaryOperator::Eq
-                } else {
-                    BinaryOperator::NotEq
-                };
-                let (scalar, _) = *self
-                    .resolve_function(*span, "if", vec![], &[
-                        &Expr::BinaryOp {
-                            span: *span,
-                            op: BinaryOperator::And,
-                            left: left_null_expr.clone(),
-                            right: right_null_expr.clone(),
-                        },
-                        &Expr::Literal {
-                            span: *span,
-                            lit: Literal::Boolean(*not),
-                        },
-                        &Expr::BinaryOp {
-                            span: *span,
-                            op: BinaryOperator::Or,
-                            left: left_null_expr.clone(),
-                            right: right_null_expr.clone(),
-                        },
-                        &Expr::Literal {
-                            span: *span,
-                            lit: Literal::Boolean(!*not),
-                        },
-                        &Expr::BinaryOp {
-                            span: *span,
-                            op,
-                            left: left.clone(),
-                            right: right.clone(),
-                        },
+                let box (left, _) = self.resolve(left).await?;
+                let box (right, _) = self.resolve(right).await?;
+                let op = ComparisonOp::NullSafeEqual;
+                let (_, data_type) = *self
+                    .resolve_scalar_function_call(*span, op.to_func_name(), vec![], vec![
+                        left.clone(),
+                        right.clone(),
                     ])
                     .await?;
-                self.resolve_scalar_function_call(*span, "assume_not_null", vec![], vec![scalar])
-                    .await?
+                let scalar: ScalarExpr = ComparisonExpr {
+                    op,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                }
+                .into();
+                if *not {
+                    Box::new((scalar, data_type))
+                } else {
+                    Box::new((
+                        NotExpr {
+                            argument: Box::new(scalar),
+                        }
+                        .into(),
+                        data_type,
+                    ))
+                }
             }
 
             Expr::InList {
@@ -297,6 +323,7 @@ impl<'a> TypeChecker<'a> {
                             args: args.iter().copied().cloned().collect(),
                             params: vec![],
                             window: None,
+                            lambda: None,
                         })
                         .await?
                     } else {
@@ -529,6 +556,7 @@ impl<'a> TypeChecker<'a> {
                                 args: vec![*operand.clone(), c.clone()],
                                 params: vec![],
                                 window: None,
+                                lambda: None,
                             };
                             arguments.push(equal_expr)
                         }
@@ -572,6 +600,15 @@ impl<'a> TypeChecker<'a> {
                 Box::new((ConstantExpr { span: *span, value }.into(), data_type))
             }
 
+            Expr::Variable { span, name } => {
+                // The value of a variable may change between queries of the session.
+                self.ctx.set_cacheable(false);
+                // Like MySQL, a variable that has not been set is NULL.
+                let value = self.ctx.get_variable(name).unwrap_or(Scalar::Null);
+                let data_type = value.as_ref().infer_data_type();
+                Box::new((ConstantExpr { span: *span, value }.into(), data_type))
+            }
+
             Expr::FunctionCall {
                 span,
                 distinct,
@@ -579,14 +616,25 @@ impl<'a> TypeChecker<'a> {
                 args,
                 params,
                 window,
+                lambda,
             } => {
                 let func_name = normalize_identifier(name, self.name_resolution_ctx).to_string();
                 let func_name = func_name.as_str();
+                if lambda.is_some() || GENERAL_LAMBDA_FUNCTIONS.contains(&func_name) {
+                    return self
+                        .resolve_lambda_function(*span, func_name, args, lambda)
+                        .await;
+                }
                 if !is_builtin_function(func_name)
                     && !Self::all_rewritable_scalar_function().contains(&func_name)
                 {
                     if let Some(udf) = self.resolve_udf(*span, func_name, args).await? {
                         return Ok(udf);
+                    } else if self.is_external_function(func_name).await {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "external function {func_name} can only be called in the SELECT list, and can't be nested"
+                        ))
+                        .set_span(*span));
                     } else {
                         // Function not found, try to find and suggest similar function name.
                         let all_funcs = BUILTIN_FUNCTIONS
@@ -652,7 +700,33 @@ impl<'a> TypeChecker<'a> {
                     return Err(ErrorCode::Internal("Logical error, there is a bug!"));
                 }
 
-                if AggregateFunctionFactory::instance().contains(func_name) {
+                if GENERAL_WINDOW_FUNCTIONS.contains(&func_name) {
+                    let window = window.as_ref().ok_or_else(|| {
+                        ErrorCode::SemanticError(format!(
+                            "window function {func_name} requires an OVER clause"
+                        ))
+                        .set_span(*span)
+                    })?;
+                    if *distinct {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "DISTINCT is not supported by window function {func_name}"
+                        ))
+                        .set_span(*span));
+                    }
+                    return self
+                        .resolve_general_window_function(
+                            *span,
+                            format!("{:#}", expr),
+                            func_name,
+                            &args,
+                            window,
+                        )
+                        .await;
+                }
+
+                if AggregateFunctionFactory::instance().contains(func_name)
+                    && !Self::is_scalar_overload_of_aggregate(func_name, &args)
+                {
                     if self.in_aggregate_function {
                         // Reset the state
                         self.in_aggregate_function = false;
@@ -663,11 +737,36 @@ impl<'a> TypeChecker<'a> {
                     }
 
                     // Check aggregate function
-                    let params = params
+                    let mut params = params
                         .iter()
                         .map(|literal| self.resolve_literal(literal).map(|box (value, _)| value))
                         .collect::<Result<Vec<_>>>()?;
 
+                    // `string_agg(<expr>, '<delimiter>')` takes the delimiter as a parameter
+                    let mut args = args;
+                    if matches!(func_name, "string_agg" | "listagg") && args.len() == 2 {
+                        match args[1] {
+                            Expr::Literal {
+                                lit: Literal::String(delimiter),
+                                ..
+                            } => {
+                                params = StringAggParams {
+                                    delimiter: delimiter.clone(),
+                                    descending: false,
+                                    overflow: None,
+                                }
+                                .into_params();
+                                args.truncate(1);
+                            }
+                            _ => {
+                                return Err(ErrorCode::SemanticError(format!(
+                                    "the delimiter of {func_name} must be a constant string"
+                                ))
+                                .set_span(args[1].span()));
+                            }
+                        }
+                    }
+
                     self.in_aggregate_function = true;
                     let mut arguments = vec![];
                     let mut arg_types = vec![];
@@ -716,6 +815,12 @@ impl<'a> TypeChecker<'a> {
 
                     if let Some(window) = window {
                         // window function
+                        if window.ignore_nulls.is_some() {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "IGNORE NULLS and RESPECT NULLS are not supported by aggregate function {func_name}"
+                            ))
+                            .set_span(*span));
+                        }
                         let mut partitions = vec![];
                         for p in window.partition_by.iter() {
                             let box (part, _part_type) = self.resolve(p).await?;
@@ -725,8 +830,10 @@ impl<'a> TypeChecker<'a> {
                             *span,
                             new_agg_func.clone(),
                             partitions,
+                            &window.order_by,
                             window.window_frame.clone(),
                             data_type.clone(),
+                            false,
                         )
                         .await?
                     } else {
@@ -848,12 +955,7 @@ impl<'a> TypeChecker<'a> {
                 span, kind, expr, ..
             } => self.resolve_extract_expr(*span, kind, expr).await?,
 
-            Expr::Interval { span, .. } => {
-                return Err(ErrorCode::SemanticError(
-                    "Unsupported interval expression yet".to_string(),
-                )
-                .set_span(*span));
-            }
+            Expr::Interval { span, expr, unit } => self.resolve_interval(*span, expr, unit).await?,
             Expr::DateAdd {
                 span,
                 unit,
@@ -883,6 +985,34 @@ impl<'a> TypeChecker<'a> {
             Expr::DateTrunc {
                 span, unit, date, ..
             } => self.resolve_date_trunc(*span, date, unit).await?,
+            Expr::ListAgg {
+                span,
+                distinct,
+                expr: arg,
+                delimiter,
+                on_overflow,
+                within_group,
+            } => {
+                self.resolve_list_agg(
+                    *span,
+                    expr,
+                    *distinct,
+                    arg,
+                    delimiter,
+                    on_overflow,
+                    within_group,
+                )
+                .await?
+            }
+            Expr::Percentile {
+                span,
+                kind,
+                level,
+                within_group,
+            } => {
+                self.resolve_percentile(*span, expr, *kind, level, within_group)
+                    .await?
+            }
             Expr::Trim {
                 span,
                 expr,
@@ -940,66 +1070,255 @@ impl<'a> TypeChecker<'a> {
     #[async_recursion::async_recursion]
     pub async fn resolve_window(
         &mut self,
-        _span: Span,
+        span: Span,
         agg_func: AggregateFunction,
         partitions: Vec<ScalarExpr>,
+        order_by: &[OrderByExpr],
         window_frame: Option<WindowFrame>,
         return_type: DataType,
+        ignore_nulls: bool,
     ) -> Result<Box<(ScalarExpr, DataType)>> {
-        let frame = window_frame.unwrap();
-        let units = match frame.units.clone() {
+        // Without a frame clause, the frame covers the whole partition, or
+        // everything up to the last peer of the current row with ORDER BY.
+        let frame = window_frame.unwrap_or_else(|| WindowFrame {
+            units: WindowFrameUnits::Range,
+            start_bound: WindowFrameBound::Preceding(None),
+            end_bound: if order_by.is_empty() {
+                WindowFrameBound::Following(None)
+            } else {
+                WindowFrameBound::CurrentRow
+            },
+            exclusion: None,
+        });
+
+        if let Some(exclusion) = &frame.exclusion {
+            if *exclusion != WindowFrameExclusion::NoOthers {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Window frame exclusion `EXCLUDE {exclusion}` is unsupported"
+                ))
+                .set_span(span));
+            }
+        }
+        if matches!(frame.start_bound, WindowFrameBound::Following(None)) {
+            return Err(ErrorCode::SemanticError(
+                "Window frame cannot start with UNBOUNDED FOLLOWING".to_string(),
+            )
+            .set_span(span));
+        }
+        if matches!(frame.end_bound, WindowFrameBound::Preceding(None)) {
+            return Err(ErrorCode::SemanticError(
+                "Window frame cannot end with UNBOUNDED PRECEDING".to_string(),
+            )
+            .set_span(span));
+        }
+
+        let units = match frame.units {
             WindowFrameUnits::Rows => WindowFuncFrameUnits::Rows,
             WindowFrameUnits::Range => WindowFuncFrameUnits::Range,
-        };
-        let start = match frame.start_bound {
-            WindowFrameBound::CurrentRow => WindowFuncFrameBound::CurrentRow,
-            WindowFrameBound::Preceding(f) => {
-                if let Some(box expr) = f {
-                    let box (result_expr, _) = self.resolve(&expr).await?;
-                    WindowFuncFrameBound::Preceding(Some(Box::new(result_expr)))
-                } else {
-                    WindowFuncFrameBound::Preceding(None)
-                }
-            }
-            WindowFrameBound::Following(f) => {
-                if let Some(box expr) = f {
-                    let box (result_expr, _) = self.resolve(&expr).await?;
-                    WindowFuncFrameBound::Following(Some(Box::new(result_expr)))
-                } else {
-                    WindowFuncFrameBound::Following(None)
+            WindowFrameUnits::Groups => {
+                if order_by.is_empty() {
+                    return Err(ErrorCode::SemanticError(
+                        "GROUPS mode requires an ORDER BY clause in the window".to_string(),
+                    )
+                    .set_span(span));
                 }
+                WindowFuncFrameUnits::Groups
             }
         };
 
-        let end = match frame.end_bound {
-            WindowFrameBound::CurrentRow => WindowFuncFrameBound::CurrentRow,
-            WindowFrameBound::Preceding(f) => {
-                if let Some(box expr) = f {
-                    let box (result_expr, _) = self.resolve(&expr).await?;
-                    WindowFuncFrameBound::Preceding(Some(Box::new(result_expr)))
-                } else {
-                    WindowFuncFrameBound::Preceding(None)
-                }
+        // A RANGE offset is added to or subtracted from the ORDER BY key, so
+        // it needs exactly one key with a numeric or datetime type.
+        let has_offset = |bound: &WindowFrameBound| {
+            matches!(
+                bound,
+                WindowFrameBound::Preceding(Some(_)) | WindowFrameBound::Following(Some(_))
+            )
+        };
+        let mut range_key_type = None;
+        if units == WindowFuncFrameUnits::Range
+            && (has_offset(&frame.start_bound) || has_offset(&frame.end_bound))
+        {
+            if order_by.len() != 1 {
+                return Err(ErrorCode::SemanticError(
+                    "RANGE with offset PRECEDING/FOLLOWING requires exactly one ORDER BY column"
+                        .to_string(),
+                )
+                .set_span(span));
             }
-            WindowFrameBound::Following(f) => {
-                if let Some(box expr) = f {
-                    let box (result_expr, _) = self.resolve(&expr).await?;
-                    WindowFuncFrameBound::Following(Some(Box::new(result_expr)))
-                } else {
-                    WindowFuncFrameBound::Following(None)
-                }
+            let box (_, key_type) = self.resolve(&order_by[0].expr).await?;
+            let key_type = key_type.remove_nullable();
+            if !key_type.is_numeric() && !key_type.is_decimal() && !key_type.is_date_or_date_time()
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "RANGE with offset PRECEDING/FOLLOWING requires a numeric, date or timestamp ORDER BY column, but got {key_type}"
+                ))
+                .set_span(order_by[0].expr.span()));
             }
-        };
+            range_key_type = Some(key_type);
+        }
+
+        let start = self
+            .resolve_window_frame_bound(&units, range_key_type.as_ref(), frame.start_bound)
+            .await?;
+        let end = self
+            .resolve_window_frame_bound(&units, range_key_type.as_ref(), frame.end_bound)
+            .await?;
 
         let window_func = WindowFunc {
             agg_func,
             partition_by: partitions,
             frame: WindowFuncFrame { units, start, end },
+            ignore_nulls,
         };
 
         Ok(Box::new((window_func.into(), return_type)))
     }
 
+    /// Resolve `lag(<expr> [, <offset> [, <default>]])`, `lead(...)` and
+    /// `nth_value(<expr>, <n>)`. The offset and `n` must be integer literals.
+    #[async_recursion::async_recursion]
+    async fn resolve_general_window_function(
+        &mut self,
+        span: Span,
+        display_name: String,
+        func_name: &str,
+        args: &[&Expr],
+        window: &WindowSpec,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let is_nth_value = func_name == "nth_value";
+        let (valid_args, expected_args) = if is_nth_value {
+            (args.len() == 2, "2")
+        } else {
+            ((1..=3).contains(&args.len()), "1 to 3")
+        };
+        if !valid_args {
+            return Err(ErrorCode::SemanticError(format!(
+                "{func_name} takes {expected_args} arguments, but got {}",
+                args.len()
+            ))
+            .set_span(span));
+        }
+
+        let offset = match args.get(1) {
+            None => 1,
+            Some(Expr::Literal {
+                lit: Literal::UInt64(offset),
+                ..
+            }) if !is_nth_value || *offset > 0 => *offset,
+            Some(arg) => {
+                let message = if is_nth_value {
+                    "the n of nth_value must be a positive integer literal".to_string()
+                } else {
+                    format!("the offset of {func_name} must be a non-negative integer literal")
+                };
+                return Err(ErrorCode::SemanticError(message).set_span(arg.span()));
+            }
+        };
+
+        let box (mut arg, arg_type) = self.resolve(args[0]).await?;
+        let mut return_type = arg_type.wrap_nullable();
+        let mut default = None;
+        if let Some(default_arg) = args.get(2) {
+            let box (scalar, default_type) = self.resolve(default_arg).await?;
+            return_type = common_super_type(
+                return_type.clone(),
+                default_type.clone(),
+                &BUILTIN_FUNCTIONS.default_cast_rules,
+            )
+            .ok_or_else(|| {
+                ErrorCode::SemanticError(format!(
+                    "the default of {func_name} has type {default_type}, which is not compatible with {arg_type}"
+                ))
+                .set_span(default_arg.span())
+            })?
+            .wrap_nullable();
+            default = Some(if default_type != return_type {
+                wrap_cast(&scalar, &return_type)
+            } else {
+                scalar
+            });
+        }
+        if arg_type != return_type {
+            arg = wrap_cast(&arg, &return_type);
+        }
+
+        let func = AggregateFunction {
+            display_name,
+            func_name: func_name.to_string(),
+            distinct: false,
+            params: vec![Scalar::Number(NumberScalar::UInt64(offset))],
+            args: std::iter::once(arg).chain(default).collect(),
+            return_type: Box::new(return_type.clone()),
+        };
+
+        let mut partitions = vec![];
+        for p in window.partition_by.iter() {
+            let box (part, _part_type) = self.resolve(p).await?;
+            partitions.push(part);
+        }
+        self.resolve_window(
+            span,
+            func,
+            partitions,
+            &window.order_by,
+            window.window_frame.clone(),
+            return_type,
+            window.ignore_nulls.unwrap_or(false),
+        )
+        .await
+    }
+
+    #[async_recursion::async_recursion]
+    async fn resolve_window_frame_bound(
+        &mut self,
+        units: &WindowFuncFrameUnits,
+        range_key_type: Option<&DataType>,
+        bound: WindowFrameBound,
+    ) -> Result<WindowFuncFrameBound> {
+        let (preceding, offset) = match bound {
+            WindowFrameBound::CurrentRow => return Ok(WindowFuncFrameBound::CurrentRow),
+            WindowFrameBound::Preceding(None) => return Ok(WindowFuncFrameBound::Preceding(None)),
+            WindowFrameBound::Following(None) => return Ok(WindowFuncFrameBound::Following(None)),
+            WindowFrameBound::Preceding(Some(box offset)) => (true, offset),
+            WindowFrameBound::Following(Some(box offset)) => (false, offset),
+        };
+
+        let box (scalar, data_type) = self.resolve(&offset).await?;
+        let valid = match units {
+            // ROWS and GROUPS count rows or peer groups, so only a non-negative
+            // integer literal makes sense.
+            WindowFuncFrameUnits::Rows | WindowFuncFrameUnits::Groups => {
+                matches!(scalar, ScalarExpr::ConstantExpr(_)) && data_type.is_unsigned_numeric()
+            }
+            WindowFuncFrameUnits::Range => {
+                let data_type = data_type.remove_nullable();
+                let key_type = range_key_type.unwrap();
+                scalar.used_columns().is_empty()
+                    && (data_type.is_numeric()
+                        || data_type.is_decimal()
+                        || (key_type.is_date_or_date_time() && data_type == DataType::Interval))
+            }
+        };
+        if !valid {
+            return Err(ErrorCode::SemanticError(format!(
+                "Invalid {} offset `{offset}` in window frame",
+                match units {
+                    WindowFuncFrameUnits::Rows => "ROWS",
+                    WindowFuncFrameUnits::Range => "RANGE",
+                    WindowFuncFrameUnits::Groups => "GROUPS",
+                }
+            ))
+            .set_span(offset.span()));
+        }
+
+        let scalar = Some(Box::new(scalar));
+        Ok(if preceding {
+            WindowFuncFrameBound::Preceding(scalar)
+        } else {
+            WindowFuncFrameBound::Following(scalar)
+        })
+    }
+
     /// Resolve function call.
     #[async_recursion::async_recursion]
     pub async fn resolve_function(
@@ -1043,7 +1362,31 @@ impl<'a> TypeChecker<'a> {
             Self::rewrite_substring(&mut args);
         }
 
-        if func_name == "grouping" {
+        // The function registry only tells that no overload matches if the result of the
+        // decimal arithmetic can't be represented, check it here to report the overflow.
+        if let ("plus" | "minus" | "multiply" | "divide", [lhs, rhs]) = (func_name, &arg_types[..])
+        {
+            if let (DataType::Decimal(lhs), DataType::Decimal(rhs)) =
+                (lhs.remove_nullable(), rhs.remove_nullable())
+            {
+                DecimalDataType::binary_result_type(
+                    &lhs,
+                    &rhs,
+                    func_name == "multiply",
+                    func_name == "divide",
+                    func_name == "plus" || func_name == "minus",
+                )
+                .map_err(|err| err.set_span(span))?;
+            }
+        }
+
+        if func_name == "grouping" || func_name == "grouping_id" {
+            if matches!(self.bind_context.expr_context, ExprContext::WhereClause) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "{func_name} can only be used in SELECT, HAVING or ORDER BY"
+                ))
+                .set_span(span));
+            }
             // `grouping` will be rewritten again after resolving grouping sets.
             return Ok(Box::new((
                 ScalarExpr::FunctionCall(FunctionCall {
@@ -1239,6 +1582,11 @@ impl<'a> TypeChecker<'a> {
                 )))
             }
 
+            UnaryOperator::Prior => Err(ErrorCode::SemanticError(
+                "PRIOR can only be used in the CONNECT BY condition",
+            )
+            .set_span(span)),
+
             other => {
                 let name = other.to_func_name();
                 self.resolve_function(span, name.as_str(), vec![], &[child])
@@ -1288,6 +1636,31 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    #[async_recursion::async_recursion]
+    pub async fn resolve_interval(
+        &mut self,
+        span: Span,
+        arg: &Expr,
+        interval_kind: &ASTIntervalKind,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let func_name = match interval_kind {
+            ASTIntervalKind::Year => "to_years",
+            ASTIntervalKind::Quarter => "to_quarters",
+            ASTIntervalKind::Month => "to_months",
+            ASTIntervalKind::Day => "to_days",
+            ASTIntervalKind::Hour => "to_hours",
+            ASTIntervalKind::Minute => "to_minutes",
+            ASTIntervalKind::Second => "to_seconds",
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Unsupported interval unit: {interval_kind}"
+                ))
+                .set_span(span));
+            }
+        };
+        self.resolve_function(span, func_name, vec![], &[arg]).await
+    }
+
     #[async_recursion::async_recursion]
     pub async fn resolve_date_add(
         &mut self,
@@ -1456,6 +1829,12 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new((subquery_expr.into(), data_type)))
     }
 
+    /// Some functions, such as `st_collect`, are both an aggregate function and a scalar function
+    /// on an array. The scalar one is used if the only argument is an array literal.
+    fn is_scalar_overload_of_aggregate(func_name: &str, args: &[&Expr]) -> bool {
+        BUILTIN_FUNCTIONS.contains(func_name) && matches!(args, [Expr::Array { .. }])
+    }
+
     pub fn all_rewritable_scalar_function() -> &'static [&'static str] {
         &[
             "database",
@@ -1473,6 +1852,11 @@ impl<'a> TypeChecker<'a> {
             "is_null",
             "coalesce",
             "last_query_id",
+            "dateadd",
+            "datediff",
+            "nextval",
+            "currval",
+            "setval",
         ]
     }
 
@@ -1585,6 +1969,7 @@ impl<'a> TypeChecker<'a> {
                         args: vec![arg_x.clone()],
                         params: vec![],
                         window: None,
+                        lambda: None,
                     })
                     .await,
                 )
@@ -1621,6 +2006,7 @@ impl<'a> TypeChecker<'a> {
                         args: vec![(*arg).clone()],
                         params: vec![],
                         window: None,
+                        lambda: None,
                     };
 
                     new_args.push(is_not_null_expr);
@@ -1634,6 +2020,28 @@ impl<'a> TypeChecker<'a> {
                 Some(self.resolve_function(span, "if", vec![], &args_ref).await)
             }
 
+            ("dateadd", &[unit, amount, date]) => {
+                // Rewrite dateadd(unit, amount, date) to add_{unit}s(date, amount)
+                Some(match resolve_date_part_unit(unit) {
+                    Ok(unit) => {
+                        self.resolve_function(span, &format!("add_{unit}s"), vec![], &[
+                            date, amount,
+                        ])
+                        .await
+                    }
+                    Err(e) => Err(e),
+                })
+            }
+            ("datediff", &[unit, start, end]) => {
+                // Rewrite datediff(unit, start, end) to diff_{unit}s(start, end)
+                Some(match resolve_date_part_unit(unit) {
+                    Ok(unit) => {
+                        self.resolve_function(span, &format!("diff_{unit}s"), vec![], &[start, end])
+                            .await
+                    }
+                    Err(e) => Err(e),
+                })
+            }
             ("last_query_id", args) => {
                 // last_query_id(index) returns query_id in current session by index
                 let res: Result<i64> = try {
@@ -1670,10 +2078,253 @@ impl<'a> TypeChecker<'a> {
                     Err(e) => Err(e),
                 })
             }
+            ("nextval" | "currval" | "setval", args) => {
+                Some(self.resolve_sequence_function(span, func_name, args).await)
+            }
             _ => None,
         }
     }
 
+    /// Resolve the sequence functions. `nextval` generates a value for each row when the
+    /// statement runs, so it's rewritten into the builtin `nextval` with everything it needs
+    /// to run on any node. `currval` and `setval` are evaluated when the statement is bound.
+    async fn resolve_sequence_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[&Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let func_name = func_name.to_lowercase();
+        let (name, value) = match (func_name.as_str(), args) {
+            (
+                "nextval" | "currval",
+                &[
+                    Expr::Literal {
+                        lit: Literal::String(name),
+                        ..
+                    },
+                ],
+            ) => (name, None),
+            (
+                "setval",
+                &[
+                    Expr::Literal {
+                        lit: Literal::String(name),
+                        ..
+                    },
+                    value,
+                ],
+            ) => (name, Some(value)),
+            ("setval", _) => {
+                return Err(ErrorCode::BadArguments(
+                    "setval expects the name of a sequence as a string literal and a value",
+                )
+                .set_span(span));
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "{func_name} expects the name of a sequence as a string literal"
+                ))
+                .set_span(span));
+            }
+        };
+
+        // Sequence values change with each call, they must never be cached.
+        self.ctx.set_cacheable(false);
+        let tenant = self.ctx.get_tenant();
+        let value = match (func_name.as_str(), value) {
+            ("nextval", _) => {
+                UserApiProvider::instance()
+                    .get_sequence(&tenant, name)
+                    .await
+                    .map_err(|e| e.set_span(span))?;
+                let cache_size = self.ctx.get_settings().get_sequence_cache_size()?;
+                let arguments = [
+                    Scalar::String(tenant.into_bytes()),
+                    Scalar::String(name.clone().into_bytes()),
+                    Scalar::String(self.ctx.get_connection_id().into_bytes()),
+                    Scalar::Number(NumberScalar::UInt64(cache_size)),
+                ]
+                .into_iter()
+                .map(|value| ConstantExpr { span, value }.into())
+                .collect();
+                return self
+                    .resolve_scalar_function_call(span, "nextval", vec![], arguments)
+                    .await;
+            }
+            (_, Some(value)) => {
+                let box (scalar, _) = self.resolve(value).await?;
+                let expr = scalar.as_expr_with_col_index()?;
+                let value = check_number::<_, i64>(
+                    span,
+                    self.ctx.get_function_context()?,
+                    &expr,
+                    &BUILTIN_FUNCTIONS,
+                )?;
+                UserApiProvider::instance()
+                    .set_sequence_value(&tenant, name, value)
+                    .await
+                    .map_err(|e| e.set_span(span))?;
+                self.ctx.set_sequence_value(name.clone(), value);
+                value
+            }
+            (_, None) => self.ctx.get_sequence_value(name).ok_or_else(|| {
+                ErrorCode::UnknownSequence(format!(
+                    "currval of sequence {name} is not yet defined in this session"
+                ))
+                .set_span(span)
+            })?,
+        };
+        Ok(Box::new((
+            ConstantExpr {
+                span,
+                value: Scalar::Number(NumberScalar::Int64(value)),
+            }
+            .into(),
+            DataType::Number(NumberDataType::Int64),
+        )))
+    }
+
+    /// Resolve `LISTAGG` into the `listagg` aggregate function. The delimiter, the sort
+    /// direction of `WITHIN GROUP` and the `ON OVERFLOW` behavior are passed as parameters,
+    /// and the `WITHIN GROUP` sort key as the second argument.
+    #[allow(clippy::too_many_arguments)]
+    #[async_recursion::async_recursion]
+    async fn resolve_list_agg(
+        &mut self,
+        span: Span,
+        expr: &Expr,
+        distinct: bool,
+        arg: &Expr,
+        delimiter: &Option<String>,
+        on_overflow: &Option<ListAggOverflow>,
+        within_group: &Option<Box<OrderByExpr>>,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if self.in_aggregate_function {
+            // Reset the state
+            self.in_aggregate_function = false;
+            return Err(ErrorCode::SemanticError(
+                "aggregate function calls cannot be nested".to_string(),
+            )
+            .set_span(span));
+        }
+
+        let overflow = match on_overflow {
+            None | Some(ListAggOverflow::Error) => OverflowMode::Error,
+            Some(ListAggOverflow::Truncate { filler, with_count }) => OverflowMode::Truncate {
+                filler: filler.clone().unwrap_or_else(|| "...".to_string()),
+                with_count: *with_count,
+            },
+        };
+        let max_length = self.ctx.get_settings().get_max_listagg_length()? as usize;
+        let params = StringAggParams {
+            delimiter: delimiter.clone().unwrap_or_default(),
+            descending: within_group
+                .as_ref()
+                .map(|order_by| order_by.asc == Some(false))
+                .unwrap_or(false),
+            overflow: Some((max_length, overflow)),
+        }
+        .into_params();
+
+        self.in_aggregate_function = true;
+        let mut arguments = vec![];
+        let mut arg_types = vec![];
+        let box (argument, arg_type) = self.resolve(arg).await?;
+        if arg_type.remove_nullable() == DataType::String {
+            arguments.push(argument);
+            arg_types.push(arg_type);
+        } else {
+            let target_type = if arg_type.is_nullable_or_null() {
+                DataType::Nullable(Box::new(DataType::String))
+            } else {
+                DataType::String
+            };
+            arguments.push(wrap_cast(&argument, &target_type));
+            arg_types.push(target_type);
+        }
+        if let Some(order_by) = within_group {
+            let box (key, key_type) = self.resolve(&order_by.expr).await?;
+            arguments.push(key);
+            arg_types.push(key_type);
+        }
+        self.in_aggregate_function = false;
+
+        let func_name = if distinct {
+            "listagg_distinct"
+        } else {
+            "listagg"
+        };
+        let agg_func = AggregateFunctionFactory::instance()
+            .get(func_name, params.clone(), arg_types)
+            .map_err(|e| e.set_span(span))?;
+        let data_type = agg_func.return_type()?;
+
+        Ok(Box::new((
+            AggregateFunction {
+                display_name: format!("{:#}", expr),
+                func_name: func_name.to_string(),
+                distinct: false,
+                params,
+                args: arguments,
+                return_type: Box::new(data_type.clone()),
+            }
+            .into(),
+            data_type,
+        )))
+    }
+
+    /// Resolve `PERCENTILE_CONT | PERCENTILE_DISC (<level>) WITHIN GROUP (ORDER BY <expr>)` into
+    /// the `percentile_cont` or `percentile_disc` aggregate function of `<expr>`, with the level
+    /// and the sort direction of `WITHIN GROUP` as parameters.
+    #[async_recursion::async_recursion]
+    async fn resolve_percentile(
+        &mut self,
+        span: Span,
+        expr: &Expr,
+        kind: PercentileKind,
+        level: &Literal,
+        within_group: &OrderByExpr,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if self.in_aggregate_function {
+            // Reset the state
+            self.in_aggregate_function = false;
+            return Err(ErrorCode::SemanticError(
+                "aggregate function calls cannot be nested".to_string(),
+            )
+            .set_span(span));
+        }
+
+        let box (level, _) = self.resolve_literal(level)?;
+        let params = vec![level, Scalar::Boolean(within_group.asc == Some(false))];
+
+        self.in_aggregate_function = true;
+        let box (argument, arg_type) = self.resolve(&within_group.expr).await?;
+        self.in_aggregate_function = false;
+
+        let func_name = match kind {
+            PercentileKind::Cont => "percentile_cont",
+            PercentileKind::Disc => "percentile_disc",
+        };
+        let agg_func = AggregateFunctionFactory::instance()
+            .get(func_name, params.clone(), vec![arg_type])
+            .map_err(|e| e.set_span(span))?;
+        let data_type = agg_func.return_type()?;
+
+        Ok(Box::new((
+            AggregateFunction {
+                display_name: format!("{:#}", expr),
+                func_name: func_name.to_string(),
+                distinct: false,
+                params,
+                args: vec![argument],
+                return_type: Box::new(data_type.clone()),
+            }
+            .into(),
+            data_type,
+        )))
+    }
+
     #[async_recursion::async_recursion]
     async fn resolve_trim_function(
         &mut self,
@@ -1843,6 +2494,214 @@ impl<'a> TypeChecker<'a> {
             .await
     }
 
+    /// Resolve a higher-order function like `array_map(arr, x -> x + 1)`.
+    ///
+    /// The lambda body is bound with the lambda params as derived columns, then lowered
+    /// into an `Expr` that refers to the params and the captured outer columns by position.
+    /// The captured columns are passed as extra arguments, so they can be replicated
+    /// across the array elements when the function is evaluated.
+    #[async_recursion::async_recursion]
+    async fn resolve_lambda_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[Expr],
+        lambda: &Option<Lambda>,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let lambda = match lambda {
+            Some(lambda) if GENERAL_LAMBDA_FUNCTIONS.contains(&func_name) => lambda,
+            Some(_) => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "function {func_name} does not accept lambda arguments"
+                ))
+                .set_span(span));
+            }
+            None => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "function {func_name} requires a lambda argument"
+                ))
+                .set_span(span));
+            }
+        };
+        if args.len() != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "function {func_name} expects an array and a lambda as arguments"
+            ))
+            .set_span(span));
+        }
+        let num_params = if func_name == "array_reduce" { 2 } else { 1 };
+        if lambda.params.len() != num_params {
+            return Err(ErrorCode::SemanticError(format!(
+                "incorrect number of parameters in lambda function, {func_name} expects {num_params} parameter(s), but got {}",
+                lambda.params.len()
+            ))
+            .set_span(span));
+        }
+
+        let box (mut arg, arg_type) = self.resolve(&args[0]).await?;
+        let elem_type = match arg_type.remove_nullable() {
+            DataType::Null => {
+                return Ok(Box::new((
+                    ConstantExpr {
+                        span,
+                        value: Scalar::Null,
+                    }
+                    .into(),
+                    DataType::Null,
+                )));
+            }
+            DataType::EmptyArray if func_name == "array_reduce" => {
+                return Ok(Box::new((
+                    ConstantExpr {
+                        span,
+                        value: Scalar::Null,
+                    }
+                    .into(),
+                    DataType::Null,
+                )));
+            }
+            DataType::EmptyArray => return Ok(Box::new((arg, arg_type))),
+            DataType::Array(box elem_type) => elem_type,
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "function {func_name} expects an array as the first argument, but got {arg_type}"
+                ))
+                .set_span(args[0].span()));
+            }
+        };
+
+        let (mut body, mut params, mut body_type) = self
+            .resolve_lambda_body(lambda, vec![elem_type.clone(); num_params])
+            .await?;
+        if func_name == "array_reduce" && body_type != elem_type {
+            // The accumulator takes the type of the lambda result, so the elements are
+            // casted to it and the lambda is bound again with the new param types.
+            let acc_type = body_type.clone();
+            let array_type = DataType::Array(Box::new(acc_type.clone()));
+            let array_type = if arg_type.is_nullable() {
+                array_type.wrap_nullable()
+            } else {
+                array_type
+            };
+            arg = wrap_cast(&arg, &array_type);
+            (body, params, body_type) = self
+                .resolve_lambda_body(lambda, vec![acc_type.clone(); num_params])
+                .await?;
+            if body_type != acc_type {
+                body = wrap_cast(&body, &acc_type);
+                body_type = acc_type;
+            }
+        }
+
+        if !find_scalars(&body, &|scalar: &ScalarExpr| {
+            matches!(
+                scalar,
+                ScalarExpr::AggregateFunction(_)
+                    | ScalarExpr::WindowFunction(_)
+                    | ScalarExpr::SubqueryExpr(_)
+            )
+        })?
+        .is_empty()
+        {
+            return Err(ErrorCode::SemanticError(
+                "aggregate functions, window functions and subqueries are not allowed in lambda functions",
+            )
+            .set_span(lambda.expr.span()));
+        }
+        if func_name == "array_filter" && body_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "the lambda function of array_filter must return a boolean, but got {body_type}"
+            ))
+            .set_span(lambda.expr.span()));
+        }
+
+        // Columns that come from outside of the lambda are captured as extra arguments.
+        let param_indexes = params.iter().map(|param| param.index).collect::<Vec<_>>();
+        let captured = find_scalars(&body, &|scalar: &ScalarExpr| match scalar {
+            ScalarExpr::BoundColumnRef(column_ref) => {
+                !param_indexes.contains(&column_ref.column.index)
+            }
+            ScalarExpr::BoundInternalColumnRef(_) => true,
+            _ => false,
+        })?;
+        let positions = param_indexes
+            .iter()
+            .cloned()
+            .chain(captured.iter().flat_map(|scalar| scalar.used_columns()))
+            .enumerate()
+            .map(|(position, index)| (index, position))
+            .collect::<HashMap<_, _>>();
+        let lambda_expr = body
+            .as_expr_with_col_index()?
+            .project_column_ref(|index| positions[index]);
+
+        let return_type = match func_name {
+            "array_map" => {
+                let array_type = DataType::Array(Box::new(body_type));
+                if arg_type.is_nullable() {
+                    array_type.wrap_nullable()
+                } else {
+                    array_type
+                }
+            }
+            "array_filter" => arg_type,
+            _ => body_type.wrap_nullable(),
+        };
+
+        let mut arguments = vec![arg];
+        arguments.extend(captured);
+        Ok(Box::new((
+            LambdaFunc {
+                span,
+                func_name: func_name.to_string(),
+                args: arguments,
+                lambda_expr: Box::new(lambda_expr.as_remote_expr()),
+                lambda_display: lambda.to_string(),
+                return_type: Box::new(return_type.clone()),
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
+    /// Bind the lambda body with the params of the given types, returns the body,
+    /// the bindings of the params and the type of the body.
+    #[async_recursion::async_recursion]
+    async fn resolve_lambda_body(
+        &mut self,
+        lambda: &Lambda,
+        param_types: Vec<DataType>,
+    ) -> Result<(ScalarExpr, Vec<ColumnBinding>, DataType)> {
+        let params = lambda
+            .params
+            .iter()
+            .zip(param_types)
+            .map(|(param, data_type)| {
+                let column_name = normalize_identifier(param, self.name_resolution_ctx).name;
+                let index = self
+                    .metadata
+                    .write()
+                    .add_derived_column(column_name.clone(), data_type.clone());
+                ColumnBinding {
+                    database_name: None,
+                    table_name: None,
+                    column_name,
+                    index,
+                    data_type: Box::new(data_type),
+                    visibility: Visibility::Visible,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let num_outer_params = self.lambda_params.len();
+        self.lambda_params.extend(params.iter().cloned());
+        let result = self.resolve(&lambda.expr).await;
+        self.lambda_params.truncate(num_outer_params);
+        let box (body, body_type) = result?;
+
+        Ok((body, params, body_type))
+    }
+
     #[async_recursion::async_recursion]
     async fn resolve_udf(
         &mut self,
@@ -1893,6 +2752,13 @@ impl<'a> TypeChecker<'a> {
         Ok(Some(self.resolve(&udf_expr).await?))
     }
 
+    async fn is_external_function(&self, func_name: &str) -> bool {
+        UserApiProvider::instance()
+            .get_external_function(self.ctx.get_tenant().as_str(), func_name)
+            .await
+            .is_ok()
+    }
+
     #[async_recursion::async_recursion]
     async fn resolve_map_access(
         &mut self,
@@ -2279,6 +3145,7 @@ impl<'a> TypeChecker<'a> {
                     args,
                     params,
                     window,
+                    lambda,
                 } => Ok(Expr::FunctionCall {
                     span: *span,
                     distinct: *distinct,
@@ -2289,6 +3156,16 @@ impl<'a> TypeChecker<'a> {
                         .collect::<Result<Vec<Expr>>>()?,
                     params: params.clone(),
                     window: window.clone(),
+                    lambda: if let Some(lambda) = lambda {
+                        Some(Lambda {
+                            params: lambda.params.clone(),
+                            expr: Box::new(
+                                self.clone_expr_with_replacement(&lambda.expr, replacement_fn)?,
+                            ),
+                        })
+                    } else {
+                        None
+                    },
                 }),
                 Expr::Case {
                     span,
@@ -2473,6 +3350,9 @@ pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
             TableDataType::Nullable(Box::new(resolve_type_name(inner_type)?))
         }
         TypeName::Variant => TableDataType::Variant,
+        TypeName::Bitmap => TableDataType::Bitmap,
+        TypeName::Binary => TableDataType::Binary,
+        TypeName::Interval => TableDataType::Interval,
     };
 
     Ok(data_type)
@@ -2507,3 +3387,41 @@ pub fn validate_function_arg(
         }
     }
 }
+
+/// Resolve the unit of `DATEADD` and `DATEDIFF` to the suffix of the functions they are
+/// rewritten to. The unit is a name or a string literal, either the full name of the unit
+/// or one of its abbreviations in SQL Server, e.g. `day`, `'dd'` or `'ms'`.
+fn resolve_date_part_unit(unit: &Expr) -> Result<&'static str> {
+    let name = match unit {
+        Expr::ColumnRef {
+            database: None,
+            table: None,
+            column,
+            ..
+        } => column.name.to_lowercase(),
+        Expr::Literal {
+            lit: Literal::String(name),
+            ..
+        } => name.to_lowercase(),
+        _ => {
+            return Err(ErrorCode::SemanticError(
+                "the unit of DATEADD and DATEDIFF must be a name or a string literal",
+            )
+            .set_span(unit.span()));
+        }
+    };
+    match name.as_str() {
+        "year" | "years" | "yy" | "yyyy" => Ok("year"),
+        "quarter" | "quarters" | "qq" | "q" => Ok("quarter"),
+        "month" | "months" | "mm" | "m" => Ok("month"),
+        "day" | "days" | "dd" | "d" => Ok("day"),
+        "hour" | "hours" | "hh" => Ok("hour"),
+        "minute" | "minutes" | "mi" | "n" => Ok("minute"),
+        "second" | "seconds" | "ss" | "s" => Ok("second"),
+        "millisecond" | "milliseconds" | "ms" => Ok("millisecond"),
+        _ => Err(ErrorCode::SemanticError(format!(
+            "unsupported unit '{name}' of DATEADD and DATEDIFF, expected one of year, quarter, month, day, hour, minute, second or millisecond"
+        ))
+        .set_span(unit.span())),
+    }
+}
@@ -16,6 +16,7 @@ use std::collections::HashSet;
 
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
+use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
 use common_ast::ast::WindowSpec;
 use common_ast::walk_expr;
@@ -88,6 +89,7 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         args: &'ast [Expr],
         _params: &'ast [Literal],
         over: &'ast Option<WindowSpec>,
+        lambda: &'ast Option<Lambda>,
     ) {
         let name = name.to_string();
         if !is_builtin_function(&name) && self.name.eq_ignore_ascii_case(&name) {
@@ -112,5 +114,17 @@ impl<'ast> Visitor<'ast> for UDFValidator {
                 self.visit_frame_bound(&frame.end_bound);
             }
         }
+
+        if let Some(lambda) = lambda {
+            // Lambda parameters are local to the lambda body, they are not UDF parameters.
+            let outer_params = self.expr_params.clone();
+            walk_expr(self, &lambda.expr);
+            for param in &lambda.params {
+                let param = param.to_string();
+                if !outer_params.contains(&param) {
+                    self.expr_params.remove(&param);
+                }
+            }
+        }
     }
 }
@@ -337,6 +337,8 @@ fn test_parse_uri_location() -> Result<()> {
                 StorageParams::Http(StorageHttpConfig {
                     endpoint_url: "https://example.com".to_string(),
                     paths: ["/tmp.csv"].iter().map(|v| v.to_string()).collect(),
+                    token: "".to_string(),
+                    allowed_url_prefixes: vec![],
                 }),
                 "/".to_string(),
             ),
@@ -357,6 +359,8 @@ fn test_parse_uri_location() -> Result<()> {
                         .iter()
                         .map(|v| v.to_string())
                         .collect(),
+                    token: "".to_string(),
+                    allowed_url_prefixes: vec![],
                 }),
                 "/".to_string(),
             ),
@@ -383,6 +387,30 @@ fn test_parse_uri_location() -> Result<()> {
                     .iter()
                     .map(|v| v.to_string())
                     .collect(),
+                    token: "".to_string(),
+                    allowed_url_prefixes: vec![],
+                }),
+                "/".to_string(),
+            ),
+        ),
+        (
+            "http_with_token",
+            UriLocation::new(
+                "https".to_string(),
+                "example.com".to_string(),
+                "/tmp.csv".to_string(),
+                "".to_string(),
+                vec![("token", "databendthebest")]
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect::<BTreeMap<_, _>>(),
+            ),
+            (
+                StorageParams::Http(StorageHttpConfig {
+                    endpoint_url: "https://example.com".to_string(),
+                    paths: ["/tmp.csv"].iter().map(|v| v.to_string()).collect(),
+                    token: "databendthebest".to_string(),
+                    allowed_url_prefixes: vec![],
                 }),
                 "/".to_string(),
             ),
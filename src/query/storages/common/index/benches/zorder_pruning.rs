@@ -0,0 +1,217 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::uninlined_format_args)]
+
+#[macro_use]
+extern crate criterion;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_expression::type_check::check;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::RawExpr;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_functions::scalars::zorder::interleave;
+use common_functions::scalars::zorder::normalize;
+use common_functions::BUILTIN_FUNCTIONS;
+use criterion::Criterion;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use storages_common_index::RangeIndex;
+use storages_common_index::ZOrderIndex;
+use storages_common_table_meta::meta::ClusterStatistics;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+const ROWS: usize = 1 << 20;
+const ROWS_PER_BLOCK: usize = 1 << 10;
+const DOMAIN: u32 = 1 << 16;
+// Every query selects a square of 1% of the whole area.
+const QUERY_SIDE: u32 = DOMAIN / 10;
+const QUERIES: usize = 100;
+
+struct Block {
+    col_stats: StatisticsOfColumns,
+    cluster_stats: Option<ClusterStatistics>,
+}
+
+/// Benchmark pruning the blocks of a table for 2D range queries, with the table
+/// clustered by `(a, b)` or by `ZORDER(a, b)`.
+///
+/// The selectivity, which is the ratio of the blocks kept to all the blocks, is printed
+/// before the benchmarks. With 1M rows of uniformly distributed points in 1K blocks
+/// and each query selecting 1% of the area, about 10% of the blocks are kept when
+/// clustered by `(a, b)`, and about 2% when clustered by `ZORDER(a, b)`.
+fn bench_zorder_pruning(c: &mut Criterion) {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::UInt32)),
+        TableField::new("b", TableDataType::Number(NumberDataType::UInt32)),
+    ]));
+    let mut rng = StdRng::seed_from_u64(0);
+    let points = (0..ROWS)
+        .map(|_| (rng.gen_range(0..DOMAIN), rng.gen_range(0..DOMAIN)))
+        .collect::<Vec<_>>();
+    let queries = (0..QUERIES)
+        .map(|_| {
+            let a = rng.gen_range(0..DOMAIN - QUERY_SIDE);
+            let b = rng.gen_range(0..DOMAIN - QUERY_SIDE);
+            range_query(a, a + QUERY_SIDE, b, b + QUERY_SIDE)
+        })
+        .collect::<Vec<_>>();
+
+    let mut linear = points.clone();
+    linear.sort();
+    let linear_blocks = build_blocks(&linear);
+
+    let mut zorder = points;
+    zorder.sort_by_key(|(a, b)| zorder_key(*a, *b));
+    let zorder_blocks = build_blocks(&zorder);
+
+    let linear_kept = queries
+        .iter()
+        .map(|expr| prune(&schema, expr, &linear_blocks, false))
+        .sum::<usize>();
+    let zorder_kept = queries
+        .iter()
+        .map(|expr| prune(&schema, expr, &zorder_blocks, true))
+        .sum::<usize>();
+    let total = (QUERIES * ROWS / ROWS_PER_BLOCK) as f64;
+    println!(
+        "CLUSTER BY (a, b): {:.1}% of the blocks kept",
+        linear_kept as f64 * 100.0 / total
+    );
+    println!(
+        "CLUSTER BY ZORDER(a, b): {:.1}% of the blocks kept",
+        zorder_kept as f64 * 100.0 / total
+    );
+
+    c.bench_function("prune_1k_blocks_clustered_by_a_b", |b| {
+        b.iter(|| {
+            for expr in queries.iter() {
+                criterion::black_box(prune(&schema, expr, &linear_blocks, false));
+            }
+        })
+    });
+    c.bench_function("prune_1k_blocks_clustered_by_zorder_a_b", |b| {
+        b.iter(|| {
+            for expr in queries.iter() {
+                criterion::black_box(prune(&schema, expr, &zorder_blocks, true));
+            }
+        })
+    });
+}
+
+fn zorder_key(a: u32, b: u32) -> u64 {
+    interleave(&[
+        normalize(ScalarRef::Number(NumberScalar::UInt32(a))),
+        normalize(ScalarRef::Number(NumberScalar::UInt32(b))),
+    ])
+}
+
+/// Returns the number of the blocks kept.
+fn prune(schema: &TableSchemaRef, expr: &Expr<String>, blocks: &[Block], zorder: bool) -> usize {
+    let func_ctx = FunctionContext::default();
+    let range_index = RangeIndex::try_create(func_ctx, expr, schema.clone()).unwrap();
+    let zorder_index =
+        ZOrderIndex::try_create(func_ctx, 0, vec!["a".to_string(), "b".to_string()], expr).unwrap();
+    blocks
+        .iter()
+        .filter(|block| {
+            range_index.apply(&block.col_stats).unwrap()
+                && (!zorder || zorder_index.apply(&block.cluster_stats).unwrap())
+        })
+        .count()
+}
+
+fn build_blocks(points: &[(u32, u32)]) -> Vec<Block> {
+    points
+        .chunks(ROWS_PER_BLOCK)
+        .map(|rows| {
+            let mut col_stats = HashMap::new();
+            col_stats.insert(0, column_stats(rows.iter().map(|(a, _)| *a)));
+            col_stats.insert(1, column_stats(rows.iter().map(|(_, b)| *b)));
+            let keys = rows.iter().map(|(a, b)| zorder_key(*a, *b));
+            let cluster_stats = ClusterStatistics {
+                cluster_key_id: 0,
+                min: vec![Scalar::Number(NumberScalar::UInt64(
+                    keys.clone().min().unwrap(),
+                ))],
+                max: vec![Scalar::Number(NumberScalar::UInt64(keys.max().unwrap()))],
+                level: 0,
+                pages: None,
+            };
+            Block {
+                col_stats,
+                cluster_stats: Some(cluster_stats),
+            }
+        })
+        .collect()
+}
+
+fn column_stats(values: impl Iterator<Item = u32> + Clone) -> ColumnStatistics {
+    ColumnStatistics {
+        min: Scalar::Number(NumberScalar::UInt32(values.clone().min().unwrap())),
+        max: Scalar::Number(NumberScalar::UInt32(values.max().unwrap())),
+        null_count: 0,
+        in_memory_size: 0,
+        distinct_of_values: None,
+    }
+}
+
+/// `a >= a_min AND a < a_max AND b >= b_min AND b < b_max`
+fn range_query(a_min: u32, a_max: u32, b_min: u32, b_max: u32) -> Expr<String> {
+    let column = |name: &str| RawExpr::ColumnRef {
+        span: None,
+        id: name.to_string(),
+        data_type: DataType::Number(NumberDataType::UInt32),
+        display_name: name.to_string(),
+    };
+    let constant = |v: u32| RawExpr::Constant {
+        span: None,
+        scalar: Scalar::Number(NumberScalar::UInt32(v)),
+    };
+    let call = |name: &str, args: Vec<RawExpr<String>>| RawExpr::FunctionCall {
+        span: None,
+        name: name.to_string(),
+        params: vec![],
+        args,
+    };
+
+    let raw_expr = call("and", vec![
+        call("and", vec![
+            call("gte", vec![column("a"), constant(a_min)]),
+            call("lt", vec![column("a"), constant(a_max)]),
+        ]),
+        call("and", vec![
+            call("gte", vec![column("b"), constant(b_min)]),
+            call("lt", vec![column("b"), constant(b_max)]),
+        ]),
+    ]);
+    check(&raw_expr, &BUILTIN_FUNCTIONS).unwrap()
+}
+
+criterion_group!(benches, bench_zorder_pruning);
+criterion_main!(benches);
@@ -175,11 +175,15 @@ impl BloomIndex {
     /// Create a filter block from source data.
     ///
     /// All input blocks should belong to a Parquet file, e.g. the block array represents the parquet file in memory.
+    ///
+    /// If `track_json_validity` is true, a filter of whether the values are valid JSON is also
+    /// created for every string column, see [`BloomIndex::build_json_validity_column_name`].
     pub fn try_create(
         func_ctx: FunctionContext,
         source_schema: TableSchemaRef,
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
+        track_json_validity: bool,
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -192,6 +196,8 @@ impl BloomIndex {
 
         let mut fields = Vec::new();
         let mut columns = Vec::new();
+        let mut json_fields = Vec::new();
+        let mut json_columns = Vec::new();
         for i in 0..num_columns {
             let data_type = &data_blocks_tobe_indexed[0].get_by_offset(i).data_type;
             match data_type {
@@ -232,6 +238,10 @@ impl BloomIndex {
                             })
                             .collect::<Vec<_>>();
                         let column = Column::concat(&source_columns);
+                        if track_json_validity && data_type.remove_nullable() == DataType::String {
+                            json_fields.push(source_schema.field(i));
+                            json_columns.push((column.clone(), data_type.clone()));
+                        }
                         columns.push((column, data_type.clone()));
                     }
                 }
@@ -296,6 +306,25 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        for (field, (column, data_type)) in json_fields.into_iter().zip(json_columns.iter()) {
+            let json_valid = Self::calculate_json_validity(func_ctx, column, data_type)?;
+            let col = Self::calculate_column_digest(
+                func_ctx,
+                &json_valid,
+                &DataType::Boolean,
+                &DataType::Number(NumberDataType::UInt64),
+            )?;
+            let column = UInt64Type::try_downcast_column(&col).unwrap();
+
+            let mut filter_builder = Xor8Builder::create();
+            filter_builder.add_digests(column.deref());
+            let filter = filter_builder.build()?;
+
+            let filter_name = Self::build_json_validity_column_name(field);
+            filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+            filters.push(Arc::new(filter));
+        }
+
         let filter_schema = Arc::new(TableSchema::new(filter_fields));
 
         Ok(Some(Self {
@@ -348,6 +377,18 @@ impl BloomIndex {
                 }
             },
         )?;
+        visit_expr_column_check_json(&mut expr, &mut |span, col_name, return_type| {
+            // If all the values of the column are valid JSON, we rewrite the expression to `false`.
+            if self.find_invalid_json(col_name)? == FilterEvalResult::MustFalse {
+                Ok(Some(Expr::Constant {
+                    span,
+                    scalar: Scalar::Boolean(false),
+                    data_type: return_type.clone(),
+                }))
+            } else {
+                Ok(None)
+            }
+        })?;
 
         let (new_expr, _) = ConstantFolder::fold(&expr, self.func_ctx, &BUILTIN_FUNCTIONS);
 
@@ -379,6 +420,33 @@ impl BloomIndex {
         Ok(column)
     }
 
+    /// calculate whether the values of a string column are valid JSON, null values are taken as valid
+    pub fn calculate_json_validity(
+        func_ctx: FunctionContext,
+        column: &Column,
+        data_type: &DataType,
+    ) -> Result<Column> {
+        let error_type = DataType::Nullable(Box::new(DataType::String));
+        let (errors, _) = eval_function(
+            None,
+            "check_json",
+            [(Value::Column(column.clone()), data_type.clone())],
+            func_ctx,
+            column.len(),
+            &BUILTIN_FUNCTIONS,
+        )?;
+        let (value, _) = eval_function(
+            None,
+            "is_null",
+            [(errors, error_type)],
+            func_ctx,
+            column.len(),
+            &BUILTIN_FUNCTIONS,
+        )?;
+        let column = value.convert_to_full_column(&DataType::Boolean, column.len());
+        Ok(column)
+    }
+
     /// calculate digest for constant scalar
     pub fn calculate_scalar_digest(
         func_ctx: FunctionContext,
@@ -425,6 +493,47 @@ impl BloomIndex {
         }
     }
 
+    /// The filter of whether the values of a string column are valid JSON will be stored with
+    /// field name 'JsonValid(column_id)'
+    pub fn build_json_validity_column_name(field: &TableField) -> String {
+        format!("JsonValid({})", field.column_id())
+    }
+
+    /// Find all columns that match the pattern of `check_json(col) IS NOT NULL` in the expression.
+    pub fn find_json_validity_columns(expr: &Expr<String>) -> Result<Vec<String>> {
+        let mut cols = Vec::new();
+        visit_expr_column_check_json(&mut expr.clone(), &mut |_, col_name, _| {
+            cols.push(col_name.to_string());
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
+    fn find_invalid_json(&self, column_name: &str) -> Result<FilterEvalResult> {
+        let filter_column = &Self::build_json_validity_column_name(
+            self.source_schema.field_with_name(column_name)?,
+        );
+
+        if !self.filter_schema.has_field(filter_column) {
+            // The validity is not tracked for the column.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let filter = &self.filters[idx];
+        let digest = Self::calculate_scalar_digest(
+            self.func_ctx,
+            &Scalar::Boolean(false),
+            &DataType::Boolean,
+        )?;
+
+        if filter.contains_digest(digest) {
+            Ok(FilterEvalResult::Uncertain)
+        } else {
+            Ok(FilterEvalResult::MustFalse)
+        }
+    }
+
     fn find(
         &self,
         column_name: &str,
@@ -557,6 +666,50 @@ fn visit_expr_column_eq_constant(
     Ok(())
 }
 
+fn visit_expr_column_check_json(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `is_not_null(check_json(Column))`
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        if function.signature.name == "is_not_null" {
+            if let [Expr::FunctionCall { id, args, .. }] = args.as_slice() {
+                if let [Expr::ColumnRef { id: col_name, .. }] = args.as_slice() {
+                    if id.name() == "check_json" {
+                        // If the visitor returns a new expression, then replace with the current expression.
+                        if let Some(new_expr) = visitor(*span, col_name, return_type)? {
+                            *expr = new_expr;
+                            return Ok(());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_check_json(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_check_json(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
 fn visit_map_column(
     span: Span,
     args: &[Expr<String>],
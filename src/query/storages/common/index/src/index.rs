@@ -24,6 +24,7 @@ pub trait Index {
                 | DataType::Date
                 | DataType::Timestamp
                 | DataType::String
+                | DataType::Binary
                 | DataType::Decimal(_)
         )
     }
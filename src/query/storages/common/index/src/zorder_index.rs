@@ -0,0 +1,149 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::ConstantFolder;
+use common_expression::Domain;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_functions::scalars::zorder::denormalize_domain;
+use common_functions::scalars::zorder::zorder_cells;
+use common_functions::scalars::zorder::ZORDER_KEY_FUNC_NAME;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_table_meta::meta::ClusterStatistics;
+
+/// Splits the key range of a block this many times at most, up to `2^depth` cells
+/// are checked against the filter for each block.
+const MAX_SPLIT_DEPTH: usize = 4;
+
+/// Prunes the blocks of a table clustered by `ZORDER(<column>, ...)`.
+///
+/// The cluster statistics of such a block hold the range of its Z-order keys, the
+/// range is split into the cells of the Z-order curve and the block is kept if the
+/// filter may be true in any of the cells.
+#[derive(Clone)]
+pub struct ZOrderIndex {
+    expr: Expr<String>,
+    column_refs: HashMap<String, DataType>,
+    func_ctx: FunctionContext,
+    cluster_key_id: u32,
+
+    // the columns of the Z-order key, in the order of its dimensions
+    zorder_columns: Vec<String>,
+}
+
+impl ZOrderIndex {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        cluster_key_id: u32,
+        zorder_columns: Vec<String>,
+        expr: &Expr<String>,
+    ) -> Result<Self> {
+        Ok(Self {
+            column_refs: expr.column_refs(),
+            expr: expr.clone(),
+            func_ctx,
+            cluster_key_id,
+            zorder_columns,
+        })
+    }
+
+    /// Returns the Z-order columns if the table is clustered by `ZORDER(<column>, ...)`.
+    pub fn zorder_columns(cluster_keys: &[RemoteExpr<String>]) -> Option<Vec<String>> {
+        match cluster_keys {
+            [RemoteExpr::FunctionCall { id, args, .. }] if id.name() == ZORDER_KEY_FUNC_NAME => {
+                args.iter()
+                    .map(|arg| match arg {
+                        RemoteExpr::ColumnRef { id, .. } => Some(id.clone()),
+                        _ => None,
+                    })
+                    .collect()
+            }
+            _ => None,
+        }
+    }
+
+    pub fn try_apply_const(&self) -> Result<bool> {
+        // if the exprs did not contains any of the Z-order columns, we should return true
+        if !self
+            .zorder_columns
+            .iter()
+            .any(|c| self.column_refs.contains_key(c))
+        {
+            return Ok(true);
+        }
+
+        // Only return false, which means to skip this block, when the expression is folded to a constant false.
+        Ok(!matches!(self.expr, Expr::Constant {
+            scalar: Scalar::Boolean(false),
+            ..
+        }))
+    }
+
+    #[tracing::instrument(level = "debug", name = "zorder_index_eval", skip_all)]
+    pub fn apply(&self, stats: &Option<ClusterStatistics>) -> Result<bool> {
+        let stats = match stats {
+            Some(stats) if stats.cluster_key_id == self.cluster_key_id => stats,
+            _ => return Ok(true),
+        };
+        let (min, max) = match (stats.min.first(), stats.max.first()) {
+            (
+                Some(Scalar::Number(NumberScalar::UInt64(min))),
+                Some(Scalar::Number(NumberScalar::UInt64(max))),
+            ) if min <= max => (*min, *max),
+            _ => return Ok(true),
+        };
+
+        let dims = self.zorder_columns.len();
+        for cell in zorder_cells(min, max, dims, MAX_SPLIT_DEPTH) {
+            if self.eval_cell(&cell) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn eval_cell(&self, cell: &[(u64, u64)]) -> bool {
+        let input_domains = self
+            .column_refs
+            .iter()
+            .map(|(name, ty)| {
+                let domain = match self.zorder_columns.iter().position(|c| c == name) {
+                    Some(dim) => denormalize_domain(ty, cell[dim].0, cell[dim].1),
+                    None => Domain::full(ty),
+                };
+                (name.clone(), domain)
+            })
+            .collect();
+
+        let (new_expr, _) = ConstantFolder::fold_with_domain(
+            &self.expr,
+            input_domains,
+            self.func_ctx,
+            &BUILTIN_FUNCTIONS,
+        );
+
+        // Only return false, which means to skip this cell, when the expression is folded to a constant false.
+        !matches!(new_expr, Expr::Constant {
+            scalar: Scalar::Boolean(false),
+            ..
+        })
+    }
+}
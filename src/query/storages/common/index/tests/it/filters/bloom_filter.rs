@@ -111,6 +111,7 @@ fn test_bloom_filter() -> Result<()> {
         schema,
         LatestBloom::VERSION,
         &blocks_ref,
+        false,
     )?
     .unwrap();
 
@@ -284,3 +285,77 @@ fn eval_map_index(
 
     index.apply(expr, &scalar_map).unwrap()
 }
+
+#[test]
+fn test_bloom_filter_json_validity() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::String),
+        TableField::new("1", TableDataType::String),
+    ]));
+    let block = DataBlock::new_from_columns(vec![
+        StringType::from_data(vec![r#"{"a":1}"#, "[1,2]"]),
+        StringType::from_data(vec![r#"{"a":1}"#, "{a:1"]),
+    ]);
+
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        schema.clone(),
+        LatestBloom::VERSION,
+        &[&block],
+        true,
+    )?
+    .unwrap();
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_json_validity_index(&index, "0")
+    );
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_json_validity_index(&index, "1")
+    );
+
+    // The validity is not tracked.
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        schema,
+        LatestBloom::VERSION,
+        &[&block],
+        false,
+    )?
+    .unwrap();
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_json_validity_index(&index, "0")
+    );
+    Ok(())
+}
+
+fn eval_json_validity_index(index: &BloomIndex, col_name: &str) -> FilterEvalResult {
+    let check_json_expr = check_function(
+        None,
+        "check_json",
+        &[],
+        &[Expr::ColumnRef {
+            span: None,
+            id: col_name.to_string(),
+            data_type: DataType::String,
+            display_name: col_name.to_string(),
+        }],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+    let expr = check_function(
+        None,
+        "is_not_null",
+        &[],
+        &[check_json_expr],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+
+    assert_eq!(
+        BloomIndex::find_json_validity_columns(&expr).unwrap(),
+        vec![col_name.to_string()]
+    );
+    index.apply(expr, &HashMap::new()).unwrap()
+}
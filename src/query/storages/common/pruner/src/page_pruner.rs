@@ -21,6 +21,7 @@ use common_expression::FunctionContext;
 use common_expression::RemoteExpr;
 use common_expression::TableSchemaRef;
 use storages_common_index::PageIndex;
+use storages_common_index::ZOrderIndex;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::ClusterStatistics;
 
@@ -58,6 +59,19 @@ impl PagePruner for PageIndex {
     }
 }
 
+impl PagePruner for ZOrderIndex {
+    fn should_keep(&self, stats: &Option<ClusterStatistics>) -> (bool, Option<Range<usize>>) {
+        match self.apply(stats) {
+            Ok(keep) => (keep, None),
+            Err(e) => {
+                // swallow exceptions intentionally, corrupted index should not prevent execution
+                tracing::warn!("failed to zorder filter, returning true. {}", e);
+                (true, None)
+            }
+        }
+    }
+}
+
 pub struct PagePrunerCreator;
 
 impl PagePrunerCreator {
@@ -71,6 +85,27 @@ impl PagePrunerCreator {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
     ) -> Result<Arc<dyn PagePruner + Send + Sync>> {
+        if let (Some(cluster_key_meta), Some(zorder_columns)) = (
+            &cluster_key_meta,
+            ZOrderIndex::zorder_columns(&cluster_keys),
+        ) {
+            return Ok(match filter_expr {
+                Some(expr) => {
+                    let zorder_filter = ZOrderIndex::try_create(
+                        func_ctx,
+                        cluster_key_meta.0,
+                        zorder_columns,
+                        expr,
+                    )?;
+                    match zorder_filter.try_apply_const() {
+                        Ok(false) => Arc::new(KeepFalse),
+                        _ => Arc::new(zorder_filter),
+                    }
+                }
+                _ => Arc::new(KeepTrue),
+            });
+        }
+
         if cluster_key_meta.is_none()
             || cluster_keys.is_empty()
             || cluster_keys
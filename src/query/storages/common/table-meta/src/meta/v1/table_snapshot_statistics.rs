@@ -14,6 +14,8 @@
 
 use std::collections::HashMap;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_expression::ColumnId;
 use serde::Deserialize;
 use serde::Serialize;
@@ -31,6 +33,10 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
 
     pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// when the statistics were collected by `ANALYZE TABLE`, absent in older statistics
+    #[serde(default)]
+    pub analyzed_on: Option<DateTime<Utc>>,
 }
 
 impl TableSnapshotStatistics {
@@ -39,6 +45,7 @@ impl TableSnapshotStatistics {
             format_version: TableSnapshotStatistics::VERSION,
             snapshot_id: SnapshotId::new_v4(),
             column_distinct_values,
+            analyzed_on: Some(Utc::now()),
         }
     }
 
@@ -12,6 +12,7 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::collections::BTreeMap;
 use std::ops::Add;
 
 use chrono::DateTime;
@@ -60,6 +61,12 @@ pub struct TableSnapshot {
     // The metadata of the cluster keys.
     pub cluster_key_meta: Option<ClusterKey>,
     pub table_statistics_location: Option<String>,
+
+    /// The next offsets to read from the partitions of the streaming sources loading
+    /// into the table, keyed by the source. They are committed along with the data read
+    /// before them, so a source is loaded exactly once.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub source_offsets: BTreeMap<String, BTreeMap<i32, i64>>,
 }
 
 impl TableSnapshot {
@@ -91,13 +98,14 @@ impl TableSnapshot {
             segments,
             cluster_key_meta,
             table_statistics_location,
+            source_offsets: BTreeMap::new(),
         }
     }
 
     pub fn from_previous(previous: &TableSnapshot) -> Self {
         let id = Uuid::new_v4();
         let clone = previous.clone();
-        Self::new(
+        let mut snapshot = Self::new(
             id,
             &clone.timestamp,
             Some((clone.snapshot_id, clone.format_version)),
@@ -106,7 +114,9 @@ impl TableSnapshot {
             clone.segments,
             clone.cluster_key_meta,
             clone.table_statistics_location,
-        )
+        );
+        snapshot.source_offsets = clone.source_offsets;
+        snapshot
     }
 
     pub fn format_version(&self) -> u64 {
@@ -132,6 +142,7 @@ impl From<v0::TableSnapshot> for TableSnapshot {
             segments: s.segments.into_iter().map(|l| (l, 0)).collect(),
             cluster_key_meta: None,
             table_statistics_location: None,
+            source_offsets: BTreeMap::new(),
         }
     }
 }
@@ -152,6 +163,7 @@ impl From<v1::TableSnapshot> for TableSnapshot {
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            source_offsets: BTreeMap::new(),
         }
     }
 }
@@ -20,6 +20,13 @@ pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
 pub const OPT_KEY_STORAGE_FORMAT: &str = "storage_format";
 pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
+/// The storage prefix of a table attached to the data of another table, instead of
+/// the prefix derived from the ids of the table.
+pub const OPT_KEY_STORAGE_PREFIX: &str = "storage_prefix";
+/// Marks a table attached to the data of another table by `ATTACH TABLE ... READ_ONLY`.
+pub const OPT_KEY_TABLE_ATTACHED_READ_ONLY: &str = "read_only_attached";
+/// Marks a table of a share which is restricted to read-only access by `SHARE_RESTRICTIONS`.
+pub const OPT_KEY_TABLE_SHARED_READ_ONLY: &str = "read_only_shared";
 
 /// Legacy table snapshot location key
 ///
@@ -38,6 +45,9 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_STORAGE_PREFIX);
+    r.insert(OPT_KEY_TABLE_ATTACHED_READ_ONLY);
+    r.insert(OPT_KEY_TABLE_SHARED_READ_ONLY);
     r
 });
 
@@ -46,6 +56,9 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_STORAGE_PREFIX);
+    r.insert(OPT_KEY_TABLE_ATTACHED_READ_ONLY);
+    r.insert(OPT_KEY_TABLE_SHARED_READ_ONLY);
     r
 });
 
@@ -29,6 +29,7 @@ use common_catalog::table::ColumnStatistics;
 use common_catalog::table::ColumnStatisticsProvider;
 use common_catalog::table::CompactTarget;
 use common_catalog::table::NavigationDescriptor;
+use common_catalog::table::ReplaceIntoStatistics;
 use common_catalog::table_context::TableContext;
 use common_catalog::table_mutator::TableMutator;
 use common_exception::ErrorCode;
@@ -46,11 +47,11 @@ use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_sharing::create_share_table_operator;
 use common_sql::parse_exprs;
-use common_storage::init_operator;
 use common_storage::DataOperator;
 use common_storage::ShareTableConfig;
 use common_storage::StorageMetrics;
 use common_storage::StorageMetricsLayer;
+use common_storage::StorageOperatorCache;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::ClusterKey;
@@ -65,7 +66,10 @@ use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_TABLE_SHARED_READ_ONLY;
 use uuid::Uuid;
 
 use crate::io::MetaReaders;
@@ -85,6 +89,7 @@ use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_SNAPSHOT_PREFIX;
 
 #[derive(Clone)]
 pub struct FuseTable {
@@ -119,7 +124,7 @@ impl FuseTable {
             DatabaseType::NormalDB => {
                 let storage_params = table_info.meta.storage_params.clone();
                 match storage_params {
-                    Some(sp) => Ok(init_operator(&sp)?),
+                    Some(sp) => StorageOperatorCache::instance().get_or_create(&sp),
                     None => Ok(DataOperator::instance().operator()),
                 }
             }
@@ -200,6 +205,10 @@ impl FuseTable {
     }
 
     pub fn parse_storage_prefix(table_info: &TableInfo) -> Result<String> {
+        // The attached tables share the prefix of the tables they are attached to.
+        if let Some(prefix) = table_info.options().get(OPT_KEY_STORAGE_PREFIX) {
+            return Ok(prefix.clone());
+        }
         let table_id = table_info.ident.table_id;
         let db_id = table_info
             .options()
@@ -218,7 +227,7 @@ impl FuseTable {
     }
 
     #[tracing::instrument(level = "debug", skip_all)]
-    pub(crate) async fn read_table_snapshot_statistics(
+    pub async fn read_table_snapshot_statistics(
         &self,
         snapshot: Option<&Arc<TableSnapshot>>,
     ) -> Result<Option<Arc<TableSnapshotStatistics>>> {
@@ -247,14 +256,18 @@ impl FuseTable {
     #[tracing::instrument(level = "debug", skip_all)]
     pub async fn read_table_snapshot(&self) -> Result<Option<Arc<TableSnapshot>>> {
         if let Some(loc) = self.snapshot_loc().await? {
-            let reader = MetaReaders::table_snapshot_reader(self.get_operator());
-            let ver = self.snapshot_format_version().await?;
+            let ver = TableMetaLocationGenerator::snapshot_version(loc.as_str());
             let params = LoadParams {
                 location: loc,
                 len_hint: None,
                 ver,
                 put_cache: true,
             };
+            if self.is_attached() {
+                let reader = MetaReaders::attached_table_snapshot_reader(self.get_operator());
+                return Ok(Some(reader.read(&params).await?));
+            }
+            let reader = MetaReaders::table_snapshot_reader(self.get_operator());
             Ok(Some(reader.read(&params).await?))
         } else {
             Ok(None)
@@ -282,15 +295,75 @@ impl FuseTable {
             }
             DatabaseType::NormalDB => {
                 let options = self.table_info.options();
-                Ok(options
+                let loc = options
                     .get(OPT_KEY_SNAPSHOT_LOCATION)
                     // for backward compatibility, we check the legacy table option
                     .or_else(|| options.get(OPT_KEY_LEGACY_SNAPSHOT_LOC))
-                    .cloned())
+                    .cloned();
+                // The snapshot location is only set for the attached tables navigated to
+                // a historical snapshot.
+                if loc.is_none() && self.is_attached() {
+                    return self.attached_snapshot_loc().await;
+                }
+                Ok(loc)
             }
         }
     }
 
+    /// The attached tables always read the latest snapshot of the tables they are attached to,
+    /// which is resolved by the last snapshot hint written by the commits of those tables.
+    async fn attached_snapshot_loc(&self) -> Result<Option<String>> {
+        let hint = self
+            .meta_location_generator
+            .gen_last_snapshot_hint_location();
+        if !self.operator.is_exist(&hint).await? {
+            return Ok(None);
+        }
+        let data = self.operator.read(&hint).await?;
+        let hint_loc = str::from_utf8(&data)?;
+
+        // The hint is prefixed by the storage root of the table attached to, which may be
+        // different from the root of the attached table.
+        let snapshot_prefix = format!(
+            "{}/{}/",
+            self.meta_location_generator.prefix(),
+            FUSE_TBL_SNAPSHOT_PREFIX
+        );
+        match hint_loc.rfind(&snapshot_prefix) {
+            Some(pos) => Ok(Some(hint_loc[pos..].to_string())),
+            None => Err(ErrorCode::StorageOther(format!(
+                "invalid last snapshot hint of attached table {}: {}",
+                self.table_info.desc, hint_loc
+            ))),
+        }
+    }
+
+    /// Whether the table is attached to the data of another table by `ATTACH TABLE`.
+    pub fn is_attached(&self) -> bool {
+        self.table_info
+            .options()
+            .contains_key(OPT_KEY_TABLE_ATTACHED_READ_ONLY)
+    }
+
+    /// Whether the table is shared to a consumer restricted to read-only access, whose
+    /// statistics are not exposed.
+    pub fn is_read_only_shared(&self) -> bool {
+        self.table_info
+            .options()
+            .contains_key(OPT_KEY_TABLE_SHARED_READ_ONLY)
+    }
+
+    /// The attached tables share the data of other tables, nothing of them can be written.
+    pub fn check_mutable(&self) -> Result<()> {
+        if self.is_attached() {
+            return Err(ErrorCode::TableNotWritable(format!(
+                "table {} is attached read-only to the data of another table, it can't be modified",
+                self.table_info.desc
+            )));
+        }
+        Ok(())
+    }
+
     pub fn get_operator(&self) -> Operator {
         self.operator.clone()
     }
@@ -340,7 +413,13 @@ impl Table for FuseTable {
     }
 
     fn has_exact_total_row_count(&self) -> bool {
-        true
+        // The statistics of the attached tables are not updated by the commits of the
+        // tables they are attached to.
+        !self.is_attached() && !self.is_read_only_shared()
+    }
+
+    fn is_read_only(&self) -> bool {
+        self.is_attached() || self.is_read_only_shared()
     }
 
     fn cluster_keys(&self, ctx: Arc<dyn TableContext>) -> Vec<RemoteExpr<String>> {
@@ -371,6 +450,7 @@ impl Table for FuseTable {
         ctx: Arc<dyn TableContext>,
         cluster_key_str: String,
     ) -> Result<()> {
+        self.check_mutable()?;
         let mut new_table_meta = self.get_table_info().meta.clone();
         new_table_meta = new_table_meta.push_cluster_key(cluster_key_str);
         let cluster_key_meta = new_table_meta.cluster_key();
@@ -383,13 +463,17 @@ impl Table for FuseTable {
         let prev_statistics_location = prev
             .as_ref()
             .and_then(|v| v.table_statistics_location.clone());
-        let (summary, segments) = if let Some(v) = prev {
-            (v.summary.clone(), v.segments.clone())
+        let (summary, segments, source_offsets) = if let Some(v) = prev {
+            (
+                v.summary.clone(),
+                v.segments.clone(),
+                v.source_offsets.clone(),
+            )
         } else {
-            (FuseStatistics::default(), vec![])
+            (FuseStatistics::default(), vec![], Default::default())
         };
 
-        let new_snapshot = TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_timestamp,
             prev_snapshot_id,
@@ -399,6 +483,7 @@ impl Table for FuseTable {
             cluster_key_meta,
             prev_statistics_location,
         );
+        new_snapshot.source_offsets = source_offsets;
 
         let mut table_info = self.table_info.clone();
         table_info.meta = new_table_meta;
@@ -416,6 +501,7 @@ impl Table for FuseTable {
     }
 
     async fn drop_table_cluster_keys(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        self.check_mutable()?;
         if self.cluster_key_meta.is_none() {
             return Ok(());
         }
@@ -433,13 +519,17 @@ impl Table for FuseTable {
             .as_ref()
             .and_then(|v| v.table_statistics_location.clone());
         let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
-        let (summary, segments) = if let Some(v) = prev {
-            (v.summary.clone(), v.segments.clone())
+        let (summary, segments, source_offsets) = if let Some(v) = prev {
+            (
+                v.summary.clone(),
+                v.segments.clone(),
+                v.source_offsets.clone(),
+            )
         } else {
-            (FuseStatistics::default(), vec![])
+            (FuseStatistics::default(), vec![], Default::default())
         };
 
-        let new_snapshot = TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_timestamp,
             prev_snapshot_id,
@@ -449,6 +539,7 @@ impl Table for FuseTable {
             None,
             prev_statistics_location,
         );
+        new_snapshot.source_offsets = source_offsets;
 
         let mut table_info = self.table_info.clone();
         table_info.meta = new_table_meta;
@@ -491,6 +582,7 @@ impl Table for FuseTable {
         append_mode: AppendMode,
         need_output: bool,
     ) -> Result<()> {
+        self.check_mutable()?;
         self.do_append_data(ctx, pipeline, append_mode, need_output)
     }
 
@@ -499,8 +591,10 @@ impl Table for FuseTable {
         ctx: Arc<dyn TableContext>,
         pipeline: &mut Pipeline,
         on_conflict_fields: Vec<TableField>,
+        statistics: Arc<ReplaceIntoStatistics>,
     ) -> Result<()> {
-        self.build_replace_pipeline(ctx, on_conflict_fields, pipeline)
+        self.check_mutable()?;
+        self.build_replace_pipeline(ctx, on_conflict_fields, pipeline, statistics)
             .await
     }
 
@@ -512,6 +606,7 @@ impl Table for FuseTable {
         copied_files: Option<UpsertTableCopiedFileReq>,
         overwrite: bool,
     ) -> Result<()> {
+        self.check_mutable()?;
         // only append operation supported currently
         let append_log_entries = operations
             .iter()
@@ -523,20 +618,26 @@ impl Table for FuseTable {
 
     #[tracing::instrument(level = "debug", name = "fuse_table_truncate", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn truncate(&self, ctx: Arc<dyn TableContext>, purge: bool) -> Result<()> {
+        self.check_mutable()?;
         self.do_truncate(ctx, purge).await
     }
 
     #[tracing::instrument(level = "debug", name = "fuse_table_optimize", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn purge(&self, ctx: Arc<dyn TableContext>, keep_last_snapshot: bool) -> Result<()> {
+        self.check_mutable()?;
         self.do_purge(&ctx, keep_last_snapshot).await
     }
 
     #[tracing::instrument(level = "debug", name = "analyze", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        self.check_mutable()?;
         self.do_analyze(&ctx).await
     }
 
     fn table_statistics(&self) -> Result<Option<TableStatistics>> {
+        if self.is_attached() || self.is_read_only_shared() {
+            return Ok(None);
+        }
         let s = &self.table_info.meta.statistics;
         Ok(Some(TableStatistics {
             num_rows: Some(s.number_of_rows),
@@ -589,6 +690,7 @@ impl Table for FuseTable {
         col_indices: Vec<usize>,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
+        self.check_mutable()?;
         self.do_delete(ctx, filter, col_indices, pipeline).await
     }
 
@@ -600,6 +702,7 @@ impl Table for FuseTable {
         update_list: Vec<(FieldIndex, RemoteExpr<String>)>,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
+        self.check_mutable()?;
         self.do_update(ctx, filter, col_indices, update_list, pipeline)
             .await
     }
@@ -622,6 +725,7 @@ impl Table for FuseTable {
         limit: Option<usize>,
         pipeline: &mut Pipeline,
     ) -> Result<bool> {
+        self.check_mutable()?;
         self.do_compact(ctx, target, limit, pipeline).await
     }
 
@@ -631,6 +735,7 @@ impl Table for FuseTable {
         pipeline: &mut Pipeline,
         push_downs: Option<PushDownInfo>,
     ) -> Result<Option<Box<dyn TableMutator>>> {
+        self.check_mutable()?;
         self.do_recluster(ctx, pipeline, push_downs).await
     }
 
@@ -639,12 +744,17 @@ impl Table for FuseTable {
         ctx: Arc<dyn TableContext>,
         point: NavigationDescriptor,
     ) -> Result<()> {
+        self.check_mutable()?;
         self.do_revert_to(ctx.as_ref(), point).await
     }
 
     fn support_prewhere(&self) -> bool {
         matches!(self.storage_format, FuseStorageFormat::Native)
     }
+
+    fn support_block_sample(&self) -> bool {
+        true
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -14,9 +14,12 @@
 
 use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::write::to_parquet_schema;
+use common_arrow::parquet::metadata::ColumnDescriptor;
 use common_arrow::parquet::metadata::SchemaDescriptor;
 use common_catalog::plan::Projection;
 use common_catalog::table_context::TableContext;
@@ -29,12 +32,17 @@ use common_expression::DataSchema;
 use common_expression::FieldIndex;
 use common_expression::Scalar;
 use common_expression::TableField;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_sql::field_default_value;
 use common_storage::ColumnNode;
 use common_storage::ColumnNodes;
 use opendal::Operator;
 
+use crate::metrics::metrics_inc_table_block_read_bytes;
+use crate::metrics::metrics_observe_table_block_read_milliseconds;
+use crate::metrics::TableMetricLabels;
+
 // TODO: make BlockReader as a trait.
 #[derive(Clone)]
 pub struct BlockReader {
@@ -45,7 +53,22 @@ pub struct BlockReader {
     pub(crate) project_column_nodes: Vec<ColumnNode>,
     pub(crate) parquet_schema_descriptor: SchemaDescriptor,
     pub(crate) default_vals: Vec<Scalar>,
+    /// Previous versions of each projected column, left behind by
+    /// `ALTER TABLE MODIFY COLUMN`, newest first. Blocks written before the
+    /// type change only contain one of these; they are read and cast.
+    pub(crate) column_origins: Vec<Vec<ColumnOrigin>>,
     pub query_internal_columns: bool,
+    /// Labels of the per-table read metrics, `None` if the reads are not accounted to a table.
+    pub(crate) metric_labels: Option<TableMetricLabels>,
+}
+
+/// A previous version of a column, stored under its own column id.
+#[derive(Clone)]
+pub struct ColumnOrigin {
+    pub column_id: ColumnId,
+    pub field: Field,
+    pub column_descriptor: ColumnDescriptor,
+    pub data_type: DataType,
 }
 
 fn inner_project_field_default_values(default_vals: &[Scalar], paths: &[usize]) -> Result<Scalar> {
@@ -79,6 +102,7 @@ impl BlockReader {
         projection: Projection,
         ctx: Arc<dyn TableContext>,
         query_internal_columns: bool,
+        metric_labels: Option<TableMetricLabels>,
     ) -> Result<Arc<BlockReader>> {
         // init projected_schema and default_vals of schema.fields
         let (projected_schema, default_vals) = match projection {
@@ -125,6 +149,7 @@ impl BlockReader {
             .map(|c| (*c).clone())
             .collect();
         let project_indices = Self::build_projection_indices(&project_column_nodes);
+        let column_origins = Self::build_column_origins(&schema, &project_column_nodes)?;
 
         Ok(Arc::new(BlockReader {
             operator,
@@ -134,10 +159,23 @@ impl BlockReader {
             project_column_nodes,
             parquet_schema_descriptor,
             default_vals,
+            column_origins,
             query_internal_columns,
+            metric_labels,
         }))
     }
 
+    /// Account the bytes read from the storage for a block, and the time spent, to the table.
+    pub(crate) fn record_table_read(&self, bytes: u64, start: Instant) {
+        if let Some(labels) = &self.metric_labels {
+            metrics_inc_table_block_read_bytes(labels, bytes);
+            metrics_observe_table_block_read_milliseconds(
+                labels,
+                start.elapsed().as_millis() as u64,
+            );
+        }
+    }
+
     pub fn support_blocking_api(&self) -> bool {
         self.operator.info().can_blocking()
     }
@@ -160,6 +198,49 @@ impl BlockReader {
         indices
     }
 
+    // Only non-nested columns may have origins, see `TableSchema::modify_column_with_origin`.
+    fn build_column_origins(
+        schema: &TableSchema,
+        columns: &[ColumnNode],
+    ) -> Result<Vec<Vec<ColumnOrigin>>> {
+        let mut column_origins = Vec::with_capacity(columns.len());
+        for column in columns {
+            let mut origins = vec![];
+            if !column.has_children() && column.leaf_column_ids.len() == 1 {
+                for (column_id, data_type) in schema.origins_of_column(column.leaf_column_ids[0]) {
+                    let field = TableField::new(column.field.name.as_str(), data_type.clone());
+                    let arrow_field = Field::from(&field);
+                    let parquet_schema =
+                        to_parquet_schema(&ArrowSchema::from(vec![arrow_field.clone()]))?;
+                    origins.push(ColumnOrigin {
+                        column_id: *column_id,
+                        field: arrow_field,
+                        column_descriptor: parquet_schema.columns()[0].clone(),
+                        data_type: data_type.into(),
+                    });
+                }
+            }
+            column_origins.push(origins);
+        }
+        Ok(column_origins)
+    }
+
+    /// Ids of the columns to fetch from a block: the projected leaf columns,
+    /// followed by the origins of the projected columns.
+    pub(crate) fn column_ids_to_read(&self) -> Vec<ColumnId> {
+        let mut column_ids: Vec<ColumnId> = self
+            .project_indices
+            .values()
+            .map(|(column_id, ..)| *column_id)
+            .collect();
+        for origin in self.column_origins.iter().flatten() {
+            if !column_ids.contains(&origin.column_id) {
+                column_ids.push(origin.column_id);
+            }
+        }
+        column_ids
+    }
+
     pub fn query_internal_columns(&self) -> bool {
         self.query_internal_columns
     }
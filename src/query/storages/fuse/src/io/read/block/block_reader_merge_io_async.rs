@@ -136,13 +136,15 @@ impl BlockReader {
             metrics_inc_remote_io_read_parts(1);
         }
 
+        let start = Instant::now();
+        let mut read_bytes = 0;
         let mut ranges = vec![];
         // for async read, try using table data cache (if enabled in settings)
         let column_data_cache = CacheManager::instance().get_table_data_cache();
         let column_array_cache = CacheManager::instance().get_table_data_array_cache();
         let mut cached_column_data = vec![];
         let mut cached_column_array = vec![];
-        for (_index, (column_id, ..)) in self.project_indices.iter() {
+        for column_id in self.column_ids_to_read().iter() {
             let column_cache_key = TableDataCacheKey::new(location, *column_id);
 
             // first, check column array object cache
@@ -161,6 +163,7 @@ impl BlockReader {
             if let Some(column_meta) = columns_meta.get(column_id) {
                 let (offset, len) = column_meta.offset_length();
                 ranges.push((*column_id, offset..(offset + len)));
+                read_bytes += len;
 
                 // Perf
                 {
@@ -175,6 +178,7 @@ impl BlockReader {
         // TODO set
         merge_io_read_res.cached_column_data = cached_column_data;
         merge_io_read_res.cached_column_array = cached_column_array;
+        self.record_table_read(read_bytes, start);
         Ok(merge_io_read_res)
     }
 
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::ops::Range;
+use std::time::Instant;
 
 use common_base::rangemap::RangeMerger;
 use common_catalog::plan::PartInfoPtr;
@@ -104,9 +105,11 @@ impl BlockReader {
         let part = FusePartInfo::from_part(&part)?;
         let column_array_cache = CacheManager::instance().get_table_data_array_cache();
 
+        let start = Instant::now();
+        let mut read_bytes = 0;
         let mut ranges = vec![];
         let mut cached_column_array = vec![];
-        for (_index, (column_id, ..)) in self.project_indices.iter() {
+        for column_id in self.column_ids_to_read().iter() {
             // first, check column array object cache
             let block_path = &part.location;
             let column_cache_key = TableDataCacheKey::new(block_path, *column_id);
@@ -117,12 +120,14 @@ impl BlockReader {
             if let Some(column_meta) = part.columns_meta.get(column_id) {
                 let (offset, len) = column_meta.offset_length();
                 ranges.push((*column_id, offset..(offset + len)));
+                read_bytes += len;
             }
         }
 
         let mut merge_io_result =
             Self::sync_merge_io_read(settings, self.operator.clone(), &part.location, ranges)?;
         merge_io_result.cached_column_array = cached_column_array;
+        self.record_table_read(read_bytes, start);
         Ok(merge_io_result)
     }
 
@@ -59,6 +59,7 @@ impl BlockReader {
 
         let part = FusePartInfo::from_part(&part)?;
         let mut join_handlers = Vec::with_capacity(self.project_column_nodes.len());
+        let mut read_bytes = 0;
 
         for (index, column_node) in self.project_column_nodes.iter().enumerate() {
             let metas: Vec<ColumnMeta> = column_node
@@ -92,6 +93,7 @@ impl BlockReader {
                     .sum();
                 metrics_inc_remote_io_seeks(column_node.leaf_column_ids.len() as u64);
                 metrics_inc_remote_io_read_bytes(total_len);
+                read_bytes += total_len;
             }
         }
         let start = Instant::now();
@@ -102,6 +104,7 @@ impl BlockReader {
         {
             metrics_inc_remote_io_read_milliseconds(start.elapsed().as_millis() as u64);
         }
+        self.record_table_read(read_bytes, start);
         Ok(results)
     }
 
@@ -136,6 +139,8 @@ impl BlockReader {
     ) -> Result<BTreeMap<usize, Vec<NativeReader<Reader>>>> {
         let part = FusePartInfo::from_part(&part)?;
 
+        let start = Instant::now();
+        let mut read_bytes = 0;
         let mut results: BTreeMap<usize, Vec<NativeReader<Reader>>> = BTreeMap::new();
         for (index, column_node) in self.project_column_nodes.iter().enumerate() {
             let op = self.operator.clone();
@@ -145,12 +150,14 @@ impl BlockReader {
                 .filter_map(|column_id| part.columns_meta.get(column_id))
                 .cloned()
                 .collect::<Vec<_>>();
+            read_bytes += metas.iter().map(|meta| meta.offset_length().1).sum::<u64>();
 
             let readers =
                 Self::sync_read_native_column(op.clone(), &part.location, metas, part.range())?;
             results.insert(index, readers);
         }
 
+        self.record_table_read(read_bytes, start);
         Ok(results)
     }
 
@@ -27,8 +27,13 @@ use common_arrow::parquet::read::PageReader;
 use common_catalog::plan::PartInfoPtr;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::Column;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_storage::ColumnNode;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache::TableDataCacheKey;
@@ -106,8 +111,13 @@ impl BlockReader {
             compression,
             uncompressed_buffer: &uncompressed_buffer,
         };
-        for column_node in &self.project_column_nodes {
-            match self.deserialize_field(&field_deserialization_ctx, column_node)? {
+        for (i, column_node) in self.project_column_nodes.iter().enumerate() {
+            let deserialized =
+                match self.deserialize_field(&field_deserialization_ctx, column_node)? {
+                    None => self.deserialize_origin_field(&field_deserialization_ctx, i)?,
+                    v => v,
+                };
+            match deserialized {
                 None => {
                     need_to_fill_default_val = true;
                     need_default_vals.push(true);
@@ -312,6 +322,75 @@ impl BlockReader {
         }
     }
 
+    /// Deserialize a column whose type has been changed by `ALTER TABLE MODIFY COLUMN`
+    /// from the newest of its origins present in the block, and cast it to the
+    /// current type of the column.
+    fn deserialize_origin_field<'a>(
+        &self,
+        deserialization_context: &'a FieldDeserializationContext,
+        index: usize,
+    ) -> Result<Option<DeserializedArray<'a>>> {
+        let column_metas = deserialization_context.column_metas;
+        let origin = match self.column_origins[index]
+            .iter()
+            .find(|origin| column_metas.contains_key(&origin.column_id))
+        {
+            Some(origin) => origin,
+            None => return Ok(None),
+        };
+
+        let num_rows = deserialization_context.num_rows;
+        let column_meta = &column_metas[&origin.column_id];
+        let array = match deserialization_context.column_chunks.get(&origin.column_id) {
+            Some(DataItem::RawData(data)) => {
+                let mut array_iter = Self::chunks_to_parquet_array_iter(
+                    vec![column_meta],
+                    vec![*data],
+                    num_rows,
+                    vec![&origin.column_descriptor],
+                    origin.field.clone(),
+                    deserialization_context.compression,
+                    deserialization_context
+                        .uncompressed_buffer
+                        .clone()
+                        .unwrap_or_else(|| UncompressedBuffer::new(0)),
+                )?;
+                array_iter.next().transpose()?.ok_or_else(|| {
+                    ErrorCode::StorageOther(format!(
+                        "unexpected deserialization error, no array found for field {}",
+                        origin.field.name
+                    ))
+                })?
+            }
+            Some(DataItem::ColumnArray(column_array)) => column_array.0.clone(),
+            None => {
+                return Err(ErrorCode::StorageOther("unexpected: column data not found"));
+            }
+        };
+
+        let column_node = &self.project_column_nodes[index];
+        let dest_type = self.project_indices[&column_node.leaf_indices[0]].2.clone();
+        let column = Column::from_arrow(array.as_ref(), &origin.data_type);
+        let block = DataBlock::new_from_columns(vec![column]);
+        let cast_expr = Expr::Cast {
+            span: None,
+            is_try: false,
+            expr: Box::new(Expr::ColumnRef {
+                span: None,
+                id: 0,
+                data_type: origin.data_type.clone(),
+                display_name: origin.field.name.clone(),
+            }),
+            dest_type: dest_type.clone(),
+        };
+        let func_ctx = FunctionContext::default();
+        let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+        let casted = evaluator
+            .run(&cast_expr)?
+            .convert_to_full_column(&dest_type, num_rows);
+        Ok(Some(DeserializedArray::NoNeedToCache(casted.as_arrow())))
+    }
+
     fn to_parquet_compression(meta_compression: &Compression) -> Result<ParquetCompression> {
         match meta_compression {
             Compression::Lz4 => {
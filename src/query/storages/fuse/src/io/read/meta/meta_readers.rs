@@ -24,6 +24,7 @@ use futures_util::AsyncReadExt;
 use futures_util::AsyncSeekExt;
 use opendal::Operator;
 use opendal::Reader;
+use storages_common_cache::CacheKey;
 use storages_common_cache::InMemoryItemCacheReader;
 use storages_common_cache::LoadParams;
 use storages_common_cache::Loader;
@@ -43,6 +44,8 @@ pub type TableSnapshotStatisticsReader =
     InMemoryItemCacheReader<TableSnapshotStatistics, LoaderWrapper<Operator>>;
 pub type BloomIndexMetaReader = InMemoryItemCacheReader<BloomIndexMeta, LoaderWrapper<Operator>>;
 pub type TableSnapshotReader = InMemoryItemCacheReader<TableSnapshot, LoaderWrapper<Operator>>;
+pub type AttachedTableSnapshotReader =
+    InMemoryItemCacheReader<TableSnapshot, LoaderWrapper<(Operator, String)>>;
 pub type SegmentInfoReader =
     InMemoryItemCacheReader<SegmentInfo, LoaderWrapper<(Operator, TableSchemaRef)>>;
 
@@ -63,6 +66,17 @@ impl MetaReaders {
         )
     }
 
+    /// The snapshots of the attached tables are cached by their storage urls, the same
+    /// location in different storages must not share the cached snapshot.
+    pub fn attached_table_snapshot_reader(dal: Operator) -> AttachedTableSnapshotReader {
+        let info = dal.info();
+        let url_prefix = format!("{}://{}{}", info.scheme(), info.name(), info.root());
+        AttachedTableSnapshotReader::new(
+            CacheManager::instance().get_table_snapshot_cache(),
+            LoaderWrapper((dal, url_prefix)),
+        )
+    }
+
     pub fn table_snapshot_statistics_reader(dal: Operator) -> TableSnapshotStatisticsReader {
         TableSnapshotStatisticsReader::new(
             CacheManager::instance().get_table_snapshot_statistics_cache(),
@@ -91,6 +105,21 @@ impl Loader<TableSnapshot> for LoaderWrapper<Operator> {
     }
 }
 
+#[async_trait::async_trait]
+impl Loader<TableSnapshot> for LoaderWrapper<(Operator, String)> {
+    async fn load(&self, params: &LoadParams) -> Result<TableSnapshot> {
+        let (operator, _) = &self.0;
+        let reader = bytes_reader(operator, params.location.as_str(), params.len_hint).await?;
+        let version = SnapshotVersion::try_from(params.ver)?;
+        version.read(reader).await
+    }
+
+    fn cache_key(&self, params: &LoadParams) -> CacheKey {
+        let (_, url_prefix) = &self.0;
+        format!("{}{}", url_prefix, params.location)
+    }
+}
+
 #[async_trait::async_trait]
 impl Loader<TableSnapshotStatistics> for LoaderWrapper<Operator> {
     async fn load(&self, params: &LoadParams) -> Result<TableSnapshotStatistics> {
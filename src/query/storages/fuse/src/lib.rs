@@ -26,6 +26,7 @@ pub mod io;
 pub mod operations;
 pub mod pruning;
 pub mod statistics;
+pub mod stream_table;
 pub mod table_functions;
 
 mod metrics;
@@ -41,7 +42,11 @@ pub use fuse_table::FuseStorageFormat;
 pub use fuse_table::FuseTable;
 pub use io::MergeIOReadResult;
 
+pub use crate::metrics::metrics_inc_table_copy_files_failed;
+pub use crate::metrics::metrics_inc_table_copy_files_loaded;
 pub use crate::metrics::metrics_reset;
+pub use crate::metrics::TableMetricLabels;
+pub use crate::metrics::TableOperation;
 
 mod sessions {
     pub use common_catalog::table_context::TableContext;
@@ -0,0 +1,128 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+
+use metrics::counter;
+use metrics::histogram;
+
+macro_rules! key {
+    ($key: literal) => {
+        concat!("fuse_table_", $key)
+    };
+}
+
+const LABEL_KEY_TENANT: &str = "tenant";
+const LABEL_KEY_TABLE_ID: &str = "table_id";
+const LABEL_KEY_OPERATION: &str = "operation";
+
+/// The table id label of the tables beyond `MAX_LABELED_TABLES`.
+const OTHER_TABLES: &str = "other";
+
+/// The number of distinct tables that get a label of their own, to bound
+/// the cardinality of the exported series.
+pub const MAX_LABELED_TABLES: usize = 1000;
+
+static LABELED_TABLES: Mutex<BTreeSet<(String, u64)>> = Mutex::new(BTreeSet::new());
+
+/// The kind of work a fuse table is read for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableOperation {
+    Scan,
+    Compact,
+    Mutation,
+    BuildIndex,
+}
+
+impl TableOperation {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TableOperation::Scan => "scan",
+            TableOperation::Compact => "compact",
+            TableOperation::Mutation => "mutation",
+            TableOperation::BuildIndex => "build_index",
+        }
+    }
+}
+
+/// Labels of the per-table metrics.
+#[derive(Clone, Debug)]
+pub struct TableMetricLabels {
+    labels: Vec<(&'static str, String)>,
+}
+
+impl TableMetricLabels {
+    pub fn create(tenant: &str, table_id: u64) -> Self {
+        let table_id = {
+            let mut tables = LABELED_TABLES.lock().unwrap_or_else(|e| e.into_inner());
+            let key = (tenant.to_string(), table_id);
+            if tables.contains(&key) || tables.len() < MAX_LABELED_TABLES {
+                tables.insert(key);
+                table_id.to_string()
+            } else {
+                OTHER_TABLES.to_string()
+            }
+        };
+
+        TableMetricLabels {
+            labels: vec![
+                (LABEL_KEY_TENANT, tenant.to_string()),
+                (LABEL_KEY_TABLE_ID, table_id),
+            ],
+        }
+    }
+
+    pub fn with_operation(mut self, operation: TableOperation) -> Self {
+        self.labels
+            .push((LABEL_KEY_OPERATION, operation.as_str().to_string()));
+        self
+    }
+
+    pub fn labels(&self) -> &Vec<(&'static str, String)> {
+        &self.labels
+    }
+}
+
+pub fn metrics_inc_table_block_read_bytes(labels: &TableMetricLabels, c: u64) {
+    counter!(key!("block_read_bytes"), c, labels.labels());
+}
+
+pub fn metrics_observe_table_block_read_milliseconds(labels: &TableMetricLabels, c: u64) {
+    histogram!(key!("block_read_milliseconds"), c as f64, labels.labels());
+}
+
+pub fn metrics_inc_table_segments_scanned(labels: &TableMetricLabels, c: u64) {
+    counter!(key!("segments_scanned"), c, labels.labels());
+}
+
+pub fn metrics_inc_table_segments_pruned(labels: &TableMetricLabels, c: u64) {
+    counter!(key!("segments_pruned"), c, labels.labels());
+}
+
+pub fn metrics_inc_table_commit_retries(labels: &TableMetricLabels) {
+    counter!(key!("commit_retries"), 1, labels.labels());
+}
+
+pub fn metrics_inc_table_compact_bytes_rewritten(labels: &TableMetricLabels, c: u64) {
+    counter!(key!("compact_bytes_rewritten"), c, labels.labels());
+}
+
+pub fn metrics_inc_table_copy_files_loaded(labels: &TableMetricLabels, c: u64) {
+    counter!(key!("copy_files_loaded"), c, labels.labels());
+}
+
+pub fn metrics_inc_table_copy_files_failed(labels: &TableMetricLabels, c: u64) {
+    counter!(key!("copy_files_failed"), c, labels.labels());
+}
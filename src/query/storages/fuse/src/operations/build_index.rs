@@ -0,0 +1,118 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::Projection;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use tracing::info;
+
+use crate::metrics::TableOperation;
+use crate::operations::mutation::BuildIndexPartInfo;
+use crate::operations::mutation::BuildIndexSource;
+use crate::operations::mutation::IndexBuildProgress;
+use crate::operations::mutation::MutationSink;
+use crate::pipelines::Pipeline;
+use crate::pruning::FusePruner;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Builds the bloom filter index of every block of the table, returns `None` if the
+    /// table has no block.
+    ///
+    /// The flow of Pipeline is as follows:
+    /// +-----------------+
+    /// |BuildIndexSource1|  ------
+    /// +-----------------+        |      +-----------------+      +------------+
+    /// |      ...        |  ...   | ---> |MutationTransform| ---> |MutationSink|
+    /// +-----------------+        |      +-----------------+      +------------+
+    /// |BuildIndexSourceN|  ------
+    /// +-----------------+
+    pub async fn do_build_bloom_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        database: &str,
+        pipeline: &mut Pipeline,
+    ) -> Result<Option<Arc<IndexBuildProgress>>> {
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) if snapshot.summary.block_count > 0 => snapshot,
+            _ => return Ok(None),
+        };
+
+        let pruner =
+            FusePruner::create(&ctx, self.operator.clone(), self.table_info.schema(), &None)?;
+        let block_metas = pruner
+            .pruning(snapshot.segments.clone(), None, None)
+            .await?;
+
+        let progress = IndexBuildProgress::register(
+            ctx.get_id(),
+            ctx.get_tenant(),
+            database.to_string(),
+            self.table_info.name.clone(),
+            block_metas.len() as u64,
+        );
+
+        // Status.
+        {
+            let status = format!(
+                "build index: begin to build the index of {} blocks",
+                block_metas.len()
+            );
+            ctx.set_status_info(&status);
+            info!(status);
+        }
+
+        let parts = Partitions::create_nolazy(
+            PartitionsShuffleKind::Mod,
+            block_metas
+                .into_iter()
+                .map(|(index, block)| BuildIndexPartInfo::create(index, block))
+                .collect(),
+        );
+        ctx.set_partitions(parts)?;
+
+        let projection = Projection::Columns(self.all_column_indices());
+        let block_reader =
+            self.create_block_reader(projection, false, ctx.clone(), TableOperation::BuildIndex)?;
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        // Add source pipe.
+        pipeline.add_source(
+            |output| {
+                BuildIndexSource::try_create(
+                    ctx.clone(),
+                    self.operator.clone(),
+                    self.table_info.schema(),
+                    self.meta_location_generator().clone(),
+                    self.storage_format,
+                    block_reader.clone(),
+                    progress.clone(),
+                    output,
+                )
+            },
+            max_threads,
+        )?;
+
+        self.try_add_mutation_transform(ctx.clone(), snapshot.segments.clone(), pipeline)?;
+
+        pipeline.add_sink(|input| {
+            MutationSink::try_create(self, ctx.clone(), snapshot.clone(), input)
+        })?;
+
+        Ok(Some(progress))
+    }
+}
@@ -12,6 +12,7 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::collections::BTreeMap;
 use std::ops::Range;
 use std::sync::Arc;
 use std::time::Duration;
@@ -28,6 +29,7 @@ use common_exception::Result;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -57,6 +59,8 @@ use crate::metrics::metrics_inc_commit_mutation_resolvable_conflict;
 use crate::metrics::metrics_inc_commit_mutation_retry;
 use crate::metrics::metrics_inc_commit_mutation_success;
 use crate::metrics::metrics_inc_commit_mutation_unresolvable_conflict;
+use crate::metrics::metrics_inc_table_commit_retries;
+use crate::metrics::TableMetricLabels;
 use crate::operations::commit::utils::no_side_effects_in_meta_store;
 use crate::operations::mutation::AbortOperation;
 use crate::operations::AppendOperationLogEntry;
@@ -70,6 +74,16 @@ const OCC_DEFAULT_BACKOFF_MAX_DELAY_MS: Duration = Duration::from_millis(20 * 10
 const OCC_DEFAULT_BACKOFF_MAX_ELAPSED_MS: Duration = Duration::from_millis(120 * 1000);
 const MAX_RETRIES: u64 = 10;
 
+/// Moves the offsets of a streaming source in the snapshot of the table, along with
+/// the data read before them.
+#[derive(Clone, Debug)]
+pub struct SourceOffsetsAdvance {
+    pub source: String,
+    /// Where the data read starts, a partition without offset has never been read.
+    pub from: BTreeMap<i32, i64>,
+    pub to: BTreeMap<i32, i64>,
+}
+
 impl FuseTable {
     pub async fn do_commit(
         &self,
@@ -78,10 +92,31 @@ impl FuseTable {
         copied_files: Option<UpsertTableCopiedFileReq>,
         overwrite: bool,
     ) -> Result<()> {
-        self.commit_with_max_retry_elapsed(ctx, operation_log, copied_files, None, overwrite)
+        self.commit_with_max_retry_elapsed(ctx, operation_log, copied_files, None, overwrite, None)
             .await
     }
 
+    /// Commits the data read from a streaming source together with the offsets after it.
+    ///
+    /// Fails with `PipeOffsetsMismatch` if the offsets of the source have moved away from
+    /// where the data starts, i.e. the data has been committed by somebody else.
+    pub async fn do_commit_with_source_offsets(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        operation_log: TableOperationLog,
+        source_offsets: SourceOffsetsAdvance,
+    ) -> Result<()> {
+        self.commit_with_max_retry_elapsed(
+            ctx,
+            operation_log,
+            None,
+            None,
+            false,
+            Some(source_offsets),
+        )
+        .await
+    }
+
     pub async fn commit_with_max_retry_elapsed(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -89,6 +124,7 @@ impl FuseTable {
         copied_files: Option<UpsertTableCopiedFileReq>,
         max_retry_elapsed: Option<Duration>,
         overwrite: bool,
+        source_offsets: Option<SourceOffsetsAdvance>,
     ) -> Result<()> {
         let mut tbl = self;
         let mut latest: Arc<dyn Table>;
@@ -118,14 +154,22 @@ impl FuseTable {
             .build();
 
         let transient = self.transient();
+        // In an explicit transaction, historical data is kept until the transaction commits.
+        let purge_historical_data = transient && !ctx.get_txn_manager().lock().is_active();
         loop {
             match tbl
-                .try_commit(ctx.clone(), &operation_log, &copied_files, overwrite)
+                .try_commit(
+                    ctx.clone(),
+                    &operation_log,
+                    &copied_files,
+                    overwrite,
+                    &source_offsets,
+                )
                 .await
             {
                 Ok(_) => {
                     break {
-                        if transient {
+                        if purge_historical_data {
                             // Removes historical data, if table is transient
                             warn!(
                                 "transient table detected, purging historical data. ({})",
@@ -162,7 +206,24 @@ impl FuseTable {
                             common_base::base::tokio::time::sleep(d).await;
                             latest = tbl.refresh(ctx.as_ref()).await?;
                             tbl = FuseTable::try_from_table(latest.as_ref())?;
+                            // The blocks of an overwrite are written with the schema it started
+                            // with, e.g. the one of a `MODIFY COLUMN` rewrite. They can not be
+                            // committed on top of another schema.
+                            if overwrite
+                                && tbl.table_info.meta.schema != self.table_info.meta.schema
+                            {
+                                let _ = utils::abort_operations(self.get_operator(), operation_log)
+                                    .await;
+                                break Err(ErrorCode::TableSchemaMismatch(format!(
+                                    "schema of table {} has been changed concurrently, aborted",
+                                    tbl.table_info.name
+                                )));
+                            }
                             retry_times += 1;
+                            metrics_inc_table_commit_retries(&TableMetricLabels::create(
+                                &ctx.get_tenant(),
+                                tbl.table_info.ident.table_id,
+                            ));
                             continue;
                         }
                         None => {
@@ -188,6 +249,12 @@ impl FuseTable {
                     }
                 }
 
+                Err(e) if e.code() == ErrorCode::PIPE_OFFSETS_MISMATCH => {
+                    // Nothing is written into the meta store, the data would be loaded twice.
+                    let _ = utils::abort_operations(self.get_operator(), operation_log).await;
+                    break Err(e);
+                }
+
                 Err(e) => {
                     // we are not sure about if the table state has been modified or not, just propagate the error
                     // and return, without aborting anything.
@@ -204,9 +271,11 @@ impl FuseTable {
         operation_log: &'a TableOperationLog,
         copied_files: &Option<UpsertTableCopiedFileReq>,
         overwrite: bool,
+        source_offsets: &Option<SourceOffsetsAdvance>,
     ) -> Result<()> {
         let prev = self.read_table_snapshot().await?;
         let prev_version = self.snapshot_format_version().await?;
+        let new_source_offsets = Self::advance_source_offsets(prev.as_deref(), source_offsets)?;
         let prev_timestamp = prev.as_ref().and_then(|v| v.timestamp);
         let prev_statistics_location = prev
             .as_ref()
@@ -225,7 +294,7 @@ impl FuseTable {
             .map(|loc| (loc, SegmentInfo::VERSION))
             .collect();
 
-        let new_snapshot = if overwrite {
+        let mut new_snapshot = if overwrite {
             TableSnapshot::new(
                 Uuid::new_v4(),
                 &prev_timestamp,
@@ -247,6 +316,7 @@ impl FuseTable {
                 self.cluster_key_meta.clone(),
             )?
         };
+        new_snapshot.source_offsets = new_source_offsets;
 
         let mut new_table_meta = self.get_table_info().meta.clone();
         // update statistics
@@ -269,6 +339,36 @@ impl FuseTable {
         .await
     }
 
+    // The offsets of the sources after the commit, the ones of the previous snapshot
+    // with those of `advance` moved.
+    fn advance_source_offsets(
+        previous: Option<&TableSnapshot>,
+        advance: &Option<SourceOffsetsAdvance>,
+    ) -> Result<BTreeMap<String, BTreeMap<i32, i64>>> {
+        let mut source_offsets = previous
+            .map(|v| v.source_offsets.clone())
+            .unwrap_or_default();
+        let Some(advance) = advance else {
+            return Ok(source_offsets);
+        };
+
+        let offsets = source_offsets.entry(advance.source.clone()).or_default();
+        for (partition, to) in &advance.to {
+            let from = advance.from.get(partition);
+            if offsets.get(partition) != from {
+                return Err(ErrorCode::PipeOffsetsMismatch(format!(
+                    "offset of partition {} of source {} is {:?} instead of {:?}, the data has been loaded already",
+                    partition,
+                    advance.source,
+                    offsets.get(partition),
+                    from
+                )));
+            }
+            offsets.insert(*partition, *to);
+        }
+        Ok(source_offsets)
+    }
+
     fn merge_table_operations(
         schema: &TableSchema,
         ctx: Arc<dyn TableContext>,
@@ -298,7 +398,9 @@ impl FuseTable {
                 leaf_default_values
                     .iter()
                     .for_each(|(col_id, default_value)| {
-                        if !summary.col_stats.contains_key(col_id) {
+                        if !summary.col_stats.contains_key(col_id)
+                            && schema.origins_of_column(*col_id).is_empty()
+                        {
                             let (null_count, distinct_of_values) = if default_value.is_null() {
                                 (summary.row_count, Some(0))
                             } else {
@@ -316,7 +418,17 @@ impl FuseTable {
                     });
             }
 
-            merge_statistics(&statistics, &summary)?
+            // Columns whose type has been changed in place are still stored under the
+            // ids of their origins in the previous blocks, so their summary would only
+            // cover the new blocks. Leave them out rather than record misleading stats.
+            let mut merged = merge_statistics(&statistics, &summary)?;
+            for field in schema.fields() {
+                if !field.origins().is_empty() && !summary.col_stats.contains_key(&field.column_id)
+                {
+                    merged.col_stats.remove(&field.column_id);
+                }
+            }
+            merged
         } else {
             statistics
         };
@@ -392,7 +504,18 @@ impl FuseTable {
             index_data_bytes: stats.index_size,
         };
 
-        // 3. prepare the request
+        // 3. in an explicit transaction, the new meta is buffered until the transaction commits
+        if Self::buffer_in_txn(ctx, table_info, &new_table_meta)? {
+            if copied_files.is_some() {
+                return Err(ErrorCode::TransactionNotSupported(
+                    "copying files is not supported in a transaction",
+                ));
+            }
+            TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+            return Ok(());
+        }
+
+        // 4. prepare the request
         let catalog = ctx.get_catalog(&table_info.meta.catalog)?;
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
@@ -404,7 +527,7 @@ impl FuseTable {
             copied_files: copied_files.clone(),
         };
 
-        // 3. let's roll
+        // 5. let's roll
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
@@ -443,6 +566,22 @@ impl FuseTable {
         }
     }
 
+    /// Buffers the new table meta in the explicit transaction of the session, returns false
+    /// if there is no transaction in progress.
+    pub fn buffer_in_txn(
+        ctx: &dyn TableContext,
+        table_info: &TableInfo,
+        new_table_meta: &TableMeta,
+    ) -> Result<bool> {
+        let txn_mgr = ctx.get_txn_manager();
+        let mut txn_mgr = txn_mgr.lock();
+        if !txn_mgr.is_active() {
+            return Ok(false);
+        }
+        txn_mgr.update_table_meta(table_info, new_table_meta.clone())?;
+        Ok(true)
+    }
+
     pub fn merge_append_operations(
         append_log_entries: &[AppendOperationLogEntry],
     ) -> Result<(Vec<String>, Statistics)> {
@@ -605,6 +744,10 @@ impl FuseTable {
 
                     retries += 1;
                     metrics_inc_commit_mutation_retry();
+                    metrics_inc_table_commit_retries(&TableMetricLabels::create(
+                        &ctx.get_tenant(),
+                        self.table_info.ident.table_id,
+                    ));
                 }
                 Err(e) => {
                     // we are not sure about if the table state has been modified or not, just propagate the error
@@ -44,6 +44,7 @@ use common_sql::evaluator::BlockOperator;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::TableSnapshot;
 
+use crate::metrics::TableOperation;
 use crate::operations::mutation::MutationAction;
 use crate::operations::mutation::MutationPartInfo;
 use crate::operations::mutation::MutationSink;
@@ -202,7 +203,8 @@ impl FuseTable {
         )
         .await?;
 
-        let block_reader = self.create_block_reader(projection, false, ctx.clone())?;
+        let block_reader =
+            self.create_block_reader(projection, false, ctx.clone(), TableOperation::Mutation)?;
         let schema = block_reader.schema();
         let filter = Arc::new(Some(
             filter
@@ -225,6 +227,7 @@ impl FuseTable {
                     Projection::Columns(remain_column_indices),
                     false,
                     ctx.clone(),
+                    TableOperation::Mutation,
                 )?)
                 .clone(),
             ))
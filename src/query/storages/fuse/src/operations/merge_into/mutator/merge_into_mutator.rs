@@ -15,10 +15,12 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::hash::Hasher;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_catalog::plan::Projection;
+use common_catalog::table::ReplaceIntoStatistics;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -41,6 +43,8 @@ use crate::io::MetaReaders;
 use crate::io::ReadSettings;
 use crate::io::SegmentInfoReader;
 use crate::io::WriteSettings;
+use crate::metrics::TableMetricLabels;
+use crate::metrics::TableOperation;
 use crate::operations::merge_into::mutation_meta::merge_into_operation_meta::DeletionByColumn;
 use crate::operations::merge_into::mutation_meta::merge_into_operation_meta::MergeIntoOperation;
 use crate::operations::merge_into::mutation_meta::merge_into_operation_meta::UniqueKeyDigest;
@@ -65,6 +69,7 @@ pub struct MergeIntoOperationAggregator {
     read_settings: ReadSettings,
     segment_reader: SegmentInfoReader,
     block_builder: BlockBuilder,
+    statistics: Arc<ReplaceIntoStatistics>,
 }
 
 impl MergeIntoOperationAggregator {
@@ -78,6 +83,8 @@ impl MergeIntoOperationAggregator {
         write_settings: WriteSettings,
         read_settings: ReadSettings,
         block_builder: BlockBuilder,
+        statistics: Arc<ReplaceIntoStatistics>,
+        metric_labels: TableMetricLabels,
     ) -> Result<Self> {
         let deletion_accumulator = DeletionAccumulator::default();
         let segment_reader =
@@ -90,6 +97,7 @@ impl MergeIntoOperationAggregator {
             projection,
             ctx.clone(),
             false,
+            Some(metric_labels.with_operation(TableOperation::Mutation)),
         )?;
 
         Ok(Self {
@@ -102,6 +110,7 @@ impl MergeIntoOperationAggregator {
             read_settings,
             segment_reader,
             block_builder,
+            statistics,
         })
     }
 }
@@ -241,6 +250,10 @@ impl MergeIntoOperationAggregator {
             bitmap.push(!deleted_key_hashes.contains(&hash));
         }
 
+        self.statistics
+            .replaced_rows
+            .fetch_add(bitmap.unset_bits() as u64, Ordering::Relaxed);
+
         // shortcuts
         if bitmap.unset_bits() == 0 {
             debug!("nothing deleted");
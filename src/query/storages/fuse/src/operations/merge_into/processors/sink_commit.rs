@@ -32,6 +32,8 @@ use crate::metrics::metrics_inc_commit_mutation_resolvable_conflict;
 use crate::metrics::metrics_inc_commit_mutation_retry;
 use crate::metrics::metrics_inc_commit_mutation_success;
 use crate::metrics::metrics_inc_commit_mutation_unresolvable_conflict;
+use crate::metrics::metrics_inc_table_commit_retries;
+use crate::metrics::TableMetricLabels;
 use crate::operations::commit::Conflict;
 use crate::operations::commit::MutatorConflictDetector;
 use crate::operations::merge_into::mutation_meta::mutation_log::CommitMeta;
@@ -180,6 +182,10 @@ impl Processor for CommitSink {
 
                         self.retries += 1;
                         metrics_inc_commit_mutation_retry();
+                        metrics_inc_table_commit_retries(&TableMetricLabels::create(
+                            &self.ctx.get_tenant(),
+                            self.table.get_id(),
+                        ));
 
                         self.state = State::MergeSegments(
                             latest_snapshot.segments[range_of_newly_append].to_owned(),
@@ -14,6 +14,7 @@
 
 mod analyze;
 mod append;
+mod build_index;
 mod commit;
 mod compact;
 mod delete;
@@ -28,6 +29,7 @@ mod read_partitions;
 mod recluster;
 mod replace;
 mod replace_into;
+mod snapshot_export;
 mod truncate;
 mod update;
 
@@ -36,16 +38,21 @@ mod read;
 mod revert;
 pub mod util;
 
+pub use commit::SourceOffsetsAdvance;
 pub use compact::CompactOptions;
 pub use fuse_sink::BloomIndexState;
 pub use fuse_sink::FuseTableSink;
+pub use mutation::list_index_builds;
 pub use mutation::BlockCompactMutator;
 pub use mutation::CompactPartInfo;
 pub use mutation::FillInternalColumnProcessor;
+pub use mutation::IndexBuildProgress;
 pub use mutation::ReclusterMutator;
 pub use mutation::SegmentCompactMutator;
 pub use mutation::SegmentCompactionState;
 pub use mutation::SegmentCompactor;
 pub use operation_log::AppendOperationLogEntry;
 pub use operation_log::TableOperationLog;
+pub use snapshot_export::SnapshotManifest;
+pub use snapshot_export::SnapshotManifestFile;
 pub use util::column_parquet_metas;
@@ -44,6 +44,12 @@ impl AbortOperation {
         }
     }
 
+    pub fn add_bloom_filter_index(&mut self, block: &BlockMeta) {
+        if let Some(index) = block.bloom_filter_index_location.clone() {
+            self.bloom_filter_indexes.push(index.0);
+        }
+    }
+
     pub fn add_segment(&mut self, segment: String) {
         self.segments.push(segment);
     }
@@ -0,0 +1,157 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use opendal::Operator;
+use uuid::Uuid;
+
+use crate::io::write_data;
+use crate::io::BlockReader;
+use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::mutation::build_index::BuildIndexPartInfo;
+use crate::operations::mutation::build_index::IndexBuildProgress;
+use crate::operations::mutation::Mutation;
+use crate::operations::mutation::MutationTransformMeta;
+use crate::operations::BloomIndexState;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::Processor;
+use crate::FuseStorageFormat;
+
+/// Reads the blocks of the partitions one by one, and writes the bloom filter index of each.
+pub struct BuildIndexSource {
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    schema: TableSchemaRef,
+    meta_locations: TableMetaLocationGenerator,
+    storage_format: FuseStorageFormat,
+    block_reader: Arc<BlockReader>,
+    progress: Arc<IndexBuildProgress>,
+
+    output: Arc<OutputPort>,
+    output_data: Option<DataBlock>,
+    finished: bool,
+}
+
+impl BuildIndexSource {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        dal: Operator,
+        schema: TableSchemaRef,
+        meta_locations: TableMetaLocationGenerator,
+        storage_format: FuseStorageFormat,
+        block_reader: Arc<BlockReader>,
+        progress: Arc<IndexBuildProgress>,
+        output: Arc<OutputPort>,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Box::new(BuildIndexSource {
+            ctx,
+            dal,
+            schema,
+            meta_locations,
+            storage_format,
+            block_reader,
+            progress,
+            output,
+            output_data: None,
+            finished: false,
+        })))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for BuildIndexSource {
+    fn name(&self) -> String {
+        "BuildIndexSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.finished {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_data.take() {
+            self.output.push_data(Ok(block));
+        }
+
+        Ok(Event::Async)
+    }
+
+    async fn async_process(&mut self) -> Result<()> {
+        match self.ctx.get_partition() {
+            Some(part) => {
+                let part = BuildIndexPartInfo::from_part(&part)?;
+                let settings = ReadSettings::from_ctx(&self.ctx)?;
+                let data_block = self
+                    .block_reader
+                    .read_by_meta(&settings, part.block.as_ref(), &self.storage_format)
+                    .await?;
+
+                // The index is written to a new location, the one replaced is left to purge.
+                let location = self
+                    .meta_locations
+                    .block_bloom_index_location(&Uuid::new_v4());
+                let bloom_index_state = BloomIndexState::try_create(
+                    self.ctx.clone(),
+                    self.schema.clone(),
+                    &data_block,
+                    location,
+                )?;
+
+                let mutation = match bloom_index_state {
+                    Some(state) => {
+                        write_data(state.data, &self.dal, &state.location.0).await?;
+                        let mut block_meta = part.block.as_ref().clone();
+                        block_meta.bloom_filter_index_location = Some(state.location);
+                        block_meta.bloom_filter_index_size = state.size;
+                        Mutation::Reindexed(Arc::new(block_meta))
+                    }
+                    // None of the columns supports the bloom filter.
+                    None => Mutation::DoNothing,
+                };
+                self.progress.incr_built_blocks(1);
+
+                self.output_data = Some(DataBlock::empty_with_meta(MutationTransformMeta::create(
+                    part.index.clone(),
+                    mutation,
+                )));
+            }
+            None => self.finished = true,
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+
+/// The number of index builds kept for `SHOW INDEX BUILD STATUS`, the oldest
+/// finished ones are dropped first.
+const MAX_INDEX_BUILDS: usize = 100;
+
+static INDEX_BUILDS: Mutex<VecDeque<Arc<IndexBuildProgress>>> = Mutex::new(VecDeque::new());
+
+struct IndexBuildOutcome {
+    finished_on: DateTime<Utc>,
+    error: Option<String>,
+}
+
+/// The progress of building the index of a table, shared by the sources of the build pipeline.
+pub struct IndexBuildProgress {
+    pub query_id: String,
+    pub tenant: String,
+    pub database: String,
+    pub table: String,
+    pub total_blocks: u64,
+    pub started_on: DateTime<Utc>,
+    built_blocks: AtomicU64,
+    outcome: Mutex<Option<IndexBuildOutcome>>,
+}
+
+impl IndexBuildProgress {
+    pub fn register(
+        query_id: String,
+        tenant: String,
+        database: String,
+        table: String,
+        total_blocks: u64,
+    ) -> Arc<IndexBuildProgress> {
+        let progress = Arc::new(IndexBuildProgress {
+            query_id,
+            tenant,
+            database,
+            table,
+            total_blocks,
+            started_on: Utc::now(),
+            built_blocks: AtomicU64::new(0),
+            outcome: Mutex::new(None),
+        });
+
+        let mut builds = INDEX_BUILDS.lock().unwrap_or_else(|e| e.into_inner());
+        if builds.len() >= MAX_INDEX_BUILDS {
+            if let Some(pos) = builds.iter().position(|b| b.is_finished()) {
+                builds.remove(pos);
+            }
+        }
+        builds.push_back(progress.clone());
+        progress
+    }
+
+    pub fn incr_built_blocks(&self, c: u64) {
+        self.built_blocks.fetch_add(c, Ordering::Relaxed);
+    }
+
+    pub fn built_blocks(&self) -> u64 {
+        self.built_blocks.load(Ordering::Relaxed)
+    }
+
+    pub fn finish(&self, error: Option<&ErrorCode>) {
+        let mut outcome = self.outcome.lock().unwrap_or_else(|e| e.into_inner());
+        *outcome = Some(IndexBuildOutcome {
+            finished_on: Utc::now(),
+            error: error.map(|e| e.message()),
+        });
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.outcome
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_some()
+    }
+
+    /// `RUNNING`, `SUCCEEDED` or `FAILED`.
+    pub fn status(&self) -> &'static str {
+        match self
+            .outcome
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+        {
+            None => "RUNNING",
+            Some(IndexBuildOutcome { error: None, .. }) => "SUCCEEDED",
+            Some(IndexBuildOutcome { error: Some(_), .. }) => "FAILED",
+        }
+    }
+
+    pub fn finished_on(&self) -> Option<DateTime<Utc>> {
+        self.outcome
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .map(|o| o.finished_on)
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.outcome
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .and_then(|o| o.error.clone())
+    }
+}
+
+/// The index builds of the tenant run by this node, oldest first.
+pub fn list_index_builds(tenant: &str) -> Vec<Arc<IndexBuildProgress>> {
+    INDEX_BUILDS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .filter(|b| b.tenant == tenant)
+        .cloned()
+        .collect()
+}
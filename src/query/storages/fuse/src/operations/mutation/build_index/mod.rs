@@ -0,0 +1,22 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod build_index_part;
+mod build_index_source;
+mod build_index_status;
+
+pub use build_index_part::BuildIndexPartInfo;
+pub use build_index_source::BuildIndexSource;
+pub use build_index_status::list_index_builds;
+pub use build_index_status::IndexBuildProgress;
@@ -169,6 +169,12 @@ impl Processor for CompactSource {
                 {
                     metrics_inc_compact_block_write_nums(1);
                     metrics_inc_compact_block_write_bytes(serialized.block_raw_data.len() as u64);
+                    if let Some(labels) = &self.block_reader.metric_labels {
+                        metrics_inc_table_compact_bytes_rewritten(
+                            labels,
+                            serialized.block_raw_data.len() as u64,
+                        );
+                    }
                 }
 
                 // write block data.
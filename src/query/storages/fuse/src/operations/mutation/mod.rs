@@ -14,6 +14,7 @@
 
 pub mod abort_operation;
 pub mod base_mutator;
+mod build_index;
 mod compact;
 pub mod mutation_fill_internal_columns;
 pub mod mutation_meta;
@@ -26,6 +27,10 @@ mod serialize_data_transform;
 
 pub use abort_operation::AbortOperation;
 pub use base_mutator::BaseMutator;
+pub use build_index::list_index_builds;
+pub use build_index::BuildIndexPartInfo;
+pub use build_index::BuildIndexSource;
+pub use build_index::IndexBuildProgress;
 pub use compact::BlockCompactMutator;
 pub use compact::CompactAggregator;
 pub use compact::CompactPartInfo;
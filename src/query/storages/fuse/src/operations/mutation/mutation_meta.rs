@@ -78,6 +78,8 @@ pub enum Mutation {
     DoNothing,
     Replaced(Arc<BlockMeta>),
     Deleted,
+    /// The same block, with a newly built index.
+    Reindexed(Arc<BlockMeta>),
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
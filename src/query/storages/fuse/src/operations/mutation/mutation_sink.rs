@@ -33,6 +33,8 @@ use crate::metrics::metrics_inc_commit_mutation_resolvable_conflict;
 use crate::metrics::metrics_inc_commit_mutation_retry;
 use crate::metrics::metrics_inc_commit_mutation_success;
 use crate::metrics::metrics_inc_commit_mutation_unresolvable_conflict;
+use crate::metrics::metrics_inc_table_commit_retries;
+use crate::metrics::TableMetricLabels;
 use crate::operations::commit::Conflict;
 use crate::operations::commit::MutatorConflictDetector;
 use crate::operations::mutation::AbortOperation;
@@ -188,6 +190,10 @@ impl Processor for MutationSink {
 
                         self.retries += 1;
                         metrics_inc_commit_mutation_retry();
+                        metrics_inc_table_commit_retries(&TableMetricLabels::create(
+                            &self.ctx.get_tenant(),
+                            self.table.get_id(),
+                        ));
 
                         self.state = State::MergeSegments(
                             latest_snapshot.segments[range_of_newly_append].to_owned(),
@@ -244,6 +244,14 @@ impl Processor for MutationTransform {
                             .or_insert((vec![(meta.index.block_idx, block_meta.clone())], vec![]));
                         self.abort_operation.add_block(block_meta);
                     }
+                    Mutation::Reindexed(block_meta) => {
+                        self.input_metas
+                            .entry(meta.index.segment_idx)
+                            .and_modify(|v| v.0.push((meta.index.block_idx, block_meta.clone())))
+                            .or_insert((vec![(meta.index.block_idx, block_meta.clone())], vec![]));
+                        // the block file is still referenced by the base snapshot.
+                        self.abort_operation.add_bloom_filter_index(block_meta);
+                    }
                     Mutation::Deleted => {
                         self.input_metas
                             .entry(meta.index.segment_idx)
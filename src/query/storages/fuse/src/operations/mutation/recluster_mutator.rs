@@ -45,9 +45,11 @@ pub struct ReclusterMutator {
     selected_blocks: Vec<Arc<BlockMeta>>,
     level: i32,
     threshold: f64,
+    block_limit: usize,
 }
 
 impl ReclusterMutator {
+    #[allow(clippy::too_many_arguments)]
     pub fn try_create(
         ctx: Arc<dyn TableContext>,
         location_generator: TableMetaLocationGenerator,
@@ -56,6 +58,7 @@ impl ReclusterMutator {
         thresholds: BlockThresholds,
         blocks_map: BTreeMap<i32, Vec<(usize, Arc<BlockMeta>)>>,
         data_accessor: Operator,
+        block_limit: Option<usize>,
     ) -> Result<Self> {
         let base_mutator = BaseMutator::try_create(
             ctx,
@@ -70,6 +73,7 @@ impl ReclusterMutator {
             selected_blocks: Vec::new(),
             level: 0,
             threshold,
+            block_limit: block_limit.unwrap_or(MAX_BLOCK_COUNT),
         })
     }
 
@@ -121,6 +125,7 @@ impl TableMutator for ReclusterMutator {
             {
                 self.selected_blocks = block_metas
                     .into_iter()
+                    .take(self.block_limit)
                     .map(|(seg_idx, block_meta)| {
                         self.base_mutator
                             .add_mutation(seg_idx, block_meta.location.clone(), None);
@@ -190,7 +195,7 @@ impl TableMutator for ReclusterMutator {
 
             self.selected_blocks = selected_idx
                 .iter()
-                .take(MAX_BLOCK_COUNT)
+                .take(self.block_limit)
                 .map(|idx| {
                     let (seg_idx, block_meta) = block_metas[*idx].clone();
                     self.base_mutator
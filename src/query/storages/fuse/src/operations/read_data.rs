@@ -28,6 +28,8 @@ use storages_common_index::RangeIndex;
 
 use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::io::BlockReader;
+use crate::metrics::TableMetricLabels;
+use crate::metrics::TableOperation;
 use crate::operations::fuse_source::build_fuse_source_pipeline;
 use crate::FuseTable;
 
@@ -37,14 +39,19 @@ impl FuseTable {
         projection: Projection,
         query_internal_columns: bool,
         ctx: Arc<dyn TableContext>,
+        operation: TableOperation,
     ) -> Result<Arc<BlockReader>> {
         let table_schema = self.table_info.schema();
+        let metric_labels =
+            TableMetricLabels::create(&ctx.get_tenant(), self.table_info.ident.table_id)
+                .with_operation(operation);
         BlockReader::create(
             self.operator.clone(),
             table_schema,
             projection,
             ctx,
             query_internal_columns,
+            Some(metric_labels),
         )
     }
 
@@ -58,6 +65,7 @@ impl FuseTable {
             PushDownInfo::projection_of_push_downs(&self.table_info.schema(), &plan.push_downs),
             plan.query_internal_columns,
             ctx,
+            TableOperation::Scan,
         )
     }
 
@@ -23,6 +23,7 @@ use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PruningStatistics;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::SampleMethod;
 use common_catalog::plan::TopK;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
@@ -37,6 +38,7 @@ use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CachedObject;
 use storages_common_index::Index;
 use storages_common_index::RangeIndex;
+use storages_common_index::ZOrderIndex;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::Location;
@@ -45,6 +47,9 @@ use tracing::info;
 
 use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::fuse_part::FusePartInfo;
+use crate::metrics::metrics_inc_table_segments_pruned;
+use crate::metrics::metrics_inc_table_segments_scanned;
+use crate::metrics::TableMetricLabels;
 use crate::pruning::FusePruner;
 use crate::FuseTable;
 
@@ -132,11 +137,14 @@ impl FuseTable {
             }
         }
 
-        let pruner = if !self.is_native() || self.cluster_key_meta.is_none() {
+        let cluster_keys = self.cluster_keys(ctx.clone());
+        // The pages are only kept by the native format, while the blocks clustered by
+        // Z-order are pruned by the range of their keys in any format.
+        let prune_pages = self.cluster_key_meta.is_some()
+            && (self.is_native() || ZOrderIndex::zorder_columns(&cluster_keys).is_some());
+        let pruner = if !prune_pages {
             FusePruner::create(&ctx, dal, table_info.schema(), &push_downs)?
         } else {
-            let cluster_keys = self.cluster_keys(ctx.clone());
-
             FusePruner::create_with_pages(
                 &ctx,
                 dal,
@@ -152,14 +160,35 @@ impl FuseTable {
             .await?;
         let pruning_stats = pruner.pruning_stats();
 
+        // Per-table segment pruning metrics.
+        {
+            let labels = TableMetricLabels::create(&ctx.get_tenant(), table_info.ident.table_id);
+            let segments_scanned = pruning_stats.segments_range_pruning_after as u64;
+            let segments_total = pruning_stats.segments_range_pruning_before as u64;
+            metrics_inc_table_segments_scanned(&labels, segments_scanned);
+            metrics_inc_table_segments_pruned(
+                &labels,
+                segments_total.saturating_sub(segments_scanned),
+            );
+        }
+
         info!(
             "prune snapshot block end, final block numbers:{}, cost:{}",
             block_metas.len(),
             start.elapsed().as_secs()
         );
 
+        // `TABLESAMPLE SYSTEM` keeps a part of the blocks left after pruning.
+        let system_sample = push_downs
+            .as_ref()
+            .and_then(|extras| extras.sample)
+            .filter(|sample| sample.method == SampleMethod::System);
+
         let block_metas = block_metas
             .into_iter()
+            .filter(|(_, block_meta)| {
+                system_sample.map_or(true, |sample| sample.keep_block(&block_meta.location.0))
+            })
             .map(|(block_meta_index, block_meta)| (Some(block_meta_index), block_meta))
             .collect::<Vec<_>>();
 
@@ -249,6 +278,7 @@ impl FuseTable {
             Some(extras) => match &extras.projection {
                 None => Self::all_columns_partitions(schema, &block_metas, top_k.clone(), limit),
                 Some(projection) => Self::projection_partitions(
+                    schema,
                     &block_metas,
                     column_nodes,
                     projection,
@@ -269,7 +299,10 @@ impl FuseTable {
     fn is_exact(push_downs: &Option<PushDownInfo>) -> bool {
         match push_downs {
             None => true,
-            Some(extra) => extra.filter.is_none(),
+            Some(extra) => {
+                extra.filter.is_none()
+                    && !matches!(&extra.sample, Some(sample) if sample.method == SampleMethod::Bernoulli)
+            }
         }
     }
 
@@ -313,6 +346,7 @@ impl FuseTable {
     }
 
     fn projection_partitions(
+        schema: Option<&TableSchemaRef>,
         block_metas: &[(Option<BlockMetaIndex>, Arc<BlockMeta>)],
         column_nodes: &ColumnNodes,
         projection: &Projection,
@@ -331,6 +365,7 @@ impl FuseTable {
 
         for (block_meta_index, block_meta) in block_metas {
             partitions.partitions.push(Self::projection_part(
+                schema,
                 block_meta,
                 block_meta_index,
                 column_nodes,
@@ -407,6 +442,7 @@ impl FuseTable {
     }
 
     fn projection_part(
+        schema: Option<&TableSchemaRef>,
         meta: &BlockMeta,
         block_meta_index: &Option<BlockMetaIndex>,
         column_nodes: &ColumnNodes,
@@ -421,6 +457,16 @@ impl FuseTable {
                 // ignore column this block dose not exist
                 if let Some(column_meta) = meta.col_metas.get(column_id) {
                     columns_meta.insert(*column_id, column_meta.clone());
+                    continue;
+                }
+                // blocks written before the type of the column was changed
+                if let Some(schema) = schema {
+                    for (origin_id, _) in schema.origins_of_column(*column_id) {
+                        if let Some(column_meta) = meta.col_metas.get(origin_id) {
+                            columns_meta.insert(*origin_id, column_meta.clone());
+                            break;
+                        }
+                    }
                 }
             }
         }
@@ -43,6 +43,19 @@ use crate::DEFAULT_AVG_DEPTH_THRESHOLD;
 use crate::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 
 impl FuseTable {
+    /// The depth above which the blocks are considered to need reclustering.
+    pub fn get_depth_threshold(&self, block_count: u64) -> f64 {
+        let avg_depth_threshold = self.get_option(
+            FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD,
+            DEFAULT_AVG_DEPTH_THRESHOLD,
+        );
+        if block_count > 100 {
+            block_count as f64 * avg_depth_threshold
+        } else {
+            1.0
+        }
+    }
+
     pub(crate) async fn do_recluster(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -61,6 +74,14 @@ impl FuseTable {
             return Ok(None);
         };
 
+        // The limit of recluster caps the number of blocks per round, it must not be
+        // used to prune the blocks by rows.
+        let block_limit = push_downs.as_ref().and_then(|extras| extras.limit);
+        let push_downs = push_downs.map(|extras| PushDownInfo {
+            limit: None,
+            ..extras
+        });
+
         let schema = self.table_info.schema();
         let segment_locations = snapshot.segments.clone();
         let pruner = FusePruner::create(&ctx, self.operator.clone(), schema, &push_downs)?;
@@ -80,16 +101,7 @@ impl FuseTable {
         });
 
         let block_compact_thresholds = self.get_block_compact_thresholds();
-        let avg_depth_threshold = self.get_option(
-            FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD,
-            DEFAULT_AVG_DEPTH_THRESHOLD,
-        );
-        let block_count = snapshot.summary.block_count;
-        let threshold = if block_count > 100 {
-            block_count as f64 * avg_depth_threshold
-        } else {
-            1.0
-        };
+        let threshold = self.get_depth_threshold(snapshot.summary.block_count);
 
         let mut mutator = ReclusterMutator::try_create(
             ctx.clone(),
@@ -99,6 +111,7 @@ impl FuseTable {
             block_compact_thresholds,
             blocks_map,
             self.operator.clone(),
+            block_limit,
         )?;
 
         let need_recluster = mutator.target_select().await?;
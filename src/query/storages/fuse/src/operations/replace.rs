@@ -15,6 +15,7 @@
 use std::default::Default;
 use std::sync::Arc;
 
+use common_catalog::table::ReplaceIntoStatistics;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
@@ -32,6 +33,7 @@ use uuid::Uuid;
 
 use crate::io::BlockBuilder;
 use crate::io::ReadSettings;
+use crate::metrics::TableMetricLabels;
 use crate::operations::merge_into::AppendTransform;
 use crate::operations::merge_into::BroadcastProcessor;
 use crate::operations::merge_into::CommitSink;
@@ -102,6 +104,7 @@ impl FuseTable {
         ctx: Arc<dyn TableContext>,
         on_conflict_field_identifiers: Vec<TableField>,
         pipeline: &'a mut Pipeline,
+        statistics: Arc<ReplaceIntoStatistics>,
     ) -> Result<()> {
         let schema = self.table_info.schema();
 
@@ -122,7 +125,8 @@ impl FuseTable {
             })
         }
 
-        // 1. resize input to 1, since the UpsertTransform need to de-duplicate inputs "globally"
+        // 1. resize input to 1, since the UpsertTransform need to de-duplicate inputs "globally",
+        //    rows of duplicated keys are resolved in the order they are received (last wins)
         pipeline.resize(1)?;
 
         // 2. connect with ReplaceIntoProcessor
@@ -147,7 +151,7 @@ impl FuseTable {
 
         let empty_table = base_snapshot.segments.is_empty();
         let replace_into_processor =
-            ReplaceIntoProcessor::create(on_conflicts.clone(), empty_table);
+            ReplaceIntoProcessor::create(on_conflicts.clone(), empty_table, statistics.clone());
         pipeline.add_pipe(replace_into_processor.into_pipe());
 
         // 3. connect to broadcast processor and append transform
@@ -228,6 +232,7 @@ impl FuseTable {
                     block_builder,
                     on_conflicts.clone(),
                     &base_snapshot,
+                    statistics,
                 )
                 .await?;
             assert_eq!(
@@ -260,9 +265,11 @@ impl FuseTable {
         block_builder: BlockBuilder,
         on_conflicts: Vec<OnConflictField>,
         table_snapshot: &TableSnapshot,
+        statistics: Arc<ReplaceIntoStatistics>,
     ) -> Result<Vec<PipeItem>> {
         let chunks = Self::partition_segments(&table_snapshot.segments, num_partition);
         let read_settings = ReadSettings::from_ctx(&ctx)?;
+        let metric_labels = TableMetricLabels::create(&ctx.get_tenant(), self.get_id());
         let mut items = vec![];
         for chunk_of_segment_locations in chunks {
             let item = MergeIntoOperationAggregator::try_create(
@@ -274,6 +281,8 @@ impl FuseTable {
                 self.get_write_settings(),
                 read_settings.clone(),
                 block_builder.clone(),
+                statistics.clone(),
+                metric_labels.clone(),
             )?;
             items.push(item.into_pipe_item());
         }
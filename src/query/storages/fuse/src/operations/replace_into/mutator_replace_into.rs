@@ -34,64 +34,60 @@ use crate::operations::merge_into::OnConflictField;
 // - update for "not-matched" branch (by sending MergeIntoOperation to downstream)
 pub struct ReplaceIntoMutator {
     on_conflict_fields: Vec<OnConflictField>,
-    key_saw: HashSet<UniqueKeyDigest>,
 }
 
 impl ReplaceIntoMutator {
     pub fn create(on_conflict_fields: Vec<OnConflictField>) -> Self {
-        Self {
-            on_conflict_fields,
-            key_saw: Default::default(),
-        }
+        Self { on_conflict_fields }
     }
 }
 
-enum ColumnHash {
-    NoConflict(HashSet<UniqueKeyDigest>),
-    Conflict,
-}
-
 impl ReplaceIntoMutator {
-    pub fn process_input_block(&mut self, data_block: &DataBlock) -> Result<MergeIntoOperation> {
+    // Returns the rows to be inserted, and the operation deleting the rows of the table
+    // conflicting with them. If several rows share the same conflict keys, the last one wins.
+    pub fn process_input_block(
+        &self,
+        data_block: &DataBlock,
+    ) -> Result<(DataBlock, MergeIntoOperation)> {
         // TODO table level pruning:
         // if we can deduced that `data_block` is insert only, return an MergeIntoOperation::None (None op for Matched Branch)
-        self.extract_on_conflict_columns(data_block)
+        let num_rows = data_block.num_rows();
+        let (rows, key_hashes) =
+            Self::build_column_hash(&self.on_conflict_columns(data_block), num_rows);
+        let data_block = if rows.len() == num_rows {
+            data_block.clone()
+        } else {
+            data_block.take(&rows)?
+        };
+
+        let columns_min_max =
+            Self::columns_min_max(&self.on_conflict_columns(&data_block), rows.len())?;
+        let delete_action = DeletionByColumn {
+            columns_min_max,
+            key_hashes,
+        };
+        Ok((data_block, MergeIntoOperation::Delete(delete_action)))
     }
 
-    fn extract_on_conflict_columns(
-        &mut self,
-        data_block: &DataBlock,
-    ) -> Result<MergeIntoOperation> {
-        let num_rows = data_block.num_rows();
-        let mut columns = Vec::with_capacity(self.on_conflict_fields.len());
-        for field in &self.on_conflict_fields {
-            let filed_index = field.field_index;
-            let entry = &data_block.columns()[filed_index];
-            let column = entry.value.as_column().unwrap();
-            columns.push(column);
-        }
-        match Self::build_column_hash(&columns, &mut self.key_saw, num_rows)? {
-            ColumnHash::NoConflict(key_hashes) => {
-                let columns_min_max = Self::columns_min_max(&columns, num_rows)?;
-                let delete_action = DeletionByColumn {
-                    columns_min_max,
-                    key_hashes,
-                };
-                Ok(MergeIntoOperation::Delete(delete_action))
-            }
-            ColumnHash::Conflict => Err(ErrorCode::StorageOther(
-                "duplicated data detected in merge source",
-            )),
-        }
+    fn on_conflict_columns<'a>(&self, data_block: &'a DataBlock) -> Vec<&'a Column> {
+        self.on_conflict_fields
+            .iter()
+            .map(|field| {
+                let entry = &data_block.columns()[field.field_index];
+                entry.value.as_column().unwrap()
+            })
+            .collect()
     }
 
+    // Returns the indices of the rows to be kept (the last row of each distinct key),
+    // in their original order, and the digests of the keys.
     fn build_column_hash(
         columns: &[&Column],
-        saw: &mut HashSet<UniqueKeyDigest>,
         num_rows: usize,
-    ) -> Result<ColumnHash> {
+    ) -> (Vec<u32>, HashSet<UniqueKeyDigest>) {
         let mut digests = HashSet::new();
-        for i in 0..num_rows {
+        let mut rows = Vec::with_capacity(num_rows);
+        for i in (0..num_rows).rev() {
             let mut sip = sip128::SipHasher24::new();
             for column in columns {
                 let value = column.index(i).unwrap();
@@ -99,14 +95,12 @@ impl ReplaceIntoMutator {
                 sip.write(string.as_bytes());
             }
             let hash = sip.finish128().as_u128();
-            if saw.contains(&hash) {
-                return Ok(ColumnHash::Conflict);
-            } else {
-                saw.insert(hash);
+            if digests.insert(hash) {
+                rows.push(i as u32);
             }
-            digests.insert(hash);
         }
-        Ok(ColumnHash::NoConflict(digests))
+        rows.reverse();
+        (rows, digests)
     }
 
     fn eval(column: Column, num_rows: usize, aggr_func_name: &str) -> Result<Scalar> {
@@ -147,36 +141,27 @@ mod tests {
         // hello   2
         let column1 = StringType::from_data(&["Hi", "Hello"]);
         let column2 = NumberType::<u8>::from_data(vec![1, 2]);
-        let mut saw = HashSet::new();
         let num_rows = 2;
 
         let columns = [&column1, &column2];
-        let r = ReplaceIntoMutator::build_column_hash(&columns, &mut saw, num_rows)?;
-        assert_eq!(saw.len(), 2);
-        assert!(matches!(r, ColumnHash::NoConflict(..)));
+        let (rows, digests) = ReplaceIntoMutator::build_column_hash(&columns, num_rows);
+        assert_eq!(digests.len(), 2);
+        assert_eq!(rows, vec![0, 1]);
 
-        // append new item, no conflict
+        // duplicated keys, the last one wins
         // ------|---
+        // Hi      1
+        // hello   2
+        // Hi      1
         // Hi      2
-        // hello   3
-        let column1 = StringType::from_data(&["Hi", "Hello"]);
-        let column2 = NumberType::<u8>::from_data(vec![2, 3]);
-        let columns = [&column1, &column2];
-        let num_rows = 2;
-        let r = ReplaceIntoMutator::build_column_hash(&columns, &mut saw, num_rows)?;
-        assert_eq!(saw.len(), 4);
-        assert!(matches!(r, ColumnHash::NoConflict(..)));
-
-        // new item, conflict
-        // ------|---
-        //  Hi     1
-        let column1 = StringType::from_data(&["Hi"]);
-        let column2 = NumberType::<u8>::from_data(vec![1]);
+        // hello   2
+        let column1 = StringType::from_data(&["Hi", "Hello", "Hi", "Hi", "Hello"]);
+        let column2 = NumberType::<u8>::from_data(vec![1, 2, 1, 2, 2]);
         let columns = [&column1, &column2];
-        let num_rows = 1;
-        let r = ReplaceIntoMutator::build_column_hash(&columns, &mut saw, num_rows)?;
-        assert_eq!(saw.len(), 4);
-        assert!(matches!(r, ColumnHash::Conflict));
+        let num_rows = 5;
+        let (rows, digests) = ReplaceIntoMutator::build_column_hash(&columns, num_rows);
+        assert_eq!(digests.len(), 3);
+        assert_eq!(rows, vec![2, 3, 4]);
 
         Ok(())
     }
@@ -14,8 +14,10 @@
 //
 
 use std::any::Any;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use common_catalog::table::ReplaceIntoStatistics;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_pipeline_core::pipe::Pipe;
@@ -29,6 +31,8 @@ use common_pipeline_core::processors::Processor;
 use crate::operations::merge_into::OnConflictField;
 use crate::operations::replace_into::mutator_replace_into::ReplaceIntoMutator;
 
+// The rows of the source are buffered until the input is finished: among the rows
+// of the same conflict keys, only the last one is kept.
 pub struct ReplaceIntoProcessor {
     replace_into_mutator: ReplaceIntoMutator,
 
@@ -38,14 +42,20 @@ pub struct ReplaceIntoProcessor {
     output_port_append_data: Arc<OutputPort>,
 
     input_data: Option<DataBlock>,
+    input_blocks: Vec<DataBlock>,
     output_data_merge_into_action: Option<DataBlock>,
     output_data_append: Option<DataBlock>,
 
     target_table_empty: bool,
+    statistics: Arc<ReplaceIntoStatistics>,
 }
 
 impl ReplaceIntoProcessor {
-    pub fn create(on_conflict_fields: Vec<OnConflictField>, target_table_empty: bool) -> Self {
+    pub fn create(
+        on_conflict_fields: Vec<OnConflictField>,
+        target_table_empty: bool,
+        statistics: Arc<ReplaceIntoStatistics>,
+    ) -> Self {
         let replace_into_mutator = ReplaceIntoMutator::create(on_conflict_fields);
         let input_port = InputPort::create();
         let output_port_merge_into_action = OutputPort::create();
@@ -57,9 +67,11 @@ impl ReplaceIntoProcessor {
             output_port_merge_into_action,
             output_port_append_data,
             input_data: None,
+            input_blocks: vec![],
             output_data_merge_into_action: None,
             output_data_append: None,
             target_table_empty,
+            statistics,
         }
     }
 
@@ -90,16 +102,6 @@ impl Processor for ReplaceIntoProcessor {
         self
     }
     fn event(&mut self) -> Result<Event> {
-        let finished = self.input_port.is_finished()
-            && self.output_data_append.is_none()
-            && self.output_data_merge_into_action.is_none();
-
-        if finished {
-            self.output_port_merge_into_action.finish();
-            self.output_port_append_data.finish();
-            return Ok(Event::Finished);
-        }
-
         let mut pushed_something = false;
         if self.output_port_append_data.can_push() {
             if let Some(data) = self.output_data_append.take() {
@@ -115,32 +117,55 @@ impl Processor for ReplaceIntoProcessor {
             }
         }
 
-        if pushed_something {
-            Ok(Event::NeedConsume)
-        } else {
-            if self.input_data.is_some() {
+        if pushed_something
+            || self.output_data_append.is_some()
+            || self.output_data_merge_into_action.is_some()
+        {
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.input_port.is_finished() {
+            if !self.input_blocks.is_empty() {
                 return Ok(Event::Sync);
             }
+            self.output_port_merge_into_action.finish();
+            self.output_port_append_data.finish();
+            return Ok(Event::Finished);
+        }
 
-            if self.input_port.has_data() {
-                self.input_data = Some(self.input_port.pull_data().unwrap()?);
-                Ok(Event::Sync)
-            } else {
-                self.input_port.set_need_data();
-                Ok(Event::NeedData)
-            }
+        if self.input_port.has_data() {
+            self.input_data = Some(self.input_port.pull_data().unwrap()?);
+            Ok(Event::Sync)
+        } else {
+            self.input_port.set_need_data();
+            Ok(Event::NeedData)
         }
     }
 
     fn process(&mut self) -> Result<()> {
         if let Some(data_block) = self.input_data.take() {
-            let merge_into_action = self.replace_into_mutator.process_input_block(&data_block)?;
+            if !data_block.is_empty() {
+                self.input_blocks.push(data_block);
+            }
+            return Ok(());
+        }
+
+        if !self.input_blocks.is_empty() {
+            let data_block = DataBlock::concat(&std::mem::take(&mut self.input_blocks))?;
+            let (data_block, merge_into_action) =
+                self.replace_into_mutator.process_input_block(&data_block)?;
+            self.statistics
+                .source_rows
+                .fetch_add(data_block.num_rows() as u64, Ordering::Relaxed);
             if !self.target_table_empty {
                 self.output_data_merge_into_action =
                     Some(DataBlock::empty_with_meta(Box::new(merge_into_action)));
             }
             self.output_data_append = Some(data_block);
-            return Ok(());
         }
 
         Ok(())
@@ -41,6 +41,11 @@ impl FuseTable {
         // 2. prepare table meta which being reverted to
         let table_meta_to_be_committed = table_reverting_to.table_info.meta.clone();
 
+        // in an explicit transaction, the new meta is buffered until the transaction commits
+        if Self::buffer_in_txn(ctx, &self.table_info, &table_meta_to_be_committed)? {
+            return Ok(());
+        }
+
         // 3. prepare the request
         //  using the CURRENT version as the base table version
         let base_version = self.table_info.ident.seq;
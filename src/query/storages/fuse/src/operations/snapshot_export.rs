@@ -0,0 +1,185 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use common_base::runtime::execute_futures_in_parallel;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableSchema;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+use crate::FUSE_TBL_SNAPSHOT_MANIFEST;
+
+/// A file of an exported snapshot, which is at the same location relative to the export path
+/// as it was relative to the storage root of the exported table.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotManifestFile {
+    pub location: String,
+    pub size: u64,
+}
+
+/// The manifest written by `EXPORT SNAPSHOT TABLE`, after all the files of the snapshot
+/// have been copied.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotManifest {
+    pub schema: TableSchema,
+    /// The options the snapshot can't be read without, like the storage format.
+    pub options: BTreeMap<String, String>,
+    /// `<database_id>/<table_id>` of the exported table, the locations of the files start with it.
+    pub storage_prefix: String,
+    pub snapshot_location: String,
+    /// The snapshot, its statistics, segments, blocks and bloom indexes.
+    pub files: Vec<SnapshotManifestFile>,
+}
+
+impl SnapshotManifest {
+    pub fn total_bytes(&self) -> u64 {
+        self.files.iter().map(|file| file.size).sum()
+    }
+
+    pub async fn read(operator: &Operator) -> Result<Self> {
+        if !operator.is_exist(FUSE_TBL_SNAPSHOT_MANIFEST).await? {
+            return Err(ErrorCode::BadArguments(format!(
+                "no exported snapshot found at {}, the {} is missing",
+                operator.info().root(),
+                FUSE_TBL_SNAPSHOT_MANIFEST
+            )));
+        }
+        let data = operator.read(FUSE_TBL_SNAPSHOT_MANIFEST).await?;
+        Ok(serde_json::from_slice(&data)?)
+    }
+
+    /// Checks that all the files of the snapshot are in place, with the sizes they were exported with.
+    pub async fn check_files(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        operator: &Operator,
+    ) -> Result<()> {
+        let tasks = self.files.iter().cloned().map(|file| {
+            let operator = operator.clone();
+            async move {
+                let size = operator.stat(&file.location).await?.content_length();
+                if size != file.size {
+                    return Err(ErrorCode::StorageOther(format!(
+                        "the size of exported file {} is {}, but {} is expected",
+                        file.location, size, file.size
+                    )));
+                }
+                Ok(())
+            }
+        });
+        execute_in_parallel(ctx, tasks, "fuse-check-snapshot-files-worker")
+            .await?
+            .into_iter()
+            .collect()
+    }
+}
+
+impl FuseTable {
+    /// Copies the files of the current snapshot of the table to `target`, keeping their locations
+    /// relative to the storage root, then writes the manifest of them.
+    ///
+    /// The manifest is written last, an export that failed half way can't be imported.
+    pub async fn do_export_snapshot(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        target: Operator,
+    ) -> Result<SnapshotManifest> {
+        let snapshot = self.read_table_snapshot().await?.ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "table {} has no snapshot to export",
+                self.table_info.desc
+            ))
+        })?;
+        let snapshot_location = self
+            .meta_location_generator
+            .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot.format_version())?;
+
+        let mut locations = vec![snapshot_location.clone()];
+        locations.extend(snapshot.table_statistics_location.iter().cloned());
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let segments = segments_io.read_segments(&snapshot.segments).await?;
+        for ((location, _), segment) in snapshot.segments.iter().zip(segments) {
+            let segment = segment?;
+            locations.push(location.clone());
+            for block in segment.blocks.iter() {
+                locations.push(block.location.0.clone());
+                if let Some((location, _)) = &block.bloom_filter_index_location {
+                    locations.push(location.clone());
+                }
+            }
+        }
+
+        let tasks = locations.into_iter().map(|location| {
+            let source = self.operator.clone();
+            let target = target.clone();
+            async move {
+                let data = source.read(&location).await?;
+                let size = data.len() as u64;
+                target.write(&location, data).await?;
+                Ok(SnapshotManifestFile { location, size })
+            }
+        });
+        let files = execute_in_parallel(ctx, tasks, "fuse-export-snapshot-worker")
+            .await?
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+
+        let options = self
+            .table_info
+            .options()
+            .iter()
+            .filter(|(k, _)| {
+                k.as_str() == OPT_KEY_STORAGE_FORMAT || k.as_str() == OPT_KEY_TABLE_COMPRESSION
+            })
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        let manifest = SnapshotManifest {
+            schema: snapshot.schema.clone(),
+            options,
+            storage_prefix: self.meta_location_generator.prefix().to_string(),
+            snapshot_location,
+            files,
+        };
+        target
+            .write(FUSE_TBL_SNAPSHOT_MANIFEST, serde_json::to_vec(&manifest)?)
+            .await?;
+        Ok(manifest)
+    }
+}
+
+async fn execute_in_parallel<Fut>(
+    ctx: &Arc<dyn TableContext>,
+    tasks: impl IntoIterator<Item = Fut>,
+    thread_name: &str,
+) -> Result<Vec<Fut::Output>>
+where
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    let threads_nums = ctx.get_settings().get_max_threads()? as usize;
+    let permit_nums = ctx.get_settings().get_max_storage_io_requests()? as usize;
+    execute_futures_in_parallel(tasks, threads_nums, permit_nums, thread_name.to_owned()).await
+}
@@ -33,7 +33,7 @@ impl FuseTable {
         if let Some(prev_snapshot) = self.read_table_snapshot().await? {
             let prev_id = prev_snapshot.snapshot_id;
 
-            let new_snapshot = TableSnapshot::new(
+            let mut new_snapshot = TableSnapshot::new(
                 Uuid::new_v4(),
                 &prev_snapshot.timestamp,
                 Some((prev_id, prev_snapshot.format_version())),
@@ -44,6 +44,8 @@ impl FuseTable {
                 // truncate MUST reset ts location
                 None,
             );
+            // The sources are not read again from the start.
+            new_snapshot.source_offsets = prev_snapshot.source_offsets.clone();
             let loc = self.meta_location_generator();
             let new_snapshot_loc =
                 loc.snapshot_location_from_uuid(&new_snapshot.snapshot_id, TableSnapshot::VERSION)?;
@@ -65,6 +67,11 @@ impl FuseTable {
             // update table statistics, all zeros
             new_table_meta.statistics = TableStatistics::default();
 
+            // in an explicit transaction, the new meta is buffered until the transaction commits
+            if Self::buffer_in_txn(ctx.as_ref(), &self.table_info, &new_table_meta)? {
+                return Ok(());
+            }
+
             let table_id = self.table_info.ident.table_id;
             let table_version = self.table_info.ident.seq;
             let catalog = ctx.get_catalog(self.table_info.catalog())?;
@@ -28,6 +28,7 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::evaluator::BlockOperator;
 use storages_common_table_meta::meta::TableSnapshot;
 
+use crate::metrics::TableOperation;
 use crate::operations::mutation::MutationAction;
 use crate::operations::mutation::MutationSink;
 use crate::operations::mutation::MutationSource;
@@ -149,6 +150,7 @@ impl FuseTable {
                     Projection::Columns(remain_col_indices),
                     false,
                     ctx.clone(),
+                    TableOperation::Mutation,
                 )?;
                 fields.extend_from_slice(reader.schema().fields());
                 remain_reader = Some((*reader).clone());
@@ -182,7 +184,12 @@ impl FuseTable {
             projection: offset_map.values().cloned().collect(),
         });
 
-        let block_reader = self.create_block_reader(projection.clone(), false, ctx.clone())?;
+        let block_reader = self.create_block_reader(
+            projection.clone(),
+            false,
+            ctx.clone(),
+            TableOperation::Mutation,
+        )?;
         let remain_reader = Arc::new(remain_reader);
         let (filter_expr, filter) = if let Some(remote_expr) = filter {
             let schema = block_reader.schema();
@@ -240,6 +240,10 @@ impl Trim for Scalar {
                     None
                 }
             },
+            Scalar::Binary(mut bytes) => {
+                bytes.truncate(STATS_STRING_PREFIX_LEN);
+                Some(Scalar::Binary(bytes))
+            }
             v => Some(v),
         }
     }
@@ -296,6 +300,19 @@ impl Trim for Scalar {
                     None
                 }
             },
+            Scalar::Binary(mut bytes) => {
+                if bytes.len() <= STATS_STRING_PREFIX_LEN {
+                    return Some(Scalar::Binary(bytes));
+                }
+
+                // binary values are compared byte-wise, so bump the last byte of the
+                // prefix that can still be incremented and drop everything after it.
+                bytes.truncate(STATS_STRING_PREFIX_LEN);
+                let idx = bytes.iter().rposition(|b| *b < u8::MAX)?;
+                bytes.truncate(idx + 1);
+                bytes[idx] += 1;
+                Some(Scalar::Binary(bytes))
+            }
             v => Some(v),
         }
     }
@@ -0,0 +1,466 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::Projection;
+use common_catalog::plan::PushDownInfo;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::Value;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::BlockReader;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::metrics::TableOperation;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::AsyncSource;
+use crate::pipelines::processors::AsyncSourcer;
+use crate::pipelines::Pipeline;
+use crate::sessions::TableContext;
+use crate::FuseTable;
+use crate::Table;
+
+pub const STREAM_ENGINE: &str = "STREAM";
+
+pub const OPT_KEY_TABLE_DATABASE: &str = "table_database";
+pub const OPT_KEY_TABLE_NAME: &str = "table_name";
+pub const OPT_KEY_TABLE_ID: &str = "table_id";
+/// Absent if the table had no snapshot at the offset.
+pub const OPT_KEY_OFFSET_SNAPSHOT_ID: &str = "offset_snapshot_id";
+pub const OPT_KEY_OFFSET_SNAPSHOT_LOCATION: &str = "offset_snapshot_location";
+
+pub const CHANGE_ACTION_COLUMN: &str = "change$action";
+pub const CHANGE_ROW_ID_COLUMN: &str = "change$row_id";
+
+const CHANGE_ACTION_INSERT: &str = "INSERT";
+
+/// A stream on a fuse table, it returns the rows appended to the table since its offset,
+/// with the metadata columns `change$action` and `change$row_id`.
+///
+/// The offset is the snapshot of the table when the stream is created, it only advances
+/// when the stream is consumed by an `INSERT INTO ... SELECT`, see `advance_offset`.
+#[derive(Clone)]
+pub struct StreamTable {
+    table_info: TableInfo,
+    table_database: String,
+    table_name: String,
+    table_id: u64,
+    offset_snapshot_location: Option<String>,
+}
+
+impl StreamTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = table_info.options();
+        let option = |key: &str| {
+            options.get(key).cloned().ok_or_else(|| {
+                ErrorCode::Internal(format!("Need `{key}` when creating StreamTable"))
+            })
+        };
+        let table_database = option(OPT_KEY_TABLE_DATABASE)?;
+        let table_name = option(OPT_KEY_TABLE_NAME)?;
+        let table_id = option(OPT_KEY_TABLE_ID)?.parse::<u64>().map_err(|e| {
+            ErrorCode::Internal(format!("invalid `{OPT_KEY_TABLE_ID}` of StreamTable: {e}"))
+        })?;
+        let offset_snapshot_location = options.get(OPT_KEY_OFFSET_SNAPSHOT_LOCATION).cloned();
+        Ok(Box::new(StreamTable {
+            table_info,
+            table_database,
+            table_name,
+            table_id,
+            offset_snapshot_location,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: STREAM_ENGINE.to_string(),
+            comment: "STREAM STORAGE (CHANGES OF A TABLE)".to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&StreamTable> {
+        tbl.as_any().downcast_ref::<StreamTable>().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "expects table of engine STREAM, but got {}",
+                tbl.engine()
+            ))
+        })
+    }
+
+    /// The schema of a stream on a table of `schema`, i.e. the columns of the table
+    /// followed by the metadata columns.
+    pub fn schema_of(schema: &TableSchema) -> Result<TableSchema> {
+        let mut fields = schema.fields().clone();
+        for name in [CHANGE_ACTION_COLUMN, CHANGE_ROW_ID_COLUMN] {
+            if schema.index_of(name).is_ok() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "can't create stream on a table with column {name}"
+                )));
+            }
+            fields.push(TableField::new(name, TableDataType::String));
+        }
+        Ok(TableSchema::new(fields))
+    }
+
+    pub fn table_database(&self) -> &str {
+        &self.table_database
+    }
+
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+
+    pub fn offset_snapshot_id(&self) -> Option<&String> {
+        self.table_info.options().get(OPT_KEY_OFFSET_SNAPSHOT_ID)
+    }
+
+    /// The table of the stream, as it is read by the query.
+    pub async fn source_table(&self, ctx: Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
+        let table = ctx
+            .get_table(
+                &self.table_info.meta.catalog,
+                &self.table_database,
+                &self.table_name,
+            )
+            .await?;
+        if table.get_id() != self.table_id {
+            return Err(ErrorCode::UnknownTable(format!(
+                "table {}.{} of stream {} has been dropped",
+                self.table_database,
+                self.table_name,
+                self.name()
+            )));
+        }
+        Ok(table)
+    }
+
+    /// Move the offset to the snapshot of the table read by the query, so that the
+    /// consumed rows are not returned again. Fails if the stream has been changed since
+    /// the query started, e.g. consumed by another query.
+    pub async fn advance_offset(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        let table = self.source_table(ctx.clone()).await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let snapshot = fuse_table.read_table_snapshot().await?;
+
+        let mut new_table_meta = self.table_info.meta.clone();
+        let options = &mut new_table_meta.options;
+        options.remove(OPT_KEY_OFFSET_SNAPSHOT_ID);
+        options.remove(OPT_KEY_OFFSET_SNAPSHOT_LOCATION);
+        if let (Some(snapshot), Some(location)) = (snapshot, fuse_table.snapshot_loc().await?) {
+            options.insert(
+                OPT_KEY_OFFSET_SNAPSHOT_ID.to_string(),
+                snapshot.snapshot_id.simple().to_string(),
+            );
+            options.insert(OPT_KEY_OFFSET_SNAPSHOT_LOCATION.to_string(), location);
+        }
+
+        // in an explicit transaction, the offset is moved when the transaction commits
+        if FuseTable::buffer_in_txn(ctx.as_ref(), &self.table_info, &new_table_meta)? {
+            return Ok(());
+        }
+
+        let catalog = ctx.get_catalog(&self.table_info.meta.catalog)?;
+        let req = UpdateTableMetaReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+        };
+        catalog.update_table_meta(&self.table_info, req).await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| StreamSource::create(ctx.clone(), output, self.clone()),
+            1,
+        )
+    }
+}
+
+/// The changes of the table between the offset of the stream and the snapshot read.
+struct StreamChanges {
+    block_reader: Arc<BlockReader>,
+    // Blocks of the snapshot read that are not in the offset snapshot.
+    added_blocks: VecDeque<Arc<BlockMeta>>,
+    // Rows of the blocks of the offset snapshot that are not in the snapshot read, with
+    // the number of their occurrences.
+    removed_rows: HashMap<Vec<Scalar>, usize>,
+}
+
+/// Read the rows appended to the table of a stream since its offset.
+///
+/// The appended rows are the rows of the added blocks. A block is removed if the table is
+/// compacted, the rows of the removed blocks are then also in the added blocks, and they
+/// are told apart by matching them with the rows of the removed blocks. Rows of removed
+/// blocks which can't be matched were updated or deleted, which are not supported yet.
+struct StreamSource {
+    ctx: Arc<dyn TableContext>,
+    stream: StreamTable,
+    changes: Option<StreamChanges>,
+}
+
+impl StreamSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        stream: StreamTable,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, StreamSource {
+            ctx,
+            stream,
+            changes: None,
+        })
+    }
+
+    async fn read_changes(&self) -> Result<StreamChanges> {
+        let table = self.stream.source_table(self.ctx.clone()).await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+
+        // The columns of the stream are looked up by name, they are the columns of the table
+        // when the stream is created.
+        let table_schema = fuse_table.schema();
+        let stream_schema = self.stream.schema();
+        let num_columns = stream_schema.num_fields() - 2;
+        let mut projection = Vec::with_capacity(num_columns);
+        for field in &stream_schema.fields()[..num_columns] {
+            match table_schema.index_of(field.name()) {
+                Ok(index) if table_schema.field(index).data_type() == field.data_type() => {
+                    projection.push(index)
+                }
+                _ => {
+                    return Err(ErrorCode::TableSchemaMismatch(format!(
+                        "column {} of stream {} doesn't match table {}.{}, the table has been altered since the stream was created",
+                        field.name(),
+                        self.stream.name(),
+                        self.stream.table_database,
+                        self.stream.table_name,
+                    )));
+                }
+            }
+        }
+        let block_reader = fuse_table.create_block_reader(
+            Projection::Columns(projection),
+            false,
+            self.ctx.clone(),
+            TableOperation::Scan,
+        )?;
+
+        let current_blocks = match fuse_table.read_table_snapshot().await? {
+            Some(snapshot) => self.read_blocks(fuse_table, &snapshot).await?,
+            None => vec![],
+        };
+        let offset_blocks = match &self.stream.offset_snapshot_location {
+            Some(location) => {
+                let reader = MetaReaders::table_snapshot_reader(fuse_table.get_operator());
+                let params = LoadParams {
+                    location: location.clone(),
+                    len_hint: None,
+                    ver: TableMetaLocationGenerator::snapshot_version(location),
+                    put_cache: true,
+                };
+                let snapshot = reader.read(&params).await.map_err(|e| {
+                    e.add_message_back(format!(
+                        " (offset snapshot of stream {} has been purged)",
+                        self.stream.name()
+                    ))
+                })?;
+                self.read_blocks(fuse_table, &snapshot).await?
+            }
+            None => vec![],
+        };
+
+        let current_locations = current_blocks
+            .iter()
+            .map(|block| block.location.0.as_str())
+            .collect::<HashSet<_>>();
+        let offset_locations = offset_blocks
+            .iter()
+            .map(|block| block.location.0.as_str())
+            .collect::<HashSet<_>>();
+        let added_blocks = current_blocks
+            .iter()
+            .filter(|block| !offset_locations.contains(block.location.0.as_str()))
+            .cloned()
+            .collect();
+
+        let settings = ReadSettings::from_ctx(&self.ctx)?;
+        let mut removed_rows = HashMap::new();
+        for block in offset_blocks
+            .iter()
+            .filter(|block| !current_locations.contains(block.location.0.as_str()))
+        {
+            let data_block = block_reader
+                .read_by_meta(&settings, block, &fuse_table.storage_format)
+                .await?;
+            for row in 0..data_block.num_rows() {
+                *removed_rows.entry(row_key(&data_block, row)).or_insert(0) += 1;
+            }
+        }
+
+        Ok(StreamChanges {
+            block_reader,
+            added_blocks,
+            removed_rows,
+        })
+    }
+
+    async fn read_blocks(
+        &self,
+        fuse_table: &FuseTable,
+        snapshot: &TableSnapshot,
+    ) -> Result<Vec<Arc<BlockMeta>>> {
+        let segments_io = SegmentsIO::create(
+            self.ctx.clone(),
+            fuse_table.get_operator(),
+            fuse_table.schema(),
+        );
+        let mut blocks = vec![];
+        for segment in segments_io.read_segments(&snapshot.segments).await? {
+            blocks.extend(segment?.blocks.iter().cloned());
+        }
+        Ok(blocks)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for StreamSource {
+    const NAME: &'static str = "stream";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.changes.is_none() {
+            self.changes = Some(self.read_changes().await?);
+        }
+        let table = self.stream.source_table(self.ctx.clone()).await?;
+        let storage_format = FuseTable::try_from_table(table.as_ref())?.storage_format;
+        let settings = ReadSettings::from_ctx(&self.ctx)?;
+        let changes = self.changes.as_mut().unwrap();
+
+        while let Some(block) = changes.added_blocks.pop_front() {
+            let data_block = changes
+                .block_reader
+                .read_by_meta(&settings, &block, &storage_format)
+                .await?;
+            let num_rows = data_block.num_rows();
+            let appended = (0..num_rows)
+                .map(|row| !take_row(&mut changes.removed_rows, row_key(&data_block, row)))
+                .collect::<Bitmap>();
+            if appended.unset_bits() == num_rows {
+                continue;
+            }
+
+            // The rows are identified by the name of their block file and their position.
+            let block_name = block.location.0.rsplit('/').next().unwrap_or_default();
+            let mut row_ids = StringColumnBuilder::with_capacity(num_rows, 0);
+            for row in 0..num_rows {
+                row_ids.put_str(&format!("{block_name}:{row}"));
+                row_ids.commit_row();
+            }
+            let mut data_block = data_block;
+            data_block.add_column(BlockEntry {
+                data_type: DataType::String,
+                value: Value::Scalar(Scalar::String(CHANGE_ACTION_INSERT.as_bytes().to_vec())),
+            });
+            data_block.add_column(BlockEntry {
+                data_type: DataType::String,
+                value: Value::Column(Column::String(row_ids.build())),
+            });
+            return Ok(Some(data_block.filter_with_bitmap(&appended)?));
+        }
+
+        if !changes.removed_rows.is_empty() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "stream {} only captures appended rows, but rows of table {}.{} have been updated or deleted since its offset",
+                self.stream.name(),
+                self.stream.table_database,
+                self.stream.table_name,
+            )));
+        }
+        Ok(None)
+    }
+}
+
+fn row_key(data_block: &DataBlock, row: usize) -> Vec<Scalar> {
+    data_block
+        .columns()
+        .iter()
+        .map(|entry| entry.value.index(row).unwrap().to_owned())
+        .collect()
+}
+
+/// Take a row out of the multiset `rows`, returns false if it isn't there.
+fn take_row(rows: &mut HashMap<Vec<Scalar>, usize>, key: Vec<Scalar>) -> bool {
+    match rows.get_mut(&key) {
+        Some(count) if *count > 1 => *count -= 1,
+        Some(_) => {
+            rows.remove(&key);
+        }
+        None => return false,
+    }
+    true
+}
@@ -53,6 +53,7 @@ struct ClusteringStatistics {
     total_constant_block_count: u64,
     average_overlaps: f64,
     average_depth: f64,
+    depth_above_threshold_percentage: f64,
     block_depth_histogram: JsonValue,
 }
 
@@ -63,6 +64,7 @@ impl Default for ClusteringStatistics {
             total_constant_block_count: 0,
             average_overlaps: 0.0,
             average_depth: 0.0,
+            depth_above_threshold_percentage: 0.0,
             block_depth_histogram: json!({}),
         }
     }
@@ -137,6 +139,12 @@ impl<'a> ClusteringInformation<'a> {
                         info.average_depth.into(),
                     ))),
                 },
+                BlockEntry {
+                    data_type: DataType::Number(NumberDataType::Float64),
+                    value: Value::Scalar(Scalar::Number(NumberScalar::Float64(
+                        info.depth_above_threshold_percentage.into(),
+                    ))),
+                },
                 BlockEntry {
                     data_type: DataType::Variant,
                     value: Value::Scalar(Scalar::Variant(
@@ -221,12 +229,18 @@ impl<'a> ClusteringInformation<'a> {
 
         let mut sum_overlap = 0;
         let mut sum_depth = 0;
+        // The blocks deeper than the threshold are the candidates of recluster.
+        let threshold = self.table.get_depth_threshold(total_block_count);
+        let mut depth_above_threshold_count = 0;
         let length = statis.len();
         let mp = statis
             .into_iter()
             .fold(BTreeMap::new(), |mut acc, (overlap, depth)| {
                 sum_overlap += overlap;
                 sum_depth += depth;
+                if depth as f64 > threshold {
+                    depth_above_threshold_count += 1;
+                }
 
                 let bucket = get_buckets(depth);
                 acc.entry(bucket).and_modify(|v| *v += 1).or_insert(1u32);
@@ -235,6 +249,8 @@ impl<'a> ClusteringInformation<'a> {
         // round the float to 4 decimal places.
         let average_depth = (10000.0 * sum_depth as f64 / length as f64).round() / 10000.0;
         let average_overlaps = (10000.0 * sum_overlap as f64 / length as f64).round() / 10000.0;
+        let depth_above_threshold_percentage =
+            (1000000.0 * depth_above_threshold_count as f64 / length as f64).round() / 10000.0;
 
         let objects = mp.iter().fold(
             serde_json::Map::with_capacity(mp.len()),
@@ -250,6 +266,7 @@ impl<'a> ClusteringInformation<'a> {
             total_constant_block_count,
             average_overlaps,
             average_depth,
+            depth_above_threshold_percentage,
             block_depth_histogram,
         })
     }
@@ -273,6 +290,10 @@ impl<'a> ClusteringInformation<'a> {
                 "average_depth",
                 TableDataType::Number(NumberDataType::Float64),
             ),
+            TableField::new(
+                "depth_above_threshold_percentage",
+                TableDataType::Number(NumberDataType::Float64),
+            ),
             TableField::new("block_depth_histogram", TableDataType::Variant),
         ])
     }
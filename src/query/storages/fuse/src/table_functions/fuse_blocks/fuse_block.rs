@@ -104,6 +104,9 @@ impl<'a> FuseBlock<'a> {
         let mut bloom_filter_location = vec![];
         let mut bloom_filter_size =
             NumberColumnBuilder::with_capacity(&NumberDataType::UInt64, len);
+        let mut segment_location = StringColumnBuilder::with_capacity(len, len);
+        let mut cluster_stats_min = vec![];
+        let mut cluster_stats_max = vec![];
 
         let segments_io = SegmentsIO::create(
             self.ctx.clone(),
@@ -111,7 +114,7 @@ impl<'a> FuseBlock<'a> {
             self.table.schema(),
         );
         let segments = segments_io.read_segments(&snapshot.segments).await?;
-        for segment in segments {
+        for (segment, (location, _)) in segments.into_iter().zip(snapshot.segments.iter()) {
             let segment = segment?;
             segment.blocks.iter().for_each(|block| {
                 let block = block.as_ref();
@@ -127,6 +130,20 @@ impl<'a> FuseBlock<'a> {
                         .map(|s| s.0.as_bytes().to_vec()),
                 );
                 bloom_filter_size.push(NumberScalar::UInt64(block.bloom_filter_index_size));
+                segment_location.put_slice(location.as_bytes());
+                segment_location.commit_row();
+                cluster_stats_min.push(
+                    block
+                        .cluster_stats
+                        .as_ref()
+                        .map(|stats| format_cluster_values(&stats.min)),
+                );
+                cluster_stats_max.push(
+                    block
+                        .cluster_stats
+                        .as_ref()
+                        .map(|stats| format_cluster_values(&stats.max)),
+                );
             });
         }
 
@@ -164,6 +181,18 @@ impl<'a> FuseBlock<'a> {
                     data_type: DataType::Number(NumberDataType::UInt64),
                     value: Value::Column(Column::Number(bloom_filter_size.build())),
                 },
+                BlockEntry {
+                    data_type: DataType::String,
+                    value: Value::Column(Column::String(segment_location.build())),
+                },
+                BlockEntry {
+                    data_type: DataType::String.wrap_nullable(),
+                    value: Value::Column(StringType::from_opt_data(cluster_stats_min)),
+                },
+                BlockEntry {
+                    data_type: DataType::String.wrap_nullable(),
+                    value: Value::Column(StringType::from_opt_data(cluster_stats_max)),
+                },
             ],
             len,
         ))
@@ -185,6 +214,15 @@ impl<'a> FuseBlock<'a> {
                 "bloom_filter_size",
                 TableDataType::Number(NumberDataType::UInt64),
             ),
+            TableField::new("segment_location", TableDataType::String),
+            TableField::new("cluster_stats_min", TableDataType::String.wrap_nullable()),
+            TableField::new("cluster_stats_max", TableDataType::String.wrap_nullable()),
         ])
     }
 }
+
+/// Formats the values of the cluster keys as a tuple, e.g. `(1, "a")`.
+fn format_cluster_values(values: &[Scalar]) -> Vec<u8> {
+    let values = values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+    format!("({})", values.join(", ")).into_bytes()
+}
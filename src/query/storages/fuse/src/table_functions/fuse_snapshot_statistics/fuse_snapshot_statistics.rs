@@ -0,0 +1,183 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+use futures_util::StreamExt;
+use futures_util::TryStreamExt;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::MetaReaders;
+use crate::io::SegmentsIO;
+use crate::io::SnapshotHistoryReader;
+use crate::sessions::TableContext;
+use crate::FuseTable;
+
+pub struct FuseSnapshotStatistics<'a> {
+    pub ctx: Arc<dyn TableContext>,
+    pub table: &'a FuseTable,
+}
+
+impl<'a> FuseSnapshotStatistics<'a> {
+    pub fn new(ctx: Arc<dyn TableContext>, table: &'a FuseTable) -> Self {
+        Self { ctx, table }
+    }
+
+    pub async fn get_statistics(self, limit: Option<usize>) -> Result<DataBlock> {
+        let tbl = self.table;
+        if let Some(snapshot_location) = tbl.snapshot_loc().await? {
+            let snapshot_version = tbl.snapshot_format_version().await?;
+            let reader = MetaReaders::table_snapshot_reader(tbl.get_operator());
+            let snapshot_stream = reader.snapshot_history(
+                snapshot_location,
+                snapshot_version,
+                tbl.meta_location_generator().clone(),
+            );
+
+            // The changes of a snapshot are computed against its previous snapshot,
+            // so one more snapshot than the limit is read.
+            let snapshots: Vec<Arc<TableSnapshot>> = match limit {
+                Some(limit) => snapshot_stream.take(limit + 1).try_collect().await?,
+                None => snapshot_stream.try_collect().await?,
+            };
+            let len = limit.map_or(snapshots.len(), |limit| limit.min(snapshots.len()));
+            return self.to_block(&snapshots, len).await;
+        }
+
+        Ok(DataBlock::empty_with_schema(Arc::new(
+            FuseSnapshotStatistics::schema().into(),
+        )))
+    }
+
+    async fn to_block(&self, snapshots: &[Arc<TableSnapshot>], len: usize) -> Result<DataBlock> {
+        let segments_io = SegmentsIO::create(
+            self.ctx.clone(),
+            self.table.operator.clone(),
+            self.table.schema(),
+        );
+
+        let mut snapshot_ids: Vec<Vec<u8>> = Vec::with_capacity(len);
+        let mut timestamps: Vec<Option<i64>> = Vec::with_capacity(len);
+        let mut added_blocks: Vec<u64> = Vec::with_capacity(len);
+        let mut removed_blocks: Vec<u64> = Vec::with_capacity(len);
+        let mut recluster_blocks: Vec<u64> = Vec::with_capacity(len);
+        for (idx, snapshot) in snapshots.iter().take(len).enumerate() {
+            // The oldest snapshot that can be reached has no previous snapshot,
+            // all of its blocks are considered as added.
+            let prev_segments: &[Location] = snapshots
+                .get(idx + 1)
+                .map_or(&[], |prev| prev.segments.as_slice());
+
+            // Segments are immutable, only the segments that differ between the two
+            // snapshots need to be read.
+            let added_segments = segments_difference(&snapshot.segments, prev_segments);
+            let removed_segments = segments_difference(prev_segments, &snapshot.segments);
+            let added = read_blocks(&segments_io, &added_segments).await?;
+            let removed = read_blocks(&segments_io, &removed_segments).await?;
+
+            let mut added_count = 0;
+            let mut recluster_count = 0;
+            for (location, block) in added.iter() {
+                if removed.contains_key(location) {
+                    continue;
+                }
+                added_count += 1;
+                // Blocks written by recluster are promoted to a level above 0.
+                if block
+                    .cluster_stats
+                    .as_ref()
+                    .map_or(false, |stats| stats.level > 0)
+                {
+                    recluster_count += 1;
+                }
+            }
+            let removed_count = removed
+                .keys()
+                .filter(|location| !added.contains_key(*location))
+                .count() as u64;
+
+            snapshot_ids.push(snapshot.snapshot_id.simple().to_string().into_bytes());
+            timestamps.push(snapshot.timestamp.map(|dt| dt.timestamp_micros()));
+            added_blocks.push(added_count);
+            removed_blocks.push(removed_count);
+            recluster_blocks.push(recluster_count);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(snapshot_ids),
+            TimestampType::from_opt_data(timestamps),
+            UInt64Type::from_data(added_blocks),
+            UInt64Type::from_data(removed_blocks),
+            UInt64Type::from_data(recluster_blocks),
+        ]))
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("snapshot_id", TableDataType::String),
+            TableField::new("timestamp", TableDataType::Timestamp.wrap_nullable()),
+            TableField::new(
+                "added_blocks",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "removed_blocks",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "recluster_blocks",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ])
+    }
+}
+
+fn segments_difference(segments: &[Location], others: &[Location]) -> Vec<Location> {
+    let others: HashSet<&Location> = others.iter().collect();
+    segments
+        .iter()
+        .filter(|location| !others.contains(location))
+        .cloned()
+        .collect()
+}
+
+async fn read_blocks(
+    segments_io: &SegmentsIO,
+    segment_locations: &[Location],
+) -> Result<HashMap<String, Arc<BlockMeta>>> {
+    let mut blocks = HashMap::new();
+    for segment in segments_io.read_segments(segment_locations).await? {
+        for block in segment?.blocks.iter() {
+            blocks.insert(block.location.0.clone(), block.clone());
+        }
+    }
+    Ok(blocks)
+}
@@ -15,6 +15,7 @@
 mod clustering_information;
 mod fuse_blocks;
 mod fuse_segments;
+mod fuse_snapshot_statistics;
 mod fuse_snapshots;
 mod fuse_statistics;
 mod table_args;
@@ -29,6 +30,8 @@ pub use fuse_blocks::FuseBlock;
 pub use fuse_blocks::FuseBlockTable;
 pub use fuse_segments::FuseSegment;
 pub use fuse_segments::FuseSegmentTable;
+pub use fuse_snapshot_statistics::FuseSnapshotStatistics;
+pub use fuse_snapshot_statistics::FuseSnapshotStatisticsTable;
 pub use fuse_snapshots::FuseSnapshot;
 pub use fuse_snapshots::FuseSnapshotTable;
 pub use fuse_statistics::FuseStatisticTable;
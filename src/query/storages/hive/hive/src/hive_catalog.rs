@@ -54,6 +54,7 @@ use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_app::storage::StorageParams;
 use common_meta_types::*;
 use thrift::protocol::*;
 use thrift::transport::*;
@@ -67,15 +68,33 @@ pub const HIVE_CATALOG: &str = "hive";
 pub struct HiveCatalog {
     /// address of hive meta store service
     client_address: String,
+    /// user name reported to hive meta store, `set_ugi` is skipped if not set
+    client_user: Option<String>,
+    /// storage holding the warehouse, the default storage is used if not set
+    warehouse: Option<StorageParams>,
 }
 
 impl HiveCatalog {
     pub fn try_create(hms_address: impl Into<String>) -> Result<HiveCatalog> {
         Ok(HiveCatalog {
             client_address: hms_address.into(),
+            client_user: None,
+            warehouse: None,
         })
     }
 
+    pub fn with_user(mut self, user: impl Into<String>) -> Self {
+        self.client_user = Some(user.into());
+        self
+    }
+
+    /// Locations of hive tables are resolved in the storage of the warehouse,
+    /// e.g. `hdfs://namenode:8020/user/hive/warehouse/db.db/t` is read as `/user/hive/warehouse/db.db/t`.
+    pub fn with_warehouse(mut self, warehouse: StorageParams) -> Self {
+        self.warehouse = Some(warehouse.map_root(|_| "/".to_string()));
+        self
+    }
+
     pub fn get_client(&self) -> Result<impl TThriftHiveMetastoreSyncClient> {
         let mut c = TTcpChannel::new();
         c.open(self.client_address.as_str())
@@ -85,7 +104,13 @@ impl HiveCatalog {
         let o_tran = TBufferedWriteTransport::new(o_chan);
         let i_prot = TBinaryInputProtocol::new(i_tran, true);
         let o_prot = TBinaryOutputProtocol::new(o_tran, true);
-        Ok(ThriftHiveMetastoreSyncClient::new(i_prot, o_prot))
+        let mut client = ThriftHiveMetastoreSyncClient::new(i_prot, o_prot);
+        if let Some(user) = &self.client_user {
+            client
+                .set_ugi(user.clone(), vec![])
+                .map_err(from_thrift_error)?;
+        }
+        Ok(client)
     }
 
     pub async fn get_partitions(
@@ -151,11 +176,11 @@ impl HiveCatalog {
     }
 
     fn do_get_table(
-        client: impl TThriftHiveMetastoreSyncClient,
+        client: &mut impl TThriftHiveMetastoreSyncClient,
         db_name: String,
         table_name: String,
+        warehouse: Option<StorageParams>,
     ) -> Result<Arc<dyn Table>> {
-        let mut client = client;
         let table = client.get_table(db_name.clone(), table_name.clone());
         let table_meta = match table {
             Ok(table_meta) => table_meta,
@@ -193,11 +218,49 @@ impl HiveCatalog {
         let fields = client
             .get_schema(db_name, table_name)
             .map_err(from_thrift_error)?;
-        let table_info: TableInfo = super::converters::try_into_table_info(table_meta, fields)?;
+        let mut table_info: TableInfo = super::converters::try_into_table_info(table_meta, fields)?;
+        table_info.meta.storage_params = warehouse;
         let res: Arc<dyn Table> = Arc::new(HiveTable::try_create(table_info)?);
         Ok(res)
     }
 
+    fn do_list_tables(
+        client: impl TThriftHiveMetastoreSyncClient,
+        db_name: String,
+        warehouse: Option<StorageParams>,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        let mut client = client;
+        let table_names = client
+            .get_all_tables(db_name.clone())
+            .map_err(from_thrift_error)?;
+
+        let mut tables = Vec::with_capacity(table_names.len());
+        for table_name in table_names {
+            match Self::do_get_table(&mut client, db_name.clone(), table_name, warehouse.clone()) {
+                Ok(table) => tables.push(table),
+                // Views and tables in unsupported formats are not listed.
+                Err(e) if e.code() == ErrorCode::UNIMPLEMENTED => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(tables)
+    }
+
+    fn do_list_databases(
+        client: impl TThriftHiveMetastoreSyncClient,
+    ) -> Result<Vec<Arc<dyn Database>>> {
+        let mut client = client;
+        let db_names = client.get_all_databases().map_err(from_thrift_error)?;
+
+        let mut databases = Vec::with_capacity(db_names.len());
+        for db_name in db_names {
+            let thrift_db_meta = client.get_database(db_name).map_err(from_thrift_error)?;
+            let hive_database: HiveDatabase = thrift_db_meta.into();
+            databases.push(Arc::new(hive_database) as Arc<dyn Database>);
+        }
+        Ok(databases)
+    }
+
     fn do_get_database(
         client: impl TThriftHiveMetastoreSyncClient,
         db_name: String,
@@ -232,7 +295,10 @@ impl Catalog for HiveCatalog {
 
     // Get all the databases.
     async fn list_databases(&self, _tenant: &str) -> Result<Vec<Arc<dyn Database>>> {
-        todo!()
+        let client = self.get_client()?;
+        tokio::task::spawn_blocking(move || Self::do_list_databases(client))
+            .await
+            .unwrap()
     }
 
     // Operation with database.
@@ -261,7 +327,11 @@ impl Catalog for HiveCatalog {
     }
 
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
-        let res: Arc<dyn Table> = Arc::new(HiveTable::try_create(table_info.clone())?);
+        let mut table_info = table_info.clone();
+        if table_info.meta.storage_params.is_none() {
+            table_info.meta.storage_params = self.warehouse.clone();
+        }
+        let res: Arc<dyn Table> = Arc::new(HiveTable::try_create(table_info)?);
         Ok(res)
     }
 
@@ -282,16 +352,24 @@ impl Catalog for HiveCatalog {
         db_name: &str,
         table_name: &str,
     ) -> Result<Arc<dyn Table>> {
-        let client = self.get_client()?;
+        let mut client = self.get_client()?;
         let db_name = db_name.to_string();
         let table_name = table_name.to_string();
-        tokio::task::spawn_blocking(move || Self::do_get_table(client, db_name, table_name))
-            .await
-            .unwrap()
+        let warehouse = self.warehouse.clone();
+        tokio::task::spawn_blocking(move || {
+            Self::do_get_table(&mut client, db_name, table_name, warehouse)
+        })
+        .await
+        .unwrap()
     }
 
-    async fn list_tables(&self, _tenant: &str, _db_name: &str) -> Result<Vec<Arc<dyn Table>>> {
-        todo!()
+    async fn list_tables(&self, _tenant: &str, db_name: &str) -> Result<Vec<Arc<dyn Table>>> {
+        let client = self.get_client()?;
+        let db_name = db_name.to_string();
+        let warehouse = self.warehouse.clone();
+        tokio::task::spawn_blocking(move || Self::do_list_tables(client, db_name, warehouse))
+            .await
+            .unwrap()
     }
 
     async fn list_tables_history(
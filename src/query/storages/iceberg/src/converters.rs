@@ -28,17 +28,15 @@ use iceberg_rs::model::schema::AllType;
 use iceberg_rs::model::schema::List as IcebergList;
 use iceberg_rs::model::schema::SchemaV2;
 use iceberg_rs::model::schema::StructField;
-use iceberg_rs::model::table::TableMetadata;
 use itertools::Itertools;
 
 /// generate TableMeta from Iceberg table meta
 pub(crate) fn meta_iceberg_to_databend(
     catalog: &str,
     storage_params: &StorageParams,
-    meta: &TableMetadata,
+    schema: Option<&SchemaV2>,
 ) -> TableMeta {
-    let meta = meta.clone().to_latest();
-    let schema = match meta.schemas.last() {
+    let schema = match schema {
         Some(scm) => schema_iceberg_to_databend(scm),
         // empty schema
         None => TableSchema::empty(),
@@ -84,7 +82,7 @@ fn primitive_iceberg_to_databend(prim: &AllType) -> TableDataType {
         iceberg_rs::model::schema::AllType::Primitive(p) => match p {
             iceberg_rs::model::schema::PrimitiveType::Boolean => TableDataType::Boolean,
             iceberg_rs::model::schema::PrimitiveType::Int => {
-                TableDataType::Number(NumberDataType::UInt32)
+                TableDataType::Number(NumberDataType::Int32)
             }
             iceberg_rs::model::schema::PrimitiveType::Long => {
                 TableDataType::Number(NumberDataType::Int64)
@@ -164,6 +162,7 @@ mod convert_test {
     use iceberg_rs::model::table::TableMetadata;
 
     use super::meta_iceberg_to_databend;
+    use crate::meta_reader::snapshot_schema;
 
     /// example metadata file
     const METADATA_FILE: &str = r#"
@@ -229,7 +228,11 @@ mod convert_test {
             root: "/".to_string(),
         });
 
-        let converted = meta_iceberg_to_databend("ctl", &mock_sp, &metadata);
+        let metadata = metadata.to_latest();
+        let schema = snapshot_schema(&metadata, None);
+        assert!(schema.is_some());
+
+        let converted = meta_iceberg_to_databend("ctl", &mock_sp, schema);
 
         assert_eq!(converted.engine, "iceberg");
         assert_eq!(converted.catalog, "ctl");
@@ -94,13 +94,18 @@ mod converters;
 /// database implementation
 mod database;
 /// reading manifestlist and manifest files
-#[allow(unused)]
 mod manifest;
 /// table metadata reader
-#[allow(unused)]
 mod meta_reader;
+/// partitions of data files to read
+mod partition;
+/// pruning data files with manifests
+mod pruning;
 /// table implementation
 mod table;
+/// source reading data files
+mod table_source;
 
 pub use catalog::IcebergCatalog;
 pub use catalog::ICEBERG_CATALOG;
+pub use table::IcebergTable;
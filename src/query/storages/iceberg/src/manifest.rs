@@ -12,56 +12,260 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Readers of the Avro encoded manifest list and manifest files.
+//!
+//! Only the fields needed for planning a scan are decoded,
+//! field names and meanings follow the Iceberg table spec (format version 2).
+
 use std::collections::HashMap;
 
-use serde::Deserialize;
+use apache_avro::types::Value;
+use apache_avro::Reader;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+
+/// type of files tracked by a manifest
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ManifestContent {
+    Data,
+    Deletes,
+}
 
 /// item in manifest list file
 /// read manifest file by this struct
-#[derive(Clone, Debug, Deserialize)]
-pub(crate) struct ManifestPtr {
+#[derive(Clone, Debug)]
+pub(crate) struct ManifestFile {
     pub manifest_path: String,
-    pub manifest_length: usize,
-    pub partition_spec_id: usize,
-    pub added_snapshot_id: usize,
-    pub existing_data_files_count: usize,
-    pub deleted_data_files_count: usize,
-    pub partitions: Vec<ManiPart>,
-    pub added_rows_count: usize,
-    pub existing_rows_count: usize,
-    pub deleted_rows_count: usize,
-}
-
-/// item of manifest spec in `ManifestPtr`
-#[derive(Clone, Debug, Deserialize)]
-pub(crate) struct ManiPart {
+    pub partition_spec_id: i32,
+    pub content: ManifestContent,
+    /// sequence number inherited by the entries added in this manifest
+    pub sequence_number: i64,
+    /// summaries of the partition fields, in the order of the partition spec
+    pub partitions: Vec<FieldSummary>,
+}
+
+/// item of manifest spec in `ManifestFile`
+#[derive(Clone, Debug)]
+pub(crate) struct FieldSummary {
     pub contains_null: bool,
-    pub lower_bound: String,
-    pub upper_bound: String,
+    pub lower_bound: Option<Vec<u8>>,
+    pub upper_bound: Option<Vec<u8>>,
 }
 
-/// manifest file
-#[derive(Clone, Debug, Deserialize)]
-pub(crate) struct Manifest {
-    pub status: isize,
-    pub snapshot_id: i64,
+/// status of an entry in manifest file
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum EntryStatus {
+    Existing,
+    Added,
+    Deleted,
+}
+
+/// item in manifest file
+#[derive(Clone, Debug)]
+pub(crate) struct ManifestEntry {
+    pub status: EntryStatus,
+    /// data sequence number, `None` if inherited from the manifest
+    pub sequence_number: Option<i64>,
     pub data_file: DataFile,
 }
 
+/// type of content stored in a data file
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DataContent {
+    Data,
+    PositionDeletes,
+    EqualityDeletes,
+}
+
 /// data file
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug)]
 pub(crate) struct DataFile {
+    pub content: DataContent,
     pub file_path: String,
     pub file_format: String,
-    pub partition: HashMap<String, u64>,
-    pub record_count: usize,
-    pub file_size_in_bytes: usize,
-    pub block_size_in_bytes: usize,
-    pub column_sizes: HashMap<u64, u64>,
-    pub value_counts: HashMap<u64, u64>,
-    pub null_value_counts: HashMap<u64, u64>,
-    pub lower_bounds: HashMap<u64, String>,
-    pub upper_bounds: HashMap<u64, String>,
-    pub key_metadata: Option<String>,
-    pub split_offsets: Vec<u64>,
+    /// partition values, in the order of the partition spec
+    pub partition: Vec<Value>,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    pub null_value_counts: HashMap<i32, i64>,
+    pub lower_bounds: HashMap<i32, Vec<u8>>,
+    pub upper_bounds: HashMap<i32, Vec<u8>>,
+}
+
+/// read all items of a manifest list file
+pub(crate) async fn read_manifest_list(op: &Operator, path: &str) -> Result<Vec<ManifestFile>> {
+    read_avro_records(op, path)
+        .await?
+        .into_iter()
+        .map(|record| {
+            let mut fields = record_fields(record, path)?;
+            let content = match take(&mut fields, "content") {
+                Value::Null => ManifestContent::Data,
+                v => match as_long(v) {
+                    Some(0) => ManifestContent::Data,
+                    Some(1) => ManifestContent::Deletes,
+                    c => return Err(invalid(path, format!("unknown manifest content {c:?}"))),
+                },
+            };
+            let partitions = match take(&mut fields, "partitions") {
+                Value::Array(summaries) => summaries
+                    .into_iter()
+                    .map(|summary| {
+                        let mut summary = record_fields(summary, path)?;
+                        Ok(FieldSummary {
+                            contains_null: as_bool(take(&mut summary, "contains_null"))
+                                .unwrap_or(true),
+                            lower_bound: as_bytes(take(&mut summary, "lower_bound")),
+                            upper_bound: as_bytes(take(&mut summary, "upper_bound")),
+                        })
+                    })
+                    .collect::<Result<_>>()?,
+                _ => vec![],
+            };
+            Ok(ManifestFile {
+                manifest_path: as_string(take(&mut fields, "manifest_path"))
+                    .ok_or_else(|| invalid(path, "missing manifest_path"))?,
+                partition_spec_id: as_long(take(&mut fields, "partition_spec_id")).unwrap_or(0)
+                    as i32,
+                content,
+                sequence_number: as_long(take(&mut fields, "sequence_number")).unwrap_or(0),
+                partitions,
+            })
+        })
+        .collect()
+}
+
+/// read all entries of a manifest file
+pub(crate) async fn read_manifest(op: &Operator, path: &str) -> Result<Vec<ManifestEntry>> {
+    read_avro_records(op, path)
+        .await?
+        .into_iter()
+        .map(|record| {
+            let mut fields = record_fields(record, path)?;
+            let status = match as_long(take(&mut fields, "status")) {
+                Some(0) => EntryStatus::Existing,
+                Some(1) => EntryStatus::Added,
+                Some(2) => EntryStatus::Deleted,
+                s => return Err(invalid(path, format!("unknown entry status {s:?}"))),
+            };
+            let sequence_number = as_long(take(&mut fields, "sequence_number"));
+            let data_file = read_data_file(take(&mut fields, "data_file"), path)?;
+            Ok(ManifestEntry {
+                status,
+                sequence_number,
+                data_file,
+            })
+        })
+        .collect()
+}
+
+fn read_data_file(value: Value, path: &str) -> Result<DataFile> {
+    let mut fields = record_fields(value, path)?;
+    let content = match as_long(take(&mut fields, "content")) {
+        None | Some(0) => DataContent::Data,
+        Some(1) => DataContent::PositionDeletes,
+        Some(2) => DataContent::EqualityDeletes,
+        Some(c) => return Err(invalid(path, format!("unknown data file content {c}"))),
+    };
+    let partition = match unwrap_union(take(&mut fields, "partition")) {
+        Value::Record(values) => values.into_iter().map(|(_, v)| unwrap_union(v)).collect(),
+        _ => vec![],
+    };
+
+    Ok(DataFile {
+        content,
+        file_path: as_string(take(&mut fields, "file_path"))
+            .ok_or_else(|| invalid(path, "missing file_path"))?,
+        file_format: as_string(take(&mut fields, "file_format"))
+            .ok_or_else(|| invalid(path, "missing file_format"))?,
+        partition,
+        record_count: as_long(take(&mut fields, "record_count")).unwrap_or(0),
+        file_size_in_bytes: as_long(take(&mut fields, "file_size_in_bytes")).unwrap_or(0),
+        null_value_counts: as_int_map(take(&mut fields, "null_value_counts"), as_long),
+        lower_bounds: as_int_map(take(&mut fields, "lower_bounds"), as_bytes),
+        upper_bounds: as_int_map(take(&mut fields, "upper_bounds"), as_bytes),
+    })
+}
+
+async fn read_avro_records(op: &Operator, path: &str) -> Result<Vec<Value>> {
+    let bytes = op
+        .read(path)
+        .await
+        .map_err(|e| invalid(path, format!("{e:?}")))?;
+    let reader = Reader::new(bytes.as_slice()).map_err(|e| invalid(path, format!("{e:?}")))?;
+    reader
+        .map(|v| v.map_err(|e| invalid(path, format!("{e:?}"))))
+        .collect()
+}
+
+fn invalid(path: &str, msg: impl AsRef<str>) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("invalid manifest in {}: {}", path, msg.as_ref()))
+}
+
+fn record_fields(value: Value, path: &str) -> Result<HashMap<String, Value>> {
+    match unwrap_union(value) {
+        Value::Record(fields) => Ok(fields.into_iter().collect()),
+        v => Err(invalid(path, format!("expect a record, got {v:?}"))),
+    }
+}
+
+fn take(fields: &mut HashMap<String, Value>, name: &str) -> Value {
+    fields.remove(name).map(unwrap_union).unwrap_or(Value::Null)
+}
+
+fn unwrap_union(value: Value) -> Value {
+    match value {
+        Value::Union(_, v) => *v,
+        v => v,
+    }
+}
+
+fn as_bool(value: Value) -> Option<bool> {
+    match value {
+        Value::Boolean(b) => Some(b),
+        _ => None,
+    }
+}
+
+fn as_long(value: Value) -> Option<i64> {
+    match value {
+        Value::Int(i) => Some(i as i64),
+        Value::Long(l) => Some(l),
+        _ => None,
+    }
+}
+
+fn as_string(value: Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s),
+        _ => None,
+    }
+}
+
+fn as_bytes(value: Value) -> Option<Vec<u8>> {
+    match value {
+        Value::Bytes(b) | Value::Fixed(_, b) => Some(b),
+        _ => None,
+    }
+}
+
+/// maps keyed by field id are stored as arrays of key-value records
+fn as_int_map<T>(value: Value, f: fn(Value) -> Option<T>) -> HashMap<i32, T> {
+    let items = match value {
+        Value::Array(items) => items,
+        _ => return HashMap::new(),
+    };
+    items
+        .into_iter()
+        .filter_map(|item| match unwrap_union(item) {
+            Value::Record(kv) => {
+                let mut kv: HashMap<String, Value> = kv.into_iter().collect();
+                let key = as_long(take(&mut kv, "key"))?;
+                let value = f(take(&mut kv, "value"))?;
+                Some((key as i32, value))
+            }
+            _ => None,
+        })
+        .collect()
 }
@@ -13,3 +13,31 @@
 // limitations under the License.
 
 //! this module contains metadata reader utilities for table metadata
+
+use iceberg_rs::model::schema::SchemaV2;
+use iceberg_rs::model::snapshot::SnapshotV2;
+use iceberg_rs::model::table::TableMetadataV2;
+
+/// find a snapshot of the table by id
+pub(crate) fn find_snapshot(meta: &TableMetadataV2, snapshot_id: i64) -> Option<&SnapshotV2> {
+    meta.snapshots
+        .as_ref()?
+        .iter()
+        .find(|s| s.snapshot_id == snapshot_id)
+}
+
+/// the schema to read a snapshot with
+///
+/// a snapshot is read with the schema it was written with if recorded,
+/// otherwise, and for tables without snapshot, with the current schema.
+pub(crate) fn snapshot_schema(
+    meta: &TableMetadataV2,
+    snapshot_id: Option<i64>,
+) -> Option<&SchemaV2> {
+    let schema_id = snapshot_id
+        .and_then(|id| find_snapshot(meta, id))
+        .and_then(|s| s.schema_id)
+        .map(|id| id as i32)
+        .unwrap_or(meta.current_schema_id);
+    meta.schemas.iter().find(|s| s.schema_id == schema_id)
+}
@@ -0,0 +1,219 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! pruning data files with partition values and column bounds recorded in manifests
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use apache_avro::types::Value;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::NumberScalar;
+use common_expression::ColumnId;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use iceberg_rs::model::partition::PartitionSpec;
+use iceberg_rs::model::partition::Transform;
+use iceberg_rs::model::schema::AllType;
+use iceberg_rs::model::schema::PrimitiveType;
+use iceberg_rs::model::schema::SchemaV2;
+use itertools::Itertools;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::manifest::DataFile;
+use crate::manifest::ManifestFile;
+
+pub(crate) struct IcebergPruner {
+    /// column id and iceberg type of the top-level primitive fields, by field id
+    columns: HashMap<i32, (ColumnId, PrimitiveType)>,
+    /// source field id of each identity partition field, by partition spec id
+    identity_sources: HashMap<i32, Vec<Option<i32>>>,
+    range_pruner: Arc<dyn RangePruner + Send + Sync>,
+}
+
+impl IcebergPruner {
+    /// `schema` must be converted from `iceberg_schema`
+    pub fn try_create(
+        ctx: &Arc<dyn TableContext>,
+        schema: &TableSchemaRef,
+        iceberg_schema: &SchemaV2,
+        partition_specs: &[PartitionSpec],
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Self> {
+        let filter = push_downs
+            .as_ref()
+            .and_then(|p| p.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+        let range_pruner =
+            RangePrunerCreator::try_create(ctx.get_function_context()?, schema, filter.as_ref())?;
+
+        let columns = iceberg_schema
+            .struct_fields
+            .fields
+            .iter()
+            .sorted_by_key(|f| f.id)
+            .zip(schema.fields())
+            .filter_map(|(f, field)| match &f.field_type {
+                AllType::Primitive(p) => Some((f.id, (field.column_id(), p.clone()))),
+                _ => None,
+            })
+            .collect();
+
+        let identity_sources = partition_specs
+            .iter()
+            .map(|spec| {
+                let sources = spec
+                    .fields
+                    .iter()
+                    .map(|f| matches!(f.transform, Transform::Identity).then_some(f.source_id))
+                    .collect();
+                (spec.spec_id, sources)
+            })
+            .collect();
+
+        Ok(Self {
+            columns,
+            identity_sources,
+            range_pruner,
+        })
+    }
+
+    /// prune a whole manifest with the partition summaries in the manifest list
+    pub fn should_keep_manifest(&self, manifest: &ManifestFile) -> bool {
+        let sources = match self.identity_sources.get(&manifest.partition_spec_id) {
+            Some(sources) => sources,
+            None => return true,
+        };
+
+        let mut stats = StatisticsOfColumns::new();
+        for (source, summary) in sources.iter().zip(&manifest.partitions) {
+            if summary.contains_null {
+                continue;
+            }
+            if let Some((column_id, ty)) = source.and_then(|s| self.columns.get(&s)) {
+                let min = summary
+                    .lower_bound
+                    .as_ref()
+                    .and_then(|b| decode_bound(ty, b));
+                let max = summary
+                    .upper_bound
+                    .as_ref()
+                    .and_then(|b| decode_bound(ty, b));
+                if let (Some(min), Some(max)) = (min, max) {
+                    stats.insert(*column_id, column_statistics(min, max, 0));
+                }
+            }
+        }
+        self.range_pruner.should_keep(&stats)
+    }
+
+    /// prune a data file with its partition values and column bounds
+    pub fn should_keep_file(&self, partition_spec_id: i32, file: &DataFile) -> bool {
+        let mut stats = StatisticsOfColumns::new();
+        for (field_id, (column_id, ty)) in &self.columns {
+            let min = file
+                .lower_bounds
+                .get(field_id)
+                .and_then(|b| decode_bound(ty, b));
+            let max = file
+                .upper_bounds
+                .get(field_id)
+                .and_then(|b| decode_bound(ty, b));
+            let null_count = file.null_value_counts.get(field_id);
+            if let (Some(min), Some(max), Some(null_count)) = (min, max, null_count) {
+                stats.insert(*column_id, column_statistics(min, max, *null_count as u64));
+            }
+        }
+
+        // values of identity partitions are exact, they take precedence over bounds
+        if let Some(sources) = self.identity_sources.get(&partition_spec_id) {
+            for (source, value) in sources.iter().zip(&file.partition) {
+                if let Some((column_id, ty)) = source.and_then(|s| self.columns.get(&s)) {
+                    if let Some(v) = partition_value(ty, value) {
+                        stats.insert(*column_id, column_statistics(v.clone(), v, 0));
+                    }
+                }
+            }
+        }
+        self.range_pruner.should_keep(&stats)
+    }
+}
+
+fn column_statistics(min: Scalar, max: Scalar, null_count: u64) -> ColumnStatistics {
+    ColumnStatistics {
+        min,
+        max,
+        null_count,
+        in_memory_size: 0,
+        distinct_of_values: None,
+    }
+}
+
+/// decode a bound in Iceberg's single-value binary serialization
+///
+/// types without a lossless mapping to the converted column type are skipped
+fn decode_bound(ty: &PrimitiveType, bytes: &[u8]) -> Option<Scalar> {
+    let scalar = match ty {
+        PrimitiveType::Boolean => Scalar::Boolean(*bytes.first()? != 0),
+        PrimitiveType::Int => Scalar::Number(NumberScalar::Int32(i32::from_le_bytes(
+            bytes.try_into().ok()?,
+        ))),
+        PrimitiveType::Long => Scalar::Number(NumberScalar::Int64(i64::from_le_bytes(
+            bytes.try_into().ok()?,
+        ))),
+        PrimitiveType::Float => Scalar::Number(NumberScalar::Float32(F32::from(
+            f32::from_le_bytes(bytes.try_into().ok()?),
+        ))),
+        PrimitiveType::Double => Scalar::Number(NumberScalar::Float64(F64::from(
+            f64::from_le_bytes(bytes.try_into().ok()?),
+        ))),
+        PrimitiveType::Date => Scalar::Date(i32::from_le_bytes(bytes.try_into().ok()?)),
+        PrimitiveType::Timestamp | PrimitiveType::Timestampz => {
+            Scalar::Timestamp(i64::from_le_bytes(bytes.try_into().ok()?))
+        }
+        PrimitiveType::String => Scalar::String(bytes.to_vec()),
+        _ => return None,
+    };
+    Some(scalar)
+}
+
+/// convert a partition value of an identity partition field
+fn partition_value(ty: &PrimitiveType, value: &Value) -> Option<Scalar> {
+    let scalar = match (ty, value) {
+        (PrimitiveType::Boolean, Value::Boolean(v)) => Scalar::Boolean(*v),
+        (PrimitiveType::Int, Value::Int(v)) => Scalar::Number(NumberScalar::Int32(*v)),
+        (PrimitiveType::Long, Value::Long(v)) => Scalar::Number(NumberScalar::Int64(*v)),
+        (PrimitiveType::Float, Value::Float(v)) => {
+            Scalar::Number(NumberScalar::Float32(F32::from(*v)))
+        }
+        (PrimitiveType::Double, Value::Double(v)) => {
+            Scalar::Number(NumberScalar::Float64(F64::from(*v)))
+        }
+        (PrimitiveType::Date, Value::Date(v) | Value::Int(v)) => Scalar::Date(*v),
+        (
+            PrimitiveType::Timestamp | PrimitiveType::Timestampz,
+            Value::TimestampMicros(v) | Value::Long(v),
+        ) => Scalar::Timestamp(*v),
+        (PrimitiveType::String, Value::String(v)) => Scalar::String(v.as_bytes().to_vec()),
+        _ => return None,
+    };
+    Some(scalar)
+}
@@ -22,36 +22,60 @@ use std::any::Any;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::IcebergTableInfo;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataSchema;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::storage::StorageParams;
+use common_pipeline_core::Pipeline;
+use common_storage::init_operator;
 use common_storage::DataOperator;
 use futures::StreamExt;
 use iceberg_rs::model::table::TableMetadata;
 use opendal::Operator;
 
 use crate::converters::meta_iceberg_to_databend;
+use crate::manifest::read_manifest;
+use crate::manifest::read_manifest_list;
+use crate::manifest::DataContent;
+use crate::manifest::DataFile;
+use crate::manifest::EntryStatus;
+use crate::manifest::ManifestContent;
+use crate::meta_reader::find_snapshot;
+use crate::meta_reader::snapshot_schema;
+use crate::partition::IcebergPartInfo;
+use crate::pruning::IcebergPruner;
+use crate::table_source::IcebergTableSource;
 
 /// file marking the current version of metadata file
 const META_PTR: &str = "metadata/version_hint.text";
 
+/// field id of the `file_path` column in position delete files
+const DELETE_FILE_PATH_FIELD_ID: i32 = 2147483546;
+
 /// accessor wrapper as a table
-#[allow(unused)]
 pub struct IcebergTable {
-    /// database that belongs to
-    database: String,
-    /// name of the current table
-    name: String,
     /// root of the table
-    tbl_root: DataOperator,
-    /// table metadata
-    manifests: TableMetadata,
+    op: Operator,
+    /// table metadata, only available on tables resolved from the catalog,
+    /// tables built from a plan only read the partitions of the plan.
+    metadata: Option<TableMetadata>,
+    /// the snapshot to read, `None` if the table has no snapshot
+    snapshot_id: Option<i64>,
+    /// iceberg field ids of the fields in the table schema
+    field_ids: Vec<i32>,
     /// table information
     info: TableInfo,
 }
@@ -82,27 +106,69 @@ impl IcebergTable {
                 ))
             })?;
 
-        let sp = tbl_root.params();
-
-        // construct table info
         let info = TableInfo {
             ident: TableIdent::new(0, 0),
             desc: format!("IcebergTable: '{database}'.'{table_name}'"),
             name: table_name.to_string(),
-            meta: meta_iceberg_to_databend(catalog, &sp, &metadata),
+            meta: TableMeta {
+                catalog: catalog.to_string(),
+                storage_params: Some(tbl_root.params()),
+                ..Default::default()
+            },
             ..Default::default()
         };
+        let snapshot_id = metadata.clone().to_latest().current_snapshot_id;
+        Self::try_create(op, metadata, snapshot_id, info)
+    }
+
+    /// create a table reading the given snapshot
+    ///
+    /// the meta of `info` is regenerated from the schema of the snapshot
+    fn try_create(
+        op: Operator,
+        metadata: TableMetadata,
+        snapshot_id: Option<i64>,
+        mut info: TableInfo,
+    ) -> Result<IcebergTable> {
+        let sp = storage_params(&info)?.clone();
+        let latest = metadata.clone().to_latest();
+        let schema = snapshot_schema(&latest, snapshot_id);
+        let mut field_ids = schema
+            .map(|s| {
+                s.struct_fields
+                    .fields
+                    .iter()
+                    .map(|f| f.id)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        // same order as the fields converted to table schema
+        field_ids.sort_unstable();
+        info.meta = meta_iceberg_to_databend(&info.meta.catalog, &sp, schema);
 
         // finish making table
         Ok(Self {
-            database: database.to_string(),
-            name: table_name.to_string(),
-            tbl_root,
-            manifests: metadata,
+            op,
+            metadata: Some(metadata),
+            snapshot_id,
+            field_ids,
             info,
         })
     }
 
+    /// create a table from the information in a plan, it can only read data
+    pub fn from_info(info: &IcebergTableInfo) -> Result<Arc<dyn Table>> {
+        let op = init_operator(storage_params(&info.table_info)?)?;
+
+        Ok(Arc::new(IcebergTable {
+            op,
+            metadata: None,
+            snapshot_id: info.snapshot_id,
+            field_ids: info.field_ids.clone(),
+            info: info.table_info.clone(),
+        }))
+    }
+
     /// version_detect figures out the manifest list version of the table
     /// and gives the relative path from table root directory
     /// to latest metadata json file
@@ -143,6 +209,165 @@ impl IcebergTable {
             .map(|s| format!("metadata/{s}"))
             .ok_or_else(|| ErrorCode::ReadTableDataError("Cannot get the latest manifest file"))
     }
+
+    fn metadata(&self) -> Result<&TableMetadata> {
+        self.metadata.as_ref().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "metadata of iceberg table {} is not loaded",
+                self.info.name
+            ))
+        })
+    }
+
+    async fn do_read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let metadata = self.metadata()?;
+        if !matches!(metadata, TableMetadata::V2(_)) {
+            return Err(ErrorCode::Unimplemented(format!(
+                "iceberg table {} is of format version 1, only format version 2 is supported",
+                self.info.name
+            )));
+        }
+        let meta = metadata.clone().to_latest();
+
+        let snapshot = match self.snapshot_id {
+            // nothing has been written to the table
+            None => return Ok((PartStatistics::default(), Partitions::default())),
+            Some(id) => find_snapshot(&meta, id).ok_or_else(|| {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "snapshot {} of iceberg table {} not found",
+                    id, self.info.name
+                ))
+            })?,
+        };
+        let schema = snapshot_schema(&meta, self.snapshot_id).ok_or_else(|| {
+            ErrorCode::ReadTableDataError(format!(
+                "schema of snapshot {} of iceberg table {} not found",
+                snapshot.snapshot_id, self.info.name
+            ))
+        })?;
+        let pruner = IcebergPruner::try_create(
+            &ctx,
+            &self.info.schema(),
+            schema,
+            &meta.partition_specs,
+            &push_downs,
+        )?;
+
+        let manifest_list = relative_path(&meta.location, &snapshot.manifest_list);
+        let mut files_total = 0;
+        // (data sequence number, file)
+        let mut data_files = vec![];
+        let mut delete_files = vec![];
+        for manifest in read_manifest_list(&self.op, &manifest_list).await? {
+            if manifest.content == ManifestContent::Data && !pruner.should_keep_manifest(&manifest)
+            {
+                continue;
+            }
+
+            let manifest_path = relative_path(&meta.location, &manifest.manifest_path);
+            for entry in read_manifest(&self.op, &manifest_path).await? {
+                if entry.status == EntryStatus::Deleted {
+                    continue;
+                }
+                let file = entry.data_file;
+                // entries added by the snapshot of the manifest inherit its sequence number
+                let sequence_number = entry.sequence_number.unwrap_or(manifest.sequence_number);
+                match file.content {
+                    DataContent::Data => {
+                        files_total += 1;
+                        if pruner.should_keep_file(manifest.partition_spec_id, &file) {
+                            self.check_file_format(&file)?;
+                            data_files.push((sequence_number, file));
+                        }
+                    }
+                    DataContent::PositionDeletes => {
+                        self.check_file_format(&file)?;
+                        delete_files.push((sequence_number, file));
+                    }
+                    DataContent::EqualityDeletes => {
+                        return Err(ErrorCode::Unimplemented(format!(
+                            "iceberg table {} has equality delete file {}, which is not supported yet",
+                            self.info.name, file.file_path
+                        )));
+                    }
+                }
+            }
+        }
+
+        let mut read_rows = 0;
+        let mut read_bytes = 0;
+        let parts = data_files
+            .into_iter()
+            .map(|(sequence_number, file)| {
+                // position deletes apply to data files of the same or older sequence number
+                let position_deletes = delete_files
+                    .iter()
+                    .filter(|(delete_sequence_number, delete)| {
+                        *delete_sequence_number >= sequence_number
+                            && may_reference(delete, &file.file_path)
+                    })
+                    .map(|(_, delete)| relative_path(&meta.location, &delete.file_path))
+                    .collect();
+                read_rows += file.record_count as usize;
+                read_bytes += file.file_size_in_bytes as usize;
+
+                IcebergPartInfo {
+                    location: relative_path(&meta.location, &file.file_path),
+                    file_path: file.file_path,
+                    record_count: file.record_count as u64,
+                    file_size: file.file_size_in_bytes as u64,
+                    position_deletes,
+                }
+                .convert_to_part_info()
+            })
+            .collect::<Vec<_>>();
+
+        let statistics =
+            PartStatistics::new_estimated(read_rows, read_bytes, parts.len(), files_total);
+        Ok((
+            statistics,
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    fn check_file_format(&self, file: &DataFile) -> Result<()> {
+        if file.file_format.eq_ignore_ascii_case("parquet") {
+            return Ok(());
+        }
+        Err(ErrorCode::Unimplemented(format!(
+            "file {} of iceberg table {} is in {} format, only parquet files are supported",
+            file.file_path, self.info.name, file.file_format
+        )))
+    }
+}
+
+fn storage_params(info: &TableInfo) -> Result<&StorageParams> {
+    info.meta.storage_params.as_ref().ok_or_else(|| {
+        ErrorCode::Internal(format!("iceberg table {} has no storage params", info.name))
+    })
+}
+
+/// paths in metadata are absolute, the operator is on the table root
+fn relative_path(table_location: &str, path: &str) -> String {
+    match path.strip_prefix(table_location.trim_end_matches('/')) {
+        Some(p) => p.trim_start_matches('/').to_string(),
+        None => path.to_string(),
+    }
+}
+
+/// whether a position delete file may reference the data file,
+/// files only referencing one data file have equal bounds on `file_path`.
+fn may_reference(delete: &DataFile, data_file_path: &str) -> bool {
+    let lower = delete.lower_bounds.get(&DELETE_FILE_PATH_FIELD_ID);
+    let upper = delete.upper_bounds.get(&DELETE_FILE_PATH_FIELD_ID);
+    match (lower, upper) {
+        (Some(lower), Some(upper)) if lower == upper => lower == data_file_path.as_bytes(),
+        _ => true,
+    }
 }
 
 #[async_trait]
@@ -163,11 +388,104 @@ impl Table for IcebergTable {
         &self.get_table_info().name
     }
 
+    fn get_data_source_info(&self) -> DataSourceInfo {
+        DataSourceInfo::IcebergSource(IcebergTableInfo {
+            table_info: self.info.clone(),
+            snapshot_id: self.snapshot_id,
+            field_ids: self.field_ids.clone(),
+        })
+    }
+
     async fn read_partitions(
         &self,
-        _ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
-        todo!()
+        self.do_read_partitions(ctx, push_downs).await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let output_schema = Arc::new(DataSchema::from(plan.schema()));
+
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_io_requests = std::cmp::max(
+            max_threads,
+            ctx.get_settings().get_max_storage_io_requests()? as usize,
+        );
+        pipeline.add_source(
+            |output| {
+                IcebergTableSource::create(
+                    ctx.clone(),
+                    output,
+                    self.op.clone(),
+                    output_schema.clone(),
+                    self.field_ids.clone(),
+                )
+            },
+            max_io_requests,
+        )?;
+        pipeline.resize(std::cmp::min(max_threads, max_io_requests))
+    }
+
+    async fn navigate_to(&self, instant: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let metadata = self.metadata()?;
+        let meta = metadata.clone().to_latest();
+        let snapshots = meta.snapshots.unwrap_or_default();
+        let snapshot = match instant {
+            NavigationPoint::SnapshotID(id) => {
+                let id = id.parse::<i64>().map_err(|_| {
+                    ErrorCode::TableHistoricalDataNotFound(format!(
+                        "invalid snapshot id {} of iceberg table {}",
+                        id, self.info.name
+                    ))
+                })?;
+                snapshots.iter().find(|s| s.snapshot_id == id)
+            }
+            NavigationPoint::TimePoint(time_point) => snapshots
+                .iter()
+                .filter(|s| s.timestamp_ms <= time_point.timestamp_millis())
+                .max_by_key(|s| s.timestamp_ms),
+        }
+        .ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "no snapshot of iceberg table {} found at {:?}",
+                self.info.name, instant
+            ))
+        })?;
+
+        let table = Self::try_create(
+            self.op.clone(),
+            metadata.clone(),
+            Some(snapshot.snapshot_id),
+            self.info.clone(),
+        )?;
+        Ok(Arc::new(table))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::relative_path;
+
+    #[test]
+    fn test_relative_path() {
+        let location = "s3://bkt/wh/db/tbl";
+        assert_eq!(
+            relative_path(location, "s3://bkt/wh/db/tbl/data/00000-0.parquet"),
+            "data/00000-0.parquet"
+        );
+        assert_eq!(
+            relative_path(
+                "s3://bkt/wh/db/tbl/",
+                "s3://bkt/wh/db/tbl/metadata/snap-1.avro"
+            ),
+            "metadata/snap-1.avro"
+        );
+        assert_eq!(relative_path(location, "data/1.parquet"), "data/1.parquet");
     }
 }
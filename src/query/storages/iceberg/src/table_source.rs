@@ -0,0 +1,249 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::read as pread;
+use common_arrow::parquet::read::read_metadata;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::Scalar;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use opendal::Operator;
+
+use crate::partition::IcebergPartInfo;
+
+/// reads the data files of an iceberg snapshot, one partition at a time
+pub struct IcebergTableSource {
+    ctx: Arc<dyn TableContext>,
+    op: Operator,
+    /// schema of the blocks this source generates
+    output_schema: DataSchemaRef,
+    /// iceberg field id of each column in `output_schema`
+    field_ids: Vec<i32>,
+    /// blocks read but not generated yet
+    blocks: VecDeque<DataBlock>,
+}
+
+impl IcebergTableSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        op: Operator,
+        output_schema: DataSchemaRef,
+        field_ids: Vec<i32>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, IcebergTableSource {
+            ctx,
+            op,
+            output_schema,
+            field_ids,
+            blocks: VecDeque::new(),
+        })
+    }
+
+    async fn read_data_file(&self, part: &IcebergPartInfo) -> Result<Vec<DataBlock>> {
+        let deleted = self.read_position_deletes(part).await?;
+
+        let data = self.op.read(&part.location).await?;
+        let mut reader = Cursor::new(data);
+        let meta = read_metadata(&mut reader)?;
+        let file_schema = pread::infer_schema(&meta)?;
+
+        // columns are matched by field id, files written without field ids
+        // fall back to matching by name
+        let field_ids: HashMap<&str, i32> = meta
+            .schema()
+            .fields()
+            .iter()
+            .filter_map(|f| {
+                let info = f.get_field_info();
+                info.id.map(|id| (info.name.as_str(), id))
+            })
+            .collect();
+        let mut projected = Vec::with_capacity(self.field_ids.len());
+        let mut defaults = Vec::with_capacity(self.field_ids.len());
+        for (field, field_id) in self.output_schema.fields().iter().zip(&self.field_ids) {
+            let found = file_schema.fields.iter().find(|f| {
+                if field_ids.is_empty() {
+                    &f.name == field.name()
+                } else {
+                    field_ids.get(f.name.as_str()) == Some(field_id)
+                }
+            });
+            match found {
+                Some(f) => {
+                    projected.push(f.clone());
+                    defaults.push(None);
+                }
+                // columns added after the file was written
+                None if field.is_nullable() => defaults.push(Some(Scalar::Null)),
+                None => {
+                    return Err(ErrorCode::ReadTableDataError(format!(
+                        "required column {} (field id {}) is missing in data file {}",
+                        field.name(),
+                        field_id,
+                        part.location
+                    )));
+                }
+            }
+        }
+
+        let mut blocks = vec![];
+        let mut row_offset = 0;
+        if projected.is_empty() {
+            let nulls = vec![Scalar::Null; defaults.len()];
+            for row_group in &meta.row_groups {
+                let num_rows = row_group.num_rows();
+                let block =
+                    DataBlock::create_with_default_value(&self.output_schema, &nulls, num_rows)?;
+                blocks.push(filter_deleted(block, row_offset, &deleted)?);
+                row_offset += num_rows as u64;
+            }
+        } else {
+            let chunks = pread::FileReader::new(
+                reader,
+                meta.row_groups,
+                ArrowSchema::from(projected),
+                None,
+                None,
+                None,
+            );
+            for chunk in chunks {
+                let chunk = chunk?;
+                let num_rows = chunk.len();
+                let block = DataBlock::create_with_default_value_and_chunk(
+                    &self.output_schema,
+                    &chunk,
+                    &defaults,
+                    num_rows,
+                )?;
+                blocks.push(filter_deleted(block, row_offset, &deleted)?);
+                row_offset += num_rows as u64;
+            }
+        }
+        Ok(blocks)
+    }
+
+    /// collect the sorted positions of the rows deleted from the data file
+    async fn read_position_deletes(&self, part: &IcebergPartInfo) -> Result<Vec<u64>> {
+        let mut deleted = vec![];
+        for location in &part.position_deletes {
+            let data = self.op.read(location).await?;
+            let mut reader = Cursor::new(data);
+            let meta = read_metadata(&mut reader)?;
+            let schema = pread::infer_schema(&meta)?;
+
+            // position delete files start with `file_path` and `pos`,
+            // the optional `row` column after them is not needed
+            let fields = schema.fields.into_iter().take(2).collect::<Vec<_>>();
+            if fields.len() != 2 {
+                return Err(ErrorCode::ReadTableDataError(format!(
+                    "invalid position delete file {location}"
+                )));
+            }
+            let data_types = [DataType::String, DataType::Number(NumberDataType::Int64)];
+            let chunks = pread::FileReader::new(
+                reader,
+                meta.row_groups,
+                ArrowSchema::from(fields),
+                None,
+                None,
+                None,
+            );
+            for chunk in chunks {
+                let block = DataBlock::from_arrow_chunk_with_types(&chunk?, &data_types)?;
+                let num_rows = block.num_rows();
+                let paths = block.columns()[0]
+                    .value
+                    .convert_to_full_column(&data_types[0], num_rows);
+                let positions = block.columns()[1]
+                    .value
+                    .convert_to_full_column(&data_types[1], num_rows);
+                let (paths, positions) = match (
+                    paths.as_string(),
+                    positions.as_number().and_then(|c| c.as_int64()),
+                ) {
+                    (Some(paths), Some(positions)) => (paths, positions),
+                    _ => {
+                        return Err(ErrorCode::ReadTableDataError(format!(
+                            "invalid position delete file {location}"
+                        )));
+                    }
+                };
+                deleted.extend(
+                    paths
+                        .iter()
+                        .zip(positions.iter())
+                        .filter(|(path, _)| *path == part.file_path.as_bytes())
+                        .map(|(_, pos)| *pos as u64),
+                );
+            }
+        }
+        deleted.sort_unstable();
+        deleted.dedup();
+        Ok(deleted)
+    }
+}
+
+/// remove the rows at `deleted` positions from a block starting at `row_offset` of the file
+fn filter_deleted(block: DataBlock, row_offset: u64, deleted: &[u64]) -> Result<DataBlock> {
+    let num_rows = block.num_rows();
+    let start = deleted.partition_point(|pos| *pos < row_offset);
+    let end = deleted.partition_point(|pos| *pos < row_offset + num_rows as u64);
+    if start == end {
+        return Ok(block);
+    }
+
+    let mut bitmap = MutableBitmap::from_len_set(num_rows);
+    for pos in &deleted[start..end] {
+        bitmap.set((pos - row_offset) as usize, false);
+    }
+    block.filter_with_bitmap(&bitmap.into())
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for IcebergTableSource {
+    const NAME: &'static str = "IcebergTableSource";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.blocks.pop_front() {
+                return Ok(Some(block));
+            }
+            match self.ctx.get_partition() {
+                None => return Ok(None),
+                Some(part_info) => {
+                    let part = IcebergPartInfo::from_part(&part_info)?;
+                    let blocks = self.read_data_file(part).await?;
+                    self.blocks.extend(blocks);
+                }
+            }
+        }
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod columns_table;
+mod copy_history_table;
 mod key_column_usage_table;
 mod keywords_table;
 mod schemata_table;
@@ -21,6 +22,7 @@ mod tables_table;
 mod views_table;
 
 pub use columns_table::ColumnsTable;
+pub use copy_history_table::CopyHistoryTable;
 pub use key_column_usage_table::KeyColumnUsageTable;
 pub use keywords_table::KeywordsTable;
 pub use schemata_table::SchemataTable;
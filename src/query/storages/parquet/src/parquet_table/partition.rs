@@ -18,6 +18,7 @@ use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::SampleMethod;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_functions::BUILTIN_FUNCTIONS;
@@ -132,6 +133,10 @@ impl ParquetTable {
             column_nodes: projected_column_nodes,
             skip_pruning,
             top_k,
+            sample: push_down
+                .as_ref()
+                .and_then(|extra| extra.sample)
+                .filter(|sample| sample.method == SampleMethod::System),
         };
 
         pruner.read_and_prune_partitions().await
@@ -30,6 +30,7 @@ use common_arrow::parquet::read::read_pages_locations;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::SampleInfo;
 use common_catalog::plan::TopK;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -68,6 +69,8 @@ pub struct PartitionPruner {
     pub skip_pruning: bool,
     /// top k information from pushed down information. The usize is the offset of top k column in `schema`.
     pub top_k: Option<(TopK, usize)>,
+    /// `TABLESAMPLE SYSTEM`, keep a part of the row groups left after pruning.
+    pub sample: Option<SampleInfo>,
     // TODO: use limit information for pruning
     // /// Limit of this query. If there is order by and filter, it will not be used (assign to `usize::MAX`).
     // pub limit: usize,
@@ -87,6 +90,7 @@ impl PartitionPruner {
             column_nodes,
             skip_pruning,
             top_k,
+            sample,
         } = self;
 
         // part stats
@@ -165,6 +169,11 @@ impl PartitionPruner {
                 if row_group_pruned[rg_idx] {
                     continue;
                 }
+                if let Some(sample) = sample {
+                    if !sample.keep_block(&(&locations[file_id].0, rg_idx)) {
+                        continue;
+                    }
+                }
 
                 read_rows += rg.num_rows();
                 read_bytes += rg.total_byte_size();
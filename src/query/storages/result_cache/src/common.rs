@@ -12,8 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
 use sha2::Digest;
 use sha2::Sha256;
+use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 const RESULT_CACHE_PREFIX: &str = "_result_cache";
 
@@ -37,6 +44,43 @@ pub(crate) fn gen_result_cache_dir(key: &str) -> String {
     format!("{RESULT_CACHE_PREFIX}/{key}")
 }
 
+/// Generate the tables (with their current snapshots) a query result depends on.
+pub fn gen_result_cache_dependencies(tables: &[Arc<dyn Table>]) -> Vec<ResultCacheDependency> {
+    let mut dependencies = tables
+        .iter()
+        .map(|table| {
+            let table_info = table.get_table_info();
+            let options = table_info.options();
+            ResultCacheDependency {
+                table_id: table_info.ident.table_id,
+                snapshot_location: options
+                    .get(OPT_KEY_SNAPSHOT_LOCATION)
+                    .or_else(|| options.get(OPT_KEY_LEGACY_SNAPSHOT_LOC))
+                    .cloned(),
+            }
+        })
+        .collect::<Vec<_>>();
+    dependencies.sort();
+    dependencies.dedup();
+    dependencies
+}
+
+/// A table the result cache depends on, the cache is invalid once the snapshot changed.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ResultCacheDependency {
+    pub table_id: u64,
+    pub snapshot_location: Option<String>,
+}
+
+impl Display for ResultCacheDependency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.snapshot_location {
+            Some(location) => write!(f, "{}@{}", self.table_id, location),
+            None => write!(f, "{}", self.table_id),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct ResultCacheValue {
     /// The original query SQL.
@@ -55,4 +99,10 @@ pub struct ResultCacheValue {
     pub partitions_shas: Vec<String>,
     /// The location of the result cache file.
     pub location: String,
+    /// The tables and their snapshots when the result is cached.
+    #[serde(default)]
+    pub dependencies: Vec<ResultCacheDependency>,
+    /// The number of times the cache is hit.
+    #[serde(default)]
+    pub hit_count: u64,
 }
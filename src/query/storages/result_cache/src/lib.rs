@@ -17,14 +17,22 @@
 
 mod common;
 mod meta_manager;
+mod persist;
 mod read;
 mod table_function;
 mod write;
 
+pub use common::gen_result_cache_dependencies;
 pub use common::gen_result_cache_key;
 pub use common::gen_result_cache_meta_key;
 pub use common::gen_result_cache_prefix;
+pub use common::ResultCacheDependency;
+pub use common::ResultCacheValue;
 pub use meta_manager::ResultCacheMetaManager;
+pub use persist::gen_result_persist_dir;
+pub use persist::gen_result_persist_prefix;
+pub use persist::ResultPersistManifest;
+pub use persist::WriteResultPersistSink;
 pub use read::ResultCacheReader;
 pub use table_function::ResultScan;
 pub use write::WriteResultCacheSink;
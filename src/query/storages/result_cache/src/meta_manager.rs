@@ -52,6 +52,7 @@ impl ResultCacheMetaManager {
                 value_meta: Some(KVMeta {
                     expire_at: Some(expire_at),
                 }),
+                keep_ttl: false,
             })
             .await?;
         Ok(())
@@ -68,19 +69,53 @@ impl ResultCacheMetaManager {
         }
     }
 
-    pub async fn list(&self, prefix: &str) -> Result<Vec<ResultCacheValue>> {
+    pub async fn list(&self, prefix: &str) -> Result<Vec<(String, ResultCacheValue)>> {
         let result = self.inner.prefix_list_kv(prefix).await?;
 
         let mut r = vec![];
-        for (_key, val) in result {
+        for (key, val) in result {
             let u = serde_json::from_slice::<ResultCacheValue>(&val.data)?;
 
-            r.push(u);
+            r.push((key, u));
         }
 
         Ok(r)
     }
 
+    /// Increase the hit count of the cache, the count may be lost if the cache is updated concurrently.
+    pub async fn increase_hit_count(&self, key: String) -> Result<()> {
+        if let Some(SeqV { seq, data, .. }) = self.inner.get_kv(&key).await? {
+            let mut value = serde_json::from_slice::<ResultCacheValue>(&data)?;
+            value.hit_count += 1;
+            let value = serde_json::to_vec(&value)?;
+            let _ = self
+                .inner
+                .upsert_kv(UpsertKV {
+                    key,
+                    seq: MatchSeq::Exact(seq),
+                    value: Operation::Update(value),
+                    value_meta: None,
+                    keep_ttl: true,
+                })
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn delete(&self, key: String) -> Result<()> {
+        let _ = self
+            .inner
+            .upsert_kv(UpsertKV {
+                key,
+                seq: MatchSeq::GE(0),
+                value: Operation::Delete,
+                value_meta: None,
+                keep_ttl: false,
+            })
+            .await?;
+        Ok(())
+    }
+
     pub fn get_ttl(&self) -> u64 {
         self.ttl
     }
@@ -0,0 +1,80 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::TableSchema;
+use common_meta_app::principal::UserIdentity;
+use opendal::Operator;
+
+const RESULT_PERSIST_PREFIX: &str = "_result_persist";
+const RESULT_PERSIST_MANIFEST: &str = "manifest.json";
+
+/// The directory holding the persisted results of all the queries of the tenant.
+#[inline(always)]
+pub fn gen_result_persist_prefix(tenant: &str) -> String {
+    format!("{RESULT_PERSIST_PREFIX}/{tenant}/")
+}
+
+/// The directory holding the persisted result of the query.
+#[inline(always)]
+pub fn gen_result_persist_dir(tenant: &str, query_id: &str) -> String {
+    format!("{RESULT_PERSIST_PREFIX}/{tenant}/{query_id}/")
+}
+
+#[inline(always)]
+pub(crate) fn gen_result_persist_manifest_location(dir: &str) -> String {
+    format!("{dir}{RESULT_PERSIST_MANIFEST}")
+}
+
+/// Describes the persisted result of a query, it is written after all the blocks
+/// so a result without the manifest is incomplete.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ResultPersistManifest {
+    pub query_id: String,
+    /// The user who ran the query, only the user and the admins may read the result.
+    pub user: UserIdentity,
+    pub schema: TableSchema,
+    /// The parquet files of the result blocks, in the order they were produced.
+    pub blocks: Vec<String>,
+    pub num_rows: usize,
+    /// The size of the result (bytes).
+    pub result_size: usize,
+    /// The time the result was persisted, in seconds.
+    pub created_on: u64,
+    /// The result is removed after this time, in seconds.
+    pub expire_at: u64,
+}
+
+impl ResultPersistManifest {
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expire_at <= now
+    }
+
+    /// Read the manifest in the directory of a persisted result, `None` if the result
+    /// does not exist or is incomplete.
+    pub async fn read(operator: &Operator, dir: &str) -> Result<Option<ResultPersistManifest>> {
+        let location = gen_result_persist_manifest_location(dir);
+        match operator.read(&location).await {
+            Ok(data) => Ok(Some(serde_json::from_slice(&data)?)),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub(crate) async fn write(&self, operator: &Operator, dir: &str) -> Result<()> {
+        let location = gen_result_persist_manifest_location(dir);
+        operator.write(&location, serde_json::to_vec(self)?).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
+use common_meta_types::SeqV;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sinks::AsyncMpscSink;
+use common_pipeline_sinks::AsyncMpscSinker;
+use common_storage::DataOperator;
+use opendal::Operator;
+use storages_common_blocks::blocks_to_parquet;
+use storages_common_table_meta::table::TableCompression;
+use uuid::Uuid;
+
+use super::manifest::gen_result_persist_dir;
+use super::manifest::ResultPersistManifest;
+
+/// Writes the result blocks of a query to storage as they come, and the manifest
+/// once the result is complete.
+///
+/// The result is dropped if it grows over `query_result_persist_max_bytes`.
+pub struct WriteResultPersistSink {
+    ctx: Arc<dyn TableContext>,
+    schema: TableSchemaRef,
+    operator: Operator,
+    dir: String,
+
+    max_bytes: usize,
+    ttl: u64,
+
+    blocks: Vec<String>,
+    num_rows: usize,
+    current_bytes: usize,
+}
+
+#[async_trait::async_trait]
+impl AsyncMpscSink for WriteResultPersistSink {
+    const NAME: &'static str = "WriteResultPersistSink";
+
+    #[async_trait::unboxed_simple]
+    async fn consume(&mut self, block: DataBlock) -> Result<bool> {
+        self.current_bytes += block.memory_size();
+        if self.over_limit() {
+            // Remove the blocks written so far, the result will not be persisted.
+            self.operator.remove_all(&self.dir).await?;
+            return Ok(true);
+        }
+
+        let mut buf = Vec::with_capacity(block.memory_size());
+        self.num_rows += block.num_rows();
+        let _ = blocks_to_parquet(&self.schema, vec![block], &mut buf, TableCompression::None)?;
+
+        let location = format!("{}{}.parquet", self.dir, Uuid::new_v4().as_simple());
+        self.operator.write(&location, buf).await?;
+        self.blocks.push(location);
+        Ok(false)
+    }
+
+    async fn on_finish(&mut self) -> Result<()> {
+        if self.over_limit() {
+            return Ok(());
+        }
+
+        let now = SeqV::<()>::now_ms() / 1000;
+        let manifest = ResultPersistManifest {
+            query_id: self.ctx.get_id(),
+            user: self.ctx.get_current_user()?.identity(),
+            schema: self.schema.as_ref().clone(),
+            blocks: std::mem::take(&mut self.blocks),
+            num_rows: self.num_rows,
+            result_size: self.current_bytes,
+            created_on: now,
+            expire_at: now + self.ttl,
+        };
+        manifest.write(&self.operator, &self.dir).await
+    }
+}
+
+impl WriteResultPersistSink {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        schema: TableSchemaRef,
+        inputs: Vec<Arc<InputPort>>,
+    ) -> Result<ProcessorPtr> {
+        let settings = ctx.get_settings();
+        let max_bytes = settings.get_query_result_persist_max_bytes()?;
+        let ttl = settings.get_query_result_persist_ttl_secs()?;
+        let dir = gen_result_persist_dir(&ctx.get_tenant(), &ctx.get_id());
+
+        Ok(ProcessorPtr::create(Box::new(AsyncMpscSinker::create(
+            inputs,
+            WriteResultPersistSink {
+                ctx,
+                schema,
+                operator: DataOperator::instance().operator(),
+                dir,
+                max_bytes,
+                ttl,
+                blocks: vec![],
+                num_rows: 0,
+                current_bytes: 0,
+            },
+        ))))
+    }
+
+    fn over_limit(&self) -> bool {
+        self.current_bytes > self.max_bytes
+    }
+}
@@ -28,6 +28,7 @@ use common_storage::DataOperator;
 use opendal::Operator;
 
 use crate::common::gen_result_cache_meta_key;
+use crate::common::ResultCacheDependency;
 use crate::meta_manager::ResultCacheMetaManager;
 
 pub struct ResultCacheReader {
@@ -37,6 +38,8 @@ pub struct ResultCacheReader {
     operator: Operator,
     /// To ensure the cache is valid.
     partitions_shas: Vec<String>,
+    /// The current snapshots of the tables in the query, to ensure the cache is valid.
+    dependencies: Vec<ResultCacheDependency>,
 
     /// If true, the cache will be used even if it is inconsistent.
     /// In another word, `partitions_sha` will not be checked.
@@ -48,6 +51,7 @@ impl ResultCacheReader {
         ctx: Arc<dyn TableContext>,
         key: &str,
         kv_store: Arc<MetaStore>,
+        dependencies: Vec<ResultCacheDependency>,
         tolerate_inconsistent: bool,
     ) -> Self {
         let tenant = ctx.get_tenant();
@@ -58,6 +62,7 @@ impl ResultCacheReader {
             meta_mgr: ResultCacheMetaManager::create(kv_store, 0),
             meta_key,
             partitions_shas,
+            dependencies,
             operator: DataOperator::instance().operator(),
             tolerate_inconsistent,
         }
@@ -76,14 +81,19 @@ impl ResultCacheReader {
         &self,
         meta_key: String,
     ) -> Result<Option<Vec<DataBlock>>> {
-        match self.meta_mgr.get(meta_key).await? {
+        match self.meta_mgr.get(meta_key.clone()).await? {
             Some(value) => {
-                if self.tolerate_inconsistent || value.partitions_shas == self.partitions_shas {
-                    if value.num_rows == 0 {
-                        Ok(Some(vec![DataBlock::empty()]))
+                if self.tolerate_inconsistent
+                    || (value.partitions_shas == self.partitions_shas
+                        && value.dependencies == self.dependencies)
+                {
+                    let blocks = if value.num_rows == 0 {
+                        vec![DataBlock::empty()]
                     } else {
-                        Ok(Some(self.read_result_from_cache(&value.location).await?))
-                    }
+                        self.read_result_from_cache(&value.location).await?
+                    };
+                    self.meta_mgr.increase_hit_count(meta_key).await?;
+                    Ok(Some(blocks))
                 } else {
                     // The cache is invalid (due to data update or other reasons).
                     Ok(None)
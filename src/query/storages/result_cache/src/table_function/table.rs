@@ -1,147 +1,218 @@
-// Copyright 2023 Datafuse Labs.
-//
-// Licensed under the Apache License, Version 2.0 (the "License");
-// you may not use this file except in compliance with the License.
-// You may obtain a copy of the License at
-//
-//     http://www.apache.org/licenses/LICENSE-2.0
-//
-// Unless required by applicable law or agreed to in writing, software
-// distributed under the License is distributed on an "AS IS" BASIS,
-// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-// See the License for the specific language governing permissions and
-// limitations under the License.
-
-use std::any::Any;
-use std::io::Cursor;
-use std::sync::Arc;
-
-use common_arrow::arrow::io::parquet::read::infer_schema;
-use common_arrow::arrow::io::parquet::read::{self as pread};
-use common_arrow::parquet::read::read_metadata;
-use common_catalog::plan::DataSourceInfo;
-use common_catalog::plan::DataSourcePlan;
-use common_catalog::plan::PartStatistics;
-use common_catalog::plan::Partitions;
-use common_catalog::plan::PushDownInfo;
-use common_catalog::plan::ResultScanTableInfo;
-use common_catalog::table::Table;
-use common_catalog::table_args::TableArgs;
-use common_catalog::table_context::TableContext;
-use common_exception::Result;
-use common_expression::DataBlock;
-use common_expression::DataSchema;
-use common_expression::Scalar;
-use common_expression::TableSchema;
-use common_meta_app::schema::TableIdent;
-use common_meta_app::schema::TableInfo;
-use common_meta_app::schema::TableMeta;
-use common_pipeline_core::Pipeline;
-use common_pipeline_sources::EmptySource;
-use common_pipeline_sources::OneBlockSource;
-
-const RESULT_SCAN: &str = "result_scan";
-
-pub struct ResultScan {
-    table_info: TableInfo,
-    query_id: String,
-    block_raw_data: Vec<u8>,
-}
-
-impl ResultScan {
-    pub fn try_create(
-        table_schema: TableSchema,
-        query_id: String,
-        block_raw_data: Vec<u8>,
-    ) -> Result<Arc<dyn Table>> {
-        let table_info = TableInfo {
-            ident: TableIdent::new(0, 0),
-            desc: format!("''.'{RESULT_SCAN}'"),
-            name: String::from(RESULT_SCAN),
-            meta: TableMeta {
-                schema: Arc::new(table_schema),
-                engine: String::from(RESULT_SCAN),
-                ..Default::default()
-            },
-            ..Default::default()
-        };
-
-        Ok(Arc::new(ResultScan {
-            table_info,
-            query_id,
-            block_raw_data,
-        }))
-    }
-
-    pub fn from_info(info: &ResultScanTableInfo) -> Result<Arc<dyn Table>> {
-        Ok(Arc::new(ResultScan {
-            table_info: info.table_info.clone(),
-            query_id: info.query_id.clone(),
-            block_raw_data: info.block_raw_data.clone(),
-        }))
-    }
-}
-
-#[async_trait::async_trait]
-impl Table for ResultScan {
-    fn is_local(&self) -> bool {
-        true
-    }
-
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
-
-    fn get_table_info(&self) -> &TableInfo {
-        &self.table_info
-    }
-
-    fn get_data_source_info(&self) -> DataSourceInfo {
-        DataSourceInfo::ResultScanSource(ResultScanTableInfo {
-            table_info: self.table_info.clone(),
-            query_id: self.query_id.clone(),
-            block_raw_data: self.block_raw_data.clone(),
-        })
-    }
-
-    async fn read_partitions(
-        &self,
-        _: Arc<dyn TableContext>,
-        _: Option<PushDownInfo>,
-    ) -> Result<(PartStatistics, Partitions)> {
-        Ok((PartStatistics::default(), Partitions::default()))
-    }
-
-    fn table_args(&self) -> Option<TableArgs> {
-        let args = vec![Scalar::String(self.query_id.as_bytes().to_vec())];
-
-        Some(TableArgs::new_positioned(args))
-    }
-
-    fn read_data(
-        &self,
-        _ctx: Arc<dyn TableContext>,
-        _plan: &DataSourcePlan,
-        pipeline: &mut Pipeline,
-    ) -> Result<()> {
-        if self.block_raw_data.is_empty() {
-            pipeline.add_source(EmptySource::create, 1)?;
-        } else {
-            let mut reader = Cursor::new(self.block_raw_data.clone());
-            let meta = read_metadata(&mut reader)?;
-            let arrow_schema = infer_schema(&meta)?;
-            let table_schema = TableSchema::from(&arrow_schema);
-            let schema = DataSchema::from(&table_schema);
-
-            // Read the parquet file into one block.
-            let chunks_iter =
-                pread::FileReader::new(reader, meta.row_groups, arrow_schema, None, None, None);
-
-            for chunk in chunks_iter {
-                let block = DataBlock::from_arrow_chunk(&chunk?, &schema)?;
-                pipeline.add_source(|output| OneBlockSource::create(output, block.clone()), 1)?;
-            }
-        }
-        Ok(())
-    }
-}
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::parquet::read::infer_schema;
+use common_arrow::arrow::io::parquet::read::{self as pread};
+use common_arrow::parquet::read::read_metadata;
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::ResultScanTableInfo;
+use common_catalog::table::Table;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Scalar;
+use common_expression::TableSchema;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_pipeline_sources::EmptySource;
+use common_pipeline_sources::OneBlockSource;
+use common_storage::DataOperator;
+use opendal::Operator;
+
+use crate::persist::ResultPersistManifest;
+
+const RESULT_SCAN: &str = "result_scan";
+
+pub struct ResultScan {
+    table_info: TableInfo,
+    query_id: String,
+    block_raw_data: Vec<u8>,
+    block_locations: Vec<String>,
+}
+
+impl ResultScan {
+    pub fn try_create(
+        table_schema: TableSchema,
+        query_id: String,
+        block_raw_data: Vec<u8>,
+    ) -> Result<Arc<dyn Table>> {
+        Ok(Arc::new(ResultScan {
+            table_info: Self::table_info(table_schema),
+            query_id,
+            block_raw_data,
+            block_locations: vec![],
+        }))
+    }
+
+    /// Scan the persisted result described by the manifest.
+    pub fn try_create_from_manifest(manifest: ResultPersistManifest) -> Result<Arc<dyn Table>> {
+        Ok(Arc::new(ResultScan {
+            table_info: Self::table_info(manifest.schema),
+            query_id: manifest.query_id,
+            block_raw_data: vec![],
+            block_locations: manifest.blocks,
+        }))
+    }
+
+    pub fn from_info(info: &ResultScanTableInfo) -> Result<Arc<dyn Table>> {
+        Ok(Arc::new(ResultScan {
+            table_info: info.table_info.clone(),
+            query_id: info.query_id.clone(),
+            block_raw_data: info.block_raw_data.clone(),
+            block_locations: info.block_locations.clone(),
+        }))
+    }
+
+    fn table_info(table_schema: TableSchema) -> TableInfo {
+        TableInfo {
+            ident: TableIdent::new(0, 0),
+            desc: format!("''.'{RESULT_SCAN}'"),
+            name: String::from(RESULT_SCAN),
+            meta: TableMeta {
+                schema: Arc::new(table_schema),
+                engine: String::from(RESULT_SCAN),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ResultScan {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_data_source_info(&self) -> DataSourceInfo {
+        DataSourceInfo::ResultScanSource(ResultScanTableInfo {
+            table_info: self.table_info.clone(),
+            query_id: self.query_id.clone(),
+            block_raw_data: self.block_raw_data.clone(),
+            block_locations: self.block_locations.clone(),
+        })
+    }
+
+    async fn read_partitions(
+        &self,
+        _: Arc<dyn TableContext>,
+        _: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        let args = vec![Scalar::String(self.query_id.as_bytes().to_vec())];
+
+        Some(TableArgs::new_positioned(args))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        if !self.block_locations.is_empty() {
+            let operator = DataOperator::instance().operator();
+            let locations = self
+                .block_locations
+                .iter()
+                .cloned()
+                .collect::<VecDeque<_>>();
+            pipeline.add_source(
+                |output| {
+                    AsyncSourcer::create(ctx.clone(), output, PersistedResultSource {
+                        operator: operator.clone(),
+                        locations: locations.clone(),
+                        blocks: VecDeque::new(),
+                    })
+                },
+                1,
+            )?;
+        } else if self.block_raw_data.is_empty() {
+            pipeline.add_source(EmptySource::create, 1)?;
+        } else {
+            for block in read_parquet_blocks(self.block_raw_data.clone())? {
+                pipeline.add_source(|output| OneBlockSource::create(output, block.clone()), 1)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads the parquet files of a persisted result one by one.
+struct PersistedResultSource {
+    operator: Operator,
+    locations: VecDeque<String>,
+    blocks: VecDeque<DataBlock>,
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for PersistedResultSource {
+    const NAME: &'static str = "PersistedResultSource";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.blocks.pop_front() {
+                return Ok(Some(block));
+            }
+            match self.locations.pop_front() {
+                Some(location) => {
+                    let data = self.operator.read(&location).await?;
+                    self.blocks.extend(read_parquet_blocks(data)?);
+                }
+                None => return Ok(None),
+            }
+        }
+    }
+}
+
+fn read_parquet_blocks(data: Vec<u8>) -> Result<Vec<DataBlock>> {
+    let mut reader = Cursor::new(data);
+    let meta = read_metadata(&mut reader)?;
+    let arrow_schema = infer_schema(&meta)?;
+    let table_schema = TableSchema::from(&arrow_schema);
+    let schema = DataSchema::from(&table_schema);
+
+    let chunks_iter =
+        pread::FileReader::new(reader, meta.row_groups, arrow_schema, None, None, None);
+    chunks_iter
+        .map(|chunk| DataBlock::from_arrow_chunk(&chunk?, &schema))
+        .collect()
+}
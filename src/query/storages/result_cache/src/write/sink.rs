@@ -30,6 +30,7 @@ use common_storage::DataOperator;
 use super::writer::ResultCacheWriter;
 use crate::common::gen_result_cache_dir;
 use crate::common::gen_result_cache_meta_key;
+use crate::common::ResultCacheDependency;
 use crate::common::ResultCacheValue;
 use crate::meta_manager::ResultCacheMetaManager;
 
@@ -37,6 +38,7 @@ pub struct WriteResultCacheSink {
     ctx: Arc<dyn TableContext>,
     sql: String,
     partitions_shas: Vec<String>,
+    dependencies: Vec<ResultCacheDependency>,
 
     meta_mgr: ResultCacheMetaManager,
     meta_key: String,
@@ -80,6 +82,8 @@ impl AsyncMpscSink for WriteResultCacheSink {
             result_size: self.cache_writer.current_bytes(),
             num_rows: self.cache_writer.num_rows(),
             location,
+            dependencies: self.dependencies.clone(),
+            hit_count: 0,
         };
         self.meta_mgr
             .set(self.meta_key.clone(), value, MatchSeq::GE(0), expire_at)
@@ -97,6 +101,7 @@ impl WriteResultCacheSink {
         schema: TableSchemaRef,
         inputs: Vec<Arc<InputPort>>,
         kv_store: Arc<MetaStore>,
+        dependencies: Vec<ResultCacheDependency>,
     ) -> Result<ProcessorPtr> {
         let settings = ctx.get_settings();
         let max_bytes = settings.get_query_result_cache_max_bytes()?;
@@ -117,6 +122,7 @@ impl WriteResultCacheSink {
                 ctx,
                 sql,
                 partitions_shas,
+                dependencies,
                 meta_mgr: ResultCacheMetaManager::create(kv_store, ttl),
                 meta_key,
                 cache_writer,
@@ -98,6 +98,7 @@ mod ext {
         database: Option<WithLocation<ShareDatabaseSpec>>,
         tables: Vec<WithLocation<ShareTableSpec>>,
         tenants: Vec<String>,
+        read_only_tenants: Vec<String>,
     }
 
     impl ShareSpecExt {
@@ -123,6 +124,7 @@ mod ext {
                     })
                     .collect(),
                 tenants: spec.tenants,
+                read_only_tenants: spec.read_only_tenants,
             }
         }
     }
@@ -175,7 +177,8 @@ mod ext {
                     table_id: 1,
                     presigned_url_timeout: "100s".to_string(),
                 }],
-                tenants: vec!["test_tenant".to_owned()],
+                tenants: vec!["test_tenant".to_owned(), "read_only_tenant".to_owned()],
+                read_only_tenants: vec!["read_only_tenant".to_owned()],
             };
             let tmp_dir = tempfile::tempdir()?;
             let test_root = tmp_dir.path().join("test_cluster_id/test_tenant_id");
@@ -210,7 +213,11 @@ mod ext {
                 }
               ],
               "tenants": [
-                "test_tenant"
+                "test_tenant",
+                "read_only_tenant"
+              ],
+              "read_only_tenants": [
+                "read_only_tenant"
               ]
             });
 
@@ -178,6 +178,7 @@ impl Table for StageTable {
             splits,
             ctx.get_scan_progress(),
             compact_threshold,
+            ctx.get_copy_status(),
         )?);
 
         input_ctx.format.exec_copy(input_ctx.clone(), pipeline)?;
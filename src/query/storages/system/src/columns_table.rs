@@ -24,6 +24,7 @@ use common_expression::DataBlock;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchemaRefExt;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -147,7 +148,21 @@ impl ColumnsTable {
                 } else {
                     table.schema().fields().clone()
                 };
+                // Users granted SELECT on only some columns of a table can only see these columns.
+                let granted_columns = ctx
+                    .get_granted_columns(
+                        CATALOG_DEFAULT,
+                        database.name(),
+                        table.name(),
+                        UserPrivilegeType::Select,
+                    )
+                    .await?;
                 for field in fields {
+                    if let Some(granted_columns) = &granted_columns {
+                        if !granted_columns.contains(field.name()) {
+                            continue;
+                        }
+                    }
                     rows.push((database.name().into(), table.name().into(), field.clone()))
                 }
             }
@@ -0,0 +1,142 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The files loaded by COPY INTO in the retention period, the latest first.
+pub struct CopyHistoryTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for CopyHistoryTable {
+    const NAME: &'static str = "system.copy_history";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let history = UserApiProvider::instance()
+            .get_copy_history(&tenant)
+            .await?;
+
+        let file_names: Vec<&str> = history.iter().map(|x| x.file_name.as_str()).collect();
+        let databases: Vec<&str> = history.iter().map(|x| x.database_name.as_str()).collect();
+        let tables: Vec<&str> = history.iter().map(|x| x.table_name.as_str()).collect();
+        let stage_locations: Vec<&str> =
+            history.iter().map(|x| x.stage_location.as_str()).collect();
+        let file_sizes: Vec<u64> = history.iter().map(|x| x.file_size).collect();
+        let rows_parsed: Vec<u64> = history.iter().map(|x| x.rows_parsed).collect();
+        let rows_loaded: Vec<u64> = history.iter().map(|x| x.rows_loaded).collect();
+        let error_counts: Vec<u64> = history.iter().map(|x| x.error_count).collect();
+        let statuses: Vec<Vec<u8>> = history
+            .iter()
+            .map(|x| x.status.to_string().into_bytes())
+            .collect();
+        let first_error_messages: Vec<Option<Vec<u8>>> = history
+            .iter()
+            .map(|x| {
+                x.first_error_message
+                    .as_ref()
+                    .map(|e| e.as_bytes().to_vec())
+            })
+            .collect();
+        let first_error_lines: Vec<Option<u64>> =
+            history.iter().map(|x| x.first_error_line).collect();
+        let first_error_columns: Vec<Option<u64>> =
+            history.iter().map(|x| x.first_error_column).collect();
+        let last_load_times: Vec<i64> = history
+            .iter()
+            .map(|x| x.last_load_time.timestamp_micros())
+            .collect();
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(file_names),
+            StringType::from_data(databases),
+            StringType::from_data(tables),
+            StringType::from_data(stage_locations),
+            UInt64Type::from_data(file_sizes),
+            UInt64Type::from_data(rows_parsed),
+            UInt64Type::from_data(rows_loaded),
+            UInt64Type::from_data(error_counts),
+            StringType::from_data(statuses),
+            StringType::from_opt_data(first_error_messages),
+            UInt64Type::from_opt_data(first_error_lines),
+            UInt64Type::from_opt_data(first_error_columns),
+            TimestampType::from_data(last_load_times),
+        ]))
+    }
+}
+
+impl CopyHistoryTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("file_name", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table_name", TableDataType::String),
+            TableField::new("stage_location", TableDataType::String),
+            TableField::new("file_size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("rows_parsed", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("rows_loaded", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("error_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("status", TableDataType::String),
+            TableField::new("first_error_message", TableDataType::String.wrap_nullable()),
+            TableField::new(
+                "first_error_line",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new(
+                "first_error_column",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new("last_load_time", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'copy_history'".to_string(),
+            name: "copy_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemCopyHistory".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(CopyHistoryTable { table_info })
+    }
+}
@@ -16,9 +16,12 @@ use std::sync::Arc;
 
 use common_catalog::catalog::Catalog;
 use common_catalog::catalog::CatalogManager;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
 use common_expression::utils::FromData;
 use common_expression::DataBlock;
@@ -55,19 +58,38 @@ impl AsyncSystemTable for DatabasesTable {
 
         let mut catalog_names = vec![];
         let mut db_names = vec![];
+        let mut default_db_ids = vec![];
         for (ctl_name, catalog) in catalogs.into_iter() {
             let databases = catalog.list_databases(tenant.as_str()).await?;
 
             for db in databases {
+                // Quotas can only be set on databases in the default catalog.
+                if ctl_name == CATALOG_DEFAULT {
+                    default_db_ids.push((db_names.len(), db.get_db_info().ident.db_id));
+                }
                 catalog_names.push(ctl_name.clone().into_bytes());
                 let db_name = db.name().to_string().into_bytes();
                 db_names.push(db_name);
             }
         }
 
+        let mut max_tables = vec![0; db_names.len()];
+        let mut max_bytes = vec![0; db_names.len()];
+        let mut max_rows = vec![0; db_names.len()];
+        let db_ids: Vec<u64> = default_db_ids.iter().map(|(_, id)| *id).collect();
+        let quotas = ctx.get_database_quotas(&db_ids).await?;
+        for ((i, _), quota) in default_db_ids.into_iter().zip(quotas) {
+            max_tables[i] = quota.max_tables;
+            max_bytes[i] = quota.max_bytes;
+            max_rows[i] = quota.max_rows;
+        }
+
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(catalog_names),
             StringType::from_data(db_names),
+            UInt64Type::from_data(max_tables),
+            UInt64Type::from_data(max_bytes),
+            UInt64Type::from_data(max_rows),
         ]))
     }
 }
@@ -77,6 +99,9 @@ impl DatabasesTable {
         let schema = TableSchemaRefExt::create(vec![
             TableField::new("catalog", TableDataType::String),
             TableField::new("name", TableDataType::String),
+            TableField::new("max_tables", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("max_bytes", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("max_rows", TableDataType::Number(NumberDataType::UInt64)),
         ]);
 
         let table_info = TableInfo {
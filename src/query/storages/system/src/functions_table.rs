@@ -88,8 +88,15 @@ impl AsyncSystemTable for FunctionsTable {
 
         let descriptions = (0..names.len())
             .map(|i| {
-                if i < builtin_func_len {
-                    ""
+                if i < func_names.len() {
+                    BUILTIN_FUNCTIONS
+                        .metas
+                        .get(names[i])
+                        .map_or("", |meta| meta.description)
+                } else if i < builtin_func_len {
+                    aggregate_function_factory
+                        .get_features(names[i])
+                        .map_or("", |features| features.description)
                 } else {
                     udfs.get(i - builtin_func_len)
                         .map_or("", |udf| udf.description.as_str())
@@ -108,7 +115,22 @@ impl AsyncSystemTable for FunctionsTable {
             })
             .collect::<Vec<&str>>();
 
-        let examples = (0..names.len()).map(|_| "").collect::<Vec<&str>>();
+        let examples = (0..names.len())
+            .map(|i| {
+                if i < func_names.len() {
+                    BUILTIN_FUNCTIONS
+                        .metas
+                        .get(names[i])
+                        .map_or("", |meta| meta.example)
+                } else if i < builtin_func_len {
+                    aggregate_function_factory
+                        .get_features(names[i])
+                        .map_or("", |features| features.example)
+                } else {
+                    ""
+                }
+            })
+            .collect::<Vec<&str>>();
 
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(names),
@@ -22,6 +22,7 @@ mod clusters_table;
 mod columns_table;
 mod configs_table;
 mod contributors_table;
+mod copy_history_table;
 mod credits_table;
 mod databases_table;
 mod engines_table;
@@ -31,16 +32,23 @@ mod malloc_stats_table;
 mod malloc_stats_totals_table;
 mod metrics_table;
 mod one_table;
+mod pipe_rejected_records_table;
+mod pipe_status_table;
 mod processes_table;
 mod query_cache_table;
 mod query_log_table;
 mod roles_table;
 mod settings_table;
+mod slow_queries_table;
 mod stages_table;
+mod streams_table;
 mod table;
 mod table_functions_table;
 mod tables_table;
+mod task_history_table;
+mod tasks_table;
 mod tracing_table;
+mod user_variables_table;
 mod users_table;
 
 pub use build_options_table::BuildOptionsTable;
@@ -52,6 +60,7 @@ pub use clusters_table::ClustersTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
+pub use copy_history_table::CopyHistoryTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
 pub use engines_table::EnginesTable;
@@ -63,6 +72,8 @@ pub use malloc_stats_table::MallocStatsTable;
 pub use malloc_stats_totals_table::MallocStatsTotalsTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
+pub use pipe_rejected_records_table::PipeRejectedRecordsTable;
+pub use pipe_status_table::PipeStatusTable;
 pub use processes_table::ProcessesTable;
 pub use query_cache_table::QueryCacheTable;
 pub use query_log_table::LogType;
@@ -71,12 +82,19 @@ pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
+pub use slow_queries_table::SlowQueryLogElement;
+pub use slow_queries_table::SlowQueryLogQueue;
+pub use slow_queries_table::SlowQueryLogTable;
 pub use stages_table::StagesTable;
+pub use streams_table::StreamsTable;
 pub use table::SyncOneBlockSystemTable;
 pub use table::SyncSystemTable;
 pub use table_functions_table::TableFunctionsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
+pub use task_history_table::TaskHistoryTable;
+pub use tasks_table::TasksTable;
 pub use tracing_table::TracingTable;
+pub use user_variables_table::UserVariablesTable;
 pub use users_table::UsersTable;
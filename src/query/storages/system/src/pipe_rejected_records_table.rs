@@ -0,0 +1,103 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::Int32Type;
+use common_expression::types::number::Int64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The records the pipes failed to parse in the retention period, the latest first.
+pub struct PipeRejectedRecordsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PipeRejectedRecordsTable {
+    const NAME: &'static str = "system.pipe_rejected_records";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let mut records = UserApiProvider::instance()
+            .get_pipe_rejected_records(&tenant)
+            .await?;
+        records.sort_by(|a, b| b.rejected_on.cmp(&a.rejected_on));
+
+        let names: Vec<&str> = records.iter().map(|x| x.pipe_name.as_str()).collect();
+        let partitions: Vec<i32> = records.iter().map(|x| x.partition).collect();
+        let offsets: Vec<i64> = records.iter().map(|x| x.offset).collect();
+        let errors: Vec<&str> = records.iter().map(|x| x.error.as_str()).collect();
+        let payloads: Vec<&str> = records.iter().map(|x| x.payload.as_str()).collect();
+        let rejected_ons: Vec<i64> = records
+            .iter()
+            .map(|x| x.rejected_on.timestamp_micros())
+            .collect();
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            Int32Type::from_data(partitions),
+            Int64Type::from_data(offsets),
+            StringType::from_data(errors),
+            StringType::from_data(payloads),
+            TimestampType::from_data(rejected_ons),
+        ]))
+    }
+}
+
+impl PipeRejectedRecordsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("partition", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("offset", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new("error", TableDataType::String),
+            TableField::new("payload", TableDataType::String),
+            TableField::new("rejected_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'pipe_rejected_records'".to_string(),
+            name: "pipe_rejected_records".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPipeRejectedRecords".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(PipeRejectedRecordsTable { table_info })
+    }
+}
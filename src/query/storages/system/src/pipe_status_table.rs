@@ -0,0 +1,227 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+use itertools::Itertools;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The pipes, along with how far they have consumed their sources.
+///
+/// A pipe no query node has consumed yet has no status, its status columns are null.
+pub struct PipeStatusTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PipeStatusTable {
+    const NAME: &'static str = "system.pipe_status";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+        let mut pipes = user_mgr.get_pipes(&tenant).await?;
+        pipes.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut statuses: HashMap<String, _> = user_mgr
+            .get_pipe_statuses(&tenant)
+            .await?
+            .into_iter()
+            .map(|x| (x.pipe_name.clone(), x))
+            .collect();
+        let statuses: Vec<_> = pipes.iter().map(|x| statuses.remove(&x.name)).collect();
+
+        let names: Vec<&str> = pipes.iter().map(|x| x.name.as_str()).collect();
+        let owners: Vec<Vec<u8>> = pipes
+            .iter()
+            .map(|x| x.owner.to_string().into_bytes())
+            .collect();
+        let databases: Vec<&str> = pipes.iter().map(|x| x.database.as_str()).collect();
+        let tables: Vec<&str> = pipes.iter().map(|x| x.table.as_str()).collect();
+        let sources: Vec<Vec<u8>> = pipes
+            .iter()
+            .map(|x| x.source.to_string().into_bytes())
+            .collect();
+        let formats: Vec<Vec<u8>> = pipes
+            .iter()
+            .map(|x| {
+                x.file_format_options
+                    .format
+                    .to_string()
+                    .to_uppercase()
+                    .into_bytes()
+            })
+            .collect();
+        let on_errors: Vec<Vec<u8>> = pipes
+            .iter()
+            .map(|x| x.on_error.to_string().into_bytes())
+            .collect();
+        let comments: Vec<&str> = pipes.iter().map(|x| x.comment.as_str()).collect();
+        let created_ons: Vec<i64> = pipes
+            .iter()
+            .map(|x| x.created_on.timestamp_micros())
+            .collect();
+
+        let holders: Vec<Option<Vec<u8>>> = statuses
+            .iter()
+            .map(|x| x.as_ref().map(|s| s.holder.as_bytes().to_vec()))
+            .collect();
+        // partition:offset/high_watermark, by partition.
+        let offsets: Vec<Option<Vec<u8>>> = statuses
+            .iter()
+            .map(|x| {
+                x.as_ref().map(|s| {
+                    s.partitions
+                        .iter()
+                        .map(|p| format!("{}:{}/{}", p.partition, p.offset, p.high_watermark))
+                        .join(",")
+                        .into_bytes()
+                })
+            })
+            .collect();
+        let lags: Vec<Option<u64>> = statuses
+            .iter()
+            .map(|x| x.as_ref().map(|s| s.lag()))
+            .collect();
+        let rows_loaded: Vec<Option<u64>> = statuses
+            .iter()
+            .map(|x| x.as_ref().map(|s| s.rows_loaded))
+            .collect();
+        let rows_rejected: Vec<Option<u64>> = statuses
+            .iter()
+            .map(|x| x.as_ref().map(|s| s.rows_rejected))
+            .collect();
+        let rows_per_second: Vec<Option<u64>> = statuses
+            .iter()
+            .map(|x| x.as_ref().map(|s| s.rows_per_second))
+            .collect();
+        let last_committed_ons: Vec<Option<i64>> = statuses
+            .iter()
+            .map(|x| {
+                x.as_ref()
+                    .and_then(|s| s.last_committed_on)
+                    .map(|t| t.timestamp_micros())
+            })
+            .collect();
+        let last_errors: Vec<Option<Vec<u8>>> = statuses
+            .iter()
+            .map(|x| {
+                x.as_ref()
+                    .and_then(|s| s.last_error.as_ref())
+                    .map(|e| e.as_bytes().to_vec())
+            })
+            .collect();
+        let updated_ons: Vec<Option<i64>> = statuses
+            .iter()
+            .map(|x| x.as_ref().map(|s| s.updated_on.timestamp_micros()))
+            .collect();
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(owners),
+            StringType::from_data(databases),
+            StringType::from_data(tables),
+            StringType::from_data(sources),
+            StringType::from_data(formats),
+            StringType::from_data(on_errors),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_ons),
+            StringType::from_opt_data(holders),
+            StringType::from_opt_data(offsets),
+            UInt64Type::from_opt_data(lags),
+            UInt64Type::from_opt_data(rows_loaded),
+            UInt64Type::from_opt_data(rows_rejected),
+            UInt64Type::from_opt_data(rows_per_second),
+            TimestampType::from_opt_data(last_committed_ons),
+            StringType::from_opt_data(last_errors),
+            TimestampType::from_opt_data(updated_ons),
+        ]))
+    }
+}
+
+impl PipeStatusTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("owner", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("source", TableDataType::String),
+            TableField::new("format", TableDataType::String),
+            TableField::new("on_error", TableDataType::String),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("holder", TableDataType::String.wrap_nullable()),
+            TableField::new("offsets", TableDataType::String.wrap_nullable()),
+            TableField::new(
+                "lag",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new(
+                "rows_loaded",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new(
+                "rows_rejected",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new(
+                "rows_per_second",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new(
+                "last_committed_on",
+                TableDataType::Timestamp.wrap_nullable(),
+            ),
+            TableField::new("last_error", TableDataType::String.wrap_nullable()),
+            TableField::new("updated_on", TableDataType::Timestamp.wrap_nullable()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'pipe_status'".to_string(),
+            name: "pipe_status".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPipeStatus".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(PipeStatusTable { table_info })
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use serde::Serialize;
+
+use crate::query_log_table::datetime_str;
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// A query that ran for `long_query_time` or longer.
+#[derive(Clone, Serialize)]
+pub struct SlowQueryLogElement {
+    // User.
+    pub tenant_id: String,
+    pub sql_user: String,
+
+    // Query.
+    pub query_id: String,
+    pub query_kind: String,
+    pub query_text: String,
+    #[serde(serialize_with = "datetime_str")]
+    pub query_start_time: i64,
+    pub query_duration_ms: u64,
+
+    // Stats.
+    pub scan_rows: u64,
+    pub scan_bytes: u64,
+    pub result_rows: u64,
+    pub peak_memory_usage: u64,
+
+    // Exception.
+    pub exception_code: i32,
+    pub exception_text: String,
+
+    // Plan.
+    /// The physical plan the query was executed with, formatted like EXPLAIN.
+    pub plan: String,
+    /// The three operators that took the most processing time, e.g. `Sort: 1200ms, TableScan: 800ms`.
+    pub slowest_operators: String,
+}
+
+impl SystemLogElement for SlowQueryLogElement {
+    const TABLE_NAME: &'static str = "slow_queries";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            // User.
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("sql_user", TableDataType::String),
+            // Query.
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("query_kind", TableDataType::String),
+            TableField::new("query_text", TableDataType::String),
+            TableField::new("query_start_time", TableDataType::Timestamp),
+            TableField::new(
+                "query_duration_ms",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            // Stats.
+            TableField::new("scan_rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("scan_bytes", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("result_rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "peak_memory_usage",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            // Exception.
+            TableField::new(
+                "exception_code",
+                TableDataType::Number(NumberDataType::Int32),
+            ),
+            TableField::new("exception_text", TableDataType::String),
+            // Plan.
+            TableField::new("plan", TableDataType::String),
+            TableField::new("slowest_operators", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        // User.
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.tenant_id.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.sql_user.as_bytes().to_vec()).as_ref());
+        // Query.
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_kind.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_text.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.query_start_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.query_duration_ms)).as_ref());
+        // Stats.
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.scan_rows)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.scan_bytes)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.result_rows)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.peak_memory_usage)).as_ref());
+        // Exception.
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Int32(self.exception_code)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.exception_text.as_bytes().to_vec()).as_ref());
+        // Plan.
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.plan.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.slowest_operators.as_bytes().to_vec()).as_ref());
+        Ok(())
+    }
+}
+
+pub type SlowQueryLogQueue = SystemLogQueue<SlowQueryLogElement>;
+pub type SlowQueryLogTable = SystemLogTable<SlowQueryLogElement>;
@@ -0,0 +1,125 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_storages_fuse::stream_table::StreamTable;
+use common_storages_fuse::stream_table::STREAM_ENGINE;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// Streams of the default catalog, the only one that can have them.
+pub struct StreamsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for StreamsTable {
+    const NAME: &'static str = "system.streams";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+
+        let mut catalogs = vec![];
+        let mut databases = vec![];
+        let mut names = vec![];
+        let mut tables_on = vec![];
+        let mut offset_snapshot_ids = vec![];
+        let mut created_ons = vec![];
+        for database in catalog.list_databases(tenant.as_str()).await? {
+            for table in catalog
+                .list_tables(tenant.as_str(), database.name())
+                .await?
+            {
+                if table.engine() != STREAM_ENGINE {
+                    continue;
+                }
+                let stream = StreamTable::try_from_table(table.as_ref())?;
+                catalogs.push(CATALOG_DEFAULT.as_bytes().to_vec());
+                databases.push(database.name().as_bytes().to_vec());
+                names.push(stream.name().as_bytes().to_vec());
+                tables_on.push(
+                    format!("{}.{}", stream.table_database(), stream.table_name()).into_bytes(),
+                );
+                offset_snapshot_ids
+                    .push(stream.offset_snapshot_id().map(|id| id.as_bytes().to_vec()));
+                created_ons.push(
+                    stream
+                        .get_table_info()
+                        .meta
+                        .created_on
+                        .format("%Y-%m-%d %H:%M:%S.%3f %z")
+                        .to_string()
+                        .into_bytes(),
+                );
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(catalogs),
+            StringType::from_data(databases),
+            StringType::from_data(names),
+            StringType::from_data(tables_on),
+            StringType::from_opt_data(offset_snapshot_ids),
+            StringType::from_data(created_ons),
+        ]))
+    }
+}
+
+impl StreamsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("catalog", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("table_on", TableDataType::String),
+            TableField::new("offset_snapshot_id", TableDataType::String.wrap_nullable()),
+            TableField::new("created_on", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'streams'".to_string(),
+            name: "streams".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemStreams".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(StreamsTable { table_info })
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The runs of the tasks in the retention period, the latest first.
+pub struct TaskHistoryTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TaskHistoryTable {
+    const NAME: &'static str = "system.task_history";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let mut runs = UserApiProvider::instance().get_task_runs(&tenant).await?;
+        runs.sort_by(|a, b| b.started_on.cmp(&a.started_on));
+
+        let names: Vec<&str> = runs.iter().map(|x| x.task_name.as_str()).collect();
+        let query_ids: Vec<&str> = runs.iter().map(|x| x.query_id.as_str()).collect();
+        let triggers: Vec<Vec<u8>> = runs
+            .iter()
+            .map(|x| x.trigger.to_string().into_bytes())
+            .collect();
+        let states: Vec<Vec<u8>> = runs
+            .iter()
+            .map(|x| x.state.to_string().into_bytes())
+            .collect();
+        let errors: Vec<Option<Vec<u8>>> = runs
+            .iter()
+            .map(|x| x.error.as_ref().map(|e| e.as_bytes().to_vec()))
+            .collect();
+        let started_ons: Vec<i64> = runs
+            .iter()
+            .map(|x| x.started_on.timestamp_micros())
+            .collect();
+        let durations: Vec<u64> = runs.iter().map(|x| x.duration_ms).collect();
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(query_ids),
+            StringType::from_data(triggers),
+            StringType::from_data(states),
+            StringType::from_opt_data(errors),
+            TimestampType::from_data(started_ons),
+            UInt64Type::from_data(durations),
+        ]))
+    }
+}
+
+impl TaskHistoryTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("trigger", TableDataType::String),
+            TableField::new("state", TableDataType::String),
+            TableField::new("error", TableDataType::String.wrap_nullable()),
+            TableField::new("started_on", TableDataType::Timestamp),
+            TableField::new("duration_ms", TableDataType::Number(NumberDataType::UInt64)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'task_history'".to_string(),
+            name: "task_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTaskHistory".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(TaskHistoryTable { table_info })
+    }
+}
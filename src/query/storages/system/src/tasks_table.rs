@@ -0,0 +1,137 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct TasksTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TasksTable {
+    const NAME: &'static str = "system.tasks";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let mut tasks = UserApiProvider::instance().get_tasks(&tenant).await?;
+        tasks.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let names: Vec<&str> = tasks.iter().map(|x| x.name.as_str()).collect();
+        let owners: Vec<Vec<u8>> = tasks
+            .iter()
+            .map(|x| x.owner.to_string().into_bytes())
+            .collect();
+        let databases: Vec<&str> = tasks.iter().map(|x| x.database.as_str()).collect();
+        let schedules: Vec<Vec<u8>> = tasks
+            .iter()
+            .map(|x| x.schedule.to_string().into_bytes())
+            .collect();
+        let states: Vec<Vec<u8>> = tasks
+            .iter()
+            .map(|x| x.state.to_string().into_bytes())
+            .collect();
+        let definitions: Vec<&str> = tasks.iter().map(|x| x.query_text.as_str()).collect();
+        let comments: Vec<&str> = tasks.iter().map(|x| x.comment.as_str()).collect();
+        let created_ons: Vec<i64> = tasks
+            .iter()
+            .map(|x| x.created_on.timestamp_micros())
+            .collect();
+        let updated_ons: Vec<i64> = tasks
+            .iter()
+            .map(|x| x.updated_on.timestamp_micros())
+            .collect();
+        let last_scheduled_ons: Vec<Option<i64>> = tasks
+            .iter()
+            .map(|x| x.last_scheduled_on.map(|t| t.timestamp_micros()))
+            .collect();
+        let next_scheduled_ons: Vec<Option<i64>> = tasks
+            .iter()
+            .map(|x| x.next_scheduled_on().map(|t| t.timestamp_micros()))
+            .collect();
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(owners),
+            StringType::from_data(databases),
+            StringType::from_data(schedules),
+            StringType::from_data(states),
+            StringType::from_data(definitions),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_ons),
+            TimestampType::from_data(updated_ons),
+            TimestampType::from_opt_data(last_scheduled_ons),
+            TimestampType::from_opt_data(next_scheduled_ons),
+        ]))
+    }
+}
+
+impl TasksTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("owner", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("schedule", TableDataType::String),
+            TableField::new("state", TableDataType::String),
+            TableField::new("definition", TableDataType::String),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("updated_on", TableDataType::Timestamp),
+            TableField::new(
+                "last_scheduled_on",
+                TableDataType::Timestamp.wrap_nullable(),
+            ),
+            TableField::new(
+                "next_scheduled_on",
+                TableDataType::Timestamp.wrap_nullable(),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tasks'".to_string(),
+            name: "tasks".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTasks".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(TasksTable { table_info })
+    }
+}
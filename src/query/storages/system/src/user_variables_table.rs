@@ -0,0 +1,89 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+pub struct UserVariablesTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for UserVariablesTable {
+    const NAME: &'static str = "system.user_variables";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let mut variables = ctx.get_all_variables().into_iter().collect::<Vec<_>>();
+        variables.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut names: Vec<Vec<u8>> = Vec::with_capacity(variables.len());
+        let mut values: Vec<Vec<u8>> = Vec::with_capacity(variables.len());
+        let mut types: Vec<Vec<u8>> = Vec::with_capacity(variables.len());
+        for (name, value) in variables {
+            let data_type = value.as_ref().infer_data_type();
+            names.push(name.into_bytes());
+            values.push(value.to_string().into_bytes());
+            types.push(data_type.to_string().into_bytes());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(values),
+            StringType::from_data(types),
+        ]))
+    }
+}
+
+impl UserVariablesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("value", TableDataType::String),
+            TableField::new("type", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'user_variables'".to_string(),
+            name: "user_variables".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemUserVariables".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(UserVariablesTable { table_info })
+    }
+}
@@ -20,13 +20,23 @@ mod jwt;
 mod role_mgr;
 mod user;
 mod user_api;
+mod user_copy_history;
+mod user_external_function;
+mod user_masking_policy;
 mod user_mgr;
+mod user_network_policy;
+mod user_pipe;
+mod user_row_access_policy;
+mod user_sequence;
 mod user_setting;
 mod user_stage;
+mod user_table_statistics;
+mod user_task;
 mod user_udf;
 
 pub mod file_format;
 pub mod idm_config;
+pub mod network_policy;
 pub mod role_cache_mgr;
 pub mod role_util;
 
@@ -0,0 +1,109 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::NetworkPolicy;
+
+/// An IPv4 or IPv6 network in CIDR notation, e.g. `192.168.1.0/24` or `fd00::/8`.
+///
+/// IPv4 networks are kept as IPv4-mapped IPv6 networks, so that both families
+/// can be matched the same way.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IpNetwork {
+    addr: u128,
+    prefix_len: u32,
+}
+
+impl IpNetwork {
+    pub fn parse(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let (addr, prefix_len) = match s.split_once('/') {
+            Some((addr, prefix_len)) => (addr, Some(prefix_len)),
+            None => (s, None),
+        };
+        let addr = addr.parse::<IpAddr>().map_err(|e| {
+            ErrorCode::BadArguments(format!("invalid ip address in '{}': {}", s, e))
+        })?;
+        let max_prefix_len = match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        let prefix_len = match prefix_len {
+            None => max_prefix_len,
+            Some(prefix_len) => match prefix_len.parse::<u32>() {
+                Ok(len) if len <= max_prefix_len => len,
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "invalid prefix length in '{}', must be between 0 and {}",
+                        s, max_prefix_len
+                    )));
+                }
+            },
+        };
+
+        // Promote IPv4 prefixes to their position in the IPv4-mapped IPv6 space.
+        let prefix_len = 128 - max_prefix_len + prefix_len;
+        Ok(IpNetwork {
+            addr: to_u128(addr) & mask(prefix_len),
+            prefix_len,
+        })
+    }
+
+    pub fn contains(&self, addr: IpAddr) -> bool {
+        to_u128(addr) & mask(self.prefix_len) == self.addr
+    }
+}
+
+fn to_u128(addr: IpAddr) -> u128 {
+    match addr {
+        IpAddr::V4(v4) => u128::from(v4.to_ipv6_mapped()),
+        IpAddr::V6(v6) => u128::from(v6),
+    }
+}
+
+fn mask(prefix_len: u32) -> u128 {
+    u128::MAX.checked_shl(128 - prefix_len).unwrap_or(0)
+}
+
+/// Make sure all the entries of the policy are valid networks.
+pub fn validate_network_policy(policy: &NetworkPolicy) -> Result<()> {
+    if policy.allowed_ip_list.is_empty() {
+        return Err(ErrorCode::BadArguments(format!(
+            "allowed ip list of network policy {} can't be empty",
+            policy.name
+        )));
+    }
+    for ip in policy.allowed_ip_list.iter().chain(&policy.blocked_ip_list) {
+        IpNetwork::parse(ip)?;
+    }
+    Ok(())
+}
+
+/// Returns true if `addr` is inside the allowed list and outside the blocked list of the policy.
+pub fn network_policy_allows(policy: &NetworkPolicy, addr: IpAddr) -> Result<bool> {
+    for ip in &policy.blocked_ip_list {
+        if IpNetwork::parse(ip)?.contains(addr) {
+            return Ok(false);
+        }
+    }
+    for ip in &policy.allowed_ip_list {
+        if IpNetwork::parse(ip)?.contains(addr) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
@@ -23,7 +23,9 @@ use common_meta_app::principal::UserPrivilegeSet;
 use common_meta_app::principal::UserPrivilegeType;
 use common_meta_types::MatchSeq;
 
-use crate::role_util::find_all_related_roles;
+use crate::role_util::find_role_grant_path;
+use crate::role_util::find_role_hierarchy;
+use crate::role_util::RoleInheritance;
 use crate::UserApiProvider;
 
 pub const BUILTIN_ROLE_ACCOUNT_ADMIN: &str = "account_admin";
@@ -140,14 +142,14 @@ impl UserApiProvider {
         target_role: &String,
         grant_role: String,
     ) -> Result<Option<u64>> {
-        let related_roles = self
-            .find_related_roles(tenant, &[grant_role.clone()])
-            .await?;
-        let have_cycle = related_roles.iter().any(|r| r.identity() == target_role);
-        if have_cycle {
-            return Err(ErrorCode::InvalidRole(format!(
-                "{} contains {}, can not be grant to {}",
-                &grant_role, &target_role, &target_role
+        let tenant_roles_map = self.get_tenant_roles_map(tenant).await?;
+        if let Some(path) = find_role_grant_path(&tenant_roles_map, &grant_role, target_role) {
+            return Err(ErrorCode::CyclicRoleGrant(format!(
+                "granting role {} to role {} would create a cycle: {} -> {}",
+                &grant_role,
+                &target_role,
+                path.join(" -> "),
+                &grant_role
             )));
         }
 
@@ -191,19 +193,18 @@ impl UserApiProvider {
         }
     }
 
-    // Find all related roles by role names. Every role have a PUBLIC role, and ACCOUNT_ADMIN
-    // default contains every role.
-    async fn find_related_roles(
-        &self,
-        tenant: &str,
-        role_identities: &[String],
-    ) -> Result<Vec<RoleInfo>> {
-        let tenant_roles_map = self
+    // Get the role hierarchy of the tenant, every role granted to another role is returned once.
+    pub async fn get_role_hierarchy(&self, tenant: &str) -> Result<Vec<RoleInheritance>> {
+        let tenant_roles_map = self.get_tenant_roles_map(tenant).await?;
+        Ok(find_role_hierarchy(&tenant_roles_map))
+    }
+
+    async fn get_tenant_roles_map(&self, tenant: &str) -> Result<HashMap<String, RoleInfo>> {
+        Ok(self
             .get_roles(tenant)
             .await?
             .into_iter()
             .map(|r| (r.identity().to_string(), r))
-            .collect::<HashMap<_, _>>();
-        Ok(find_all_related_roles(&tenant_roles_map, role_identities))
+            .collect())
     }
 }
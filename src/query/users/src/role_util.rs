@@ -54,3 +54,109 @@ pub fn find_all_related_roles(
     }
     result
 }
+
+/// Find a path of role grants from `from` to `to` in a DFS manner, the returned path starts
+/// with `from` and ends with `to`. Granting `from` to `to` creates a cycle if such a path exists.
+pub fn find_role_grant_path(
+    cache: &HashMap<String, RoleInfo>,
+    from: &str,
+    to: &str,
+) -> Option<Vec<String>> {
+    // ACCOUNT_ADMIN contains every role, and every role contains PUBLIC.
+    if from == BUILTIN_ROLE_ACCOUNT_ADMIN || to == BUILTIN_ROLE_PUBLIC {
+        return Some(vec![from.to_string(), to.to_string()]);
+    }
+
+    fn dfs(
+        cache: &HashMap<String, RoleInfo>,
+        role: String,
+        to: &str,
+        visited: &mut HashSet<String>,
+        path: &mut Vec<String>,
+    ) -> bool {
+        if !visited.insert(role.clone()) {
+            return false;
+        }
+        path.push(role);
+        let role = path.last().unwrap();
+        if role == to {
+            return true;
+        }
+        if let Some(role_info) = cache.get(role) {
+            for granted_role in role_info.grants.roles() {
+                if dfs(cache, granted_role, to, visited, path) {
+                    return true;
+                }
+            }
+        }
+        path.pop();
+        false
+    }
+
+    let mut visited = HashSet::new();
+    let mut path = vec![];
+    match dfs(cache, from.to_string(), to, &mut visited, &mut path) {
+        true => Some(path),
+        false => None,
+    }
+}
+
+/// A role granted to another role, `path` is the roles from the root of the hierarchy
+/// down to `child`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoleInheritance {
+    pub parent: String,
+    pub child: String,
+    pub path: Vec<String>,
+}
+
+/// Walk the role hierarchy in a DFS manner from the roles which are not granted to any other
+/// role, every role grant is returned once. The roles granted by a visited role are not walked
+/// again, which also stops the walk at a cycle.
+pub fn find_role_hierarchy(cache: &HashMap<String, RoleInfo>) -> Vec<RoleInheritance> {
+    fn dfs(
+        cache: &HashMap<String, RoleInfo>,
+        role: &str,
+        visited: &mut HashSet<String>,
+        path: &mut Vec<String>,
+        result: &mut Vec<RoleInheritance>,
+    ) {
+        visited.insert(role.to_string());
+        let mut granted_roles = match cache.get(role) {
+            Some(role_info) => role_info.grants.roles(),
+            None => return,
+        };
+        granted_roles.sort();
+        for granted_role in granted_roles {
+            path.push(granted_role.clone());
+            result.push(RoleInheritance {
+                parent: role.to_string(),
+                child: granted_role.clone(),
+                path: path.clone(),
+            });
+            if !visited.contains(&granted_role) {
+                dfs(cache, &granted_role, visited, path, result);
+            }
+            path.pop();
+        }
+    }
+
+    let granted: HashSet<String> = cache
+        .values()
+        .flat_map(|role_info| role_info.grants.roles())
+        .collect();
+    let mut roles: Vec<&String> = cache.keys().collect();
+    roles.sort();
+    // The roots go first, the rest roles are only left unvisited if they are in a cycle.
+    roles.sort_by_key(|role| granted.contains(*role));
+
+    let mut visited = HashSet::new();
+    let mut result = vec![];
+    for role in roles {
+        if !visited.contains(role) {
+            let mut path = vec![role.clone()];
+            dfs(cache, role, &mut visited, &mut path, &mut result);
+        }
+    }
+    result
+}
@@ -17,16 +17,34 @@ use std::sync::Arc;
 use common_base::base::GlobalInstance;
 use common_exception::Result;
 use common_grpc::RpcClientConf;
+use common_management::CopyHistoryApi;
+use common_management::CopyHistoryMgr;
+use common_management::ExternalFunctionApi;
+use common_management::ExternalFunctionMgr;
 use common_management::FileFormatApi;
 use common_management::FileFormatMgr;
+use common_management::MaskingPolicyApi;
+use common_management::MaskingPolicyMgr;
+use common_management::NetworkPolicyApi;
+use common_management::NetworkPolicyMgr;
+use common_management::PipeApi;
+use common_management::PipeMgr;
 use common_management::QuotaApi;
 use common_management::QuotaMgr;
 use common_management::RoleApi;
 use common_management::RoleMgr;
+use common_management::RowAccessPolicyApi;
+use common_management::RowAccessPolicyMgr;
+use common_management::SequenceApi;
+use common_management::SequenceMgr;
 use common_management::SettingApi;
 use common_management::SettingMgr;
 use common_management::StageApi;
 use common_management::StageMgr;
+use common_management::TableStatisticsApi;
+use common_management::TableStatisticsMgr;
+use common_management::TaskApi;
+use common_management::TaskMgr;
 use common_management::UdfApi;
 use common_management::UdfMgr;
 use common_management::UserApi;
@@ -41,11 +59,13 @@ use common_meta_types::MetaError;
 use tracing::warn;
 
 use crate::idm_config::IDMConfig;
+use crate::user_sequence::SequenceCache;
 
 pub struct UserApiProvider {
     meta: MetaStore,
     client: Arc<dyn kvapi::KVApi<Error = MetaError>>,
     idm_config: IDMConfig,
+    pub(crate) sequence_cache: SequenceCache,
 }
 
 impl UserApiProvider {
@@ -84,6 +104,7 @@ impl UserApiProvider {
             meta: client.clone(),
             client: client.arc(),
             idm_config,
+            sequence_cache: SequenceCache::default(),
         }))
     }
 
@@ -114,10 +135,73 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_external_function_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<dyn ExternalFunctionApi>> {
+        Ok(Arc::new(ExternalFunctionMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_udf_api_client(&self, tenant: &str) -> Result<Arc<dyn UdfApi>> {
         Ok(Arc::new(UdfMgr::create(self.client.clone(), tenant)?))
     }
 
+    pub fn get_masking_policy_api_client(&self, tenant: &str) -> Result<Arc<dyn MaskingPolicyApi>> {
+        Ok(Arc::new(MaskingPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_row_access_policy_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<dyn RowAccessPolicyApi>> {
+        Ok(Arc::new(RowAccessPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_sequence_api_client(&self, tenant: &str) -> Result<Arc<dyn SequenceApi>> {
+        Ok(Arc::new(SequenceMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_network_policy_api_client(&self, tenant: &str) -> Result<Arc<dyn NetworkPolicyApi>> {
+        Ok(Arc::new(NetworkPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_copy_history_api_client(&self, tenant: &str) -> Result<Arc<dyn CopyHistoryApi>> {
+        Ok(Arc::new(CopyHistoryMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_table_statistics_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<dyn TableStatisticsApi>> {
+        Ok(Arc::new(TableStatisticsMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_pipe_api_client(&self, tenant: &str) -> Result<Arc<dyn PipeApi>> {
+        Ok(Arc::new(PipeMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_task_api_client(&self, tenant: &str) -> Result<Arc<dyn TaskApi>> {
+        Ok(Arc::new(TaskMgr::create(self.client.clone(), tenant)?))
+    }
+
     pub fn get_tenant_quota_api_client(&self, tenant: &str) -> Result<Arc<dyn QuotaApi>> {
         Ok(Arc::new(QuotaMgr::create(self.client.clone(), tenant)?))
     }
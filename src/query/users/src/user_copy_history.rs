@@ -0,0 +1,30 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::schema::TableCopyHistoryInfo;
+
+use crate::UserApiProvider;
+
+/// Copy history operations.
+impl UserApiProvider {
+    // Get the load history of all the tables for the tenant.
+    pub async fn get_copy_history(&self, tenant: &str) -> Result<Vec<TableCopyHistoryInfo>> {
+        let client = self.get_copy_history_api_client(tenant)?;
+        match client.get_copy_history().await {
+            Err(e) => Err(e.add_message_back("(while get copy history).")),
+            Ok(history) => Ok(history),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::ExternalFunctionInfo;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// External function operations.
+impl UserApiProvider {
+    // Add a new external function.
+    pub async fn add_external_function(
+        &self,
+        tenant: &str,
+        info: ExternalFunctionInfo,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let client = self.get_external_function_api_client(tenant)?;
+        match client.add_external_function(info).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::EXTERNAL_FUNCTION_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get an external function by name.
+    pub async fn get_external_function(
+        &self,
+        tenant: &str,
+        name: &str,
+    ) -> Result<ExternalFunctionInfo> {
+        let client = self.get_external_function_api_client(tenant)?;
+        let res = client.get_external_function(name, MatchSeq::GE(0)).await?;
+        Ok(res.data)
+    }
+
+    // Get all external functions for the tenant.
+    pub async fn get_external_functions(&self, tenant: &str) -> Result<Vec<ExternalFunctionInfo>> {
+        let client = self.get_external_function_api_client(tenant)?;
+        match client.get_external_functions().await {
+            Err(e) => Err(e.add_message_back("(while get external functions).")),
+            Ok(functions) => Ok(functions),
+        }
+    }
+
+    // Drop an external function by name.
+    pub async fn drop_external_function(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let client = self.get_external_function_api_client(tenant)?;
+        match client.drop_external_function(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_EXTERNAL_FUNCTION {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop external function)"))
+                }
+            }
+        }
+    }
+}
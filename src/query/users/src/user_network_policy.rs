@@ -0,0 +1,91 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::NetworkPolicy;
+use common_meta_types::MatchSeq;
+
+use crate::network_policy::validate_network_policy;
+use crate::UserApiProvider;
+
+/// Network policy operations.
+impl UserApiProvider {
+    // Add a new network policy.
+    pub async fn add_network_policy(
+        &self,
+        tenant: &str,
+        policy: NetworkPolicy,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        validate_network_policy(&policy)?;
+        let client = self.get_network_policy_api_client(tenant)?;
+        match client.add_network_policy(policy).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::NETWORK_POLICY_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a network policy by name.
+    pub async fn get_network_policy(&self, tenant: &str, name: &str) -> Result<NetworkPolicy> {
+        let client = self.get_network_policy_api_client(tenant)?;
+        let get_policy = client.get_network_policy(name, MatchSeq::GE(0));
+        Ok(get_policy.await?.data)
+    }
+
+    // Get all network policies for the tenant.
+    pub async fn get_network_policies(&self, tenant: &str) -> Result<Vec<NetworkPolicy>> {
+        let client = self.get_network_policy_api_client(tenant)?;
+        match client.get_network_policies().await {
+            Err(e) => Err(e.add_message_back("(while get network policies).")),
+            Ok(policies) => Ok(policies),
+        }
+    }
+
+    // Drop a network policy by name, it must not be attached to any user.
+    pub async fn drop_network_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        for user in self.get_users(tenant).await? {
+            if user.option.network_policy().map(String::as_str) == Some(name) {
+                return Err(ErrorCode::NetworkPolicyIsUsedByUser(format!(
+                    "network policy {} is used by user {}",
+                    name,
+                    user.identity()
+                )));
+            }
+        }
+
+        let client = self.get_network_policy_api_client(tenant)?;
+        match client.drop_network_policy(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_NETWORK_POLICY {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop network policy)"))
+                }
+            }
+        }
+    }
+}
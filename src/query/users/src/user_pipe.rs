@@ -0,0 +1,108 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeRejectedRecord;
+use common_meta_app::principal::PipeStatus;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// Pipe operations.
+impl UserApiProvider {
+    // Add a new pipe.
+    pub async fn add_pipe(&self, tenant: &str, pipe: PipeInfo, if_not_exists: bool) -> Result<u64> {
+        let client = self.get_pipe_api_client(tenant)?;
+        match client.add_pipe(pipe).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::PIPE_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a pipe by name.
+    pub async fn get_pipe(&self, tenant: &str, name: &str) -> Result<PipeInfo> {
+        let client = self.get_pipe_api_client(tenant)?;
+        let pipe = client.get_pipe(name, MatchSeq::GE(0)).await?;
+        Ok(pipe.data)
+    }
+
+    // Get all pipes for the tenant.
+    pub async fn get_pipes(&self, tenant: &str) -> Result<Vec<PipeInfo>> {
+        let client = self.get_pipe_api_client(tenant)?;
+        match client.get_pipes().await {
+            Err(e) => Err(e.add_message_back("(while get pipes).")),
+            Ok(pipes) => Ok(pipes),
+        }
+    }
+
+    // Drop a pipe by name.
+    pub async fn drop_pipe(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.get_pipe_api_client(tenant)?;
+        match client.drop_pipe(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PIPE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop pipe)"))
+                }
+            }
+        }
+    }
+
+    // Report the status of a pipe.
+    pub async fn set_pipe_status(&self, tenant: &str, status: PipeStatus) -> Result<()> {
+        let client = self.get_pipe_api_client(tenant)?;
+        client.set_pipe_status(status).await
+    }
+
+    // Get the status of all pipes for the tenant.
+    pub async fn get_pipe_statuses(&self, tenant: &str) -> Result<Vec<PipeStatus>> {
+        let client = self.get_pipe_api_client(tenant)?;
+        match client.get_pipe_statuses().await {
+            Err(e) => Err(e.add_message_back("(while get pipe statuses).")),
+            Ok(statuses) => Ok(statuses),
+        }
+    }
+
+    // Record the records a pipe failed to parse.
+    pub async fn add_pipe_rejected_records(
+        &self,
+        tenant: &str,
+        records: Vec<PipeRejectedRecord>,
+        retention: Duration,
+    ) -> Result<()> {
+        let client = self.get_pipe_api_client(tenant)?;
+        client.add_pipe_rejected_records(records, retention).await
+    }
+
+    // Get the recorded rejected records of all pipes for the tenant.
+    pub async fn get_pipe_rejected_records(&self, tenant: &str) -> Result<Vec<PipeRejectedRecord>> {
+        let client = self.get_pipe_api_client(tenant)?;
+        match client.get_pipe_rejected_records().await {
+            Err(e) => Err(e.add_message_back("(while get pipe rejected records).")),
+            Ok(records) => Ok(records),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_base::base::tokio::sync::Mutex;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::SequenceInfo;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// The sequence values reserved by this node from the meta service but not generated yet,
+/// by tenant and sequence name.
+#[derive(Default)]
+pub(crate) struct SequenceCache {
+    sequences: parking_lot::Mutex<HashMap<(String, String), Arc<Mutex<VecDeque<i64>>>>>,
+}
+
+impl SequenceCache {
+    fn get(&self, tenant: &str, name: &str) -> Arc<Mutex<VecDeque<i64>>> {
+        self.sequences
+            .lock()
+            .entry((tenant.to_string(), name.to_string()))
+            .or_default()
+            .clone()
+    }
+
+    fn invalidate(&self, tenant: &str, name: &str) {
+        self.sequences
+            .lock()
+            .remove(&(tenant.to_string(), name.to_string()));
+    }
+}
+
+/// Sequence operations.
+impl UserApiProvider {
+    // Add a new sequence.
+    pub async fn add_sequence(
+        &self,
+        tenant: &str,
+        info: SequenceInfo,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let client = self.get_sequence_api_client(tenant)?;
+        // Values reserved for a dropped sequence of the same name must not be generated.
+        self.sequence_cache.invalidate(tenant, &info.name);
+        match client.add_sequence(info).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::SEQUENCE_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a sequence by name.
+    pub async fn get_sequence(&self, tenant: &str, name: &str) -> Result<SequenceInfo> {
+        let client = self.get_sequence_api_client(tenant)?;
+        let get_sequence = client.get_sequence(name, MatchSeq::GE(0));
+        Ok(get_sequence.await?.data)
+    }
+
+    // Get all sequences for the tenant.
+    pub async fn get_sequences(&self, tenant: &str) -> Result<Vec<SequenceInfo>> {
+        let client = self.get_sequence_api_client(tenant)?;
+        match client.get_sequences().await {
+            Err(e) => Err(e.add_message_back("(while get sequences).")),
+            Ok(sequences) => Ok(sequences),
+        }
+    }
+
+    // Drop a sequence by name.
+    pub async fn drop_sequence(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.get_sequence_api_client(tenant)?;
+        self.sequence_cache.invalidate(tenant, name);
+        match client.drop_sequence(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_SEQUENCE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop sequence)"))
+                }
+            }
+        }
+    }
+
+    // Generate the next `count` values of a sequence, in increasing order of generation.
+    //
+    // The values are taken from the ones this node has reserved, at least `cache_size` values
+    // are reserved from the meta service at once, so the nodes don't contend on the meta
+    // service for each value. Values reserved but never generated are skipped.
+    pub async fn next_sequence_values(
+        &self,
+        tenant: &str,
+        name: &str,
+        count: usize,
+        cache_size: u64,
+    ) -> Result<Vec<i64>> {
+        let cached = self.sequence_cache.get(tenant, name);
+        let mut cached = cached.lock().await;
+        while cached.len() < count {
+            let client = self.get_sequence_api_client(tenant)?;
+            let reserve = cache_size.max((count - cached.len()) as u64);
+            let values = client.reserve_sequence_values(name, reserve).await?;
+            cached.extend(values);
+        }
+        Ok(cached.drain(..count).collect())
+    }
+
+    // Set the current value of a sequence, the values reserved by this node are discarded.
+    pub async fn set_sequence_value(&self, tenant: &str, name: &str, value: i64) -> Result<()> {
+        let client = self.get_sequence_api_client(tenant)?;
+        let cached = self.sequence_cache.get(tenant, name);
+        let mut cached = cached.lock().await;
+        client.set_sequence_value(name, value).await?;
+        cached.clear();
+        Ok(())
+    }
+}
@@ -16,6 +16,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::StageInfo;
 use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
 
 use crate::UserApiProvider;
 
@@ -49,6 +50,37 @@ impl UserApiProvider {
         Ok(get_stage.await?.data)
     }
 
+    // Get one stage with its seq, which is used to update the stage only if it is not changed.
+    pub async fn get_stage_with_seq(
+        &self,
+        tenant: &str,
+        stage_name: &str,
+    ) -> Result<SeqV<StageInfo>> {
+        let stage_api_provider = self.get_stage_api_client(tenant)?;
+        let get_stage = stage_api_provider.get_stage(stage_name, MatchSeq::GE(0));
+        get_stage.await
+    }
+
+    // Update an existing stage.
+    pub async fn update_stage(&self, tenant: &str, info: StageInfo, seq: MatchSeq) -> Result<u64> {
+        let stage_api_provider = self.get_stage_api_client(tenant)?;
+        let update_stage = stage_api_provider.update_stage(info, seq);
+        match update_stage.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back(" (while update stage)")),
+        }
+    }
+
+    // Rename a stage, the files recorded for the stage are moved along.
+    pub async fn rename_stage(&self, tenant: &str, name: &str, new_name: &str) -> Result<()> {
+        let stage_api_provider = self.get_stage_api_client(tenant)?;
+        let rename_stage = stage_api_provider.rename_stage(name, new_name);
+        match rename_stage.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back(" (while rename stage)")),
+        }
+    }
+
     // Get the tenant all stage list.
     pub async fn get_stages(&self, tenant: &str) -> Result<Vec<StageInfo>> {
         let stage_api_provider = self.get_stage_api_client(tenant)?;
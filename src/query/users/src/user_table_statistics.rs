@@ -0,0 +1,67 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::schema::ExternalTableStatistics;
+
+use crate::UserApiProvider;
+
+/// Statistics of external tables, refreshed by the analyze daemon.
+impl UserApiProvider {
+    // Add or replace the statistics of a table.
+    pub async fn upsert_table_statistics(
+        &self,
+        tenant: &str,
+        statistics: ExternalTableStatistics,
+    ) -> Result<u64> {
+        let client = self.get_table_statistics_api_client(tenant)?;
+        client.upsert_table_statistics(statistics).await
+    }
+
+    // Get the statistics of a table.
+    pub async fn get_table_statistics(
+        &self,
+        tenant: &str,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Option<ExternalTableStatistics>> {
+        let client = self.get_table_statistics_api_client(tenant)?;
+        client.get_table_statistics(catalog, database, table).await
+    }
+
+    // Get the statistics of all tables for the tenant.
+    pub async fn list_table_statistics(
+        &self,
+        tenant: &str,
+    ) -> Result<Vec<ExternalTableStatistics>> {
+        let client = self.get_table_statistics_api_client(tenant)?;
+        match client.list_table_statistics().await {
+            Err(e) => Err(e.add_message_back("(while list table statistics).")),
+            Ok(statistics) => Ok(statistics),
+        }
+    }
+
+    // Drop the statistics of a table.
+    pub async fn drop_table_statistics(
+        &self,
+        tenant: &str,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<()> {
+        let client = self.get_table_statistics_api_client(tenant)?;
+        client.drop_table_statistics(catalog, database, table).await
+    }
+}
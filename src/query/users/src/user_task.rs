@@ -0,0 +1,98 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::TaskInfo;
+use common_meta_app::principal::TaskRun;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+use crate::UserApiProvider;
+
+/// Task operations.
+impl UserApiProvider {
+    // Add a new task.
+    pub async fn add_task(&self, tenant: &str, task: TaskInfo, if_not_exists: bool) -> Result<u64> {
+        let client = self.get_task_api_client(tenant)?;
+        match client.add_task(task).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::TASK_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a task by name, with its seq to update it.
+    pub async fn get_task(&self, tenant: &str, name: &str) -> Result<SeqV<TaskInfo>> {
+        let client = self.get_task_api_client(tenant)?;
+        client.get_task(name, MatchSeq::GE(0)).await
+    }
+
+    // Get all tasks for the tenant.
+    pub async fn get_tasks(&self, tenant: &str) -> Result<Vec<TaskInfo>> {
+        let client = self.get_task_api_client(tenant)?;
+        match client.get_tasks().await {
+            Err(e) => Err(e.add_message_back("(while get tasks).")),
+            Ok(tasks) => Ok(tasks),
+        }
+    }
+
+    // Update a task, fails if it has been changed since `seq`.
+    pub async fn update_task(&self, tenant: &str, task: TaskInfo, seq: u64) -> Result<u64> {
+        let client = self.get_task_api_client(tenant)?;
+        client.update_task(task, MatchSeq::Exact(seq)).await
+    }
+
+    // Drop a task by name.
+    pub async fn drop_task(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.get_task_api_client(tenant)?;
+        match client.drop_task(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_TASK {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop task)"))
+                }
+            }
+        }
+    }
+
+    // Record a finished run of a task.
+    pub async fn add_task_run(
+        &self,
+        tenant: &str,
+        run: TaskRun,
+        retention: Duration,
+    ) -> Result<()> {
+        let client = self.get_task_api_client(tenant)?;
+        client.add_task_run(run, retention).await
+    }
+
+    // Get the recorded runs of all tasks for the tenant.
+    pub async fn get_task_runs(&self, tenant: &str) -> Result<Vec<TaskRun>> {
+        let client = self.get_task_api_client(tenant)?;
+        match client.get_task_runs().await {
+            Err(e) => Err(e.add_message_back("(while get task runs).")),
+            Ok(runs) => Ok(runs),
+        }
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod jwt;
+mod network_policy;
 mod role_cache_mgr;
 mod role_mgr;
 mod user_mgr;
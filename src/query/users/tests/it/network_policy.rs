@@ -0,0 +1,87 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+
+use chrono::Utc;
+use common_exception::Result;
+use common_meta_app::principal::NetworkPolicy;
+use common_users::network_policy::network_policy_allows;
+use common_users::network_policy::validate_network_policy;
+use common_users::network_policy::IpNetwork;
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_ip_network() -> Result<()> {
+    let network = IpNetwork::parse("10.0.0.0/8")?;
+    assert!(network.contains(ip("10.1.2.3")));
+    assert!(network.contains(ip("::ffff:10.1.2.3")));
+    assert!(!network.contains(ip("11.0.0.1")));
+    assert!(!network.contains(ip("fd00::1")));
+
+    let network = IpNetwork::parse("192.168.1.7")?;
+    assert!(network.contains(ip("192.168.1.7")));
+    assert!(!network.contains(ip("192.168.1.8")));
+
+    let network = IpNetwork::parse("fd00::/8")?;
+    assert!(network.contains(ip("fd12:3456::1")));
+    assert!(!network.contains(ip("fe80::1")));
+    assert!(!network.contains(ip("10.0.0.1")));
+
+    let network = IpNetwork::parse("0.0.0.0/0")?;
+    assert!(network.contains(ip("8.8.8.8")));
+    assert!(!network.contains(ip("2001:db8::1")));
+
+    assert!(IpNetwork::parse("::/0")?.contains(ip("2001:db8::1")));
+
+    assert!(IpNetwork::parse("10.0.0.0/33").is_err());
+    assert!(IpNetwork::parse("fd00::/129").is_err());
+    assert!(IpNetwork::parse("10.0.0/8").is_err());
+    assert!(IpNetwork::parse("localhost").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_network_policy_allows() -> Result<()> {
+    let policy = NetworkPolicy {
+        name: "np".to_string(),
+        allowed_ip_list: vec!["10.0.0.0/8".to_string(), "2001:db8::/32".to_string()],
+        blocked_ip_list: vec!["10.0.0.1".to_string()],
+        comment: "".to_string(),
+        create_on: Utc::now(),
+    };
+    validate_network_policy(&policy)?;
+
+    assert!(network_policy_allows(&policy, ip("10.0.0.2"))?);
+    assert!(network_policy_allows(&policy, ip("2001:db8::1"))?);
+    assert!(!network_policy_allows(&policy, ip("10.0.0.1"))?);
+    assert!(!network_policy_allows(&policy, ip("::ffff:10.0.0.1"))?);
+    assert!(!network_policy_allows(&policy, ip("192.168.1.1"))?);
+
+    let invalid = NetworkPolicy {
+        blocked_ip_list: vec!["10.0.0.256".to_string()],
+        ..policy.clone()
+    };
+    assert!(validate_network_policy(&invalid).is_err());
+
+    let empty = NetworkPolicy {
+        allowed_ip_list: vec![],
+        ..policy
+    };
+    assert!(validate_network_policy(&empty).is_err());
+    Ok(())
+}
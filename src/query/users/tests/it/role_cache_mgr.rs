@@ -22,6 +22,8 @@ use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserPrivilegeSet;
 use common_users::role_util::find_all_related_roles;
+use common_users::role_util::find_role_grant_path;
+use common_users::role_util::find_role_hierarchy;
 use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
 
@@ -95,3 +97,93 @@ async fn test_find_all_related_roles() -> Result<()> {
     }
     Ok(())
 }
+
+fn roles_with_grants(role_grants: &[(&str, &str)]) -> HashMap<String, RoleInfo> {
+    let mut cached: HashMap<String, RoleInfo> = HashMap::new();
+    for (lhs, rhs) in role_grants {
+        for role in [lhs, rhs] {
+            cached
+                .entry(role.to_string())
+                .or_insert_with(|| RoleInfo::new(role));
+        }
+        cached
+            .get_mut(&lhs.to_string())
+            .unwrap()
+            .grants
+            .grant_role(rhs.to_string())
+    }
+    cached
+}
+
+#[test]
+fn test_find_role_grant_path() -> Result<()> {
+    // role1 -> role2 -> role4 -> role5
+    //       -> role3
+    let cached = roles_with_grants(&[
+        ("role1", "role2"),
+        ("role1", "role3"),
+        ("role2", "role4"),
+        ("role4", "role5"),
+    ]);
+
+    let tests = vec![
+        (
+            "role1",
+            "role5",
+            Some(vec!["role1", "role2", "role4", "role5"]),
+        ),
+        ("role2", "role4", Some(vec!["role2", "role4"])),
+        ("role3", "role3", Some(vec!["role3"])),
+        ("role5", "role1", None),
+        ("role3", "role4", None),
+        ("role6", "role1", None),
+        (
+            "account_admin",
+            "role1",
+            Some(vec!["account_admin", "role1"]),
+        ),
+        ("role1", "public", Some(vec!["role1", "public"])),
+    ];
+    for (from, to, want) in tests {
+        let want = want.map(|path| path.iter().map(|s| s.to_string()).collect::<Vec<_>>());
+        assert_eq!(
+            find_role_grant_path(&cached, from, to),
+            want,
+            "{from} -> {to}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_find_role_hierarchy() -> Result<()> {
+    // role1 -> role2 -> role4
+    //       -> role3 -> role4
+    // role5 -> role6 -> role5
+    let cached = roles_with_grants(&[
+        ("role1", "role2"),
+        ("role1", "role3"),
+        ("role2", "role4"),
+        ("role3", "role4"),
+        ("role5", "role6"),
+        ("role6", "role5"),
+    ]);
+
+    let got = find_role_hierarchy(&cached)
+        .into_iter()
+        .map(|r| (r.parent, r.child, r.path.join(" -> ")))
+        .collect::<Vec<_>>();
+    let want = vec![
+        ("role1", "role2", "role1 -> role2"),
+        ("role2", "role4", "role1 -> role2 -> role4"),
+        ("role1", "role3", "role1 -> role3"),
+        ("role3", "role4", "role1 -> role3 -> role4"),
+        ("role5", "role6", "role5 -> role6"),
+        ("role6", "role5", "role5 -> role6 -> role5"),
+    ]
+    .into_iter()
+    .map(|(parent, child, path)| (parent.to_string(), child.to_string(), path.to_string()))
+    .collect::<Vec<_>>();
+    assert_eq!(got, want);
+    Ok(())
+}
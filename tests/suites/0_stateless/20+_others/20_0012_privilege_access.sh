@@ -97,6 +97,9 @@ echo "select count(*)=1 from fuse_block('default', 't20_0012_a')" | $TEST_USER_C
 echo "GRANT SELECT ON system.fuse_block TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
 echo "select count(*)=1 from fuse_block('default', 't20_0012_a')" | $TEST_USER_CONNECT
 
+## the shares of another tenant can only be listed by account_admin
+echo "show shares of tenant 'other_tenant_20_0012'" | $TEST_USER_CONNECT
+
 ## Drop table.
 echo "drop table default.t20_0012 all" | $MYSQL_CLIENT_CONNECT
 echo "drop table default.t20_0012_a all" | $MYSQL_CLIENT_CONNECT
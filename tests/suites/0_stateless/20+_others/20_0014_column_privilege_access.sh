@@ -0,0 +1,60 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-user\npassword=${TEST_USER_PASSWORD}" >> password.out
+
+## create user, role and table
+echo "create user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $MYSQL_CLIENT_CONNECT
+echo "create role 'test-role'" | $MYSQL_CLIENT_CONNECT
+echo "create table t20_0014(a int, b int)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0014 values(1, 2)" | $MYSQL_CLIENT_CONNECT
+
+## no privilege
+echo "select a from t20_0014" | $TEST_USER_CONNECT
+
+## grant column privilege to the default role of the user
+echo "GRANT SELECT(a) ON default.t20_0014 TO ROLE 'test-role'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT ROLE 'test-role' TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "ALTER USER 'test-user'@'$QUERY_MYSQL_HANDLER_HOST' WITH DEFAULT_ROLE = 'test-role'" | $MYSQL_CLIENT_CONNECT
+
+## verify
+echo "select a from t20_0014" | $TEST_USER_CONNECT
+echo "select * from t20_0014" | $TEST_USER_CONNECT
+echo "select b from t20_0014" | $TEST_USER_CONNECT
+echo "desc t20_0014" | $TEST_USER_CONNECT
+echo "select name from system.columns where table = 't20_0014'" | $TEST_USER_CONNECT
+
+## column privilege granted through a role granted to the current role
+echo "create role 'test-parent-role'" | $MYSQL_CLIENT_CONNECT
+echo "create table t20_0014_2(c int, d int)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0014_2 values(3, 4)" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT(c) ON default.t20_0014_2 TO ROLE 'test-parent-role'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT ROLE 'test-parent-role' TO ROLE 'test-role'" | $MYSQL_CLIENT_CONNECT
+echo "select c from t20_0014_2" | $TEST_USER_CONNECT
+echo "select d from t20_0014_2" | $TEST_USER_CONNECT
+echo "select name from system.columns where table = 't20_0014_2'" | $TEST_USER_CONNECT
+
+## a denied column can't be a join key of USING or NATURAL
+echo "create table t20_0014_3(a int, b int)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0014_3 values(1, 2)" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT(a) ON default.t20_0014_3 TO ROLE 'test-role'" | $MYSQL_CLIENT_CONNECT
+echo "select a from t20_0014 join t20_0014_3 using(a)" | $TEST_USER_CONNECT
+echo "select a from t20_0014 join t20_0014_3 using(b)" | $TEST_USER_CONNECT
+echo "select a from t20_0014 natural join t20_0014_3" | $TEST_USER_CONNECT
+
+## the privilege on table means all the columns
+echo "GRANT SELECT ON default.t20_0014 TO ROLE 'test-role'" | $MYSQL_CLIENT_CONNECT
+echo "select * from t20_0014" | $TEST_USER_CONNECT
+
+## cleanup
+echo "drop table default.t20_0014 all" | $MYSQL_CLIENT_CONNECT
+echo "drop table default.t20_0014_2 all" | $MYSQL_CLIENT_CONNECT
+echo "drop table default.t20_0014_3 all" | $MYSQL_CLIENT_CONNECT
+echo "drop role 'test-role'" | $MYSQL_CLIENT_CONNECT
+echo "drop role 'test-parent-role'" | $MYSQL_CLIENT_CONNECT
+echo "drop user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+rm -rf password.out
@@ -0,0 +1,43 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-user\npassword=${TEST_USER_PASSWORD}" >> password.out
+
+## create user, role, policy and tables
+echo "create user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $MYSQL_CLIENT_CONNECT
+echo "create role 'test-role'" | $MYSQL_CLIENT_CONNECT
+echo "create row access policy p20_0015 as (region string) returns boolean -> region = 'eu'" | $MYSQL_CLIENT_CONNECT
+echo "create table t20_0015(id int, region string)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0015 values(1, 'eu'), (2, 'us'), (3, 'eu')" | $MYSQL_CLIENT_CONNECT
+echo "alter table t20_0015 add row access policy p20_0015 on (region)" | $MYSQL_CLIENT_CONNECT
+echo "create view v20_0015 as select id, region from t20_0015" | $MYSQL_CLIENT_CONNECT
+
+echo "GRANT SELECT ON default.* TO ROLE 'test-role'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT ROLE 'test-role' TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "ALTER USER 'test-user'@'$QUERY_MYSQL_HANDLER_HOST' WITH DEFAULT_ROLE = 'test-role'" | $MYSQL_CLIENT_CONNECT
+
+## the hidden rows are not visible to the user
+echo "select id, region from t20_0015 order by id" | $TEST_USER_CONNECT
+echo "select id from t20_0015 where region = 'us'" | $TEST_USER_CONNECT
+echo "select count(*) from t20_0015" | $TEST_USER_CONNECT
+echo "select id from t20_0015 union all select id from t20_0015 where id = 2 order by id" | $TEST_USER_CONNECT
+echo "select id from (select id, region from t20_0015) t where region <> 'eu'" | $TEST_USER_CONNECT
+echo "select id from t20_0015 where id in (select id from t20_0015 where region = 'us')" | $TEST_USER_CONNECT
+echo "select id, region from v20_0015 order by id" | $TEST_USER_CONNECT
+
+## the rows are visible to the user once the policy is bypassed
+echo "GRANT BYPASS ROW ACCESS POLICY ON *.* TO ROLE 'test-role'" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t20_0015" | $TEST_USER_CONNECT
+echo "select count(*) from v20_0015" | $TEST_USER_CONNECT
+
+## cleanup
+echo "drop view default.v20_0015" | $MYSQL_CLIENT_CONNECT
+echo "drop table default.t20_0015 all" | $MYSQL_CLIENT_CONNECT
+echo "drop row access policy p20_0015" | $MYSQL_CLIENT_CONNECT
+echo "drop role 'test-role'" | $MYSQL_CLIENT_CONNECT
+echo "drop user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+rm -rf password.out
@@ -0,0 +1,46 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-user\npassword=${TEST_USER_PASSWORD}" >> password.out
+
+## create user and stage
+echo "create user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s20_0016" | $MYSQL_CLIENT_CONNECT
+echo "create stage s20_0016" | $MYSQL_CLIENT_CONNECT
+
+## no privilege
+echo "show stage files in @s20_0016" | $TEST_USER_CONNECT
+
+echo "remove @s20_0016" | $TEST_USER_CONNECT
+
+## READ on the stage is not enough
+echo "GRANT READ ON STAGE s20_0016 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "show stage files in @s20_0016" | $TEST_USER_CONNECT
+echo "remove @s20_0016" | $TEST_USER_CONNECT
+
+## grant WRITE on the stage
+echo "GRANT WRITE ON STAGE s20_0016 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "show stage files in @s20_0016" | $TEST_USER_CONNECT
+echo "select 'show stage files ok'" | $TEST_USER_CONNECT
+echo "remove @s20_0016" | $TEST_USER_CONNECT
+echo "select 'remove ok'" | $TEST_USER_CONNECT
+
+## presign needs READ to download and WRITE to upload, the endpoint is never requested
+echo "drop stage if exists s20_0016_s3" | $MYSQL_CLIENT_CONNECT
+echo "create stage s20_0016_s3 url = 's3://testbucket/data/' connection = (endpoint_url = 'https://s3.example.com' region = 'us-east-1' access_key_id = 'access_key' secret_access_key = 'secret_key')" | $MYSQL_CLIENT_CONNECT
+echo "presign download @s20_0016_s3/books.csv" | $TEST_USER_CONNECT | awk '{print $1}'
+echo "GRANT READ ON STAGE s20_0016_s3 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "presign download @s20_0016_s3/books.csv" | $TEST_USER_CONNECT | awk '{print $1}'
+echo "presign upload @s20_0016_s3/books.csv" | $TEST_USER_CONNECT | awk '{print $1}'
+echo "GRANT WRITE ON STAGE s20_0016_s3 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "presign upload @s20_0016_s3/books.csv" | $TEST_USER_CONNECT | awk '{print $1}'
+
+## cleanup
+echo "drop stage s20_0016" | $MYSQL_CLIENT_CONNECT
+echo "drop stage s20_0016_s3" | $MYSQL_CLIENT_CONNECT
+echo "drop user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+rm -rf password.out
@@ -0,0 +1,60 @@
+#!/usr/bin/env python3
+
+import os
+import time
+import mysql.connector
+import sys
+
+CURDIR = os.path.dirname(os.path.realpath(__file__))
+sys.path.insert(0, os.path.join(CURDIR, "../../../helpers"))
+
+from native_client import NativeClient
+from native_client import prompt
+
+log = None
+
+# client1 send long query, mydb kill it and check the query is gone from SHOW PROCESSLIST
+# once KILL QUERY returns, KILL QUERY waits at most kill_query_timeout_ms for the query to stop.
+
+mydb = mysql.connector.connect(
+    host="127.0.0.1", user="root", passwd="root", port="3307"
+)
+
+
+def running_long_queries(cursor):
+    cursor.execute("SHOW PROCESSLIST;")
+    rows = cursor.fetchall()
+    extra_info = cursor.column_names.index("extra_info")
+    return [
+        row
+        for row in rows
+        if "SELECT max(number)" in row[extra_info]
+        and "system.processes" not in row[extra_info]
+    ]
+
+
+with NativeClient(name="client1>") as client1:
+    client1.expect(prompt)
+    client1.expect("")
+
+    client1.send(
+        "SELECT max(number), sum(number) FROM numbers_mt(100000000000) GROUP BY number % 3, number % 4, number % 5 LIMIT 10;"
+    )
+    time.sleep(0.5)
+
+    mycursor = mydb.cursor()
+    mycursor.execute(
+        "SELECT mysql_connection_id FROM system.processes WHERE extra_info LIKE '%SELECT max(number)%' AND extra_info NOT LIKE '%system.processes%';"
+    )
+    res = mycursor.fetchone()
+
+    mycursor.execute("SET kill_query_timeout_ms = 5000;")
+    start = time.time()
+    mycursor.execute("kill query " + str(res[0]) + ";")
+    elapsed = time.time() - start
+
+    assert elapsed < 5
+    assert len(running_long_queries(mycursor)) == 0
+    print("killed query is gone from processlist")
+
+    client1.expect(prompt)
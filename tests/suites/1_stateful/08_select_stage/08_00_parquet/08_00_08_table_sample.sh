@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+
+DATADIR_PATH="/tmp/08_00_08"
+rm -rf ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}
+DATADIR="fs://$DATADIR_PATH/"
+
+echo "drop stage if exists s8;" | $MYSQL_CLIENT_CONNECT
+echo "create stage s8 url = '${DATADIR}' FILE_FORMAT = (type = PARQUET);"  | $MYSQL_CLIENT_CONNECT
+
+cp "$CURDIR"/../../../../data/tuple.parquet ${DATADIR_PATH}/tuple1.parquet
+cp "$CURDIR"/../../../../data/tuple.parquet ${DATADIR_PATH}/tuple2.parquet
+
+echo "select count(*) from @s8 (pattern => '.*parquet') tablesample system (0);" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from @s8 (pattern => '.*parquet') tablesample system (100) repeatable (1);" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from @s8 (pattern => '.*parquet') tablesample bernoulli (0);" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from @s8 (pattern => '.*parquet') tablesample bernoulli (100) where id > 1;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop stage if exists s8;" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,31 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+
+DATADIR_PATH="/tmp/08_00_09/"
+rm -rf ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}/data
+DATADIR="fs://$DATADIR_PATH/"
+
+cp "$CURDIR"/../../../../data/json_sample.ndjson ${DATADIR_PATH}/data
+echo '[{"id":1,"tags":["a"]},{"id":1000,"tags":[],"extra":{"k":1.5}}]' > ${DATADIR_PATH}/data/sample.json
+
+echo "drop stage if exists s9;" | $MYSQL_CLIENT_CONNECT
+echo "create stage s9 url = '${DATADIR}' FILE_FORMAT = (type = NDJSON);"  | $MYSQL_CLIENT_CONNECT
+
+echo "--- ndjson:"
+echo "select * from infer_schema(location => '@s9/data/json_sample.ndjson');" | $MYSQL_CLIENT_CONNECT
+
+echo "--- sample_rows:"
+echo "select * from infer_schema(location => '@s9/data/json_sample.ndjson', sample_rows => 1);" | $MYSQL_CLIENT_CONNECT
+
+echo "--- flatten_nested:"
+echo "select * from infer_schema(location => '@s9/data/json_sample.ndjson', flatten_nested => true);" | $MYSQL_CLIENT_CONNECT
+
+echo "--- json:"
+echo "select * from infer_schema(location => '@s9/data/sample.json', file_format => 'JSON');" | $MYSQL_CLIENT_CONNECT
+
+echo "drop stage if exists s9;" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,47 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop share if exists test_share" | $MYSQL_CLIENT_CONNECT
+echo "drop database if exists test_database" | $MYSQL_CLIENT_CONNECT
+echo "drop database if exists shared"  | $SHARING_MYSQL_CLIENT_CONNECT
+
+# prepare shared database and tables
+echo "CREATE SHARE test_share" | $MYSQL_CLIENT_CONNECT
+echo "CREATE DATABASE test_database" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_database.t1 (number UInt64)" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_database.t2 (number UInt64)" | $MYSQL_CLIENT_CONNECT
+echo "INSERT INTO test_database.t1 VALUES (1),(2),(3)" | $MYSQL_CLIENT_CONNECT
+echo "INSERT INTO test_database.t2 VALUES (4),(5)" | $MYSQL_CLIENT_CONNECT
+echo "GRANT USAGE ON DATABASE test_database TO SHARE test_share" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT ON TABLE test_database.t1 TO SHARE test_share" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT ON TABLE test_database.t2 TO SHARE test_share" | $MYSQL_CLIENT_CONNECT
+echo "ALTER SHARE test_share ADD ACCOUNTS = (shared_tenant) SHARE_RESTRICTIONS = (READ_ONLY = true)" | $MYSQL_CLIENT_CONNECT
+echo "ALTER SHARE test_share SET COMMENT = 'read_only_share'" | $MYSQL_CLIENT_CONNECT
+echo "SHOW SHARES" | $MYSQL_CLIENT_CONNECT | awk '{print $(NF-5), $(NF-4), $NF}'
+
+# read-only tenant can still select the shared tables, row counts are computed from the data
+echo "CREATE DATABASE if not exists shared FROM SHARE test_tenant.test_share" | $SHARING_MYSQL_CLIENT_CONNECT
+echo "SELECT * FROM shared.t1 ORDER BY number" | $SHARING_MYSQL_CLIENT_CONNECT
+echo "SELECT count(*) FROM shared.t1" | $SHARING_MYSQL_CLIENT_CONNECT
+echo "SELECT count(*) FROM shared.t2" | $SHARING_MYSQL_CLIENT_CONNECT
+
+# dropped table is no longer accessible through the share
+echo "DROP TABLE test_database.t2" | $MYSQL_CLIENT_CONNECT
+echo "SELECT * FROM shared.t2" | $SHARING_MYSQL_CLIENT_CONNECT 2>&1 | grep -q "ERROR" && echo "t2 is not accessible"
+
+# revoked table is no longer accessible through the share
+echo "REVOKE SELECT ON TABLE test_database.t1 FROM SHARE test_share" | $MYSQL_CLIENT_CONNECT
+echo "SELECT * FROM shared.t1" | $SHARING_MYSQL_CLIENT_CONNECT 2>&1 | grep -q "ERROR" && echo "t1 is not accessible"
+
+# removed tenant can not access the share any more
+echo "GRANT SELECT ON TABLE test_database.t1 TO SHARE test_share" | $MYSQL_CLIENT_CONNECT
+echo "SELECT count(*) FROM shared.t1" | $SHARING_MYSQL_CLIENT_CONNECT
+echo "ALTER SHARE test_share REMOVE ACCOUNTS = (shared_tenant)" | $MYSQL_CLIENT_CONNECT
+echo "SELECT * FROM shared.t1" | $SHARING_MYSQL_CLIENT_CONNECT 2>&1 | grep -q "ERROR" && echo "shared_tenant is not allowed"
+
+## Drop table.
+echo "drop database if exists shared"  | $SHARING_MYSQL_CLIENT_CONNECT
+echo "drop share if exists test_share" | $MYSQL_CLIENT_CONNECT
+echo "drop database if exists test_database" | $MYSQL_CLIENT_CONNECT